@@ -0,0 +1,226 @@
+//! Runtime capability discovery
+//!
+//! The SDK is compiled with a selection of Cargo features, so the formats,
+//! backends and commands available differ between builds (the CLI, the WASM
+//! module, embedding applications). [`capabilities`] reports what this build
+//! supports, so frontends can adapt their UI up front instead of offering an
+//! option and failing with a feature-not-available error:
+//!
+//! - **features** - the enabled Cargo features
+//! - **import / export formats** - the formats of the default
+//!   [`ImporterRegistry`] and [`ExporterRegistry`], with their versions
+//! - **SQL dialects** - the dialects the SQL importer parses
+//! - **LLM backends** - the backends available for schema refinement
+//!
+//! # Example
+//!
+//! ```rust
+//! let capabilities = data_modelling_core::capabilities();
+//! assert!(capabilities.can_import("sql"));
+//! assert!(capabilities.sql_dialects.iter().any(|d| d == "postgres"));
+//!
+//! let json = serde_json::to_string(&capabilities).unwrap();
+//! assert!(json.contains("\"exportFormats\""));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::export::ExporterRegistry;
+use crate::import::{ImporterRegistry, SQLImporter};
+
+/// Names of the Cargo features that are enabled in this build
+macro_rules! enabled_features {
+    ($($feature:literal),* $(,)?) => {
+        [$(($feature, cfg!(feature = $feature))),*]
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(feature, _)| feature.to_string())
+            .collect::<Vec<_>>()
+    };
+}
+
+/// What this build of the SDK supports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /// SDK version
+    pub version: String,
+    /// Enabled Cargo features, sorted
+    pub features: Vec<String>,
+    /// Formats that can be imported, sorted by name
+    pub import_formats: Vec<FormatCapability>,
+    /// Formats that can be exported, sorted by name
+    pub export_formats: Vec<FormatCapability>,
+    /// SQL dialects that can be imported, sorted
+    pub sql_dialects: Vec<String>,
+    /// LLM backends available for schema refinement (e.g. "ollama", "llama.cpp")
+    pub llm_backends: Vec<String>,
+}
+
+/// An import or export format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatCapability {
+    /// Format name, as accepted by the registries and the CLI
+    pub name: String,
+    /// Short human-readable description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// File extension of exported output (without the leading dot)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_extension: Option<String>,
+    /// MIME content type of exported output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Supported versions of the format (empty if unversioned)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<String>,
+}
+
+impl Capabilities {
+    /// Check whether a Cargo feature is enabled
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
+    /// Look up an import format (case-insensitive, `_` and `-` are equivalent)
+    pub fn import_format(&self, name: &str) -> Option<&FormatCapability> {
+        find_format(&self.import_formats, name)
+    }
+
+    /// Look up an export format (case-insensitive, `_` and `-` are equivalent)
+    pub fn export_format(&self, name: &str) -> Option<&FormatCapability> {
+        find_format(&self.export_formats, name)
+    }
+
+    /// Check whether a format can be imported
+    pub fn can_import(&self, name: &str) -> bool {
+        self.import_format(name).is_some()
+    }
+
+    /// Check whether a format can be exported
+    pub fn can_export(&self, name: &str) -> bool {
+        self.export_format(name).is_some()
+    }
+}
+
+/// Capabilities of this build of the SDK
+pub fn capabilities() -> Capabilities {
+    let importers = ImporterRegistry::default();
+    let import_formats = importers
+        .names()
+        .into_iter()
+        .filter_map(|name| importers.get(&name))
+        .map(|importer| FormatCapability {
+            name: importer.name().to_string(),
+            description: None,
+            file_extension: None,
+            content_type: None,
+            versions: to_strings(importer.versions()),
+        })
+        .collect();
+
+    let export_formats = ExporterRegistry::default()
+        .iter()
+        .map(|exporter| FormatCapability {
+            name: exporter.name().to_string(),
+            description: Some(exporter.description())
+                .filter(|d| !d.is_empty())
+                .map(str::to_string),
+            file_extension: Some(exporter.file_extension().to_string()),
+            content_type: Some(exporter.content_type().to_string()),
+            versions: to_strings(exporter.versions()),
+        })
+        .collect();
+
+    let mut llm_backends = Vec::new();
+    if cfg!(feature = "llm-online") {
+        llm_backends.push("ollama".to_string());
+    }
+    if cfg!(feature = "llm-offline") {
+        llm_backends.push("llama.cpp".to_string());
+    }
+
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features: enabled_features!(
+            "api-backend",
+            "aws",
+            "azure-storage",
+            "bpmn",
+            "bundle",
+            "config",
+            "database",
+            "databricks",
+            "databricks-dialect",
+            "dmn",
+            "duckdb-backend",
+            "encryption",
+            "gcs-storage",
+            "git",
+            "iceberg",
+            "iceberg-glue",
+            "inference",
+            "llm",
+            "llm-offline",
+            "llm-online",
+            "mapping",
+            "native-fs",
+            "object-storage",
+            "odps-validation",
+            "openapi",
+            "pipeline",
+            "png-export",
+            "postgres-backend",
+            "s3",
+            "schema-registry",
+            "schema-validation",
+            "sqlite",
+            "staging",
+            "staging-postgres",
+            "wasm",
+            "xsd",
+        ),
+        import_formats,
+        export_formats,
+        sql_dialects: to_strings(SQLImporter::DIALECTS),
+        llm_backends,
+    }
+}
+
+fn find_format<'a>(formats: &'a [FormatCapability], name: &str) -> Option<&'a FormatCapability> {
+    let name = name.trim().to_lowercase().replace('_', "-");
+    formats.iter().find(|f| f.name == name)
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = capabilities();
+        assert_eq!(capabilities.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(capabilities.has_feature("xsd"), cfg!(feature = "xsd"));
+        assert_eq!(capabilities.can_import("xsd"), cfg!(feature = "xsd"));
+        assert!(!capabilities.has_feature("default"));
+
+        assert!(capabilities.can_import("JSON_Schema"));
+        assert!(!capabilities.can_import("rdf"));
+        let protobuf = capabilities.export_format("protobuf").unwrap();
+        assert_eq!(protobuf.versions, vec!["proto2", "proto3"]);
+        assert_eq!(protobuf.file_extension.as_deref(), Some("proto"));
+
+        let mut sorted = capabilities.features.clone();
+        sorted.sort();
+        assert_eq!(capabilities.features, sorted);
+        assert_eq!(
+            capabilities.llm_backends.contains(&"ollama".to_string()),
+            cfg!(feature = "llm-online")
+        );
+    }
+}
@@ -0,0 +1,734 @@
+//! Layered SDK configuration
+//!
+//! Settings shared by the CLI, the pipeline and library consumers (default SQL
+//! dialect, LLM endpoint, API URL, authentication mode and storage root) are read
+//! from up to three layers, each overriding the one before:
+//!
+//! 1. The user configuration, `~/.config/odm/config.toml` (or
+//!    `$XDG_CONFIG_HOME/odm/config.toml`, or the file named by `ODM_CONFIG`)
+//! 2. The workspace configuration, `.odm.toml` in the workspace directory or
+//!    the nearest parent directory containing one
+//! 3. `ODM_*` environment variables
+//!
+//! Each file may define named profiles under `[profiles.<name>]`, which overlay
+//! the settings of that file when the profile is selected. The profile is chosen
+//! explicitly, by `ODM_PROFILE`, or by a top-level `profile` key in the workspace
+//! or user configuration, in that order.
+//!
+//! Relative paths in a file are resolved against the directory containing it.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::config::ConfigLoader;
+//!
+//! let config = ConfigLoader::new()
+//!     .with_workspace(std::env::current_dir()?)
+//!     .load()?;
+//! let dialect = config.sql_dialect().unwrap_or("generic");
+//! let auth_mode = config.auth_mode()?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use crate::auth::AuthMode;
+#[cfg(any(feature = "llm", feature = "llm-online", feature = "llm-offline"))]
+use crate::llm::LlmMode;
+
+/// Workspace configuration filename
+pub const WORKSPACE_CONFIG_FILENAME: &str = ".odm.toml";
+
+/// User configuration filename, within the `odm` configuration directory
+pub const USER_CONFIG_FILENAME: &str = "config.toml";
+
+/// Default Ollama API URL when an LLM model is configured without a URL
+pub const DEFAULT_LLM_URL: &str = "http://localhost:11434";
+
+/// Default LLM model when an LLM URL is configured without a model
+pub const DEFAULT_LLM_MODEL: &str = "llama3.2";
+
+/// Environment variable for the user configuration file path
+pub const ENV_CONFIG: &str = "ODM_CONFIG";
+
+/// Environment variable for the selected profile
+pub const ENV_PROFILE: &str = "ODM_PROFILE";
+
+/// Environment variable for the default SQL dialect
+pub const ENV_SQL_DIALECT: &str = "ODM_SQL_DIALECT";
+
+/// Environment variable for the API URL
+pub const ENV_API_URL: &str = "ODM_API_URL";
+
+/// Environment variable for the authentication mode
+pub const ENV_AUTH_MODE: &str = "ODM_AUTH_MODE";
+
+/// Environment variable for the LLM endpoint URL
+pub const ENV_LLM_URL: &str = "ODM_LLM_URL";
+
+/// Environment variable for the LLM model name
+pub const ENV_LLM_MODEL: &str = "ODM_LLM_MODEL";
+
+/// Environment variable for the local LLM model file
+pub const ENV_LLM_MODEL_PATH: &str = "ODM_LLM_MODEL_PATH";
+
+/// Environment variable for the storage root
+pub const ENV_STORAGE_ROOT: &str = "ODM_STORAGE_ROOT";
+
+/// Configuration errors
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse config {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+
+    #[error("Unknown profile: {0}")]
+    UnknownProfile(String),
+
+    #[error("Invalid configuration: {0}")]
+    Invalid(String),
+
+    #[error("Failed to serialize config: {0}")]
+    Serialization(String),
+}
+
+/// Authentication mode setting
+///
+/// The `online` mode takes its API URL from the `[api]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthModeSetting {
+    None,
+    Web,
+    Local,
+    Online,
+}
+
+impl std::str::FromStr for AuthModeSetting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(AuthModeSetting::None),
+            "web" => Ok(AuthModeSetting::Web),
+            "local" => Ok(AuthModeSetting::Local),
+            "online" => Ok(AuthModeSetting::Online),
+            _ => Err(format!(
+                "Unknown auth mode: {}. Use 'none', 'web', 'local' or 'online'.",
+                s
+            )),
+        }
+    }
+}
+
+/// Default settings section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DefaultsSection {
+    /// SQL dialect used for import and export when none is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_dialect: Option<String>,
+}
+
+/// LLM settings section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LlmSection {
+    /// Ollama API URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Model name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// GGUF model file for offline refinement
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_path: Option<PathBuf>,
+
+    /// GPU layers to offload in offline mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_layers: Option<u32>,
+}
+
+/// API settings section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiSection {
+    /// Base URL of the API server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Authentication settings section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthSection {
+    /// Authentication mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<AuthModeSetting>,
+}
+
+/// Storage settings section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageSection {
+    /// Root directory of workspace storage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<PathBuf>,
+}
+
+/// Settings of one configuration layer or profile
+///
+/// Every value is optional; unset values fall through to lower layers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub defaults: DefaultsSection,
+
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub llm: LlmSection,
+
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub api: ApiSection,
+
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub auth: AuthSection,
+
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub storage: StorageSection,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+impl Settings {
+    /// Override these settings with the values set in `other`
+    pub fn merge(&mut self, other: Settings) {
+        fn set<T>(value: &mut Option<T>, other: Option<T>) {
+            if other.is_some() {
+                *value = other;
+            }
+        }
+
+        set(&mut self.defaults.sql_dialect, other.defaults.sql_dialect);
+        set(&mut self.llm.url, other.llm.url);
+        set(&mut self.llm.model, other.llm.model);
+        set(&mut self.llm.model_path, other.llm.model_path);
+        set(&mut self.llm.gpu_layers, other.llm.gpu_layers);
+        set(&mut self.api.url, other.api.url);
+        set(&mut self.auth.mode, other.auth.mode);
+        set(&mut self.storage.root, other.storage.root);
+    }
+
+    /// Override these settings with `ODM_*` environment variables
+    fn apply_env(&mut self, env: &impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        if let Some(dialect) = env(ENV_SQL_DIALECT) {
+            self.defaults.sql_dialect = Some(dialect);
+        }
+        if let Some(url) = env(ENV_API_URL) {
+            self.api.url = Some(url);
+        }
+        if let Some(mode) = env(ENV_AUTH_MODE) {
+            self.auth.mode = Some(
+                mode.parse()
+                    .map_err(|e| ConfigError::Invalid(format!("{}: {}", ENV_AUTH_MODE, e)))?,
+            );
+        }
+        if let Some(url) = env(ENV_LLM_URL) {
+            self.llm.url = Some(url);
+        }
+        if let Some(model) = env(ENV_LLM_MODEL) {
+            self.llm.model = Some(model);
+        }
+        if let Some(path) = env(ENV_LLM_MODEL_PATH) {
+            self.llm.model_path = Some(PathBuf::from(path));
+        }
+        if let Some(root) = env(ENV_STORAGE_ROOT) {
+            self.storage.root = Some(PathBuf::from(root));
+        }
+        Ok(())
+    }
+
+    /// Resolve relative paths against `base`
+    fn resolve_paths(&mut self, base: &Path) {
+        for path in [&mut self.llm.model_path, &mut self.storage.root]
+            .into_iter()
+            .flatten()
+        {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        }
+    }
+}
+
+/// Contents of one configuration file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFile {
+    /// Profile selected by this file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    /// Settings applied regardless of profile
+    #[serde(flatten)]
+    pub settings: Settings,
+
+    /// Named profiles overlaying `settings`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Settings>,
+}
+
+impl ConfigFile {
+    /// Parse a configuration file from a TOML string
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Load a configuration file, resolving relative paths against its directory
+    ///
+    /// Returns `None` when the file does not exist.
+    pub fn load(path: &Path) -> Result<Option<Self>, ConfigError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(ConfigError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                });
+            }
+        };
+        let mut file = Self::parse(&content).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+
+        if let Some(base) = path.parent() {
+            file.settings.resolve_paths(base);
+            for settings in file.profiles.values_mut() {
+                settings.resolve_paths(base);
+            }
+        }
+        Ok(Some(file))
+    }
+}
+
+/// Default user configuration file path
+///
+/// `ODM_CONFIG` if set, otherwise `odm/config.toml` in `$XDG_CONFIG_HOME`,
+/// `~/.config` or, on Windows, `%APPDATA%`.
+pub fn user_config_path() -> Option<PathBuf> {
+    user_config_path_with(&|name| std::env::var(name).ok())
+}
+
+fn user_config_path_with(env: &impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    if let Some(path) = env(ENV_CONFIG) {
+        return Some(PathBuf::from(path));
+    }
+    let config_dir = env("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env("HOME").map(|home| Path::new(&home).join(".config")))
+        .or_else(|| env("APPDATA").map(PathBuf::from))?;
+    Some(config_dir.join("odm").join(USER_CONFIG_FILENAME))
+}
+
+/// Find the workspace configuration file in `dir` or its nearest parent
+pub fn find_workspace_config(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(WORKSPACE_CONFIG_FILENAME))
+        .find(|path| path.is_file())
+}
+
+/// Loads the layered configuration
+#[derive(Debug, Clone, Default)]
+pub struct ConfigLoader {
+    user_config: Option<PathBuf>,
+    skip_user_config: bool,
+    workspace: Option<PathBuf>,
+    profile: Option<String>,
+    skip_env: bool,
+}
+
+impl ConfigLoader {
+    /// Create a loader reading the default user configuration only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the user configuration from `path` instead of the default location
+    pub fn with_user_config(mut self, path: impl Into<PathBuf>) -> Self {
+        self.user_config = Some(path.into());
+        self.skip_user_config = false;
+        self
+    }
+
+    /// Do not read a user configuration
+    pub fn without_user_config(mut self) -> Self {
+        self.skip_user_config = true;
+        self
+    }
+
+    /// Read the workspace configuration found from `dir`
+    pub fn with_workspace(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.workspace = Some(dir.into());
+        self
+    }
+
+    /// Select a profile, overriding `ODM_PROFILE` and the configuration files
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Ignore `ODM_*` environment variables
+    pub fn without_env(mut self) -> Self {
+        self.skip_env = true;
+        self
+    }
+
+    /// Load the configuration
+    pub fn load(&self) -> Result<SdkConfig, ConfigError> {
+        if self.skip_env {
+            self.load_with_env(|_| None)
+        } else {
+            self.load_with_env(|name| std::env::var(name).ok())
+        }
+    }
+
+    /// Load the configuration, reading environment variables through `env`
+    pub fn load_with_env(
+        &self,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<SdkConfig, ConfigError> {
+        let mut sources = Vec::new();
+
+        let user_path = if self.skip_user_config {
+            None
+        } else {
+            self.user_config
+                .clone()
+                .or_else(|| user_config_path_with(&env))
+        };
+        let user = match user_path {
+            Some(path) => ConfigFile::load(&path)?.inspect(|_| sources.push(path)),
+            None => None,
+        };
+
+        let workspace_path = self.workspace.as_deref().and_then(find_workspace_config);
+        let workspace = match workspace_path {
+            Some(path) => ConfigFile::load(&path)?.inspect(|_| sources.push(path)),
+            None => None,
+        };
+
+        let profile = self
+            .profile
+            .clone()
+            .or_else(|| env(ENV_PROFILE).filter(|profile| !profile.is_empty()))
+            .or_else(|| workspace.as_ref().and_then(|file| file.profile.clone()))
+            .or_else(|| user.as_ref().and_then(|file| file.profile.clone()));
+
+        let mut settings = Settings::default();
+        let mut profile_found = false;
+        for mut file in [user, workspace].into_iter().flatten() {
+            settings.merge(file.settings);
+            if let Some(overlay) = profile.as_ref().and_then(|name| file.profiles.remove(name)) {
+                settings.merge(overlay);
+                profile_found = true;
+            }
+        }
+        if let Some(name) = &profile
+            && !profile_found
+        {
+            return Err(ConfigError::UnknownProfile(name.clone()));
+        }
+
+        settings.apply_env(&env)?;
+
+        Ok(SdkConfig {
+            profile,
+            settings,
+            sources,
+        })
+    }
+}
+
+/// Resolved SDK configuration
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SdkConfig {
+    /// Selected profile
+    pub profile: Option<String>,
+
+    /// Merged settings
+    pub settings: Settings,
+
+    /// Configuration files that were read, lowest precedence first
+    pub sources: Vec<PathBuf>,
+}
+
+impl SdkConfig {
+    /// Load the configuration for a workspace directory
+    pub fn load(workspace: &Path) -> Result<Self, ConfigError> {
+        ConfigLoader::new().with_workspace(workspace).load()
+    }
+
+    /// Default SQL dialect
+    pub fn sql_dialect(&self) -> Option<&str> {
+        self.settings.defaults.sql_dialect.as_deref()
+    }
+
+    /// API server URL
+    pub fn api_url(&self) -> Option<&str> {
+        self.settings.api.url.as_deref()
+    }
+
+    /// Root directory of workspace storage
+    pub fn storage_root(&self) -> Option<&Path> {
+        self.settings.storage.root.as_deref()
+    }
+
+    /// Authentication mode, `AuthMode::None` when unset
+    pub fn auth_mode(&self) -> Result<AuthMode, ConfigError> {
+        Ok(match self.settings.auth.mode {
+            None | Some(AuthModeSetting::None) => AuthMode::None,
+            Some(AuthModeSetting::Web) => AuthMode::Web,
+            Some(AuthModeSetting::Local) => AuthMode::Local,
+            Some(AuthModeSetting::Online) => AuthMode::Online {
+                api_url: self.api_url().map(str::to_string).ok_or_else(|| {
+                    ConfigError::Invalid("auth mode 'online' requires api.url".to_string())
+                })?,
+            },
+        })
+    }
+
+    /// LLM refinement mode
+    ///
+    /// Offline when a model file is configured, online when a URL or model name
+    /// is, and disabled otherwise.
+    #[cfg(any(feature = "llm", feature = "llm-online", feature = "llm-offline"))]
+    pub fn llm_mode(&self) -> LlmMode {
+        let llm = &self.settings.llm;
+        if let Some(model_path) = &llm.model_path {
+            LlmMode::offline_with_gpu(model_path, llm.gpu_layers.unwrap_or(0))
+        } else if llm.url.is_some() || llm.model.is_some() {
+            LlmMode::online_with_url(
+                llm.url.as_deref().unwrap_or(DEFAULT_LLM_URL),
+                llm.model.as_deref().unwrap_or(DEFAULT_LLM_MODEL),
+            )
+        } else {
+            LlmMode::None
+        }
+    }
+
+    /// Merged settings as TOML
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        toml::to_string_pretty(&self.settings)
+            .map_err(|e| ConfigError::Serialization(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> + use<> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_parse_config_file() {
+        let file = ConfigFile::parse(
+            r#"
+profile = "dev"
+
+[defaults]
+sql_dialect = "postgres"
+
+[auth]
+mode = "online"
+
+[profiles.dev.api]
+url = "http://localhost:8080"
+"#,
+        )
+        .unwrap();
+        assert_eq!(file.profile.as_deref(), Some("dev"));
+        assert_eq!(
+            file.settings.defaults.sql_dialect.as_deref(),
+            Some("postgres")
+        );
+        assert_eq!(file.settings.auth.mode, Some(AuthModeSetting::Online));
+        assert_eq!(
+            file.profiles["dev"].api.url.as_deref(),
+            Some("http://localhost:8080")
+        );
+    }
+
+    #[test]
+    fn test_layer_precedence() {
+        let dir = tempdir().unwrap();
+        let user = dir.path().join("user.toml");
+        std::fs::write(
+            &user,
+            r#"
+[defaults]
+sql_dialect = "mysql"
+
+[llm]
+url = "http://gpu-box:11434"
+model = "mistral"
+
+[profiles.prod.api]
+url = "https://api.example.com"
+"#,
+        )
+        .unwrap();
+
+        let workspace = dir.path().join("project");
+        std::fs::create_dir_all(workspace.join("models")).unwrap();
+        std::fs::write(
+            workspace.join(WORKSPACE_CONFIG_FILENAME),
+            r#"
+profile = "prod"
+
+[defaults]
+sql_dialect = "postgres"
+
+[storage]
+root = "data"
+
+[profiles.prod.auth]
+mode = "online"
+"#,
+        )
+        .unwrap();
+
+        let config = ConfigLoader::new()
+            .with_user_config(&user)
+            .with_workspace(workspace.join("models"))
+            .load_with_env(env(&[(ENV_LLM_MODEL, "codellama")]))
+            .unwrap();
+
+        assert_eq!(config.profile.as_deref(), Some("prod"));
+        assert_eq!(config.sql_dialect(), Some("postgres"));
+        assert_eq!(
+            config.storage_root(),
+            Some(workspace.join("data").as_path())
+        );
+        assert_eq!(
+            config.settings.llm.url.as_deref(),
+            Some("http://gpu-box:11434")
+        );
+        assert_eq!(config.settings.llm.model.as_deref(), Some("codellama"));
+        assert_eq!(
+            config.auth_mode().unwrap(),
+            AuthMode::Online {
+                api_url: "https://api.example.com".to_string()
+            }
+        );
+        assert_eq!(
+            config.sources,
+            vec![user, workspace.join(WORKSPACE_CONFIG_FILENAME)]
+        );
+    }
+
+    #[test]
+    fn test_profile_selection() {
+        let dir = tempdir().unwrap();
+        let user = dir.path().join("user.toml");
+        std::fs::write(
+            &user,
+            r#"
+[profiles.ci.defaults]
+sql_dialect = "databricks"
+"#,
+        )
+        .unwrap();
+        let loader = ConfigLoader::new().with_user_config(&user);
+
+        let config = loader.load_with_env(env(&[])).unwrap();
+        assert_eq!(config.profile, None);
+        assert_eq!(config.sql_dialect(), None);
+
+        let config = loader.load_with_env(env(&[(ENV_PROFILE, "ci")])).unwrap();
+        assert_eq!(config.sql_dialect(), Some("databricks"));
+
+        let err = loader
+            .clone()
+            .with_profile("staging")
+            .load_with_env(env(&[(ENV_PROFILE, "ci")]))
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownProfile(name) if name == "staging"));
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let config = ConfigLoader::new()
+            .without_user_config()
+            .load_with_env(env(&[
+                (ENV_SQL_DIALECT, "snowflake"),
+                (ENV_AUTH_MODE, "local"),
+                (ENV_STORAGE_ROOT, "/srv/models"),
+            ]))
+            .unwrap();
+        assert!(config.sources.is_empty());
+        assert_eq!(config.sql_dialect(), Some("snowflake"));
+        assert_eq!(config.auth_mode().unwrap(), AuthMode::Local);
+        assert_eq!(config.storage_root(), Some(Path::new("/srv/models")));
+
+        let err = ConfigLoader::new()
+            .without_user_config()
+            .load_with_env(env(&[(ENV_AUTH_MODE, "sso")]))
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::Invalid(_)));
+    }
+
+    #[test]
+    fn test_online_auth_requires_api_url() {
+        let mut config = SdkConfig::default();
+        config.settings.auth.mode = Some(AuthModeSetting::Online);
+        assert!(config.auth_mode().is_err());
+    }
+
+    #[cfg(any(feature = "llm", feature = "llm-online", feature = "llm-offline"))]
+    #[test]
+    fn test_llm_mode() {
+        let mut config = SdkConfig::default();
+        assert_eq!(config.llm_mode(), LlmMode::None);
+
+        config.settings.llm.model = Some("mistral".to_string());
+        assert_eq!(
+            config.llm_mode(),
+            LlmMode::online_with_url(DEFAULT_LLM_URL, "mistral")
+        );
+
+        config.settings.llm.model_path = Some(PathBuf::from("/models/llama.gguf"));
+        assert_eq!(config.llm_mode(), LlmMode::offline("/models/llama.gguf"));
+    }
+
+    #[test]
+    fn test_user_config_path() {
+        assert_eq!(
+            user_config_path_with(&env(&[("HOME", "/home/ada")])),
+            Some(PathBuf::from("/home/ada/.config/odm/config.toml"))
+        );
+        assert_eq!(
+            user_config_path_with(&env(&[("HOME", "/home/ada"), ("XDG_CONFIG_HOME", "/cfg")])),
+            Some(PathBuf::from("/cfg/odm/config.toml"))
+        );
+        assert_eq!(
+            user_config_path_with(&env(&[(ENV_CONFIG, "/etc/odm.toml")])),
+            Some(PathBuf::from("/etc/odm.toml"))
+        );
+    }
+}
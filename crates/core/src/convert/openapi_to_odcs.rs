@@ -3,11 +3,13 @@
 //! Provides functionality to convert OpenAPI schema components to ODCS table definitions.
 
 use crate::convert::ConversionError;
+use crate::import::ref_resolver::{RefResolver, inline_external_refs};
 use crate::models::{Column, Table};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Strategy for handling nested objects in OpenAPI schemas
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -63,12 +65,23 @@ pub struct ConversionReport {
 /// OpenAPI to ODCS Converter
 ///
 /// Converts OpenAPI schema components to ODCS table definitions.
-#[derive(Debug)]
 pub struct OpenAPIToODCSConverter {
     /// Strategy for handling nested objects
     pub nested_object_strategy: NestedObjectStrategy,
     /// Whether to flatten simple nested objects
     pub flatten_simple_objects: bool,
+    /// Loads documents named by external `$ref`s
+    resolver: Option<Arc<dyn RefResolver>>,
+}
+
+impl std::fmt::Debug for OpenAPIToODCSConverter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAPIToODCSConverter")
+            .field("nested_object_strategy", &self.nested_object_strategy)
+            .field("flatten_simple_objects", &self.flatten_simple_objects)
+            .field("resolver", &self.resolver.is_some())
+            .finish()
+    }
 }
 
 impl Default for OpenAPIToODCSConverter {
@@ -76,6 +89,7 @@ impl Default for OpenAPIToODCSConverter {
         Self {
             nested_object_strategy: NestedObjectStrategy::Hybrid,
             flatten_simple_objects: true,
+            resolver: None,
         }
     }
 }
@@ -94,9 +108,42 @@ impl OpenAPIToODCSConverter {
                 nested_object_strategy,
                 NestedObjectStrategy::Flatten | NestedObjectStrategy::Hybrid
             ),
+            resolver: None,
         }
     }
 
+    /// Inline external `$ref`s (e.g., `common.yaml#/components/schemas/Address`)
+    /// loaded with `resolver` before converting.
+    ///
+    /// Without a resolver, external references are left unresolved.
+    pub fn with_resolver(mut self, resolver: impl RefResolver + 'static) -> Self {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Parse OpenAPI content and inline its external references.
+    fn parse_openapi(&self, openapi_content: &str) -> Result<JsonValue, ConversionError> {
+        let mut openapi_value: JsonValue = if openapi_content.trim_start().starts_with('{') {
+            serde_json::from_str(openapi_content).map_err(|e| {
+                ConversionError::OpenAPISchemaInvalid(format!("Invalid JSON: {}", e))
+            })?
+        } else {
+            serde_yaml::from_str(openapi_content).map_err(|e| {
+                ConversionError::OpenAPISchemaInvalid(format!("Invalid YAML: {}", e))
+            })?
+        };
+
+        if let Some(resolver) = &self.resolver {
+            inline_external_refs(&mut openapi_value, resolver.as_ref()).map_err(|e| {
+                ConversionError::OpenAPISchemaInvalid(format!(
+                    "Failed to resolve external reference: {}",
+                    e
+                ))
+            })?;
+        }
+        Ok(openapi_value)
+    }
+
     /// Convert an OpenAPI component to an ODCS table
     ///
     /// # Arguments
@@ -115,15 +162,7 @@ impl OpenAPIToODCSConverter {
         table_name: Option<&str>,
     ) -> Result<Table, ConversionError> {
         // Parse OpenAPI content
-        let openapi_value: JsonValue = if openapi_content.trim_start().starts_with('{') {
-            serde_json::from_str(openapi_content).map_err(|e| {
-                ConversionError::OpenAPISchemaInvalid(format!("Invalid JSON: {}", e))
-            })?
-        } else {
-            serde_yaml::from_str(openapi_content).map_err(|e| {
-                ConversionError::OpenAPISchemaInvalid(format!("Invalid YAML: {}", e))
-            })?
-        };
+        let openapi_value = self.parse_openapi(openapi_content)?;
 
         // Extract components section
         let components = openapi_value
@@ -236,6 +275,13 @@ impl OpenAPIToODCSConverter {
             column.description = desc.to_string();
         }
 
+        // Keep OpenAPI 3.0 `example` or OpenAPI 3.1 / JSON Schema `examples`
+        column.examples = match (schema_obj.get("examples"), schema_obj.get("example")) {
+            (Some(JsonValue::Array(examples)), _) => examples.clone(),
+            (_, Some(example)) => vec![example.clone()],
+            _ => Vec::new(),
+        };
+
         // Add quality rules for constraints
         self.add_constraints_to_column(&mut column, schema_obj, openapi_type, format)?;
 
@@ -406,15 +452,7 @@ impl OpenAPIToODCSConverter {
         component_name: &str,
     ) -> Result<ConversionReport, ConversionError> {
         // Parse OpenAPI content
-        let openapi_value: JsonValue = if openapi_content.trim_start().starts_with('{') {
-            serde_json::from_str(openapi_content).map_err(|e| {
-                ConversionError::OpenAPISchemaInvalid(format!("Invalid JSON: {}", e))
-            })?
-        } else {
-            serde_yaml::from_str(openapi_content).map_err(|e| {
-                ConversionError::OpenAPISchemaInvalid(format!("Invalid YAML: {}", e))
-            })?
-        };
+        let openapi_value = self.parse_openapi(openapi_content)?;
 
         // Extract components section
         let components = openapi_value
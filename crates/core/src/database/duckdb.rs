@@ -8,7 +8,8 @@ use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use uuid::Uuid;
 
-use super::schema::{DatabaseSchema, SCHEMA_VERSION};
+use super::migrations;
+use super::schema::workspace_record_sql;
 use super::{DatabaseBackend, DatabaseError, DatabaseResult, QueryResult, SyncStatus};
 use crate::models::{Domain, Relationship, Table, Workspace};
 
@@ -177,29 +178,15 @@ impl DuckDBBackend {
 #[async_trait(?Send)]
 impl DatabaseBackend for DuckDBBackend {
     async fn initialize(&self) -> DatabaseResult<()> {
-        // Create tables
-        self.execute_batch(DatabaseSchema::create_tables_sql())?;
-
-        // Create indexes
-        self.execute_batch(DatabaseSchema::create_indexes_sql())?;
-
-        // Record schema version
-        let conn = self
-            .connection
-            .lock()
-            .map_err(|e| DatabaseError::ConnectionFailed(format!("Lock error: {}", e)))?;
-
-        conn.execute(
-            "INSERT INTO schema_version (version) VALUES (?) ON CONFLICT (version) DO NOTHING",
-            [SCHEMA_VERSION],
-        )
-        .map_err(|e| {
-            DatabaseError::MigrationFailed(format!("Failed to record schema version: {}", e))
-        })?;
-
+        // Create or upgrade the schema
+        migrations::migrate(self).await?;
         Ok(())
     }
 
+    async fn execute_script(&self, sql: &str) -> DatabaseResult<()> {
+        self.execute_batch(sql)
+    }
+
     async fn execute_query(&self, sql: &str) -> DatabaseResult<QueryResult> {
         let start = std::time::Instant::now();
 
@@ -855,7 +842,12 @@ impl DatabaseBackend for DuckDBBackend {
 
         let now = chrono::Utc::now();
 
-        conn.execute(
+        // The workspace and its name row are written together, so a name taken
+        // within the tenant leaves the workspace unchanged
+        let tx = conn.unchecked_transaction().map_err(|e| {
+            DatabaseError::SyncFailed(format!("Failed to begin transaction: {}", e))
+        })?;
+        tx.execute(
             r#"
             INSERT INTO workspaces (id, name, owner_id, created_at, last_modified_at, yaml_hash, metadata)
             VALUES (?, ?, ?, ?, ?, ?, ?)
@@ -876,6 +868,19 @@ impl DatabaseBackend for DuckDBBackend {
                 Option::<String>::None,
             ],
         ).map_err(|e| DatabaseError::SyncFailed(format!("Failed to upsert workspace: {}", e)))?;
+        tx.execute(
+            workspace_record_sql::SET_TENANT,
+            duckdb::params![workspace.id.to_string(), &workspace.tenant, &workspace.name],
+        )
+        .map_err(|e| {
+            DatabaseError::SyncFailed(format!(
+                "Failed to record workspace name '{}': {}",
+                workspace.name, e
+            ))
+        })?;
+        tx.commit().map_err(|e| {
+            DatabaseError::SyncFailed(format!("Failed to commit transaction: {}", e))
+        })?;
 
         Ok(())
     }
@@ -922,6 +927,13 @@ impl DatabaseBackend for DuckDBBackend {
                 name.replace('\'', "''") // Escape single quotes
             ))
             .await?;
+        if result.rows.len() > 1 {
+            return Err(DatabaseError::InvalidInput(format!(
+                "Workspace name '{}' is used by {} workspaces of different tenants",
+                name,
+                result.rows.len()
+            )));
+        }
 
         if let Some(row) = result.rows.first() {
             let id: Uuid = row
@@ -951,6 +963,10 @@ impl DatabaseBackend for DuckDBBackend {
     }
 
     async fn delete_workspace(&self, workspace_id: Uuid) -> DatabaseResult<()> {
+        self.execute(&format!(
+            "DELETE FROM workspace_tenants WHERE workspace_id = '{}'",
+            workspace_id
+        ))?;
         // Cascade delete will handle related tables
         self.execute(&format!(
             "DELETE FROM workspaces WHERE id = '{}'",
@@ -0,0 +1,721 @@
+//! Live database introspection for PostgreSQL
+//!
+//! [`PostgresIntrospector`] reads the catalog of a running PostgreSQL database
+//! (`information_schema` and `pg_catalog`) and builds a [`DataModel`] from it:
+//!
+//! - Tables, views and materialized views with their comments and owners
+//! - Columns with types, nullability, defaults and comments
+//! - Primary keys and unique constraints
+//! - Foreign keys, as column references and [`Relationship`] objects
+//! - Indexes, recorded as a custom property of each table
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::database::PostgresIntrospector;
+//!
+//! let introspector = PostgresIntrospector::connect("postgresql://localhost/shop")
+//!     .await?
+//!     .with_schemas(["public", "sales"]);
+//! let model = introspector.introspect().await?;
+//! println!(
+//!     "{} tables, {} relationships",
+//!     model.tables.len(),
+//!     model.relationships.len()
+//! );
+//! ```
+
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use super::store::{query, required_text, text};
+use super::{DatabaseBackend, DatabaseResult, QueryRow};
+use crate::models::column::ForeignKey;
+use crate::models::enums::{
+    Cardinality, DatabaseType, EndpointCardinality, InfrastructureType, RelationshipType,
+};
+use crate::models::relationship::ForeignKeyDetails;
+use crate::models::{Column, DataModel, Relationship, Table};
+
+/// Catalog queries used by the introspector
+///
+/// Every query takes a single parameter: a comma-separated list of schemas to
+/// include, or an empty string for all non-system schemas. All selected values
+/// are cast to text.
+pub mod introspect_sql {
+    /// Name of the connected database
+    pub const CURRENT_DATABASE: &str = "SELECT current_database()::TEXT AS name";
+
+    /// Tables, views, materialized views and foreign tables
+    pub const TABLES: &str = r#"
+SELECT
+    n.nspname::TEXT AS schema_name,
+    c.relname::TEXT AS table_name,
+    c.relkind::TEXT AS kind,
+    pg_get_userbyid(c.relowner)::TEXT AS owner,
+    obj_description(c.oid, 'pg_class')::TEXT AS comment
+FROM pg_catalog.pg_class c
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f')
+  AND NOT c.relispartition
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND ($1::TEXT = '' OR n.nspname = ANY(string_to_array($1::TEXT, ',')))
+ORDER BY n.nspname, c.relname
+"#;
+
+    /// Columns in ordinal order
+    pub const COLUMNS: &str = r#"
+SELECT
+    col.table_schema::TEXT AS schema_name,
+    col.table_name::TEXT AS table_name,
+    col.column_name::TEXT AS column_name,
+    col.ordinal_position::TEXT AS position,
+    col.data_type::TEXT AS data_type,
+    format_type(a.atttypid, a.atttypmod)::TEXT AS physical_type,
+    col.is_nullable::TEXT AS is_nullable,
+    col.column_default::TEXT AS column_default,
+    col.character_maximum_length::TEXT AS max_length,
+    col.numeric_precision::TEXT AS numeric_precision,
+    col.numeric_scale::TEXT AS numeric_scale,
+    col_description(c.oid, a.attnum)::TEXT AS comment
+FROM information_schema.columns col
+JOIN pg_catalog.pg_namespace n ON n.nspname = col.table_schema
+JOIN pg_catalog.pg_class c ON c.relnamespace = n.oid AND c.relname = col.table_name
+JOIN pg_catalog.pg_attribute a ON a.attrelid = c.oid AND a.attname = col.column_name
+WHERE col.table_schema NOT IN ('pg_catalog', 'information_schema')
+  AND ($1::TEXT = '' OR col.table_schema = ANY(string_to_array($1::TEXT, ',')))
+ORDER BY col.table_schema, col.table_name, col.ordinal_position
+"#;
+
+    /// Primary key, unique and foreign key constraints, one row per column
+    pub const CONSTRAINTS: &str = r#"
+SELECT
+    n.nspname::TEXT AS schema_name,
+    c.relname::TEXT AS table_name,
+    con.conname::TEXT AS constraint_name,
+    con.contype::TEXT AS constraint_type,
+    a.attname::TEXT AS column_name,
+    k.ord::TEXT AS position,
+    fn.nspname::TEXT AS foreign_schema,
+    fc.relname::TEXT AS foreign_table,
+    fa.attname::TEXT AS foreign_column,
+    NULLIF(con.confupdtype, ' ')::TEXT AS on_update,
+    NULLIF(con.confdeltype, ' ')::TEXT AS on_delete
+FROM pg_catalog.pg_constraint con
+JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+CROSS JOIN LATERAL unnest(con.conkey, con.confkey) WITH ORDINALITY AS k(attnum, fattnum, ord)
+JOIN pg_catalog.pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+LEFT JOIN pg_catalog.pg_class fc ON fc.oid = con.confrelid
+LEFT JOIN pg_catalog.pg_namespace fn ON fn.oid = fc.relnamespace
+LEFT JOIN pg_catalog.pg_attribute fa ON fa.attrelid = con.confrelid AND fa.attnum = k.fattnum
+WHERE con.contype IN ('p', 'u', 'f')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND ($1::TEXT = '' OR n.nspname = ANY(string_to_array($1::TEXT, ',')))
+ORDER BY n.nspname, c.relname, con.conname, k.ord
+"#;
+
+    /// Indexes with their key columns
+    pub const INDEXES: &str = r#"
+SELECT
+    n.nspname::TEXT AS schema_name,
+    t.relname::TEXT AS table_name,
+    i.relname::TEXT AS index_name,
+    ix.indisunique::TEXT AS is_unique,
+    ix.indisprimary::TEXT AS is_primary,
+    array_to_string(ARRAY(
+        SELECT pg_get_indexdef(ix.indexrelid, k + 1, true)
+        FROM generate_subscripts(ix.indkey, 1) AS k
+        ORDER BY k
+    ), ',')::TEXT AS columns,
+    pg_get_indexdef(ix.indexrelid)::TEXT AS definition
+FROM pg_catalog.pg_index ix
+JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+JOIN pg_catalog.pg_class t ON t.oid = ix.indrelid
+JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND n.nspname NOT LIKE 'pg\_toast%'
+  AND ($1::TEXT = '' OR n.nspname = ANY(string_to_array($1::TEXT, ',')))
+ORDER BY n.nspname, t.relname, i.relname
+"#;
+}
+
+/// Builds a data model from the catalog of a live PostgreSQL database
+///
+/// The introspector runs its catalog queries through any [`DatabaseBackend`]
+/// connected to PostgreSQL; [`PostgresIntrospector::connect`] opens a
+/// [`PostgresBackend`](super::PostgresBackend) from a connection string.
+pub struct PostgresIntrospector<B: DatabaseBackend> {
+    backend: B,
+    schemas: Vec<String>,
+    model_name: Option<String>,
+}
+
+#[cfg(feature = "postgres-backend")]
+impl PostgresIntrospector<super::PostgresBackend> {
+    /// Connect to a PostgreSQL database
+    ///
+    /// # Arguments
+    /// * `connection_string` - PostgreSQL connection string
+    pub async fn connect(connection_string: &str) -> DatabaseResult<Self> {
+        Ok(Self::new(
+            super::PostgresBackend::new(connection_string).await?,
+        ))
+    }
+}
+
+impl<B: DatabaseBackend> PostgresIntrospector<B> {
+    /// Create an introspector for a backend connected to PostgreSQL
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            schemas: Vec::new(),
+            model_name: None,
+        }
+    }
+
+    /// Only introspect the given schemas (default: all non-system schemas)
+    pub fn with_schemas<I, S>(mut self, schemas: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.schemas = schemas.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Name of the resulting data model (default: the database name)
+    pub fn with_model_name(mut self, name: impl Into<String>) -> Self {
+        self.model_name = Some(name.into());
+        self
+    }
+
+    /// The underlying database backend
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Read the catalog and build a data model
+    pub async fn introspect(&self) -> DatabaseResult<DataModel> {
+        let name = match &self.model_name {
+            Some(name) => name.clone(),
+            None => query(&self.backend, introspect_sql::CURRENT_DATABASE, &[])
+                .await?
+                .first()
+                .and_then(|row| text(row, "name"))
+                .unwrap_or_else(|| "postgres".to_string()),
+        };
+
+        let params = [Value::String(self.schemas.join(","))];
+        let catalog = CatalogRows {
+            tables: query(&self.backend, introspect_sql::TABLES, &params).await?,
+            columns: query(&self.backend, introspect_sql::COLUMNS, &params).await?,
+            constraints: query(&self.backend, introspect_sql::CONSTRAINTS, &params).await?,
+            indexes: query(&self.backend, introspect_sql::INDEXES, &params).await?,
+        };
+        build_model(&name, &catalog)
+    }
+}
+
+impl<B: DatabaseBackend> std::fmt::Debug for PostgresIntrospector<B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresIntrospector")
+            .field("schemas", &self.schemas)
+            .field("model_name", &self.model_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Result rows of the catalog queries
+#[derive(Debug, Clone, Default)]
+pub struct CatalogRows {
+    /// Rows of [`introspect_sql::TABLES`]
+    pub tables: Vec<QueryRow>,
+    /// Rows of [`introspect_sql::COLUMNS`]
+    pub columns: Vec<QueryRow>,
+    /// Rows of [`introspect_sql::CONSTRAINTS`]
+    pub constraints: Vec<QueryRow>,
+    /// Rows of [`introspect_sql::INDEXES`]
+    pub indexes: Vec<QueryRow>,
+}
+
+/// A constraint with its columns in key order
+struct Constraint {
+    schema: String,
+    table: String,
+    name: String,
+    kind: String,
+    columns: Vec<String>,
+    foreign_table: Option<(String, String)>,
+    foreign_columns: Vec<String>,
+    on_update: Option<String>,
+    on_delete: Option<String>,
+}
+
+/// Build a data model from catalog rows
+pub fn build_model(name: &str, catalog: &CatalogRows) -> DatabaseResult<DataModel> {
+    build_catalog_model(
+        name,
+        catalog,
+        DatabaseType::Postgres,
+        InfrastructureType::PostgreSQL,
+    )
+}
+
+/// Build a data model from catalog rows in the shape of [`introspect_sql`]
+///
+/// Other introspectors select their catalog in the same shape, with
+/// `pg_class`/`pg_constraint` codes for table kinds, constraint types and
+/// referential actions.
+pub(super) fn build_catalog_model(
+    name: &str,
+    catalog: &CatalogRows,
+    database_type: DatabaseType,
+    infrastructure_type: InfrastructureType,
+) -> DatabaseResult<DataModel> {
+    let mut model = DataModel::new(name.to_string(), String::new(), String::new());
+    let mut positions: HashMap<(String, String), usize> = HashMap::new();
+
+    for row in &catalog.tables {
+        let schema = required_text(row, "schema_name")?;
+        let table_name = required_text(row, "table_name")?;
+        let mut table = Table::new(table_name.clone(), Vec::new());
+        table.database_type = Some(database_type);
+        table.infrastructure_type = Some(infrastructure_type);
+        table.schema_name = Some(schema.clone());
+        table.owner = text(row, "owner");
+        table.odcl_metadata.insert(
+            "physicalName".to_string(),
+            json!(format!("{}.{}", schema, table_name)),
+        );
+        let physical_type = match text(row, "kind").as_deref() {
+            Some("v") => "view",
+            Some("m") => "materializedView",
+            _ => "table",
+        };
+        table
+            .odcl_metadata
+            .insert("physicalType".to_string(), json!(physical_type));
+        if let Some(comment) = text(row, "comment").filter(|c| !c.is_empty()) {
+            table
+                .odcl_metadata
+                .insert("schemaDescription".to_string(), json!(comment));
+        }
+        positions.insert((schema, table_name), model.tables.len());
+        model.tables.push(table);
+    }
+
+    for row in &catalog.columns {
+        let key = (
+            required_text(row, "schema_name")?,
+            required_text(row, "table_name")?,
+        );
+        let Some(&index) = positions.get(&key) else {
+            continue;
+        };
+        let table = &mut model.tables[index];
+        let column = column_from_row(row, table.columns.len())?;
+        table.columns.push(column);
+    }
+
+    let constraints = group_constraints(&catalog.constraints)?;
+    for constraint in &constraints {
+        let Some(&index) = positions.get(&(constraint.schema.clone(), constraint.table.clone()))
+        else {
+            continue;
+        };
+        match constraint.kind.as_str() {
+            "p" => {
+                for (position, name) in constraint.columns.iter().enumerate() {
+                    if let Some(column) = find_column(&mut model.tables[index], name) {
+                        column.primary_key = true;
+                        column.nullable = false;
+                        if constraint.columns.len() > 1 {
+                            column.primary_key_position = Some(position as i32 + 1);
+                        }
+                    }
+                }
+            }
+            "u" => {
+                let table = &mut model.tables[index];
+                if constraint.columns.len() == 1 {
+                    if let Some(column) = find_column(table, &constraint.columns[0]) {
+                        column.unique = true;
+                    }
+                } else {
+                    for name in &constraint.columns {
+                        if let Some(column) = find_column(table, name) {
+                            column.composite_key = Some(constraint.name.clone());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Foreign keys last, so that their cardinality sees all key columns
+    for constraint in constraints.iter().filter(|c| c.kind == "f") {
+        let Some(&index) = positions.get(&(constraint.schema.clone(), constraint.table.clone()))
+        else {
+            continue;
+        };
+        let Some(target_index) = constraint
+            .foreign_table
+            .as_ref()
+            .and_then(|target| positions.get(target).copied())
+        else {
+            continue;
+        };
+        let relationship = foreign_key_relationship(&mut model, constraint, index, target_index);
+        model.relationships.push(relationship);
+    }
+
+    let mut indexes: HashMap<usize, Vec<Value>> = HashMap::new();
+    for row in &catalog.indexes {
+        let key = (
+            required_text(row, "schema_name")?,
+            required_text(row, "table_name")?,
+        );
+        let Some(&index) = positions.get(&key) else {
+            continue;
+        };
+        let columns: Vec<String> = text(row, "columns")
+            .map(|columns| columns.split(',').map(str::to_string).collect())
+            .unwrap_or_default();
+        indexes.entry(index).or_default().push(json!({
+            "name": required_text(row, "index_name")?,
+            "columns": columns,
+            "unique": flag(row, "is_unique"),
+            "primary": flag(row, "is_primary"),
+            "definition": text(row, "definition"),
+        }));
+    }
+    for (index, table_indexes) in indexes {
+        model.tables[index].odcl_metadata.insert(
+            "customProperties".to_string(),
+            json!([{"property": "indexes", "value": table_indexes}]),
+        );
+    }
+
+    Ok(model)
+}
+
+fn column_from_row(row: &QueryRow, order: usize) -> DatabaseResult<Column> {
+    let physical_type = text(row, "physical_type");
+    let data_type = physical_type
+        .clone()
+        .or_else(|| text(row, "data_type"))
+        .unwrap_or_else(|| "text".to_string());
+    let mut column = Column::new(required_text(row, "column_name")?, data_type);
+    column.physical_type = physical_type;
+    column.nullable = text(row, "is_nullable").as_deref() != Some("NO");
+    column.column_order = order as i32;
+    if let Some(comment) = text(row, "comment").filter(|c| !c.is_empty()) {
+        column.description = comment;
+    }
+    if let Some(default) = text(row, "column_default") {
+        column.default_value = Some(Value::String(default));
+    }
+    for (property, key) in [
+        ("characterMaximumLength", "max_length"),
+        ("numericPrecision", "numeric_precision"),
+        ("numericScale", "numeric_scale"),
+    ] {
+        if let Some(value) = text(row, key).and_then(|v| v.parse::<i64>().ok()) {
+            column
+                .custom_properties
+                .insert(property.to_string(), json!(value));
+        }
+    }
+    Ok(column)
+}
+
+fn group_constraints(rows: &[QueryRow]) -> DatabaseResult<Vec<Constraint>> {
+    let mut constraints: Vec<Constraint> = Vec::new();
+    for row in rows {
+        let schema = required_text(row, "schema_name")?;
+        let table = required_text(row, "table_name")?;
+        let name = required_text(row, "constraint_name")?;
+        let column = required_text(row, "column_name")?;
+        let foreign_column = text(row, "foreign_column");
+
+        if let Some(last) = constraints.last_mut()
+            && last.schema == schema
+            && last.table == table
+            && last.name == name
+        {
+            last.columns.push(column);
+            last.foreign_columns.extend(foreign_column);
+            continue;
+        }
+
+        let foreign_table = match (text(row, "foreign_schema"), text(row, "foreign_table")) {
+            (Some(schema), Some(table)) => Some((schema, table)),
+            _ => None,
+        };
+        constraints.push(Constraint {
+            schema,
+            table,
+            name,
+            kind: required_text(row, "constraint_type")?,
+            columns: vec![column],
+            foreign_table,
+            foreign_columns: foreign_column.into_iter().collect(),
+            on_update: text(row, "on_update").and_then(|action| referential_action(&action)),
+            on_delete: text(row, "on_delete").and_then(|action| referential_action(&action)),
+        });
+    }
+    Ok(constraints)
+}
+
+/// Mark the foreign key columns and build the relationship for a constraint
+fn foreign_key_relationship(
+    model: &mut DataModel,
+    constraint: &Constraint,
+    source_index: usize,
+    target_index: usize,
+) -> Relationship {
+    let target = &model.tables[target_index];
+    let target_id = target.id;
+    let target_name = target.name.clone();
+    let target_unique = target_unique(target, &constraint.foreign_columns);
+
+    let source = &mut model.tables[source_index];
+    let source_id = source.id;
+    let mut optional = false;
+    let mut unique = constraint.columns.len() == 1;
+    for (name, target_column) in constraint.columns.iter().zip(&constraint.foreign_columns) {
+        if let Some(column) = find_column(source, name) {
+            optional |= column.nullable;
+            unique &=
+                column.unique || (column.primary_key && column.primary_key_position.is_none());
+            column.foreign_key = Some(ForeignKey {
+                table_id: target_id.to_string(),
+                column_name: target_column.clone(),
+            });
+        }
+    }
+
+    let source_key = constraint.columns.join(", ");
+    let target_key = constraint.foreign_columns.join(", ");
+    let mut relationship = Relationship::new(source_id, target_id);
+    relationship.label = Some(constraint.name.clone());
+    relationship.source_key = Some(source_key.clone());
+    relationship.target_key = Some(target_key.clone());
+    relationship.relationship_type = Some(RelationshipType::ForeignKey);
+    relationship.foreign_key_details = Some(ForeignKeyDetails {
+        source_column: source_key,
+        target_column: target_key,
+    });
+    relationship.cardinality = Some(if unique && target_unique {
+        Cardinality::OneToOne
+    } else {
+        Cardinality::ManyToOne
+    });
+    relationship.source_optional = Some(optional);
+    relationship.target_optional = Some(false);
+    relationship.source_cardinality = Some(if unique {
+        EndpointCardinality::ZeroOrOne
+    } else {
+        EndpointCardinality::ZeroOrMany
+    });
+    relationship.target_cardinality = Some(if optional {
+        EndpointCardinality::ZeroOrOne
+    } else {
+        EndpointCardinality::ExactlyOne
+    });
+
+    let actions: Vec<String> = [
+        ("ON UPDATE", &constraint.on_update),
+        ("ON DELETE", &constraint.on_delete),
+    ]
+    .into_iter()
+    .filter_map(|(clause, action)| action.as_ref().map(|a| format!("{} {}", clause, a)))
+    .collect();
+    relationship.notes = Some(if actions.is_empty() {
+        format!("Foreign key to {}", target_name)
+    } else {
+        format!("Foreign key to {} ({})", target_name, actions.join(", "))
+    });
+    relationship
+}
+
+/// Whether the referenced columns are a single-column key of the target table
+fn target_unique(table: &Table, columns: &[String]) -> bool {
+    match columns {
+        [name] => table.columns.iter().any(|column| {
+            column.name == *name
+                && (column.unique || (column.primary_key && column.primary_key_position.is_none()))
+        }),
+        _ => false,
+    }
+}
+
+fn find_column<'a>(table: &'a mut Table, name: &str) -> Option<&'a mut Column> {
+    table.columns.iter_mut().find(|column| column.name == name)
+}
+
+fn flag(row: &QueryRow, column: &str) -> bool {
+    matches!(text(row, column).as_deref(), Some("true" | "t"))
+}
+
+/// Name of a `pg_constraint` referential action code
+fn referential_action(code: &str) -> Option<String> {
+    let action = match code {
+        "c" => "CASCADE",
+        "n" => "SET NULL",
+        "d" => "SET DEFAULT",
+        "r" => "RESTRICT",
+        // "a" (NO ACTION) is the default
+        _ => return None,
+    };
+    Some(action.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(
+        table: &str,
+        name: &str,
+        position: u32,
+        physical_type: &str,
+        nullable: bool,
+    ) -> Value {
+        json!({
+            "schema_name": "public",
+            "table_name": table,
+            "column_name": name,
+            "position": position.to_string(),
+            "data_type": physical_type,
+            "physical_type": physical_type,
+            "is_nullable": if nullable { "YES" } else { "NO" },
+            "column_default": null,
+            "comment": null,
+        })
+    }
+
+    fn constraint(
+        table: &str,
+        name: &str,
+        kind: &str,
+        column: &str,
+        foreign: Option<(&str, &str)>,
+    ) -> Value {
+        json!({
+            "schema_name": "public",
+            "table_name": table,
+            "constraint_name": name,
+            "constraint_type": kind,
+            "column_name": column,
+            "position": "1",
+            "foreign_schema": foreign.map(|_| "public"),
+            "foreign_table": foreign.map(|(table, _)| table),
+            "foreign_column": foreign.map(|(_, column)| column),
+            "on_update": null,
+            "on_delete": if kind == "f" { json!("c") } else { Value::Null },
+        })
+    }
+
+    #[test]
+    fn test_build_model_from_catalog() {
+        let catalog = CatalogRows {
+            tables: vec![
+                json!({"schema_name": "public", "table_name": "customers", "kind": "r", "owner": "app", "comment": "Customer master"}),
+                json!({"schema_name": "public", "table_name": "orders", "kind": "r", "owner": "app", "comment": null}),
+            ],
+            columns: vec![
+                column("customers", "id", 1, "bigint", false),
+                column("customers", "email", 2, "character varying(255)", false),
+                column("orders", "id", 1, "bigint", false),
+                column("orders", "customer_id", 2, "bigint", true),
+                column("orders", "total", 3, "numeric(10,2)", true),
+            ],
+            constraints: vec![
+                constraint("customers", "customers_email_key", "u", "email", None),
+                constraint("customers", "customers_pkey", "p", "id", None),
+                constraint(
+                    "orders",
+                    "orders_customer_id_fkey",
+                    "f",
+                    "customer_id",
+                    Some(("customers", "id")),
+                ),
+                constraint("orders", "orders_pkey", "p", "id", None),
+            ],
+            indexes: vec![json!({
+                "schema_name": "public",
+                "table_name": "orders",
+                "index_name": "orders_customer_id_idx",
+                "is_unique": "false",
+                "is_primary": "false",
+                "columns": "customer_id",
+                "definition": "CREATE INDEX orders_customer_id_idx ON public.orders USING btree (customer_id)",
+            })],
+        };
+
+        let model = build_model("shop", &catalog).unwrap();
+        assert_eq!(model.name, "shop");
+        assert_eq!(model.tables.len(), 2);
+
+        let customers = &model.tables[0];
+        assert_eq!(customers.schema_name.as_deref(), Some("public"));
+        assert_eq!(customers.database_type, Some(DatabaseType::Postgres));
+        assert_eq!(
+            customers.odcl_metadata.get("schemaDescription"),
+            Some(&json!("Customer master"))
+        );
+        assert!(customers.columns[0].primary_key);
+        assert!(customers.columns[1].unique);
+        assert_eq!(
+            customers.columns[1].physical_type.as_deref(),
+            Some("character varying(255)")
+        );
+
+        let orders = &model.tables[1];
+        let customer_id = &orders.columns[1];
+        assert!(customer_id.nullable);
+        assert_eq!(
+            customer_id
+                .foreign_key
+                .as_ref()
+                .map(|fk| fk.table_id.clone()),
+            Some(customers.id.to_string())
+        );
+        let indexes = &orders.odcl_metadata["customProperties"][0];
+        assert_eq!(indexes["property"], "indexes");
+        assert_eq!(indexes["value"][0]["columns"], json!(["customer_id"]));
+
+        assert_eq!(model.relationships.len(), 1);
+        let relationship = &model.relationships[0];
+        assert_eq!(relationship.source_table_id, orders.id);
+        assert_eq!(relationship.target_table_id, customers.id);
+        assert_eq!(relationship.source_key.as_deref(), Some("customer_id"));
+        assert_eq!(relationship.target_key.as_deref(), Some("id"));
+        assert_eq!(relationship.cardinality, Some(Cardinality::ManyToOne));
+        assert_eq!(
+            relationship.target_cardinality,
+            Some(EndpointCardinality::ZeroOrOne)
+        );
+        assert_eq!(
+            relationship.notes.as_deref(),
+            Some("Foreign key to customers (ON DELETE CASCADE)")
+        );
+    }
+
+    #[test]
+    fn test_introspect_sql_casts_parameters() {
+        for sql in [
+            introspect_sql::TABLES,
+            introspect_sql::COLUMNS,
+            introspect_sql::CONSTRAINTS,
+            introspect_sql::INDEXES,
+        ] {
+            assert!(sql.contains("$1::TEXT"));
+            assert!(!sql.contains("$2"));
+        }
+    }
+}
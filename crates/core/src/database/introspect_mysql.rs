@@ -0,0 +1,406 @@
+//! Live database introspection for MySQL and MariaDB
+//!
+//! [`MySqlIntrospector`] reads `information_schema` of a running MySQL or
+//! MariaDB server and builds a [`DataModel`] from it:
+//!
+//! - Tables and views with their comments
+//! - Columns with types, nullability, defaults, comments and `AUTO_INCREMENT`
+//! - Values of `ENUM` columns
+//! - Primary keys and unique constraints
+//! - Foreign keys, as column references and [`Relationship`](crate::models::Relationship) objects
+//! - Indexes, recorded as a custom property of each table
+//!
+//! The catalog queries select the same row shape as the PostgreSQL
+//! [`introspect_sql`](super::introspect::introspect_sql) queries, so both
+//! introspectors share [`build_model`](super::introspect::build_model)'s
+//! mapping. With the `mysql-backend` feature, [`MySqlIntrospector::connect`]
+//! opens a [`MySqlBackend`](super::MySqlBackend); otherwise the introspector
+//! runs on any [`DatabaseBackend`] connected to MySQL that binds `?` placeholders.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::database::MySqlIntrospector;
+//!
+//! let introspector = MySqlIntrospector::connect("mysql://root@localhost:3306/shop")
+//!     .await?
+//!     .with_databases(["shop", "billing"]);
+//! let model = introspector.introspect().await?;
+//! println!("{} tables", model.tables.len());
+//! ```
+
+use serde_json::{Value, json};
+use std::collections::HashSet;
+
+use super::introspect::{CatalogRows, build_catalog_model};
+use super::store::{query, required_text, text};
+use super::{DatabaseBackend, DatabaseResult};
+use crate::models::DataModel;
+use crate::models::enums::{DatabaseType, InfrastructureType};
+
+/// Catalog queries used by the introspector
+///
+/// Every query takes a single `?` parameter: a comma-separated list of
+/// databases to include, or an empty string for the current database. All
+/// selected values are cast to text, and table kinds, constraint types and
+/// referential actions are mapped to the `pg_catalog` codes expected by
+/// [`build_model`](super::introspect::build_model).
+pub mod introspect_sql {
+    /// Name of the current database
+    pub const CURRENT_DATABASE: &str = "SELECT CAST(DATABASE() AS CHAR) AS name";
+
+    /// Tables and views
+    pub const TABLES: &str = r#"
+SELECT
+    CAST(t.TABLE_SCHEMA AS CHAR) AS schema_name,
+    CAST(t.TABLE_NAME AS CHAR) AS table_name,
+    CASE t.TABLE_TYPE WHEN 'VIEW' THEN 'v' ELSE 'r' END AS kind,
+    CAST(NULL AS CHAR) AS owner,
+    CASE t.TABLE_TYPE WHEN 'VIEW' THEN NULL ELSE CAST(t.TABLE_COMMENT AS CHAR) END AS comment
+FROM information_schema.TABLES t
+WHERE t.TABLE_TYPE IN ('BASE TABLE', 'VIEW', 'SYSTEM VERSIONED')
+  AND FIND_IN_SET(t.TABLE_SCHEMA, COALESCE(NULLIF(?, ''), DATABASE())) > 0
+ORDER BY t.TABLE_SCHEMA, t.TABLE_NAME
+"#;
+
+    /// Columns in ordinal order
+    pub const COLUMNS: &str = r#"
+SELECT
+    CAST(c.TABLE_SCHEMA AS CHAR) AS schema_name,
+    CAST(c.TABLE_NAME AS CHAR) AS table_name,
+    CAST(c.COLUMN_NAME AS CHAR) AS column_name,
+    CAST(c.ORDINAL_POSITION AS CHAR) AS position,
+    CAST(c.DATA_TYPE AS CHAR) AS data_type,
+    CAST(c.COLUMN_TYPE AS CHAR) AS physical_type,
+    CAST(c.IS_NULLABLE AS CHAR) AS is_nullable,
+    CAST(c.COLUMN_DEFAULT AS CHAR) AS column_default,
+    CAST(c.CHARACTER_MAXIMUM_LENGTH AS CHAR) AS max_length,
+    CAST(c.NUMERIC_PRECISION AS CHAR) AS numeric_precision,
+    CAST(c.NUMERIC_SCALE AS CHAR) AS numeric_scale,
+    CAST(c.COLUMN_COMMENT AS CHAR) AS comment,
+    CAST(c.EXTRA AS CHAR) AS extra
+FROM information_schema.COLUMNS c
+WHERE FIND_IN_SET(c.TABLE_SCHEMA, COALESCE(NULLIF(?, ''), DATABASE())) > 0
+ORDER BY c.TABLE_SCHEMA, c.TABLE_NAME, c.ORDINAL_POSITION
+"#;
+
+    /// Primary key, unique and foreign key constraints, one row per column
+    pub const CONSTRAINTS: &str = r#"
+SELECT
+    CAST(k.TABLE_SCHEMA AS CHAR) AS schema_name,
+    CAST(k.TABLE_NAME AS CHAR) AS table_name,
+    CAST(k.CONSTRAINT_NAME AS CHAR) AS constraint_name,
+    CASE tc.CONSTRAINT_TYPE
+        WHEN 'PRIMARY KEY' THEN 'p'
+        WHEN 'UNIQUE' THEN 'u'
+        ELSE 'f'
+    END AS constraint_type,
+    CAST(k.COLUMN_NAME AS CHAR) AS column_name,
+    CAST(k.ORDINAL_POSITION AS CHAR) AS position,
+    CAST(k.REFERENCED_TABLE_SCHEMA AS CHAR) AS foreign_schema,
+    CAST(k.REFERENCED_TABLE_NAME AS CHAR) AS foreign_table,
+    CAST(k.REFERENCED_COLUMN_NAME AS CHAR) AS foreign_column,
+    CASE rc.UPDATE_RULE
+        WHEN 'CASCADE' THEN 'c'
+        WHEN 'SET NULL' THEN 'n'
+        WHEN 'SET DEFAULT' THEN 'd'
+        WHEN 'RESTRICT' THEN 'r'
+    END AS on_update,
+    CASE rc.DELETE_RULE
+        WHEN 'CASCADE' THEN 'c'
+        WHEN 'SET NULL' THEN 'n'
+        WHEN 'SET DEFAULT' THEN 'd'
+        WHEN 'RESTRICT' THEN 'r'
+    END AS on_delete
+FROM information_schema.KEY_COLUMN_USAGE k
+JOIN information_schema.TABLE_CONSTRAINTS tc
+  ON tc.CONSTRAINT_SCHEMA = k.CONSTRAINT_SCHEMA
+ AND tc.TABLE_NAME = k.TABLE_NAME
+ AND tc.CONSTRAINT_NAME = k.CONSTRAINT_NAME
+LEFT JOIN information_schema.REFERENTIAL_CONSTRAINTS rc
+  ON rc.CONSTRAINT_SCHEMA = k.CONSTRAINT_SCHEMA
+ AND rc.TABLE_NAME = k.TABLE_NAME
+ AND rc.CONSTRAINT_NAME = k.CONSTRAINT_NAME
+WHERE tc.CONSTRAINT_TYPE IN ('PRIMARY KEY', 'UNIQUE', 'FOREIGN KEY')
+  AND FIND_IN_SET(k.TABLE_SCHEMA, COALESCE(NULLIF(?, ''), DATABASE())) > 0
+ORDER BY k.TABLE_SCHEMA, k.TABLE_NAME, k.CONSTRAINT_NAME, k.ORDINAL_POSITION
+"#;
+
+    /// Indexes with their key columns
+    pub const INDEXES: &str = r#"
+SELECT
+    CAST(s.TABLE_SCHEMA AS CHAR) AS schema_name,
+    CAST(s.TABLE_NAME AS CHAR) AS table_name,
+    CAST(s.INDEX_NAME AS CHAR) AS index_name,
+    CASE WHEN MAX(s.NON_UNIQUE) = 0 THEN 'true' ELSE 'false' END AS is_unique,
+    CASE WHEN s.INDEX_NAME = 'PRIMARY' THEN 'true' ELSE 'false' END AS is_primary,
+    CAST(GROUP_CONCAT(s.COLUMN_NAME ORDER BY s.SEQ_IN_INDEX SEPARATOR ',') AS CHAR) AS columns,
+    CAST(MAX(s.INDEX_TYPE) AS CHAR) AS definition
+FROM information_schema.STATISTICS s
+WHERE FIND_IN_SET(s.TABLE_SCHEMA, COALESCE(NULLIF(?, ''), DATABASE())) > 0
+GROUP BY s.TABLE_SCHEMA, s.TABLE_NAME, s.INDEX_NAME
+ORDER BY s.TABLE_SCHEMA, s.TABLE_NAME, s.INDEX_NAME
+"#;
+}
+
+/// Builds a data model from the catalog of a live MySQL or MariaDB database
+///
+/// The introspector runs its catalog queries through any [`DatabaseBackend`]
+/// connected to MySQL or MariaDB; [`MySqlIntrospector::connect`] opens a
+/// [`MySqlBackend`](super::MySqlBackend) from a connection URL.
+pub struct MySqlIntrospector<B: DatabaseBackend> {
+    backend: B,
+    databases: Vec<String>,
+    model_name: Option<String>,
+}
+
+#[cfg(feature = "mysql-backend")]
+impl MySqlIntrospector<super::MySqlBackend> {
+    /// Connect to a MySQL or MariaDB server
+    ///
+    /// # Arguments
+    /// * `connection_url` - MySQL connection URL
+    pub async fn connect(connection_url: &str) -> DatabaseResult<Self> {
+        Ok(Self::new(super::MySqlBackend::new(connection_url).await?))
+    }
+}
+
+impl<B: DatabaseBackend> MySqlIntrospector<B> {
+    /// Create an introspector for a backend connected to MySQL or MariaDB
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            databases: Vec::new(),
+            model_name: None,
+        }
+    }
+
+    /// Only introspect the given databases (default: the current database)
+    pub fn with_databases<I, S>(mut self, databases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.databases = databases.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Name of the resulting data model (default: the current database name)
+    pub fn with_model_name(mut self, name: impl Into<String>) -> Self {
+        self.model_name = Some(name.into());
+        self
+    }
+
+    /// The underlying database backend
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Read the catalog and build a data model
+    pub async fn introspect(&self) -> DatabaseResult<DataModel> {
+        let name = match &self.model_name {
+            Some(name) => name.clone(),
+            None => query(&self.backend, introspect_sql::CURRENT_DATABASE, &[])
+                .await?
+                .first()
+                .and_then(|row| text(row, "name"))
+                .or_else(|| self.databases.first().cloned())
+                .unwrap_or_else(|| "mysql".to_string()),
+        };
+
+        let params = [Value::String(self.databases.join(","))];
+        let catalog = CatalogRows {
+            tables: query(&self.backend, introspect_sql::TABLES, &params).await?,
+            columns: query(&self.backend, introspect_sql::COLUMNS, &params).await?,
+            constraints: query(&self.backend, introspect_sql::CONSTRAINTS, &params).await?,
+            indexes: query(&self.backend, introspect_sql::INDEXES, &params).await?,
+        };
+        build_model(&name, &catalog)
+    }
+}
+
+impl<B: DatabaseBackend> std::fmt::Debug for MySqlIntrospector<B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlIntrospector")
+            .field("databases", &self.databases)
+            .field("model_name", &self.model_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Build a data model from MySQL catalog rows
+///
+/// On top of the shared mapping, `ENUM` column types become enum values and
+/// `AUTO_INCREMENT` columns get the `autoIncrement` custom property.
+pub fn build_model(name: &str, catalog: &CatalogRows) -> DatabaseResult<DataModel> {
+    let mut model = build_catalog_model(
+        name,
+        catalog,
+        DatabaseType::Mysql,
+        InfrastructureType::MySQL,
+    )?;
+
+    let mut auto_increment: HashSet<(String, String, String)> = HashSet::new();
+    for row in &catalog.columns {
+        let extra = text(row, "extra").unwrap_or_default().to_lowercase();
+        if extra.contains("auto_increment") {
+            auto_increment.insert((
+                required_text(row, "schema_name")?,
+                required_text(row, "table_name")?,
+                required_text(row, "column_name")?,
+            ));
+        }
+    }
+
+    for table in &mut model.tables {
+        let schema = table.schema_name.clone().unwrap_or_default();
+        for column in &mut table.columns {
+            if let Some(values) = column.physical_type.as_deref().and_then(enum_values) {
+                column.enum_values = values;
+            }
+            // MariaDB reports the default of nullable columns as the literal NULL
+            if column.default_value == Some(json!("NULL")) {
+                column.default_value = None;
+            }
+            let key = (schema.clone(), table.name.clone(), column.name.clone());
+            if auto_increment.contains(&key) {
+                column
+                    .custom_properties
+                    .insert("autoIncrement".to_string(), json!(true));
+            }
+        }
+    }
+
+    Ok(model)
+}
+
+/// Values of an `enum('a','b')` column type
+fn enum_values(column_type: &str) -> Option<Vec<String>> {
+    let body = column_type
+        .get(..5)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("enum("))
+        .and_then(|_| column_type[5..].strip_suffix(')'))?;
+
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if quoted && chars.peek() == Some(&'\'') => {
+                current.push('\'');
+                chars.next();
+            }
+            '\'' if quoted => {
+                values.push(std::mem::take(&mut current));
+                quoted = false;
+            }
+            '\'' => quoted = true,
+            '\\' if quoted => current.extend(chars.next()),
+            _ if quoted => current.push(c),
+            _ => {}
+        }
+    }
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::enums::Cardinality;
+
+    fn column(table: &str, name: &str, column_type: &str, extra: &str) -> Value {
+        json!({
+            "schema_name": "shop",
+            "table_name": table,
+            "column_name": name,
+            "data_type": column_type.split('(').next(),
+            "physical_type": column_type,
+            "is_nullable": "NO",
+            "column_default": null,
+            "comment": "",
+            "extra": extra,
+        })
+    }
+
+    #[test]
+    fn test_build_model_from_mysql_catalog() {
+        let catalog = CatalogRows {
+            tables: vec![
+                json!({"schema_name": "shop", "table_name": "customers", "kind": "r", "comment": "Customer master"}),
+                json!({"schema_name": "shop", "table_name": "orders", "kind": "r", "comment": ""}),
+            ],
+            columns: vec![
+                column("customers", "id", "bigint unsigned", "auto_increment"),
+                column("orders", "id", "bigint unsigned", "auto_increment"),
+                column("orders", "customer_id", "bigint unsigned", ""),
+                column("orders", "status", "enum('open','it''s closed')", ""),
+            ],
+            constraints: vec![
+                json!({"schema_name": "shop", "table_name": "customers", "constraint_name": "PRIMARY", "constraint_type": "p", "column_name": "id"}),
+                json!({"schema_name": "shop", "table_name": "orders", "constraint_name": "PRIMARY", "constraint_type": "p", "column_name": "id"}),
+                json!({
+                    "schema_name": "shop",
+                    "table_name": "orders",
+                    "constraint_name": "fk_orders_customer",
+                    "constraint_type": "f",
+                    "column_name": "customer_id",
+                    "foreign_schema": "shop",
+                    "foreign_table": "customers",
+                    "foreign_column": "id",
+                    "on_update": null,
+                    "on_delete": "r",
+                }),
+            ],
+            indexes: Vec::new(),
+        };
+
+        let model = build_model("shop", &catalog).unwrap();
+        let customers = &model.tables[0];
+        assert_eq!(customers.database_type, Some(DatabaseType::Mysql));
+        assert_eq!(
+            customers.infrastructure_type,
+            Some(InfrastructureType::MySQL)
+        );
+        assert_eq!(
+            customers.columns[0].custom_properties.get("autoIncrement"),
+            Some(&json!(true))
+        );
+
+        let orders = &model.tables[1];
+        assert_eq!(orders.columns[2].enum_values, vec!["open", "it's closed"]);
+        assert!(
+            !orders.columns[1]
+                .custom_properties
+                .contains_key("autoIncrement")
+        );
+
+        assert_eq!(model.relationships.len(), 1);
+        assert_eq!(
+            model.relationships[0].cardinality,
+            Some(Cardinality::ManyToOne)
+        );
+        assert_eq!(
+            model.relationships[0].notes.as_deref(),
+            Some("Foreign key to customers (ON DELETE RESTRICT)")
+        );
+    }
+
+    #[test]
+    fn test_introspect_sql_binds_one_parameter() {
+        for sql in [
+            introspect_sql::TABLES,
+            introspect_sql::COLUMNS,
+            introspect_sql::CONSTRAINTS,
+            introspect_sql::INDEXES,
+        ] {
+            assert_eq!(sql.matches('?').count(), 1);
+            assert!(!sql.contains("$1"));
+        }
+        assert_eq!(
+            enum_values("ENUM('a','b')"),
+            Some(vec!["a".into(), "b".into()])
+        );
+        assert_eq!(enum_values("varchar(10)"), None);
+    }
+}
@@ -0,0 +1,171 @@
+//! Background job persistence in the metadata store database
+//!
+//! [`DatabaseJobStore`] keeps [`Job`]s in the `jobs` table created by migration 5.
+//! Claims update the row's status only while it is still queued, so several
+//! workers can share one database. Cancellation requests and progress are
+//! written to their own columns (migration 7), so a worker reporting progress
+//! and a caller cancelling the job never overwrite each other.
+
+use async_trait::async_trait;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::schema::job_sql;
+use super::store::{execute, query, required_text, text};
+use super::{DatabaseBackend, DatabaseError, QueryRow};
+use crate::jobs::{Job, JobError, JobProgress, JobResult, JobStatus, JobStore, request_cancel};
+
+/// Job store backed by a database
+pub struct DatabaseJobStore<B: DatabaseBackend> {
+    backend: B,
+}
+
+impl<B: DatabaseBackend> DatabaseJobStore<B> {
+    /// Create a store on a migrated database
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// Get the database backend
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    async fn select(&self, sql: &str, params: &[serde_json::Value]) -> JobResult<Vec<Job>> {
+        query(&self.backend, sql, params)
+            .await?
+            .iter()
+            .map(job_from_row)
+            .collect()
+    }
+}
+
+fn job_from_row(row: &QueryRow) -> JobResult<Job> {
+    let data = required_text(row, "data")?;
+    let mut job: Job = serde_json::from_str(&data)?;
+    // Columns updated on their own take precedence over `data`
+    if let Some(status) = text(row, "status") {
+        job.status = status.parse().map_err(JobError::SerializationError)?;
+    }
+    if row.get("cancel_requested") == Some(&serde_json::Value::Bool(true)) {
+        job.cancel_requested = true;
+    }
+    if let Some(progress) = text(row, "progress") {
+        job.progress = serde_json::from_str(&progress)?;
+    }
+    Ok(job)
+}
+
+impl From<DatabaseError> for JobError {
+    fn from(err: DatabaseError) -> Self {
+        JobError::StoreError(err.to_string())
+    }
+}
+
+#[async_trait(?Send)]
+impl<B: DatabaseBackend> JobStore for DatabaseJobStore<B> {
+    async fn save(&self, job: &Job) -> JobResult<()> {
+        execute(
+            &self.backend,
+            job_sql::UPSERT,
+            &[
+                json!(job.id.to_string()),
+                json!(job.kind.name()),
+                json!(job.status.name()),
+                json!(serde_json::to_string(job)?),
+                json!(job.created_at.to_rfc3339()),
+                json!(job.cancel_requested),
+                json!(serde_json::to_string(&job.progress)?),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> JobResult<Option<Job>> {
+        Ok(self
+            .select(job_sql::SELECT_BY_ID, &[json!(id.to_string())])
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn list(&self, status: Option<JobStatus>) -> JobResult<Vec<Job>> {
+        match status {
+            Some(status) => {
+                self.select(job_sql::SELECT_BY_STATUS, &[json!(status.name())])
+                    .await
+            }
+            None => self.select(job_sql::SELECT_ALL, &[]).await,
+        }
+    }
+
+    async fn claim_next(&self, worker: &str) -> JobResult<Option<Job>> {
+        let token = json!(Uuid::new_v4().to_string());
+        // Retry when another worker claims the selected job first
+        loop {
+            let rows = query(&self.backend, job_sql::SELECT_NEXT_QUEUED, &[]).await?;
+            let Some(id) = rows
+                .first()
+                .map(|row| required_text(row, "id"))
+                .transpose()?
+            else {
+                return Ok(None);
+            };
+            let id = json!(id);
+            execute(&self.backend, job_sql::CLAIM, &[id.clone(), token.clone()]).await?;
+            if let Some(mut job) = self
+                .select(job_sql::SELECT_CLAIMED, &[id, token.clone()])
+                .await?
+                .into_iter()
+                .next()
+            {
+                job.start(worker);
+                self.save(&job).await?;
+                return Ok(Some(job));
+            }
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> JobResult<bool> {
+        let existed = self.get(id).await?.is_some();
+        execute(&self.backend, job_sql::DELETE, &[json!(id.to_string())]).await?;
+        Ok(existed)
+    }
+
+    async fn request_cancel(&self, id: Uuid) -> JobResult<Job> {
+        // A worker claiming the job meanwhile sees the request once it runs
+        execute(
+            &self.backend,
+            job_sql::REQUEST_CANCEL,
+            &[json!(id.to_string())],
+        )
+        .await?;
+        let mut job = self.get(id).await?.ok_or(JobError::NotFound(id))?;
+        request_cancel(&mut job)?;
+        if job.status != JobStatus::Cancelled {
+            return Ok(job);
+        }
+        execute(
+            &self.backend,
+            job_sql::CANCEL_QUEUED,
+            &[json!(id.to_string()), json!(serde_json::to_string(&job)?)],
+        )
+        .await?;
+        // Reload, as the job is running instead when a worker claimed it first
+        self.get(id).await?.ok_or(JobError::NotFound(id))
+    }
+
+    async fn update_progress(&self, id: Uuid, progress: &JobProgress) -> JobResult<Job> {
+        execute(
+            &self.backend,
+            job_sql::UPDATE_PROGRESS,
+            &[
+                json!(id.to_string()),
+                json!(serde_json::to_string(progress)?),
+            ],
+        )
+        .await?;
+        self.get(id).await?.ok_or(JobError::NotFound(id))
+    }
+}
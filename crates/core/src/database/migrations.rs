@@ -0,0 +1,176 @@
+//! Versioned schema migrations
+//!
+//! Each [`Migration`] is an idempotent SQL script. [`migrate`] applies the
+//! migrations newer than the highest version recorded in `schema_version`, so
+//! databases created before a migration existed are upgraded in place.
+//!
+//! Databases initialized before migrations were tracked individually recorded
+//! only the schema version they were created with, which covers every earlier
+//! migration.
+
+use super::schema::{DatabaseSchema, SCHEMA_VERSION};
+use super::{DatabaseBackend, DatabaseError, DatabaseResult};
+
+/// SQL creating the version tracking table, run before any migration
+const SCHEMA_VERSION_TABLE_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS schema_version (
+    version INTEGER PRIMARY KEY,
+    applied_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+)
+"#;
+
+/// A schema migration
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Schema version after applying the migration
+    pub version: i32,
+    /// Short description of the change
+    pub description: &'static str,
+    /// SQL script (statements separated by `;`)
+    pub sql: &'static str,
+}
+
+/// All migrations, in version order
+///
+/// The last migration's version is [`SCHEMA_VERSION`].
+pub fn migrations() -> Vec<Migration> {
+    vec![
+        Migration {
+            version: 1,
+            description: "Workspaces, tables, relationships, decisions and knowledge articles",
+            sql: DatabaseSchema::create_tables_sql(),
+        },
+        Migration {
+            version: 2,
+            description: "Query indexes",
+            sql: DatabaseSchema::create_indexes_sql(),
+        },
+        Migration {
+            version: 3,
+            description: "Metadata store: contracts, versions, consumers and audit events",
+            sql: DatabaseSchema::create_metadata_store_sql(),
+        },
+        Migration {
+            version: 4,
+            description: "Search index",
+            sql: DatabaseSchema::create_search_index_sql(),
+        },
+        Migration {
+            version: 5,
+            description: "Background jobs",
+            sql: DatabaseSchema::create_jobs_sql(),
+        },
+        Migration {
+            version: 6,
+            description: "Workspace tenants",
+            sql: DatabaseSchema::create_tenants_sql(),
+        },
+        Migration {
+            version: 7,
+            description: "Job cancellation and progress columns",
+            sql: DatabaseSchema::create_job_updates_sql(),
+        },
+        Migration {
+            version: 8,
+            description: "Workspace names unique per tenant",
+            sql: DatabaseSchema::create_workspace_names_sql(),
+        },
+    ]
+}
+
+/// Get the highest applied schema version, or `None` for a new database
+pub async fn current_version<B: DatabaseBackend + ?Sized>(
+    backend: &B,
+) -> DatabaseResult<Option<i32>> {
+    backend.execute_script(SCHEMA_VERSION_TABLE_SQL).await?;
+    let result = backend
+        .execute_query(DatabaseSchema::check_schema_version_sql())
+        .await?;
+    Ok(result
+        .rows
+        .first()
+        .and_then(|row| row.get("version"))
+        .and_then(|v| v.as_i64())
+        .map(|v| v as i32))
+}
+
+/// Apply pending migrations
+///
+/// # Returns
+/// Versions of the migrations that were applied
+pub async fn migrate<B: DatabaseBackend + ?Sized>(backend: &B) -> DatabaseResult<Vec<i32>> {
+    let current = current_version(backend).await?.unwrap_or(0);
+    if current > SCHEMA_VERSION {
+        return Err(DatabaseError::MigrationFailed(format!(
+            "Database schema version {} is newer than supported version {}",
+            current, SCHEMA_VERSION
+        )));
+    }
+
+    let mut applied = Vec::new();
+    for migration in migrations().into_iter().filter(|m| m.version > current) {
+        backend.execute_script(migration.sql).await.map_err(|e| {
+            DatabaseError::MigrationFailed(format!(
+                "Migration {} ({}) failed: {}",
+                migration.version, migration.description, e
+            ))
+        })?;
+        backend
+            .execute_script(&format!(
+                "INSERT INTO schema_version (version) VALUES ({}) ON CONFLICT (version) DO NOTHING",
+                migration.version
+            ))
+            .await?;
+        applied.push(migration.version);
+    }
+    Ok(applied)
+}
+
+/// Split a SQL script into statements
+///
+/// Drops `--` comment lines and empty statements. Statements must not contain
+/// `;` inside string literals.
+pub fn split_statements(sql: &str) -> Vec<String> {
+    let without_comments: String = sql
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    without_comments
+        .split(';')
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_are_ordered() {
+        let migrations = migrations();
+        assert!(
+            migrations
+                .windows(2)
+                .all(|pair| pair[0].version < pair[1].version)
+        );
+        assert_eq!(migrations.last().unwrap().version, SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_split_statements() {
+        let statements = split_statements(
+            "-- Workspaces\nCREATE TABLE a (id INTEGER);\n\n-- Index\nCREATE INDEX i ON a(id);\n",
+        );
+        assert_eq!(
+            statements,
+            vec!["CREATE TABLE a (id INTEGER)", "CREATE INDEX i ON a(id)"]
+        );
+        assert_eq!(
+            split_statements(DatabaseSchema::create_metadata_store_sql()).len(),
+            11
+        );
+    }
+}
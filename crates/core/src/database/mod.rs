@@ -3,6 +3,14 @@
 //! This module provides a database abstraction layer that supports:
 //! - DuckDB: Embedded database for native CLI and in-memory for WASM
 //! - PostgreSQL: For server deployments (CLI only for now)
+//! - MySQL/MariaDB: As a source for live introspection only
+//!
+//! The schema is created and upgraded by versioned [`migrations`]. On top of the
+//! cached workspace data, the [`store`] module keeps a metadata store of contracts,
+//! contract versions, consumers and audit events for the API server, with
+//! faceted [`search`] across them, and the [`jobs`] table persists background
+//! jobs. The [`introspect`] and [`introspect_mysql`] modules build a data model
+//! from the catalog of a live PostgreSQL or MySQL/MariaDB database.
 //!
 //! The database layer provides 10-100x performance improvements over
 //! file-based operations for large workspaces by caching YAML data
@@ -19,8 +27,17 @@ pub mod duckdb;
 #[cfg(feature = "postgres-backend")]
 pub mod postgres;
 
+#[cfg(feature = "mysql-backend")]
+pub mod mysql;
+
 pub mod config;
+pub mod introspect;
+pub mod introspect_mysql;
+pub mod jobs;
+pub mod migrations;
 pub mod schema;
+pub mod search;
+pub mod store;
 pub mod sync;
 
 #[cfg(feature = "duckdb-backend")]
@@ -29,8 +46,17 @@ pub use self::duckdb::DuckDBBackend;
 #[cfg(feature = "postgres-backend")]
 pub use self::postgres::PostgresBackend;
 
+#[cfg(feature = "mysql-backend")]
+pub use self::mysql::MySqlBackend;
+
 pub use config::DatabaseConfig;
+pub use introspect::PostgresIntrospector;
+pub use introspect_mysql::MySqlIntrospector;
+pub use jobs::DatabaseJobStore;
+pub use migrations::{Migration, migrate};
 pub use schema::DatabaseSchema;
+pub use search::{SearchQuery, SearchResults};
+pub use store::{MetadataStore, MirrorOptions, MirrorResult};
 pub use sync::{SyncEngine, SyncResult};
 
 /// Error type for database operations
@@ -208,6 +234,21 @@ pub trait DatabaseBackend: Send + Sync {
         params: &[serde_json::Value],
     ) -> DatabaseResult<QueryResult>;
 
+    /// Execute a script of `;`-separated statements, such as a migration
+    ///
+    /// The default implementation runs the statements one at a time with
+    /// [`execute_query`](Self::execute_query); backends that can execute a batch
+    /// natively override it.
+    ///
+    /// # Arguments
+    /// * `sql` - SQL statements to execute
+    async fn execute_script(&self, sql: &str) -> DatabaseResult<()> {
+        for statement in migrations::split_statements(sql) {
+            self.execute_query(&statement).await?;
+        }
+        Ok(())
+    }
+
     /// Sync tables from YAML data to database
     ///
     /// # Arguments
@@ -367,11 +408,19 @@ pub trait DatabaseBackend: Send + Sync {
 
     /// Get workspace by name
     ///
+    /// Names are only unique per tenant; use
+    /// [`WorkspaceDao::get_by_name`](store::WorkspaceDao::get_by_name) to look
+    /// up the workspace of a tenant.
+    ///
     /// # Arguments
     /// * `name` - Workspace name
     ///
     /// # Returns
     /// Optional Workspace if found
+    ///
+    /// # Errors
+    /// [`DatabaseError::InvalidInput`] when several tenants have a workspace
+    /// with the name.
     async fn get_workspace_by_name(
         &self,
         name: &str,
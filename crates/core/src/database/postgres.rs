@@ -8,7 +8,8 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
-use super::schema::DatabaseSchema;
+use super::migrations;
+use super::schema::workspace_record_sql;
 use super::{DatabaseBackend, DatabaseError, DatabaseResult, QueryResult, SyncStatus};
 use crate::models::{Domain, Relationship, Table, Workspace};
 
@@ -117,36 +118,17 @@ impl PostgresBackend {
 #[async_trait(?Send)]
 impl DatabaseBackend for PostgresBackend {
     async fn initialize(&self) -> DatabaseResult<()> {
-        let client = self.client.lock().await;
-
-        // Create tables
-        client
-            .batch_execute(DatabaseSchema::create_tables_sql())
-            .await
-            .map_err(|e| {
-                DatabaseError::MigrationFailed(format!("Failed to create tables: {}", e))
-            })?;
-
-        // Create indexes
-        client
-            .batch_execute(DatabaseSchema::create_indexes_sql())
-            .await
-            .map_err(|e| {
-                DatabaseError::MigrationFailed(format!("Failed to create indexes: {}", e))
-            })?;
+        // Create or upgrade the schema
+        migrations::migrate(self).await?;
+        Ok(())
+    }
 
-        // Record schema version
+    async fn execute_script(&self, sql: &str) -> DatabaseResult<()> {
+        let client = self.client.lock().await;
         client
-            .execute(
-                "INSERT INTO schema_version (version) VALUES ($1) ON CONFLICT (version) DO NOTHING",
-                &[&super::schema::SCHEMA_VERSION],
-            )
+            .batch_execute(sql)
             .await
-            .map_err(|e| {
-                DatabaseError::MigrationFailed(format!("Failed to record schema version: {}", e))
-            })?;
-
-        Ok(())
+            .map_err(|e| DatabaseError::QueryFailed(format!("Batch execute failed: {}", e)))
     }
 
     async fn execute_query(&self, sql: &str) -> DatabaseResult<QueryResult> {
@@ -709,10 +691,15 @@ impl DatabaseBackend for PostgresBackend {
     }
 
     async fn upsert_workspace(&self, workspace: &Workspace) -> DatabaseResult<()> {
-        let client = self.client.lock().await;
+        let mut client = self.client.lock().await;
         let now = chrono::Utc::now();
 
-        client.execute(
+        // The workspace and its name row are written together, so a name taken
+        // within the tenant leaves the workspace unchanged
+        let tx = client.transaction().await.map_err(|e| {
+            DatabaseError::SyncFailed(format!("Failed to begin transaction: {}", e))
+        })?;
+        tx.execute(
             r#"
             INSERT INTO workspaces (id, name, owner_id, created_at, last_modified_at, yaml_hash, metadata)
             VALUES ($1, $2, $3, $4, $5, $6, $7)
@@ -734,6 +721,20 @@ impl DatabaseBackend for PostgresBackend {
             ],
         ).await
             .map_err(|e| DatabaseError::SyncFailed(format!("Failed to upsert workspace: {}", e)))?;
+        tx.execute(
+            workspace_record_sql::SET_TENANT,
+            &[&workspace.id.to_string(), &workspace.tenant, &workspace.name],
+        )
+        .await
+        .map_err(|e| {
+            DatabaseError::SyncFailed(format!(
+                "Failed to record workspace name '{}': {}",
+                workspace.name, e
+            ))
+        })?;
+        tx.commit().await.map_err(|e| {
+            DatabaseError::SyncFailed(format!("Failed to commit transaction: {}", e))
+        })?;
 
         Ok(())
     }
@@ -775,6 +776,13 @@ impl DatabaseBackend for PostgresBackend {
                 name.replace('\'', "''")
             ))
             .await?;
+        if result.rows.len() > 1 {
+            return Err(DatabaseError::InvalidInput(format!(
+                "Workspace name '{}' is used by {} workspaces of different tenants",
+                name,
+                result.rows.len()
+            )));
+        }
 
         if let Some(row) = result.rows.first() {
             let id: Uuid = row
@@ -806,6 +814,15 @@ impl DatabaseBackend for PostgresBackend {
 
     async fn delete_workspace(&self, workspace_id: Uuid) -> DatabaseResult<()> {
         let client = self.client.lock().await;
+        client
+            .execute(
+                workspace_record_sql::DELETE_TENANT,
+                &[&workspace_id.to_string()],
+            )
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryFailed(format!("Failed to delete workspace: {}", e))
+            })?;
         client
             .execute(
                 "DELETE FROM workspaces WHERE id = $1",
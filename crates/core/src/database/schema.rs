@@ -4,7 +4,7 @@
 //! Complex nested data (JSONB) is used for fields that don't need to be indexed.
 
 /// Schema version for migrations
-pub const SCHEMA_VERSION: i32 = 2;
+pub const SCHEMA_VERSION: i32 = 8;
 
 /// Database schema helper
 pub struct DatabaseSchema;
@@ -318,6 +318,200 @@ CREATE INDEX IF NOT EXISTS idx_knowledge_number ON knowledge_articles(workspace_
 "#
     }
 
+    /// Get the metadata store schema SQL
+    ///
+    /// Contracts, their version history, consumers and the audit log, mirrored
+    /// from the Git workspace for the API server. Audit events keep the ID of the
+    /// entity they describe without a foreign key so they outlive deleted entities.
+    pub fn create_metadata_store_sql() -> &'static str {
+        r#"
+-- Data contracts mirrored from ODCS files
+CREATE TABLE IF NOT EXISTS contracts (
+    id UUID PRIMARY KEY,
+    workspace_id UUID NOT NULL REFERENCES workspaces(id),
+    contract_key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    version TEXT NOT NULL,
+    status TEXT,
+    domain_name TEXT,
+    owner TEXT,
+    file_path TEXT,
+    yaml_hash TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(workspace_id, contract_key)
+);
+
+-- Contract version history (one row per distinct file content)
+CREATE TABLE IF NOT EXISTS contract_versions (
+    id UUID PRIMARY KEY,
+    contract_id UUID NOT NULL REFERENCES contracts(id),
+    version TEXT NOT NULL,
+    yaml_hash TEXT NOT NULL,
+    content TEXT NOT NULL,
+    revision TEXT,
+    created_by TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(contract_id, yaml_hash)
+);
+
+-- Principals consuming a contract
+CREATE TABLE IF NOT EXISTS contract_consumers (
+    id UUID PRIMARY KEY,
+    contract_id UUID NOT NULL REFERENCES contracts(id),
+    principal TEXT NOT NULL,
+    role TEXT,
+    access TEXT,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(contract_id, principal)
+);
+
+-- Audit log of changes to the metadata store
+CREATE TABLE IF NOT EXISTS audit_events (
+    id UUID PRIMARY KEY,
+    workspace_id UUID NOT NULL REFERENCES workspaces(id),
+    entity_type TEXT NOT NULL,
+    entity_id UUID,
+    action TEXT NOT NULL,
+    actor TEXT,
+    revision TEXT,
+    details JSON,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_contracts_workspace ON contracts(workspace_id);
+CREATE INDEX IF NOT EXISTS idx_contracts_status ON contracts(status);
+CREATE INDEX IF NOT EXISTS idx_contract_versions_contract ON contract_versions(contract_id);
+CREATE INDEX IF NOT EXISTS idx_contract_consumers_contract ON contract_consumers(contract_id);
+CREATE INDEX IF NOT EXISTS idx_contract_consumers_principal ON contract_consumers(principal);
+CREATE INDEX IF NOT EXISTS idx_audit_events_workspace ON audit_events(workspace_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_events_entity ON audit_events(entity_type, entity_id);
+"#
+    }
+
+    /// Get the search index schema SQL
+    ///
+    /// One document per searchable entity (contracts and their schema objects),
+    /// with the lowercased text of names and descriptions in `content`, and one
+    /// facet row per tag, classification, owner, domain, status and entity type.
+    pub fn create_search_index_sql() -> &'static str {
+        r#"
+-- Searchable entities of the metadata store
+CREATE TABLE IF NOT EXISTS search_documents (
+    id UUID PRIMARY KEY,
+    workspace_id UUID NOT NULL REFERENCES workspaces(id),
+    entity_type TEXT NOT NULL,
+    parent_id UUID,
+    name TEXT NOT NULL,
+    description TEXT,
+    owner TEXT,
+    domain_name TEXT,
+    status TEXT,
+    file_path TEXT,
+    tags TEXT,
+    content TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Facet values of search documents
+CREATE TABLE IF NOT EXISTS search_facets (
+    document_id UUID NOT NULL REFERENCES search_documents(id),
+    facet TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (document_id, facet, value)
+);
+
+CREATE INDEX IF NOT EXISTS idx_search_documents_workspace ON search_documents(workspace_id);
+CREATE INDEX IF NOT EXISTS idx_search_documents_parent ON search_documents(parent_id);
+CREATE INDEX IF NOT EXISTS idx_search_facets_value ON search_facets(facet, value);
+"#
+    }
+
+    /// Get the background job schema SQL
+    ///
+    /// `data` holds the serialized [`Job`](crate::jobs::Job); `status` and
+    /// `claim_token` are kept in columns so workers can claim jobs atomically.
+    pub fn create_jobs_sql() -> &'static str {
+        r#"
+-- Background jobs
+CREATE TABLE IF NOT EXISTS jobs (
+    id UUID PRIMARY KEY,
+    kind TEXT NOT NULL,
+    status TEXT NOT NULL,
+    claim_token TEXT,
+    data TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_jobs_status ON jobs(status, created_at);
+"#
+    }
+
+    /// Get the SQL adding the job columns updated while a job runs
+    ///
+    /// Cancellation requests and progress are written to `cancel_requested`
+    /// and `progress` on their own, so they never overwrite each other; both
+    /// take precedence over the values in `data`.
+    pub fn create_job_updates_sql() -> &'static str {
+        r#"
+-- Job fields updated while a job runs; DuckDB cannot alter a table an index
+-- depends on, so the status index is rebuilt around the new columns
+DROP INDEX IF EXISTS idx_jobs_status;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS cancel_requested BOOLEAN DEFAULT FALSE;
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS progress TEXT;
+CREATE INDEX IF NOT EXISTS idx_jobs_status ON jobs(status, created_at);
+"#
+    }
+
+    /// Get the workspace tenant schema SQL
+    ///
+    /// A workspace is assigned to a tenant when it is first stored by a
+    /// tenant-scoped [`MetadataStore`](super::MetadataStore); workspaces without
+    /// a row, or with a NULL tenant (see [`Self::create_workspace_names_sql`]),
+    /// belong to no tenant.
+    pub fn create_tenants_sql() -> &'static str {
+        r#"
+-- Tenant owning each workspace
+CREATE TABLE IF NOT EXISTS workspace_tenants (
+    workspace_id UUID PRIMARY KEY,
+    tenant TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_workspace_tenants_tenant ON workspace_tenants(tenant);
+"#
+    }
+
+    /// Get the SQL making workspace names unique per tenant
+    ///
+    /// `workspace_tenants` gets a row for every workspace, with a NULL tenant
+    /// for workspaces without one, and a copy of the workspace name. The name
+    /// is unique per tenant, and among the workspaces without a tenant,
+    /// replacing the index that made names unique across all tenants. The
+    /// backends write the row together with the workspace.
+    ///
+    /// The index is not on `workspaces` itself, because DuckDB cannot update
+    /// indexed columns of rows that other tables reference.
+    pub fn create_workspace_names_sql() -> &'static str {
+        r#"
+-- Workspace names are unique per tenant, and among workspaces without a tenant
+DROP INDEX IF EXISTS idx_workspaces_name;
+DROP INDEX IF EXISTS idx_workspace_tenants_tenant;
+ALTER TABLE workspace_tenants ALTER COLUMN tenant DROP NOT NULL;
+ALTER TABLE workspace_tenants ADD COLUMN IF NOT EXISTS name TEXT;
+UPDATE workspace_tenants SET name = (
+    SELECT w.name FROM workspaces w WHERE w.id = workspace_tenants.workspace_id
+);
+INSERT INTO workspace_tenants (workspace_id, name)
+SELECT id, name FROM workspaces WHERE id NOT IN (SELECT workspace_id FROM workspace_tenants);
+CREATE INDEX IF NOT EXISTS idx_workspace_tenants_tenant ON workspace_tenants(tenant);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_workspace_tenants_name
+    ON workspace_tenants ((COALESCE(tenant, '')), name);
+"#
+    }
+
     /// Get DuckDB-specific optimizations
     #[cfg(feature = "duckdb-backend")]
     pub fn duckdb_optimizations_sql() -> &'static str {
@@ -352,6 +546,13 @@ PRAGMA threads=4;
     /// Drop all tables (for testing/reset)
     pub fn drop_all_tables_sql() -> &'static str {
         r#"
+DROP TABLE IF EXISTS jobs;
+DROP TABLE IF EXISTS search_facets;
+DROP TABLE IF EXISTS search_documents;
+DROP TABLE IF EXISTS audit_events;
+DROP TABLE IF EXISTS contract_consumers;
+DROP TABLE IF EXISTS contract_versions;
+DROP TABLE IF EXISTS contracts;
 DROP TABLE IF EXISTS sync_log;
 DROP TABLE IF EXISTS file_hashes;
 DROP TABLE IF EXISTS tags;
@@ -362,6 +563,7 @@ DROP TABLE IF EXISTS tables;
 DROP TABLE IF EXISTS knowledge_articles;
 DROP TABLE IF EXISTS decisions;
 DROP TABLE IF EXISTS domains;
+DROP TABLE IF EXISTS workspace_tenants;
 DROP TABLE IF EXISTS workspaces;
 DROP TABLE IF EXISTS schema_version;
 "#
@@ -382,10 +584,426 @@ ON CONFLICT (id) DO UPDATE SET
 "#;
 
     pub const SELECT_BY_ID: &str = "SELECT * FROM workspaces WHERE id = $1";
-    pub const SELECT_BY_NAME: &str = "SELECT * FROM workspaces WHERE name = $1";
+    /// Workspace with a name, of the tenant bound to `$2` (NULL for workspaces
+    /// without a tenant)
+    pub const SELECT_BY_NAME: &str = r#"
+SELECT * FROM workspaces WHERE name = $1 AND id IN (
+    SELECT workspace_id FROM workspace_tenants WHERE tenant IS NOT DISTINCT FROM $2
+)
+"#;
     pub const DELETE: &str = "DELETE FROM workspaces WHERE id = $1";
 }
 
+// The metadata store statements below are run through
+// `DatabaseBackend::execute_query_params`, which passes every parameter as text,
+// so parameters are cast explicitly and non-text columns are selected as text.
+
+/// Condition limiting `column`, a workspace ID, to the workspaces of the tenant
+/// bound to `$param`
+fn tenant_workspaces(column: &str, param: usize) -> String {
+    format!(
+        "{} IN (SELECT workspace_id FROM workspace_tenants WHERE tenant = ${}::TEXT)",
+        column, param
+    )
+}
+
+/// ` AND` condition limiting `contract_id` to the contracts in the workspaces of
+/// the tenant bound to `$param`; empty for queries that aren't tenant-scoped
+fn tenant_contracts(tenant_scoped: bool, param: usize) -> String {
+    if !tenant_scoped {
+        return String::new();
+    }
+    format!(
+        " AND contract_id IN (SELECT c.id FROM contracts c WHERE {})",
+        tenant_workspaces("c.workspace_id", param)
+    )
+}
+
+/// SQL for the metadata store's workspace queries
+pub mod workspace_record_sql {
+    const COLUMNS: &str = r#"
+    CAST(w.id AS TEXT) AS id, w.name, CAST(w.owner_id AS TEXT) AS owner_id, t.tenant,
+    CAST(w.created_at AS TEXT) AS created_at, CAST(w.last_modified_at AS TEXT) AS last_modified_at
+"#;
+
+    const FROM: &str = "workspaces w LEFT JOIN workspace_tenants t ON t.workspace_id = w.id";
+
+    /// Record the tenant (NULL for none) and name of a workspace; an existing
+    /// tenant is kept, and fails when the workspace's tenant has another
+    /// workspace with the name
+    pub const SET_TENANT: &str = r#"
+INSERT INTO workspace_tenants (workspace_id, tenant, name)
+VALUES ($1::TEXT::UUID, $2::TEXT, $3::TEXT)
+ON CONFLICT (workspace_id) DO UPDATE SET
+    name = EXCLUDED.name,
+    tenant = COALESCE(workspace_tenants.tenant, EXCLUDED.tenant)
+"#;
+
+    pub const DELETE_TENANT: &str =
+        "DELETE FROM workspace_tenants WHERE workspace_id = $1::TEXT::UUID";
+
+    pub fn select_all() -> String {
+        format!("SELECT {} FROM {} ORDER BY w.name", COLUMNS, FROM)
+    }
+
+    pub fn select_by_id() -> String {
+        format!(
+            "SELECT {} FROM {} WHERE w.id = $1::TEXT::UUID",
+            COLUMNS, FROM
+        )
+    }
+
+    /// Workspace with a name; a tenant-scoped query binds the tenant to `$2`,
+    /// others only find workspaces without a tenant
+    pub fn select_by_name(tenant_scoped: bool) -> String {
+        let tenant = if tenant_scoped {
+            "t.tenant = $2::TEXT"
+        } else {
+            "t.tenant IS NULL"
+        };
+        format!(
+            "SELECT {} FROM {} WHERE w.name = $1::TEXT AND {}",
+            COLUMNS, FROM, tenant
+        )
+    }
+
+    pub fn select_by_tenant() -> String {
+        format!(
+            "SELECT {} FROM {} WHERE t.tenant = $1::TEXT ORDER BY w.name",
+            COLUMNS, FROM
+        )
+    }
+}
+
+/// SQL for contracts
+pub mod contract_sql {
+    const COLUMNS: &str = r#"
+    CAST(id AS TEXT) AS id, CAST(workspace_id AS TEXT) AS workspace_id, contract_key,
+    name, version, status, domain_name, owner, file_path, yaml_hash,
+    CAST(created_at AS TEXT) AS created_at, CAST(updated_at AS TEXT) AS updated_at
+"#;
+
+    pub const UPSERT: &str = r#"
+INSERT INTO contracts (
+    id, workspace_id, contract_key, name, version, status, domain_name, owner,
+    file_path, yaml_hash
+)
+VALUES (
+    $1::TEXT::UUID, $2::TEXT::UUID, $3::TEXT, $4::TEXT, $5::TEXT, NULLIF($6::TEXT, ''),
+    NULLIF($7::TEXT, ''), NULLIF($8::TEXT, ''), NULLIF($9::TEXT, ''), NULLIF($10::TEXT, '')
+)
+ON CONFLICT (id) DO UPDATE SET
+    contract_key = EXCLUDED.contract_key,
+    name = EXCLUDED.name,
+    version = EXCLUDED.version,
+    status = EXCLUDED.status,
+    domain_name = EXCLUDED.domain_name,
+    owner = EXCLUDED.owner,
+    file_path = EXCLUDED.file_path,
+    yaml_hash = EXCLUDED.yaml_hash,
+    updated_at = now()
+WHERE contracts.workspace_id = EXCLUDED.workspace_id
+"#;
+
+    pub fn select_by_workspace() -> String {
+        format!(
+            "SELECT {} FROM contracts WHERE workspace_id = $1::TEXT::UUID ORDER BY name",
+            COLUMNS
+        )
+    }
+
+    pub fn select_by_id() -> String {
+        format!(
+            "SELECT {} FROM contracts WHERE id = $1::TEXT::UUID",
+            COLUMNS
+        )
+    }
+
+    pub fn select_by_status() -> String {
+        format!(
+            "SELECT {} FROM contracts WHERE workspace_id = $1::TEXT::UUID AND status = $2::TEXT ORDER BY name",
+            COLUMNS
+        )
+    }
+
+    pub const DELETE: &str = "DELETE FROM contracts WHERE id = $1::TEXT::UUID";
+    pub const DELETE_BY_WORKSPACE: &str =
+        "DELETE FROM contracts WHERE workspace_id = $1::TEXT::UUID";
+    pub const COUNT_BY_WORKSPACE: &str =
+        "SELECT COUNT(*) AS count FROM contracts WHERE workspace_id = $1::TEXT::UUID";
+}
+
+/// SQL for contract versions
+pub mod contract_version_sql {
+    const COLUMNS: &str = r#"
+    CAST(id AS TEXT) AS id, CAST(contract_id AS TEXT) AS contract_id, version,
+    yaml_hash, content, revision, created_by, CAST(created_at AS TEXT) AS created_at
+"#;
+
+    pub const INSERT: &str = r#"
+INSERT INTO contract_versions (
+    id, contract_id, version, yaml_hash, content, revision, created_by
+)
+VALUES (
+    $1::TEXT::UUID, $2::TEXT::UUID, $3::TEXT, $4::TEXT, $5::TEXT,
+    NULLIF($6::TEXT, ''), NULLIF($7::TEXT, '')
+)
+ON CONFLICT (contract_id, yaml_hash) DO NOTHING
+"#;
+
+    /// Versions of a contract; a tenant-scoped query binds the tenant to `$2`
+    pub fn select_by_contract(tenant_scoped: bool) -> String {
+        format!(
+            "SELECT {} FROM contract_versions WHERE contract_id = $1::TEXT::UUID{} ORDER BY created_at DESC",
+            COLUMNS,
+            super::tenant_contracts(tenant_scoped, 2)
+        )
+    }
+
+    /// Latest version of a contract; a tenant-scoped query binds the tenant to `$2`
+    pub fn select_latest(tenant_scoped: bool) -> String {
+        format!(
+            "SELECT {} FROM contract_versions WHERE contract_id = $1::TEXT::UUID{} ORDER BY created_at DESC LIMIT 1",
+            COLUMNS,
+            super::tenant_contracts(tenant_scoped, 2)
+        )
+    }
+
+    /// Contents of a contract version; a tenant-scoped query binds the tenant to `$3`
+    pub fn select_by_version(tenant_scoped: bool) -> String {
+        format!(
+            "SELECT {} FROM contract_versions WHERE contract_id = $1::TEXT::UUID AND version = $2::TEXT{} ORDER BY created_at DESC",
+            COLUMNS,
+            super::tenant_contracts(tenant_scoped, 3)
+        )
+    }
+
+    pub const DELETE_BY_CONTRACT: &str =
+        "DELETE FROM contract_versions WHERE contract_id = $1::TEXT::UUID";
+}
+
+/// SQL for contract consumers
+pub mod consumer_sql {
+    const COLUMNS: &str = r#"
+    CAST(id AS TEXT) AS id, CAST(contract_id AS TEXT) AS contract_id, principal,
+    role, access, description, CAST(created_at AS TEXT) AS created_at
+"#;
+
+    pub const UPSERT: &str = r#"
+INSERT INTO contract_consumers (id, contract_id, principal, role, access, description)
+VALUES (
+    $1::TEXT::UUID, $2::TEXT::UUID, $3::TEXT, NULLIF($4::TEXT, ''),
+    NULLIF($5::TEXT, ''), NULLIF($6::TEXT, '')
+)
+ON CONFLICT (contract_id, principal) DO UPDATE SET
+    role = EXCLUDED.role,
+    access = EXCLUDED.access,
+    description = EXCLUDED.description
+"#;
+
+    pub fn select_by_id() -> String {
+        format!(
+            "SELECT {} FROM contract_consumers WHERE id = $1::TEXT::UUID",
+            COLUMNS
+        )
+    }
+
+    /// Consumers of a contract; a tenant-scoped query binds the tenant to `$2`
+    pub fn select_by_contract(tenant_scoped: bool) -> String {
+        format!(
+            "SELECT {} FROM contract_consumers WHERE contract_id = $1::TEXT::UUID{} ORDER BY principal",
+            COLUMNS,
+            super::tenant_contracts(tenant_scoped, 2)
+        )
+    }
+
+    /// Contracts a principal consumes; a tenant-scoped query binds the tenant to `$2`
+    pub fn select_by_principal(tenant_scoped: bool) -> String {
+        format!(
+            "SELECT {} FROM contract_consumers WHERE principal = $1::TEXT{} ORDER BY contract_id",
+            COLUMNS,
+            super::tenant_contracts(tenant_scoped, 2)
+        )
+    }
+
+    pub const DELETE: &str = "DELETE FROM contract_consumers WHERE contract_id = $1::TEXT::UUID AND principal = $2::TEXT";
+    pub const DELETE_BY_CONTRACT: &str =
+        "DELETE FROM contract_consumers WHERE contract_id = $1::TEXT::UUID";
+}
+
+/// SQL for audit events
+pub mod audit_event_sql {
+    const COLUMNS: &str = r#"
+    CAST(id AS TEXT) AS id, CAST(workspace_id AS TEXT) AS workspace_id, entity_type,
+    CAST(entity_id AS TEXT) AS entity_id, action, actor, revision,
+    CAST(details AS TEXT) AS details, CAST(created_at AS TEXT) AS created_at
+"#;
+
+    pub const INSERT: &str = r#"
+INSERT INTO audit_events (
+    id, workspace_id, entity_type, entity_id, action, actor, revision, details
+)
+VALUES (
+    $1::TEXT::UUID, $2::TEXT::UUID, $3::TEXT, NULLIF($4::TEXT, '')::UUID, $5::TEXT,
+    NULLIF($6::TEXT, ''), NULLIF($7::TEXT, ''), NULLIF($8::TEXT, '')::JSON
+)
+"#;
+
+    pub fn select_by_workspace(limit: usize) -> String {
+        format!(
+            "SELECT {} FROM audit_events WHERE workspace_id = $1::TEXT::UUID ORDER BY created_at DESC LIMIT {}",
+            COLUMNS, limit
+        )
+    }
+
+    pub fn select_by_entity() -> String {
+        format!(
+            "SELECT {} FROM audit_events WHERE entity_type = $1::TEXT AND entity_id = $2::TEXT::UUID ORDER BY created_at DESC",
+            COLUMNS
+        )
+    }
+
+    pub const DELETE_BY_WORKSPACE: &str =
+        "DELETE FROM audit_events WHERE workspace_id = $1::TEXT::UUID";
+}
+
+/// SQL for the search index
+///
+/// Search queries are assembled from their filters in
+/// [`SearchQuery`](super::search::SearchQuery).
+pub mod search_sql {
+    pub const COLUMNS: &str = r#"
+    CAST(d.id AS TEXT) AS id, d.entity_type, CAST(d.parent_id AS TEXT) AS parent_id,
+    d.name, d.description, d.owner, d.domain_name, d.status, d.file_path, d.tags
+"#;
+
+    pub const UPSERT_DOCUMENT: &str = r#"
+INSERT INTO search_documents (
+    id, workspace_id, entity_type, parent_id, name, description, owner, domain_name,
+    status, file_path, tags, content
+)
+VALUES (
+    $1::TEXT::UUID, $2::TEXT::UUID, $3::TEXT, NULLIF($4::TEXT, '')::UUID, $5::TEXT,
+    NULLIF($6::TEXT, ''), NULLIF($7::TEXT, ''), NULLIF($8::TEXT, ''), NULLIF($9::TEXT, ''),
+    NULLIF($10::TEXT, ''), NULLIF($11::TEXT, ''), $12::TEXT
+)
+ON CONFLICT (id) DO UPDATE SET
+    entity_type = EXCLUDED.entity_type,
+    parent_id = EXCLUDED.parent_id,
+    name = EXCLUDED.name,
+    description = EXCLUDED.description,
+    owner = EXCLUDED.owner,
+    domain_name = EXCLUDED.domain_name,
+    status = EXCLUDED.status,
+    file_path = EXCLUDED.file_path,
+    tags = EXCLUDED.tags,
+    content = EXCLUDED.content,
+    updated_at = now()
+"#;
+
+    pub const INSERT_FACET: &str = r#"
+INSERT INTO search_facets (document_id, facet, value)
+VALUES ($1::TEXT::UUID, $2::TEXT, $3::TEXT)
+ON CONFLICT (document_id, facet, value) DO NOTHING
+"#;
+
+    pub const DELETE_FACETS: &str = "DELETE FROM search_facets WHERE document_id = $1::TEXT::UUID";
+
+    /// Deletes the facets of an entity and its children
+    pub const DELETE_FACETS_BY_ENTITY: &str = r#"
+DELETE FROM search_facets WHERE document_id IN (
+    SELECT id FROM search_documents
+    WHERE id = $1::TEXT::UUID OR parent_id = $1::TEXT::UUID
+)
+"#;
+
+    /// Deletes an entity and its children
+    pub const DELETE_BY_ENTITY: &str =
+        "DELETE FROM search_documents WHERE id = $1::TEXT::UUID OR parent_id = $1::TEXT::UUID";
+
+    pub const DELETE_FACETS_BY_WORKSPACE: &str = r#"
+DELETE FROM search_facets WHERE document_id IN (
+    SELECT id FROM search_documents WHERE workspace_id = $1::TEXT::UUID
+)
+"#;
+
+    pub const DELETE_BY_WORKSPACE: &str =
+        "DELETE FROM search_documents WHERE workspace_id = $1::TEXT::UUID";
+
+    pub const SELECT_IDS_BY_TYPE: &str = r#"
+SELECT CAST(id AS TEXT) AS id FROM search_documents
+WHERE workspace_id = $1::TEXT::UUID AND entity_type = $2::TEXT
+"#;
+
+    pub const SELECT_WORKSPACE_BY_ENTITY: &str = "SELECT CAST(workspace_id AS TEXT) AS workspace_id FROM search_documents WHERE id = $1::TEXT::UUID";
+
+    /// Condition limiting `d.workspace_id` to the workspaces of the tenant bound to `$param`
+    pub fn tenant_filter(param: usize) -> String {
+        super::tenant_workspaces("d.workspace_id", param)
+    }
+}
+
+/// SQL for background jobs
+pub mod job_sql {
+    /// Saves a job; a cancellation requested meanwhile is kept
+    pub const UPSERT: &str = r#"
+INSERT INTO jobs (id, kind, status, data, created_at, cancel_requested, progress)
+VALUES (
+    $1::TEXT::UUID, $2::TEXT, $3::TEXT, $4::TEXT, $5::TEXT::TIMESTAMPTZ, $6::TEXT::BOOLEAN,
+    $7::TEXT
+)
+ON CONFLICT (id) DO UPDATE SET
+    status = EXCLUDED.status,
+    data = EXCLUDED.data,
+    cancel_requested = COALESCE(jobs.cancel_requested, FALSE) OR EXCLUDED.cancel_requested,
+    progress = EXCLUDED.progress,
+    updated_at = now()
+"#;
+
+    pub const SELECT_BY_ID: &str =
+        "SELECT data, status, cancel_requested, progress FROM jobs WHERE id = $1::TEXT::UUID";
+
+    pub const SELECT_ALL: &str =
+        "SELECT data, status, cancel_requested, progress FROM jobs ORDER BY created_at, id";
+
+    pub const SELECT_BY_STATUS: &str = "SELECT data, status, cancel_requested, progress FROM jobs WHERE status = $1::TEXT ORDER BY created_at, id";
+
+    pub const SELECT_NEXT_QUEUED: &str = r#"
+SELECT CAST(id AS TEXT) AS id FROM jobs
+WHERE status = 'queued'
+ORDER BY created_at, id
+LIMIT 1
+"#;
+
+    /// Claims a queued job; affects no row when another worker claimed it first
+    pub const CLAIM: &str = r#"
+UPDATE jobs SET status = 'running', claim_token = $2::TEXT, updated_at = CURRENT_TIMESTAMP
+WHERE id = $1::TEXT::UUID AND status = 'queued'
+"#;
+
+    pub const SELECT_CLAIMED: &str = "SELECT data, status, cancel_requested, progress FROM jobs WHERE id = $1::TEXT::UUID AND claim_token = $2::TEXT";
+
+    /// Requests cancellation of an unfinished job, leaving the rest of it alone
+    pub const REQUEST_CANCEL: &str = r#"
+UPDATE jobs SET cancel_requested = TRUE, updated_at = CURRENT_TIMESTAMP
+WHERE id = $1::TEXT::UUID AND status IN ('queued', 'running')
+"#;
+
+    /// Cancels a queued job; affects no row when a worker claimed it first
+    pub const CANCEL_QUEUED: &str = r#"
+UPDATE jobs SET status = 'cancelled', data = $2::TEXT, updated_at = CURRENT_TIMESTAMP
+WHERE id = $1::TEXT::UUID AND status = 'queued'
+"#;
+
+    /// Records the progress of a job, leaving the rest of it alone
+    pub const UPDATE_PROGRESS: &str = r#"
+UPDATE jobs SET progress = $2::TEXT, updated_at = CURRENT_TIMESTAMP
+WHERE id = $1::TEXT::UUID
+"#;
+
+    pub const DELETE: &str = "DELETE FROM jobs WHERE id = $1::TEXT::UUID";
+}
+
 /// SQL for inserting/updating domains
 pub mod domain_sql {
     pub const UPSERT: &str = r#"
@@ -734,7 +1352,31 @@ mod tests {
     #[test]
     fn test_schema_version() {
         // Verify schema version is a positive integer
-        assert_eq!(SCHEMA_VERSION, 2);
+        assert_eq!(SCHEMA_VERSION, 8);
+    }
+
+    #[test]
+    fn test_metadata_store_sql_casts_parameters() {
+        assert!(DatabaseSchema::create_metadata_store_sql().contains("audit_events"));
+        // Every parameter is cast, as backends bind parameters as text
+        for sql in [
+            contract_sql::UPSERT,
+            contract_version_sql::INSERT,
+            consumer_sql::UPSERT,
+            audit_event_sql::INSERT,
+            search_sql::UPSERT_DOCUMENT,
+            search_sql::INSERT_FACET,
+            job_sql::UPSERT,
+            job_sql::CLAIM,
+            job_sql::REQUEST_CANCEL,
+            job_sql::CANCEL_QUEUED,
+            job_sql::UPDATE_PROGRESS,
+            workspace_record_sql::SET_TENANT,
+        ] {
+            let params = sql.matches('$').count();
+            let cast = sql.matches("::TEXT").count();
+            assert_eq!(params, cast, "{}", sql);
+        }
     }
 
     #[test]
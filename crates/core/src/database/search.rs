@@ -0,0 +1,835 @@
+//! Faceted search over the metadata store
+//!
+//! Contracts and their schema objects are indexed as [`SearchDocument`]s when a
+//! workspace is mirrored. A [`SearchQuery`] combines:
+//!
+//! - **Free text** - every term must occur in the name, description, tags or
+//!   property names and descriptions (case-insensitive)
+//! - **Name** - substring of the entity name
+//! - **Facet filters** - entity type, tag, classification, owner, domain and status;
+//!   values of one facet are alternatives, different facets must all match
+//!
+//! [`SearchResults`] hold one page of hits, the total number of matches and the
+//! value counts of every facet across all matches.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashSet};
+use uuid::Uuid;
+
+use super::schema::search_sql;
+use super::store::{
+    CONTRACT_ENTITY, authorize_workspace, execute, optional_uuid, query, required_text,
+    required_uuid, text,
+};
+use super::{DatabaseBackend, DatabaseResult, QueryRow};
+use crate::models::odcs::{ODCSContract, Property, SchemaObject};
+use crate::storage::tenant::TenantId;
+
+/// Entity type of schema objects (tables) of a contract
+pub const TABLE_ENTITY: &str = "table";
+
+/// Entity type facet
+pub const TYPE_FACET: &str = "type";
+/// Tag facet
+pub const TAG_FACET: &str = "tag";
+/// Classification facet
+pub const CLASSIFICATION_FACET: &str = "classification";
+/// Owner facet
+pub const OWNER_FACET: &str = "owner";
+/// Domain facet
+pub const DOMAIN_FACET: &str = "domain";
+/// Status facet
+pub const STATUS_FACET: &str = "status";
+
+/// Default page size
+pub const DEFAULT_LIMIT: usize = 20;
+/// Largest page size
+pub const MAX_LIMIT: usize = 100;
+
+/// A searchable entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchDocument {
+    /// Entity ID
+    pub id: Uuid,
+    /// Workspace ID
+    pub workspace_id: Uuid,
+    /// Entity type (`contract` or `table`)
+    pub entity_type: String,
+    /// ID of the containing entity (the contract of a table)
+    pub parent_id: Option<Uuid>,
+    /// Entity name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Owning team
+    pub owner: Option<String>,
+    /// Domain name
+    pub domain: Option<String>,
+    /// Status
+    pub status: Option<String>,
+    /// File path relative to the workspace root
+    pub file_path: Option<String>,
+    /// Tags
+    pub tags: Vec<String>,
+    /// Data classifications of the entity's properties
+    pub classifications: Vec<String>,
+    /// Lowercased searchable text
+    pub content: String,
+}
+
+impl SearchDocument {
+    /// Documents for a contract and each of its schema objects
+    pub fn for_contract(
+        workspace_id: Uuid,
+        contract_id: Uuid,
+        contract: &ODCSContract,
+        file_path: Option<&str>,
+    ) -> Vec<Self> {
+        let owner = contract.team.as_ref().and_then(|team| team.name.clone());
+        let description = contract.description_string();
+
+        let mut tags = contract.tags.clone();
+        let mut classifications = Vec::new();
+        let mut words: Vec<String> = [
+            Some(contract.name.clone()),
+            Some(contract.id.clone()),
+            description.clone(),
+            contract.domain.clone(),
+            contract.data_product.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let mut documents = Vec::new();
+        for schema in &contract.schema {
+            let table = Self::for_schema_object(workspace_id, contract_id, schema);
+            words.push(table.content.clone());
+            tags.extend(table.tags.iter().cloned());
+            classifications.extend(table.classifications.iter().cloned());
+            documents.push(Self {
+                owner: owner.clone(),
+                domain: contract.domain.clone(),
+                status: contract.status.clone(),
+                file_path: file_path.map(str::to_string),
+                ..table
+            });
+        }
+        words.extend(tags.iter().cloned());
+
+        documents.insert(
+            0,
+            Self {
+                id: contract_id,
+                workspace_id,
+                entity_type: CONTRACT_ENTITY.to_string(),
+                parent_id: None,
+                name: contract.name.clone(),
+                description,
+                owner,
+                domain: contract.domain.clone(),
+                status: contract.status.clone(),
+                file_path: file_path.map(str::to_string),
+                tags: dedup(tags),
+                classifications: dedup(classifications),
+                content: searchable_text(&words),
+            },
+        );
+        documents
+    }
+
+    /// Document for a schema object, without the contract-level fields
+    fn for_schema_object(workspace_id: Uuid, contract_id: Uuid, schema: &SchemaObject) -> Self {
+        let mut tags = schema.tags.clone();
+        let mut classifications = Vec::new();
+        let mut words: Vec<String> = [
+            Some(schema.name.clone()),
+            schema.physical_name.clone(),
+            schema.business_name.clone(),
+            schema.description.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        collect_properties(
+            &schema.properties,
+            &mut words,
+            &mut tags,
+            &mut classifications,
+        );
+        words.extend(tags.iter().cloned());
+
+        Self {
+            id: Uuid::new_v5(&contract_id, schema.name.as_bytes()),
+            workspace_id,
+            entity_type: TABLE_ENTITY.to_string(),
+            parent_id: Some(contract_id),
+            name: schema.name.clone(),
+            description: schema.description.clone(),
+            owner: None,
+            domain: None,
+            status: None,
+            file_path: None,
+            tags: dedup(tags),
+            classifications: dedup(classifications),
+            content: searchable_text(&words),
+        }
+    }
+
+    /// Facet values of the document
+    pub fn facets(&self) -> Vec<(&'static str, String)> {
+        let mut facets = vec![(TYPE_FACET, self.entity_type.clone())];
+        facets.extend(self.tags.iter().map(|tag| (TAG_FACET, tag.clone())));
+        facets.extend(
+            self.classifications
+                .iter()
+                .map(|c| (CLASSIFICATION_FACET, c.clone())),
+        );
+        for (facet, value) in [
+            (OWNER_FACET, &self.owner),
+            (DOMAIN_FACET, &self.domain),
+            (STATUS_FACET, &self.status),
+        ] {
+            if let Some(value) = value {
+                facets.push((facet, value.clone()));
+            }
+        }
+        facets
+    }
+}
+
+/// A search query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SearchQuery {
+    /// Free text; every term must match
+    pub text: Option<String>,
+    /// Substring of the entity name
+    pub name: Option<String>,
+    /// Entity types to include
+    pub entity_types: Vec<String>,
+    /// Tags to include
+    pub tags: Vec<String>,
+    /// Classifications to include
+    pub classifications: Vec<String>,
+    /// Owners to include
+    pub owners: Vec<String>,
+    /// Domains to include
+    pub domains: Vec<String>,
+    /// Statuses to include
+    pub statuses: Vec<String>,
+    /// Number of hits to skip
+    pub offset: usize,
+    /// Page size (at most [`MAX_LIMIT`])
+    pub limit: usize,
+}
+
+impl Default for SearchQuery {
+    fn default() -> Self {
+        Self {
+            text: None,
+            name: None,
+            entity_types: Vec::new(),
+            tags: Vec::new(),
+            classifications: Vec::new(),
+            owners: Vec::new(),
+            domains: Vec::new(),
+            statuses: Vec::new(),
+            offset: 0,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl SearchQuery {
+    /// Create a query matching everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the free text
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Set the name substring
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Include an entity type
+    pub fn with_entity_type(mut self, entity_type: impl Into<String>) -> Self {
+        self.entity_types.push(entity_type.into());
+        self
+    }
+
+    /// Include a tag
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Include a classification
+    pub fn with_classification(mut self, classification: impl Into<String>) -> Self {
+        self.classifications.push(classification.into());
+        self
+    }
+
+    /// Include an owner
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        self.owners.push(owner.into());
+        self
+    }
+
+    /// Include a domain
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domains.push(domain.into());
+        self
+    }
+
+    /// Include a status
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.statuses.push(status.into());
+        self
+    }
+
+    /// Set the page
+    pub fn with_page(mut self, offset: usize, limit: usize) -> Self {
+        self.offset = offset;
+        self.limit = limit;
+        self
+    }
+
+    /// Page size, clamped to `1..=MAX_LIMIT`
+    pub fn page_size(&self) -> usize {
+        self.limit.clamp(1, MAX_LIMIT)
+    }
+
+    /// Lowercased free text terms
+    fn terms(&self) -> Vec<String> {
+        self.text
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    /// WHERE clause over `search_documents d`, with its parameters
+    ///
+    /// A tenant limits the matches to the workspaces of that tenant.
+    fn filter(&self, workspace_id: Uuid, tenant: Option<&TenantId>) -> (String, Vec<Value>) {
+        let mut params = vec![json!(workspace_id.to_string())];
+        let mut conditions = vec!["d.workspace_id = $1::TEXT::UUID".to_string()];
+        if let Some(tenant) = tenant {
+            params.push(json!(tenant.as_str()));
+            conditions.push(search_sql::tenant_filter(params.len()));
+        }
+
+        for term in self.terms() {
+            params.push(json!(like_pattern(&term)));
+            conditions.push(format!(
+                "d.content LIKE ${}::TEXT ESCAPE '\\'",
+                params.len()
+            ));
+        }
+        if let Some(name) = self.name.as_deref().filter(|n| !n.trim().is_empty()) {
+            params.push(json!(like_pattern(&name.trim().to_lowercase())));
+            conditions.push(format!(
+                "LOWER(d.name) LIKE ${}::TEXT ESCAPE '\\'",
+                params.len()
+            ));
+        }
+        for (facet, values) in [
+            (TYPE_FACET, &self.entity_types),
+            (TAG_FACET, &self.tags),
+            (CLASSIFICATION_FACET, &self.classifications),
+            (OWNER_FACET, &self.owners),
+            (DOMAIN_FACET, &self.domains),
+            (STATUS_FACET, &self.statuses),
+        ] {
+            if values.is_empty() {
+                continue;
+            }
+            let placeholders: Vec<String> = values
+                .iter()
+                .map(|value| {
+                    params.push(json!(value));
+                    format!("${}::TEXT", params.len())
+                })
+                .collect();
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM search_facets sf WHERE sf.document_id = d.id AND sf.facet = '{}' AND sf.value IN ({}))",
+                facet,
+                placeholders.join(", ")
+            ));
+        }
+
+        (conditions.join(" AND "), params)
+    }
+
+    /// ORDER BY clause ranking exact and partial name matches of the text first
+    fn order_by(&self, params: &mut Vec<Value>) -> String {
+        let text = self.terms().join(" ");
+        if text.is_empty() {
+            return "d.name, d.id".to_string();
+        }
+        params.push(json!(text));
+        params.push(json!(like_pattern(&text)));
+        format!(
+            "CASE WHEN LOWER(d.name) = ${}::TEXT THEN 0 WHEN LOWER(d.name) LIKE ${}::TEXT ESCAPE '\\' THEN 1 ELSE 2 END, d.name, d.id",
+            params.len() - 1,
+            params.len()
+        )
+    }
+}
+
+/// A search hit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    /// Entity ID
+    pub id: Uuid,
+    /// Entity type
+    pub entity_type: String,
+    /// ID of the containing entity
+    pub parent_id: Option<Uuid>,
+    /// Entity name
+    pub name: String,
+    /// Description
+    pub description: Option<String>,
+    /// Owning team
+    pub owner: Option<String>,
+    /// Domain name
+    pub domain: Option<String>,
+    /// Status
+    pub status: Option<String>,
+    /// File path relative to the workspace root
+    pub file_path: Option<String>,
+    /// Tags
+    pub tags: Vec<String>,
+}
+
+impl SearchHit {
+    fn from_row(row: &QueryRow) -> DatabaseResult<Self> {
+        Ok(Self {
+            id: required_uuid(row, "id")?,
+            entity_type: required_text(row, "entity_type")?,
+            parent_id: optional_uuid(row, "parent_id")?,
+            name: required_text(row, "name")?,
+            description: text(row, "description"),
+            owner: text(row, "owner"),
+            domain: text(row, "domain_name"),
+            status: text(row, "status"),
+            file_path: text(row, "file_path"),
+            tags: text(row, "tags")
+                .and_then(|tags| serde_json::from_str(&tags).ok())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Number of matches with a facet value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FacetCount {
+    /// Facet value
+    pub value: String,
+    /// Number of matches
+    pub count: usize,
+}
+
+/// One page of search results
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    /// Total number of matches
+    pub total: usize,
+    /// Number of skipped matches
+    pub offset: usize,
+    /// Page size
+    pub limit: usize,
+    /// Matches on this page
+    pub hits: Vec<SearchHit>,
+    /// Value counts by facet across all matches, most frequent first
+    pub facets: BTreeMap<String, Vec<FacetCount>>,
+}
+
+impl SearchResults {
+    /// Check if there are matches after this page
+    pub fn has_more(&self) -> bool {
+        self.offset + self.hits.len() < self.total
+    }
+}
+
+/// Search index DAO
+///
+/// A tenant-scoped DAO only searches the workspaces of its tenant, and rejects
+/// changes to the index of other tenants' workspaces.
+pub struct SearchDao<'a, B: DatabaseBackend> {
+    pub(super) backend: &'a B,
+    pub(super) tenant: Option<&'a TenantId>,
+}
+
+impl<B: DatabaseBackend> SearchDao<'_, B> {
+    /// Index a document, replacing its facets
+    pub async fn index(&self, document: &SearchDocument) -> DatabaseResult<()> {
+        authorize_workspace(self.backend, self.tenant, document.workspace_id).await?;
+        let id = json!(document.id.to_string());
+        execute(
+            self.backend,
+            search_sql::DELETE_FACETS,
+            std::slice::from_ref(&id),
+        )
+        .await?;
+        let tags = (!document.tags.is_empty()).then(|| json!(document.tags).to_string());
+        execute(
+            self.backend,
+            search_sql::UPSERT_DOCUMENT,
+            &[
+                id.clone(),
+                json!(document.workspace_id.to_string()),
+                json!(document.entity_type),
+                json!(document.parent_id.map(|id| id.to_string())),
+                json!(document.name),
+                json!(document.description),
+                json!(document.owner),
+                json!(document.domain),
+                json!(document.status),
+                json!(document.file_path),
+                json!(tags),
+                json!(document.content),
+            ],
+        )
+        .await?;
+        for (facet, value) in document.facets() {
+            execute(
+                self.backend,
+                search_sql::INSERT_FACET,
+                &[id.clone(), json!(facet), json!(value)],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Index a contract and its schema objects, replacing their previous documents
+    ///
+    /// # Returns
+    /// Number of documents indexed
+    pub async fn index_contract(
+        &self,
+        workspace_id: Uuid,
+        contract_id: Uuid,
+        contract: &ODCSContract,
+        file_path: Option<&str>,
+    ) -> DatabaseResult<usize> {
+        authorize_workspace(self.backend, self.tenant, workspace_id).await?;
+        self.remove(contract_id).await?;
+        let documents =
+            SearchDocument::for_contract(workspace_id, contract_id, contract, file_path);
+        for document in &documents {
+            self.index(document).await?;
+        }
+        Ok(documents.len())
+    }
+
+    /// Remove an entity and its children from the index
+    pub async fn remove(&self, entity_id: Uuid) -> DatabaseResult<()> {
+        let params = [json!(entity_id.to_string())];
+        if self.tenant.is_some() {
+            let rows = query(
+                self.backend,
+                search_sql::SELECT_WORKSPACE_BY_ENTITY,
+                &params,
+            )
+            .await?;
+            if let Some(row) = rows.first() {
+                authorize_workspace(
+                    self.backend,
+                    self.tenant,
+                    required_uuid(row, "workspace_id")?,
+                )
+                .await?;
+            }
+        }
+        execute(self.backend, search_sql::DELETE_FACETS_BY_ENTITY, &params).await?;
+        execute(self.backend, search_sql::DELETE_BY_ENTITY, &params).await
+    }
+
+    /// Remove all documents of a workspace from the index
+    pub async fn remove_workspace(&self, workspace_id: Uuid) -> DatabaseResult<()> {
+        authorize_workspace(self.backend, self.tenant, workspace_id).await?;
+        let params = [json!(workspace_id.to_string())];
+        execute(
+            self.backend,
+            search_sql::DELETE_FACETS_BY_WORKSPACE,
+            &params,
+        )
+        .await?;
+        execute(self.backend, search_sql::DELETE_BY_WORKSPACE, &params).await
+    }
+
+    /// IDs of the indexed entities of a type
+    pub async fn indexed_ids(
+        &self,
+        workspace_id: Uuid,
+        entity_type: &str,
+    ) -> DatabaseResult<HashSet<Uuid>> {
+        authorize_workspace(self.backend, self.tenant, workspace_id).await?;
+        let rows = query(
+            self.backend,
+            search_sql::SELECT_IDS_BY_TYPE,
+            &[json!(workspace_id.to_string()), json!(entity_type)],
+        )
+        .await?;
+        rows.iter().map(|row| required_uuid(row, "id")).collect()
+    }
+
+    /// Search a workspace
+    ///
+    /// A workspace of another tenant has no matches.
+    pub async fn search(
+        &self,
+        workspace_id: Uuid,
+        search: &SearchQuery,
+    ) -> DatabaseResult<SearchResults> {
+        let (filter, params) = search.filter(workspace_id, self.tenant);
+        let limit = search.page_size();
+
+        let total = query(
+            self.backend,
+            &format!(
+                "SELECT COUNT(*) AS count FROM search_documents d WHERE {}",
+                filter
+            ),
+            &params,
+        )
+        .await?
+        .first()
+        .and_then(|row| row.get("count"))
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+
+        let mut hit_params = params.clone();
+        let order_by = search.order_by(&mut hit_params);
+        let hits = query(
+            self.backend,
+            &format!(
+                "SELECT {} FROM search_documents d WHERE {} ORDER BY {} LIMIT {} OFFSET {}",
+                search_sql::COLUMNS,
+                filter,
+                order_by,
+                limit,
+                search.offset
+            ),
+            &hit_params,
+        )
+        .await?
+        .iter()
+        .map(SearchHit::from_row)
+        .collect::<DatabaseResult<Vec<_>>>()?;
+
+        let mut facets: BTreeMap<String, Vec<FacetCount>> = BTreeMap::new();
+        let facet_rows = query(
+            self.backend,
+            &format!(
+                "SELECT f.facet, f.value, COUNT(*) AS count FROM search_facets f JOIN search_documents d ON d.id = f.document_id WHERE {} GROUP BY f.facet, f.value ORDER BY f.facet, count DESC, f.value",
+                filter
+            ),
+            &params,
+        )
+        .await?;
+        for row in &facet_rows {
+            let count = row.get("count").and_then(Value::as_u64).unwrap_or(0) as usize;
+            facets
+                .entry(required_text(row, "facet")?)
+                .or_default()
+                .push(FacetCount {
+                    value: required_text(row, "value")?,
+                    count,
+                });
+        }
+
+        Ok(SearchResults {
+            total,
+            offset: search.offset,
+            limit,
+            hits,
+            facets,
+        })
+    }
+}
+
+/// Collect the text, tags and classifications of properties and their nested properties
+fn collect_properties(
+    properties: &[Property],
+    words: &mut Vec<String>,
+    tags: &mut Vec<String>,
+    classifications: &mut Vec<String>,
+) {
+    for property in properties {
+        words.push(property.name.clone());
+        words.extend(property.business_name.iter().cloned());
+        words.extend(property.description.iter().cloned());
+        tags.extend(property.tags.iter().cloned());
+        classifications.extend(property.classification.iter().cloned());
+        collect_properties(&property.properties, words, tags, classifications);
+        if let Some(items) = &property.items {
+            collect_properties(
+                std::slice::from_ref(items.as_ref()),
+                words,
+                tags,
+                classifications,
+            );
+        }
+    }
+}
+
+/// Lowercased text of words, one per line
+fn searchable_text(words: &[String]) -> String {
+    words
+        .iter()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .to_lowercase()
+}
+
+/// `LIKE` pattern matching a substring, with wildcards in it escaped
+fn like_pattern(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Remove duplicates, keeping the first occurrence
+fn dedup(values: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    values
+        .into_iter()
+        .filter(|value| seen.insert(value.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT_YAML: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders-contract
+version: 1.0.0
+name: Orders
+status: active
+domain: sales
+description:
+  purpose: Customer orders placed in the web shop
+team:
+  name: sales-data
+tags: [finance]
+schema:
+  - name: orders
+    description: One row per order
+    tags: [gold]
+    properties:
+      - name: customer_email
+        logicalType: string
+        classification: confidential
+        tags: [pii]
+      - name: address
+        logicalType: object
+        properties:
+          - name: city
+            logicalType: string
+            classification: internal
+"#;
+
+    #[test]
+    fn test_documents_for_contract() {
+        let contract: ODCSContract = serde_yaml::from_str(CONTRACT_YAML).unwrap();
+        let workspace_id = Uuid::new_v4();
+        let contract_id = Uuid::new_v4();
+        let documents = SearchDocument::for_contract(
+            workspace_id,
+            contract_id,
+            &contract,
+            Some("orders.odcs.yaml"),
+        );
+        assert_eq!(documents.len(), 2);
+
+        let contract_doc = &documents[0];
+        assert_eq!(contract_doc.id, contract_id);
+        assert_eq!(contract_doc.tags, vec!["finance", "gold", "pii"]);
+        assert_eq!(
+            contract_doc.classifications,
+            vec!["confidential", "internal"]
+        );
+        assert!(contract_doc.content.contains("web shop"));
+        assert!(contract_doc.content.contains("customer_email"));
+
+        let table_doc = &documents[1];
+        assert_eq!(table_doc.entity_type, TABLE_ENTITY);
+        assert_eq!(table_doc.parent_id, Some(contract_id));
+        assert_eq!(table_doc.owner.as_deref(), Some("sales-data"));
+        assert_eq!(table_doc.tags, vec!["gold", "pii"]);
+        assert!(!table_doc.content.contains("web shop"));
+        assert!(
+            table_doc
+                .facets()
+                .contains(&(STATUS_FACET, "active".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_query_filter() {
+        let workspace_id = Uuid::new_v4();
+        let search = SearchQuery::new()
+            .with_text("Order  100%")
+            .with_tag("pii")
+            .with_tag("gold")
+            .with_owner("sales-data")
+            .with_page(40, 500);
+
+        let (filter, params) = search.filter(workspace_id, None);
+        assert_eq!(
+            params,
+            vec![
+                json!(workspace_id.to_string()),
+                json!("%order%"),
+                json!("%100\\%%"),
+                json!("pii"),
+                json!("gold"),
+                json!("sales-data"),
+            ]
+        );
+        assert!(filter.contains("sf.facet = 'tag' AND sf.value IN ($4::TEXT, $5::TEXT)"));
+        assert!(filter.contains("sf.facet = 'owner' AND sf.value IN ($6::TEXT)"));
+        assert_eq!(search.page_size(), MAX_LIMIT);
+
+        let mut order_params = params.clone();
+        let order_by = search.order_by(&mut order_params);
+        assert!(order_by.starts_with("CASE WHEN LOWER(d.name) = $7::TEXT"));
+        assert_eq!(order_params[6], json!("order 100%"));
+
+        let (filter, params) = SearchQuery::new().filter(workspace_id, None);
+        assert_eq!(filter, "d.workspace_id = $1::TEXT::UUID");
+        assert_eq!(params.len(), 1);
+
+        let acme = TenantId::parse("acme").unwrap();
+        let (filter, params) = SearchQuery::new()
+            .with_tag("pii")
+            .filter(workspace_id, Some(&acme));
+        assert!(filter.contains(
+            "d.workspace_id IN (SELECT workspace_id FROM workspace_tenants WHERE tenant = $2::TEXT)"
+        ));
+        assert!(filter.contains("sf.value IN ($3::TEXT)"));
+        assert_eq!(params[1], json!("acme"));
+    }
+}
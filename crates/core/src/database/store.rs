@@ -0,0 +1,2253 @@
+//! Metadata store for the API server
+//!
+//! Keeps the contracts of a Git workspace in the database so the API server can
+//! query them without reading YAML files:
+//!
+//! - **Contracts** - one row per ODCS file, keyed by workspace and ODCS `id`
+//! - **Contract versions** - the file content each time it changed, with the Git
+//!   revision and actor that recorded it
+//! - **Consumers** - principals of the contract's roles (or roles without a
+//!   principal), plus any registered through the API
+//! - **Audit events** - an append-only log of changes
+//! - **Search index** - contracts and their schema objects, see [`super::search`]
+//!
+//! Each table has a DAO borrowed from [`MetadataStore`]. The Git workspace stays
+//! the source of truth: [`MetadataStore::mirror_workspace`] brings the store in
+//! line with the workspace files, recording a version and an audit event for every
+//! contract that was created, changed or removed.
+//!
+//! ## Tenants
+//!
+//! A store created with [`MetadataStore::with_tenant`] only sees the workspaces
+//! of that tenant: listing and lookups skip other tenants' workspaces, and
+//! contract and audit queries for them fail with
+//! [`DatabaseError::WorkspaceNotFound`]. A workspace is assigned to the tenant
+//! the first time the store saves it and cannot move to another tenant. Contract
+//! version, consumer and search queries only return rows of the tenant's
+//! workspaces, and writes for contracts or workspaces of other tenants fail.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use uuid::Uuid;
+
+use super::schema::{
+    audit_event_sql, consumer_sql, contract_sql, contract_version_sql, workspace_record_sql,
+};
+use super::search::SearchDao;
+use super::sync::{FileInfo, scan_workspace_files};
+use super::{DatabaseBackend, DatabaseError, DatabaseResult, QueryRow, migrations};
+use crate::models::Workspace;
+use crate::models::odcs::ODCSContract;
+use crate::models::workspace::AssetType;
+use crate::storage::tenant::TenantId;
+
+/// Audit event entity type of contracts
+pub const CONTRACT_ENTITY: &str = "contract";
+
+/// Audit event entity type of workspaces
+pub const WORKSPACE_ENTITY: &str = "workspace";
+
+/// A workspace row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceRecord {
+    /// Workspace ID
+    pub id: Uuid,
+    /// Workspace name
+    pub name: String,
+    /// Owner ID
+    pub owner_id: Option<Uuid>,
+    /// Tenant owning the workspace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    /// Creation timestamp
+    pub created_at: Option<DateTime<Utc>>,
+    /// Last modification timestamp
+    pub last_modified_at: Option<DateTime<Utc>>,
+}
+
+impl WorkspaceRecord {
+    fn from_row(row: &QueryRow) -> DatabaseResult<Self> {
+        Ok(Self {
+            id: required_uuid(row, "id")?,
+            name: required_text(row, "name")?,
+            owner_id: optional_uuid(row, "owner_id")?,
+            tenant: text(row, "tenant"),
+            created_at: timestamp(row, "created_at"),
+            last_modified_at: timestamp(row, "last_modified_at"),
+        })
+    }
+}
+
+/// A contract row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractRecord {
+    /// Contract ID, derived from the workspace and contract key
+    pub id: Uuid,
+    /// Workspace ID
+    pub workspace_id: Uuid,
+    /// ODCS contract `id`
+    pub contract_key: String,
+    /// Contract name
+    pub name: String,
+    /// Current contract version
+    pub version: String,
+    /// Contract status
+    pub status: Option<String>,
+    /// Domain name
+    pub domain: Option<String>,
+    /// Owning team
+    pub owner: Option<String>,
+    /// File path relative to the workspace root
+    pub file_path: Option<String>,
+    /// SHA256 hash of the file content
+    pub yaml_hash: Option<String>,
+    /// Creation timestamp
+    pub created_at: Option<DateTime<Utc>>,
+    /// Last update timestamp
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl ContractRecord {
+    /// Create a record for an ODCS contract
+    pub fn from_contract(workspace_id: Uuid, contract: &ODCSContract) -> Self {
+        Self {
+            id: contract_record_id(workspace_id, &contract.id),
+            workspace_id,
+            contract_key: contract.id.clone(),
+            name: contract.name.clone(),
+            version: contract.version.clone(),
+            status: contract.status.clone(),
+            domain: contract.domain.clone(),
+            owner: contract.team.as_ref().and_then(|team| team.name.clone()),
+            file_path: None,
+            yaml_hash: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn from_row(row: &QueryRow) -> DatabaseResult<Self> {
+        Ok(Self {
+            id: required_uuid(row, "id")?,
+            workspace_id: required_uuid(row, "workspace_id")?,
+            contract_key: required_text(row, "contract_key")?,
+            name: required_text(row, "name")?,
+            version: required_text(row, "version")?,
+            status: text(row, "status"),
+            domain: text(row, "domain_name"),
+            owner: text(row, "owner"),
+            file_path: text(row, "file_path"),
+            yaml_hash: text(row, "yaml_hash"),
+            created_at: timestamp(row, "created_at"),
+            updated_at: timestamp(row, "updated_at"),
+        })
+    }
+}
+
+/// A contract version row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractVersionRecord {
+    /// Version row ID
+    pub id: Uuid,
+    /// Contract ID
+    pub contract_id: Uuid,
+    /// Contract version at the time
+    pub version: String,
+    /// SHA256 hash of the content
+    pub yaml_hash: String,
+    /// Contract YAML
+    pub content: String,
+    /// Git revision the content was read from
+    pub revision: Option<String>,
+    /// Who recorded the version
+    pub created_by: Option<String>,
+    /// Recording timestamp
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ContractVersionRecord {
+    /// Create a version record for contract content
+    pub fn new(
+        contract_id: Uuid,
+        version: impl Into<String>,
+        content: impl Into<String>,
+        yaml_hash: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            contract_id,
+            version: version.into(),
+            yaml_hash: yaml_hash.into(),
+            content: content.into(),
+            revision: None,
+            created_by: None,
+            created_at: None,
+        }
+    }
+
+    fn from_row(row: &QueryRow) -> DatabaseResult<Self> {
+        Ok(Self {
+            id: required_uuid(row, "id")?,
+            contract_id: required_uuid(row, "contract_id")?,
+            version: required_text(row, "version")?,
+            yaml_hash: required_text(row, "yaml_hash")?,
+            content: required_text(row, "content")?,
+            revision: text(row, "revision"),
+            created_by: text(row, "created_by"),
+            created_at: timestamp(row, "created_at"),
+        })
+    }
+}
+
+/// A contract consumer row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsumerRecord {
+    /// Consumer row ID
+    pub id: Uuid,
+    /// Contract ID
+    pub contract_id: Uuid,
+    /// Consuming user, group or service
+    pub principal: String,
+    /// Role granted to the principal
+    pub role: Option<String>,
+    /// Access level (e.g. `read`)
+    pub access: Option<String>,
+    /// Description
+    pub description: Option<String>,
+    /// Registration timestamp
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ConsumerRecord {
+    /// Create a consumer record
+    pub fn new(contract_id: Uuid, principal: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            contract_id,
+            principal: principal.into(),
+            role: None,
+            access: None,
+            description: None,
+            created_at: None,
+        }
+    }
+
+    /// Consumers declared by the roles of a contract
+    ///
+    /// Roles without a principal are consumed by the role itself.
+    pub fn from_contract(contract_id: Uuid, contract: &ODCSContract) -> Vec<Self> {
+        let mut seen = HashSet::new();
+        contract
+            .roles
+            .iter()
+            .filter_map(|role| {
+                let principal = role.principal.clone().or_else(|| role.role.clone())?;
+                seen.insert(principal.clone()).then(|| Self {
+                    role: role.role.clone(),
+                    access: role.access.clone(),
+                    description: role.description.clone(),
+                    ..Self::new(contract_id, principal)
+                })
+            })
+            .collect()
+    }
+
+    fn from_row(row: &QueryRow) -> DatabaseResult<Self> {
+        Ok(Self {
+            id: required_uuid(row, "id")?,
+            contract_id: required_uuid(row, "contract_id")?,
+            principal: required_text(row, "principal")?,
+            role: text(row, "role"),
+            access: text(row, "access"),
+            description: text(row, "description"),
+            created_at: timestamp(row, "created_at"),
+        })
+    }
+}
+
+/// An audit log entry
+///
+/// Mirroring records `created`, `updated` and `deleted` events for contracts and a
+/// `mirrored` event for the workspace; the API server may record its own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// Event ID
+    pub id: Uuid,
+    /// Workspace ID
+    pub workspace_id: Uuid,
+    /// Type of the affected entity (e.g. `contract`)
+    pub entity_type: String,
+    /// ID of the affected entity
+    pub entity_id: Option<Uuid>,
+    /// What happened (e.g. `updated`)
+    pub action: String,
+    /// Who made the change
+    pub actor: Option<String>,
+    /// Git revision of the change
+    pub revision: Option<String>,
+    /// Event details
+    pub details: Option<Value>,
+    /// Event timestamp
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl AuditEvent {
+    /// Create an audit event
+    pub fn new(
+        workspace_id: Uuid,
+        entity_type: impl Into<String>,
+        action: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            workspace_id,
+            entity_type: entity_type.into(),
+            entity_id: None,
+            action: action.into(),
+            actor: None,
+            revision: None,
+            details: None,
+            created_at: None,
+        }
+    }
+
+    /// Set the affected entity
+    pub fn with_entity(mut self, entity_id: Uuid) -> Self {
+        self.entity_id = Some(entity_id);
+        self
+    }
+
+    /// Set who made the change
+    pub fn with_actor(mut self, actor: Option<&str>) -> Self {
+        self.actor = actor.map(str::to_string);
+        self
+    }
+
+    /// Set the Git revision of the change
+    pub fn with_revision(mut self, revision: Option<&str>) -> Self {
+        self.revision = revision.map(str::to_string);
+        self
+    }
+
+    /// Set the event details
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    fn from_row(row: &QueryRow) -> DatabaseResult<Self> {
+        Ok(Self {
+            id: required_uuid(row, "id")?,
+            workspace_id: required_uuid(row, "workspace_id")?,
+            entity_type: required_text(row, "entity_type")?,
+            entity_id: optional_uuid(row, "entity_id")?,
+            action: required_text(row, "action")?,
+            actor: text(row, "actor"),
+            revision: text(row, "revision"),
+            details: text(row, "details").and_then(|d| serde_json::from_str(&d).ok()),
+            created_at: timestamp(row, "created_at"),
+        })
+    }
+}
+
+/// Workspace DAO
+pub struct WorkspaceDao<'a, B: DatabaseBackend> {
+    backend: &'a B,
+    tenant: Option<&'a TenantId>,
+}
+
+impl<B: DatabaseBackend> WorkspaceDao<'_, B> {
+    /// List all workspaces by name
+    pub async fn list(&self) -> DatabaseResult<Vec<WorkspaceRecord>> {
+        let rows = match self.tenant {
+            Some(tenant) => {
+                query(
+                    self.backend,
+                    &workspace_record_sql::select_by_tenant(),
+                    &[json!(tenant.as_str())],
+                )
+                .await?
+            }
+            None => {
+                self.backend
+                    .execute_query(&workspace_record_sql::select_all())
+                    .await?
+                    .rows
+            }
+        };
+        rows.iter().map(WorkspaceRecord::from_row).collect()
+    }
+
+    /// Get a workspace by ID
+    pub async fn get(&self, id: Uuid) -> DatabaseResult<Option<WorkspaceRecord>> {
+        let rows = query(
+            self.backend,
+            &workspace_record_sql::select_by_id(),
+            &[json!(id.to_string())],
+        )
+        .await?;
+        self.visible(rows.first())
+    }
+
+    /// Get a workspace by name
+    ///
+    /// Names are unique per tenant; a DAO without a tenant finds workspaces
+    /// without a tenant.
+    pub async fn get_by_name(&self, name: &str) -> DatabaseResult<Option<WorkspaceRecord>> {
+        let rows = query(
+            self.backend,
+            &workspace_record_sql::select_by_name(self.tenant.is_some()),
+            &tenant_params(vec![json!(name)], self.tenant),
+        )
+        .await?;
+        rows.first().map(WorkspaceRecord::from_row).transpose()
+    }
+
+    /// Create or update a workspace
+    ///
+    /// A tenant-scoped DAO assigns new workspaces to its tenant, and rejects
+    /// workspaces of another tenant. Names must be unique within the tenant,
+    /// which a unique index enforces for concurrent upserts as well.
+    pub async fn upsert(&self, workspace: &Workspace) -> DatabaseResult<()> {
+        let Some(tenant) = self.tenant else {
+            self.check_name(workspace).await?;
+            return self.backend.upsert_workspace(workspace).await;
+        };
+        if workspace.tenant.is_some() && !tenant.matches(workspace.tenant.as_deref()) {
+            return Err(DatabaseError::InvalidInput(format!(
+                "Workspace '{}' belongs to tenant '{}', not '{}'",
+                workspace.name,
+                workspace.tenant.as_deref().unwrap_or_default(),
+                tenant
+            )));
+        }
+        let rows = query(
+            self.backend,
+            &workspace_record_sql::select_by_id(),
+            &[json!(workspace.id.to_string())],
+        )
+        .await?;
+        // Workspaces of other tenants, or of none, cannot be claimed
+        if !rows.is_empty() && self.visible(rows.first())?.is_none() {
+            return Err(DatabaseError::WorkspaceNotFound(workspace.id.to_string()));
+        }
+        self.check_name(workspace).await?;
+        // The backend records the tenant together with the workspace
+        let mut workspace = workspace.clone();
+        workspace.tenant = Some(tenant.to_string());
+        self.backend.upsert_workspace(&workspace).await
+    }
+
+    /// Delete a workspace with its contracts and audit log
+    pub async fn delete(&self, id: Uuid) -> DatabaseResult<()> {
+        if self.tenant.is_some() && self.get(id).await?.is_none() {
+            return Err(DatabaseError::WorkspaceNotFound(id.to_string()));
+        }
+        let contracts = ContractDao {
+            backend: self.backend,
+            tenant: None,
+        };
+        for contract in contracts.list(id).await? {
+            contracts.delete(contract.id).await?;
+        }
+        SearchDao {
+            backend: self.backend,
+            tenant: None,
+        }
+        .remove_workspace(id)
+        .await?;
+        execute(
+            self.backend,
+            audit_event_sql::DELETE_BY_WORKSPACE,
+            &[json!(id.to_string())],
+        )
+        .await?;
+        self.backend.delete_workspace(id).await
+    }
+
+    /// Assign an unassigned workspace to a tenant
+    ///
+    /// Used to hand over workspaces stored before tenants were introduced; a
+    /// workspace that already has a tenant keeps it.
+    pub async fn assign_tenant(&self, id: Uuid, tenant: &TenantId) -> DatabaseResult<()> {
+        let workspace = self
+            .get(id)
+            .await?
+            .ok_or_else(|| DatabaseError::WorkspaceNotFound(id.to_string()))?;
+        execute(
+            self.backend,
+            workspace_record_sql::SET_TENANT,
+            &[
+                json!(id.to_string()),
+                json!(tenant.as_str()),
+                json!(workspace.name),
+            ],
+        )
+        .await
+    }
+
+    /// Fail when another workspace of this DAO's tenant has the workspace's name
+    ///
+    /// Reports a taken name before writing; the unique index on the names
+    /// still rejects a name taken concurrently.
+    async fn check_name(&self, workspace: &Workspace) -> DatabaseResult<()> {
+        match self.get_by_name(&workspace.name).await? {
+            Some(existing) if existing.id != workspace.id => Err(DatabaseError::InvalidInput(
+                format!("Workspace name '{}' is already in use", workspace.name),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fail unless the workspace exists and is visible to this DAO's tenant
+    async fn authorize(&self, id: Uuid) -> DatabaseResult<()> {
+        if self.tenant.is_none() || self.get(id).await?.is_some() {
+            Ok(())
+        } else {
+            Err(DatabaseError::WorkspaceNotFound(id.to_string()))
+        }
+    }
+
+    fn visible(&self, row: Option<&QueryRow>) -> DatabaseResult<Option<WorkspaceRecord>> {
+        let record = row.map(WorkspaceRecord::from_row).transpose()?;
+        Ok(record.filter(|record| match self.tenant {
+            Some(tenant) => tenant.matches(record.tenant.as_deref()),
+            None => true,
+        }))
+    }
+}
+
+/// Contract DAO
+pub struct ContractDao<'a, B: DatabaseBackend> {
+    backend: &'a B,
+    tenant: Option<&'a TenantId>,
+}
+
+impl<B: DatabaseBackend> ContractDao<'_, B> {
+    /// Create or update a contract
+    ///
+    /// Fails when the ID belongs to a contract of another workspace.
+    pub async fn upsert(&self, contract: &ContractRecord) -> DatabaseResult<()> {
+        self.authorize(contract.workspace_id).await?;
+        let rows = query(
+            self.backend,
+            &contract_sql::select_by_id(),
+            &[json!(contract.id.to_string())],
+        )
+        .await?;
+        if let Some(existing) = rows.first().map(ContractRecord::from_row).transpose()?
+            && existing.workspace_id != contract.workspace_id
+        {
+            return Err(DatabaseError::InvalidInput(format!(
+                "Contract {} belongs to another workspace",
+                contract.id
+            )));
+        }
+        execute(
+            self.backend,
+            contract_sql::UPSERT,
+            &[
+                json!(contract.id.to_string()),
+                json!(contract.workspace_id.to_string()),
+                json!(contract.contract_key),
+                json!(contract.name),
+                json!(contract.version),
+                json!(contract.status),
+                json!(contract.domain),
+                json!(contract.owner),
+                json!(contract.file_path),
+                json!(contract.yaml_hash),
+            ],
+        )
+        .await
+    }
+
+    /// Get a contract by ID
+    pub async fn get(&self, id: Uuid) -> DatabaseResult<Option<ContractRecord>> {
+        let rows = query(
+            self.backend,
+            &contract_sql::select_by_id(),
+            &[json!(id.to_string())],
+        )
+        .await?;
+        match rows.first().map(ContractRecord::from_row).transpose()? {
+            Some(contract) if self.authorize(contract.workspace_id).await.is_ok() => {
+                Ok(Some(contract))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get a contract by its ODCS `id`
+    pub async fn get_by_key(
+        &self,
+        workspace_id: Uuid,
+        contract_key: &str,
+    ) -> DatabaseResult<Option<ContractRecord>> {
+        self.get(contract_record_id(workspace_id, contract_key))
+            .await
+    }
+
+    /// List the contracts of a workspace by name
+    pub async fn list(&self, workspace_id: Uuid) -> DatabaseResult<Vec<ContractRecord>> {
+        self.authorize(workspace_id).await?;
+        let rows = query(
+            self.backend,
+            &contract_sql::select_by_workspace(),
+            &[json!(workspace_id.to_string())],
+        )
+        .await?;
+        rows.iter().map(ContractRecord::from_row).collect()
+    }
+
+    /// List the contracts of a workspace with a status
+    pub async fn list_by_status(
+        &self,
+        workspace_id: Uuid,
+        status: &str,
+    ) -> DatabaseResult<Vec<ContractRecord>> {
+        self.authorize(workspace_id).await?;
+        let rows = query(
+            self.backend,
+            &contract_sql::select_by_status(),
+            &[json!(workspace_id.to_string()), json!(status)],
+        )
+        .await?;
+        rows.iter().map(ContractRecord::from_row).collect()
+    }
+
+    /// Count the contracts of a workspace
+    pub async fn count(&self, workspace_id: Uuid) -> DatabaseResult<usize> {
+        self.authorize(workspace_id).await?;
+        let rows = query(
+            self.backend,
+            contract_sql::COUNT_BY_WORKSPACE,
+            &[json!(workspace_id.to_string())],
+        )
+        .await?;
+        Ok(rows
+            .first()
+            .and_then(|row| row.get("count"))
+            .and_then(|count| count.as_u64())
+            .unwrap_or(0) as usize)
+    }
+
+    /// Delete a contract with its versions, consumers and search documents
+    pub async fn delete(&self, id: Uuid) -> DatabaseResult<()> {
+        if self.tenant.is_some() && self.get(id).await?.is_none() {
+            return Ok(());
+        }
+        SearchDao {
+            backend: self.backend,
+            tenant: None,
+        }
+        .remove(id)
+        .await?;
+        let params = [json!(id.to_string())];
+        execute(self.backend, consumer_sql::DELETE_BY_CONTRACT, &params).await?;
+        execute(
+            self.backend,
+            contract_version_sql::DELETE_BY_CONTRACT,
+            &params,
+        )
+        .await?;
+        execute(self.backend, contract_sql::DELETE, &params).await
+    }
+
+    async fn authorize(&self, workspace_id: Uuid) -> DatabaseResult<()> {
+        WorkspaceDao {
+            backend: self.backend,
+            tenant: self.tenant,
+        }
+        .authorize(workspace_id)
+        .await
+    }
+}
+
+/// Contract version DAO
+pub struct ContractVersionDao<'a, B: DatabaseBackend> {
+    backend: &'a B,
+    tenant: Option<&'a TenantId>,
+}
+
+impl<B: DatabaseBackend> ContractVersionDao<'_, B> {
+    /// Record a version, ignoring content that was already recorded for the contract
+    pub async fn insert(&self, version: &ContractVersionRecord) -> DatabaseResult<()> {
+        authorize_contract(self.backend, self.tenant, version.contract_id).await?;
+        execute(
+            self.backend,
+            contract_version_sql::INSERT,
+            &[
+                json!(version.id.to_string()),
+                json!(version.contract_id.to_string()),
+                json!(version.version),
+                json!(version.yaml_hash),
+                json!(version.content),
+                json!(version.revision),
+                json!(version.created_by),
+            ],
+        )
+        .await
+    }
+
+    /// List the versions of a contract, newest first
+    pub async fn list(&self, contract_id: Uuid) -> DatabaseResult<Vec<ContractVersionRecord>> {
+        let rows = query(
+            self.backend,
+            &contract_version_sql::select_by_contract(self.tenant.is_some()),
+            &tenant_params(vec![json!(contract_id.to_string())], self.tenant),
+        )
+        .await?;
+        rows.iter().map(ContractVersionRecord::from_row).collect()
+    }
+
+    /// Get the latest version of a contract
+    pub async fn latest(&self, contract_id: Uuid) -> DatabaseResult<Option<ContractVersionRecord>> {
+        let rows = query(
+            self.backend,
+            &contract_version_sql::select_latest(self.tenant.is_some()),
+            &tenant_params(vec![json!(contract_id.to_string())], self.tenant),
+        )
+        .await?;
+        rows.first()
+            .map(ContractVersionRecord::from_row)
+            .transpose()
+    }
+
+    /// Get the recorded contents of a contract version, newest first
+    pub async fn by_version(
+        &self,
+        contract_id: Uuid,
+        version: &str,
+    ) -> DatabaseResult<Vec<ContractVersionRecord>> {
+        let rows = query(
+            self.backend,
+            &contract_version_sql::select_by_version(self.tenant.is_some()),
+            &tenant_params(
+                vec![json!(contract_id.to_string()), json!(version)],
+                self.tenant,
+            ),
+        )
+        .await?;
+        rows.iter().map(ContractVersionRecord::from_row).collect()
+    }
+}
+
+/// Contract consumer DAO
+pub struct ConsumerDao<'a, B: DatabaseBackend> {
+    backend: &'a B,
+    tenant: Option<&'a TenantId>,
+}
+
+impl<B: DatabaseBackend> ConsumerDao<'_, B> {
+    /// Register or update a consumer
+    ///
+    /// Fails when the ID belongs to a consumer of another contract.
+    pub async fn upsert(&self, consumer: &ConsumerRecord) -> DatabaseResult<()> {
+        authorize_contract(self.backend, self.tenant, consumer.contract_id).await?;
+        let rows = query(
+            self.backend,
+            &consumer_sql::select_by_id(),
+            &[json!(consumer.id.to_string())],
+        )
+        .await?;
+        if let Some(existing) = rows.first().map(ConsumerRecord::from_row).transpose()?
+            && existing.contract_id != consumer.contract_id
+        {
+            return Err(DatabaseError::InvalidInput(format!(
+                "Consumer {} belongs to another contract",
+                consumer.id
+            )));
+        }
+        execute(
+            self.backend,
+            consumer_sql::UPSERT,
+            &[
+                json!(consumer.id.to_string()),
+                json!(consumer.contract_id.to_string()),
+                json!(consumer.principal),
+                json!(consumer.role),
+                json!(consumer.access),
+                json!(consumer.description),
+            ],
+        )
+        .await
+    }
+
+    /// Replace the consumers of a contract
+    pub async fn replace(
+        &self,
+        contract_id: Uuid,
+        consumers: &[ConsumerRecord],
+    ) -> DatabaseResult<()> {
+        authorize_contract(self.backend, self.tenant, contract_id).await?;
+        execute(
+            self.backend,
+            consumer_sql::DELETE_BY_CONTRACT,
+            &[json!(contract_id.to_string())],
+        )
+        .await?;
+        for consumer in consumers {
+            self.upsert(consumer).await?;
+        }
+        Ok(())
+    }
+
+    /// List the consumers of a contract
+    pub async fn list(&self, contract_id: Uuid) -> DatabaseResult<Vec<ConsumerRecord>> {
+        let rows = query(
+            self.backend,
+            &consumer_sql::select_by_contract(self.tenant.is_some()),
+            &tenant_params(vec![json!(contract_id.to_string())], self.tenant),
+        )
+        .await?;
+        rows.iter().map(ConsumerRecord::from_row).collect()
+    }
+
+    /// List the contracts a principal consumes
+    pub async fn list_by_principal(&self, principal: &str) -> DatabaseResult<Vec<ConsumerRecord>> {
+        let rows = query(
+            self.backend,
+            &consumer_sql::select_by_principal(self.tenant.is_some()),
+            &tenant_params(vec![json!(principal)], self.tenant),
+        )
+        .await?;
+        rows.iter().map(ConsumerRecord::from_row).collect()
+    }
+
+    /// Remove a consumer from a contract
+    pub async fn remove(&self, contract_id: Uuid, principal: &str) -> DatabaseResult<()> {
+        authorize_contract(self.backend, self.tenant, contract_id).await?;
+        execute(
+            self.backend,
+            consumer_sql::DELETE,
+            &[json!(contract_id.to_string()), json!(principal)],
+        )
+        .await
+    }
+}
+
+/// Audit event DAO
+pub struct AuditEventDao<'a, B: DatabaseBackend> {
+    backend: &'a B,
+    tenant: Option<&'a TenantId>,
+}
+
+impl<B: DatabaseBackend> AuditEventDao<'_, B> {
+    /// Record an event
+    pub async fn record(&self, event: &AuditEvent) -> DatabaseResult<()> {
+        self.authorize(event.workspace_id).await?;
+        execute(
+            self.backend,
+            audit_event_sql::INSERT,
+            &[
+                json!(event.id.to_string()),
+                json!(event.workspace_id.to_string()),
+                json!(event.entity_type),
+                json!(event.entity_id.map(|id| id.to_string())),
+                json!(event.action),
+                json!(event.actor),
+                json!(event.revision),
+                json!(event.details.as_ref().map(Value::to_string)),
+            ],
+        )
+        .await
+    }
+
+    /// List the most recent events of a workspace, newest first
+    pub async fn list(&self, workspace_id: Uuid, limit: usize) -> DatabaseResult<Vec<AuditEvent>> {
+        self.authorize(workspace_id).await?;
+        let rows = query(
+            self.backend,
+            &audit_event_sql::select_by_workspace(limit),
+            &[json!(workspace_id.to_string())],
+        )
+        .await?;
+        rows.iter().map(AuditEvent::from_row).collect()
+    }
+
+    /// List the events of an entity, newest first
+    pub async fn for_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> DatabaseResult<Vec<AuditEvent>> {
+        let rows = query(
+            self.backend,
+            &audit_event_sql::select_by_entity(),
+            &[json!(entity_type), json!(entity_id.to_string())],
+        )
+        .await?;
+        let mut events = Vec::new();
+        for event in rows.iter().map(AuditEvent::from_row) {
+            let event = event?;
+            if self.authorize(event.workspace_id).await.is_ok() {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+
+    async fn authorize(&self, workspace_id: Uuid) -> DatabaseResult<()> {
+        WorkspaceDao {
+            backend: self.backend,
+            tenant: self.tenant,
+        }
+        .authorize(workspace_id)
+        .await
+    }
+}
+
+/// Options for mirroring a workspace
+#[derive(Debug, Clone, Default)]
+pub struct MirrorOptions {
+    /// Who triggered the mirror (recorded on versions and audit events)
+    pub actor: Option<String>,
+    /// Git revision of the workspace files
+    pub revision: Option<String>,
+}
+
+impl MirrorOptions {
+    /// Set who triggered the mirror
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    /// Set the Git revision of the workspace files
+    pub fn with_revision(mut self, revision: impl Into<String>) -> Self {
+        self.revision = Some(revision.into());
+        self
+    }
+}
+
+/// Result of mirroring a workspace
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MirrorResult {
+    /// Workspace ID that was mirrored
+    pub workspace_id: Uuid,
+    /// Number of new contracts
+    pub contracts_created: usize,
+    /// Number of changed contracts
+    pub contracts_updated: usize,
+    /// Number of contracts removed from the workspace
+    pub contracts_deleted: usize,
+    /// Number of unchanged contracts
+    pub contracts_unchanged: usize,
+    /// Number of consumers of the new and changed contracts
+    pub consumers_synced: usize,
+    /// Number of search documents indexed
+    pub documents_indexed: usize,
+    /// Contract files that couldn't be mirrored
+    pub errors: Vec<String>,
+    /// Duration of the mirror in milliseconds
+    pub duration_ms: u64,
+}
+
+impl MirrorResult {
+    /// Check if every contract file was mirrored
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Metadata store over a database backend
+pub struct MetadataStore<B: DatabaseBackend> {
+    backend: B,
+    tenant: Option<TenantId>,
+}
+
+impl<B: DatabaseBackend> MetadataStore<B> {
+    /// Create a metadata store with the given database backend
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            tenant: None,
+        }
+    }
+
+    /// Scope the store to the workspaces of a tenant
+    pub fn with_tenant(mut self, tenant: TenantId) -> Self {
+        self.tenant = Some(tenant);
+        self
+    }
+
+    /// The tenant the store is scoped to, if any
+    pub fn tenant(&self) -> Option<&TenantId> {
+        self.tenant.as_ref()
+    }
+
+    /// Get reference to the database backend
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Apply pending schema migrations
+    ///
+    /// # Returns
+    /// Versions of the migrations that were applied
+    pub async fn migrate(&self) -> DatabaseResult<Vec<i32>> {
+        migrations::migrate(&self.backend).await
+    }
+
+    /// Workspace DAO
+    pub fn workspaces(&self) -> WorkspaceDao<'_, B> {
+        WorkspaceDao {
+            backend: &self.backend,
+            tenant: self.tenant.as_ref(),
+        }
+    }
+
+    /// Contract DAO
+    pub fn contracts(&self) -> ContractDao<'_, B> {
+        ContractDao {
+            backend: &self.backend,
+            tenant: self.tenant.as_ref(),
+        }
+    }
+
+    /// Contract version DAO
+    pub fn versions(&self) -> ContractVersionDao<'_, B> {
+        ContractVersionDao {
+            backend: &self.backend,
+            tenant: self.tenant.as_ref(),
+        }
+    }
+
+    /// Contract consumer DAO
+    pub fn consumers(&self) -> ConsumerDao<'_, B> {
+        ConsumerDao {
+            backend: &self.backend,
+            tenant: self.tenant.as_ref(),
+        }
+    }
+
+    /// Audit event DAO
+    pub fn audit_events(&self) -> AuditEventDao<'_, B> {
+        AuditEventDao {
+            backend: &self.backend,
+            tenant: self.tenant.as_ref(),
+        }
+    }
+
+    /// Fail with [`DatabaseError::WorkspaceNotFound`] unless the workspace is
+    /// visible to the store's tenant
+    pub async fn authorize_workspace(&self, workspace_id: Uuid) -> DatabaseResult<()> {
+        self.workspaces().authorize(workspace_id).await
+    }
+
+    /// Search index DAO
+    pub fn search(&self) -> SearchDao<'_, B> {
+        SearchDao {
+            backend: &self.backend,
+            tenant: self.tenant.as_ref(),
+        }
+    }
+
+    /// Mirror the contracts of a workspace directory
+    ///
+    /// See [`Self::workspace_for_directory`] for how the workspace is identified.
+    pub async fn mirror_directory(
+        &self,
+        workspace_path: &Path,
+        options: &MirrorOptions,
+    ) -> DatabaseResult<MirrorResult> {
+        let workspace = self.workspace_for_directory(workspace_path).await?;
+        let mut files = Vec::new();
+        for path in scan_workspace_files(workspace_path)? {
+            let content = std::fs::read(workspace_path.join(&path)).map_err(|e| {
+                DatabaseError::IoError(format!("Failed to read {}: {}", path.display(), e))
+            })?;
+            files.push(FileInfo::new(path.to_string_lossy(), content));
+        }
+        self.mirror_workspace(&workspace, &files, options).await
+    }
+
+    /// Mirror the contracts of a workspace
+    ///
+    /// Contracts whose file content changed get a new version and are reindexed for
+    /// search; contracts without a file anymore are deleted. Files that fail to parse
+    /// are reported in the result and leave their stored contract untouched.
+    ///
+    /// # Arguments
+    /// * `workspace` - Workspace metadata
+    /// * `files` - Workspace files; only ODCS files are mirrored
+    /// * `options` - Actor and Git revision to record
+    pub async fn mirror_workspace(
+        &self,
+        workspace: &Workspace,
+        files: &[FileInfo],
+        options: &MirrorOptions,
+    ) -> DatabaseResult<MirrorResult> {
+        let start = std::time::Instant::now();
+        let mut result = MirrorResult {
+            workspace_id: workspace.id,
+            ..Default::default()
+        };
+        let actor = options.actor.as_deref();
+        let revision = options.revision.as_deref();
+
+        self.workspaces().upsert(workspace).await?;
+        let existing: HashMap<Uuid, ContractRecord> = self
+            .contracts()
+            .list(workspace.id)
+            .await?
+            .into_iter()
+            .map(|contract| (contract.id, contract))
+            .collect();
+        let indexed = self
+            .search()
+            .indexed_ids(workspace.id, CONTRACT_ENTITY)
+            .await?;
+
+        let mut seen = HashSet::new();
+        let mut failed_paths = HashSet::new();
+        for file in files {
+            let ParsedContract {
+                record,
+                consumers,
+                contract,
+            } = match parse_contract_file(workspace.id, file) {
+                Some(Ok(parsed)) => parsed,
+                Some(Err(error)) => {
+                    failed_paths.insert(file.path.clone());
+                    result.errors.push(error);
+                    continue;
+                }
+                None => continue,
+            };
+            if !seen.insert(record.id) {
+                result.errors.push(format!(
+                    "{}: duplicate contract id '{}'",
+                    file.path, record.contract_key
+                ));
+                continue;
+            }
+
+            let previous = existing.get(&record.id);
+            if previous
+                .is_some_and(|p| p.yaml_hash == record.yaml_hash && p.file_path == record.file_path)
+            {
+                result.contracts_unchanged += 1;
+                // Contracts mirrored before the search index existed
+                if !indexed.contains(&record.id) {
+                    result.documents_indexed += self
+                        .search()
+                        .index_contract(workspace.id, record.id, &contract, Some(&file.path))
+                        .await?;
+                }
+                continue;
+            }
+
+            self.contracts().upsert(&record).await?;
+            let mut version = ContractVersionRecord::new(
+                record.id,
+                &record.version,
+                String::from_utf8_lossy(&file.content),
+                &file.hash,
+            );
+            version.revision = options.revision.clone();
+            version.created_by = options.actor.clone();
+            // The contract was just saved to the authorized workspace
+            ContractVersionDao {
+                backend: &self.backend,
+                tenant: None,
+            }
+            .insert(&version)
+            .await?;
+            ConsumerDao {
+                backend: &self.backend,
+                tenant: None,
+            }
+            .replace(record.id, &consumers)
+            .await?;
+            result.consumers_synced += consumers.len();
+            result.documents_indexed += self
+                .search()
+                .index_contract(workspace.id, record.id, &contract, Some(&file.path))
+                .await?;
+
+            let (action, details) = match previous {
+                Some(previous) => {
+                    result.contracts_updated += 1;
+                    (
+                        "updated",
+                        json!({
+                            "version": record.version,
+                            "previousVersion": previous.version,
+                            "filePath": record.file_path,
+                        }),
+                    )
+                }
+                None => {
+                    result.contracts_created += 1;
+                    (
+                        "created",
+                        json!({"version": record.version, "filePath": record.file_path}),
+                    )
+                }
+            };
+            self.audit_events()
+                .record(
+                    &AuditEvent::new(workspace.id, CONTRACT_ENTITY, action)
+                        .with_entity(record.id)
+                        .with_actor(actor)
+                        .with_revision(revision)
+                        .with_details(details),
+                )
+                .await?;
+        }
+
+        for contract in existing.values() {
+            let file_failed = contract
+                .file_path
+                .as_ref()
+                .is_some_and(|path| failed_paths.contains(path));
+            if seen.contains(&contract.id) || file_failed {
+                continue;
+            }
+            self.contracts().delete(contract.id).await?;
+            result.contracts_deleted += 1;
+            self.audit_events()
+                .record(
+                    &AuditEvent::new(workspace.id, CONTRACT_ENTITY, "deleted")
+                        .with_entity(contract.id)
+                        .with_actor(actor)
+                        .with_revision(revision)
+                        .with_details(json!({
+                            "contractKey": contract.contract_key,
+                            "version": contract.version,
+                        })),
+                )
+                .await?;
+        }
+
+        self.audit_events()
+            .record(
+                &AuditEvent::new(workspace.id, WORKSPACE_ENTITY, "mirrored")
+                    .with_entity(workspace.id)
+                    .with_actor(actor)
+                    .with_revision(revision)
+                    .with_details(json!({
+                        "created": result.contracts_created,
+                        "updated": result.contracts_updated,
+                        "deleted": result.contracts_deleted,
+                        "unchanged": result.contracts_unchanged,
+                        "errors": result.errors.len(),
+                    })),
+            )
+            .await?;
+
+        result.duration_ms = start.elapsed().as_millis() as u64;
+        Ok(result)
+    }
+
+    /// Identity of a workspace directory
+    ///
+    /// Uses `workspace.yaml` when present, otherwise the stored workspace named
+    /// after the directory (or a new one).
+    pub async fn workspace_for_directory(
+        &self,
+        workspace_path: &Path,
+    ) -> DatabaseResult<Workspace> {
+        let workspace_file = workspace_path.join("workspace.yaml");
+        if workspace_file.exists() {
+            let content = std::fs::read_to_string(&workspace_file).map_err(|e| {
+                DatabaseError::IoError(format!("Failed to read workspace.yaml: {}", e))
+            })?;
+            return Workspace::from_yaml(&content).map_err(|e| {
+                DatabaseError::SerializationError(format!("Invalid workspace.yaml: {}", e))
+            });
+        }
+
+        let name = workspace_path
+            .canonicalize()
+            .ok()
+            .and_then(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_else(|| "workspace".to_string());
+        Ok(match self.workspaces().get_by_name(&name).await? {
+            Some(existing) => {
+                Workspace::with_id(existing.id, name, existing.owner_id.unwrap_or_default())
+            }
+            None => Workspace::new(name, Uuid::nil()),
+        })
+    }
+}
+
+/// ID of the contract record for an ODCS contract `id` within a workspace
+///
+/// Derived rather than taken from the contract, so the same contract can be
+/// mirrored into several workspaces.
+pub fn contract_record_id(workspace_id: Uuid, contract_key: &str) -> Uuid {
+    Uuid::new_v5(&workspace_id, contract_key.as_bytes())
+}
+
+/// A contract file parsed for the metadata store
+#[derive(Debug, Clone)]
+pub struct ParsedContract {
+    /// Contract row
+    pub record: ContractRecord,
+    /// Consumers declared by the contract's roles
+    pub consumers: Vec<ConsumerRecord>,
+    /// The contract itself
+    pub contract: ODCSContract,
+}
+
+/// Parse an ODCS file into a contract record and its consumers
+///
+/// Returns `None` for files that aren't ODCS contracts.
+pub fn parse_contract_file(
+    workspace_id: Uuid,
+    file: &FileInfo,
+) -> Option<Result<ParsedContract, String>> {
+    let file_name = Path::new(&file.path).file_name()?.to_str()?;
+    if AssetType::from_filename(file_name) != Some(AssetType::Odcs) {
+        return None;
+    }
+
+    let parsed = std::str::from_utf8(&file.content)
+        .map_err(|e| e.to_string())
+        .and_then(|content| {
+            serde_yaml::from_str::<ODCSContract>(content).map_err(|e| e.to_string())
+        })
+        .map_err(|e| format!("{}: {}", file.path, e))
+        .map(|contract| {
+            let mut record = ContractRecord::from_contract(workspace_id, &contract);
+            record.file_path = Some(file.path.clone());
+            record.yaml_hash = Some(file.hash.clone());
+            let consumers = ConsumerRecord::from_contract(record.id, &contract);
+            ParsedContract {
+                record,
+                consumers,
+                contract,
+            }
+        });
+    Some(parsed)
+}
+
+/// Run a statement that returns no rows
+/// Fail unless the workspace is visible to `tenant`
+pub(super) async fn authorize_workspace<B: DatabaseBackend>(
+    backend: &B,
+    tenant: Option<&TenantId>,
+    workspace_id: Uuid,
+) -> DatabaseResult<()> {
+    WorkspaceDao { backend, tenant }
+        .authorize(workspace_id)
+        .await
+}
+
+/// Fail unless the contract exists in a workspace visible to `tenant`
+async fn authorize_contract<B: DatabaseBackend>(
+    backend: &B,
+    tenant: Option<&TenantId>,
+    contract_id: Uuid,
+) -> DatabaseResult<()> {
+    let contracts = ContractDao { backend, tenant };
+    if tenant.is_none() || contracts.get(contract_id).await?.is_some() {
+        Ok(())
+    } else {
+        Err(DatabaseError::InvalidInput(format!(
+            "Contract not found: {}",
+            contract_id
+        )))
+    }
+}
+
+/// Parameters of a query, followed by the tenant for tenant-scoped queries
+fn tenant_params(mut params: Vec<Value>, tenant: Option<&TenantId>) -> Vec<Value> {
+    params.extend(tenant.map(|tenant| json!(tenant.as_str())));
+    params
+}
+
+pub(super) async fn execute<B: DatabaseBackend>(
+    backend: &B,
+    sql: &str,
+    params: &[Value],
+) -> DatabaseResult<()> {
+    backend.execute_query_params(sql, params).await.map(|_| ())
+}
+
+/// Run a query and return its rows
+pub(super) async fn query<B: DatabaseBackend>(
+    backend: &B,
+    sql: &str,
+    params: &[Value],
+) -> DatabaseResult<Vec<QueryRow>> {
+    Ok(backend.execute_query_params(sql, params).await?.rows)
+}
+
+/// Text value of a column, `None` when null
+pub(super) fn text(row: &QueryRow, column: &str) -> Option<String> {
+    match row.get(column)? {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+pub(super) fn required_text(row: &QueryRow, column: &str) -> DatabaseResult<String> {
+    text(row, column)
+        .ok_or_else(|| DatabaseError::SerializationError(format!("Missing column: {}", column)))
+}
+
+pub(super) fn optional_uuid(row: &QueryRow, column: &str) -> DatabaseResult<Option<Uuid>> {
+    text(row, column)
+        .map(|value| {
+            Uuid::parse_str(&value).map_err(|e| {
+                DatabaseError::SerializationError(format!("Invalid UUID in {}: {}", column, e))
+            })
+        })
+        .transpose()
+}
+
+pub(super) fn required_uuid(row: &QueryRow, column: &str) -> DatabaseResult<Uuid> {
+    optional_uuid(row, column)?
+        .ok_or_else(|| DatabaseError::SerializationError(format!("Missing column: {}", column)))
+}
+
+/// Timestamp of a column cast to text (e.g. `2026-01-31 12:00:00.5+00`)
+fn timestamp(row: &QueryRow, column: &str) -> Option<DateTime<Utc>> {
+    let value = text(row, column)?;
+    DateTime::parse_from_rfc3339(&value)
+        .or_else(|_| DateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S%.f%#z"))
+        .map(|ts| ts.with_timezone(&Utc))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::search::SearchQuery;
+    use crate::database::{QueryResult, SyncStatus};
+    use crate::models::decision::Decision;
+    use crate::models::knowledge::KnowledgeArticle;
+    use crate::models::{Domain, Relationship, Table};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    const CONTRACT_YAML: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders-contract
+version: 1.2.0
+name: Orders
+status: active
+domain: sales
+team:
+  name: sales-data
+roles:
+  - role: analyst
+    principal: analytics-team
+    access: read
+  - role: finance_read
+    access: read
+"#;
+
+    /// Backend that records parameterized statements and answers queries with
+    /// canned rows
+    ///
+    /// Tenant-filtered queries only get rows when they bind `rows_tenant`.
+    #[derive(Default)]
+    struct RecordingBackend {
+        statements: Mutex<Vec<(String, Vec<Value>)>>,
+        contract_rows: Vec<Value>,
+        workspace_rows: Vec<Value>,
+        version_rows: Vec<Value>,
+        consumer_rows: Vec<Value>,
+        document_rows: Vec<Value>,
+        rows_tenant: Option<&'static str>,
+    }
+
+    impl RecordingBackend {
+        fn statements_for(&self, table: &str) -> Vec<Vec<Value>> {
+            self.statements
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(sql, _)| sql.contains(table))
+                .map(|(_, params)| params.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl DatabaseBackend for RecordingBackend {
+        async fn initialize(&self) -> DatabaseResult<()> {
+            Ok(())
+        }
+
+        async fn execute_query(&self, _sql: &str) -> DatabaseResult<QueryResult> {
+            Ok(QueryResult::empty())
+        }
+
+        async fn execute_query_params(
+            &self,
+            sql: &str,
+            params: &[Value],
+        ) -> DatabaseResult<QueryResult> {
+            let is_insert = sql.trim_start().starts_with("INSERT");
+            let is_delete = sql.trim_start().starts_with("DELETE");
+            let op = if is_insert {
+                "INSERT"
+            } else if is_delete {
+                "DELETE"
+            } else {
+                "SELECT"
+            };
+            let table = sql
+                .split_whitespace()
+                .skip_while(|w| !matches!(*w, "INTO" | "FROM"))
+                .nth(1)
+                .unwrap_or_default();
+            self.statements
+                .lock()
+                .unwrap()
+                .push((format!("{} {}", op, table), params.to_vec()));
+            let other_tenant = sql.contains("FROM workspace_tenants WHERE tenant")
+                && !params.contains(&json!(self.rows_tenant));
+            let rows = match (op, table) {
+                _ if other_tenant => Vec::new(),
+                ("SELECT", "contracts") => self.contract_rows.clone(),
+                ("SELECT", "workspaces") => self
+                    .workspace_rows
+                    .iter()
+                    .filter(|row| workspace_matches(sql, params, row))
+                    .cloned()
+                    .collect(),
+                ("SELECT", "contract_versions") => self.version_rows.clone(),
+                ("SELECT", "contract_consumers") => self.consumer_rows.clone(),
+                ("SELECT", "search_documents") => self.document_rows.clone(),
+                _ => Vec::new(),
+            };
+            Ok(QueryResult::new(Vec::new(), rows))
+        }
+
+        async fn sync_tables(&self, _: Uuid, _: &[Table]) -> DatabaseResult<usize> {
+            Ok(0)
+        }
+
+        async fn sync_domains(&self, _: Uuid, _: &[Domain]) -> DatabaseResult<usize> {
+            Ok(0)
+        }
+
+        async fn sync_relationships(&self, _: Uuid, _: &[Relationship]) -> DatabaseResult<usize> {
+            Ok(0)
+        }
+
+        async fn export_tables(&self, _: Uuid) -> DatabaseResult<Vec<Table>> {
+            Ok(Vec::new())
+        }
+
+        async fn export_domains(&self, _: Uuid) -> DatabaseResult<Vec<Domain>> {
+            Ok(Vec::new())
+        }
+
+        async fn export_relationships(&self, _: Uuid) -> DatabaseResult<Vec<Relationship>> {
+            Ok(Vec::new())
+        }
+
+        async fn sync_decisions(&self, _: Uuid, _: &[Decision]) -> DatabaseResult<usize> {
+            Ok(0)
+        }
+
+        async fn sync_knowledge(&self, _: Uuid, _: &[KnowledgeArticle]) -> DatabaseResult<usize> {
+            Ok(0)
+        }
+
+        async fn export_decisions(&self, _: Uuid) -> DatabaseResult<Vec<Decision>> {
+            Ok(Vec::new())
+        }
+
+        async fn export_knowledge(&self, _: Uuid) -> DatabaseResult<Vec<KnowledgeArticle>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_sync_status(&self, _: Uuid) -> DatabaseResult<SyncStatus> {
+            Ok(SyncStatus::default())
+        }
+
+        async fn upsert_workspace(&self, workspace: &Workspace) -> DatabaseResult<()> {
+            self.statements.lock().unwrap().push((
+                "UPSERT workspaces".to_string(),
+                vec![
+                    json!(workspace.id.to_string()),
+                    json!(workspace.tenant),
+                    json!(workspace.name),
+                ],
+            ));
+            Ok(())
+        }
+
+        async fn get_workspace(&self, _: Uuid) -> DatabaseResult<Option<Workspace>> {
+            Ok(None)
+        }
+
+        async fn get_workspace_by_name(&self, _: &str) -> DatabaseResult<Option<Workspace>> {
+            Ok(None)
+        }
+
+        async fn delete_workspace(&self, _: Uuid) -> DatabaseResult<()> {
+            Ok(())
+        }
+
+        async fn record_file_hash(&self, _: Uuid, _: &str, _: &str) -> DatabaseResult<()> {
+            Ok(())
+        }
+
+        async fn get_file_hash(&self, _: Uuid, _: &str) -> DatabaseResult<Option<String>> {
+            Ok(None)
+        }
+
+        async fn health_check(&self) -> DatabaseResult<bool> {
+            Ok(true)
+        }
+
+        fn backend_type(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn close(&self) -> DatabaseResult<()> {
+            Ok(())
+        }
+    }
+
+    /// Whether a workspace row matches the ID, name and tenant a query binds
+    fn workspace_matches(sql: &str, params: &[Value], row: &Value) -> bool {
+        let bound = |column: &str| row.get(column).unwrap_or(&Value::Null);
+        if sql.contains("w.id = $1") && bound("id") != &params[0] {
+            return false;
+        }
+        if sql.contains("w.name = $1") && bound("name") != &params[0] {
+            return false;
+        }
+        if sql.contains("t.tenant = $2") && bound("tenant") != &params[1] {
+            return false;
+        }
+        !sql.contains("t.tenant IS NULL") || bound("tenant").is_null()
+    }
+
+    fn contract_file() -> FileInfo {
+        FileInfo::new(
+            "acme_sales_orders.odcs.yaml",
+            CONTRACT_YAML.as_bytes().to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_parse_contract_file() {
+        let workspace_id = Uuid::new_v4();
+        let ParsedContract {
+            record, consumers, ..
+        } = parse_contract_file(workspace_id, &contract_file())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            record.id,
+            contract_record_id(workspace_id, "orders-contract")
+        );
+        assert_eq!(record.version, "1.2.0");
+        assert_eq!(record.owner.as_deref(), Some("sales-data"));
+        assert_eq!(record.domain.as_deref(), Some("sales"));
+        assert_eq!(
+            record.file_path.as_deref(),
+            Some("acme_sales_orders.odcs.yaml")
+        );
+
+        let principals: Vec<&str> = consumers.iter().map(|c| c.principal.as_str()).collect();
+        assert_eq!(principals, vec!["analytics-team", "finance_read"]);
+        assert_eq!(consumers[0].role.as_deref(), Some("analyst"));
+
+        let other = FileInfo::new("workspace.yaml", b"name: acme".to_vec());
+        assert!(parse_contract_file(workspace_id, &other).is_none());
+        let invalid = FileInfo::new("acme_sales_bad.odcs.yaml", b"kind: [".to_vec());
+        assert!(
+            parse_contract_file(workspace_id, &invalid)
+                .unwrap()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_record_from_row() {
+        let id = Uuid::new_v4();
+        let row = json!({
+            "id": id.to_string(),
+            "workspace_id": Uuid::nil().to_string(),
+            "entity_type": "contract",
+            "entity_id": null,
+            "action": "updated",
+            "actor": "ci",
+            "revision": null,
+            "details": "{\"version\": \"2.0.0\"}",
+            "created_at": "2026-01-31 12:00:00.5+00",
+        });
+        let event = AuditEvent::from_row(&row).unwrap();
+        assert_eq!(event.id, id);
+        assert_eq!(event.entity_id, None);
+        assert_eq!(event.details, Some(json!({"version": "2.0.0"})));
+        assert_eq!(
+            event.created_at.unwrap().to_rfc3339(),
+            "2026-01-31T12:00:00.500+00:00"
+        );
+        assert!(AuditEvent::from_row(&json!({"id": "not-a-uuid"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_new_and_removed_contracts() {
+        let workspace = Workspace::new("acme".to_string(), Uuid::nil());
+        let removed_id = contract_record_id(workspace.id, "legacy");
+        let backend = RecordingBackend {
+            contract_rows: vec![json!({
+                "id": removed_id.to_string(),
+                "workspace_id": workspace.id.to_string(),
+                "contract_key": "legacy",
+                "name": "Legacy",
+                "version": "0.1.0",
+                "file_path": "acme_sales_legacy.odcs.yaml",
+                "yaml_hash": "abc",
+            })],
+            ..Default::default()
+        };
+        let store = MetadataStore::new(backend);
+
+        let options = MirrorOptions::default()
+            .with_actor("ci")
+            .with_revision("3f2c1e0");
+        let result = store
+            .mirror_workspace(&workspace, &[contract_file()], &options)
+            .await
+            .unwrap();
+
+        assert!(result.is_success());
+        assert_eq!(result.contracts_created, 1);
+        assert_eq!(result.contracts_deleted, 1);
+        assert_eq!(result.consumers_synced, 2);
+        assert_eq!(result.documents_indexed, 1);
+
+        let backend = store.backend();
+        let versions = backend.statements_for("INSERT contract_versions");
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0][2], json!("1.2.0"));
+        assert_eq!(versions[0][5], json!("3f2c1e0"));
+        assert_eq!(versions[0][6], json!("ci"));
+        assert_eq!(backend.statements_for("INSERT contract_consumers").len(), 2);
+        assert_eq!(
+            backend.statements_for("DELETE contracts"),
+            vec![vec![json!(removed_id.to_string())]]
+        );
+        assert!(
+            backend
+                .statements_for("DELETE search_documents")
+                .contains(&vec![json!(removed_id.to_string())])
+        );
+
+        let actions: Vec<Value> = backend
+            .statements_for("INSERT audit_events")
+            .into_iter()
+            .map(|params| params[4].clone())
+            .collect();
+        assert_eq!(actions, vec!["created", "deleted", "mirrored"]);
+    }
+
+    #[tokio::test]
+    async fn test_mirror_skips_unchanged_contracts() {
+        let workspace = Workspace::new("acme".to_string(), Uuid::nil());
+        let file = contract_file();
+        let backend = RecordingBackend {
+            contract_rows: vec![json!({
+                "id": contract_record_id(workspace.id, "orders-contract").to_string(),
+                "workspace_id": workspace.id.to_string(),
+                "contract_key": "orders-contract",
+                "name": "Orders",
+                "version": "1.2.0",
+                "file_path": file.path,
+                "yaml_hash": file.hash,
+            })],
+            ..Default::default()
+        };
+        let store = MetadataStore::new(backend);
+
+        let broken = FileInfo::new("acme_sales_broken.odcs.yaml", b"id: [".to_vec());
+        let result = store
+            .mirror_workspace(&workspace, &[file, broken], &MirrorOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.contracts_unchanged, 1);
+        assert_eq!(result.contracts_created + result.contracts_updated, 0);
+        assert_eq!(result.errors.len(), 1);
+        // Not in the search index yet, so indexed without a new version
+        assert_eq!(result.documents_indexed, 1);
+        assert!(
+            store
+                .backend()
+                .statements_for("INSERT contracts")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tenant_scoped_store() {
+        let workspace = Workspace::new("acme".to_string(), Uuid::nil());
+        let backend = RecordingBackend {
+            workspace_rows: vec![json!({
+                "id": workspace.id.to_string(),
+                "name": "acme",
+                "tenant": "acme",
+            })],
+            ..Default::default()
+        };
+        let globex = MetadataStore::new(backend).with_tenant(TenantId::parse("globex").unwrap());
+
+        assert!(
+            globex
+                .workspaces()
+                .get(workspace.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(matches!(
+            globex.contracts().list(workspace.id).await,
+            Err(DatabaseError::WorkspaceNotFound(_))
+        ));
+        assert!(matches!(
+            globex
+                .mirror_workspace(&workspace, &[contract_file()], &MirrorOptions::default())
+                .await,
+            Err(DatabaseError::WorkspaceNotFound(_))
+        ));
+        assert!(
+            globex
+                .backend()
+                .statements_for("INSERT contracts")
+                .is_empty()
+        );
+
+        let backend = RecordingBackend {
+            workspace_rows: globex.backend().workspace_rows.clone(),
+            ..Default::default()
+        };
+        let acme = MetadataStore::new(backend).with_tenant(TenantId::parse("acme").unwrap());
+        let result = acme
+            .mirror_workspace(&workspace, &[contract_file()], &MirrorOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(result.contracts_created, 1);
+        assert_eq!(
+            acme.backend().statements_for("UPSERT workspaces"),
+            vec![vec![
+                json!(workspace.id.to_string()),
+                json!("acme"),
+                json!("acme"),
+            ]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tenant_cannot_read_other_tenants_contracts() {
+        let workspace = Workspace::new("acme".to_string(), Uuid::nil());
+        let contract_id = contract_record_id(workspace.id, "orders-contract");
+        let backend = || RecordingBackend {
+            workspace_rows: vec![json!({
+                "id": workspace.id.to_string(),
+                "name": "acme",
+                "tenant": "acme",
+            })],
+            contract_rows: vec![json!({
+                "id": contract_id.to_string(),
+                "workspace_id": workspace.id.to_string(),
+                "contract_key": "orders-contract",
+                "name": "Orders",
+                "version": "1.2.0",
+            })],
+            version_rows: vec![json!({
+                "id": Uuid::new_v4().to_string(),
+                "contract_id": contract_id.to_string(),
+                "version": "1.2.0",
+                "yaml_hash": "abc",
+                "content": CONTRACT_YAML,
+            })],
+            consumer_rows: vec![json!({
+                "id": Uuid::new_v4().to_string(),
+                "contract_id": contract_id.to_string(),
+                "principal": "analytics-team",
+            })],
+            document_rows: vec![json!({
+                "id": contract_id.to_string(),
+                "entity_type": CONTRACT_ENTITY,
+                "name": "Orders",
+            })],
+            rows_tenant: Some("acme"),
+            ..Default::default()
+        };
+
+        let globex = MetadataStore::new(backend()).with_tenant(TenantId::parse("globex").unwrap());
+        let results = globex
+            .search()
+            .search(workspace.id, &SearchQuery::new())
+            .await
+            .unwrap();
+        assert!(results.hits.is_empty());
+        assert!(
+            globex
+                .versions()
+                .list(contract_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            globex
+                .versions()
+                .latest(contract_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            globex
+                .consumers()
+                .list(contract_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            globex
+                .consumers()
+                .list_by_principal("analytics-team")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            globex
+                .consumers()
+                .upsert(&ConsumerRecord::new(contract_id, "globex-team"))
+                .await
+                .is_err()
+        );
+        assert!(matches!(
+            globex.search().remove_workspace(workspace.id).await,
+            Err(DatabaseError::WorkspaceNotFound(_))
+        ));
+        assert!(
+            globex
+                .backend()
+                .statements_for("INSERT contract_consumers")
+                .is_empty()
+        );
+        assert!(
+            globex
+                .backend()
+                .statements_for("DELETE search_documents")
+                .is_empty()
+        );
+
+        let acme = MetadataStore::new(backend()).with_tenant(TenantId::parse("acme").unwrap());
+        let results = acme
+            .search()
+            .search(workspace.id, &SearchQuery::new())
+            .await
+            .unwrap();
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(acme.versions().list(contract_id).await.unwrap().len(), 1);
+        assert_eq!(
+            acme.consumers()
+                .list_by_principal("analytics-team")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        acme.consumers()
+            .upsert(&ConsumerRecord::new(contract_id, "acme-team"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tenant_cannot_overwrite_other_tenants_contracts() {
+        let globex_workspace = Workspace::new("globex".to_string(), Uuid::nil());
+        let acme_contract = contract_record_id(Uuid::new_v4(), "orders-contract");
+        let globex_contract = contract_record_id(globex_workspace.id, "orders-contract");
+        let acme_consumer = Uuid::new_v4();
+        let backend = |contract_id: Uuid, workspace_id: Uuid| RecordingBackend {
+            workspace_rows: vec![json!({
+                "id": globex_workspace.id.to_string(),
+                "name": "globex",
+                "tenant": "globex",
+            })],
+            contract_rows: vec![json!({
+                "id": contract_id.to_string(),
+                "workspace_id": workspace_id.to_string(),
+                "contract_key": "orders-contract",
+                "name": "Orders",
+                "version": "1.2.0",
+            })],
+            consumer_rows: vec![json!({
+                "id": acme_consumer.to_string(),
+                "contract_id": acme_contract.to_string(),
+                "principal": "analytics-team",
+            })],
+            ..Default::default()
+        };
+        let globex_tenant = TenantId::parse("globex").unwrap();
+
+        // The ID of acme's contract, sent for a globex workspace
+        let globex = MetadataStore::new(backend(acme_contract, Uuid::new_v4()))
+            .with_tenant(globex_tenant.clone());
+        let mut contract = parse_contract_file(globex_workspace.id, &contract_file())
+            .unwrap()
+            .unwrap()
+            .record;
+        contract.id = acme_contract;
+        assert!(matches!(
+            globex.contracts().upsert(&contract).await,
+            Err(DatabaseError::InvalidInput(_))
+        ));
+        assert!(
+            globex
+                .backend()
+                .statements_for("INSERT contracts")
+                .is_empty()
+        );
+
+        // The ID of acme's consumer, sent for a globex contract
+        let globex = MetadataStore::new(backend(globex_contract, globex_workspace.id))
+            .with_tenant(globex_tenant);
+        let consumer = ConsumerRecord {
+            id: acme_consumer,
+            ..ConsumerRecord::new(globex_contract, "globex-team")
+        };
+        assert!(matches!(
+            globex.consumers().upsert(&consumer).await,
+            Err(DatabaseError::InvalidInput(_))
+        ));
+        assert!(
+            globex
+                .backend()
+                .statements_for("INSERT contract_consumers")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_workspace_names_are_unique_per_tenant() {
+        let acme_default = Uuid::new_v4();
+        let backend = || RecordingBackend {
+            workspace_rows: vec![json!({
+                "id": acme_default.to_string(),
+                "name": "default",
+                "tenant": "acme",
+            })],
+            ..Default::default()
+        };
+
+        // Another tenant can use the name, and doesn't see acme's workspace
+        let globex = MetadataStore::new(backend()).with_tenant(TenantId::parse("globex").unwrap());
+        assert!(
+            globex
+                .workspaces()
+                .get_by_name("default")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let workspace = Workspace::new("default".to_string(), Uuid::nil());
+        globex.workspaces().upsert(&workspace).await.unwrap();
+        assert_eq!(
+            globex.backend().statements_for("UPSERT workspaces"),
+            vec![vec![
+                json!(workspace.id.to_string()),
+                json!("globex"),
+                json!("default"),
+            ]]
+        );
+
+        // The tenant itself cannot use the name twice
+        let acme = MetadataStore::new(backend()).with_tenant(TenantId::parse("acme").unwrap());
+        assert_eq!(
+            acme.workspaces()
+                .get_by_name("default")
+                .await
+                .unwrap()
+                .unwrap()
+                .id,
+            acme_default
+        );
+        assert!(matches!(
+            acme.workspaces().upsert(&workspace).await,
+            Err(DatabaseError::InvalidInput(_))
+        ));
+        assert!(
+            acme.backend()
+                .statements_for("UPSERT workspaces")
+                .is_empty()
+        );
+    }
+
+    /// The tenant checks against a real database, where the unique index on
+    /// workspace names applies
+    #[cfg(feature = "duckdb-backend")]
+    mod duckdb {
+        use super::*;
+        use crate::database::DuckDBBackend;
+
+        async fn backend() -> DuckDBBackend {
+            let backend = DuckDBBackend::in_memory().unwrap();
+            backend.initialize().await.unwrap();
+            backend
+        }
+
+        fn workspaces<'a>(
+            backend: &'a DuckDBBackend,
+            tenant: Option<&'a TenantId>,
+        ) -> WorkspaceDao<'a, DuckDBBackend> {
+            WorkspaceDao { backend, tenant }
+        }
+
+        #[tokio::test]
+        async fn test_workspace_names_are_unique_per_tenant() {
+            let backend = backend().await;
+            let acme = TenantId::parse("acme").unwrap();
+            let globex = TenantId::parse("globex").unwrap();
+
+            // Each tenant, and the workspaces without one, can use a name once
+            let acme_default = Workspace::new("default".to_string(), Uuid::nil());
+            let globex_default = Workspace::new("default".to_string(), Uuid::nil());
+            let shared_default = Workspace::new("default".to_string(), Uuid::nil());
+            workspaces(&backend, Some(&acme))
+                .upsert(&acme_default)
+                .await
+                .unwrap();
+            workspaces(&backend, Some(&globex))
+                .upsert(&globex_default)
+                .await
+                .unwrap();
+            workspaces(&backend, None)
+                .upsert(&shared_default)
+                .await
+                .unwrap();
+            for (tenant, id) in [
+                (Some(&globex), globex_default.id),
+                (None, shared_default.id),
+            ] {
+                let found = workspaces(&backend, tenant).get_by_name("default").await;
+                assert_eq!(found.unwrap().unwrap().id, id);
+            }
+            assert!(matches!(
+                backend.get_workspace_by_name("default").await,
+                Err(DatabaseError::InvalidInput(_))
+            ));
+
+            let duplicate = Workspace::new("default".to_string(), Uuid::nil());
+            for tenant in [Some(&acme), None] {
+                assert!(matches!(
+                    workspaces(&backend, tenant).upsert(&duplicate).await,
+                    Err(DatabaseError::InvalidInput(_))
+                ));
+            }
+
+            // The index rejects duplicates that skip the name check, as
+            // concurrent upserts would
+            let mut acme_duplicate = duplicate.clone();
+            acme_duplicate.tenant = Some("acme".to_string());
+            assert!(backend.upsert_workspace(&acme_duplicate).await.is_err());
+            assert!(backend.upsert_workspace(&duplicate).await.is_err());
+            assert!(backend.get_workspace(duplicate.id).await.unwrap().is_none());
+
+            // Renaming frees the name, and keeps the tenant
+            let mut archive = acme_default.clone();
+            archive.name = "archive".to_string();
+            workspaces(&backend, None).upsert(&archive).await.unwrap();
+            workspaces(&backend, Some(&acme))
+                .upsert(&duplicate)
+                .await
+                .unwrap();
+            let renamed = workspaces(&backend, Some(&acme))
+                .get_by_name("archive")
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(renamed.tenant.as_deref(), Some("acme"));
+        }
+
+        #[tokio::test]
+        async fn test_tenant_cannot_take_over_other_tenants_records() {
+            let backend = backend().await;
+            let acme = TenantId::parse("acme").unwrap();
+            let globex = TenantId::parse("globex").unwrap();
+            let acme_workspace = Workspace::new("orders".to_string(), Uuid::nil());
+            let globex_workspace = Workspace::new("orders".to_string(), Uuid::nil());
+            workspaces(&backend, Some(&acme))
+                .upsert(&acme_workspace)
+                .await
+                .unwrap();
+            workspaces(&backend, Some(&globex))
+                .upsert(&globex_workspace)
+                .await
+                .unwrap();
+            let acme_contracts = ContractDao {
+                backend: &backend,
+                tenant: Some(&acme),
+            };
+            let contract = parse_contract_file(acme_workspace.id, &contract_file())
+                .unwrap()
+                .unwrap()
+                .record;
+            acme_contracts.upsert(&contract).await.unwrap();
+
+            // globex neither sees nor claims acme's workspace
+            let globex_workspaces = workspaces(&backend, Some(&globex));
+            assert!(
+                globex_workspaces
+                    .get(acme_workspace.id)
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+            assert_eq!(globex_workspaces.list().await.unwrap().len(), 1);
+            assert!(matches!(
+                globex_workspaces.upsert(&acme_workspace).await,
+                Err(DatabaseError::WorkspaceNotFound(_))
+            ));
+            assert!(matches!(
+                globex_workspaces.delete(acme_workspace.id).await,
+                Err(DatabaseError::WorkspaceNotFound(_))
+            ));
+            workspaces(&backend, None)
+                .assign_tenant(acme_workspace.id, &globex)
+                .await
+                .unwrap();
+            let stored = workspaces(&backend, Some(&acme))
+                .get(acme_workspace.id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(stored.tenant.as_deref(), Some("acme"));
+
+            // Nor does it move acme's contract into its own workspace
+            let globex_contracts = ContractDao {
+                backend: &backend,
+                tenant: Some(&globex),
+            };
+            let moved = ContractRecord {
+                workspace_id: globex_workspace.id,
+                ..contract.clone()
+            };
+            assert!(matches!(
+                globex_contracts.upsert(&moved).await,
+                Err(DatabaseError::InvalidInput(_))
+            ));
+            assert!(globex_contracts.get(contract.id).await.unwrap().is_none());
+            let stored = acme_contracts.get(contract.id).await.unwrap().unwrap();
+            assert_eq!(stored.workspace_id, acme_workspace.id);
+        }
+    }
+}
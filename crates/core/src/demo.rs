@@ -0,0 +1,876 @@
+//! Sample e-commerce workspace generator
+//!
+//! Builds a complete, realistic workspace programmatically: customer, catalog,
+//! sales and fulfillment domains with their systems, ODCS contracts carrying
+//! quality rules and example values, foreign-key relationships between them and
+//! accepted MADR decision records. Useful for tests, documentation screenshots
+//! and onboarding.
+//!
+//! Generation is deterministic: identifiers, timestamps, example values and
+//! quality thresholds are derived from the seed, so the same seed always yields
+//! byte-identical files.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::demo::DemoGenerator;
+//!
+//! let demo = DemoGenerator::new(7).generate().unwrap();
+//! assert_eq!(demo.workspace.domains.len(), 4);
+//! assert!(!demo.workspace.relationships.is_empty());
+//!
+//! // Same seed, same workspace
+//! let again = DemoGenerator::new(7).generate().unwrap();
+//! assert_eq!(demo.files, again.files);
+//! ```
+
+use crate::generate::SplitMix64;
+use crate::models::decision::{
+    AssetLink, AssetRelationship, Decision, DecisionCategory, DecisionDriver, DecisionIndex,
+    DecisionOption, DecisionStatus,
+};
+use crate::models::enums::{Cardinality, RelationshipType};
+use crate::models::odcs::{ODCSContract, Property, QualityRule, SchemaObject, SchemaRelationship};
+use crate::models::relationship::{ForeignKeyDetails, Relationship};
+use crate::models::workspace::{AssetReference, AssetType, Workspace};
+use crate::models::workspace_template::ScaffoldFile;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+/// Default seed used by [`DemoGenerator::default`]
+pub const DEFAULT_SEED: u64 = 42;
+
+/// Author recorded on generated decisions
+const DEMO_AUTHOR: &str = "Demo Data Team";
+
+/// Number of example values generated per property
+const EXAMPLES_PER_PROPERTY: usize = 3;
+
+/// Generator for the sample e-commerce workspace
+#[derive(Debug, Clone)]
+pub struct DemoGenerator {
+    seed: u64,
+    workspace_name: String,
+}
+
+impl Default for DemoGenerator {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+/// A generated sample workspace
+#[derive(Debug, Clone)]
+pub struct DemoWorkspace {
+    /// The workspace, including domains, systems, assets and relationships
+    pub workspace: Workspace,
+    /// One ODCS contract per table
+    pub contracts: Vec<ODCSContract>,
+    /// Accepted decision records
+    pub decisions: Vec<Decision>,
+    /// All files to write: `workspace.yaml`, contracts, decisions and `decisions.yaml`
+    pub files: Vec<ScaffoldFile>,
+}
+
+impl DemoGenerator {
+    /// Create a generator with the given seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            workspace_name: "acme-shop".to_string(),
+        }
+    }
+
+    /// Name of the generated workspace (default: `acme-shop`)
+    pub fn with_workspace_name(mut self, name: impl Into<String>) -> Self {
+        self.workspace_name = name.into();
+        self
+    }
+
+    /// Seed used for generation
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate the sample workspace
+    pub fn generate(&self) -> Result<DemoWorkspace, serde_yaml::Error> {
+        let mut rng = SplitMix64::new(self.seed);
+        let namespace = Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("data-modelling-demo:{}", self.seed).as_bytes(),
+        );
+        let id = |kind: &str, name: &str| {
+            Uuid::new_v5(&namespace, format!("{}:{}", kind, name).as_bytes())
+        };
+        let created_at = base_timestamp() + Duration::days(rng.below(365) as i64);
+
+        let mut workspace = Workspace::with_id(
+            id("workspace", &self.workspace_name),
+            self.workspace_name.clone(),
+            id("owner", DEMO_AUTHOR),
+        );
+        workspace.description =
+            Some("Sample e-commerce workspace generated for demos and tests".to_string());
+
+        for domain in DOMAINS {
+            workspace.add_domain_with_description(
+                id("domain", domain.name),
+                domain.name.to_string(),
+                Some(domain.description.to_string()),
+            );
+            workspace.add_system_to_domain(
+                domain.name,
+                id("system", domain.system),
+                domain.system.to_string(),
+                Some(domain.system_description.to_string()),
+            );
+        }
+
+        let mut contracts = Vec::new();
+        let mut files = Vec::new();
+        for (domain, table) in DOMAINS
+            .iter()
+            .flat_map(|d| d.tables.iter().map(move |t| (d, t)))
+        {
+            let table_id = id("table", table.name);
+            let contract = build_contract(
+                table_id,
+                domain.name,
+                table,
+                &mut rng,
+                created_at + Duration::hours(contracts.len() as i64),
+            );
+
+            let asset = AssetReference {
+                id: table_id,
+                name: table.name.to_string(),
+                domain: domain.name.to_string(),
+                system: Some(domain.system.to_string()),
+                asset_type: AssetType::Odcs,
+                file_path: None,
+            };
+            let path = workspace.generate_asset_filename(&asset);
+            workspace.add_asset(AssetReference {
+                file_path: Some(path.clone()),
+                ..asset
+            });
+            if let Some(system) = workspace
+                .domains
+                .iter_mut()
+                .find(|d| d.name == domain.name)
+                .and_then(|d| d.systems.iter_mut().find(|s| s.name == domain.system))
+            {
+                system.table_ids.push(table_id);
+            }
+
+            files.push(ScaffoldFile {
+                path,
+                content: serde_yaml::to_string(&contract)?,
+            });
+            contracts.push(contract);
+        }
+
+        for (source, source_column, target, target_column, label) in RELATIONSHIPS {
+            let mut relationship = Relationship::new(id("table", source), id("table", target));
+            relationship.id = id("relationship", &format!("{}.{}", source, source_column));
+            relationship.label = Some(label.to_string());
+            relationship.source_key = Some(source_column.to_string());
+            relationship.target_key = Some(target_column.to_string());
+            relationship.cardinality = Some(Cardinality::ManyToOne);
+            relationship.relationship_type = Some(RelationshipType::ForeignKey);
+            relationship.foreign_key_details = Some(ForeignKeyDetails {
+                source_column: source_column.to_string(),
+                target_column: target_column.to_string(),
+            });
+            relationship.created_at = created_at;
+            relationship.updated_at = created_at;
+            workspace.add_relationship(relationship);
+        }
+
+        let mut decisions = Vec::new();
+        let mut index = DecisionIndex::new();
+        for (number, spec) in DECISIONS.iter().enumerate() {
+            let decided = created_at + Duration::days(number as i64 + 1);
+            let mut decision = Decision::new(
+                number as u64 + 1,
+                spec.title,
+                spec.context,
+                spec.decision,
+                DEMO_AUTHOR,
+            );
+            decision.id = id("decision", spec.title);
+            decision.status = DecisionStatus::Accepted;
+            decision.category = spec.category.clone();
+            decision.domain = spec.domain.map(str::to_string);
+            decision.domain_id = spec.domain.map(|d| id("domain", d));
+            decision.workspace_id = Some(workspace.id);
+            decision.date = decided;
+            decision.decided_at = Some(decided);
+            decision.created_at = decided;
+            decision.updated_at = decided;
+            decision.consequences = Some(spec.consequences.to_string());
+            decision.drivers = spec
+                .drivers
+                .iter()
+                .map(|d| DecisionDriver::new(*d))
+                .collect();
+            decision.options = spec
+                .options
+                .iter()
+                .enumerate()
+                .map(|(i, name)| DecisionOption::new(*name, i == 0))
+                .collect();
+            decision.linked_assets = spec
+                .tables
+                .iter()
+                .map(|table| AssetLink {
+                    asset_type: "odcs".to_string(),
+                    asset_id: id("table", table),
+                    asset_name: table.to_string(),
+                    relationship: Some(AssetRelationship::Affects),
+                })
+                .collect();
+
+            let path = decision.filename(&self.workspace_name);
+            index.add_decision(&decision, path.clone());
+            files.push(ScaffoldFile {
+                path,
+                content: decision.to_yaml()?,
+            });
+            decisions.push(decision);
+        }
+        index.last_updated = decisions.last().map(|d| d.date);
+        files.push(ScaffoldFile {
+            path: "decisions.yaml".to_string(),
+            content: index.to_yaml()?,
+        });
+
+        workspace.created_at = created_at;
+        workspace.last_modified_at = created_at;
+        files.insert(
+            0,
+            ScaffoldFile {
+                path: "workspace.yaml".to_string(),
+                content: workspace.to_yaml()?,
+            },
+        );
+
+        Ok(DemoWorkspace {
+            workspace,
+            contracts,
+            decisions,
+            files,
+        })
+    }
+}
+
+/// Generate the sample workspace with the [`DEFAULT_SEED`]
+pub fn generate_demo() -> Result<DemoWorkspace, serde_yaml::Error> {
+    DemoGenerator::default().generate()
+}
+
+fn base_timestamp() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0)
+        .single()
+        .expect("valid base timestamp")
+}
+
+fn build_contract(
+    id: Uuid,
+    domain: &str,
+    table: &TableSpec,
+    rng: &mut SplitMix64,
+    created_at: DateTime<Utc>,
+) -> ODCSContract {
+    let properties = table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(position, column)| {
+            let mut property = Property::new(column.name, column.logical_type)
+                .with_primary_key(column.primary_key)
+                .with_required(column.primary_key || column.required)
+                .with_unique(column.primary_key)
+                .with_description(column.description);
+            if column.primary_key {
+                property = property.with_primary_key_position(1);
+            }
+            if let Some(classification) = column.classification {
+                property = property.with_classification(classification);
+            }
+            if let Some(minimum) = column.minimum {
+                property.quality.push(QualityRule {
+                    rule_type: Some("library".to_string()),
+                    dimension: Some("accuracy".to_string()),
+                    description: Some(format!("{} must not be negative", column.name)),
+                    must_be_greater_than_or_equal: Some(json!(minimum)),
+                    ..Default::default()
+                });
+            }
+            property.examples = (0..EXAMPLES_PER_PROPERTY)
+                .map(|i| column.example.generate(rng, position * 100 + i + 1))
+                .collect();
+            property
+        })
+        .collect();
+
+    let mut schema = SchemaObject::new(table.name)
+        .with_physical_name(table.name)
+        .with_physical_type("table")
+        .with_description(table.description)
+        .with_properties(properties)
+        .with_quality_rule(QualityRule {
+            rule_type: Some("library".to_string()),
+            metric: Some("rowCount".to_string()),
+            dimension: Some("completeness".to_string()),
+            description: Some(format!("{} is not empty", table.name)),
+            must_be_greater_than: Some(json!(rng.between(table.min_rows / 2, table.min_rows))),
+            ..Default::default()
+        });
+    if let Some(key) = table.columns.iter().find(|c| c.primary_key) {
+        schema = schema.with_quality_rule(QualityRule {
+            rule_type: Some("library".to_string()),
+            metric: Some("duplicateValues".to_string()),
+            dimension: Some("uniqueness".to_string()),
+            description: Some(format!("{} is unique", key.name)),
+            must_be: Some(json!(0)),
+            extra: [("arguments".to_string(), json!({"properties": [key.name]}))]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        });
+    }
+    for (source, source_column, target, target_column, label) in RELATIONSHIPS {
+        if *source == table.name {
+            schema = schema.with_relationship(SchemaRelationship {
+                relationship_type: "foreignKey".to_string(),
+                from_properties: vec![source_column.to_string()],
+                to_schema: target.to_string(),
+                to_properties: vec![target_column.to_string()],
+                description: Some(label.to_string()),
+            });
+        }
+    }
+
+    ODCSContract::new_with_id(id.to_string(), table.name, "1.0.0")
+        .with_status("active")
+        .with_domain(domain)
+        .with_data_product(table.data_product)
+        .with_description(table.description)
+        .with_tags(vec!["demo".to_string(), domain.to_string()])
+        .with_schema(schema)
+        .with_contract_created_ts(created_at.to_rfc3339())
+}
+
+// ============================================================================
+// Sample data
+// ============================================================================
+
+const FIRST_NAMES: &[&str] = &[
+    "Anna", "Ben", "Clara", "David", "Elif", "Finn", "Greta", "Hannah", "Jonas", "Lea", "Mehmet",
+    "Noah", "Sofia", "Yusuf",
+];
+const LAST_NAMES: &[&str] = &[
+    "Schmidt", "Müller", "Weber", "Fischer", "Yilmaz", "Becker", "Hoffmann", "Nowak", "Rossi",
+    "Jansen",
+];
+const CITIES: &[&str] = &[
+    "Berlin",
+    "Hamburg",
+    "München",
+    "Köln",
+    "Leipzig",
+    "Wien",
+    "Zürich",
+    "Amsterdam",
+];
+const COUNTRIES: &[&str] = &["DE", "AT", "CH", "NL"];
+const PRODUCTS: &[&str] = &[
+    "Espresso Machine",
+    "Ceramic Mug",
+    "Coffee Grinder",
+    "Milk Frother",
+    "Pour-Over Kettle",
+    "Coffee Beans 1kg",
+    "Travel Tumbler",
+];
+const CATEGORIES: &[&str] = &["Machines", "Accessories", "Coffee", "Tableware"];
+const ORDER_STATUSES: &[&str] = &["placed", "paid", "shipped", "delivered", "cancelled"];
+const CARRIERS: &[&str] = &["DHL", "DPD", "UPS", "GLS"];
+
+/// How example values are generated for a column
+#[derive(Clone, Copy)]
+enum Example {
+    Id,
+    Email,
+    FullName,
+    City,
+    Country,
+    Product,
+    Category,
+    Sku,
+    Amount,
+    Quantity,
+    Date,
+    Timestamp,
+    OneOf(&'static [&'static str]),
+    Flag,
+}
+
+impl Example {
+    fn generate(self, rng: &mut SplitMix64, sequence: usize) -> Value {
+        match self {
+            Example::Id => json!(1000 + sequence as u64 * 7 + rng.below(7)),
+            Example::Email => {
+                let first = rng.pick(FIRST_NAMES).to_lowercase();
+                let last = rng.pick(LAST_NAMES).to_lowercase();
+                json!(format!("{}.{}@example.com", first, last))
+            }
+            Example::FullName => {
+                json!(format!(
+                    "{} {}",
+                    rng.pick(FIRST_NAMES),
+                    rng.pick(LAST_NAMES)
+                ))
+            }
+            Example::City => json!(rng.pick(CITIES)),
+            Example::Country => json!(rng.pick(COUNTRIES)),
+            Example::Product => json!(rng.pick(PRODUCTS)),
+            Example::Category => json!(rng.pick(CATEGORIES)),
+            Example::Sku => json!(format!("SKU-{:05}", rng.below(100_000))),
+            Example::Amount => json!(rng.between(299, 49_999) as f64 / 100.0),
+            Example::Quantity => json!(rng.between(1, 5)),
+            Example::Date => {
+                let day = base_timestamp() + Duration::days(rng.below(365) as i64);
+                json!(day.format("%Y-%m-%d").to_string())
+            }
+            Example::Timestamp => {
+                let at = base_timestamp() + Duration::minutes(rng.below(525_600) as i64);
+                json!(at.to_rfc3339())
+            }
+            Example::OneOf(values) => json!(rng.pick(values)),
+            Example::Flag => json!(rng.below(2) == 1),
+        }
+    }
+}
+
+struct ColumnSpec {
+    name: &'static str,
+    logical_type: &'static str,
+    primary_key: bool,
+    required: bool,
+    description: &'static str,
+    classification: Option<&'static str>,
+    /// Lower bound checked by a property-level quality rule
+    minimum: Option<u32>,
+    example: Example,
+}
+
+const fn key(name: &'static str, description: &'static str) -> ColumnSpec {
+    ColumnSpec {
+        name,
+        logical_type: "integer",
+        primary_key: true,
+        required: true,
+        description,
+        classification: None,
+        minimum: None,
+        example: Example::Id,
+    }
+}
+
+const fn column(
+    name: &'static str,
+    logical_type: &'static str,
+    description: &'static str,
+    example: Example,
+) -> ColumnSpec {
+    ColumnSpec {
+        name,
+        logical_type,
+        primary_key: false,
+        required: true,
+        description,
+        classification: None,
+        minimum: None,
+        example,
+    }
+}
+
+const fn optional(mut spec: ColumnSpec) -> ColumnSpec {
+    spec.required = false;
+    spec
+}
+
+const fn classified(mut spec: ColumnSpec, classification: &'static str) -> ColumnSpec {
+    spec.classification = Some(classification);
+    spec
+}
+
+const fn non_negative(mut spec: ColumnSpec) -> ColumnSpec {
+    spec.minimum = Some(0);
+    spec
+}
+
+struct TableSpec {
+    name: &'static str,
+    description: &'static str,
+    data_product: &'static str,
+    /// Upper bound of the seeded `rowCount` threshold
+    min_rows: u64,
+    columns: &'static [ColumnSpec],
+}
+
+struct DomainSpec {
+    name: &'static str,
+    description: &'static str,
+    system: &'static str,
+    system_description: &'static str,
+    tables: &'static [TableSpec],
+}
+
+const DOMAINS: &[DomainSpec] = &[
+    DomainSpec {
+        name: "customer",
+        description: "Customer profiles, addresses and consent",
+        system: "crm",
+        system_description: "Customer relationship management system",
+        tables: &[
+            TableSpec {
+                name: "customers",
+                description: "Registered shop customers",
+                data_product: "customer-360",
+                min_rows: 1000,
+                columns: &[
+                    key("customer_id", "Unique customer identifier"),
+                    classified(
+                        column("email", "string", "Primary contact email", Example::Email),
+                        "confidential",
+                    ),
+                    classified(
+                        column("full_name", "string", "Customer name", Example::FullName),
+                        "confidential",
+                    ),
+                    column(
+                        "marketing_consent",
+                        "boolean",
+                        "Customer agreed to marketing emails",
+                        Example::Flag,
+                    ),
+                    column(
+                        "created_at",
+                        "timestamp",
+                        "Registration timestamp",
+                        Example::Timestamp,
+                    ),
+                ],
+            },
+            TableSpec {
+                name: "addresses",
+                description: "Shipping and billing addresses of customers",
+                data_product: "customer-360",
+                min_rows: 1000,
+                columns: &[
+                    key("address_id", "Unique address identifier"),
+                    column(
+                        "customer_id",
+                        "integer",
+                        "Customer the address belongs to",
+                        Example::Id,
+                    ),
+                    classified(column("city", "string", "City", Example::City), "internal"),
+                    column(
+                        "country_code",
+                        "string",
+                        "ISO 3166-1 alpha-2 country code",
+                        Example::Country,
+                    ),
+                ],
+            },
+        ],
+    },
+    DomainSpec {
+        name: "catalog",
+        description: "Products, categories and pricing",
+        system: "pim",
+        system_description: "Product information management system",
+        tables: &[
+            TableSpec {
+                name: "categories",
+                description: "Product category hierarchy",
+                data_product: "product-catalog",
+                min_rows: 10,
+                columns: &[
+                    key("category_id", "Unique category identifier"),
+                    column("name", "string", "Category name", Example::Category),
+                ],
+            },
+            TableSpec {
+                name: "products",
+                description: "Sellable products",
+                data_product: "product-catalog",
+                min_rows: 100,
+                columns: &[
+                    key("product_id", "Unique product identifier"),
+                    column("sku", "string", "Stock keeping unit", Example::Sku),
+                    column("name", "string", "Product name", Example::Product),
+                    column(
+                        "category_id",
+                        "integer",
+                        "Category of the product",
+                        Example::Id,
+                    ),
+                    non_negative(column(
+                        "list_price",
+                        "number",
+                        "List price in EUR",
+                        Example::Amount,
+                    )),
+                ],
+            },
+        ],
+    },
+    DomainSpec {
+        name: "sales",
+        description: "Orders and order lines placed in the web shop",
+        system: "shop-db",
+        system_description: "Web shop transactional database",
+        tables: &[
+            TableSpec {
+                name: "orders",
+                description: "Customer orders",
+                data_product: "sales-orders",
+                min_rows: 5000,
+                columns: &[
+                    key("order_id", "Unique order identifier"),
+                    column("customer_id", "integer", "Ordering customer", Example::Id),
+                    column(
+                        "status",
+                        "string",
+                        "Order lifecycle status",
+                        Example::OneOf(ORDER_STATUSES),
+                    ),
+                    non_negative(column(
+                        "total_amount",
+                        "number",
+                        "Order total in EUR",
+                        Example::Amount,
+                    )),
+                    column(
+                        "order_date",
+                        "date",
+                        "Date the order was placed",
+                        Example::Date,
+                    ),
+                ],
+            },
+            TableSpec {
+                name: "order_items",
+                description: "Line items of customer orders",
+                data_product: "sales-orders",
+                min_rows: 10000,
+                columns: &[
+                    key("order_item_id", "Unique order line identifier"),
+                    column("order_id", "integer", "Order of the line", Example::Id),
+                    column("product_id", "integer", "Ordered product", Example::Id),
+                    non_negative(column(
+                        "quantity",
+                        "integer",
+                        "Ordered quantity",
+                        Example::Quantity,
+                    )),
+                    non_negative(column(
+                        "unit_price",
+                        "number",
+                        "Price per unit in EUR at order time",
+                        Example::Amount,
+                    )),
+                ],
+            },
+        ],
+    },
+    DomainSpec {
+        name: "fulfillment",
+        description: "Shipping and delivery of orders",
+        system: "wms",
+        system_description: "Warehouse management system",
+        tables: &[TableSpec {
+            name: "shipments",
+            description: "Parcels shipped for orders",
+            data_product: "order-fulfillment",
+            min_rows: 4000,
+            columns: &[
+                key("shipment_id", "Unique shipment identifier"),
+                column("order_id", "integer", "Shipped order", Example::Id),
+                column(
+                    "carrier",
+                    "string",
+                    "Parcel carrier",
+                    Example::OneOf(CARRIERS),
+                ),
+                column(
+                    "shipped_at",
+                    "timestamp",
+                    "Handover to the carrier",
+                    Example::Timestamp,
+                ),
+                optional(column(
+                    "delivered_at",
+                    "timestamp",
+                    "Delivery confirmation",
+                    Example::Timestamp,
+                )),
+            ],
+        }],
+    },
+];
+
+/// (source table, source column, target table, target column, label)
+const RELATIONSHIPS: &[(&str, &str, &str, &str, &str)] = &[
+    (
+        "addresses",
+        "customer_id",
+        "customers",
+        "customer_id",
+        "belongs to",
+    ),
+    (
+        "products",
+        "category_id",
+        "categories",
+        "category_id",
+        "is categorized as",
+    ),
+    (
+        "orders",
+        "customer_id",
+        "customers",
+        "customer_id",
+        "placed by",
+    ),
+    ("order_items", "order_id", "orders", "order_id", "part of"),
+    (
+        "order_items",
+        "product_id",
+        "products",
+        "product_id",
+        "refers to",
+    ),
+    ("shipments", "order_id", "orders", "order_id", "ships"),
+];
+
+struct DecisionSpec {
+    title: &'static str,
+    category: DecisionCategory,
+    domain: Option<&'static str>,
+    context: &'static str,
+    decision: &'static str,
+    consequences: &'static str,
+    drivers: &'static [&'static str],
+    /// Considered options; the first one is chosen
+    options: &'static [&'static str],
+    /// Tables affected by the decision
+    tables: &'static [&'static str],
+}
+
+const DECISIONS: &[DecisionSpec] = &[
+    DecisionSpec {
+        title: "Publish domain data as ODCS contracts",
+        category: DecisionCategory::Data,
+        domain: None,
+        context: "Downstream teams depend on shop data without a documented schema or quality guarantees.",
+        decision: "Every domain publishes its tables as ODCS v3.1.0 data contracts with quality rules.",
+        consequences: "Schema changes require a contract version bump and are visible to consumers.",
+        drivers: &[
+            "Consumers need stable schemas",
+            "Quality expectations must be explicit",
+        ],
+        options: &[
+            "ODCS data contracts",
+            "Wiki documentation",
+            "No formal contracts",
+        ],
+        tables: &[],
+    },
+    DecisionSpec {
+        title: "Use surrogate integer keys",
+        category: DecisionCategory::DataDesign,
+        domain: Some("sales"),
+        context: "Orders are referenced by shipments and order lines across domain boundaries.",
+        decision: "All tables use a surrogate integer primary key; natural keys such as SKUs are kept as unique attributes.",
+        consequences: "Joins across domains are cheap; natural keys can change without rewriting references.",
+        drivers: &["Stable cross-domain references", "Join performance"],
+        options: &["Surrogate integer keys", "Natural keys", "UUID keys"],
+        tables: &["orders", "order_items", "shipments"],
+    },
+    DecisionSpec {
+        title: "Classify customer contact data as confidential",
+        category: DecisionCategory::Security,
+        domain: Some("customer"),
+        context: "Customer emails and names are personal data under GDPR.",
+        decision: "Contact columns are classified as confidential and only exposed to the customer domain and approved consumers.",
+        consequences: "Analytics on customer data must use pseudonymized extracts.",
+        drivers: &["GDPR compliance", "Least-privilege access"],
+        options: &["Classify as confidential", "Classify as internal"],
+        tables: &["customers"],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::odcs::ODCSImporter;
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        let first = DemoGenerator::new(1).generate().unwrap();
+        let second = DemoGenerator::new(1).generate().unwrap();
+        assert_eq!(first.files, second.files);
+
+        let other = DemoGenerator::new(2).generate().unwrap();
+        assert_ne!(first.workspace.id, other.workspace.id);
+        assert_ne!(first.files, other.files);
+    }
+
+    #[test]
+    fn test_demo_workspace_is_consistent() {
+        let demo = generate_demo().unwrap();
+        let workspace = &demo.workspace;
+
+        assert_eq!(demo.contracts.len(), workspace.assets.len());
+        assert_eq!(workspace.relationships.len(), RELATIONSHIPS.len());
+        for relationship in &workspace.relationships {
+            assert!(
+                workspace
+                    .assets
+                    .iter()
+                    .any(|a| a.id == relationship.source_table_id)
+            );
+            assert!(
+                workspace
+                    .assets
+                    .iter()
+                    .any(|a| a.id == relationship.target_table_id)
+            );
+        }
+
+        for asset in &workspace.assets {
+            let path = asset.file_path.as_ref().unwrap();
+            let file = demo.files.iter().find(|f| &f.path == path).unwrap();
+            let (table, _) = ODCSImporter::new().parse_table(&file.content).unwrap();
+            assert_eq!(table.id, asset.id);
+        }
+
+        let orders = demo.contracts.iter().find(|c| c.name == "orders").unwrap();
+        assert!(!orders.schema[0].quality.is_empty());
+        assert_eq!(orders.schema[0].relationships.len(), 1);
+
+        assert_eq!(demo.decisions.len(), DECISIONS.len());
+        assert!(demo.files.iter().any(|f| f.path == "decisions.yaml"));
+        assert_eq!(
+            Workspace::from_yaml(&demo.files[0].content).unwrap(),
+            *workspace
+        );
+    }
+}
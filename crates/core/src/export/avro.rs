@@ -1,7 +1,7 @@
 //! AVRO schema exporter for generating AVRO schemas from data models.
 
 use super::{ExportError, ExportResult};
-use crate::models::{DataModel, Table};
+use crate::models::{Column, DataModel, Table, TypeMappingRegistry};
 use serde_json::{Value, json};
 
 /// Exporter for AVRO schema format.
@@ -35,7 +35,16 @@ impl AvroExporter {
     /// assert_eq!(result.format, "avro");
     /// ```
     pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
-        let schema = Self::export_model_from_tables(tables);
+        self.export_with_type_mappings(tables, TypeMappingRegistry::builtin())
+    }
+
+    /// Export tables to AVRO schema format, mapping data types with `type_mappings`.
+    pub fn export_with_type_mappings(
+        &self,
+        tables: &[Table],
+        type_mappings: &TypeMappingRegistry,
+    ) -> Result<ExportResult, ExportError> {
+        let schema = Self::export_model_from_tables(tables, type_mappings);
         let content = serde_json::to_string_pretty(&schema)
             .map_err(|e| ExportError::SerializationError(e.to_string()))?;
 
@@ -53,11 +62,17 @@ impl AvroExporter {
         })
     }
 
-    fn export_model_from_tables(tables: &[Table]) -> serde_json::Value {
+    fn export_model_from_tables(
+        tables: &[Table],
+        type_mappings: &TypeMappingRegistry,
+    ) -> serde_json::Value {
         if tables.len() == 1 {
-            Self::export_table(&tables[0])
+            Self::export_table_with_type_mappings(&tables[0], type_mappings)
         } else {
-            let schemas: Vec<serde_json::Value> = tables.iter().map(Self::export_table).collect();
+            let schemas: Vec<serde_json::Value> = tables
+                .iter()
+                .map(|t| Self::export_table_with_type_mappings(t, type_mappings))
+                .collect();
             serde_json::json!(schemas)
         }
     }
@@ -88,6 +103,14 @@ impl AvroExporter {
     /// assert_eq!(schema["name"], "User");
     /// ```
     pub fn export_table(table: &Table) -> Value {
+        Self::export_table_with_type_mappings(table, TypeMappingRegistry::builtin())
+    }
+
+    /// Export a table to AVRO schema format, mapping data types with `type_mappings`.
+    pub fn export_table_with_type_mappings(
+        table: &Table,
+        type_mappings: &TypeMappingRegistry,
+    ) -> Value {
         let mut fields = Vec::new();
 
         for column in &table.columns {
@@ -95,12 +118,15 @@ impl AvroExporter {
             field.insert("name".to_string(), json!(column.name));
 
             // Map data type to AVRO type
-            let avro_type = Self::map_data_type_to_avro(&column.data_type, column.nullable);
+            let (avro_type, default) = Self::map_column_to_avro(column, type_mappings);
             field.insert("type".to_string(), avro_type);
 
             if !column.description.is_empty() {
                 field.insert("doc".to_string(), json!(column.description));
             }
+            if let Some(default) = default {
+                field.insert("default".to_string(), default);
+            }
 
             fields.push(json!(field));
         }
@@ -108,6 +134,14 @@ impl AvroExporter {
         let mut schema = serde_json::Map::new();
         schema.insert("type".to_string(), json!("record"));
         schema.insert("name".to_string(), json!(table.name));
+        if let Some(doc) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(|v| v.as_str())
+            .filter(|d| !d.is_empty())
+        {
+            schema.insert("doc".to_string(), json!(doc));
+        }
 
         // Add tags if present (AVRO doesn't have standard tags, but we can add them as metadata)
         if !table.tags.is_empty() {
@@ -145,27 +179,239 @@ impl AvroExporter {
         }
     }
 
-    /// Map SQL/ODCL data types to AVRO types.
-    fn map_data_type_to_avro(data_type: &str, nullable: bool) -> Value {
-        let dt_lower = data_type.to_lowercase();
-
-        let avro_type = match dt_lower.as_str() {
-            "int" | "integer" | "smallint" | "tinyint" => json!("int"),
-            "bigint" => json!("long"),
-            "float" | "real" => json!("float"),
-            "double" | "decimal" | "numeric" => json!("double"),
-            "boolean" | "bool" => json!("boolean"),
-            "bytes" | "binary" | "varbinary" => json!("bytes"),
-            _ => {
-                // Default to string for VARCHAR, TEXT, CHAR, DATE, TIMESTAMP, etc.
-                json!("string")
+    /// Map a column to its AVRO type and field default.
+    ///
+    /// Nullable columns become a union with `null`. The union branch matching the
+    /// column's `default_value` comes first, as AVRO requires; nullable columns
+    /// without a usable default get `null` as default so readers can evolve the
+    /// schema.
+    fn map_column_to_avro(
+        column: &Column,
+        type_mappings: &TypeMappingRegistry,
+    ) -> (Value, Option<Value>) {
+        let avro_type = Self::map_column_type(column, type_mappings);
+        let default = column
+            .default_value
+            .as_ref()
+            .filter(|v| !v.is_null())
+            .and_then(|v| avro_default(&avro_type, v));
+
+        match (column.nullable, default) {
+            (true, Some(default)) => (json!([avro_type, "null"]), Some(default)),
+            (true, None) => (json!(["null", avro_type]), Some(Value::Null)),
+            (false, default) => (avro_type, default),
+        }
+    }
+
+    /// Map a column to its non-null AVRO type, preferring logical types where possible.
+    ///
+    /// AVRO types preserved by the importer because they had no mapping are
+    /// written back as-is.
+    fn map_column_type(column: &Column, type_mappings: &TypeMappingRegistry) -> Value {
+        Self::map_logical_type_to_avro(column)
+            .or_else(|| {
+                let preserved = type_mappings.preserved_type("avro", column)?;
+                Some(if column.data_type.to_uppercase().starts_with("ARRAY<") {
+                    json!({"type": "array", "items": preserved})
+                } else {
+                    json!(preserved)
+                })
+            })
+            .unwrap_or_else(|| Self::map_data_type_to_avro(&column.data_type, type_mappings))
+    }
+
+    /// Map a column to an AVRO logical type (decimal, date, time, timestamp, uuid).
+    ///
+    /// Precision and scale for decimals come from `logicalTypeOptions` or the
+    /// data type parameters (e.g., `DECIMAL(10,2)`). A `format` option such as
+    /// `timestamp-millis` selects the time unit; micros is used by default.
+    fn map_logical_type_to_avro(column: &Column) -> Option<Value> {
+        let dt_lower = column.data_type.trim().to_lowercase();
+        let base = dt_lower.split('(').next().unwrap_or("").trim();
+        let options = column.logical_type_options.as_ref();
+        let format = options
+            .and_then(|o| o.format.as_deref())
+            .map(|f| f.to_lowercase());
+        let millis = format.as_deref().is_some_and(|f| f.ends_with("-millis"));
+
+        match base {
+            "decimal" | "numeric" | "number" => {
+                let (type_precision, type_scale) = parse_type_params(&dt_lower);
+                let precision = options.and_then(|o| o.precision).or(type_precision)?;
+                let scale = options.and_then(|o| o.scale).or(type_scale).unwrap_or(0);
+                Some(json!({
+                    "type": "bytes",
+                    "logicalType": "decimal",
+                    "precision": precision,
+                    "scale": scale
+                }))
             }
+            "date" => Some(json!({"type": "int", "logicalType": "date"})),
+            "time" => Some(if millis {
+                json!({"type": "int", "logicalType": "time-millis"})
+            } else {
+                json!({"type": "long", "logicalType": "time-micros"})
+            }),
+            "timestamp"
+            | "timestamp_ltz"
+            | "timestamptz"
+            | "datetime"
+            | "timestamp with time zone" => Some(json!({
+                "type": "long",
+                "logicalType": if millis { "timestamp-millis" } else { "timestamp-micros" }
+            })),
+            "timestamp_ntz" | "timestamp without time zone" => Some(json!({
+                "type": "long",
+                "logicalType": if millis { "local-timestamp-millis" } else { "local-timestamp-micros" }
+            })),
+            "uuid" => Some(json!({"type": "string", "logicalType": "uuid"})),
+            _ if format.as_deref() == Some("uuid") => {
+                Some(json!({"type": "string", "logicalType": "uuid"}))
+            }
+            _ => None,
+        }
+    }
+
+    /// Map SQL/ODCL data types to AVRO primitive types.
+    fn map_data_type_to_avro(data_type: &str, type_mappings: &TypeMappingRegistry) -> Value {
+        // Default to string for VARCHAR, TEXT, CHAR, etc.
+        json!(
+            type_mappings
+                .export_type("avro", data_type)
+                .unwrap_or_else(|| "string".to_string())
+        )
+    }
+}
+
+/// Encode a column default as a default value of `avro_type`.
+///
+/// Dates and times given as ISO strings are converted to their AVRO
+/// representation (days, milli- or microseconds) and decimals to the bytes of
+/// their unscaled value. Returns `None` for SQL expressions (e.g. `` `now()` ``)
+/// and values that do not fit the type.
+fn avro_default(avro_type: &Value, value: &Value) -> Option<Value> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    let text = value.as_str().map(str::trim);
+    if text.is_some_and(|t| t.starts_with('`')) {
+        return None;
+    }
+
+    if let Some(logical_type) = avro_type.get("logicalType").and_then(|t| t.as_str()) {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+        let timestamp = || {
+            let text = text?;
+            DateTime::parse_from_rfc3339(text)
+                .map(|t| t.naive_utc())
+                .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f"))
+                .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+                .ok()
+        };
+        let time = || NaiveTime::parse_from_str(text?, "%H:%M:%S%.f").ok();
+        return match logical_type {
+            "date" => match text {
+                Some(text) => {
+                    let date = NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()?;
+                    Some(json!((date - epoch).num_days()))
+                }
+                None => value.as_i64().map(|days| json!(days)),
+            },
+            "timestamp-millis" | "local-timestamp-millis" => match timestamp() {
+                Some(t) => Some(json!(t.and_utc().timestamp_millis())),
+                None => value.as_i64().map(|v| json!(v)),
+            },
+            "timestamp-micros" | "local-timestamp-micros" => match timestamp() {
+                Some(t) => Some(json!(t.and_utc().timestamp_micros())),
+                None => value.as_i64().map(|v| json!(v)),
+            },
+            "time-millis" => time()
+                .map(|t| {
+                    json!(
+                        t.num_seconds_from_midnight() as i64 * 1_000
+                            + t.nanosecond() as i64 / 1_000_000
+                    )
+                })
+                .or_else(|| value.as_i64().map(|v| json!(v))),
+            "time-micros" => time()
+                .map(|t| {
+                    json!(
+                        t.num_seconds_from_midnight() as i64 * 1_000_000
+                            + t.nanosecond() as i64 / 1_000
+                    )
+                })
+                .or_else(|| value.as_i64().map(|v| json!(v))),
+            "decimal" => {
+                let scale = avro_type.get("scale").and_then(|s| s.as_u64()).unwrap_or(0);
+                let number = match value {
+                    Value::Number(n) => n.to_string(),
+                    _ => text?.to_string(),
+                };
+                decimal_bytes(&number, scale as usize).map(Value::String)
+            }
+            "uuid" => text.map(|t| json!(t)),
+            _ => None,
         };
+    }
 
-        if nullable {
-            json!(["null", avro_type])
-        } else {
-            avro_type
-        }
+    match avro_type.as_str()? {
+        "string" => match value {
+            Value::String(s) => Some(json!(s)),
+            Value::Number(_) | Value::Bool(_) => Some(json!(value.to_string())),
+            _ => None,
+        },
+        "bytes" => text.map(|t| json!(t)),
+        "int" | "long" => value
+            .as_i64()
+            .or_else(|| text?.parse().ok())
+            .map(|v| json!(v)),
+        "float" | "double" => value
+            .as_f64()
+            .or_else(|| text?.parse().ok())
+            .map(|v| json!(v)),
+        "boolean" => value
+            .as_bool()
+            .or_else(|| text?.to_lowercase().parse().ok())
+            .map(|v| json!(v)),
+        _ => None,
+    }
+}
+
+/// Big-endian two's complement bytes of a decimal's unscaled value, as the
+/// ISO-8859-1 string AVRO uses for `bytes` defaults.
+fn decimal_bytes(number: &str, scale: usize) -> Option<String> {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if fraction.len() > scale {
+        return None;
+    }
+    let digits = format!(
+        "{}{}{}",
+        whole,
+        fraction,
+        "0".repeat(scale - fraction.len())
+    );
+    let unscaled: i128 = digits.parse().ok()?;
+
+    let bytes = unscaled.to_be_bytes();
+    // Drop redundant sign-extension bytes
+    let mut start = 0;
+    while start < bytes.len() - 1
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
     }
+    Some(bytes[start..].iter().map(|&b| b as char).collect())
+}
+
+/// Parse `(precision, scale)` parameters from a type such as `decimal(10,2)`.
+fn parse_type_params(data_type: &str) -> (Option<i32>, Option<i32>) {
+    let Some(params) = data_type
+        .split_once('(')
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .map(|(params, _)| params)
+    else {
+        return (None, None);
+    };
+    let mut parts = params.split(',').map(|p| p.trim().parse::<i32>().ok());
+    (parts.next().flatten(), parts.next().flatten())
 }
@@ -0,0 +1,472 @@
+//! Backstage software catalog exporter
+//!
+//! Generates a multi-document `catalog-info.yaml` compatible with the Backstage catalog
+//! model so the workspace shows up in a developer portal:
+//!
+//! - **Domain** - one per workspace domain
+//! - **System** - one per domain system, attached to its domain
+//! - **API** - one per ODCS contract (type `data-contract`, the contract YAML as its
+//!   definition), attached to the system listing the contract's table
+//! - **Resource** - one per contract table (type `table`), with `dependsOn` relations
+//!   to upstream tables derived from the workspace relationships
+//! - **Component** - one per CADS asset (applications, pipelines, models), attached to
+//!   the system listing the asset
+//!
+//! Entity names follow the Backstage naming rules (see [`entity_name`]); owners fall back
+//! to [`BackstageExporter::with_default_owner`] when the model has none.
+
+use super::{ExportError, ExportResult};
+use crate::models::cads::{CADSAsset, CADSKind, CADSStatus};
+use crate::models::enums::{FlowDirection, RelationshipType};
+use crate::models::odcs::ODCSContract;
+use crate::models::workspace::{SystemReference, Workspace};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+/// Backstage catalog API version of the generated entities
+pub(crate) const BACKSTAGE_API_VERSION: &str = "backstage.io/v1alpha1";
+
+/// Annotation carrying the ODCS contract id on Backstage entities
+pub(crate) const ODCS_ID_ANNOTATION: &str = "data-modelling/odcs-id";
+
+/// Annotation carrying the workspace domain/system id on Backstage entities
+const WORKSPACE_ID_ANNOTATION: &str = "data-modelling/id";
+
+/// Annotation carrying the CADS asset id on Backstage entities
+const CADS_ID_ANNOTATION: &str = "data-modelling/cads-id";
+
+/// Exporter for Backstage catalog entities derived from a workspace.
+#[derive(Debug, Clone)]
+pub struct BackstageExporter {
+    default_owner: String,
+}
+
+impl Default for BackstageExporter {
+    fn default() -> Self {
+        Self {
+            default_owner: "unknown".to_string(),
+        }
+    }
+}
+
+impl BackstageExporter {
+    /// Create a new exporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Owner used for entities without an owning team
+    pub fn with_default_owner(mut self, owner: impl Into<String>) -> Self {
+        self.default_owner = owner.into();
+        self
+    }
+
+    /// Export the Backstage catalog as YAML (SDK interface).
+    pub fn export(
+        &self,
+        workspace: &Workspace,
+        contracts: &[ODCSContract],
+        assets: &[CADSAsset],
+    ) -> Result<ExportResult, ExportError> {
+        Ok(ExportResult {
+            content: self.export_catalog(workspace, contracts, assets)?,
+            format: "backstage".to_string(),
+        })
+    }
+
+    /// Export a multi-document Backstage `catalog-info.yaml`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::backstage::BackstageExporter;
+    /// use data_modelling_core::models::odcs::ODCSContract;
+    /// use data_modelling_core::models::workspace::Workspace;
+    /// use uuid::Uuid;
+    ///
+    /// let mut workspace = Workspace::new("demo".to_string(), Uuid::new_v4());
+    /// workspace.add_domain(Uuid::new_v4(), "sales".to_string());
+    /// let contract = ODCSContract::new_with_id(Uuid::new_v4().to_string(), "orders", "1.0.0");
+    ///
+    /// let yaml = BackstageExporter::new()
+    ///     .export_catalog(&workspace, &[contract], &[])
+    ///     .unwrap();
+    /// assert!(yaml.contains("kind: Domain"));
+    /// assert!(yaml.contains("kind: API"));
+    /// ```
+    pub fn export_catalog(
+        &self,
+        workspace: &Workspace,
+        contracts: &[ODCSContract],
+        assets: &[CADSAsset],
+    ) -> Result<String, ExportError> {
+        let entities = self.build_entities(workspace, contracts, assets);
+        if entities.is_empty() {
+            return Err(ExportError::ValidationError(
+                "Workspace has no domains, contracts or assets to export to Backstage".to_string(),
+            ));
+        }
+        to_yaml_documents(&entities)
+    }
+
+    /// Build the catalog entities as JSON values, in dependency order
+    pub fn build_entities(
+        &self,
+        workspace: &Workspace,
+        contracts: &[ODCSContract],
+        assets: &[CADSAsset],
+    ) -> Vec<Value> {
+        let mut entities = Vec::new();
+        let systems: Vec<(&str, &SystemReference)> = workspace
+            .domains
+            .iter()
+            .flat_map(|domain| {
+                domain
+                    .systems
+                    .iter()
+                    .map(move |system| (domain.name.as_str(), system))
+            })
+            .collect();
+
+        for domain in &workspace.domains {
+            let mut metadata = json!({
+                "name": entity_name(&domain.name),
+                "title": domain.name,
+                "annotations": { WORKSPACE_ID_ANNOTATION: domain.id.to_string() },
+            });
+            insert_optional(&mut metadata, "description", domain.description.clone());
+            entities.push(entity(
+                "Domain",
+                metadata,
+                json!({ "owner": self.default_owner }),
+            ));
+        }
+
+        for (domain, system) in &systems {
+            let mut metadata = json!({
+                "name": entity_name(&system.name),
+                "title": system.name,
+                "annotations": { WORKSPACE_ID_ANNOTATION: system.id.to_string() },
+            });
+            insert_optional(&mut metadata, "description", system.description.clone());
+            if let Some(tag) = system
+                .system_type
+                .as_ref()
+                .and_then(|system_type| serde_json::to_value(system_type).ok())
+                .and_then(|value| value.as_str().map(|s| entity_name(s).to_lowercase()))
+            {
+                metadata["tags"] = json!([tag]);
+            }
+            let owner = system
+                .environments
+                .iter()
+                .find_map(|environment| environment.owner.as_deref())
+                .map(entity_name)
+                .unwrap_or_else(|| self.default_owner.clone());
+            entities.push(entity(
+                "System",
+                metadata,
+                json!({ "owner": owner, "domain": entity_name(domain) }),
+            ));
+        }
+
+        // Resource names keyed by table id, used to resolve lineage relations
+        let resources: BTreeMap<Uuid, String> = contracts
+            .iter()
+            .filter_map(|contract| {
+                Uuid::parse_str(&contract.id)
+                    .ok()
+                    .map(|id| (id, entity_name(&contract.name)))
+            })
+            .collect();
+        let upstream = upstream_tables(workspace);
+
+        for contract in contracts {
+            let name = entity_name(&contract.name);
+            let table_id = Uuid::parse_str(&contract.id).ok();
+            let system = table_id.and_then(|id| {
+                systems
+                    .iter()
+                    .find(|(_, system)| system.table_ids.contains(&id))
+                    .map(|(_, system)| entity_name(&system.name))
+            });
+            let owner = contract
+                .team
+                .as_ref()
+                .and_then(|team| team.name.as_deref())
+                .map(entity_name)
+                .unwrap_or_else(|| self.default_owner.clone());
+            let description = contract
+                .description
+                .as_ref()
+                .map(|description| description.as_string())
+                .filter(|description| !description.is_empty());
+            let definition = serde_yaml::to_string(contract).unwrap_or_default();
+
+            let mut metadata = json!({
+                "name": name,
+                "title": contract.name,
+                "annotations": { ODCS_ID_ANNOTATION: contract.id },
+            });
+            insert_optional(&mut metadata, "description", description);
+            let mut spec = json!({
+                "type": "data-contract",
+                "lifecycle": contract_lifecycle(contract.status.as_deref()),
+                "owner": owner,
+                "definition": definition,
+            });
+            insert_optional(&mut spec, "system", system.clone());
+            entities.push(entity("API", metadata.clone(), spec));
+
+            let mut spec = json!({ "type": "table", "owner": owner });
+            insert_optional(&mut spec, "system", system);
+            let depends_on: Vec<String> = table_id
+                .and_then(|id| upstream.get(&id))
+                .into_iter()
+                .flatten()
+                .filter_map(|id| resources.get(id))
+                .map(|resource| format!("resource:{}", resource))
+                .collect();
+            if !depends_on.is_empty() {
+                spec["dependsOn"] = json!(depends_on);
+            }
+            entities.push(entity("Resource", metadata, spec));
+        }
+
+        for asset in assets {
+            let mut metadata = json!({
+                "name": entity_name(&asset.name),
+                "title": asset.name,
+                "annotations": { CADS_ID_ANNOTATION: asset.id },
+            });
+            insert_optional(
+                &mut metadata,
+                "description",
+                asset
+                    .description
+                    .as_ref()
+                    .and_then(|description| description.purpose.clone()),
+            );
+            let tags: Vec<String> = asset
+                .tags
+                .iter()
+                .map(|tag| entity_name(&tag.to_string()).to_lowercase())
+                .filter(|tag| !tag.is_empty())
+                .collect();
+            if !tags.is_empty() {
+                metadata["tags"] = json!(tags);
+            }
+
+            let owner = asset
+                .team
+                .iter()
+                .flatten()
+                .find(|member| member.role.to_lowercase().contains("owner"))
+                .or_else(|| asset.team.iter().flatten().next())
+                .map(|member| entity_name(&member.name))
+                .unwrap_or_else(|| self.default_owner.clone());
+            let mut spec = json!({
+                "type": component_type(asset.kind),
+                "lifecycle": asset_lifecycle(asset.status),
+                "owner": owner,
+            });
+            insert_optional(
+                &mut spec,
+                "system",
+                systems
+                    .iter()
+                    .find(|(_, system)| {
+                        system.asset_ids.iter().any(|id| id.to_string() == asset.id)
+                    })
+                    .map(|(_, system)| entity_name(&system.name)),
+            );
+            entities.push(entity("Component", metadata, spec));
+        }
+
+        entities
+    }
+}
+
+/// Serialize catalog entities as a multi-document YAML stream
+pub(crate) fn to_yaml_documents(entities: &[Value]) -> Result<String, ExportError> {
+    entities
+        .iter()
+        .map(|entity| {
+            serde_yaml::to_string(entity)
+                .map_err(|e| ExportError::SerializationError(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|documents| documents.join("---\n"))
+}
+
+/// Backstage entity name: `[A-Za-z0-9]` separated by `-`, at most 63 characters
+pub(crate) fn entity_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .take(63)
+        .collect::<String>()
+        .trim_end_matches('-')
+        .to_string()
+}
+
+pub(crate) fn insert_optional(target: &mut Value, key: &str, value: Option<String>) {
+    if let Some(value) = value {
+        target[key] = json!(value);
+    }
+}
+
+fn entity(kind: &str, metadata: Value, spec: Value) -> Value {
+    json!({
+        "apiVersion": BACKSTAGE_API_VERSION,
+        "kind": kind,
+        "metadata": metadata,
+        "spec": spec,
+    })
+}
+
+/// Upstream tables of every table, following the relationship semantics
+///
+/// Foreign keys and dependencies point from the dependent table to the table it
+/// depends on; data flows point downstream unless their flow direction says otherwise.
+fn upstream_tables(workspace: &Workspace) -> BTreeMap<Uuid, BTreeSet<Uuid>> {
+    let mut upstream: BTreeMap<Uuid, BTreeSet<Uuid>> = BTreeMap::new();
+    for relationship in &workspace.relationships {
+        let (source, target) = (relationship.source_table_id, relationship.target_table_id);
+        let edge = match (relationship.relationship_type, relationship.flow_direction) {
+            (_, Some(FlowDirection::Bidirectional)) => None,
+            (Some(RelationshipType::ForeignKey | RelationshipType::Dependency), _) => {
+                Some((source, target))
+            }
+            (_, Some(FlowDirection::TargetToSource)) => Some((source, target)),
+            _ => Some((target, source)),
+        };
+        if let Some((downstream, upstream_id)) = edge
+            && downstream != upstream_id
+        {
+            upstream.entry(downstream).or_default().insert(upstream_id);
+        }
+    }
+    upstream
+}
+
+/// Backstage lifecycle for an ODCS contract status
+fn contract_lifecycle(status: Option<&str>) -> &'static str {
+    match status.map(str::to_lowercase).as_deref() {
+        Some("active" | "production") => "production",
+        Some("deprecated" | "retired") => "deprecated",
+        _ => "experimental",
+    }
+}
+
+/// Backstage lifecycle for a CADS asset status
+fn asset_lifecycle(status: CADSStatus) -> &'static str {
+    match status {
+        CADSStatus::Production => "production",
+        CADSStatus::Draft | CADSStatus::Validated => "experimental",
+        CADSStatus::Deprecated => "deprecated",
+    }
+}
+
+/// Backstage component type for a CADS asset kind
+fn component_type(kind: CADSKind) -> &'static str {
+    match kind {
+        CADSKind::AIModel => "ml-model",
+        CADSKind::MLPipeline => "ml-pipeline",
+        CADSKind::Application => "service",
+        CADSKind::DataPipeline | CADSKind::ETLProcess | CADSKind::ETLPipeline => "data-pipeline",
+        CADSKind::SourceSystem | CADSKind::DestinationSystem => "external-system",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::relationship::Relationship;
+
+    fn kinds(entities: &[Value]) -> Vec<(&str, &str)> {
+        entities
+            .iter()
+            .map(|e| {
+                (
+                    e["kind"].as_str().unwrap(),
+                    e["metadata"]["name"].as_str().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build_entities_from_workspace() {
+        let mut workspace = Workspace::new("demo".to_string(), Uuid::new_v4());
+        workspace.add_domain(Uuid::new_v4(), "Sales Ops".to_string());
+        workspace.add_system_to_domain("Sales Ops", Uuid::new_v4(), "crm db".to_string(), None);
+
+        let orders = Uuid::new_v4();
+        let customers = Uuid::new_v4();
+        workspace.domains[0].systems[0].table_ids = vec![orders, customers];
+        let mut relationship = Relationship::new(orders, customers);
+        relationship.relationship_type = Some(RelationshipType::ForeignKey);
+        workspace.relationships.push(relationship);
+
+        let mut orders_contract = ODCSContract::new_with_id(orders.to_string(), "orders", "1.0.0");
+        orders_contract.status = Some("active".to_string());
+        let customers_contract =
+            ODCSContract::new_with_id(customers.to_string(), "customers", "1.0.0");
+
+        let asset: CADSAsset = serde_yaml::from_str(
+            "apiVersion: v1.0\nkind: ETLPipeline\nid: pipe-1\nname: Orders Loader\n\
+             version: 1.0.0\nstatus: production\nteam:\n  - role: owner\n    name: data team\n",
+        )
+        .unwrap();
+
+        let entities = BackstageExporter::new()
+            .with_default_owner("platform")
+            .build_entities(&workspace, &[orders_contract, customers_contract], &[asset]);
+
+        assert_eq!(
+            kinds(&entities),
+            vec![
+                ("Domain", "Sales-Ops"),
+                ("System", "crm-db"),
+                ("API", "orders"),
+                ("Resource", "orders"),
+                ("API", "customers"),
+                ("Resource", "customers"),
+                ("Component", "Orders-Loader"),
+            ]
+        );
+        assert_eq!(entities[1]["spec"]["domain"], "Sales-Ops");
+        assert_eq!(entities[1]["spec"]["owner"], "platform");
+        assert_eq!(entities[2]["spec"]["system"], "crm-db");
+        assert_eq!(entities[2]["spec"]["lifecycle"], "production");
+        assert_eq!(
+            entities[3]["spec"]["dependsOn"],
+            json!(["resource:customers"])
+        );
+        assert!(entities[5]["spec"].get("dependsOn").is_none());
+        assert_eq!(entities[6]["spec"]["type"], "data-pipeline");
+        assert_eq!(entities[6]["spec"]["owner"], "data-team");
+    }
+
+    #[test]
+    fn test_export_catalog_requires_entities() {
+        let workspace = Workspace::new("empty".to_string(), Uuid::new_v4());
+        let exporter = BackstageExporter::new();
+        assert!(exporter.export_catalog(&workspace, &[], &[]).is_err());
+
+        let contract = ODCSContract::new_with_id("orders-v1", "orders", "1.0.0");
+        let yaml = exporter
+            .export_catalog(&workspace, &[contract], &[])
+            .unwrap();
+        assert_eq!(yaml.matches("---\n").count(), 1);
+        assert!(yaml.contains("type: data-contract"));
+        assert!(yaml.contains("type: table"));
+    }
+}
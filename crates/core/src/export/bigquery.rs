@@ -0,0 +1,314 @@
+//! BigQuery JSON table schema exporter
+//!
+//! Writes tables as the [JSON schema files](https://cloud.google.com/bigquery/docs/schemas#specifying_a_json_schema_file)
+//! accepted by `bq load --schema` and `bq mk --table`:
+//!
+//! - column types are mapped with the `bigquery` dialect of the SQL exporter and
+//!   written with the type names `bq show --schema` uses (`INTEGER`, `FLOAT`,
+//!   `BOOLEAN`, `RECORD`)
+//! - `STRUCT<...>` types and nested columns (`parent.child`, `parent.[].child`)
+//!   become `RECORD` fields, and `ARRAY<...>` types `REPEATED` fields
+//! - `MAP<K, V>` types become repeated `key`/`value` records, as BigQuery has no map type
+//!
+//! A single table is written as an array of fields, several tables as an object
+//! of field arrays keyed by table name.
+
+use super::sql::SQLExporter;
+use super::{ExportError, ExportResult};
+use crate::models::{Column, NestedType, Table, TypeMappingRegistry};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// A field of a BigQuery table schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BigQueryField {
+    /// Field name
+    pub name: String,
+    /// Field type (e.g. `STRING`, `INTEGER`, `RECORD`)
+    #[serde(rename = "type")]
+    pub field_type: String,
+    /// `NULLABLE`, `REQUIRED` or `REPEATED`
+    pub mode: String,
+    /// Field description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Maximum length of `STRING` and `BYTES` fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<String>,
+    /// Precision of `NUMERIC` and `BIGNUMERIC` fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precision: Option<String>,
+    /// Scale of `NUMERIC` and `BIGNUMERIC` fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<String>,
+    /// Fields of a `RECORD`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<BigQueryField>,
+}
+
+/// Exporter for BigQuery JSON table schemas.
+#[derive(Debug, Clone, Default)]
+pub struct BigQuerySchemaExporter {
+    type_mappings: TypeMappingRegistry,
+}
+
+impl BigQuerySchemaExporter {
+    /// Create a new BigQuery schema exporter with the default type mappings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map column types with a custom type mapping registry
+    pub fn with_type_mappings(mut self, type_mappings: TypeMappingRegistry) -> Self {
+        self.type_mappings = type_mappings;
+        self
+    }
+
+    /// Export tables to BigQuery JSON table schemas (SDK interface).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::bigquery::BigQuerySchemaExporter;
+    /// use data_modelling_core::models::{Column, Table};
+    ///
+    /// let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+    /// id.nullable = false;
+    /// let table = Table::new("users".to_string(), vec![id]);
+    ///
+    /// let result = BigQuerySchemaExporter::new().export(&[table]).unwrap();
+    /// assert!(result.content.contains("\"type\": \"INTEGER\""));
+    /// assert!(result.content.contains("\"mode\": \"REQUIRED\""));
+    /// ```
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        let content = match tables {
+            [table] => serde_json::to_string_pretty(&self.table_schema(table)),
+            _ => serde_json::to_string_pretty(
+                &tables
+                    .iter()
+                    .map(|table| (table.name.as_str(), self.table_schema(table)))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+        }
+        .map_err(|e| ExportError::SerializationError(e.to_string()))?;
+        Ok(ExportResult {
+            content,
+            format: "bigquery".to_string(),
+        })
+    }
+
+    /// Schema fields of a table
+    pub fn table_schema(&self, table: &Table) -> Vec<BigQueryField> {
+        self.column_fields(&table.columns, "")
+    }
+
+    /// Fields of the columns directly under `prefix`
+    fn column_fields(&self, columns: &[Column], prefix: &str) -> Vec<BigQueryField> {
+        columns
+            .iter()
+            .filter(|c| {
+                c.name
+                    .strip_prefix(prefix)
+                    .is_some_and(|name| !name.is_empty() && !name.contains('.'))
+            })
+            .map(|column| {
+                let name = column.name[prefix.len()..].to_string();
+                let description =
+                    (!column.description.is_empty()).then(|| column.description.clone());
+                let mut children = self.column_fields(columns, &format!("{}.", column.name));
+                children.extend(self.column_fields(columns, &format!("{}.[].", column.name)));
+
+                let mut field = if children.is_empty() {
+                    let data_type = match NestedType::parse(&column.data_type) {
+                        Some(nested) => nested.maps_as_entries(),
+                        None => NestedType::Scalar(column.data_type.clone()),
+                    };
+                    self.field(&name, &data_type)
+                } else {
+                    let repeated = column.data_type.trim().to_uppercase().starts_with("ARRAY");
+                    BigQueryField {
+                        fields: children,
+                        ..record(&name, repeated)
+                    }
+                };
+                if field.mode == "NULLABLE" && !column.nullable {
+                    field.mode = "REQUIRED".to_string();
+                }
+                field.description = description;
+                field
+            })
+            .collect()
+    }
+
+    /// Nullable field of a (nested) data type
+    fn field(&self, name: &str, data_type: &NestedType) -> BigQueryField {
+        match data_type {
+            NestedType::Scalar(scalar) => self.scalar_field(name, scalar),
+            NestedType::Struct(struct_fields) => BigQueryField {
+                fields: struct_fields
+                    .iter()
+                    .map(|f| self.field(&f.name, &f.data_type))
+                    .collect(),
+                ..record(name, false)
+            },
+            // BigQuery has no arrays of arrays, so nested arrays repeat their innermost element
+            NestedType::Array(element) => BigQueryField {
+                mode: "REPEATED".to_string(),
+                ..self.field(name, element)
+            },
+            NestedType::Map(..) => self.field(name, &data_type.maps_as_entries()),
+        }
+    }
+
+    /// Nullable field of a scalar data type
+    fn scalar_field(&self, name: &str, data_type: &str) -> BigQueryField {
+        let native = SQLExporter::scalar_type(data_type, "bigquery", &self.type_mappings);
+        let (base, params) = split_params(&native);
+        let field_type = match base.as_str() {
+            "INT64" => "INTEGER",
+            "FLOAT64" => "FLOAT",
+            "BOOL" => "BOOLEAN",
+            "STRUCT" => "RECORD",
+            other => other,
+        }
+        .to_string();
+
+        let mut field = BigQueryField {
+            name: name.to_string(),
+            field_type,
+            mode: "NULLABLE".to_string(),
+            description: None,
+            max_length: None,
+            precision: None,
+            scale: None,
+            fields: Vec::new(),
+        };
+        match field.field_type.as_str() {
+            "STRING" | "BYTES" => {
+                // Type mappings drop the length of VARCHAR(n) and similar types
+                let (_, original) = split_params(data_type);
+                field.max_length = params.or(original).filter(|p| !p.contains(','));
+            }
+            "NUMERIC" | "BIGNUMERIC" => {
+                if let Some(params) = params {
+                    let mut parts = params.split(',').map(|p| p.trim().to_string());
+                    field.precision = parts.next();
+                    field.scale = parts.next();
+                }
+            }
+            _ => {}
+        }
+        field
+    }
+}
+
+/// Empty `RECORD` field
+fn record(name: &str, repeated: bool) -> BigQueryField {
+    BigQueryField {
+        name: name.to_string(),
+        field_type: "RECORD".to_string(),
+        mode: if repeated { "REPEATED" } else { "NULLABLE" }.to_string(),
+        description: None,
+        max_length: None,
+        precision: None,
+        scale: None,
+        fields: Vec::new(),
+    }
+}
+
+/// Split `NAME(params)` into the uppercase name and its parameters
+fn split_params(data_type: &str) -> (String, Option<String>) {
+    let data_type = data_type.trim();
+    match data_type.split_once('(') {
+        Some((base, rest)) => (
+            base.trim().to_uppercase(),
+            rest.strip_suffix(')').map(|p| p.trim().to_string()),
+        ),
+        None => (data_type.to_uppercase(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    #[test]
+    fn test_export_table_schema() {
+        let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+        id.nullable = false;
+        id.description = "Order id".to_string();
+        let table = Table::new(
+            "orders".to_string(),
+            vec![
+                id,
+                Column::new("email".to_string(), "VARCHAR(255)".to_string()),
+                Column::new("total".to_string(), "DECIMAL(40,10)".to_string()),
+                Column::new(
+                    "lines".to_string(),
+                    "ARRAY<STRUCT<sku STRING, qty INT>>".to_string(),
+                ),
+                Column::new("attributes".to_string(), "MAP<STRING, DOUBLE>".to_string()),
+            ],
+        );
+
+        let result = BigQuerySchemaExporter::new().export(&[table]).unwrap();
+        let schema: Value = serde_json::from_str(&result.content).unwrap();
+        assert_eq!(
+            schema,
+            json!([
+                {"name": "id", "type": "INTEGER", "mode": "REQUIRED", "description": "Order id"},
+                {"name": "email", "type": "STRING", "mode": "NULLABLE", "maxLength": "255"},
+                {"name": "total", "type": "BIGNUMERIC", "mode": "NULLABLE", "precision": "40", "scale": "10"},
+                {"name": "lines", "type": "RECORD", "mode": "REPEATED", "fields": [
+                    {"name": "sku", "type": "STRING", "mode": "NULLABLE"},
+                    {"name": "qty", "type": "INTEGER", "mode": "NULLABLE"}
+                ]},
+                {"name": "attributes", "type": "RECORD", "mode": "REPEATED", "fields": [
+                    {"name": "key", "type": "STRING", "mode": "NULLABLE"},
+                    {"name": "value", "type": "FLOAT", "mode": "NULLABLE"}
+                ]}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_export_nested_columns() {
+        let mut address = Column::new("address".to_string(), "OBJECT".to_string());
+        address.nullable = false;
+        let orders = Table::new(
+            "customers".to_string(),
+            vec![
+                Column::new("id".to_string(), "INTEGER".to_string()),
+                address,
+                Column::new("address.city".to_string(), "STRING".to_string()),
+                Column::new("phones".to_string(), "ARRAY<OBJECT>".to_string()),
+                Column::new("phones.[].number".to_string(), "STRING".to_string()),
+            ],
+        );
+        let events = Table::new(
+            "events".to_string(),
+            vec![Column::new("at".to_string(), "TIMESTAMP".to_string())],
+        );
+
+        let result = BigQuerySchemaExporter::new()
+            .export(&[orders, events])
+            .unwrap();
+        let schema: Value = serde_json::from_str(&result.content).unwrap();
+        assert_eq!(
+            schema["customers"][1],
+            json!({"name": "address", "type": "RECORD", "mode": "REQUIRED", "fields": [
+                {"name": "city", "type": "STRING", "mode": "NULLABLE"}
+            ]})
+        );
+        assert_eq!(
+            schema["customers"][2],
+            json!({"name": "phones", "type": "RECORD", "mode": "REPEATED", "fields": [
+                {"name": "number", "type": "STRING", "mode": "NULLABLE"}
+            ]})
+        );
+        assert_eq!(schema["customers"].as_array().unwrap().len(), 3);
+        assert_eq!(schema["events"][0]["type"], "TIMESTAMP");
+    }
+}
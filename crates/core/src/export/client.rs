@@ -0,0 +1,1242 @@
+//! Consumer client stub generator
+//!
+//! Generates a small, self-contained client module for reading a dataset as
+//! described by its contract, in Rust, Python or TypeScript ([`ClientLanguage`]).
+//! For every table the module contains:
+//!
+//! - **A typed record** - a serde struct (Rust), dataclass (Python) or interface
+//!   (TypeScript) with one field per column; optional columns are nullable
+//! - **Readers** - JSON (array or JSON Lines) and Parquet readers returning typed
+//!   records
+//! - **Validation** - every record read is checked against the contract: required
+//!   columns, value types (Python and TypeScript), enum values, string lengths and
+//!   patterns, and numeric bounds. The first invalid record fails the read with a
+//!   `RecordError` listing its violations
+//!
+//! The generated modules depend on `serde`, `serde_json`, `parquet` and, when a
+//! column has a pattern, `regex` (Rust); `pyarrow` for Parquet (Python); and
+//! `hyparquet` for Parquet (TypeScript). Nested (dotted) columns are left to the
+//! JSON value of their parent column.
+
+use super::{ExportError, ExportResult};
+use crate::models::{Column, Table};
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Target language of the generated client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClientLanguage {
+    /// Rust module using serde
+    #[default]
+    Rust,
+    /// Python module using dataclasses
+    Python,
+    /// TypeScript module
+    TypeScript,
+}
+
+impl ClientLanguage {
+    /// Parse a language name, returning `None` if unsupported
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "rust" | "rs" => Some(Self::Rust),
+            "python" | "py" => Some(Self::Python),
+            "typescript" | "ts" => Some(Self::TypeScript),
+            _ => None,
+        }
+    }
+
+    /// Language name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Python => "python",
+            Self::TypeScript => "typescript",
+        }
+    }
+
+    /// File extension of the generated module
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Rust => "rs",
+            Self::Python => "py",
+            Self::TypeScript => "ts",
+        }
+    }
+}
+
+/// Value type of a record field
+#[derive(Debug, Clone, PartialEq)]
+enum FieldType {
+    Integer,
+    Number,
+    Boolean,
+    String,
+    /// Date, time or timestamp, as an ISO 8601 string
+    Temporal,
+    /// Object or struct, as an untyped JSON value
+    Object,
+    Array(Box<FieldType>),
+}
+
+impl FieldType {
+    fn from_data_type(data_type: &str) -> Self {
+        let lower = data_type.trim().to_lowercase();
+        if let Some(inner) = lower
+            .strip_prefix("array<")
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            return Self::Array(Box::new(Self::from_data_type(inner)));
+        }
+        if lower.starts_with("array") || lower.ends_with("[]") || lower == "list" {
+            return Self::Array(Box::new(Self::Object));
+        }
+        let base = lower.split(['(', '<', ' ']).next().unwrap_or_default();
+        match base {
+            "int" | "integer" | "bigint" | "smallint" | "tinyint" | "long" | "short" | "int8"
+            | "int16" | "int32" | "int64" | "serial" | "bigserial" => Self::Integer,
+            "number" | "float" | "double" | "real" | "decimal" | "numeric" | "float32"
+            | "float64" => Self::Number,
+            "bool" | "boolean" => Self::Boolean,
+            "date" | "time" | "timestamp" | "timestamptz" | "timestamp_ntz" | "timestamp_ltz"
+            | "datetime" => Self::Temporal,
+            "object" | "struct" | "record" | "map" | "json" | "jsonb" | "variant" => Self::Object,
+            _ => Self::String,
+        }
+    }
+
+    fn rust(&self) -> String {
+        match self {
+            Self::Integer => "i64".to_string(),
+            Self::Number => "f64".to_string(),
+            Self::Boolean => "bool".to_string(),
+            Self::String | Self::Temporal => "String".to_string(),
+            Self::Object => "serde_json::Value".to_string(),
+            Self::Array(inner) => format!("Vec<{}>", inner.rust()),
+        }
+    }
+
+    fn python(&self) -> String {
+        match self {
+            Self::Integer => "int".to_string(),
+            Self::Number => "float".to_string(),
+            Self::Boolean => "bool".to_string(),
+            Self::String => "str".to_string(),
+            Self::Temporal => "str | date".to_string(),
+            Self::Object => "dict[str, Any]".to_string(),
+            Self::Array(inner) => format!("list[{}]", inner.python()),
+        }
+    }
+
+    /// Python `isinstance` check of `value`
+    fn python_check(&self, value: &str) -> String {
+        match self {
+            Self::Integer => format!("isinstance({0}, int) and not isinstance({0}, bool)", value),
+            Self::Number => format!(
+                "isinstance({0}, (int, float, Decimal)) and not isinstance({0}, bool)",
+                value
+            ),
+            Self::Boolean => format!("isinstance({}, bool)", value),
+            Self::String => format!("isinstance({}, str)", value),
+            Self::Temporal => format!("isinstance({}, (str, date))", value),
+            Self::Object => format!("isinstance({}, dict)", value),
+            Self::Array(_) => format!("isinstance({}, list)", value),
+        }
+    }
+
+    fn typescript(&self) -> String {
+        match self {
+            Self::Integer | Self::Number => "number".to_string(),
+            Self::Boolean => "boolean".to_string(),
+            Self::String => "string".to_string(),
+            Self::Temporal => "string | Date".to_string(),
+            Self::Object => "Record<string, unknown>".to_string(),
+            Self::Array(inner) => match **inner {
+                Self::Temporal => "(string | Date)[]".to_string(),
+                _ => format!("{}[]", inner.typescript()),
+            },
+        }
+    }
+
+    /// TypeScript check of `value`
+    fn typescript_check(&self, value: &str) -> String {
+        match self {
+            Self::Integer => format!("Number.isInteger({})", value),
+            Self::Number => format!("(typeof {} === \"number\")", value),
+            Self::Boolean => format!("(typeof {} === \"boolean\")", value),
+            Self::String => format!("(typeof {} === \"string\")", value),
+            Self::Temporal => format!("(typeof {0} === \"string\" || {0} instanceof Date)", value),
+            Self::Object => format!(
+                "(typeof {0} === \"object\" && {0} !== null && !Array.isArray({0}))",
+                value
+            ),
+            Self::Array(_) => format!("Array.isArray({})", value),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::String => "string",
+            Self::Temporal => "date/time",
+            Self::Object => "object",
+            Self::Array(_) => "array",
+        }
+    }
+}
+
+/// A record field derived from a column
+struct Field {
+    /// Column name, the key in the data
+    name: String,
+    description: String,
+    field_type: FieldType,
+    required: bool,
+    enum_values: Vec<String>,
+    min_length: Option<i64>,
+    max_length: Option<i64>,
+    pattern: Option<String>,
+    /// Numeric bounds as `(operator violating the bound, bound)`
+    bounds: Vec<(&'static str, f64)>,
+}
+
+impl Field {
+    fn from_column(column: &Column) -> Self {
+        let field_type = FieldType::from_data_type(&column.data_type);
+        let options = column.logical_type_options.as_ref();
+        let string_like = field_type == FieldType::String;
+        let numeric = matches!(field_type, FieldType::Integer | FieldType::Number);
+
+        let mut bounds = Vec::new();
+        if let (true, Some(options)) = (numeric, options) {
+            for (bound, operator) in [
+                (&options.minimum, "<"),
+                (&options.exclusive_minimum, "<="),
+                (&options.maximum, ">"),
+                (&options.exclusive_maximum, ">="),
+            ] {
+                if let Some(limit) = bound.as_ref().and_then(number) {
+                    bounds.push((operator, limit));
+                }
+            }
+        }
+
+        Self {
+            name: column.name.clone(),
+            description: column.description.trim().to_string(),
+            field_type,
+            required: !column.nullable || column.primary_key,
+            enum_values: if string_like {
+                column.enum_values.clone()
+            } else {
+                Vec::new()
+            },
+            min_length: options.and_then(|o| o.min_length).filter(|_| string_like),
+            max_length: options.and_then(|o| o.max_length).filter(|_| string_like),
+            pattern: options
+                .and_then(|o| o.pattern.clone())
+                .filter(|_| string_like),
+            bounds,
+        }
+    }
+}
+
+/// Numeric value of a bound, which may be given as a string
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// A record type derived from a table
+struct Record {
+    /// Table name
+    name: String,
+    /// Type name of the record
+    type_name: String,
+    fields: Vec<Field>,
+}
+
+/// Type names used by the generated modules themselves
+const RESERVED_TYPE_NAMES: &[&str] = &["Record", "RecordError"];
+
+/// Exporter for typed consumer client modules.
+#[derive(Debug, Clone, Default)]
+pub struct ClientExporter {
+    language: ClientLanguage,
+}
+
+impl ClientExporter {
+    /// Create a new client exporter generating Rust
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate a client in a different language
+    pub fn with_language(mut self, language: ClientLanguage) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Export tables to a client module (SDK interface).
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        Ok(ExportResult {
+            content: self.export_tables(tables)?,
+            format: format!("client-{}", self.language.name()),
+        })
+    }
+
+    /// Export tables to a client module with one typed record per table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::client::{ClientExporter, ClientLanguage};
+    /// use data_modelling_core::models::{Column, Table};
+    ///
+    /// let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+    /// id.nullable = false;
+    /// let table = Table::new(
+    ///     "orders".to_string(),
+    ///     vec![id, Column::new("status".to_string(), "VARCHAR(20)".to_string())],
+    /// );
+    ///
+    /// let module = ClientExporter::new()
+    ///     .with_language(ClientLanguage::Python)
+    ///     .export_tables(&[table])
+    ///     .unwrap();
+    /// assert!(module.contains("class Orders:"));
+    /// assert!(module.contains("def read_parquet(cls, path: str)"));
+    /// ```
+    pub fn export_tables(&self, tables: &[Table]) -> Result<String, ExportError> {
+        if tables.is_empty() {
+            return Err(ExportError::ValidationError(
+                "No tables to generate a client for".to_string(),
+            ));
+        }
+
+        let mut records: Vec<Record> = Vec::new();
+        for table in tables {
+            let mut type_name = pascal_case(&table.name);
+            if RESERVED_TYPE_NAMES.contains(&type_name.as_str()) {
+                type_name.push_str("Row");
+            }
+            if let Some(existing) = records.iter().find(|r| r.type_name == type_name) {
+                return Err(ExportError::ValidationError(format!(
+                    "Tables '{}' and '{}' map to the same record type '{}'",
+                    existing.name, table.name, type_name
+                )));
+            }
+            records.push(Record {
+                name: table.name.clone(),
+                type_name,
+                fields: table
+                    .columns
+                    .iter()
+                    .filter(|c| !c.name.contains('.'))
+                    .map(Field::from_column)
+                    .collect(),
+            });
+        }
+
+        Ok(match self.language {
+            ClientLanguage::Rust => rust_module(&records),
+            ClientLanguage::Python => python_module(&records),
+            ClientLanguage::TypeScript => typescript_module(&records),
+        })
+    }
+}
+
+fn dataset_names(records: &[Record]) -> String {
+    records
+        .iter()
+        .map(|r| format!("`{}`", r.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// === Rust ===
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+fn rust_module(records: &[Record]) -> String {
+    let uses_regex = records
+        .iter()
+        .any(|r| r.fields.iter().any(|f| f.pattern.is_some()));
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "//! Typed client for the {} dataset(s)",
+        dataset_names(records)
+    );
+    out.push_str("//!\n//! Generated from the data contract by odm. Do not edit.\n");
+    let _ = writeln!(
+        out,
+        "//!\n//! Requires `serde` (derive), `serde_json`, `parquet`{}.",
+        if uses_regex { " and `regex`" } else { "" }
+    );
+    out.push_str(
+        r#"
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+/// Error reading records
+#[derive(Debug)]
+pub enum RecordError {
+    /// The input could not be read
+    Io(std::io::Error),
+    /// A record could not be parsed
+    Parse { record: usize, message: String },
+    /// A record does not conform to the contract
+    Invalid { record: usize, errors: Vec<String> },
+}
+
+impl std::fmt::Display for RecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse { record, message } => write!(f, "record {}: {}", record, message),
+            Self::Invalid { record, errors } => {
+                write!(f, "record {}: {}", record, errors.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecordError {}
+"#,
+    );
+
+    for record in records {
+        rust_record(&mut out, record);
+    }
+    out
+}
+
+fn rust_record(out: &mut String, record: &Record) {
+    let _ = writeln!(out, "\n/// A record of the `{}` dataset", record.name);
+    out.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+    let _ = writeln!(out, "pub struct {} {{", record.type_name);
+    for field in &record.fields {
+        for line in field.description.lines() {
+            let _ = writeln!(out, "    /// {}", line.trim_end());
+        }
+        let ident = rust_ident(&field.name);
+        if ident.trim_start_matches("r#") != field.name {
+            let _ = writeln!(out, "    #[serde(rename = {:?})]", field.name);
+        }
+        if field.required {
+            let _ = writeln!(out, "    pub {}: {},", ident, field.field_type.rust());
+        } else {
+            out.push_str("    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n");
+            let _ = writeln!(
+                out,
+                "    pub {}: Option<{}>,",
+                ident,
+                field.field_type.rust()
+            );
+        }
+    }
+    out.push_str("}\n");
+
+    let _ = writeln!(out, "\nimpl {} {{", record.type_name);
+    out.push_str("    /// Check the record against the constraints of the contract\n");
+    out.push_str("    pub fn validate(&self) -> Result<(), Vec<String>> {\n");
+    out.push_str("        #[allow(unused_mut)]\n");
+    out.push_str("        let mut errors: Vec<String> = Vec::new();\n");
+    for field in &record.fields {
+        let checks = rust_checks(field);
+        if checks.is_empty() {
+            continue;
+        }
+        let ident = rust_ident(&field.name);
+        if field.required {
+            let _ = writeln!(out, "        {{\n            let value = &self.{};", ident);
+        } else {
+            let _ = writeln!(out, "        if let Some(value) = &self.{} {{", ident);
+        }
+        for (condition, message) in checks {
+            let _ = writeln!(
+                out,
+                "            if {} {{\n                errors.push({:?}.to_string());\n            }}",
+                condition,
+                format!("{}: {}", field.name, message)
+            );
+        }
+        out.push_str("        }\n");
+    }
+    out.push_str(
+        r#"        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Read records from a JSON array or JSON Lines, validating each
+    pub fn read_json<R: Read>(mut reader: R) -> Result<Vec<Self>, RecordError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(RecordError::Io)?;
+        let values: Vec<serde_json::Value> = if text.trim_start().starts_with('[') {
+            serde_json::from_str(&text).map_err(|e| RecordError::Parse {
+                record: 0,
+                message: e.to_string(),
+            })?
+        } else {
+            text.lines()
+                .filter(|line| !line.trim().is_empty())
+                .enumerate()
+                .map(|(record, line)| {
+                    serde_json::from_str(line).map_err(|e| RecordError::Parse {
+                        record,
+                        message: e.to_string(),
+                    })
+                })
+                .collect::<Result<_, _>>()?
+        };
+        Self::from_values(values)
+    }
+
+    /// Read records from a Parquet file, validating each
+    pub fn read_parquet(file: std::fs::File) -> Result<Vec<Self>, RecordError> {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let parse = |record: usize, e: parquet::errors::ParquetError| RecordError::Parse {
+            record,
+            message: e.to_string(),
+        };
+        let reader = SerializedFileReader::new(file).map_err(|e| parse(0, e))?;
+        let mut values = Vec::new();
+        for (record, row) in reader.get_row_iter(None).map_err(|e| parse(0, e))?.enumerate() {
+            values.push(row.map_err(|e| parse(record, e))?.to_json_value());
+        }
+        Self::from_values(values)
+    }
+
+    /// Convert and validate parsed JSON records
+    pub fn from_values(values: Vec<serde_json::Value>) -> Result<Vec<Self>, RecordError> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(record, value)| {
+                let parsed: Self = serde_json::from_value(value).map_err(|e| RecordError::Parse {
+                    record,
+                    message: e.to_string(),
+                })?;
+                parsed
+                    .validate()
+                    .map_err(|errors| RecordError::Invalid { record, errors })?;
+                Ok(parsed)
+            })
+            .collect()
+    }
+}
+"#,
+    );
+}
+
+/// Conditions violating the constraints of a field, with their messages
+fn rust_checks(field: &Field) -> Vec<(String, String)> {
+    let mut checks = Vec::new();
+    if !field.enum_values.is_empty() {
+        checks.push((
+            format!("!{:?}.contains(&value.as_str())", field.enum_values),
+            format!("must be one of {}", field.enum_values.join(", ")),
+        ));
+    }
+    if let Some(min) = field.min_length {
+        checks.push((
+            format!("value.chars().count() < {}", min),
+            format!("must be at least {} characters", min),
+        ));
+    }
+    if let Some(max) = field.max_length {
+        checks.push((
+            format!("value.chars().count() > {}", max),
+            format!("must be at most {} characters", max),
+        ));
+    }
+    if let Some(pattern) = &field.pattern {
+        checks.push((
+            format!(
+                "regex::Regex::new({:?}).is_ok_and(|re| !re.is_match(value))",
+                pattern
+            ),
+            format!("must match {}", pattern),
+        ));
+    }
+    let value = match field.field_type {
+        FieldType::Integer => "(*value as f64)",
+        _ => "*value",
+    };
+    for (operator, limit) in &field.bounds {
+        checks.push((
+            format!("{} {} {:?}", value, operator, limit),
+            bound_message(operator, *limit),
+        ));
+    }
+    checks
+}
+
+fn rust_ident(name: &str) -> String {
+    let ident = snake_case(name);
+    match ident.as_str() {
+        "self" | "super" | "crate" | "Self" => format!("{}_", ident),
+        _ if RUST_KEYWORDS.contains(&ident.as_str()) => format!("r#{}", ident),
+        _ => ident,
+    }
+}
+
+// === Python ===
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+fn python_module(records: &[Record]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "\"\"\"Typed client for the {} dataset(s).",
+        dataset_names(records).replace('`', "")
+    );
+    out.push_str(
+        r#"
+Generated from the data contract by odm. Do not edit.
+Reading Parquet requires pyarrow.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from dataclasses import dataclass
+from datetime import date
+from decimal import Decimal
+from typing import Any, Iterable
+
+
+class RecordError(ValueError):
+    """A record could not be parsed or does not conform to the contract."""
+
+    def __init__(self, record: int, errors: list[str]):
+        super().__init__(f"record {record}: {'; '.join(errors)}")
+        self.record = record
+        self.errors = errors
+"#,
+    );
+
+    for record in records {
+        python_record(&mut out, record);
+    }
+    out
+}
+
+fn python_record(out: &mut String, record: &Record) {
+    let name = &record.type_name;
+    let _ = writeln!(out, "\n\n@dataclass\nclass {}:", name);
+    let _ = writeln!(
+        out,
+        "    \"\"\"A record of the {} dataset.\"\"\"\n",
+        python_string(&record.name).trim_matches('"')
+    );
+
+    // Dataclass fields without a default must come first
+    let mut fields: Vec<&Field> = record.fields.iter().filter(|f| f.required).collect();
+    fields.extend(record.fields.iter().filter(|f| !f.required));
+    for field in &fields {
+        if let Some(line) = field.description.lines().next() {
+            let _ = writeln!(out, "    # {}", line.trim_end());
+        }
+        let ident = python_ident(&field.name);
+        if field.required {
+            let _ = writeln!(out, "    {}: {}", ident, field.field_type.python());
+        } else {
+            let _ = writeln!(
+                out,
+                "    {}: {} | None = None",
+                ident,
+                field.field_type.python()
+            );
+        }
+    }
+    if fields.is_empty() {
+        out.push_str("    pass\n");
+    }
+
+    let _ = writeln!(
+        out,
+        "\n    @classmethod\n    def from_dict(cls, data: dict[str, Any]) -> {}:",
+        name
+    );
+    out.push_str("        return cls(\n");
+    for field in &fields {
+        let _ = writeln!(
+            out,
+            "            {}=data.get({}),",
+            python_ident(&field.name),
+            python_string(&field.name)
+        );
+    }
+    out.push_str("        )\n");
+
+    out.push_str("\n    def to_dict(self) -> dict[str, Any]:\n        return {\n");
+    for field in &record.fields {
+        let _ = writeln!(
+            out,
+            "            {}: self.{},",
+            python_string(&field.name),
+            python_ident(&field.name)
+        );
+    }
+    out.push_str("        }\n");
+
+    out.push_str("\n    def validate(self) -> list[str]:\n");
+    out.push_str("        \"\"\"Check the record against the constraints of the contract.\"\"\"\n");
+    out.push_str("        errors: list[str] = []\n");
+    for field in &record.fields {
+        let ident = python_ident(&field.name);
+        let _ = writeln!(out, "        value = self.{}", ident);
+        if field.required {
+            let _ = writeln!(
+                out,
+                "        if value is None:\n            errors.append({})",
+                python_string(&format!("{}: is required", field.name))
+            );
+            let _ = writeln!(
+                out,
+                "        elif not ({}):",
+                field.field_type.python_check("value")
+            );
+        } else {
+            let _ = writeln!(
+                out,
+                "        if value is not None and not ({}):",
+                field.field_type.python_check("value")
+            );
+        }
+        let _ = writeln!(
+            out,
+            "            errors.append({})",
+            python_string(&format!(
+                "{}: expected {}",
+                field.name,
+                field.field_type.type_name()
+            ))
+        );
+        let guard = if field.required {
+            ""
+        } else {
+            "value is not None and "
+        };
+        for (condition, message) in python_checks(field) {
+            let _ = writeln!(
+                out,
+                "        elif {}{}:\n            errors.append({})",
+                guard,
+                condition,
+                python_string(&format!("{}: {}", field.name, message))
+            );
+        }
+    }
+    out.push_str("        return errors\n");
+
+    let _ = writeln!(
+        out,
+        r#"
+    @classmethod
+    def read_json(cls, path: str) -> list[{0}]:
+        """Read a JSON array or JSON Lines file, validating each record."""
+        with open(path, encoding="utf-8") as f:
+            text = f.read()
+        if text.lstrip().startswith("["):
+            rows = json.loads(text)
+        else:
+            rows = [json.loads(line) for line in text.splitlines() if line.strip()]
+        return cls.from_rows(rows)
+
+    @classmethod
+    def read_parquet(cls, path: str) -> list[{0}]:
+        """Read a Parquet file, validating each record."""
+        import pyarrow.parquet as pq
+
+        return cls.from_rows(pq.read_table(path).to_pylist())
+
+    @classmethod
+    def from_rows(cls, rows: Iterable[dict[str, Any]]) -> list[{0}]:
+        """Convert and validate parsed records."""
+        records = []
+        for index, row in enumerate(rows):
+            record = cls.from_dict(row)
+            errors = record.validate()
+            if errors:
+                raise RecordError(index, errors)
+            records.append(record)
+        return records"#,
+        name
+    );
+}
+
+/// Conditions violating the constraints of a field, checked once the type is valid
+fn python_checks(field: &Field) -> Vec<(String, String)> {
+    let mut checks = Vec::new();
+    if !field.enum_values.is_empty() {
+        let values: Vec<String> = field.enum_values.iter().map(|v| python_string(v)).collect();
+        checks.push((
+            format!("value not in ({},)", values.join(", ")),
+            format!("must be one of {}", field.enum_values.join(", ")),
+        ));
+    }
+    if let Some(min) = field.min_length {
+        checks.push((
+            format!("len(value) < {}", min),
+            format!("must be at least {} characters", min),
+        ));
+    }
+    if let Some(max) = field.max_length {
+        checks.push((
+            format!("len(value) > {}", max),
+            format!("must be at most {} characters", max),
+        ));
+    }
+    if let Some(pattern) = &field.pattern {
+        checks.push((
+            format!("re.fullmatch({}, value) is None", python_string(pattern)),
+            format!("must match {}", pattern),
+        ));
+    }
+    for (operator, limit) in &field.bounds {
+        checks.push((
+            format!("value {} {}", operator, limit),
+            bound_message(operator, *limit),
+        ));
+    }
+    checks
+}
+
+fn python_ident(name: &str) -> String {
+    let ident = snake_case(name);
+    if PYTHON_KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+fn python_string(value: &str) -> String {
+    // JSON string escaping is valid Python string literal syntax
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+// === TypeScript ===
+
+fn typescript_module(records: &[Record]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "/**\n * Typed client for the {} dataset(s).",
+        dataset_names(records)
+            .replace('`', "")
+            .replace("*/", "*\\/")
+    );
+    out.push_str(
+        r#" *
+ * Generated from the data contract by odm. Do not edit.
+ * Reading Parquet requires hyparquet.
+ */
+
+import { parquetReadObjects, type AsyncBuffer } from "hyparquet";
+
+/** A record could not be parsed or does not conform to the contract. */
+export class RecordError extends Error {
+  constructor(
+    public readonly record: number,
+    public readonly errors: string[],
+  ) {
+    super(`record ${record}: ${errors.join("; ")}`);
+  }
+}
+
+/** Parse a JSON array or JSON Lines text */
+function parseJson(text: string): unknown[] {
+  const trimmed = text.trim();
+  if (trimmed.startsWith("[")) {
+    return JSON.parse(trimmed);
+  }
+  return trimmed
+    .split("\n")
+    .filter((line) => line.trim() !== "")
+    .map((line) => JSON.parse(line));
+}
+
+/** Read Parquet rows, converting 64-bit integers to numbers */
+async function readParquetRows(file: AsyncBuffer): Promise<unknown[]> {
+  const rows = await parquetReadObjects({ file });
+  return rows.map((row) =>
+    Object.fromEntries(
+      Object.entries(row).map(([key, value]) => [
+        key,
+        typeof value === "bigint" ? Number(value) : value,
+      ]),
+    ),
+  );
+}
+"#,
+    );
+
+    for record in records {
+        typescript_record(&mut out, record);
+    }
+    out
+}
+
+fn typescript_record(out: &mut String, record: &Record) {
+    let name = &record.type_name;
+    let _ = writeln!(
+        out,
+        "\n/** A record of the {} dataset */\nexport interface {} {{",
+        record.name.replace("*/", "*\\/"),
+        name
+    );
+    for field in &record.fields {
+        if let Some(line) = field.description.lines().next() {
+            let _ = writeln!(out, "  /** {} */", line.trim_end().replace("*/", "*\\/"));
+        }
+        let key = typescript_key(&field.name);
+        if field.required {
+            let _ = writeln!(out, "  {}: {};", key, field.field_type.typescript());
+        } else {
+            let _ = writeln!(out, "  {}?: {} | null;", key, field.field_type.typescript());
+        }
+    }
+    out.push_str("}\n");
+
+    let _ = writeln!(
+        out,
+        "\n/** Check a value against the constraints of the contract */\nexport function validate{}(value: unknown): string[] {{",
+        name
+    );
+    out.push_str(
+        "  if (typeof value !== \"object\" || value === null || Array.isArray(value)) {\n    return [\"expected an object\"];\n  }\n",
+    );
+    out.push_str("  const record = value as Record<string, unknown>;\n");
+    out.push_str("  const errors: string[] = [];\n");
+    for field in &record.fields {
+        let prefix = format!("{}: ", field.name);
+        let _ = writeln!(
+            out,
+            "  {{\n    const v = record[{}];",
+            python_string(&field.name)
+        );
+        if field.required {
+            let _ = writeln!(
+                out,
+                "    if (v === undefined || v === null) {{\n      errors.push({});\n    }} else if (!{}) {{",
+                python_string(&format!("{}is required", prefix)),
+                field.field_type.typescript_check("v")
+            );
+        } else {
+            let _ = writeln!(
+                out,
+                "    if (v !== undefined && v !== null && !{}) {{",
+                field.field_type.typescript_check("v")
+            );
+        }
+        let _ = writeln!(
+            out,
+            "      errors.push({});",
+            python_string(&format!(
+                "{}expected {}",
+                prefix,
+                field.field_type.type_name()
+            ))
+        );
+        for (condition, message) in typescript_checks(field) {
+            let _ = writeln!(
+                out,
+                "    }} else if (v !== undefined && v !== null && {}) {{\n      errors.push({});",
+                condition,
+                python_string(&format!("{}{}", prefix, message))
+            );
+        }
+        out.push_str("    }\n  }\n");
+    }
+    out.push_str("  return errors;\n}\n");
+
+    let _ = writeln!(
+        out,
+        r#"
+/** Validate parsed rows as {0} records */
+export function parse{0}(rows: unknown[]): {0}[] {{
+  return rows.map((row, index) => {{
+    const errors = validate{0}(row);
+    if (errors.length > 0) {{
+      throw new RecordError(index, errors);
+    }}
+    return row as {0};
+  }});
+}}
+
+/** Read {0} records from a JSON array or JSON Lines text */
+export function read{0}Json(text: string): {0}[] {{
+  return parse{0}(parseJson(text));
+}}
+
+/** Read {0} records from a Parquet file */
+export async function read{0}Parquet(file: AsyncBuffer): Promise<{0}[]> {{
+  return parse{0}(await readParquetRows(file));
+}}"#,
+        name
+    );
+}
+
+/// Conditions violating the constraints of a field `v`, checked once the type is valid
+fn typescript_checks(field: &Field) -> Vec<(String, String)> {
+    let mut checks = Vec::new();
+    if !field.enum_values.is_empty() {
+        let values: Vec<String> = field.enum_values.iter().map(|v| python_string(v)).collect();
+        checks.push((
+            format!("![{}].includes(v as string)", values.join(", ")),
+            format!("must be one of {}", field.enum_values.join(", ")),
+        ));
+    }
+    if let Some(min) = field.min_length {
+        checks.push((
+            format!("[...(v as string)].length < {}", min),
+            format!("must be at least {} characters", min),
+        ));
+    }
+    if let Some(max) = field.max_length {
+        checks.push((
+            format!("[...(v as string)].length > {}", max),
+            format!("must be at most {} characters", max),
+        ));
+    }
+    if let Some(pattern) = &field.pattern {
+        checks.push((
+            format!(
+                "!new RegExp({}, \"u\").test(v as string)",
+                python_string(&format!("^(?:{})$", pattern))
+            ),
+            format!("must match {}", pattern),
+        ));
+    }
+    for (operator, limit) in &field.bounds {
+        checks.push((
+            format!("(v as number) {} {}", operator, limit),
+            bound_message(operator, *limit),
+        ));
+    }
+    checks
+}
+
+fn typescript_key(name: &str) -> String {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if valid {
+        name.to_string()
+    } else {
+        python_string(name)
+    }
+}
+
+// === Shared ===
+
+fn bound_message(operator: &str, limit: f64) -> String {
+    match operator {
+        "<" => format!("must be at least {}", limit),
+        "<=" => format!("must be greater than {}", limit),
+        ">" => format!("must be at most {}", limit),
+        _ => format!("must be less than {}", limit),
+    }
+}
+
+/// Split a name into lowercase alphanumeric words
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && previous_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        current.push(c.to_ascii_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn snake_case(name: &str) -> String {
+    let ident = words(name).join("_");
+    if ident.is_empty() {
+        "field".to_string()
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else {
+        ident
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    let ident: String = words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Record{}", ident)
+    } else {
+        ident
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogicalTypeOptions;
+    use serde_json::json;
+
+    fn orders() -> Table {
+        let mut id = Column::new("orderId".to_string(), "BIGINT".to_string());
+        id.primary_key = true;
+        id.nullable = false;
+        id.description = "Order identifier".to_string();
+        let mut status = Column::new("status".to_string(), "VARCHAR(20)".to_string());
+        status.nullable = false;
+        status.enum_values = vec!["open".to_string(), "shipped".to_string()];
+        let mut amount = Column::new("amount".to_string(), "DECIMAL(10,2)".to_string());
+        amount.logical_type_options = Some(LogicalTypeOptions {
+            minimum: Some(json!(0)),
+            ..Default::default()
+        });
+        let mut code = Column::new("type".to_string(), "string".to_string());
+        code.logical_type_options = Some(LogicalTypeOptions {
+            max_length: Some(8),
+            pattern: Some("[A-Z]+".to_string()),
+            ..Default::default()
+        });
+        Table::new(
+            "order_lines".to_string(),
+            vec![
+                id,
+                status,
+                amount,
+                code,
+                Column::new("placed_at".to_string(), "TIMESTAMP".to_string()),
+                Column::new("tags".to_string(), "array<string>".to_string()),
+                Column::new("tags.value".to_string(), "string".to_string()),
+            ],
+        )
+    }
+
+    fn export(language: ClientLanguage) -> String {
+        ClientExporter::new()
+            .with_language(language)
+            .export_tables(&[orders()])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_field_types() {
+        assert_eq!(FieldType::from_data_type("BIGINT"), FieldType::Integer);
+        assert_eq!(
+            FieldType::from_data_type("decimal(10,2)"),
+            FieldType::Number
+        );
+        assert_eq!(FieldType::from_data_type("timestamp"), FieldType::Temporal);
+        assert_eq!(
+            FieldType::from_data_type("ARRAY<INT>"),
+            FieldType::Array(Box::new(FieldType::Integer))
+        );
+        assert_eq!(FieldType::from_data_type("varchar(20)"), FieldType::String);
+        assert_eq!(FieldType::from_data_type("struct"), FieldType::Object);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(pascal_case("order_lines"), "OrderLines");
+        assert_eq!(pascal_case("2024-sales"), "Record2024Sales");
+        assert_eq!(rust_ident("orderId"), "order_id");
+        assert_eq!(rust_ident("type"), "r#type");
+        assert_eq!(rust_ident("self"), "self_");
+        assert_eq!(python_ident("class"), "class_");
+        assert_eq!(typescript_key("order id"), "\"order id\"");
+    }
+
+    #[test]
+    fn test_export_rust() {
+        let module = export(ClientLanguage::Rust);
+        assert!(module.contains("pub struct OrderLines {"));
+        assert!(module.contains(
+            "    /// Order identifier\n    #[serde(rename = \"orderId\")]\n    pub order_id: i64,"
+        ));
+        assert!(module.contains("pub r#type: Option<String>,"));
+        assert!(module.contains("pub tags: Option<Vec<String>>,"));
+        assert!(!module.contains("tags.value"));
+        assert!(module.contains("![\"open\", \"shipped\"].contains(&value.as_str())"));
+        assert!(module.contains("*value < 0.0"));
+        assert!(module.contains("regex::Regex::new(\"[A-Z]+\")"));
+        assert!(module.contains("pub fn read_parquet(file: std::fs::File)"));
+        assert!(module.contains("Requires `serde` (derive), `serde_json`, `parquet` and `regex`."));
+    }
+
+    #[test]
+    fn test_export_python() {
+        let module = export(ClientLanguage::Python);
+        assert!(module.contains("class OrderLines:"));
+        assert!(
+            module.contains("    order_id: int\n    status: str\n    amount: float | None = None")
+        );
+        assert!(module.contains("    type: str | None = None\n"));
+        assert!(module.contains("errors.append(\"orderId: is required\")"));
+        assert!(module.contains("elif value not in (\"open\", \"shipped\",):"));
+        assert!(
+            module.contains("elif value is not None and re.fullmatch(\"[A-Z]+\", value) is None:")
+        );
+        assert!(module.contains("pq.read_table(path).to_pylist()"));
+    }
+
+    #[test]
+    fn test_export_typescript() {
+        let module = export(ClientLanguage::TypeScript);
+        assert!(module.contains("export interface OrderLines {"));
+        assert!(module.contains("  orderId: number;"));
+        assert!(module.contains("  placed_at?: string | Date | null;"));
+        assert!(module.contains("export function validateOrderLines(value: unknown): string[]"));
+        assert!(module.contains("new RegExp(\"^(?:[A-Z]+)$\", \"u\")"));
+        assert!(module.contains("export async function readOrderLinesParquet(file: AsyncBuffer)"));
+    }
+
+    #[test]
+    fn test_export_requires_tables() {
+        assert!(ClientExporter::new().export_tables(&[]).is_err());
+    }
+}
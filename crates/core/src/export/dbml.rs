@@ -0,0 +1,413 @@
+//! DBML (dbdiagram.io) exporter
+//!
+//! Writes tables as [DBML](https://dbml.dbdiagram.io/docs/) so models can be opened
+//! in dbdiagram.io and other DBML tooling. This is the inverse of
+//! [`DbmlImporter`](crate::import::dbml::DbmlImporter):
+//!
+//! - columns with `enum_values` get an `Enum` named after their data type, or
+//!   `<table>_<column>` when the data type is a standard type
+//! - `foreignKey` relationships of the form `table.column` become top-level `Ref`s,
+//!   using the [`DBML_RELATION_PROPERTY`] and `onDelete`/`onUpdate` custom properties
+//! - the `indexes` metadata of a table becomes its `indexes` block and composite
+//!   primary keys a `[pk]` index
+
+use super::{ExportError, ExportResult};
+use crate::import::dbml::DBML_RELATION_PROPERTY;
+use crate::models::{Column, DataModel, Table};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Exporter for DBML format.
+pub struct DbmlExporter;
+
+impl DbmlExporter {
+    /// Export tables to DBML (SDK interface).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::dbml::DbmlExporter;
+    /// use data_modelling_core::models::{Column, Table};
+    ///
+    /// let mut id = Column::new("id".to_string(), "integer".to_string());
+    /// id.primary_key = true;
+    /// let table = Table::new("users".to_string(), vec![id]);
+    ///
+    /// let result = DbmlExporter.export(&[table]).unwrap();
+    /// assert!(result.content.contains("Table users {\n  id INTEGER [pk]\n}"));
+    /// ```
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        Ok(ExportResult {
+            content: Self::export_tables(tables),
+            format: "dbml".to_string(),
+        })
+    }
+
+    /// Export all tables of a data model to DBML.
+    pub fn export_model(model: &DataModel) -> String {
+        Self::export_tables(&model.tables)
+    }
+
+    /// Export tables to a DBML document: enums, tables, then refs.
+    pub fn export_tables(tables: &[Table]) -> String {
+        let mut enums: BTreeMap<String, &[String]> = BTreeMap::new();
+        let mut blocks = Vec::new();
+        let mut refs = Vec::new();
+
+        for table in tables {
+            blocks.push(Self::export_table(table, &mut enums));
+            for column in &table.columns {
+                refs.extend(Self::column_refs(table, column));
+            }
+        }
+
+        let mut sections: Vec<String> = enums
+            .iter()
+            .map(|(name, values)| {
+                let values: Vec<String> = values
+                    .iter()
+                    .map(|v| format!("  {}\n", quote_name(v)))
+                    .collect();
+                format!("Enum {} {{\n{}}}\n", quote_name(name), values.concat())
+            })
+            .collect();
+        sections.extend(blocks);
+        if !refs.is_empty() {
+            sections.push(refs.concat());
+        }
+        sections.join("\n")
+    }
+
+    fn export_table<'a>(table: &'a Table, enums: &mut BTreeMap<String, &'a [String]>) -> String {
+        let mut out = format!("Table {}", quote_name(&Self::qualified_name(table)));
+        if let Some(note) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(Value::as_str)
+        {
+            out.push_str(&format!(" [note: {}]", quote_string(note)));
+        }
+        out.push_str(" {\n");
+
+        let primary_keys = table.columns.iter().filter(|c| c.primary_key).count();
+        for column in &table.columns {
+            let mut data_type = column.data_type.clone();
+            if !column.enum_values.is_empty() {
+                if is_standard_type(&data_type) {
+                    data_type = format!("{}_{}", table.name, column.name);
+                }
+                enums.insert(data_type.clone(), &column.enum_values);
+            }
+            out.push_str(&format!(
+                "  {} {}",
+                quote_name(&column.name),
+                quote_type(&data_type)
+            ));
+
+            let settings = Self::column_settings(column, primary_keys == 1);
+            if !settings.is_empty() {
+                out.push_str(&format!(" [{}]", settings.join(", ")));
+            }
+            out.push('\n');
+        }
+
+        let indexes = Self::indexes(table, primary_keys > 1);
+        if !indexes.is_empty() {
+            out.push_str("\n  indexes {\n");
+            for index in indexes {
+                out.push_str(&format!("    {}\n", index));
+            }
+            out.push_str("  }\n");
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn qualified_name(table: &Table) -> String {
+        let schema = table.schema_name.as_deref().or_else(|| {
+            table
+                .odcl_metadata
+                .get("schemaName")
+                .and_then(Value::as_str)
+        });
+        match schema {
+            Some(schema) => format!("{}.{}", schema, table.name),
+            None => table.name.clone(),
+        }
+    }
+
+    fn column_settings(column: &Column, single_primary_key: bool) -> Vec<String> {
+        let mut settings = Vec::new();
+        let inline_primary_key = column.primary_key && single_primary_key;
+        if inline_primary_key {
+            settings.push("pk".to_string());
+        }
+        if column.custom_properties.get("autoIncrement") == Some(&Value::Bool(true)) {
+            settings.push("increment".to_string());
+        }
+        if !column.nullable && !inline_primary_key {
+            settings.push("not null".to_string());
+        }
+        if column.unique {
+            settings.push("unique".to_string());
+        }
+        if let Some(default) = &column.default_value {
+            settings.push(format!("default: {}", format_default(default)));
+        }
+        if !column.description.is_empty() {
+            settings.push(format!("note: {}", quote_string(&column.description)));
+        }
+        settings
+    }
+
+    fn indexes(table: &Table, composite_primary_key: bool) -> Vec<String> {
+        let mut indexes = Vec::new();
+        if composite_primary_key {
+            let mut keys: Vec<&Column> = table.columns.iter().filter(|c| c.primary_key).collect();
+            keys.sort_by_key(|c| c.primary_key_position.unwrap_or(i32::MAX));
+            let names: Vec<String> = keys.iter().map(|c| quote_name(&c.name)).collect();
+            indexes.push(format!("({}) [pk]", names.join(", ")));
+        }
+
+        let Some(Value::Array(defs)) = table.odcl_metadata.get("indexes") else {
+            return indexes;
+        };
+        for def in defs {
+            let columns: Vec<String> = def
+                .get("columns")
+                .and_then(Value::as_array)
+                .map(|cols| {
+                    cols.iter()
+                        .filter_map(Value::as_str)
+                        .map(|c| {
+                            if c.starts_with('`') {
+                                c.to_string()
+                            } else {
+                                quote_name(c)
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            if columns.is_empty() {
+                continue;
+            }
+            let mut index = if columns.len() == 1 {
+                columns[0].clone()
+            } else {
+                format!("({})", columns.join(", "))
+            };
+
+            let mut settings = Vec::new();
+            if def.get("unique").and_then(Value::as_bool) == Some(true) {
+                settings.push("unique".to_string());
+            }
+            for key in ["name", "type", "note"] {
+                if let Some(value) = def.get(key).and_then(Value::as_str) {
+                    match key {
+                        "type" => settings.push(format!("type: {}", value)),
+                        _ => settings.push(format!("{}: {}", key, quote_string(value))),
+                    }
+                }
+            }
+            if !settings.is_empty() {
+                index.push_str(&format!(" [{}]", settings.join(", ")));
+            }
+            indexes.push(index);
+        }
+        indexes
+    }
+
+    /// Top-level `Ref` lines for the column's `table.column` foreign keys
+    fn column_refs(table: &Table, column: &Column) -> Vec<String> {
+        let op = match column
+            .custom_properties
+            .get(DBML_RELATION_PROPERTY)
+            .and_then(Value::as_str)
+        {
+            Some("one-to-one") => "-",
+            Some("many-to-many") => "<>",
+            _ => ">",
+        };
+        let mut settings = Vec::new();
+        for (property, key) in [("onDelete", "delete"), ("onUpdate", "update")] {
+            if let Some(action) = column
+                .custom_properties
+                .get(property)
+                .and_then(Value::as_str)
+            {
+                settings.push(format!("{}: {}", key, action));
+            }
+        }
+        let settings = if settings.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", settings.join(", "))
+        };
+
+        let mut refs: Vec<String> = Vec::new();
+        for rel in &column.relationships {
+            // Only plain `table.column` targets; JSON Schema style paths have no DBML form
+            let Some((target_table, target_column)) = rel.to.rsplit_once('.') else {
+                continue;
+            };
+            if rel.relationship_type != "foreignKey" || rel.to.contains('/') {
+                continue;
+            }
+            let line = format!(
+                "Ref: {}.{} {} {}.{}{}\n",
+                quote_name(&Self::qualified_name(table)),
+                quote_name(&column.name),
+                op,
+                quote_name(target_table),
+                quote_name(target_column),
+                settings
+            );
+            if !refs.contains(&line) {
+                refs.push(line);
+            }
+        }
+        refs
+    }
+}
+
+/// Whether a data type is a standard SQL type rather than a named (enum) type
+fn is_standard_type(data_type: &str) -> bool {
+    let base = data_type
+        .split(['(', '<', '['])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    matches!(
+        base.as_str(),
+        "string" | "text" | "varchar" | "char" | "nvarchar" | "enum" | "int" | "integer"
+    )
+}
+
+fn is_plain_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Quote a name unless it is a plain identifier; dotted names are quoted per part
+fn quote_name(name: &str) -> String {
+    if name.contains('.') && name.split('.').all(is_plain_identifier) {
+        return name.to_string();
+    }
+    if is_plain_identifier(name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Quote a type unless DBML can read it as a name with optional parameters
+fn quote_type(data_type: &str) -> String {
+    let base = data_type.split('(').next().unwrap_or_default();
+    let params_ok = data_type[base.len()..].is_empty()
+        || (data_type.ends_with(')')
+            && data_type[base.len() + 1..data_type.len() - 1]
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == ',' || c == ' ' || c == '_'));
+    if is_plain_identifier(base) && params_ok {
+        data_type.to_string()
+    } else {
+        format!("\"{}\"", data_type.replace('"', "\\\""))
+    }
+}
+
+/// Quote a string, using `'''` for multi-line text
+fn quote_string(value: &str) -> String {
+    if value.contains('\n') {
+        format!(
+            "'''{}'''",
+            value.replace('\\', "\\\\").replace("'''", "\\'''")
+        )
+    } else {
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+    }
+}
+
+fn format_default(value: &Value) -> String {
+    match value {
+        // Expressions were imported with their backticks
+        Value::String(s) if s.len() > 1 && s.starts_with('`') && s.ends_with('`') => s.clone(),
+        Value::String(s) => quote_string(s),
+        Value::Null => "null".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::dbml::DbmlImporter;
+    use crate::models::PropertyRelationship;
+
+    #[test]
+    fn test_export_table() {
+        let mut id = Column::new("id".to_string(), "integer".to_string());
+        id.primary_key = true;
+        let mut status = Column::new("status".to_string(), "VARCHAR(20)".to_string());
+        status.enum_values = vec!["open".to_string(), "closed".to_string()];
+        status.nullable = false;
+        status.description = "Ticket's state".to_string();
+        let mut owner = Column::new("owner id".to_string(), "integer".to_string());
+        owner.relationships.push(PropertyRelationship {
+            relationship_type: "foreignKey".to_string(),
+            to: "users.id".to_string(),
+        });
+        owner.default_value = Some(serde_json::json!("`current_user_id()`"));
+        let mut table = Table::new("tickets".to_string(), vec![id, status, owner]);
+        table.schema_name = Some("support".to_string());
+
+        let dbml = DbmlExporter::export_tables(&[table]);
+        assert!(dbml.starts_with("Enum tickets_status {\n  open\n  closed\n}\n"));
+        assert!(dbml.contains("Table support.tickets {\n  id INTEGER [pk]\n"));
+        assert!(dbml.contains("  status tickets_status [not null, note: 'Ticket\\'s state']\n"));
+        assert!(dbml.contains("  \"owner id\" INTEGER [default: `current_user_id()`]\n"));
+        assert!(dbml.contains("Ref: support.tickets.\"owner id\" > users.id\n"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dbml = r#"
+Enum status {
+  active
+  "on hold"
+}
+
+Table core.accounts [note: 'Customer accounts'] {
+  id bigint [pk, increment]
+  status status [not null, default: 'active']
+}
+
+Table memberships {
+  account_id bigint
+  group_id bigint
+  role "character varying(20)"
+
+  indexes {
+    (account_id, group_id) [pk]
+    role [name: 'memberships_role', type: hash]
+  }
+}
+
+Ref: memberships.account_id > core.accounts.id [delete: cascade]
+"#;
+        let imported = DbmlImporter::new().import(dbml).unwrap();
+        assert!(imported.errors.is_empty(), "{:?}", imported.errors);
+        let tables: Vec<Table> = imported.tables.iter().map(Table::from_table_data).collect();
+
+        let exported = DbmlExporter::export_tables(&tables);
+        let reimported = DbmlImporter::new().import(&exported).unwrap();
+        assert!(reimported.errors.is_empty(), "{:?}", reimported.errors);
+        assert_eq!(
+            serde_json::to_value(&imported.tables).unwrap(),
+            serde_json::to_value(&reimported.tables).unwrap(),
+            "{}",
+            exported
+        );
+    }
+}
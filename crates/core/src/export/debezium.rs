@@ -0,0 +1,363 @@
+//! Debezium connector configuration exporter
+//!
+//! Turns the CDC metadata of tables ([`CdcConfig`]) into Kafka Connect connector
+//! configurations for [Debezium](https://debezium.io/documentation/reference/stable/connectors/):
+//!
+//! - one connector per table captured with the `log-based` or `snapshot` method;
+//!   `trigger` and `timestamp` capture is not done by Debezium and is skipped
+//! - the connector class follows the table's database type, or the connector the
+//!   exporter was created with (PostgreSQL by default)
+//! - `message.key.columns` is set to the CDC key columns
+//! - snapshot capture uses `snapshot.mode = initial_only`
+//! - change events are flattened with `ExtractNewRecordState`; with a soft-delete
+//!   column, deletes are rewritten into records with that column set
+//!
+//! Connection settings are written as `${...}` placeholders to be filled in
+//! (e.g. by a Kafka Connect config provider) when the connector is deployed.
+//! The output is a JSON array of `{"name", "config"}` objects, each of which can
+//! be posted to the Kafka Connect REST API.
+
+use super::{ExportError, ExportResult};
+use crate::models::cdc::{CaptureMethod, CdcConfig};
+use crate::models::{DatabaseType, Table};
+use serde_json::{Map, Value, json};
+
+/// Default Kafka topic prefix of the generated connectors
+pub const DEFAULT_TOPIC_PREFIX: &str = "cdc";
+
+/// Source database connector of Debezium
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DebeziumConnector {
+    #[default]
+    Postgres,
+    MySql,
+    SqlServer,
+}
+
+impl DebeziumConnector {
+    /// Parse a dialect name such as `postgres`, `mysql` or `sqlserver`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace(['-', '_'], "").as_str() {
+            "postgres" | "postgresql" => Some(Self::Postgres),
+            "mysql" | "mariadb" => Some(Self::MySql),
+            "sqlserver" | "mssql" | "tsql" => Some(Self::SqlServer),
+            _ => None,
+        }
+    }
+
+    fn for_database(database_type: Option<DatabaseType>) -> Option<Self> {
+        match database_type? {
+            DatabaseType::Postgres | DatabaseType::DatabricksLakebase => Some(Self::Postgres),
+            DatabaseType::Mysql => Some(Self::MySql),
+            DatabaseType::SqlServer => Some(Self::SqlServer),
+            _ => None,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Self::Postgres => "io.debezium.connector.postgresql.PostgresConnector",
+            Self::MySql => "io.debezium.connector.mysql.MySqlConnector",
+            Self::SqlServer => "io.debezium.connector.sqlserver.SqlServerConnector",
+        }
+    }
+
+    pub(crate) fn port(self) -> &'static str {
+        match self {
+            Self::Postgres => "5432",
+            Self::MySql => "3306",
+            Self::SqlServer => "1433",
+        }
+    }
+
+    fn default_schema(self) -> Option<&'static str> {
+        match self {
+            Self::Postgres => Some("public"),
+            Self::MySql => None,
+            Self::SqlServer => Some("dbo"),
+        }
+    }
+}
+
+/// Exporter for Debezium connector configuration templates.
+#[derive(Debug, Clone)]
+pub struct DebeziumExporter {
+    connector: DebeziumConnector,
+    topic_prefix: String,
+}
+
+impl Default for DebeziumExporter {
+    fn default() -> Self {
+        Self {
+            connector: DebeziumConnector::default(),
+            topic_prefix: DEFAULT_TOPIC_PREFIX.to_string(),
+        }
+    }
+}
+
+impl DebeziumExporter {
+    /// Create a new Debezium exporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connector used for tables without a supported database type
+    pub fn with_connector(mut self, connector: DebeziumConnector) -> Self {
+        self.connector = connector;
+        self
+    }
+
+    /// Kafka topic prefix of the connectors
+    pub fn with_topic_prefix(mut self, topic_prefix: impl Into<String>) -> Self {
+        self.topic_prefix = topic_prefix.into();
+        self
+    }
+
+    /// Export the CDC metadata of tables to Debezium connector configurations (SDK interface).
+    ///
+    /// Fails if any CDC metadata is inconsistent with its table, or if no table is
+    /// captured with a method Debezium supports.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::debezium::DebeziumExporter;
+    /// use data_modelling_core::models::cdc::{CaptureMethod, CdcConfig};
+    /// use data_modelling_core::models::{Column, Table};
+    ///
+    /// let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+    /// id.primary_key = true;
+    /// let mut table = Table::new("orders".to_string(), vec![id]);
+    /// CdcConfig::new(CaptureMethod::LogBased).set_on_table(&mut table);
+    ///
+    /// let result = DebeziumExporter::new().export(&[table]).unwrap();
+    /// assert!(result.content.contains("PostgresConnector"));
+    /// ```
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        let connectors: Vec<Value> = captured_tables(tables)?
+            .iter()
+            .map(|(table, cdc)| self.connector_config(table, cdc))
+            .collect();
+        let content = serde_json::to_string_pretty(&connectors)
+            .map_err(|e| ExportError::SerializationError(e.to_string()))?;
+        Ok(ExportResult {
+            content,
+            format: "debezium".to_string(),
+        })
+    }
+
+    /// Connector configuration of one table
+    pub fn connector_config(&self, table: &Table, cdc: &CdcConfig) -> Value {
+        let connector =
+            DebeziumConnector::for_database(table.database_type).unwrap_or(self.connector);
+        self.connector_config_for(connector, table, cdc)
+    }
+
+    /// Connector configuration of one table for the given connector
+    pub(crate) fn connector_config_for(
+        &self,
+        connector: DebeziumConnector,
+        table: &Table,
+        cdc: &CdcConfig,
+    ) -> Value {
+        let schema = table
+            .schema_name
+            .as_deref()
+            .or(connector.default_schema())
+            .or(table.catalog_name.as_deref());
+        let qualified = match schema {
+            Some(schema) => format!("{}.{}", schema, table.name),
+            None => table.name.clone(),
+        };
+
+        let mut config = Map::new();
+        let mut set = |key: &str, value: String| {
+            config.insert(key.to_string(), Value::String(value));
+        };
+        set("connector.class", connector.class().to_string());
+        set("tasks.max", "1".to_string());
+        set("database.hostname", "${DATABASE_HOSTNAME}".to_string());
+        set("database.port", connector.port().to_string());
+        set("database.user", "${DATABASE_USER}".to_string());
+        set("database.password", "${DATABASE_PASSWORD}".to_string());
+        match connector {
+            DebeziumConnector::Postgres => {
+                set("database.dbname", "${DATABASE_NAME}".to_string());
+                set("plugin.name", "pgoutput".to_string());
+            }
+            DebeziumConnector::MySql => {
+                set("database.server.id", "${DATABASE_SERVER_ID}".to_string());
+                if let Some(database) = schema {
+                    set("database.include.list", database.to_string());
+                }
+                set(
+                    "schema.history.internal.kafka.bootstrap.servers",
+                    "${KAFKA_BOOTSTRAP_SERVERS}".to_string(),
+                );
+                set(
+                    "schema.history.internal.kafka.topic",
+                    format!("{}.schema-history.{}", self.topic_prefix, table.name),
+                );
+            }
+            DebeziumConnector::SqlServer => {
+                set("database.names", "${DATABASE_NAME}".to_string());
+            }
+        }
+        set("topic.prefix", self.topic_prefix.clone());
+        set("table.include.list", qualified.clone());
+        set(
+            "message.key.columns",
+            format!("{}:{}", qualified, cdc.key_columns(table).join(",")),
+        );
+        set(
+            "snapshot.mode",
+            match cdc.capture_method {
+                CaptureMethod::Snapshot => "initial_only",
+                _ => "initial",
+            }
+            .to_string(),
+        );
+
+        let mut transforms = vec!["unwrap"];
+        set(
+            "transforms.unwrap.type",
+            "io.debezium.transforms.ExtractNewRecordState".to_string(),
+        );
+        set(
+            "transforms.unwrap.add.fields",
+            "op,source.ts_ms".to_string(),
+        );
+        if let Some(column) = &cdc.soft_delete_column {
+            set(
+                "transforms.unwrap.delete.tombstone.handling.mode",
+                "rewrite".to_string(),
+            );
+            transforms.push("softDelete");
+            set(
+                "transforms.softDelete.type",
+                "org.apache.kafka.connect.transforms.ReplaceField$Value".to_string(),
+            );
+            set(
+                "transforms.softDelete.renames",
+                format!("__deleted:{}", column),
+            );
+        }
+        set("transforms", transforms.join(","));
+
+        json!({
+            "name": format!("{}-cdc", table.name.replace('_', "-")),
+            "config": config,
+        })
+    }
+}
+
+/// Tables whose changes Debezium captures, with their CDC metadata
+///
+/// Fails if any CDC metadata is malformed or inconsistent with its table, or if
+/// no table is captured with the `log-based` or `snapshot` method.
+pub(crate) fn captured_tables(tables: &[Table]) -> Result<Vec<(&Table, CdcConfig)>, ExportError> {
+    let mut captured = Vec::new();
+    for table in tables {
+        let cdc = CdcConfig::from_table(table).map_err(|e| {
+            ExportError::ValidationError(format!(
+                "Invalid CDC metadata on table '{}': {}",
+                table.name, e
+            ))
+        })?;
+        let Some(cdc) = cdc else { continue };
+        let violations = cdc.validate(table);
+        if !violations.is_empty() {
+            let messages: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            return Err(ExportError::ValidationError(messages.join("; ")));
+        }
+        if matches!(
+            cdc.capture_method,
+            CaptureMethod::LogBased | CaptureMethod::Snapshot
+        ) {
+            captured.push((table, cdc));
+        }
+    }
+
+    if captured.is_empty() {
+        return Err(ExportError::ExportError(
+            "No tables with log-based or snapshot CDC metadata".to_string(),
+        ));
+    }
+    Ok(captured)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+
+    fn orders(cdc: CdcConfig) -> Table {
+        let mut id = Column::new("order_id".to_string(), "BIGINT".to_string());
+        id.primary_key = true;
+        id.nullable = false;
+        let deleted = Column::new("is_deleted".to_string(), "BOOLEAN".to_string());
+        let mut table = Table::new("sales_orders".to_string(), vec![id, deleted]);
+        cdc.set_on_table(&mut table);
+        table
+    }
+
+    #[test]
+    fn test_export_connector_config() {
+        let mut mysql = orders(CdcConfig::new(CaptureMethod::Snapshot));
+        mysql.database_type = Some(DatabaseType::Mysql);
+        mysql.schema_name = Some("shop".to_string());
+        let trigger = orders(CdcConfig::new(CaptureMethod::Trigger));
+        let postgres =
+            orders(CdcConfig::new(CaptureMethod::LogBased).with_soft_delete_column("is_deleted"));
+
+        let result = DebeziumExporter::new()
+            .with_topic_prefix("erp")
+            .export(&[postgres, trigger, mysql])
+            .unwrap();
+        let connectors: Vec<Value> = serde_json::from_str(&result.content).unwrap();
+        assert_eq!(connectors.len(), 2);
+
+        let postgres = &connectors[0];
+        assert_eq!(postgres["name"], "sales-orders-cdc");
+        let config = &postgres["config"];
+        assert_eq!(
+            config["connector.class"],
+            "io.debezium.connector.postgresql.PostgresConnector"
+        );
+        assert_eq!(config["topic.prefix"], "erp");
+        assert_eq!(config["table.include.list"], "public.sales_orders");
+        assert_eq!(
+            config["message.key.columns"],
+            "public.sales_orders:order_id"
+        );
+        assert_eq!(config["snapshot.mode"], "initial");
+        assert_eq!(config["transforms"], "unwrap,softDelete");
+        assert_eq!(
+            config["transforms.softDelete.renames"],
+            "__deleted:is_deleted"
+        );
+
+        let config = &connectors[1]["config"];
+        assert_eq!(
+            config["connector.class"],
+            "io.debezium.connector.mysql.MySqlConnector"
+        );
+        assert_eq!(config["database.include.list"], "shop");
+        assert_eq!(config["snapshot.mode"], "initial_only");
+        assert_eq!(config["transforms"], "unwrap");
+    }
+
+    #[test]
+    fn test_export_errors() {
+        let plain = Table::new("plain".to_string(), vec![]);
+        assert!(matches!(
+            DebeziumExporter::new().export(&[plain]),
+            Err(ExportError::ExportError(_))
+        ));
+
+        let invalid =
+            orders(CdcConfig::new(CaptureMethod::LogBased).with_sequence_column("missing"));
+        let err = DebeziumExporter::new().export(&[invalid]).unwrap_err();
+        assert!(err.to_string().contains("sequence column does not exist"));
+    }
+}
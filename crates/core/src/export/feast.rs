@@ -0,0 +1,737 @@
+//! Feast feature store exporter
+//!
+//! Generates a [Feast](https://docs.feast.dev/) feature repository (Python) from
+//! tables tagged for the feature store, so feature definitions stay consistent with
+//! the data contracts they are built from:
+//!
+//! - **Entities** - one per distinct primary key; entities with the same join keys
+//!   are shared between feature views
+//! - **Sources** - a batch source per table (`FileSource` by default, or the
+//!   offline store selected with [`FeastSource`]); the event timestamp is the
+//!   `event_timestamp`, `event_time` or `timestamp` column, otherwise the first
+//!   timestamp column
+//! - **Feature views** - one per table, with every other column as a feature and the
+//!   TTL taken from the table's SLAs (`ttl`, then `freshness`, `latency` or
+//!   `retention`)
+//!
+//! Tables are selected by tag: `feast` exports a table under its own name and
+//! `feast:<name>` under a custom feature view name.
+
+use super::{ExportError, ExportResult};
+use crate::models::{BinaryType, Column, Table, Tag, VectorElement, VectorType};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Tag selecting tables for export by default
+pub const FEAST_TAG: &str = "feast";
+
+/// Column names preferred as the event timestamp of a feature view
+const TIMESTAMP_FIELDS: &[&str] = &["event_timestamp", "event_time", "timestamp"];
+
+/// SLA properties used for the feature view TTL, in order of preference
+const TTL_PROPERTIES: &[&str] = &["ttl", "freshness", "latency", "retention"];
+
+/// Offline store the generated batch sources read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeastSource {
+    /// Parquet files (`FileSource`)
+    #[default]
+    File,
+    /// BigQuery tables (`BigQuerySource`)
+    BigQuery,
+    /// Snowflake tables (`SnowflakeSource`)
+    Snowflake,
+    /// Redshift tables (`RedshiftSource`)
+    Redshift,
+    /// Spark / Databricks tables (`SparkSource`)
+    Spark,
+    /// PostgreSQL tables (`PostgreSQLSource`)
+    Postgres,
+}
+
+impl FeastSource {
+    /// Parse a source kind or SQL dialect name, returning `None` if unsupported
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "file" | "parquet" => Some(Self::File),
+            "bigquery" => Some(Self::BigQuery),
+            "snowflake" => Some(Self::Snowflake),
+            "redshift" => Some(Self::Redshift),
+            "spark" | "databricks" => Some(Self::Spark),
+            "postgres" | "postgresql" => Some(Self::Postgres),
+            _ => None,
+        }
+    }
+
+    /// Python class of the batch source
+    fn class_name(&self) -> &'static str {
+        match self {
+            Self::File => "FileSource",
+            Self::BigQuery => "BigQuerySource",
+            Self::Snowflake => "SnowflakeSource",
+            Self::Redshift => "RedshiftSource",
+            Self::Spark => "SparkSource",
+            Self::Postgres => "PostgreSQLSource",
+        }
+    }
+
+    /// Import statement of the batch source class
+    fn import(&self) -> String {
+        let module = match self {
+            Self::Spark => "feast.infra.offline_stores.contrib.spark_offline_store.spark_source",
+            Self::Postgres => {
+                "feast.infra.offline_stores.contrib.postgres_offline_store.postgres_source"
+            }
+            _ => "feast",
+        };
+        format!("from {} import {}", module, self.class_name())
+    }
+
+    /// Keyword arguments locating the table in the offline store
+    fn location(&self, table: &Table) -> Vec<String> {
+        let qualified: Vec<&str> = [
+            table.catalog_name.as_deref(),
+            table.schema_name.as_deref(),
+            Some(table.name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        match self {
+            Self::File => vec![format!(
+                "path={}",
+                python_string(&format!("data/{}.parquet", table.name))
+            )],
+            Self::BigQuery | Self::Spark => {
+                vec![format!("table={}", python_string(&qualified.join(".")))]
+            }
+            Self::Snowflake => {
+                let mut args = Vec::new();
+                if let Some(catalog) = &table.catalog_name {
+                    args.push(format!("database={}", python_string(catalog)));
+                }
+                if let Some(schema) = &table.schema_name {
+                    args.push(format!("schema={}", python_string(schema)));
+                }
+                args.push(format!("table={}", python_string(&table.name)));
+                args
+            }
+            Self::Redshift => {
+                let mut args = Vec::new();
+                if let Some(schema) = &table.schema_name {
+                    args.push(format!("schema={}", python_string(schema)));
+                }
+                args.push(format!("table={}", python_string(&table.name)));
+                args
+            }
+            Self::Postgres => vec![format!(
+                "query={}",
+                python_string(&format!("SELECT * FROM {}", qualified.join(".")))
+            )],
+        }
+    }
+}
+
+/// A Feast entity shared by the feature views keyed on the same columns
+struct Entity {
+    variable: String,
+    name: String,
+    join_keys: Vec<String>,
+    description: String,
+}
+
+/// Exporter for Feast feature repositories.
+#[derive(Debug, Clone)]
+pub struct FeastExporter {
+    tag: String,
+    source: FeastSource,
+}
+
+impl Default for FeastExporter {
+    fn default() -> Self {
+        Self {
+            tag: FEAST_TAG.to_string(),
+            source: FeastSource::default(),
+        }
+    }
+}
+
+impl FeastExporter {
+    /// Create a new Feast exporter selecting tables tagged `feast`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select tables with a different tag
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    /// Read feature data from a different offline store
+    pub fn with_source(mut self, source: FeastSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Export tagged tables to a Feast feature repository (SDK interface).
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        Ok(ExportResult {
+            content: self.export_tables(tables)?,
+            format: "feast".to_string(),
+        })
+    }
+
+    /// Export tagged tables to Python Feast definitions.
+    ///
+    /// Tagged tables need a primary key (the entity join keys) and a timestamp
+    /// column (the event timestamp).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::feast::FeastExporter;
+    /// use data_modelling_core::models::{Column, Table, Tag};
+    ///
+    /// let mut customer_id = Column::new("customer_id".to_string(), "BIGINT".to_string());
+    /// customer_id.primary_key = true;
+    /// let mut table = Table::new(
+    ///     "customer_stats".to_string(),
+    ///     vec![
+    ///         customer_id,
+    ///         Column::new("event_timestamp".to_string(), "TIMESTAMP".to_string()),
+    ///         Column::new("total_orders".to_string(), "INTEGER".to_string()),
+    ///     ],
+    /// );
+    /// table.tags.push(Tag::Simple("feast".to_string()));
+    ///
+    /// let python = FeastExporter::new().export_tables(&[table]).unwrap();
+    /// assert!(python.contains("join_keys=[\"customer_id\"]"));
+    /// assert!(python.contains("Field(name=\"total_orders\", dtype=Int32)"));
+    /// ```
+    pub fn export_tables(&self, tables: &[Table]) -> Result<String, ExportError> {
+        let tagged: Vec<(&Table, String)> = tables
+            .iter()
+            .filter_map(|table| self.view_name(table).map(|name| (table, name)))
+            .collect();
+        if tagged.is_empty() {
+            return Err(ExportError::ValidationError(format!(
+                "No tables tagged '{}' to export as Feast feature views",
+                self.tag
+            )));
+        }
+
+        let mut entities: Vec<Entity> = Vec::new();
+        let mut views = Vec::new();
+        let mut dtypes = BTreeSet::new();
+        for (table, view_name) in &tagged {
+            let keys: Vec<&Column> = {
+                let mut keys: Vec<&Column> =
+                    table.columns.iter().filter(|c| c.primary_key).collect();
+                keys.sort_by_key(|c| c.primary_key_position.unwrap_or(i32::MAX));
+                keys
+            };
+            if keys.is_empty() {
+                return Err(ExportError::ValidationError(format!(
+                    "Table '{}' has no primary key to use as Feast entity key",
+                    table.name
+                )));
+            }
+            let join_keys: Vec<String> = keys.iter().map(|c| c.name.clone()).collect();
+            let entity = match entities.iter().position(|e| e.join_keys == join_keys) {
+                Some(index) => index,
+                None => {
+                    entities.push(Self::entity(table, &keys, &entities));
+                    entities.len() - 1
+                }
+            };
+
+            let timestamp = Self::timestamp_column(table).ok_or_else(|| {
+                ExportError::ValidationError(format!(
+                    "Table '{}' has no timestamp column to use as Feast event timestamp",
+                    table.name
+                ))
+            })?;
+
+            let features: Vec<(&Column, String)> = table
+                .columns
+                .iter()
+                .filter(|c| !c.primary_key && c.name != timestamp.name)
+                .map(|c| (c, feast_type(c)))
+                .collect();
+            for (_, dtype) in &features {
+                dtypes.insert(base_type(dtype).to_string());
+                if dtype.starts_with("Array(") {
+                    dtypes.insert("Array".to_string());
+                }
+            }
+            views.push(self.feature_view(
+                table,
+                view_name,
+                &entities[entity].variable,
+                &timestamp.name,
+                &features,
+            ));
+        }
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "# Feast feature definitions generated by Open Data Modelling SDK {}\n\n",
+            env!("CARGO_PKG_VERSION")
+        ));
+        if views.iter().any(|v| v.contains("ttl=timedelta(")) {
+            out.push_str("from datetime import timedelta\n\n");
+        }
+        match self.source {
+            FeastSource::File
+            | FeastSource::BigQuery
+            | FeastSource::Snowflake
+            | FeastSource::Redshift => out.push_str(&format!(
+                "from feast import Entity, FeatureView, Field, {}\n",
+                self.source.class_name()
+            )),
+            FeastSource::Spark | FeastSource::Postgres => {
+                out.push_str("from feast import Entity, FeatureView, Field\n");
+                out.push_str(&self.source.import());
+                out.push('\n');
+            }
+        }
+        if !dtypes.is_empty() {
+            let dtypes: Vec<String> = dtypes.into_iter().collect();
+            out.push_str(&format!("from feast.types import {}\n", dtypes.join(", ")));
+        }
+
+        for entity in &entities {
+            out.push_str(&format!(
+                "\n{} = Entity(\n    name={},\n    join_keys=[{}],\n",
+                entity.variable,
+                python_string(&entity.name),
+                entity
+                    .join_keys
+                    .iter()
+                    .map(|k| python_string(k))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            if !entity.description.is_empty() {
+                out.push_str(&format!(
+                    "    description={},\n",
+                    python_string(&entity.description)
+                ));
+            }
+            out.push_str(")\n");
+        }
+        for view in views {
+            out.push('\n');
+            out.push_str(&view);
+        }
+        Ok(out)
+    }
+
+    /// Feature view name of a table tagged for export
+    fn view_name(&self, table: &Table) -> Option<String> {
+        table.tags.iter().find_map(|tag| match tag {
+            Tag::Simple(name) if name.eq_ignore_ascii_case(&self.tag) => Some(table.name.clone()),
+            Tag::Pair(key, value) if key.eq_ignore_ascii_case(&self.tag) => Some(value.clone()),
+            _ => None,
+        })
+    }
+
+    /// Entity for the primary key of a table, named after a single `<name>_id` key
+    fn entity(table: &Table, keys: &[&Column], existing: &[Entity]) -> Entity {
+        let name = match keys {
+            [key] => key
+                .name
+                .strip_suffix("_id")
+                .filter(|n| !n.is_empty())
+                .unwrap_or(&table.name)
+                .to_string(),
+            _ => table.name.clone(),
+        };
+        let mut variable = python_identifier(&name);
+        while existing.iter().any(|e| e.variable == variable) {
+            variable.push('_');
+        }
+        Entity {
+            variable,
+            name,
+            join_keys: keys.iter().map(|c| c.name.clone()).collect(),
+            description: match keys {
+                [key] => key.description.clone(),
+                _ => String::new(),
+            },
+        }
+    }
+
+    /// Event timestamp column of a table
+    fn timestamp_column(table: &Table) -> Option<&Column> {
+        TIMESTAMP_FIELDS
+            .iter()
+            .find_map(|name| {
+                table
+                    .columns
+                    .iter()
+                    .find(|c| c.name.eq_ignore_ascii_case(name))
+            })
+            .or_else(|| {
+                table
+                    .columns
+                    .iter()
+                    .find(|c| !c.primary_key && is_timestamp_type(&c.data_type))
+            })
+    }
+
+    fn feature_view(
+        &self,
+        table: &Table,
+        view_name: &str,
+        entity: &str,
+        timestamp_field: &str,
+        features: &[(&Column, String)],
+    ) -> String {
+        let variable = python_identifier(view_name);
+        let source_variable = format!("{}_source", variable);
+
+        let mut out = format!(
+            "{} = {}(\n    name={},\n",
+            source_variable,
+            self.source.class_name(),
+            python_string(&format!("{}_source", view_name))
+        );
+        for arg in self.source.location(table) {
+            out.push_str(&format!("    {},\n", arg));
+        }
+        out.push_str(&format!(
+            "    timestamp_field={},\n)\n\n",
+            python_string(timestamp_field)
+        ));
+
+        out.push_str(&format!(
+            "{} = FeatureView(\n    name={},\n    entities=[{}],\n",
+            variable,
+            python_string(view_name),
+            entity
+        ));
+        if let Some(ttl) = table_ttl(table) {
+            out.push_str(&format!("    ttl={},\n", format_timedelta(ttl)));
+        }
+        out.push_str("    schema=[\n");
+        for (column, dtype) in features {
+            let mut field = format!(
+                "Field(name={}, dtype={}",
+                python_string(&column.name),
+                dtype
+            );
+            if !column.description.is_empty() {
+                field.push_str(&format!(
+                    ", description={}",
+                    python_string(&column.description)
+                ));
+            }
+            out.push_str(&format!("        {}),\n", field));
+        }
+        out.push_str("    ],\n    online=True,\n");
+        out.push_str(&format!("    source={},\n", source_variable));
+        if let Some(description) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(Value::as_str)
+            .filter(|d| !d.is_empty())
+        {
+            out.push_str(&format!(
+                "    description={},\n",
+                python_string(description)
+            ));
+        }
+        if let Some(owner) = &table.owner {
+            out.push_str(&format!("    owner={},\n", python_string(owner)));
+        }
+        let tags: Vec<String> = table
+            .tags
+            .iter()
+            .filter_map(|tag| match tag {
+                Tag::Pair(key, value) if !key.eq_ignore_ascii_case(&self.tag) => {
+                    Some(format!("{}: {}", python_string(key), python_string(value)))
+                }
+                _ => None,
+            })
+            .collect();
+        if !tags.is_empty() {
+            out.push_str(&format!("    tags={{{}}},\n", tags.join(", ")));
+        }
+        out.push_str(")\n");
+        out
+    }
+}
+
+/// Feast type of a column (`feast.types`)
+fn feast_type(column: &Column) -> String {
+    if let Some(vector) = VectorType::from_column(column) {
+        let element = match vector.element {
+            VectorElement::Float => "Float32",
+            VectorElement::Double => "Float64",
+            VectorElement::Integer => "Int32",
+        };
+        return format!("Array({})", element);
+    }
+    if BinaryType::parse(&column.data_type).is_some() {
+        return "Bytes".to_string();
+    }
+
+    let data_type = column.data_type.trim().to_lowercase();
+    if let Some(element) = data_type
+        .strip_prefix("array<")
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        let element = Column::new(String::new(), element.to_string());
+        return format!("Array({})", feast_type(&element));
+    }
+    let base = data_type
+        .split(['(', '<'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    match base {
+        "tinyint" | "smallint" | "int" | "integer" | "int32" => "Int32",
+        "bigint" | "long" | "int64" => "Int64",
+        "float" | "real" | "float32" | "float4" => "Float32",
+        "double" | "double precision" | "float64" | "float8" | "decimal" | "numeric" | "number" => {
+            "Float64"
+        }
+        "bool" | "boolean" => "Bool",
+        _ if is_timestamp_type(base) => "UnixTimestamp",
+        _ => "String",
+    }
+    .to_string()
+}
+
+/// Type name without `Array(...)`
+fn base_type(dtype: &str) -> &str {
+    let mut dtype = dtype;
+    while let Some(inner) = dtype
+        .strip_prefix("Array(")
+        .and_then(|d| d.strip_suffix(')'))
+    {
+        dtype = inner;
+    }
+    dtype
+}
+
+fn is_timestamp_type(data_type: &str) -> bool {
+    let data_type = data_type.trim().to_lowercase();
+    data_type.starts_with("timestamp") || data_type.starts_with("datetime") || data_type == "date"
+}
+
+/// TTL in seconds from the first matching SLA of a table
+///
+/// SLAs come from the table itself or from the `serviceLevels`/`servicelevels`
+/// metadata of an imported contract.
+fn table_ttl(table: &Table) -> Option<u64> {
+    let mut slas: Vec<(String, Value, String)> = table
+        .sla
+        .iter()
+        .flatten()
+        .map(|sla| (sla.property.clone(), sla.value.clone(), sla.unit.clone()))
+        .collect();
+    for key in ["serviceLevels", "servicelevels"] {
+        if let Some(Value::Array(levels)) = table.odcl_metadata.get(key) {
+            slas.extend(levels.iter().filter_map(|level| {
+                Some((
+                    level.get("property")?.as_str()?.to_string(),
+                    level.get("value")?.clone(),
+                    level
+                        .get("unit")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                ))
+            }));
+        }
+    }
+
+    TTL_PROPERTIES.iter().find_map(|property| {
+        slas.iter()
+            .filter(|(name, _, _)| name.eq_ignore_ascii_case(property))
+            .find_map(|(_, value, unit)| duration_seconds(value, unit))
+    })
+}
+
+/// Seconds in an SLA value, or `None` if the value or unit is not a duration
+fn duration_seconds(value: &Value, unit: &str) -> Option<u64> {
+    let amount = match value {
+        Value::Number(n) => n.as_f64()?,
+        Value::String(s) => s.trim().parse().ok()?,
+        _ => return None,
+    };
+    let seconds_per_unit = match unit.trim().to_lowercase().as_str() {
+        "s" | "sec" | "secs" | "second" | "seconds" => 1,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600,
+        "d" | "day" | "days" => 86_400,
+        "w" | "week" | "weeks" => 604_800,
+        "y" | "yr" | "yrs" | "year" | "years" => 31_536_000,
+        _ => return None,
+    };
+    (amount >= 0.0).then(|| (amount * seconds_per_unit as f64).round() as u64)
+}
+
+/// Python `timedelta` in the largest whole unit
+fn format_timedelta(seconds: u64) -> String {
+    match seconds {
+        0 => "timedelta(0)".to_string(),
+        s if s % 86_400 == 0 => format!("timedelta(days={})", s / 86_400),
+        s if s % 3_600 == 0 => format!("timedelta(hours={})", s / 3_600),
+        s if s % 60 == 0 => format!("timedelta(minutes={})", s / 60),
+        s => format!("timedelta(seconds={})", s),
+    }
+}
+
+/// Python identifier for a name (`order-items` -> `order_items`)
+fn python_identifier(name: &str) -> String {
+    let mut identifier: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    identifier
+}
+
+/// Double-quoted Python string literal
+fn python_string(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SlaProperty;
+    use serde_json::json;
+
+    fn feature_table(name: &str, key: &str) -> Table {
+        let mut id = Column::new(key.to_string(), "BIGINT".to_string());
+        id.primary_key = true;
+        id.description = "Customer identifier".to_string();
+        let mut table = Table::new(
+            name.to_string(),
+            vec![
+                id,
+                Column::new("event_timestamp".to_string(), "TIMESTAMP".to_string()),
+                Column::new("lifetime_value".to_string(), "DECIMAL(12,2)".to_string()),
+                Column::new("embedding".to_string(), "vector(3)".to_string()),
+            ],
+        );
+        table.tags.push(Tag::Simple("feast".to_string()));
+        table
+    }
+
+    #[test]
+    fn test_export_feature_views() {
+        let mut stats = feature_table("customer_stats", "customer_id");
+        stats.sla = Some(vec![SlaProperty {
+            property: "latency".to_string(),
+            value: json!(4),
+            unit: "h".to_string(),
+            element: None,
+            driver: None,
+            description: None,
+            scheduler: None,
+            schedule: None,
+        }]);
+        stats.owner = Some("ml-team@example.com".to_string());
+        stats
+            .tags
+            .push(Tag::Pair("team".to_string(), "growth".to_string()));
+
+        let mut profile = feature_table("customer_profile", "customer_id");
+        profile.tags = vec![Tag::Pair("feast".to_string(), "profiles".to_string())];
+        profile.odcl_metadata.insert(
+            "serviceLevels".to_string(),
+            json!([{"property": "retention", "value": 1, "unit": "y"}]),
+        );
+
+        let untagged = Table::new("audit_log".to_string(), vec![]);
+
+        let python = FeastExporter::new()
+            .export_tables(&[stats, profile, untagged])
+            .unwrap();
+
+        assert!(python.contains("from datetime import timedelta\n"));
+        assert!(python.contains("from feast import Entity, FeatureView, Field, FileSource\n"));
+        assert!(python.contains("from feast.types import Array, Float32, Float64\n"));
+        assert_eq!(python.matches("= Entity(").count(), 1);
+        assert!(python.contains(
+            "customer = Entity(\n    name=\"customer\",\n    join_keys=[\"customer_id\"],\n    description=\"Customer identifier\",\n)\n"
+        ));
+        assert!(python.contains(
+            "    path=\"data/customer_stats.parquet\",\n    timestamp_field=\"event_timestamp\",\n"
+        ));
+        assert!(python.contains("    ttl=timedelta(hours=4),\n"));
+        assert!(python.contains("        Field(name=\"lifetime_value\", dtype=Float64),\n"));
+        assert!(python.contains("        Field(name=\"embedding\", dtype=Array(Float32)),\n"));
+        assert!(
+            python
+                .contains("    owner=\"ml-team@example.com\",\n    tags={\"team\": \"growth\"},\n")
+        );
+        assert!(python.contains("profiles = FeatureView(\n    name=\"profiles\",\n    entities=[customer],\n    ttl=timedelta(days=365),\n"));
+        assert!(!python.contains("audit_log"));
+    }
+
+    #[test]
+    fn test_offline_store_sources() {
+        let mut table = feature_table("customer_stats", "customer_id");
+        table.catalog_name = Some("analytics".to_string());
+        table.schema_name = Some("features".to_string());
+
+        let snowflake = FeastExporter::new()
+            .with_source(FeastSource::Snowflake)
+            .export_tables(std::slice::from_ref(&table))
+            .unwrap();
+        assert!(snowflake.contains(
+            "    database=\"analytics\",\n    schema=\"features\",\n    table=\"customer_stats\",\n"
+        ));
+
+        let spark = FeastExporter::new()
+            .with_source(FeastSource::parse("databricks").unwrap())
+            .export_tables(&[table])
+            .unwrap();
+        assert!(spark.contains("import SparkSource\n"));
+        assert!(spark.contains("    table=\"analytics.features.customer_stats\",\n"));
+        assert!(!spark.contains("timedelta"));
+    }
+
+    #[test]
+    fn test_missing_keys_and_tags() {
+        let mut table = feature_table("customer_stats", "customer_id");
+        table.columns[0].primary_key = false;
+        let err = FeastExporter::new().export_tables(&[table]).unwrap_err();
+        assert!(err.to_string().contains("no primary key"));
+
+        let table = feature_table("customer_stats", "customer_id");
+        let err = FeastExporter::new()
+            .with_tag("features")
+            .export_tables(&[table])
+            .unwrap_err();
+        assert!(err.to_string().contains("No tables tagged 'features'"));
+    }
+}
@@ -0,0 +1,472 @@
+//! HTML contract viewer exporter
+//!
+//! Renders an ODCS contract as a single self-contained HTML file. CSS and
+//! JavaScript are embedded, so the file can be emailed or attached to a ticket
+//! and opened in any browser without the app or repository access.
+//!
+//! The viewer shows the contract metadata, team and service levels, and one
+//! collapsible section per schema object. Nested properties (objects and array
+//! items) are collapsible as well, and a search box filters properties by name,
+//! type or description.
+
+use crate::export::ExportError;
+use crate::models::Table;
+use crate::models::odcs::{ODCSContract, Property, QualityRule, SchemaObject};
+
+/// Embedded stylesheet for the viewer
+const VIEWER_CSS: &str = r#"
+:root { --brand: #0066cc; --muted: #6b7280; --border: #e5e7eb; }
+* { box-sizing: border-box; }
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; margin: 0; color: #111827; background: #f9fafb; }
+header { background: var(--brand); color: #fff; padding: 24px 32px; }
+header h1 { margin: 0 0 4px 0; font-size: 1.6em; }
+header .meta { opacity: 0.9; font-size: 0.9em; }
+main { max-width: 1100px; margin: 0 auto; padding: 24px 32px; }
+section { background: #fff; border: 1px solid var(--border); border-radius: 6px; padding: 16px 20px; margin-bottom: 16px; }
+h2 { font-size: 1.15em; margin: 0 0 12px 0; }
+.tag { display: inline-block; background: #eef2ff; color: #3730a3; border-radius: 10px; padding: 1px 8px; margin: 0 4px 4px 0; font-size: 0.8em; }
+.badge { display: inline-block; border-radius: 4px; padding: 0 6px; margin-left: 4px; font-size: 0.75em; background: #f3f4f6; color: #374151; }
+.badge.pk { background: #fef3c7; color: #92400e; }
+.badge.req { background: #fee2e2; color: #991b1b; }
+.badge.cls { background: #dcfce7; color: #166534; }
+table { border-collapse: collapse; width: 100%; font-size: 0.9em; }
+th, td { text-align: left; padding: 6px 8px; border-bottom: 1px solid var(--border); vertical-align: top; }
+th { color: var(--muted); font-weight: 600; }
+details { margin: 2px 0; }
+details > summary { cursor: pointer; padding: 4px 0; }
+details.schema > summary { font-weight: 600; font-size: 1.05em; }
+.property { padding: 4px 0 4px 12px; border-left: 2px solid var(--border); }
+.property .name { font-family: SFMono-Regular, Consolas, monospace; font-weight: 600; }
+.property .type { color: var(--muted); font-family: SFMono-Regular, Consolas, monospace; margin-left: 6px; }
+.property .desc { color: #374151; font-size: 0.9em; margin: 2px 0 0 0; }
+.children { margin-left: 16px; }
+#search { width: 100%; padding: 8px 12px; font-size: 1em; border: 1px solid var(--border); border-radius: 6px; margin-bottom: 16px; }
+.hidden { display: none; }
+.muted { color: var(--muted); }
+footer { text-align: center; color: var(--muted); font-size: 0.8em; padding: 16px; }
+"#;
+
+/// Embedded script implementing the property search
+const VIEWER_JS: &str = r#"
+(function () {
+  var input = document.getElementById('search');
+  if (!input) { return; }
+  input.addEventListener('input', function () {
+    var query = input.value.trim().toLowerCase();
+    var props = document.querySelectorAll('.property');
+    props.forEach(function (el) { el.classList.remove('hidden'); });
+    if (!query) { return; }
+    props.forEach(function (el) {
+      var text = (el.getAttribute('data-search') || '');
+      var nested = el.querySelectorAll('.property');
+      var childMatch = Array.prototype.some.call(nested, function (c) {
+        return (c.getAttribute('data-search') || '').indexOf(query) !== -1;
+      });
+      if (text.indexOf(query) === -1 && !childMatch) {
+        el.classList.add('hidden');
+      } else if (childMatch) {
+        el.querySelectorAll('details').forEach(function (d) { d.open = true; });
+      }
+    });
+    document.querySelectorAll('details.schema').forEach(function (d) { d.open = true; });
+  });
+})();
+"#;
+
+/// Exporter producing a self-contained HTML viewer for ODCS contracts
+#[derive(Debug, Clone, Default)]
+pub struct HtmlExporter {
+    brand_color: Option<String>,
+}
+
+impl HtmlExporter {
+    /// Create a new HTML exporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use a custom brand color (hex, e.g. "#0066CC") for the page header
+    pub fn with_brand_color(mut self, color: impl Into<String>) -> Self {
+        self.brand_color = Some(color.into());
+        self
+    }
+
+    /// Export a table as a single-file HTML contract viewer
+    pub fn export_table(&self, table: &Table) -> Result<String, ExportError> {
+        self.export_contract(&ODCSContract::from_table(table))
+    }
+
+    /// Export an ODCS contract as a single-file HTML contract viewer
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::html::HtmlExporter;
+    /// use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+    ///
+    /// let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+    ///     SchemaObject::new("orders").with_properties(vec![Property::new("id", "integer")]),
+    /// );
+    /// let html = HtmlExporter::new().export_contract(&contract).unwrap();
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// ```
+    pub fn export_contract(&self, contract: &ODCSContract) -> Result<String, ExportError> {
+        if let Some(color) = &self.brand_color
+            && !is_hex_color(color)
+        {
+            return Err(ExportError::InvalidArgument(format!(
+                "Invalid brand color: {}",
+                color
+            )));
+        }
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        html.push_str("<meta charset=\"utf-8\">\n");
+        html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+        html.push_str(&format!(
+            "<meta name=\"generator\" content=\"Open Data Modelling SDK {}\">\n",
+            env!("CARGO_PKG_VERSION")
+        ));
+        html.push_str(&format!(
+            "<title>{} {}</title>\n",
+            escape(&contract.name),
+            escape(&contract.version)
+        ));
+        html.push_str("<style>");
+        html.push_str(VIEWER_CSS);
+        if let Some(color) = &self.brand_color {
+            html.push_str(&format!(":root {{ --brand: {}; }}\n", color));
+        }
+        html.push_str("</style>\n</head>\n<body>\n");
+
+        self.render_header(&mut html, contract);
+
+        html.push_str("<main>\n");
+        html.push_str(
+            "<input id=\"search\" type=\"search\" placeholder=\"Search properties by name, type or description\">\n",
+        );
+        self.render_overview(&mut html, contract);
+        for schema in &contract.schema {
+            self.render_schema(&mut html, schema);
+        }
+        self.render_service_levels(&mut html, contract);
+        self.render_quality(&mut html, "Quality", &contract.quality);
+        html.push_str("</main>\n");
+
+        html.push_str(
+            "<footer>Generated by Open Data Modelling &middot; Open Data Contract Standard</footer>\n",
+        );
+        html.push_str("<script>");
+        html.push_str(VIEWER_JS);
+        html.push_str("</script>\n</body>\n</html>\n");
+
+        Ok(html)
+    }
+
+    fn render_header(&self, html: &mut String, contract: &ODCSContract) {
+        html.push_str("<header>\n");
+        html.push_str(&format!("<h1>{}</h1>\n", escape(&contract.name)));
+        let mut meta = vec![format!("Version {}", escape(&contract.version))];
+        if let Some(status) = &contract.status {
+            meta.push(format!("Status: {}", escape(status)));
+        }
+        if let Some(domain) = &contract.domain {
+            meta.push(format!("Domain: {}", escape(domain)));
+        }
+        if let Some(product) = &contract.data_product {
+            meta.push(format!("Data product: {}", escape(product)));
+        }
+        html.push_str(&format!(
+            "<div class=\"meta\">{}</div>\n",
+            meta.join(" &middot; ")
+        ));
+        html.push_str("</header>\n");
+    }
+
+    fn render_overview(&self, html: &mut String, contract: &ODCSContract) {
+        html.push_str("<section>\n<h2>Overview</h2>\n");
+        match &contract.description {
+            Some(description) if !description.as_string().trim().is_empty() => {
+                html.push_str(&format!("<p>{}</p>\n", escape(&description.as_string())));
+            }
+            _ => html.push_str("<p class=\"muted\">No description.</p>\n"),
+        }
+
+        if !contract.tags.is_empty() {
+            html.push_str("<p>");
+            for tag in &contract.tags {
+                html.push_str(&format!("<span class=\"tag\">{}</span>", escape(tag)));
+            }
+            html.push_str("</p>\n");
+        }
+
+        if let Some(team) = &contract.team
+            && (team.name.is_some() || !team.members.is_empty())
+        {
+            html.push_str("<h2>Team</h2>\n");
+            if let Some(name) = &team.name {
+                html.push_str(&format!("<p>{}</p>\n", escape(name)));
+            }
+            if !team.members.is_empty() {
+                html.push_str("<table>\n<tr><th>Name</th><th>Email</th><th>Role</th></tr>\n");
+                for member in &team.members {
+                    html.push_str(&format!(
+                        "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                        escape(member.name.as_deref().unwrap_or("-")),
+                        escape(member.email.as_deref().unwrap_or("-")),
+                        escape(member.role.as_deref().unwrap_or("-"))
+                    ));
+                }
+                html.push_str("</table>\n");
+            }
+        }
+        html.push_str("</section>\n");
+    }
+
+    fn render_schema(&self, html: &mut String, schema: &SchemaObject) {
+        html.push_str("<section>\n<details class=\"schema\" open>\n");
+        html.push_str(&format!(
+            "<summary>{} <span class=\"muted\">({} properties)</span></summary>\n",
+            escape(&schema.name),
+            schema.properties.len()
+        ));
+        if let Some(description) = &schema.description {
+            html.push_str(&format!("<p>{}</p>\n", escape(description)));
+        }
+        if let Some(physical) = &schema.physical_name {
+            html.push_str(&format!(
+                "<p class=\"muted\">Physical name: <code>{}</code></p>\n",
+                escape(physical)
+            ));
+        }
+        for property in &schema.properties {
+            self.render_property(html, property);
+        }
+        html.push_str("</details>\n");
+        self.render_quality(html, &format!("{} quality", schema.name), &schema.quality);
+        html.push_str("</section>\n");
+    }
+
+    fn render_property(&self, html: &mut String, property: &Property) {
+        let type_label = match &property.physical_type {
+            Some(physical) if !physical.eq_ignore_ascii_case(&property.logical_type) => {
+                format!("{} ({})", property.logical_type, physical)
+            }
+            _ => property.logical_type.clone(),
+        };
+        let search_text = format!(
+            "{} {} {} {}",
+            property.name,
+            property.business_name.as_deref().unwrap_or(""),
+            type_label,
+            property.description.as_deref().unwrap_or("")
+        )
+        .to_lowercase();
+
+        let mut line = format!(
+            "<span class=\"name\">{}</span><span class=\"type\">{}</span>",
+            escape(&property.name),
+            escape(&type_label)
+        );
+        if property.primary_key {
+            line.push_str("<span class=\"badge pk\">PK</span>");
+        }
+        if property.required {
+            line.push_str("<span class=\"badge req\">required</span>");
+        }
+        if property.unique {
+            line.push_str("<span class=\"badge\">unique</span>");
+        }
+        if let Some(classification) = &property.classification {
+            line.push_str(&format!(
+                "<span class=\"badge cls\">{}</span>",
+                escape(classification)
+            ));
+        }
+        for tag in &property.tags {
+            line.push_str(&format!("<span class=\"tag\">{}</span>", escape(tag)));
+        }
+
+        html.push_str(&format!(
+            "<div class=\"property\" data-search=\"{}\">\n",
+            escape(&search_text)
+        ));
+
+        let children: Vec<&Property> = property
+            .properties
+            .iter()
+            .chain(property.items.as_deref())
+            .collect();
+        if children.is_empty() {
+            html.push_str(&line);
+            html.push('\n');
+            self.render_property_details(html, property);
+        } else {
+            html.push_str(&format!("<details>\n<summary>{}</summary>\n", line));
+            self.render_property_details(html, property);
+            html.push_str("<div class=\"children\">\n");
+            for child in children {
+                self.render_property(html, child);
+            }
+            html.push_str("</div>\n</details>\n");
+        }
+        html.push_str("</div>\n");
+    }
+
+    fn render_property_details(&self, html: &mut String, property: &Property) {
+        if let Some(description) = &property.description {
+            html.push_str(&format!("<p class=\"desc\">{}</p>\n", escape(description)));
+        }
+        if !property.enum_values.is_empty() {
+            html.push_str(&format!(
+                "<p class=\"desc\">Allowed values: {}</p>\n",
+                escape(&property.enum_values.join(", "))
+            ));
+        }
+        if !property.examples.is_empty() {
+            let examples: Vec<String> = property
+                .examples
+                .iter()
+                .map(|v| match v {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+            html.push_str(&format!(
+                "<p class=\"desc\">Examples: {}</p>\n",
+                escape(&examples.join(", "))
+            ));
+        }
+    }
+
+    fn render_service_levels(&self, html: &mut String, contract: &ODCSContract) {
+        if contract.service_levels.is_empty() {
+            return;
+        }
+        html.push_str("<section>\n<h2>Service Levels</h2>\n<table>\n");
+        html.push_str("<tr><th>Property</th><th>Value</th><th>Description</th></tr>\n");
+        for sla in &contract.service_levels {
+            let value = match (&sla.value, &sla.unit) {
+                (Some(value), Some(unit)) => format!("{} {}", json_display(value), unit),
+                (Some(value), None) => json_display(value),
+                (None, _) => "-".to_string(),
+            };
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(sla.property.as_deref().unwrap_or("-")),
+                escape(&value),
+                escape(sla.description.as_deref().unwrap_or(""))
+            ));
+        }
+        html.push_str("</table>\n</section>\n");
+    }
+
+    fn render_quality(&self, html: &mut String, title: &str, rules: &[QualityRule]) {
+        if rules.is_empty() {
+            return;
+        }
+        html.push_str(&format!("<h2>{}</h2>\n<table>\n", escape(title)));
+        html.push_str("<tr><th>Type</th><th>Dimension</th><th>Description</th></tr>\n");
+        for rule in rules {
+            let description = rule
+                .description
+                .as_deref()
+                .or(rule.metric.as_deref())
+                .unwrap_or("");
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(rule.rule_type.as_deref().unwrap_or("-")),
+                escape(rule.dimension.as_deref().unwrap_or("-")),
+                escape(description)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+}
+
+/// Escape text for use in HTML element content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn json_display(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::{ODCSContract, Property, SchemaObject};
+
+    fn sample_contract() -> ODCSContract {
+        ODCSContract::new("customer-orders", "2.1.0")
+            .with_domain("sales")
+            .with_schema(SchemaObject::new("orders").with_properties(vec![
+                    Property::new("order_id", "integer")
+                        .with_primary_key(true)
+                        .with_description("Unique <order> identifier"),
+                    Property::new("shipping", "object").with_nested_properties(vec![
+                        Property::new("street", "string"),
+                        Property::new("city", "string"),
+                    ]),
+                ]))
+    }
+
+    #[test]
+    fn test_export_contract_is_self_contained() {
+        let html = HtmlExporter::new()
+            .export_contract(&sample_contract())
+            .unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<script>"));
+        assert!(!html.contains("<link "));
+        assert!(!html.contains("src=\"http"));
+        assert!(html.contains("<h1>customer-orders</h1>"));
+        assert!(html.contains("Domain: sales"));
+        assert!(html.contains("id=\"search\""));
+        // Description text is escaped
+        assert!(html.contains("Unique &lt;order&gt; identifier"));
+        // Nested properties are rendered in a collapsible block
+        let shipping = html.find("<span class=\"name\">shipping</span>").unwrap();
+        let city = html.find("<span class=\"name\">city</span>").unwrap();
+        assert!(shipping < city);
+        assert!(html[..shipping].ends_with("<details>\n<summary>"));
+    }
+
+    #[test]
+    fn test_brand_color() {
+        let html = HtmlExporter::new()
+            .with_brand_color("#AA0000")
+            .export_contract(&sample_contract())
+            .unwrap();
+        assert!(html.contains(":root { --brand: #AA0000; }"));
+
+        let err = HtmlExporter::new()
+            .with_brand_color("red;}</style>")
+            .export_contract(&sample_contract());
+        assert!(matches!(err, Err(ExportError::InvalidArgument(_))));
+    }
+}
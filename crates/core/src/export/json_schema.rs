@@ -1,8 +1,14 @@
 //! JSON Schema exporter for generating JSON Schema from data models.
 
+use super::registry::ExportOptions;
 use super::{ExportError, ExportResult};
-use crate::models::{Column, DataModel, Table};
-use serde_json::{Value, json};
+use crate::models::definitions::{DEFINITION_PROPERTY, PropertyDefinitions};
+use crate::models::{
+    BinaryType, Column, DataModel, SpatialType, Table, TypeMappingRegistry, VectorType,
+};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
 
 /// Extract $ref path from column relationships.
 /// Returns the first foreignKey relationship as a $ref path.
@@ -17,6 +23,127 @@ fn get_ref_path_from_relationships(column: &Column) -> Option<String> {
     })
 }
 
+/// JSON Schema draft targeted by the exporter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonSchemaDraft {
+    /// Draft-07 with `definitions`
+    #[default]
+    Draft07,
+    /// Draft 2019-09 with `$defs`
+    Draft201909,
+    /// Draft 2020-12 with `$defs`
+    Draft202012,
+}
+
+impl JsonSchemaDraft {
+    /// Meta-schema URI written to `$schema`
+    pub fn schema_uri(&self) -> &'static str {
+        match self {
+            Self::Draft07 => "http://json-schema.org/draft-07/schema#",
+            Self::Draft201909 => "https://json-schema.org/draft/2019-09/schema",
+            Self::Draft202012 => "https://json-schema.org/draft/2020-12/schema",
+        }
+    }
+
+    /// Keyword holding reusable schemas (`definitions` or `$defs`)
+    pub fn definitions_keyword(&self) -> &'static str {
+        match self {
+            Self::Draft07 => "definitions",
+            Self::Draft201909 | Self::Draft202012 => "$defs",
+        }
+    }
+}
+
+impl std::str::FromStr for JsonSchemaDraft {
+    type Err = ExportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .trim_start_matches("draft")
+            .trim_matches('-')
+        {
+            "07" | "7" => Ok(Self::Draft07),
+            "2019-09" => Ok(Self::Draft201909),
+            "2020-12" => Ok(Self::Draft202012),
+            _ => Err(ExportError::InvalidArgument(format!(
+                "Unknown JSON Schema draft: {} (expected draft-07, 2019-09 or 2020-12)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Options for JSON Schema output
+///
+/// The default reproduces [`JSONSchemaExporter::export`].
+///
+/// # Example
+///
+/// ```rust
+/// use data_modelling_core::export::json_schema::{
+///     JSONSchemaExporter, JsonSchemaDraft, JsonSchemaOptions,
+/// };
+/// use data_modelling_core::models::{Column, Table};
+///
+/// let tables = vec![
+///     Table::new("users".to_string(), vec![Column::new("tenant_id".to_string(), "UUID".to_string())]),
+///     Table::new("orders".to_string(), vec![Column::new("tenant_id".to_string(), "UUID".to_string())]),
+/// ];
+/// let options = JsonSchemaOptions {
+///     draft: JsonSchemaDraft::Draft202012,
+///     shared_definitions: true,
+///     ..Default::default()
+/// };
+///
+/// let result = JSONSchemaExporter.export_with_options(&tables, &options).unwrap();
+/// assert!(result.content.contains("https://json-schema.org/draft/2020-12/schema"));
+/// assert!(result.content.contains("\"$ref\": \"#/$defs/tenant_id\""));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonSchemaOptions {
+    /// Draft to target
+    pub draft: JsonSchemaDraft,
+    /// Emit column types shared between tables once under `definitions`/`$defs`
+    /// and reference them with `$ref`
+    pub shared_definitions: bool,
+    /// Data type mappings (the built-in mappings when `None`)
+    pub type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+impl JsonSchemaOptions {
+    /// Read JSON Schema options from exporter registry options.
+    ///
+    /// The draft comes from the format version (`draft-07`, `2019-09` or
+    /// `2020-12`); the `shared_definitions` property (`true` or `false`) enables
+    /// shared column types. The type mappings are taken from the options.
+    pub fn from_export_options(options: &ExportOptions) -> Result<Self, ExportError> {
+        let mut json_options = Self {
+            type_mappings: options.type_mappings.clone(),
+            ..Self::default()
+        };
+        if let Some(version) = &options.version {
+            json_options.draft = version.parse()?;
+        }
+        if let Some(shared) = options.property("shared_definitions") {
+            json_options.shared_definitions = shared.trim().parse().map_err(|_| {
+                ExportError::InvalidArgument(format!(
+                    "Invalid shared_definitions value: {} (expected true or false)",
+                    shared
+                ))
+            })?;
+        }
+        Ok(json_options)
+    }
+
+    fn type_mappings(&self) -> &TypeMappingRegistry {
+        self.type_mappings
+            .as_deref()
+            .unwrap_or(TypeMappingRegistry::builtin())
+    }
+}
+
 /// Exporter for JSON Schema format.
 pub struct JSONSchemaExporter;
 
@@ -47,7 +174,21 @@ impl JSONSchemaExporter {
     /// assert!(result.content.contains("\"definitions\""));
     /// ```
     pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
-        let schema = Self::export_model_from_tables(tables);
+        self.export_with_options(tables, &JsonSchemaOptions::default())
+    }
+
+    /// Export tables to JSON Schema format for a given draft.
+    ///
+    /// Tables are placed under `definitions` (draft-07) or `$defs` (2019-09 and
+    /// 2020-12). With `shared_definitions`, columns resolved from the same
+    /// workspace definition, or with the same name and schema in several tables,
+    /// are emitted once next to the tables and referenced with `$ref`.
+    pub fn export_with_options(
+        &self,
+        tables: &[Table],
+        options: &JsonSchemaOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let schema = Self::export_model_from_tables(tables, options);
         let content = serde_json::to_string_pretty(&schema)
             .map_err(|e| ExportError::SerializationError(e.to_string()))?;
 
@@ -66,20 +207,32 @@ impl JSONSchemaExporter {
         })
     }
 
-    fn export_model_from_tables(tables: &[Table]) -> serde_json::Value {
-        let mut definitions = serde_json::Map::new();
+    fn export_model_from_tables(tables: &[Table], options: &JsonSchemaOptions) -> Value {
+        let draft = options.draft;
+        let type_mappings = options.type_mappings();
+        let shared = if options.shared_definitions {
+            SharedTypes::collect(tables, type_mappings)
+        } else {
+            SharedTypes::default()
+        };
+
+        let mut definitions = Map::new();
+        for (name, schema) in &shared.schemas {
+            definitions.insert(name.clone(), json!(schema));
+        }
         for table in tables {
-            let schema = Self::export_table(table);
-            definitions.insert(table.name.clone(), schema);
+            let mut schema = Self::table_schema(table, draft, &shared, type_mappings);
+            // Subschemas only carry `$schema` in draft-07 output, as before
+            if draft != JsonSchemaDraft::Draft07 {
+                schema.remove("$schema");
+            }
+            definitions.insert(table.name.clone(), json!(schema));
         }
-        let mut root = serde_json::Map::new();
-        root.insert(
-            "$schema".to_string(),
-            serde_json::json!("http://json-schema.org/draft-07/schema#"),
-        );
-        root.insert("type".to_string(), serde_json::json!("object"));
-        root.insert("definitions".to_string(), serde_json::json!(definitions));
-        serde_json::json!(root)
+        let mut root = Map::new();
+        root.insert("$schema".to_string(), json!(draft.schema_uri()));
+        root.insert("type".to_string(), json!("object"));
+        root.insert(draft.definitions_keyword().to_string(), json!(definitions));
+        json!(root)
     }
 
     /// Export a table to JSON Schema format.
@@ -108,66 +261,52 @@ impl JSONSchemaExporter {
     /// assert_eq!(schema["type"], "object");
     /// ```
     pub fn export_table(table: &Table) -> Value {
+        json!(Self::table_schema(
+            table,
+            JsonSchemaDraft::Draft07,
+            &SharedTypes::default(),
+            TypeMappingRegistry::builtin(),
+        ))
+    }
+
+    fn table_schema(
+        table: &Table,
+        draft: JsonSchemaDraft,
+        shared: &SharedTypes,
+        type_mappings: &TypeMappingRegistry,
+    ) -> Map<String, Value> {
         let mut properties = serde_json::Map::new();
 
         for column in &table.columns {
-            let mut property = serde_json::Map::new();
-
-            // Map data types to JSON Schema types
-            let (json_type, format) = Self::map_data_type_to_json_schema(&column.data_type);
-            property.insert("type".to_string(), json!(json_type));
-
-            if let Some(fmt) = format {
-                property.insert("format".to_string(), json!(fmt));
-            }
-
-            if !column.nullable {
-                // Note: JSON Schema uses "required" array at schema level
-            }
-
-            if !column.description.is_empty() {
-                property.insert("description".to_string(), json!(column.description));
-            }
-
-            // Export $ref if present (from relationships)
-            if let Some(ref_path) = get_ref_path_from_relationships(column) {
-                property.insert("$ref".to_string(), json!(ref_path));
-            }
-
-            // Export enum values
-            if !column.enum_values.is_empty() {
-                let enum_vals: Vec<Value> = column
-                    .enum_values
-                    .iter()
-                    .map(|v| {
-                        // Try to parse as number or boolean, otherwise use as string
-                        if let Ok(num) = v.parse::<i64>() {
-                            json!(num)
-                        } else if let Ok(num) = v.parse::<f64>() {
-                            json!(num)
-                        } else if let Ok(b) = v.parse::<bool>() {
-                            json!(b)
-                        } else if v == "null" {
-                            json!(null)
-                        } else {
-                            json!(v)
-                        }
-                    })
-                    .collect();
-                property.insert("enum".to_string(), json!(enum_vals));
+            if let Some(name) = shared
+                .columns
+                .get(&(table.name.clone(), column.name.clone()))
+            {
+                let mut property = Map::new();
+                property.insert(
+                    "$ref".to_string(),
+                    json!(format!("#/{}/{}", draft.definitions_keyword(), name)),
+                );
+                // Keywords next to `$ref` are ignored before 2019-09
+                if draft != JsonSchemaDraft::Draft07 {
+                    if !column.description.is_empty() {
+                        property.insert("description".to_string(), json!(column.description));
+                    }
+                    if !column.examples.is_empty() {
+                        property.insert("examples".to_string(), json!(column.examples));
+                    }
+                }
+                properties.insert(column.name.clone(), json!(property));
+                continue;
             }
-
-            // Export validation keywords from quality rules
-            Self::export_validation_keywords(&mut property, column);
-
-            properties.insert(column.name.clone(), json!(property));
+            properties.insert(
+                column.name.clone(),
+                json!(Self::column_schema(column, type_mappings)),
+            );
         }
 
         let mut schema = serde_json::Map::new();
-        schema.insert(
-            "$schema".to_string(),
-            json!("http://json-schema.org/draft-07/schema#"),
-        );
+        schema.insert("$schema".to_string(), json!(draft.schema_uri()));
         schema.insert("type".to_string(), json!("object"));
         schema.insert("title".to_string(), json!(table.name));
         schema.insert("properties".to_string(), json!(properties));
@@ -190,7 +329,77 @@ impl JSONSchemaExporter {
             schema.insert("tags".to_string(), json!(tags_array));
         }
 
-        json!(schema)
+        schema
+    }
+
+    /// JSON Schema of a single column.
+    fn column_schema(column: &Column, type_mappings: &TypeMappingRegistry) -> Map<String, Value> {
+        let mut property = serde_json::Map::new();
+
+        // Map data types to JSON Schema types; spatial types become GeoJSON
+        // geometries, vectors fixed-length arrays and binary data base64 strings
+        let special_schema = SpatialType::from_column(column)
+            .map(|spatial| spatial.to_json_schema())
+            .or_else(|| VectorType::from_column(column).map(|v| v.to_json_schema()))
+            .or_else(|| BinaryType::parse(&column.data_type).map(|b| b.to_json_schema()));
+        if let Some(Value::Object(schema)) = special_schema {
+            property.extend(schema);
+        } else {
+            let (json_type, format) =
+                Self::map_data_type_to_json_schema(&column.data_type, type_mappings);
+            property.insert("type".to_string(), json!(json_type));
+
+            if let Some(fmt) = format {
+                property.insert("format".to_string(), json!(fmt));
+            }
+        }
+
+        if !column.nullable {
+            // Note: JSON Schema uses "required" array at schema level
+        }
+
+        if !column.description.is_empty() {
+            property.insert("description".to_string(), json!(column.description));
+        }
+
+        if !column.examples.is_empty() {
+            property.insert("examples".to_string(), json!(column.examples));
+        }
+
+        // Export $ref if present (from relationships)
+        if let Some(ref_path) = get_ref_path_from_relationships(column) {
+            property.insert("$ref".to_string(), json!(ref_path));
+        }
+
+        // Export enum values
+        if !column.enum_values.is_empty() {
+            let enum_vals: Vec<Value> = column
+                .enum_values
+                .iter()
+                .map(|v| {
+                    // Try to parse as number or boolean, otherwise use as string
+                    if let Ok(num) = v.parse::<i64>() {
+                        json!(num)
+                    } else if let Ok(num) = v.parse::<f64>() {
+                        json!(num)
+                    } else if let Ok(b) = v.parse::<bool>() {
+                        json!(b)
+                    } else if v == "null" {
+                        json!(null)
+                    } else {
+                        json!(v)
+                    }
+                })
+                .collect();
+            property.insert("enum".to_string(), json!(enum_vals));
+        }
+
+        // Export constraints from logicalTypeOptions, then validation
+        // keywords from quality rules
+        Self::export_logical_type_options(&mut property, column);
+        Self::export_validation_keywords(&mut property, column);
+
+        property
     }
 
     /// Export a data model to JSON Schema format (legacy method for compatibility).
@@ -225,24 +434,65 @@ impl JSONSchemaExporter {
     }
 
     /// Map SQL/ODCL data types to JSON Schema types and formats.
-    fn map_data_type_to_json_schema(data_type: &str) -> (String, Option<String>) {
-        let dt_lower = data_type.to_lowercase();
-
-        match dt_lower.as_str() {
-            "int" | "integer" | "bigint" | "smallint" | "tinyint" => ("integer".to_string(), None),
-            "float" | "double" | "real" | "decimal" | "numeric" => ("number".to_string(), None),
-            "boolean" | "bool" => ("boolean".to_string(), None),
-            "date" => ("string".to_string(), Some("date".to_string())),
-            "time" => ("string".to_string(), Some("time".to_string())),
-            "timestamp" | "datetime" => ("string".to_string(), Some("date-time".to_string())),
-            "uuid" => ("string".to_string(), Some("uuid".to_string())),
-            "uri" | "url" => ("string".to_string(), Some("uri".to_string())),
-            "email" => ("string".to_string(), Some("email".to_string())),
-            _ => {
-                // Default to string for VARCHAR, TEXT, CHAR, etc.
-                ("string".to_string(), None)
+    ///
+    /// Types come from the `json-schema` type mappings; strings of temporal and
+    /// well-known types get a format.
+    fn map_data_type_to_json_schema(
+        data_type: &str,
+        type_mappings: &TypeMappingRegistry,
+    ) -> (String, Option<String>) {
+        // Default to string for VARCHAR, TEXT, CHAR, etc.
+        let json_type = type_mappings
+            .export_type("json-schema", data_type)
+            .unwrap_or_else(|| "string".to_string());
+        if json_type != "string" {
+            return (json_type, None);
+        }
+
+        let format = match data_type.to_lowercase().as_str() {
+            "date" => Some("date"),
+            "time" => Some("time"),
+            "timestamp" | "datetime" => Some("date-time"),
+            "uuid" => Some("uuid"),
+            "uri" | "url" => Some("uri"),
+            "email" => Some("email"),
+            _ => None,
+        };
+        (json_type, format.map(str::to_string))
+    }
+
+    /// Export `logicalTypeOptions` constraints to JSON Schema keywords.
+    ///
+    /// Exclusive bounds are written as numbers, as in draft-06 and later.
+    fn export_logical_type_options(property: &mut Map<String, Value>, column: &Column) {
+        let Some(options) = &column.logical_type_options else {
+            return;
+        };
+        if let Some(pattern) = &options.pattern {
+            property.insert("pattern".to_string(), json!(pattern));
+        }
+        if let Some(min_length) = options.min_length {
+            property.insert("minLength".to_string(), json!(min_length));
+        }
+        if let Some(max_length) = options.max_length {
+            property.insert("maxLength".to_string(), json!(max_length));
+        }
+        for (keyword, value) in [
+            ("minimum", &options.minimum),
+            ("maximum", &options.maximum),
+            ("exclusiveMinimum", &options.exclusive_minimum),
+            ("exclusiveMaximum", &options.exclusive_maximum),
+        ] {
+            if let Some(value) = value.as_ref().filter(|v| v.is_number()) {
+                property.insert(keyword.to_string(), value.clone());
             }
         }
+        if let Some(format) = &options.format
+            && !property.contains_key("format")
+            && property.get("type").and_then(Value::as_str) == Some("string")
+        {
+            property.insert("format".to_string(), json!(format));
+        }
     }
 
     /// Export validation keywords from quality rules to JSON Schema property.
@@ -375,3 +625,79 @@ impl JSONSchemaExporter {
         }
     }
 }
+
+/// Column types emitted once and referenced with `$ref`
+#[derive(Default)]
+struct SharedTypes {
+    /// Shared schemas by definition name
+    schemas: BTreeMap<String, Map<String, Value>>,
+    /// Definition name of each shared column, keyed by table and column name
+    columns: HashMap<(String, String), String>,
+}
+
+impl SharedTypes {
+    /// Find the column types shared between tables.
+    ///
+    /// Columns resolved from a workspace definition are grouped by definition
+    /// name; other columns are grouped by column name and only shared when they
+    /// appear in at least two tables. A group is shared when all its columns
+    /// have the same schema, descriptions and examples aside. Names of tables are never
+    /// reused for shared types.
+    fn collect(tables: &[Table], type_mappings: &TypeMappingRegistry) -> Self {
+        let table_names: HashSet<&str> = tables.iter().map(|t| t.name.as_str()).collect();
+        let mut groups: BTreeMap<String, Vec<(&Table, &Column)>> = BTreeMap::new();
+        let mut from_definition: HashSet<String> = HashSet::new();
+
+        for table in tables {
+            for column in &table.columns {
+                let definition = column
+                    .custom_properties
+                    .get(DEFINITION_PROPERTY)
+                    .and_then(|marker| marker.get("ref"))
+                    .and_then(Value::as_str)
+                    .and_then(PropertyDefinitions::definition_name);
+                let name = match definition {
+                    Some(name) => {
+                        from_definition.insert(name.to_string());
+                        name
+                    }
+                    None => column.name.as_str(),
+                };
+                groups
+                    .entry(name.to_string())
+                    .or_default()
+                    .push((table, column));
+            }
+        }
+
+        let mut shared = Self::default();
+        for (name, members) in groups {
+            if table_names.contains(name.as_str()) {
+                continue;
+            }
+            let used_by: HashSet<&str> = members.iter().map(|(t, _)| t.name.as_str()).collect();
+            if !from_definition.contains(&name) && used_by.len() < 2 {
+                continue;
+            }
+            let mut schemas = members.iter().map(|(_, column)| {
+                let mut schema = JSONSchemaExporter::column_schema(column, type_mappings);
+                schema.remove("description");
+                schema.remove("examples");
+                schema
+            });
+            let Some(schema) = schemas.next() else {
+                continue;
+            };
+            if schemas.any(|other| other != schema) {
+                continue;
+            }
+            for (table, column) in members {
+                shared
+                    .columns
+                    .insert((table.name.clone(), column.name.clone()), name.clone());
+            }
+            shared.schemas.insert(name, schema);
+        }
+        shared
+    }
+}
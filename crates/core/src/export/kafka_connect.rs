@@ -0,0 +1,547 @@
+//! Kafka Connect connector configuration exporter
+//!
+//! Generates the source and sink connectors streaming a contract's tables through
+//! Kafka, from the CDC metadata of the tables ([`CdcConfig`]) and the contract's
+//! `servers`:
+//!
+//! - **source**: a Debezium connector per captured table (see
+//!   [`DebeziumExporter`]), connected to the first PostgreSQL, MySQL or
+//!   SQL Server server of the contract
+//! - **sinks**: a connector per captured table and further server; relational
+//!   servers get the Debezium JDBC sink (upserting on the CDC key columns), `s3`
+//!   servers the Confluent S3 sink
+//! - **topics**: Debezium's `<prefix>.<schema>.<table>`, or the `topic` of the
+//!   contract's `kafka` server when a single table is captured (routed with a
+//!   `RegexRouter` SMT)
+//! - **converters**: Avro or JSON Schema through the schema registry, or plain
+//!   JSON. Registry converters use the latest registered schema instead of
+//!   registering their own, so the schemas exported from the contract (e.g. with
+//!   [`AvroExporter`](super::AvroExporter)) stay authoritative
+//!
+//! Server settings the contract does not record (credentials, unknown ports,
+//! schema registry URL) are written as `${...}` placeholders.
+
+use super::debezium::{DebeziumConnector, DebeziumExporter, captured_tables};
+use super::{ExportError, ExportResult};
+use crate::models::Table;
+use crate::models::cdc::CdcConfig;
+use crate::models::odcs::{ODCSContract, Server};
+use serde_json::{Map, Value, json};
+
+/// Record format of the connector converters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConnectConverter {
+    /// Avro through the schema registry
+    #[default]
+    Avro,
+    /// JSON Schema through the schema registry
+    JsonSchema,
+    /// JSON with embedded schemas
+    Json,
+}
+
+impl ConnectConverter {
+    /// Parse a format name such as `avro`, `json-schema` or `json`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace('_', "-").as_str() {
+            "avro" => Some(Self::Avro),
+            "json-schema" | "jsonschema" => Some(Self::JsonSchema),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Self::Avro => "io.confluent.connect.avro.AvroConverter",
+            Self::JsonSchema => "io.confluent.connect.json.JsonSchemaConverter",
+            Self::Json => "org.apache.kafka.connect.json.JsonConverter",
+        }
+    }
+}
+
+/// Exporter for Kafka Connect source and sink connector configurations.
+#[derive(Debug, Clone)]
+pub struct KafkaConnectExporter {
+    converter: ConnectConverter,
+    topic_prefix: String,
+    schema_registry_url: String,
+}
+
+impl Default for KafkaConnectExporter {
+    fn default() -> Self {
+        Self {
+            converter: ConnectConverter::default(),
+            topic_prefix: super::debezium::DEFAULT_TOPIC_PREFIX.to_string(),
+            schema_registry_url: "${SCHEMA_REGISTRY_URL}".to_string(),
+        }
+    }
+}
+
+impl KafkaConnectExporter {
+    /// Create a new Kafka Connect exporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record format of the converters
+    pub fn with_converter(mut self, converter: ConnectConverter) -> Self {
+        self.converter = converter;
+        self
+    }
+
+    /// Kafka topic prefix of the source connectors
+    pub fn with_topic_prefix(mut self, topic_prefix: impl Into<String>) -> Self {
+        self.topic_prefix = topic_prefix.into();
+        self
+    }
+
+    /// Schema registry used by the Avro and JSON Schema converters
+    pub fn with_schema_registry_url(mut self, url: impl Into<String>) -> Self {
+        self.schema_registry_url = url.into();
+        self
+    }
+
+    /// Export tables to connector configurations (SDK interface).
+    ///
+    /// The servers are taken from the table metadata, as recorded when the
+    /// tables were converted from a contract.
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        self.export_contract(&ODCSContract::from_tables(tables))
+    }
+
+    /// Export a contract to connector configurations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::kafka_connect::KafkaConnectExporter;
+    ///
+    /// let contract = serde_yaml::from_str(r#"
+    /// apiVersion: v3.1.0
+    /// kind: DataContract
+    /// id: orders
+    /// name: orders
+    /// version: 1.0.0
+    /// status: active
+    /// servers:
+    ///   - server: erp
+    ///     type: postgres
+    ///     host: erp.internal
+    ///     database: erp
+    ///     schema: sales
+    /// schema:
+    ///   - name: orders
+    ///     customProperties:
+    ///       - property: cdc
+    ///         value: { captureMethod: log-based }
+    ///     properties:
+    ///       - name: id
+    ///         logicalType: integer
+    ///         physicalType: BIGINT
+    ///         primaryKey: true
+    /// "#).unwrap();
+    ///
+    /// let result = KafkaConnectExporter::new().export_contract(&contract).unwrap();
+    /// assert!(result.content.contains("\"database.hostname\": \"erp.internal\""));
+    /// ```
+    pub fn export_contract(&self, contract: &ODCSContract) -> Result<ExportResult, ExportError> {
+        let tables = contract.to_tables();
+        let captured = captured_tables(&tables)?;
+
+        let source_index = contract
+            .servers
+            .iter()
+            .position(|server| DebeziumConnector::parse(&server_type(server)).is_some());
+        let source = source_index.and_then(|i| {
+            let server = &contract.servers[i];
+            Some((server, DebeziumConnector::parse(&server_type(server))?))
+        });
+        let kafka = contract
+            .servers
+            .iter()
+            .find(|server| server_type(server) == "kafka");
+        let sinks: Vec<&Server> = contract
+            .servers
+            .iter()
+            .enumerate()
+            .filter(|(i, server)| {
+                Some(*i) != source_index
+                    && (server_type(server) == "s3"
+                        || DebeziumConnector::parse(&server_type(server)).is_some())
+            })
+            .map(|(_, server)| server)
+            .collect();
+        let routed_topic = kafka
+            .and_then(|kafka| kafka.topic.as_deref())
+            .filter(|_| captured.len() == 1);
+
+        let debezium = DebeziumExporter::new().with_topic_prefix(self.topic_prefix.clone());
+        let mut connectors = Vec::new();
+        let mut sink_connectors = Vec::new();
+        for (table, cdc) in &captured {
+            let mut table = (*table).clone();
+            if let Some((server, _)) = source
+                && table.schema_name.is_none()
+            {
+                table.schema_name = server.schema.clone();
+            }
+            let mut connector = match source {
+                Some((_, connector)) => debezium.connector_config_for(connector, &table, cdc),
+                None => debezium.connector_config(&table, cdc),
+            };
+            let config = connector["config"]
+                .as_object_mut()
+                .expect("connector config is an object");
+            if let Some((server, connector)) = source {
+                apply_source_server(config, server, connector);
+            }
+            if let Some(bootstrap) = kafka.and_then(|kafka| kafka.host.as_deref())
+                && config.contains_key("schema.history.internal.kafka.bootstrap.servers")
+            {
+                set(
+                    config,
+                    "schema.history.internal.kafka.bootstrap.servers",
+                    bootstrap,
+                );
+            }
+
+            let topic = match routed_topic {
+                Some(topic) => {
+                    add_transform(config, "route");
+                    set(
+                        config,
+                        "transforms.route.type",
+                        "org.apache.kafka.connect.transforms.RegexRouter",
+                    );
+                    set(config, "transforms.route.regex", ".*");
+                    set(config, "transforms.route.replacement", topic);
+                    topic.to_string()
+                }
+                None => {
+                    let include = config["table.include.list"].as_str().unwrap_or_default();
+                    format!("{}.{}", self.topic_prefix, include)
+                }
+            };
+            self.apply_converters(config);
+            connectors.push(connector);
+
+            for server in &sinks {
+                sink_connectors.push(self.sink_config(&table, cdc, server, &topic));
+            }
+        }
+        connectors.extend(sink_connectors);
+
+        let content = serde_json::to_string_pretty(&connectors)
+            .map_err(|e| ExportError::SerializationError(e.to_string()))?;
+        Ok(ExportResult {
+            content,
+            format: "kafka-connect".to_string(),
+        })
+    }
+
+    /// Sink connector writing a table's topic to a server
+    fn sink_config(&self, table: &Table, cdc: &CdcConfig, server: &Server, topic: &str) -> Value {
+        let server_name = server.server.clone().unwrap_or_else(|| server_type(server));
+        let mut config = Map::new();
+        set(&mut config, "tasks.max", "1");
+        set(&mut config, "topics", topic);
+
+        match DebeziumConnector::parse(&server_type(server)) {
+            Some(connector) => {
+                set(
+                    &mut config,
+                    "connector.class",
+                    "io.debezium.connector.jdbc.JdbcSinkConnector",
+                );
+                set(&mut config, "connection.url", &jdbc_url(server, connector));
+                set(&mut config, "connection.username", "${SINK_DATABASE_USER}");
+                set(
+                    &mut config,
+                    "connection.password",
+                    "${SINK_DATABASE_PASSWORD}",
+                );
+                set(&mut config, "insert.mode", "upsert");
+                set(&mut config, "primary.key.mode", "record_key");
+                set(
+                    &mut config,
+                    "primary.key.fields",
+                    &cdc.key_columns(table).join(","),
+                );
+                // Soft deletes arrive as updates of the flag column
+                let hard_deletes = cdc.soft_delete_column.is_none();
+                set(&mut config, "delete.enabled", &hard_deletes.to_string());
+                set(&mut config, "schema.evolution", "basic");
+                let table_name = match &server.schema {
+                    Some(schema) => format!("{}.{}", schema, table.name),
+                    None => table.name.clone(),
+                };
+                set(&mut config, "table.name.format", &table_name);
+            }
+            None => {
+                set(
+                    &mut config,
+                    "connector.class",
+                    "io.confluent.connect.s3.S3SinkConnector",
+                );
+                let location = server
+                    .location
+                    .as_deref()
+                    .or(server.host.as_deref())
+                    .unwrap_or("${S3_BUCKET}");
+                let location = location.strip_prefix("s3://").unwrap_or(location);
+                let (bucket, dir) = location.split_once('/').unwrap_or((location, ""));
+                set(&mut config, "s3.bucket.name", bucket);
+                if !dir.trim_matches('/').is_empty() {
+                    set(&mut config, "topics.dir", dir.trim_matches('/'));
+                }
+                set(
+                    &mut config,
+                    "storage.class",
+                    "io.confluent.connect.s3.storage.S3Storage",
+                );
+                let format = match server.format.as_deref().map(str::to_lowercase).as_deref() {
+                    Some("parquet") => "io.confluent.connect.s3.format.parquet.ParquetFormat",
+                    Some("avro") => "io.confluent.connect.s3.format.avro.AvroFormat",
+                    _ => "io.confluent.connect.s3.format.json.JsonFormat",
+                };
+                set(&mut config, "format.class", format);
+                set(&mut config, "flush.size", "1000");
+            }
+        }
+        self.apply_converters(&mut config);
+
+        json!({
+            "name": format!(
+                "{}-{}-sink",
+                table.name.replace('_', "-"),
+                server_name.to_lowercase().replace(['_', ' '], "-")
+            ),
+            "config": config,
+        })
+    }
+
+    /// Key and value converters of a connector
+    fn apply_converters(&self, config: &mut Map<String, Value>) {
+        for side in ["key", "value"] {
+            set(
+                config,
+                &format!("{}.converter", side),
+                self.converter.class(),
+            );
+            match self.converter {
+                ConnectConverter::Json => {
+                    set(
+                        config,
+                        &format!("{}.converter.schemas.enable", side),
+                        "true",
+                    );
+                }
+                ConnectConverter::Avro | ConnectConverter::JsonSchema => {
+                    set(
+                        config,
+                        &format!("{}.converter.schema.registry.url", side),
+                        &self.schema_registry_url,
+                    );
+                    set(
+                        config,
+                        &format!("{}.converter.auto.register.schemas", side),
+                        "false",
+                    );
+                    set(
+                        config,
+                        &format!("{}.converter.use.latest.version", side),
+                        "true",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Replace the connection placeholders of a Debezium source with server settings
+fn apply_source_server(
+    config: &mut Map<String, Value>,
+    server: &Server,
+    connector: DebeziumConnector,
+) {
+    if let Some(host) = &server.host {
+        set(config, "database.hostname", host);
+    }
+    if let Some(port) = server_port(server) {
+        set(config, "database.port", &port);
+    }
+    if let Some(database) = &server.database {
+        let key = match connector {
+            DebeziumConnector::Postgres => "database.dbname",
+            DebeziumConnector::MySql => "database.include.list",
+            DebeziumConnector::SqlServer => "database.names",
+        };
+        set(config, key, database);
+    }
+}
+
+/// JDBC URL of a relational sink server
+fn jdbc_url(server: &Server, connector: DebeziumConnector) -> String {
+    let host = server
+        .host
+        .as_deref()
+        .unwrap_or("${SINK_DATABASE_HOSTNAME}");
+    let port = server_port(server).unwrap_or_else(|| connector.port().to_string());
+    let database = server
+        .database
+        .as_deref()
+        .unwrap_or("${SINK_DATABASE_NAME}");
+    match connector {
+        DebeziumConnector::Postgres => {
+            format!("jdbc:postgresql://{}:{}/{}", host, port, database)
+        }
+        DebeziumConnector::MySql => format!("jdbc:mysql://{}:{}/{}", host, port, database),
+        DebeziumConnector::SqlServer => format!(
+            "jdbc:sqlserver://{}:{};databaseName={}",
+            host, port, database
+        ),
+    }
+}
+
+fn server_type(server: &Server) -> String {
+    server
+        .server_type
+        .as_deref()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+fn server_port(server: &Server) -> Option<String> {
+    match server.extra.get("port")? {
+        Value::Number(port) => Some(port.to_string()),
+        Value::String(port) => Some(port.clone()),
+        _ => None,
+    }
+}
+
+fn add_transform(config: &mut Map<String, Value>, name: &str) {
+    let transforms = match config.get("transforms").and_then(Value::as_str) {
+        Some(existing) if !existing.is_empty() => format!("{},{}", existing, name),
+        _ => name.to_string(),
+    };
+    set(config, "transforms", &transforms);
+}
+
+fn set(config: &mut Map<String, Value>, key: &str, value: &str) {
+    config.insert(key.to_string(), Value::String(value.to_string()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders
+name: orders
+version: 1.0.0
+status: active
+servers:
+  - server: erp
+    type: postgres
+    host: erp.internal
+    port: 5433
+    database: erp
+    schema: sales
+  - server: events
+    type: kafka
+    host: broker-1:9092
+    topic: erp.orders
+  - server: Reporting DB
+    type: mysql
+    host: reporting.internal
+    database: reporting
+  - server: lake
+    type: s3
+    location: s3://lake/raw/orders
+    format: parquet
+schema:
+  - name: orders
+    customProperties:
+      - property: cdc
+        value:
+          captureMethod: log-based
+          softDeleteColumn: is_deleted
+    properties:
+      - name: order_id
+        logicalType: integer
+        physicalType: BIGINT
+        primaryKey: true
+        required: true
+      - name: is_deleted
+        logicalType: boolean
+        physicalType: BOOLEAN
+"#;
+
+    fn export(exporter: KafkaConnectExporter) -> Vec<Value> {
+        let contract: ODCSContract = serde_yaml::from_str(CONTRACT).unwrap();
+        let result = exporter.export_contract(&contract).unwrap();
+        serde_json::from_str(&result.content).unwrap()
+    }
+
+    #[test]
+    fn test_source_and_sinks() {
+        let connectors = export(KafkaConnectExporter::new());
+        let names: Vec<&str> = connectors
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["orders-cdc", "orders-reporting-db-sink", "orders-lake-sink"]
+        );
+
+        let source = &connectors[0]["config"];
+        assert_eq!(source["database.hostname"], "erp.internal");
+        assert_eq!(source["database.port"], "5433");
+        assert_eq!(source["database.dbname"], "erp");
+        assert_eq!(source["table.include.list"], "sales.orders");
+        assert_eq!(source["transforms"], "unwrap,softDelete,route");
+        assert_eq!(source["transforms.route.replacement"], "erp.orders");
+        assert_eq!(
+            source["value.converter"],
+            "io.confluent.connect.avro.AvroConverter"
+        );
+        assert_eq!(source["value.converter.auto.register.schemas"], "false");
+
+        let jdbc = &connectors[1]["config"];
+        assert_eq!(jdbc["topics"], "erp.orders");
+        assert_eq!(
+            jdbc["connection.url"],
+            "jdbc:mysql://reporting.internal:3306/reporting"
+        );
+        assert_eq!(jdbc["primary.key.fields"], "order_id");
+        assert_eq!(jdbc["delete.enabled"], "false");
+
+        let s3 = &connectors[2]["config"];
+        assert_eq!(s3["s3.bucket.name"], "lake");
+        assert_eq!(s3["topics.dir"], "raw/orders");
+        assert_eq!(
+            s3["format.class"],
+            "io.confluent.connect.s3.format.parquet.ParquetFormat"
+        );
+    }
+
+    #[test]
+    fn test_json_converter() {
+        let connectors = export(
+            KafkaConnectExporter::new()
+                .with_converter(ConnectConverter::parse("JSON").unwrap())
+                .with_topic_prefix("cdc"),
+        );
+        let source = &connectors[0]["config"];
+        assert_eq!(
+            source["key.converter"],
+            "org.apache.kafka.connect.json.JsonConverter"
+        );
+        assert_eq!(source["value.converter.schemas.enable"], "true");
+        assert!(source.get("value.converter.schema.registry.url").is_none());
+    }
+}
@@ -0,0 +1,549 @@
+//! Data product marketplace manifest exporter
+//!
+//! Produces a consolidated, machine-readable catalog of data products for internal
+//! developer portals:
+//!
+//! - **Manifest** - one JSON document listing every published ODPS product with its
+//!   owner, support channels and ports; output ports are summarised from the ODCS
+//!   contract they reference (schemas, owner, service levels, roles and servers)
+//! - **Backstage** - a multi-document `catalog-info.yaml` with a `Component` per data
+//!   product and an `API` per output port carrying the contract as its definition
+//!
+//! Only `active` products are published by default; see
+//! [`MarketplaceExporter::with_statuses`].
+
+use super::backstage::{
+    BACKSTAGE_API_VERSION, ODCS_ID_ANNOTATION, entity_name, insert_optional, to_yaml_documents,
+};
+use super::{ExportError, ExportResult};
+use crate::models::odcs::{ODCSContract, Role, Server, ServiceLevel};
+use crate::models::odps::{ODPSDataProduct, ODPSOutputPort, ODPSStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+/// Kind of the manifest document
+pub const MANIFEST_KIND: &str = "DataProductCatalog";
+
+/// Annotation carrying the ODPS product id on Backstage entities
+const ODPS_ID_ANNOTATION: &str = "data-modelling/odps-id";
+
+/// Consolidated catalog of published data products
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplaceManifest {
+    pub kind: String,
+    pub products: Vec<MarketplaceProduct>,
+}
+
+/// Catalog entry for one data product
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplaceProduct {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub status: ODPSStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Owning team, or the first team member with an owner role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Support channels (`channel` and `url`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub support: Vec<MarketplaceLink>,
+    /// Contract ids the product consumes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_contracts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output_ports: Vec<MarketplacePort>,
+}
+
+/// A named link such as a support channel
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MarketplaceLink {
+    pub title: String,
+    pub url: String,
+}
+
+/// Output port of a data product with a summary of its contract
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplacePort {
+    pub name: String,
+    pub version: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub port_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_id: Option<String>,
+    /// Summary of the referenced contract, if it was provided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<ContractSummary>,
+}
+
+/// Summary of an ODCS contract backing an output port
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractSummary {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Schema object names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
+    /// Service level agreements
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub service_levels: Vec<ServiceLevel>,
+    /// Access instructions: who may access the data and how
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Role>,
+    /// Where the data can be read from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<Server>,
+}
+
+/// Exporter for data product marketplace manifests and Backstage catalogs.
+#[derive(Debug, Clone)]
+pub struct MarketplaceExporter {
+    statuses: Vec<ODPSStatus>,
+    default_owner: String,
+}
+
+impl Default for MarketplaceExporter {
+    fn default() -> Self {
+        Self {
+            statuses: vec![ODPSStatus::Active],
+            default_owner: "unknown".to_string(),
+        }
+    }
+}
+
+impl MarketplaceExporter {
+    /// Create a new exporter publishing active products
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publish products with any of the given statuses
+    pub fn with_statuses(mut self, statuses: Vec<ODPSStatus>) -> Self {
+        self.statuses = statuses;
+        self
+    }
+
+    /// Owner used for Backstage entities of products without an owning team
+    pub fn with_default_owner(mut self, owner: impl Into<String>) -> Self {
+        self.default_owner = owner.into();
+        self
+    }
+
+    /// Export the marketplace manifest as JSON (SDK interface).
+    pub fn export(
+        &self,
+        products: &[ODPSDataProduct],
+        contracts: &[ODCSContract],
+    ) -> Result<ExportResult, ExportError> {
+        Ok(ExportResult {
+            content: self.export_manifest(products, contracts)?,
+            format: "marketplace".to_string(),
+        })
+    }
+
+    /// Export the marketplace manifest as pretty-printed JSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::marketplace::MarketplaceExporter;
+    /// use data_modelling_core::models::odcs::ODCSContract;
+    /// use data_modelling_core::models::odps::ODPSDataProduct;
+    ///
+    /// let product: ODPSDataProduct = serde_yaml::from_str(
+    ///     "apiVersion: v1.0.0\nkind: DataProduct\nid: customers\nstatus: active\n\
+    ///      outputPorts:\n  - name: customers\n    version: 1.0.0\n    contractId: customers-v1\n",
+    /// )
+    /// .unwrap();
+    /// let contract = ODCSContract::new_with_id("customers-v1", "customers", "1.0.0");
+    ///
+    /// let json = MarketplaceExporter::new()
+    ///     .export_manifest(&[product], &[contract])
+    ///     .unwrap();
+    /// assert!(json.contains("\"contractId\": \"customers-v1\""));
+    /// ```
+    pub fn export_manifest(
+        &self,
+        products: &[ODPSDataProduct],
+        contracts: &[ODCSContract],
+    ) -> Result<String, ExportError> {
+        let manifest = self.build_manifest(products, contracts);
+        serde_json::to_string_pretty(&manifest)
+            .map_err(|e| ExportError::SerializationError(e.to_string()))
+    }
+
+    /// Build the manifest for the published products
+    pub fn build_manifest(
+        &self,
+        products: &[ODPSDataProduct],
+        contracts: &[ODCSContract],
+    ) -> MarketplaceManifest {
+        MarketplaceManifest {
+            kind: MANIFEST_KIND.to_string(),
+            products: self
+                .published(products)
+                .map(|product| summarize_product(product, contracts))
+                .collect(),
+        }
+    }
+
+    /// Export a Backstage `catalog-info.yaml` for the published products.
+    ///
+    /// Each product becomes a `Component` of type `data-product` (its domain becomes
+    /// the Backstage `system`) providing one `API` of type `data-contract` per output
+    /// port; the API definition is the ODCS contract YAML when the contract is known.
+    pub fn export_backstage(
+        &self,
+        products: &[ODPSDataProduct],
+        contracts: &[ODCSContract],
+    ) -> Result<String, ExportError> {
+        let mut documents = Vec::new();
+        for product in self.published(products) {
+            let summary = summarize_product(product, contracts);
+            let component_name = entity_name(&summary.name);
+            let owner = summary
+                .owner
+                .as_deref()
+                .map(entity_name)
+                .unwrap_or_else(|| self.default_owner.clone());
+            let lifecycle = lifecycle(product.status);
+
+            let mut apis = Vec::new();
+            let mut api_documents = Vec::new();
+            for port in &summary.output_ports {
+                let api_name = entity_name(&format!("{}-{}", summary.name, port.name));
+                let contract = port
+                    .contract_id
+                    .as_deref()
+                    .and_then(|id| contracts.iter().find(|c| c.id == id));
+                let definition = match contract {
+                    Some(contract) => serde_yaml::to_string(contract)
+                        .map_err(|e| ExportError::SerializationError(e.to_string()))?,
+                    None => serde_yaml::to_string(port)
+                        .map_err(|e| ExportError::SerializationError(e.to_string()))?,
+                };
+
+                let mut annotations = Map::new();
+                if let Some(contract_id) = &port.contract_id {
+                    annotations.insert(ODCS_ID_ANNOTATION.to_string(), json!(contract_id));
+                }
+                let mut metadata = json!({ "name": api_name, "title": port.name });
+                insert_optional(&mut metadata, "description", port.description.clone());
+                if !annotations.is_empty() {
+                    metadata["annotations"] = Value::Object(annotations);
+                }
+
+                api_documents.push(json!({
+                    "apiVersion": BACKSTAGE_API_VERSION,
+                    "kind": "API",
+                    "metadata": metadata,
+                    "spec": {
+                        "type": "data-contract",
+                        "lifecycle": lifecycle,
+                        "owner": owner,
+                        "definition": definition,
+                    },
+                }));
+                apis.push(api_name);
+            }
+
+            let mut metadata = json!({
+                "name": component_name,
+                "title": summary.name,
+                "annotations": { ODPS_ID_ANNOTATION: summary.id },
+            });
+            insert_optional(&mut metadata, "description", summary.description.clone());
+            let tags: Vec<String> = summary
+                .tags
+                .iter()
+                .map(|tag| entity_name(tag).to_lowercase())
+                .filter(|tag| !tag.is_empty())
+                .collect();
+            if !tags.is_empty() {
+                metadata["tags"] = json!(tags);
+            }
+            if !summary.support.is_empty() {
+                metadata["links"] = json!(summary.support);
+            }
+
+            let mut spec = json!({
+                "type": "data-product",
+                "lifecycle": lifecycle,
+                "owner": owner,
+            });
+            insert_optional(
+                &mut spec,
+                "system",
+                summary.domain.as_deref().map(entity_name),
+            );
+            if !apis.is_empty() {
+                spec["providesApis"] = json!(apis);
+            }
+
+            // Component first, followed by the APIs it provides
+            documents.push(json!({
+                "apiVersion": BACKSTAGE_API_VERSION,
+                "kind": "Component",
+                "metadata": metadata,
+                "spec": spec,
+            }));
+            documents.extend(api_documents);
+        }
+
+        if documents.is_empty() {
+            return Err(ExportError::ValidationError(
+                "No published data products to export to Backstage".to_string(),
+            ));
+        }
+
+        to_yaml_documents(&documents)
+    }
+
+    fn published<'a>(
+        &'a self,
+        products: &'a [ODPSDataProduct],
+    ) -> impl Iterator<Item = &'a ODPSDataProduct> {
+        products
+            .iter()
+            .filter(|product| self.statuses.contains(&product.status))
+    }
+}
+
+fn summarize_product(product: &ODPSDataProduct, contracts: &[ODCSContract]) -> MarketplaceProduct {
+    let owner = product.team.as_ref().and_then(|team| {
+        team.name.clone().or_else(|| {
+            team.members.iter().flatten().find_map(|member| {
+                member
+                    .role
+                    .as_deref()
+                    .filter(|role| role.to_lowercase().contains("owner"))
+                    .map(|_| member.name.clone().unwrap_or(member.username.clone()))
+            })
+        })
+    });
+
+    MarketplaceProduct {
+        id: product.id.clone(),
+        name: product.name.clone().unwrap_or_else(|| product.id.clone()),
+        version: product.version.clone(),
+        status: product.status,
+        domain: product.domain.clone(),
+        tenant: product.tenant.clone(),
+        description: product
+            .description
+            .as_ref()
+            .and_then(|description| description.purpose.clone()),
+        owner,
+        tags: product.tags.iter().map(|tag| tag.to_string()).collect(),
+        support: product
+            .support
+            .iter()
+            .flatten()
+            .map(|support| MarketplaceLink {
+                title: support.channel.clone(),
+                url: support.url.clone(),
+            })
+            .collect(),
+        input_contracts: product
+            .input_ports
+            .iter()
+            .flatten()
+            .map(|port| port.contract_id.clone())
+            .collect(),
+        output_ports: product
+            .output_ports
+            .iter()
+            .flatten()
+            .map(|port| summarize_port(port, contracts))
+            .collect(),
+    }
+}
+
+fn summarize_port(port: &ODPSOutputPort, contracts: &[ODCSContract]) -> MarketplacePort {
+    let contract = port
+        .contract_id
+        .as_deref()
+        .and_then(|id| contracts.iter().find(|contract| contract.id == id));
+
+    MarketplacePort {
+        name: port.name.clone(),
+        version: port.version.clone(),
+        port_type: port.r#type.clone(),
+        description: port.description.clone(),
+        contract_id: port.contract_id.clone(),
+        contract: contract.map(|contract| ContractSummary {
+            id: contract.id.clone(),
+            name: contract.name.clone(),
+            version: contract.version.clone(),
+            status: contract.status.clone(),
+            owner: contract.team.as_ref().and_then(|team| team.name.clone()),
+            schemas: contract
+                .schema_names()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            service_levels: contract.service_levels.clone(),
+            roles: contract.roles.clone(),
+            servers: contract.servers.clone(),
+        }),
+    }
+}
+
+/// Backstage lifecycle for an ODPS status
+fn lifecycle(status: ODPSStatus) -> &'static str {
+    match status {
+        ODPSStatus::Active => "production",
+        ODPSStatus::Proposed | ODPSStatus::Draft => "experimental",
+        ODPSStatus::Deprecated | ODPSStatus::Retired => "deprecated",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRODUCT: &str = r#"
+apiVersion: v1.0.0
+kind: DataProduct
+id: prod-customers
+name: Customer 360
+version: 2.0.0
+status: active
+domain: Sales & Marketing
+description:
+  purpose: Unified view of customers
+tags:
+  - PII
+  - Environment:Prod
+team:
+  members:
+    - username: jdoe
+      name: Jane Doe
+      role: Owner
+support:
+  - channel: slack
+    url: https://slack.example.com/customers
+inputPorts:
+  - name: crm
+    version: 1.0.0
+    contractId: crm-accounts
+outputPorts:
+  - name: customers
+    version: 2.0.0
+    type: tables
+    contractId: customers-v2
+"#;
+
+    const CONTRACT: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: customers-v2
+name: customers
+version: 2.0.0
+status: active
+schema:
+  - name: customers
+serviceLevels:
+  - property: freshness
+    value: 1
+    unit: d
+roles:
+  - role: customers_reader
+    access: read
+servers:
+  - server: prod
+    type: postgres
+    host: db.example.com
+"#;
+
+    fn fixtures() -> (Vec<ODPSDataProduct>, Vec<ODCSContract>) {
+        let product: ODPSDataProduct = serde_yaml::from_str(PRODUCT).unwrap();
+        let mut draft = product.clone();
+        draft.id = "prod-draft".to_string();
+        draft.status = ODPSStatus::Draft;
+        let contract: ODCSContract = serde_yaml::from_str(CONTRACT).unwrap();
+        (vec![product, draft], vec![contract])
+    }
+
+    #[test]
+    fn test_manifest_summarizes_active_products() {
+        let (products, contracts) = fixtures();
+        let manifest = MarketplaceExporter::new().build_manifest(&products, &contracts);
+
+        assert_eq!(manifest.products.len(), 1);
+        let product = &manifest.products[0];
+        assert_eq!(product.owner.as_deref(), Some("Jane Doe"));
+        assert_eq!(product.input_contracts, vec!["crm-accounts"]);
+        assert_eq!(product.support[0].title, "slack");
+
+        let contract = product.output_ports[0].contract.as_ref().unwrap();
+        assert_eq!(contract.schemas, vec!["customers"]);
+        assert_eq!(
+            contract.service_levels[0].property.as_deref(),
+            Some("freshness")
+        );
+        assert_eq!(contract.roles[0].access.as_deref(), Some("read"));
+        assert_eq!(contract.servers[0].host.as_deref(), Some("db.example.com"));
+    }
+
+    #[test]
+    fn test_backstage_catalog_info() {
+        let (products, contracts) = fixtures();
+        let yaml = MarketplaceExporter::new()
+            .export_backstage(&products, &contracts)
+            .unwrap();
+
+        let documents: Vec<Value> = yaml
+            .split("---\n")
+            .map(|document| serde_yaml::from_str(document).unwrap())
+            .collect();
+        assert_eq!(documents.len(), 2);
+
+        let component = &documents[0];
+        assert_eq!(component["kind"], "Component");
+        assert_eq!(component["metadata"]["name"], "Customer-360");
+        assert_eq!(
+            component["metadata"]["tags"],
+            json!(["pii", "environment-prod"])
+        );
+        assert_eq!(component["spec"]["owner"], "Jane-Doe");
+        assert_eq!(component["spec"]["system"], "Sales-Marketing");
+        assert_eq!(component["spec"]["lifecycle"], "production");
+        assert_eq!(
+            component["spec"]["providesApis"],
+            json!(["Customer-360-customers"])
+        );
+
+        let api = &documents[1];
+        assert_eq!(api["kind"], "API");
+        assert_eq!(
+            api["metadata"]["annotations"][ODCS_ID_ANNOTATION],
+            "customers-v2"
+        );
+        assert!(
+            api["spec"]["definition"]
+                .as_str()
+                .unwrap()
+                .contains("id: customers-v2")
+        );
+    }
+}
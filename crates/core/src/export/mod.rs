@@ -2,26 +2,54 @@
 //!
 //! Provides exporters for various formats:
 //! - SQL
+//! - BigQuery JSON table schemas (for `bq load`)
 //! - JSON Schema
 //! - AVRO
 //! - Protobuf
+//! - DBML (dbdiagram.io)
+//! - Feast (feature store definitions)
+//! - Typed consumer client modules (Rust, Python, TypeScript)
 //! - ODCS (Open Data Contract Standard) v3.1.0
 //! - PNG
 //! - PDF (with branding support)
+//! - HTML (single-file contract viewer)
 //! - Decision (MADR-compliant decision records)
 //! - Knowledge (Knowledge Base articles)
 //! - Markdown (for GitHub readability)
+//! - Domain READMEs (managed summary blocks)
+//! - Data product marketplace manifests (JSON catalog and Backstage `catalog-info.yaml`)
+//! - Backstage catalog entities (domains, systems, contracts, tables and CADS assets)
+//! - SodaCL checks (Soda Core) from quality rules and column metadata
+//! - Debezium connector configurations from CDC table metadata
+//! - Kafka Connect source and sink connector configurations from contracts
+//! - RDF knowledge graphs (Turtle, JSON-LD) of domains, tables, relationships and glossary terms
+//! - SQLite model bundles of whole workspaces for offline browsing (feature `sqlite`)
+//!
+//! A whole workspace can be exported to several formats in one pass with
+//! [`workspace::export_workspace`].
+//!
+//! Custom formats can be plugged in through the [`Exporter`] trait and
+//! [`ExporterRegistry`].
 
 pub mod avro;
+pub mod backstage;
+pub mod bigquery;
 #[cfg(feature = "bpmn")]
 pub mod bpmn;
 pub mod cads;
+pub mod client;
+pub mod dbml;
+pub mod debezium;
 pub mod decision;
 #[cfg(feature = "dmn")]
 pub mod dmn;
+pub mod feast;
+pub mod html;
 pub mod json_schema;
+pub mod kafka_connect;
 pub mod knowledge;
 pub mod markdown;
+pub mod marketplace;
 pub mod odcl;
 pub mod odcs;
 pub mod odps;
@@ -31,8 +59,15 @@ pub mod pdf;
 #[cfg(feature = "png-export")]
 pub mod png;
 pub mod protobuf;
+pub mod rdf;
+pub mod readme;
+pub mod registry;
 pub mod sketch;
+pub mod soda;
 pub mod sql;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod workspace;
 
 // anyhow::Result not currently used in this module
 
@@ -48,6 +83,31 @@ pub struct ExportResult {
     pub format: String,
 }
 
+impl ExportResult {
+    /// Preview writing the content to `path` without touching storage
+    ///
+    /// Returns whether the file would be created, modified or left unchanged,
+    /// with a diff against its current content.
+    pub async fn preview<B: crate::storage::StorageBackend + ?Sized>(
+        &self,
+        backend: &B,
+        path: &str,
+    ) -> Result<crate::storage::dry_run::FileChange, ExportError> {
+        let io_error = |e: crate::storage::StorageError| ExportError::IoError(e.to_string());
+        let current = if backend.file_exists(path).await.map_err(io_error)? {
+            Some(backend.read_file(path).await.map_err(io_error)?)
+        } else {
+            None
+        };
+        crate::storage::dry_run::FileChange::compare(
+            path,
+            current.as_deref(),
+            Some(self.content.as_bytes()),
+        )
+        .ok_or_else(|| ExportError::ExportError(format!("Nothing to write to {}", path)))
+    }
+}
+
 /// Error during export
 #[derive(Debug, thiserror::Error, serde::Serialize, serde::Deserialize)]
 pub enum ExportError {
@@ -79,23 +139,39 @@ impl From<Box<dyn std::error::Error>> for ExportError {
 
 // Re-export for convenience
 pub use avro::AvroExporter;
+pub use backstage::BackstageExporter;
+pub use bigquery::{BigQueryField, BigQuerySchemaExporter};
 #[cfg(feature = "bpmn")]
 pub use bpmn::BPMNExporter;
 pub use cads::CADSExporter;
+pub use client::{ClientExporter, ClientLanguage};
+pub use dbml::DbmlExporter;
+pub use debezium::{DebeziumConnector, DebeziumExporter};
 pub use decision::DecisionExporter;
 #[cfg(feature = "dmn")]
 pub use dmn::DMNExporter;
+pub use feast::FeastExporter;
+pub use html::HtmlExporter;
 pub use json_schema::JSONSchemaExporter;
+pub use kafka_connect::{ConnectConverter, KafkaConnectExporter};
 pub use knowledge::KnowledgeExporter;
 pub use markdown::{BrandedMarkdownExporter, MarkdownBrandingConfig, MarkdownExporter};
+pub use marketplace::{MarketplaceExporter, MarketplaceManifest};
 pub use odcl::ODCLExporter;
 pub use odcs::ODCSExporter;
 pub use odps::ODPSExporter;
 #[cfg(feature = "openapi")]
 pub use openapi::OpenAPIExporter;
-pub use pdf::{BrandingConfig, PageSize, PdfExportResult, PdfExporter};
+pub use pdf::{
+    BrandingConfig, GovernanceScorecard, PageSize, PdfExportResult, PdfExporter, ScorecardCheck,
+    ScorecardStatus,
+};
 #[cfg(feature = "png-export")]
 pub use png::PNGExporter;
 pub use protobuf::ProtobufExporter;
+pub use rdf::{RdfExporter, RdfSyntax};
+pub use readme::{DomainReadme, DomainReadmeGenerator, merge_managed_block};
+pub use registry::{ExportOptions, Exporter, ExporterRegistry};
 pub use sketch::SketchExporter;
+pub use soda::{SodaCheck, SodaExporter};
 pub use sql::SQLExporter;
@@ -4,11 +4,18 @@
 //! Legacy ODCL formats are no longer supported for export.
 //!
 //! The exporter uses struct-based serialization via `ODCSContract` to ensure consistent
-//! key ordering in the YAML output, which produces stable git diffs.
+//! key ordering in the YAML output, which produces stable git diffs. The output never
+//! contains anchors or aliases.
+//!
+//! [`ODCSStyleOptions`] controls the remaining style choices (schema and property
+//! ordering, empty sections, line wrapping) so contract files can follow an
+//! organization-wide style.
 
 use super::{ExportError, ExportResult};
-use crate::models::odcs::ODCSContract;
-use crate::models::{Column, DataModel, Table};
+use crate::export::registry::ExportOptions;
+use crate::models::definitions::PropertyDefinitions;
+use crate::models::odcs::{ODCSContract, Property};
+use crate::models::{Column, DataModel, SpatialType, Table, VectorType};
 use serde_yaml;
 use std::collections::HashMap;
 
@@ -21,6 +28,235 @@ fn get_physical_type(column: &Column) -> String {
         .unwrap_or_else(|| column.data_type.clone())
 }
 
+/// Order of schema objects or properties in exported contracts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ODCSOrdering {
+    /// Keep the order of the model
+    #[default]
+    Model,
+    /// Sort alphabetically by name
+    Alphabetical,
+}
+
+impl std::str::FromStr for ODCSOrdering {
+    type Err = ExportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "model" => Ok(Self::Model),
+            "alphabetical" | "alpha" | "name" => Ok(Self::Alphabetical),
+            other => Err(ExportError::InvalidArgument(format!(
+                "Unknown ordering: {} (expected model or alphabetical)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Style options for ODCS YAML output
+///
+/// The default reproduces [`ODCSExporter::export_contract`].
+///
+/// # Example
+///
+/// ```rust
+/// use data_modelling_core::export::odcs::{ODCSExporter, ODCSOrdering, ODCSStyleOptions};
+/// use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+///
+/// let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+///     SchemaObject::new("orders").with_properties(vec![
+///         Property::new("status", "string"),
+///         Property::new("id", "integer"),
+///     ]),
+/// );
+/// let style = ODCSStyleOptions {
+///     property_order: ODCSOrdering::Alphabetical,
+///     ..Default::default()
+/// };
+///
+/// let yaml = ODCSExporter::export_contract_with_style(&contract, &style).unwrap();
+/// assert!(yaml.find("name: id").unwrap() < yaml.find("name: status").unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ODCSStyleOptions {
+    /// Order of schema objects
+    pub schema_order: ODCSOrdering,
+    /// Order of properties within each schema object (including nested properties)
+    pub property_order: ODCSOrdering,
+    /// Keep empty lists and mappings (e.g., `properties: []`)
+    pub include_empty_sections: bool,
+    /// Wrap long plain text values at this width using folded block scalars
+    pub line_width: Option<usize>,
+}
+
+impl Default for ODCSStyleOptions {
+    fn default() -> Self {
+        Self {
+            schema_order: ODCSOrdering::Model,
+            property_order: ODCSOrdering::Model,
+            include_empty_sections: true,
+            line_width: None,
+        }
+    }
+}
+
+impl ODCSStyleOptions {
+    /// Read style options from exporter registry options.
+    ///
+    /// Recognized properties: `schema_order`, `property_order` (`model` or
+    /// `alphabetical`), `include_empty` (`true` or `false`) and `line_width`.
+    pub fn from_export_options(options: &ExportOptions) -> Result<Self, ExportError> {
+        let mut style = Self::default();
+        if let Some(order) = options.property("schema_order") {
+            style.schema_order = order.parse()?;
+        }
+        if let Some(order) = options.property("property_order") {
+            style.property_order = order.parse()?;
+        }
+        if let Some(include) = options.property("include_empty") {
+            style.include_empty_sections = include.trim().parse().map_err(|_| {
+                ExportError::InvalidArgument(format!(
+                    "Invalid include_empty value: {} (expected true or false)",
+                    include
+                ))
+            })?;
+        }
+        if let Some(width) = options.property("line_width") {
+            let width: usize = width.trim().parse().map_err(|_| {
+                ExportError::InvalidArgument(format!("Invalid line_width value: {}", width))
+            })?;
+            style.line_width = (width > 0).then_some(width);
+        }
+        Ok(style)
+    }
+}
+
+/// Sort properties (and their nested properties) by name.
+fn sort_properties(properties: &mut [Property]) {
+    properties.sort_by(|a, b| a.name.cmp(&b.name));
+    for property in properties {
+        sort_properties(&mut property.properties);
+        if let Some(items) = property.items.as_mut() {
+            sort_properties(&mut items.properties);
+        }
+    }
+}
+
+/// Remove empty lists and mappings from a YAML value.
+///
+/// Custom property values are data rather than sections and are kept as-is.
+fn prune_empty_sections(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (key, child) in map.iter_mut() {
+                if key.as_str() != Some("value") {
+                    prune_empty_sections(child);
+                }
+            }
+            map.retain(|key, child| key.as_str() == Some("value") || !is_empty_section(child));
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items.iter_mut() {
+                prune_empty_sections(item);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_empty_section(value: &serde_yaml::Value) -> bool {
+    match value {
+        serde_yaml::Value::Sequence(items) => items.is_empty(),
+        serde_yaml::Value::Mapping(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+/// Wrap `key: value` lines with plain text values longer than `width`.
+///
+/// Long values become folded block scalars (`>-`), which load as the same
+/// string. Quoted values and values that can't be folded losslessly are kept.
+fn wrap_long_lines(yaml: &str, width: usize) -> String {
+    let mut output = String::with_capacity(yaml.len());
+    // Indentation of the key that started the current block scalar
+    let mut block_indent: Option<usize> = None;
+    for line in yaml.lines() {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if let Some(block) = block_indent {
+            if line.trim().is_empty() || indent > block {
+                output.push_str(line);
+                output.push('\n');
+                continue;
+            }
+            block_indent = None;
+        }
+
+        let starts_block =
+            line.trim_end().rsplit(' ').next().is_some_and(|token| {
+                token.starts_with(['|', '>']) && token.len() < line.trim().len()
+            });
+        if starts_block {
+            block_indent = Some(indent);
+            output.push_str(line);
+        } else {
+            match wrap_line(line, width) {
+                Some(wrapped) => output.push_str(&wrapped),
+                None => output.push_str(line),
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn wrap_line(line: &str, width: usize) -> Option<String> {
+    if line.chars().count() <= width {
+        return None;
+    }
+
+    // Column of the key, after indentation and any sequence markers
+    let key_start = line.len() - line.trim_start_matches([' ', '-']).len();
+    if line[..key_start].ends_with('-') {
+        return None;
+    }
+    let (key, value) = line[key_start..].split_once(": ")?;
+    if key.is_empty() || key.contains([' ', '\'', '"']) {
+        return None;
+    }
+
+    // Only plain scalars are folded; a folded scalar only round-trips words
+    // separated by single spaces
+    let first = value.chars().next()?;
+    if "'\"|>{[&*!#%@`".contains(first)
+        || value.contains("  ")
+        || value.contains(" #")
+        || !value.contains(' ')
+    {
+        return None;
+    }
+
+    let indent = " ".repeat(key_start + 2);
+    let available = width.saturating_sub(indent.len()).max(20);
+    let mut wrapped = format!("{}{}: >-", &line[..key_start], key);
+    let mut current = String::new();
+    for word in value.split(' ') {
+        if !current.is_empty() && current.len() + 1 + word.len() > available {
+            wrapped.push('\n');
+            wrapped.push_str(&indent);
+            wrapped.push_str(&current);
+            current.clear();
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    wrapped.push('\n');
+    wrapped.push_str(&indent);
+    wrapped.push_str(&current);
+    Some(wrapped)
+}
+
 /// Exporter for ODCS (Open Data Contract Standard) v3.1.0 YAML format.
 pub struct ODCSExporter;
 
@@ -147,6 +383,89 @@ impl ODCSExporter {
         Ok(yaml)
     }
 
+    /// Export an ODCSContract to YAML with the given style options.
+    ///
+    /// Like `export_contract_validated()`, the output is validated when the
+    /// `schema-validation` feature is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The ODCSContract to export
+    /// * `style` - Ordering, empty-section and line-width options
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the YAML string or an ExportError.
+    pub fn export_contract_with_style(
+        contract: &ODCSContract,
+        style: &ODCSStyleOptions,
+    ) -> Result<String, ExportError> {
+        let mut contract = contract.clone();
+        if style.schema_order == ODCSOrdering::Alphabetical {
+            contract.schema.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        if style.property_order == ODCSOrdering::Alphabetical {
+            for schema in &mut contract.schema {
+                sort_properties(&mut schema.properties);
+            }
+        }
+
+        let yaml = if style.include_empty_sections {
+            serde_yaml::to_string(&contract)
+        } else {
+            serde_yaml::to_value(&contract).and_then(|mut value| {
+                prune_empty_sections(&mut value);
+                serde_yaml::to_string(&value)
+            })
+        }
+        .map_err(|e| {
+            ExportError::SerializationError(format!("Failed to serialize contract: {}", e))
+        })?;
+
+        let yaml = match style.line_width {
+            Some(width) => wrap_long_lines(&yaml, width),
+            None => yaml,
+        };
+
+        // Validate exported YAML against ODCS schema (if feature enabled)
+        #[cfg(feature = "schema-validation")]
+        {
+            use crate::validation::schema::validate_odcs_internal;
+            validate_odcs_internal(&yaml).map_err(|e| {
+                ExportError::ValidationError(format!("ODCS validation failed: {}", e))
+            })?;
+        }
+
+        Ok(yaml)
+    }
+
+    /// Export an ODCSContract to YAML, keeping references to workspace definitions.
+    ///
+    /// Properties that were resolved from the workspace's reusable property
+    /// definitions are written back as `$ref`s with their overridden fields, so
+    /// that shared fields stay defined once in `definitions.yaml`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The ODCSContract to export
+    /// * `definitions` - The workspace property definitions
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the YAML string or an ExportError.
+    pub fn export_contract_with_definitions(
+        contract: &ODCSContract,
+        definitions: &PropertyDefinitions,
+    ) -> Result<String, ExportError> {
+        let mut value = serde_json::to_value(contract).map_err(|e| {
+            ExportError::SerializationError(format!("Failed to serialize contract: {}", e))
+        })?;
+        definitions.collapse(&mut value);
+        serde_yaml::to_string(&value).map_err(|e| {
+            ExportError::SerializationError(format!("Failed to serialize contract: {}", e))
+        })
+    }
+
     /// Parse STRUCT definition from data_type string and create nested properties
     /// This is used when SQL parser doesn't create nested columns but we have STRUCT types
     fn parse_struct_properties_from_data_type(
@@ -381,6 +700,14 @@ impl ODCSExporter {
         if upper.starts_with("ARRAY<") {
             return ("array".to_string(), true);
         }
+        // Spatial values are GeoJSON geometry objects
+        if SpatialType::parse(data_type).is_some() {
+            return ("object".to_string(), false);
+        }
+        // Embedding vectors are fixed-length numeric arrays
+        if VectorType::parse(data_type).is_some() {
+            return ("array".to_string(), false);
+        }
 
         // Map to ODCS logical types
         if upper.contains("INT") || upper == "BIGINT" || upper == "SMALLINT" || upper == "TINYINT" {
@@ -2095,6 +2422,24 @@ impl ODCSExporter {
                         serde_yaml::Value::Number(serde_yaml::Number::from(scale)),
                     );
                 }
+                if let Some(srid) = opts.srid {
+                    opts_map.insert(
+                        serde_yaml::Value::String("srid".to_string()),
+                        serde_yaml::Value::Number(serde_yaml::Number::from(srid)),
+                    );
+                }
+                if let Some(dimension) = opts.dimension {
+                    opts_map.insert(
+                        serde_yaml::Value::String("dimension".to_string()),
+                        serde_yaml::Value::Number(serde_yaml::Number::from(dimension)),
+                    );
+                }
+                if let Some(ref metric) = opts.distance_metric {
+                    opts_map.insert(
+                        serde_yaml::Value::String("distanceMetric".to_string()),
+                        serde_yaml::Value::String(metric.clone()),
+                    );
+                }
                 if !opts_map.is_empty() {
                     prop.insert(
                         serde_yaml::Value::String("logicalTypeOptions".to_string()),
@@ -2588,4 +2933,150 @@ mod tests {
         assert!(yaml.contains("tags:"));
         assert!(yaml.contains("- test"));
     }
+
+    fn style_contract() -> ODCSContract {
+        use crate::models::odcs::SchemaObject;
+
+        ODCSContract::new("shop", "1.0.0").with_schemas(vec![
+            SchemaObject::new("orders").with_properties(vec![
+                Property::new("status", "string"),
+                Property::new("customer", "object").with_nested_properties(vec![
+                    Property::new("name", "string"),
+                    Property::new("email", "string"),
+                ]),
+                Property::new("id", "integer"),
+            ]),
+            SchemaObject::new("customers"),
+        ])
+    }
+
+    #[test]
+    fn test_export_contract_with_default_style_matches_export_contract() {
+        let contract = style_contract();
+        let yaml =
+            ODCSExporter::export_contract_with_style(&contract, &ODCSStyleOptions::default())
+                .unwrap();
+        assert_eq!(yaml, ODCSExporter::export_contract(&contract));
+    }
+
+    #[test]
+    fn test_export_contract_alphabetical_order() {
+        let style = ODCSStyleOptions {
+            schema_order: ODCSOrdering::Alphabetical,
+            property_order: ODCSOrdering::Alphabetical,
+            ..Default::default()
+        };
+        let yaml = ODCSExporter::export_contract_with_style(&style_contract(), &style).unwrap();
+
+        let position = |needle: &str| yaml.find(needle).unwrap();
+        assert!(position("name: customers") < position("name: orders"));
+        assert!(position("name: customer\n") < position("name: id"));
+        assert!(position("name: id") < position("name: status"));
+        assert!(position("name: email") < position("name: name\n"));
+    }
+
+    #[test]
+    fn test_export_contract_without_empty_sections() {
+        let style = ODCSStyleOptions {
+            include_empty_sections: false,
+            ..Default::default()
+        };
+        let contract = style_contract();
+        assert!(ODCSExporter::export_contract(&contract).contains("properties: []"));
+
+        let yaml = ODCSExporter::export_contract_with_style(&contract, &style).unwrap();
+        assert!(!yaml.contains("[]"));
+        let parsed: ODCSContract = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, contract);
+    }
+
+    #[test]
+    fn test_export_contract_line_width() {
+        let description = "Identifier of the order as assigned by the upstream order \
+                           management system when the order was first placed";
+        let mut contract = style_contract();
+        contract.schema[0].properties[2].description = Some(description.to_string());
+        contract.schema[0].properties[0].description =
+            Some("First line of a long literal block\nkey: value that must not be folded because it is inside a block".to_string());
+
+        let style = ODCSStyleOptions {
+            line_width: Some(60),
+            ..Default::default()
+        };
+        let yaml = ODCSExporter::export_contract_with_style(&contract, &style).unwrap();
+        assert!(yaml.contains("description: >-"));
+        assert!(
+            yaml.lines()
+                .filter(|l| l.contains("upstream"))
+                .all(|l| l.len() <= 60)
+        );
+
+        let parsed: ODCSContract = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, contract);
+    }
+
+    #[test]
+    fn test_style_from_export_options() {
+        let options = ExportOptions::new()
+            .with_property("property_order", "alphabetical")
+            .with_property("include_empty", "false")
+            .with_property("line_width", "80");
+        let style = ODCSStyleOptions::from_export_options(&options).unwrap();
+        assert_eq!(style.property_order, ODCSOrdering::Alphabetical);
+        assert_eq!(style.schema_order, ODCSOrdering::Model);
+        assert!(!style.include_empty_sections);
+        assert_eq!(style.line_width, Some(80));
+
+        let invalid = ExportOptions::new().with_property("schema_order", "random");
+        assert!(ODCSStyleOptions::from_export_options(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_export_contract_with_definitions_round_trip() {
+        use crate::import::odcs::ODCSImporter;
+
+        let definitions = PropertyDefinitions::from_yaml(
+            "definitions:\n  order_id:\n    logicalType: string\n    physicalType: uuid\n    required: true\n",
+        )
+        .unwrap();
+        let yaml = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders-contract
+version: 1.0.0
+status: active
+name: orders
+schema:
+  - name: orders
+    properties:
+      - $ref: definitions.yaml#/definitions/order_id
+      - name: parent_order_id
+        $ref: definitions.yaml#/definitions/order_id
+        required: false
+"#;
+        let contract = ODCSImporter::new()
+            .with_definitions(definitions.clone())
+            .import_contract(yaml)
+            .unwrap();
+        let properties = &contract.schema[0].properties;
+        assert_eq!(properties[0].name, "order_id");
+        assert_eq!(properties[0].physical_type.as_deref(), Some("uuid"));
+        assert!(properties[0].required);
+        assert!(!properties[1].required);
+
+        let exported =
+            ODCSExporter::export_contract_with_definitions(&contract, &definitions).unwrap();
+        let value: serde_json::Value = serde_yaml::from_str(&exported).unwrap();
+        assert_eq!(
+            value["schema"][0]["properties"],
+            serde_json::json!([
+                {"$ref": "definitions.yaml#/definitions/order_id"},
+                {
+                    "name": "parent_order_id",
+                    "$ref": "definitions.yaml#/definitions/order_id",
+                    "required": false
+                }
+            ])
+        );
+    }
 }
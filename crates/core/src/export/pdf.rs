@@ -1,7 +1,8 @@
 //! PDF exporter with branding support
 //!
-//! Exports ODCS, ODPS, Knowledge Base articles, and Architecture Decision Records
-//! to PDF format with customizable branding options.
+//! Exports ODCS, ODPS, Knowledge Base articles, Architecture Decision Records,
+//! governance scorecards and pipeline reports to PDF format with customizable
+//! branding options.
 //!
 //! ## Features
 //!
@@ -9,6 +10,7 @@
 //! - Customizable header and footer
 //! - Brand color theming
 //! - Page numbering
+//! - Optional table of contents
 //! - Proper GitHub Flavored Markdown rendering
 //!
 //! ## WASM Compatibility
@@ -70,6 +72,10 @@ pub struct BrandingConfig {
     /// Page size (A4 or Letter)
     #[serde(default)]
     pub page_size: PageSize,
+
+    /// Include a table of contents listing the document's sections
+    #[serde(default)]
+    pub include_toc: bool,
 }
 
 fn default_logo_url() -> Option<String> {
@@ -105,10 +111,41 @@ impl Default for BrandingConfig {
             show_timestamp: default_true(),
             font_size: default_font_size(),
             page_size: PageSize::default(),
+            include_toc: false,
         }
     }
 }
 
+impl BrandingConfig {
+    /// Brand color as PDF RGB components (0.0-1.0).
+    ///
+    /// Accepts `#RRGGBB` or `#RGB`; falls back to the default brand color
+    /// when the value cannot be parsed.
+    pub fn brand_rgb(&self) -> (f64, f64, f64) {
+        parse_hex_color(&self.brand_color)
+            .or_else(|| parse_hex_color(&default_brand_color()))
+            .unwrap_or((0.0, 0.4, 0.8))
+    }
+}
+
+fn parse_hex_color(value: &str) -> Option<(f64, f64, f64)> {
+    let hex = value.trim().trim_start_matches('#');
+    if !hex.is_ascii() {
+        return None;
+    }
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
+    let channel = |i: usize| {
+        u8::from_str_radix(&expanded[i..i + 2], 16)
+            .ok()
+            .map(|v| v as f64 / 255.0)
+    };
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
 /// Page size options
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -156,6 +193,79 @@ pub struct PdfExportResult {
     pub title: String,
 }
 
+/// Outcome of a single governance check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScorecardStatus {
+    /// Check passed
+    Pass,
+    /// Check passed with findings
+    Warn,
+    /// Check failed
+    Fail,
+}
+
+impl ScorecardStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            ScorecardStatus::Pass => "Pass",
+            ScorecardStatus::Warn => "Warn",
+            ScorecardStatus::Fail => "Fail",
+        }
+    }
+}
+
+/// A single check on a governance scorecard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScorecardCheck {
+    /// Check name (e.g., "Owner assigned")
+    pub name: String,
+    /// Category used to group checks (e.g., "Ownership", "Quality")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Check outcome
+    pub status: ScorecardStatus,
+    /// Additional details or remediation hints
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+/// Governance scorecard for a workspace, domain or asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceScorecard {
+    /// Scorecard title
+    pub title: String,
+    /// What the scorecard covers (e.g., a domain or contract name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Individual checks
+    #[serde(default)]
+    pub checks: Vec<ScorecardCheck>,
+}
+
+impl GovernanceScorecard {
+    /// Overall score in percent; warnings count as half a pass
+    pub fn overall_score(&self) -> f64 {
+        if self.checks.is_empty() {
+            return 100.0;
+        }
+        let points: f64 = self
+            .checks
+            .iter()
+            .map(|check| match check.status {
+                ScorecardStatus::Pass => 1.0,
+                ScorecardStatus::Warn => 0.5,
+                ScorecardStatus::Fail => 0.0,
+            })
+            .sum();
+        points / self.checks.len() as f64 * 100.0
+    }
+
+    fn count(&self, status: ScorecardStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+}
+
 /// PDF exporter with branding support
 pub struct PdfExporter {
     branding: BrandingConfig,
@@ -266,6 +376,40 @@ impl PdfExporter {
         self.generate_pdf(&title, &markdown, &filename, "Compute Asset")
     }
 
+    /// Export a governance scorecard to PDF
+    pub fn export_scorecard(
+        &self,
+        scorecard: &GovernanceScorecard,
+    ) -> Result<PdfExportResult, ExportError> {
+        let markdown = self.scorecard_to_markdown(scorecard);
+        let filename = format!(
+            "{}.pdf",
+            scorecard
+                .title
+                .to_lowercase()
+                .replace(' ', "_")
+                .replace('/', "-")
+        );
+        self.generate_pdf(
+            &scorecard.title,
+            &markdown,
+            &filename,
+            "Governance Scorecard",
+        )
+    }
+
+    /// Export a pipeline run report to PDF
+    #[cfg(feature = "pipeline")]
+    pub fn export_pipeline_report(
+        &self,
+        report: &crate::pipeline::PipelineReport,
+    ) -> Result<PdfExportResult, ExportError> {
+        let title = format!("Pipeline Run {}", report.run_id);
+        let markdown = self.pipeline_report_to_markdown(report);
+        let filename = format!("pipeline-report-{}.pdf", report.run_id);
+        self.generate_pdf(&title, &markdown, &filename, "Pipeline Report")
+    }
+
     // ============================================================================
     // Public Markdown Generation Methods (for WASM bindings)
     // ============================================================================
@@ -1500,6 +1644,119 @@ impl PdfExporter {
         md
     }
 
+    /// Convert a governance scorecard to markdown, grouping checks by category
+    fn scorecard_to_markdown(&self, scorecard: &GovernanceScorecard) -> String {
+        let mut md = format!("# {}\n\n", scorecard.title);
+
+        md.push_str("| Property | Value |\n");
+        md.push_str("|----------|-------|\n");
+        if let Some(scope) = &scorecard.scope {
+            md.push_str(&format!("| **Scope** | {} |\n", scope));
+        }
+        md.push_str(&format!(
+            "| **Score** | {:.0}% |\n",
+            scorecard.overall_score()
+        ));
+        md.push_str(&format!(
+            "| **Checks** | {} passed, {} warnings, {} failed |\n\n",
+            scorecard.count(ScorecardStatus::Pass),
+            scorecard.count(ScorecardStatus::Warn),
+            scorecard.count(ScorecardStatus::Fail)
+        ));
+
+        let mut categories: Vec<&str> = Vec::new();
+        for check in &scorecard.checks {
+            let category = check.category.as_deref().unwrap_or("General");
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+
+        for category in categories {
+            md.push_str(&format!("## {}\n\n", category));
+            md.push_str("| Check | Status | Details |\n");
+            md.push_str("|-------|--------|---------|\n");
+            for check in scorecard
+                .checks
+                .iter()
+                .filter(|c| c.category.as_deref().unwrap_or("General") == category)
+            {
+                md.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    check.name,
+                    check.status.label(),
+                    check.details.as_deref().unwrap_or("-")
+                ));
+            }
+            md.push('\n');
+        }
+
+        md
+    }
+
+    /// Convert a pipeline run report to markdown
+    #[cfg(feature = "pipeline")]
+    fn pipeline_report_to_markdown(&self, report: &crate::pipeline::PipelineReport) -> String {
+        let mut md = format!("# Pipeline Run {}\n\n", report.run_id);
+
+        md.push_str("| Property | Value |\n");
+        md.push_str("|----------|-------|\n");
+        md.push_str(&format!("| **Status** | {} |\n", report.status));
+        md.push_str(&format!(
+            "| **Duration** | {} |\n",
+            report.duration_formatted()
+        ));
+        md.push_str(&format!(
+            "| **Stages Completed** | {} |\n\n",
+            report.stages_completed.len()
+        ));
+
+        md.push_str("## Stages\n\n");
+        md.push_str("| Stage | Status | Duration | Files |\n");
+        md.push_str("|-------|--------|----------|-------|\n");
+        for stage in &report.stages_completed {
+            let Some(output) = report.outputs.get(stage.name()) else {
+                continue;
+            };
+            let status = if output.skipped {
+                "Skipped"
+            } else if output.success {
+                "OK"
+            } else {
+                "Failed"
+            };
+            md.push_str(&format!(
+                "| {} | {} | {}ms | {} |\n",
+                stage.name(),
+                status,
+                output.duration_ms,
+                output.files.len()
+            ));
+        }
+        md.push('\n');
+
+        let skipped: Vec<_> = report
+            .stages_completed
+            .iter()
+            .filter_map(|stage| {
+                let output = report.outputs.get(stage.name())?;
+                output
+                    .skip_reason
+                    .as_ref()
+                    .map(|reason| (stage.name(), reason))
+            })
+            .collect();
+        if !skipped.is_empty() {
+            md.push_str("## Skipped Stages\n\n");
+            for (stage, reason) in skipped {
+                md.push_str(&format!("- **{}**: {}\n", stage, reason));
+            }
+            md.push('\n');
+        }
+
+        md
+    }
+
     /// Generate PDF from markdown content
     fn generate_pdf(
         &self,
@@ -1508,6 +1765,12 @@ impl PdfExporter {
         filename: &str,
         doc_type: &str,
     ) -> Result<PdfExportResult, ExportError> {
+        let markdown = if self.branding.include_toc {
+            self.with_table_of_contents(markdown)
+        } else {
+            markdown.to_string()
+        };
+        let markdown = markdown.as_str();
         let pdf_content = self.create_pdf_document(title, markdown, doc_type)?;
         let pdf_base64 =
             base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &pdf_content);
@@ -1524,6 +1787,50 @@ impl PdfExporter {
         })
     }
 
+    /// Insert a "Contents" section listing the `##` headings after the H1 title
+    fn with_table_of_contents(&self, markdown: &str) -> String {
+        let mut in_code_block = false;
+        let headings: Vec<&str> = markdown
+            .lines()
+            .filter(|line| {
+                if line.trim().starts_with("```") {
+                    in_code_block = !in_code_block;
+                }
+                !in_code_block
+            })
+            .filter_map(|line| line.trim().strip_prefix("## "))
+            .map(str::trim)
+            .filter(|heading| !heading.is_empty())
+            .collect();
+        if headings.is_empty() {
+            return markdown.to_string();
+        }
+
+        let mut toc = String::from("## Contents\n\n");
+        for (i, heading) in headings.iter().enumerate() {
+            toc.push_str(&format!("{}. {}\n", i + 1, heading));
+        }
+        toc.push('\n');
+
+        let mut result = String::with_capacity(markdown.len() + toc.len());
+        let mut inserted = false;
+        for line in markdown.lines() {
+            let trimmed = line.trim();
+            if !inserted && trimmed.starts_with("## ") {
+                result.push_str(&toc);
+                inserted = true;
+            }
+            result.push_str(line);
+            result.push('\n');
+            if !inserted && trimmed.starts_with("# ") {
+                result.push('\n');
+                result.push_str(&toc);
+                inserted = true;
+            }
+        }
+        result
+    }
+
     /// Create a PDF document with proper GFM rendering and multi-page support
     fn create_pdf_document(
         &self,
@@ -1687,6 +1994,17 @@ impl PdfExporter {
         let line_height = body_font_size * 1.4;
         let max_width = width - (2.0 * margin);
         let mut page_num = 1;
+        let (brand_r, brand_g, brand_b) = self.branding.brand_rgb();
+        let brand_fill = format!("{:.3} {:.3} {:.3} rg\n", brand_r, brand_g, brand_b);
+        let running_header = self
+            .branding
+            .header
+            .clone()
+            .or_else(|| self.branding.company_name.clone())
+            .unwrap_or_else(|| "Open Data Modelling".to_string());
+        let footer_text =
+            self.escape_pdf_string(self.branding.footer.as_deref().unwrap_or(DEFAULT_COPYRIGHT));
+        let running_header = self.escape_pdf_string(&running_header);
 
         // === HEADER SECTION WITH LOGO ===
 
@@ -1695,9 +2013,9 @@ impl PdfExporter {
         let logo_cy = height - margin - 10.0;
         let logo_r = 12.0;
 
-        // Draw filled blue circle
+        // Draw filled circle in the brand color
         stream.push_str("q\n");
-        stream.push_str("0 0.4 0.8 rg\n"); // RGB for #0066CC
+        stream.push_str(&brand_fill);
         stream.push_str(&format!("{:.2} {:.2} m\n", logo_cx + logo_r, logo_cy));
         // Approximate circle with bezier curves
         let k = 0.5523; // bezier constant for circle
@@ -1769,11 +2087,33 @@ impl PdfExporter {
         let logo_text_y = height - margin - 5.0;
         stream.push_str("/F2 11 Tf\n"); // Bold font
         stream.push_str(&format!("{:.2} {:.2} Td\n", logo_text_x, logo_text_y));
-        stream.push_str("(Open Data) Tj\n");
-        stream.push_str(&format!("0 {:.2} Td\n", -12.0));
-        stream.push_str("(Modelling) Tj\n");
+        if let Some(company) = &self.branding.company_name {
+            stream.push_str(&format!(
+                "0 {:.2} Td\n({}) Tj\n",
+                -6.0,
+                self.escape_pdf_string(company)
+            ));
+        } else {
+            stream.push_str("(Open Data) Tj\n");
+            stream.push_str(&format!("0 {:.2} Td\n", -12.0));
+            stream.push_str("(Modelling) Tj\n");
+        }
         stream.push_str("ET\n");
 
+        // Custom header text on the right of the first page
+        if let Some(header) = &self.branding.header {
+            stream.push_str("BT\n");
+            stream.push_str("/F1 9 Tf\n");
+            stream.push_str("0.3 0.3 0.3 rg\n");
+            stream.push_str(&format!(
+                "1 0 0 1 {:.2} {:.2} Tm\n",
+                width - margin - (header.chars().count() as f64 * 9.0 * 0.5),
+                height - margin - 10.0
+            ));
+            stream.push_str(&format!("({}) Tj\n", self.escape_pdf_string(header)));
+            stream.push_str("ET\n");
+        }
+
         // Draw header line below logo
         let header_line_y = height - margin - 30.0;
         stream.push_str(&format!(
@@ -1834,7 +2174,7 @@ impl PdfExporter {
                         margin,
                         height - margin - 10.0
                     ));
-                    stream.push_str("(Open Data Modelling) Tj\n");
+                    stream.push_str(&format!("({}) Tj\n", running_header));
                     stream.push_str("ET\n");
                 }
 
@@ -1850,14 +2190,18 @@ impl PdfExporter {
 
                 let footer_y = margin + 15.0;
 
-                // Copyright text on the left (use octal \251 for © symbol)
+                // Footer text on the left (defaults to the copyright notice)
                 stream.push_str("BT\n");
                 stream.push_str("/F1 9 Tf\n");
                 stream.push_str("0 0 0 rg\n");
                 stream.push_str(&format!("1 0 0 1 {:.2} {:.2} Tm\n", margin, footer_y));
-                stream.push_str("(\\251 opendatamodelling.com) Tj\n");
+                stream.push_str(&format!("({}) Tj\n", footer_text));
                 stream.push_str("ET\n");
 
+                if !self.branding.show_page_numbers {
+                    return;
+                }
+
                 // Page number on the right
                 stream.push_str("BT\n");
                 stream.push_str("/F1 9 Tf\n");
@@ -2077,7 +2421,7 @@ impl PdfExporter {
                 y_pos -= line_height * 0.3;
                 stream.push_str("BT\n");
                 stream.push_str(&format!("/F2 {:.1} Tf\n", h2_size));
-                stream.push_str("0 0 0 rg\n");
+                stream.push_str(&brand_fill);
                 stream.push_str(&format!("1 0 0 1 {:.2} {:.2} Tm\n", margin, y_pos));
                 stream.push_str(&format!("({}) Tj\n", self.escape_pdf_string(text)));
                 stream.push_str("ET\n");
@@ -2744,6 +3088,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_brand_rgb() {
+        let mut branding = BrandingConfig {
+            brand_color: "#FF0000".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(branding.brand_rgb(), (1.0, 0.0, 0.0));
+
+        branding.brand_color = "0f0".to_string();
+        assert_eq!(branding.brand_rgb(), (0.0, 1.0, 0.0));
+
+        branding.brand_color = "not-a-color".to_string();
+        assert_eq!(branding.brand_rgb(), (0.0, 0.4, 0.8));
+    }
+
+    #[test]
+    fn test_table_of_contents() {
+        let exporter = PdfExporter::new();
+        let markdown =
+            "# Title\n\nIntro\n\n## First\n\ntext\n\n```\n## not a heading\n```\n\n## Second\n";
+        let with_toc = exporter.with_table_of_contents(markdown);
+
+        let toc_pos = with_toc.find("## Contents").unwrap();
+        assert!(toc_pos > with_toc.find("# Title").unwrap());
+        assert!(toc_pos < with_toc.find("## First").unwrap());
+        assert!(with_toc.contains("1. First\n2. Second\n"));
+        assert!(!with_toc.contains("3. "));
+
+        // Documents without sections are left untouched
+        assert_eq!(exporter.with_table_of_contents("# Title\n"), "# Title\n");
+    }
+
+    #[test]
+    fn test_export_scorecard_to_pdf() {
+        let scorecard = GovernanceScorecard {
+            title: "Sales Domain Scorecard".to_string(),
+            scope: Some("sales".to_string()),
+            checks: vec![
+                ScorecardCheck {
+                    name: "Owner assigned".to_string(),
+                    category: Some("Ownership".to_string()),
+                    status: ScorecardStatus::Pass,
+                    details: None,
+                },
+                ScorecardCheck {
+                    name: "Quality rules defined".to_string(),
+                    category: Some("Quality".to_string()),
+                    status: ScorecardStatus::Warn,
+                    details: Some("2 of 5 tables have no rules".to_string()),
+                },
+                ScorecardCheck {
+                    name: "SLA defined".to_string(),
+                    category: None,
+                    status: ScorecardStatus::Fail,
+                    details: None,
+                },
+            ],
+        };
+        assert_eq!(scorecard.overall_score(), 50.0);
+
+        let exporter = PdfExporter::with_branding(BrandingConfig {
+            include_toc: true,
+            ..Default::default()
+        });
+        let markdown = exporter.scorecard_to_markdown(&scorecard);
+        assert!(markdown.contains("| **Score** | 50% |"));
+        assert!(markdown.contains("## Ownership"));
+        assert!(markdown.contains("## General"));
+        assert!(
+            markdown.contains("| Quality rules defined | Warn | 2 of 5 tables have no rules |")
+        );
+
+        let result = exporter.export_scorecard(&scorecard).unwrap();
+        assert_eq!(result.filename, "sales_domain_scorecard.pdf");
+        assert!(!result.pdf_base64.is_empty());
+    }
+
+    #[test]
+    fn test_branding_applied_to_pdf() {
+        let exporter = PdfExporter::with_branding(BrandingConfig {
+            brand_color: "#FF0000".to_string(),
+            header: Some("Internal".to_string()),
+            footer: Some("Confidential".to_string()),
+            show_page_numbers: false,
+            ..Default::default()
+        });
+        let pdf = exporter
+            .create_pdf_document("Doc", "# Doc\n\n## Section\n\nBody", "Document")
+            .unwrap();
+        let pdf = String::from_utf8_lossy(&pdf);
+        assert!(pdf.contains("1.000 0.000 0.000 rg"));
+        assert!(pdf.contains("(Internal) Tj"));
+        assert!(pdf.contains("(Confidential) Tj"));
+        assert!(!pdf.contains("(Page 1) Tj"));
+    }
+
     /// Generate sample PDFs for visual inspection (writes to /tmp)
     /// Run with: cargo test generate_sample_pdfs_for_inspection -- --ignored --nocapture
     #[test]
@@ -5,8 +5,11 @@
 //! All identifiers are sanitized to comply with Protobuf naming rules.
 //! Reserved words are prefixed with an underscore to avoid conflicts.
 
+use std::collections::{BTreeSet, HashMap};
+
 use super::{ExportError, ExportResult};
-use crate::models::{DataModel, Table};
+use crate::models::odcs::Property;
+use crate::models::{Column, DataModel, Table, TypeMappingRegistry};
 
 /// Protobuf reserved words that cannot be used as field names.
 const PROTOBUF_RESERVED: &[&str] = &[
@@ -85,29 +88,141 @@ impl ProtobufExporter {
         tables: &[Table],
         version: &str,
     ) -> Result<ExportResult, ExportError> {
+        self.export_with_type_mappings(tables, version, TypeMappingRegistry::builtin())
+    }
+
+    /// Export tables to Protobuf format, mapping data types with `type_mappings`.
+    pub fn export_with_type_mappings(
+        &self,
+        tables: &[Table],
+        version: &str,
+        type_mappings: &TypeMappingRegistry,
+    ) -> Result<ExportResult, ExportError> {
+        Self::check_version(version)?;
+        let proto = Self::export_model_from_tables_with_version(tables, version, type_mappings);
+        Ok(ExportResult {
+            content: proto,
+            format: "protobuf".to_string(),
+        })
+    }
+
+    /// Export tables to Protobuf format with nested messages.
+    ///
+    /// Instead of flattening, dot-notation columns (`customer.name`, `items.[].sku`)
+    /// are rebuilt into nested `message` definitions, columns with `enum_values`
+    /// get an `enum` block and `UNION<...>` columns become a `oneof`. Field numbers
+    /// start at 1 in every message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::protobuf::ProtobufExporter;
+    /// use data_modelling_core::models::{Table, Column};
+    ///
+    /// let tables = vec![Table::new(
+    ///     "Order".to_string(),
+    ///     vec![
+    ///         Column::new("id".to_string(), "BIGINT".to_string()),
+    ///         Column::new("customer".to_string(), "OBJECT".to_string()),
+    ///         Column::new("customer.name".to_string(), "STRING".to_string()),
+    ///     ],
+    /// )];
+    ///
+    /// let result = ProtobufExporter.export_nested(&tables, "proto3").unwrap();
+    /// assert!(result.content.contains("  message Customer {"));
+    /// assert!(result.content.contains("Customer customer = 2;"));
+    /// ```
+    pub fn export_nested(
+        &self,
+        tables: &[Table],
+        version: &str,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_nested_with_type_mappings(tables, version, TypeMappingRegistry::builtin())
+    }
+
+    /// Export tables to Protobuf format with nested messages, mapping data types
+    /// with `type_mappings`.
+    pub fn export_nested_with_type_mappings(
+        &self,
+        tables: &[Table],
+        version: &str,
+        type_mappings: &TypeMappingRegistry,
+    ) -> Result<ExportResult, ExportError> {
+        Self::check_version(version)?;
+        let mut proto = Self::export_header(tables, version);
+        for table in tables {
+            let writer = NestedWriter {
+                columns: table.columns.iter().map(|c| (c.name.as_str(), c)).collect(),
+                version,
+                type_mappings,
+            };
+            let properties = Property::from_flat_paths(
+                &table
+                    .columns
+                    .iter()
+                    .map(|c| (c.name.clone(), Property::from(c)))
+                    .collect::<Vec<_>>(),
+            );
+            proto.push_str(&format!(
+                "message {} {{\n",
+                Self::sanitize_identifier(&table.name)
+            ));
+            proto.push_str(&Self::export_tags_as_comments(&table.tags));
+            proto.push_str(&writer.message_body(&properties, "", 1));
+            proto.push_str("}\n\n");
+        }
+        Ok(ExportResult {
+            content: proto,
+            format: "protobuf".to_string(),
+        })
+    }
+
+    fn check_version(version: &str) -> Result<(), ExportError> {
         if version != "proto2" && version != "proto3" {
             return Err(ExportError::InvalidArgument(format!(
                 "Invalid protobuf version: {}. Must be 'proto2' or 'proto3'",
                 version
             )));
         }
-        let proto = Self::export_model_from_tables_with_version(tables, version);
-        Ok(ExportResult {
-            content: proto,
-            format: "protobuf".to_string(),
-        })
+        Ok(())
     }
 
-    fn export_model_from_tables_with_version(tables: &[Table], version: &str) -> String {
+    /// Syntax, package and well-known type imports shared by all exports.
+    fn export_header(tables: &[Table], version: &str) -> String {
         let mut proto = String::new();
         proto.push_str(&format!("syntax = \"{}\";\n\n", version));
         proto.push_str("package com.datamodel;\n\n");
+
+        // Import the definitions of any well-known types in use
+        let imports: BTreeSet<&str> = tables
+            .iter()
+            .flat_map(|t| t.columns.iter())
+            .filter_map(Self::well_known_type)
+            .filter_map(well_known_import)
+            .collect();
+        if !imports.is_empty() {
+            for import in &imports {
+                proto.push_str(&format!("import \"{}\";\n", import));
+            }
+            proto.push('\n');
+        }
+        proto
+    }
+
+    fn export_model_from_tables_with_version(
+        tables: &[Table],
+        version: &str,
+        type_mappings: &TypeMappingRegistry,
+    ) -> String {
+        let mut proto = Self::export_header(tables, version);
+
         let mut field_number = 0u32;
         for table in tables {
-            proto.push_str(&Self::export_table_with_version(
+            proto.push_str(&Self::export_table_with_type_mappings(
                 table,
                 &mut field_number,
                 version,
+                type_mappings,
             ));
             proto.push('\n');
         }
@@ -159,6 +274,21 @@ impl ProtobufExporter {
         table: &Table,
         field_number: &mut u32,
         version: &str,
+    ) -> String {
+        Self::export_table_with_type_mappings(
+            table,
+            field_number,
+            version,
+            TypeMappingRegistry::builtin(),
+        )
+    }
+
+    /// Export a table to Protobuf message format, mapping data types with `type_mappings`.
+    pub fn export_table_with_type_mappings(
+        table: &Table,
+        field_number: &mut u32,
+        version: &str,
+        type_mappings: &TypeMappingRegistry,
     ) -> String {
         let mut proto = String::new();
 
@@ -172,26 +302,19 @@ impl ProtobufExporter {
         for column in &table.columns {
             *field_number += 1;
 
-            let proto_type = Self::map_data_type_to_protobuf(&column.data_type);
+            // Enum and message types preserved by the importer are written back as-is
+            let proto_type = Self::well_known_type(column)
+                .or_else(|| type_mappings.preserved_type("protobuf", column))
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| {
+                    Self::map_data_type_to_protobuf(&column.data_type, type_mappings)
+                });
             let is_repeated = column.data_type.to_lowercase().contains("array");
             let repeated = if is_repeated { "repeated " } else { "" };
 
             let field_name = Self::sanitize_identifier(&column.name);
 
-            // Handle field labels based on proto version
-            let field_label = if is_repeated {
-                "" // Repeated fields don't need optional/required
-            } else if version == "proto2" {
-                // proto2: all fields need a label
-                if column.nullable {
-                    "optional "
-                } else {
-                    "required "
-                }
-            } else {
-                // proto3: optional only for nullable fields
-                if column.nullable { "optional " } else { "" }
-            };
+            let field_label = field_label(is_repeated, column.nullable, version);
 
             proto.push_str(&format!(
                 "  {}{}{} {} = {};",
@@ -257,32 +380,343 @@ impl ProtobufExporter {
 
         // Convert Vec<&Table> to &[Table] by cloning
         let tables: Vec<Table> = tables_to_export.iter().map(|t| (*t).clone()).collect();
-        Self::export_model_from_tables_with_version(&tables, "proto3")
+        Self::export_model_from_tables_with_version(
+            &tables,
+            "proto3",
+            TypeMappingRegistry::builtin(),
+        )
+    }
+
+    /// Get the well-known type for a column, if its `logicalTypeOptions.format`
+    /// names one (e.g., `google.protobuf.Timestamp`, `google.type.Money`).
+    fn well_known_type(column: &Column) -> Option<&str> {
+        column
+            .logical_type_options
+            .as_ref()
+            .and_then(|o| o.format.as_deref())
+            .filter(|f| well_known_import(f).is_some())
     }
 
     /// Map SQL/ODCL data types to Protobuf types.
     ///
-    /// Note: For timestamp types, this returns basic proto types. If you need
-    /// google.protobuf.Timestamp or wrapper types, consider using the wrapper
-    /// type export option (future enhancement).
-    fn map_data_type_to_protobuf(data_type: &str) -> String {
-        let dt_lower = data_type.to_lowercase();
-
-        match dt_lower.as_str() {
-            "int" | "integer" | "smallint" | "tinyint" | "int32" => "int32".to_string(),
-            "bigint" | "int64" | "long" => "int64".to_string(),
-            "float" | "real" => "float".to_string(),
-            "double" | "decimal" | "numeric" => "double".to_string(),
-            "boolean" | "bool" => "bool".to_string(),
-            "bytes" | "binary" | "varbinary" => "bytes".to_string(),
-            // Temporal types - use int64 for timestamps (epoch millis) or string
-            "timestamp" | "datetime" => "int64".to_string(),
-            "date" | "time" => "string".to_string(),
-            "uuid" => "string".to_string(),
-            _ => {
-                // Default to string for VARCHAR, TEXT, CHAR, etc.
-                "string".to_string()
+    /// Note: For timestamp types, this returns basic proto types. Set
+    /// `logicalTypeOptions.format` to a well-known type name such as
+    /// `google.protobuf.Timestamp` to emit that type instead.
+    fn map_data_type_to_protobuf(data_type: &str, type_mappings: &TypeMappingRegistry) -> String {
+        // Default to string for VARCHAR, TEXT, CHAR, etc.
+        type_mappings
+            .export_type("protobuf", data_type)
+            .unwrap_or_else(|| "string".to_string())
+    }
+}
+
+/// Writes the body of a message whose fields are reconstructed from
+/// dot-notation columns.
+struct NestedWriter<'a> {
+    /// Columns of the table being exported, keyed by their flat path
+    columns: HashMap<&'a str, &'a Column>,
+    version: &'a str,
+    type_mappings: &'a TypeMappingRegistry,
+}
+
+impl NestedWriter<'_> {
+    /// Nested type definitions followed by the fields of `properties`.
+    fn message_body(&self, properties: &[Property], path: &str, depth: usize) -> String {
+        let indent = "  ".repeat(depth);
+        let mut definitions = String::new();
+        let mut fields = String::new();
+        let mut field_number = 0u32;
+
+        for property in properties {
+            let field_path = child_path(path, &property.name);
+            let column = self.columns.get(field_path.as_str()).copied();
+            let data_type = column.map(|c| c.data_type.trim()).unwrap_or("OBJECT");
+            let field_name = ProtobufExporter::sanitize_identifier(&property.name);
+            let type_name = message_name(&property.name);
+            let is_repeated = property.items.is_some() || is_array(data_type);
+
+            // Union-typed fields become a oneof with one field per branch type
+            if !is_repeated
+                && property.properties.is_empty()
+                && let Some(branches) = union_branches(data_type)
+            {
+                fields.push_str(&format!("{}oneof {} {{\n", indent, field_name));
+                let mut seen = BTreeSet::new();
+                for branch in branches {
+                    let proto_type =
+                        ProtobufExporter::map_data_type_to_protobuf(branch, self.type_mappings);
+                    if seen.insert(proto_type.clone()) {
+                        field_number += 1;
+                        fields.push_str(&format!(
+                            "{}  {} {} = {};\n",
+                            indent,
+                            proto_type,
+                            ProtobufExporter::sanitize_identifier(&format!(
+                                "{}_{}",
+                                property.name, proto_type
+                            )),
+                            field_number
+                        ));
+                    }
+                }
+                fields.push_str(&format!("{}}}\n", indent));
+                continue;
+            }
+
+            let proto_type = if let Some(items) = &property.items {
+                let items_path = format!("{}.[]", field_path);
+                if items.properties.is_empty() {
+                    let element_type = element_type(data_type);
+                    match self.columns.get(items_path.as_str()).copied() {
+                        Some(items_column) => self.scalar_type(
+                            items_column,
+                            &items_column.data_type,
+                            &type_name,
+                            &mut definitions,
+                            depth,
+                        ),
+                        None => match column {
+                            Some(column) => self.scalar_type(
+                                column,
+                                element_type,
+                                &type_name,
+                                &mut definitions,
+                                depth,
+                            ),
+                            None => ProtobufExporter::map_data_type_to_protobuf(
+                                element_type,
+                                self.type_mappings,
+                            ),
+                        },
+                    }
+                } else {
+                    definitions.push_str(&self.message(
+                        &type_name,
+                        &items.properties,
+                        &items_path,
+                        depth,
+                    ));
+                    type_name
+                }
+            } else if !property.properties.is_empty() {
+                definitions.push_str(&self.message(
+                    &type_name,
+                    &property.properties,
+                    &field_path,
+                    depth,
+                ));
+                type_name
+            } else {
+                match column {
+                    Some(column) => self.scalar_type(
+                        column,
+                        element_type(data_type),
+                        &type_name,
+                        &mut definitions,
+                        depth,
+                    ),
+                    None => "string".to_string(),
+                }
+            };
+
+            field_number += 1;
+            let label = field_label(is_repeated, !property.required, self.version);
+            let repeated = if is_repeated { "repeated " } else { "" };
+            fields.push_str(&format!(
+                "{}{}{}{} {} = {};",
+                indent, label, repeated, proto_type, field_name, field_number
+            ));
+            if let Some(description) = &property.description {
+                let desc = description.replace('\n', " ").replace('\r', "");
+                fields.push_str(&format!(" // {}", desc));
+            }
+            fields.push('\n');
+        }
+
+        definitions + &fields
+    }
+
+    /// A nested message definition at `depth`.
+    fn message(&self, name: &str, properties: &[Property], path: &str, depth: usize) -> String {
+        let indent = "  ".repeat(depth);
+        format!(
+            "{}message {} {{\n{}{}}}\n",
+            indent,
+            name,
+            self.message_body(properties, path, depth + 1),
+            indent
+        )
+    }
+
+    /// Protobuf type of a scalar column, adding an `enum` block to `definitions`
+    /// when the column lists its allowed values.
+    fn scalar_type(
+        &self,
+        column: &Column,
+        data_type: &str,
+        type_name: &str,
+        definitions: &mut String,
+        depth: usize,
+    ) -> String {
+        if let Some(native) = ProtobufExporter::well_known_type(column)
+            .or_else(|| self.type_mappings.preserved_type("protobuf", column))
+        {
+            return native.to_string();
+        }
+        if !column.enum_values.is_empty() {
+            definitions.push_str(&self.enumeration(type_name, &column.enum_values, depth));
+            return type_name.to_string();
+        }
+        ProtobufExporter::map_data_type_to_protobuf(data_type, self.type_mappings)
+    }
+
+    /// An enum definition; values are prefixed with the enum name because enum
+    /// values share the scope of the enclosing message.
+    fn enumeration(&self, name: &str, values: &[String], depth: usize) -> String {
+        let indent = "  ".repeat(depth);
+        let prefix = constant_name(name);
+        let mut proto = format!("{}enum {} {{\n", indent, name);
+        // proto3 requires the first enum value to be zero
+        let mut number = 0u32;
+        if self.version == "proto3" {
+            proto.push_str(&format!("{}  {}_UNSPECIFIED = 0;\n", indent, prefix));
+            number = 1;
+        }
+        let mut seen = BTreeSet::new();
+        for value in values {
+            let constant = format!("{}_{}", prefix, constant_name(value));
+            if seen.insert(constant.clone()) {
+                proto.push_str(&format!("{}  {} = {};\n", indent, constant, number));
+                number += 1;
+            }
+        }
+        proto.push_str(&format!("{}}}\n", indent));
+        proto
+    }
+}
+
+/// Field label for the protobuf version: proto2 labels every singular field,
+/// proto3 only marks nullable fields as optional.
+fn field_label(is_repeated: bool, nullable: bool, version: &str) -> &'static str {
+    if is_repeated {
+        "" // Repeated fields don't need optional/required
+    } else if version == "proto2" {
+        if nullable { "optional " } else { "required " }
+    } else if nullable {
+        "optional "
+    } else {
+        ""
+    }
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+fn is_array(data_type: &str) -> bool {
+    data_type.to_uppercase().starts_with("ARRAY")
+}
+
+/// Element type of `ARRAY<T>`, or the type itself.
+fn element_type(data_type: &str) -> &str {
+    data_type
+        .get(..6)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("ARRAY<"))
+        .and_then(|_| data_type[6..].strip_suffix('>'))
+        .map(str::trim)
+        .unwrap_or(data_type)
+}
+
+/// Branch types of `UNION<A, B, ...>`, split at top-level commas.
+fn union_branches(data_type: &str) -> Option<Vec<&str>> {
+    let inner = data_type
+        .get(..6)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("UNION<"))
+        .and_then(|_| data_type[6..].strip_suffix('>'))?;
+    let mut branches = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                branches.push(inner[start..i].trim());
+                start = i + 1;
             }
+            _ => {}
         }
     }
+    branches.push(inner[start..].trim());
+    Some(branches)
+}
+
+/// PascalCase message or enum name for a field, e.g. `line_items` -> `LineItems`.
+fn message_name(field: &str) -> String {
+    let name: String = field
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    ProtobufExporter::sanitize_identifier(&name)
+}
+
+/// UPPER_SNAKE_CASE enum constant, e.g. `OrderStatus` -> `ORDER_STATUS`.
+fn constant_name(value: &str) -> String {
+    let mut constant = String::new();
+    let mut previous: Option<char> = None;
+    for c in value.chars() {
+        if c.is_alphanumeric() {
+            if c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric()) {
+                constant.push('_');
+            }
+            constant.extend(c.to_uppercase());
+        } else if !constant.is_empty() && !constant.ends_with('_') {
+            constant.push('_');
+        }
+        previous = Some(c);
+    }
+    let constant = constant.trim_end_matches('_').to_string();
+    match constant.chars().next() {
+        Some(first) if first.is_alphabetic() => constant,
+        _ => format!("_{}", constant),
+    }
+}
+
+/// Get the `.proto` import path that defines a well-known type.
+fn well_known_import(type_name: &str) -> Option<&'static str> {
+    let import = match type_name {
+        "google.protobuf.Timestamp" => "google/protobuf/timestamp.proto",
+        "google.protobuf.Duration" => "google/protobuf/duration.proto",
+        "google.protobuf.Struct" | "google.protobuf.Value" | "google.protobuf.ListValue" => {
+            "google/protobuf/struct.proto"
+        }
+        "google.protobuf.Any" => "google/protobuf/any.proto",
+        "google.protobuf.FieldMask" => "google/protobuf/field_mask.proto",
+        "google.protobuf.StringValue"
+        | "google.protobuf.BytesValue"
+        | "google.protobuf.Int32Value"
+        | "google.protobuf.UInt32Value"
+        | "google.protobuf.Int64Value"
+        | "google.protobuf.UInt64Value"
+        | "google.protobuf.FloatValue"
+        | "google.protobuf.DoubleValue"
+        | "google.protobuf.BoolValue" => "google/protobuf/wrappers.proto",
+        "google.type.Money" => "google/type/money.proto",
+        "google.type.Date" => "google/type/date.proto",
+        "google.type.TimeOfDay" => "google/type/timeofday.proto",
+        "google.type.DateTime" => "google/type/datetime.proto",
+        _ => return None,
+    };
+    Some(import)
 }
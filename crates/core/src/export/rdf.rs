@@ -0,0 +1,937 @@
+//! RDF knowledge-graph exporter
+//!
+//! Emits the model as RDF triples, in Turtle or JSON-LD, so it can be loaded
+//! into a triple store and federated with other metadata sources. Resources
+//! are described with the ODM ontology ([`ODM_ONTOLOGY`], namespace
+//! [`ONTOLOGY_NAMESPACE`]) plus RDFS, SKOS and Dublin Core terms:
+//!
+//! | Model element | Class | Main properties |
+//! |---------------|-------|-----------------|
+//! | Workspace | `odm:Workspace` | `rdfs:label`, `odm:hasDomain` |
+//! | Domain | `odm:Domain` | `rdfs:label`, `odm:hasSystem` |
+//! | System | `odm:System` | `rdfs:label`, `odm:systemType` |
+//! | Table | `odm:Table` | `odm:inDomain`, `odm:inSystem`, `odm:hasColumn`, `odm:hasGlossaryTerm` |
+//! | Column | `odm:Column` | `odm:dataType`, `odm:primaryKey`, `odm:nullable`, `odm:position` |
+//! | Relationship | `odm:Relationship` | `odm:sourceTable`, `odm:targetTable`, `odm:relationshipType`, `odm:cardinality` |
+//! | Glossary term | `odm:GlossaryTerm`, `skos:Concept` | `skos:prefLabel`, `skos:definition`, `odm:inDomain` |
+//!
+//! Glossary terms are the knowledge articles of type
+//! [`KnowledgeType::Glossary`]; the tables and relationships they link to get
+//! `odm:hasGlossaryTerm`. Every resource carries its UUID as
+//! `dcterms:identifier`.
+//!
+//! Resource IRIs are `<base><kind>/<uuid>` (columns:
+//! `<base>table/<uuid>/column/<name>`), with the base IRI set by
+//! [`RdfExporter::with_base_iri`] (default [`DEFAULT_BASE_IRI`]).
+//!
+//! [`KnowledgeType::Glossary`]: crate::models::knowledge::KnowledgeType::Glossary
+
+use super::{ExportError, ExportResult};
+use crate::models::knowledge::{KnowledgeArticle, KnowledgeType};
+use crate::models::workspace::Workspace;
+use crate::models::{Relationship, Table};
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+/// Namespace of the ODM ontology
+pub const ONTOLOGY_NAMESPACE: &str =
+    "https://github.com/offenedatenmodellierung/data-modelling-sdk/ontology#";
+
+/// Default base IRI of exported resources
+pub const DEFAULT_BASE_IRI: &str = "urn:odm:";
+
+/// The ODM ontology (OWL, Turtle)
+pub const ODM_ONTOLOGY: &str = r#"@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+@prefix dcterms: <http://purl.org/dc/terms/> .
+@prefix odm: <https://github.com/offenedatenmodellierung/data-modelling-sdk/ontology#> .
+
+<https://github.com/offenedatenmodellierung/data-modelling-sdk/ontology> a owl:Ontology ;
+    rdfs:label "Open Data Modelling ontology" ;
+    rdfs:comment "Workspaces, domains, systems, tables, columns, relationships and glossary terms of a data model." .
+
+odm:Workspace a owl:Class ;
+    rdfs:label "Workspace" ;
+    rdfs:comment "Top-level container of domains, assets and relationships." .
+
+odm:Domain a owl:Class ;
+    rdfs:label "Domain" ;
+    rdfs:comment "Business domain grouping systems and tables." .
+
+odm:System a owl:Class ;
+    rdfs:label "System" ;
+    rdfs:comment "Physical infrastructure hosting tables within a domain." .
+
+odm:Table a owl:Class ;
+    rdfs:label "Table" ;
+    rdfs:comment "Table or dataset described by a data contract." .
+
+odm:Column a owl:Class ;
+    rdfs:label "Column" ;
+    rdfs:comment "Field of a table." .
+
+odm:Relationship a owl:Class ;
+    rdfs:label "Relationship" ;
+    rdfs:comment "Foreign key, data flow, dependency or ETL link between two tables." .
+
+odm:GlossaryTerm a owl:Class ;
+    rdfs:subClassOf skos:Concept ;
+    rdfs:label "Glossary term" ;
+    rdfs:comment "Business term defined in a glossary knowledge article." .
+
+odm:hasDomain a owl:ObjectProperty ;
+    rdfs:label "has domain" ;
+    rdfs:domain odm:Workspace ;
+    rdfs:range odm:Domain .
+
+odm:hasSystem a owl:ObjectProperty ;
+    rdfs:label "has system" ;
+    rdfs:domain odm:Domain ;
+    rdfs:range odm:System .
+
+odm:inDomain a owl:ObjectProperty ;
+    rdfs:label "in domain" ;
+    rdfs:range odm:Domain .
+
+odm:inSystem a owl:ObjectProperty ;
+    rdfs:label "in system" ;
+    rdfs:domain odm:Table ;
+    rdfs:range odm:System .
+
+odm:hasColumn a owl:ObjectProperty ;
+    rdfs:label "has column" ;
+    rdfs:domain odm:Table ;
+    rdfs:range odm:Column .
+
+odm:sourceTable a owl:ObjectProperty ;
+    rdfs:label "source table" ;
+    rdfs:domain odm:Relationship ;
+    rdfs:range odm:Table .
+
+odm:targetTable a owl:ObjectProperty ;
+    rdfs:label "target table" ;
+    rdfs:domain odm:Relationship ;
+    rdfs:range odm:Table .
+
+odm:hasGlossaryTerm a owl:ObjectProperty ;
+    rdfs:label "has glossary term" ;
+    rdfs:range odm:GlossaryTerm .
+
+odm:systemType a owl:DatatypeProperty ;
+    rdfs:label "system type" ;
+    rdfs:domain odm:System ;
+    rdfs:range xsd:string .
+
+odm:catalogName a owl:DatatypeProperty ;
+    rdfs:label "catalog name" ;
+    rdfs:domain odm:Table ;
+    rdfs:range xsd:string .
+
+odm:schemaName a owl:DatatypeProperty ;
+    rdfs:label "schema name" ;
+    rdfs:domain odm:Table ;
+    rdfs:range xsd:string .
+
+odm:owner a owl:DatatypeProperty ;
+    rdfs:label "owner" ;
+    rdfs:range xsd:string .
+
+odm:tag a owl:DatatypeProperty ;
+    rdfs:label "tag" ;
+    rdfs:range xsd:string .
+
+odm:dataType a owl:DatatypeProperty ;
+    rdfs:label "data type" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:string .
+
+odm:physicalType a owl:DatatypeProperty ;
+    rdfs:label "physical type" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:string .
+
+odm:primaryKey a owl:DatatypeProperty ;
+    rdfs:label "primary key" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:boolean .
+
+odm:nullable a owl:DatatypeProperty ;
+    rdfs:label "nullable" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:boolean .
+
+odm:unique a owl:DatatypeProperty ;
+    rdfs:label "unique" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:boolean .
+
+odm:position a owl:DatatypeProperty ;
+    rdfs:label "position" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:integer .
+
+odm:classification a owl:DatatypeProperty ;
+    rdfs:label "classification" ;
+    rdfs:domain odm:Column ;
+    rdfs:range xsd:string .
+
+odm:relationshipType a owl:DatatypeProperty ;
+    rdfs:label "relationship type" ;
+    rdfs:domain odm:Relationship ;
+    rdfs:range xsd:string .
+
+odm:cardinality a owl:DatatypeProperty ;
+    rdfs:label "cardinality" ;
+    rdfs:domain odm:Relationship ;
+    rdfs:range xsd:string .
+
+odm:sourceKey a owl:DatatypeProperty ;
+    rdfs:label "source key" ;
+    rdfs:domain odm:Relationship ;
+    rdfs:range xsd:string .
+
+odm:targetKey a owl:DatatypeProperty ;
+    rdfs:label "target key" ;
+    rdfs:domain odm:Relationship ;
+    rdfs:range xsd:string .
+"#;
+
+/// Prefixes used in exported graphs
+const PREFIXES: &[(&str, &str)] = &[
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("odm", ONTOLOGY_NAMESPACE),
+];
+
+/// RDF serialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RdfSyntax {
+    /// Turtle (`.ttl`)
+    #[default]
+    Turtle,
+    /// JSON-LD (`.jsonld`)
+    JsonLd,
+}
+
+impl RdfSyntax {
+    /// Parse a syntax name (`turtle`/`ttl`, `jsonld`/`json-ld`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "turtle" | "ttl" => Some(RdfSyntax::Turtle),
+            "jsonld" | "json-ld" => Some(RdfSyntax::JsonLd),
+            _ => None,
+        }
+    }
+
+    /// Syntax name
+    pub fn name(&self) -> &'static str {
+        match self {
+            RdfSyntax::Turtle => "turtle",
+            RdfSyntax::JsonLd => "jsonld",
+        }
+    }
+
+    /// File extension (without the leading dot)
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            RdfSyntax::Turtle => "ttl",
+            RdfSyntax::JsonLd => "jsonld",
+        }
+    }
+}
+
+/// Exporter for RDF knowledge graphs of the model
+#[derive(Debug, Clone)]
+pub struct RdfExporter {
+    syntax: RdfSyntax,
+    base_iri: String,
+}
+
+impl Default for RdfExporter {
+    fn default() -> Self {
+        Self {
+            syntax: RdfSyntax::default(),
+            base_iri: DEFAULT_BASE_IRI.to_string(),
+        }
+    }
+}
+
+impl RdfExporter {
+    /// Create a new exporter writing Turtle
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the serialization
+    pub fn with_syntax(mut self, syntax: RdfSyntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    /// Set the base IRI of exported resources (e.g. `https://data.example.com/model/`)
+    pub fn with_base_iri(mut self, base_iri: impl Into<String>) -> Self {
+        self.base_iri = base_iri.into();
+        self
+    }
+
+    /// Export a workspace graph (SDK interface)
+    pub fn export(
+        &self,
+        workspace: &Workspace,
+        tables: &[Table],
+        articles: &[KnowledgeArticle],
+    ) -> Result<ExportResult, ExportError> {
+        Ok(ExportResult {
+            content: self.export_workspace(workspace, tables, articles)?,
+            format: format!("rdf-{}", self.syntax.name()),
+        })
+    }
+
+    /// Export the domains, systems, tables, relationships and glossary terms
+    /// of a workspace
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::rdf::RdfExporter;
+    /// use data_modelling_core::models::workspace::Workspace;
+    /// use data_modelling_core::models::{Column, Table};
+    /// use uuid::Uuid;
+    ///
+    /// let mut workspace = Workspace::new("demo".to_string(), Uuid::new_v4());
+    /// workspace.add_domain(Uuid::new_v4(), "sales".to_string());
+    /// let table = Table::new(
+    ///     "orders".to_string(),
+    ///     vec![Column::new("id".to_string(), "BIGINT".to_string())],
+    /// );
+    ///
+    /// let turtle = RdfExporter::new()
+    ///     .export_workspace(&workspace, &[table], &[])
+    ///     .unwrap();
+    /// assert!(turtle.contains("a odm:Domain"));
+    /// assert!(turtle.contains("a odm:Column"));
+    /// ```
+    pub fn export_workspace(
+        &self,
+        workspace: &Workspace,
+        tables: &[Table],
+        articles: &[KnowledgeArticle],
+    ) -> Result<String, ExportError> {
+        let mut graph = self.graph()?;
+        graph.add_workspace(workspace);
+        for table in tables {
+            graph.add_table(table, Some(workspace));
+        }
+        for relationship in &workspace.relationships {
+            graph.add_relationship(relationship);
+        }
+        for article in articles
+            .iter()
+            .filter(|a| a.article_type == KnowledgeType::Glossary)
+        {
+            graph.add_glossary_term(article, Some(workspace));
+        }
+        self.serialize(&graph)
+    }
+
+    /// Export tables and the relationships between them, without workspace
+    /// structure
+    pub fn export_tables(
+        &self,
+        tables: &[Table],
+        relationships: &[Relationship],
+    ) -> Result<String, ExportError> {
+        let mut graph = self.graph()?;
+        for table in tables {
+            graph.add_table(table, None);
+        }
+        for relationship in relationships {
+            graph.add_relationship(relationship);
+        }
+        self.serialize(&graph)
+    }
+
+    fn graph(&self) -> Result<Graph, ExportError> {
+        if self.base_iri.is_empty()
+            || !self.base_iri.contains(':')
+            || self
+                .base_iri
+                .chars()
+                .any(|c| c.is_whitespace() || "<>\"{}|^`\\".contains(c))
+        {
+            return Err(ExportError::InvalidArgument(format!(
+                "Invalid base IRI: '{}'",
+                self.base_iri
+            )));
+        }
+        Ok(Graph {
+            base_iri: self.base_iri.clone(),
+            triples: Vec::new(),
+        })
+    }
+
+    fn serialize(&self, graph: &Graph) -> Result<String, ExportError> {
+        match self.syntax {
+            RdfSyntax::Turtle => Ok(graph.to_turtle()),
+            RdfSyntax::JsonLd => serde_json::to_string_pretty(&graph.to_json_ld())
+                .map_err(|e| ExportError::SerializationError(e.to_string())),
+        }
+    }
+}
+
+/// Object of a triple
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    /// Resource IRI
+    Iri(String),
+    /// Vocabulary term as a prefixed name (e.g. `odm:Table`)
+    Name(&'static str),
+    Text(String),
+    Boolean(bool),
+    Integer(i64),
+}
+
+#[derive(Debug, Clone)]
+struct Triple {
+    subject: String,
+    /// Prefixed name of the predicate
+    predicate: &'static str,
+    object: Term,
+}
+
+/// Triples of an export, in insertion order
+struct Graph {
+    base_iri: String,
+    triples: Vec<Triple>,
+}
+
+impl Graph {
+    fn iri(&self, kind: &str, id: &Uuid) -> String {
+        format!("{}{}/{}", self.base_iri, kind, id)
+    }
+
+    fn column_iri(&self, table_id: &Uuid, column: &str) -> String {
+        format!(
+            "{}/column/{}",
+            self.iri("table", table_id),
+            percent_encode(column)
+        )
+    }
+
+    fn add(&mut self, subject: &str, predicate: &'static str, object: Term) {
+        self.triples.push(Triple {
+            subject: subject.to_string(),
+            predicate,
+            object,
+        });
+    }
+
+    fn add_text(&mut self, subject: &str, predicate: &'static str, value: Option<&str>) {
+        if let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) {
+            self.add(subject, predicate, Term::Text(value.to_string()));
+        }
+    }
+
+    fn add_workspace(&mut self, workspace: &Workspace) {
+        let iri = self.iri("workspace", &workspace.id);
+        self.add(&iri, "rdf:type", Term::Name("odm:Workspace"));
+        self.add_text(&iri, "rdfs:label", Some(&workspace.name));
+        self.add_text(&iri, "dcterms:identifier", Some(&workspace.id.to_string()));
+        self.add_text(
+            &iri,
+            "dcterms:description",
+            workspace.description.as_deref(),
+        );
+
+        for domain in &workspace.domains {
+            let domain_iri = self.iri("domain", &domain.id);
+            self.add(&iri, "odm:hasDomain", Term::Iri(domain_iri.clone()));
+            self.add(&domain_iri, "rdf:type", Term::Name("odm:Domain"));
+            self.add_text(&domain_iri, "rdfs:label", Some(&domain.name));
+            self.add_text(
+                &domain_iri,
+                "dcterms:identifier",
+                Some(&domain.id.to_string()),
+            );
+            self.add_text(
+                &domain_iri,
+                "dcterms:description",
+                domain.description.as_deref(),
+            );
+
+            for system in &domain.systems {
+                let system_iri = self.iri("system", &system.id);
+                self.add(&domain_iri, "odm:hasSystem", Term::Iri(system_iri.clone()));
+                self.add(&system_iri, "rdf:type", Term::Name("odm:System"));
+                self.add_text(&system_iri, "rdfs:label", Some(&system.name));
+                self.add_text(
+                    &system_iri,
+                    "dcterms:identifier",
+                    Some(&system.id.to_string()),
+                );
+                self.add_text(
+                    &system_iri,
+                    "dcterms:description",
+                    system.description.as_deref(),
+                );
+                let system_type = system.system_type.as_ref().and_then(enum_name);
+                self.add_text(&system_iri, "odm:systemType", system_type.as_deref());
+            }
+        }
+    }
+
+    fn add_table(&mut self, table: &Table, workspace: Option<&Workspace>) {
+        let iri = self.iri("table", &table.id);
+        self.add(&iri, "rdf:type", Term::Name("odm:Table"));
+        self.add_text(&iri, "rdfs:label", Some(&table.name));
+        self.add_text(&iri, "dcterms:identifier", Some(&table.id.to_string()));
+        self.add_text(
+            &iri,
+            "dcterms:description",
+            table_description(table).as_deref(),
+        );
+        self.add_text(&iri, "odm:catalogName", table.catalog_name.as_deref());
+        self.add_text(&iri, "odm:schemaName", table.schema_name.as_deref());
+        self.add_text(&iri, "odm:owner", table.owner.as_deref());
+        for tag in &table.tags {
+            self.add_text(&iri, "odm:tag", Some(&tag.to_string()));
+        }
+
+        if let Some(workspace) = workspace {
+            let domain = workspace
+                .assets
+                .iter()
+                .find(|a| a.id == table.id)
+                .and_then(|asset| {
+                    workspace
+                        .domains
+                        .iter()
+                        .find(|d| d.name.eq_ignore_ascii_case(&asset.domain))
+                });
+            if let Some(domain) = domain {
+                let domain_iri = self.iri("domain", &domain.id);
+                self.add(&iri, "odm:inDomain", Term::Iri(domain_iri));
+            }
+            for system in workspace.domains.iter().flat_map(|d| &d.systems) {
+                if system.table_ids.contains(&table.id) {
+                    let system_iri = self.iri("system", &system.id);
+                    self.add(&iri, "odm:inSystem", Term::Iri(system_iri));
+                }
+            }
+        }
+
+        for (index, column) in table.columns.iter().enumerate() {
+            let column_iri = self.column_iri(&table.id, &column.name);
+            self.add(&iri, "odm:hasColumn", Term::Iri(column_iri.clone()));
+            self.add(&column_iri, "rdf:type", Term::Name("odm:Column"));
+            self.add_text(&column_iri, "rdfs:label", Some(&column.name));
+            self.add_text(
+                &column_iri,
+                "skos:altLabel",
+                column.business_name.as_deref(),
+            );
+            self.add_text(
+                &column_iri,
+                "dcterms:description",
+                Some(&column.description),
+            );
+            self.add_text(&column_iri, "odm:dataType", Some(&column.data_type));
+            self.add_text(
+                &column_iri,
+                "odm:physicalType",
+                column.physical_type.as_deref(),
+            );
+            self.add(
+                &column_iri,
+                "odm:primaryKey",
+                Term::Boolean(column.primary_key),
+            );
+            self.add(&column_iri, "odm:nullable", Term::Boolean(column.nullable));
+            if column.unique {
+                self.add(&column_iri, "odm:unique", Term::Boolean(true));
+            }
+            self.add(&column_iri, "odm:position", Term::Integer(index as i64 + 1));
+            self.add_text(
+                &column_iri,
+                "odm:classification",
+                column.classification.as_deref(),
+            );
+            for tag in &column.tags {
+                self.add_text(&column_iri, "odm:tag", Some(tag));
+            }
+            for definition in &column.authoritative_definitions {
+                if is_iri(&definition.url) {
+                    self.add(
+                        &column_iri,
+                        "rdfs:seeAlso",
+                        Term::Iri(definition.url.clone()),
+                    );
+                }
+            }
+        }
+    }
+
+    fn add_relationship(&mut self, relationship: &Relationship) {
+        let iri = self.iri("relationship", &relationship.id);
+        self.add(&iri, "rdf:type", Term::Name("odm:Relationship"));
+        self.add_text(
+            &iri,
+            "dcterms:identifier",
+            Some(&relationship.id.to_string()),
+        );
+        self.add_text(&iri, "rdfs:label", relationship.label.as_deref());
+        let source = self.iri("table", &relationship.source_table_id);
+        let target = self.iri("table", &relationship.target_table_id);
+        self.add(&iri, "odm:sourceTable", Term::Iri(source));
+        self.add(&iri, "odm:targetTable", Term::Iri(target));
+        let relationship_type = relationship.relationship_type.as_ref().and_then(enum_name);
+        self.add_text(&iri, "odm:relationshipType", relationship_type.as_deref());
+        let cardinality = relationship.cardinality.as_ref().and_then(enum_name);
+        self.add_text(&iri, "odm:cardinality", cardinality.as_deref());
+        self.add_text(&iri, "odm:sourceKey", relationship.source_key.as_deref());
+        self.add_text(&iri, "odm:targetKey", relationship.target_key.as_deref());
+        self.add_text(&iri, "odm:owner", relationship.owner.as_deref());
+        self.add_text(&iri, "dcterms:description", relationship.notes.as_deref());
+    }
+
+    fn add_glossary_term(&mut self, article: &KnowledgeArticle, workspace: Option<&Workspace>) {
+        let iri = self.iri("term", &article.id);
+        self.add(&iri, "rdf:type", Term::Name("odm:GlossaryTerm"));
+        self.add(&iri, "rdf:type", Term::Name("skos:Concept"));
+        self.add_text(&iri, "skos:prefLabel", Some(&article.title));
+        self.add_text(&iri, "dcterms:identifier", Some(&article.id.to_string()));
+        self.add_text(&iri, "skos:definition", Some(&article.summary));
+
+        let domain = workspace.and_then(|w| {
+            w.domains.iter().find(|d| {
+                Some(d.id) == article.domain_id
+                    || article
+                        .domain
+                        .as_deref()
+                        .is_some_and(|name| d.name.eq_ignore_ascii_case(name))
+            })
+        });
+        if let Some(domain) = domain {
+            let domain_iri = self.iri("domain", &domain.id);
+            self.add(&iri, "odm:inDomain", Term::Iri(domain_iri));
+        }
+
+        for link in &article.linked_assets {
+            let kind = match link.asset_type.to_lowercase().as_str() {
+                "odcs" | "odcl" | "table" => "table",
+                "relationship" => "relationship",
+                _ => continue,
+            };
+            let asset_iri = self.iri(kind, &link.asset_id);
+            self.add(&asset_iri, "odm:hasGlossaryTerm", Term::Iri(iri.clone()));
+        }
+    }
+
+    /// Group the triples by subject, keeping the insertion order
+    fn subjects(&self) -> Vec<(&str, Vec<&Triple>)> {
+        let mut subjects: Vec<(&str, Vec<&Triple>)> = Vec::new();
+        let mut index: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+        for triple in &self.triples {
+            match index.get(triple.subject.as_str()) {
+                Some(&i) => subjects[i].1.push(triple),
+                None => {
+                    index.insert(triple.subject.as_str(), subjects.len());
+                    subjects.push((triple.subject.as_str(), vec![triple]));
+                }
+            }
+        }
+        subjects
+    }
+
+    fn to_turtle(&self) -> String {
+        let mut out = String::new();
+        for (prefix, namespace) in PREFIXES {
+            out.push_str(&format!("@prefix {}: <{}> .\n", prefix, namespace));
+        }
+        for (subject, triples) in self.subjects() {
+            out.push_str(&format!("\n<{}>", subject));
+            for (i, triple) in triples.iter().enumerate() {
+                let separator = if i == 0 { " " } else { " ;\n    " };
+                let predicate = match triple.predicate {
+                    "rdf:type" => "a",
+                    other => other,
+                };
+                out.push_str(&format!(
+                    "{}{} {}",
+                    separator,
+                    predicate,
+                    turtle_term(&triple.object)
+                ));
+            }
+            out.push_str(" .\n");
+        }
+        out
+    }
+
+    fn to_json_ld(&self) -> Value {
+        let context: Map<String, Value> = PREFIXES
+            .iter()
+            .map(|(prefix, namespace)| (prefix.to_string(), json!(namespace)))
+            .collect();
+
+        let nodes: Vec<Value> = self
+            .subjects()
+            .into_iter()
+            .map(|(subject, triples)| {
+                let mut node = Map::new();
+                node.insert("@id".to_string(), json!(subject));
+                for triple in triples {
+                    let (key, value) = match (triple.predicate, &triple.object) {
+                        ("rdf:type", Term::Name(name)) => ("@type", json!(name)),
+                        (predicate, object) => (predicate, json_ld_term(object)),
+                    };
+                    match node.get_mut(key) {
+                        Some(Value::Array(values)) => values.push(value),
+                        Some(existing) => *existing = json!([existing.clone(), value]),
+                        None => {
+                            node.insert(key.to_string(), value);
+                        }
+                    }
+                }
+                Value::Object(node)
+            })
+            .collect();
+
+        json!({
+            "@context": context,
+            "@graph": nodes,
+        })
+    }
+}
+
+fn turtle_term(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{}>", iri),
+        Term::Name(name) => name.to_string(),
+        Term::Text(text) => format!("\"{}\"", escape_turtle(text)),
+        Term::Boolean(b) => b.to_string(),
+        Term::Integer(i) => i.to_string(),
+    }
+}
+
+fn json_ld_term(term: &Term) -> Value {
+    match term {
+        Term::Iri(iri) => json!({ "@id": iri }),
+        Term::Name(name) => json!({ "@id": name }),
+        Term::Text(text) => json!(text),
+        Term::Boolean(b) => json!(b),
+        Term::Integer(i) => json!(i),
+    }
+}
+
+/// Escape a Turtle string literal
+fn escape_turtle(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Percent-encode an IRI path segment
+fn percent_encode(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Whether a string can be written as an IRI
+fn is_iri(value: &str) -> bool {
+    value.contains(':')
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "<>\"{}|^`\\".contains(c))
+}
+
+/// Serialized name of a model enum (e.g. `oneToMany`)
+fn enum_name<T: serde::Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Description of a table: the ODCS description, or its purpose
+fn table_description(table: &Table) -> Option<String> {
+    match table.odcl_metadata.get("description")? {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(o) => o
+            .get("purpose")
+            .or_else(|| o.get("usage"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+    use crate::models::decision::AssetLink;
+    use crate::models::enums::{Cardinality, RelationshipType};
+    use crate::models::workspace::AssetType;
+
+    fn sample() -> (Workspace, Vec<Table>, Vec<KnowledgeArticle>) {
+        let mut workspace = Workspace::new("enterprise".to_string(), Uuid::new_v4());
+        let domain_id = Uuid::new_v4();
+        workspace.add_domain(domain_id, "sales".to_string());
+
+        let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+        id.primary_key = true;
+        id.nullable = false;
+        let mut note = Column::new("order note".to_string(), "STRING".to_string());
+        note.description = "Free \"text\"\nfrom the customer".to_string();
+        let orders = Table::new("orders".to_string(), vec![id, note]);
+        let customers = Table::new(
+            "customers".to_string(),
+            vec![Column::new("id".to_string(), "BIGINT".to_string())],
+        );
+        workspace.add_asset(crate::models::workspace::AssetReference {
+            id: orders.id,
+            name: "orders".to_string(),
+            domain: "sales".to_string(),
+            system: None,
+            asset_type: AssetType::Odcs,
+            file_path: None,
+        });
+
+        let mut relationship = Relationship::new(orders.id, customers.id);
+        relationship.relationship_type = Some(RelationshipType::ForeignKey);
+        relationship.cardinality = Some(Cardinality::ManyToOne);
+        workspace.relationships.push(relationship);
+
+        let mut term = KnowledgeArticle::new(
+            1,
+            "Order",
+            "A confirmed customer purchase",
+            "Details",
+            "jane@example.com",
+        );
+        term.article_type = KnowledgeType::Glossary;
+        term.domain = Some("sales".to_string());
+        term.linked_assets
+            .push(AssetLink::new("odcs", orders.id, "orders"));
+        let mut guide = term.clone();
+        guide.id = Uuid::new_v4();
+        guide.article_type = KnowledgeType::Guide;
+
+        (workspace, vec![orders, customers], vec![term, guide])
+    }
+
+    #[test]
+    fn test_rdf_syntax_parse() {
+        assert_eq!(RdfSyntax::parse("TTL"), Some(RdfSyntax::Turtle));
+        assert_eq!(RdfSyntax::parse("json-ld"), Some(RdfSyntax::JsonLd));
+        assert_eq!(RdfSyntax::parse("n3"), None);
+        assert_eq!(RdfSyntax::JsonLd.file_extension(), "jsonld");
+    }
+
+    #[test]
+    fn test_export_turtle() {
+        let (workspace, tables, articles) = sample();
+        let orders = &tables[0];
+        let result = RdfExporter::new()
+            .with_base_iri("https://data.example.com/")
+            .export(&workspace, &tables, &articles)
+            .unwrap();
+        let ttl = result.content;
+
+        assert_eq!(result.format, "rdf-turtle");
+        assert!(ttl.starts_with("@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .\n"));
+        let domain_iri = format!(
+            "<https://data.example.com/domain/{}>",
+            workspace.domains[0].id
+        );
+        let table_iri = format!("<https://data.example.com/table/{}>", orders.id);
+        assert!(ttl.contains(&format!("{} a odm:Domain", domain_iri)));
+        assert!(ttl.contains(&format!("{} a odm:Table", table_iri)));
+        assert!(ttl.contains(&format!("odm:inDomain {}", domain_iri)));
+        assert!(ttl.contains(&format!(
+            "odm:hasColumn <https://data.example.com/table/{}/column/order%20note>",
+            orders.id
+        )));
+        assert!(ttl.contains("odm:primaryKey true"));
+        assert!(ttl.contains("dcterms:description \"Free \\\"text\\\"\\nfrom the customer\""));
+        assert!(ttl.contains("odm:relationshipType \"foreignKey\""));
+        assert!(ttl.contains("odm:cardinality \"manyToOne\""));
+        assert!(ttl.contains("a odm:GlossaryTerm ;\n    a skos:Concept"));
+        assert!(ttl.contains("skos:definition \"A confirmed customer purchase\""));
+        assert!(ttl.contains(&format!(
+            "odm:hasGlossaryTerm <https://data.example.com/term/{}>",
+            articles[0].id
+        )));
+        // Only glossary articles become terms
+        assert!(!ttl.contains(&articles[1].id.to_string()));
+        // Every statement block is terminated
+        assert!(ttl.trim_end().ends_with(" ."));
+    }
+
+    #[test]
+    fn test_export_json_ld() {
+        let (workspace, tables, articles) = sample();
+        let json = RdfExporter::new()
+            .with_syntax(RdfSyntax::JsonLd)
+            .export_workspace(&workspace, &tables, &articles)
+            .unwrap();
+        let doc: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(doc["@context"]["odm"], ONTOLOGY_NAMESPACE);
+        let graph = doc["@graph"].as_array().unwrap();
+        let table_id = format!("urn:odm:table/{}", tables[0].id);
+        let table = graph.iter().find(|n| n["@id"] == table_id).unwrap();
+        assert_eq!(table["@type"], "odm:Table");
+        assert_eq!(table["rdfs:label"], "orders");
+        assert_eq!(table["odm:hasColumn"].as_array().unwrap().len(), 2);
+
+        let term_id = format!("urn:odm:term/{}", articles[0].id);
+        let term = graph.iter().find(|n| n["@id"] == term_id).unwrap();
+        assert_eq!(term["@type"], json!(["odm:GlossaryTerm", "skos:Concept"]));
+
+        let column_id = format!("{}/column/id", table_id);
+        let column = graph.iter().find(|n| n["@id"] == column_id).unwrap();
+        assert_eq!(column["odm:primaryKey"], true);
+        assert_eq!(column["odm:position"], 1);
+    }
+
+    #[test]
+    fn test_export_tables_and_invalid_base_iri() {
+        let (workspace, tables, _) = sample();
+        let ttl = RdfExporter::new()
+            .export_tables(&tables, &workspace.relationships)
+            .unwrap();
+        assert!(ttl.contains("a odm:Relationship"));
+        assert!(!ttl.contains("odm:Workspace"));
+
+        assert!(matches!(
+            RdfExporter::new()
+                .with_base_iri("not an iri")
+                .export_tables(&tables, &[]),
+            Err(ExportError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,429 @@
+//! Domain README generation
+//!
+//! Generates a `README.md` per domain summarizing its systems, tables, data products
+//! and recent decisions. The generated content is wrapped in a managed block so it can
+//! be refreshed without touching text written by hand around it:
+//!
+//! ```markdown
+//! Hand-written introduction (kept on refresh)
+//!
+//! <!-- BEGIN odm:managed -->
+//! ...generated content...
+//! <!-- END odm:managed -->
+//! ```
+
+use crate::models::Table;
+use crate::models::decision::Decision;
+use crate::models::odps::{ODPSDataProduct, ODPSStatus};
+use crate::models::workspace::{AssetType, DomainReference, Workspace};
+
+/// Marker starting the generated block of a README
+pub const MANAGED_BLOCK_START: &str = "<!-- BEGIN odm:managed -->";
+
+/// Marker ending the generated block of a README
+pub const MANAGED_BLOCK_END: &str = "<!-- END odm:managed -->";
+
+/// Generated README for one domain
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainReadme {
+    /// Domain name
+    pub domain: String,
+    /// Managed block content, including the markers
+    pub content: String,
+}
+
+/// Generator for per-domain README files
+#[derive(Debug, Clone)]
+pub struct DomainReadmeGenerator {
+    /// Maximum number of decisions listed (most recent first)
+    recent_decisions: usize,
+}
+
+impl Default for DomainReadmeGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainReadmeGenerator {
+    /// Create a generator listing the 5 most recent decisions
+    pub fn new() -> Self {
+        Self {
+            recent_decisions: 5,
+        }
+    }
+
+    /// Set the maximum number of decisions listed
+    pub fn with_recent_decisions(mut self, count: usize) -> Self {
+        self.recent_decisions = count;
+        self
+    }
+
+    /// Generate READMEs for every domain of a workspace
+    ///
+    /// Tables belong to a domain when a system of the domain lists them, when the
+    /// workspace has an ODCS asset reference for them in the domain, or when their
+    /// contract declares the domain. Data products and decisions are matched on their
+    /// `domain` field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::readme::DomainReadmeGenerator;
+    /// use data_modelling_core::models::workspace::Workspace;
+    /// use uuid::Uuid;
+    ///
+    /// let mut workspace = Workspace::new("acme".to_string(), Uuid::new_v4());
+    /// workspace.add_domain(Uuid::new_v4(), "sales".to_string());
+    ///
+    /// let readmes = DomainReadmeGenerator::new().generate_for_workspace(&workspace, &[], &[], &[]);
+    /// assert_eq!(readmes[0].domain, "sales");
+    /// assert!(readmes[0].content.contains("# sales"));
+    /// ```
+    pub fn generate_for_workspace(
+        &self,
+        workspace: &Workspace,
+        tables: &[Table],
+        products: &[ODPSDataProduct],
+        decisions: &[Decision],
+    ) -> Vec<DomainReadme> {
+        workspace
+            .domains
+            .iter()
+            .map(|domain| {
+                let domain_tables: Vec<&Table> = tables
+                    .iter()
+                    .filter(|t| table_in_domain(workspace, domain, t))
+                    .collect();
+                let domain_products: Vec<&ODPSDataProduct> = products
+                    .iter()
+                    .filter(|p| {
+                        p.domain
+                            .as_deref()
+                            .is_some_and(|d| same_name(d, &domain.name))
+                    })
+                    .collect();
+                let domain_decisions: Vec<&Decision> = decisions
+                    .iter()
+                    .filter(|d| {
+                        d.domain_id == Some(domain.id)
+                            || d.domain
+                                .as_deref()
+                                .is_some_and(|n| same_name(n, &domain.name))
+                    })
+                    .collect();
+
+                DomainReadme {
+                    domain: domain.name.clone(),
+                    content: self.generate(
+                        domain,
+                        &domain_tables,
+                        &domain_products,
+                        &domain_decisions,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Generate the managed README block for a domain
+    pub fn generate(
+        &self,
+        domain: &DomainReference,
+        tables: &[&Table],
+        products: &[&ODPSDataProduct],
+        decisions: &[&Decision],
+    ) -> String {
+        let mut md = String::new();
+
+        md.push_str(MANAGED_BLOCK_START);
+        md.push('\n');
+        md.push_str(
+            "<!-- Generated by `odm readme`. Changes inside this block are overwritten. -->\n\n",
+        );
+
+        md.push_str(&format!("# {}\n\n", domain.name));
+        if let Some(description) = &domain.description {
+            md.push_str(description.trim());
+            md.push_str("\n\n");
+        }
+
+        md.push_str("| Systems | Tables | Data Products | Decisions |\n");
+        md.push_str("|---------|--------|---------------|-----------|\n");
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n\n",
+            domain.systems.len(),
+            tables.len(),
+            products.len(),
+            decisions.len()
+        ));
+
+        // Systems
+        if !domain.systems.is_empty() {
+            md.push_str("## Systems\n\n");
+            md.push_str("| System | Type | Tables | Description |\n");
+            md.push_str("|--------|------|--------|-------------|\n");
+            for system in &domain.systems {
+                let system_type = system
+                    .system_type
+                    .map(|t| format!("{:?}", t))
+                    .unwrap_or_default();
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    escape_cell(&system.name),
+                    system_type,
+                    system.table_ids.len(),
+                    escape_cell(system.description.as_deref().unwrap_or(""))
+                ));
+            }
+            md.push('\n');
+        }
+
+        // Tables
+        if !tables.is_empty() {
+            let mut tables = tables.to_vec();
+            tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+            md.push_str("## Tables\n\n");
+            md.push_str("| Table | System | Columns | Owner |\n");
+            md.push_str("|-------|--------|---------|-------|\n");
+            for table in tables {
+                let system = domain
+                    .systems
+                    .iter()
+                    .find(|s| s.table_ids.contains(&table.id))
+                    .map(|s| s.name.as_str())
+                    .unwrap_or("");
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    escape_cell(&table.name),
+                    escape_cell(system),
+                    table.columns.len(),
+                    escape_cell(table.owner.as_deref().unwrap_or("-"))
+                ));
+            }
+            md.push('\n');
+        }
+
+        // Data products
+        if !products.is_empty() {
+            let mut products = products.to_vec();
+            products.sort_by(|a, b| product_name(a).cmp(product_name(b)));
+
+            md.push_str("## Data Products\n\n");
+            md.push_str("| Product | Status | Version |\n");
+            md.push_str("|---------|--------|---------|\n");
+            for product in products {
+                md.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    escape_cell(product_name(product)),
+                    product_status(product.status),
+                    escape_cell(product.version.as_deref().unwrap_or("-"))
+                ));
+            }
+            md.push('\n');
+        }
+
+        // Recent decisions
+        if !decisions.is_empty() && self.recent_decisions > 0 {
+            let mut decisions = decisions.to_vec();
+            decisions.sort_by(|a, b| b.date.cmp(&a.date).then(b.number.cmp(&a.number)));
+
+            md.push_str("## Recent Decisions\n\n");
+            md.push_str("| Number | Title | Status | Date |\n");
+            md.push_str("|--------|-------|--------|------|\n");
+            for decision in decisions.iter().take(self.recent_decisions) {
+                md.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    decision.formatted_number(),
+                    escape_cell(&decision.title),
+                    decision.status,
+                    decision.date.format("%Y-%m-%d")
+                ));
+            }
+            md.push('\n');
+        }
+
+        md.push_str(MANAGED_BLOCK_END);
+        md.push('\n');
+        md
+    }
+}
+
+/// Replace the managed block of an existing README with `block`
+///
+/// Text outside the markers is kept. Without an existing README the block is
+/// returned as-is; an existing README without markers gets the block appended.
+///
+/// # Example
+///
+/// ```rust
+/// use data_modelling_core::export::readme::merge_managed_block;
+///
+/// let existing = "Intro\n\n<!-- BEGIN odm:managed -->\nold\n<!-- END odm:managed -->\n";
+/// let block = "<!-- BEGIN odm:managed -->\nnew\n<!-- END odm:managed -->\n";
+/// assert_eq!(
+///     merge_managed_block(Some(existing), block),
+///     "Intro\n\n<!-- BEGIN odm:managed -->\nnew\n<!-- END odm:managed -->\n"
+/// );
+/// ```
+pub fn merge_managed_block(existing: Option<&str>, block: &str) -> String {
+    let Some(existing) = existing.filter(|e| !e.trim().is_empty()) else {
+        return block.to_string();
+    };
+
+    let start = existing.find(MANAGED_BLOCK_START);
+    let end = start.and_then(|start| {
+        existing[start..]
+            .find(MANAGED_BLOCK_END)
+            .map(|i| start + i + MANAGED_BLOCK_END.len())
+    });
+
+    match (start, end) {
+        (Some(start), Some(end)) => {
+            let after = existing[end..]
+                .strip_prefix("\r\n")
+                .or_else(|| existing[end..].strip_prefix('\n'))
+                .unwrap_or(&existing[end..]);
+            format!("{}{}{}", &existing[..start], block, after)
+        }
+        _ => format!("{}\n\n{}", existing.trim_end(), block),
+    }
+}
+
+/// Check whether a table belongs to a domain
+fn table_in_domain(workspace: &Workspace, domain: &DomainReference, table: &Table) -> bool {
+    domain
+        .systems
+        .iter()
+        .any(|s| s.table_ids.contains(&table.id))
+        || workspace.assets.iter().any(|a| {
+            a.id == table.id
+                && a.asset_type == AssetType::Odcs
+                && same_name(&a.domain, &domain.name)
+        })
+        || table
+            .odcl_metadata
+            .get("domain")
+            .and_then(|v| v.as_str())
+            .is_some_and(|d| same_name(d, &domain.name))
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+fn product_name(product: &ODPSDataProduct) -> &str {
+    product.name.as_deref().unwrap_or(&product.id)
+}
+
+fn product_status(status: ODPSStatus) -> &'static str {
+    match status {
+        ODPSStatus::Proposed => "Proposed",
+        ODPSStatus::Draft => "Draft",
+        ODPSStatus::Active => "Active",
+        ODPSStatus::Deprecated => "Deprecated",
+        ODPSStatus::Retired => "Retired",
+    }
+}
+
+/// Escape text for use in a Markdown table cell
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+    use crate::models::workspace::SystemReference;
+    use uuid::Uuid;
+
+    fn sample_workspace() -> (Workspace, Vec<Table>) {
+        let mut orders = Table::new(
+            "orders".to_string(),
+            vec![
+                Column::new("id".to_string(), "BIGINT".to_string()),
+                Column::new("total".to_string(), "DECIMAL".to_string()),
+            ],
+        );
+        orders.owner = Some("sales-team".to_string());
+        let mut leads = Table::new(
+            "leads".to_string(),
+            vec![Column::new("id".to_string(), "BIGINT".to_string())],
+        );
+        leads
+            .odcl_metadata
+            .insert("domain".to_string(), serde_json::json!("Sales"));
+        let other = Table::new("invoices".to_string(), Vec::new());
+
+        let mut workspace = Workspace::new("acme".to_string(), Uuid::new_v4());
+        workspace.add_domain(Uuid::new_v4(), "sales".to_string());
+        workspace.domains[0].systems.push(SystemReference {
+            id: Uuid::new_v4(),
+            name: "crm".to_string(),
+            description: Some("Customer | relationship management".to_string()),
+            system_type: None,
+            table_ids: vec![orders.id],
+            asset_ids: Vec::new(),
+            environments: Vec::new(),
+        });
+
+        (workspace, vec![orders, leads, other])
+    }
+
+    #[test]
+    fn test_generate_for_workspace() {
+        let (workspace, tables) = sample_workspace();
+        let decisions = vec![
+            Decision::new(
+                1,
+                "Use CDC for orders",
+                "context",
+                "decision",
+                "alice@example.com",
+            )
+            .with_domain("sales"),
+            Decision::new(2, "Unrelated", "context", "decision", "bob@example.com"),
+        ];
+
+        let readmes = DomainReadmeGenerator::new().generate_for_workspace(
+            &workspace,
+            &tables,
+            &[],
+            &decisions,
+        );
+        assert_eq!(readmes.len(), 1);
+        let content = &readmes[0].content;
+
+        assert!(content.starts_with(MANAGED_BLOCK_START));
+        assert!(content.trim_end().ends_with(MANAGED_BLOCK_END));
+        assert!(content.contains("| orders | crm | 2 | sales-team |"));
+        assert!(content.contains("| leads |  | 1 | - |"));
+        assert!(!content.contains("invoices"));
+        assert!(content.contains("Customer \\| relationship management"));
+        assert!(content.contains("Use CDC for orders"));
+        assert!(!content.contains("Unrelated"));
+        assert!(!content.contains("## Data Products"));
+    }
+
+    #[test]
+    fn test_merge_managed_block() {
+        let block = format!("{}\nnew\n{}\n", MANAGED_BLOCK_START, MANAGED_BLOCK_END);
+
+        assert_eq!(merge_managed_block(None, &block), block);
+        assert_eq!(
+            merge_managed_block(Some("# Notes\n"), &block),
+            format!("# Notes\n\n{}", block)
+        );
+
+        let existing = format!(
+            "Intro\n\n{}\nold\n{}\n\nFooter\n",
+            MANAGED_BLOCK_START, MANAGED_BLOCK_END
+        );
+        let merged = merge_managed_block(Some(&existing), &block);
+        assert_eq!(merged, format!("Intro\n\n{}\nFooter\n", block));
+        // Refreshing is idempotent
+        assert_eq!(merge_managed_block(Some(&merged), &block), merged);
+    }
+}
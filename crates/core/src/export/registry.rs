@@ -0,0 +1,1191 @@
+//! Pluggable export targets
+//!
+//! Defines the [`Exporter`] trait and an [`ExporterRegistry`] so downstream crates can
+//! register custom export formats alongside the built-in ones. Every registered format
+//! is available by name to the CLI (`odm export <format>`) and to conversion tooling.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use data_modelling_core::export::{
+//!     ExportError, ExportOptions, ExportResult, Exporter, ExporterRegistry,
+//! };
+//! use data_modelling_core::models::Table;
+//!
+//! struct CsvHeaderExporter;
+//!
+//! impl Exporter for CsvHeaderExporter {
+//!     fn name(&self) -> &str {
+//!         "csv-header"
+//!     }
+//!
+//!     fn content_type(&self) -> &str {
+//!         "text/csv"
+//!     }
+//!
+//!     fn file_extension(&self) -> &str {
+//!         "csv"
+//!     }
+//!
+//!     fn export_tables(
+//!         &self,
+//!         tables: &[Table],
+//!         _options: &ExportOptions,
+//!     ) -> Result<ExportResult, ExportError> {
+//!         let content = tables
+//!             .iter()
+//!             .map(|t| t.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>().join(","))
+//!             .collect::<Vec<_>>()
+//!             .join("\n");
+//!         Ok(ExportResult {
+//!             content,
+//!             format: self.name().to_string(),
+//!         })
+//!     }
+//! }
+//!
+//! let mut registry = ExporterRegistry::default();
+//! registry.register(CsvHeaderExporter);
+//! assert!(registry.get("csv-header").is_some());
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use super::feast::FeastSource;
+use super::json_schema::JsonSchemaOptions;
+use super::odcs::ODCSStyleOptions;
+use super::{
+    AvroExporter, BigQuerySchemaExporter, ClientExporter, ClientLanguage, ConnectConverter,
+    DbmlExporter, DebeziumConnector, DebeziumExporter, ExportError, ExportResult, FeastExporter,
+    HtmlExporter, JSONSchemaExporter, KafkaConnectExporter, ODCSExporter, ProtobufExporter,
+    RdfExporter, RdfSyntax, SQLExporter, SodaExporter,
+};
+use crate::metrics;
+use crate::models::odcs::ODCSContract;
+use crate::models::physical_names::PhysicalNameMap;
+use crate::models::{DataModel, Table, TypeMappingRegistry};
+
+/// Options passed to an [`Exporter`]
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// SQL dialect (e.g., "postgres", "databricks")
+    pub dialect: Option<String>,
+    /// Format version (e.g., "proto2" or "proto3" for Protobuf)
+    pub version: Option<String>,
+    /// Free-form options understood by individual exporters
+    pub properties: HashMap<String, String>,
+    /// Platform whose physical names are used (defaults to the dialect or format)
+    pub platform: Option<String>,
+    /// Logical to physical name mappings applied by the registry
+    pub physical_names: Option<Arc<PhysicalNameMap>>,
+    /// Data type mappings (the built-in mappings when `None`)
+    pub type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+impl ExportOptions {
+    /// Create empty export options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the SQL dialect
+    pub fn with_dialect(mut self, dialect: impl Into<String>) -> Self {
+        self.dialect = Some(dialect.into());
+        self
+    }
+
+    /// Set the format version
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Set a free-form option
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get a free-form option
+    pub fn property(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).map(|s| s.as_str())
+    }
+
+    /// Set the platform whose physical names are used
+    pub fn with_platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+
+    /// Set the logical to physical name mappings
+    pub fn with_physical_names(mut self, names: impl Into<Arc<PhysicalNameMap>>) -> Self {
+        self.physical_names = Some(names.into());
+        self
+    }
+
+    /// Set the data type mappings, e.g. a workspace's overrides
+    pub fn with_type_mappings(mut self, mappings: impl Into<Arc<TypeMappingRegistry>>) -> Self {
+        self.type_mappings = Some(mappings.into());
+        self
+    }
+
+    /// Data type mappings to export with
+    pub fn type_mappings(&self) -> &TypeMappingRegistry {
+        self.type_mappings
+            .as_deref()
+            .unwrap_or(TypeMappingRegistry::builtin())
+    }
+
+    /// Platform of an export to `format`: the explicit platform, else the dialect,
+    /// else the format name
+    pub fn platform_for<'a>(&'a self, format: &'a str) -> &'a str {
+        self.platform
+            .as_deref()
+            .or(self.dialect.as_deref())
+            .unwrap_or(format)
+    }
+}
+
+/// An export target that can be registered with an [`ExporterRegistry`]
+///
+/// Implementors only need to provide [`Exporter::export_tables`]; data models and
+/// ODCS contracts are converted to tables by default.
+pub trait Exporter: Send + Sync {
+    /// Unique format name (e.g., "avro", "json-schema")
+    fn name(&self) -> &str;
+
+    /// MIME content type of the exported output
+    fn content_type(&self) -> &str;
+
+    /// File extension for the exported output (without the leading dot)
+    fn file_extension(&self) -> &str;
+
+    /// Short human-readable description of the format
+    fn description(&self) -> &str {
+        ""
+    }
+
+    /// Versions of the format that can be exported, selected with
+    /// [`ExportOptions::version`] (empty if unversioned)
+    fn versions(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Export a set of tables
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError>;
+
+    /// Export all tables of a data model
+    fn export_model(
+        &self,
+        model: &DataModel,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_tables(&model.tables, options)
+    }
+
+    /// Export an ODCS contract
+    fn export_contract(
+        &self,
+        contract: &ODCSContract,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_tables(&contract.to_tables(), options)
+    }
+
+    /// Whether physical names are recorded next to the logical names (e.g. as
+    /// `physicalName`) instead of replacing them
+    fn keeps_logical_names(&self) -> bool {
+        false
+    }
+}
+
+/// Registry of export targets, keyed by format name
+///
+/// `ExporterRegistry::default()` contains the built-in formats; use
+/// [`ExporterRegistry::new`] for an empty registry.
+#[derive(Clone)]
+pub struct ExporterRegistry {
+    exporters: BTreeMap<String, Arc<dyn Exporter>>,
+}
+
+impl ExporterRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            exporters: BTreeMap::new(),
+        }
+    }
+
+    /// Register an exporter, replacing any existing exporter with the same name
+    pub fn register<E: Exporter + 'static>(&mut self, exporter: E) -> &mut Self {
+        self.register_arc(Arc::new(exporter))
+    }
+
+    /// Register a shared exporter, replacing any existing exporter with the same name
+    pub fn register_arc(&mut self, exporter: Arc<dyn Exporter>) -> &mut Self {
+        self.exporters
+            .insert(normalize_name(exporter.name()), exporter);
+        self
+    }
+
+    /// Look up an exporter by name (case-insensitive, `_` and `-` are equivalent)
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Exporter>> {
+        self.exporters.get(&normalize_name(name)).cloned()
+    }
+
+    /// Check whether a format is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.exporters.contains_key(&normalize_name(name))
+    }
+
+    /// Names of all registered formats, sorted
+    pub fn names(&self) -> Vec<String> {
+        self.exporters.keys().cloned().collect()
+    }
+
+    /// Iterate over all registered exporters, sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn Exporter>> {
+        self.exporters.values()
+    }
+
+    /// Export tables using the named format
+    pub fn export_tables(
+        &self,
+        format: &str,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let exporter = self.require(format)?;
+        track_export(exporter.as_ref(), || {
+            match physical_names(exporter.as_ref(), options) {
+                Some((names, platform)) => {
+                    let mut tables = tables.to_vec();
+                    if exporter.keeps_logical_names() {
+                        names.annotate_tables(platform, &mut tables);
+                    } else {
+                        names.rename_tables(platform, &mut tables);
+                    }
+                    exporter.export_tables(&tables, options)
+                }
+                None => exporter.export_tables(tables, options),
+            }
+        })
+    }
+
+    /// Export a data model using the named format
+    pub fn export_model(
+        &self,
+        format: &str,
+        model: &DataModel,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let exporter = self.require(format)?;
+        track_export(exporter.as_ref(), || {
+            match physical_names(exporter.as_ref(), options) {
+                Some((names, platform)) => {
+                    let mut model = model.clone();
+                    if exporter.keeps_logical_names() {
+                        names.annotate_tables(platform, &mut model.tables);
+                    } else {
+                        names.rename_tables(platform, &mut model.tables);
+                    }
+                    exporter.export_model(&model, options)
+                }
+                None => exporter.export_model(model, options),
+            }
+        })
+    }
+
+    /// Export an ODCS contract using the named format
+    pub fn export_contract(
+        &self,
+        format: &str,
+        contract: &ODCSContract,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let exporter = self.require(format)?;
+        track_export(exporter.as_ref(), || {
+            export_contract_with(exporter.as_ref(), contract, options)
+        })
+    }
+
+    fn require(&self, format: &str) -> Result<Arc<dyn Exporter>, ExportError> {
+        self.get(format).ok_or_else(|| {
+            ExportError::InvalidArgument(format!(
+                "Unknown export format: {}. Available: {}",
+                format,
+                self.names().join(", ")
+            ))
+        })
+    }
+}
+
+impl Default for ExporterRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(OdcsFormat)
+            .register(AvroFormat)
+            .register(JsonSchemaFormat)
+            .register(ProtobufFormat)
+            .register(SqlFormat)
+            .register(BigQueryFormat)
+            .register(DbmlFormat)
+            .register(FeastFormat)
+            .register(ClientFormat(ClientLanguage::Rust))
+            .register(ClientFormat(ClientLanguage::Python))
+            .register(ClientFormat(ClientLanguage::TypeScript))
+            .register(HtmlFormat)
+            .register(SodaFormat)
+            .register(DebeziumFormat)
+            .register(KafkaConnectFormat)
+            .register(RdfFormat(RdfSyntax::Turtle))
+            .register(RdfFormat(RdfSyntax::JsonLd));
+        registry
+    }
+}
+
+impl std::fmt::Debug for ExporterRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExporterRegistry")
+            .field("formats", &self.names())
+            .finish()
+    }
+}
+
+/// Run an export, reporting it to the installed metrics recorder
+fn track_export(
+    exporter: &dyn Exporter,
+    export: impl FnOnce() -> Result<ExportResult, ExportError>,
+) -> Result<ExportResult, ExportError> {
+    metrics::track(
+        metrics::EXPORTS,
+        metrics::EXPORT_DURATION,
+        exporter.name(),
+        export,
+    )
+}
+
+/// Normalize a format name for lookup
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase().replace('_', "-")
+}
+
+/// Export a contract, applying the physical names of the export platform
+pub(crate) fn export_contract_with(
+    exporter: &dyn Exporter,
+    contract: &ODCSContract,
+    options: &ExportOptions,
+) -> Result<ExportResult, ExportError> {
+    let Some((names, platform)) = physical_names(exporter, options) else {
+        return exporter.export_contract(contract, options);
+    };
+    let mut contract = contract.clone();
+    if exporter.keeps_logical_names() {
+        names.annotate_contract(platform, &mut contract);
+    } else {
+        names.rename_contract(platform, &mut contract);
+    }
+    exporter.export_contract(&contract, options)
+}
+
+/// Physical name mappings and platform of an export, if any names apply
+fn physical_names<'a>(
+    exporter: &'a dyn Exporter,
+    options: &'a ExportOptions,
+) -> Option<(&'a PhysicalNameMap, &'a str)> {
+    let names = options.physical_names.as_deref()?;
+    let platform = options.platform_for(exporter.name());
+    names
+        .platform(platform)
+        .is_some()
+        .then_some((names, platform))
+}
+
+// ============================================================================
+// Built-in formats
+// ============================================================================
+
+/// ODCS v3.1.0 YAML
+struct OdcsFormat;
+
+impl Exporter for OdcsFormat {
+    fn name(&self) -> &str {
+        "odcs"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/yaml"
+    }
+
+    fn file_extension(&self) -> &str {
+        "odcs.yaml"
+    }
+
+    fn description(&self) -> &str {
+        "Open Data Contract Standard v3.1.0"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["v3.1.0"]
+    }
+
+    fn keeps_logical_names(&self) -> bool {
+        true
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_contract(&ODCSContract::from_tables(tables), options)
+    }
+
+    fn export_contract(
+        &self,
+        contract: &ODCSContract,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let style = ODCSStyleOptions::from_export_options(options)?;
+        Ok(ExportResult {
+            content: ODCSExporter::export_contract_with_style(contract, &style)?,
+            format: "odcs_v3_1_0".to_string(),
+        })
+    }
+}
+
+/// Apache Avro schema
+struct AvroFormat;
+
+impl Exporter for AvroFormat {
+    fn name(&self) -> &str {
+        "avro"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn file_extension(&self) -> &str {
+        "avsc"
+    }
+
+    fn description(&self) -> &str {
+        "Apache Avro schema"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        AvroExporter.export_with_type_mappings(tables, options.type_mappings())
+    }
+}
+
+/// JSON Schema
+struct JsonSchemaFormat;
+
+impl Exporter for JsonSchemaFormat {
+    fn name(&self) -> &str {
+        "json-schema"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/schema+json"
+    }
+
+    fn file_extension(&self) -> &str {
+        "schema.json"
+    }
+
+    fn description(&self) -> &str {
+        "JSON Schema"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["draft-07", "2019-09", "2020-12"]
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let json_options = JsonSchemaOptions::from_export_options(options)?;
+        JSONSchemaExporter.export_with_options(tables, &json_options)
+    }
+}
+
+/// Protocol Buffers `.proto`
+struct ProtobufFormat;
+
+impl Exporter for ProtobufFormat {
+    fn name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn content_type(&self) -> &str {
+        "text/plain"
+    }
+
+    fn file_extension(&self) -> &str {
+        "proto"
+    }
+
+    fn description(&self) -> &str {
+        "Protocol Buffers (proto2/proto3)"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["proto2", "proto3"]
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let version = options.version.as_deref().unwrap_or("proto3");
+        let type_mappings = options.type_mappings();
+        if options.property("nested") == Some("true") {
+            ProtobufExporter.export_nested_with_type_mappings(tables, version, type_mappings)
+        } else {
+            ProtobufExporter.export_with_type_mappings(tables, version, type_mappings)
+        }
+    }
+}
+
+/// SQL `CREATE TABLE` statements
+struct SqlFormat;
+
+impl Exporter for SqlFormat {
+    fn name(&self) -> &str {
+        "sql"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/sql"
+    }
+
+    fn file_extension(&self) -> &str {
+        "sql"
+    }
+
+    fn description(&self) -> &str {
+        "SQL CREATE TABLE statements"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        SQLExporter.export_with_type_mappings(
+            tables,
+            options.dialect.as_deref(),
+            options.type_mappings(),
+        )
+    }
+}
+
+/// BigQuery JSON table schema
+struct BigQueryFormat;
+
+impl Exporter for BigQueryFormat {
+    fn name(&self) -> &str {
+        "bigquery"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn description(&self) -> &str {
+        "BigQuery JSON table schema for bq load"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        BigQuerySchemaExporter::new()
+            .with_type_mappings(options.type_mappings().clone())
+            .export(tables)
+    }
+}
+
+/// SodaCL checks for Soda Core
+struct SodaFormat;
+
+impl Exporter for SodaFormat {
+    fn name(&self) -> &str {
+        "soda"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/yaml"
+    }
+
+    fn file_extension(&self) -> &str {
+        "yaml"
+    }
+
+    fn description(&self) -> &str {
+        "SodaCL checks for Soda Core"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        _options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        SodaExporter::new().export(tables)
+    }
+}
+
+/// Debezium connector configurations
+struct DebeziumFormat;
+
+impl Exporter for DebeziumFormat {
+    fn name(&self) -> &str {
+        "debezium"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn description(&self) -> &str {
+        "Debezium connector configurations from CDC metadata"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let mut exporter = DebeziumExporter::new();
+        if let Some(dialect) = options.dialect.as_deref() {
+            let connector = DebeziumConnector::parse(dialect).ok_or_else(|| {
+                ExportError::InvalidArgument(format!("Unsupported Debezium connector: {}", dialect))
+            })?;
+            exporter = exporter.with_connector(connector);
+        }
+        if let Some(prefix) = options.property("topic.prefix") {
+            exporter = exporter.with_topic_prefix(prefix);
+        }
+        exporter.export(tables)
+    }
+}
+
+/// Kafka Connect source and sink connector configurations
+struct KafkaConnectFormat;
+
+impl Exporter for KafkaConnectFormat {
+    fn name(&self) -> &str {
+        "kafka-connect"
+    }
+
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn description(&self) -> &str {
+        "Kafka Connect source and sink connectors from CDC metadata and servers"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_contract(&ODCSContract::from_tables(tables), options)
+    }
+
+    fn export_contract(
+        &self,
+        contract: &ODCSContract,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let mut exporter = KafkaConnectExporter::new();
+        if let Some(converter) = options.property("converter") {
+            let converter = ConnectConverter::parse(converter).ok_or_else(|| {
+                ExportError::InvalidArgument(format!(
+                    "Unsupported Kafka Connect converter: {}",
+                    converter
+                ))
+            })?;
+            exporter = exporter.with_converter(converter);
+        }
+        if let Some(prefix) = options.property("topic.prefix") {
+            exporter = exporter.with_topic_prefix(prefix);
+        }
+        if let Some(url) = options.property("schema.registry.url") {
+            exporter = exporter.with_schema_registry_url(url);
+        }
+        exporter.export_contract(contract)
+    }
+}
+
+/// DBML (dbdiagram.io)
+struct DbmlFormat;
+
+impl Exporter for DbmlFormat {
+    fn name(&self) -> &str {
+        "dbml"
+    }
+
+    fn content_type(&self) -> &str {
+        "text/plain"
+    }
+
+    fn file_extension(&self) -> &str {
+        "dbml"
+    }
+
+    fn description(&self) -> &str {
+        "DBML for dbdiagram.io"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        _options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        DbmlExporter.export(tables)
+    }
+}
+
+/// Feast feature repository definitions
+///
+/// The dialect selects the offline store of the batch sources and the `tag`
+/// property the tag marking tables for export.
+struct FeastFormat;
+
+impl Exporter for FeastFormat {
+    fn name(&self) -> &str {
+        "feast"
+    }
+
+    fn content_type(&self) -> &str {
+        "text/x-python"
+    }
+
+    fn file_extension(&self) -> &str {
+        "py"
+    }
+
+    fn description(&self) -> &str {
+        "Feast feature views from tagged tables"
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let mut exporter = FeastExporter::new();
+        if let Some(dialect) = options.dialect.as_deref() {
+            let source = FeastSource::parse(dialect).ok_or_else(|| {
+                ExportError::InvalidArgument(format!(
+                    "Unsupported Feast offline store: {}",
+                    dialect
+                ))
+            })?;
+            exporter = exporter.with_source(source);
+        }
+        if let Some(tag) = options.property("tag") {
+            exporter = exporter.with_tag(tag);
+        }
+        exporter.export(tables)
+    }
+}
+
+/// Typed consumer client module
+struct ClientFormat(ClientLanguage);
+
+impl Exporter for ClientFormat {
+    fn name(&self) -> &str {
+        match self.0 {
+            ClientLanguage::Rust => "client-rust",
+            ClientLanguage::Python => "client-python",
+            ClientLanguage::TypeScript => "client-typescript",
+        }
+    }
+
+    fn content_type(&self) -> &str {
+        match self.0 {
+            ClientLanguage::Rust => "text/x-rust",
+            ClientLanguage::Python => "text/x-python",
+            ClientLanguage::TypeScript => "application/typescript",
+        }
+    }
+
+    fn file_extension(&self) -> &str {
+        self.0.file_extension()
+    }
+
+    fn description(&self) -> &str {
+        match self.0 {
+            ClientLanguage::Rust => "Typed Rust client with readers and validation",
+            ClientLanguage::Python => "Typed Python client with readers and validation",
+            ClientLanguage::TypeScript => "Typed TypeScript client with readers and validation",
+        }
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        _options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        ClientExporter::new().with_language(self.0).export(tables)
+    }
+}
+
+/// RDF knowledge graph of tables and relationships
+struct RdfFormat(RdfSyntax);
+
+impl RdfFormat {
+    fn exporter(&self, options: &ExportOptions) -> RdfExporter {
+        let exporter = RdfExporter::new().with_syntax(self.0);
+        match options.property("base.iri") {
+            Some(base_iri) => exporter.with_base_iri(base_iri),
+            None => exporter,
+        }
+    }
+
+    fn result(&self, content: String) -> ExportResult {
+        ExportResult {
+            content,
+            format: self.name().to_string(),
+        }
+    }
+}
+
+impl Exporter for RdfFormat {
+    fn name(&self) -> &str {
+        match self.0 {
+            RdfSyntax::Turtle => "rdf-turtle",
+            RdfSyntax::JsonLd => "rdf-jsonld",
+        }
+    }
+
+    fn content_type(&self) -> &str {
+        match self.0 {
+            RdfSyntax::Turtle => "text/turtle",
+            RdfSyntax::JsonLd => "application/ld+json",
+        }
+    }
+
+    fn file_extension(&self) -> &str {
+        self.0.file_extension()
+    }
+
+    fn description(&self) -> &str {
+        match self.0 {
+            RdfSyntax::Turtle => "RDF knowledge graph (Turtle) using the ODM ontology",
+            RdfSyntax::JsonLd => "RDF knowledge graph (JSON-LD) using the ODM ontology",
+        }
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let content = self.exporter(options).export_tables(tables, &[])?;
+        Ok(self.result(content))
+    }
+
+    fn export_model(
+        &self,
+        model: &DataModel,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let content = self
+            .exporter(options)
+            .export_tables(&model.tables, &model.relationships)?;
+        Ok(self.result(content))
+    }
+}
+
+/// Self-contained HTML contract viewer
+struct HtmlFormat;
+
+impl Exporter for HtmlFormat {
+    fn name(&self) -> &str {
+        "html"
+    }
+
+    fn content_type(&self) -> &str {
+        "text/html"
+    }
+
+    fn file_extension(&self) -> &str {
+        "html"
+    }
+
+    fn description(&self) -> &str {
+        "Single-file HTML contract viewer"
+    }
+
+    fn keeps_logical_names(&self) -> bool {
+        true
+    }
+
+    fn export_tables(
+        &self,
+        tables: &[Table],
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_contract(&ODCSContract::from_tables(tables), options)
+    }
+
+    fn export_contract(
+        &self,
+        contract: &ODCSContract,
+        options: &ExportOptions,
+    ) -> Result<ExportResult, ExportError> {
+        let mut exporter = HtmlExporter::new();
+        if let Some(color) = options.property("brand_color") {
+            exporter = exporter.with_brand_color(color);
+        }
+        Ok(ExportResult {
+            content: exporter.export_contract(contract)?,
+            format: self.name().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+
+    struct UpperNamesExporter;
+
+    impl Exporter for UpperNamesExporter {
+        fn name(&self) -> &str {
+            "upper_names"
+        }
+
+        fn content_type(&self) -> &str {
+            "text/plain"
+        }
+
+        fn file_extension(&self) -> &str {
+            "txt"
+        }
+
+        fn export_tables(
+            &self,
+            tables: &[Table],
+            options: &ExportOptions,
+        ) -> Result<ExportResult, ExportError> {
+            let separator = options.property("separator").unwrap_or("\n");
+            Ok(ExportResult {
+                content: tables
+                    .iter()
+                    .map(|t| t.name.to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(separator),
+                format: self.name().to_string(),
+            })
+        }
+    }
+
+    fn sample_tables() -> Vec<Table> {
+        vec![
+            Table::new(
+                "users".to_string(),
+                vec![Column::new("id".to_string(), "BIGINT".to_string())],
+            ),
+            Table::new(
+                "orders".to_string(),
+                vec![Column::new("id".to_string(), "BIGINT".to_string())],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_default_registry_has_builtins() {
+        let registry = ExporterRegistry::default();
+        assert_eq!(
+            registry.names(),
+            vec![
+                "avro",
+                "bigquery",
+                "client-python",
+                "client-rust",
+                "client-typescript",
+                "dbml",
+                "debezium",
+                "feast",
+                "html",
+                "json-schema",
+                "kafka-connect",
+                "odcs",
+                "protobuf",
+                "rdf-jsonld",
+                "rdf-turtle",
+                "soda",
+                "sql"
+            ]
+        );
+        assert!(registry.contains("json_schema"));
+        assert!(registry.contains("AVRO"));
+        assert!(ExporterRegistry::new().names().is_empty());
+    }
+
+    #[test]
+    fn test_register_custom_exporter() {
+        let mut registry = ExporterRegistry::default();
+        registry.register(UpperNamesExporter);
+
+        assert!(registry.contains("upper-names"));
+        let options = ExportOptions::new().with_property("separator", ",");
+        let result = registry
+            .export_tables("upper-names", &sample_tables(), &options)
+            .unwrap();
+        assert_eq!(result.content, "USERS,ORDERS");
+    }
+
+    #[test]
+    fn test_builtin_exports() {
+        let registry = ExporterRegistry::default();
+        let tables = sample_tables();
+
+        let sql = registry
+            .export_tables(
+                "sql",
+                &tables,
+                &ExportOptions::new().with_dialect("postgres"),
+            )
+            .unwrap();
+        assert!(sql.content.contains("CREATE TABLE"));
+
+        let proto = registry
+            .export_tables(
+                "protobuf",
+                &tables,
+                &ExportOptions::new().with_version("proto2"),
+            )
+            .unwrap();
+        assert!(proto.content.contains("syntax = \"proto2\""));
+
+        let contract = ODCSContract::from_tables(&tables);
+        let odcs = registry
+            .export_contract("odcs", &contract, &ExportOptions::new())
+            .unwrap();
+        assert!(odcs.content.contains("kind: DataContract"));
+
+        let sorted = registry
+            .export_contract(
+                "odcs",
+                &contract,
+                &ExportOptions::new().with_property("schema_order", "alphabetical"),
+            )
+            .unwrap();
+        assert!(
+            sorted.content.find("name: orders").unwrap()
+                < sorted.content.find("name: users").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_physical_names_per_platform() {
+        let registry = ExporterRegistry::default();
+        let mut names = PhysicalNameMap::new();
+        names.set_table("snowflake", "orders", "SALES_ORDERS");
+        names.set_column("snowflake", Some("orders"), "id", "ORDER_ID");
+        names.set_table("avro", "orders", "OrderEvent");
+        let options = ExportOptions::new().with_physical_names(names);
+
+        let sql = registry
+            .export_tables(
+                "sql",
+                &sample_tables(),
+                &options.clone().with_dialect("snowflake"),
+            )
+            .unwrap();
+        assert!(sql.content.contains("SALES_ORDERS"));
+        assert!(sql.content.contains("ORDER_ID"));
+        assert!(!sql.content.contains("orders"));
+
+        let avro = registry
+            .export_tables("avro", &sample_tables(), &options)
+            .unwrap();
+        assert!(avro.content.contains("OrderEvent"));
+
+        // ODCS keeps the logical names and records the physical ones
+        let odcs = registry
+            .export_tables(
+                "odcs",
+                &sample_tables(),
+                &options.clone().with_platform("snowflake"),
+            )
+            .unwrap();
+        assert!(odcs.content.contains("name: orders"));
+        assert!(odcs.content.contains("physicalName: SALES_ORDERS"));
+        assert!(odcs.content.contains("physicalName: ORDER_ID"));
+    }
+
+    #[test]
+    fn test_json_schema_draft_and_shared_definitions() {
+        let registry = ExporterRegistry::default();
+        let options = ExportOptions::new()
+            .with_version("2020-12")
+            .with_property("shared_definitions", "true");
+        let result = registry
+            .export_tables("json-schema", &sample_tables(), &options)
+            .unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&result.content).unwrap();
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+        assert_eq!(schema["$defs"]["id"]["type"], "integer");
+        assert_eq!(
+            schema["$defs"]["orders"]["properties"]["id"]["$ref"],
+            "#/$defs/id"
+        );
+
+        let err = registry
+            .export_tables(
+                "json-schema",
+                &sample_tables(),
+                &ExportOptions::new().with_version("draft-04"),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown JSON Schema draft"));
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        let registry = ExporterRegistry::default();
+        let err = registry
+            .export_tables("nope", &sample_tables(), &ExportOptions::new())
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown export format: nope"));
+    }
+}
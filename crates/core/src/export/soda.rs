@@ -0,0 +1,642 @@
+//! Soda Checks Language (SodaCL) exporter
+//!
+//! Writes a `checks for <table>` block per table for [Soda Core](https://docs.soda.io/soda-cl/soda-cl-overview.html):
+//!
+//! - a `schema` check failing when a column is missing or, for columns with a
+//!   physical type, has another type
+//! - `missing_count` for required and primary key columns, `duplicate_count` for
+//!   unique and primary key columns
+//! - `invalid_count` for columns with enum values or `logicalTypeOptions`
+//!   (`valid values`, `valid regex`, `valid min/max length`, `valid min/max`,
+//!   `valid format`)
+//! - ODCS library rules (`rowCount`, `nullValues`, `missingValues`, `invalidValues`,
+//!   `duplicateValues`, with `unit: percent`) as the matching Soda metrics, and
+//!   `type: sql` rules as user-defined metrics
+//!
+//! Rule expectations (`mustBe`, `mustBeGreaterThan`, `mustBeBetween`, ...) become
+//! check thresholds. Rules with severity `warning` or `info` become `warn` alert
+//! configurations instead. Nested columns and rules Soda cannot express (e.g.
+//! `type: text`) are left out.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+use serde_yaml::{Mapping, Value as YamlValue};
+
+use super::{ExportError, ExportResult};
+use crate::models::odcs::QualityRule;
+use crate::models::{Column, Table};
+
+/// Exporter for SodaCL check files.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SodaExporter;
+
+/// A single SodaCL check
+#[derive(Debug, Clone, PartialEq)]
+pub struct SodaCheck {
+    /// Check expression, e.g. `missing_count(id) = 0`
+    pub expression: String,
+    /// Check configuration (`name`, `valid values`, `warn`, ...)
+    pub config: Mapping,
+}
+
+impl SodaCheck {
+    fn new(expression: impl Into<String>) -> Self {
+        Self {
+            expression: expression.into(),
+            config: Mapping::new(),
+        }
+    }
+
+    fn with(mut self, key: &str, value: impl Into<YamlValue>) -> Self {
+        self.config.insert(key.into(), value.into());
+        self
+    }
+
+    fn to_yaml(&self) -> YamlValue {
+        if self.config.is_empty() {
+            return YamlValue::String(self.expression.clone());
+        }
+        let mut check = Mapping::new();
+        check.insert(
+            self.expression.clone().into(),
+            YamlValue::Mapping(self.config.clone()),
+        );
+        YamlValue::Mapping(check)
+    }
+}
+
+/// Threshold of a check, from a rule expectation
+#[derive(Debug, Clone, PartialEq)]
+enum Threshold {
+    Compare(&'static str, String),
+    Between(String, String),
+    NotBetween(String, String),
+}
+
+impl Threshold {
+    /// Condition under which the threshold is breached (for `warn: when ...`)
+    fn breach(&self) -> Self {
+        match self {
+            Threshold::Compare(operator, value) => {
+                let negated = match *operator {
+                    "=" => "!=",
+                    "!=" => "=",
+                    ">" => "<=",
+                    ">=" => "<",
+                    "<" => ">=",
+                    _ => ">",
+                };
+                Threshold::Compare(negated, value.clone())
+            }
+            Threshold::Between(low, high) => Threshold::NotBetween(low.clone(), high.clone()),
+            Threshold::NotBetween(low, high) => Threshold::Between(low.clone(), high.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Threshold::Compare(operator, value) => write!(f, "{} {}", operator, value),
+            Threshold::Between(low, high) => write!(f, "between {} and {}", low, high),
+            Threshold::NotBetween(low, high) => write!(f, "not between {} and {}", low, high),
+        }
+    }
+}
+
+impl SodaExporter {
+    /// Create a new SodaCL exporter
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Export tables to a SodaCL checks file (SDK interface).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::soda::SodaExporter;
+    /// use data_modelling_core::models::{Column, Table};
+    ///
+    /// let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+    /// id.primary_key = true;
+    /// let table = Table::new("users".to_string(), vec![id]);
+    ///
+    /// let result = SodaExporter::new().export(&[table]).unwrap();
+    /// assert!(result.content.contains("checks for users:"));
+    /// assert!(result.content.contains("- missing_count(id) = 0"));
+    /// assert!(result.content.contains("- duplicate_count(id) = 0"));
+    /// ```
+    pub fn export(&self, tables: &[Table]) -> Result<ExportResult, ExportError> {
+        let mut document = Mapping::new();
+        for table in tables {
+            let checks = self
+                .table_checks(table)
+                .iter()
+                .map(SodaCheck::to_yaml)
+                .collect();
+            document.insert(
+                format!("checks for {}", table.name).into(),
+                YamlValue::Sequence(checks),
+            );
+        }
+        let content = serde_yaml::to_string(&YamlValue::Mapping(document))
+            .map_err(|e| ExportError::SerializationError(e.to_string()))?;
+        Ok(ExportResult {
+            content,
+            format: "soda".to_string(),
+        })
+    }
+
+    /// Checks of a table: schema check, column metadata checks and quality rules
+    pub fn table_checks(&self, table: &Table) -> Vec<SodaCheck> {
+        let columns: Vec<&Column> = table
+            .columns
+            .iter()
+            .filter(|c| !c.name.contains('.'))
+            .collect();
+
+        let mut checks = Vec::new();
+        if let Some(schema) = schema_check(&columns) {
+            checks.push(schema);
+        }
+        for (index, rule) in table.quality.iter().enumerate() {
+            checks.extend(rule_checks(&table.name, None, &parse_rule(rule), index));
+        }
+        for column in columns {
+            checks.extend(column_checks(column));
+            for (index, rule) in column.quality.iter().enumerate() {
+                checks.extend(rule_checks(
+                    &table.name,
+                    Some(&column.name),
+                    &parse_rule(rule),
+                    index,
+                ));
+            }
+        }
+        checks
+    }
+}
+
+fn parse_rule(rule: &HashMap<String, Value>) -> QualityRule {
+    serde_json::to_value(rule)
+        .and_then(serde_json::from_value)
+        .unwrap_or_default()
+}
+
+fn schema_check(columns: &[&Column]) -> Option<SodaCheck> {
+    if columns.is_empty() {
+        return None;
+    }
+    let mut fail = Mapping::new();
+    fail.insert(
+        "when required column missing".into(),
+        YamlValue::Sequence(columns.iter().map(|c| c.name.clone().into()).collect()),
+    );
+    let types: Mapping = columns
+        .iter()
+        .filter_map(|c| {
+            let physical = c.physical_type.as_deref()?.trim();
+            (!physical.is_empty()).then(|| (c.name.clone().into(), physical.to_lowercase().into()))
+        })
+        .collect();
+    if !types.is_empty() {
+        fail.insert("when wrong column type".into(), YamlValue::Mapping(types));
+    }
+    Some(SodaCheck::new("schema").with("fail", YamlValue::Mapping(fail)))
+}
+
+/// Checks implied by column metadata
+fn column_checks(column: &Column) -> Vec<SodaCheck> {
+    let name = &column.name;
+    let mut checks = Vec::new();
+    if !column.nullable || column.primary_key {
+        checks.push(SodaCheck::new(format!("missing_count({}) = 0", name)));
+    }
+    if column.unique || column.primary_key {
+        checks.push(SodaCheck::new(format!("duplicate_count({}) = 0", name)));
+    }
+
+    let mut validity = SodaCheck::new(format!("invalid_count({}) = 0", name));
+    if !column.enum_values.is_empty() {
+        validity = validity.with(
+            "valid values",
+            YamlValue::Sequence(
+                column
+                    .enum_values
+                    .iter()
+                    .map(|v| v.clone().into())
+                    .collect(),
+            ),
+        );
+    }
+    if let Some(options) = &column.logical_type_options {
+        if let Some(pattern) = &options.pattern {
+            validity = validity.with("valid regex", pattern.clone());
+        }
+        if let Some(length) = options.min_length {
+            validity = validity.with("valid min length", length);
+        }
+        if let Some(length) = options.max_length {
+            validity = validity.with("valid max length", length);
+        }
+        if let Some(minimum) = options.minimum.as_ref().and_then(yaml_number) {
+            validity = validity.with("valid min", YamlValue::Number(minimum));
+        }
+        if let Some(maximum) = options.maximum.as_ref().and_then(yaml_number) {
+            validity = validity.with("valid max", YamlValue::Number(maximum));
+        }
+        if let Some(format) = options.format.as_deref().and_then(soda_format) {
+            validity = validity.with("valid format", format);
+        }
+    }
+    if !validity.config.is_empty() {
+        checks.push(validity);
+    }
+    checks
+}
+
+/// Soda's name for a `logicalTypeOptions.format`
+fn soda_format(format: &str) -> Option<&'static str> {
+    match format.to_lowercase().as_str() {
+        "email" => Some("email"),
+        "uuid" => Some("uuid"),
+        "ipv4" => Some("ip address v4"),
+        "ipv6" => Some("ip address v6"),
+        "date" | "date-time" => Some("date iso 8601"),
+        "phone" => Some("phone number"),
+        _ => None,
+    }
+}
+
+/// Checks of an ODCS quality rule on a table or column
+fn rule_checks(
+    table: &str,
+    column: Option<&str>,
+    rule: &QualityRule,
+    index: usize,
+) -> Vec<SodaCheck> {
+    let argument_list = |key: &str| -> Vec<Value> {
+        rule.extra
+            .get("arguments")
+            .and_then(|a| a.get(key))
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let percent = rule.extra.get("unit").and_then(Value::as_str) == Some("percent");
+    let count_or_percent = |metric: &str| {
+        if percent {
+            format!("{}_percent", metric)
+        } else {
+            format!("{}_count", metric)
+        }
+    };
+
+    let mut config = Vec::new();
+    let (metric, default) = if rule.rule_type.as_deref() == Some("sql") {
+        let Some(query) = &rule.query else {
+            return Vec::new();
+        };
+        let name = metric_name(table, column, rule, index);
+        let query = query
+            .replace("${object}", table)
+            .replace("${table}", table)
+            .replace("${property}", column.unwrap_or_default())
+            .replace("${column}", column.unwrap_or_default());
+        config.push((format!("{} query", name), YamlValue::String(query)));
+        (name, Threshold::Compare("=", "0".to_string()))
+    } else {
+        match (rule.metric.as_deref(), column) {
+            (Some("rowCount"), _) => (
+                "row_count".to_string(),
+                Threshold::Compare(">", "0".to_string()),
+            ),
+            (Some("nullValues"), Some(column)) => (
+                format!("{}({})", count_or_percent("missing"), column),
+                Threshold::Compare("=", "0".to_string()),
+            ),
+            (Some("missingValues"), Some(column)) => {
+                let missing: Vec<YamlValue> = argument_list("missingValues")
+                    .iter()
+                    .filter(|v| !v.is_null())
+                    .filter_map(|v| serde_yaml::to_value(v).ok())
+                    .collect();
+                if !missing.is_empty() {
+                    config.push(("missing values".to_string(), YamlValue::Sequence(missing)));
+                }
+                (
+                    format!("{}({})", count_or_percent("missing"), column),
+                    Threshold::Compare("=", "0".to_string()),
+                )
+            }
+            (Some("invalidValues"), Some(column)) => {
+                let valid: Vec<YamlValue> = argument_list("validValues")
+                    .iter()
+                    .filter_map(|v| serde_yaml::to_value(v).ok())
+                    .collect();
+                if !valid.is_empty() {
+                    config.push(("valid values".to_string(), YamlValue::Sequence(valid)));
+                }
+                if let Some(pattern) = rule
+                    .extra
+                    .get("arguments")
+                    .and_then(|a| a.get("pattern"))
+                    .and_then(Value::as_str)
+                {
+                    config.push(("valid regex".to_string(), pattern.into()));
+                }
+                if config.is_empty() {
+                    return Vec::new();
+                }
+                (
+                    format!("{}({})", count_or_percent("invalid"), column),
+                    Threshold::Compare("=", "0".to_string()),
+                )
+            }
+            (Some("duplicateValues"), _) => {
+                let columns: Vec<String> = match argument_list("properties").as_slice() {
+                    [] => column.map(|c| vec![c.to_string()]).unwrap_or_default(),
+                    names => names
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect(),
+                };
+                if columns.is_empty() {
+                    return Vec::new();
+                }
+                (
+                    format!("{}({})", count_or_percent("duplicate"), columns.join(", ")),
+                    Threshold::Compare("=", "0".to_string()),
+                )
+            }
+            (None, Some(column)) => return value_range_check(column, rule).into_iter().collect(),
+            _ => return Vec::new(),
+        }
+    };
+
+    let mut thresholds = thresholds(rule);
+    if thresholds.is_empty() {
+        thresholds.push(default);
+    }
+    let warning = matches!(
+        rule.extra
+            .get("severity")
+            .and_then(Value::as_str)
+            .map(str::to_lowercase)
+            .as_deref(),
+        Some("warning" | "info")
+    );
+
+    thresholds
+        .into_iter()
+        .map(|threshold| {
+            let mut check = if warning {
+                SodaCheck::new(metric.clone()).with("warn", format!("when {}", threshold.breach()))
+            } else {
+                SodaCheck::new(format!("{} {}", metric, threshold))
+            };
+            if let Some(description) = &rule.description {
+                check = check.with("name", description.clone());
+            }
+            for (key, value) in &config {
+                check = check.with(key, value.clone());
+            }
+            check
+        })
+        .collect()
+}
+
+/// Property rule without a metric, checking every value (e.g. `mustBeBetween: [0, 100]`)
+fn value_range_check(column: &str, rule: &QualityRule) -> Option<SodaCheck> {
+    let mut check = SodaCheck::new(format!("invalid_count({}) = 0", column));
+    let bound = |value: &Option<Value>| value.as_ref().and_then(yaml_number);
+    if let Some(minimum) = bound(&rule.must_be_greater_than_or_equal) {
+        check = check.with("valid min", YamlValue::Number(minimum));
+    }
+    if let Some(maximum) = bound(&rule.must_be_less_than_or_equal) {
+        check = check.with("valid max", YamlValue::Number(maximum));
+    }
+    if let Some([low, high]) = range(rule, "mustBeBetween") {
+        check = check
+            .with("valid min", YamlValue::Number(low))
+            .with("valid max", YamlValue::Number(high));
+    }
+    if let Some(values) = &rule.must_be_in {
+        let values = values
+            .iter()
+            .filter_map(|v| serde_yaml::to_value(v).ok())
+            .collect();
+        check = check.with("valid values", YamlValue::Sequence(values));
+    }
+    if let Some(values) = &rule.must_not_be_in {
+        let values = values
+            .iter()
+            .filter_map(|v| serde_yaml::to_value(v).ok())
+            .collect();
+        check = check.with("invalid values", YamlValue::Sequence(values));
+    }
+    if check.config.is_empty() {
+        return None;
+    }
+    if let Some(description) = &rule.description {
+        check = check.with("name", description.clone());
+    }
+    Some(check)
+}
+
+/// Thresholds of a rule's expectations, in declaration order of the ODCS fields
+fn thresholds(rule: &QualityRule) -> Vec<Threshold> {
+    let mut thresholds = Vec::new();
+    for (value, operator) in [
+        (&rule.must_be, "="),
+        (&rule.must_not_be, "!="),
+        (&rule.must_be_greater_than, ">"),
+        (&rule.must_be_greater_than_or_equal, ">="),
+        (&rule.must_be_less_than, "<"),
+        (&rule.must_be_less_than_or_equal, "<="),
+    ] {
+        if let Some(value) = value.as_ref().and_then(threshold_value) {
+            thresholds.push(Threshold::Compare(operator, value));
+        }
+    }
+    if let Some([low, high]) = range(rule, "mustBeBetween") {
+        thresholds.push(Threshold::Between(low.to_string(), high.to_string()));
+    }
+    if let Some([low, high]) = range(rule, "mustNotBeBetween") {
+        thresholds.push(Threshold::NotBetween(low.to_string(), high.to_string()));
+    }
+    thresholds
+}
+
+/// `[low, high]` bounds of a range expectation, which are not modeled on [`QualityRule`]
+fn range(rule: &QualityRule, key: &str) -> Option<[serde_yaml::Number; 2]> {
+    match rule.extra.get(key).and_then(Value::as_array)?.as_slice() {
+        [low, high] => Some([yaml_number(low)?, yaml_number(high)?]),
+        _ => None,
+    }
+}
+
+fn threshold_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) if s.trim().parse::<f64>().is_ok() => Some(s.trim().to_string()),
+        _ => None,
+    }
+}
+
+fn yaml_number(value: &Value) -> Option<serde_yaml::Number> {
+    match value {
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Some(i.into())
+            } else {
+                n.as_f64().map(Into::into)
+            }
+        }
+        Value::String(s) => s.trim().parse::<f64>().ok().map(Into::into),
+        _ => None,
+    }
+}
+
+/// Name of the user-defined metric of a SQL rule
+fn metric_name(table: &str, column: Option<&str>, rule: &QualityRule, index: usize) -> String {
+    let raw = match rule.extra.get("name").and_then(Value::as_str) {
+        Some(name) => name.to_string(),
+        None => match column {
+            Some(column) => format!("{}_{}_rule_{}", table, column, index + 1),
+            None => format!("{}_rule_{}", table, index + 1),
+        },
+    };
+    raw.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::ODCSContract;
+
+    fn contract() -> ODCSContract {
+        serde_yaml::from_str(
+            r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders
+name: orders
+version: 1.0.0
+status: active
+schema:
+  - name: orders
+    quality:
+      - type: library
+        metric: rowCount
+        mustBeGreaterThan: 100
+      - type: sql
+        name: Negative totals
+        query: SELECT COUNT(*) FROM ${object} WHERE total < 0
+        description: No negative totals
+    properties:
+      - name: id
+        logicalType: integer
+        physicalType: BIGINT
+        primaryKey: true
+      - name: status
+        logicalType: string
+        logicalTypeOptions:
+          maxLength: 10
+        quality:
+          - type: library
+            metric: invalidValues
+            arguments:
+              validValues: [open, closed]
+            mustBeLessThan: 5
+            unit: percent
+            severity: warning
+      - name: total
+        logicalType: number
+        quality:
+          - mustBeBetween: [0, 1000]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_table_checks() {
+        let tables = contract().to_tables();
+        let checks = SodaExporter::new().table_checks(&tables[0]);
+        let expressions: Vec<&str> = checks.iter().map(|c| c.expression.as_str()).collect();
+        assert_eq!(
+            expressions,
+            vec![
+                "schema",
+                "row_count > 100",
+                "negative_totals = 0",
+                "missing_count(id) = 0",
+                "duplicate_count(id) = 0",
+                "invalid_count(status) = 0",
+                "invalid_percent(status)",
+                "invalid_count(total) = 0",
+            ]
+        );
+
+        let sql = &checks[2].config;
+        assert_eq!(
+            sql.get("negative_totals query").and_then(YamlValue::as_str),
+            Some("SELECT COUNT(*) FROM orders WHERE total < 0")
+        );
+        assert_eq!(
+            checks[6].config.get("warn").and_then(YamlValue::as_str),
+            Some("when >= 5")
+        );
+        assert_eq!(
+            checks[7]
+                .config
+                .get("valid max")
+                .and_then(YamlValue::as_i64),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_export_yaml() {
+        let result = SodaExporter::new().export(&contract().to_tables()).unwrap();
+        assert_eq!(result.format, "soda");
+        let document: YamlValue = serde_yaml::from_str(&result.content).unwrap();
+        let checks = document["checks for orders"].as_sequence().unwrap();
+        assert_eq!(
+            checks[0]["schema"]["fail"]["when required column missing"],
+            serde_yaml::from_str::<YamlValue>("[id, status, total]").unwrap()
+        );
+        assert_eq!(
+            checks[0]["schema"]["fail"]["when wrong column type"]["id"],
+            YamlValue::from("bigint")
+        );
+        assert!(result.content.contains("- missing_count(id) = 0\n"));
+        assert!(result.content.contains("valid values:\n"));
+    }
+
+    #[test]
+    fn test_threshold_breach() {
+        let between = Threshold::Between("1".to_string(), "5".to_string());
+        assert_eq!(between.to_string(), "between 1 and 5");
+        assert_eq!(between.breach().to_string(), "not between 1 and 5");
+        assert_eq!(
+            Threshold::Compare("<=", "3".to_string())
+                .breach()
+                .to_string(),
+            "> 3"
+        );
+    }
+}
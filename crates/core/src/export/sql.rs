@@ -7,7 +7,9 @@
 //! by doubling them according to SQL standards.
 
 use crate::export::{ExportError, ExportResult};
-use crate::models::{DataModel, Table};
+use crate::models::{
+    BinaryType, Column, DataModel, NestedType, SpatialType, Table, TypeMappingRegistry, VectorType,
+};
 
 /// Exporter for SQL CREATE TABLE format.
 pub struct SQLExporter;
@@ -18,7 +20,7 @@ impl SQLExporter {
     /// # Arguments
     ///
     /// * `table` - The table to export
-    /// * `dialect` - Optional SQL dialect ("postgres", "mysql", "sqlserver", etc.)
+    /// * `dialect` - Optional SQL dialect ("postgres", "mysql", "sqlserver", "snowflake", etc.)
     ///
     /// # Returns
     ///
@@ -40,56 +42,84 @@ impl SQLExporter {
     /// // Returns: CREATE TABLE "users" (\n  "id" INT\n);
     /// ```
     pub fn export_table(table: &Table, dialect: Option<&str>) -> String {
-        let dialect = dialect.unwrap_or("standard");
-        let mut sql = String::new();
+        Self::export_table_with_type_mappings(table, dialect, TypeMappingRegistry::builtin())
+    }
 
-        // CREATE TABLE statement
-        sql.push_str(&format!(
-            "CREATE TABLE {}",
-            Self::quote_identifier(&table.name, dialect)
-        ));
+    /// Export a table to a SQL CREATE TABLE statement, mapping column data types
+    /// to the dialect's native types with `type_mappings`.
+    ///
+    /// Data types without a mapping for the dialect are written as-is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::export::sql::SQLExporter;
+    /// use data_modelling_core::models::{Column, Table, TypeMappingRegistry};
+    ///
+    /// let table = Table::new(
+    ///     "users".to_string(),
+    ///     vec![Column::new("name".to_string(), "STRING".to_string())],
+    /// );
+    /// let mut mappings = TypeMappingRegistry::default();
+    /// mappings.set_export("postgres", "string", "TEXT");
+    ///
+    /// let sql = SQLExporter::export_table_with_type_mappings(&table, Some("postgres"), &mappings);
+    /// assert!(sql.contains("\"name\" TEXT"));
+    /// ```
+    pub fn export_table_with_type_mappings(
+        table: &Table,
+        dialect: Option<&str>,
+        type_mappings: &TypeMappingRegistry,
+    ) -> String {
+        let dialect = dialect.unwrap_or("standard");
 
         // Build fully-qualified table name based on catalog and schema
-        sql = match (&table.catalog_name, &table.schema_name) {
-            (Some(catalog), Some(schema)) => {
-                format!(
-                    "CREATE TABLE {}.{}.{}",
-                    Self::quote_identifier(catalog, dialect),
-                    Self::quote_identifier(schema, dialect),
-                    Self::quote_identifier(&table.name, dialect)
-                )
-            }
-            (Some(catalog), None) => {
-                format!(
-                    "CREATE TABLE {}.{}",
-                    Self::quote_identifier(catalog, dialect),
-                    Self::quote_identifier(&table.name, dialect)
-                )
-            }
-            (None, Some(schema)) => {
-                format!(
-                    "CREATE TABLE {}.{}",
-                    Self::quote_identifier(schema, dialect),
-                    Self::quote_identifier(&table.name, dialect)
-                )
-            }
-            (None, None) => sql, // Keep default "CREATE TABLE tablename"
-        };
+        let table_ref = [
+            table.catalog_name.as_deref(),
+            Self::schema_name(table),
+            Some(table.name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|part| Self::quote_identifier(part, dialect))
+        .collect::<Vec<_>>()
+        .join(".");
 
-        sql.push_str(" (\n");
+        // CREATE TABLE statement
+        let transient = dialect == "snowflake"
+            && table
+                .odcl_metadata
+                .get("transient")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+        let mut sql = if transient {
+            format!("CREATE TRANSIENT TABLE {} (\n", table_ref)
+        } else {
+            format!("CREATE TABLE {} (\n", table_ref)
+        };
 
         // Column definitions
         let mut column_defs = Vec::new();
         for column in &table.columns {
             let mut col_def = format!("  {}", Self::quote_identifier(&column.name, dialect));
             col_def.push(' ');
-            col_def.push_str(&column.data_type);
+            col_def.push_str(&Self::native_type(column, dialect, type_mappings));
+
+            if column.custom_properties.get("autoIncrement") == Some(&serde_json::Value::Bool(true))
+            {
+                match dialect {
+                    "sqlserver" | "mssql" => col_def.push_str(" IDENTITY(1,1)"),
+                    "oracle" => col_def.push_str(" GENERATED BY DEFAULT AS IDENTITY"),
+                    _ => {}
+                }
+            }
 
             if !column.nullable {
                 col_def.push_str(" NOT NULL");
             }
 
-            if column.primary_key {
+            // BigQuery only supports unenforced table-level primary keys
+            if column.primary_key && dialect != "bigquery" {
                 col_def.push_str(" PRIMARY KEY");
             }
 
@@ -99,12 +129,20 @@ impl SQLExporter {
                     "postgres" | "postgresql" => {
                         col_def.push_str(&format!(" -- {}", column.description));
                     }
-                    "mysql" => {
+                    "mysql" | "snowflake" => {
                         col_def.push_str(&format!(
                             " COMMENT '{}'",
                             column.description.replace('\'', "''")
                         ));
                     }
+                    "bigquery" => {
+                        col_def.push_str(&format!(
+                            " OPTIONS(description = {})",
+                            Self::bigquery_string(&column.description)
+                        ));
+                    }
+                    // Written as extended properties or COMMENT ON statements
+                    "sqlserver" | "mssql" | "oracle" => {}
                     _ => {
                         col_def.push_str(&format!(" -- {}", column.description));
                     }
@@ -114,8 +152,59 @@ impl SQLExporter {
             column_defs.push(col_def);
         }
 
+        if dialect == "bigquery" {
+            let primary_key: Vec<String> = table
+                .columns
+                .iter()
+                .filter(|c| c.primary_key)
+                .map(|c| Self::quote_identifier(&c.name, dialect))
+                .collect();
+            if !primary_key.is_empty() {
+                column_defs.push(format!(
+                    "  PRIMARY KEY ({}) NOT ENFORCED",
+                    primary_key.join(", ")
+                ));
+            }
+        }
+
         sql.push_str(&column_defs.join(",\n"));
-        sql.push_str("\n);\n");
+        sql.push_str("\n)");
+        match dialect {
+            "snowflake" => sql.push_str(&Self::snowflake_table_options(table)),
+            "bigquery" => sql.push_str(&Self::bigquery_table_options(table)),
+            _ => {}
+        }
+        sql.push_str(";\n");
+
+        // Similarity search indexes for embedding vectors with a distance metric
+        for column in &table.columns {
+            let Some(vector) = VectorType::from_column(column) else {
+                continue;
+            };
+            let index_name =
+                Self::quote_identifier(&format!("{}_{}_idx", table.name, column.name), dialect);
+            let column_name = Self::quote_identifier(&column.name, dialect);
+            match (dialect, vector.metric) {
+                ("postgres" | "postgresql", _) => {
+                    if let Some(ops) = vector.index_operator_class() {
+                        sql.push_str(&format!(
+                            "CREATE INDEX {} ON {} USING hnsw ({} {});\n",
+                            index_name, table_ref, column_name, ops
+                        ));
+                    }
+                }
+                ("duckdb", Some(metric)) => {
+                    sql.push_str(&format!(
+                        "CREATE INDEX {} ON {} USING HNSW ({}) WITH (metric = '{}');\n",
+                        index_name,
+                        table_ref,
+                        column_name,
+                        metric.duckdb_name()
+                    ));
+                }
+                _ => {}
+            }
+        }
 
         // Add table comment if available (from odcl_metadata)
         if let Some(desc) = table
@@ -138,6 +227,8 @@ impl SQLExporter {
                         desc.replace("'", "''")
                     ));
                 }
+                // Written as a table option, extended property or COMMENT ON statement
+                "snowflake" | "bigquery" | "sqlserver" | "mssql" | "oracle" => {}
                 _ => {
                     // Default: SQL comment
                     sql.push_str(&format!("-- Table: {}\n", table.name));
@@ -146,6 +237,12 @@ impl SQLExporter {
             }
         }
 
+        match dialect {
+            "sqlserver" | "mssql" => sql.push_str(&Self::tsql_descriptions(table)),
+            "oracle" => sql.push_str(&Self::comment_statements(table, &table_ref, dialect)),
+            _ => {}
+        }
+
         sql
     }
 
@@ -179,10 +276,24 @@ impl SQLExporter {
         &self,
         tables: &[Table],
         dialect: Option<&str>,
+    ) -> Result<ExportResult, ExportError> {
+        self.export_with_type_mappings(tables, dialect, TypeMappingRegistry::builtin())
+    }
+
+    /// Export tables to SQL CREATE TABLE statements, mapping data types with `type_mappings`.
+    pub fn export_with_type_mappings(
+        &self,
+        tables: &[Table],
+        dialect: Option<&str>,
+        type_mappings: &TypeMappingRegistry,
     ) -> Result<ExportResult, ExportError> {
         let mut sql = String::new();
         for table in tables {
-            sql.push_str(&Self::export_table(table, dialect));
+            sql.push_str(&Self::export_table_with_type_mappings(
+                table,
+                dialect,
+                type_mappings,
+            ));
             sql.push('\n');
         }
         Ok(ExportResult {
@@ -217,6 +328,266 @@ impl SQLExporter {
         sql
     }
 
+    /// Native type of a column for a SQL dialect
+    ///
+    /// Uses, in order: the native type preserved by an importer of the same
+    /// dialect, the dialect's type mappings, dialect-specific conversions, and
+    /// spatial, vector and binary types. Other data types are written as-is.
+    pub(crate) fn native_type(
+        column: &Column,
+        dialect: &str,
+        type_mappings: &TypeMappingRegistry,
+    ) -> String {
+        if let Some(native_type) = type_mappings.preserved_type(dialect, column) {
+            return native_type.to_string();
+        }
+        if dialect == "bigquery"
+            && let Some(nested) = NestedType::parse(&column.data_type)
+        {
+            // BigQuery has no MAP type; scalar types inside STRUCT/ARRAY are mapped too
+            return nested
+                .maps_as_entries()
+                .map_scalars(&|t| Self::scalar_type(t, dialect, type_mappings))
+                .to_string();
+        }
+        type_mappings
+            .export_type(dialect, &column.data_type)
+            .or_else(|| Self::dialect_type(&column.data_type, dialect))
+            .or_else(|| SpatialType::from_column(column).and_then(|s| s.to_sql(dialect)))
+            .or_else(|| VectorType::from_column(column).and_then(|v| v.to_sql(dialect)))
+            .or_else(|| BinaryType::parse(&column.data_type).and_then(|b| b.to_sql(dialect)))
+            .unwrap_or_else(|| column.data_type.clone())
+    }
+
+    /// Native type of a scalar data type (e.g. inside a STRUCT) for a SQL dialect
+    pub(crate) fn scalar_type(
+        data_type: &str,
+        dialect: &str,
+        type_mappings: &TypeMappingRegistry,
+    ) -> String {
+        type_mappings
+            .export_type(dialect, data_type)
+            .or_else(|| Self::dialect_type(data_type, dialect))
+            .or_else(|| SpatialType::parse(data_type).and_then(|s| s.to_sql(dialect)))
+            .or_else(|| BinaryType::parse(data_type).and_then(|b| b.to_sql(dialect)))
+            .unwrap_or_else(|| data_type.to_string())
+    }
+
+    /// Dialect-specific conversions that type mappings can't express
+    ///
+    /// - Snowflake: structured types become the semi-structured `ARRAY` and
+    ///   `OBJECT` types, and fixed-point types become `NUMBER(p,s)`
+    /// - BigQuery: fixed-point types become `NUMERIC(p,s)`, or `BIGNUMERIC(p,s)`
+    ///   beyond `NUMERIC`'s precision of 38 and scale of 9
+    /// - Oracle: fixed-point types become `NUMBER(p,s)` and variable-length
+    ///   strings `VARCHAR2(n)`/`NVARCHAR2(n)`
+    fn dialect_type(data_type: &str, dialect: &str) -> Option<String> {
+        let upper = data_type.trim().to_uppercase();
+        if dialect == "oracle" {
+            for base in ["NVARCHAR", "VARCHAR"] {
+                if let Some(params) = upper.strip_prefix(base).map(str::trim_start) {
+                    if params.is_empty() {
+                        return Some(format!("{}2(4000)", base));
+                    }
+                    if params.starts_with('(') {
+                        return Some(format!("{}2{}", base, params));
+                    }
+                }
+            }
+        }
+        if dialect == "snowflake" {
+            if upper.starts_with("ARRAY<") {
+                return Some("ARRAY".to_string());
+            }
+            if upper.starts_with("STRUCT<") || upper.starts_with("MAP<") {
+                return Some("OBJECT".to_string());
+            }
+        }
+
+        let params = ["DECIMAL", "NUMERIC"]
+            .iter()
+            .find_map(|prefix| upper.strip_prefix(prefix))
+            .map(str::trim_start)
+            .filter(|params| params.is_empty() || params.starts_with('('))?;
+        match dialect {
+            "snowflake" | "oracle" => Some(format!("NUMBER{}", params)),
+            "bigquery" => {
+                let numbers: Vec<u32> = params
+                    .trim_matches(|c| c == '(' || c == ')')
+                    .split(',')
+                    .filter_map(|n| n.trim().parse().ok())
+                    .collect();
+                let big = match numbers.as_slice() {
+                    [precision] => *precision > 38,
+                    [precision, scale] => *precision > 38 || *scale > 9,
+                    _ => false,
+                };
+                let base = if big { "BIGNUMERIC" } else { "NUMERIC" };
+                Some(format!("{}{}", base, params))
+            }
+            _ => None,
+        }
+    }
+
+    /// BigQuery `PARTITION BY`, `CLUSTER BY` and `OPTIONS` table options
+    ///
+    /// Partitioning and clustering come from the `partitionBy`/`clusterBy`
+    /// metadata kept by the SQL importer, falling back to the partitioned and
+    /// clustered columns.
+    fn bigquery_table_options(table: &Table) -> String {
+        let mut options = String::new();
+
+        let partition_by = table
+            .odcl_metadata
+            .get("partitionBy")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or_else(|| {
+                table
+                    .columns
+                    .iter()
+                    .filter(|c| c.partitioned)
+                    .min_by_key(|c| c.partition_key_position.unwrap_or(i32::MAX))
+                    .map(|c| Self::quote_identifier(&c.name, "bigquery"))
+            });
+        if let Some(partition_by) = partition_by {
+            options.push_str(&format!("\nPARTITION BY {}", partition_by));
+        }
+
+        let cluster_by = Self::cluster_keys(table, "bigquery");
+        if !cluster_by.is_empty() {
+            options.push_str(&format!("\nCLUSTER BY {}", cluster_by.join(", ")));
+        }
+
+        if let Some(desc) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(|v| v.as_str())
+        {
+            options.push_str(&format!(
+                "\nOPTIONS(description = {})",
+                Self::bigquery_string(desc)
+            ));
+        }
+
+        options
+    }
+
+    /// Clustering keys from the `clusterBy` metadata, falling back to the clustered columns
+    fn cluster_keys(table: &Table, dialect: &str) -> Vec<String> {
+        match table
+            .odcl_metadata
+            .get("clusterBy")
+            .and_then(|v| v.as_array())
+        {
+            Some(keys) => keys
+                .iter()
+                .filter_map(|k| k.as_str().map(str::to_string))
+                .collect(),
+            None => table
+                .columns
+                .iter()
+                .filter(|c| c.clustered)
+                .map(|c| Self::quote_identifier(&c.name, dialect))
+                .collect(),
+        }
+    }
+
+    /// Double-quoted BigQuery string literal
+    fn bigquery_string(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    /// Snowflake `CLUSTER BY` and `COMMENT` table options
+    fn snowflake_table_options(table: &Table) -> String {
+        let mut options = String::new();
+
+        let cluster_by = Self::cluster_keys(table, "snowflake");
+        if !cluster_by.is_empty() {
+            options.push_str(&format!("\nCLUSTER BY ({})", cluster_by.join(", ")));
+        }
+
+        if let Some(desc) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(|v| v.as_str())
+        {
+            options.push_str(&format!("\nCOMMENT = '{}'", desc.replace('\'', "''")));
+        }
+
+        options
+    }
+
+    /// Schema of a table, falling back to the `schemaName` kept by importers
+    fn schema_name(table: &Table) -> Option<&str> {
+        table.schema_name.as_deref().or_else(|| {
+            table
+                .odcl_metadata
+                .get("schemaName")
+                .and_then(|v| v.as_str())
+        })
+    }
+
+    /// SQL Server `MS_Description` extended properties for table and column descriptions
+    ///
+    /// Tables without a schema are described in `dbo`.
+    fn tsql_descriptions(table: &Table) -> String {
+        let literal = |value: &str| format!("N'{}'", value.replace('\'', "''"));
+        let target = format!(
+            "@level0type = N'SCHEMA', @level0name = {}, @level1type = N'TABLE', @level1name = {}",
+            literal(Self::schema_name(table).unwrap_or("dbo")),
+            literal(&table.name)
+        );
+
+        let mut sql = String::new();
+        if let Some(desc) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(|v| v.as_str())
+        {
+            sql.push_str(&format!(
+                "EXEC sys.sp_addextendedproperty @name = N'MS_Description', @value = {}, {};\n",
+                literal(desc),
+                target
+            ));
+        }
+        for column in table.columns.iter().filter(|c| !c.description.is_empty()) {
+            sql.push_str(&format!(
+                "EXEC sys.sp_addextendedproperty @name = N'MS_Description', @value = {}, {}, @level2type = N'COLUMN', @level2name = {};\n",
+                literal(&column.description),
+                target,
+                literal(&column.name)
+            ));
+        }
+        sql
+    }
+
+    /// `COMMENT ON TABLE/COLUMN` statements for table and column descriptions
+    fn comment_statements(table: &Table, table_ref: &str, dialect: &str) -> String {
+        let literal = |value: &str| format!("'{}'", value.replace('\'', "''"));
+        let mut sql = String::new();
+        if let Some(desc) = table
+            .odcl_metadata
+            .get("description")
+            .and_then(|v| v.as_str())
+        {
+            sql.push_str(&format!(
+                "COMMENT ON TABLE {} IS {};\n",
+                table_ref,
+                literal(desc)
+            ));
+        }
+        for column in table.columns.iter().filter(|c| !c.description.is_empty()) {
+            sql.push_str(&format!(
+                "COMMENT ON COLUMN {}.{} IS {};\n",
+                table_ref,
+                Self::quote_identifier(&column.name, dialect),
+                literal(&column.description)
+            ));
+        }
+        sql
+    }
+
     /// Quote and escape identifier based on SQL dialect.
     ///
     /// # Security
@@ -229,6 +600,7 @@ impl SQLExporter {
     /// - **PostgreSQL**: Uses double quotes (`"identifier"`)
     /// - **MySQL**: Uses backticks (`` `identifier` ``)
     /// - **SQL Server**: Uses brackets (`[identifier]`)
+    /// - **BigQuery**: Uses backticks (`` `identifier` ``)
     /// - **Standard SQL**: Uses double quotes
     ///
     /// # Example
@@ -254,6 +626,10 @@ impl SQLExporter {
                 // PostgreSQL uses double quotes; escape by doubling
                 format!("\"{}\"", identifier.replace('"', "\"\""))
             }
+            "bigquery" => {
+                // BigQuery uses backticks; escape internal backticks with a backslash
+                format!("`{}`", identifier.replace('`', "\\`"))
+            }
             "sqlserver" | "mssql" => {
                 // SQL Server uses brackets; escape ] by doubling
                 format!("[{}]", identifier.replace(']', "]]"))
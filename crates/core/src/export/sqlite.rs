@@ -0,0 +1,711 @@
+//! SQLite model bundles
+//!
+//! Flattens a whole workspace into one compact, read-only SQLite database for
+//! offline browsing in mobile and desktop apps, and reads it back with
+//! [`SqliteModelBundle`]. The database has four tables:
+//!
+//! - `metadata` - key/value pairs: bundle `name`, `created_at` and `generator`
+//! - `tables` - one row per table with its schema, owner, description, tags
+//!   (JSON array) and canvas position
+//! - `columns` - one row per column, keyed by table and ordinal
+//! - `relationships` - one row per relationship with its label, cardinality,
+//!   keys and visual metadata (JSON)
+//!
+//! The bundle format version is stored in `PRAGMA user_version`. Bundles are
+//! written in a single transaction, analyzed and vacuumed, so they are as small
+//! as possible and need no journal when opened read-only.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::export::sqlite::{SqliteModelBundle, export_workspace_sqlite};
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//!
+//! let backend = FileSystemStorageBackend::new("/path/to/workspaces");
+//! let info = export_workspace_sqlite(&backend, "sales", "sales.odm.sqlite").await?;
+//! println!("{} tables, {} columns", info.table_count, info.column_count);
+//!
+//! let bundle = SqliteModelBundle::open("sales.odm.sqlite")?;
+//! for table in bundle.tables()? {
+//!     println!("{} ({} columns)", table.name, table.column_count);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OpenFlags, OptionalExtension, Row, params};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::import::odcs::ODCSImporter;
+use crate::model::ModelLoader;
+use crate::models::{Position, Relationship, Table};
+use crate::storage::{StorageBackend, StorageError};
+
+/// Version of the bundle format written by [`export_sqlite`]
+pub const SQLITE_BUNDLE_VERSION: i64 = 1;
+
+/// Conventional file extension of SQLite model bundles
+pub const SQLITE_BUNDLE_EXTENSION: &str = "odm.sqlite";
+
+const SCHEMA_SQL: &str = r#"
+CREATE TABLE metadata (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+) WITHOUT ROWID;
+
+CREATE TABLE tables (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    catalog_name TEXT,
+    schema_name TEXT,
+    owner TEXT,
+    description TEXT,
+    tags TEXT NOT NULL,
+    position_x REAL,
+    position_y REAL,
+    column_count INTEGER NOT NULL
+);
+
+CREATE TABLE columns (
+    table_id TEXT NOT NULL REFERENCES tables(id),
+    ordinal INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    data_type TEXT NOT NULL,
+    nullable INTEGER NOT NULL,
+    primary_key INTEGER NOT NULL,
+    description TEXT,
+    PRIMARY KEY (table_id, ordinal)
+) WITHOUT ROWID;
+
+CREATE TABLE relationships (
+    id TEXT PRIMARY KEY,
+    source_table_id TEXT NOT NULL,
+    target_table_id TEXT NOT NULL,
+    label TEXT,
+    cardinality TEXT,
+    source_key TEXT,
+    target_key TEXT,
+    notes TEXT,
+    visual_metadata TEXT
+);
+
+CREATE INDEX idx_tables_name ON tables(name);
+CREATE INDEX idx_columns_name ON columns(name);
+CREATE INDEX idx_relationships_source ON relationships(source_table_id);
+CREATE INDEX idx_relationships_target ON relationships(target_table_id);
+"#;
+
+/// Errors from writing or reading a SQLite model bundle
+#[derive(Debug, thiserror::Error)]
+pub enum SqliteBundleError {
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unsupported SQLite bundle version: {0}")]
+    UnsupportedVersion(i64),
+}
+
+/// Summary of a SQLite model bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqliteBundleInfo {
+    /// Bundle name, usually the workspace name
+    pub name: String,
+    /// Bundle format version
+    pub version: i64,
+    /// When the bundle was written
+    pub created_at: DateTime<Utc>,
+    pub table_count: usize,
+    pub column_count: usize,
+    pub relationship_count: usize,
+}
+
+/// A table in a SQLite model bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleTable {
+    pub id: Uuid,
+    pub name: String,
+    pub catalog_name: Option<String>,
+    pub schema_name: Option<String>,
+    pub owner: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    /// Position on the canvas
+    pub position: Option<Position>,
+    pub column_count: usize,
+}
+
+/// A column in a SQLite model bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleColumn {
+    pub table_id: Uuid,
+    /// Position of the column in its table, from 0
+    pub ordinal: usize,
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub primary_key: bool,
+    pub description: Option<String>,
+}
+
+/// A relationship in a SQLite model bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleRelationship {
+    pub id: Uuid,
+    pub source_table_id: Uuid,
+    pub target_table_id: Uuid,
+    pub label: Option<String>,
+    /// Cardinality as in ODCS, e.g. `oneToMany`
+    pub cardinality: Option<String>,
+    pub source_key: Option<String>,
+    pub target_key: Option<String>,
+    pub notes: Option<String>,
+    /// Visual metadata (connection points, waypoints) as JSON
+    pub visual_metadata: Option<serde_json::Value>,
+}
+
+/// A table or column matching a [`SqliteModelBundle::search`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleSearchHit {
+    pub table_id: Uuid,
+    pub table_name: String,
+    /// Matching column; `None` when the table itself matched
+    pub column_name: Option<String>,
+}
+
+/// Write tables and relationships to a new SQLite model bundle at `path`
+///
+/// An existing file at `path` is replaced.
+pub fn export_sqlite(
+    path: impl AsRef<Path>,
+    name: &str,
+    tables: &[Table],
+    relationships: &[Relationship],
+) -> Result<SqliteBundleInfo, SqliteBundleError> {
+    let path = path.as_ref();
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+
+    let created_at = Utc::now();
+    let mut conn = Connection::open(path)?;
+    conn.execute_batch("PRAGMA page_size = 4096; PRAGMA journal_mode = DELETE;")?;
+
+    let tx = conn.transaction()?;
+    tx.execute_batch(SCHEMA_SQL)?;
+    {
+        let mut metadata = tx.prepare("INSERT INTO metadata (key, value) VALUES (?1, ?2)")?;
+        metadata.execute(params!["name", name])?;
+        metadata.execute(params!["created_at", created_at.to_rfc3339()])?;
+        metadata.execute(params![
+            "generator",
+            format!("data-modelling-core {}", env!("CARGO_PKG_VERSION"))
+        ])?;
+
+        let mut insert_table = tx.prepare(
+            "INSERT INTO tables (id, name, catalog_name, schema_name, owner, description, tags, \
+             position_x, position_y, column_count) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        )?;
+        let mut insert_column = tx.prepare(
+            "INSERT INTO columns (table_id, ordinal, name, data_type, nullable, primary_key, \
+             description) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )?;
+        for table in tables {
+            let tags: Vec<String> = table.tags.iter().map(|t| t.to_string()).collect();
+            insert_table.execute(params![
+                table.id.to_string(),
+                table.name,
+                table.catalog_name,
+                table.schema_name,
+                table.owner,
+                table_description(table),
+                serde_json::to_string(&tags).unwrap_or_else(|_| "[]".to_string()),
+                table.position.as_ref().map(|p| p.x),
+                table.position.as_ref().map(|p| p.y),
+                table.columns.len() as i64,
+            ])?;
+            for (ordinal, column) in table.columns.iter().enumerate() {
+                insert_column.execute(params![
+                    table.id.to_string(),
+                    ordinal as i64,
+                    column.name,
+                    column.data_type,
+                    column.nullable,
+                    column.primary_key,
+                    non_empty(&column.description),
+                ])?;
+            }
+        }
+
+        let mut insert_relationship = tx.prepare(
+            "INSERT INTO relationships (id, source_table_id, target_table_id, label, \
+             cardinality, source_key, target_key, notes, visual_metadata) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )?;
+        for relationship in relationships {
+            let cardinality = relationship
+                .cardinality
+                .and_then(|c| serde_json::to_value(c).ok())
+                .and_then(|v| v.as_str().map(str::to_string));
+            let visual_metadata = relationship
+                .visual_metadata
+                .as_ref()
+                .and_then(|v| serde_json::to_string(v).ok());
+            insert_relationship.execute(params![
+                relationship.id.to_string(),
+                relationship.source_table_id.to_string(),
+                relationship.target_table_id.to_string(),
+                relationship.label,
+                cardinality,
+                relationship.source_key,
+                relationship.target_key,
+                relationship.notes,
+                visual_metadata,
+            ])?;
+        }
+    }
+    tx.pragma_update(None, "user_version", SQLITE_BUNDLE_VERSION)?;
+    tx.commit()?;
+    conn.execute_batch("ANALYZE; VACUUM;")?;
+
+    Ok(SqliteBundleInfo {
+        name: name.to_string(),
+        version: SQLITE_BUNDLE_VERSION,
+        created_at,
+        table_count: tables.len(),
+        column_count: tables.iter().map(|t| t.columns.len()).sum(),
+        relationship_count: relationships.len(),
+    })
+}
+
+/// Load a workspace through a storage backend and write it to a SQLite model bundle
+///
+/// Tables are read from the workspace's ODCS files and relationships from
+/// `relationships.yaml`; relationships to missing tables are left out. The
+/// bundle is named after the last segment of `workspace_path`.
+pub async fn export_workspace_sqlite<B: StorageBackend>(
+    storage: &B,
+    workspace_path: &str,
+    path: impl AsRef<Path>,
+) -> Result<SqliteBundleInfo, SqliteBundleError> {
+    let loaded = ModelLoader::new(storage).load_model(workspace_path).await?;
+
+    let mut tables = Vec::new();
+    for table_data in &loaded.tables {
+        let (table, _) = ODCSImporter::new()
+            .parse_table(&table_data.yaml_content)
+            .map_err(|e| {
+                StorageError::SerializationError(format!(
+                    "Failed to parse table {}: {}",
+                    table_data.name, e
+                ))
+            })?;
+        tables.push(table);
+    }
+
+    // The loader only keeps relationship endpoints; take the details from the file
+    let mut details = relationship_details(storage, workspace_path).await?;
+    let relationships: Vec<Relationship> = loaded
+        .relationships
+        .iter()
+        .map(|r| {
+            details.remove(&r.id).unwrap_or_else(|| {
+                let mut relationship = Relationship::new(r.source_table_id, r.target_table_id);
+                relationship.id = r.id;
+                relationship
+            })
+        })
+        .collect();
+
+    let name = workspace_path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|n| !n.is_empty())
+        .unwrap_or("workspace");
+    export_sqlite(path, name, &tables, &relationships)
+}
+
+/// Fully parsed relationships of `relationships.yaml`, by id
+async fn relationship_details<B: StorageBackend>(
+    storage: &B,
+    workspace_path: &str,
+) -> Result<HashMap<Uuid, Relationship>, StorageError> {
+    let file = format!("{}/relationships.yaml", workspace_path);
+    if !storage.file_exists(&file).await? {
+        return Ok(HashMap::new());
+    }
+    let content = storage.read_file(&file).await?;
+    let data: serde_yaml::Value = serde_yaml::from_slice(&content)
+        .map_err(|e| StorageError::SerializationError(format!("Failed to parse YAML: {}", e)))?;
+    let entries = data
+        .get("relationships")
+        .and_then(|v| v.as_sequence())
+        .or_else(|| data.as_sequence())
+        .cloned()
+        .unwrap_or_default();
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| serde_yaml::from_value::<Relationship>(entry).ok())
+        .map(|relationship| (relationship.id, relationship))
+        .collect())
+}
+
+/// Read-only access to a SQLite model bundle
+pub struct SqliteModelBundle {
+    conn: Connection,
+}
+
+impl SqliteModelBundle {
+    /// Open a bundle read-only
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SqliteBundleError> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        if version == 0 || version > SQLITE_BUNDLE_VERSION {
+            return Err(SqliteBundleError::UnsupportedVersion(version));
+        }
+        Ok(Self { conn })
+    }
+
+    /// Summary of the bundle
+    pub fn info(&self) -> Result<SqliteBundleInfo, SqliteBundleError> {
+        let metadata = |key: &str| -> Result<Option<String>, rusqlite::Error> {
+            self.conn
+                .query_row("SELECT value FROM metadata WHERE key = ?1", [key], |row| {
+                    row.get(0)
+                })
+                .optional()
+        };
+        let count = |table: &str| -> Result<usize, rusqlite::Error> {
+            self.conn
+                .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                    row.get::<_, i64>(0)
+                })
+                .map(|n| n as usize)
+        };
+        Ok(SqliteBundleInfo {
+            name: metadata("name")?.unwrap_or_default(),
+            version: self
+                .conn
+                .pragma_query_value(None, "user_version", |row| row.get(0))?,
+            created_at: metadata("created_at")?
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_default(),
+            table_count: count("tables")?,
+            column_count: count("columns")?,
+            relationship_count: count("relationships")?,
+        })
+    }
+
+    /// All tables, sorted by name
+    pub fn tables(&self) -> Result<Vec<BundleTable>, SqliteBundleError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tables ORDER BY name, id",
+            TABLE_COLUMNS
+        ))?;
+        let tables = stmt
+            .query_map([], table_from_row)?
+            .collect::<Result<_, _>>()?;
+        Ok(tables)
+    }
+
+    /// The table with the given name
+    pub fn table(&self, name: &str) -> Result<Option<BundleTable>, SqliteBundleError> {
+        Ok(self
+            .conn
+            .query_row(
+                &format!("SELECT {} FROM tables WHERE name = ?1", TABLE_COLUMNS),
+                [name],
+                table_from_row,
+            )
+            .optional()?)
+    }
+
+    /// Columns of a table, in order
+    pub fn columns(&self, table_id: Uuid) -> Result<Vec<BundleColumn>, SqliteBundleError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT table_id, ordinal, name, data_type, nullable, primary_key, description \
+             FROM columns WHERE table_id = ?1 ORDER BY ordinal",
+        )?;
+        let columns = stmt
+            .query_map([table_id.to_string()], |row| {
+                Ok(BundleColumn {
+                    table_id: uuid_from(row, 0)?,
+                    ordinal: row.get::<_, i64>(1)? as usize,
+                    name: row.get(2)?,
+                    data_type: row.get(3)?,
+                    nullable: row.get(4)?,
+                    primary_key: row.get(5)?,
+                    description: row.get(6)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(columns)
+    }
+
+    /// All relationships
+    pub fn relationships(&self) -> Result<Vec<BundleRelationship>, SqliteBundleError> {
+        self.query_relationships("", None)
+    }
+
+    /// Relationships from or to a table
+    pub fn relationships_of(
+        &self,
+        table_id: Uuid,
+    ) -> Result<Vec<BundleRelationship>, SqliteBundleError> {
+        self.query_relationships(
+            "WHERE source_table_id = ?1 OR target_table_id = ?1",
+            Some(table_id),
+        )
+    }
+
+    /// Tables and columns whose name or description contains `text`,
+    /// ignoring ASCII case
+    pub fn search(&self, text: &str) -> Result<Vec<BundleSearchHit>, SqliteBundleError> {
+        let pattern = format!(
+            "%{}%",
+            text.replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, NULL FROM tables \
+             WHERE name LIKE ?1 ESCAPE '\\' OR description LIKE ?1 ESCAPE '\\' \
+             UNION ALL \
+             SELECT t.id, t.name, c.name FROM columns c JOIN tables t ON t.id = c.table_id \
+             WHERE c.name LIKE ?1 ESCAPE '\\' OR c.description LIKE ?1 ESCAPE '\\' \
+             ORDER BY 2, 3",
+        )?;
+        let hits = stmt
+            .query_map([pattern], |row| {
+                Ok(BundleSearchHit {
+                    table_id: uuid_from(row, 0)?,
+                    table_name: row.get(1)?,
+                    column_name: row.get(2)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(hits)
+    }
+
+    fn query_relationships(
+        &self,
+        filter: &str,
+        table_id: Option<Uuid>,
+    ) -> Result<Vec<BundleRelationship>, SqliteBundleError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT id, source_table_id, target_table_id, label, cardinality, source_key, \
+             target_key, notes, visual_metadata FROM relationships {} ORDER BY id",
+            filter
+        ))?;
+        let map = |row: &Row<'_>| {
+            let visual_metadata: Option<String> = row.get(8)?;
+            Ok(BundleRelationship {
+                id: uuid_from(row, 0)?,
+                source_table_id: uuid_from(row, 1)?,
+                target_table_id: uuid_from(row, 2)?,
+                label: row.get(3)?,
+                cardinality: row.get(4)?,
+                source_key: row.get(5)?,
+                target_key: row.get(6)?,
+                notes: row.get(7)?,
+                visual_metadata: visual_metadata.and_then(|v| serde_json::from_str(&v).ok()),
+            })
+        };
+        let relationships: Result<Vec<_>, _> = match table_id {
+            Some(id) => stmt.query_map([id.to_string()], map)?.collect(),
+            None => stmt.query_map([], map)?.collect(),
+        };
+        Ok(relationships?)
+    }
+}
+
+const TABLE_COLUMNS: &str = "id, name, catalog_name, schema_name, owner, description, tags, \
+                             position_x, position_y, column_count";
+
+fn table_from_row(row: &Row<'_>) -> Result<BundleTable, rusqlite::Error> {
+    let tags: String = row.get(6)?;
+    let x: Option<f64> = row.get(7)?;
+    let y: Option<f64> = row.get(8)?;
+    Ok(BundleTable {
+        id: uuid_from(row, 0)?,
+        name: row.get(1)?,
+        catalog_name: row.get(2)?,
+        schema_name: row.get(3)?,
+        owner: row.get(4)?,
+        description: row.get(5)?,
+        tags: serde_json::from_str(&tags).unwrap_or_default(),
+        position: x.zip(y).map(|(x, y)| Position { x, y }),
+        column_count: row.get::<_, i64>(9)? as usize,
+    })
+}
+
+fn uuid_from(row: &Row<'_>, index: usize) -> Result<Uuid, rusqlite::Error> {
+    let value: String = row.get(index)?;
+    Uuid::parse_str(&value).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(index, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+/// Description of a table: the ODCS description, or its purpose
+fn table_description(table: &Table) -> Option<String> {
+    match table.odcl_metadata.get("description")? {
+        serde_json::Value::String(s) => non_empty(s),
+        serde_json::Value::Object(o) => o
+            .get("purpose")
+            .or_else(|| o.get("usage"))
+            .and_then(|v| v.as_str())
+            .and_then(non_empty),
+        _ => None,
+    }
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::enums::Cardinality;
+    use crate::models::{Column, Tag};
+    use crate::storage::memory::MemoryStorageBackend;
+
+    fn model() -> (Vec<Table>, Vec<Relationship>) {
+        let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+        id.primary_key = true;
+        id.nullable = false;
+        let mut customer_id = Column::new("customer_id".to_string(), "BIGINT".to_string());
+        customer_id.description = "Customer placing the order".to_string();
+        let mut orders = Table::new("orders".to_string(), vec![id, customer_id]);
+        orders.tags.push(Tag::Simple("sales".to_string()));
+        orders.position = Some(Position { x: 10.0, y: 20.0 });
+        let customers = Table::new(
+            "customers".to_string(),
+            vec![Column::new("id".to_string(), "BIGINT".to_string())],
+        );
+
+        let mut relationship = Relationship::new(orders.id, customers.id);
+        relationship.label = Some("placed by".to_string());
+        relationship.cardinality = Some(Cardinality::ManyToOne);
+        (vec![orders, customers], vec![relationship])
+    }
+
+    #[test]
+    fn test_export_and_read_bundle() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sales.odm.sqlite");
+        let (tables, relationships) = model();
+
+        let written = export_sqlite(&path, "sales", &tables, &relationships).unwrap();
+        assert_eq!(written.table_count, 2);
+        assert_eq!(written.column_count, 3);
+
+        let bundle = SqliteModelBundle::open(&path).unwrap();
+        let info = bundle.info().unwrap();
+        assert_eq!(info.name, "sales");
+        assert_eq!(info.version, SQLITE_BUNDLE_VERSION);
+        assert_eq!(info.relationship_count, 1);
+
+        let names: Vec<String> = bundle
+            .tables()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["customers", "orders"]);
+
+        let orders = bundle.table("orders").unwrap().unwrap();
+        assert_eq!(orders.id, tables[0].id);
+        assert_eq!(orders.tags, vec!["sales"]);
+        assert_eq!(orders.position, Some(Position { x: 10.0, y: 20.0 }));
+        let columns = bundle.columns(orders.id).unwrap();
+        assert_eq!(columns.len(), 2);
+        assert!(columns[0].primary_key && !columns[0].nullable);
+        assert_eq!(
+            columns[1].description.as_deref(),
+            Some("Customer placing the order")
+        );
+
+        let related = bundle.relationships_of(orders.id).unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].label.as_deref(), Some("placed by"));
+        assert_eq!(related[0].cardinality.as_deref(), Some("manyToOne"));
+
+        let hits = bundle.search("CUSTOMER").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].table_name, "customers");
+        assert_eq!(hits[1].column_name.as_deref(), Some("customer_id"));
+        assert!(bundle.search("100%").unwrap().is_empty());
+        assert!(bundle.table("missing").unwrap().is_none());
+
+        // Re-exporting replaces the bundle
+        export_sqlite(&path, "sales", &tables[..1], &[]).unwrap();
+        let bundle = SqliteModelBundle::open(&path).unwrap();
+        assert_eq!(bundle.info().unwrap().table_count, 1);
+    }
+
+    #[test]
+    fn test_open_rejects_other_databases() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("other.sqlite");
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE t (x INTEGER);")
+            .unwrap();
+        assert!(matches!(
+            SqliteModelBundle::open(&path),
+            Err(SqliteBundleError::UnsupportedVersion(0))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_export_workspace() {
+        let orders = Table::new(
+            "orders".to_string(),
+            vec![Column::new("id".to_string(), "BIGINT".to_string())],
+        );
+        let contract = crate::export::ODCSExporter::export_table(&orders, "odcs_v3_1_0");
+        let orphan = Relationship::new(orders.id, Uuid::new_v4());
+        let storage = MemoryStorageBackend::new()
+            .with_file("ws/orders.odcs.yaml", contract)
+            .with_file(
+                "ws/relationships.yaml",
+                serde_yaml::to_string(&serde_json::json!({ "relationships": [orphan] })).unwrap(),
+            );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ws.odm.sqlite");
+        let info = export_workspace_sqlite(&storage, "ws", &path)
+            .await
+            .unwrap();
+        assert_eq!(info.name, "ws");
+        assert_eq!(info.table_count, 1);
+        assert_eq!(
+            info.relationship_count, 0,
+            "orphaned relationships are left out"
+        );
+
+        let bundle = SqliteModelBundle::open(&path).unwrap();
+        assert_eq!(bundle.table("orders").unwrap().unwrap().column_count, 1);
+    }
+}
@@ -0,0 +1,657 @@
+//! Batch export of a workspace
+//!
+//! Exports every ODCS contract in a workspace directory to several formats in
+//! one pass and writes a manifest describing the generated files:
+//!
+//! ```text
+//! {out_dir}/
+//! ├── manifest.json
+//! ├── avro/
+//! │   └── {contract}.avsc
+//! ├── json-schema/
+//! │   └── {contract}.schema.json
+//! └── sql/
+//!     └── {contract}.sql
+//! ```
+//!
+//! `$ref`s to the workspace's reusable property definitions (`definitions.yaml`
+//! in the workspace directory) are resolved before exporting, and the workspace's
+//! physical name mappings (`physical-names.yaml`) are applied for the platform of
+//! each format unless the options already carry mappings.
+//!
+//! Contracts are parsed and exported in parallel on native targets. A contract
+//! that fails to parse or export is recorded in the manifest and does not stop
+//! the rest of the workspace from being exported.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::export::workspace::export_workspace;
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//!
+//! let backend = FileSystemStorageBackend::new("/path/to/repo");
+//! let manifest = export_workspace(&backend, "workspace", &["avro", "sql"], "dist").await?;
+//! println!("{} files, {} errors", manifest.file_count(), manifest.error_count());
+//! ```
+
+use super::ExportError;
+use super::registry::{ExportOptions, Exporter, ExporterRegistry, export_contract_with};
+use crate::models::definitions::{DEFINITIONS_FILE, PropertyDefinitions};
+use crate::models::odcs::ODCSContract;
+use crate::models::physical_names::{PHYSICAL_NAMES_FILE, PhysicalNameMap};
+use crate::storage::StorageBackend;
+use crate::storage::dry_run::{Changeset, DryRunStorage};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Name of the manifest written to the output directory
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Formats exported when none are given
+pub const DEFAULT_FORMATS: &[&str] = &["avro", "json-schema", "sql"];
+
+/// A file generated for an asset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedFile {
+    /// Export format name
+    pub format: String,
+    /// Path of the generated file, relative to the storage backend
+    pub path: String,
+}
+
+/// Export outcome of a single ODCS asset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedAsset {
+    /// Path of the source ODCS file
+    pub source: String,
+    /// Contract name (empty if the file could not be parsed)
+    pub name: String,
+    /// Names of the tables in the contract
+    pub tables: Vec<String>,
+    /// Generated files, one per successful format
+    pub files: Vec<ExportedFile>,
+    /// Parse and export errors
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+/// Manifest of a workspace export
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceExportManifest {
+    /// When the export was started
+    pub generated_at: DateTime<Utc>,
+    /// Workspace directory that was exported
+    pub root: String,
+    /// Exported formats
+    pub formats: Vec<String>,
+    /// Exported assets, sorted by source path
+    pub assets: Vec<ExportedAsset>,
+    /// Duration of the export in milliseconds
+    pub duration_ms: u64,
+}
+
+impl WorkspaceExportManifest {
+    /// Total number of generated files
+    pub fn file_count(&self) -> usize {
+        self.assets.iter().map(|asset| asset.files.len()).sum()
+    }
+
+    /// Total number of errors across all assets
+    pub fn error_count(&self) -> usize {
+        self.assets.iter().map(|asset| asset.errors.len()).sum()
+    }
+
+    /// Whether every asset was exported to every format
+    pub fn is_success(&self) -> bool {
+        self.error_count() == 0
+    }
+}
+
+/// Export every ODCS asset in `root` to the given formats
+///
+/// Uses the built-in [`ExporterRegistry`] and default [`ExportOptions`]. An empty
+/// format list exports [`DEFAULT_FORMATS`].
+pub async fn export_workspace<B: StorageBackend + ?Sized>(
+    backend: &B,
+    root: &str,
+    formats: &[&str],
+    out_dir: &str,
+) -> Result<WorkspaceExportManifest, ExportError> {
+    export_workspace_with(
+        backend,
+        root,
+        formats,
+        out_dir,
+        &ExporterRegistry::default(),
+        &ExportOptions::default(),
+    )
+    .await
+}
+
+/// Preview exporting every ODCS asset in `root` without writing anything
+///
+/// Runs [`export_workspace_with`] against a [`DryRunStorage`] and returns the
+/// manifest together with the files that would be created or modified. The
+/// manifest itself always differs, as it records when the export ran.
+pub async fn preview_workspace_export<B: StorageBackend + ?Sized>(
+    backend: &B,
+    root: &str,
+    formats: &[&str],
+    out_dir: &str,
+    registry: &ExporterRegistry,
+    options: &ExportOptions,
+) -> Result<(WorkspaceExportManifest, Changeset), ExportError> {
+    let preview = DryRunStorage::new(backend);
+    let manifest =
+        export_workspace_with(&preview, root, formats, out_dir, registry, options).await?;
+    Ok((manifest, preview.changeset()))
+}
+
+/// Export every ODCS asset in `root` using a custom registry and options
+///
+/// Unknown formats are rejected before anything is read. Storage errors abort
+/// the export; parse and export errors are recorded per asset in the manifest.
+pub async fn export_workspace_with<B: StorageBackend + ?Sized>(
+    backend: &B,
+    root: &str,
+    formats: &[&str],
+    out_dir: &str,
+    registry: &ExporterRegistry,
+    options: &ExportOptions,
+) -> Result<WorkspaceExportManifest, ExportError> {
+    let started = Utc::now();
+    let formats = if formats.is_empty() {
+        DEFAULT_FORMATS
+    } else {
+        formats
+    };
+
+    let mut exporters: Vec<Arc<dyn Exporter>> = Vec::new();
+    for format in formats {
+        let exporter = registry.get(format).ok_or_else(|| {
+            ExportError::InvalidArgument(format!(
+                "Unknown export format: {}. Available: {}",
+                format,
+                registry.names().join(", ")
+            ))
+        })?;
+        if !exporters.iter().any(|e| e.name() == exporter.name()) {
+            exporters.push(exporter);
+        }
+    }
+
+    let root = root.trim_end_matches('/');
+    let out_dir = out_dir.trim_end_matches('/');
+
+    let mut names: Vec<String> = backend
+        .list_files(root)
+        .await
+        .map_err(storage_error)?
+        .into_iter()
+        .filter(|name| odcs_stem(name).is_some())
+        .collect();
+    names.sort();
+
+    let mut sources = Vec::with_capacity(names.len());
+    for name in names {
+        let path = join(root, &name);
+        let content = backend.read_file(&path).await.map_err(storage_error)?;
+        sources.push(Source {
+            path,
+            stem: odcs_stem(&name).unwrap_or(&name).to_string(),
+            content: String::from_utf8_lossy(&content).into_owned(),
+        });
+    }
+
+    let definitions_path = join(root, DEFINITIONS_FILE);
+    let definitions = if backend
+        .file_exists(&definitions_path)
+        .await
+        .map_err(storage_error)?
+    {
+        let content = backend
+            .read_file(&definitions_path)
+            .await
+            .map_err(storage_error)?;
+        let definitions = PropertyDefinitions::from_yaml(&String::from_utf8_lossy(&content))
+            .map_err(|e| {
+                ExportError::SerializationError(format!(
+                    "Failed to parse {}: {}",
+                    definitions_path, e
+                ))
+            })?;
+        Some(definitions)
+    } else {
+        None
+    };
+
+    let physical_names_path = join(root, PHYSICAL_NAMES_FILE);
+    let workspace_options;
+    let options = if options.physical_names.is_none()
+        && backend
+            .file_exists(&physical_names_path)
+            .await
+            .map_err(storage_error)?
+    {
+        let content = backend
+            .read_file(&physical_names_path)
+            .await
+            .map_err(storage_error)?;
+        let names =
+            PhysicalNameMap::from_yaml(&String::from_utf8_lossy(&content)).map_err(|e| {
+                ExportError::SerializationError(format!(
+                    "Failed to parse {}: {}",
+                    physical_names_path, e
+                ))
+            })?;
+        workspace_options = options.clone().with_physical_names(names);
+        &workspace_options
+    } else {
+        options
+    };
+
+    let rendered = render_all(&sources, &exporters, options, definitions.as_ref());
+
+    for exporter in &exporters {
+        let dir = join(out_dir, exporter.name());
+        if !backend.dir_exists(&dir).await.map_err(storage_error)? {
+            backend.create_dir(&dir).await.map_err(storage_error)?;
+        }
+    }
+
+    let mut assets = Vec::with_capacity(rendered.len());
+    for (source, output) in sources.iter().zip(rendered) {
+        let mut files = Vec::with_capacity(output.outputs.len());
+        for (exporter, content) in output.outputs {
+            let path = join(
+                &join(out_dir, exporter.name()),
+                &format!("{}.{}", source.stem, exporter.file_extension()),
+            );
+            backend
+                .write_file(&path, content.as_bytes())
+                .await
+                .map_err(storage_error)?;
+            files.push(ExportedFile {
+                format: exporter.name().to_string(),
+                path,
+            });
+        }
+        assets.push(ExportedAsset {
+            source: source.path.clone(),
+            name: output.name,
+            tables: output.tables,
+            files,
+            errors: output.errors,
+        });
+    }
+
+    let manifest = WorkspaceExportManifest {
+        generated_at: started,
+        root: root.to_string(),
+        formats: exporters.iter().map(|e| e.name().to_string()).collect(),
+        assets,
+        duration_ms: (Utc::now() - started).num_milliseconds().max(0) as u64,
+    };
+
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| ExportError::SerializationError(e.to_string()))?;
+    backend
+        .write_file(&join(out_dir, MANIFEST_FILE), json.as_bytes())
+        .await
+        .map_err(storage_error)?;
+
+    Ok(manifest)
+}
+
+/// An ODCS file read from the workspace
+struct Source {
+    path: String,
+    stem: String,
+    content: String,
+}
+
+/// Exported content of one source, before it is written
+struct Rendered {
+    name: String,
+    tables: Vec<String>,
+    outputs: Vec<(Arc<dyn Exporter>, String)>,
+    errors: Vec<String>,
+}
+
+fn render(
+    source: &Source,
+    exporters: &[Arc<dyn Exporter>],
+    options: &ExportOptions,
+    definitions: Option<&PropertyDefinitions>,
+) -> Rendered {
+    let contract = match parse_contract(&source.content, definitions) {
+        Ok(contract) => contract,
+        Err(e) => {
+            return Rendered {
+                name: String::new(),
+                tables: Vec::new(),
+                outputs: Vec::new(),
+                errors: vec![format!("Failed to parse {}: {}", source.path, e)],
+            };
+        }
+    };
+
+    let mut rendered = Rendered {
+        name: contract.name.clone(),
+        tables: contract.schema.iter().map(|s| s.name.clone()).collect(),
+        outputs: Vec::with_capacity(exporters.len()),
+        errors: Vec::new(),
+    };
+    for exporter in exporters {
+        match export_contract_with(exporter.as_ref(), &contract, options) {
+            Ok(result) => rendered
+                .outputs
+                .push((Arc::clone(exporter), result.content)),
+            Err(e) => rendered.errors.push(format!("{}: {}", exporter.name(), e)),
+        }
+    }
+    rendered
+}
+
+/// Render all sources, spreading the work over the available cores
+#[cfg(not(target_arch = "wasm32"))]
+fn render_all(
+    sources: &[Source],
+    exporters: &[Arc<dyn Exporter>],
+    options: &ExportOptions,
+    definitions: Option<&PropertyDefinitions>,
+) -> Vec<Rendered> {
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    if threads <= 1 || sources.len() <= 1 {
+        return sources
+            .iter()
+            .map(|source| render(source, exporters, options, definitions))
+            .collect();
+    }
+
+    let chunk_size = sources.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = sources
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|source| render(source, exporters, options, definitions))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("export worker panicked"))
+            .collect()
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn render_all(
+    sources: &[Source],
+    exporters: &[Arc<dyn Exporter>],
+    options: &ExportOptions,
+    definitions: Option<&PropertyDefinitions>,
+) -> Vec<Rendered> {
+    sources
+        .iter()
+        .map(|source| render(source, exporters, options, definitions))
+        .collect()
+}
+
+/// Parse an ODCS contract, inlining workspace property definitions
+fn parse_contract(
+    content: &str,
+    definitions: Option<&PropertyDefinitions>,
+) -> Result<ODCSContract, String> {
+    let Some(definitions) = definitions else {
+        return serde_yaml::from_str(content).map_err(|e| e.to_string());
+    };
+    let mut document: serde_json::Value =
+        serde_yaml::from_str(content).map_err(|e| e.to_string())?;
+    definitions.resolve(&mut document)?;
+    serde_json::from_value(document).map_err(|e| e.to_string())
+}
+
+fn odcs_stem(name: &str) -> Option<&str> {
+    name.strip_suffix(".odcs.yaml")
+        .or_else(|| name.strip_suffix(".odcs.yml"))
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+fn storage_error(err: crate::storage::StorageError) -> ExportError {
+    ExportError::IoError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::StorageError;
+    use crate::storage::dry_run::FileChangeKind;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStorage {
+        files: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    impl MemoryStorage {
+        fn read(&self, path: &str) -> Option<String> {
+            self.files
+                .lock()
+                .unwrap()
+                .get(path)
+                .map(|content| String::from_utf8(content.clone()).unwrap())
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl StorageBackend for MemoryStorage {
+        async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .get(path)
+                .cloned()
+                .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+        }
+
+        async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .insert(path.to_string(), content.to_vec());
+            Ok(())
+        }
+
+        async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+            let prefix = format!("{}/", dir);
+            Ok(self
+                .files
+                .lock()
+                .unwrap()
+                .keys()
+                .filter_map(|path| path.strip_prefix(&prefix))
+                .filter(|name| !name.contains('/'))
+                .map(str::to_string)
+                .collect())
+        }
+
+        async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+            Ok(self.files.lock().unwrap().contains_key(path))
+        }
+
+        async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .remove(path)
+                .map(|_| ())
+                .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+        }
+
+        async fn create_dir(&self, _path: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+
+        async fn dir_exists(&self, _path: &str) -> Result<bool, StorageError> {
+            Ok(false)
+        }
+    }
+
+    const ORDERS: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: 6f1c2a4e-0000-4000-8000-000000000001
+version: 1.0.0
+status: active
+name: orders
+schema:
+  - name: orders
+    properties:
+      - name: id
+        logicalType: integer
+        required: true
+      - name: total
+        logicalType: number
+      - $ref: definitions.yaml#/definitions/tenant_id
+"#;
+
+    #[tokio::test]
+    async fn test_export_workspace_writes_files_and_manifest() {
+        let storage = MemoryStorage::default();
+        storage
+            .write_file("ws/acme_sales_orders.odcs.yaml", ORDERS.as_bytes())
+            .await
+            .unwrap();
+        storage
+            .write_file("ws/broken.odcs.yaml", b"schema: [")
+            .await
+            .unwrap();
+        storage
+            .write_file(
+                "ws/definitions.yaml",
+                b"definitions:\n  tenant_id:\n    logicalType: string\n",
+            )
+            .await
+            .unwrap();
+        storage
+            .write_file("ws/notes.md", b"# not a contract")
+            .await
+            .unwrap();
+
+        let manifest = export_workspace(&storage, "ws", &["avro", "sql"], "dist")
+            .await
+            .unwrap();
+
+        assert_eq!(manifest.formats, vec!["avro", "sql"]);
+        assert_eq!(manifest.assets.len(), 2);
+        assert_eq!(manifest.file_count(), 2);
+        assert_eq!(manifest.error_count(), 1);
+        assert!(!manifest.is_success());
+
+        let orders = &manifest.assets[0];
+        assert_eq!(orders.source, "ws/acme_sales_orders.odcs.yaml");
+        assert_eq!(orders.tables, vec!["orders"]);
+        assert!(orders.errors.is_empty());
+        assert!(
+            storage
+                .read("dist/avro/acme_sales_orders.avsc")
+                .unwrap()
+                .contains("\"tenant_id\"")
+        );
+        assert!(
+            storage
+                .read("dist/sql/acme_sales_orders.sql")
+                .unwrap()
+                .contains("CREATE TABLE")
+        );
+
+        let written: WorkspaceExportManifest =
+            serde_json::from_str(&storage.read("dist/manifest.json").unwrap()).unwrap();
+        assert_eq!(written, manifest);
+    }
+
+    #[tokio::test]
+    async fn test_preview_workspace_export_writes_nothing() {
+        let storage = MemoryStorage::default();
+        storage
+            .write_file("ws/orders.odcs.yaml", ORDERS.as_bytes())
+            .await
+            .unwrap();
+        storage
+            .write_file(
+                "ws/definitions.yaml",
+                b"definitions:\n  tenant_id:\n    logicalType: string\n",
+            )
+            .await
+            .unwrap();
+        storage
+            .write_file("dist/sql/orders.sql", b"-- stale\n")
+            .await
+            .unwrap();
+
+        let (manifest, changeset) = preview_workspace_export(
+            &storage,
+            "ws",
+            &["avro", "sql"],
+            "dist",
+            &ExporterRegistry::default(),
+            &ExportOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(manifest.file_count(), 2);
+        let kinds: Vec<_> = changeset
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("dist/avro/orders.avsc", FileChangeKind::Created),
+                ("dist/manifest.json", FileChangeKind::Created),
+                ("dist/sql/orders.sql", FileChangeKind::Modified),
+            ]
+        );
+        assert!(
+            changeset.files[2]
+                .diff
+                .as_deref()
+                .unwrap()
+                .contains("--- stale")
+        );
+        assert!(storage.read("dist/avro/orders.avsc").is_none());
+        assert_eq!(storage.read("dist/sql/orders.sql").unwrap(), "-- stale\n");
+    }
+
+    #[tokio::test]
+    async fn test_export_workspace_rejects_unknown_format() {
+        let storage = MemoryStorage::default();
+        let err = export_workspace(&storage, "ws", &["avro", "parquet"], "dist")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExportError::InvalidArgument(_)));
+        assert!(storage.read("dist/manifest.json").is_none());
+    }
+}
@@ -0,0 +1,838 @@
+//! Synthetic test data generation from data contracts
+//!
+//! [`RecordGenerator`] produces example records that conform to an ODCS contract
+//! schema or a JSON Schema document. Generated values honor the declared types,
+//! string formats (`email`, `uuid`, `uri`, `date`, `date-time`, ...), enum values,
+//! `minimum`/`maximum` bounds, string length limits and required-ness; optional
+//! fields are included at a configurable rate. Regex `pattern` constraints are not
+//! interpreted.
+//!
+//! Generation is deterministic: the same seed always yields the same records, so
+//! the output can be checked in as fixtures for consumer-driven contract tests.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::generate::{RecordGenerator, records_to_csv};
+//! use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+//!
+//! let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+//!     SchemaObject::new("orders").with_properties(vec![
+//!         Property::new("id", "integer").with_primary_key(true),
+//!         Property::new("status", "string")
+//!             .with_required(true)
+//!             .with_enum_values(vec!["open".to_string(), "shipped".to_string()]),
+//!     ]),
+//! );
+//!
+//! let records = RecordGenerator::new(7).generate_contract(&contract, None, 3).unwrap();
+//! assert_eq!(records.len(), 3);
+//! assert!(records.iter().all(|r| r["id"].is_i64()));
+//!
+//! // Same seed, same records
+//! let again = RecordGenerator::new(7).generate_contract(&contract, None, 3).unwrap();
+//! assert_eq!(records, again);
+//!
+//! let csv = records_to_csv(&records);
+//! assert!(csv.starts_with("id,status\n"));
+//! ```
+
+use crate::models::odcs::{ODCSContract, Property, SchemaObject};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use serde_json::{Map, Number, Value};
+
+/// Default seed used by [`RecordGenerator::default`]
+pub const DEFAULT_SEED: u64 = 42;
+
+/// Default share of optional fields included in a record
+const DEFAULT_OPTIONAL_RATIO: f64 = 0.5;
+
+/// Default maximum number of generated array items
+const DEFAULT_MAX_ITEMS: usize = 3;
+
+/// Span of generated numbers when only one bound (or none) is declared
+const DEFAULT_NUMBER_SPAN: f64 = 1000.0;
+
+/// Span of generated dates, in days, when no bounds are declared
+const DEFAULT_DAY_SPAN: i64 = 1825;
+
+/// Nesting depth at which `$ref` cycles in JSON Schemas stop being followed
+const MAX_REF_DEPTH: usize = 8;
+
+/// Error raised when records cannot be generated
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum GenerateError {
+    #[error("Contract has no schema objects")]
+    NoSchema,
+    #[error("Schema object not found: {0}")]
+    SchemaNotFound(String),
+    #[error("Invalid JSON Schema: {0}")]
+    InvalidJsonSchema(String),
+}
+
+/// Seedable generator of example records
+#[derive(Debug, Clone)]
+pub struct RecordGenerator {
+    rng: SplitMix64,
+    optional_ratio: f64,
+    max_items: usize,
+}
+
+impl Default for RecordGenerator {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl RecordGenerator {
+    /// Create a generator with the given seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SplitMix64::new(seed),
+            optional_ratio: DEFAULT_OPTIONAL_RATIO,
+            max_items: DEFAULT_MAX_ITEMS,
+        }
+    }
+
+    /// Share of optional fields included in each record, from 0.0 (only required
+    /// fields) to 1.0 (every field); default 0.5
+    pub fn with_optional_ratio(mut self, ratio: f64) -> Self {
+        self.optional_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Maximum number of items generated for arrays without `maxItems` (default 3)
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items;
+        self
+    }
+
+    /// Generate `count` records for a schema object of a contract
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The data contract
+    /// * `schema_name` - Name of the schema object; the first one when `None`
+    /// * `count` - Number of records to generate
+    pub fn generate_contract(
+        &mut self,
+        contract: &ODCSContract,
+        schema_name: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<Value>, GenerateError> {
+        let schema = match schema_name {
+            Some(name) => contract
+                .schema
+                .iter()
+                .find(|s| s.name == name)
+                .ok_or_else(|| GenerateError::SchemaNotFound(name.to_string()))?,
+            None => contract.schema.first().ok_or(GenerateError::NoSchema)?,
+        };
+        Ok(self.generate_schema(schema, count))
+    }
+
+    /// Generate `count` records for an ODCS schema object
+    pub fn generate_schema(&mut self, schema: &SchemaObject, count: usize) -> Vec<Value> {
+        let spec = ValueSpec::object(schema.properties.iter().map(FieldSpec::from_property));
+        (0..count).map(|_| self.value(&spec)).collect()
+    }
+
+    /// Generate `count` values conforming to a JSON Schema document
+    ///
+    /// Local `$ref`s (`#/definitions/...`, `#/$defs/...`) are resolved against the
+    /// document.
+    pub fn generate_json_schema(
+        &mut self,
+        schema: &Value,
+        count: usize,
+    ) -> Result<Vec<Value>, GenerateError> {
+        let spec = ValueSpec::from_json_schema(schema, schema, 0)?;
+        Ok((0..count).map(|_| self.value(&spec)).collect())
+    }
+
+    fn value(&mut self, spec: &ValueSpec) -> Value {
+        if !spec.enum_values.is_empty() {
+            let index = self.rng.below(spec.enum_values.len() as u64) as usize;
+            return spec.enum_values[index].clone();
+        }
+        match &spec.kind {
+            Kind::String => Value::String(self.string(spec)),
+            Kind::Integer => Value::from(self.integer(spec)),
+            Kind::Number => Number::from_f64(self.number(spec))
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            Kind::Boolean => Value::Bool(self.rng.below(2) == 1),
+            Kind::Date => Value::String(self.date(spec).format("%Y-%m-%d").to_string()),
+            Kind::Timestamp => Value::String(self.timestamp(spec).to_rfc3339()),
+            Kind::Time => Value::String(self.time()),
+            Kind::Object(fields) => {
+                let mut record = Map::new();
+                for field in fields {
+                    if field.required || self.rng.unit() < self.optional_ratio {
+                        let value = self.value(&field.spec);
+                        record.insert(field.name.clone(), value);
+                    }
+                }
+                Value::Object(record)
+            }
+            Kind::Array(items) => {
+                let min = spec.min_items.unwrap_or(1);
+                let max = spec.max_items.unwrap_or(self.max_items.max(min)).max(min);
+                let len = self.rng.between(min as u64, max as u64);
+                Value::Array((0..len).map(|_| self.value(items)).collect())
+            }
+        }
+    }
+
+    fn string(&mut self, spec: &ValueSpec) -> String {
+        match spec.format.as_deref() {
+            Some("email") => format!(
+                "{}.{}{}@example.com",
+                self.rng.pick(WORDS),
+                self.rng.pick(WORDS),
+                self.rng.below(100)
+            ),
+            Some("uuid") => {
+                let bytes = (self.rng.next_u64() as u128) << 64 | self.rng.next_u64() as u128;
+                uuid::Builder::from_random_bytes(bytes.to_be_bytes())
+                    .into_uuid()
+                    .to_string()
+            }
+            Some("uri") | Some("url") => format!(
+                "https://example.com/{}/{}",
+                self.rng.pick(WORDS),
+                self.rng.below(10_000)
+            ),
+            Some("hostname") => format!("{}.example.com", self.rng.pick(WORDS)),
+            Some("ipv4") => format!(
+                "10.{}.{}.{}",
+                self.rng.below(256),
+                self.rng.below(256),
+                self.rng.between(1, 254)
+            ),
+            Some("date") => self.date(spec).format("%Y-%m-%d").to_string(),
+            Some("date-time") => self.timestamp(spec).to_rfc3339(),
+            Some("time") => self.time(),
+            _ => {
+                let min = spec.min_length.unwrap_or(1);
+                let max = spec.max_length.unwrap_or(min.max(12)).max(min);
+                let len = self.rng.between(min as u64, max as u64) as usize;
+                let mut text = String::with_capacity(len);
+                while text.len() < len {
+                    if !text.is_empty() {
+                        text.push('_');
+                    }
+                    text.push_str(self.rng.pick(WORDS));
+                }
+                text.truncate(len);
+                text
+            }
+        }
+    }
+
+    fn integer(&mut self, spec: &ValueSpec) -> i64 {
+        let (low, high) = spec.numeric_bounds();
+        let mut low = low.ceil() as i64;
+        let mut high = high.floor() as i64;
+        if spec.exclusive_minimum && low as f64 == spec.lower_bound().unwrap_or(f64::NAN) {
+            low += 1;
+        }
+        if spec.exclusive_maximum && high as f64 == spec.upper_bound().unwrap_or(f64::NAN) {
+            high -= 1;
+        }
+        if high <= low {
+            return low;
+        }
+        let span = (high as i128 - low as i128) as u64;
+        (low as i128 + self.rng.between(0, span) as i128) as i64
+    }
+
+    fn number(&mut self, spec: &ValueSpec) -> f64 {
+        let (low, high) = spec.numeric_bounds();
+        let factor = 10f64.powi(spec.scale.unwrap_or(2));
+        let mut value = ((low + self.rng.unit() * (high - low)) * factor).round() / factor;
+        // Rounding may land on an excluded or out-of-range bound
+        if value < low || (spec.exclusive_minimum && value <= low) {
+            value = low + (high - low) / 2.0;
+        }
+        if value > high || (spec.exclusive_maximum && value >= high) {
+            value = low + (high - low) / 2.0;
+        }
+        value
+    }
+
+    fn date(&mut self, spec: &ValueSpec) -> NaiveDate {
+        let (low, high) = spec.timestamp_bounds();
+        let days = (high.date_naive() - low.date_naive()).num_days().max(0);
+        low.date_naive() + Duration::days(self.rng.between(0, days as u64) as i64)
+    }
+
+    fn timestamp(&mut self, spec: &ValueSpec) -> DateTime<Utc> {
+        let (low, high) = spec.timestamp_bounds();
+        let seconds = (high - low).num_seconds().max(0);
+        low + Duration::seconds(self.rng.between(0, seconds as u64) as i64)
+    }
+
+    fn time(&mut self) -> String {
+        format!(
+            "{:02}:{:02}:{:02}",
+            self.rng.below(24),
+            self.rng.below(60),
+            self.rng.below(60)
+        )
+    }
+}
+
+/// Render records as CSV
+///
+/// Columns are the union of all record keys in first-seen order. Nested objects
+/// are flattened into dot-separated columns; arrays are written as JSON.
+pub fn records_to_csv(records: &[Value]) -> String {
+    let rows: Vec<Vec<(String, &Value)>> = records
+        .iter()
+        .map(|record| {
+            let mut row = Vec::new();
+            flatten(None, record, &mut row);
+            row
+        })
+        .collect();
+
+    let mut columns: Vec<&str> = Vec::new();
+    for (column, _) in rows.iter().flatten() {
+        if !columns.contains(&column.as_str()) {
+            columns.push(column);
+        }
+    }
+
+    let mut output = columns
+        .iter()
+        .map(|c| csv_field(c))
+        .collect::<Vec<_>>()
+        .join(",");
+    output.push('\n');
+    for row in &rows {
+        let values: Vec<String> = columns
+            .iter()
+            .map(|column| match row.iter().find(|(c, _)| c == column) {
+                Some((_, Value::String(s))) => csv_field(s),
+                Some((_, Value::Null)) | None => String::new(),
+                Some((_, other)) => csv_field(&other.to_string()),
+            })
+            .collect();
+        output.push_str(&values.join(","));
+        output.push('\n');
+    }
+    output
+}
+
+fn flatten<'a>(prefix: Option<&str>, value: &'a Value, row: &mut Vec<(String, &'a Value)>) {
+    match (prefix, value) {
+        (_, Value::Object(fields)) => {
+            for (name, field) in fields {
+                let column = match prefix {
+                    Some(prefix) => format!("{}.{}", prefix, name),
+                    None => name.clone(),
+                };
+                flatten(Some(&column), field, row);
+            }
+        }
+        (Some(prefix), _) => row.push((prefix.to_string(), value)),
+        (None, _) => row.push(("value".to_string(), value)),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// ============================================================================
+// Value specifications
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum Kind {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Date,
+    Timestamp,
+    Time,
+    Object(Vec<FieldSpec>),
+    Array(Box<ValueSpec>),
+}
+
+/// Constraints of a generated value, shared by ODCS properties and JSON Schemas
+#[derive(Debug, Clone)]
+struct ValueSpec {
+    kind: Kind,
+    format: Option<String>,
+    enum_values: Vec<Value>,
+    minimum: Option<Value>,
+    maximum: Option<Value>,
+    exclusive_minimum: bool,
+    exclusive_maximum: bool,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    scale: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+struct FieldSpec {
+    name: String,
+    required: bool,
+    spec: ValueSpec,
+}
+
+impl FieldSpec {
+    fn from_property(property: &Property) -> Self {
+        Self {
+            name: property.name.clone(),
+            required: property.required || property.primary_key,
+            spec: ValueSpec::from_property(property),
+        }
+    }
+}
+
+impl ValueSpec {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            format: None,
+            enum_values: Vec::new(),
+            minimum: None,
+            maximum: None,
+            exclusive_minimum: false,
+            exclusive_maximum: false,
+            min_length: None,
+            max_length: None,
+            min_items: None,
+            max_items: None,
+            scale: None,
+        }
+    }
+
+    fn object(fields: impl IntoIterator<Item = FieldSpec>) -> Self {
+        Self::new(Kind::Object(fields.into_iter().collect()))
+    }
+
+    fn from_property(property: &Property) -> Self {
+        let kind = match property.logical_type.to_lowercase().as_str() {
+            "integer" | "int" | "long" | "bigint" => Kind::Integer,
+            "number" | "decimal" | "double" | "float" | "numeric" => Kind::Number,
+            "boolean" | "bool" => Kind::Boolean,
+            "date" => Kind::Date,
+            "timestamp" | "datetime" | "date-time" => Kind::Timestamp,
+            "time" => Kind::Time,
+            "object" | "struct" | "record" => Kind::Object(
+                property
+                    .properties
+                    .iter()
+                    .map(FieldSpec::from_property)
+                    .collect(),
+            ),
+            "array" | "list" => Kind::Array(Box::new(match &property.items {
+                Some(items) => Self::from_property(items),
+                None => Self::new(Kind::String),
+            })),
+            _ => Kind::String,
+        };
+        let mut spec = Self::new(kind);
+        spec.enum_values = property
+            .enum_values
+            .iter()
+            .map(|v| spec.typed_enum_value(v))
+            .collect();
+
+        if let Some(options) = &property.logical_type_options {
+            spec.format = options.format.clone();
+            spec.minimum = options.minimum.clone();
+            spec.maximum = options.maximum.clone();
+            spec.min_length = options.min_length.map(|n| n.max(0) as usize);
+            spec.max_length = options.max_length.map(|n| n.max(0) as usize);
+            spec.scale = options.scale;
+            spec.apply_exclusive_bounds(
+                options.exclusive_minimum.as_ref(),
+                options.exclusive_maximum.as_ref(),
+            );
+        }
+        spec
+    }
+
+    fn from_json_schema(schema: &Value, root: &Value, depth: usize) -> Result<Self, GenerateError> {
+        let object = schema.as_object().ok_or_else(|| {
+            GenerateError::InvalidJsonSchema(format!("expected an object, found {}", schema))
+        })?;
+
+        if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+            if depth >= MAX_REF_DEPTH {
+                return Ok(Self::object(Vec::new()));
+            }
+            let target = reference
+                .strip_prefix('#')
+                .and_then(|pointer| root.pointer(pointer))
+                .ok_or_else(|| {
+                    GenerateError::InvalidJsonSchema(format!("unresolved reference: {}", reference))
+                })?;
+            return Self::from_json_schema(target, root, depth + 1);
+        }
+
+        let declared_type = match object.get("type") {
+            Some(Value::String(name)) => Some(name.as_str()),
+            // e.g. ["string", "null"]: generate the first non-null type
+            Some(Value::Array(names)) => names
+                .iter()
+                .filter_map(Value::as_str)
+                .find(|name| *name != "null"),
+            _ => None,
+        };
+        let format = object
+            .get("format")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let kind = match declared_type {
+            Some("integer") => Kind::Integer,
+            Some("number") => Kind::Number,
+            Some("boolean") => Kind::Boolean,
+            Some("array") => Kind::Array(Box::new(match object.get("items") {
+                Some(items) => Self::from_json_schema(items, root, depth)?,
+                None => Self::new(Kind::String),
+            })),
+            Some("object") => Kind::Object(Vec::new()),
+            None if object.contains_key("properties") => Kind::Object(Vec::new()),
+            _ => Kind::String,
+        };
+
+        let mut spec = Self::new(kind);
+        if let Kind::Object(fields) = &mut spec.kind {
+            let required: Vec<&str> = object
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|names| names.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            if let Some(properties) = object.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    fields.push(FieldSpec {
+                        name: name.clone(),
+                        required: required.contains(&name.as_str()),
+                        spec: Self::from_json_schema(property, root, depth)?,
+                    });
+                }
+            }
+        }
+
+        spec.format = format;
+        spec.enum_values = match (object.get("const"), object.get("enum")) {
+            (Some(value), _) => vec![value.clone()],
+            (None, Some(Value::Array(values))) => values.clone(),
+            _ => Vec::new(),
+        };
+        spec.minimum = object.get("minimum").cloned();
+        spec.maximum = object.get("maximum").cloned();
+        spec.apply_exclusive_bounds(
+            object.get("exclusiveMinimum"),
+            object.get("exclusiveMaximum"),
+        );
+        let count = |key: &str| object.get(key).and_then(Value::as_u64).map(|n| n as usize);
+        spec.min_length = count("minLength");
+        spec.max_length = count("maxLength");
+        spec.min_items = count("minItems");
+        spec.max_items = count("maxItems");
+        Ok(spec)
+    }
+
+    /// Exclusive bounds are either flags on `minimum`/`maximum` (JSON Schema draft 4)
+    /// or bounds of their own
+    fn apply_exclusive_bounds(&mut self, minimum: Option<&Value>, maximum: Option<&Value>) {
+        match minimum {
+            Some(Value::Bool(exclusive)) => self.exclusive_minimum = *exclusive,
+            Some(bound @ Value::Number(_)) => {
+                self.minimum = Some(bound.clone());
+                self.exclusive_minimum = true;
+            }
+            _ => {}
+        }
+        match maximum {
+            Some(Value::Bool(exclusive)) => self.exclusive_maximum = *exclusive,
+            Some(bound @ Value::Number(_)) => {
+                self.maximum = Some(bound.clone());
+                self.exclusive_maximum = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// ODCS enum values are strings; numeric and boolean fields get typed values
+    fn typed_enum_value(&self, value: &str) -> Value {
+        let typed = match self.kind {
+            Kind::Integer => value.parse::<i64>().ok().map(Value::from),
+            Kind::Number => value
+                .parse::<f64>()
+                .ok()
+                .and_then(|n| Number::from_f64(n).map(Value::Number)),
+            Kind::Boolean => value.parse::<bool>().ok().map(Value::Bool),
+            _ => None,
+        };
+        typed.unwrap_or_else(|| Value::String(value.to_string()))
+    }
+
+    fn lower_bound(&self) -> Option<f64> {
+        self.minimum.as_ref().and_then(Value::as_f64)
+    }
+
+    fn upper_bound(&self) -> Option<f64> {
+        self.maximum.as_ref().and_then(Value::as_f64)
+    }
+
+    fn numeric_bounds(&self) -> (f64, f64) {
+        match (self.lower_bound(), self.upper_bound()) {
+            (Some(low), Some(high)) if high >= low => (low, high),
+            (Some(low), Some(_)) => (low, low),
+            (Some(low), None) => (low, low + DEFAULT_NUMBER_SPAN),
+            (None, Some(high)) => (high - DEFAULT_NUMBER_SPAN, high),
+            (None, None) => (0.0, DEFAULT_NUMBER_SPAN),
+        }
+    }
+
+    fn timestamp_bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let low = self.minimum.as_ref().and_then(parse_timestamp);
+        let high = self.maximum.as_ref().and_then(parse_timestamp);
+        let span = Duration::days(DEFAULT_DAY_SPAN);
+        match (low, high) {
+            (Some(low), Some(high)) if high >= low => (low, high),
+            (Some(low), _) => (low, low + span),
+            (None, Some(high)) => (high - span, high),
+            (None, None) => {
+                let base = base_timestamp();
+                (base, base + span)
+            }
+        }
+    }
+}
+
+fn base_timestamp() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0)
+        .single()
+        .expect("valid base timestamp")
+}
+
+/// Date and timestamp bounds are ISO 8601 strings
+fn parse_timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    let text = value.as_str()?;
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(text) {
+        return Some(timestamp.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()?;
+    Some(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?))
+}
+
+const WORDS: &[&str] = &[
+    "alpha", "amber", "birch", "cedar", "delta", "ember", "fjord", "grove", "harbor", "iris",
+    "juniper", "kestrel", "linden", "maple", "nova", "orchid", "pine", "quartz", "river", "sage",
+    "tundra", "umber", "violet", "willow", "yarrow", "zephyr",
+];
+
+// ============================================================================
+// Deterministic random numbers
+// ============================================================================
+
+/// SplitMix64 generator: tiny, fast and stable across platforms and releases
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n` (`n` must be non-zero)
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Uniform value in `low..=high`
+    pub(crate) fn between(&mut self, low: u64, high: u64) -> u64 {
+        match (high - low).checked_add(1) {
+            Some(n) => low + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    /// Uniform value in `[0, 1)`
+    pub(crate) fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub(crate) fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::LogicalTypeOptions;
+    use serde_json::json;
+
+    fn bounded(mut property: Property, options: LogicalTypeOptions) -> Property {
+        property.logical_type_options = Some(options);
+        property
+    }
+
+    #[test]
+    fn test_contract_records_honor_constraints() {
+        let schema = SchemaObject::new("customers").with_properties(vec![
+            Property::new("id", "integer").with_primary_key(true),
+            bounded(
+                Property::new("age", "integer").with_required(true),
+                LogicalTypeOptions {
+                    minimum: Some(json!(18)),
+                    maximum: Some(json!(21)),
+                    exclusive_maximum: Some(json!(true)),
+                    ..Default::default()
+                },
+            ),
+            bounded(
+                Property::new("email", "string").with_required(true),
+                LogicalTypeOptions {
+                    format: Some("email".to_string()),
+                    ..Default::default()
+                },
+            ),
+            bounded(
+                Property::new("code", "string").with_required(true),
+                LogicalTypeOptions {
+                    min_length: Some(4),
+                    max_length: Some(6),
+                    ..Default::default()
+                },
+            ),
+            Property::new("tier", "integer")
+                .with_required(true)
+                .with_enum_values(vec!["1".to_string(), "2".to_string()]),
+            bounded(
+                Property::new("joined", "date").with_required(true),
+                LogicalTypeOptions {
+                    minimum: Some(json!("2024-01-01")),
+                    maximum: Some(json!("2024-01-31")),
+                    ..Default::default()
+                },
+            ),
+            Property::new("address", "object")
+                .with_required(true)
+                .with_nested_properties(vec![Property::new("city", "string").with_required(true)]),
+            Property::new("tags", "array").with_items(Property::new("tag", "string")),
+            Property::new("note", "string"),
+        ]);
+
+        let records = RecordGenerator::new(1)
+            .with_optional_ratio(0.0)
+            .generate_schema(&schema, 50);
+        for record in &records {
+            assert!(record["id"].is_i64());
+            let age = record["age"].as_i64().unwrap();
+            assert!((18..21).contains(&age), "age {}", age);
+            let email = record["email"].as_str().unwrap();
+            assert!(email.ends_with("@example.com"));
+            let code = record["code"].as_str().unwrap();
+            assert!((4..=6).contains(&code.len()), "code {}", code);
+            assert!(record["tier"] == json!(1) || record["tier"] == json!(2));
+            let joined = record["joined"].as_str().unwrap();
+            assert!(joined.starts_with("2024-01-"), "joined {}", joined);
+            assert!(record["address"]["city"].is_string());
+            assert!(record.get("tags").is_none());
+            assert!(record.get("note").is_none());
+        }
+
+        let all = RecordGenerator::new(1)
+            .with_optional_ratio(1.0)
+            .generate_schema(&schema, 5);
+        assert!(
+            all.iter()
+                .all(|r| r["tags"].is_array() && r["note"].is_string())
+        );
+    }
+
+    #[test]
+    fn test_json_schema_records_are_reproducible() {
+        let schema = json!({
+            "type": "object",
+            "required": ["id", "price", "items"],
+            "properties": {
+                "id": { "type": "string", "format": "uuid" },
+                "price": { "type": "number", "exclusiveMinimum": 0, "maximum": 5 },
+                "created": { "type": ["string", "null"], "format": "date-time" },
+                "items": { "type": "array", "items": { "$ref": "#/$defs/item" }, "minItems": 2, "maxItems": 2 },
+                "state": { "const": "new" }
+            },
+            "$defs": {
+                "item": {
+                    "type": "object",
+                    "required": ["sku"],
+                    "properties": { "sku": { "enum": ["A-1", "B-2"] } }
+                }
+            }
+        });
+
+        let records = RecordGenerator::new(9)
+            .generate_json_schema(&schema, 20)
+            .unwrap();
+        for record in &records {
+            assert!(uuid::Uuid::parse_str(record["id"].as_str().unwrap()).is_ok());
+            let price = record["price"].as_f64().unwrap();
+            assert!(price > 0.0 && price <= 5.0, "price {}", price);
+            if let Some(created) = record.get("created") {
+                assert!(DateTime::parse_from_rfc3339(created.as_str().unwrap()).is_ok());
+            }
+            let items = record["items"].as_array().unwrap();
+            assert_eq!(items.len(), 2);
+            assert!(items.iter().all(|i| i["sku"] == "A-1" || i["sku"] == "B-2"));
+            assert!(record.get("state").is_none_or(|s| s == "new"));
+        }
+
+        let again = RecordGenerator::new(9)
+            .generate_json_schema(&schema, 20)
+            .unwrap();
+        assert_eq!(records, again);
+        let other = RecordGenerator::new(10)
+            .generate_json_schema(&schema, 20)
+            .unwrap();
+        assert_ne!(records, other);
+
+        let error = RecordGenerator::default()
+            .generate_json_schema(&json!({ "$ref": "#/$defs/missing" }), 1)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            GenerateError::InvalidJsonSchema("unresolved reference: #/$defs/missing".to_string())
+        );
+    }
+
+    #[test]
+    fn test_records_to_csv() {
+        let records = vec![
+            json!({ "id": 1, "name": "a, b", "address": { "city": "Köln" } }),
+            json!({ "id": 2, "tags": ["x"], "name": "say \"hi\"" }),
+        ];
+        assert_eq!(
+            records_to_csv(&records),
+            "address.city,id,name,tags\n\
+             Köln,1,\"a, b\",\n\
+             ,2,\"say \"\"hi\"\"\",\"[\"\"x\"\"]\"\n"
+        );
+    }
+}
@@ -1,10 +1,16 @@
 //! Git operations for managing Git repositories
 //!
 //! Provides Git repository management (init, open, commit, push, status) that can be used
-//! by both the API and native app.
+//! by both the API and native app, plus a time-travel diff of workspace assets between two refs.
 
 #[cfg(feature = "git")]
 mod git_service;
 
 #[cfg(feature = "git")]
 pub use git_service::{GitCredentials, GitError, GitService, GitStatus};
+
+#[cfg(feature = "git")]
+pub mod workspace_diff;
+
+#[cfg(feature = "git")]
+pub use workspace_diff::{AssetChange, ChangeKind, ContractChange, WorkspaceDiff, workspace_diff};
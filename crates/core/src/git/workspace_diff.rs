@@ -0,0 +1,337 @@
+//! Time-travel diff of a workspace between two Git refs
+//!
+//! Compares the workspace assets committed at two refs (branches, tags, commit ids or
+//! any other revision understood by `git rev-parse`) without touching the working tree.
+//! Assets are recognised by file name (see [`AssetType::from_filename`]); ODCS contracts
+//! that changed between the refs additionally carry a field-level contract diff.
+//!
+//! The result is serializable so the CLI, PR bots and changelog generators can consume
+//! the same structure.
+
+use anyhow::Result;
+use git2::{ObjectType, Oid, Repository, TreeWalkMode, TreeWalkResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::git_service::{GitError, GitService};
+use crate::models::workspace::AssetType;
+
+/// Keys used to match array entries (schema objects, properties, servers, ...) by identity
+const IDENTITY_KEYS: &[&str] = &["name", "property", "server", "role"];
+
+/// Kind of change to a single contract field
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// A single field-level change between two versions of a contract
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContractChange {
+    /// Path of the field, e.g. `schema[orders].properties[id].logicalType`
+    pub path: String,
+    pub kind: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+/// An asset that was added, removed or changed between the two refs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AssetChange {
+    /// Path of the asset file relative to the repository root
+    pub path: String,
+    pub asset_type: AssetType,
+    /// Field-level changes for modified ODCS contracts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contract_changes: Vec<ContractChange>,
+}
+
+/// Differences between the workspace assets at two Git refs
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WorkspaceDiff {
+    pub from_ref: String,
+    pub to_ref: String,
+    pub added: Vec<AssetChange>,
+    pub removed: Vec<AssetChange>,
+    pub changed: Vec<AssetChange>,
+}
+
+impl WorkspaceDiff {
+    /// Returns true when no asset differs between the two refs
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Diff the workspace assets between `ref_a` and `ref_b` in an opened repository
+///
+/// `ref_a` is treated as the older side: assets only present at `ref_b` are reported
+/// as added, assets only present at `ref_a` as removed.
+pub fn workspace_diff(repo: &GitService, ref_a: &str, ref_b: &str) -> Result<WorkspaceDiff> {
+    let repository = repo
+        .repository()
+        .ok_or_else(|| GitError::Operation("Repository not opened".to_string()))?;
+
+    let before = asset_blobs(repository, ref_a)?;
+    let after = asset_blobs(repository, ref_b)?;
+
+    let mut diff = WorkspaceDiff {
+        from_ref: ref_a.to_string(),
+        to_ref: ref_b.to_string(),
+        ..Default::default()
+    };
+
+    for (path, (asset_type, old_id)) in &before {
+        match after.get(path) {
+            None => diff.removed.push(AssetChange {
+                path: path.clone(),
+                asset_type: asset_type.clone(),
+                contract_changes: Vec::new(),
+            }),
+            Some((_, new_id)) if new_id != old_id => {
+                let contract_changes = if *asset_type == AssetType::Odcs {
+                    contract_diff(
+                        &blob_value(repository, *old_id)?,
+                        &blob_value(repository, *new_id)?,
+                    )
+                } else {
+                    Vec::new()
+                };
+                diff.changed.push(AssetChange {
+                    path: path.clone(),
+                    asset_type: asset_type.clone(),
+                    contract_changes,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (path, (asset_type, _)) in &after {
+        if !before.contains_key(path) {
+            diff.added.push(AssetChange {
+                path: path.clone(),
+                asset_type: asset_type.clone(),
+                contract_changes: Vec::new(),
+            });
+        }
+    }
+
+    Ok(diff)
+}
+
+/// Compute the field-level changes between two contract documents
+///
+/// Arrays whose entries all carry an identity key (`name`, `property`, `server` or
+/// `role`) are matched entry by entry, so reordering schema objects or properties is not
+/// reported as a change. Other arrays and scalars are compared as a whole.
+pub fn contract_diff(before: &Value, after: &Value) -> Vec<ContractChange> {
+    let mut changes = Vec::new();
+    diff_values("", before, after, &mut changes);
+    changes
+}
+
+/// Collect the asset blobs reachable from `reference`, keyed by path
+fn asset_blobs(
+    repository: &Repository,
+    reference: &str,
+) -> Result<BTreeMap<String, (AssetType, Oid)>> {
+    let tree = repository
+        .revparse_single(reference)
+        .and_then(|object| object.peel_to_tree())
+        .map_err(|e| GitError::Operation(format!("Failed to resolve '{}': {}", reference, e)))?;
+
+    let mut blobs = BTreeMap::new();
+    tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+        if entry.kind() == Some(ObjectType::Blob)
+            && let Some(name) = entry.name()
+            && let Some(asset_type) = AssetType::from_filename(name)
+        {
+            blobs.insert(format!("{}{}", root, name), (asset_type, entry.id()));
+        }
+        TreeWalkResult::Ok
+    })
+    .map_err(|e| GitError::Operation(format!("Failed to walk '{}': {}", reference, e)))?;
+
+    Ok(blobs)
+}
+
+/// Parse a YAML blob into a JSON value, treating unparseable content as null
+fn blob_value(repository: &Repository, id: Oid) -> Result<Value> {
+    let blob = repository
+        .find_blob(id)
+        .map_err(|e| GitError::Operation(format!("Failed to read blob {}: {}", id, e)))?;
+    let content = String::from_utf8_lossy(blob.content());
+    Ok(serde_yaml::from_str(&content).unwrap_or(Value::Null))
+}
+
+fn diff_values(path: &str, before: &Value, after: &Value, changes: &mut Vec<ContractChange>) {
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            let old: BTreeMap<&str, &Value> = old.iter().map(|(k, v)| (k.as_str(), v)).collect();
+            let new: BTreeMap<&str, &Value> = new.iter().map(|(k, v)| (k.as_str(), v)).collect();
+            diff_entries(&old, &new, |key| child_path(path, key), changes);
+        }
+        (Value::Array(old), Value::Array(new)) => match (keyed(old), keyed(new)) {
+            (Some(old), Some(new)) => {
+                diff_entries(&old, &new, |key| format!("{}[{}]", path, key), changes);
+            }
+            _ => diff_scalar(path, before, after, changes),
+        },
+        _ => diff_scalar(path, before, after, changes),
+    }
+}
+
+fn diff_entries(
+    old: &BTreeMap<&str, &Value>,
+    new: &BTreeMap<&str, &Value>,
+    path_of: impl Fn(&str) -> String,
+    changes: &mut Vec<ContractChange>,
+) {
+    for (key, value) in old {
+        match new.get(key) {
+            Some(other) => diff_values(&path_of(key), value, other, changes),
+            None => changes.push(ContractChange {
+                path: path_of(key),
+                kind: ChangeKind::Removed,
+                before: Some((*value).clone()),
+                after: None,
+            }),
+        }
+    }
+    for (key, value) in new {
+        if !old.contains_key(key) {
+            changes.push(ContractChange {
+                path: path_of(key),
+                kind: ChangeKind::Added,
+                before: None,
+                after: Some((*value).clone()),
+            });
+        }
+    }
+}
+
+fn diff_scalar(path: &str, before: &Value, after: &Value, changes: &mut Vec<ContractChange>) {
+    if before != after {
+        changes.push(ContractChange {
+            path: path.to_string(),
+            kind: ChangeKind::Changed,
+            before: Some(before.clone()),
+            after: Some(after.clone()),
+        });
+    }
+}
+
+/// Index array entries by identity key, or None if any entry lacks a unique one
+fn keyed(items: &[Value]) -> Option<BTreeMap<&str, &Value>> {
+    let map: BTreeMap<&str, &Value> = items
+        .iter()
+        .map(|item| {
+            IDENTITY_KEYS
+                .iter()
+                .find_map(|key| item.get(key)?.as_str())
+                .map(|id| (id, item))
+        })
+        .collect::<Option<_>>()?;
+    (map.len() == items.len()).then_some(map)
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write(dir: &std::path::Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn test_contract_diff_matches_properties_by_name() {
+        let before = json!({
+            "version": "1.0.0",
+            "schema": [{"name": "orders", "properties": [
+                {"name": "id", "logicalType": "integer"},
+                {"name": "note", "logicalType": "string"}
+            ]}]
+        });
+        let after = json!({
+            "version": "1.1.0",
+            "schema": [{"name": "orders", "properties": [
+                {"name": "amount", "logicalType": "number"},
+                {"name": "id", "logicalType": "string"}
+            ]}]
+        });
+
+        let changes = contract_diff(&before, &after);
+        let summary: Vec<_> = changes.iter().map(|c| (c.path.as_str(), c.kind)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "schema[orders].properties[id].logicalType",
+                    ChangeKind::Changed
+                ),
+                ("schema[orders].properties[note]", ChangeKind::Removed),
+                ("schema[orders].properties[amount]", ChangeKind::Added),
+                ("version", ChangeKind::Changed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_workspace_diff_between_commits() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut git = GitService::new();
+        git.open_or_init(dir.path()).unwrap();
+
+        write(dir.path(), "workspace.yaml", "name: demo\n");
+        write(
+            dir.path(),
+            "demo_orders.odcs.yaml",
+            "id: orders\nversion: 1.0.0\nschema:\n  - name: orders\n",
+        );
+        write(dir.path(), "demo_legacy.odcs.yaml", "id: legacy\n");
+        write(dir.path(), "notes.txt", "not an asset\n");
+        git.commit_all("initial", "Test", "test@example.com")
+            .unwrap();
+
+        write(
+            dir.path(),
+            "demo_orders.odcs.yaml",
+            "id: orders\nversion: 1.1.0\nschema:\n  - name: orders\n",
+        );
+        std::fs::remove_file(dir.path().join("demo_legacy.odcs.yaml")).unwrap();
+        let mut index = git.repository().unwrap().index().unwrap();
+        index
+            .remove_path(std::path::Path::new("demo_legacy.odcs.yaml"))
+            .unwrap();
+        index.write().unwrap();
+        write(dir.path(), "demo_customers.odcs.yaml", "id: customers\n");
+        write(dir.path(), "notes.txt", "still not an asset\n");
+        git.commit_all("update", "Test", "test@example.com")
+            .unwrap();
+
+        let diff = workspace_diff(&git, "HEAD~1", "HEAD").unwrap();
+        assert_eq!(diff.added[0].path, "demo_customers.odcs.yaml");
+        assert_eq!(diff.removed[0].path, "demo_legacy.odcs.yaml");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].path, "demo_orders.odcs.yaml");
+        assert_eq!(diff.changed[0].contract_changes[0].path, "version");
+
+        assert!(workspace_diff(&git, "HEAD", "HEAD").unwrap().is_empty());
+    }
+}
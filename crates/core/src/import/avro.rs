@@ -1,5 +1,12 @@
 //! AVRO schema parser for importing AVRO schemas into data models.
 //!
+//! # Named Types
+//!
+//! Fields referencing a named type that isn't defined in the schema (e.g.,
+//! `"type": "com.acme.Address"`) are loaded from `com/acme/Address.avsc`,
+//! `com.acme.Address.avsc` or `Address.avsc` when a resolver is set with
+//! [`AvroImporter::with_resolver`].
+//!
 //! # Validation
 //!
 //! All imported table and column names are validated for:
@@ -7,18 +14,37 @@
 //! - Maximum length limits
 
 use crate::import::odcs_shared::column_to_column_data;
-use crate::import::{ImportError, ImportResult, TableData};
-use crate::models::{Column, Table, Tag};
+use crate::import::ref_resolver::RefResolver;
+use crate::import::{ImportError, ImportResult, TableData, check_limits, unmapped_type_warnings};
+use crate::models::type_mapping::source_type_property;
+use crate::models::{
+    Column, IdGenerator, LogicalTypeOptions, SOURCE_TYPE_PROPERTY, Table, Tag, TypeMappingRegistry,
+};
 use crate::validation::input::{validate_column_name, validate_table_name};
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use serde_json::{Value, json};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::Arc;
 use tracing::{info, warn};
 
+/// AVRO primitive type names.
+const AVRO_PRIMITIVES: &[&str] = &[
+    "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+];
+
 /// Parser for AVRO schema format.
 #[derive(Default)]
-pub struct AvroImporter;
+pub struct AvroImporter {
+    /// Loads schema files of named types referenced but not defined in the schema
+    resolver: Option<Arc<dyn RefResolver>>,
+    /// Type mappings (the built-in mappings when `None`)
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+    limits: ResourceLimits,
+    /// Generator for the IDs of imported tables
+    ids: IdGenerator,
+}
 
 impl AvroImporter {
     /// Create a new AVRO parser instance.
@@ -31,7 +57,54 @@ impl AvroImporter {
     /// let importer = AvroImporter::new();
     /// ```
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Set the [`ResourceLimits`] for Avro schemas
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Generate table IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Load named types that aren't defined in the schema with `resolver`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::avro::AvroImporter;
+    /// use data_modelling_core::import::ref_resolver::LocalFileResolver;
+    ///
+    /// let importer = AvroImporter::new().with_resolver(LocalFileResolver::new("schemas"));
+    /// ```
+    pub fn with_resolver(mut self, resolver: impl RefResolver + 'static) -> Self {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Map AVRO types with `type_mappings` instead of the built-in mappings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::avro::AvroImporter;
+    /// use data_modelling_core::models::TypeMappingRegistry;
+    ///
+    /// let mut mappings = TypeMappingRegistry::default();
+    /// mappings.set_import("avro", "long", "NUMBER(19)");
+    /// let importer = AvroImporter::new().with_type_mappings(mappings);
+    /// ```
+    pub fn with_type_mappings(
+        mut self,
+        type_mappings: impl Into<Arc<TypeMappingRegistry>>,
+    ) -> Self {
+        self.type_mappings = Some(type_mappings.into());
+        self
     }
 
     /// Import AVRO schema content and create Table(s) (SDK interface).
@@ -62,6 +135,7 @@ impl AvroImporter {
     /// let result = importer.import(schema).unwrap();
     /// ```
     pub fn import(&self, avro_content: &str) -> Result<ImportResult, ImportError> {
+        check_limits(&self.limits, avro_content)?;
         match self.parse(avro_content) {
             Ok((tables, errors)) => {
                 let mut sdk_tables = Vec::new();
@@ -74,10 +148,11 @@ impl AvroImporter {
                         ..Default::default()
                     });
                 }
-                let sdk_errors: Vec<ImportError> = errors
+                let mut sdk_errors: Vec<ImportError> = errors
                     .iter()
                     .map(|e| ImportError::ParseError(e.message.clone()))
                     .collect();
+                sdk_errors.extend(unmapped_type_warnings("avro", &sdk_tables));
                 Ok(ImportResult {
                     tables: sdk_tables,
                     tables_requiring_name: Vec::new(),
@@ -98,8 +173,9 @@ impl AvroImporter {
         let mut errors = Vec::new();
 
         // Parse JSON
-        let schema: Value =
+        let mut schema: Value =
             serde_json::from_str(avro_content).context("Failed to parse AVRO schema as JSON")?;
+        self.inline_named_types(&mut schema);
 
         let mut tables = Vec::new();
 
@@ -221,7 +297,12 @@ impl AvroImporter {
         }
 
         let table = Table {
-            id: crate::models::table::Table::generate_id(&name, None, None, namespace.as_deref()),
+            id: crate::models::table::Table::generate_id_with_generator(
+                &self.ids,
+                &name,
+                None,
+                namespace.as_deref(),
+            ),
             name: name.clone(),
             columns,
             database_type: None,
@@ -311,16 +392,33 @@ impl AvroImporter {
 
         // Parse the actual type
         let mut columns = Vec::new();
-        if let Some(type_str) = avro_type.as_str() {
-            // Simple type
-            let data_type = self.map_avro_type_to_sql(type_str);
+        if let Some((data_type, physical_type, options)) = avro_type
+            .as_object()
+            .and_then(|type_obj| self.map_avro_logical_type(type_obj))
+        {
+            // Logical type (decimal, date, timestamp-micros, uuid, ...)
             columns.push(Column {
                 name: field_name,
                 data_type,
+                physical_type: Some(physical_type),
+                logical_type_options: options,
                 nullable,
                 description,
                 ..Default::default()
             });
+        } else if let Some(type_str) = avro_type.as_str() {
+            // Simple type
+            let data_type = self.map_avro_type_to_sql(type_str);
+            columns.push(self.preserve_unmapped_type(
+                Column {
+                    name: field_name,
+                    data_type,
+                    nullable,
+                    description,
+                    ..Default::default()
+                },
+                type_str,
+            ));
         } else if let Some(type_obj) = avro_type.as_object() {
             // Complex type (record, array, map)
             if type_obj.get("type").and_then(|v| v.as_str()) == Some("record") {
@@ -358,7 +456,8 @@ impl AvroImporter {
                     .get("items")
                     .ok_or_else(|| anyhow::anyhow!("Array type missing items"))?;
 
-                let data_type = if let Some(items_str) = items.as_str() {
+                let items_type = items.as_str();
+                let data_type = if let Some(items_str) = items_type {
                     format!("ARRAY<{}>", self.map_avro_type_to_sql(items_str))
                 } else if let Some(items_obj) = items.as_object() {
                     if items_obj.get("type").and_then(|v| v.as_str()) == Some("record") {
@@ -397,12 +496,36 @@ impl AvroImporter {
                     "ARRAY<STRING>".to_string()
                 };
 
-                columns.push(Column {
+                let column = Column {
+                    name: field_name,
+                    data_type,
+                    nullable,
+                    description,
+                    ..Default::default()
+                };
+                columns.push(match items_type {
+                    Some(items_str) => self.preserve_unmapped_type(column, items_str),
+                    None => column,
+                });
+            } else if let Some(base_type) = type_obj.get("type").and_then(|v| v.as_str()) {
+                // Primitive type in object form (e.g., {"type": "long"}) or
+                // an unknown logical type, which falls back to its underlying type
+                let data_type = match base_type {
+                    "map" => "MAP<STRING,STRING>".to_string(),
+                    "enum" => "STRING".to_string(),
+                    "fixed" => "BYTES".to_string(),
+                    _ => self.map_avro_type_to_sql(base_type),
+                };
+                let column = Column {
                     name: field_name,
                     data_type,
                     nullable,
                     description,
                     ..Default::default()
+                };
+                columns.push(match base_type {
+                    "map" | "enum" | "fixed" => column,
+                    _ => self.preserve_unmapped_type(column, base_type),
                 });
             } else {
                 // Other complex types - default to STRUCT
@@ -421,20 +544,226 @@ impl AvroImporter {
         Ok(columns)
     }
 
+    /// Replace references to named types that aren't defined in the schema with
+    /// their definitions loaded by the resolver.
+    fn inline_named_types(&self, schema: &mut Value) {
+        let Some(resolver) = &self.resolver else {
+            return;
+        };
+        let mut defined = HashSet::new();
+        collect_named_types(schema, None, &mut defined);
+        let mut visiting = Vec::new();
+        inline_type_refs(schema, None, resolver.as_ref(), &defined, &mut visiting);
+    }
+
+    /// Map an AVRO logical type to a SQL/ODCL data type.
+    ///
+    /// Returns the data type, the underlying AVRO physical type and any
+    /// logical type options (precision/scale for decimals, format for
+    /// time-based types and UUIDs). Unknown logical types return `None` so the
+    /// underlying type is used, as required by the AVRO specification.
+    fn map_avro_logical_type(
+        &self,
+        type_obj: &serde_json::Map<String, Value>,
+    ) -> Option<(String, String, Option<LogicalTypeOptions>)> {
+        let logical_type = type_obj.get("logicalType")?.as_str()?;
+        let base_type = type_obj
+            .get("type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("bytes")
+            .to_string();
+        let with_format = |format: &str| LogicalTypeOptions {
+            format: Some(format.to_string()),
+            ..Default::default()
+        };
+
+        let (data_type, options) = match logical_type {
+            "decimal" => {
+                let precision = type_obj.get("precision").and_then(|v| v.as_i64())? as i32;
+                let scale = type_obj.get("scale").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+                (
+                    format!("DECIMAL({},{})", precision, scale),
+                    Some(LogicalTypeOptions {
+                        precision: Some(precision),
+                        scale: Some(scale),
+                        ..Default::default()
+                    }),
+                )
+            }
+            "date" => ("DATE".to_string(), None),
+            "time-millis" | "time-micros" => ("TIME".to_string(), Some(with_format(logical_type))),
+            "timestamp-millis" | "timestamp-micros" | "timestamp-nanos" => {
+                ("TIMESTAMP".to_string(), Some(with_format(logical_type)))
+            }
+            "local-timestamp-millis" | "local-timestamp-micros" | "local-timestamp-nanos" => {
+                ("TIMESTAMP_NTZ".to_string(), Some(with_format(logical_type)))
+            }
+            "uuid" => ("UUID".to_string(), Some(with_format("uuid"))),
+            "duration" => ("INTERVAL".to_string(), Some(with_format("duration"))),
+            _ => return None,
+        };
+
+        Some((data_type, base_type, options))
+    }
+
     /// Map AVRO type to SQL/ODCL data type.
     fn map_avro_type_to_sql(&self, avro_type: &str) -> String {
-        match avro_type {
-            "int" => "INTEGER".to_string(),
-            "long" => "BIGINT".to_string(),
-            "float" => "FLOAT".to_string(),
-            "double" => "DOUBLE".to_string(),
-            "boolean" => "BOOLEAN".to_string(),
-            "bytes" => "BYTES".to_string(),
-            "string" => "STRING".to_string(),
-            "null" => "NULL".to_string(),
-            _ => "STRING".to_string(), // Default fallback
+        self.type_mappings()
+            .import_type("avro", avro_type)
+            .unwrap_or_else(|| "STRING".to_string())
+    }
+
+    /// Keep an AVRO type without a mapping (e.g. a named type reference) on the
+    /// column so it can be exported again.
+    fn preserve_unmapped_type(&self, mut column: Column, avro_type: &str) -> Column {
+        if !self.type_mappings().has_import("avro", avro_type) {
+            column.physical_type = Some(avro_type.to_string());
+            column.custom_properties.insert(
+                SOURCE_TYPE_PROPERTY.to_string(),
+                source_type_property("avro", avro_type),
+            );
+        }
+        column
+    }
+
+    fn type_mappings(&self) -> &TypeMappingRegistry {
+        self.type_mappings
+            .as_deref()
+            .unwrap_or(TypeMappingRegistry::builtin())
+    }
+}
+
+/// Get the full name of a named type, qualified by `namespace` if needed.
+fn full_type_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) if !name.contains('.') && !ns.is_empty() => format!("{}.{}", ns, name),
+        _ => name.to_string(),
+    }
+}
+
+/// Get the namespace that applies within a named type definition.
+fn type_namespace<'a>(
+    obj: &'a serde_json::Map<String, Value>,
+    namespace: Option<&'a str>,
+) -> Option<&'a str> {
+    let name = obj.get("name").and_then(|v| v.as_str());
+    match name.and_then(|n| n.rsplit_once('.')) {
+        Some((ns, _)) => Some(ns),
+        None => obj.get("namespace").and_then(|v| v.as_str()).or(namespace),
+    }
+}
+
+/// Collect the full names of all records, enums and fixed types defined in a schema.
+fn collect_named_types(schema: &Value, namespace: Option<&str>, defined: &mut HashSet<String>) {
+    match schema {
+        Value::Array(types) => {
+            for t in types {
+                collect_named_types(t, namespace, defined);
+            }
         }
+        Value::Object(obj) => {
+            let namespace = type_namespace(obj, namespace);
+            if let Some(name) = obj.get("name").and_then(|v| v.as_str()) {
+                defined.insert(full_type_name(name, namespace));
+            }
+            for field in obj
+                .get("fields")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+            {
+                if let Some(t) = field.get("type") {
+                    collect_named_types(t, namespace, defined);
+                }
+            }
+            for key in ["items", "values"] {
+                if let Some(t) = obj.get(key) {
+                    collect_named_types(t, namespace, defined);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Inline references to undefined named types within a schema.
+///
+/// Recursive references and types the resolver can't load are kept as names.
+fn inline_type_refs(
+    schema: &mut Value,
+    namespace: Option<&str>,
+    resolver: &dyn RefResolver,
+    defined: &HashSet<String>,
+    visiting: &mut Vec<String>,
+) {
+    match schema {
+        Value::String(name) => {
+            if AVRO_PRIMITIVES.contains(&name.as_str()) {
+                return;
+            }
+            let full_name = full_type_name(name, namespace);
+            if defined.contains(&full_name) || defined.contains(name.as_str()) {
+                return;
+            }
+            if visiting.contains(&full_name) {
+                return;
+            }
+            let Some(mut resolved) = load_named_type(&full_name, resolver) else {
+                warn!("Could not resolve AVRO named type '{}'", full_name);
+                return;
+            };
+            visiting.push(full_name);
+            inline_type_refs(&mut resolved, namespace, resolver, defined, visiting);
+            visiting.pop();
+            *schema = resolved;
+        }
+        Value::Array(types) => {
+            for t in types {
+                inline_type_refs(t, namespace, resolver, defined, visiting);
+            }
+        }
+        Value::Object(obj) => {
+            let namespace = type_namespace(obj, namespace).map(String::from);
+            if let Some(Value::Array(fields)) = obj.get_mut("fields") {
+                for field in fields {
+                    if let Some(t) = field.get_mut("type") {
+                        inline_type_refs(t, namespace.as_deref(), resolver, defined, visiting);
+                    }
+                }
+            }
+            for key in ["items", "values"] {
+                if let Some(t) = obj.get_mut(key) {
+                    inline_type_refs(t, namespace.as_deref(), resolver, defined, visiting);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Load the schema file of a named type.
+fn load_named_type(full_name: &str, resolver: &dyn RefResolver) -> Option<Value> {
+    let short_name = full_name.rsplit('.').next().unwrap_or(full_name);
+    let mut candidates = vec![
+        format!("{}.avsc", full_name.replace('.', "/")),
+        format!("{}.avsc", full_name),
+    ];
+    if short_name != full_name {
+        candidates.push(format!("{}.avsc", short_name));
     }
+    candidates.dedup();
+
+    candidates.iter().find_map(|candidate| {
+        let document = resolver.resolve(candidate, None).ok()?;
+        match document.parse() {
+            Ok(value) if value.is_object() => Some(value),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Failed to parse AVRO schema {}: {}", document.location, e);
+                None
+            }
+        }
+    })
 }
 
 /// Parser error structure (matches ODCL parser format).
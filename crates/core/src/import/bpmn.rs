@@ -2,6 +2,9 @@
 //!
 //! Provides functionality to import BPMN 2.0 XML files with validation.
 
+use crate::import::check_size;
+use crate::validation::input::MAX_BPMN_DMN_FILE_SIZE;
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -15,16 +18,34 @@ const BPMNDI_NAMESPACE: &str = "http://www.omg.org/spec/BPMN/20100524/DI";
 /// BPMN Importer
 ///
 /// Imports BPMN 2.0 XML content into a BPMNModel struct.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct BPMNImporter {
     /// List of errors encountered during parsing
     pub errors: Vec<String>,
+    limits: ResourceLimits,
+}
+
+impl Default for BPMNImporter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BPMNImporter {
     /// Create a new BPMNImporter
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            limits: ResourceLimits::default().with_max_file_size(MAX_BPMN_DMN_FILE_SIZE),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for BPMN models
+    ///
+    /// The default limits allow at most [`MAX_BPMN_DMN_FILE_SIZE`] bytes.
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Validate BPMN XML against XSD schema
@@ -282,6 +303,7 @@ impl BPMNImporter {
         domain_id: Uuid,
         model_name: Option<&str>,
     ) -> Result<BPMNModel> {
+        check_size(&self.limits, xml_content)?;
         // Validate XML
         self.validate(xml_content)
             .context("BPMN XML validation failed")?;
@@ -2,9 +2,10 @@
 //!
 //! Parses CADS v1.0 YAML files and converts them to CADSAsset models.
 
-use super::ImportError;
+use super::{ImportError, check_limits};
 use crate::models::Tag;
 use crate::models::cads::*;
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use serde_json::Value as JsonValue;
 use serde_yaml::Value as YamlValue;
@@ -12,12 +13,22 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 /// CADS importer for parsing CADS v1.0 YAML files
-pub struct CADSImporter;
+pub struct CADSImporter {
+    limits: ResourceLimits,
+}
 
 impl CADSImporter {
     /// Create a new CADS importer instance
     pub fn new() -> Self {
-        Self
+        Self {
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for CADS documents
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Import CADS YAML content and create CADSAsset
@@ -48,6 +59,7 @@ impl CADSImporter {
     /// assert_eq!(asset.name, "sentiment-analysis-model");
     /// ```
     pub fn import(&self, yaml_content: &str) -> Result<CADSAsset, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         let yaml_value: YamlValue = serde_yaml::from_str(yaml_content)
             .map_err(|e| ImportError::ParseError(format!("Failed to parse YAML: {}", e)))?;
 
@@ -0,0 +1,610 @@
+//! Import conflict detection and resolution
+//!
+//! Importing into an existing workspace can produce tables that collide with
+//! tables the workspace already has. [`ImportConflictReport::detect`] compares
+//! the imported tables with the existing ones and classifies every collision:
+//!
+//! - **same id** - the imported table reuses the ID of a differently named table
+//! - **identical** - same name and the same schema
+//! - **superset** - same name, the import adds columns to the existing table
+//! - **subset** - same name, the import lacks some of the existing columns
+//! - **same name, different schema** - same name, columns were added, removed
+//!   or changed
+//!
+//! Each conflict lists the resolutions that make sense for it (skip, overwrite,
+//! merge, rename). [`ImportConflictReport::apply`] applies the chosen
+//! resolutions in one call and returns the resulting set of workspace tables.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::import::conflicts::{ConflictResolution, ImportConflictReport};
+//! use data_modelling_core::models::{Column, Table};
+//!
+//! let id = Column::new("id".to_string(), "BIGINT".to_string());
+//! let existing = vec![Table::new("orders".to_string(), vec![id.clone()])];
+//! let incoming = vec![Table::new(
+//!     "orders".to_string(),
+//!     vec![id, Column::new("total".to_string(), "DECIMAL".to_string())],
+//! )];
+//!
+//! let report = ImportConflictReport::detect(&existing, &incoming);
+//! assert_eq!(report.conflicts.len(), 1);
+//!
+//! let mut resolutions = report.default_resolutions();
+//! resolutions.insert(0, ConflictResolution::Merge);
+//! let resolved = report.apply(&existing, &incoming, &resolutions).unwrap();
+//! assert_eq!(resolved.tables[0].columns.len(), 2);
+//! ```
+
+use crate::models::column::Column;
+use crate::models::table::Table;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Error applying conflict resolutions
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ImportConflictError {
+    #[error("No resolution chosen for conflicting table '{0}'")]
+    Unresolved(String),
+    #[error("Resolution '{resolution}' is not available for table '{table}'")]
+    NotAllowed { table: String, resolution: String },
+    #[error("Cannot rename to '{0}': a table with that name already exists")]
+    NameTaken(String),
+}
+
+/// How an imported table collides with an existing one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ConflictKind {
+    /// The imported table reuses the ID of a table with another name
+    SameId,
+    /// Same name and the same columns
+    Identical,
+    /// Same name; the import has all existing columns plus `added`
+    Superset { added: Vec<String> },
+    /// Same name; the import lacks the existing `missing` columns
+    Subset { missing: Vec<String> },
+    /// Same name; columns were added, removed or changed
+    #[serde(rename_all = "camelCase")]
+    SameNameDifferentSchema {
+        added: Vec<String>,
+        removed: Vec<String>,
+        changed: Vec<String>,
+    },
+}
+
+impl ConflictKind {
+    /// Kind name as used in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SameId => "same-id",
+            Self::Identical => "identical",
+            Self::Superset { .. } => "superset",
+            Self::Subset { .. } => "subset",
+            Self::SameNameDifferentSchema { .. } => "same-name-different-schema",
+        }
+    }
+
+    /// Human-readable description
+    pub fn message(&self) -> String {
+        match self {
+            Self::SameId => "ID already used by another table".to_string(),
+            Self::Identical => "table already exists with the same schema".to_string(),
+            Self::Superset { added } => format!("adds column(s) {}", added.join(", ")),
+            Self::Subset { missing } => format!("lacks column(s) {}", missing.join(", ")),
+            Self::SameNameDifferentSchema {
+                added,
+                removed,
+                changed,
+            } => {
+                let mut parts = Vec::new();
+                if !added.is_empty() {
+                    parts.push(format!("adds {}", added.join(", ")));
+                }
+                if !removed.is_empty() {
+                    parts.push(format!("removes {}", removed.join(", ")));
+                }
+                if !changed.is_empty() {
+                    parts.push(format!("changes {}", changed.join(", ")));
+                }
+                parts.join("; ")
+            }
+        }
+    }
+
+    /// Resolutions that make sense for this kind of conflict, recommended first
+    fn options(&self, rename_to: &str) -> Vec<ConflictResolution> {
+        use ConflictResolution::*;
+        match self {
+            Self::SameId => vec![Rename(rename_to.to_string()), Skip, Overwrite],
+            Self::Identical => vec![Skip, Overwrite],
+            Self::Superset { .. } => vec![Merge, Overwrite, Skip, Rename(rename_to.to_string())],
+            Self::Subset { .. } => vec![Skip, Merge, Overwrite, Rename(rename_to.to_string())],
+            Self::SameNameDifferentSchema { .. } => {
+                vec![Merge, Overwrite, Skip, Rename(rename_to.to_string())]
+            }
+        }
+    }
+}
+
+/// What to do with a conflicting imported table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", content = "name", rename_all = "camelCase")]
+pub enum ConflictResolution {
+    /// Keep the existing table and drop the imported one
+    Skip,
+    /// Replace the existing table with the imported one, keeping the existing ID
+    Overwrite,
+    /// Add the imported columns to the existing table; columns present in both
+    /// take the imported definition
+    Merge,
+    /// Import the table under another name (and a fresh ID if the ID collides)
+    Rename(String),
+}
+
+impl ConflictResolution {
+    /// Resolution name without its argument
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::Overwrite => "overwrite",
+            Self::Merge => "merge",
+            Self::Rename(_) => "rename",
+        }
+    }
+}
+
+/// A collision between an imported table and an existing table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportConflict {
+    /// Index of the imported table in the incoming list
+    pub incoming_index: usize,
+    pub incoming_id: Uuid,
+    pub incoming_name: String,
+    pub existing_id: Uuid,
+    pub existing_name: String,
+    #[serde(flatten)]
+    pub kind: ConflictKind,
+    /// Available resolutions, recommended first
+    pub options: Vec<ConflictResolution>,
+}
+
+impl ImportConflict {
+    /// Recommended resolution
+    pub fn recommended(&self) -> &ConflictResolution {
+        &self.options[0]
+    }
+
+    /// Whether `resolution` is one of the available options
+    pub fn allows(&self, resolution: &ConflictResolution) -> bool {
+        self.options.iter().any(|o| o.name() == resolution.name())
+    }
+}
+
+/// Conflicts between an import and an existing workspace
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportConflictReport {
+    /// Number of imported tables compared
+    pub tables_checked: usize,
+    pub conflicts: Vec<ImportConflict>,
+}
+
+/// Outcome of applying conflict resolutions
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedImport {
+    /// Existing tables with overwrites and merges applied, followed by the new tables
+    pub tables: Vec<Table>,
+    /// IDs of tables added to the workspace (including renamed ones)
+    pub added: Vec<Uuid>,
+    /// IDs of existing tables replaced by an imported table
+    pub overwritten: Vec<Uuid>,
+    /// IDs of existing tables that imported columns were merged into
+    pub merged: Vec<Uuid>,
+    /// Names of imported tables that were skipped
+    pub skipped: Vec<String>,
+}
+
+impl ImportConflictReport {
+    /// Compare imported tables with the tables already in the workspace
+    ///
+    /// An imported table conflicts with an existing table that has the same ID
+    /// or, failing that, the same name (case-insensitive). Imported tables
+    /// without a conflict are not listed.
+    pub fn detect(existing: &[Table], incoming: &[Table]) -> Self {
+        let by_id: HashMap<Uuid, &Table> = existing.iter().map(|t| (t.id, t)).collect();
+        let by_name: HashMap<String, &Table> = existing
+            .iter()
+            .map(|t| (t.name.to_lowercase(), t))
+            .collect();
+        let mut taken: HashSet<String> = existing
+            .iter()
+            .chain(incoming)
+            .map(|t| t.name.to_lowercase())
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for (index, table) in incoming.iter().enumerate() {
+            let (current, kind) = match by_id.get(&table.id) {
+                Some(current) if !current.name.eq_ignore_ascii_case(&table.name) => {
+                    (*current, ConflictKind::SameId)
+                }
+                Some(current) => (*current, compare_schemas(current, table)),
+                None => match by_name.get(&table.name.to_lowercase()) {
+                    Some(current) => (*current, compare_schemas(current, table)),
+                    None => continue,
+                },
+            };
+
+            let rename_to = free_name(&table.name, &taken);
+            taken.insert(rename_to.to_lowercase());
+            conflicts.push(ImportConflict {
+                incoming_index: index,
+                incoming_id: table.id,
+                incoming_name: table.name.clone(),
+                existing_id: current.id,
+                existing_name: current.name.clone(),
+                options: kind.options(&rename_to),
+                kind,
+            });
+        }
+
+        Self {
+            tables_checked: incoming.len(),
+            conflicts,
+        }
+    }
+
+    /// Whether the import can be applied without choosing resolutions
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// The recommended resolution for every conflict, keyed by incoming index
+    pub fn default_resolutions(&self) -> HashMap<usize, ConflictResolution> {
+        self.conflicts
+            .iter()
+            .map(|c| (c.incoming_index, c.recommended().clone()))
+            .collect()
+    }
+
+    /// Apply `resolutions` (keyed by incoming index) and return the resulting tables
+    ///
+    /// Every conflict needs a resolution from its options; imported tables
+    /// without a conflict are added as they are. Nothing is applied if any
+    /// resolution is missing or invalid.
+    pub fn apply(
+        &self,
+        existing: &[Table],
+        incoming: &[Table],
+        resolutions: &HashMap<usize, ConflictResolution>,
+    ) -> Result<ResolvedImport, ImportConflictError> {
+        let mut names: HashSet<String> = existing.iter().map(|t| t.name.to_lowercase()).collect();
+        let conflicts: HashMap<usize, &ImportConflict> = self
+            .conflicts
+            .iter()
+            .map(|c| (c.incoming_index, c))
+            .collect();
+
+        // Validate everything up front so a bad choice leaves nothing half-applied
+        for conflict in &self.conflicts {
+            let resolution = resolutions
+                .get(&conflict.incoming_index)
+                .ok_or_else(|| ImportConflictError::Unresolved(conflict.incoming_name.clone()))?;
+            if !conflict.allows(resolution) {
+                return Err(ImportConflictError::NotAllowed {
+                    table: conflict.incoming_name.clone(),
+                    resolution: resolution.name().to_string(),
+                });
+            }
+            if let ConflictResolution::Rename(name) = resolution
+                && !names.insert(name.to_lowercase())
+            {
+                return Err(ImportConflictError::NameTaken(name.clone()));
+            }
+        }
+
+        let mut result = ResolvedImport {
+            tables: existing.to_vec(),
+            ..Default::default()
+        };
+        let mut ids: HashSet<Uuid> = existing.iter().map(|t| t.id).collect();
+
+        for (index, table) in incoming.iter().enumerate() {
+            let Some(conflict) = conflicts.get(&index) else {
+                result.added.push(table.id);
+                ids.insert(table.id);
+                result.tables.push(table.clone());
+                continue;
+            };
+            let position = result
+                .tables
+                .iter()
+                .position(|t| t.id == conflict.existing_id);
+
+            match &resolutions[&index] {
+                ConflictResolution::Skip => result.skipped.push(table.name.clone()),
+                ConflictResolution::Overwrite => {
+                    let mut replacement = table.clone();
+                    replacement.id = conflict.existing_id;
+                    replacement.updated_at = Utc::now();
+                    if let Some(position) = position {
+                        result.tables[position] = replacement;
+                    }
+                    result.overwritten.push(conflict.existing_id);
+                }
+                ConflictResolution::Merge => {
+                    if let Some(position) = position {
+                        merge_columns(&mut result.tables[position], table);
+                    }
+                    result.merged.push(conflict.existing_id);
+                }
+                ConflictResolution::Rename(name) => {
+                    let mut renamed = table.clone();
+                    renamed.name = name.clone();
+                    if ids.contains(&renamed.id) {
+                        renamed.id = Uuid::new_v4();
+                    }
+                    ids.insert(renamed.id);
+                    result.added.push(renamed.id);
+                    result.tables.push(renamed);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Render the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Import Conflict Report\n\n");
+        out.push_str(&format!(
+            "{} imported table(s) checked: {} conflict(s).\n",
+            self.tables_checked,
+            self.conflicts.len()
+        ));
+
+        if !self.conflicts.is_empty() {
+            out.push_str(
+                "\n## Conflicts\n\n| Imported | Existing | Conflict | Details | Options |\n|---|---|---|---|---|\n",
+            );
+            for conflict in &self.conflicts {
+                let options: Vec<String> = conflict
+                    .options
+                    .iter()
+                    .map(|o| match o {
+                        ConflictResolution::Rename(name) => format!("rename to '{}'", name),
+                        other => other.name().to_string(),
+                    })
+                    .collect();
+                out.push_str(&format!(
+                    "| {} | {} ({}) | {} | {} | {} |\n",
+                    conflict.incoming_name,
+                    conflict.existing_name,
+                    conflict.existing_id,
+                    conflict.kind.name(),
+                    conflict.kind.message(),
+                    options.join(", ")
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+/// Column definition compared between tables
+fn column_signature(column: &Column) -> (String, bool, bool) {
+    (
+        column.data_type.to_lowercase(),
+        column.nullable,
+        column.primary_key,
+    )
+}
+
+/// Classify two tables with the same name by their columns
+fn compare_schemas(existing: &Table, incoming: &Table) -> ConflictKind {
+    let current: HashMap<&str, (String, bool, bool)> = existing
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), column_signature(c)))
+        .collect();
+    let imported: HashMap<&str, (String, bool, bool)> = incoming
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), column_signature(c)))
+        .collect();
+
+    let added: Vec<String> = incoming
+        .columns
+        .iter()
+        .filter(|c| !current.contains_key(c.name.as_str()))
+        .map(|c| c.name.clone())
+        .collect();
+    let removed: Vec<String> = existing
+        .columns
+        .iter()
+        .filter(|c| !imported.contains_key(c.name.as_str()))
+        .map(|c| c.name.clone())
+        .collect();
+    let changed: Vec<String> = incoming
+        .columns
+        .iter()
+        .filter(|c| {
+            current
+                .get(c.name.as_str())
+                .is_some_and(|sig| *sig != column_signature(c))
+        })
+        .map(|c| c.name.clone())
+        .collect();
+
+    match (added.is_empty(), removed.is_empty(), changed.is_empty()) {
+        (true, true, true) => ConflictKind::Identical,
+        (false, true, true) => ConflictKind::Superset { added },
+        (true, false, true) => ConflictKind::Subset { missing: removed },
+        _ => ConflictKind::SameNameDifferentSchema {
+            added,
+            removed,
+            changed,
+        },
+    }
+}
+
+/// Add the imported columns to `target`; shared columns take the imported definition
+fn merge_columns(target: &mut Table, incoming: &Table) {
+    for column in &incoming.columns {
+        match target.columns.iter_mut().find(|c| c.name == column.name) {
+            Some(existing) => *existing = column.clone(),
+            None => target.columns.push(column.clone()),
+        }
+    }
+    target.updated_at = Utc::now();
+}
+
+/// First `{name}_imported`, `{name}_imported_2`, ... not in `taken`
+fn free_name(name: &str, taken: &HashSet<String>) -> String {
+    let base = format!("{}_imported", name);
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while taken.contains(&candidate.to_lowercase()) {
+        candidate = format!("{}_{}", base, n);
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str, columns: &[(&str, &str)]) -> Table {
+        Table::new(
+            name.to_string(),
+            columns
+                .iter()
+                .map(|(n, t)| Column::new(n.to_string(), t.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_detect_classifies_conflicts() {
+        let orders = table("orders", &[("id", "BIGINT"), ("total", "DECIMAL")]);
+        let customers = table("customers", &[("id", "BIGINT"), ("name", "TEXT")]);
+        let items = table("items", &[("id", "BIGINT")]);
+        let events = table("events", &[("id", "BIGINT")]);
+        let existing = vec![orders.clone(), customers, items, events.clone()];
+
+        let mut reused_id = table("payments", &[("id", "BIGINT")]);
+        reused_id.id = events.id;
+        let incoming = vec![
+            table("Orders", &[("id", "BIGINT"), ("total", "DECIMAL")]),
+            table(
+                "customers",
+                &[("id", "BIGINT"), ("name", "TEXT"), ("email", "TEXT")],
+            ),
+            table("items", &[("id", "INT"), ("sku", "TEXT")]),
+            reused_id,
+            table("shipments", &[("id", "BIGINT")]),
+        ];
+
+        let report = ImportConflictReport::detect(&existing, &incoming);
+        assert_eq!(report.tables_checked, 5);
+        let kinds: Vec<&str> = report.conflicts.iter().map(|c| c.kind.name()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "identical",
+                "superset",
+                "same-name-different-schema",
+                "same-id"
+            ]
+        );
+        assert_eq!(report.conflicts[0].existing_id, orders.id);
+        assert_eq!(
+            report.conflicts[1].kind,
+            ConflictKind::Superset {
+                added: vec!["email".to_string()]
+            }
+        );
+        assert_eq!(
+            report.conflicts[3].recommended(),
+            &ConflictResolution::Rename("payments_imported".to_string())
+        );
+        assert!(report.to_markdown().contains("| items | items ("));
+    }
+
+    #[test]
+    fn test_apply_resolutions() {
+        let orders = table("orders", &[("id", "BIGINT"), ("total", "DECIMAL")]);
+        let customers = table("customers", &[("id", "BIGINT"), ("name", "TEXT")]);
+        let items = table("items", &[("id", "BIGINT")]);
+        let existing = vec![orders.clone(), customers.clone(), items.clone()];
+        let incoming = vec![
+            table("orders", &[("id", "BIGINT"), ("status", "TEXT")]),
+            table("customers", &[("id", "INT")]),
+            table("items", &[("id", "BIGINT")]),
+            table("shipments", &[("id", "BIGINT")]),
+        ];
+
+        let report = ImportConflictReport::detect(&existing, &incoming);
+        let mut resolutions = HashMap::new();
+        resolutions.insert(0, ConflictResolution::Merge);
+        resolutions.insert(1, ConflictResolution::Overwrite);
+        resolutions.insert(2, ConflictResolution::Skip);
+
+        let resolved = report.apply(&existing, &incoming, &resolutions).unwrap();
+        assert_eq!(resolved.tables.len(), 4);
+        let merged: Vec<&str> = resolved.tables[0]
+            .columns
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(merged, vec!["id", "total", "status"]);
+        assert_eq!(resolved.tables[1].id, customers.id);
+        assert_eq!(resolved.tables[1].columns[0].data_type, "INT");
+        assert_eq!(resolved.merged, vec![orders.id]);
+        assert_eq!(resolved.overwritten, vec![customers.id]);
+        assert_eq!(resolved.skipped, vec!["items".to_string()]);
+        assert_eq!(resolved.added, vec![incoming[3].id]);
+    }
+
+    #[test]
+    fn test_apply_rejects_missing_and_invalid_resolutions() {
+        let existing = vec![table("orders", &[("id", "BIGINT")])];
+        let mut reused = table("customers", &[("id", "BIGINT")]);
+        let incoming = vec![table("orders", &[("id", "BIGINT")])];
+        let report = ImportConflictReport::detect(&existing, &incoming);
+
+        assert_eq!(
+            report
+                .apply(&existing, &incoming, &HashMap::new())
+                .unwrap_err(),
+            ImportConflictError::Unresolved("orders".to_string())
+        );
+        let merge = HashMap::from([(0, ConflictResolution::Merge)]);
+        assert!(matches!(
+            report.apply(&existing, &incoming, &merge),
+            Err(ImportConflictError::NotAllowed { .. })
+        ));
+
+        reused.id = existing[0].id;
+        let incoming = vec![reused];
+        let report = ImportConflictReport::detect(&existing, &incoming);
+        let taken = HashMap::from([(0, ConflictResolution::Rename("Orders".to_string()))]);
+        assert_eq!(
+            report.apply(&existing, &incoming, &taken).unwrap_err(),
+            ImportConflictError::NameTaken("Orders".to_string())
+        );
+
+        let resolved = report
+            .apply(&existing, &incoming, &report.default_resolutions())
+            .unwrap();
+        assert_eq!(resolved.tables[1].name, "customers_imported");
+        assert_ne!(resolved.tables[1].id, existing[0].id);
+    }
+}
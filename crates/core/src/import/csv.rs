@@ -0,0 +1,448 @@
+//! CSV/TSV importer with delimiter, header and type sniffing
+//!
+//! Samples the first rows of a delimited text file and infers a table from them:
+//!
+//! - **Delimiter detection** - picks the most consistent of `,`, `\t`, `;` and `|`
+//! - **Header detection** - treats the first row as a header when its cells are
+//!   distinct, non-empty and untyped (no numbers, booleans or dates)
+//! - **Type inference** - cells are converted to JSON scalars and passed through
+//!   the [`inference`](crate::inference) engine, so integer, number, boolean,
+//!   date/timestamp and string formats (uuid, email, ...) are detected the same
+//!   way as for JSON records
+//!
+//! Quoted fields follow RFC 4180: fields may be wrapped in `"`, contain the
+//! delimiter or line breaks, and escape quotes by doubling them.
+
+use crate::import::odcs_shared::column_to_column_data;
+use crate::import::{ImportError, ImportResult, TableData, check_size};
+use crate::inference::{Format, InferenceConfig, SchemaInferrer, detect_format};
+use crate::models::{Column, IdGenerator, Table};
+use crate::validation::limits::ResourceLimits;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// Delimiters considered during detection, in order of preference
+const CANDIDATE_DELIMITERS: &[char] = &[',', '\t', ';', '|'];
+
+/// Number of records inspected when detecting the delimiter
+const DELIMITER_SAMPLE_LINES: usize = 20;
+
+/// Importer for CSV and TSV files.
+#[derive(Debug, Clone)]
+pub struct CsvImporter {
+    /// Maximum number of data rows sampled for type inference
+    sample_rows: usize,
+    /// Fixed delimiter (detected when `None`)
+    delimiter: Option<char>,
+    /// Whether the first row is a header (detected when `None`)
+    has_header: Option<bool>,
+    /// Name of the imported table
+    table_name: String,
+    limits: ResourceLimits,
+    /// Generator for the ID of the imported table
+    ids: IdGenerator,
+}
+
+impl Default for CsvImporter {
+    fn default() -> Self {
+        Self {
+            sample_rows: 1000,
+            delimiter: None,
+            has_header: None,
+            table_name: "data".to_string(),
+            limits: ResourceLimits::default(),
+            ids: IdGenerator::default(),
+        }
+    }
+}
+
+impl CsvImporter {
+    /// Create a new CSV importer that detects the delimiter and header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::csv::CsvImporter;
+    ///
+    /// let importer = CsvImporter::new().with_table_name("orders");
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`ResourceLimits`] for CSV files
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Generate the table ID following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Number of data rows sampled for type inference (0 = all rows)
+    pub fn with_sample_rows(mut self, rows: usize) -> Self {
+        self.sample_rows = rows;
+        self
+    }
+
+    /// Use a fixed delimiter instead of detecting it
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Declare whether the first row is a header instead of detecting it
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = Some(has_header);
+        self
+    }
+
+    /// Name of the imported table
+    pub fn with_table_name(mut self, name: impl Into<String>) -> Self {
+        self.table_name = name.into();
+        self
+    }
+
+    /// Import CSV/TSV content as a single table.
+    ///
+    /// Rows whose field count differs from the header are padded or truncated and
+    /// reported in [`ImportResult::errors`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::csv::CsvImporter;
+    ///
+    /// let csv = "id,email,signed_up\n1,alice@example.com,2024-01-15\n2,bob@example.com,2024-02-01\n";
+    /// let result = CsvImporter::new().with_table_name("users").import(csv).unwrap();
+    ///
+    /// let columns = &result.tables[0].columns;
+    /// assert_eq!(columns[0].name, "id");
+    /// assert_eq!(columns[0].data_type, "integer");
+    /// assert_eq!(columns[2].data_type, "date");
+    /// ```
+    pub fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        check_size(&self.limits, content)?;
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let delimiter = match self.delimiter {
+            Some(delimiter) => delimiter,
+            None => detect_delimiter(content),
+        };
+
+        // Header (if any) plus the sampled data rows
+        let limit = match self.sample_rows {
+            0 => None,
+            rows => Some(rows + 1),
+        };
+        let records = parse_records(content, delimiter, limit);
+        if records.is_empty() {
+            return Err(ImportError::ParseError(
+                "CSV content contains no rows".to_string(),
+            ));
+        }
+
+        let has_header = self.has_header.unwrap_or_else(|| detect_header(&records));
+        let width = records.iter().map(Vec::len).max().unwrap_or(0);
+        let (names, rows) = if has_header {
+            (column_names(&records[0], width), &records[1..])
+        } else {
+            (column_names(&[], width), &records[..])
+        };
+
+        let mut errors = Vec::new();
+        let config = InferenceConfig::builder()
+            .sample_size(self.sample_rows)
+            .build();
+        let mut inferrer = SchemaInferrer::with_config(config);
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != names.len() {
+                let line = i + 1 + usize::from(has_header);
+                errors.push(ImportError::ParseError(format!(
+                    "Row {} has {} fields, expected {}",
+                    line,
+                    row.len(),
+                    names.len()
+                )));
+            }
+            let record: Map<String, Value> = names
+                .iter()
+                .enumerate()
+                .map(|(idx, name)| {
+                    let cell = row.get(idx).map(String::as_str).unwrap_or("");
+                    (name.clone(), cell_value(cell))
+                })
+                .collect();
+            inferrer
+                .add_value(&Value::Object(record))
+                .map_err(|e| ImportError::ParseError(e.to_string()))?;
+        }
+
+        let schema = inferrer
+            .finalize()
+            .map_err(|e| ImportError::ParseError(e.to_string()))?
+            .to_schema_object(&self.table_name);
+
+        // The inferrer orders fields by name; keep the file's column order instead
+        let columns: Vec<Column> = names
+            .iter()
+            .map(
+                |name| match schema.properties.iter().find(|p| &p.name == name) {
+                    Some(property) => Column::from(property),
+                    // Only seen without data rows
+                    None => Column::new(name.clone(), "string".to_string()),
+                },
+            )
+            .collect();
+        let table = Table::new_with_generator(self.table_name.clone(), columns, &self.ids);
+
+        Ok(ImportResult {
+            tables: vec![TableData {
+                table_index: 0,
+                id: Some(table.id.to_string()),
+                name: Some(table.name.clone()),
+                columns: table.columns.iter().map(column_to_column_data).collect(),
+                ..Default::default()
+            }],
+            tables_requiring_name: Vec::new(),
+            errors,
+            ai_suggestions: None,
+        })
+    }
+}
+
+/// Detect the delimiter of delimited text.
+///
+/// Each candidate is scored by how consistently it splits the first records into
+/// the same number of fields (more than one); ties go to the earlier candidate.
+/// Falls back to `,`.
+pub fn detect_delimiter(content: &str) -> char {
+    let mut best = (',', 0usize);
+    for &candidate in CANDIDATE_DELIMITERS {
+        let records = parse_records(content, candidate, Some(DELIMITER_SAMPLE_LINES));
+        let Some(first) = records.first() else {
+            continue;
+        };
+        let width = first.len();
+        if width < 2 {
+            continue;
+        }
+        let consistent = records.iter().filter(|r| r.len() == width).count();
+        // Consistent rows dominate; the field count breaks ties between candidates
+        let score = consistent * 1000 + width;
+        if score > best.1 {
+            best = (candidate, score);
+        }
+    }
+    best.0
+}
+
+/// Parse delimited text into records, honouring quoted fields.
+///
+/// Blank lines are skipped. At most `limit` records are returned.
+pub(crate) fn parse_records(
+    content: &str,
+    delimiter: char,
+    limit: Option<usize>,
+) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                finish_record(&mut records, &mut record, &mut field);
+                if limit.is_some_and(|limit| records.len() >= limit) {
+                    return records;
+                }
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        finish_record(&mut records, &mut record, &mut field);
+    }
+    if let Some(limit) = limit {
+        records.truncate(limit);
+    }
+    records
+}
+
+/// Close the current field and record, dropping blank lines
+fn finish_record(records: &mut Vec<Vec<String>>, record: &mut Vec<String>, field: &mut String) {
+    record.push(std::mem::take(field));
+    let done = std::mem::take(record);
+    if !(done.len() == 1 && done[0].is_empty()) {
+        records.push(done);
+    }
+}
+
+/// Whether the first record looks like a header row.
+///
+/// A header has distinct, non-empty cells that are not typed values. If every
+/// record is untyped, a first row repeated in the data is not a header.
+pub(crate) fn detect_header(records: &[Vec<String>]) -> bool {
+    let Some(first) = records.first() else {
+        return false;
+    };
+    let mut seen = HashSet::new();
+    let distinct = first
+        .iter()
+        .all(|cell| !cell.trim().is_empty() && seen.insert(cell.trim()));
+    if !distinct || first.iter().any(|cell| is_typed(cell)) {
+        return false;
+    }
+    // Single-row files: a row of distinct labels is most likely a header
+    records.len() == 1 || !records[1..].iter().any(|row| row == first)
+}
+
+/// Whether a cell holds a number, boolean or date/time value
+fn is_typed(cell: &str) -> bool {
+    !matches!(cell_value(cell), Value::String(_) | Value::Null)
+        || matches!(
+            detect_format(cell),
+            Format::Date | Format::DateTime | Format::Time
+        )
+}
+
+/// Column names from the header row, filling blanks and de-duplicating
+pub(crate) fn column_names(header: &[String], width: usize) -> Vec<String> {
+    let mut seen = HashSet::new();
+    (0..width.max(header.len()))
+        .map(|idx| {
+            let base = header
+                .get(idx)
+                .map(|name| name.trim())
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("column_{}", idx + 1));
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while !seen.insert(name.clone()) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+/// Convert a cell to the JSON scalar it most likely represents
+pub(crate) fn cell_value(cell: &str) -> Value {
+    let trimmed = cell.trim();
+    if trimmed.is_empty() {
+        return Value::Null;
+    }
+    if trimmed.eq_ignore_ascii_case("true") {
+        return Value::Bool(true);
+    }
+    if trimmed.eq_ignore_ascii_case("false") {
+        return Value::Bool(false);
+    }
+    // Keep identifiers with leading zeros (e.g. zip codes) as strings
+    let leading_zero = trimmed.len() > 1 && trimmed.starts_with('0') && !trimmed.starts_with("0.");
+    if !leading_zero {
+        if let Ok(i) = trimmed.parse::<i64>() {
+            return Value::from(i);
+        }
+        if let Ok(f) = trimmed.parse::<f64>()
+            && f.is_finite()
+            && trimmed.chars().any(|c| c.is_ascii_digit())
+        {
+            return Value::from(f);
+        }
+    }
+    Value::String(cell.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_delimiter() {
+        assert_eq!(detect_delimiter("a,b,c\n1,2,3\n"), ',');
+        assert_eq!(detect_delimiter("a\tb\tc\n1\t2,5\t3\n"), '\t');
+        assert_eq!(detect_delimiter("a;b\n1,5;2,5\n3,0;4,0\n"), ';');
+        assert_eq!(detect_delimiter("a|b\n1|2\n"), '|');
+    }
+
+    #[test]
+    fn test_quoted_fields() {
+        let records = parse_records(
+            "name,notes\r\n\"Smith, J\",\"said \"\"hi\"\"\nthen left\"\r\n",
+            ',',
+            None,
+        );
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1][0], "Smith, J");
+        assert_eq!(records[1][1], "said \"hi\"\nthen left");
+    }
+
+    #[test]
+    fn test_tsv_import_infers_types() {
+        let tsv = "id\tprice\tactive\tcreated_at\tzip\tcomment\n\
+                   1\t9.99\ttrue\t2024-01-15T10:00:00Z\t01234\t\n\
+                   2\t12\tfalse\t2024-01-16T11:30:00Z\t99999\tfragile\n";
+        let result = CsvImporter::new().import(tsv).unwrap();
+        assert!(result.errors.is_empty());
+
+        let columns = &result.tables[0].columns;
+        let types: Vec<(&str, &str)> = columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.data_type.as_str()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("id", "integer"),
+                ("price", "number"),
+                ("active", "boolean"),
+                ("created_at", "timestamp"),
+                ("zip", "string"),
+                ("comment", "string"),
+            ]
+        );
+        assert!(columns[5].nullable);
+        assert!(!columns[0].nullable);
+    }
+
+    #[test]
+    fn test_headerless_import() {
+        let csv = "1,alice\n2,bob\n3\n";
+        let result = CsvImporter::new()
+            .with_table_name("people")
+            .import(csv)
+            .unwrap();
+
+        let table = &result.tables[0];
+        assert_eq!(table.name.as_deref(), Some("people"));
+        assert_eq!(table.columns[0].name, "column_1");
+        assert_eq!(table.columns[0].data_type, "integer");
+        assert_eq!(table.columns[1].name, "column_2");
+        // The short row is reported but still imported
+        assert_eq!(result.errors.len(), 1);
+    }
+}
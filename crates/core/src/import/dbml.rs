@@ -0,0 +1,1060 @@
+//! DBML (dbdiagram.io) importer
+//!
+//! Parses [DBML](https://dbml.dbdiagram.io/docs/) documents into tables:
+//!
+//! - **Tables** - columns with their type and settings (`pk`, `not null`, `unique`,
+//!   `increment`, `default`, `note`); the table `Note` becomes its description and
+//!   a schema prefix (`core.users`) is kept as the `schemaName` metadata
+//! - **Enums** - columns typed with an enum get its values as `enum_values`
+//! - **Refs** - top-level (`Ref: a.b > c.d`) and inline (`[ref: > c.d]`) refs become
+//!   `foreignKey` relationships on the referencing column; one-to-one (`-`) and
+//!   many-to-many (`<>`) refs are recorded in the [`DBML_RELATION_PROPERTY`] custom
+//!   property and `delete`/`update` actions as `onDelete`/`onUpdate`
+//! - **Indexes** - stored as the `indexes` metadata of the table; composite
+//!   primary keys (`(a, b) [pk]`) mark the columns as primary key instead
+//!
+//! `Project`, `TableGroup`, `TablePartial`, `Records` and sticky note blocks are
+//! skipped.
+
+use super::{ColumnData, ImportError, ImportResult, TableData, check_size};
+use crate::models::PropertyRelationship;
+use crate::validation::limits::ResourceLimits;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Column custom property recording the cardinality of one-to-one (`-`) and
+/// many-to-many (`<>`) refs; many-to-one refs (`>`) have none
+pub const DBML_RELATION_PROPERTY: &str = "dbmlRelation";
+
+/// Top-level blocks that carry no table structure
+const SKIPPED_BLOCKS: &[&str] = &["project", "tablegroup", "tablepartial", "records", "note"];
+
+/// Importer for DBML documents.
+#[derive(Debug, Clone, Default)]
+pub struct DbmlImporter {
+    limits: ResourceLimits,
+}
+
+impl DbmlImporter {
+    /// Create a new DBML importer
+    pub fn new() -> Self {
+        Self {
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for DBML files
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Import DBML content.
+    ///
+    /// Refs to unknown tables or columns are reported in [`ImportResult::errors`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::dbml::DbmlImporter;
+    ///
+    /// let dbml = r#"
+    /// Table users {
+    ///   id integer [pk]
+    ///   email varchar(255) [not null, unique]
+    /// }
+    ///
+    /// Table posts {
+    ///   id integer [pk]
+    ///   user_id integer [ref: > users.id]
+    /// }
+    /// "#;
+    /// let result = DbmlImporter::new().import(dbml).unwrap();
+    ///
+    /// assert_eq!(result.tables.len(), 2);
+    /// assert_eq!(result.tables[1].columns[1].relationships[0].to, "users.id");
+    /// ```
+    pub fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        check_size(&self.limits, content)?;
+        let tokens = tokenize(content)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            document: Document::default(),
+        };
+        parser.parse_document()?;
+        Ok(parser.document.into_import_result())
+    }
+}
+
+// ============================================================================
+// Tokenizer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Identifier, keyword, number or color (`users`, `pk`, `42`, `#3498db`)
+    Word(String),
+    /// Quoted string (`'...'`, `"..."` or `'''...'''`)
+    Str(String),
+    /// Backtick expression (`` `now()` ``)
+    Expr(String),
+    /// Punctuation and ref operators (`{`, `:`, `>`, `-`, ...)
+    Sym(char),
+    /// Many-to-many ref operator `<>`
+    ManyToMany,
+    Newline,
+}
+
+fn tokenize(content: &str) -> Result<Vec<(Token, usize)>, ImportError> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\n' => {
+                tokens.push((Token::Newline, line));
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    if chars[i] == '\n' {
+                        line += 1;
+                    }
+                    i += 1;
+                }
+                i += 2;
+            }
+            '\'' | '"' | '`' => {
+                let start_line = line;
+                let triple = c == '\'' && next == Some('\'') && chars.get(i + 2) == Some(&'\'');
+                let delimiter_len = if triple { 3 } else { 1 };
+                i += delimiter_len;
+                let mut value = String::new();
+                loop {
+                    let Some(&ch) = chars.get(i) else {
+                        return Err(ImportError::ParseError(format!(
+                            "Unterminated string starting on line {}",
+                            start_line
+                        )));
+                    };
+                    if ch == '\\' && i + 1 < chars.len() {
+                        value.push(chars[i + 1]);
+                        i += 2;
+                        continue;
+                    }
+                    let closes = if triple {
+                        chars[i..].starts_with(&['\'', '\'', '\''])
+                    } else {
+                        ch == c
+                    };
+                    if closes {
+                        i += delimiter_len;
+                        break;
+                    }
+                    if ch == '\n' {
+                        line += 1;
+                    }
+                    value.push(ch);
+                    i += 1;
+                }
+                let token = match c {
+                    '`' => Token::Expr(value),
+                    _ if triple => Token::Str(dedent(&value)),
+                    _ => Token::Str(value),
+                };
+                tokens.push((token, start_line));
+            }
+            '<' if next == Some('>') => {
+                tokens.push((Token::ManyToMany, line));
+                i += 2;
+            }
+            '{' | '}' | '[' | ']' | '(' | ')' | ':' | ',' | '.' | '<' | '>' | '-' | '~' => {
+                tokens.push((Token::Sym(c), line));
+                i += 1;
+            }
+            _ => {
+                let start = i;
+                let is_number = c.is_ascii_digit();
+                while i < chars.len() {
+                    let ch = chars[i];
+                    let is_word = ch.is_alphanumeric() || ch == '_' || ch == '#' || ch == '$';
+                    let is_decimal_point = is_number
+                        && ch == '.'
+                        && chars.get(i + 1).is_some_and(|d| d.is_ascii_digit());
+                    if !is_word && !is_decimal_point {
+                        break;
+                    }
+                    i += 1;
+                }
+                if i == start {
+                    return Err(ImportError::ParseError(format!(
+                        "Unexpected character '{}' on line {}",
+                        c, line
+                    )));
+                }
+                tokens.push((Token::Word(chars[start..i].iter().collect()), line));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Strip the common indentation and surrounding blank lines of a multi-line string
+fn dedent(value: &str) -> String {
+    let lines: Vec<&str> = value.lines().collect();
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+/// Value of a `[key: value]` setting
+#[derive(Debug, Clone)]
+enum SettingValue {
+    None,
+    Str(String),
+    Expr(String),
+    Word(String),
+    Ref(char, Endpoint),
+}
+
+impl SettingValue {
+    fn text(&self) -> Option<&str> {
+        match self {
+            Self::Str(s) | Self::Expr(s) | Self::Word(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// JSON value of a column default
+    fn to_default(&self) -> Option<Value> {
+        match self {
+            Self::Str(s) => Some(json!(s)),
+            // Expressions keep their backticks so exporters can tell them from strings
+            Self::Expr(e) => Some(json!(format!("`{}`", e))),
+            Self::Word(w) => Some(match w.to_lowercase().as_str() {
+                "true" => json!(true),
+                "false" => json!(false),
+                "null" => Value::Null,
+                _ => w
+                    .parse::<i64>()
+                    .map(Value::from)
+                    .or_else(|_| w.parse::<f64>().map(Value::from))
+                    .unwrap_or_else(|_| json!(w)),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// One side of a ref: a table and one or more of its columns
+#[derive(Debug, Clone)]
+struct Endpoint {
+    table: String,
+    columns: Vec<String>,
+}
+
+#[derive(Debug)]
+struct RefDef {
+    op: char,
+    from: Endpoint,
+    to: Endpoint,
+    settings: Vec<(String, SettingValue)>,
+    line: usize,
+}
+
+#[derive(Debug, Default)]
+struct Document {
+    tables: Vec<TableData>,
+    aliases: HashMap<String, String>,
+    enums: HashMap<String, Vec<String>>,
+    refs: Vec<RefDef>,
+    errors: Vec<ImportError>,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    document: Document,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|(_, l)| *l)
+            .unwrap_or(1)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> ImportError {
+        ImportError::ParseError(format!("Line {}: {}", self.line(), message))
+    }
+
+    fn skip_newlines(&mut self) {
+        while self.peek() == Some(&Token::Newline) {
+            self.pos += 1;
+        }
+    }
+
+    fn at_sym(&self, sym: char) -> bool {
+        self.peek() == Some(&Token::Sym(sym))
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_sym(&mut self, sym: char) -> Result<(), ImportError> {
+        if self.at_sym(sym) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", sym)))
+        }
+    }
+
+    /// Identifier: a word or a quoted name
+    fn ident(&mut self) -> Result<String, ImportError> {
+        match self.peek() {
+            Some(Token::Word(_)) | Some(Token::Str(_)) => match self.next() {
+                Some(Token::Word(s)) | Some(Token::Str(s)) => Ok(s),
+                _ => unreachable!(),
+            },
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    /// Dotted name such as `core.users`
+    fn qualified_name(&mut self) -> Result<Vec<String>, ImportError> {
+        let mut parts = vec![self.ident()?];
+        while self.at_sym('.')
+            && !matches!(self.tokens.get(self.pos + 1), Some((Token::Sym('('), _)))
+        {
+            self.pos += 1;
+            parts.push(self.ident()?);
+        }
+        Ok(parts)
+    }
+
+    fn parse_document(&mut self) -> Result<(), ImportError> {
+        loop {
+            self.skip_newlines();
+            let Some(token) = self.peek().cloned() else {
+                return Ok(());
+            };
+            let Token::Word(keyword) = token else {
+                return Err(self.error("expected Table, Enum or Ref"));
+            };
+            let keyword = keyword.to_lowercase();
+            match keyword.as_str() {
+                "table" => {
+                    self.pos += 1;
+                    self.parse_table()?;
+                }
+                "enum" => {
+                    self.pos += 1;
+                    self.parse_enum()?;
+                }
+                "ref" => {
+                    self.pos += 1;
+                    self.parse_ref_statement()?;
+                }
+                _ if SKIPPED_BLOCKS.contains(&keyword.as_str()) => self.skip_block()?,
+                _ => return Err(self.error(format!("unknown element '{}'", keyword))),
+            }
+        }
+    }
+
+    /// Skip a block header and its balanced `{ ... }` body
+    fn skip_block(&mut self) -> Result<(), ImportError> {
+        while !self.at_sym('{') {
+            if self.next().is_none() {
+                return Err(self.error("expected '{'"));
+            }
+        }
+        let mut depth = 0;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Sym('{') => depth += 1,
+                Token::Sym('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("unterminated block"))
+    }
+
+    fn parse_table(&mut self) -> Result<(), ImportError> {
+        let name = self.qualified_name()?;
+        let (schema, table_name) = split_schema(name);
+        if self.at_keyword("as") {
+            self.pos += 1;
+            let alias = self.ident()?;
+            self.document.aliases.insert(alias, table_name.clone());
+        }
+        let mut note = None;
+        if self.at_sym('[') {
+            for (key, value) in self.parse_settings()? {
+                if key == "note" {
+                    note = value.text().map(String::from);
+                }
+            }
+        }
+        self.skip_newlines();
+        self.expect_sym('{')?;
+
+        let mut columns: Vec<ColumnData> = Vec::new();
+        let mut indexes = Vec::new();
+        loop {
+            self.skip_newlines();
+            if self.at_sym('}') {
+                self.pos += 1;
+                break;
+            }
+            if self.peek().is_none() {
+                return Err(self.error(format!("unterminated table '{}'", table_name)));
+            }
+            let is_block = matches!(
+                self.tokens.get(self.pos + 1),
+                Some((Token::Sym(':'), _)) | Some((Token::Sym('{'), _))
+            );
+            if is_block && self.at_keyword("note") {
+                self.pos += 1;
+                note = Some(self.parse_note_body()?);
+            } else if is_block && self.at_keyword("indexes") {
+                self.pos += 1;
+                indexes = self.parse_indexes(&mut columns)?;
+            } else if self.at_sym('~') {
+                // Table partial injection (~partial_name)
+                self.pos += 1;
+                self.ident()?;
+            } else {
+                let column = self.parse_column(&table_name)?;
+                columns.push(column);
+            }
+        }
+
+        let mut odcs_metadata = HashMap::new();
+        if let Some(schema) = schema {
+            odcs_metadata.insert("schemaName".to_string(), json!(schema));
+        }
+        if !indexes.is_empty() {
+            odcs_metadata.insert("indexes".to_string(), Value::Array(indexes));
+        }
+        self.document.tables.push(TableData {
+            table_index: self.document.tables.len(),
+            name: Some(table_name),
+            description: note.map(Value::String),
+            columns,
+            odcs_metadata,
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    /// `Note: '...'` or `Note { '...' }`
+    fn parse_note_body(&mut self) -> Result<String, ImportError> {
+        let braced = self.at_sym('{');
+        self.pos += 1;
+        self.skip_newlines();
+        let note = match self.next() {
+            Some(Token::Str(s)) => s,
+            _ => return Err(self.error("expected a note string")),
+        };
+        if braced {
+            self.skip_newlines();
+            self.expect_sym('}')?;
+        }
+        Ok(note)
+    }
+
+    fn parse_column(&mut self, table_name: &str) -> Result<ColumnData, ImportError> {
+        let name = self.ident()?;
+        let data_type = self.parse_type()?;
+        let mut column = ColumnData {
+            name,
+            data_type,
+            ..Default::default()
+        };
+        if self.at_sym('[') {
+            for (key, value) in self.parse_settings()? {
+                match key.as_str() {
+                    "pk" | "primary key" => column.primary_key = true,
+                    "not null" => column.nullable = false,
+                    "null" => column.nullable = true,
+                    "unique" => column.unique = true,
+                    "increment" => {
+                        column
+                            .custom_properties
+                            .insert("autoIncrement".to_string(), json!(true));
+                    }
+                    "note" => column.description = value.text().map(String::from),
+                    "default" => column.default_value = value.to_default(),
+                    "ref" => {
+                        if let SettingValue::Ref(op, to) = value {
+                            let from = Endpoint {
+                                table: table_name.to_string(),
+                                columns: vec![column.name.clone()],
+                            };
+                            let line = self.line();
+                            self.document.refs.push(RefDef {
+                                op,
+                                from,
+                                to,
+                                settings: Vec::new(),
+                                line,
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        match self.peek() {
+            None | Some(Token::Newline) | Some(Token::Sym('}')) => Ok(column),
+            _ => Err(self.error(format!("unexpected token after column '{}'", column.name))),
+        }
+    }
+
+    /// Column type: a name with optional `(...)` parameters
+    fn parse_type(&mut self) -> Result<String, ImportError> {
+        let mut data_type = self.qualified_name()?.join(".");
+        if self.at_sym('(') {
+            self.pos += 1;
+            let mut params = Vec::new();
+            loop {
+                match self.next() {
+                    Some(Token::Sym(')')) => break,
+                    Some(Token::Sym(',')) => {}
+                    Some(Token::Word(w)) | Some(Token::Str(w)) => params.push(w),
+                    _ => return Err(self.error("unterminated type parameters")),
+                }
+            }
+            data_type = format!("{}({})", data_type, params.join(","));
+        }
+        Ok(data_type)
+    }
+
+    /// `[key, key: value, ...]`; keys are lowercased and may span several words
+    fn parse_settings(&mut self) -> Result<Vec<(String, SettingValue)>, ImportError> {
+        self.expect_sym('[')?;
+        let mut settings = Vec::new();
+        loop {
+            self.skip_newlines();
+            if self.at_sym(']') {
+                self.pos += 1;
+                return Ok(settings);
+            }
+            let mut key_words = Vec::new();
+            while let Some(Token::Word(w)) = self.peek() {
+                key_words.push(w.to_lowercase());
+                self.pos += 1;
+            }
+            if key_words.is_empty() {
+                return Err(self.error("expected a setting"));
+            }
+            let key = key_words.join(" ");
+            let value = if self.at_sym(':') {
+                self.pos += 1;
+                if key == "ref" {
+                    let op = self.parse_ref_op()?;
+                    SettingValue::Ref(op, self.parse_endpoint()?)
+                } else {
+                    self.parse_setting_value()?
+                }
+            } else {
+                SettingValue::None
+            };
+            settings.push((key, value));
+            self.skip_newlines();
+            if self.at_sym(',') {
+                self.pos += 1;
+            } else if !self.at_sym(']') {
+                return Err(self.error("expected ',' or ']'"));
+            }
+        }
+    }
+
+    fn parse_setting_value(&mut self) -> Result<SettingValue, ImportError> {
+        match self.next() {
+            Some(Token::Str(s)) => Ok(SettingValue::Str(s)),
+            Some(Token::Expr(e)) => Ok(SettingValue::Expr(e)),
+            Some(Token::Sym('-')) => match self.next() {
+                Some(Token::Word(w)) => Ok(SettingValue::Word(format!("-{}", w))),
+                _ => Err(self.error("expected a number")),
+            },
+            Some(Token::Word(w)) => {
+                // Multi-word values such as `no action` or `set null`
+                let mut words = vec![w];
+                while let Some(Token::Word(w)) = self.peek() {
+                    words.push(w.clone());
+                    self.pos += 1;
+                }
+                Ok(SettingValue::Word(words.join(" ")))
+            }
+            _ => Err(self.error("expected a setting value")),
+        }
+    }
+
+    fn parse_ref_op(&mut self) -> Result<char, ImportError> {
+        match self.next() {
+            Some(Token::Sym(op @ ('>' | '<' | '-'))) => Ok(op),
+            Some(Token::ManyToMany) => Ok('*'),
+            _ => Err(self.error("expected a ref operator (>, <, - or <>)")),
+        }
+    }
+
+    /// `table.column`, `schema.table.column` or `table.(a, b)`
+    fn parse_endpoint(&mut self) -> Result<Endpoint, ImportError> {
+        let mut parts = self.qualified_name()?;
+        if self.at_sym('.') {
+            self.pos += 1;
+            self.expect_sym('(')?;
+            let mut columns = Vec::new();
+            loop {
+                columns.push(self.ident()?);
+                if self.at_sym(',') {
+                    self.pos += 1;
+                } else {
+                    break;
+                }
+            }
+            self.expect_sym(')')?;
+            return Ok(Endpoint {
+                table: parts.join("."),
+                columns,
+            });
+        }
+        if parts.len() < 2 {
+            return Err(self.error("ref endpoints must be written as table.column"));
+        }
+        let column = parts.pop().unwrap_or_default();
+        Ok(Endpoint {
+            table: parts.join("."),
+            columns: vec![column],
+        })
+    }
+
+    /// `Ref [name]: a.b > c.d [settings]` or `Ref [name] { ... }`
+    fn parse_ref_statement(&mut self) -> Result<(), ImportError> {
+        if !self.at_sym(':') && !self.at_sym('{') {
+            self.ident()?;
+        }
+        if self.at_sym(':') {
+            self.pos += 1;
+            return self.parse_ref_definition();
+        }
+        self.expect_sym('{')?;
+        loop {
+            self.skip_newlines();
+            if self.at_sym('}') {
+                self.pos += 1;
+                return Ok(());
+            }
+            self.parse_ref_definition()?;
+        }
+    }
+
+    fn parse_ref_definition(&mut self) -> Result<(), ImportError> {
+        let line = self.line();
+        let from = self.parse_endpoint()?;
+        let op = self.parse_ref_op()?;
+        let to = self.parse_endpoint()?;
+        let settings = if self.at_sym('[') {
+            self.parse_settings()?
+        } else {
+            Vec::new()
+        };
+        self.document.refs.push(RefDef {
+            op,
+            from,
+            to,
+            settings,
+            line,
+        });
+        Ok(())
+    }
+
+    fn parse_enum(&mut self) -> Result<(), ImportError> {
+        let name = self.qualified_name()?.join(".");
+        self.skip_newlines();
+        self.expect_sym('{')?;
+        let mut values = Vec::new();
+        loop {
+            self.skip_newlines();
+            if self.at_sym('}') {
+                self.pos += 1;
+                break;
+            }
+            values.push(self.ident()?);
+            if self.at_sym('[') {
+                self.parse_settings()?;
+            }
+        }
+        self.document.enums.insert(name.to_lowercase(), values);
+        Ok(())
+    }
+
+    /// `indexes { ... }` entries, marking composite primary keys on `columns`
+    fn parse_indexes(&mut self, columns: &mut [ColumnData]) -> Result<Vec<Value>, ImportError> {
+        self.expect_sym('{')?;
+        let mut indexes = Vec::new();
+        loop {
+            self.skip_newlines();
+            if self.at_sym('}') {
+                self.pos += 1;
+                return Ok(indexes);
+            }
+            let mut index_columns = Vec::new();
+            if self.at_sym('(') {
+                self.pos += 1;
+                loop {
+                    match self.next() {
+                        Some(Token::Word(w)) | Some(Token::Str(w)) => index_columns.push(w),
+                        Some(Token::Expr(e)) => index_columns.push(format!("`{}`", e)),
+                        _ => return Err(self.error("expected an index column")),
+                    }
+                    match self.next() {
+                        Some(Token::Sym(',')) => {}
+                        Some(Token::Sym(')')) => break,
+                        _ => return Err(self.error("expected ',' or ')'")),
+                    }
+                }
+            } else {
+                match self.next() {
+                    Some(Token::Word(w)) | Some(Token::Str(w)) => index_columns.push(w),
+                    Some(Token::Expr(e)) => index_columns.push(format!("`{}`", e)),
+                    _ => return Err(self.error("expected an index column")),
+                }
+            }
+
+            let mut index = serde_json::Map::new();
+            index.insert("columns".to_string(), json!(index_columns));
+            let mut primary_key = false;
+            if self.at_sym('[') {
+                for (key, value) in self.parse_settings()? {
+                    match key.as_str() {
+                        "pk" | "primary key" => primary_key = true,
+                        "unique" => {
+                            index.insert("unique".to_string(), json!(true));
+                        }
+                        "name" | "type" | "note" => {
+                            if let Some(text) = value.text() {
+                                index.insert(key, json!(text));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if primary_key {
+                for (position, name) in index_columns.iter().enumerate() {
+                    if let Some(column) = columns.iter_mut().find(|c| &c.name == name) {
+                        column.primary_key = true;
+                        column.primary_key_position = Some(position as i32 + 1);
+                    }
+                }
+            } else {
+                indexes.push(Value::Object(index));
+            }
+        }
+    }
+}
+
+/// Split `schema.table` into its schema and table name
+fn split_schema(mut parts: Vec<String>) -> (Option<String>, String) {
+    let table = parts.pop().unwrap_or_default();
+    let schema = (!parts.is_empty()).then(|| parts.join("."));
+    (schema, table)
+}
+
+impl Document {
+    fn into_import_result(mut self) -> ImportResult {
+        self.apply_enums();
+        for def in std::mem::take(&mut self.refs) {
+            self.apply_ref(def);
+        }
+        ImportResult {
+            tables: self.tables,
+            tables_requiring_name: Vec::new(),
+            errors: self.errors,
+            ai_suggestions: None,
+        }
+    }
+
+    fn apply_enums(&mut self) {
+        for table in &mut self.tables {
+            for column in &mut table.columns {
+                let data_type = column.data_type.to_lowercase();
+                let short_name = data_type.rsplit('.').next().unwrap_or(&data_type);
+                if let Some(values) = self
+                    .enums
+                    .get(&data_type)
+                    .or_else(|| self.enums.get(short_name))
+                {
+                    column.enum_values = Some(values.clone());
+                }
+            }
+        }
+    }
+
+    /// Index of the table named by a ref endpoint (by name, schema-qualified name or alias)
+    fn find_table(&self, name: &str) -> Option<usize> {
+        let name = self.aliases.get(name).map(String::as_str).unwrap_or(name);
+        let (schema, table_name) = split_schema(name.split('.').map(String::from).collect());
+        self.tables.iter().position(|t| {
+            t.name.as_deref() == Some(table_name.as_str())
+                && schema.as_ref().is_none_or(|schema| {
+                    t.odcs_metadata.get("schemaName").and_then(Value::as_str)
+                        == Some(schema.as_str())
+                        || schema == "public"
+                })
+        })
+    }
+
+    fn apply_ref(&mut self, def: RefDef) {
+        // The referencing (foreign key) side is the "many" side of `>` and `<`
+        let (from, to, relation) = match def.op {
+            '<' => (def.to, def.from, None),
+            '-' => (def.from, def.to, Some("one-to-one")),
+            '*' => (def.from, def.to, Some("many-to-many")),
+            _ => (def.from, def.to, None),
+        };
+        let (Some(from_table), Some(to_table)) =
+            (self.find_table(&from.table), self.find_table(&to.table))
+        else {
+            self.errors.push(ImportError::ValidationError(format!(
+                "Line {}: ref between unknown tables {} and {}",
+                def.line, from.table, to.table
+            )));
+            return;
+        };
+        if from.columns.len() != to.columns.len() {
+            self.errors.push(ImportError::ValidationError(format!(
+                "Line {}: ref column counts differ",
+                def.line
+            )));
+            return;
+        }
+        let to_name = self.tables[to_table].name.clone().unwrap_or_default();
+        for (from_column, to_column) in from.columns.iter().zip(&to.columns) {
+            if !self.tables[to_table]
+                .columns
+                .iter()
+                .any(|c| &c.name == to_column)
+            {
+                self.errors.push(ImportError::ValidationError(format!(
+                    "Line {}: ref to unknown column {}.{}",
+                    def.line, to_name, to_column
+                )));
+                continue;
+            }
+            let Some(column) = self.tables[from_table]
+                .columns
+                .iter_mut()
+                .find(|c| &c.name == from_column)
+            else {
+                self.errors.push(ImportError::ValidationError(format!(
+                    "Line {}: ref from unknown column {}.{}",
+                    def.line, from.table, from_column
+                )));
+                continue;
+            };
+            column.relationships.push(PropertyRelationship {
+                relationship_type: "foreignKey".to_string(),
+                to: format!("{}.{}", to_name, to_column),
+            });
+            if let Some(relation) = relation {
+                column
+                    .custom_properties
+                    .insert(DBML_RELATION_PROPERTY.to_string(), json!(relation));
+            }
+            for (key, value) in &def.settings {
+                let property = match key.as_str() {
+                    "delete" => "onDelete",
+                    "update" => "onUpdate",
+                    _ => continue,
+                };
+                if let Some(action) = value.text() {
+                    column
+                        .custom_properties
+                        .insert(property.to_string(), json!(action));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+Project shop {
+  database_type: 'PostgreSQL'
+}
+
+// Order lifecycle
+Enum order_status {
+  pending
+  "in progress" [note: 'Being picked']
+  shipped
+}
+
+Table core.users as U [note: 'Registered customers'] {
+  id integer [pk, increment]
+  email varchar(255) [not null, unique, note: 'Login email']
+  created_at timestamp [default: `now()`]
+}
+
+Table orders {
+  id integer
+  line integer
+  user_id integer [ref: > U.id]
+  status order_status [default: 'pending']
+  total decimal(10, 2) [default: 0.5]
+
+  Note {
+    '''
+    Orders placed
+    in the shop
+    '''
+  }
+
+  indexes {
+    (id, line) [pk]
+    (user_id, status) [unique, name: 'orders_user_status']
+    `lower(status)`
+  }
+}
+
+Ref fk_orders: orders.user_id > core.users.id [delete: cascade, update: no action]
+Ref: users.id - orders.id
+"#;
+
+    #[test]
+    fn test_import_tables_and_columns() {
+        let result = DbmlImporter::new().import(SAMPLE).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.tables.len(), 2);
+
+        let users = &result.tables[0];
+        assert_eq!(users.name.as_deref(), Some("users"));
+        assert_eq!(users.odcs_metadata["schemaName"], "core");
+        assert_eq!(users.description, Some(json!("Registered customers")));
+        let email = &users.columns[1];
+        assert_eq!(email.data_type, "varchar(255)");
+        assert!(!email.nullable && email.unique);
+        assert_eq!(email.description.as_deref(), Some("Login email"));
+        assert_eq!(users.columns[0].custom_properties["autoIncrement"], true);
+        assert_eq!(users.columns[2].default_value, Some(json!("`now()`")));
+
+        let orders = &result.tables[1];
+        assert_eq!(
+            orders.description,
+            Some(json!("Orders placed\nin the shop"))
+        );
+        assert_eq!(orders.columns[4].data_type, "decimal(10,2)");
+        assert_eq!(orders.columns[4].default_value, Some(json!(0.5)));
+        assert_eq!(
+            orders.columns[3].enum_values,
+            Some(vec![
+                "pending".to_string(),
+                "in progress".to_string(),
+                "shipped".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_import_refs_and_indexes() {
+        let result = DbmlImporter::new().import(SAMPLE).unwrap();
+        let orders = &result.tables[1];
+
+        // Inline ref through the alias and the named top-level ref
+        let user_id = &orders.columns[2];
+        assert_eq!(user_id.relationships.len(), 2);
+        assert!(user_id.relationships.iter().all(|r| r.to == "users.id"));
+        assert_eq!(user_id.custom_properties["onDelete"], "cascade");
+        assert_eq!(user_id.custom_properties["onUpdate"], "no action");
+
+        // One-to-one ref lives on the left-hand column
+        let users_id = &result.tables[0].columns[0];
+        assert_eq!(users_id.relationships[0].to, "orders.id");
+        assert_eq!(
+            users_id.custom_properties[DBML_RELATION_PROPERTY],
+            "one-to-one"
+        );
+
+        // Composite primary key from the index block
+        assert!(orders.columns[0].primary_key && orders.columns[1].primary_key);
+        assert_eq!(orders.columns[1].primary_key_position, Some(2));
+
+        let indexes = orders.odcs_metadata["indexes"].as_array().unwrap();
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes[0]["columns"], json!(["user_id", "status"]));
+        assert_eq!(indexes[0]["unique"], true);
+        assert_eq!(indexes[0]["name"], "orders_user_status");
+        assert_eq!(indexes[1]["columns"], json!(["`lower(status)`"]));
+    }
+
+    #[test]
+    fn test_unknown_ref_target() {
+        let dbml = "Table a {\n  b_id int [ref: > b.id]\n}\n";
+        let result = DbmlImporter::new().import(dbml).unwrap();
+        assert_eq!(result.tables.len(), 1);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ImportError::ValidationError(message)] if message.contains("unknown tables")
+        ));
+    }
+
+    #[test]
+    fn test_syntax_error_reports_line() {
+        let err = DbmlImporter::new()
+            .import("Table a {\n  id int [pk\n}\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("Line 3"), "{}", err);
+    }
+}
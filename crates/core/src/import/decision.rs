@@ -3,19 +3,30 @@
 //! Parses MADR-compliant decision YAML files (.madr.yaml) and converts them to Decision models.
 //! Also handles the decisions index file (decisions.yaml).
 
-use super::ImportError;
+use super::{ImportError, check_limits};
 use crate::models::decision::{Decision, DecisionIndex};
+use crate::validation::limits::ResourceLimits;
 
 #[cfg(feature = "schema-validation")]
 use crate::validation::schema::validate_decision_internal;
 
 /// Decision importer for parsing MADR-compliant YAML files
-pub struct DecisionImporter;
+pub struct DecisionImporter {
+    limits: ResourceLimits,
+}
 
 impl DecisionImporter {
     /// Create a new Decision importer instance
     pub fn new() -> Self {
-        Self
+        Self {
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for decision YAML
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Import a decision from YAML content
@@ -52,6 +63,7 @@ impl DecisionImporter {
     /// assert_eq!(decision.title, "Use ODCS Format for Data Contracts");
     /// ```
     pub fn import(&self, yaml_content: &str) -> Result<Decision, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         // Validate against JSON Schema if feature is enabled
         #[cfg(feature = "schema-validation")]
         {
@@ -76,6 +88,7 @@ impl DecisionImporter {
     ///
     /// A `Decision` parsed from the YAML content
     pub fn import_without_validation(&self, yaml_content: &str) -> Result<Decision, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         Decision::from_yaml(yaml_content)
             .map_err(|e| ImportError::ParseError(format!("Failed to parse decision YAML: {}", e)))
     }
@@ -105,6 +118,7 @@ impl DecisionImporter {
     /// assert_eq!(index.next_number, 1);
     /// ```
     pub fn import_index(&self, yaml_content: &str) -> Result<DecisionIndex, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         DecisionIndex::from_yaml(yaml_content).map_err(|e| {
             ImportError::ParseError(format!("Failed to parse decisions index YAML: {}", e))
         })
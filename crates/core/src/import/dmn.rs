@@ -2,6 +2,9 @@
 //!
 //! Provides functionality to import DMN 1.3 XML files with validation.
 
+use crate::import::check_size;
+use crate::validation::input::MAX_BPMN_DMN_FILE_SIZE;
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -16,16 +19,34 @@ const DMNDI_NAMESPACE: &str = "https://www.omg.org/spec/DMN/20191111/DMNDI/";
 /// DMN Importer
 ///
 /// Imports DMN 1.3 XML content into a DMNModel struct.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct DMNImporter {
     /// List of errors encountered during parsing
     pub errors: Vec<String>,
+    limits: ResourceLimits,
+}
+
+impl Default for DMNImporter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DMNImporter {
     /// Create a new DMNImporter
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            limits: ResourceLimits::default().with_max_file_size(MAX_BPMN_DMN_FILE_SIZE),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for DMN models
+    ///
+    /// The default limits allow at most [`MAX_BPMN_DMN_FILE_SIZE`] bytes.
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Validate DMN XML against XSD schema
@@ -322,6 +343,7 @@ impl DMNImporter {
         domain_id: Uuid,
         model_name: Option<&str>,
     ) -> Result<DMNModel> {
+        check_size(&self.limits, xml_content)?;
         // Validate XML
         self.validate(xml_content)
             .context("DMN XML validation failed")?;
@@ -0,0 +1,431 @@
+//! AWS Glue Data Catalog table importer
+//!
+//! Reads database and table definitions through the Glue API and converts them
+//! into [`Table`] models:
+//!
+//! - **Columns** of the storage descriptor become columns with the Glue (Hive)
+//!   type as physical type, followed by the **partition keys**, which are marked
+//!   as partitioned in key order
+//! - **Comments** become descriptions
+//! - Table type, location, input/output format, SerDe library and table
+//!   **parameters** are kept as schema custom properties; column parameters
+//!   become column custom properties
+//!
+//! The imported tables convert into data contracts with
+//! [`ODCSContract::from_tables`](crate::models::odcs::ODCSContract::from_tables).
+
+use std::collections::{BTreeMap, HashMap};
+
+use aws_config::BehaviorVersion;
+use serde_json::{Value, json};
+
+use super::ImportError;
+use crate::models::enums::{DatabaseType, InfrastructureType};
+use crate::models::{Column, IdGenerator, Table};
+
+/// A Glue database
+#[derive(Debug, Clone, Default)]
+pub struct GlueDatabaseInfo {
+    /// Database name
+    pub name: String,
+    /// Database description
+    pub description: Option<String>,
+    /// Default location of the database's tables
+    pub location_uri: Option<String>,
+    /// Database parameters
+    pub parameters: BTreeMap<String, String>,
+}
+
+/// A Glue column or partition key
+#[derive(Debug, Clone, Default)]
+pub struct GlueColumnInfo {
+    /// Column name
+    pub name: String,
+    /// Glue type (e.g. `bigint`, `decimal(10,2)`, `array<struct<sku:string>>`)
+    pub data_type: String,
+    /// Column comment
+    pub comment: Option<String>,
+    /// Column parameters
+    pub parameters: BTreeMap<String, String>,
+}
+
+/// A Glue table
+#[derive(Debug, Clone, Default)]
+pub struct GlueTableInfo {
+    /// Table name
+    pub name: String,
+    /// Database name
+    pub database_name: String,
+    /// Table description
+    pub description: Option<String>,
+    /// Table owner
+    pub owner: Option<String>,
+    /// Table type (e.g. `EXTERNAL_TABLE`, `VIRTUAL_VIEW`)
+    pub table_type: Option<String>,
+    /// Table parameters (e.g. `classification`)
+    pub parameters: BTreeMap<String, String>,
+    /// Columns of the storage descriptor
+    pub columns: Vec<GlueColumnInfo>,
+    /// Partition keys, in key order
+    pub partition_keys: Vec<GlueColumnInfo>,
+    /// Data location (e.g. `s3://bucket/prefix/`)
+    pub location: Option<String>,
+    /// Input format class
+    pub input_format: Option<String>,
+    /// Output format class
+    pub output_format: Option<String>,
+    /// SerDe serialization library
+    pub serialization_library: Option<String>,
+}
+
+/// Importer for tables of the AWS Glue Data Catalog
+pub struct GlueCatalogImporter {
+    client: aws_sdk_glue::Client,
+    /// Catalog ID (AWS account ID); the caller's account when not set
+    catalog_id: Option<String>,
+    /// Generator for the IDs of imported tables
+    ids: IdGenerator,
+}
+
+impl GlueCatalogImporter {
+    /// Create an importer with a Glue client
+    pub fn new(client: aws_sdk_glue::Client) -> Self {
+        Self {
+            client,
+            catalog_id: None,
+            ids: IdGenerator::default(),
+        }
+    }
+
+    /// Create an importer with the default AWS configuration
+    ///
+    /// Credentials are loaded from the environment in this order:
+    /// 1. Environment variables (AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY)
+    /// 2. AWS credentials file (~/.aws/credentials)
+    /// 3. IAM role (if running on AWS infrastructure)
+    pub async fn from_env(region: Option<&str>, profile: Option<&str>) -> Self {
+        let mut config_loader = aws_config::defaults(BehaviorVersion::latest());
+        if let Some(region) = region {
+            config_loader = config_loader.region(aws_config::Region::new(region.to_string()));
+        }
+        if let Some(profile) = profile {
+            config_loader = config_loader.profile_name(profile);
+        }
+        let config = config_loader.load().await;
+        Self::new(aws_sdk_glue::Client::new(&config))
+    }
+
+    /// Read from the catalog of another account
+    pub fn with_catalog_id(mut self, catalog_id: impl Into<String>) -> Self {
+        self.catalog_id = Some(catalog_id.into());
+        self
+    }
+
+    /// Generate table IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// List the databases of the catalog
+    pub async fn list_databases(&self) -> Result<Vec<GlueDatabaseInfo>, ImportError> {
+        let mut databases = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let output = self
+                .client
+                .get_databases()
+                .set_catalog_id(self.catalog_id.clone())
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| sdk_error("GetDatabases", e))?;
+            databases.extend(output.database_list().iter().map(database_info));
+            match output.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => return Ok(databases),
+            }
+        }
+    }
+
+    /// List the tables of a database
+    pub async fn list_tables(&self, database: &str) -> Result<Vec<GlueTableInfo>, ImportError> {
+        let mut tables = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let output = self
+                .client
+                .get_tables()
+                .set_catalog_id(self.catalog_id.clone())
+                .database_name(database)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| sdk_error("GetTables", e))?;
+            tables.extend(output.table_list().iter().map(table_info));
+            match output.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => return Ok(tables),
+            }
+        }
+    }
+
+    /// Get a table
+    pub async fn get_table(
+        &self,
+        database: &str,
+        name: &str,
+    ) -> Result<GlueTableInfo, ImportError> {
+        let output = self
+            .client
+            .get_table()
+            .set_catalog_id(self.catalog_id.clone())
+            .database_name(database)
+            .name(name)
+            .send()
+            .await
+            .map_err(|e| sdk_error("GetTable", e))?;
+        output.table().map(table_info).ok_or_else(|| {
+            ImportError::IoError(format!("Glue table not found: {}.{}", database, name))
+        })
+    }
+
+    /// Import a table
+    pub async fn import_table(&self, database: &str, name: &str) -> Result<Table, ImportError> {
+        Ok(glue_table_to_table(
+            &self.get_table(database, name).await?,
+            &self.ids,
+        ))
+    }
+
+    /// Import every table of a database
+    pub async fn import_database(&self, database: &str) -> Result<Vec<Table>, ImportError> {
+        Ok(self
+            .list_tables(database)
+            .await?
+            .iter()
+            .map(|table| glue_table_to_table(table, &self.ids))
+            .collect())
+    }
+}
+
+impl std::fmt::Debug for GlueCatalogImporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GlueCatalogImporter")
+            .field("catalog_id", &self.catalog_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Convert a Glue table into a table model, with an ID generated by `ids`
+pub fn glue_table_to_table(glue: &GlueTableInfo, ids: &IdGenerator) -> Table {
+    let mut columns: Vec<Column> = glue.columns.iter().map(glue_column_to_column).collect();
+    for (index, key) in glue.partition_keys.iter().enumerate() {
+        let mut column = glue_column_to_column(key);
+        column.partitioned = true;
+        column.partition_key_position = Some(index as i32 + 1);
+        columns.push(column);
+    }
+    for (index, column) in columns.iter_mut().enumerate() {
+        column.column_order = index as i32;
+    }
+
+    let schema_name = Some(glue.database_name.clone()).filter(|name| !name.is_empty());
+    let mut table = Table::new(glue.name.clone(), columns);
+    table.id = Table::generate_id_with_generator(ids, &glue.name, None, schema_name.as_deref());
+    table.database_type = Some(DatabaseType::AwsGlue);
+    table.infrastructure_type = Some(InfrastructureType::Glue);
+    table.schema_name = schema_name;
+    table.owner = glue.owner.clone().filter(|owner| !owner.is_empty());
+
+    let physical_name = if glue.database_name.is_empty() {
+        glue.name.clone()
+    } else {
+        format!("{}.{}", glue.database_name, glue.name)
+    };
+    let physical_type = match glue.table_type.as_deref() {
+        Some("VIRTUAL_VIEW") => "view",
+        _ => "table",
+    };
+    table
+        .odcl_metadata
+        .insert("physicalName".to_string(), json!(physical_name));
+    table
+        .odcl_metadata
+        .insert("physicalType".to_string(), json!(physical_type));
+    if let Some(description) = glue.description.as_ref().filter(|d| !d.is_empty()) {
+        table
+            .odcl_metadata
+            .insert("schemaDescription".to_string(), json!(description));
+    }
+
+    let mut custom_properties = Vec::new();
+    for (property, value) in [
+        ("tableType", &glue.table_type),
+        ("location", &glue.location),
+        ("inputFormat", &glue.input_format),
+        ("outputFormat", &glue.output_format),
+        ("serializationLibrary", &glue.serialization_library),
+    ] {
+        if let Some(value) = value {
+            custom_properties.push(json!({"property": property, "value": value}));
+        }
+    }
+    if !glue.parameters.is_empty() {
+        custom_properties.push(json!({"property": "tableParameters", "value": glue.parameters}));
+    }
+    if !custom_properties.is_empty() {
+        table
+            .odcl_metadata
+            .insert("customProperties".to_string(), json!(custom_properties));
+    }
+    table
+}
+
+/// Convert a Glue column into a column model
+fn glue_column_to_column(glue: &GlueColumnInfo) -> Column {
+    let mut column = Column::new(glue.name.clone(), glue.data_type.clone());
+    column.physical_type = Some(glue.data_type.clone());
+    if let Some(comment) = glue.comment.as_ref().filter(|c| !c.is_empty()) {
+        column.description = comment.clone();
+    }
+    column.custom_properties = glue
+        .parameters
+        .iter()
+        .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+        .collect();
+    column
+}
+
+fn database_info(database: &aws_sdk_glue::types::Database) -> GlueDatabaseInfo {
+    GlueDatabaseInfo {
+        name: database.name().to_string(),
+        description: database.description().map(str::to_string),
+        location_uri: database.location_uri().map(str::to_string),
+        parameters: parameters(database.parameters()),
+    }
+}
+
+fn table_info(table: &aws_sdk_glue::types::Table) -> GlueTableInfo {
+    let storage = table.storage_descriptor();
+    GlueTableInfo {
+        name: table.name().to_string(),
+        database_name: table.database_name().unwrap_or_default().to_string(),
+        description: table.description().map(str::to_string),
+        owner: table.owner().map(str::to_string),
+        table_type: table.table_type().map(str::to_string),
+        parameters: parameters(table.parameters()),
+        columns: storage
+            .map(|sd| sd.columns().iter().map(column_info).collect())
+            .unwrap_or_default(),
+        partition_keys: table.partition_keys().iter().map(column_info).collect(),
+        location: storage.and_then(|sd| sd.location()).map(str::to_string),
+        input_format: storage.and_then(|sd| sd.input_format()).map(str::to_string),
+        output_format: storage
+            .and_then(|sd| sd.output_format())
+            .map(str::to_string),
+        serialization_library: storage
+            .and_then(|sd| sd.serde_info())
+            .and_then(|serde| serde.serialization_library())
+            .map(str::to_string),
+    }
+}
+
+fn column_info(column: &aws_sdk_glue::types::Column) -> GlueColumnInfo {
+    GlueColumnInfo {
+        name: column.name().to_string(),
+        data_type: column.r#type().unwrap_or("string").to_string(),
+        comment: column.comment().map(str::to_string),
+        parameters: parameters(column.parameters()),
+    }
+}
+
+fn parameters(parameters: Option<&HashMap<String, String>>) -> BTreeMap<String, String> {
+    parameters
+        .map(|p| p.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        .unwrap_or_default()
+}
+
+fn sdk_error<E: std::error::Error + 'static>(operation: &str, err: E) -> ImportError {
+    ImportError::IoError(format!(
+        "Glue {} failed: {}",
+        operation,
+        aws_sdk_glue::error::DisplayErrorContext(&err)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::ODCSContract;
+
+    fn orders() -> GlueTableInfo {
+        let column = |name: &str, data_type: &str, comment: Option<&str>| GlueColumnInfo {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            comment: comment.map(str::to_string),
+            parameters: BTreeMap::new(),
+        };
+        GlueTableInfo {
+            name: "orders".to_string(),
+            database_name: "sales".to_string(),
+            description: Some("Customer orders".to_string()),
+            owner: Some("sales-data".to_string()),
+            table_type: Some("EXTERNAL_TABLE".to_string()),
+            parameters: BTreeMap::from([("classification".to_string(), "parquet".to_string())]),
+            columns: vec![
+                column("id", "bigint", Some("Order id")),
+                column("total", "decimal(10,2)", None),
+                column("lines", "array<struct<sku:string,qty:int>>", None),
+            ],
+            partition_keys: vec![
+                column("year", "string", None),
+                column("month", "string", None),
+            ],
+            location: Some("s3://lake/sales/orders/".to_string()),
+            serialization_library: Some(
+                "org.apache.hadoop.hive.ql.io.parquet.serde.ParquetHiveSerDe".to_string(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_glue_table_to_table() {
+        let table = glue_table_to_table(&orders(), &IdGenerator::default());
+
+        assert_eq!(table.name, "orders");
+        assert_eq!(table.schema_name.as_deref(), Some("sales"));
+        assert_eq!(table.database_type, Some(DatabaseType::AwsGlue));
+        assert_eq!(table.owner.as_deref(), Some("sales-data"));
+
+        let names: Vec<&str> = table.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "total", "lines", "year", "month"]);
+        assert_eq!(table.columns[0].description, "Order id");
+        assert_eq!(
+            table.columns[1].physical_type.as_deref(),
+            Some("decimal(10,2)")
+        );
+        assert!(!table.columns[0].partitioned);
+        assert!(table.columns[4].partitioned);
+        assert_eq!(table.columns[4].partition_key_position, Some(2));
+        assert_eq!(table.columns[4].column_order, 4);
+
+        let contract = ODCSContract::from_tables(std::slice::from_ref(&table));
+        let schema = &contract.schema[0];
+        assert_eq!(schema.physical_name.as_deref(), Some("sales.orders"));
+        assert_eq!(schema.description.as_deref(), Some("Customer orders"));
+        let location = schema
+            .custom_properties
+            .iter()
+            .find(|p| p.property == "location")
+            .unwrap();
+        assert_eq!(location.value, json!("s3://lake/sales/orders/"));
+        let parameters = schema
+            .custom_properties
+            .iter()
+            .find(|p| p.property == "tableParameters")
+            .unwrap();
+        assert_eq!(parameters.value["classification"], "parquet");
+    }
+}
@@ -1,5 +1,15 @@
 //! JSON Schema parser for importing JSON Schema into data models.
 //!
+//! # Composition
+//!
+//! - `allOf` sub-schemas are merged into a single object schema
+//! - `oneOf`/`anyOf` unions of objects become nested columns covering every branch,
+//!   with the discriminator property detected where possible
+//! - Other `oneOf`/`anyOf` unions become a single `UNION<...>` column
+//! - `$ref`s to other documents (e.g., `address.json#/definitions/Address`) are inlined
+//!   when a resolver is set with [`JSONSchemaImporter::with_resolver`] (or
+//!   [`JSONSchemaImporter::with_base_dir`] for local files)
+//!
 //! # Validation
 //!
 //! All imported table and column names are validated for:
@@ -7,15 +17,31 @@
 //! - Maximum length limits
 
 use super::odcs_shared::column_to_column_data;
-use super::{ImportError, ImportResult, TableData};
-use crate::models::{Column, PropertyRelationship, Table, Tag};
+use super::{ImportError, ImportResult, TableData, check_limits};
+use crate::import::ref_resolver::{LocalFileResolver, RefResolver};
+use crate::models::{Column, IdGenerator, PropertyRelationship, Table, Tag, TypeMappingRegistry};
 use crate::validation::input::{validate_column_name, validate_data_type, validate_table_name};
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
+use serde_json::Map;
 use serde_json::{Value, json};
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::Arc;
 use tracing::{info, warn};
 
+/// Maximum number of nested `$ref`s followed while resolving a schema.
+const MAX_REF_DEPTH: usize = 64;
+
+/// Keywords whose value is a schema.
+const SCHEMA_KEYWORDS: &[&str] = &["items", "additionalProperties", "not"];
+
+/// Keywords whose value maps names to schemas.
+const SCHEMA_MAP_KEYWORDS: &[&str] = &["properties", "patternProperties", "definitions", "$defs"];
+
 /// Convert a $ref path to a PropertyRelationship.
 fn ref_to_relationships(ref_path: &Option<String>) -> Vec<PropertyRelationship> {
     match ref_path {
@@ -38,7 +64,24 @@ fn ref_to_relationships(ref_path: &Option<String>) -> Vec<PropertyRelationship>
 }
 
 /// Parser for JSON Schema format.
-pub struct JSONSchemaImporter;
+#[derive(Clone)]
+pub struct JSONSchemaImporter {
+    /// Loads documents named by external `$ref`s
+    resolver: Option<Arc<dyn RefResolver>>,
+    /// Type mappings (the built-in mappings when `None`)
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+    limits: ResourceLimits,
+    /// Generator for the IDs of imported tables
+    ids: IdGenerator,
+}
+
+impl std::fmt::Debug for JSONSchemaImporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JSONSchemaImporter")
+            .field("resolver", &self.resolver.is_some())
+            .finish()
+    }
+}
 
 impl Default for JSONSchemaImporter {
     fn default() -> Self {
@@ -57,7 +100,79 @@ impl JSONSchemaImporter {
     /// let importer = JSONSchemaImporter::new();
     /// ```
     pub fn new() -> Self {
-        Self
+        Self {
+            resolver: None,
+            type_mappings: None,
+            limits: ResourceLimits::default(),
+            ids: IdGenerator::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for JSON Schema documents
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Generate table IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Resolve external `$ref`s with `resolver`.
+    ///
+    /// Referenced documents may be JSON or YAML.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::json_schema::JSONSchemaImporter;
+    /// use data_modelling_core::import::ref_resolver::StorageRefResolver;
+    ///
+    /// let mut resolver = StorageRefResolver::new();
+    /// resolver.insert("common.json", r#"{"definitions": {"Id": {"type": "string"}}}"#);
+    /// let importer = JSONSchemaImporter::new().with_resolver(resolver);
+    /// ```
+    pub fn with_resolver(mut self, resolver: impl RefResolver + 'static) -> Self {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Resolve relative file `$ref`s against `dir`.
+    ///
+    /// Shorthand for [`with_resolver`](Self::with_resolver) with a [`LocalFileResolver`];
+    /// referenced files may not resolve outside `dir`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::json_schema::JSONSchemaImporter;
+    ///
+    /// let importer = JSONSchemaImporter::new().with_base_dir("schemas");
+    /// ```
+    pub fn with_base_dir(self, dir: impl Into<PathBuf>) -> Self {
+        self.with_resolver(LocalFileResolver::new(dir))
+    }
+
+    /// Map JSON Schema types with `type_mappings` instead of the built-in mappings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::json_schema::JSONSchemaImporter;
+    /// use data_modelling_core::models::TypeMappingRegistry;
+    ///
+    /// let mut mappings = TypeMappingRegistry::default();
+    /// mappings.set_import("json-schema", "number", "DECIMAL(38,10)");
+    /// let importer = JSONSchemaImporter::new().with_type_mappings(mappings);
+    /// ```
+    pub fn with_type_mappings(
+        mut self,
+        type_mappings: impl Into<Arc<TypeMappingRegistry>>,
+    ) -> Self {
+        self.type_mappings = Some(type_mappings.into());
+        self
     }
 
     /// Import JSON Schema content and create Table(s) (SDK interface).
@@ -89,6 +204,7 @@ impl JSONSchemaImporter {
     /// let result = importer.import(schema).unwrap();
     /// ```
     pub fn import(&self, json_content: &str) -> Result<ImportResult, ImportError> {
+        check_limits(&self.limits, json_content)?;
         match self.parse(json_content) {
             Ok((tables, errors)) => {
                 let mut sdk_tables = Vec::new();
@@ -128,10 +244,43 @@ impl JSONSchemaImporter {
         let schema: Value =
             serde_json::from_str(json_content).context("Failed to parse JSON Schema")?;
 
+        // Resolve $refs and merge allOf before extracting tables
+        let document = SchemaDocument {
+            root: Rc::new(schema),
+            location: None,
+            is_main: true,
+            key: String::new(),
+        };
+        let schema = self.resolve_schema(
+            &document.root,
+            &document,
+            false,
+            &mut ResolveContext::default(),
+            &mut errors,
+        );
+
         let mut tables = Vec::new();
 
         // Check if it's a schema with definitions (multiple tables)
-        if let Some(definitions) = schema.get("definitions").and_then(|v| v.as_object()) {
+        if let Some(definitions) = schema
+            .get("definitions")
+            .or_else(|| schema.get("$defs"))
+            .and_then(|v| v.as_object())
+        {
+            // A root object schema alongside its definitions is a table too
+            if schema.get("properties").is_some() {
+                match self.parse_schema(&schema, None, &mut errors) {
+                    Ok(table) => tables.push(table),
+                    Err(e) => {
+                        errors.push(ParserError {
+                            error_type: "parse_error".to_string(),
+                            field: None,
+                            message: format!("Failed to parse schema: {}", e),
+                        });
+                    }
+                }
+            }
+
             // Multiple schemas in definitions
             for (name, def_schema) in definitions {
                 match self.parse_schema(def_schema, Some(name), &mut errors) {
@@ -197,22 +346,27 @@ impl JSONSchemaImporter {
             .map(|s| s.to_string())
             .unwrap_or_default();
 
+        // A union of object schemas becomes one table covering every branch
+        let (body, discriminator) = match union_branches(schema_obj) {
+            Some(branches)
+                if !schema_obj.contains_key("properties")
+                    && !branches.is_empty()
+                    && branches.iter().all(|b| b.contains_key("properties")) =>
+            {
+                let (merged, discriminator) = merge_union_branches(schema_obj, &branches);
+                (Cow::Owned(merged), discriminator)
+            }
+            _ => (Cow::Borrowed(schema_obj), None),
+        };
+
         // Extract properties
-        let properties = schema_obj
+        let properties = body
             .get("properties")
             .and_then(|v| v.as_object())
             .ok_or_else(|| anyhow::anyhow!("Missing required field: properties"))?;
 
         // Extract required fields
-        let required_fields: Vec<String> = schema_obj
-            .get("required")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                    .collect()
-            })
-            .unwrap_or_default();
+        let required_fields = required_fields(&body);
 
         let mut columns = Vec::new();
         for (prop_name, prop_schema) in properties {
@@ -229,6 +383,11 @@ impl JSONSchemaImporter {
             }
         }
 
+        if let Some(discriminator) = &discriminator {
+            let column_name = discriminator.property.clone();
+            annotate_discriminator(&mut columns, &column_name, discriminator);
+        }
+
         // Extract tags from JSON Schema (can be in root or in customProperties)
         let mut tags: Vec<Tag> = Vec::new();
         if let Some(tags_arr) = schema_obj.get("tags").and_then(|v| v.as_array()) {
@@ -270,9 +429,14 @@ impl JSONSchemaImporter {
         if !description.is_empty() {
             odcl_metadata.insert("description".to_string(), json!(description));
         }
+        if let Some(discriminator) = &discriminator {
+            odcl_metadata.insert("discriminator".to_string(), json!(discriminator.property));
+        }
 
         let table = Table {
-            id: crate::models::table::Table::generate_id(&name, None, None, None),
+            id: crate::models::table::Table::generate_id_with_generator(
+                &self.ids, &name, None, None,
+            ),
             name: name.clone(),
             columns,
             database_type: None,
@@ -340,11 +504,56 @@ impl JSONSchemaImporter {
                 nullable,
                 description,
                 quality: quality_rules,
+                examples: self.extract_examples(prop_obj),
                 relationships: ref_to_relationships(&Some(ref_path.to_string())),
                 ..Default::default()
             }]);
         }
 
+        // Unions without a single type (oneOf/anyOf)
+        if !prop_obj.contains_key("type")
+            && let Some(branches) = union_branches(prop_obj)
+        {
+            return self.parse_union_property(prop_name, prop_obj, &branches, nullable, errors);
+        }
+
+        // Lists of types (e.g., ["string", "null"])
+        if let Some(types) = prop_obj.get("type").and_then(|v| v.as_array()) {
+            let type_names: Vec<&str> = types.iter().filter_map(|t| t.as_str()).collect();
+            let non_null: Vec<&str> = type_names
+                .iter()
+                .copied()
+                .filter(|t| *t != "null")
+                .collect();
+            let nullable = nullable || non_null.len() < type_names.len();
+
+            if let [single] = non_null.as_slice() {
+                let mut single_schema = prop_obj.clone();
+                single_schema.insert("type".to_string(), json!(single));
+                return self.parse_property(
+                    prop_name,
+                    &Value::Object(single_schema),
+                    nullable,
+                    errors,
+                );
+            }
+
+            return Ok(vec![Column {
+                name: prop_name.to_string(),
+                data_type: union_data_type(non_null.iter().map(|t| self.map_json_type_to_sql(t))),
+                nullable,
+                description: prop_obj
+                    .get("description")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+                quality: self.extract_validation_keywords(prop_obj, prop_name),
+                enum_values: self.extract_enum_values(prop_obj),
+                examples: self.extract_examples(prop_obj),
+                ..Default::default()
+            }]);
+        }
+
         let prop_type = prop_obj
             .get("type")
             .and_then(|v| v.as_str())
@@ -362,9 +571,10 @@ impl JSONSchemaImporter {
             .map(|s| s.to_string())
             .unwrap_or_default();
 
-        // Extract validation keywords and enum values
+        // Extract validation keywords, enum values and examples
         let quality_rules = self.extract_validation_keywords(prop_obj, prop_name);
         let enum_values = self.extract_enum_values(prop_obj);
+        let examples = self.extract_examples(prop_obj);
 
         let mut columns = Vec::new();
 
@@ -422,6 +632,7 @@ impl JSONSchemaImporter {
                         nullable,
                         description,
                         quality: struct_quality,
+                        examples,
                         ..Default::default()
                     });
                 }
@@ -500,6 +711,7 @@ impl JSONSchemaImporter {
                     nullable,
                     description,
                     quality: array_quality,
+                    examples,
                     ..Default::default()
                 });
             }
@@ -513,6 +725,7 @@ impl JSONSchemaImporter {
                     description,
                     quality: quality_rules,
                     enum_values: enum_values.clone(),
+                    examples,
                     ..Default::default()
                 });
             }
@@ -521,16 +734,259 @@ impl JSONSchemaImporter {
         Ok(columns)
     }
 
+    /// Parse a `oneOf`/`anyOf` property.
+    ///
+    /// Unions of object schemas become nested columns covering every branch;
+    /// other unions become a single column with a `UNION<...>` type. A `null`
+    /// branch makes the column nullable.
+    fn parse_union_property(
+        &self,
+        prop_name: &str,
+        prop_obj: &Map<String, Value>,
+        branches: &[&Map<String, Value>],
+        nullable: bool,
+        errors: &mut Vec<ParserError>,
+    ) -> Result<Vec<Column>> {
+        let branches: Vec<&Map<String, Value>> = branches
+            .iter()
+            .copied()
+            .filter(|b| b.get("type").and_then(|v| v.as_str()) != Some("null"))
+            .collect();
+        let nullable =
+            nullable || union_branches(prop_obj).map(|b| b.len()) != Some(branches.len());
+
+        if !branches.is_empty() && branches.iter().all(|b| b.contains_key("properties")) {
+            let (merged, discriminator) = merge_union_branches(prop_obj, &branches);
+            let mut columns =
+                self.parse_property(prop_name, &Value::Object(merged), nullable, errors)?;
+            if let Some(discriminator) = &discriminator {
+                let column_name = format!("{}.{}", prop_name, discriminator.property);
+                annotate_discriminator(&mut columns, &column_name, discriminator);
+            }
+            return Ok(columns);
+        }
+
+        if let [single] = branches.as_slice() {
+            // e.g. oneOf: [{"type": "string"}, {"type": "null"}]
+            let mut single_schema = (*single).clone();
+            for keyword in ["description", "examples"] {
+                if let Some(value) = prop_obj.get(keyword) {
+                    single_schema
+                        .entry(keyword)
+                        .or_insert_with(|| value.clone());
+                }
+            }
+            return self.parse_property(prop_name, &Value::Object(single_schema), nullable, errors);
+        }
+
+        Ok(vec![Column {
+            name: prop_name.to_string(),
+            data_type: union_data_type(branches.iter().map(|b| self.branch_data_type(b))),
+            nullable,
+            description: prop_obj
+                .get("description")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
+            quality: self.extract_validation_keywords(prop_obj, prop_name),
+            examples: self.extract_examples(prop_obj),
+            ..Default::default()
+        }])
+    }
+
+    /// Get the SQL/ODCL data type of a single union branch.
+    fn branch_data_type(&self, branch: &Map<String, Value>) -> String {
+        match branch.get("type").and_then(|v| v.as_str()) {
+            Some("object") => "STRUCT".to_string(),
+            Some("array") => match branch
+                .get("items")
+                .and_then(|i| i.get("type"))
+                .and_then(|v| v.as_str())
+            {
+                Some("object") => "ARRAY<STRUCT>".to_string(),
+                Some(item_type) => format!("ARRAY<{}>", self.map_json_type_to_sql(item_type)),
+                None => "ARRAY<STRING>".to_string(),
+            },
+            Some(json_type) => self.map_json_type_to_sql(json_type),
+            None if branch.contains_key("properties") => "STRUCT".to_string(),
+            None => "STRING".to_string(),
+        }
+    }
+
+    /// Resolve `$ref`s and merge `allOf` so the schema can be parsed directly.
+    ///
+    /// References to object definitions of the imported document are kept so they
+    /// still become relationships (unless `inline_refs` is set, as for `allOf` and
+    /// union branches); all other references are inlined.
+    fn resolve_schema(
+        &self,
+        schema: &Value,
+        document: &SchemaDocument,
+        inline_refs: bool,
+        ctx: &mut ResolveContext,
+        errors: &mut Vec<ParserError>,
+    ) -> Value {
+        let Some(obj) = schema.as_object() else {
+            return schema.clone();
+        };
+
+        if let Some(ref_path) = obj.get("$ref").and_then(|v| v.as_str()) {
+            return self
+                .resolve_ref(ref_path, obj, document, inline_refs, ctx, errors)
+                .unwrap_or_else(|| schema.clone());
+        }
+
+        let mut resolved = Map::new();
+        for (key, value) in obj {
+            let value = match key.as_str() {
+                "allOf" => continue,
+                "oneOf" | "anyOf" => match value.as_array() {
+                    Some(branches) => Value::Array(
+                        branches
+                            .iter()
+                            .map(|b| self.resolve_schema(b, document, true, ctx, errors))
+                            .collect(),
+                    ),
+                    None => value.clone(),
+                },
+                k if SCHEMA_KEYWORDS.contains(&k) => {
+                    self.resolve_schema(value, document, false, ctx, errors)
+                }
+                k if SCHEMA_MAP_KEYWORDS.contains(&k) => match value.as_object() {
+                    Some(schemas) => Value::Object(
+                        schemas
+                            .iter()
+                            .map(|(name, s)| {
+                                let s = self.resolve_schema(s, document, false, ctx, errors);
+                                (name.clone(), s)
+                            })
+                            .collect(),
+                    ),
+                    None => value.clone(),
+                },
+                _ => value.clone(),
+            };
+            resolved.insert(key.clone(), value);
+        }
+
+        if let Some(all_of) = obj.get("allOf").and_then(|v| v.as_array()) {
+            for sub_schema in all_of {
+                if let Value::Object(sub_schema) =
+                    self.resolve_schema(sub_schema, document, true, ctx, errors)
+                {
+                    merge_schema(&mut resolved, sub_schema);
+                }
+            }
+        }
+
+        Value::Object(resolved)
+    }
+
+    /// Resolve a single `$ref`, returning `None` if it should be kept as-is.
+    fn resolve_ref(
+        &self,
+        ref_path: &str,
+        ref_obj: &Map<String, Value>,
+        document: &SchemaDocument,
+        inline_refs: bool,
+        ctx: &mut ResolveContext,
+        errors: &mut Vec<ParserError>,
+    ) -> Option<Value> {
+        let (file, pointer) = ref_path.split_once('#').unwrap_or((ref_path, ""));
+
+        let target_document = if file.is_empty() {
+            document.clone()
+        } else {
+            match self.load_ref_document(file, document, ctx) {
+                Ok(target_document) => target_document,
+                Err(e) => {
+                    errors.push(ParserError {
+                        error_type: "reference_error".to_string(),
+                        field: Some(ref_path.to_string()),
+                        message: format!("Failed to resolve reference: {:#}", e),
+                    });
+                    return None;
+                }
+            }
+        };
+
+        let Some(target) = resolve_pointer(&target_document.root, pointer) else {
+            if file.is_empty() {
+                warn!("Unresolved JSON Schema reference: {}", ref_path);
+            } else {
+                errors.push(ParserError {
+                    error_type: "reference_error".to_string(),
+                    field: Some(ref_path.to_string()),
+                    message: format!("Reference target not found: {}", ref_path),
+                });
+            }
+            return None;
+        };
+
+        // Object definitions in the imported document are tables of their own
+        if file.is_empty() && document.is_main && !inline_refs && is_table_schema(target) {
+            return None;
+        }
+
+        // Recursive references are kept as-is
+        let key = format!("{}#{}", target_document.key, pointer);
+        if ctx.visiting.contains(&key) || ctx.visiting.len() >= MAX_REF_DEPTH {
+            return None;
+        }
+
+        ctx.visiting.push(key);
+        let mut resolved = self.resolve_schema(target, &target_document, inline_refs, ctx, errors);
+        ctx.visiting.pop();
+
+        // Keywords next to the $ref (e.g., description) override the referenced schema
+        if let Value::Object(resolved_obj) = &mut resolved {
+            for (key, value) in ref_obj {
+                if key != "$ref" {
+                    resolved_obj.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        Some(resolved)
+    }
+
+    /// Load a referenced schema document relative to the referencing document.
+    fn load_ref_document(
+        &self,
+        file: &str,
+        document: &SchemaDocument,
+        ctx: &mut ResolveContext,
+    ) -> Result<SchemaDocument> {
+        let Some(resolver) = &self.resolver else {
+            anyhow::bail!("Cannot resolve reference '{}' without a resolver", file);
+        };
+
+        let resolved = resolver
+            .resolve(file, document.location.as_deref())
+            .with_context(|| format!("Failed to resolve '{}'", file))?;
+        let root = match ctx.files.get(&resolved.location) {
+            Some(root) => root.clone(),
+            None => {
+                let root = Rc::new(resolved.parse()?);
+                ctx.files.insert(resolved.location.clone(), root.clone());
+                root
+            }
+        };
+
+        Ok(SchemaDocument {
+            root,
+            location: Some(resolved.location.clone()),
+            is_main: false,
+            key: resolved.location,
+        })
+    }
+
     /// Map JSON Schema type to SQL/ODCL data type.
     fn map_json_type_to_sql(&self, json_type: &str) -> String {
-        match json_type {
-            "integer" => "INTEGER".to_string(),
-            "number" => "DOUBLE".to_string(),
-            "boolean" => "BOOLEAN".to_string(),
-            "string" => "STRING".to_string(),
-            "null" => "NULL".to_string(),
-            _ => "STRING".to_string(), // Default fallback
-        }
+        self.type_mappings
+            .as_deref()
+            .unwrap_or(TypeMappingRegistry::builtin())
+            .import_type("json-schema", json_type)
+            .unwrap_or_else(|| "STRING".to_string())
     }
 
     /// Extract validation keywords from JSON Schema property and convert to quality rules.
@@ -689,6 +1145,15 @@ impl JSONSchemaImporter {
         quality_rules
     }
 
+    /// Extract example values (`examples`, or the OpenAPI-style `example`).
+    fn extract_examples(&self, prop_obj: &serde_json::Map<String, Value>) -> Vec<Value> {
+        match (prop_obj.get("examples"), prop_obj.get("example")) {
+            (Some(Value::Array(examples)), _) => examples.clone(),
+            (_, Some(example)) => vec![example.clone()],
+            _ => Vec::new(),
+        }
+    }
+
     /// Extract enum values from JSON Schema property.
     fn extract_enum_values(&self, prop_obj: &serde_json::Map<String, Value>) -> Vec<String> {
         prop_obj
@@ -712,6 +1177,259 @@ impl JSONSchemaImporter {
     }
 }
 
+/// A JSON document that `$ref` pointers are resolved against.
+#[derive(Clone)]
+struct SchemaDocument {
+    root: Rc<Value>,
+    /// Location of a referenced document (`None` for the imported document)
+    location: Option<String>,
+    /// Whether this is the document being imported rather than a referenced file
+    is_main: bool,
+    /// Identifies the document when detecting recursive references
+    key: String,
+}
+
+/// State shared while resolving the references of one import.
+#[derive(Default)]
+struct ResolveContext {
+    /// Referenced documents already loaded, by location
+    files: HashMap<String, Rc<Value>>,
+    /// References currently being expanded
+    visiting: Vec<String>,
+}
+
+/// Discriminator of a union of object schemas.
+struct Discriminator {
+    property: String,
+    /// Discriminator value to branch title
+    mapping: Map<String, Value>,
+}
+
+/// Resolve a JSON pointer (the part of a `$ref` after `#`).
+fn resolve_pointer<'a>(root: &'a Value, pointer: &str) -> Option<&'a Value> {
+    if pointer.is_empty() || pointer == "/" {
+        Some(root)
+    } else {
+        root.pointer(pointer)
+    }
+}
+
+/// Check if a referenced schema describes an object (and so a table of its own).
+fn is_table_schema(schema: &Value) -> bool {
+    schema.get("properties").is_some()
+        || schema.get("allOf").is_some()
+        || schema.get("type").and_then(|v| v.as_str()) == Some("object")
+}
+
+/// Get the names listed in a schema's `required` keyword.
+fn required_fields(schema: &Map<String, Value>) -> Vec<String> {
+    schema
+        .get("required")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Get the branches of a `oneOf` (or `anyOf`) keyword.
+fn union_branches(schema: &Map<String, Value>) -> Option<Vec<&Map<String, Value>>> {
+    schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(|v| v.as_array())
+        .map(|branches| branches.iter().filter_map(|b| b.as_object()).collect())
+}
+
+/// Merge an `allOf` sub-schema into `target`.
+///
+/// `properties` and `required` are combined; for other keywords the value
+/// already in `target` wins.
+fn merge_schema(target: &mut Map<String, Value>, sub_schema: Map<String, Value>) {
+    for (key, value) in sub_schema {
+        match (key.as_str(), target.get_mut(&key), value) {
+            ("properties", Some(Value::Object(existing)), Value::Object(properties)) => {
+                for (name, schema) in properties {
+                    existing.entry(name).or_insert(schema);
+                }
+            }
+            ("required", Some(Value::Array(existing)), Value::Array(required)) => {
+                for name in required {
+                    if !existing.contains(&name) {
+                        existing.push(name);
+                    }
+                }
+            }
+            (_, Some(_), _) => {}
+            (_, None, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Combine the object branches of a union into a single object schema.
+///
+/// A property is only required if every branch requires it. The discriminator
+/// property (if detected) becomes an enum of the branch values.
+fn merge_union_branches(
+    union: &Map<String, Value>,
+    branches: &[&Map<String, Value>],
+) -> (Map<String, Value>, Option<Discriminator>) {
+    let mut properties = Map::new();
+    for branch in branches {
+        if let Some(branch_properties) = branch.get("properties").and_then(|v| v.as_object()) {
+            for (name, schema) in branch_properties {
+                properties
+                    .entry(name.clone())
+                    .or_insert_with(|| schema.clone());
+            }
+        }
+    }
+
+    let branch_required: Vec<Vec<String>> = branches.iter().map(|b| required_fields(b)).collect();
+    let required: Vec<Value> = properties
+        .keys()
+        .filter(|name| branch_required.iter().all(|r| r.contains(name)))
+        .map(|name| json!(name))
+        .collect();
+
+    let discriminator = detect_discriminator(union, branches);
+    if let Some(discriminator) = &discriminator
+        && !discriminator.mapping.is_empty()
+    {
+        let values: Vec<Value> = discriminator.mapping.keys().map(|v| json!(v)).collect();
+        properties.insert(
+            discriminator.property.clone(),
+            json!({ "type": "string", "enum": values }),
+        );
+    }
+
+    let mut merged = Map::new();
+    merged.insert("type".to_string(), json!("object"));
+    merged.insert("properties".to_string(), Value::Object(properties));
+    merged.insert("required".to_string(), Value::Array(required));
+    (merged, discriminator)
+}
+
+/// Detect the discriminator of a union of object schemas.
+///
+/// Uses an explicit OpenAPI-style `discriminator.propertyName` if present;
+/// otherwise looks for a property that every branch pins to a distinct value
+/// with `const` (or a single-value `enum`).
+fn detect_discriminator(
+    union: &Map<String, Value>,
+    branches: &[&Map<String, Value>],
+) -> Option<Discriminator> {
+    let explicit = union.get("discriminator");
+    let candidates: Vec<String> = match explicit
+        .and_then(|d| d.get("propertyName"))
+        .and_then(|v| v.as_str())
+    {
+        Some(property) => vec![property.to_string()],
+        None => branches
+            .first()?
+            .get("properties")?
+            .as_object()?
+            .keys()
+            .cloned()
+            .collect(),
+    };
+
+    for property in candidates {
+        let values: Option<Vec<String>> = branches
+            .iter()
+            .map(|b| branch_discriminator_value(b, &property))
+            .collect();
+        let distinct = values
+            .as_ref()
+            .is_some_and(|v| v.iter().collect::<HashSet<_>>().len() == v.len());
+
+        if let (Some(values), true) = (values, distinct) {
+            let mapping = values
+                .into_iter()
+                .zip(branches)
+                .enumerate()
+                .map(|(i, (value, branch))| {
+                    let title = branch
+                        .get("title")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| format!("option{}", i + 1));
+                    (value, json!(title))
+                })
+                .collect();
+            return Some(Discriminator { property, mapping });
+        }
+
+        if explicit.is_some() {
+            // Fall back to the explicit mapping (if any)
+            let mapping = explicit
+                .and_then(|d| d.get("mapping"))
+                .and_then(|v| v.as_object())
+                .cloned()
+                .unwrap_or_default();
+            return Some(Discriminator { property, mapping });
+        }
+    }
+
+    None
+}
+
+/// Get the value a union branch pins its discriminator property to.
+fn branch_discriminator_value(branch: &Map<String, Value>, property: &str) -> Option<String> {
+    let schema = branch.get("properties")?.get(property)?;
+    let value = match schema.get("const") {
+        Some(value) => value,
+        None => match schema.get("enum")?.as_array()?.as_slice() {
+            [value] => value,
+            _ => return None,
+        },
+    };
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Mark the discriminator column of a union.
+fn annotate_discriminator(
+    columns: &mut [Column],
+    column_name: &str,
+    discriminator: &Discriminator,
+) {
+    if let Some(column) = columns.iter_mut().find(|c| c.name == column_name) {
+        column
+            .custom_properties
+            .insert("discriminator".to_string(), json!(true));
+        if !discriminator.mapping.is_empty() {
+            column.custom_properties.insert(
+                "discriminatorMapping".to_string(),
+                Value::Object(discriminator.mapping.clone()),
+            );
+        }
+    }
+}
+
+/// Build the data type of a union from its branch types.
+fn union_data_type(types: impl Iterator<Item = String>) -> String {
+    let mut distinct: Vec<String> = Vec::new();
+    for data_type in types {
+        if !distinct.contains(&data_type) {
+            distinct.push(data_type);
+        }
+    }
+    match distinct.as_slice() {
+        [] => "STRING".to_string(),
+        [single] => single.clone(),
+        _ => format!("UNION<{}>", distinct.join(", ")),
+    }
+}
+
 /// Parser error structure (matches ODCL parser format).
 #[derive(Debug, Clone)]
 pub struct ParserError {
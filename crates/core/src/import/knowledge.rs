@@ -3,19 +3,30 @@
 //! Parses Knowledge Base article YAML files (.kb.yaml) and converts them to KnowledgeArticle models.
 //! Also handles the knowledge index file (knowledge.yaml).
 
-use super::ImportError;
+use super::{ImportError, check_limits};
 use crate::models::knowledge::{KnowledgeArticle, KnowledgeIndex};
+use crate::validation::limits::ResourceLimits;
 
 #[cfg(feature = "schema-validation")]
 use crate::validation::schema::validate_knowledge_internal;
 
 /// Knowledge importer for parsing Knowledge Base article YAML files
-pub struct KnowledgeImporter;
+pub struct KnowledgeImporter {
+    limits: ResourceLimits,
+}
 
 impl KnowledgeImporter {
     /// Create a new Knowledge importer instance
     pub fn new() -> Self {
-        Self
+        Self {
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for knowledge article YAML
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Import a knowledge article from YAML content
@@ -53,6 +64,7 @@ impl KnowledgeImporter {
     /// assert_eq!(article.title, "Data Classification Guide");
     /// ```
     pub fn import(&self, yaml_content: &str) -> Result<KnowledgeArticle, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         // Validate against JSON Schema if feature is enabled
         #[cfg(feature = "schema-validation")]
         {
@@ -81,6 +93,7 @@ impl KnowledgeImporter {
         &self,
         yaml_content: &str,
     ) -> Result<KnowledgeArticle, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         KnowledgeArticle::from_yaml(yaml_content).map_err(|e| {
             ImportError::ParseError(format!("Failed to parse knowledge article YAML: {}", e))
         })
@@ -111,6 +124,7 @@ impl KnowledgeImporter {
     /// assert_eq!(index.next_number, 1);
     /// ```
     pub fn import_index(&self, yaml_content: &str) -> Result<KnowledgeIndex, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         KnowledgeIndex::from_yaml(yaml_content).map_err(|e| {
             ImportError::ParseError(format!("Failed to parse knowledge index YAML: {}", e))
         })
@@ -5,17 +5,34 @@
 //! - ODCS (Open Data Contract Standard) v3.1.0 YAML format (legacy ODCL formats supported for import)
 //! - JSON Schema
 //! - AVRO
+//! - CSV/TSV (with delimiter, header and type sniffing; requires the `inference` feature)
 //! - Protobuf
+//! - DBML (dbdiagram.io)
+//! - OpenAPI 3.x component schemas
+//! - XML Schema (XSD) elements and complex types (requires the `xsd` feature)
+//! - Databricks Unity Catalog tables (requires the `databricks` feature)
+//! - AWS Glue Data Catalog tables (requires the `aws` feature)
 //! - Decision (MADR-compliant decision records)
 //! - Knowledge (Knowledge Base articles)
+//!
+//! Use [`import_auto`] to detect the format of unknown content, or register custom
+//! formats with an [`ImporterRegistry`]. When importing into an existing
+//! workspace, [`conflicts::ImportConflictReport`] detects and resolves tables that
+//! collide with existing ones.
 
 pub mod avro;
 #[cfg(feature = "bpmn")]
 pub mod bpmn;
 pub mod cads;
+pub mod conflicts;
+#[cfg(feature = "inference")]
+pub mod csv;
+pub mod dbml;
 pub mod decision;
 #[cfg(feature = "dmn")]
 pub mod dmn;
+#[cfg(feature = "aws")]
+pub mod glue;
 pub mod json_schema;
 pub mod knowledge;
 pub mod odcl;
@@ -24,9 +41,17 @@ pub mod odcs_shared;
 pub mod odps;
 #[cfg(feature = "openapi")]
 pub mod openapi;
+pub mod openapi_schema;
 pub mod protobuf;
+pub mod ref_resolver;
+pub mod registry;
 pub mod sketch;
 pub mod sql;
+pub mod sql_stream;
+#[cfg(feature = "databricks")]
+pub mod unity_catalog;
+#[cfg(feature = "xsd")]
+pub mod xsd;
 
 // anyhow::Result not currently used in this module
 
@@ -67,6 +92,8 @@ pub enum ImportError {
     DMNParseError(String),
     #[error("OpenAPI parse error: {0}")]
     OpenAPIParseError(String),
+    #[error("Unmapped type: {0}")]
+    UnmappedType(String),
 }
 
 /// Table data from import - preserves all ODCS v3.1.0 contract-level fields
@@ -343,9 +370,65 @@ impl Default for ColumnData {
     }
 }
 
+/// Warnings for native types of `dialect` that were preserved because they couldn't be mapped.
+///
+/// Returns one [`ImportError::UnmappedType`] per native type, listing the columns using it.
+pub(crate) fn unmapped_type_warnings(dialect: &str, tables: &[TableData]) -> Vec<ImportError> {
+    let mut unmapped: std::collections::BTreeMap<&str, Vec<String>> =
+        std::collections::BTreeMap::new();
+    for table in tables {
+        for column in &table.columns {
+            if let Some(native_type) =
+                crate::models::type_mapping::source_type(&column.custom_properties, dialect)
+            {
+                let column_name = match &table.name {
+                    Some(table_name) => format!("{}.{}", table_name, column.name),
+                    None => column.name.clone(),
+                };
+                unmapped.entry(native_type).or_default().push(column_name);
+            }
+        }
+    }
+    unmapped
+        .into_iter()
+        .map(|(native_type, columns)| {
+            ImportError::UnmappedType(format!(
+                "{} type '{}' has no mapping and was preserved as-is on {}",
+                dialect,
+                native_type,
+                columns.join(", ")
+            ))
+        })
+        .collect()
+}
+
+/// Check a JSON or YAML document against resource limits
+pub(crate) fn check_limits(
+    limits: &crate::validation::limits::ResourceLimits,
+    content: &str,
+) -> Result<(), ImportError> {
+    limits
+        .check_content("import content", content)
+        .map_err(|e| ImportError::ValidationError(e.to_string()))
+}
+
+/// Check the size of import content in any other format against resource limits
+pub(crate) fn check_size(
+    limits: &crate::validation::limits::ResourceLimits,
+    content: &str,
+) -> Result<(), ImportError> {
+    limits
+        .check_size("import content", content.len() as u64)
+        .map_err(|e| ImportError::ValidationError(e.to_string()))
+}
+
 // Re-export for convenience
 pub use avro::AvroImporter;
 pub use cads::CADSImporter;
+pub use conflicts::{ConflictResolution, ImportConflictReport};
+#[cfg(feature = "inference")]
+pub use csv::CsvImporter;
+pub use dbml::DbmlImporter;
 pub use decision::DecisionImporter;
 pub use json_schema::JSONSchemaImporter;
 pub use knowledge::KnowledgeImporter;
@@ -353,9 +436,14 @@ pub use odcl::ODCLImporter;
 pub use odcs::ODCSImporter;
 pub use odcs_shared::ParserError;
 pub use odps::ODPSImporter;
+pub use openapi_schema::OpenApiImporter;
 pub use protobuf::ProtobufImporter;
+pub use registry::{DetectedImport, Importer, ImporterRegistry, import_auto};
 pub use sketch::SketchImporter;
 pub use sql::SQLImporter;
+pub use sql_stream::{SqlStatementReader, SqlStreamProgress, StreamingSqlImporter};
+#[cfg(feature = "xsd")]
+pub use xsd::XsdImporter;
 
 /// Table requiring name input (for SQL imports)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -16,9 +16,10 @@ use super::odcs_shared::{
     parse_foreign_key_from_data_contract, parse_medallion_layer, parse_scd_pattern,
     parse_struct_fields_from_string, resolve_ref, yaml_to_json_value,
 };
-use super::{ImportError, ImportResult, TableData};
+use super::{ImportError, ImportResult, TableData, check_limits};
 use crate::models::enums::{DataVaultClassification, DatabaseType, MedallionLayer, SCDPattern};
-use crate::models::{Column, PropertyRelationship, Table, Tag};
+use crate::models::{Column, IdGenerator, PropertyRelationship, Table, Tag};
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
@@ -54,6 +55,9 @@ fn ref_to_relationships(ref_path: &Option<String>) -> Vec<PropertyRelationship>
 pub struct ODCLImporter {
     /// Current YAML data for $ref resolution
     current_yaml_data: Option<serde_yaml::Value>,
+    limits: ResourceLimits,
+    /// Generator for the IDs of tables without a UUID
+    ids: IdGenerator,
 }
 
 impl ODCLImporter {
@@ -69,9 +73,23 @@ impl ODCLImporter {
     pub fn new() -> Self {
         Self {
             current_yaml_data: None,
+            limits: ResourceLimits::default(),
+            ids: IdGenerator::default(),
         }
     }
 
+    /// Set the [`ResourceLimits`] for ODCL contracts
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Give tables without a UUID IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
     /// Import ODCL YAML content and create Table (SDK interface).
     ///
     /// Supports Data Contract Specification format and simple ODCL format.
@@ -103,6 +121,7 @@ impl ODCLImporter {
     /// assert_eq!(result.tables.len(), 1);
     /// ```
     pub fn import(&mut self, yaml_content: &str) -> Result<ImportResult, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         // First parse YAML to get raw data for field extraction
         let yaml_data: serde_yaml::Value = serde_yaml::from_str(yaml_content)
             .map_err(|e| ImportError::ParseError(format!("Failed to parse YAML: {}", e)))?;
@@ -211,6 +230,7 @@ impl ODCLImporter {
     /// Returns a tuple of (Table, list of errors/warnings).
     /// Errors list is empty if parsing is successful.
     pub fn parse_table(&mut self, yaml_content: &str) -> Result<(Table, Vec<ParserError>)> {
+        check_limits(&self.limits, yaml_content)?;
         self.parse(yaml_content)
     }
 
@@ -1509,14 +1529,16 @@ impl ODCLImporter {
             return uuid;
         }
 
-        // Generate deterministic UUID v5 if not found (based on table name)
+        // Generate a UUID with the configured strategy if not found
         let table_name = data
             .get("name")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown");
-        let new_uuid = crate::models::table::Table::generate_id(table_name, None, None, None);
+        let new_uuid = crate::models::table::Table::generate_id_with_generator(
+            &self.ids, table_name, None, None,
+        );
         tracing::warn!(
-            "[ODCLImporter] No UUID found for table '{}', generating deterministic UUID: {}",
+            "[ODCLImporter] No UUID found for table '{}', generating UUID: {}",
             table_name,
             new_uuid
         );
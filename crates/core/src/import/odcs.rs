@@ -13,12 +13,15 @@ use super::odcs_shared::{
     normalize_data_type, parse_data_vault_classification, parse_medallion_layer, parse_scd_pattern,
     resolve_ref, yaml_to_json_value,
 };
-use super::{ImportError, ImportResult, TableData};
+use super::{ImportError, ImportResult, TableData, check_limits};
 use crate::models::column::ForeignKey;
+use crate::models::definitions::PropertyDefinitions;
 use crate::models::enums::{DataVaultClassification, DatabaseType, MedallionLayer, SCDPattern};
-use crate::models::{Column, PropertyRelationship, Table, Tag};
+use crate::models::{Column, IdGenerator, PropertyRelationship, Table, Tag};
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use serde_json::Value as JsonValue;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::str::FromStr;
 use tracing::info;
@@ -53,6 +56,11 @@ fn ref_to_relationships(ref_path: &Option<String>) -> Vec<PropertyRelationship>
 pub struct ODCSImporter {
     /// Current YAML data for $ref resolution
     current_yaml_data: Option<serde_yaml::Value>,
+    /// Workspace property definitions for `$ref` resolution
+    definitions: Option<PropertyDefinitions>,
+    limits: ResourceLimits,
+    /// Generator for the IDs of tables without an `id`
+    ids: IdGenerator,
 }
 
 impl ODCSImporter {
@@ -68,6 +76,66 @@ impl ODCSImporter {
     pub fn new() -> Self {
         Self {
             current_yaml_data: None,
+            definitions: None,
+            limits: ResourceLimits::default(),
+            ids: IdGenerator::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for ODCS contracts
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Give tables without an `id` IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Resolve `$ref`s to the workspace's reusable property definitions.
+    ///
+    /// Referenced definitions are inlined before parsing; see
+    /// [`crate::models::definitions`] for the reference syntax.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::odcs::ODCSImporter;
+    /// use data_modelling_core::models::definitions::PropertyDefinitions;
+    ///
+    /// let definitions = PropertyDefinitions::from_yaml(
+    ///     "definitions:\n  tenant_id:\n    logicalType: string\n    required: true\n",
+    /// )
+    /// .unwrap();
+    /// let mut importer = ODCSImporter::new().with_definitions(definitions);
+    /// let yaml = r#"
+    /// apiVersion: v3.1.0
+    /// kind: DataContract
+    /// id: orders-contract
+    /// version: 1.0.0
+    /// name: orders
+    /// schema:
+    ///   - name: orders
+    ///     properties:
+    ///       - $ref: definitions.yaml#/definitions/tenant_id
+    /// "#;
+    /// let result = importer.import(yaml).unwrap();
+    /// assert_eq!(result.tables[0].columns[0].name, "tenant_id");
+    /// ```
+    pub fn with_definitions(mut self, definitions: PropertyDefinitions) -> Self {
+        self.definitions = Some(definitions);
+        self
+    }
+
+    /// Inline workspace property definitions referenced by the content
+    fn resolve_definitions<'a>(&self, yaml_content: &'a str) -> Result<Cow<'a, str>, String> {
+        match &self.definitions {
+            Some(definitions) if !definitions.is_empty() => {
+                definitions.resolve_yaml(yaml_content).map(Cow::Owned)
+            }
+            _ => Ok(Cow::Borrowed(yaml_content)),
         }
     }
 
@@ -105,6 +173,11 @@ impl ODCSImporter {
     /// assert_eq!(result.tables.len(), 1);
     /// ```
     pub fn import(&mut self, yaml_content: &str) -> Result<ImportResult, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
+        let yaml_content = &*self
+            .resolve_definitions(yaml_content)
+            .map_err(ImportError::ParseError)?;
+
         // First parse YAML to get raw data for ODCS field extraction
         let yaml_data: serde_yaml::Value = serde_yaml::from_str(yaml_content)
             .map_err(|e| ImportError::ParseError(format!("Failed to parse YAML: {}", e)))?;
@@ -346,11 +419,16 @@ impl ODCSImporter {
         &mut self,
         yaml_content: &str,
     ) -> Result<crate::models::odcs::ODCSContract, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         use crate::models::odcs::{
             AuthoritativeDefinition, CustomProperty, Description, ODCSContract, QualityRule, Role,
             Server, ServiceLevel, Support, Team,
         };
 
+        let yaml_content = &*self
+            .resolve_definitions(yaml_content)
+            .map_err(ImportError::ParseError)?;
+
         // Parse YAML
         let yaml_data: serde_yaml::Value = serde_yaml::from_str(yaml_content)
             .map_err(|e| ImportError::ParseError(format!("Failed to parse YAML: {}", e)))?;
@@ -1001,6 +1079,7 @@ impl ODCSImporter {
     /// Returns a tuple of (Table, list of errors/warnings).
     /// Errors list is empty if parsing is successful.
     pub fn parse_table(&mut self, yaml_content: &str) -> Result<(Table, Vec<ParserError>)> {
+        check_limits(&self.limits, yaml_content)?;
         self.parse(yaml_content)
     }
 
@@ -1014,6 +1093,10 @@ impl ODCSImporter {
     /// Returns a tuple of (Table, list of errors/warnings).
     /// Errors list is empty if parsing is successful.
     fn parse(&mut self, yaml_content: &str) -> Result<(Table, Vec<ParserError>)> {
+        let yaml_content = &*self
+            .resolve_definitions(yaml_content)
+            .map_err(anyhow::Error::msg)?;
+
         // Errors are collected in helper functions, not here
         let _errors: Vec<ParserError> = Vec::new();
 
@@ -1538,7 +1621,7 @@ impl ODCSImporter {
 
         let table_name = table_name
             .ok_or_else(|| anyhow::anyhow!("Liquibase changelog did not contain a createTable"))?;
-        let table = Table::new(table_name, columns);
+        let table = Table::new_with_generator(table_name, columns, &self.ids);
         // Preserve any errors collected.
         Ok((table, errors))
     }
@@ -2054,11 +2137,12 @@ impl ODCSImporter {
             // First check if schema object has its own id
             let table_uuid = if let Some(id_str) = schema_object.get("id").and_then(|v| v.as_str())
             {
-                uuid::Uuid::parse_str(id_str)
-                    .unwrap_or_else(|_| Table::generate_id(&table_name, None, None, None))
+                uuid::Uuid::parse_str(id_str).unwrap_or_else(|_| {
+                    Table::generate_id_with_generator(&self.ids, &table_name, None, None)
+                })
             } else {
-                // For multi-table ODCS, generate deterministic UUID from table name
-                Table::generate_id(&table_name, None, None, None)
+                // For multi-table ODCS, generate a UUID with the configured strategy
+                Table::generate_id_with_generator(&self.ids, &table_name, None, None)
             };
 
             // Clone metadata for this table
@@ -2396,18 +2480,17 @@ impl ODCSImporter {
             return uuid;
         }
 
-        // Generate deterministic UUID v5 if not found (based on table name)
+        // Generate a UUID with the configured strategy if not found
         let table_name = data
             .get("name")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown");
-        let new_uuid = crate::models::table::Table::generate_id(
-            table_name, None, // database_type not available here
-            None, // catalog_name not available here
+        let new_uuid = crate::models::table::Table::generate_id_with_generator(
+            &self.ids, table_name, None, // catalog_name not available here
             None, // schema_name not available here
         );
         tracing::warn!(
-            "[ODCSImporter] No UUID found for table '{}', generating deterministic UUID: {}. This may cause relationships to become orphaned!",
+            "[ODCSImporter] No UUID found for table '{}', generating UUID: {}. This may cause relationships to become orphaned!",
             table_name,
             new_uuid
         );
@@ -3639,6 +3722,15 @@ impl ODCSImporter {
                     .and_then(|v| v.as_i64())
                     .map(|n| n as i32),
                 scale: opts.get("scale").and_then(|v| v.as_i64()).map(|n| n as i32),
+                srid: opts.get("srid").and_then(|v| v.as_i64()).map(|n| n as i32),
+                dimension: opts
+                    .get("dimension")
+                    .and_then(|v| v.as_i64())
+                    .map(|n| n as i32),
+                distance_metric: opts
+                    .get("distanceMetric")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
             })
         });
 
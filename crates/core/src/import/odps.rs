@@ -2,9 +2,10 @@
 //!
 //! Parses ODPS YAML files and converts them to ODPSDataProduct models.
 
-use super::ImportError;
+use super::{ImportError, check_limits};
 use crate::models::Tag;
 use crate::models::odps::*;
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use serde_json::Value as JsonValue;
 use serde_yaml::Value as YamlValue;
@@ -14,6 +15,7 @@ use std::str::FromStr;
 pub struct ODPSImporter {
     /// Optional: Known ODCS Table IDs for contractId validation
     known_table_ids: Option<Vec<String>>,
+    limits: ResourceLimits,
 }
 
 impl ODPSImporter {
@@ -21,13 +23,21 @@ impl ODPSImporter {
     pub fn new() -> Self {
         Self {
             known_table_ids: None,
+            limits: ResourceLimits::default(),
         }
     }
 
+    /// Set the [`ResourceLimits`] for ODPS documents
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Create a new ODPS importer with known table IDs for contractId validation
     pub fn with_table_ids(table_ids: Vec<String>) -> Self {
         Self {
             known_table_ids: Some(table_ids),
+            limits: ResourceLimits::default(),
         }
     }
 
@@ -59,6 +69,7 @@ impl ODPSImporter {
     /// assert_eq!(product.name, Some("customer-data-product".to_string()));
     /// ```
     pub fn import(&self, yaml_content: &str) -> Result<ODPSDataProduct, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         // Validate against ODPS schema before parsing (if feature enabled)
         #[cfg(feature = "odps-validation")]
         {
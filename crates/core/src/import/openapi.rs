@@ -2,6 +2,9 @@
 //!
 //! Provides functionality to import OpenAPI 3.1.1 YAML or JSON files with validation.
 
+use crate::import::check_limits;
+use crate::validation::input::MAX_OPENAPI_FILE_SIZE;
+use crate::validation::limits::ResourceLimits;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -11,16 +14,34 @@ use crate::models::openapi::{OpenAPIFormat, OpenAPIModel};
 /// OpenAPI Importer
 ///
 /// Imports OpenAPI 3.1.1 YAML or JSON content into an OpenAPIModel struct.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct OpenAPIImporter {
     /// List of errors encountered during parsing
     pub errors: Vec<String>,
+    limits: ResourceLimits,
+}
+
+impl Default for OpenAPIImporter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl OpenAPIImporter {
     /// Create a new OpenAPIImporter
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            limits: ResourceLimits::default().with_max_file_size(MAX_OPENAPI_FILE_SIZE),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for OpenAPI specifications
+    ///
+    /// The default limits allow at most [`MAX_OPENAPI_FILE_SIZE`] bytes.
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Detect format (YAML or JSON) from content
@@ -404,6 +425,7 @@ impl OpenAPIImporter {
         domain_id: Uuid,
         api_name: Option<&str>,
     ) -> Result<OpenAPIModel> {
+        check_limits(&self.limits, content)?;
         // Detect format
         let format = self.detect_format(content);
 
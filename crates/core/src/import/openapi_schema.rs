@@ -0,0 +1,770 @@
+//! OpenAPI 3.x component schema importer
+//!
+//! Extracts the object schemas in `components.schemas` of an OpenAPI 3.0 / 3.1
+//! document as tables, so API payloads can be modelled alongside database tables:
+//!
+//! - **Nested objects** are flattened into dotted columns (`address.city`) below an
+//!   `OBJECT` column; items of arrays of objects use `.[].` (`lines.[].sku`) below an
+//!   `ARRAY<OBJECT>` column
+//! - **`$ref`s** to other components are inlined, `allOf` is merged and `oneOf` /
+//!   `anyOf` of objects are merged into optional properties
+//! - **`format`** maps to the data type (`int64` -> `BIGINT`, `date-time` ->
+//!   `TIMESTAMP`, ...) and is kept in the column's logical type options along with
+//!   length, pattern and range constraints
+//! - **`required`** makes columns non-nullable unless they are marked `nullable`
+//!   (3.0) or allow `null` (3.1); properties of optional objects stay nullable
+//!
+//! Components that are not object schemas (enums, arrays, scalars) become no table
+//! but are inlined where referenced.
+
+use super::{ColumnData, ImportError, ImportResult, TableData, check_limits};
+use crate::models::LogicalTypeOptions;
+use crate::validation::limits::ResourceLimits;
+use serde_json::Value as JsonValue;
+use serde_yaml::{Mapping, Value};
+
+/// Prefix of references to component schemas
+const COMPONENT_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Importer for the component schemas of OpenAPI 3.x documents.
+#[derive(Debug, Clone, Default)]
+pub struct OpenApiImporter {
+    limits: ResourceLimits,
+}
+
+impl OpenApiImporter {
+    /// Create a new OpenAPI component schema importer
+    pub fn new() -> Self {
+        Self {
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for OpenAPI specifications
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Import the object schemas of an OpenAPI document (YAML or JSON) as tables.
+    ///
+    /// Unresolved `$ref`s are reported in [`ImportResult::errors`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::openapi_schema::OpenApiImporter;
+    ///
+    /// let spec = r#"
+    /// openapi: 3.0.3
+    /// info: {title: Orders, version: 1.0.0}
+    /// paths: {}
+    /// components:
+    ///   schemas:
+    ///     Order:
+    ///       type: object
+    ///       required: [id]
+    ///       properties:
+    ///         id: {type: string, format: uuid}
+    ///         placedAt: {type: string, format: date-time}
+    /// "#;
+    /// let result = OpenApiImporter::new().import(spec).unwrap();
+    ///
+    /// let order = &result.tables[0];
+    /// assert_eq!(order.name.as_deref(), Some("Order"));
+    /// assert_eq!(order.columns[0].data_type, "UUID");
+    /// assert!(!order.columns[0].nullable);
+    /// assert_eq!(order.columns[1].data_type, "TIMESTAMP");
+    /// ```
+    pub fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        check_limits(&self.limits, content)?;
+        let document: Value = serde_yaml::from_str(content)
+            .map_err(|e| ImportError::ParseError(format!("Invalid OpenAPI document: {}", e)))?;
+
+        if let Some(version) = document.get("openapi") {
+            let version = version.as_str().unwrap_or_default();
+            if !version.starts_with("3.") {
+                return Err(ImportError::ParseError(format!(
+                    "Unsupported OpenAPI version '{}'. Only OpenAPI 3.x is supported.",
+                    version
+                )));
+            }
+        }
+        let schemas = document
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(Value::as_mapping)
+            .ok_or_else(|| {
+                ImportError::ParseError("OpenAPI document has no components.schemas".to_string())
+            })?;
+
+        let mut walker = SchemaWalker {
+            schemas,
+            visiting: Vec::new(),
+            errors: Vec::new(),
+        };
+        let mut tables = Vec::new();
+        for (name, schema) in schemas {
+            let Some(name) = name.as_str() else {
+                continue;
+            };
+            if let Some(table) = walker.table(name, schema) {
+                tables.push(TableData {
+                    table_index: tables.len(),
+                    ..table
+                });
+            }
+        }
+
+        Ok(ImportResult {
+            tables,
+            tables_requiring_name: Vec::new(),
+            errors: walker.errors,
+            ai_suggestions: None,
+        })
+    }
+}
+
+/// Resolves and flattens schemas of one document
+struct SchemaWalker<'a> {
+    schemas: &'a Mapping,
+    /// Components being inlined, to stop at recursive references
+    visiting: Vec<String>,
+    errors: Vec<ImportError>,
+}
+
+impl SchemaWalker<'_> {
+    /// Table for an object component, or `None` for other components
+    fn table(&mut self, name: &str, schema: &Value) -> Option<TableData> {
+        self.visiting.push(name.to_string());
+        let table = self.resolve(schema, name).and_then(|resolved| {
+            // Only the component itself counts as recursive below this point
+            self.visiting.truncate(1);
+            let object = self.object_schema(&resolved, name)?;
+            let mut columns = Vec::new();
+            self.properties(&object, "", false, name, &mut columns);
+            Some(TableData {
+                name: Some(name.to_string()),
+                description: text(&object, "description").map(JsonValue::String),
+                columns,
+                ..Default::default()
+            })
+        });
+        self.visiting.clear();
+        table
+    }
+
+    /// Columns for the properties of an object schema, prefixed with `prefix`
+    fn properties(
+        &mut self,
+        object: &Mapping,
+        prefix: &str,
+        optional: bool,
+        path: &str,
+        columns: &mut Vec<ColumnData>,
+    ) {
+        let Some(properties) = object.get("properties").and_then(Value::as_mapping) else {
+            return;
+        };
+        let required: Vec<&str> = object
+            .get("required")
+            .and_then(Value::as_sequence)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        for (name, schema) in properties {
+            let Some(name) = name.as_str() else {
+                continue;
+            };
+            let nullable = optional || !required.contains(&name);
+            self.column(
+                format!("{}{}", prefix, name),
+                schema,
+                nullable,
+                &format!("{}.{}", path, name),
+                columns,
+            );
+        }
+    }
+
+    /// Column(s) for a property schema: the column itself and any nested columns
+    fn column(
+        &mut self,
+        name: String,
+        schema: &Value,
+        nullable: bool,
+        path: &str,
+        columns: &mut Vec<ColumnData>,
+    ) {
+        let depth = self.visiting.len();
+        let Some(resolved) = self.resolve(schema, path) else {
+            self.visiting.truncate(depth);
+            columns.push(ColumnData {
+                name,
+                data_type: "OBJECT".to_string(),
+                nullable,
+                ..Default::default()
+            });
+            return;
+        };
+        let (resolved, allows_null) = self.without_null(resolved, path);
+        let nullable = nullable || allows_null;
+
+        let mut column = ColumnData {
+            name: name.clone(),
+            nullable,
+            description: text(&resolved, "description"),
+            logical_type_options: logical_type_options(&resolved),
+            default_value: resolved.get("default").and_then(to_json),
+            examples: examples(&resolved),
+            enum_values: enum_values(&resolved),
+            ..Default::default()
+        };
+        for flag in ["readOnly", "writeOnly", "deprecated"] {
+            if resolved.get(flag).and_then(Value::as_bool) == Some(true) {
+                column
+                    .custom_properties
+                    .insert(flag.to_string(), JsonValue::Bool(true));
+            }
+        }
+
+        let mut nested = Vec::new();
+        column.data_type = match schema_type(&resolved) {
+            Some("object") => match self.object_schema(&resolved, path) {
+                Some(object) if object.contains_key("properties") => {
+                    self.properties(&object, &format!("{}.", name), nullable, path, &mut nested);
+                    "OBJECT".to_string()
+                }
+                _ => self.map_type(&resolved, path),
+            },
+            Some("array") => {
+                let items = resolved.get("items").cloned().unwrap_or(Value::Null);
+                let item_path = format!("{}[]", path);
+                match self.item_object(&items, &item_path) {
+                    Some(object) => {
+                        self.properties(
+                            &object,
+                            &format!("{}.[].", name),
+                            nullable,
+                            &item_path,
+                            &mut nested,
+                        );
+                        "ARRAY<OBJECT>".to_string()
+                    }
+                    None => format!("ARRAY<{}>", self.data_type(&items, &item_path)),
+                }
+            }
+            Some(openapi_type) => scalar_type(openapi_type, text(&resolved, "format").as_deref()),
+            None => "VARIANT".to_string(),
+        };
+
+        columns.push(column);
+        columns.append(&mut nested);
+        self.visiting.truncate(depth);
+    }
+
+    /// Object schema of array items with properties, if any
+    fn item_object(&mut self, items: &Value, path: &str) -> Option<Mapping> {
+        let depth = self.visiting.len();
+        let object = self
+            .resolve(items, path)
+            .map(|resolved| self.without_null(resolved, path).0)
+            .filter(|resolved| schema_type(resolved) == Some("object"))
+            .and_then(|resolved| self.object_schema(&resolved, path))
+            .filter(|object| object.contains_key("properties"));
+        self.visiting.truncate(depth);
+        object
+    }
+
+    /// Data type of a schema without nested columns (array items, map values)
+    fn data_type(&mut self, schema: &Value, path: &str) -> String {
+        let depth = self.visiting.len();
+        let data_type = match self.resolve(schema, path) {
+            Some(resolved) => {
+                let (resolved, _) = self.without_null(resolved, path);
+                match schema_type(&resolved) {
+                    Some("object") => self.map_type(&resolved, path),
+                    Some("array") => {
+                        let items = resolved.get("items").cloned().unwrap_or(Value::Null);
+                        format!("ARRAY<{}>", self.data_type(&items, &format!("{}[]", path)))
+                    }
+                    Some(openapi_type) => {
+                        scalar_type(openapi_type, text(&resolved, "format").as_deref())
+                    }
+                    None => "VARIANT".to_string(),
+                }
+            }
+            None => "OBJECT".to_string(),
+        };
+        self.visiting.truncate(depth);
+        data_type
+    }
+
+    /// `MAP<STRING, ...>` for objects with typed `additionalProperties`, else `OBJECT`
+    fn map_type(&mut self, schema: &Mapping, path: &str) -> String {
+        match schema.get("additionalProperties") {
+            Some(values @ Value::Mapping(_)) => {
+                format!("MAP<STRING, {}>", self.data_type(values, path))
+            }
+            _ => "OBJECT".to_string(),
+        }
+    }
+
+    /// Resolve a `$ref` and merge `allOf`, returning `None` for schemas that can't be
+    /// inlined (unresolved or recursive references)
+    ///
+    /// Components referenced on the way are pushed to `visiting`; callers truncate it
+    /// once the schema is processed.
+    fn resolve(&mut self, schema: &Value, path: &str) -> Option<Mapping> {
+        let Some(schema) = schema.as_mapping() else {
+            return Some(Mapping::new());
+        };
+
+        let mut resolved = match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => {
+                let Some(name) = reference.strip_prefix(COMPONENT_REF_PREFIX) else {
+                    self.errors.push(ImportError::ValidationError(format!(
+                        "{}: unsupported reference '{}'",
+                        path, reference
+                    )));
+                    return None;
+                };
+                if self.visiting.iter().any(|v| v == name) {
+                    return None;
+                }
+                let Some(target) = self.schemas.get(name) else {
+                    self.errors.push(ImportError::ValidationError(format!(
+                        "{}: unresolved reference '{}'",
+                        path, reference
+                    )));
+                    return None;
+                };
+                self.visiting.push(name.to_string());
+                let mut target = self.resolve(target, path)?;
+                // Keywords next to the $ref (e.g., description) override the target
+                for (key, value) in schema {
+                    if key.as_str() != Some("$ref") {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+                target
+            }
+            None => schema.clone(),
+        };
+
+        if let Some(Value::Sequence(all_of)) = resolved.remove("allOf") {
+            for sub_schema in &all_of {
+                if let Some(sub_schema) = self.resolve(sub_schema, path) {
+                    merge_schema(&mut resolved, sub_schema);
+                }
+            }
+        }
+        Some(resolved)
+    }
+
+    /// Split `null` off a schema: `oneOf`/`anyOf` with a `null` branch collapse to the
+    /// remaining branch, and a lone object branch is returned as the schema itself
+    fn without_null(&mut self, mut schema: Mapping, path: &str) -> (Mapping, bool) {
+        let mut allows_null = schema.get("nullable").and_then(Value::as_bool) == Some(true);
+        if let Some(Value::Sequence(types)) = schema.get("type") {
+            let non_null: Vec<Value> = types
+                .iter()
+                .filter(|t| t.as_str() != Some("null"))
+                .cloned()
+                .collect();
+            allows_null |= non_null.len() < types.len();
+            match non_null.as_slice() {
+                [single] => {
+                    schema.insert(Value::from("type"), single.clone());
+                }
+                _ => {
+                    schema.remove("type");
+                }
+            }
+        }
+
+        if schema.contains_key("type") {
+            return (schema, allows_null);
+        }
+        for keyword in ["oneOf", "anyOf"] {
+            let Some(Value::Sequence(branches)) = schema.get(keyword).cloned() else {
+                continue;
+            };
+            let mut resolved = Vec::new();
+            for branch in &branches {
+                let depth = self.visiting.len();
+                if let Some(branch) = self.resolve(branch, path) {
+                    if schema_type(&branch) == Some("null") {
+                        allows_null = true;
+                    } else {
+                        resolved.push(branch);
+                    }
+                }
+                self.visiting.truncate(depth);
+            }
+            schema.remove(keyword);
+            match resolved.len() {
+                0 => {}
+                1 => {
+                    let mut single = resolved.remove(0);
+                    for (key, value) in schema {
+                        single.insert(key, value);
+                    }
+                    return self.without_null(single, path);
+                }
+                _ if resolved
+                    .iter()
+                    .all(|b| schema_type(b) == Some("object") && b.contains_key("properties")) =>
+                {
+                    // Every branch's properties are optional in the merged object
+                    let mut merged = Mapping::new();
+                    for mut branch in resolved {
+                        branch.remove("required");
+                        merge_schema(&mut merged, branch);
+                    }
+                    merged.remove("required");
+                    for (key, value) in schema {
+                        merged.insert(key, value);
+                    }
+                    return (merged, allows_null);
+                }
+                _ => {}
+            }
+            break;
+        }
+        (schema, allows_null)
+    }
+
+    /// The schema as an object, if it is one
+    fn object_schema(&mut self, schema: &Mapping, path: &str) -> Option<Mapping> {
+        let (schema, _) = self.without_null(schema.clone(), path);
+        (schema_type(&schema) == Some("object")).then_some(schema)
+    }
+}
+
+/// Type of a resolved schema, inferred from `properties`/`items` if missing
+fn schema_type(schema: &Mapping) -> Option<&str> {
+    match schema.get("type") {
+        Some(Value::String(t)) => Some(t.as_str()),
+        _ if schema.contains_key("properties") || schema.contains_key("additionalProperties") => {
+            Some("object")
+        }
+        _ if schema.contains_key("items") => Some("array"),
+        _ if schema.contains_key("enum") => Some("string"),
+        _ => None,
+    }
+}
+
+/// Data type of a scalar OpenAPI type and format
+fn scalar_type(openapi_type: &str, format: Option<&str>) -> String {
+    match (openapi_type, format) {
+        ("integer", Some("int64")) => "BIGINT",
+        ("integer", _) => "INTEGER",
+        ("number", Some("float")) => "FLOAT",
+        ("number", Some("decimal")) => "DECIMAL",
+        ("number", _) => "DOUBLE",
+        ("boolean", _) => "BOOLEAN",
+        ("string", Some("date")) => "DATE",
+        ("string", Some("date-time")) => "TIMESTAMP",
+        ("string", Some("time")) => "TIME",
+        ("string", Some("uuid")) => "UUID",
+        ("string", Some("byte" | "binary")) => "BINARY",
+        _ => "STRING",
+    }
+    .to_string()
+}
+
+/// Merge the properties, `required` and other keywords of `sub_schema` into `target`
+fn merge_schema(target: &mut Mapping, sub_schema: Mapping) {
+    for (key, value) in sub_schema {
+        let Some(existing) = target.get_mut(&key) else {
+            target.insert(key, value);
+            continue;
+        };
+        match (existing, value) {
+            (Value::Mapping(properties), Value::Mapping(more))
+                if key.as_str() == Some("properties") =>
+            {
+                properties.extend(more);
+            }
+            (Value::Sequence(required), Value::Sequence(more))
+                if key.as_str() == Some("required") =>
+            {
+                for name in more {
+                    if !required.contains(&name) {
+                        required.push(name);
+                    }
+                }
+            }
+            // Keywords already set on the target take precedence
+            _ => {}
+        }
+    }
+}
+
+/// Logical type options for the `format` and constraints of a schema
+fn logical_type_options(schema: &Mapping) -> Option<LogicalTypeOptions> {
+    let number = |key: &str| schema.get(key).filter(|v| v.is_number()).and_then(to_json);
+    let mut options = LogicalTypeOptions {
+        format: text(schema, "format"),
+        pattern: text(schema, "pattern"),
+        min_length: schema.get("minLength").and_then(Value::as_i64),
+        max_length: schema.get("maxLength").and_then(Value::as_i64),
+        minimum: number("minimum"),
+        maximum: number("maximum"),
+        exclusive_minimum: number("exclusiveMinimum"),
+        exclusive_maximum: number("exclusiveMaximum"),
+        ..Default::default()
+    };
+    // OpenAPI 3.0 marks `minimum`/`maximum` as exclusive with a boolean
+    if schema.get("exclusiveMinimum").and_then(Value::as_bool) == Some(true) {
+        options.exclusive_minimum = options.minimum.take();
+    }
+    if schema.get("exclusiveMaximum").and_then(Value::as_bool) == Some(true) {
+        options.exclusive_maximum = options.maximum.take();
+    }
+    (!options.is_empty()).then_some(options)
+}
+
+/// Examples of a schema: OpenAPI 3.0 `example` or JSON Schema `examples`
+fn examples(schema: &Mapping) -> Vec<JsonValue> {
+    match (schema.get("examples"), schema.get("example")) {
+        (Some(Value::Sequence(examples)), _) => examples.iter().filter_map(to_json).collect(),
+        (_, Some(example)) => to_json(example).into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn enum_values(schema: &Mapping) -> Option<Vec<String>> {
+    let values: Vec<String> = schema
+        .get("enum")
+        .and_then(Value::as_sequence)?
+        .iter()
+        .filter_map(|v| match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Null => None,
+            other => to_json(other).map(|v| v.to_string()),
+        })
+        .collect();
+    (!values.is_empty()).then_some(values)
+}
+
+fn text(schema: &Mapping, key: &str) -> Option<String> {
+    schema.get(key).and_then(Value::as_str).map(String::from)
+}
+
+fn to_json(value: &Value) -> Option<JsonValue> {
+    serde_json::to_value(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SPEC: &str = r#"
+openapi: 3.0.3
+info:
+  title: Orders API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Status:
+      type: string
+      enum: [pending, shipped]
+    Address:
+      type: object
+      required: [city]
+      properties:
+        street:
+          type: string
+        city:
+          type: string
+    Order:
+      description: A customer order
+      type: object
+      required: [id, status, lines]
+      properties:
+        id:
+          type: integer
+          format: int64
+          readOnly: true
+        status:
+          $ref: '#/components/schemas/Status'
+        total:
+          type: number
+          format: double
+          minimum: 0
+          exclusiveMinimum: true
+          example: 42.5
+        shippingAddress:
+          $ref: '#/components/schemas/Address'
+        lines:
+          type: array
+          items:
+            type: object
+            required: [sku]
+            properties:
+              sku:
+                type: string
+                pattern: '^[A-Z]{3}-\d+$'
+              quantity:
+                type: integer
+                format: int32
+        tags:
+          type: array
+          items:
+            type: string
+        attributes:
+          type: object
+          additionalProperties:
+            type: string
+        note:
+          type: string
+          nullable: true
+"#;
+
+    #[test]
+    fn test_import_components() {
+        let result = OpenApiImporter::new().import(SPEC).unwrap();
+        assert!(result.errors.is_empty());
+
+        let names: Vec<_> = result.tables.iter().map(|t| t.name.as_deref()).collect();
+        assert_eq!(names, vec![Some("Address"), Some("Order")]);
+
+        let order = &result.tables[1];
+        assert_eq!(order.table_index, 1);
+        assert_eq!(order.description, Some(json!("A customer order")));
+
+        let columns: Vec<(&str, &str, bool)> = order
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.data_type.as_str(), c.nullable))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id", "BIGINT", false),
+                ("status", "STRING", false),
+                ("total", "DOUBLE", true),
+                ("shippingAddress", "OBJECT", true),
+                ("shippingAddress.street", "STRING", true),
+                ("shippingAddress.city", "STRING", true),
+                ("lines", "ARRAY<OBJECT>", false),
+                ("lines.[].sku", "STRING", false),
+                ("lines.[].quantity", "INTEGER", true),
+                ("tags", "ARRAY<STRING>", true),
+                ("attributes", "MAP<STRING, STRING>", true),
+                ("note", "STRING", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_formats_and_constraints() {
+        let result = OpenApiImporter::new().import(SPEC).unwrap();
+        let order = &result.tables[1];
+        let column = |name: &str| order.columns.iter().find(|c| c.name == name).unwrap();
+
+        let id = column("id");
+        assert_eq!(
+            id.logical_type_options.as_ref().unwrap().format.as_deref(),
+            Some("int64")
+        );
+        assert_eq!(id.custom_properties.get("readOnly"), Some(&json!(true)));
+
+        assert_eq!(
+            column("status").enum_values,
+            Some(vec!["pending".to_string(), "shipped".to_string()])
+        );
+
+        let total = column("total");
+        let options = total.logical_type_options.as_ref().unwrap();
+        assert_eq!(options.minimum, None);
+        assert_eq!(options.exclusive_minimum, Some(json!(0)));
+        assert_eq!(total.examples, vec![json!(42.5)]);
+
+        let sku = column("lines.[].sku");
+        assert_eq!(
+            sku.logical_type_options
+                .as_ref()
+                .unwrap()
+                .pattern
+                .as_deref(),
+            Some(r"^[A-Z]{3}-\d+$")
+        );
+    }
+
+    #[test]
+    fn test_composition_and_nullable_types() {
+        let spec = r##"{
+  "openapi": "3.1.0",
+  "info": {"title": "Pets", "version": "1"},
+  "components": {
+    "schemas": {
+      "Named": {
+        "type": "object",
+        "required": ["name"],
+        "properties": {"name": {"type": "string"}}
+      },
+      "Pet": {
+        "allOf": [
+          {"$ref": "#/components/schemas/Named"},
+          {
+            "type": "object",
+            "required": ["born"],
+            "properties": {
+              "born": {"type": "string", "format": "date"},
+              "owner": {"anyOf": [{"$ref": "#/components/schemas/Named"}, {"type": "null"}]},
+              "weight": {"type": ["number", "null"], "format": "float"},
+              "parent": {"$ref": "#/components/schemas/Pet"},
+              "chip": {"$ref": "#/components/schemas/Chip"}
+            }
+          }
+        ]
+      }
+    }
+  }
+}"##;
+        let result = OpenApiImporter::new().import(spec).unwrap();
+        assert_eq!(result.errors.len(), 1);
+        assert!(
+            result.errors[0]
+                .to_string()
+                .contains("Pet.chip: unresolved reference")
+        );
+
+        let pet = &result.tables[1];
+        let columns: Vec<(&str, &str, bool)> = pet
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.data_type.as_str(), c.nullable))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("name", "STRING", false),
+                ("born", "DATE", false),
+                ("owner", "OBJECT", true),
+                ("owner.name", "STRING", true),
+                ("weight", "FLOAT", true),
+                ("parent", "OBJECT", true),
+                ("chip", "OBJECT", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_other_documents() {
+        let err = OpenApiImporter::new()
+            .import("swagger: '2.0'\nopenapi: 2.0\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("Unsupported OpenAPI version"));
+
+        let err = OpenApiImporter::new()
+            .import("openapi: 3.0.0\ninfo: {title: x, version: '1'}\npaths: {}\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("no components.schemas"));
+    }
+}
@@ -20,15 +20,26 @@
 //! for runtime parsing of .proto file content.
 
 use crate::import::odcs_shared::column_to_column_data;
-use crate::import::{ImportError, ImportResult, TableData};
-use crate::models::{Column, Table, Tag};
+use crate::import::{ImportError, ImportResult, TableData, check_size, unmapped_type_warnings};
+use crate::models::type_mapping::source_type_property;
+use crate::models::{
+    Column, IdGenerator, LogicalTypeOptions, SOURCE_TYPE_PROPERTY, Table, Tag, TypeMappingRegistry,
+};
 use crate::validation::input::{validate_column_name, validate_data_type, validate_table_name};
+use crate::validation::limits::ResourceLimits;
 use anyhow::Result;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{info, warn};
 
 /// Parser for Protobuf format.
-pub struct ProtobufImporter;
+pub struct ProtobufImporter {
+    /// Type mappings (the built-in mappings when `None`)
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+    limits: ResourceLimits,
+    /// Generator for the IDs of imported tables
+    ids: IdGenerator,
+}
 
 impl Default for ProtobufImporter {
     fn default() -> Self {
@@ -47,7 +58,32 @@ impl ProtobufImporter {
     /// let importer = ProtobufImporter::new();
     /// ```
     pub fn new() -> Self {
-        Self
+        Self {
+            type_mappings: None,
+            limits: ResourceLimits::default(),
+            ids: IdGenerator::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for Protobuf files
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Generate table IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Map Protobuf scalar types with `type_mappings` instead of the built-in mappings.
+    pub fn with_type_mappings(
+        mut self,
+        type_mappings: impl Into<Arc<TypeMappingRegistry>>,
+    ) -> Self {
+        self.type_mappings = Some(type_mappings.into());
+        self
     }
 
     /// Import Protobuf content and create Table(s) (SDK interface).
@@ -76,6 +112,7 @@ impl ProtobufImporter {
     /// let result = importer.import(proto).unwrap();
     /// ```
     pub fn import(&self, proto_content: &str) -> Result<ImportResult, ImportError> {
+        check_size(&self.limits, proto_content)?;
         match self.parse(proto_content) {
             Ok((tables, errors)) => {
                 let mut sdk_tables = Vec::new();
@@ -88,10 +125,11 @@ impl ProtobufImporter {
                         ..Default::default()
                     });
                 }
-                let sdk_errors: Vec<ImportError> = errors
+                let mut sdk_errors: Vec<ImportError> = errors
                     .iter()
                     .map(|e| ImportError::ParseError(e.message.clone()))
                     .collect();
+                sdk_errors.extend(unmapped_type_warnings("protobuf", &sdk_tables));
                 Ok(ImportResult {
                     tables: sdk_tables,
                     tables_requiring_name: Vec::new(),
@@ -310,55 +348,20 @@ impl ProtobufImporter {
                     {
                         // Deeply nested message - create columns for its fields
                         for deep_nested_field in &deep_nested_msg.fields {
-                            let data_type = if deep_nested_field.repeated {
-                                format!(
-                                    "ARRAY<{}>",
-                                    self.map_proto_type_to_sql(&deep_nested_field.field_type)
-                                )
-                            } else {
-                                self.map_proto_type_to_sql(&deep_nested_field.field_type)
-                            };
-
-                            columns.push(Column {
-                                name: format!("{}.{}", nested_field_name, deep_nested_field.name),
-                                data_type,
-                                nullable: nested_field.nullable || deep_nested_field.nullable,
-                                ..Default::default()
-                            });
+                            columns.push(self.field_to_column(
+                                format!("{}.{}", nested_field_name, deep_nested_field.name),
+                                deep_nested_field,
+                                nested_field.nullable,
+                            ));
                         }
                     } else {
                         // Simple nested field
-                        let data_type = if nested_field.repeated {
-                            format!(
-                                "ARRAY<{}>",
-                                self.map_proto_type_to_sql(&nested_field.field_type)
-                            )
-                        } else {
-                            self.map_proto_type_to_sql(&nested_field.field_type)
-                        };
-
-                        columns.push(Column {
-                            name: nested_field_name,
-                            data_type,
-                            nullable: nested_field.nullable,
-                            ..Default::default()
-                        });
+                        columns.push(self.field_to_column(nested_field_name, nested_field, false));
                     }
                 }
             } else {
                 // Simple field
-                let data_type = if field.repeated {
-                    format!("ARRAY<{}>", self.map_proto_type_to_sql(&field.field_type))
-                } else {
-                    self.map_proto_type_to_sql(&field.field_type)
-                };
-
-                columns.push(Column {
-                    name: field.name.clone(),
-                    data_type,
-                    nullable: field.nullable,
-                    ..Default::default()
-                });
+                columns.push(self.field_to_column(field.name.clone(), field, false));
             }
         }
 
@@ -375,7 +378,12 @@ impl ProtobufImporter {
         );
 
         let table = Table {
-            id: crate::models::table::Table::generate_id(&message.name, None, None, None),
+            id: crate::models::table::Table::generate_id_with_generator(
+                &self.ids,
+                &message.name,
+                None,
+                None,
+            ),
             name: message.name.clone(),
             columns,
             database_type: None,
@@ -409,49 +417,99 @@ impl ProtobufImporter {
         Ok(table)
     }
 
-    /// Map Protobuf scalar type to SQL/ODCL data type (including well-known wrapper types).
-    fn map_proto_type_to_sql(&self, proto_type: &str) -> String {
-        match proto_type {
-            // Basic scalar types
-            "int32" | "int" => "INTEGER".to_string(),
-            "int64" | "long" => "BIGINT".to_string(),
-            "uint32" => "INTEGER".to_string(), // Unsigned, but SQL doesn't distinguish
-            "uint64" => "BIGINT".to_string(),
-            "sint32" => "INTEGER".to_string(), // Signed, zigzag encoding
-            "sint64" => "BIGINT".to_string(),
-            "fixed32" => "INTEGER".to_string(),  // Fixed 32-bit
-            "fixed64" => "BIGINT".to_string(),   // Fixed 64-bit
-            "sfixed32" => "INTEGER".to_string(), // Signed fixed 32-bit
-            "sfixed64" => "BIGINT".to_string(),  // Signed fixed 64-bit
-            "float" => "FLOAT".to_string(),
-            "double" => "DOUBLE".to_string(),
-            "bool" | "boolean" => "BOOLEAN".to_string(),
-            "bytes" => "BYTES".to_string(),
-            "string" => "STRING".to_string(),
-            // Google protobuf wrapper types (nullable scalars)
-            "google.protobuf.StringValue" => "STRING".to_string(),
-            "google.protobuf.BytesValue" => "BYTES".to_string(),
-            "google.protobuf.Int32Value" => "INTEGER".to_string(),
-            "google.protobuf.Int64Value" => "BIGINT".to_string(),
-            "google.protobuf.UInt32Value" => "INTEGER".to_string(),
-            "google.protobuf.UInt64Value" => "BIGINT".to_string(),
-            "google.protobuf.FloatValue" => "FLOAT".to_string(),
-            "google.protobuf.DoubleValue" => "DOUBLE".to_string(),
-            "google.protobuf.BoolValue" => "BOOLEAN".to_string(),
-            // Timestamp and duration
-            "google.protobuf.Timestamp" => "TIMESTAMP".to_string(),
-            "google.protobuf.Duration" => "STRING".to_string(),
-            // Other well-known types (map to JSON-compatible STRING)
-            "google.protobuf.Any" => "STRING".to_string(),
-            "google.protobuf.Struct" => "STRING".to_string(),
-            "google.protobuf.Value" => "STRING".to_string(),
-            "google.protobuf.ListValue" => "STRING".to_string(),
-            "google.protobuf.FieldMask" => "STRING".to_string(),
-            "google.protobuf.Empty" => "STRING".to_string(),
-            // Default fallback
-            _ => "STRING".to_string(),
+    /// Build a column for a Protobuf field.
+    ///
+    /// Well-known types (`google.protobuf.*`, `google.type.*`) keep their full type
+    /// name in `logicalTypeOptions.format` so they can be emitted again on export.
+    /// Wrapper types are always nullable.
+    fn field_to_column(
+        &self,
+        name: String,
+        field: &ProtobufField,
+        parent_nullable: bool,
+    ) -> Column {
+        let well_known = map_well_known_type(&field.field_type);
+        let base_type = well_known
+            .map(|(data_type, _)| data_type.to_string())
+            .unwrap_or_else(|| self.map_proto_type_to_sql(&field.field_type));
+        let data_type = if field.repeated {
+            format!("ARRAY<{}>", base_type)
+        } else {
+            base_type
+        };
+        let is_wrapper = well_known.is_some_and(|(_, wrapper)| wrapper);
+
+        let mut column = Column {
+            name,
+            data_type,
+            nullable: parent_nullable || field.nullable || is_wrapper,
+            logical_type_options: well_known.map(|_| LogicalTypeOptions {
+                format: Some(field.field_type.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // Keep enum and message references without a mapping so they can be exported again
+        if well_known.is_none()
+            && !self
+                .type_mappings()
+                .has_import("protobuf", &field.field_type)
+        {
+            column.physical_type = Some(field.field_type.clone());
+            column.custom_properties.insert(
+                SOURCE_TYPE_PROPERTY.to_string(),
+                source_type_property("protobuf", &field.field_type),
+            );
         }
+        column
     }
+
+    /// Map Protobuf scalar type to SQL/ODCL data type.
+    ///
+    /// Well-known types are handled by [`map_well_known_type`].
+    fn map_proto_type_to_sql(&self, proto_type: &str) -> String {
+        self.type_mappings()
+            .import_type("protobuf", proto_type)
+            .unwrap_or_else(|| "STRING".to_string())
+    }
+
+    fn type_mappings(&self) -> &TypeMappingRegistry {
+        self.type_mappings
+            .as_deref()
+            .unwrap_or(TypeMappingRegistry::builtin())
+    }
+}
+
+/// Map a well-known Protobuf type to a SQL/ODCL data type.
+///
+/// Returns the data type and whether the type is a nullable scalar wrapper, or
+/// `None` for anything that is not a well-known type.
+fn map_well_known_type(proto_type: &str) -> Option<(&'static str, bool)> {
+    let mapped = match proto_type {
+        // Scalar wrappers (nullable scalars)
+        "google.protobuf.StringValue" => ("STRING", true),
+        "google.protobuf.BytesValue" => ("BYTES", true),
+        "google.protobuf.Int32Value" | "google.protobuf.UInt32Value" => ("INTEGER", true),
+        "google.protobuf.Int64Value" | "google.protobuf.UInt64Value" => ("BIGINT", true),
+        "google.protobuf.FloatValue" => ("FLOAT", true),
+        "google.protobuf.DoubleValue" => ("DOUBLE", true),
+        "google.protobuf.BoolValue" => ("BOOLEAN", true),
+        // Time types
+        "google.protobuf.Timestamp" => ("TIMESTAMP", false),
+        "google.protobuf.Duration" => ("INTERVAL", false),
+        // Dynamic JSON-like values
+        "google.protobuf.Struct" | "google.protobuf.Value" => ("JSON", false),
+        "google.protobuf.ListValue" => ("ARRAY<JSON>", false),
+        "google.protobuf.Any" => ("JSON", false),
+        "google.protobuf.FieldMask" => ("STRING", false),
+        // Common google.type messages
+        "google.type.Money" => ("DECIMAL(38,9)", false),
+        "google.type.Date" => ("DATE", false),
+        "google.type.TimeOfDay" => ("TIME", false),
+        "google.type.DateTime" => ("TIMESTAMP", false),
+        _ => return None,
+    };
+    Some(mapped)
 }
 
 /// Protobuf message structure.
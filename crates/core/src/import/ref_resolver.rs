@@ -0,0 +1,785 @@
+//! External `$ref` resolution
+//!
+//! Schemas split across multiple files reference each other with `$ref`s such as
+//! `common.json#/definitions/Address`. A [`RefResolver`] loads the referenced
+//! document so importers can inline it and import everything as one model:
+//!
+//! - [`LocalFileResolver`] - files under a root directory
+//! - [`StorageRefResolver`] - files loaded from a [`StorageBackend`]
+//! - [`HttpRefResolver`] - HTTP(S) URLs on hosts in an allowlist (requires `api-backend`)
+//! - [`RefResolverChain`] - tries several resolvers in order
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use data_modelling_core::import::json_schema::JSONSchemaImporter;
+//! use data_modelling_core::import::ref_resolver::LocalFileResolver;
+//!
+//! let importer = JSONSchemaImporter::new().with_resolver(LocalFileResolver::new("schemas"));
+//! let schema = std::fs::read_to_string("schemas/order.json").unwrap();
+//! let result = importer.import(&schema).unwrap();
+//! ```
+
+use crate::storage::{StorageBackend, StorageError};
+#[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+use crate::validation::limits::ResourceLimits;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Error resolving an external reference
+#[derive(Debug, thiserror::Error)]
+pub enum RefResolverError {
+    #[error("Reference not found: {0}")]
+    NotFound(String),
+    #[error("Reference not allowed: {0}")]
+    NotAllowed(String),
+    #[error("IO error: {0}")]
+    IoError(String),
+    #[error("Network error: {0}")]
+    NetworkError(String),
+    #[error("Parse error: {0}")]
+    ParseError(String),
+}
+
+/// A document loaded for a reference
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedDocument {
+    /// Canonical location of the document (references made from it are relative to this)
+    pub location: String,
+    /// Document content
+    pub content: String,
+}
+
+impl ResolvedDocument {
+    /// Parse the document as JSON (or YAML).
+    pub fn parse(&self) -> Result<Value, RefResolverError> {
+        serde_json::from_str(&self.content)
+            .or_else(|_| serde_yaml::from_str(&self.content))
+            .map_err(|e| RefResolverError::ParseError(format!("{}: {}", self.location, e)))
+    }
+}
+
+/// Loads documents referenced by `$ref`s
+pub trait RefResolver: Send + Sync {
+    /// Load the document at `reference` (the part of a `$ref` before `#`).
+    ///
+    /// `base` is the location of the referencing document, or `None` for the
+    /// document being imported.
+    fn resolve(
+        &self,
+        reference: &str,
+        base: Option<&str>,
+    ) -> Result<ResolvedDocument, RefResolverError>;
+}
+
+impl<T: RefResolver + ?Sized> RefResolver for Arc<T> {
+    fn resolve(
+        &self,
+        reference: &str,
+        base: Option<&str>,
+    ) -> Result<ResolvedDocument, RefResolverError> {
+        (**self).resolve(reference, base)
+    }
+}
+
+/// Check if a reference is a URL rather than a path.
+pub fn is_remote_reference(reference: &str) -> bool {
+    reference.contains("://")
+}
+
+/// Resolves references to files under a root directory.
+///
+/// References may not resolve outside the root directory.
+#[derive(Debug, Clone)]
+pub struct LocalFileResolver {
+    root: PathBuf,
+}
+
+impl LocalFileResolver {
+    /// Create a resolver for files under `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Get the root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl RefResolver for LocalFileResolver {
+    fn resolve(
+        &self,
+        reference: &str,
+        base: Option<&str>,
+    ) -> Result<ResolvedDocument, RefResolverError> {
+        if is_remote_reference(reference) {
+            return Err(RefResolverError::NotAllowed(reference.to_string()));
+        }
+
+        let dir = match base {
+            Some(base) => Path::new(base)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| self.root.clone()),
+            None => self.root.clone(),
+        };
+        let path = dir
+            .join(reference)
+            .canonicalize()
+            .map_err(|e| RefResolverError::NotFound(format!("{}: {}", reference, e)))?;
+        let root = self.root.canonicalize().map_err(|e| {
+            RefResolverError::IoError(format!("Failed to locate {:?}: {}", self.root, e))
+        })?;
+        if !path.starts_with(&root) {
+            return Err(RefResolverError::NotAllowed(format!(
+                "{} resolves outside {:?}",
+                reference, self.root
+            )));
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| RefResolverError::IoError(format!("Failed to read {:?}: {}", path, e)))?;
+        Ok(ResolvedDocument {
+            location: path.display().to_string(),
+            content,
+        })
+    }
+}
+
+/// Resolves references against files loaded from a storage backend.
+///
+/// Importers are synchronous, so files are loaded up front with [`load_dir`](Self::load_dir)
+/// (or added with [`insert`](Self::insert)) and then resolved from memory.
+#[derive(Debug, Clone, Default)]
+pub struct StorageRefResolver {
+    files: HashMap<String, String>,
+}
+
+impl StorageRefResolver {
+    /// Create an empty resolver.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file.
+    pub fn insert(&mut self, path: &str, content: impl Into<String>) {
+        self.files.insert(normalize_path(path), content.into());
+    }
+
+    /// Load all schema files (`.json`, `.yaml`, `.yml`, `.avsc`) in a storage directory.
+    ///
+    /// Returns the number of files loaded.
+    pub async fn load_dir(
+        &mut self,
+        storage: &dyn StorageBackend,
+        dir: &str,
+    ) -> Result<usize, StorageError> {
+        let mut loaded = 0;
+        for name in storage.list_files(dir).await? {
+            let is_schema = ["json", "yaml", "yml", "avsc"].iter().any(|ext| {
+                Path::new(&name)
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(ext))
+            });
+            if !is_schema {
+                continue;
+            }
+            let path = join_path(Some(&format!("{}/", dir.trim_end_matches('/'))), &name);
+            let content = storage.read_file(&path).await?;
+            self.insert(&path, String::from_utf8_lossy(&content).into_owned());
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+}
+
+impl RefResolver for StorageRefResolver {
+    fn resolve(
+        &self,
+        reference: &str,
+        base: Option<&str>,
+    ) -> Result<ResolvedDocument, RefResolverError> {
+        if is_remote_reference(reference) {
+            return Err(RefResolverError::NotAllowed(reference.to_string()));
+        }
+
+        let location = join_path(base, reference);
+        self.files
+            .get(&location)
+            .map(|content| ResolvedDocument {
+                location: location.clone(),
+                content: content.clone(),
+            })
+            .ok_or(RefResolverError::NotFound(location))
+    }
+}
+
+/// Resolves HTTP(S) references on an allowlist of hosts.
+///
+/// A host in the allowlist also allows its subdomains; IP addresses must match
+/// exactly. Hosts are taken from the URL as reqwest parses it, so the check
+/// sees the host that is connected to. Only `http` and `https` URLs without
+/// credentials are accepted, redirects are only followed to allowed hosts, and
+/// documents larger than the resource limits are rejected while they are
+/// downloaded.
+///
+/// Requests are made with a blocking client, which panics when used on a
+/// thread that runs an async runtime; async callers should resolve from
+/// `tokio::task::spawn_blocking`.
+#[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone)]
+pub struct HttpRefResolver {
+    allowed_hosts: Vec<String>,
+    timeout: std::time::Duration,
+    limits: ResourceLimits,
+}
+
+#[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+impl HttpRefResolver {
+    /// Create a resolver for URLs on the given hosts.
+    pub fn new<I, S>(allowed_hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            allowed_hosts: allowed_hosts
+                .into_iter()
+                .map(|h| h.into().to_lowercase())
+                .collect(),
+            timeout: std::time::Duration::from_secs(10),
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the request timeout (default 10 seconds).
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the [`ResourceLimits`] for referenced documents
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Check if a URL is on an allowed host.
+    pub fn is_allowed(&self, url: &str) -> bool {
+        reqwest::Url::parse(url).is_ok_and(|url| host_allowed(&self.allowed_hosts, &url))
+    }
+}
+
+/// Check if a URL is on one of the allowed hosts or their subdomains.
+///
+/// Only `http(s)` URLs without credentials qualify. Domains match an allowed
+/// host or one of its subdomains, IP addresses only match exactly.
+#[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+fn host_allowed(allowed_hosts: &[String], url: &reqwest::Url) -> bool {
+    if !matches!(url.scheme(), "http" | "https")
+        || !url.username().is_empty()
+        || url.password().is_some()
+    {
+        return false;
+    }
+    let Some(host) = url.host_str().map(str::to_lowercase) else {
+        return false;
+    };
+    let is_ip = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<std::net::IpAddr>()
+        .is_ok();
+    allowed_hosts
+        .iter()
+        .any(|allowed| host == *allowed || (!is_ip && host.ends_with(&format!(".{}", allowed))))
+}
+
+#[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+impl RefResolver for HttpRefResolver {
+    fn resolve(
+        &self,
+        reference: &str,
+        base: Option<&str>,
+    ) -> Result<ResolvedDocument, RefResolverError> {
+        let url = match base {
+            Some(base) if !is_remote_reference(reference) && is_remote_reference(base) => {
+                join_path(Some(base), reference)
+            }
+            _ => reference.to_string(),
+        };
+        if !self.is_allowed(&url) {
+            return Err(RefResolverError::NotAllowed(url));
+        }
+
+        // Every redirect is checked, so an allowed host cannot forward to any other
+        let allowed_hosts = self.allowed_hosts.clone();
+        let redirect = reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if host_allowed(&allowed_hosts, attempt.url()) {
+                attempt.follow()
+            } else {
+                let message = format!("redirect to {} is not allowed", attempt.url());
+                attempt.error(message)
+            }
+        });
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .redirect(redirect)
+            .build()
+            .map_err(|e| RefResolverError::NetworkError(e.to_string()))?;
+        let response = client.get(&url).send().map_err(|e| {
+            if e.is_redirect() {
+                RefResolverError::NotAllowed(format!("{}: {}", url, e))
+            } else {
+                RefResolverError::NetworkError(format!("{}: {}", url, e))
+            }
+        })?;
+        if !response.status().is_success() {
+            return Err(RefResolverError::NotFound(format!(
+                "{} (HTTP {})",
+                url,
+                response.status()
+            )));
+        }
+        let content = self
+            .limits
+            .read_limited("reference", response)
+            .and_then(|content| {
+                self.limits
+                    .check_content("reference", &content)
+                    .map(|_| content)
+            })
+            .map_err(|e| RefResolverError::NotAllowed(format!("{}: {}", url, e)))?;
+
+        Ok(ResolvedDocument {
+            location: url,
+            content,
+        })
+    }
+}
+
+/// Tries several resolvers in order, returning the first document found.
+#[derive(Clone, Default)]
+pub struct RefResolverChain {
+    resolvers: Vec<Arc<dyn RefResolver>>,
+}
+
+impl RefResolverChain {
+    /// Create an empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a resolver to the end of the chain.
+    pub fn with(mut self, resolver: impl RefResolver + 'static) -> Self {
+        self.resolvers.push(Arc::new(resolver));
+        self
+    }
+}
+
+impl std::fmt::Debug for RefResolverChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefResolverChain")
+            .field("resolvers", &self.resolvers.len())
+            .finish()
+    }
+}
+
+impl RefResolver for RefResolverChain {
+    fn resolve(
+        &self,
+        reference: &str,
+        base: Option<&str>,
+    ) -> Result<ResolvedDocument, RefResolverError> {
+        let mut error = None;
+        for resolver in &self.resolvers {
+            match resolver.resolve(reference, base) {
+                Ok(document) => return Ok(document),
+                // Resolvers that don't handle this kind of reference shouldn't hide real errors
+                Err(RefResolverError::NotAllowed(_)) if error.is_some() => {}
+                Err(e) => error = Some(e),
+            }
+        }
+        Err(error.unwrap_or_else(|| RefResolverError::NotFound(reference.to_string())))
+    }
+}
+
+/// Maximum number of nested references followed by [`inline_external_refs`].
+const MAX_INLINE_DEPTH: usize = 64;
+
+/// Inline all external `$ref`s (those naming another document) in `value`.
+///
+/// References within the imported document (`#/...`) are kept; references
+/// within referenced documents are inlined as well since they can't be
+/// resolved once the document is merged. Keywords next to a `$ref` override
+/// the referenced schema. Recursive references are kept as-is.
+pub fn inline_external_refs(
+    value: &mut Value,
+    resolver: &dyn RefResolver,
+) -> Result<(), RefResolverError> {
+    let mut inliner = RefInliner {
+        resolver,
+        documents: HashMap::new(),
+        visiting: Vec::new(),
+    };
+    inliner.inline(value, None)
+}
+
+/// State for [`inline_external_refs`].
+struct RefInliner<'a> {
+    resolver: &'a dyn RefResolver,
+    /// Loaded documents by location
+    documents: HashMap<String, Arc<Value>>,
+    /// References currently being inlined
+    visiting: Vec<String>,
+}
+
+impl RefInliner<'_> {
+    /// Inline references in `value`, which belongs to the document at `location`
+    /// (`None` for the imported document).
+    fn inline(
+        &mut self,
+        value: &mut Value,
+        location: Option<&str>,
+    ) -> Result<(), RefResolverError> {
+        match value {
+            Value::Object(obj) => {
+                if let Some(reference) = obj.get("$ref").and_then(|v| v.as_str()).map(String::from)
+                    && let Some(mut resolved) = self.resolve(&reference, location)?
+                {
+                    if let Value::Object(resolved_obj) = &mut resolved {
+                        for (key, sibling) in obj.iter() {
+                            if key != "$ref" {
+                                resolved_obj.insert(key.clone(), sibling.clone());
+                            }
+                        }
+                    }
+                    *value = resolved;
+                    return Ok(());
+                }
+                for child in obj.values_mut() {
+                    self.inline(child, location)?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.inline(item, location)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Resolve a reference, returning `None` if it should be kept.
+    fn resolve(
+        &mut self,
+        reference: &str,
+        location: Option<&str>,
+    ) -> Result<Option<Value>, RefResolverError> {
+        let (document_ref, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        if document_ref.is_empty() && location.is_none() {
+            return Ok(None);
+        }
+
+        let (target_location, root) = if document_ref.is_empty() {
+            let location = location.unwrap_or_default().to_string();
+            let root = self
+                .documents
+                .get(&location)
+                .cloned()
+                .ok_or_else(|| RefResolverError::NotFound(location.clone()))?;
+            (location, root)
+        } else {
+            let document = self.resolver.resolve(document_ref, location)?;
+            let root = match self.documents.get(&document.location) {
+                Some(root) => root.clone(),
+                None => {
+                    let root = Arc::new(document.parse()?);
+                    self.documents
+                        .insert(document.location.clone(), root.clone());
+                    root
+                }
+            };
+            (document.location, root)
+        };
+
+        let key = format!("{}#{}", target_location, pointer);
+        if self.visiting.contains(&key) || self.visiting.len() >= MAX_INLINE_DEPTH {
+            return Ok(None);
+        }
+
+        let target = if pointer.is_empty() || pointer == "/" {
+            Some(root.as_ref())
+        } else {
+            root.pointer(pointer)
+        };
+        let mut target = target
+            .cloned()
+            .ok_or_else(|| RefResolverError::NotFound(reference.to_string()))?;
+
+        self.visiting.push(key);
+        let result = self.inline(&mut target, Some(&target_location));
+        self.visiting.pop();
+        result.map(|_| Some(target))
+    }
+}
+
+/// Normalize a `/`-separated path, resolving `.` and `..` segments.
+fn normalize_path(path: &str) -> String {
+    let (prefix, rest) = match path.find("://") {
+        Some(idx) => {
+            let after_scheme = idx + 3;
+            let host_end = path[after_scheme..]
+                .find('/')
+                .map(|i| after_scheme + i)
+                .unwrap_or(path.len());
+            (&path[..host_end], &path[host_end..])
+        }
+        None => ("", path),
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in rest.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            s => segments.push(s),
+        }
+    }
+    let joined = segments.join("/");
+    if prefix.is_empty() {
+        if rest.starts_with('/') {
+            format!("/{}", joined)
+        } else {
+            joined
+        }
+    } else {
+        format!("{}/{}", prefix, joined)
+    }
+}
+
+/// Resolve `reference` relative to the document at `base`.
+fn join_path(base: Option<&str>, reference: &str) -> String {
+    match base {
+        Some(base) if !reference.starts_with('/') && !is_remote_reference(reference) => {
+            let dir = base.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+            if dir.is_empty() {
+                normalize_path(reference)
+            } else {
+                normalize_path(&format!("{}/{}", dir, reference))
+            }
+        }
+        _ => normalize_path(reference),
+    }
+}
+
+/// Maximum number of redirects followed for one reference
+#[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+const MAX_REDIRECTS: usize = 10;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_join_path() {
+        assert_eq!(join_path(None, "common.json"), "common.json");
+        assert_eq!(
+            join_path(Some("schemas/order.json"), "common.json"),
+            "schemas/common.json"
+        );
+        assert_eq!(
+            join_path(Some("schemas/v1/order.json"), "../shared/address.json"),
+            "schemas/shared/address.json"
+        );
+        assert_eq!(
+            join_path(Some("https://example.com/a/b.json"), "./c.json"),
+            "https://example.com/a/c.json"
+        );
+    }
+
+    #[test]
+    fn test_local_file_resolver() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("shared")).unwrap();
+        std::fs::write(dir.path().join("shared/address.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("shared/city.json"), "{}").unwrap();
+
+        let resolver = LocalFileResolver::new(dir.path());
+        let address = resolver.resolve("shared/address.json", None).unwrap();
+        assert!(address.location.ends_with("address.json"));
+
+        // Relative to the referencing document
+        let city = resolver
+            .resolve("./city.json", Some(&address.location))
+            .unwrap();
+        assert!(city.location.ends_with("city.json"));
+
+        assert!(matches!(
+            resolver.resolve("missing.json", None),
+            Err(RefResolverError::NotFound(_))
+        ));
+        assert!(matches!(
+            resolver.resolve("https://example.com/a.json", None),
+            Err(RefResolverError::NotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_local_file_resolver_rejects_outside_root() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("root")).unwrap();
+        std::fs::write(dir.path().join("secret.json"), "{}").unwrap();
+
+        let resolver = LocalFileResolver::new(dir.path().join("root"));
+        assert!(matches!(
+            resolver.resolve("../secret.json", None),
+            Err(RefResolverError::NotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_storage_ref_resolver() {
+        let mut resolver = StorageRefResolver::new();
+        resolver.insert("schemas/common.json", r#"{"a": 1}"#);
+
+        let document = resolver
+            .resolve("common.json", Some("schemas/order.json"))
+            .unwrap();
+        assert_eq!(document.location, "schemas/common.json");
+        assert_eq!(document.parse().unwrap(), json!({"a": 1}));
+        assert!(resolver.resolve("other.json", None).is_err());
+    }
+
+    #[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_http_ref_resolver_allowlist() {
+        let resolver = HttpRefResolver::new(["schemas.example.com", "example.org"]);
+        assert!(resolver.is_allowed("https://schemas.example.com/a.json"));
+        assert!(resolver.is_allowed("https://api.example.org:8443/a.json"));
+        assert!(!resolver.is_allowed("https://example.com/a.json"));
+        assert!(!resolver.is_allowed("https://user:pw@example.org/a.json"));
+        assert!(!resolver.is_allowed("ftp://example.org/a.json"));
+        assert!(!resolver.is_allowed("https://user@example.org/a.json"));
+        assert!(!resolver.is_allowed("https://example.org@169.254.169.254/a.json"));
+        // reqwest reads a backslash as a path separator, so the host is the IP
+        assert!(!resolver.is_allowed("http://169.254.169.254\\.example.org/latest/meta-data/"));
+        assert!(!resolver.is_allowed("http://169.254.169.254/latest/meta-data/"));
+        assert!(!resolver.is_allowed("http://[::1]/a.json"));
+
+        let resolver = HttpRefResolver::new(["127.0.0.1", "0.1"]);
+        assert!(resolver.is_allowed("http://127.0.0.1:8080/a.json"));
+        assert!(!resolver.is_allowed("http://10.0.1/a.json"));
+        assert!(matches!(
+            resolver.resolve("https://evil.test/a.json", None),
+            Err(RefResolverError::NotAllowed(_))
+        ));
+    }
+
+    /// Serve one canned HTTP response per connection on a loopback port
+    #[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+    fn serve(responses: Vec<String>) -> u16 {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let _ = stream.read(&mut [0u8; 4096]);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        port
+    }
+
+    #[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_http_ref_resolver_checks_redirects() {
+        let port = serve(vec![
+            "HTTP/1.1 302 Found\r\nLocation: http://localhost:1/internal\r\nContent-Length: 0\r\n\r\n"
+                .to_string(),
+        ]);
+        let resolver = HttpRefResolver::new(["127.0.0.1"]);
+        assert!(matches!(
+            resolver.resolve(&format!("http://127.0.0.1:{}/a.json", port), None),
+            Err(RefResolverError::NotAllowed(_))
+        ));
+    }
+
+    #[cfg(all(feature = "api-backend", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_http_ref_resolver_limits_size() {
+        let body = r#"{"type": "object", "description": "an oversized document"}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let port = serve(vec![response.clone(), response]);
+        let url = format!("http://127.0.0.1:{}/a.json", port);
+
+        let resolver = HttpRefResolver::new(["127.0.0.1"]);
+        assert_eq!(resolver.resolve(&url, None).unwrap().content, body);
+        let resolver = resolver.with_limits(ResourceLimits::default().with_max_file_size(16));
+        assert!(matches!(
+            resolver.resolve(&url, None),
+            Err(RefResolverError::NotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_chain_falls_through() {
+        let mut first = StorageRefResolver::new();
+        first.insert("a.json", "{}");
+        let mut second = StorageRefResolver::new();
+        second.insert("b.json", "{}");
+
+        let chain = RefResolverChain::new().with(first).with(second);
+        assert!(chain.resolve("a.json", None).is_ok());
+        assert!(chain.resolve("b.json", None).is_ok());
+        assert!(matches!(
+            chain.resolve("c.json", None),
+            Err(RefResolverError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_inline_external_refs() {
+        let mut resolver = StorageRefResolver::new();
+        resolver.insert(
+            "common.yaml",
+            "definitions:\n  Address:\n    type: object\n    properties:\n      city:\n        $ref: '#/definitions/City'\n  City:\n    type: string\n",
+        );
+
+        let mut schema = json!({
+            "properties": {
+                "address": { "$ref": "common.yaml#/definitions/Address", "description": "Home" },
+                "other": { "$ref": "#/definitions/Other" }
+            }
+        });
+        inline_external_refs(&mut schema, &resolver).unwrap();
+
+        assert_eq!(
+            schema["properties"]["address"],
+            json!({
+                "type": "object",
+                "description": "Home",
+                "properties": { "city": { "type": "string" } }
+            })
+        );
+        // Local refs of the imported document are kept
+        assert_eq!(
+            schema["properties"]["other"],
+            json!({ "$ref": "#/definitions/Other" })
+        );
+    }
+}
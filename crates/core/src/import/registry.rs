@@ -0,0 +1,867 @@
+//! Pluggable importers with format auto-detection
+//!
+//! Defines the [`Importer`] trait and an [`ImporterRegistry`]. Each importer reports
+//! how confident it is that it can handle a piece of content via [`Importer::sniff`],
+//! which lets [`import_auto`] pick the right format (SQL, Avro, Protobuf, ODCS,
+//! ODCL, JSON Schema, DBML, OpenAPI, ...) without the caller specifying it.
+//!
+//! Content is checked against the registry's maximum size before any importer
+//! sees it, so oversized uploads are rejected up front. The importers are handed
+//! the registry's [`ResourceLimits`] and check the structure of JSON and YAML
+//! documents while scanning them.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use data_modelling_core::import::import_auto;
+//!
+//! let detected = import_auto("CREATE TABLE users (id INT PRIMARY KEY);").unwrap();
+//! assert_eq!(detected.format, "sql");
+//! assert_eq!(detected.result.tables.len(), 1);
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::metrics;
+use crate::models::TypeMappingRegistry;
+use crate::validation::limits::ResourceLimits;
+
+use super::{
+    AvroImporter, DbmlImporter, ImportError, ImportResult, JSONSchemaImporter, ODCLImporter,
+    ODCSImporter, OpenApiImporter, ProtobufImporter, SQLImporter, check_limits, check_size,
+};
+
+/// Minimum confidence required for auto-detection to accept a format
+pub const MIN_DETECTION_CONFIDENCE: f32 = 0.3;
+
+/// An importer that can be registered with an [`ImporterRegistry`]
+pub trait Importer: Send + Sync {
+    /// Unique format name (e.g., "sql", "json-schema")
+    fn name(&self) -> &str;
+
+    /// Confidence (0.0 - 1.0) that this importer can handle the content
+    fn sniff(&self, content: &str) -> f32;
+
+    /// Versions of the format that can be imported (empty if unversioned)
+    fn versions(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Import the content
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError>;
+
+    /// Import the content within resource limits
+    ///
+    /// The default checks the content as a JSON or YAML document before
+    /// importing it; importers that enforce the limits themselves, or whose
+    /// format is neither, override it.
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        check_limits(limits, content)?;
+        self.import(content)
+    }
+}
+
+/// Result of an auto-detected import
+#[derive(Debug)]
+#[must_use = "import results should be processed or errors checked"]
+pub struct DetectedImport {
+    /// Name of the detected format
+    pub format: String,
+    /// Detection confidence (0.0 - 1.0)
+    pub confidence: f32,
+    /// The import result
+    pub result: ImportResult,
+}
+
+/// Registry of importers, keyed by format name
+///
+/// `ImporterRegistry::default()` contains the built-in formats; use
+/// [`ImporterRegistry::new`] for an empty registry.
+#[derive(Clone)]
+pub struct ImporterRegistry {
+    importers: BTreeMap<String, Arc<dyn Importer>>,
+    limits: ResourceLimits,
+}
+
+impl ImporterRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            importers: BTreeMap::new(),
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the limits content must stay within to be imported
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Map the types of the built-in SQL, AVRO, Protobuf, JSON Schema and XSD
+    /// importers with `type_mappings`, e.g. a workspace's overrides
+    ///
+    /// Replaces any importers registered under those names.
+    pub fn with_type_mappings(
+        mut self,
+        type_mappings: impl Into<Arc<TypeMappingRegistry>>,
+    ) -> Self {
+        let type_mappings = Some(type_mappings.into());
+        self.register(SqlFormat {
+            type_mappings: type_mappings.clone(),
+        })
+        .register(AvroFormat {
+            type_mappings: type_mappings.clone(),
+        })
+        .register(ProtobufFormat {
+            type_mappings: type_mappings.clone(),
+        })
+        .register(JsonSchemaFormat {
+            type_mappings: type_mappings.clone(),
+        });
+        #[cfg(feature = "xsd")]
+        self.register(XsdFormat { type_mappings });
+        self
+    }
+
+    /// Limits content must stay within to be imported
+    pub fn limits(&self) -> &ResourceLimits {
+        &self.limits
+    }
+
+    /// Check the size of content against the registry's limits
+    ///
+    /// The importers check the rest of the limits while parsing the content.
+    pub fn check_limits(&self, content: &str) -> Result<(), ImportError> {
+        check_size(&self.limits, content)
+    }
+
+    /// Register an importer, replacing any existing importer with the same name
+    pub fn register<I: Importer + 'static>(&mut self, importer: I) -> &mut Self {
+        self.register_arc(Arc::new(importer))
+    }
+
+    /// Register a shared importer, replacing any existing importer with the same name
+    pub fn register_arc(&mut self, importer: Arc<dyn Importer>) -> &mut Self {
+        self.importers
+            .insert(normalize_name(importer.name()), importer);
+        self
+    }
+
+    /// Look up an importer by name (case-insensitive, `_` and `-` are equivalent)
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Importer>> {
+        self.importers.get(&normalize_name(name)).cloned()
+    }
+
+    /// Names of all registered formats, sorted
+    pub fn names(&self) -> Vec<String> {
+        self.importers.keys().cloned().collect()
+    }
+
+    /// Score every registered importer against the content, best match first
+    pub fn rank(&self, content: &str) -> Vec<(String, f32)> {
+        let mut scores: Vec<(String, f32)> = self
+            .importers
+            .iter()
+            .map(|(name, importer)| (name.clone(), importer.sniff(content).clamp(0.0, 1.0)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scores
+    }
+
+    /// Detect the most likely format for the content
+    pub fn detect(&self, content: &str) -> Option<(String, f32)> {
+        self.rank(content)
+            .into_iter()
+            .next()
+            .filter(|(_, score)| *score >= MIN_DETECTION_CONFIDENCE)
+    }
+
+    /// Import content using the named format
+    pub fn import(&self, format: &str, content: &str) -> Result<ImportResult, ImportError> {
+        self.check_limits(content)?;
+        self.import_unchecked(format, content)
+    }
+
+    /// Detect the format of the content and import it
+    pub fn import_auto(&self, content: &str) -> Result<DetectedImport, ImportError> {
+        self.check_limits(content)?;
+        let (format, confidence) = self.detect(content).ok_or_else(|| {
+            ImportError::ParseError(
+                "Could not auto-detect format. Please specify format explicitly.".to_string(),
+            )
+        })?;
+        let result = self.import_unchecked(&format, content)?;
+        Ok(DetectedImport {
+            format,
+            confidence,
+            result,
+        })
+    }
+
+    /// Import content that has passed the limit checks
+    fn import_unchecked(&self, format: &str, content: &str) -> Result<ImportResult, ImportError> {
+        let importer = self.get(format).ok_or_else(|| {
+            ImportError::ValidationError(format!(
+                "Unknown import format: {}. Available: {}",
+                format,
+                self.names().join(", ")
+            ))
+        })?;
+        metrics::track(
+            metrics::IMPORTS,
+            metrics::IMPORT_DURATION,
+            importer.name(),
+            || importer.import_with_limits(content, &self.limits),
+        )
+    }
+}
+
+impl Default for ImporterRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(SqlFormat::default())
+            .register(AvroFormat::default())
+            .register(ProtobufFormat::default())
+            .register(JsonSchemaFormat::default())
+            .register(OdcsFormat)
+            .register(OdclFormat)
+            .register(DbmlFormat)
+            .register(OpenApiFormat);
+        #[cfg(feature = "xsd")]
+        registry.register(XsdFormat::default());
+        registry
+    }
+}
+
+impl std::fmt::Debug for ImporterRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImporterRegistry")
+            .field("formats", &self.names())
+            .finish()
+    }
+}
+
+/// Detect the format of the content and import it using the built-in importers
+pub fn import_auto(content: &str) -> Result<DetectedImport, ImportError> {
+    ImporterRegistry::default().import_auto(content)
+}
+
+/// Normalize a format name for lookup
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase().replace('_', "-")
+}
+
+/// Parse content as a JSON object, if it is one
+fn json_object(content: &str) -> Option<serde_json::Map<String, serde_json::Value>> {
+    let trimmed = content.trim_start();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    match serde_json::from_str::<serde_json::Value>(trimmed) {
+        Ok(serde_json::Value::Object(map)) => Some(map),
+        _ => None,
+    }
+}
+
+/// Parse content as a YAML mapping, if it is one
+fn yaml_mapping(content: &str) -> Option<serde_yaml::Mapping> {
+    match serde_yaml::from_str::<serde_yaml::Value>(content) {
+        Ok(serde_yaml::Value::Mapping(map)) => Some(map),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Built-in formats
+// ============================================================================
+
+/// SQL `CREATE TABLE` statements (generic dialect)
+#[derive(Default)]
+struct SqlFormat {
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+impl Importer for SqlFormat {
+    fn name(&self) -> &str {
+        "sql"
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let upper = content.to_uppercase();
+        if upper.contains("CREATE TABLE") || upper.contains("CREATE OR REPLACE TABLE") {
+            0.9
+        } else if upper.contains("CREATE VIEW") || upper.contains("CREATE MATERIALIZED VIEW") {
+            0.7
+        } else {
+            0.0
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        let mut importer = SQLImporter::default().with_limits(*limits);
+        if let Some(type_mappings) = &self.type_mappings {
+            importer = importer.with_type_mappings(type_mappings.clone());
+        }
+        importer
+            .parse(content)
+            .map_err(|e| ImportError::ParseError(e.to_string()))
+    }
+}
+
+/// Apache Avro schema (JSON)
+#[derive(Default)]
+struct AvroFormat {
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+impl Importer for AvroFormat {
+    fn name(&self) -> &str {
+        "avro"
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let is_record = |v: &serde_json::Value| {
+            v.get("type").and_then(|t| t.as_str()) == Some("record")
+                && v.get("fields").map(|f| f.is_array()).unwrap_or(false)
+        };
+
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('[') {
+            return match serde_json::from_str::<serde_json::Value>(trimmed) {
+                Ok(serde_json::Value::Array(items)) if items.iter().any(is_record) => 0.9,
+                _ => 0.0,
+            };
+        }
+
+        match json_object(content) {
+            Some(obj) if is_record(&serde_json::Value::Object(obj.clone())) => {
+                if obj.contains_key("namespace") || obj.contains_key("name") {
+                    0.95
+                } else {
+                    0.8
+                }
+            }
+            _ => 0.0,
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        let mut importer = AvroImporter::new().with_limits(*limits);
+        if let Some(type_mappings) = &self.type_mappings {
+            importer = importer.with_type_mappings(type_mappings.clone());
+        }
+        importer.import(content)
+    }
+}
+
+/// Protocol Buffers `.proto` definitions
+#[derive(Default)]
+struct ProtobufFormat {
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+impl Importer for ProtobufFormat {
+    fn name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["proto2", "proto3"]
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let has_message = content
+            .lines()
+            .any(|l| l.trim_start().starts_with("message ") && l.contains('{'));
+        let has_syntax = content
+            .lines()
+            .any(|l| l.trim_start().starts_with("syntax") && l.contains("proto"));
+
+        match (has_syntax, has_message) {
+            (true, true) => 0.95,
+            (false, true) => 0.7,
+            (true, false) => 0.5,
+            (false, false) => 0.0,
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        let mut importer = ProtobufImporter::new().with_limits(*limits);
+        if let Some(type_mappings) = &self.type_mappings {
+            importer = importer.with_type_mappings(type_mappings.clone());
+        }
+        importer.import(content)
+    }
+}
+
+/// JSON Schema
+#[derive(Default)]
+struct JsonSchemaFormat {
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+impl Importer for JsonSchemaFormat {
+    fn name(&self) -> &str {
+        "json-schema"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["draft-07", "2019-09", "2020-12"]
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let Some(obj) = json_object(content) else {
+            return 0.0;
+        };
+        if obj.contains_key("$schema") {
+            0.95
+        } else if obj.contains_key("properties") || obj.contains_key("definitions") {
+            0.6
+        } else if obj.contains_key("$defs") {
+            0.5
+        } else {
+            0.0
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        let mut importer = JSONSchemaImporter::new().with_limits(*limits);
+        if let Some(type_mappings) = &self.type_mappings {
+            importer = importer.with_type_mappings(type_mappings.clone());
+        }
+        importer.import(content)
+    }
+}
+
+/// ODCS v3.x data contracts (YAML)
+struct OdcsFormat;
+
+impl Importer for OdcsFormat {
+    fn name(&self) -> &str {
+        "odcs"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["v3.0", "v3.1"]
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let Some(map) = yaml_mapping(content) else {
+            return 0.0;
+        };
+        let get = |key: &str| map.get(key).and_then(|v| v.as_str());
+
+        match (get("apiVersion"), get("kind")) {
+            (Some(v), Some("DataContract")) if v.starts_with("v3") => 1.0,
+            (Some(_), Some("DataContract")) => 0.8,
+            _ => 0.0,
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        ODCSImporter::new().with_limits(*limits).import(content)
+    }
+}
+
+/// Legacy ODCL / Data Contract Specification (YAML)
+struct OdclFormat;
+
+impl Importer for OdclFormat {
+    fn name(&self) -> &str {
+        "odcl"
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let Some(map) = yaml_mapping(content) else {
+            return 0.0;
+        };
+        if map.contains_key("dataContractSpecification") {
+            0.95
+        } else if ODCLImporter::new().can_handle(content) {
+            0.6
+        } else {
+            0.0
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        ODCLImporter::new().with_limits(*limits).import(content)
+    }
+}
+
+/// DBML (dbdiagram.io)
+struct DbmlFormat;
+
+impl Importer for DbmlFormat {
+    fn name(&self) -> &str {
+        "dbml"
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let mut has_table = false;
+        let mut has_other = false;
+        for line in content.lines().map(str::trim_start) {
+            let keyword = line
+                .split(|c: char| c.is_whitespace() || c == ':' || c == '{')
+                .next()
+                .unwrap_or_default()
+                .to_lowercase();
+            match keyword.as_str() {
+                "table" if line.trim_end().ends_with('{') => has_table = true,
+                "ref" | "enum" | "project" | "tablegroup" => has_other = true,
+                _ => {}
+            }
+        }
+        match (has_table, has_other) {
+            (true, true) => 0.95,
+            (true, false) => 0.85,
+            (false, true) => 0.4,
+            (false, false) => 0.0,
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        DbmlImporter::new().with_limits(*limits).import(content)
+    }
+}
+
+/// OpenAPI 3.x component schemas (YAML or JSON)
+struct OpenApiFormat;
+
+impl Importer for OpenApiFormat {
+    fn name(&self) -> &str {
+        "openapi"
+    }
+
+    fn versions(&self) -> &[&'static str] {
+        &["3.0", "3.1"]
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        let Some(map) = yaml_mapping(content) else {
+            return 0.0;
+        };
+        let is_v3 = map
+            .get("openapi")
+            .and_then(|v| v.as_str())
+            .is_some_and(|v| v.starts_with("3."));
+        let has_schemas = map
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .is_some_and(|s| s.is_mapping());
+        match (is_v3, has_schemas) {
+            (true, true) => 0.95,
+            (true, false) => 0.5,
+            _ => 0.0,
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        OpenApiImporter::new().with_limits(*limits).import(content)
+    }
+}
+
+/// XML Schema (XSD) documents
+#[cfg(feature = "xsd")]
+#[derive(Default)]
+struct XsdFormat {
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+}
+
+#[cfg(feature = "xsd")]
+impl Importer for XsdFormat {
+    fn name(&self) -> &str {
+        "xsd"
+    }
+
+    fn sniff(&self, content: &str) -> f32 {
+        if !content.trim_start().starts_with('<') {
+            return 0.0;
+        }
+        if content.contains("http://www.w3.org/2001/XMLSchema\"") {
+            0.95
+        } else if content.contains("<xs:schema") || content.contains("<xsd:schema") {
+            0.8
+        } else {
+            0.0
+        }
+    }
+
+    fn import(&self, content: &str) -> Result<ImportResult, ImportError> {
+        self.import_with_limits(content, &ResourceLimits::default())
+    }
+
+    fn import_with_limits(
+        &self,
+        content: &str,
+        limits: &ResourceLimits,
+    ) -> Result<ImportResult, ImportError> {
+        let mut importer = super::XsdImporter::new().with_limits(*limits);
+        if let Some(type_mappings) = &self.type_mappings {
+            importer = importer.with_type_mappings(type_mappings.clone());
+        }
+        importer.import(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_sql() {
+        let registry = ImporterRegistry::default();
+        let (format, _) = registry
+            .detect("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(100));")
+            .unwrap();
+        assert_eq!(format, "sql");
+    }
+
+    #[test]
+    fn test_detect_avro() {
+        let avro =
+            r#"{"type": "record", "name": "User", "fields": [{"name": "id", "type": "long"}]}"#;
+        let detected = import_auto(avro).unwrap();
+        assert_eq!(detected.format, "avro");
+        assert_eq!(detected.result.tables.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_protobuf() {
+        let proto = "syntax = \"proto3\";\n\nmessage User {\n  int64 id = 1;\n}\n";
+        let detected = import_auto(proto).unwrap();
+        assert_eq!(detected.format, "protobuf");
+        assert_eq!(detected.result.tables.len(), 1);
+    }
+
+    #[cfg(feature = "xsd")]
+    #[test]
+    fn test_detect_xsd() {
+        let xsd = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+  <xs:element name="User" type="xs:string"/>
+</xs:schema>"#;
+        let detected = import_auto(xsd).unwrap();
+        assert_eq!(detected.format, "xsd");
+        assert_eq!(detected.result.tables.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_json_schema() {
+        let schema = r#"{
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "User",
+            "type": "object",
+            "properties": {"id": {"type": "integer"}}
+        }"#;
+        let (format, confidence) = ImporterRegistry::default().detect(schema).unwrap();
+        assert_eq!(format, "json-schema");
+        assert!(confidence > 0.9);
+    }
+
+    #[test]
+    fn test_detect_dbml() {
+        let dbml = "Table users {\n  id integer [pk]\n}\n\nRef: posts.user_id > users.id\n";
+        let (format, confidence) = ImporterRegistry::default().detect(dbml).unwrap();
+        assert_eq!(format, "dbml");
+        assert!(confidence > 0.9);
+    }
+
+    #[test]
+    fn test_detect_openapi() {
+        let spec = "openapi: 3.1.0\ninfo:\n  title: Pets\n  version: '1'\ncomponents:\n  schemas:\n    Pet:\n      type: object\n      properties:\n        name:\n          type: string\n";
+        let detected = ImporterRegistry::default().import_auto(spec).unwrap();
+        assert_eq!(detected.format, "openapi");
+        assert_eq!(detected.result.tables[0].name.as_deref(), Some("Pet"));
+    }
+
+    #[test]
+    fn test_detect_odcs() {
+        let yaml = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: 550e8400-e29b-41d4-a716-446655440000
+version: 1.0.0
+status: draft
+name: users
+schema:
+  - name: users
+    properties:
+      - name: id
+        logicalType: integer
+"#;
+        let detected = import_auto(yaml).unwrap();
+        assert_eq!(detected.format, "odcs");
+        assert_eq!(detected.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_detect_unknown() {
+        let registry = ImporterRegistry::default();
+        assert!(registry.detect("just some text").is_none());
+        assert!(import_auto("just some text").is_err());
+    }
+
+    #[test]
+    fn test_limits_reject_content() {
+        let registry = ImporterRegistry::default().with_limits(
+            ResourceLimits::default()
+                .with_max_file_size(64)
+                .with_max_depth(4),
+        );
+        let nested = r#"{"type": "object", "properties": {"a": {"type": "object", "properties": {"b": {"type": "string"}}}}}"#;
+        assert!(matches!(
+            registry.import("json-schema", nested),
+            Err(ImportError::ValidationError(_))
+        ));
+        assert!(registry.import_auto(&"-- padding\n".repeat(10)).is_err());
+        assert!(registry.import("sql", "CREATE TABLE t (id INT);").is_ok());
+
+        // Importers enforce their limits when used directly
+        let limits = ResourceLimits::default().with_max_depth(4);
+        assert!(matches!(
+            JSONSchemaImporter::new().with_limits(limits).import(nested),
+            Err(ImportError::ValidationError(_))
+        ));
+        assert!(matches!(
+            ODCSImporter::new()
+                .with_limits(ResourceLimits::default().with_max_properties(1))
+                .import("apiVersion: v3.1.0\nkind: DataContract\n"),
+            Err(ImportError::ValidationError(_))
+        ));
+        // The registry hands its limits to the importers
+        let permissive =
+            ImporterRegistry::default().with_limits(ResourceLimits::default().with_max_depth(128));
+        let deep_schema = format!(
+            r#"{{"type": "object", "properties": {{"a": {}"string"{}}}}}"#,
+            "{\"type\": \"array\", \"items\": ".repeat(70),
+            "}".repeat(70)
+        );
+        assert!(
+            ResourceLimits::default()
+                .check_content("input", &deep_schema)
+                .is_err()
+        );
+        assert!(permissive.import("json-schema", &deep_schema).is_ok());
+    }
+
+    #[test]
+    fn test_type_mappings() {
+        let mut mappings = TypeMappingRegistry::default();
+        mappings.set_import("avro", "long", "NUMBER(19)");
+        let registry = ImporterRegistry::default().with_type_mappings(mappings);
+
+        let schema =
+            r#"{"type": "record", "name": "t", "fields": [{"name": "id", "type": "long"}]}"#;
+        let result = registry.import("avro", schema).unwrap();
+        assert_eq!(result.tables[0].columns[0].data_type, "NUMBER(19)");
+    }
+
+    #[test]
+    fn test_register_custom_importer() {
+        struct CsvImporter;
+
+        impl Importer for CsvImporter {
+            fn name(&self) -> &str {
+                "csv"
+            }
+
+            fn sniff(&self, content: &str) -> f32 {
+                if content.lines().next().is_some_and(|l| l.contains(',')) {
+                    0.4
+                } else {
+                    0.0
+                }
+            }
+
+            fn import(&self, _content: &str) -> Result<ImportResult, ImportError> {
+                Ok(ImportResult {
+                    tables: Vec::new(),
+                    tables_requiring_name: Vec::new(),
+                    errors: Vec::new(),
+                    ai_suggestions: None,
+                })
+            }
+        }
+
+        let mut registry = ImporterRegistry::default();
+        registry.register(CsvImporter);
+        assert_eq!(registry.detect("id,name\n1,a").unwrap().0, "csv");
+        // Built-in formats still win when they are more confident
+        assert_eq!(
+            registry.detect("CREATE TABLE t (id INT);").unwrap().0,
+            "sql"
+        );
+    }
+}
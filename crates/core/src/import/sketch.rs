@@ -3,19 +3,30 @@
 //! Parses Excalidraw sketch YAML files (.sketch.yaml) and converts them to Sketch models.
 //! Also handles the sketch index file (sketches.yaml).
 
-use super::ImportError;
+use super::{ImportError, check_limits};
 use crate::models::sketch::{Sketch, SketchIndex};
+use crate::validation::limits::ResourceLimits;
 
 #[cfg(feature = "schema-validation")]
 use crate::validation::schema::validate_sketch_internal;
 
 /// Sketch importer for parsing Excalidraw sketch YAML files
-pub struct SketchImporter;
+pub struct SketchImporter {
+    limits: ResourceLimits,
+}
 
 impl SketchImporter {
     /// Create a new Sketch importer instance
     pub fn new() -> Self {
-        Self
+        Self {
+            limits: ResourceLimits::default(),
+        }
+    }
+
+    /// Set the [`ResourceLimits`] for sketch YAML
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Import a sketch from YAML content
@@ -50,6 +61,7 @@ impl SketchImporter {
     /// assert_eq!(sketch.title, "Architecture Diagram");
     /// ```
     pub fn import(&self, yaml_content: &str) -> Result<Sketch, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         // Validate against JSON Schema if feature is enabled
         #[cfg(feature = "schema-validation")]
         {
@@ -74,6 +86,7 @@ impl SketchImporter {
     ///
     /// A `Sketch` parsed from the YAML content
     pub fn import_without_validation(&self, yaml_content: &str) -> Result<Sketch, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         Sketch::from_yaml(yaml_content)
             .map_err(|e| ImportError::ParseError(format!("Failed to parse sketch YAML: {}", e)))
     }
@@ -103,6 +116,7 @@ impl SketchImporter {
     /// assert_eq!(index.next_number, 1);
     /// ```
     pub fn import_index(&self, yaml_content: &str) -> Result<SketchIndex, ImportError> {
+        check_limits(&self.limits, yaml_content)?;
         SketchIndex::from_yaml(yaml_content).map_err(|e| {
             ImportError::ParseError(format!("Failed to parse sketch index YAML: {}", e))
         })
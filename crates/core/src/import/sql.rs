@@ -11,18 +11,26 @@
 //! - Maximum length limits
 //! - SQL reserved word detection
 
-use super::{ColumnData, ImportError, ImportResult, TableData};
+use super::{ColumnData, ImportError, ImportResult, TableData, check_size, unmapped_type_warnings};
+use crate::models::type_mapping::source_type_property;
+use crate::models::{NestedType, SOURCE_TYPE_PROPERTY, TypeMappingRegistry};
 use crate::validation::input::{validate_column_name, validate_data_type, validate_table_name};
+use crate::validation::limits::ResourceLimits;
 use anyhow::Result;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use sqlparser::ast::{ColumnDef, ColumnOption, ObjectName, Statement, TableConstraint};
+use sqlparser::ast::{
+    ColumnDef, ColumnOption, CommentObject, CreateTable, CreateTableOptions, Expr, GeneratedAs,
+    ObjectName, SqlOption, Statement, TableConstraint, WrappedCollection,
+};
 use sqlparser::dialect::{
     AnsiDialect, BigQueryDialect, DatabricksDialect as OfficialDatabricksDialect, Dialect,
-    GenericDialect, HiveDialect, MsSqlDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect,
+    GenericDialect, HiveDialect, MsSqlDialect, MySqlDialect, OracleDialect, PostgreSqlDialect,
+    SQLiteDialect, SnowflakeDialect,
 };
 use sqlparser::parser::Parser;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 // Static regex patterns compiled once for performance
 static RE_IDENTIFIER: Lazy<Regex> =
@@ -54,6 +62,99 @@ static RE_FIELD_VARIABLE: Lazy<Regex> = Lazy::new(|| {
 });
 static RE_COMPLEX_TYPE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(\w+)\s+(STRUCT<|ARRAY<|MAP<)").expect("Invalid regex"));
+// Snowflake fixed-point types: NUMBER, NUMBER(p), NUMBER(p,s) and their DECIMAL/NUMERIC aliases
+static RE_SNOWFLAKE_NUMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:NUMBER|NUMERIC|DECIMAL)\s*(?:\(\s*(\d+)\s*(?:,\s*(\d+)\s*)?\))?$")
+        .expect("Invalid regex")
+});
+
+// T-SQL batch separator: `GO` on its own line, optionally with a repeat count
+static RE_TSQL_GO: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?im)^[ \t]*GO(?:[ \t]+\d+)?[ \t]*;?[ \t]*$").expect("Invalid regex")
+});
+static RE_TSQL_EXTENDED_PROPERTY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bEXEC(?:UTE)?\s+(?:\[?\w+\]?\.){0,2}\[?sp_addextendedproperty\]?")
+        .expect("Invalid regex")
+});
+// Index options of PRIMARY KEY/UNIQUE constraints, e.g. `WITH (PAD_INDEX = OFF, ...)`
+static RE_TSQL_INDEX_OPTIONS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\)\s*WITH\s*\(\s*(?:PAD_INDEX|FILLFACTOR|STATISTICS_NORECOMPUTE|IGNORE_DUP_KEY|ALLOW_ROW_LOCKS|ALLOW_PAGE_LOCKS|OPTIMIZE_FOR_SEQUENTIAL_KEY|DATA_COMPRESSION|SORT_IN_TEMPDB|ONLINE)\b[^)]*\)",
+    )
+    .expect("Invalid regex")
+});
+// Storage clauses: `ON [PRIMARY]`, `TEXTIMAGE_ON [PRIMARY]`, `ON ps_orders([created])`
+static RE_TSQL_STORAGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\)\s*(?:ON|TEXTIMAGE_ON|FILESTREAM_ON)\s+(\[[^\]]*\]|\w+)(?:\s*\(\s*[\[\]\w]+\s*\))?",
+    )
+    .expect("Invalid regex")
+});
+static RE_TSQL_CLUSTERED_INDEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bCREATE\s+(UNIQUE\s+)?(?:NON)?CLUSTERED\s+(?:COLUMNSTORE\s+)?INDEX\b")
+        .expect("Invalid regex")
+});
+static RE_TSQL_WITH_CHECK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bWITH\s+(?:NO)?CHECK\s+ADD\b").expect("Invalid regex"));
+
+// Statements creating tables or views; other statements of a script that fail
+// to parse are skipped
+static RE_CREATE_TABLE_OR_VIEW: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bCREATE\s+(?:OR\s+(?:ALTER|REPLACE)\s+)?(?:GLOBAL\s+TEMPORARY\s+)?(?:TABLE|VIEW)\b",
+    )
+    .expect("Invalid regex")
+});
+
+// Oracle SQL*Plus statement terminator: `/` on its own line
+static RE_ORACLE_SLASH: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^[ \t]*/[ \t]*$").expect("Invalid regex"));
+static RE_ORACLE_TRIGGER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?im)^[ \t]*CREATE\s+(?:OR\s+REPLACE\s+)?(?:(?:NON)?EDITIONABLE\s+)?TRIGGER\b")
+        .expect("Invalid regex")
+});
+static RE_ORACLE_STATEMENT_START: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?im)^[ \t]*CREATE\s").expect("Invalid regex"));
+// Table a trigger fires on, e.g. `BEFORE INSERT ON hr.employees`
+static RE_ORACLE_TRIGGER_TABLE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)\bINSERT\b[^;]*?\bON\s+((?:"[^"]+"|[\w$#]+)(?:\s*\.\s*(?:"[^"]+"|[\w$#]+))?)"#,
+    )
+    .expect("Invalid regex")
+});
+// Sequence values assigned to a column: `SELECT seq.NEXTVAL INTO :NEW.id` or `:NEW.id := seq.NEXTVAL`
+static RE_ORACLE_NEXTVAL_COLUMN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)\.\s*NEXTVAL\s+INTO\s+:NEW\s*\.\s*("[^"]+"|[\w$#]+)|:NEW\s*\.\s*("[^"]+"|[\w$#]+)\s*:=\s*[\w$#".]+\.\s*NEXTVAL\b"#,
+    )
+    .expect("Invalid regex")
+});
+static RE_ORACLE_IDENTITY_ON_NULL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bBY\s+DEFAULT\s+ON\s+NULL\s+AS\s+IDENTITY\b").expect("Invalid regex")
+});
+static RE_ORACLE_USING_INDEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\s+USING\s+INDEX\b").expect("Invalid regex"));
+// Constraint states, e.g. `PRIMARY KEY (id) ENABLE` or `NOT NULL ENABLE NOVALIDATE`
+static RE_ORACLE_CONSTRAINT_STATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\s+(?:(?:NO)?RELY\s+)?(?:ENABLE|DISABLE)(?:\s+(?:NO)?VALIDATE)?(?:\s+(?:NO)?RELY)?(\s*(?:[,)]|$))",
+    )
+    .expect("Invalid regex")
+});
+static RE_ORACLE_CREATE_TABLE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)^\s*CREATE\s+(?:GLOBAL\s+TEMPORARY\s+)?TABLE\s+(?:"[^"]*"|[^\s("])+\s*\("#)
+        .expect("Invalid regex")
+});
+// Oracle numbers, character types with length semantics and raw binary values
+static RE_ORACLE_NUMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^NUMBER\s*(?:\(\s*(\d+|\*)\s*(?:,\s*(-?\d+)\s*)?\))?$").expect("Invalid regex")
+});
+static RE_ORACLE_CHARACTER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(N?VARCHAR|N?CHAR)2?\s*\(\s*(\d+)\s*,?\s*(?:BYTE|CHAR)?\s*\)$")
+        .expect("Invalid regex")
+});
+static RE_ORACLE_RAW: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^RAW\s*\(\s*(\d+)\s*\)$").expect("Invalid regex"));
 
 /// Custom Databricks SQL dialect implementation
 ///
@@ -97,31 +198,159 @@ impl Dialect for CustomDatabricksDialect {
 struct PreprocessingState {
     /// Maps placeholder table names to original IDENTIFIER() expressions
     identifier_replacements: HashMap<String, String>,
+    /// Descriptions taken out of the script (T-SQL `MS_Description` extended properties)
+    descriptions: Vec<ObjectDescription>,
+    /// Columns filled from sequences by Oracle triggers
+    identity_columns: Vec<IdentityHint>,
 }
 
 impl PreprocessingState {
     fn new() -> Self {
         Self {
             identifier_replacements: HashMap::new(),
+            descriptions: Vec::new(),
+            identity_columns: Vec::new(),
+        }
+    }
+}
+
+/// `(parameter name, value)` of a T-SQL procedure argument; `None` for
+/// positional arguments and `NULL` values
+type TsqlArgument = (Option<String>, Option<String>);
+
+/// Description of a table or column, set with T-SQL `sp_addextendedproperty`
+/// or a `COMMENT ON TABLE/COLUMN` statement
+#[derive(Debug)]
+struct ObjectDescription {
+    schema: Option<String>,
+    table: String,
+    column: Option<String>,
+    description: String,
+}
+
+impl ObjectDescription {
+    /// Parameter names of `sp_addextendedproperty` in positional order
+    const PARAMETERS: [&'static str; 8] = [
+        "name",
+        "value",
+        "level0type",
+        "level0name",
+        "level1type",
+        "level1name",
+        "level2type",
+        "level2name",
+    ];
+
+    /// Build from the (named or positional) arguments of an `sp_addextendedproperty` call
+    ///
+    /// Only `MS_Description` properties of tables, views and their columns are kept.
+    fn from_arguments(arguments: &[TsqlArgument]) -> Option<Self> {
+        let mut values: HashMap<&str, &str> = HashMap::new();
+        for (index, (name, value)) in arguments.iter().enumerate() {
+            let parameter = match name {
+                Some(name) => Self::PARAMETERS
+                    .into_iter()
+                    .find(|p| p.eq_ignore_ascii_case(name.trim_start_matches('@'))),
+                None => Self::PARAMETERS.get(index).copied(),
+            };
+            if let (Some(parameter), Some(value)) = (parameter, value) {
+                values.insert(parameter, value.as_str());
+            }
+        }
+
+        let is = |parameter: &str, expected: &[&str]| {
+            values
+                .get(parameter)
+                .is_some_and(|v| expected.iter().any(|e| v.eq_ignore_ascii_case(e)))
+        };
+        if !is("name", &["MS_Description"]) || !is("level1type", &["TABLE", "VIEW"]) {
+            return None;
         }
+        let column = if values.contains_key("level2type") {
+            if !is("level2type", &["COLUMN"]) {
+                return None;
+            }
+            Some(values.get("level2name")?.to_string())
+        } else {
+            None
+        };
+        Some(Self {
+            schema: values
+                .get("level0name")
+                .filter(|_| is("level0type", &["SCHEMA"]))
+                .map(|s| s.to_string()),
+            table: values.get("level1name")?.to_string(),
+            column,
+            description: values.get("value")?.to_string(),
+        })
+    }
+
+    /// Build from a `COMMENT ON TABLE/COLUMN` statement
+    fn from_comment(object_type: &CommentObject, name: &ObjectName, comment: &str) -> Option<Self> {
+        let parts: Vec<String> = name
+            .0
+            .iter()
+            .map(|part| SQLImporter::unquote_identifier(&part.to_string()))
+            .collect();
+        let (parts, column) = match object_type {
+            CommentObject::Table => (parts.as_slice(), None),
+            CommentObject::Column => {
+                let (column, parts) = parts.split_last()?;
+                (parts, Some(column.clone()))
+            }
+            _ => return None,
+        };
+        let (table, parts) = parts.split_last()?;
+        Some(Self {
+            schema: parts.last().cloned(),
+            table: table.clone(),
+            column,
+            description: comment.to_string(),
+        })
     }
 }
 
+/// Column of a table whose values are generated, e.g. by an Oracle trigger
+/// assigning a sequence value
+#[derive(Debug)]
+struct IdentityHint {
+    schema: Option<String>,
+    table: String,
+    column: String,
+}
+
 /// SQL Importer - parses CREATE TABLE statements
 pub struct SQLImporter {
     /// SQL dialect to use for parsing
     pub dialect: String,
+    /// Type mappings (the built-in mappings when `None`)
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+    limits: ResourceLimits,
 }
 
 impl Default for SQLImporter {
     fn default() -> Self {
-        Self {
-            dialect: "generic".to_string(),
-        }
+        Self::new("generic")
     }
 }
 
 impl SQLImporter {
+    /// Names of the supported dialects (see [`SQLImporter::new`]); the aliases
+    /// `postgresql` and `sqlserver` are accepted as well
+    pub const DIALECTS: &'static [&'static str] = &[
+        "ansi",
+        "bigquery",
+        "databricks",
+        "generic",
+        "hive",
+        "mssql",
+        "mysql",
+        "oracle",
+        "postgres",
+        "snowflake",
+        "sqlite",
+    ];
+
     /// Create a new SQL importer with the specified dialect
     ///
     /// # Arguments
@@ -131,16 +360,34 @@ impl SQLImporter {
     /// # Supported Dialects
     ///
     /// - **ansi**: ANSI SQL dialect
-    /// - **bigquery**: Google BigQuery dialect (natively supports STRUCT/ARRAY types)
+    /// - **bigquery**: Google BigQuery dialect with support for:
+    ///   - `STRUCT<>` and `ARRAY<>` nested types (scalar types inside are mapped too)
+    ///   - `PARTITION BY` and `CLUSTER BY` (kept as `partitionBy`/`clusterBy` metadata)
+    ///   - `OPTIONS(description = ...)` on tables and columns, and table `labels` as tags
     /// - **databricks**: Databricks SQL dialect with support for:
     ///   - `IDENTIFIER()` function calls in table/view names
     ///   - Variable references (`:variable_name`) in type definitions, column definitions, and metadata
     ///   - `STRUCT` and `ARRAY` complex types
     ///   - `CREATE VIEW` and `CREATE MATERIALIZED VIEW` statements
     /// - **hive**: Apache Hive dialect (natively supports STRUCT/ARRAY types)
-    /// - **mssql** / **sqlserver**: Microsoft SQL Server dialect
+    /// - **mssql** / **sqlserver**: Microsoft SQL Server (T-SQL) dialect with support for:
+    ///   - Bracketed identifiers and types (`[dbo].[orders]`, `[nvarchar](100)`)
+    ///   - Schema-qualified names (kept as `schemaName` metadata)
+    ///   - `IDENTITY` columns (kept as the `autoIncrement` custom property)
+    ///   - `GO` batch separators, index options and `ON [PRIMARY]` storage clauses
+    ///   - `MS_Description` extended properties (imported as descriptions)
     /// - **mysql**: MySQL dialect
+    /// - **oracle**: Oracle dialect with support for:
+    ///   - `NUMBER`, `VARCHAR2`, `CLOB`, `RAW` and other Oracle types
+    ///   - Identity columns, sequence defaults and sequence triggers (`autoIncrement`)
+    ///   - `COMMENT ON TABLE/COLUMN` descriptions
+    ///   - Scripts with `/` terminators, storage clauses and constraint states
     /// - **postgres** / **postgresql**: PostgreSQL dialect
+    /// - **snowflake**: Snowflake dialect with support for:
+    ///   - `VARIANT`, `OBJECT` and `ARRAY` semi-structured types
+    ///   - `NUMBER(p,s)` (imported as `DECIMAL(p,s)`)
+    ///   - `CLUSTER BY` clustering keys and `TRANSIENT` tables
+    ///   - Table and column `COMMENT` clauses (imported as descriptions)
     /// - **sqlite**: SQLite dialect
     /// - **generic**: Generic SQL dialect (default)
     ///
@@ -158,9 +405,42 @@ impl SQLImporter {
     pub fn new(dialect: &str) -> Self {
         Self {
             dialect: dialect.to_string(),
+            type_mappings: None,
+            limits: ResourceLimits::default(),
         }
     }
 
+    /// Set the [`ResourceLimits`] for SQL scripts
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Map column types of this dialect with `type_mappings`.
+    ///
+    /// Types without an import mapping for the dialect are kept as written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::sql::SQLImporter;
+    /// use data_modelling_core::models::TypeMappingRegistry;
+    ///
+    /// let mut mappings = TypeMappingRegistry::default();
+    /// mappings.set_import("postgres", "text", "STRING");
+    ///
+    /// let importer = SQLImporter::new("postgres").with_type_mappings(mappings);
+    /// let result = importer.parse("CREATE TABLE t (name TEXT);").unwrap();
+    /// assert_eq!(result.tables[0].columns[0].data_type, "STRING");
+    /// ```
+    pub fn with_type_mappings(
+        mut self,
+        type_mappings: impl Into<Arc<TypeMappingRegistry>>,
+    ) -> Self {
+        self.type_mappings = Some(type_mappings.into());
+        self
+    }
+
     /// Preprocess Databricks SQL to handle IDENTIFIER() expressions
     ///
     /// Replaces IDENTIFIER() function calls with placeholder table names
@@ -635,6 +915,7 @@ impl SQLImporter {
     /// assert_eq!(result.tables.len(), 1);
     /// ```
     pub fn parse(&self, sql: &str) -> Result<ImportResult> {
+        check_size(&self.limits, sql)?;
         // Minimal preprocessing: only handle variable replacement and unsupported clauses
         // For Databricks: let DatabricksDialect try to parse STRUCT/ARRAY first, then restore full type strings
         // For BigQuery/Hive: can parse STRUCT/ARRAY directly without extraction
@@ -679,36 +960,68 @@ impl SQLImporter {
                 (normalized, cols)
             };
 
-            (final_sql, state, complex_cols)
+            (vec![final_sql], state, complex_cols)
         } else if matches!(self.dialect.to_lowercase().as_str(), "bigquery" | "hive") {
             // BigQuery/Hive: can parse STRUCT/ARRAY directly, just normalize
             let normalized = Self::normalize_sql_preserving_quotes(sql);
             let normalized = Self::convert_backslash_escaped_quotes(&normalized);
-            (normalized, PreprocessingState::new(), Vec::new())
+            (vec![normalized], PreprocessingState::new(), Vec::new())
+        } else if matches!(self.dialect.to_lowercase().as_str(), "mssql" | "sqlserver") {
+            // T-SQL: parse each GO batch on its own, after taking out the
+            // extended properties and the clauses sqlparser doesn't support
+            let mut state = PreprocessingState::new();
+            let batches = RE_TSQL_GO
+                .split(sql)
+                .map(|batch| {
+                    let batch = Self::extract_extended_properties(batch, &mut state);
+                    Self::normalize_sql_preserving_quotes(&Self::preprocess_tsql(&batch))
+                })
+                .filter(|batch| !batch.is_empty())
+                .collect();
+            (batches, state, Vec::new())
+        } else if self.dialect.eq_ignore_ascii_case("oracle") {
+            // Oracle: take out triggers (identity hints) and parse each statement
+            // on its own, without the physical attributes sqlparser doesn't support
+            let mut state = PreprocessingState::new();
+            let script = Self::extract_oracle_triggers(sql, &mut state);
+            let script = RE_ORACLE_SLASH.replace_all(&script, ";");
+            let statements =
+                Self::split_statements(&Self::normalize_sql_preserving_quotes(&script))
+                    .iter()
+                    .map(|statement| Self::preprocess_oracle(statement))
+                    .filter(|statement| !statement.is_empty())
+                    .collect();
+            (statements, state, Vec::new())
         } else {
             // Other dialects: extract STRUCT/ARRAY if present (they may not support them)
             let normalized = Self::normalize_sql_preserving_quotes(sql);
             let normalized = Self::convert_backslash_escaped_quotes(&normalized);
             let (simplified_sql, complex_cols) = Self::extract_complex_type_columns(&normalized);
-            (simplified_sql, PreprocessingState::new(), complex_cols)
+            (
+                vec![simplified_sql],
+                PreprocessingState::new(),
+                complex_cols,
+            )
         };
 
         let dialect = self.dialect_impl();
-        let statements = match Parser::parse_sql(dialect.as_ref(), &preprocessed_sql) {
-            Ok(stmts) => stmts,
-            Err(e) => {
-                return Ok(ImportResult {
-                    tables: Vec::new(),
-                    tables_requiring_name: Vec::new(),
-                    errors: vec![ImportError::ParseError(e.to_string())],
-                    ai_suggestions: None,
-                });
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
+        for batch in &preprocessed_sql {
+            match Parser::parse_sql(dialect.as_ref(), batch) {
+                Ok(stmts) => statements.extend(stmts),
+                // Scripts generated by database tools contain statements sqlparser
+                // can't parse (e.g. `ALTER TABLE ... CHECK CONSTRAINT`, `CREATE
+                // SEQUENCE`); only statements creating tables or views are imported
+                Err(_)
+                    if preprocessed_sql.len() > 1 && !RE_CREATE_TABLE_OR_VIEW.is_match(batch) => {}
+                Err(e) => errors.push(ImportError::ParseError(e.to_string())),
             }
-        };
+        }
 
         let mut tables = Vec::new();
-        let mut errors = Vec::new();
         let mut tables_requiring_name = Vec::new();
+        let mut comments = Vec::new();
 
         for (idx, stmt) in statements.into_iter().enumerate() {
             match stmt {
@@ -721,7 +1034,8 @@ impl SQLImporter {
                         &preprocessing_state,
                         &complex_types,
                     ) {
-                        Ok((table, requires_name)) => {
+                        Ok((mut table, requires_name)) => {
+                            Self::apply_table_options(&mut table, &create);
                             if requires_name {
                                 tables_requiring_name.push(super::TableRequiringName {
                                     table_index: idx,
@@ -747,12 +1061,29 @@ impl SQLImporter {
                         Err(e) => errors.push(ImportError::ParseError(e)),
                     }
                 }
+                Statement::Comment {
+                    object_type,
+                    object_name,
+                    comment: Some(comment),
+                    ..
+                } => comments.extend(ObjectDescription::from_comment(
+                    &object_type,
+                    &object_name,
+                    &comment,
+                )),
                 _ => {
                     // Other statements (INSERT, UPDATE, DELETE, etc.) are ignored.
                 }
             }
         }
 
+        Self::apply_descriptions(
+            &mut tables,
+            preprocessing_state.descriptions.iter().chain(&comments),
+        );
+        Self::apply_identity_hints(&mut tables, &preprocessing_state.identity_columns);
+        errors.extend(unmapped_type_warnings(&self.dialect, &tables));
+
         Ok(ImportResult {
             tables,
             tables_requiring_name,
@@ -788,6 +1119,7 @@ impl SQLImporter {
     /// let result = importer.parse_liquibase(sql).unwrap();
     /// ```
     pub fn parse_liquibase(&self, sql: &str) -> Result<ImportResult> {
+        check_size(&self.limits, sql)?;
         // Liquibase "formatted SQL" is still SQL, but often includes directive comments like:
         // --liquibase formatted sql
         // --changeset user:id
@@ -808,6 +1140,345 @@ impl SQLImporter {
         self.parse(&cleaned)
     }
 
+    /// Take `CREATE TRIGGER` statements out of an Oracle script
+    ///
+    /// Triggers assigning a sequence value to a column (`SELECT seq.NEXTVAL INTO
+    /// :NEW.id FROM dual` or `:NEW.id := seq.NEXTVAL`) are kept as identity hints.
+    /// A trigger ends at the next `/` line, or else at the next `CREATE` statement.
+    fn extract_oracle_triggers(sql: &str, state: &mut PreprocessingState) -> String {
+        let mut result = String::with_capacity(sql.len());
+        let mut rest = sql;
+        while let Some(start) = RE_ORACLE_TRIGGER.find(rest) {
+            result.push_str(&rest[..start.start()]);
+            let body = &rest[start.end()..];
+            let end = RE_ORACLE_SLASH
+                .find(body)
+                .map(|m| m.end())
+                .or_else(|| RE_ORACLE_STATEMENT_START.find(body).map(|m| m.start()))
+                .unwrap_or(body.len());
+            let trigger = &body[..end];
+
+            if let Some(target) = RE_ORACLE_TRIGGER_TABLE.captures(trigger) {
+                let mut parts: Vec<String> =
+                    target[1].split('.').map(Self::unquote_identifier).collect();
+                let table = parts.pop().unwrap_or_default();
+                let schema = parts.pop();
+                for caps in RE_ORACLE_NEXTVAL_COLUMN.captures_iter(trigger) {
+                    if let Some(column) = caps.get(1).or_else(|| caps.get(2)) {
+                        state.identity_columns.push(IdentityHint {
+                            schema: schema.clone(),
+                            table: table.clone(),
+                            column: Self::unquote_identifier(column.as_str()),
+                        });
+                    }
+                }
+            }
+
+            result.push('\n');
+            rest = &body[end..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// Remove Oracle clauses that sqlparser doesn't support
+    ///
+    /// - Physical attributes after the column list of `CREATE TABLE`
+    ///   (`TABLESPACE`, `STORAGE (...)`, `SEGMENT CREATION`, partitioning, ...)
+    /// - `USING INDEX ...` and constraint states (`ENABLE`, `DISABLE NOVALIDATE`)
+    /// - `ON NULL` in `GENERATED BY DEFAULT ON NULL AS IDENTITY`
+    fn preprocess_oracle(statement: &str) -> String {
+        let mut result = RE_ORACLE_IDENTITY_ON_NULL
+            .replace_all(statement, "BY DEFAULT AS IDENTITY")
+            .to_string();
+
+        while let Some(using_index) = RE_ORACLE_USING_INDEX.find(&result) {
+            // The index clause runs to the end of the constraint
+            let end = Self::clause_end(&result, using_index.end(), true);
+            result.replace_range(using_index.start()..end, "");
+        }
+        result = RE_ORACLE_CONSTRAINT_STATE
+            .replace_all(&result, "${1}")
+            .to_string();
+
+        if let Some(columns) = RE_ORACLE_CREATE_TABLE.find(&result) {
+            let end = Self::clause_end(&result, columns.end(), false);
+            result.truncate((end + 1).min(result.len()));
+        }
+        result.trim().to_string()
+    }
+
+    /// Position of the `)` (or, with `at_comma`, the `,`) closing the clause
+    /// starting at `start`, skipping nested parentheses and quoted text
+    fn clause_end(sql: &str, start: usize, at_comma: bool) -> usize {
+        let mut depth = 0usize;
+        let mut quote = None;
+        for (offset, c) in sql[start..].char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '\'' | '"') => quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, ')') if depth > 0 => depth -= 1,
+                (None, ')') => return start + offset,
+                (None, ',') if at_comma && depth == 0 => return start + offset,
+                _ => {}
+            }
+        }
+        sql.len()
+    }
+
+    /// Split a script into statements at semicolons outside quoted text
+    fn split_statements(sql: &str) -> Vec<String> {
+        let mut statements = Vec::new();
+        let mut current = String::new();
+        let mut quote = None;
+        for c in sql.chars() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '\'' | '"') => quote = Some(c),
+                (None, ';') => {
+                    statements.push(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            }
+            current.push(c);
+        }
+        statements.push(current);
+        statements
+            .into_iter()
+            .map(|statement| statement.trim().to_string())
+            .filter(|statement| !statement.is_empty())
+            .collect()
+    }
+
+    /// Canonical type for an Oracle type that needs its parameters converted
+    ///
+    /// - `NUMBER(p)`/`NUMBER(p,0)` become `SMALLINT`, `INTEGER` or `BIGINT` up to 18
+    ///   digits and `DECIMAL(p,0)` beyond; `NUMBER(p,s)` becomes `DECIMAL(p,s)` and an
+    ///   unconstrained `NUMBER` becomes `DECIMAL`
+    /// - `VARCHAR2(n BYTE|CHAR)` and `NVARCHAR2(n)` become `VARCHAR(n)`/`NVARCHAR(n)`
+    /// - `RAW(n)` becomes `VARBINARY(n)`
+    fn oracle_type(data_type: &str) -> Option<String> {
+        let data_type = data_type.trim();
+        if let Some(caps) = RE_ORACLE_NUMBER.captures(data_type) {
+            let Some(precision) = caps.get(1).map(|m| m.as_str()) else {
+                return Some("DECIMAL".to_string());
+            };
+            let precision: u32 = precision.parse().unwrap_or(38);
+            return Some(match caps.get(2).map_or("0", |m| m.as_str()) {
+                "0" if precision <= 4 => "SMALLINT".to_string(),
+                "0" if precision <= 9 => "INTEGER".to_string(),
+                "0" if precision <= 18 => "BIGINT".to_string(),
+                scale => format!("DECIMAL({},{})", precision, scale),
+            });
+        }
+        if let Some(caps) = RE_ORACLE_CHARACTER.captures(data_type) {
+            return Some(format!("{}({})", caps[1].to_uppercase(), &caps[2]));
+        }
+        RE_ORACLE_RAW
+            .captures(data_type)
+            .map(|caps| format!("VARBINARY({})", &caps[1]))
+    }
+
+    /// Remove T-SQL clauses that sqlparser doesn't support
+    ///
+    /// - Index options and storage clauses (`WITH (PAD_INDEX = OFF, ...)`,
+    ///   `ON [PRIMARY]`, `TEXTIMAGE_ON [PRIMARY]`)
+    /// - `CLUSTERED`/`NONCLUSTERED` in `CREATE INDEX`
+    /// - `WITH CHECK` in `ALTER TABLE ... WITH CHECK ADD CONSTRAINT`
+    fn preprocess_tsql(sql: &str) -> String {
+        let mut result = RE_TSQL_INDEX_OPTIONS.replace_all(sql, ")").to_string();
+        // Storage clauses can follow each other (`ON [PRIMARY] TEXTIMAGE_ON [PRIMARY]`)
+        loop {
+            let stripped = RE_TSQL_STORAGE
+                .replace_all(&result, |caps: &regex::Captures| {
+                    // `) ON DELETE`/`) ON UPDATE` belong to foreign keys
+                    match caps[1].to_uppercase().as_str() {
+                        "DELETE" | "UPDATE" => caps[0].to_string(),
+                        _ => ")".to_string(),
+                    }
+                })
+                .to_string();
+            if stripped == result {
+                break;
+            }
+            result = stripped;
+        }
+        let result = RE_TSQL_CLUSTERED_INDEX.replace_all(&result, "CREATE ${1}INDEX");
+        RE_TSQL_WITH_CHECK.replace_all(&result, "ADD").to_string()
+    }
+
+    /// Take `sp_addextendedproperty` calls out of a T-SQL batch
+    ///
+    /// `MS_Description` properties are kept in the preprocessing state and
+    /// applied to the parsed tables and columns.
+    fn extract_extended_properties(sql: &str, state: &mut PreprocessingState) -> String {
+        let mut result = String::with_capacity(sql.len());
+        let mut rest = sql;
+        while let Some(call) = RE_TSQL_EXTENDED_PROPERTY.find(rest) {
+            result.push_str(&rest[..call.start()]);
+            let (arguments, length) = Self::tsql_arguments(&rest[call.end()..]);
+            state
+                .descriptions
+                .extend(ObjectDescription::from_arguments(&arguments));
+            rest = rest[call.end() + length..].trim_start();
+            rest = rest.strip_prefix(';').unwrap_or(rest);
+            result.push('\n');
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// Parse the comma-separated arguments of a T-SQL procedure call
+    ///
+    /// Returns the arguments and the length of the argument list.
+    fn tsql_arguments(sql: &str) -> (Vec<TsqlArgument>, usize) {
+        let bytes = sql.as_bytes();
+        let skip_whitespace = |mut pos: usize| {
+            while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+            pos
+        };
+        let mut arguments = Vec::new();
+        let mut pos = 0;
+        loop {
+            pos = skip_whitespace(pos);
+            let mut name = None;
+            if bytes.get(pos) == Some(&b'@') {
+                let start = pos;
+                while pos < bytes.len()
+                    && (bytes[pos] == b'@'
+                        || bytes[pos].is_ascii_alphanumeric()
+                        || bytes[pos] == b'_')
+                {
+                    pos += 1;
+                }
+                name = Some(sql[start..pos].to_string());
+                pos = skip_whitespace(pos);
+                if bytes.get(pos) != Some(&b'=') {
+                    break;
+                }
+                pos = skip_whitespace(pos + 1);
+            }
+
+            if matches!(bytes.get(pos), Some(b'N' | b'n')) && bytes.get(pos + 1) == Some(&b'\'') {
+                pos += 1;
+            }
+            let value = if bytes.get(pos) == Some(&b'\'') {
+                let mut value = String::new();
+                let mut chars = sql[pos + 1..].char_indices().peekable();
+                let mut end = sql.len();
+                while let Some((offset, c)) = chars.next() {
+                    if c == '\'' {
+                        if chars.peek().map(|(_, next)| *next) == Some('\'') {
+                            value.push('\'');
+                            chars.next();
+                            continue;
+                        }
+                        end = pos + 1 + offset + 1;
+                        break;
+                    }
+                    value.push(c);
+                }
+                pos = end;
+                Some(value)
+            } else {
+                let start = pos;
+                while pos < bytes.len()
+                    && !bytes[pos].is_ascii_whitespace()
+                    && !matches!(bytes[pos], b',' | b';')
+                {
+                    pos += 1;
+                }
+                let token = &sql[start..pos];
+                if token.is_empty() {
+                    break;
+                }
+                (!token.eq_ignore_ascii_case("NULL")).then(|| token.to_string())
+            };
+            arguments.push((name, value));
+
+            let next = skip_whitespace(pos);
+            if bytes.get(next) != Some(&b',') {
+                break;
+            }
+            pos = next + 1;
+        }
+        (arguments, pos)
+    }
+
+    /// Apply extended properties and `COMMENT ON` statements to the parsed tables
+    fn apply_descriptions<'a>(
+        tables: &mut [TableData],
+        descriptions: impl IntoIterator<Item = &'a ObjectDescription>,
+    ) {
+        for description in descriptions {
+            let Some(table) =
+                Self::find_table(tables, description.schema.as_deref(), &description.table)
+            else {
+                continue;
+            };
+            match &description.column {
+                Some(column) => {
+                    if let Some(column) = table
+                        .columns
+                        .iter_mut()
+                        .find(|c| c.name.eq_ignore_ascii_case(column))
+                    {
+                        column.description = Some(description.description.clone());
+                    }
+                }
+                None => table.description = Some(serde_json::json!(description.description)),
+            }
+        }
+    }
+
+    /// Mark columns filled by Oracle sequence triggers as auto-increment
+    fn apply_identity_hints(tables: &mut [TableData], hints: &[IdentityHint]) {
+        for hint in hints {
+            if let Some(column) = Self::find_table(tables, hint.schema.as_deref(), &hint.table)
+                .and_then(|table| {
+                    table
+                        .columns
+                        .iter_mut()
+                        .find(|c| c.name.eq_ignore_ascii_case(&hint.column))
+                })
+            {
+                column
+                    .custom_properties
+                    .insert("autoIncrement".to_string(), serde_json::json!(true));
+            }
+        }
+    }
+
+    /// Find a parsed table by (optionally schema-qualified) name
+    ///
+    /// Names are compared case-insensitively, as unquoted identifiers are in
+    /// SQL Server's default collation and in Oracle.
+    fn find_table<'a>(
+        tables: &'a mut [TableData],
+        schema: Option<&str>,
+        name: &str,
+    ) -> Option<&'a mut TableData> {
+        tables.iter_mut().find(|table| {
+            table
+                .name
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                && schema.is_none_or(|schema| {
+                    table
+                        .odcs_metadata
+                        .get("schemaName")
+                        .and_then(|s| s.as_str())
+                        .is_none_or(|s| s.eq_ignore_ascii_case(schema))
+                })
+        })
+    }
+
     fn dialect_impl(&self) -> Box<dyn Dialect + Send + Sync> {
         match self.dialect.to_lowercase().as_str() {
             "ansi" => Box::new(AnsiDialect {}),
@@ -816,7 +1487,9 @@ impl SQLImporter {
             "hive" => Box::new(HiveDialect {}),
             "mssql" | "sqlserver" => Box::new(MsSqlDialect {}),
             "mysql" => Box::new(MySqlDialect {}),
+            "oracle" => Box::new(OracleDialect {}),
             "postgres" | "postgresql" => Box::new(PostgreSqlDialect {}),
+            "snowflake" => Box::new(SnowflakeDialect {}),
             "sqlite" => Box::new(SQLiteDialect {}),
             _ => Box::new(GenericDialect {}),
         }
@@ -916,7 +1589,8 @@ impl SQLImporter {
                     // In sqlparser 0.60, IndexColumn structure may have changed
                     // Try to get the column name - might be a field or method
                     // Unquote the column name to match against column definitions
-                    pk_cols.insert(Self::unquote_identifier(&col.to_string()));
+                    // Only the column expression: T-SQL adds ASC/DESC to key columns
+                    pk_cols.insert(Self::unquote_identifier(&col.column.expr.to_string()));
                 }
             }
         }
@@ -925,9 +1599,22 @@ impl SQLImporter {
         for col in columns {
             let mut nullable = true;
             let mut is_pk = false;
+            let mut auto_increment = false;
 
             for opt_def in &col.options {
                 match &opt_def.option {
+                    ColumnOption::Identity(_) => auto_increment = true,
+                    ColumnOption::Generated {
+                        generated_as: GeneratedAs::Always | GeneratedAs::ByDefault,
+                        generation_expr: None,
+                        ..
+                    } => auto_increment = true,
+                    // Oracle 12c sequence defaults, e.g. `DEFAULT orders_seq.NEXTVAL`
+                    ColumnOption::Default(expr)
+                        if expr.to_string().to_uppercase().ends_with(".NEXTVAL") =>
+                    {
+                        auto_increment = true
+                    }
                     ColumnOption::NotNull => nullable = false,
                     ColumnOption::Null => nullable = true,
                     ColumnOption::Unique(_) => {
@@ -947,12 +1634,22 @@ impl SQLImporter {
                 is_pk = true;
             }
             let mut data_type = col.data_type.to_string();
+            // T-SQL bracketed types, e.g. `[nvarchar](100)`
+            if data_type.starts_with('[') {
+                data_type = data_type.replace(['[', ']'], "").to_uppercase();
+            }
             let mut description = None;
 
-            // Extract COMMENT clause from column options
+            // Extract COMMENT clause (or BigQuery OPTIONS(description = ...)) from column options
             for opt_def in &col.options {
-                if let ColumnOption::Comment(comment) = &opt_def.option {
-                    description = Some(comment.clone());
+                match &opt_def.option {
+                    ColumnOption::Comment(comment) => description = Some(comment.clone()),
+                    ColumnOption::Options(options) => {
+                        if let Some(comment) = Self::option_string(options, "description") {
+                            description = Some(comment);
+                        }
+                    }
+                    _ => {}
                 }
             }
 
@@ -963,6 +1660,52 @@ impl SQLImporter {
             {
                 data_type = original_type.clone();
             }
+            if self.dialect.eq_ignore_ascii_case("snowflake")
+                && let Some(decimal) = Self::snowflake_decimal_type(&data_type)
+            {
+                data_type = decimal;
+            }
+            if self.dialect.eq_ignore_ascii_case("oracle")
+                && let Some(canonical) = Self::oracle_type(&data_type)
+            {
+                data_type = canonical;
+            }
+            let type_mappings = self
+                .type_mappings
+                .as_deref()
+                .unwrap_or(TypeMappingRegistry::builtin());
+            let native_type = data_type.clone();
+            if let Some(mapped) = type_mappings.import_type(&self.dialect, &data_type) {
+                data_type = mapped;
+            } else if type_mappings.dialect(&self.dialect).is_some()
+                && let Some(nested) = NestedType::parse(&data_type)
+            {
+                // Map the scalar types inside STRUCT/ARRAY/MAP types
+                data_type = nested
+                    .map_scalars(&|t| {
+                        type_mappings
+                            .import_type(&self.dialect, t)
+                            .unwrap_or_else(|| t.to_string())
+                    })
+                    .to_string();
+            }
+            // Keep types without a mapping or a standard meaning (e.g. PostGIS
+            // geometry, Snowflake VARIANT) visible as the physical type
+            let mut physical_type = None;
+            let mut custom_properties = HashMap::new();
+            if !type_mappings.has_import(&self.dialect, &native_type)
+                && !type_mappings.is_known_type(&data_type)
+            {
+                physical_type = Some(native_type.clone());
+                custom_properties.insert(
+                    SOURCE_TYPE_PROPERTY.to_string(),
+                    source_type_property(&self.dialect, &native_type),
+                );
+            }
+
+            if auto_increment {
+                custom_properties.insert("autoIncrement".to_string(), serde_json::json!(true));
+            }
 
             // Validate column name and data type (warnings are logged but don't fail import)
             if let Err(e) = validate_column_name(&col_name) {
@@ -975,25 +1718,149 @@ impl SQLImporter {
             out_cols.push(ColumnData {
                 name: col_name,
                 data_type,
+                physical_type,
                 nullable,
                 primary_key: is_pk,
                 description,
+                custom_properties,
                 ..Default::default()
             });
         }
 
+        // Schema of schema-qualified names (e.g. `dbo` in `[dbo].[orders]`)
+        let mut odcs_metadata = HashMap::new();
+        if let [.., schema, _] = name.0.as_slice() {
+            odcs_metadata.insert(
+                "schemaName".to_string(),
+                serde_json::json!(Self::unquote_identifier(&schema.to_string())),
+            );
+        }
+
         Ok((
             TableData {
                 table_index,
                 id: None, // SQL imports don't have UUIDs - generated later during model creation
                 name: Some(table_name),
                 columns: out_cols,
+                odcs_metadata,
                 ..Default::default()
             },
             requires_name,
         ))
     }
 
+    /// Apply table-level clauses of a CREATE TABLE statement
+    ///
+    /// - `COMMENT` becomes the table description
+    /// - `CLUSTER BY` keys are kept in order as `clusterBy` metadata, and plain
+    ///   column keys mark their columns as clustered
+    /// - `TRANSIENT` tables are flagged with `transient` metadata
+    fn apply_table_options(table: &mut TableData, create: &CreateTable) {
+        // Snowflake and MySQL keep the comment with the other table options
+        let option_comment = match &create.table_options {
+            CreateTableOptions::Plain(options)
+            | CreateTableOptions::With(options)
+            | CreateTableOptions::Options(options) => options.iter().find_map(|o| match o {
+                SqlOption::Comment(comment) => Some(comment),
+                _ => None,
+            }),
+            _ => None,
+        };
+        if let Some(comment) = create.comment.as_ref().or(option_comment) {
+            table.description = Some(serde_json::json!(comment.to_string()));
+        }
+
+        // BigQuery: OPTIONS(description = "...", labels = [("key", "value")])
+        if let CreateTableOptions::Options(options) = &create.table_options {
+            if let Some(description) = Self::option_string(options, "description") {
+                table.description = Some(serde_json::json!(description));
+            }
+            if let Some(Expr::Array(labels)) = Self::option_value(options, "labels") {
+                for label in &labels.elem {
+                    if let Expr::Tuple(pair) = label
+                        && let [key, value] = pair.as_slice()
+                        && let (Some(key), Some(value)) =
+                            (Self::expr_string(key), Self::expr_string(value))
+                    {
+                        table.tags.push(format!("{}:{}", key, value));
+                    }
+                }
+            }
+        }
+
+        if let Some(partition_by) = &create.partition_by {
+            let expr = partition_by.to_string();
+            // The partitioning column is the first column named in the expression,
+            // e.g. `created_at` in `DATE(created_at)`
+            let column = expr
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .find_map(|token| table.columns.iter().position(|c| c.name == token));
+            if let Some(index) = column {
+                table.columns[index].partitioned = true;
+                table.columns[index].partition_key_position = Some(1);
+            }
+            table
+                .odcs_metadata
+                .insert("partitionBy".to_string(), serde_json::json!(expr));
+        }
+
+        if let Some(WrappedCollection::NoWrapping(keys) | WrappedCollection::Parentheses(keys)) =
+            &create.cluster_by
+        {
+            let mut cluster_by = Vec::new();
+            for key in keys {
+                if let Expr::Identifier(ident) = key
+                    && let Some(column) = table.columns.iter_mut().find(|c| c.name == ident.value)
+                {
+                    column.clustered = true;
+                }
+                cluster_by.push(serde_json::json!(key.to_string()));
+            }
+            table.odcs_metadata.insert(
+                "clusterBy".to_string(),
+                serde_json::Value::Array(cluster_by),
+            );
+        }
+
+        if create.transient {
+            table
+                .odcs_metadata
+                .insert("transient".to_string(), serde_json::json!(true));
+        }
+    }
+
+    /// Value of a `key = value` option
+    fn option_value<'a>(options: &'a [SqlOption], key: &str) -> Option<&'a Expr> {
+        options.iter().find_map(|option| match option {
+            SqlOption::KeyValue { key: k, value } if k.value.eq_ignore_ascii_case(key) => {
+                Some(value)
+            }
+            _ => None,
+        })
+    }
+
+    /// String value of a `key = 'value'` option
+    fn option_string(options: &[SqlOption], key: &str) -> Option<String> {
+        Self::option_value(options, key).and_then(Self::expr_string)
+    }
+
+    fn expr_string(expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Value(value) => value.clone().into_string(),
+            _ => None,
+        }
+    }
+
+    /// Canonical `DECIMAL(p,s)` type for a Snowflake fixed-point type
+    ///
+    /// Snowflake defaults to a precision of 38 and a scale of 0.
+    fn snowflake_decimal_type(data_type: &str) -> Option<String> {
+        let caps = RE_SNOWFLAKE_NUMBER.captures(data_type.trim())?;
+        let precision = caps.get(1).map_or("38", |m| m.as_str());
+        let scale = caps.get(2).map_or("0", |m| m.as_str());
+        Some(format!("DECIMAL({},{})", precision, scale))
+    }
+
     /// Parse CREATE VIEW statement
     ///
     /// Extracts view name and creates a TableData entry for the view.
@@ -1079,6 +1946,220 @@ mod tests {
         assert!(t.columns.iter().any(|c| c.name == "id" && c.primary_key));
     }
 
+    #[test]
+    fn test_snowflake_create_table() {
+        let importer = SQLImporter::new("snowflake");
+        let sql = r#"
+CREATE TRANSIENT TABLE analytics.events (
+    id NUMBER(38,0) NOT NULL PRIMARY KEY,
+    amount NUMBER(10,2) COMMENT 'Event amount',
+    total NUMBER,
+    payload VARIANT,
+    attributes OBJECT,
+    tags ARRAY,
+    event_date DATE
+)
+CLUSTER BY (event_date, TO_DATE(payload:ts))
+COMMENT = 'Raw events';
+"#;
+        let result = importer.parse(sql).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let t = &result.tables[0];
+        assert_eq!(t.name.as_deref(), Some("events"));
+        assert_eq!(t.description, Some(serde_json::json!("Raw events")));
+        assert_eq!(
+            t.odcs_metadata.get("transient"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(
+            t.odcs_metadata.get("clusterBy"),
+            Some(&serde_json::json!(["event_date", "TO_DATE(payload:ts)"]))
+        );
+
+        let column = |name: &str| t.columns.iter().find(|c| c.name == name).unwrap();
+        assert_eq!(column("id").data_type, "DECIMAL(38,0)");
+        assert!(column("id").primary_key);
+        assert_eq!(column("amount").data_type, "DECIMAL(10,2)");
+        assert_eq!(
+            column("amount").description.as_deref(),
+            Some("Event amount")
+        );
+        assert_eq!(column("total").data_type, "DECIMAL(38,0)");
+        assert_eq!(column("payload").data_type, "VARIANT");
+        assert!(column("payload").physical_type.is_none());
+        assert_eq!(column("attributes").data_type, "OBJECT");
+        assert_eq!(column("tags").data_type, "ARRAY<VARIANT>");
+        assert!(column("event_date").clustered);
+        assert!(!column("payload").clustered);
+    }
+
+    #[test]
+    fn test_bigquery_create_table() {
+        let importer = SQLImporter::new("bigquery");
+        let sql = r#"
+CREATE TABLE `project.sales.orders` (
+    id INT64 NOT NULL OPTIONS(description = "Order id"),
+    total NUMERIC(10, 2),
+    paid BOOL,
+    shipping STRUCT<street STRING, zip INT64>,
+    lines ARRAY<STRUCT<sku STRING, qty INT64, price FLOAT64>>,
+    created_at TIMESTAMP
+)
+PARTITION BY DATE(created_at)
+CLUSTER BY id
+OPTIONS(description = "Customer orders", labels = [("team", "sales")]);
+"#;
+        let result = importer.parse(sql).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let t = &result.tables[0];
+        assert_eq!(t.description, Some(serde_json::json!("Customer orders")));
+        assert_eq!(t.tags, vec!["team:sales".to_string()]);
+        assert_eq!(
+            t.odcs_metadata.get("partitionBy"),
+            Some(&serde_json::json!("DATE(created_at)"))
+        );
+        assert_eq!(
+            t.odcs_metadata.get("clusterBy"),
+            Some(&serde_json::json!(["id"]))
+        );
+
+        let column = |name: &str| t.columns.iter().find(|c| c.name == name).unwrap();
+        assert_eq!(column("id").data_type, "BIGINT");
+        assert_eq!(column("id").description.as_deref(), Some("Order id"));
+        assert!(column("id").clustered);
+        assert_eq!(column("total").data_type, "NUMERIC(10,2)");
+        assert_eq!(column("paid").data_type, "BOOLEAN");
+        assert_eq!(
+            column("shipping").data_type,
+            "STRUCT<street STRING, zip BIGINT>"
+        );
+        assert_eq!(
+            column("lines").data_type,
+            "ARRAY<STRUCT<sku STRING, qty BIGINT, price DOUBLE>>"
+        );
+        assert!(column("created_at").partitioned);
+        assert_eq!(column("created_at").partition_key_position, Some(1));
+    }
+
+    #[test]
+    fn test_mssql_create_table() {
+        let importer = SQLImporter::new("mssql");
+        let sql = r#"
+SET ANSI_NULLS ON
+GO
+CREATE TABLE [dbo].[Customers](
+    [CustomerID] [int] IDENTITY(1,1) NOT NULL,
+    [Name] [nvarchar](100) NOT NULL,
+    [Notes] [nvarchar](max) NULL,
+    [Active] [bit] NOT NULL,
+ CONSTRAINT [PK_Customers] PRIMARY KEY CLUSTERED
+(
+    [CustomerID] ASC
+)WITH (PAD_INDEX = OFF, IGNORE_DUP_KEY = OFF) ON [PRIMARY]
+) ON [PRIMARY] TEXTIMAGE_ON [PRIMARY]
+GO
+ALTER TABLE [dbo].[Orders] CHECK CONSTRAINT [FK_Orders_Customers]
+GO
+EXEC sys.sp_addextendedproperty @name=N'MS_Description', @value=N'Customer''s master data' , @level0type=N'SCHEMA',@level0name=N'dbo', @level1type=N'TABLE',@level1name=N'Customers'
+GO
+EXEC sp_addextendedproperty N'MS_Description', N'Display name', N'SCHEMA', N'dbo', N'TABLE', N'Customers', N'COLUMN', N'Name';
+"#;
+        let result = importer.parse(sql).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.tables.len(), 1);
+        let t = &result.tables[0];
+        assert_eq!(t.name.as_deref(), Some("Customers"));
+        assert_eq!(
+            t.odcs_metadata.get("schemaName"),
+            Some(&serde_json::json!("dbo"))
+        );
+        assert_eq!(
+            t.description,
+            Some(serde_json::json!("Customer's master data"))
+        );
+
+        let column = |name: &str| t.columns.iter().find(|c| c.name == name).unwrap();
+        assert!(column("CustomerID").primary_key);
+        assert_eq!(column("CustomerID").data_type, "INT");
+        assert_eq!(
+            column("CustomerID").custom_properties.get("autoIncrement"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(column("Name").data_type, "NVARCHAR(100)");
+        assert_eq!(column("Name").description.as_deref(), Some("Display name"));
+        assert_eq!(column("Notes").data_type, "STRING");
+        assert_eq!(column("Active").data_type, "BOOLEAN");
+    }
+
+    #[test]
+    fn test_oracle_create_table() {
+        let importer = SQLImporter::new("oracle");
+        let sql = r#"
+  CREATE TABLE "HR"."EMPLOYEES"
+   (	"ID" NUMBER(10,0) NOT NULL ENABLE,
+	"NAME" VARCHAR2(100 BYTE),
+	"BIO" CLOB,
+	"SALARY" NUMBER(8,2),
+	"HIRED" DATE,
+	 CONSTRAINT "EMP_PK" PRIMARY KEY ("ID")
+  USING INDEX PCTFREE 10 STORAGE(INITIAL 65536 NEXT 1048576) TABLESPACE "USERS"  ENABLE
+   ) SEGMENT CREATION IMMEDIATE
+  STORAGE(INITIAL 65536 NEXT 1048576)
+  TABLESPACE "USERS" ;
+
+COMMENT ON TABLE "HR"."EMPLOYEES" IS 'Employee''s records';
+COMMENT ON COLUMN "HR"."EMPLOYEES"."NAME" IS 'Full name';
+
+CREATE SEQUENCE emp_seq START WITH 1 INCREMENT BY 1 NOCACHE;
+
+CREATE OR REPLACE TRIGGER emp_bir
+BEFORE INSERT ON hr.employees
+FOR EACH ROW
+BEGIN
+  SELECT emp_seq.NEXTVAL INTO :new.id FROM dual;
+END;
+/
+
+CREATE TABLE departments (
+  id NUMBER GENERATED BY DEFAULT ON NULL AS IDENTITY,
+  code NVARCHAR2(10)
+);
+"#;
+        let result = importer.parse(sql).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.tables.len(), 2);
+        let t = &result.tables[0];
+        assert_eq!(t.name.as_deref(), Some("EMPLOYEES"));
+        assert_eq!(
+            t.odcs_metadata.get("schemaName"),
+            Some(&serde_json::json!("HR"))
+        );
+        assert_eq!(t.description, Some(serde_json::json!("Employee's records")));
+
+        let column = |name: &str| t.columns.iter().find(|c| c.name == name).unwrap();
+        assert!(column("ID").primary_key);
+        assert_eq!(column("ID").data_type, "BIGINT");
+        assert_eq!(
+            column("ID").custom_properties.get("autoIncrement"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(column("NAME").data_type, "VARCHAR(100)");
+        assert_eq!(column("NAME").description.as_deref(), Some("Full name"));
+        assert_eq!(column("BIO").data_type, "STRING");
+        assert_eq!(column("SALARY").data_type, "DECIMAL(8,2)");
+        assert_eq!(column("HIRED").data_type, "TIMESTAMP");
+
+        let departments = &result.tables[1];
+        assert_eq!(departments.columns[0].data_type, "DECIMAL");
+        assert_eq!(
+            departments.columns[0]
+                .custom_properties
+                .get("autoIncrement"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(departments.columns[1].data_type, "NVARCHAR(10)");
+    }
+
     #[test]
     fn test_sql_importer_parse_liquibase_formatted_sql() {
         let importer = SQLImporter::new("postgres");
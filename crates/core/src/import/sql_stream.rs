@@ -0,0 +1,718 @@
+//! Streaming SQL import for very large dumps
+//!
+//! [`SQLImporter::parse`](super::SQLImporter::parse) needs the whole script in memory.
+//! This module reads a dump statement-by-statement from any `BufRead` source, so
+//! multi-gigabyte `pg_dump`/`mysqldump` files can be imported with bounded memory:
+//!
+//! - Only DDL statements (`CREATE TABLE`, `CREATE VIEW`, ...) are buffered and parsed
+//! - `INSERT`/`COPY` data sections are scanned without being buffered
+//! - The input is read in fixed-size chunks and buffered statements are capped
+//!   in length, so even single-line dumps are read with bounded memory
+//! - Progress is reported through a callback as the input is consumed
+//!
+//! ## Example
+//!
+//! ```rust
+//! use data_modelling_core::import::sql_stream::StreamingSqlImporter;
+//!
+//! let dump = "CREATE TABLE users (id INT PRIMARY KEY);\nINSERT INTO users VALUES (1), (2);\n";
+//! let importer = StreamingSqlImporter::new("postgres");
+//! let result = importer.import_reader(dump.as_bytes(), |_| {}).unwrap();
+//! assert_eq!(result.tables.len(), 1);
+//! ```
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use super::{ImportError, ImportResult, SQLImporter, TableRequiringName};
+
+/// Statement keywords whose bodies are scanned but never buffered
+const SKIPPED_KEYWORDS: &[&str] = &["INSERT", "COPY", "REPLACE", "VALUES", "LOCK", "UNLOCK"];
+
+/// Maximum number of bytes kept from a skipped statement (to detect `COPY ... FROM stdin`)
+const SKIPPED_HEADER_LIMIT: usize = 256;
+
+/// Bytes kept from a `COPY` data line to recognise the `\.` terminator
+const COPY_TERMINATOR_LINE_LIMIT: usize = 16;
+
+/// Number of bytes read from the source at a time
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Default maximum length of a buffered statement in bytes
+pub const DEFAULT_MAX_STATEMENT_LEN: usize = 16 * 1024 * 1024;
+
+/// Longest dollar-quote tag (`$tag$`) recognised
+const MAX_DOLLAR_TAG_LEN: usize = 64;
+
+/// Dialects whose string literals use backslash escapes
+const BACKSLASH_ESCAPE_DIALECTS: &[&str] = &["mysql", "bigquery", "databricks", "hive"];
+
+/// Progress information reported while streaming a SQL dump
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SqlStreamProgress {
+    /// Bytes consumed from the input so far
+    pub bytes_read: u64,
+    /// Statements encountered so far
+    pub statements_seen: usize,
+    /// Statements skipped without parsing (data sections and non-DDL)
+    pub statements_skipped: usize,
+    /// Tables and views imported so far
+    pub tables_found: usize,
+}
+
+/// A single statement read from a SQL stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SqlStatement {
+    /// A buffered statement (without the trailing `;`)
+    Statement(String),
+    /// A data statement (`INSERT`, `COPY`, ...) that was scanned but not buffered
+    Skipped {
+        /// Leading keyword of the skipped statement (uppercase)
+        keyword: String,
+    },
+}
+
+/// Lexer state while scanning for statement terminators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LexState {
+    Normal,
+    SingleQuote,
+    /// `E'...'` string, or any string in a dialect with backslash escapes
+    EscapeQuote,
+    DoubleQuote,
+    Backtick,
+    LineComment,
+    BlockComment,
+    DollarQuote,
+}
+
+/// Iterator splitting a SQL source into statements with bounded memory
+///
+/// The source is read in fixed-size chunks, so neither long lines nor large
+/// data statements are held in memory. Statements are terminated by `;`
+/// outside of quotes, comments and dollar-quoted bodies. `COPY ... FROM stdin`
+/// data blocks are skipped up to the terminating `\.` line.
+///
+/// Backslashes escape characters only in `E'...'` strings, unless
+/// [`with_backslash_escapes`](Self::with_backslash_escapes) enables them for
+/// all string literals. Buffered statements longer than
+/// [`DEFAULT_MAX_STATEMENT_LEN`] are rejected with an
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) error.
+pub struct SqlStatementReader<R: BufRead> {
+    reader: R,
+    buf: Vec<u8>,
+    buf_pos: usize,
+    current: Vec<u8>,
+    keyword: Option<String>,
+    skipping: bool,
+    state: LexState,
+    dollar_tag: Vec<u8>,
+    /// Length and first byte of the word directly before the current position
+    word_len: usize,
+    word_start: u8,
+    in_copy_data: bool,
+    copy_line: Vec<u8>,
+    backslash_escapes: bool,
+    max_statement_len: usize,
+    bytes_read: u64,
+    finished: bool,
+}
+
+impl<R: BufRead> SqlStatementReader<R> {
+    /// Create a new statement reader
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            buf_pos: 0,
+            current: Vec::new(),
+            keyword: None,
+            skipping: false,
+            state: LexState::Normal,
+            dollar_tag: Vec::new(),
+            word_len: 0,
+            word_start: 0,
+            in_copy_data: false,
+            copy_line: Vec::new(),
+            backslash_escapes: false,
+            max_statement_len: DEFAULT_MAX_STATEMENT_LEN,
+            bytes_read: 0,
+            finished: false,
+        }
+    }
+
+    /// Treat backslashes as escapes in all string literals, as MySQL does
+    pub fn with_backslash_escapes(mut self, enabled: bool) -> Self {
+        self.backslash_escapes = enabled;
+        self
+    }
+
+    /// Reject buffered statements longer than `max_len` bytes (default: [`DEFAULT_MAX_STATEMENT_LEN`])
+    pub fn with_max_statement_len(mut self, max_len: usize) -> Self {
+        self.max_statement_len = max_len;
+        self
+    }
+
+    /// Bytes consumed from the underlying reader so far
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Make at least `n` unconsumed bytes available if the input has them.
+    /// Returns the number of unconsumed bytes.
+    fn fill(&mut self, n: usize) -> std::io::Result<usize> {
+        while self.buf.len() - self.buf_pos < n {
+            // Keep the last consumed byte, dollar quotes are matched from it
+            let consumed = self.buf_pos.saturating_sub(1);
+            self.buf.drain(..consumed);
+            self.buf_pos -= consumed;
+            let start = self.buf.len();
+            self.buf.resize(start + CHUNK_SIZE, 0);
+            let read = loop {
+                match self.reader.read(&mut self.buf[start..]) {
+                    Ok(read) => break read,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        self.buf.truncate(start);
+                        return Err(e);
+                    }
+                }
+            };
+            self.buf.truncate(start + read);
+            self.bytes_read += read as u64;
+            if read == 0 {
+                break;
+            }
+        }
+        Ok(self.buf.len() - self.buf_pos)
+    }
+
+    /// Look at the byte `offset` positions past the current one
+    fn peek(&mut self, offset: usize) -> std::io::Result<Option<u8>> {
+        self.fill(offset + 1)?;
+        Ok(self.buf.get(self.buf_pos + offset).copied())
+    }
+
+    /// Append a byte to the current statement (unless skipping)
+    fn push(&mut self, b: u8) -> std::io::Result<()> {
+        if self.skipping {
+            if self.current.len() < SKIPPED_HEADER_LIMIT {
+                self.current.push(b);
+            }
+            return Ok(());
+        }
+        if self.current.len() >= self.max_statement_len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "SQL statement exceeds the maximum length of {} bytes",
+                    self.max_statement_len
+                ),
+            ));
+        }
+        self.current.push(b);
+
+        // Determine the leading keyword once the first word is complete
+        if self.keyword.is_none() && !b.is_ascii_alphanumeric() && b != b'_' {
+            let text = self.current.trim_ascii_start();
+            let len = text
+                .iter()
+                .take_while(|ch| ch.is_ascii_alphanumeric() || **ch == b'_')
+                .count();
+            if len > 0 {
+                let word = String::from_utf8_lossy(&text[..len]).to_uppercase();
+                self.skipping = SKIPPED_KEYWORDS.contains(&word.as_str());
+                self.keyword = Some(word);
+            }
+        }
+        Ok(())
+    }
+
+    /// Append several bytes to the current statement
+    fn push_all(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        for &b in bytes {
+            self.push(b)?;
+        }
+        Ok(())
+    }
+
+    /// Finish the current statement
+    fn take_statement(&mut self) -> std::io::Result<Option<SqlStatement>> {
+        let keyword = self.keyword.take();
+        let skipping = std::mem::replace(&mut self.skipping, false);
+        let text = std::mem::take(&mut self.current);
+
+        if skipping {
+            let keyword = keyword.unwrap_or_default();
+            if keyword == "COPY"
+                && String::from_utf8_lossy(&text)
+                    .to_uppercase()
+                    .contains("FROM STDIN")
+            {
+                self.in_copy_data = true;
+                self.copy_line.clear();
+            }
+            return Ok(Some(SqlStatement::Skipped { keyword }));
+        }
+
+        let text = String::from_utf8(text)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            // Only whitespace/comments
+            return Ok(None);
+        }
+        Ok(Some(SqlStatement::Statement(trimmed.to_string())))
+    }
+
+    /// Skip a `COPY` data byte, leaving data mode after the `\.` line
+    fn skip_copy_data(&mut self, b: u8) {
+        if b == b'\n' {
+            if self.copy_line.trim_ascii_end() == b"\\." {
+                self.in_copy_data = false;
+            }
+            self.copy_line.clear();
+        } else if self.copy_line.len() < COPY_TERMINATOR_LINE_LIMIT {
+            self.copy_line.push(b);
+        } else {
+            // Longer lines are data, whatever follows
+            self.copy_line[0] = 0;
+        }
+    }
+
+    /// Scan for the next statement
+    fn next_statement(&mut self) -> std::io::Result<Option<SqlStatement>> {
+        loop {
+            let Some(b) = self.peek(0)? else {
+                self.finished = true;
+                return self.take_statement();
+            };
+            let next = self.peek(1)?;
+            self.buf_pos += 1;
+
+            // Skip COPY data blocks without buffering
+            if self.in_copy_data {
+                self.skip_copy_data(b);
+                continue;
+            }
+
+            match self.state {
+                LexState::Normal => {
+                    let (word_len, word_start) = (self.word_len, self.word_start);
+                    if b.is_ascii_alphanumeric() || b == b'_' {
+                        self.word_len += 1;
+                        if word_len == 0 {
+                            self.word_start = b;
+                        }
+                    } else {
+                        self.word_len = 0;
+                    }
+
+                    match b {
+                        b';' => {
+                            if let Some(stmt) = self.take_statement()? {
+                                return Ok(Some(stmt));
+                            }
+                        }
+                        b'\'' => {
+                            let escape_string =
+                                word_len == 1 && word_start.eq_ignore_ascii_case(&b'e');
+                            self.state = if escape_string || self.backslash_escapes {
+                                LexState::EscapeQuote
+                            } else {
+                                LexState::SingleQuote
+                            };
+                            self.push(b)?;
+                        }
+                        b'"' => {
+                            self.state = LexState::DoubleQuote;
+                            self.push(b)?;
+                        }
+                        b'`' => {
+                            self.state = LexState::Backtick;
+                            self.push(b)?;
+                        }
+                        b'-' if next == Some(b'-') => {
+                            // Comments are dropped from buffered statements
+                            self.state = LexState::LineComment;
+                            self.buf_pos += 1;
+                        }
+                        b'/' if next == Some(b'*') => {
+                            self.state = LexState::BlockComment;
+                            self.buf_pos += 1;
+                        }
+                        b'$' => {
+                            if let Some(tag_len) = self.dollar_tag_len()? {
+                                let tag = self.buf[self.buf_pos - 1..self.buf_pos + tag_len + 1]
+                                    .to_vec();
+                                self.push_all(&tag)?;
+                                self.dollar_tag = tag;
+                                self.buf_pos += tag_len + 1;
+                                self.state = LexState::DollarQuote;
+                                continue;
+                            }
+                            self.push(b)?;
+                        }
+                        _ => self.push(b)?,
+                    }
+                }
+                LexState::SingleQuote => {
+                    self.push(b)?;
+                    if b == b'\'' {
+                        self.state = LexState::Normal;
+                    }
+                }
+                LexState::EscapeQuote => {
+                    self.push(b)?;
+                    if b == b'\\' {
+                        if let Some(n) = next {
+                            self.push(n)?;
+                            self.buf_pos += 1;
+                        }
+                    } else if b == b'\'' {
+                        self.state = LexState::Normal;
+                    }
+                }
+                LexState::DoubleQuote => {
+                    self.push(b)?;
+                    if b == b'"' {
+                        self.state = LexState::Normal;
+                    }
+                }
+                LexState::Backtick => {
+                    self.push(b)?;
+                    if b == b'`' {
+                        self.state = LexState::Normal;
+                    }
+                }
+                LexState::LineComment => {
+                    if b == b'\n' {
+                        self.state = LexState::Normal;
+                        self.push(b)?;
+                    }
+                }
+                LexState::BlockComment => {
+                    if b == b'*' && next == Some(b'/') {
+                        self.buf_pos += 1;
+                        self.state = LexState::Normal;
+                        self.push(b' ')?;
+                    }
+                }
+                LexState::DollarQuote => {
+                    let tag_len = self.dollar_tag.len();
+                    if b == b'$'
+                        && self.fill(tag_len - 1)? >= tag_len - 1
+                        && self.buf[self.buf_pos - 1..].starts_with(&self.dollar_tag)
+                    {
+                        let tag = std::mem::take(&mut self.dollar_tag);
+                        self.push_all(&tag)?;
+                        self.buf_pos += tag_len - 1;
+                        self.state = LexState::Normal;
+                    } else {
+                        self.push(b)?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Length of the tag of a dollar quote opened by the `$` just consumed
+    fn dollar_tag_len(&mut self) -> std::io::Result<Option<usize>> {
+        for len in 0..=MAX_DOLLAR_TAG_LEN {
+            match self.peek(len)? {
+                Some(b'$') => return Ok(Some(len)),
+                Some(ch) if ch.is_ascii_alphanumeric() || ch == b'_' || !ch.is_ascii() => {}
+                _ => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<R: BufRead> Iterator for SqlStatementReader<R> {
+    type Item = std::io::Result<SqlStatement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            match self.next_statement() {
+                Ok(Some(stmt)) => return Some(Ok(stmt)),
+                Ok(None) => continue,
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Check whether a statement defines a table or view
+fn is_ddl_statement(statement: &str) -> bool {
+    let upper: String = statement
+        .split_whitespace()
+        .take(6)
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase();
+    upper.starts_with("CREATE")
+        && (upper.contains(" TABLE") || upper.contains(" VIEW"))
+        && !upper.contains(" INDEX")
+}
+
+/// Streaming SQL importer for large dumps
+///
+/// Parses each DDL statement individually with [`SQLImporter`], so a statement
+/// that fails to parse is recorded as an error without aborting the import.
+#[derive(Debug, Clone)]
+pub struct StreamingSqlImporter {
+    dialect: String,
+    progress_interval: usize,
+    max_statement_len: usize,
+}
+
+impl StreamingSqlImporter {
+    /// Create a streaming importer for the given SQL dialect
+    pub fn new(dialect: &str) -> Self {
+        Self {
+            dialect: dialect.to_string(),
+            progress_interval: 1000,
+            max_statement_len: DEFAULT_MAX_STATEMENT_LEN,
+        }
+    }
+
+    /// Report progress every `interval` statements (default: 1000)
+    pub fn with_progress_interval(mut self, interval: usize) -> Self {
+        self.progress_interval = interval.max(1);
+        self
+    }
+
+    /// Fail the import on DDL statements longer than `max_len` bytes
+    /// (default: [`DEFAULT_MAX_STATEMENT_LEN`])
+    pub fn with_max_statement_len(mut self, max_len: usize) -> Self {
+        self.max_statement_len = max_len;
+        self
+    }
+
+    /// Import a SQL dump from a file
+    pub fn import_file<F>(&self, path: &Path, on_progress: F) -> Result<ImportResult, ImportError>
+    where
+        F: FnMut(&SqlStreamProgress),
+    {
+        let file = File::open(path)
+            .map_err(|e| ImportError::IoError(format!("{}: {}", path.display(), e)))?;
+        self.import_reader(BufReader::new(file), on_progress)
+    }
+
+    /// Import a SQL dump from a buffered reader
+    ///
+    /// `on_progress` is called every `progress_interval` statements and once
+    /// when the input is exhausted.
+    pub fn import_reader<R, F>(
+        &self,
+        reader: R,
+        mut on_progress: F,
+    ) -> Result<ImportResult, ImportError>
+    where
+        R: BufRead,
+        F: FnMut(&SqlStreamProgress),
+    {
+        let importer = SQLImporter::new(&self.dialect);
+        let backslash_escapes =
+            BACKSLASH_ESCAPE_DIALECTS.contains(&self.dialect.to_lowercase().as_str());
+        let mut statements = SqlStatementReader::new(reader)
+            .with_backslash_escapes(backslash_escapes)
+            .with_max_statement_len(self.max_statement_len);
+        let mut progress = SqlStreamProgress::default();
+        let mut result = ImportResult {
+            tables: Vec::new(),
+            tables_requiring_name: Vec::new(),
+            errors: Vec::new(),
+            ai_suggestions: None,
+        };
+
+        while let Some(statement) = statements.next() {
+            let statement = statement.map_err(|e| ImportError::IoError(e.to_string()))?;
+            progress.statements_seen += 1;
+
+            match statement {
+                SqlStatement::Statement(sql) if is_ddl_statement(&sql) => {
+                    import_statement(&importer, &sql, &mut result)?;
+                    progress.tables_found = result.tables.len();
+                }
+                _ => progress.statements_skipped += 1,
+            }
+
+            if progress.statements_seen % self.progress_interval == 0 {
+                progress.bytes_read = statements.bytes_read();
+                on_progress(&progress);
+            }
+        }
+
+        progress.bytes_read = statements.bytes_read();
+        on_progress(&progress);
+
+        Ok(result)
+    }
+}
+
+/// Parse a single DDL statement and merge it into the result
+fn import_statement(
+    importer: &SQLImporter,
+    sql: &str,
+    result: &mut ImportResult,
+) -> Result<(), ImportError> {
+    let parsed = importer
+        .parse(&format!("{};", sql))
+        .map_err(|e| ImportError::ParseError(e.to_string()))?;
+
+    for req in parsed.tables_requiring_name {
+        result.tables_requiring_name.push(TableRequiringName {
+            table_index: result.tables.len() + req.table_index,
+            suggested_name: req.suggested_name,
+        });
+    }
+    for mut table in parsed.tables {
+        table.table_index = result.tables.len();
+        result.tables.push(table);
+    }
+    result.errors.extend(parsed.errors);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statements(sql: &str) -> Vec<SqlStatement> {
+        SqlStatementReader::new(sql.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_statement_reader_splits_statements() {
+        let sql = "-- header comment\nCREATE TABLE a (id INT);\n/* block; comment */\nCREATE TABLE b (name VARCHAR(10) DEFAULT 'x;y');";
+        let stmts = statements(sql);
+        assert_eq!(
+            stmts,
+            vec![
+                SqlStatement::Statement("CREATE TABLE a (id INT)".to_string()),
+                SqlStatement::Statement(
+                    "CREATE TABLE b (name VARCHAR(10) DEFAULT 'x;y')".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_statement_reader_skips_data() {
+        let sql = "INSERT INTO a VALUES (1, 'it''s; fine'), (2, 'b');\nCOPY public.a (id) FROM stdin;\n1\n2;3\n\\.\nCREATE TABLE c (id INT);";
+        let stmts = statements(sql);
+        assert_eq!(
+            stmts,
+            vec![
+                SqlStatement::Skipped {
+                    keyword: "INSERT".to_string()
+                },
+                SqlStatement::Skipped {
+                    keyword: "COPY".to_string()
+                },
+                SqlStatement::Statement("CREATE TABLE c (id INT)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_statement_reader_dollar_quotes() {
+        let sql = "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql;\nCREATE TABLE t (id INT);";
+        let stmts = statements(sql);
+        assert_eq!(stmts.len(), 2);
+        assert!(matches!(&stmts[0], SqlStatement::Statement(s) if s.ends_with("LANGUAGE sql")));
+    }
+
+    #[test]
+    fn test_statement_reader_backslashes() {
+        // Backslashes are literal in standard strings, but escape in E'' strings
+        let sql = "CREATE TABLE a (p TEXT DEFAULT 'C:\\');\nCREATE TABLE b (q TEXT DEFAULT E'it\\'s;');\nCREATE TABLE c (id INT);";
+        let stmts = statements(sql);
+        assert_eq!(stmts.len(), 3);
+        assert_eq!(
+            stmts[1],
+            SqlStatement::Statement("CREATE TABLE b (q TEXT DEFAULT E'it\\'s;')".to_string())
+        );
+
+        let mysql = "INSERT INTO a VALUES ('it\\'s; fine');\nCREATE TABLE c (id INT);";
+        let stmts = SqlStatementReader::new(mysql.as_bytes())
+            .with_backslash_escapes(true)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(stmts.len(), 2);
+        assert_eq!(
+            stmts[1],
+            SqlStatement::Statement("CREATE TABLE c (id INT)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_statement_reader_reads_long_lines_in_chunks() {
+        let values = "(1, 'ü'), ".repeat(CHUNK_SIZE);
+        let sql = format!(
+            "INSERT INTO a VALUES {values}(2, 'x'); CREATE FUNCTION f() AS $body$ SELECT 1; $body$ LANGUAGE sql; CREATE TABLE c (id INT);"
+        );
+        let stmts = statements(&sql);
+        assert_eq!(stmts.len(), 3);
+        assert_eq!(
+            stmts[2],
+            SqlStatement::Statement("CREATE TABLE c (id INT)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_statement_reader_caps_statement_length() {
+        let sql = format!("CREATE TABLE a ({});", "c INT, ".repeat(100));
+        let err = SqlStatementReader::new(sql.as_bytes())
+            .with_max_statement_len(64)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_streaming_import() {
+        let dump = r#"
+SET statement_timeout = 0;
+CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(100));
+INSERT INTO users VALUES (1, 'alice'), (2, 'bob');
+CREATE INDEX idx_users_name ON users (name);
+CREATE TABLE orders (id INT PRIMARY KEY, user_id INT);
+"#;
+        let importer = StreamingSqlImporter::new("postgres").with_progress_interval(1);
+        let mut updates = Vec::new();
+        let result = importer
+            .import_reader(dump.as_bytes(), |p| updates.push(p.clone()))
+            .unwrap();
+
+        assert_eq!(result.tables.len(), 2);
+        assert_eq!(result.tables[0].name.as_deref(), Some("users"));
+        assert_eq!(result.tables[1].name.as_deref(), Some("orders"));
+        assert_eq!(result.tables[1].table_index, 1);
+
+        let last = updates.last().unwrap();
+        assert_eq!(last.statements_seen, 5);
+        assert_eq!(last.statements_skipped, 3);
+        assert_eq!(last.tables_found, 2);
+        assert_eq!(last.bytes_read, dump.len() as u64);
+    }
+
+    #[test]
+    fn test_streaming_import_records_parse_errors() {
+        let dump = "CREATE TABLE ok (id INT);\nCREATE TABLE broken (id INT,,);\nCREATE TABLE ok2 (id INT);";
+        let result = StreamingSqlImporter::new("generic")
+            .import_reader(dump.as_bytes(), |_| {})
+            .unwrap();
+        assert_eq!(result.tables.len(), 2);
+        assert_eq!(result.errors.len(), 1);
+    }
+}
@@ -0,0 +1,666 @@
+//! Databricks Unity Catalog table importer
+//!
+//! Reads table metadata from the [Unity Catalog REST API](https://docs.databricks.com/api/workspace/catalogs)
+//! and converts it into ODCS schema objects:
+//!
+//! - **Tables and views** become schema objects named after the table, with the
+//!   three-level name (`catalog.schema.table`) as physical name
+//! - **Columns** become properties with the Unity Catalog type text as physical type;
+//!   primary key constraints and partition columns are preserved
+//! - **Comments** become descriptions
+//! - **Tags** of tables and columns become ODCS tags (`key:value`, or `key` for
+//!   tags without a value)
+//! - Owner, storage location, data source format (e.g. `DELTA`) and table
+//!   properties are kept as custom properties
+//!
+//! [`UnityCatalogImporter::import_schema`] writes one contract per Unity Catalog
+//! schema, holding a schema object for each of its tables.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use super::ImportError;
+use crate::models::IdGenerator;
+use crate::models::odcs::converters::map_data_type_to_logical_type;
+use crate::models::odcs::{CustomProperty, ODCSContract, Property, SchemaObject};
+
+/// Page size requested from list endpoints
+const PAGE_SIZE: usize = 100;
+
+/// A Unity Catalog catalog
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnityCatalogInfo {
+    /// Catalog name
+    pub name: String,
+    /// Catalog comment
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Catalog owner
+    #[serde(default)]
+    pub owner: Option<String>,
+}
+
+/// A Unity Catalog schema
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnitySchemaInfo {
+    /// Schema name
+    pub name: String,
+    /// Catalog name
+    #[serde(default)]
+    pub catalog_name: String,
+    /// Two-level name (`catalog.schema`)
+    #[serde(default)]
+    pub full_name: String,
+    /// Schema comment
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Schema owner
+    #[serde(default)]
+    pub owner: Option<String>,
+}
+
+/// A Unity Catalog table or view
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnityTableInfo {
+    /// Table name
+    pub name: String,
+    /// Catalog name
+    #[serde(default)]
+    pub catalog_name: String,
+    /// Schema name
+    #[serde(default)]
+    pub schema_name: String,
+    /// Three-level name (`catalog.schema.table`)
+    #[serde(default)]
+    pub full_name: String,
+    /// `MANAGED`, `EXTERNAL`, `VIEW`, `MATERIALIZED_VIEW`, ...
+    #[serde(default)]
+    pub table_type: Option<String>,
+    /// `DELTA`, `PARQUET`, `CSV`, ...
+    #[serde(default)]
+    pub data_source_format: Option<String>,
+    /// Table comment
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Table owner
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Storage location of external tables
+    #[serde(default)]
+    pub storage_location: Option<String>,
+    /// Table properties
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+    /// Columns
+    #[serde(default)]
+    pub columns: Vec<UnityColumnInfo>,
+    /// Table constraints
+    #[serde(default)]
+    pub table_constraints: Vec<UnityTableConstraint>,
+}
+
+/// A column of a Unity Catalog table
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnityColumnInfo {
+    /// Column name
+    pub name: String,
+    /// Full type as written in SQL (e.g. `decimal(10,2)`, `array<string>`)
+    #[serde(default)]
+    pub type_text: String,
+    /// Type name (e.g. `DECIMAL`, `ARRAY`)
+    #[serde(default)]
+    pub type_name: Option<String>,
+    /// Ordinal position
+    #[serde(default)]
+    pub position: Option<i32>,
+    /// Column comment
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Whether the column is nullable
+    #[serde(default = "default_nullable")]
+    pub nullable: bool,
+    /// Position of the column in the partition key
+    #[serde(default)]
+    pub partition_index: Option<i32>,
+}
+
+fn default_nullable() -> bool {
+    true
+}
+
+/// A table constraint
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnityTableConstraint {
+    /// Primary key constraint
+    #[serde(default)]
+    pub primary_key_constraint: Option<UnityPrimaryKeyConstraint>,
+}
+
+/// A primary key constraint
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnityPrimaryKeyConstraint {
+    /// Constraint name
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Key columns, in key order
+    #[serde(default)]
+    pub child_columns: Vec<String>,
+}
+
+/// Tags assigned to a table and its columns
+#[derive(Debug, Clone, Default)]
+pub struct UnityTableTags {
+    /// Table tags (key, value)
+    pub table: Vec<(String, String)>,
+    /// Column tags (key, value) by column name
+    pub columns: HashMap<String, Vec<(String, String)>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CatalogList {
+    #[serde(default)]
+    catalogs: Vec<UnityCatalogInfo>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SchemaList {
+    #[serde(default)]
+    schemas: Vec<UnitySchemaInfo>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TableList {
+    #[serde(default)]
+    tables: Vec<UnityTableInfo>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagAssignmentList {
+    #[serde(default)]
+    tag_assignments: Vec<TagAssignment>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagAssignment {
+    tag_key: String,
+    #[serde(default)]
+    tag_value: Option<String>,
+}
+
+/// Importer for Databricks Unity Catalog tables.
+pub struct UnityCatalogImporter {
+    /// Databricks workspace URL (e.g., https://xxx.cloud.databricks.com)
+    workspace_url: String,
+    /// Authentication token
+    token: String,
+    /// Whether to fetch table and column tags
+    include_tags: bool,
+    /// Generator for the IDs of imported contracts
+    ids: IdGenerator,
+    client: reqwest::Client,
+}
+
+impl UnityCatalogImporter {
+    /// Create a new Unity Catalog importer
+    ///
+    /// The token is never logged.
+    pub fn new(workspace_url: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            workspace_url: workspace_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+            include_tags: true,
+            ids: IdGenerator::default(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Create from environment variables
+    ///
+    /// Reads DATABRICKS_HOST and DATABRICKS_TOKEN from environment.
+    pub fn from_env() -> Result<Self, ImportError> {
+        let host = std::env::var("DATABRICKS_HOST").map_err(|_| {
+            ImportError::IoError("DATABRICKS_HOST environment variable not set".to_string())
+        })?;
+        let token = std::env::var("DATABRICKS_TOKEN").map_err(|_| {
+            ImportError::IoError("DATABRICKS_TOKEN environment variable not set".to_string())
+        })?;
+        Ok(Self::new(host, token))
+    }
+
+    /// Set whether to fetch table and column tags (default: true)
+    ///
+    /// Tags need one request per table and column.
+    pub fn with_tags(mut self, include_tags: bool) -> Self {
+        self.include_tags = include_tags;
+        self
+    }
+
+    /// Generate contract IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// List the catalogs visible to the token
+    pub async fn list_catalogs(&self) -> Result<Vec<UnityCatalogInfo>, ImportError> {
+        let mut catalogs = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page: CatalogList = self.get("catalogs", &[], page_token.as_deref()).await?;
+            catalogs.extend(page.catalogs);
+            page_token = page.next_page_token.filter(|t| !t.is_empty());
+            if page_token.is_none() {
+                return Ok(catalogs);
+            }
+        }
+    }
+
+    /// List the schemas of a catalog
+    pub async fn list_schemas(&self, catalog: &str) -> Result<Vec<UnitySchemaInfo>, ImportError> {
+        let mut schemas = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page: SchemaList = self
+                .get(
+                    "schemas",
+                    &[("catalog_name", catalog)],
+                    page_token.as_deref(),
+                )
+                .await?;
+            schemas.extend(page.schemas);
+            page_token = page.next_page_token.filter(|t| !t.is_empty());
+            if page_token.is_none() {
+                return Ok(schemas);
+            }
+        }
+    }
+
+    /// List the tables of a schema, with their columns
+    pub async fn list_tables(
+        &self,
+        catalog: &str,
+        schema: &str,
+    ) -> Result<Vec<UnityTableInfo>, ImportError> {
+        let mut tables = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page: TableList = self
+                .get(
+                    "tables",
+                    &[("catalog_name", catalog), ("schema_name", schema)],
+                    page_token.as_deref(),
+                )
+                .await?;
+            tables.extend(page.tables);
+            page_token = page.next_page_token.filter(|t| !t.is_empty());
+            if page_token.is_none() {
+                return Ok(tables);
+            }
+        }
+    }
+
+    /// Get a table by its three-level name
+    pub async fn get_table(&self, full_name: &str) -> Result<UnityTableInfo, ImportError> {
+        self.get(
+            &format!("tables/{}", urlencoding::encode(full_name)),
+            &[],
+            None,
+        )
+        .await
+    }
+
+    /// Get the tags of a table and its columns
+    pub async fn get_table_tags(
+        &self,
+        table: &UnityTableInfo,
+    ) -> Result<UnityTableTags, ImportError> {
+        let mut tags = UnityTableTags {
+            table: self.get_tags("tables", &table.full_name).await?,
+            ..Default::default()
+        };
+        for column in &table.columns {
+            let column_tags = self
+                .get_tags("columns", &format!("{}.{}", table.full_name, column.name))
+                .await?;
+            if !column_tags.is_empty() {
+                tags.columns.insert(column.name.clone(), column_tags);
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Import a table as a schema object
+    pub async fn import_table(&self, full_name: &str) -> Result<SchemaObject, ImportError> {
+        let table = self.get_table(full_name).await?;
+        let tags = if self.include_tags {
+            self.get_table_tags(&table).await?
+        } else {
+            UnityTableTags::default()
+        };
+        Ok(table_to_schema_object(&table, &tags))
+    }
+
+    /// Import the tables of a schema as a contract
+    ///
+    /// The contract is named after the schema (`catalog.schema`) and holds one schema
+    /// object per table.
+    pub async fn import_schema(
+        &self,
+        catalog: &str,
+        schema: &str,
+    ) -> Result<ODCSContract, ImportError> {
+        let mut schema_objects = Vec::new();
+        for table in self.list_tables(catalog, schema).await? {
+            let tags = if self.include_tags {
+                self.get_table_tags(&table).await?
+            } else {
+                UnityTableTags::default()
+            };
+            schema_objects.push(table_to_schema_object(&table, &tags));
+        }
+
+        let full_name = format!("{}.{}", catalog, schema);
+        let mut contract = ODCSContract::new_with_generator(full_name.clone(), "1.0.0", &self.ids)
+            .with_status("draft")
+            .with_domain(schema)
+            .with_schemas(schema_objects)
+            .with_custom_property(CustomProperty::string("unityCatalogSchema", full_name));
+        if let Some(info) = self
+            .list_schemas(catalog)
+            .await?
+            .into_iter()
+            .find(|s| s.name == schema)
+            && let Some(comment) = info.comment.filter(|c| !c.is_empty())
+        {
+            contract = contract.with_description(comment);
+        }
+        Ok(contract)
+    }
+
+    /// Import every schema of a catalog, one contract per schema
+    ///
+    /// Skips `information_schema`.
+    pub async fn import_catalog(&self, catalog: &str) -> Result<Vec<ODCSContract>, ImportError> {
+        let mut contracts = Vec::new();
+        for schema in self.list_schemas(catalog).await? {
+            if schema.name == "information_schema" {
+                continue;
+            }
+            contracts.push(self.import_schema(catalog, &schema.name).await?);
+        }
+        Ok(contracts)
+    }
+
+    /// Tags assigned to a table or column
+    async fn get_tags(
+        &self,
+        entity_type: &str,
+        entity_name: &str,
+    ) -> Result<Vec<(String, String)>, ImportError> {
+        let path = format!(
+            "entity-tag-assignments/{}/{}/tags",
+            entity_type,
+            urlencoding::encode(entity_name)
+        );
+        let mut tags = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page: TagAssignmentList = self.get(&path, &[], page_token.as_deref()).await?;
+            tags.extend(
+                page.tag_assignments
+                    .into_iter()
+                    .map(|tag| (tag.tag_key, tag.tag_value.unwrap_or_default())),
+            );
+            page_token = page.next_page_token.filter(|t| !t.is_empty());
+            if page_token.is_none() {
+                return Ok(tags);
+            }
+        }
+    }
+
+    /// GET a Unity Catalog API endpoint
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        page_token: Option<&str>,
+    ) -> Result<T, ImportError> {
+        let url = format!("{}/api/2.1/unity-catalog/{}", self.workspace_url, path);
+        let page_size = PAGE_SIZE.to_string();
+        let mut request = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .query(query);
+        if !path.starts_with("tables/") {
+            request = request.query(&[("max_results", page_size.as_str())]);
+        }
+        if let Some(page_token) = page_token {
+            request = request.query(&[("page_token", page_token)]);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ImportError::IoError(format!("Unity Catalog request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ImportError::IoError(format!(
+                "Unity Catalog request to {} failed: HTTP {}: {}",
+                path, status, body
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ImportError::ParseError(format!("Invalid Unity Catalog response: {}", e)))
+    }
+}
+
+// Implement Debug manually to prevent accidental token logging
+impl std::fmt::Debug for UnityCatalogImporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnityCatalogImporter")
+            .field("workspace_url", &self.workspace_url)
+            .field("include_tags", &self.include_tags)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Convert a Unity Catalog table into an ODCS schema object
+pub fn table_to_schema_object(table: &UnityTableInfo, tags: &UnityTableTags) -> SchemaObject {
+    let primary_key: Vec<&str> = table
+        .table_constraints
+        .iter()
+        .filter_map(|c| c.primary_key_constraint.as_ref())
+        .flat_map(|pk| pk.child_columns.iter().map(String::as_str))
+        .collect();
+
+    let mut columns: Vec<&UnityColumnInfo> = table.columns.iter().collect();
+    columns.sort_by_key(|c| c.position.unwrap_or(i32::MAX));
+    let properties = columns
+        .into_iter()
+        .map(|column| {
+            let column_tags = tags
+                .columns
+                .get(&column.name)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            column_to_property(column, &primary_key, column_tags)
+        })
+        .collect();
+
+    let physical_type = match table.table_type.as_deref() {
+        Some("VIEW") => "view",
+        Some("MATERIALIZED_VIEW") => "materialized_view",
+        _ => "table",
+    };
+    let physical_name = if table.full_name.is_empty() {
+        table.name.clone()
+    } else {
+        table.full_name.clone()
+    };
+
+    let mut schema = SchemaObject::new(&table.name)
+        .with_physical_name(physical_name)
+        .with_physical_type(physical_type)
+        .with_properties(properties)
+        .with_tags(tags.table.iter().map(|(k, v)| format_tag(k, v)).collect());
+    if let Some(comment) = table.comment.as_ref().filter(|c| !c.is_empty()) {
+        schema = schema.with_description(comment);
+    }
+    if let Some(table_type) = &table.table_type {
+        schema = schema.with_custom_property(CustomProperty::string("tableType", table_type));
+    }
+    if let Some(format) = &table.data_source_format {
+        schema = schema.with_custom_property(CustomProperty::string("dataSourceFormat", format));
+    }
+    if let Some(owner) = &table.owner {
+        schema = schema.with_custom_property(CustomProperty::string("owner", owner));
+    }
+    if let Some(location) = &table.storage_location {
+        schema = schema.with_custom_property(CustomProperty::string("storageLocation", location));
+    }
+    if !table.properties.is_empty() {
+        schema = schema.with_custom_property(CustomProperty::new(
+            "tableProperties",
+            serde_json::json!(table.properties),
+        ));
+    }
+    schema
+}
+
+/// Convert a Unity Catalog column into an ODCS property
+fn column_to_property(
+    column: &UnityColumnInfo,
+    primary_key: &[&str],
+    tags: &[(String, String)],
+) -> Property {
+    let (logical_type, _) = map_data_type_to_logical_type(&column.type_text);
+    let mut property = Property::new(&column.name, logical_type)
+        .with_physical_type(&column.type_text)
+        .with_required(!column.nullable);
+    if let Some(position) = primary_key.iter().position(|c| *c == column.name) {
+        property = property
+            .with_primary_key(true)
+            .with_primary_key_position(position as i32 + 1);
+    }
+    if let Some(index) = column.partition_index {
+        property.partitioned = true;
+        property.partition_key_position = Some(index + 1);
+    }
+    if let Some(comment) = column.comment.as_ref().filter(|c| !c.is_empty()) {
+        property = property.with_description(comment);
+    }
+    for (key, value) in tags {
+        property = property.with_tag(format_tag(key, value));
+    }
+    property
+}
+
+/// Format a Unity Catalog tag as an ODCS tag
+fn format_tag(key: &str, value: &str) -> String {
+    if value.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}:{}", key, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE_JSON: &str = r#"{
+        "name": "orders",
+        "catalog_name": "main",
+        "schema_name": "sales",
+        "full_name": "main.sales.orders",
+        "table_type": "MANAGED",
+        "data_source_format": "DELTA",
+        "comment": "Customer orders",
+        "owner": "sales-data",
+        "properties": {"delta.minReaderVersion": "1"},
+        "columns": [
+            {"name": "order_date", "type_text": "date", "type_name": "DATE", "position": 2, "nullable": false, "partition_index": 0},
+            {"name": "id", "type_text": "bigint", "type_name": "LONG", "position": 0, "nullable": false, "comment": "Order id"},
+            {"name": "total", "type_text": "decimal(10,2)", "type_name": "DECIMAL", "position": 1, "nullable": true},
+            {"name": "lines", "type_text": "array<struct<sku:string,qty:int>>", "type_name": "ARRAY", "position": 3}
+        ],
+        "table_constraints": [
+            {"primary_key_constraint": {"name": "orders_pk", "child_columns": ["id"]}}
+        ]
+    }"#;
+
+    #[test]
+    fn test_table_to_schema_object() {
+        let table: UnityTableInfo = serde_json::from_str(TABLE_JSON).unwrap();
+        let tags = UnityTableTags {
+            table: vec![("certified".to_string(), String::new())],
+            columns: HashMap::from([(
+                "total".to_string(),
+                vec![("pii".to_string(), "false".to_string())],
+            )]),
+        };
+
+        let schema = table_to_schema_object(&table, &tags);
+        assert_eq!(schema.name, "orders");
+        assert_eq!(schema.physical_name.as_deref(), Some("main.sales.orders"));
+        assert_eq!(schema.physical_type.as_deref(), Some("table"));
+        assert_eq!(schema.description.as_deref(), Some("Customer orders"));
+        assert_eq!(schema.tags, vec!["certified"]);
+
+        let names: Vec<&str> = schema.properties.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "total", "order_date", "lines"]);
+
+        let id = &schema.properties[0];
+        assert!(id.primary_key && id.required);
+        assert_eq!(id.primary_key_position, Some(1));
+        assert_eq!(id.logical_type, "integer");
+        assert_eq!(id.description.as_deref(), Some("Order id"));
+
+        let total = &schema.properties[1];
+        assert_eq!(total.logical_type, "number");
+        assert_eq!(total.physical_type.as_deref(), Some("decimal(10,2)"));
+        assert_eq!(total.tags, vec!["pii:false"]);
+        assert!(!total.required);
+
+        assert!(schema.properties[2].partitioned);
+        assert_eq!(schema.properties[2].partition_key_position, Some(1));
+        assert_eq!(schema.properties[3].logical_type, "array");
+        assert!(!schema.properties[3].required);
+
+        let custom: Vec<&str> = schema
+            .custom_properties
+            .iter()
+            .map(|p| p.property.as_str())
+            .collect();
+        assert_eq!(
+            custom,
+            vec!["tableType", "dataSourceFormat", "owner", "tableProperties"]
+        );
+    }
+
+    #[test]
+    fn test_view_without_full_name() {
+        let table: UnityTableInfo =
+            serde_json::from_str(r#"{"name": "daily_totals", "table_type": "VIEW"}"#).unwrap();
+        let schema = table_to_schema_object(&table, &UnityTableTags::default());
+        assert_eq!(schema.physical_name.as_deref(), Some("daily_totals"));
+        assert_eq!(schema.physical_type.as_deref(), Some("view"));
+        assert!(schema.properties.is_empty());
+    }
+}
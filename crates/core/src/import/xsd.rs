@@ -0,0 +1,1094 @@
+//! XML Schema (XSD) parser for importing XML interfaces into data models.
+//!
+//! Global elements become tables whose columns describe the XML structure, so
+//! XML-based interfaces (for example ISO 20022 messages) can be modelled like any
+//! other schema:
+//!
+//! - Complex types become nested columns using the ODCS naming convention: an
+//!   `OBJECT` column with `parent.child` columns, or an `ARRAY<OBJECT>` column with
+//!   `parent.[].child` columns for repeated elements
+//! - `minOccurs="0"`, `choice` branches and optional attributes are nullable;
+//!   `maxOccurs` above one (or `unbounded`) turns the column into an array
+//! - Named simple types resolve to their built-in base type; enumerations become enum
+//!   values and length, pattern, range and digit facets become `logicalTypeOptions`
+//! - `complexContent` extensions inherit the content of their base type, and
+//!   `simpleContent` becomes an `OBJECT` with a `value` column next to its attributes
+//!
+//! Schemas without global elements produce one table per named complex type.
+//! `xs:include` and `xs:import` are not followed: types they define are imported as
+//! strings and reported as warnings.
+
+use crate::import::odcs_shared::column_to_column_data;
+use crate::import::{ImportError, ImportResult, TableData, check_size, unmapped_type_warnings};
+use crate::models::type_mapping::source_type_property;
+use crate::models::{
+    Column, IdGenerator, LogicalTypeOptions, SOURCE_TYPE_PROPERTY, Table, TypeMappingRegistry,
+};
+use crate::validation::input::{validate_column_name, validate_table_name};
+use crate::validation::limits::ResourceLimits;
+use anyhow::{Context, Result};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Maximum number of simple type derivations followed before giving up
+const MAX_DERIVATION_DEPTH: usize = 32;
+
+/// Parser for XML Schema (XSD) documents.
+#[derive(Default)]
+pub struct XsdImporter {
+    /// Type mappings (the built-in mappings when `None`)
+    type_mappings: Option<Arc<TypeMappingRegistry>>,
+    limits: ResourceLimits,
+    /// Generator for the IDs of imported tables
+    ids: IdGenerator,
+}
+
+impl XsdImporter {
+    /// Create a new XSD parser instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::xsd::XsdImporter;
+    ///
+    /// let importer = XsdImporter::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`ResourceLimits`] for XSD schemas
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Generate table IDs following a workspace's ID strategy
+    pub fn with_id_generator(mut self, ids: IdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Map XSD built-in types with `type_mappings` instead of the built-in mappings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::xsd::XsdImporter;
+    /// use data_modelling_core::models::TypeMappingRegistry;
+    ///
+    /// let mut mappings = TypeMappingRegistry::default();
+    /// mappings.set_import("xsd", "integer", "NUMBER(38)");
+    /// let importer = XsdImporter::new().with_type_mappings(mappings);
+    /// ```
+    pub fn with_type_mappings(
+        mut self,
+        type_mappings: impl Into<Arc<TypeMappingRegistry>>,
+    ) -> Self {
+        self.type_mappings = Some(type_mappings.into());
+        self
+    }
+
+    /// Import an XSD document and create Table(s) (SDK interface).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::import::xsd::XsdImporter;
+    ///
+    /// let xsd = r#"
+    /// <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    ///   <xs:element name="Customer">
+    ///     <xs:complexType>
+    ///       <xs:sequence>
+    ///         <xs:element name="Id" type="xs:long"/>
+    ///         <xs:element name="Email" type="xs:string" minOccurs="0" maxOccurs="unbounded"/>
+    ///       </xs:sequence>
+    ///     </xs:complexType>
+    ///   </xs:element>
+    /// </xs:schema>
+    /// "#;
+    /// let result = XsdImporter::new().import(xsd).unwrap();
+    /// assert_eq!(result.tables[0].columns[1].data_type, "ARRAY<STRING>");
+    /// ```
+    pub fn import(&self, xsd_content: &str) -> Result<ImportResult, ImportError> {
+        check_size(&self.limits, xsd_content)?;
+        match self.parse(xsd_content) {
+            Ok((tables, mut errors)) => {
+                let sdk_tables: Vec<TableData> = tables
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, table)| TableData {
+                        table_index: idx,
+                        id: Some(table.id.to_string()),
+                        name: Some(table.name.clone()),
+                        columns: table.columns.iter().map(column_to_column_data).collect(),
+                        ..Default::default()
+                    })
+                    .collect();
+                errors.extend(unmapped_type_warnings("xsd", &sdk_tables));
+                Ok(ImportResult {
+                    tables: sdk_tables,
+                    tables_requiring_name: Vec::new(),
+                    errors,
+                    ai_suggestions: None,
+                })
+            }
+            Err(e) => Err(ImportError::ParseError(e.to_string())),
+        }
+    }
+
+    /// Parse XSD content and create Table(s) (internal method).
+    fn parse(&self, xsd_content: &str) -> Result<(Vec<Table>, Vec<ImportError>)> {
+        let root = parse_tree(xsd_content)?;
+        if root.name != "schema" {
+            return Err(anyhow::anyhow!(
+                "Expected an xs:schema root element, found '{}'",
+                root.name
+            ));
+        }
+
+        let schema = Schema::new(&root);
+        let mut builder = ColumnBuilder {
+            importer: self,
+            schema: &schema,
+            errors: Vec::new(),
+            visiting: Vec::new(),
+        };
+
+        let mut tables = Vec::new();
+        if schema.elements.is_empty() {
+            for (name, complex_type) in &schema.complex_types {
+                let mut columns = Vec::new();
+                builder.visiting.push(name.to_string());
+                builder.complex_columns(complex_type, "", &mut columns);
+                builder.visiting.pop();
+                tables.push(self.table(name, documentation(complex_type), columns, &schema));
+            }
+        } else {
+            for element in root.children_named("element") {
+                let Some(name) = element.attr("name") else {
+                    continue;
+                };
+                let columns = builder.root_columns(element);
+                tables.push(self.table(name, documentation(element), columns, &schema));
+            }
+        }
+
+        Ok((tables, builder.errors))
+    }
+
+    fn table(
+        &self,
+        name: &str,
+        description: Option<String>,
+        columns: Vec<Column>,
+        schema: &Schema<'_>,
+    ) -> Table {
+        if let Err(e) = validate_table_name(name) {
+            warn!("Table name validation warning for '{}': {}", name, e);
+        }
+
+        let mut table = Table::new_with_generator(name.to_string(), columns, &self.ids);
+        if let Some(namespace) = schema.target_namespace {
+            table
+                .odcl_metadata
+                .insert("namespace".to_string(), json!(namespace));
+        }
+        if let Some(description) = description {
+            table
+                .odcl_metadata
+                .insert("description".to_string(), json!(description));
+        }
+
+        info!(
+            "Parsed XSD element: {} with {} columns",
+            name,
+            table.columns.len()
+        );
+        table
+    }
+
+    fn type_mappings(&self) -> &TypeMappingRegistry {
+        self.type_mappings
+            .as_deref()
+            .unwrap_or(TypeMappingRegistry::builtin())
+    }
+}
+
+/// An XML element of the schema document
+#[derive(Debug, Default)]
+struct Node {
+    /// Local name, without namespace prefix
+    name: String,
+    /// Namespace prefix of the element name
+    prefix: String,
+    attributes: HashMap<String, String>,
+    children: Vec<Node>,
+    text: String,
+}
+
+impl Node {
+    fn from_start(start: &BytesStart<'_>) -> Result<Self> {
+        let qname = start.name();
+        let mut node = Node {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).to_string(),
+            prefix: qname
+                .prefix()
+                .map(|prefix| String::from_utf8_lossy(prefix.as_ref()).to_string())
+                .unwrap_or_default(),
+            ..Default::default()
+        };
+        for attribute in start.attributes() {
+            let attribute = attribute.context("Invalid XML attribute")?;
+            node.attributes.insert(
+                String::from_utf8_lossy(attribute.key.local_name().as_ref()).to_string(),
+                attribute
+                    .unescape_value()
+                    .context("Invalid XML attribute value")?
+                    .to_string(),
+            );
+        }
+        Ok(node)
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+
+    fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Node> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/// Parse an XML document into a tree of nodes, returning the root element
+fn parse_tree(content: &str) -> Result<Node> {
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+
+    let mut stack: Vec<Node> = Vec::new();
+    loop {
+        match reader.read_event().context("Failed to parse XSD as XML")? {
+            Event::Start(start) => stack.push(Node::from_start(&start)?),
+            Event::Empty(start) => {
+                let node = Node::from_start(&start)?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => return Ok(node),
+                }
+            }
+            Event::End(_) => {
+                let node = stack
+                    .pop()
+                    .ok_or_else(|| anyhow::anyhow!("Unbalanced XML end tag"))?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => return Ok(node),
+                }
+            }
+            Event::Text(text) => {
+                if let Some(node) = stack.last_mut() {
+                    node.text
+                        .push_str(&text.unescape().context("Invalid XML text")?);
+                }
+            }
+            Event::CData(data) => {
+                if let Some(node) = stack.last_mut() {
+                    node.text
+                        .push_str(&String::from_utf8_lossy(&data.into_inner()));
+                }
+            }
+            Event::Eof => return Err(anyhow::anyhow!("XSD document has no root element")),
+            _ => {}
+        }
+    }
+}
+
+/// Global definitions of a schema, keyed by local name
+struct Schema<'a> {
+    /// Prefix bound to the XML Schema namespace (e.g. `xs`)
+    xsd_prefix: &'a str,
+    target_namespace: Option<&'a str>,
+    elements: Vec<(&'a str, &'a Node)>,
+    complex_types: Vec<(&'a str, &'a Node)>,
+    simple_types: HashMap<&'a str, &'a Node>,
+    groups: HashMap<&'a str, &'a Node>,
+    attribute_groups: HashMap<&'a str, &'a Node>,
+    attributes: HashMap<&'a str, &'a Node>,
+}
+
+impl<'a> Schema<'a> {
+    fn new(root: &'a Node) -> Self {
+        let named = |kind: &str| -> Vec<(&'a str, &'a Node)> {
+            root.children
+                .iter()
+                .filter(|child| child.name == kind)
+                .filter_map(|child| child.attr("name").map(|name| (name, child)))
+                .collect()
+        };
+        Self {
+            xsd_prefix: &root.prefix,
+            target_namespace: root.attr("targetNamespace"),
+            elements: named("element"),
+            complex_types: named("complexType"),
+            simple_types: named("simpleType").into_iter().collect(),
+            groups: named("group").into_iter().collect(),
+            attribute_groups: named("attributeGroup").into_iter().collect(),
+            attributes: named("attribute").into_iter().collect(),
+        }
+    }
+
+    fn element(&self, qname: &str) -> Option<&'a Node> {
+        lookup(&self.elements, local_name(qname))
+    }
+
+    fn complex_type(&self, qname: &str) -> Option<&'a Node> {
+        lookup(&self.complex_types, local_name(qname))
+    }
+
+    /// Whether a type reference names an XML Schema built-in type
+    fn is_builtin(&self, qname: &str) -> bool {
+        match qname.split_once(':') {
+            Some((prefix, _)) => prefix == self.xsd_prefix,
+            None => {
+                self.xsd_prefix.is_empty()
+                    && !self.simple_types.contains_key(qname)
+                    && self.complex_type(qname).is_none()
+            }
+        }
+    }
+}
+
+fn lookup<'a>(definitions: &[(&str, &'a Node)], name: &str) -> Option<&'a Node> {
+    definitions
+        .iter()
+        .find(|(defined, _)| *defined == name)
+        .map(|(_, node)| *node)
+}
+
+/// Resolved simple type of a column
+#[derive(Default)]
+struct SimpleType {
+    data_type: String,
+    /// Name of the referenced XSD type
+    physical_type: Option<String>,
+    options: LogicalTypeOptions,
+    enum_values: Vec<String>,
+    /// Built-in type without a mapping, preserved on the column
+    unmapped: Option<String>,
+}
+
+struct ColumnBuilder<'s, 'a> {
+    importer: &'s XsdImporter,
+    schema: &'s Schema<'a>,
+    errors: Vec<ImportError>,
+    /// Complex types being expanded, to stop on recursive structures
+    visiting: Vec<String>,
+}
+
+impl<'a> ColumnBuilder<'_, 'a> {
+    /// Columns of a table created from a global element
+    fn root_columns(&mut self, element: &'a Node) -> Vec<Column> {
+        let mut columns = Vec::new();
+        let name = element.attr("name").unwrap_or_default();
+        match self.element_content(element) {
+            Content::Complex(complex_type, type_name) => {
+                self.visiting.push(type_name.unwrap_or(name).to_string());
+                self.complex_columns(complex_type, "", &mut columns);
+                self.visiting.pop();
+            }
+            Content::Simple(simple) => {
+                columns.push(simple_column(name.to_string(), *simple, false, false, None))
+            }
+        }
+        columns
+    }
+
+    /// Columns for the content of a complex type, prefixed with `prefix`
+    fn complex_columns(&mut self, complex_type: &'a Node, prefix: &str, columns: &mut Vec<Column>) {
+        for child in &complex_type.children {
+            match child.name.as_str() {
+                "sequence" | "all" | "choice" | "group" => {
+                    self.particle_columns(child, prefix, false, columns)
+                }
+                "attribute" | "attributeGroup" => self.attribute_columns(child, prefix, columns),
+                "complexContent" => {
+                    for derivation in &child.children {
+                        if derivation.name == "extension"
+                            && let Some(base) = derivation.attr("base")
+                        {
+                            self.base_columns(base, prefix, columns);
+                        }
+                        self.complex_columns(derivation, prefix, columns);
+                    }
+                }
+                "simpleContent" => {
+                    for derivation in &child.children {
+                        let mut simple = match derivation.attr("base") {
+                            Some(base) => self.simple_content_base(base),
+                            None => self.resolve_simple(None, 0),
+                        };
+                        apply_facets(&mut simple, derivation);
+                        columns.push(simple_column(
+                            child_name(prefix, "value"),
+                            simple,
+                            false,
+                            false,
+                            None,
+                        ));
+                        self.complex_columns(derivation, prefix, columns);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Columns of the base type of a `complexContent` extension
+    fn base_columns(&mut self, base: &str, prefix: &str, columns: &mut Vec<Column>) {
+        let name = local_name(base);
+        if self.schema.is_builtin(base) || self.visiting.iter().any(|v| v == name) {
+            return;
+        }
+        match self.schema.complex_type(base) {
+            Some(base_type) => {
+                self.visiting.push(name.to_string());
+                self.complex_columns(base_type, prefix, columns);
+                self.visiting.pop();
+            }
+            None => self.warn(format!("Base type '{}' is not defined in the schema", base)),
+        }
+    }
+
+    /// Value type of `simpleContent` whose base may itself be a complex type
+    fn simple_content_base(&mut self, base: &str) -> SimpleType {
+        if !self.schema.is_builtin(base)
+            && let Some(base_type) = self.schema.complex_type(base)
+            && let Some(derivation) = base_type
+                .child("simpleContent")
+                .and_then(|content| content.children.first())
+        {
+            let mut simple = match derivation.attr("base") {
+                Some(inner) if local_name(inner) != local_name(base) => {
+                    self.simple_content_base(inner)
+                }
+                _ => self.resolve_simple(None, 0),
+            };
+            apply_facets(&mut simple, derivation);
+            return simple;
+        }
+        self.resolve_simple(Some(base), 0)
+    }
+
+    /// Columns of the elements in a model group (`sequence`, `choice`, `all`, `group`)
+    fn particle_columns(
+        &mut self,
+        group: &'a Node,
+        prefix: &str,
+        optional: bool,
+        columns: &mut Vec<Column>,
+    ) {
+        let optional = optional || group.attr("minOccurs") == Some("0");
+        if group.name == "group" {
+            if let Some(reference) = group.attr("ref") {
+                match self.schema.groups.get(local_name(reference)) {
+                    Some(definition) => {
+                        for child in &definition.children {
+                            self.particle_columns(child, prefix, optional, columns);
+                        }
+                    }
+                    None => self.warn(format!(
+                        "Group '{}' is not defined in the schema",
+                        reference
+                    )),
+                }
+            }
+            return;
+        }
+
+        // Only one branch of a choice is present in a document
+        let branch_optional = optional || group.name == "choice";
+        for child in &group.children {
+            match child.name.as_str() {
+                "element" => self.element_columns(child, prefix, branch_optional, columns),
+                "sequence" | "choice" | "all" | "group" => {
+                    self.particle_columns(child, prefix, branch_optional, columns)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Columns of a local element (and its nested content)
+    fn element_columns(
+        &mut self,
+        element: &'a Node,
+        prefix: &str,
+        optional: bool,
+        columns: &mut Vec<Column>,
+    ) {
+        // Occurrences are taken from the reference, everything else from the declaration
+        let declaration = match element.attr("ref") {
+            Some(reference) => match self.schema.element(reference) {
+                Some(declaration) => declaration,
+                None => {
+                    self.warn(format!(
+                        "Element '{}' is not defined in the schema",
+                        reference
+                    ));
+                    return;
+                }
+            },
+            None => element,
+        };
+        let Some(name) = declaration.attr("name") else {
+            return;
+        };
+        if let Err(e) = validate_column_name(name) {
+            warn!("Column name validation warning for '{}': {}", name, e);
+        }
+
+        let column_name = child_name(prefix, name);
+        let nullable = optional
+            || element.attr("minOccurs") == Some("0")
+            || declaration.attr("nillable") == Some("true");
+        let repeated = element
+            .attr("maxOccurs")
+            .is_some_and(|max| max == "unbounded" || max.parse::<u64>().is_ok_and(|n| n > 1));
+        let description = documentation(element).or_else(|| documentation(declaration));
+
+        match self.element_content(declaration) {
+            Content::Simple(simple) => {
+                let mut column =
+                    simple_column(column_name, *simple, nullable, repeated, description);
+                column.default_value = declaration
+                    .attr("default")
+                    .or(declaration.attr("fixed"))
+                    .map(|value| json!(value));
+                columns.push(column);
+            }
+            Content::Complex(complex_type, type_name) => {
+                let data_type = if repeated { "ARRAY<OBJECT>" } else { "OBJECT" };
+                columns.push(Column {
+                    name: column_name.clone(),
+                    data_type: data_type.to_string(),
+                    physical_type: type_name.map(String::from),
+                    nullable,
+                    description: description.unwrap_or_default(),
+                    ..Default::default()
+                });
+
+                if let Some(type_name) = type_name
+                    && self.visiting.iter().any(|v| v == type_name)
+                {
+                    self.warn(format!(
+                        "Recursive type '{}' of '{}' is not expanded",
+                        type_name, column_name
+                    ));
+                    return;
+                }
+
+                let nested_prefix = if repeated {
+                    format!("{}.[]", column_name)
+                } else {
+                    column_name
+                };
+                if let Some(type_name) = type_name {
+                    self.visiting.push(type_name.to_string());
+                    self.complex_columns(complex_type, &nested_prefix, columns);
+                    self.visiting.pop();
+                } else {
+                    self.complex_columns(complex_type, &nested_prefix, columns);
+                }
+            }
+        }
+    }
+
+    /// Columns of an attribute or attribute group
+    fn attribute_columns(&mut self, attribute: &'a Node, prefix: &str, columns: &mut Vec<Column>) {
+        if attribute.name == "attributeGroup" {
+            let Some(reference) = attribute.attr("ref") else {
+                return;
+            };
+            match self.schema.attribute_groups.get(local_name(reference)) {
+                Some(group) => {
+                    for child in &group.children {
+                        self.attribute_columns(child, prefix, columns);
+                    }
+                }
+                None => self.warn(format!(
+                    "Attribute group '{}' is not defined in the schema",
+                    reference
+                )),
+            }
+            return;
+        }
+        if attribute.name != "attribute" || attribute.attr("use") == Some("prohibited") {
+            return;
+        }
+
+        let declaration = attribute
+            .attr("ref")
+            .and_then(|reference| self.schema.attributes.get(local_name(reference)).copied())
+            .unwrap_or(attribute);
+        let Some(name) = declaration.attr("name") else {
+            return;
+        };
+
+        let simple = match declaration.child("simpleType") {
+            Some(inline) => self.simple_type_definition(inline, None, 0),
+            None => self.resolve_simple(declaration.attr("type"), 0),
+        };
+        let nullable = attribute.attr("use") != Some("required");
+        let mut column = simple_column(
+            child_name(prefix, name),
+            simple,
+            nullable,
+            false,
+            documentation(attribute).or_else(|| documentation(declaration)),
+        );
+        column.default_value = declaration
+            .attr("default")
+            .or(declaration.attr("fixed"))
+            .map(|value| json!(value));
+        columns.push(column);
+    }
+
+    /// Content model of an element declaration
+    fn element_content(&mut self, declaration: &'a Node) -> Content<'a> {
+        if let Some(complex_type) = declaration.child("complexType") {
+            return Content::Complex(complex_type, None);
+        }
+        if let Some(simple_type) = declaration.child("simpleType") {
+            return Content::Simple(Box::new(self.simple_type_definition(simple_type, None, 0)));
+        }
+        match declaration.attr("type") {
+            Some(type_name) if !self.schema.is_builtin(type_name) => {
+                match self.schema.complex_type(type_name) {
+                    Some(complex_type) => {
+                        Content::Complex(complex_type, Some(local_name(type_name)))
+                    }
+                    None => Content::Simple(Box::new(self.resolve_simple(Some(type_name), 0))),
+                }
+            }
+            type_name => Content::Simple(Box::new(self.resolve_simple(type_name, 0))),
+        }
+    }
+
+    /// Resolve a simple type reference; no type means `xs:anyType`
+    fn resolve_simple(&mut self, type_name: Option<&str>, depth: usize) -> SimpleType {
+        let Some(type_name) = type_name else {
+            return self.builtin("anyType");
+        };
+        if self.schema.is_builtin(type_name) {
+            return self.builtin(local_name(type_name));
+        }
+        let name = local_name(type_name);
+        match self.schema.simple_types.get(name).copied() {
+            Some(definition) if depth < MAX_DERIVATION_DEPTH => {
+                self.simple_type_definition(definition, Some(name), depth + 1)
+            }
+            Some(_) => {
+                self.warn(format!("Simple type '{}' derives too deeply", type_name));
+                self.builtin("anyType")
+            }
+            None => {
+                self.warn(format!(
+                    "Type '{}' is not defined in the schema and was imported as a string",
+                    type_name
+                ));
+                SimpleType {
+                    physical_type: Some(name.to_string()),
+                    ..self.builtin("string")
+                }
+            }
+        }
+    }
+
+    /// Resolve a `simpleType` definition (restriction, list or union)
+    fn simple_type_definition(
+        &mut self,
+        definition: &'a Node,
+        name: Option<&str>,
+        depth: usize,
+    ) -> SimpleType {
+        let mut simple = if let Some(restriction) = definition.child("restriction") {
+            let mut base = match restriction.child("simpleType") {
+                Some(inline) => self.simple_type_definition(inline, None, depth + 1),
+                None => self.resolve_simple(restriction.attr("base"), depth),
+            };
+            apply_facets(&mut base, restriction);
+            base
+        } else if let Some(list) = definition.child("list") {
+            let item = match list.child("simpleType") {
+                Some(inline) => self.simple_type_definition(inline, None, depth + 1),
+                None => self.resolve_simple(list.attr("itemType"), depth),
+            };
+            SimpleType {
+                data_type: format!("ARRAY<{}>", item.data_type),
+                ..Default::default()
+            }
+        } else {
+            // Unions accept values of several types
+            self.builtin("string")
+        };
+        if let Some(name) = name {
+            simple.physical_type = Some(name.to_string());
+        }
+        simple
+    }
+
+    fn builtin(&self, type_name: &str) -> SimpleType {
+        let mappings = self.importer.type_mappings();
+        SimpleType {
+            data_type: mappings
+                .import_type("xsd", type_name)
+                .unwrap_or_else(|| "STRING".to_string()),
+            unmapped: (!mappings.has_import("xsd", type_name)).then(|| type_name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn warn(&mut self, message: String) {
+        warn!("{}", message);
+        self.errors.push(ImportError::ParseError(message));
+    }
+}
+
+/// Content model of an element
+enum Content<'a> {
+    /// Complex type definition, with its name when it is a named global type
+    Complex(&'a Node, Option<&'a str>),
+    Simple(Box<SimpleType>),
+}
+
+/// Build a column with a simple type
+fn simple_column(
+    name: String,
+    simple: SimpleType,
+    nullable: bool,
+    repeated: bool,
+    description: Option<String>,
+) -> Column {
+    let data_type = if repeated {
+        format!("ARRAY<{}>", simple.data_type)
+    } else {
+        simple.data_type
+    };
+    let mut column = Column {
+        name,
+        data_type,
+        physical_type: simple.physical_type,
+        logical_type_options: (simple.options != LogicalTypeOptions::default())
+            .then_some(simple.options),
+        nullable,
+        description: description.unwrap_or_default(),
+        enum_values: simple.enum_values,
+        ..Default::default()
+    };
+    // Keep built-in types without a mapping so they can be exported again
+    if let Some(xsd_type) = simple.unmapped {
+        column.physical_type.get_or_insert_with(|| xsd_type.clone());
+        column.custom_properties.insert(
+            SOURCE_TYPE_PROPERTY.to_string(),
+            source_type_property("xsd", &xsd_type),
+        );
+    }
+    column
+}
+
+/// Apply the constraining facets of a restriction to a simple type
+fn apply_facets(simple: &mut SimpleType, restriction: &Node) {
+    let options = &mut simple.options;
+    for facet in &restriction.children {
+        let Some(value) = facet.attr("value") else {
+            continue;
+        };
+        match facet.name.as_str() {
+            "enumeration" => simple.enum_values.push(value.to_string()),
+            "length" => {
+                options.min_length = value.parse().ok();
+                options.max_length = value.parse().ok();
+            }
+            "minLength" => options.min_length = value.parse().ok(),
+            "maxLength" => options.max_length = value.parse().ok(),
+            "pattern" => options.pattern = Some(value.to_string()),
+            "minInclusive" => options.minimum = Some(facet_number(value)),
+            "maxInclusive" => options.maximum = Some(facet_number(value)),
+            "minExclusive" => options.exclusive_minimum = Some(facet_number(value)),
+            "maxExclusive" => options.exclusive_maximum = Some(facet_number(value)),
+            "totalDigits" => options.precision = value.parse().ok(),
+            "fractionDigits" => options.scale = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    // Carry digit facets into the decimal type itself
+    if let Some(precision) = options.precision
+        && simple.data_type.to_uppercase().starts_with("DECIMAL")
+    {
+        simple.data_type = format!("DECIMAL({},{})", precision, options.scale.unwrap_or(0));
+    }
+}
+
+fn facet_number(value: &str) -> Value {
+    value
+        .parse::<i64>()
+        .map(Value::from)
+        .or_else(|_| value.parse::<f64>().map(Value::from))
+        .unwrap_or_else(|_| json!(value))
+}
+
+/// Text of the `xs:annotation/xs:documentation` of a declaration
+fn documentation(node: &Node) -> Option<String> {
+    let text = node
+        .child("annotation")?
+        .children_named("documentation")
+        .map(|documentation| documentation.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!text.is_empty()).then_some(text)
+}
+
+fn local_name(qname: &str) -> &str {
+    qname.rsplit(':').next().unwrap_or(qname)
+}
+
+fn child_name(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Excerpt of an ISO 20022 pacs.008 credit transfer message
+    const PACS_008: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"
+           xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08"
+           targetNamespace="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08"
+           elementFormDefault="qualified">
+  <xs:element name="Document" type="Document"/>
+  <xs:complexType name="Document">
+    <xs:sequence>
+      <xs:element name="FIToFICstmrCdtTrf" type="FIToFICustomerCreditTransferV08"/>
+    </xs:sequence>
+  </xs:complexType>
+  <xs:complexType name="FIToFICustomerCreditTransferV08">
+    <xs:sequence>
+      <xs:element name="GrpHdr" type="GroupHeader93"/>
+      <xs:element name="CdtTrfTxInf" type="CreditTransferTransaction39" maxOccurs="unbounded"/>
+    </xs:sequence>
+  </xs:complexType>
+  <xs:complexType name="GroupHeader93">
+    <xs:sequence>
+      <xs:element name="MsgId" type="Max35Text">
+        <xs:annotation><xs:documentation>Point to point reference.</xs:documentation></xs:annotation>
+      </xs:element>
+      <xs:element name="CreDtTm" type="ISODateTime"/>
+      <xs:element name="NbOfTxs" type="Max15NumericText"/>
+    </xs:sequence>
+  </xs:complexType>
+  <xs:complexType name="CreditTransferTransaction39">
+    <xs:sequence>
+      <xs:element name="IntrBkSttlmAmt" type="ActiveCurrencyAndAmount"/>
+      <xs:element name="ChrgBr" type="ChargeBearerType1Code"/>
+      <xs:choice>
+        <xs:element name="Cd" type="Max35Text"/>
+        <xs:element name="Prtry" type="Max35Text"/>
+      </xs:choice>
+      <xs:element name="RmtInf" type="xs:string" minOccurs="0" maxOccurs="10"/>
+    </xs:sequence>
+  </xs:complexType>
+  <xs:complexType name="ActiveCurrencyAndAmount">
+    <xs:simpleContent>
+      <xs:extension base="ActiveCurrencyAndAmount_SimpleType">
+        <xs:attribute name="Ccy" type="ActiveCurrencyCode" use="required"/>
+      </xs:extension>
+    </xs:simpleContent>
+  </xs:complexType>
+  <xs:simpleType name="ActiveCurrencyAndAmount_SimpleType">
+    <xs:restriction base="xs:decimal">
+      <xs:fractionDigits value="5"/>
+      <xs:totalDigits value="18"/>
+      <xs:minInclusive value="0"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:simpleType name="ActiveCurrencyCode">
+    <xs:restriction base="xs:string">
+      <xs:pattern value="[A-Z]{3,3}"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:simpleType name="ChargeBearerType1Code">
+    <xs:restriction base="xs:string">
+      <xs:enumeration value="DEBT"/>
+      <xs:enumeration value="CRED"/>
+      <xs:enumeration value="SHAR"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:simpleType name="ISODateTime">
+    <xs:restriction base="xs:dateTime"/>
+  </xs:simpleType>
+  <xs:simpleType name="Max15NumericText">
+    <xs:restriction base="xs:string">
+      <xs:pattern value="[0-9]{1,15}"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:simpleType name="Max35Text">
+    <xs:restriction base="xs:string">
+      <xs:minLength value="1"/>
+      <xs:maxLength value="35"/>
+    </xs:restriction>
+  </xs:simpleType>
+</xs:schema>
+"#;
+
+    fn column<'a>(table: &'a TableData, name: &str) -> &'a crate::import::ColumnData {
+        table
+            .columns
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap_or_else(|| panic!("missing column {}", name))
+    }
+
+    #[test]
+    fn test_import_iso20022_message() {
+        let result = XsdImporter::new().import(PACS_008).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.tables.len(), 1);
+        let table = &result.tables[0];
+        assert_eq!(table.name.as_deref(), Some("Document"));
+
+        let names: Vec<&str> = table.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "FIToFICstmrCdtTrf",
+                "FIToFICstmrCdtTrf.GrpHdr",
+                "FIToFICstmrCdtTrf.GrpHdr.MsgId",
+                "FIToFICstmrCdtTrf.GrpHdr.CreDtTm",
+                "FIToFICstmrCdtTrf.GrpHdr.NbOfTxs",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].IntrBkSttlmAmt",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].IntrBkSttlmAmt.value",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].IntrBkSttlmAmt.Ccy",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].ChrgBr",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].Cd",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].Prtry",
+                "FIToFICstmrCdtTrf.CdtTrfTxInf.[].RmtInf",
+            ]
+        );
+
+        let transactions = column(table, "FIToFICstmrCdtTrf.CdtTrfTxInf");
+        assert_eq!(transactions.data_type, "ARRAY<OBJECT>");
+        assert!(!transactions.nullable);
+
+        let msg_id = column(table, "FIToFICstmrCdtTrf.GrpHdr.MsgId");
+        assert_eq!(msg_id.data_type, "STRING");
+        assert_eq!(msg_id.physical_type.as_deref(), Some("Max35Text"));
+        assert_eq!(
+            msg_id.description.as_deref(),
+            Some("Point to point reference.")
+        );
+        let options = msg_id.logical_type_options.as_ref().unwrap();
+        assert_eq!(options.max_length, Some(35));
+
+        let created = column(table, "FIToFICstmrCdtTrf.GrpHdr.CreDtTm");
+        assert_eq!(created.data_type, "TIMESTAMP");
+
+        let amount = column(
+            table,
+            "FIToFICstmrCdtTrf.CdtTrfTxInf.[].IntrBkSttlmAmt.value",
+        );
+        assert_eq!(amount.data_type, "DECIMAL(18,5)");
+        let currency = column(table, "FIToFICstmrCdtTrf.CdtTrfTxInf.[].IntrBkSttlmAmt.Ccy");
+        assert!(!currency.nullable);
+
+        let bearer = column(table, "FIToFICstmrCdtTrf.CdtTrfTxInf.[].ChrgBr");
+        assert_eq!(
+            bearer.enum_values.as_deref(),
+            Some(&["DEBT".to_string(), "CRED".to_string(), "SHAR".to_string()][..])
+        );
+        assert!(column(table, "FIToFICstmrCdtTrf.CdtTrfTxInf.[].Cd").nullable);
+
+        let remittance = column(table, "FIToFICstmrCdtTrf.CdtTrfTxInf.[].RmtInf");
+        assert_eq!(remittance.data_type, "ARRAY<STRING>");
+        assert!(remittance.nullable);
+    }
+
+    #[test]
+    fn test_import_extension_and_recursion() {
+        let xsd = r#"
+<xsd:schema xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <xsd:complexType name="Party">
+    <xsd:sequence>
+      <xsd:element name="Name" type="xsd:string"/>
+      <xsd:element name="Since" type="xsd:gYear" minOccurs="0"/>
+    </xsd:sequence>
+    <xsd:attribute name="id" type="xsd:ID" use="required"/>
+  </xsd:complexType>
+  <xsd:complexType name="Organisation">
+    <xsd:complexContent>
+      <xsd:extension base="Party">
+        <xsd:sequence>
+          <xsd:element name="Parent" type="Organisation" minOccurs="0"/>
+        </xsd:sequence>
+      </xsd:extension>
+    </xsd:complexContent>
+  </xsd:complexType>
+</xsd:schema>
+"#;
+        let result = XsdImporter::new().import(xsd).unwrap();
+        let names: Vec<_> = result.tables.iter().map(|t| t.name.as_deref()).collect();
+        assert_eq!(names, vec![Some("Party"), Some("Organisation")]);
+
+        let organisation = &result.tables[1];
+        let columns: Vec<_> = organisation
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.data_type.as_str(), c.nullable))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("Name", "STRING", false),
+                ("Since", "STRING", true),
+                ("id", "STRING", false),
+                ("Parent", "OBJECT", true),
+            ]
+        );
+
+        // The recursive type is reported and gYear is preserved as an unmapped type
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.to_string().contains("Recursive type"))
+        );
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| matches!(e, ImportError::UnmappedType(m) if m.contains("gYear")))
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_non_schema() {
+        assert!(XsdImporter::new().import("<root/>").is_err());
+        assert!(XsdImporter::new().import("not xml <").is_err());
+    }
+}
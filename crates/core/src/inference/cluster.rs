@@ -0,0 +1,425 @@
+//! Schema clustering across staged partitions
+//!
+//! Partitions often contain more than one message type (for example an event
+//! topic carrying `order_created` and `order_shipped` events). Clustering infers
+//! a schema per record shape, groups similar shapes across all partitions using
+//! [`schema_similarity`](super::merge::schema_similarity), names each cluster and
+//! proposes one ODCS contract per cluster instead of one per partition.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::config::InferenceConfig;
+use super::error::InferenceError;
+use super::inferrer::SchemaInferrer;
+use super::merge::{group_similar_schemas, merge_schemas};
+use super::types::{InferredSchema, InferredType};
+use crate::models::odcs::{CustomProperty, ODCSContract};
+
+/// Field names checked (in order) when naming a cluster after a discriminator value
+pub const DEFAULT_NAME_HINTS: &[&str] = &[
+    "event_type",
+    "eventType",
+    "message_type",
+    "messageType",
+    "record_type",
+    "recordType",
+    "type",
+    "kind",
+    "event",
+    "@type",
+];
+
+/// Configuration for schema clustering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterConfig {
+    /// Similarity threshold for grouping record shapes (0.0 - 1.0)
+    pub threshold: f64,
+    /// Clusters with fewer records than this are dropped as noise
+    pub min_records: usize,
+    /// Field names whose single observed value is used as the cluster name
+    pub name_hints: Vec<String>,
+}
+
+impl Default for ClusterConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.8,
+            min_records: 1,
+            name_hints: DEFAULT_NAME_HINTS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl ClusterConfig {
+    /// Create a configuration with the given similarity threshold
+    pub fn with_threshold(threshold: f64) -> Self {
+        Self {
+            threshold: threshold.clamp(0.0, 1.0),
+            ..Default::default()
+        }
+    }
+
+    /// Set the minimum number of records for a cluster to be kept
+    pub fn min_records(mut self, min_records: usize) -> Self {
+        self.min_records = min_records;
+        self
+    }
+
+    /// Replace the discriminator field names used for naming clusters
+    pub fn name_hints(mut self, hints: Vec<String>) -> Self {
+        self.name_hints = hints;
+        self
+    }
+}
+
+/// A group of similar record shapes, possibly spanning several partitions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaCluster {
+    /// Cluster name (discriminator value, partition name or `cluster_N`)
+    pub name: String,
+    /// Partitions contributing records to this cluster
+    pub partitions: Vec<String>,
+    /// Number of records in this cluster
+    pub record_count: usize,
+    /// Number of distinct record shapes merged into this cluster
+    pub shape_count: usize,
+    /// Merged schema for the cluster
+    pub schema: InferredSchema,
+}
+
+impl SchemaCluster {
+    /// Propose a draft ODCS contract for this cluster
+    ///
+    /// The contract has a single schema object named after the cluster and
+    /// records the contributing partitions as a custom property.
+    pub fn to_contract(&self, version: &str) -> ODCSContract {
+        let mut contract = ODCSContract::new(self.name.clone(), version)
+            .with_status("draft")
+            .with_schema(self.schema.to_schema_object(&self.name))
+            .with_custom_property(CustomProperty::new(
+                "sourcePartitions",
+                serde_json::json!(self.partitions),
+            ))
+            .with_custom_property(CustomProperty::new(
+                "inferredRecordCount",
+                serde_json::json!(self.record_count),
+            ));
+        if let Some(ref desc) = self.schema.description {
+            contract = contract.with_description(desc.clone());
+        }
+        contract
+    }
+}
+
+/// Cluster per-partition schemas and name each cluster
+///
+/// Each schema's `partition` is carried into the resulting cluster.
+pub fn cluster_schemas(schemas: Vec<InferredSchema>, config: &ClusterConfig) -> Vec<SchemaCluster> {
+    let partitions = schemas
+        .iter()
+        .map(|s| s.partition.iter().cloned().collect())
+        .collect();
+    build_clusters(schemas, partitions, config)
+}
+
+/// Cluster individual records by shape across partitions
+///
+/// Records are first bucketed by their top-level key set, a schema is inferred
+/// for each bucket, and the buckets are then grouped by similarity. This
+/// separates multiple message types sharing one partition.
+pub fn cluster_records<I>(
+    records: I,
+    inference: &InferenceConfig,
+    config: &ClusterConfig,
+) -> Result<Vec<SchemaCluster>, InferenceError>
+where
+    I: IntoIterator<Item = (Option<String>, Value)>,
+{
+    let mut shapes: BTreeMap<Vec<String>, ShapeBucket> = BTreeMap::new();
+
+    for (partition, value) in records {
+        let Some(obj) = value.as_object() else {
+            continue;
+        };
+        let mut keys: Vec<String> = obj.keys().cloned().collect();
+        keys.sort();
+
+        let bucket = shapes.entry(keys).or_insert_with(|| ShapeBucket {
+            inferrer: SchemaInferrer::with_config(inference.clone()),
+            partitions: BTreeSet::new(),
+        });
+        match partition {
+            Some(partition) => {
+                bucket.inferrer.add_value_from(&value, &partition)?;
+                bucket.partitions.insert(partition);
+            }
+            None => bucket.inferrer.add_value(&value)?,
+        }
+    }
+
+    let mut schemas = Vec::new();
+    let mut shape_partitions = Vec::new();
+    for bucket in shapes.into_values() {
+        match bucket.inferrer.finalize() {
+            Ok(schema) => {
+                schemas.push(schema);
+                shape_partitions.push(bucket.partitions);
+            }
+            Err(InferenceError::NoRecords) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(build_clusters(schemas, shape_partitions, config))
+}
+
+/// Group similar schemas into named clusters
+///
+/// `partitions[i]` holds the partitions that contributed to `schemas[i]`.
+/// Clusters are sorted largest first, so generated names are stable for the
+/// dominant types.
+fn build_clusters(
+    schemas: Vec<InferredSchema>,
+    partitions: Vec<BTreeSet<String>>,
+    config: &ClusterConfig,
+) -> Vec<SchemaCluster> {
+    let groups = group_similar_schemas(&schemas, config.threshold);
+    let mut slots: Vec<Option<InferredSchema>> = schemas.into_iter().map(Some).collect();
+
+    let mut clusters = Vec::new();
+    for group in groups {
+        let members: Vec<InferredSchema> =
+            group.iter().filter_map(|&idx| slots[idx].take()).collect();
+        let record_count = members.iter().map(|s| s.record_count).sum();
+        if record_count < config.min_records {
+            continue;
+        }
+
+        let cluster_partitions: BTreeSet<String> = group
+            .iter()
+            .flat_map(|&idx| partitions[idx].iter().cloned())
+            .collect();
+        let shape_count = members.len();
+        let mut merged = merge_schemas(members);
+        merged.record_count = record_count;
+        merged.partition = None;
+
+        clusters.push(SchemaCluster {
+            name: String::new(),
+            partitions: cluster_partitions.into_iter().collect(),
+            record_count,
+            shape_count,
+            schema: merged,
+        });
+    }
+
+    clusters.sort_by(|a, b| {
+        b.record_count
+            .cmp(&a.record_count)
+            .then_with(|| a.partitions.cmp(&b.partitions))
+    });
+    assign_names(&mut clusters, config);
+    clusters
+}
+
+/// Sample every staged partition and cluster the records by shape
+///
+/// `samples_per_partition` bounds how many records are read from each partition,
+/// which are selected with `inference.sampling`.
+#[cfg(feature = "staging")]
+pub fn cluster_staged_partitions(
+    db: &crate::staging::StagingDb,
+    samples_per_partition: usize,
+    inference: &InferenceConfig,
+    config: &ClusterConfig,
+) -> Result<Vec<SchemaCluster>, InferenceError> {
+    let partitions = db
+        .partition_stats()
+        .map_err(|e| InferenceError::Staging(e.to_string()))?;
+
+    let mut records = Vec::new();
+    for (partition, _) in partitions {
+        // partition_stats reports unpartitioned records as '<none>'
+        let key = (partition != "<none>").then_some(partition);
+        let samples = db
+            .get_sample_with(samples_per_partition, key.as_deref(), &inference.sampling)
+            .map_err(|e| InferenceError::Staging(e.to_string()))?;
+        for sample in samples {
+            if let Ok(value) = serde_json::from_str::<Value>(&sample) {
+                records.push((key.clone(), value));
+            }
+        }
+    }
+
+    cluster_records(records, inference, config)
+}
+
+struct ShapeBucket {
+    inferrer: SchemaInferrer,
+    partitions: BTreeSet<String>,
+}
+
+/// Assign unique, human-readable names to clusters
+fn assign_names(clusters: &mut [SchemaCluster], config: &ClusterConfig) {
+    let mut used: BTreeSet<String> = BTreeSet::new();
+
+    for (i, cluster) in clusters.iter_mut().enumerate() {
+        let base = discriminator_name(&cluster.schema, &config.name_hints)
+            .or_else(|| match cluster.partitions.as_slice() {
+                [only] => Some(sanitize_name(only)),
+                _ => None,
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| format!("cluster_{}", i + 1));
+
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while used.contains(&name) {
+            name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        used.insert(name.clone());
+        cluster.schema.name = Some(name.clone());
+        cluster.name = name;
+    }
+}
+
+/// Use the value of a discriminator field when the cluster only ever saw one
+fn discriminator_name(schema: &InferredSchema, hints: &[String]) -> Option<String> {
+    let InferredType::Object { ref properties } = schema.root else {
+        return None;
+    };
+
+    hints.iter().find_map(|hint| {
+        let field = properties.get(hint)?;
+        match field.examples.as_slice() {
+            [Value::String(value)] => Some(sanitize_name(value)),
+            _ => None,
+        }
+    })
+}
+
+/// Turn an arbitrary value into a snake_case identifier
+fn sanitize_name(value: &str) -> String {
+    let mut name = String::with_capacity(value.len());
+    let mut prev_underscore = true;
+    for (i, c) in value.chars().enumerate() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && i > 0 && !prev_underscore {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+            prev_underscore = false;
+        } else if !prev_underscore {
+            name.push('_');
+            prev_underscore = true;
+        }
+    }
+    name.trim_end_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn records() -> Vec<(Option<String>, Value)> {
+        vec![
+            (
+                Some("orders".to_string()),
+                json!({"event_type": "OrderCreated", "order_id": 1, "total": 9.5}),
+            ),
+            (
+                Some("orders".to_string()),
+                json!({"event_type": "OrderCreated", "order_id": 2, "total": 3.0}),
+            ),
+            (
+                Some("orders".to_string()),
+                json!({"event_type": "OrderShipped", "order_id": 1, "carrier": "dhl", "tracking": "x1"}),
+            ),
+            (
+                Some("orders-eu".to_string()),
+                json!({"event_type": "OrderCreated", "order_id": 3, "total": 1.0}),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_cluster_records_splits_message_types() {
+        let clusters = cluster_records(
+            records(),
+            &InferenceConfig::default(),
+            &ClusterConfig::with_threshold(0.7),
+        )
+        .unwrap();
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].name, "order_created");
+        assert_eq!(clusters[0].record_count, 3);
+        assert_eq!(clusters[0].partitions, vec!["orders", "orders-eu"]);
+        assert_eq!(clusters[1].name, "order_shipped");
+        assert_eq!(clusters[1].partitions, vec!["orders"]);
+    }
+
+    #[test]
+    fn test_cluster_names_are_unique() {
+        let recs = vec![
+            (Some("a".to_string()), json!({"x": 1})),
+            (Some("a".to_string()), json!({"y": "s"})),
+        ];
+        let clusters =
+            cluster_records(recs, &InferenceConfig::default(), &ClusterConfig::default()).unwrap();
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].name, "a");
+        assert_eq!(clusters[1].name, "a_2");
+    }
+
+    #[test]
+    fn test_cluster_min_records() {
+        let clusters = cluster_records(
+            records(),
+            &InferenceConfig::default(),
+            &ClusterConfig::with_threshold(0.7).min_records(2),
+        )
+        .unwrap();
+
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].name, "order_created");
+    }
+
+    #[test]
+    fn test_cluster_to_contract() {
+        let clusters = cluster_records(
+            records(),
+            &InferenceConfig::default(),
+            &ClusterConfig::with_threshold(0.7),
+        )
+        .unwrap();
+
+        let contract = clusters[1].to_contract("0.1.0");
+        assert_eq!(contract.name, "order_shipped");
+        assert_eq!(contract.status.as_deref(), Some("draft"));
+        assert_eq!(contract.schema_count(), 1);
+        let schema = contract.get_schema("order_shipped").unwrap();
+        assert!(schema.get_property("carrier").is_some());
+        assert!(
+            contract
+                .custom_properties
+                .iter()
+                .any(|p| p.property == "sourcePartitions")
+        );
+    }
+
+    #[test]
+    fn test_sanitize_name() {
+        assert_eq!(sanitize_name("OrderCreated"), "order_created");
+        assert_eq!(sanitize_name("order.created-v2"), "order_created_v2");
+        assert_eq!(sanitize_name("  "), "");
+    }
+}
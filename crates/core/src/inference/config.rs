@@ -2,6 +2,102 @@
 
 use serde::{Deserialize, Serialize};
 
+/// How the inferrer resolves a field whose samples disagree on its type
+/// (e.g. integers in some records and strings in others)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TypeConflictPolicy {
+    /// Widen to a type accepting every sample (a mixed type)
+    #[default]
+    Widen,
+    /// Fail inference with [`InferenceError::TypeConflicts`](super::InferenceError::TypeConflicts)
+    Fail,
+    /// Leave the field out of the schema
+    QuarantineField,
+    /// Use the type of the majority of samples
+    PreferMajority,
+}
+
+impl std::str::FromStr for TypeConflictPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "widen" => Ok(TypeConflictPolicy::Widen),
+            "fail" => Ok(TypeConflictPolicy::Fail),
+            "quarantine" | "quarantine-field" => Ok(TypeConflictPolicy::QuarantineField),
+            "majority" | "prefer-majority" => Ok(TypeConflictPolicy::PreferMajority),
+            _ => Err(format!(
+                "Invalid type conflict policy: {}. Expected: widen, fail, quarantine-field, prefer-majority",
+                s
+            )),
+        }
+    }
+}
+
+/// How sample records are drawn from a staging database
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum SamplingStrategy {
+    /// Uniformly random records (sorts every candidate record)
+    #[default]
+    Random,
+    /// The first records in ingestion order
+    FirstN,
+    /// Uniformly random records drawn in a single pass, without sorting
+    Reservoir,
+    /// Equal share of records from every partition
+    StratifiedByPartition,
+    /// Equal share of records for every value of a JSON field (e.g. `$.event_type`)
+    StratifiedByField { field: String },
+    /// Every k-th record in ingestion order, with k chosen to spread the sample
+    /// over all records
+    Systematic,
+}
+
+impl std::str::FromStr for SamplingStrategy {
+    type Err = String;
+
+    /// Parse `random`, `first-n`, `reservoir`, `stratified` (by partition),
+    /// `stratified:<json path>` or `systematic`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((kind, field)) = s.split_once(':') {
+            return match kind.to_lowercase().replace('_', "-").as_str() {
+                "stratified" | "stratified-by-field" if !field.trim().is_empty() => {
+                    Ok(SamplingStrategy::StratifiedByField {
+                        field: field.trim().to_string(),
+                    })
+                }
+                _ => Err(format!("Invalid sampling strategy: {}", s)),
+            };
+        }
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "random" => Ok(SamplingStrategy::Random),
+            "first" | "first-n" => Ok(SamplingStrategy::FirstN),
+            "reservoir" => Ok(SamplingStrategy::Reservoir),
+            "stratified" | "stratified-by-partition" => Ok(SamplingStrategy::StratifiedByPartition),
+            "systematic" => Ok(SamplingStrategy::Systematic),
+            _ => Err(format!(
+                "Invalid sampling strategy: {}. Expected: random, first-n, reservoir, stratified, stratified:<json path>, systematic",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for SamplingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SamplingStrategy::Random => write!(f, "random"),
+            SamplingStrategy::FirstN => write!(f, "first-n"),
+            SamplingStrategy::Reservoir => write!(f, "reservoir"),
+            SamplingStrategy::StratifiedByPartition => write!(f, "stratified"),
+            SamplingStrategy::StratifiedByField { field } => write!(f, "stratified:{}", field),
+            SamplingStrategy::Systematic => write!(f, "systematic"),
+        }
+    }
+}
+
 /// Configuration for schema inference
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -30,6 +126,14 @@ pub struct InferenceConfig {
 
     /// Minimum confidence threshold for format detection (0.0 - 1.0)
     pub format_confidence_threshold: f64,
+
+    /// Resolution of fields whose samples disagree on the type
+    #[serde(default)]
+    pub conflict_policy: TypeConflictPolicy,
+
+    /// How records are sampled from a staging database
+    #[serde(default)]
+    pub sampling: SamplingStrategy,
 }
 
 impl Default for InferenceConfig {
@@ -43,6 +147,8 @@ impl Default for InferenceConfig {
             max_examples: 5,
             assume_nullable: false,
             format_confidence_threshold: 0.9,
+            conflict_policy: TypeConflictPolicy::Widen,
+            sampling: SamplingStrategy::Random,
         }
     }
 }
@@ -114,6 +220,18 @@ impl InferenceConfigBuilder {
         self
     }
 
+    /// Set how fields with conflicting sample types are resolved
+    pub fn conflict_policy(mut self, policy: TypeConflictPolicy) -> Self {
+        self.config.conflict_policy = policy;
+        self
+    }
+
+    /// Set how records are sampled from a staging database
+    pub fn sampling(mut self, strategy: SamplingStrategy) -> Self {
+        self.config.sampling = strategy;
+        self
+    }
+
     /// Build the configuration
     pub fn build(self) -> InferenceConfig {
         self.config
@@ -147,6 +265,23 @@ mod tests {
         assert_eq!(config.max_depth, 5);
     }
 
+    #[test]
+    fn test_conflict_policy_from_str() {
+        assert_eq!(
+            "prefer-majority".parse::<TypeConflictPolicy>().unwrap(),
+            TypeConflictPolicy::PreferMajority
+        );
+        assert_eq!(
+            "quarantine_field".parse::<TypeConflictPolicy>().unwrap(),
+            TypeConflictPolicy::QuarantineField
+        );
+        assert!("coerce".parse::<TypeConflictPolicy>().is_err());
+        assert_eq!(
+            InferenceConfig::default().conflict_policy,
+            TypeConflictPolicy::Widen
+        );
+    }
+
     #[test]
     fn test_frequency_clamping() {
         let config = InferenceConfig::builder()
@@ -155,4 +290,48 @@ mod tests {
 
         assert_eq!(config.min_field_frequency, 1.0);
     }
+
+    #[test]
+    fn test_sampling_strategy_from_str() {
+        for strategy in [
+            SamplingStrategy::Random,
+            SamplingStrategy::FirstN,
+            SamplingStrategy::Reservoir,
+            SamplingStrategy::StratifiedByPartition,
+            SamplingStrategy::StratifiedByField {
+                field: "$.event.type".to_string(),
+            },
+            SamplingStrategy::Systematic,
+        ] {
+            assert_eq!(
+                strategy.to_string().parse::<SamplingStrategy>(),
+                Ok(strategy)
+            );
+        }
+        assert_eq!(
+            "stratified_by_partition".parse::<SamplingStrategy>(),
+            Ok(SamplingStrategy::StratifiedByPartition)
+        );
+        assert!("stratified:".parse::<SamplingStrategy>().is_err());
+        assert!("every-other".parse::<SamplingStrategy>().is_err());
+
+        let config: InferenceConfig = serde_json::from_value(serde_json::json!({
+            "sampleSize": 10,
+            "minFieldFrequency": 0.0,
+            "detectFormats": true,
+            "maxDepth": 5,
+            "collectExamples": false,
+            "maxExamples": 0,
+            "assumeNullable": false,
+            "formatConfidenceThreshold": 0.9,
+            "sampling": {"type": "stratified-by-field", "field": "$.kind"}
+        }))
+        .unwrap();
+        assert_eq!(
+            config.sampling,
+            SamplingStrategy::StratifiedByField {
+                field: "$.kind".to_string()
+            }
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use super::types::TypeConflict;
+
 /// Errors that can occur during schema inference
 #[derive(Error, Debug, Clone)]
 pub enum InferenceError {
@@ -29,6 +31,10 @@ pub enum InferenceError {
     #[error("Staging error: {0}")]
     Staging(String),
 
+    /// Samples disagree on field types under [`TypeConflictPolicy::Fail`](super::TypeConflictPolicy::Fail)
+    #[error("Conflicting types in samples: {}", describe_conflicts(.0))]
+    TypeConflicts(Vec<TypeConflict>),
+
     /// Lock acquisition failed (for parallel processing)
     #[error("Failed to acquire lock for parallel processing")]
     LockError,
@@ -45,3 +51,14 @@ impl From<std::io::Error> for InferenceError {
         InferenceError::Io(e.to_string())
     }
 }
+
+fn describe_conflicts(conflicts: &[TypeConflict]) -> String {
+    conflicts
+        .iter()
+        .map(|conflict| {
+            let types: Vec<&str> = conflict.observed.keys().map(String::as_str).collect();
+            format!("{} ({})", conflict.path, types.join(" vs "))
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -8,10 +8,13 @@ use std::sync::{Arc, Mutex};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::config::InferenceConfig;
+use super::config::{InferenceConfig, TypeConflictPolicy};
 use super::error::InferenceError;
 use super::formats::{Format, detect_format};
-use super::types::{FieldStats, InferredField, InferredSchema, InferredType};
+use super::types::{
+    FieldConfidence, FieldStats, InferredField, InferredSchema, InferredType, MAX_FIELD_SOURCES,
+    TypeConflict,
+};
 
 /// Statistics from schema inference
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -44,6 +47,10 @@ pub struct SchemaInferrer {
     field_examples: HashMap<String, Vec<Value>>,
     /// Numeric stats per field
     field_numeric_stats: HashMap<String, NumericStats>,
+    /// Sample sources per field
+    field_sources: HashMap<String, Vec<String>>,
+    /// Source of the record being analyzed
+    current_source: Option<String>,
     /// Total records processed
     record_count: usize,
     /// Records skipped
@@ -97,6 +104,8 @@ impl SchemaInferrer {
             field_nulls: HashMap::new(),
             field_examples: HashMap::new(),
             field_numeric_stats: HashMap::new(),
+            field_sources: HashMap::new(),
+            current_source: None,
             record_count: 0,
             skipped_count: 0,
             max_depth_seen: 0,
@@ -141,6 +150,17 @@ impl SchemaInferrer {
         Ok(())
     }
 
+    /// Add a parsed JSON value, recording where it came from
+    ///
+    /// The source (e.g. a partition or file name) is kept as provenance on
+    /// every field the record contributes to.
+    pub fn add_value_from(&mut self, value: &Value, source: &str) -> Result<(), InferenceError> {
+        self.current_source = Some(source.to_string());
+        let result = self.add_value(value);
+        self.current_source = None;
+        result
+    }
+
     /// Add a batch of JSON strings
     pub fn add_json_batch(&mut self, records: &[String]) -> Result<(), InferenceError> {
         for json in records {
@@ -149,13 +169,13 @@ impl SchemaInferrer {
         Ok(())
     }
 
-    /// Analyze a JSON value at a given path
+    /// Analyze a JSON value at a given path, returning its inferred type
     fn analyze_value(
         &mut self,
         value: &Value,
         path: &str,
         depth: usize,
-    ) -> Result<(), InferenceError> {
+    ) -> Result<InferredType, InferenceError> {
         if depth > self.config.max_depth {
             return Err(InferenceError::MaxDepthExceeded {
                 depth,
@@ -173,7 +193,15 @@ impl SchemaInferrer {
         self.field_types
             .entry(path.to_string())
             .or_default()
-            .push(inferred_type);
+            .push(inferred_type.clone());
+
+        // Track provenance
+        if let Some(ref source) = self.current_source {
+            let sources = self.field_sources.entry(path.to_string()).or_default();
+            if sources.len() < MAX_FIELD_SOURCES && !sources.contains(source) {
+                sources.push(source.clone());
+            }
+        }
 
         // Track nulls
         if value.is_null() {
@@ -196,7 +224,7 @@ impl SchemaInferrer {
                 .add(n);
         }
 
-        Ok(())
+        Ok(inferred_type)
     }
 
     /// Infer the type of a JSON value
@@ -240,8 +268,7 @@ impl SchemaInferrer {
                     let item_path = format!("{}[]", path);
 
                     for item in arr {
-                        self.analyze_value(item, &item_path, depth + 1)?;
-                        let t = self.infer_type(item, &item_path, depth + 1)?;
+                        let t = self.analyze_value(item, &item_path, depth + 1)?;
                         item_type = item_type.merge_with(t);
                     }
 
@@ -255,9 +282,7 @@ impl SchemaInferrer {
 
                 for (key, val) in obj {
                     let field_path = format!("{}.{}", path, key);
-                    self.analyze_value(val, &field_path, depth + 1)?;
-
-                    let field_type = self.infer_type(val, &field_path, depth + 1)?;
+                    let field_type = self.analyze_value(val, &field_path, depth + 1)?;
                     let nullable = val.is_null();
 
                     let mut field = InferredField::new(field_type);
@@ -282,6 +307,11 @@ impl SchemaInferrer {
             return Err(InferenceError::NoRecords);
         }
 
+        let conflicts = self.detect_conflicts();
+        if self.config.conflict_policy == TypeConflictPolicy::Fail && !conflicts.is_empty() {
+            return Err(InferenceError::TypeConflicts(conflicts));
+        }
+
         // Build the root type by analyzing collected data
         let root = self.build_root_type()?;
 
@@ -311,6 +341,7 @@ impl SchemaInferrer {
             record_count: self.record_count,
             partition: None,
             field_stats,
+            conflicts,
         })
     }
 
@@ -326,15 +357,11 @@ impl SchemaInferrer {
             }
 
             let field_name = path.strip_prefix("$.").unwrap();
-            if field_name.contains('.') || field_name.contains('[') {
+            if field_name.contains('.') || field_name.contains('[') || self.is_quarantined(path) {
                 continue;
             }
 
-            // Merge all observed types
-            let mut primary_type = InferredType::Unknown;
-            for t in types {
-                primary_type = primary_type.merge_with(t.clone());
-            }
+            let mut primary_type = self.resolve_type(path, types);
 
             // If we have object types, recursively build nested structure
             if matches!(primary_type, InferredType::Object { .. }) {
@@ -350,6 +377,7 @@ impl SchemaInferrer {
             field.required = frequency >= 1.0 - f64::EPSILON;
             field.nullable = null_count > 0;
             field.occurrences = occurrences;
+            field.confidence = Some(self.field_confidence(path));
 
             // Check frequency threshold
             if frequency < self.config.min_field_frequency {
@@ -379,15 +407,11 @@ impl SchemaInferrer {
 
             let rest = path.strip_prefix(&prefix).unwrap();
             // Only direct children
-            if rest.contains('.') || rest.contains('[') {
+            if rest.contains('.') || rest.contains('[') || self.is_quarantined(path) {
                 continue;
             }
 
-            // Merge all observed types
-            let mut primary_type = InferredType::Unknown;
-            for t in types {
-                primary_type = primary_type.merge_with(t.clone());
-            }
+            let mut primary_type = self.resolve_type(path, types);
 
             // Recursively build if object
             if matches!(primary_type, InferredType::Object { .. }) {
@@ -400,6 +424,7 @@ impl SchemaInferrer {
             let mut field = InferredField::new(primary_type);
             field.nullable = null_count > 0;
             field.occurrences = occurrences;
+            field.confidence = Some(self.field_confidence(path));
 
             if let Some(examples) = self.field_examples.get(path) {
                 field.examples = examples.clone();
@@ -411,6 +436,103 @@ impl SchemaInferrer {
         Ok(InferredType::Object { properties })
     }
 
+    /// Confidence in the type inferred for a field path
+    fn field_confidence(&self, path: &str) -> FieldConfidence {
+        let sample_count = self.field_occurrences.get(path).copied().unwrap_or(0);
+        let null_count = self.field_nulls.get(path).copied().unwrap_or(0);
+
+        let classes = self.type_classes(path);
+        let non_null: usize = classes.values().sum();
+        let type_agreement = match classes.values().max() {
+            Some(&dominant) => dominant as f64 / non_null as f64,
+            None => 1.0,
+        };
+
+        FieldConfidence {
+            sample_count,
+            null_ratio: if sample_count == 0 {
+                0.0
+            } else {
+                null_count as f64 / sample_count as f64
+            },
+            type_agreement,
+            sources: self.field_sources.get(path).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Number of non-null samples per type class of a field
+    fn type_classes(&self, path: &str) -> BTreeMap<&'static str, usize> {
+        let mut classes = BTreeMap::new();
+        for t in self.field_types.get(path).into_iter().flatten() {
+            if *t != InferredType::Null {
+                *classes.entry(t.type_class()).or_insert(0) += 1;
+            }
+        }
+        classes
+    }
+
+    /// Type class shared by most samples of a field (ties go to the first class by name)
+    fn majority_class(&self, path: &str) -> Option<&'static str> {
+        let classes = self.type_classes(path);
+        let max = classes.values().copied().max()?;
+        classes
+            .into_iter()
+            .find(|(_, count)| *count == max)
+            .map(|(class, _)| class)
+    }
+
+    fn has_conflict(&self, path: &str) -> bool {
+        self.type_classes(path).len() > 1
+    }
+
+    fn is_quarantined(&self, path: &str) -> bool {
+        self.config.conflict_policy == TypeConflictPolicy::QuarantineField
+            && self.has_conflict(path)
+    }
+
+    /// Merge the observed types of a field under the configured conflict policy
+    fn resolve_type(&self, path: &str, types: &[InferredType]) -> InferredType {
+        let majority = match self.config.conflict_policy {
+            TypeConflictPolicy::PreferMajority if self.has_conflict(path) => {
+                self.majority_class(path)
+            }
+            _ => None,
+        };
+        types
+            .iter()
+            .filter(|t| majority.is_none_or(|class| t.type_class() == class))
+            .fold(InferredType::Unknown, |merged, t| {
+                merged.merge_with(t.clone())
+            })
+    }
+
+    /// Fields whose non-null samples have more than one type class
+    fn detect_conflicts(&self) -> Vec<TypeConflict> {
+        let mut conflicts: Vec<TypeConflict> = self
+            .field_types
+            .iter()
+            .filter(|(path, _)| path.starts_with("$.") && !path.contains('['))
+            .filter(|(path, _)| self.has_conflict(path))
+            .map(|(path, types)| {
+                let mut observed = BTreeMap::new();
+                for t in types.iter().filter(|t| **t != InferredType::Null) {
+                    *observed.entry(t.type_name().to_string()).or_insert(0) += 1;
+                }
+                let resolution = match self.config.conflict_policy {
+                    TypeConflictPolicy::QuarantineField => "quarantined".to_string(),
+                    _ => self.resolve_type(path, types).type_name().to_string(),
+                };
+                TypeConflict {
+                    path: path.clone(),
+                    observed,
+                    resolution,
+                }
+            })
+            .collect();
+        conflicts.sort_by(|a, b| a.path.cmp(&b.path));
+        conflicts
+    }
+
     /// Get current inference statistics
     pub fn stats(&self) -> InferenceStats {
         let mut type_distribution = HashMap::new();
@@ -492,6 +614,14 @@ impl ParallelSchemaInferrer {
             .add_value(value)
     }
 
+    /// Add a parsed JSON value with its source for analysis (thread-safe)
+    pub fn add_value_from(&self, value: &Value, source: &str) -> Result<(), InferenceError> {
+        self.inner
+            .lock()
+            .map_err(|_| InferenceError::LockError)?
+            .add_value_from(value, source)
+    }
+
     /// Get current inference statistics (thread-safe)
     pub fn stats(&self) -> Result<InferenceStats, InferenceError> {
         Ok(self
@@ -748,6 +878,128 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_field_confidence_and_sources() {
+        let mut inferrer = SchemaInferrer::new();
+        let records = [
+            (
+                r#"{"id": 1, "value": 42, "user": {"name": "a"}}"#,
+                "2024-01",
+            ),
+            (
+                r#"{"id": 2, "value": "text", "user": {"name": "b"}}"#,
+                "2024-01",
+            ),
+            (
+                r#"{"id": 3, "value": null, "user": {"name": "c"}}"#,
+                "2024-02",
+            ),
+            (
+                r#"{"id": 4, "value": 7, "user": {"name": null}}"#,
+                "2024-02",
+            ),
+        ];
+        for (json, source) in records {
+            let value: Value = serde_json::from_str(json).unwrap();
+            inferrer.add_value_from(&value, source).unwrap();
+        }
+        let schema = inferrer.finalize().unwrap();
+
+        let InferredType::Object { ref properties } = schema.root else {
+            panic!("Expected object type");
+        };
+        let value = properties["value"].confidence.as_ref().unwrap();
+        assert_eq!(value.sample_count, 4);
+        assert_eq!(value.null_ratio, 0.25);
+        assert!((value.type_agreement - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(value.sources, ["2024-01", "2024-02"]);
+
+        // Nested fields are counted once per record
+        let InferredType::Object {
+            properties: ref user,
+        } = properties["user"].field_type
+        else {
+            panic!("Expected nested object");
+        };
+        assert_eq!(user["name"].confidence.as_ref().unwrap().sample_count, 4);
+
+        let ambiguous: Vec<String> = schema
+            .ambiguous_fields(0.35)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(ambiguous, ["value", "user.name"]);
+    }
+
+    fn infer_with_policy(policy: TypeConflictPolicy) -> Result<InferredSchema, InferenceError> {
+        let config = InferenceConfig::builder().conflict_policy(policy).build();
+        let mut inferrer = SchemaInferrer::with_config(config);
+        for json in [
+            r#"{"id": 1, "code": 10}"#,
+            r#"{"id": 2, "code": 11.5}"#,
+            r#"{"id": 3, "code": "X12"}"#,
+            r#"{"id": "4", "code": null}"#,
+        ] {
+            inferrer.add_json(json).unwrap();
+        }
+        inferrer.finalize()
+    }
+
+    fn field_type(schema: &InferredSchema, name: &str) -> Option<InferredType> {
+        match &schema.root {
+            InferredType::Object { properties } => {
+                properties.get(name).map(|f| f.field_type.clone())
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_conflict_policy_widen() {
+        let schema = infer_with_policy(TypeConflictPolicy::Widen).unwrap();
+        assert!(matches!(
+            field_type(&schema, "id"),
+            Some(InferredType::Mixed { .. })
+        ));
+        let paths: Vec<String> = schema.conflicts.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            paths,
+            [
+                "$.code: integer x1, number x1, string x1 -> mixed",
+                "$.id: integer x3, string x1 -> mixed",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conflict_policy_prefer_majority() {
+        let schema = infer_with_policy(TypeConflictPolicy::PreferMajority).unwrap();
+        assert_eq!(field_type(&schema, "id"), Some(InferredType::Integer));
+        // Integers and numbers agree, and together outnumber strings
+        assert_eq!(field_type(&schema, "code"), Some(InferredType::Number));
+        assert_eq!(schema.conflicts[1].resolution, "integer");
+    }
+
+    #[test]
+    fn test_conflict_policy_quarantine_and_fail() {
+        let schema = infer_with_policy(TypeConflictPolicy::QuarantineField).unwrap();
+        assert_eq!(field_type(&schema, "id"), None);
+        assert_eq!(field_type(&schema, "code"), None);
+        assert!(
+            schema
+                .conflicts
+                .iter()
+                .all(|c| c.resolution == "quarantined")
+        );
+
+        let error = infer_with_policy(TypeConflictPolicy::Fail).unwrap_err();
+        assert!(matches!(error, InferenceError::TypeConflicts(ref c) if c.len() == 2));
+        assert_eq!(
+            error.to_string(),
+            "Conflicting types in samples: $.code (integer vs number vs string); $.id (integer vs string)"
+        );
+    }
+
     #[test]
     fn test_stats() {
         let mut inferrer = SchemaInferrer::new();
@@ -39,8 +39,22 @@ pub fn merge_schemas(schemas: Vec<InferredSchema>) -> InferredSchema {
 
     result.root = merged_root;
 
-    // Merge field stats
+    // Merge field stats and conflict reports
     for schema in schemas {
+        for conflict in schema.conflicts {
+            match result
+                .conflicts
+                .iter_mut()
+                .find(|c| c.path == conflict.path)
+            {
+                Some(existing) => {
+                    for (type_name, count) in conflict.observed {
+                        *existing.observed.entry(type_name).or_insert(0) += count;
+                    }
+                }
+                None => result.conflicts.push(conflict),
+            }
+        }
         for (key, stats) in schema.field_stats {
             result
                 .field_stats
@@ -58,6 +72,7 @@ pub fn merge_schemas(schemas: Vec<InferredSchema>) -> InferredSchema {
                 .or_insert(stats);
         }
     }
+    result.conflicts.sort_by(|a, b| a.path.cmp(&b.path));
 
     result
 }
@@ -71,10 +86,14 @@ fn merge_object_types(schemas: &[InferredSchema]) -> InferredType {
     for schema in schemas {
         if let InferredType::Object { ref properties } = schema.root {
             for (name, field) in properties {
-                all_properties
-                    .entry(name.clone())
-                    .or_default()
-                    .push(field.clone());
+                let mut field = field.clone();
+                // Schemas inferred per partition record it as the sample source
+                if let (Some(partition), Some(confidence)) =
+                    (&schema.partition, &mut field.confidence)
+                {
+                    confidence.add_source(partition);
+                }
+                all_properties.entry(name.clone()).or_default().push(field);
             }
         }
     }
@@ -222,6 +241,7 @@ mod tests {
             record_count: 1,
             partition: None,
             field_stats: std::collections::HashMap::new(),
+            conflicts: Vec::new(),
         }
     }
 
@@ -9,7 +9,18 @@
 //! - **Format detection** - Recognize common formats (date, uuid, email, uri, etc.)
 //! - **Schema merging** - Combine schemas to find the minimum common schema
 //! - **Nullability tracking** - Track optional vs required fields
+//! - **Type conflict policy** - Widen, fail, quarantine or prefer the majority type when
+//!   samples disagree, with a report of every conflicting field
 //! - **Example collection** - Gather sample values for documentation
+//! - **Confidence and provenance** - Per-field sample counts, null and type agreement
+//!   ratios and contributing sources, to flag ambiguous fields for review
+//! - **Sampling strategies** - Random, first-N, reservoir, stratified and systematic
+//!   sampling of staged records
+//! - **Schema clustering** - Group record shapes across partitions into one contract per message type
+//! - **Column profiling** - Null percentage, distinct count, min/max and top values per
+//!   column, embedded into ODCS properties with a refresh/expiry policy
+//! - **Relationship inference** - Propose foreign keys from `*_id` column names, optionally
+//!   verified by value overlap in staged data (see [`relationships`])
 //!
 //! ## Example
 //!
@@ -27,20 +38,39 @@
 //! println!("{}", serde_json::to_string_pretty(&schema)?);
 //! ```
 
+mod cluster;
 mod config;
 mod error;
 mod formats;
 mod inferrer;
 mod merge;
+mod profile;
+pub mod relationships;
 mod types;
 
-pub use config::{InferenceConfig, InferenceConfigBuilder};
+pub use cluster::{
+    ClusterConfig, DEFAULT_NAME_HINTS, SchemaCluster, cluster_records, cluster_schemas,
+};
+pub use config::{InferenceConfig, InferenceConfigBuilder, SamplingStrategy, TypeConflictPolicy};
 pub use error::InferenceError;
 pub use formats::{Format, detect_format};
 pub use inferrer::{InferenceStats, ParallelSchemaInferrer, SchemaInferrer};
-pub use merge::{group_similar_schemas, merge_schemas};
-pub use types::{InferredField, InferredSchema, InferredType};
+pub use merge::{group_similar_schemas, merge_schemas, schema_similarity};
+pub use profile::{
+    ColumnProfiler, ColumnStatistics, DataProfile, MAX_TRACKED_DISTINCT, STATISTICS_PROPERTY,
+    StatisticsPolicy, StatisticsRefresh, StatisticsUpdate, ValueCount, embed_statistics,
+    expire_statistics, statistics_profiled_at,
+};
+pub use relationships::{RelationshipInferrer, RelationshipSuggestion, ValueOverlap};
+pub use types::{
+    AMBIGUOUS_CONFIDENCE_THRESHOLD, CONFIDENT_SAMPLE_COUNT, FieldConfidence, InferredField,
+    InferredSchema, InferredType, MAX_FIELD_SOURCES,
+};
 
 // Re-export parallel inference functions when staging feature is enabled
 #[cfg(feature = "staging")]
 pub use inferrer::{infer_schema_parallel, infer_schema_parallel_values};
+
+// Re-export staged partition clustering when staging feature is enabled
+#[cfg(feature = "staging")]
+pub use cluster::cluster_staged_partitions;
@@ -0,0 +1,689 @@
+//! Column profiling and statistics embedding
+//!
+//! [`ColumnProfiler`] computes summary statistics of every field of a set of
+//! JSON records: null percentage, distinct count, minimum and maximum, and the
+//! most frequent values. [`embed_statistics`] stores them in the
+//! `customProperties` of the matching ODCS properties under
+//! [`STATISTICS_PROPERTY`], stamped with the time the profile was taken, and
+//! [`StatisticsPolicy`] decides on later runs whether embedded statistics are
+//! refreshed, kept or expired.
+//!
+//! Nested fields are profiled by their dot path (e.g. `customer.email`) and
+//! matched against nested properties the same way; arrays are profiled as
+//! single values.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::error::InferenceError;
+use crate::models::odcs::{CustomProperty, Property, SchemaObject};
+
+/// Name of the custom property holding column statistics
+pub const STATISTICS_PROPERTY: &str = "columnStatistics";
+
+/// Distinct values tracked per column before the distinct count becomes a lower bound
+pub const MAX_TRACKED_DISTINCT: usize = 10_000;
+
+/// Statistics of one column
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnStatistics {
+    /// Number of records profiled
+    pub row_count: usize,
+    /// Records where the column was missing or null
+    pub null_count: usize,
+    /// Number of distinct non-null values
+    pub distinct_count: usize,
+    /// Whether `distinct_count` is exact, or a lower bound because more than
+    /// [`MAX_TRACKED_DISTINCT`] values were seen
+    pub distinct_exact: bool,
+    /// Smallest value (numbers compare numerically, other values as text)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<Value>,
+    /// Largest value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<Value>,
+    /// Most frequent values, most frequent first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub top_values: Vec<ValueCount>,
+}
+
+/// A value and how often it occurred
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueCount {
+    pub value: String,
+    pub count: usize,
+}
+
+impl ColumnStatistics {
+    /// Share of null values in percent (0.0 - 100.0)
+    pub fn null_percent(&self) -> f64 {
+        if self.row_count == 0 {
+            0.0
+        } else {
+            self.null_count as f64 * 100.0 / self.row_count as f64
+        }
+    }
+
+    /// Statistics as an ODCS custom property, summarized according to `policy`
+    pub fn to_custom_property(
+        &self,
+        profiled_at: DateTime<Utc>,
+        policy: &StatisticsPolicy,
+    ) -> CustomProperty {
+        let truncate = |value: &Value| match value {
+            Value::String(s) => Value::String(truncate(s, policy.max_value_length)),
+            other => other.clone(),
+        };
+        let mut value = serde_json::json!({
+            "profiledAt": profiled_at.to_rfc3339(),
+            "rowCount": self.row_count,
+            "nullPercent": (self.null_percent() * 100.0).round() / 100.0,
+            "distinctCount": self.distinct_count,
+        });
+        if !self.distinct_exact {
+            value["distinctCountApproximate"] = Value::Bool(true);
+        }
+        if let Some(min) = &self.min {
+            value["min"] = truncate(min);
+        }
+        if let Some(max) = &self.max {
+            value["max"] = truncate(max);
+        }
+        let top: Vec<Value> = self
+            .top_values
+            .iter()
+            .take(policy.max_top_values)
+            .map(|v| {
+                serde_json::json!({
+                    "value": truncate(&Value::String(v.value.clone())),
+                    "count": v.count,
+                })
+            })
+            .collect();
+        if !top.is_empty() {
+            value["topValues"] = Value::Array(top);
+        }
+        CustomProperty::new(STATISTICS_PROPERTY, value)
+    }
+}
+
+/// Statistics of every column of a data set at one point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataProfile {
+    /// When the profile was taken
+    pub profiled_at: DateTime<Utc>,
+    /// Number of records profiled
+    pub record_count: usize,
+    /// Statistics per column path
+    pub columns: BTreeMap<String, ColumnStatistics>,
+}
+
+/// Computes column statistics from JSON records
+#[derive(Debug, Default)]
+pub struct ColumnProfiler {
+    record_count: usize,
+    columns: BTreeMap<String, ColumnAccumulator>,
+}
+
+#[derive(Debug, Default)]
+struct ColumnAccumulator {
+    non_null: usize,
+    counts: HashMap<String, usize>,
+    overflowed: bool,
+    min_number: Option<f64>,
+    max_number: Option<f64>,
+    min_text: Option<String>,
+    max_text: Option<String>,
+}
+
+impl ColumnProfiler {
+    /// Create a new profiler
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a JSON record
+    pub fn add_json(&mut self, json: &str) -> Result<(), InferenceError> {
+        let value: Value = serde_json::from_str(json)?;
+        self.add_value(&value);
+        Ok(())
+    }
+
+    /// Add a record; non-object records are profiled as a single `value` column
+    pub fn add_value(&mut self, record: &Value) {
+        self.record_count += 1;
+        match record {
+            Value::Object(_) => self.add_fields(record, ""),
+            other => self.add_field("value", other),
+        }
+    }
+
+    /// Number of records added
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    fn add_fields(&mut self, object: &Value, prefix: &str) {
+        let Value::Object(fields) = object else {
+            return;
+        };
+        for (name, value) in fields {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            if value.is_object() {
+                self.add_fields(value, &path);
+            } else {
+                self.add_field(&path, value);
+            }
+        }
+    }
+
+    fn add_field(&mut self, path: &str, value: &Value) {
+        let column = self.columns.entry(path.to_string()).or_default();
+        if value.is_null() {
+            return;
+        }
+        column.non_null += 1;
+
+        let text = match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        if let Some(n) = value.as_f64() {
+            column.min_number = Some(column.min_number.map_or(n, |m| m.min(n)));
+            column.max_number = Some(column.max_number.map_or(n, |m| m.max(n)));
+        } else if !value.is_array() {
+            if column.min_text.as_ref().is_none_or(|m| text < *m) {
+                column.min_text = Some(text.clone());
+            }
+            if column.max_text.as_ref().is_none_or(|m| text > *m) {
+                column.max_text = Some(text.clone());
+            }
+        }
+
+        if let Some(count) = column.counts.get_mut(&text) {
+            *count += 1;
+        } else if column.counts.len() < MAX_TRACKED_DISTINCT {
+            column.counts.insert(text, 1);
+        } else {
+            column.overflowed = true;
+        }
+    }
+
+    /// Finish profiling, stamping the profile with the current time
+    pub fn finish(self) -> DataProfile {
+        self.finish_at(Utc::now())
+    }
+
+    /// Finish profiling, stamping the profile with `profiled_at`
+    pub fn finish_at(self, profiled_at: DateTime<Utc>) -> DataProfile {
+        let record_count = self.record_count;
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|(path, column)| {
+                let mut top_values: Vec<ValueCount> = column
+                    .counts
+                    .iter()
+                    .map(|(value, count)| ValueCount {
+                        value: value.clone(),
+                        count: *count,
+                    })
+                    .collect();
+                top_values
+                    .sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+                // Keep only values that repeat; unique values are not "top"
+                top_values.retain(|v| v.count > 1);
+
+                let (min, max) = match (column.min_number, column.max_number) {
+                    (Some(min), Some(max)) => (number(min), number(max)),
+                    _ => (
+                        column.min_text.map(Value::String),
+                        column.max_text.map(Value::String),
+                    ),
+                };
+                let statistics = ColumnStatistics {
+                    row_count: record_count,
+                    null_count: record_count - column.non_null,
+                    distinct_count: column.counts.len(),
+                    distinct_exact: !column.overflowed,
+                    min,
+                    max,
+                    top_values,
+                };
+                (path, statistics)
+            })
+            .collect();
+        DataProfile {
+            profiled_at,
+            record_count,
+            columns,
+        }
+    }
+}
+
+/// When embedded statistics are replaced by a newer profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StatisticsRefresh {
+    /// Replace statistics on every run
+    Always,
+    /// Replace statistics older than the policy's maximum age
+    #[default]
+    IfStale,
+    /// Only add statistics to properties without any
+    Never,
+}
+
+impl StatisticsRefresh {
+    /// Parse a refresh mode name (`always`, `if-stale`, `never`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace('_', "-").as_str() {
+            "always" => Some(StatisticsRefresh::Always),
+            "if-stale" | "stale" => Some(StatisticsRefresh::IfStale),
+            "never" => Some(StatisticsRefresh::Never),
+            _ => None,
+        }
+    }
+}
+
+/// Policy for embedding, refreshing and expiring column statistics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticsPolicy {
+    /// When existing statistics are replaced
+    pub refresh: StatisticsRefresh,
+    /// Age after which statistics are stale, in hours
+    pub max_age_hours: i64,
+    /// Remove stale statistics of columns missing from the new profile
+    pub expire_stale: bool,
+    /// Most frequent values kept per column
+    pub max_top_values: usize,
+    /// Characters kept of text values before truncating them
+    pub max_value_length: usize,
+}
+
+impl Default for StatisticsPolicy {
+    fn default() -> Self {
+        Self {
+            refresh: StatisticsRefresh::IfStale,
+            max_age_hours: 24 * 7,
+            expire_stale: true,
+            max_top_values: 5,
+            max_value_length: 64,
+        }
+    }
+}
+
+impl StatisticsPolicy {
+    /// Create the default policy (refresh after 7 days, expire stale statistics)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set when existing statistics are replaced
+    pub fn with_refresh(mut self, refresh: StatisticsRefresh) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Set the age after which statistics are stale
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age_hours = max_age.num_hours();
+        self
+    }
+
+    /// Set whether stale statistics of unprofiled columns are removed
+    pub fn with_expire_stale(mut self, expire_stale: bool) -> Self {
+        self.expire_stale = expire_stale;
+        self
+    }
+
+    /// Set how many of the most frequent values are kept
+    pub fn with_max_top_values(mut self, max_top_values: usize) -> Self {
+        self.max_top_values = max_top_values;
+        self
+    }
+
+    /// Set how many characters of text values are kept
+    pub fn with_max_value_length(mut self, max_value_length: usize) -> Self {
+        self.max_value_length = max_value_length;
+        self
+    }
+
+    /// Whether statistics taken at `profiled_at` are stale at `now`
+    pub fn is_stale(&self, profiled_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        now - profiled_at > Duration::hours(self.max_age_hours)
+    }
+}
+
+/// Columns whose statistics changed in [`embed_statistics`] or [`expire_statistics`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatisticsUpdate {
+    /// Columns given new statistics
+    pub refreshed: Vec<String>,
+    /// Columns whose existing statistics were kept
+    pub kept: Vec<String>,
+    /// Columns whose stale statistics were removed
+    pub expired: Vec<String>,
+}
+
+/// Embed the statistics of a profile into the properties of a schema object
+///
+/// Statistics are written to every property the profile covers, subject to
+/// `policy.refresh`. Stale statistics of properties the profile does not
+/// cover are removed when `policy.expire_stale` is set. Staleness is judged
+/// at the time the profile was taken.
+pub fn embed_statistics(
+    schema: &mut SchemaObject,
+    profile: &DataProfile,
+    policy: &StatisticsPolicy,
+) -> StatisticsUpdate {
+    let mut update = StatisticsUpdate::default();
+    for property in &mut schema.properties {
+        embed_property(
+            property,
+            "",
+            Some(profile),
+            profile.profiled_at,
+            policy,
+            &mut update,
+        );
+    }
+    update
+}
+
+/// Remove statistics that are stale at `now` from the properties of a schema
+/// object, when `policy.expire_stale` is set
+pub fn expire_statistics(
+    schema: &mut SchemaObject,
+    policy: &StatisticsPolicy,
+    now: DateTime<Utc>,
+) -> StatisticsUpdate {
+    let mut update = StatisticsUpdate::default();
+    for property in &mut schema.properties {
+        embed_property(property, "", None, now, policy, &mut update);
+    }
+    update
+}
+
+/// Time the embedded statistics of a property were taken
+pub fn statistics_profiled_at(property: &Property) -> Option<DateTime<Utc>> {
+    let statistics = property
+        .custom_properties
+        .iter()
+        .find(|p| p.property == STATISTICS_PROPERTY)?;
+    let profiled_at = statistics.value.get("profiledAt")?.as_str()?;
+    DateTime::parse_from_rfc3339(profiled_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn embed_property(
+    property: &mut Property,
+    prefix: &str,
+    profile: Option<&DataProfile>,
+    now: DateTime<Utc>,
+    policy: &StatisticsPolicy,
+    update: &mut StatisticsUpdate,
+) {
+    let path = if prefix.is_empty() {
+        property.name.clone()
+    } else {
+        format!("{}.{}", prefix, property.name)
+    };
+    for nested in &mut property.properties {
+        embed_property(nested, &path, profile, now, policy, update);
+    }
+
+    let existing = property
+        .custom_properties
+        .iter()
+        .position(|p| p.property == STATISTICS_PROPERTY);
+    // Statistics without a readable timestamp count as stale
+    let stale = statistics_profiled_at(property).is_none_or(|t| policy.is_stale(t, now));
+
+    match (profile.and_then(|p| p.columns.get(&path)), existing) {
+        (Some(statistics), None) => {
+            let profiled_at = profile.map_or(now, |p| p.profiled_at);
+            property
+                .custom_properties
+                .push(statistics.to_custom_property(profiled_at, policy));
+            update.refreshed.push(path);
+        }
+        (Some(statistics), Some(index)) => {
+            let replace = match policy.refresh {
+                StatisticsRefresh::Always => true,
+                StatisticsRefresh::IfStale => stale,
+                StatisticsRefresh::Never => false,
+            };
+            if replace {
+                let profiled_at = profile.map_or(now, |p| p.profiled_at);
+                property.custom_properties[index] =
+                    statistics.to_custom_property(profiled_at, policy);
+                update.refreshed.push(path);
+            } else {
+                update.kept.push(path);
+            }
+        }
+        (None, Some(index)) => {
+            if policy.expire_stale && stale {
+                property.custom_properties.remove(index);
+                update.expired.push(path);
+            } else {
+                update.kept.push(path);
+            }
+        }
+        (None, None) => {}
+    }
+}
+
+fn number(value: f64) -> Option<Value> {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        Some(Value::from(value as i64))
+    } else {
+        serde_json::Number::from_f64(value).map(Value::Number)
+    }
+}
+
+/// Truncate text to `max` characters, marking the cut with an ellipsis
+fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() <= max {
+        value.to_string()
+    } else {
+        let mut truncated: String = value.chars().take(max.saturating_sub(1)).collect();
+        truncated.push('…');
+        truncated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(records: &[&str], profiled_at: DateTime<Utc>) -> DataProfile {
+        let mut profiler = ColumnProfiler::new();
+        for record in records {
+            profiler.add_json(record).unwrap();
+        }
+        profiler.finish_at(profiled_at)
+    }
+
+    fn schema() -> SchemaObject {
+        SchemaObject::new("orders").with_properties(vec![
+            Property::new("id", "integer"),
+            Property::new("status", "string"),
+            Property::new("customer", "object")
+                .with_nested_properties(vec![Property::new("email", "string")]),
+        ])
+    }
+
+    fn statistics(property: &Property) -> Option<&Value> {
+        property
+            .custom_properties
+            .iter()
+            .find(|p| p.property == STATISTICS_PROPERTY)
+            .map(|p| &p.value)
+    }
+
+    #[test]
+    fn test_profiler_statistics() {
+        let now = Utc::now();
+        let profile = profile(
+            &[
+                r#"{"id": 1, "status": "open", "customer": {"email": "a@example.com"}}"#,
+                r#"{"id": 5, "status": "open"}"#,
+                r#"{"id": 3, "status": "closed", "customer": {"email": null}}"#,
+                r#"{"id": 2.5, "status": null}"#,
+            ],
+            now,
+        );
+
+        assert_eq!(profile.record_count, 4);
+        let id = &profile.columns["id"];
+        assert_eq!(id.null_count, 0);
+        assert_eq!(id.distinct_count, 4);
+        assert!(id.distinct_exact);
+        assert_eq!(id.min, Some(serde_json::json!(1)));
+        assert_eq!(id.max, Some(serde_json::json!(5)));
+        assert!(id.top_values.is_empty());
+
+        let status = &profile.columns["status"];
+        assert_eq!(status.null_percent(), 25.0);
+        assert_eq!(status.min, Some(serde_json::json!("closed")));
+        assert_eq!(
+            status.top_values,
+            vec![ValueCount {
+                value: "open".to_string(),
+                count: 2
+            }]
+        );
+
+        let email = &profile.columns["customer.email"];
+        assert_eq!(email.null_count, 3);
+        assert_eq!(email.distinct_count, 1);
+    }
+
+    #[test]
+    fn test_custom_property_truncates_values() {
+        let long = "x".repeat(100);
+        let records: Vec<String> = (0..3)
+            .map(|_| format!(r#"{{"note": "{}"}}"#, long))
+            .collect();
+        let records: Vec<&str> = records.iter().map(String::as_str).collect();
+        let now = Utc::now();
+        let profile = profile(&records, now);
+
+        let policy = StatisticsPolicy::new().with_max_value_length(10);
+        let property = profile.columns["note"].to_custom_property(now, &policy);
+        assert_eq!(property.property, STATISTICS_PROPERTY);
+        assert_eq!(property.value["profiledAt"], now.to_rfc3339());
+        assert_eq!(property.value["rowCount"], 3);
+        assert_eq!(property.value["nullPercent"], 0.0);
+        assert_eq!(property.value["max"], format!("{}…", "x".repeat(9)));
+        assert_eq!(
+            property.value["topValues"][0]["value"],
+            format!("{}…", "x".repeat(9))
+        );
+        assert_eq!(property.value["topValues"][0]["count"], 3);
+    }
+
+    #[test]
+    fn test_embed_statistics_with_refresh_policy() {
+        let first = Utc::now() - Duration::days(30);
+        let mut schema = schema();
+        let policy = StatisticsPolicy::new();
+
+        let update = embed_statistics(
+            &mut schema,
+            &profile(
+                &[r#"{"id": 1, "status": "open", "customer": {"email": "a@example.com"}}"#],
+                first,
+            ),
+            &policy,
+        );
+        assert_eq!(update.refreshed, vec!["id", "status", "customer.email"]);
+        let email = &schema.properties[2].properties[0];
+        assert_eq!(statistics_profiled_at(email), Some(first));
+
+        // A profile a day later keeps the fresh statistics
+        let second = first + Duration::days(1);
+        let update = embed_statistics(
+            &mut schema,
+            &profile(&[r#"{"id": 2, "status": "open"}"#], second),
+            &policy,
+        );
+        assert!(update.refreshed.is_empty());
+        assert_eq!(update.kept.len(), 3);
+
+        // ... unless the policy always refreshes
+        let always = StatisticsPolicy::new().with_refresh(StatisticsRefresh::Always);
+        let update = embed_statistics(
+            &mut schema,
+            &profile(&[r#"{"id": 2, "status": "open"}"#], second),
+            &always,
+        );
+        assert_eq!(update.refreshed, vec!["id", "status"]);
+        assert_eq!(statistics(&schema.properties[0]).unwrap()["min"], 2);
+
+        // A profile after the statistics went stale refreshes them and
+        // expires the statistics of columns it no longer covers
+        let third = second + Duration::days(10);
+        let update = embed_statistics(
+            &mut schema,
+            &profile(&[r#"{"id": 3, "status": "open"}"#], third),
+            &policy,
+        );
+        assert_eq!(update.refreshed, vec!["id", "status"]);
+        assert_eq!(update.expired, vec!["customer.email"]);
+        assert!(statistics(&schema.properties[2].properties[0]).is_none());
+        assert_eq!(statistics_profiled_at(&schema.properties[0]), Some(third));
+    }
+
+    #[test]
+    fn test_expire_statistics() {
+        let then = Utc::now() - Duration::days(8);
+        let mut schema = schema();
+        embed_statistics(
+            &mut schema,
+            &profile(&[r#"{"id": 1}"#], then),
+            &StatisticsPolicy::new(),
+        );
+
+        let keep = StatisticsPolicy::new().with_expire_stale(false);
+        let update = expire_statistics(&mut schema, &keep, Utc::now());
+        assert_eq!(update.kept, vec!["id"]);
+
+        let fresh = StatisticsPolicy::new().with_max_age(Duration::days(30));
+        assert!(
+            expire_statistics(&mut schema, &fresh, Utc::now())
+                .expired
+                .is_empty()
+        );
+
+        let update = expire_statistics(&mut schema, &StatisticsPolicy::new(), Utc::now());
+        assert_eq!(update.expired, vec!["id"]);
+        assert!(statistics(&schema.properties[0]).is_none());
+    }
+
+    #[test]
+    fn test_statistics_refresh_parse() {
+        assert_eq!(
+            StatisticsRefresh::parse("if_stale"),
+            Some(StatisticsRefresh::IfStale)
+        );
+        assert_eq!(
+            StatisticsRefresh::parse("Always"),
+            Some(StatisticsRefresh::Always)
+        );
+        assert_eq!(StatisticsRefresh::parse("sometimes"), None);
+    }
+}
@@ -0,0 +1,476 @@
+//! Relationship inference
+//!
+//! Proposes foreign key [`Relationship`]s between tables that do not declare
+//! them, by matching `*_id` style column names against the primary keys of the
+//! other tables: `orders.customer_id` is matched against `customers.id` (or
+//! `customer.id`, `customers.customer_id`, ...).
+//!
+//! Every suggestion carries a confidence score between 0 and 1 built from the
+//! naming evidence: how closely the column stem matches the table name, whether
+//! the target column is a declared primary key and whether the data types are
+//! compatible. When sample data is available, [`RelationshipSuggestion::apply_overlap`]
+//! folds in the share of distinct foreign key values found in the target
+//! column; with the `staging` feature, [`RelationshipInferrer::verify_staged`]
+//! measures that overlap in the staging database.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::inference::relationships::RelationshipInferrer;
+//! use data_modelling_core::models::{Column, Table};
+//!
+//! let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+//! id.primary_key = true;
+//! let customers = Table::new("customers".to_string(), vec![id]);
+//! let orders = Table::new(
+//!     "orders".to_string(),
+//!     vec![Column::new("customer_id".to_string(), "BIGINT".to_string())],
+//! );
+//!
+//! let suggestions = RelationshipInferrer::new().infer(&[customers, orders]);
+//! assert_eq!(suggestions.len(), 1);
+//! assert_eq!(suggestions[0].target_table, "customers");
+//! let relationship = suggestions[0].to_relationship();
+//! ```
+
+use crate::models::column::Column;
+use crate::models::enums::RelationshipType;
+use crate::models::odcs::converters::map_data_type_to_logical_type;
+use crate::models::relationship::{ForeignKeyDetails, Relationship};
+use crate::models::table::Table;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Column name suffixes marking a reference to another table
+pub const DEFAULT_REFERENCE_SUFFIXES: &[&str] = &["_id", "id", "_key", "_fk"];
+
+/// Suggestions below this confidence are dropped unless overridden
+pub const DEFAULT_MIN_CONFIDENCE: f64 = 0.5;
+
+/// Weight of the value overlap in the confidence of a verified suggestion
+const OVERLAP_WEIGHT: f64 = 0.6;
+
+/// Distinct foreign key values and how many of them exist in the target column
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueOverlap {
+    /// Distinct non-null values in the source column
+    pub distinct_values: u64,
+    /// Distinct source values also present in the target column
+    pub matched_values: u64,
+}
+
+impl ValueOverlap {
+    /// Measure the overlap of two value samples
+    pub fn compute<S, T>(source: S, target: T) -> Self
+    where
+        S: IntoIterator,
+        S::Item: Into<String>,
+        T: IntoIterator,
+        T::Item: Into<String>,
+    {
+        let source: HashSet<String> = source.into_iter().map(Into::into).collect();
+        let target: HashSet<String> = target.into_iter().map(Into::into).collect();
+        Self {
+            distinct_values: source.len() as u64,
+            matched_values: source.intersection(&target).count() as u64,
+        }
+    }
+
+    /// Share of source values found in the target, `None` without source values
+    pub fn ratio(&self) -> Option<f64> {
+        (self.distinct_values > 0).then(|| self.matched_values as f64 / self.distinct_values as f64)
+    }
+}
+
+/// A proposed foreign key relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipSuggestion {
+    pub source_table_id: Uuid,
+    pub source_table: String,
+    pub source_column: String,
+    pub target_table_id: Uuid,
+    pub target_table: String,
+    pub target_column: String,
+    /// Confidence between 0 and 1
+    pub confidence: f64,
+    /// Evidence behind the confidence score
+    pub reasons: Vec<String>,
+    /// Value overlap, once verified against data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlap: Option<ValueOverlap>,
+}
+
+impl RelationshipSuggestion {
+    /// Fold a measured value overlap into the confidence
+    ///
+    /// The verified confidence weighs the overlap ratio at 60% and the naming
+    /// evidence at 40%. Without any source values the confidence is unchanged.
+    pub fn apply_overlap(&mut self, overlap: ValueOverlap) {
+        if let Some(ratio) = overlap.ratio() {
+            self.confidence =
+                round(self.confidence * (1.0 - OVERLAP_WEIGHT) + ratio * OVERLAP_WEIGHT);
+            self.reasons.push(format!(
+                "{} of {} distinct values found in target",
+                overlap.matched_values, overlap.distinct_values
+            ));
+        }
+        self.overlap = Some(overlap);
+    }
+
+    /// Build the foreign key relationship this suggestion proposes
+    pub fn to_relationship(&self) -> Relationship {
+        let mut relationship = Relationship::new(self.source_table_id, self.target_table_id);
+        relationship.source_key = Some(self.source_column.clone());
+        relationship.target_key = Some(self.target_column.clone());
+        relationship.foreign_key_details = Some(ForeignKeyDetails {
+            source_column: self.source_column.clone(),
+            target_column: self.target_column.clone(),
+        });
+        relationship.relationship_type = Some(RelationshipType::ForeignKey);
+        relationship.notes = Some(format!(
+            "Inferred with confidence {:.2}: {}",
+            self.confidence,
+            self.reasons.join("; ")
+        ));
+        relationship
+    }
+}
+
+/// Proposes relationships from column naming patterns
+#[derive(Debug, Clone)]
+pub struct RelationshipInferrer {
+    suffixes: Vec<String>,
+    min_confidence: f64,
+}
+
+impl Default for RelationshipInferrer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelationshipInferrer {
+    pub fn new() -> Self {
+        Self {
+            suffixes: DEFAULT_REFERENCE_SUFFIXES
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+        }
+    }
+
+    /// Replace the column name suffixes that mark a reference
+    pub fn with_suffixes<I, S>(mut self, suffixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.suffixes = suffixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Drop suggestions below `min_confidence`
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Propose relationships between `tables`, most confident first
+    ///
+    /// Columns that are already the primary key of their own table are only
+    /// matched when the table is composite-keyed (e.g. link tables). For each
+    /// source column only the best matching target is kept.
+    pub fn infer(&self, tables: &[Table]) -> Vec<RelationshipSuggestion> {
+        let mut suggestions = Vec::new();
+
+        for source in tables {
+            let single_key = primary_keys(source).len() == 1;
+            for column in &source.columns {
+                if column.primary_key && single_key {
+                    continue;
+                }
+                let Some(stem) = self.stem(&column.name) else {
+                    continue;
+                };
+
+                let best = tables
+                    .iter()
+                    .filter_map(|target| self.score(source, column, &stem, target))
+                    .max_by(|a, b| a.confidence.total_cmp(&b.confidence));
+                if let Some(suggestion) = best
+                    && suggestion.confidence >= self.min_confidence
+                {
+                    suggestions.push(suggestion);
+                }
+            }
+        }
+
+        suggestions.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        suggestions
+    }
+
+    /// Measure value overlap in the staging database and fold it into each suggestion
+    ///
+    /// `partition_for` maps a table name to the staging partition holding its
+    /// records; suggestions whose tables have no partition are left unverified.
+    /// Columns are read from the top level of the staged JSON records.
+    #[cfg(feature = "staging")]
+    pub fn verify_staged(
+        &self,
+        db: &crate::staging::StagingDb,
+        suggestions: &mut [RelationshipSuggestion],
+        partition_for: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), super::InferenceError> {
+        for suggestion in suggestions.iter_mut() {
+            let (Some(source), Some(target)) = (
+                partition_for(&suggestion.source_table),
+                partition_for(&suggestion.target_table),
+            ) else {
+                continue;
+            };
+
+            let sql = format!(
+                "WITH fk AS (\
+                    SELECT DISTINCT json_extract_string(raw_json, '$.\"{source_column}\"') AS v \
+                    FROM staged_json WHERE partition_key = '{source}'\
+                 ), pk AS (\
+                    SELECT DISTINCT json_extract_string(raw_json, '$.\"{target_column}\"') AS v \
+                    FROM staged_json WHERE partition_key = '{target}'\
+                 ) \
+                 SELECT \
+                    (SELECT count(*) FROM fk WHERE v IS NOT NULL) AS distinct_values, \
+                    (SELECT count(*) FROM fk JOIN pk ON fk.v = pk.v) AS matched_values",
+                source_column = sql_literal(&suggestion.source_column),
+                target_column = sql_literal(&suggestion.target_column),
+                source = sql_literal(&source),
+                target = sql_literal(&target),
+            );
+            let rows = db
+                .query(&sql)
+                .map_err(|e| super::InferenceError::Staging(e.to_string()))?;
+            let count = |key: &str| {
+                rows.first()
+                    .and_then(|row| row.get(key))
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0)
+            };
+            suggestion.apply_overlap(ValueOverlap {
+                distinct_values: count("distinct_values"),
+                matched_values: count("matched_values"),
+            });
+        }
+
+        suggestions.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        Ok(())
+    }
+
+    /// The referenced entity name of a column, e.g. `customer` for `customer_id`
+    fn stem(&self, column: &str) -> Option<String> {
+        let lower = column.to_lowercase();
+        self.suffixes.iter().find_map(|suffix| {
+            let stem = lower.strip_suffix(suffix.to_lowercase().as_str())?;
+            let stem = stem.trim_end_matches('_');
+            (!stem.is_empty()).then(|| stem.to_string())
+        })
+    }
+
+    /// Score `target` as the table referenced by `column`
+    fn score(
+        &self,
+        source: &Table,
+        column: &Column,
+        stem: &str,
+        target: &Table,
+    ) -> Option<RelationshipSuggestion> {
+        let table_name = target.name.to_lowercase();
+        // Ignore schema/catalog qualifiers such as `sales.customers`
+        let table_name = table_name.rsplit('.').next().unwrap_or_default();
+
+        let mut reasons = Vec::new();
+        let mut confidence: f64 = if table_name == stem {
+            reasons.push(format!("column stem '{}' matches table name", stem));
+            0.6
+        } else if plural_forms(stem).iter().any(|p| p == table_name)
+            || plural_forms(table_name).iter().any(|p| p == stem)
+        {
+            reasons.push(format!(
+                "column stem '{}' matches table name (plural)",
+                stem
+            ));
+            0.55
+        } else {
+            return None;
+        };
+
+        let keys = primary_keys(target);
+        let target_column = match keys.as_slice() {
+            [key] => {
+                reasons.push(format!("'{}' is the primary key", key.name));
+                confidence += 0.2;
+                *key
+            }
+            _ => {
+                let candidate = target.columns.iter().find(|c| {
+                    let name = c.name.to_lowercase();
+                    name == "id" || name == column.name.to_lowercase()
+                })?;
+                reasons.push(format!("'{}' looks like a key column", candidate.name));
+                confidence += 0.1;
+                candidate
+            }
+        };
+        // A table's own key is not a reference to itself
+        if source.id == target.id && target_column.name == column.name {
+            return None;
+        }
+
+        if target_column.name.eq_ignore_ascii_case(&column.name) {
+            reasons.push("column names match".to_string());
+            confidence += 0.1;
+        }
+
+        let (source_type, _) = map_data_type_to_logical_type(&column.data_type);
+        let (target_type, _) = map_data_type_to_logical_type(&target_column.data_type);
+        if source_type == target_type {
+            reasons.push(format!("both columns are {}", source_type));
+            confidence += 0.1;
+        } else {
+            reasons.push(format!(
+                "type mismatch ({} vs {})",
+                source_type, target_type
+            ));
+            confidence -= 0.35;
+        }
+
+        Some(RelationshipSuggestion {
+            source_table_id: source.id,
+            source_table: source.name.clone(),
+            source_column: column.name.clone(),
+            target_table_id: target.id,
+            target_table: target.name.clone(),
+            target_column: target_column.name.clone(),
+            confidence: round(confidence.clamp(0.0, 1.0)),
+            reasons,
+            overlap: None,
+        })
+    }
+}
+
+fn primary_keys(table: &Table) -> Vec<&Column> {
+    table.columns.iter().filter(|c| c.primary_key).collect()
+}
+
+/// Common English plurals of `word`
+fn plural_forms(word: &str) -> Vec<String> {
+    let mut forms = vec![format!("{}s", word), format!("{}es", word)];
+    if let Some(stem) = word.strip_suffix('y') {
+        forms.push(format!("{}ies", stem));
+    }
+    forms
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(feature = "staging")]
+fn sql_literal(value: &str) -> String {
+    value.replace('\'', "''").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, data_type: &str, primary_key: bool) -> Column {
+        let mut column = Column::new(name.to_string(), data_type.to_string());
+        column.primary_key = primary_key;
+        column
+    }
+
+    fn tables() -> Vec<Table> {
+        vec![
+            Table::new(
+                "customers".to_string(),
+                vec![column("id", "BIGINT", true), column("name", "TEXT", false)],
+            ),
+            Table::new(
+                "category".to_string(),
+                vec![
+                    column("category_id", "INT", true),
+                    column("parent_id", "INT", false),
+                ],
+            ),
+            Table::new(
+                "orders".to_string(),
+                vec![
+                    column("id", "BIGINT", true),
+                    column("customer_id", "BIGINT", false),
+                    column("categoryId", "VARCHAR", false),
+                    column("tracking_id", "TEXT", false),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_infer_matches_id_columns_to_primary_keys() {
+        let tables = tables();
+        let suggestions = RelationshipInferrer::new().infer(&tables);
+
+        assert_eq!(suggestions.len(), 1);
+        let customer = &suggestions[0];
+        assert_eq!(customer.source_table, "orders");
+        assert_eq!(customer.source_column, "customer_id");
+        assert_eq!(customer.target_table, "customers");
+        assert_eq!(customer.target_column, "id");
+        assert_eq!(customer.confidence, 0.85);
+
+        let relationship = customer.to_relationship();
+        assert_eq!(relationship.source_table_id, tables[2].id);
+        assert_eq!(relationship.target_table_id, tables[0].id);
+        assert_eq!(
+            relationship.relationship_type,
+            Some(RelationshipType::ForeignKey)
+        );
+    }
+
+    #[test]
+    fn test_low_confidence_suggestions_are_kept_on_request() {
+        let suggestions = RelationshipInferrer::new()
+            .with_min_confidence(0.0)
+            .infer(&tables());
+
+        // categoryId is a string while category_id is an integer
+        let category = suggestions
+            .iter()
+            .find(|s| s.source_column == "categoryId")
+            .unwrap();
+        assert_eq!(category.target_table, "category");
+        assert_eq!(category.target_column, "category_id");
+        assert_eq!(category.confidence, 0.45);
+        assert!(!suggestions.iter().any(|s| s.source_column == "tracking_id"));
+        assert!(!suggestions.iter().any(|s| s.source_column == "parent_id"));
+    }
+
+    #[test]
+    fn test_overlap_adjusts_confidence() {
+        let tables = tables();
+        let mut suggestion = RelationshipInferrer::new().infer(&tables).remove(0);
+
+        let overlap = ValueOverlap::compute(["1", "2", "2", "3", "4"], ["1", "2", "3", "5"]);
+        assert_eq!(overlap.distinct_values, 4);
+        assert_eq!(overlap.matched_values, 3);
+        suggestion.apply_overlap(overlap);
+        assert_eq!(suggestion.confidence, 0.79);
+        assert_eq!(suggestion.overlap, Some(overlap));
+
+        let mut unrelated = suggestion.clone();
+        unrelated.apply_overlap(ValueOverlap::compute(["9"], ["1"]));
+        assert!(unrelated.confidence < 0.5);
+    }
+}
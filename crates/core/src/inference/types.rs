@@ -4,10 +4,21 @@
 #![allow(clippy::only_used_in_recursion)]
 
 use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
 use super::formats::Format;
+use crate::models::odcs::{CustomProperty, LogicalTypeOptions, Property, SchemaObject};
+
+/// Non-null samples needed before a field's type is trusted fully
+pub const CONFIDENT_SAMPLE_COUNT: usize = 10;
+
+/// Confidence score below which a field is considered ambiguous by default
+pub const AMBIGUOUS_CONFIDENCE_THRESHOLD: f64 = 0.8;
+
+/// Maximum number of sample sources recorded per field
+pub const MAX_FIELD_SOURCES: usize = 16;
 
 /// Inferred JSON type
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -51,6 +62,15 @@ impl InferredType {
         }
     }
 
+    /// Type name with integers and numbers in one class, used to decide
+    /// whether two observations agree
+    pub fn type_class(&self) -> &'static str {
+        match self {
+            InferredType::Integer => "number",
+            other => other.type_name(),
+        }
+    }
+
     /// Check if this type can be merged with another type
     pub fn can_merge_with(&self, other: &InferredType) -> bool {
         match (self, other) {
@@ -167,6 +187,9 @@ pub struct InferredField {
     /// Description (can be set by LLM later)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Confidence in the inferred type, with the samples it was inferred from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<FieldConfidence>,
 }
 
 impl InferredField {
@@ -179,6 +202,7 @@ impl InferredField {
             occurrences: 1,
             examples: Vec::new(),
             description: None,
+            confidence: None,
         }
     }
 
@@ -203,6 +227,11 @@ impl InferredField {
 
     /// Merge with another field
     pub fn merge_with(self, other: InferredField) -> InferredField {
+        let types_agree = self.field_type.type_class() == other.field_type.type_class();
+        let confidence = match (self.confidence, other.confidence) {
+            (Some(a), Some(b)) => Some(a.merge_with(b, types_agree)),
+            (a, b) => a.or(b),
+        };
         InferredField {
             field_type: self.field_type.merge_with(other.field_type),
             required: self.required && other.required,
@@ -218,10 +247,115 @@ impl InferredField {
                 examples
             },
             description: self.description.or(other.description),
+            confidence,
+        }
+    }
+}
+
+/// Confidence in an inferred field
+///
+/// Recorded from the samples a field was inferred from, so that ambiguous
+/// fields (few samples, mostly null, or conflicting types) can be reviewed first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldConfidence {
+    /// Number of samples in which the field appeared
+    pub sample_count: usize,
+    /// Share of those samples with a null value (0.0 - 1.0)
+    pub null_ratio: f64,
+    /// Share of non-null samples agreeing with the most common type (0.0 - 1.0)
+    pub type_agreement: f64,
+    /// Sources (partitions or files) that contributed samples, capped at
+    /// [`MAX_FIELD_SOURCES`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
+}
+
+impl FieldConfidence {
+    /// Number of non-null samples
+    pub fn non_null_count(&self) -> f64 {
+        self.sample_count as f64 * (1.0 - self.null_ratio)
+    }
+
+    /// Overall confidence score (0.0 - 1.0)
+    ///
+    /// The type agreement, scaled down when fewer than
+    /// [`CONFIDENT_SAMPLE_COUNT`] non-null samples support it.
+    pub fn score(&self) -> f64 {
+        let support = (self.non_null_count() / CONFIDENT_SAMPLE_COUNT as f64).min(1.0);
+        self.type_agreement * support
+    }
+
+    /// Record a contributing source
+    pub fn add_source(&mut self, source: &str) {
+        if self.sources.len() < MAX_FIELD_SOURCES && !self.sources.iter().any(|s| s == source) {
+            self.sources.push(source.to_string());
+        }
+    }
+
+    /// Combine the confidence of the same field inferred from two sample sets
+    ///
+    /// When the two sets disagree on the field's type, only the larger set's
+    /// agreeing samples count as agreeing.
+    pub fn merge_with(self, other: FieldConfidence, types_agree: bool) -> FieldConfidence {
+        let sample_count = self.sample_count + other.sample_count;
+        let (a, b) = (self.non_null_count(), other.non_null_count());
+        let null_ratio = if sample_count == 0 {
+            0.0
+        } else {
+            1.0 - (a + b) / sample_count as f64
+        };
+        let type_agreement = if a + b == 0.0 {
+            1.0
+        } else if types_agree {
+            (a * self.type_agreement + b * other.type_agreement) / (a + b)
+        } else {
+            (a * self.type_agreement).max(b * other.type_agreement) / (a + b)
+        };
+        let mut merged = FieldConfidence {
+            sample_count,
+            null_ratio,
+            type_agreement,
+            sources: self.sources,
+        };
+        for source in &other.sources {
+            merged.add_source(source);
         }
+        merged
+    }
+
+    /// Confidence as an ODCS custom property value
+    pub fn to_custom_property(&self) -> CustomProperty {
+        CustomProperty::new(
+            "inferenceConfidence",
+            serde_json::json!({
+                "score": round(self.score()),
+                "sampleCount": self.sample_count,
+                "nullRatio": round(self.null_ratio),
+                "typeAgreement": round(self.type_agreement),
+            }),
+        )
+    }
+}
+
+impl fmt::Display for FieldConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "confidence {:.2}: {} samples, {:.0}% null, {:.0}% type agreement",
+            self.score(),
+            self.sample_count,
+            self.null_ratio * 100.0,
+            self.type_agreement * 100.0
+        )
     }
 }
 
+/// Round a ratio to three decimals for display in contracts
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
 /// Complete inferred schema
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -242,6 +376,38 @@ pub struct InferredSchema {
     /// Field statistics
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub field_stats: HashMap<String, FieldStats>,
+    /// Fields whose samples disagreed on the type, and how each was resolved
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<TypeConflict>,
+}
+
+/// A field whose samples disagree on its type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeConflict {
+    /// Field path (e.g. `$.customer.id`)
+    pub path: String,
+    /// Number of non-null samples per observed type
+    pub observed: BTreeMap<String, usize>,
+    /// Type the field was resolved to, or `quarantined` when it was left out
+    pub resolution: String,
+}
+
+impl fmt::Display for TypeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let observed: Vec<String> = self
+            .observed
+            .iter()
+            .map(|(type_name, count)| format!("{} x{}", type_name, count))
+            .collect();
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            observed.join(", "),
+            self.resolution
+        )
+    }
 }
 
 /// Statistics for a field
@@ -275,6 +441,7 @@ impl InferredSchema {
             record_count: 0,
             partition: None,
             field_stats: HashMap::new(),
+            conflicts: Vec::new(),
         }
     }
 
@@ -366,6 +533,127 @@ impl InferredSchema {
             }
         }
     }
+
+    /// Fields whose confidence score is below `threshold`, least confident first
+    ///
+    /// Nested fields are named by their dot path (e.g. `customer.email`).
+    pub fn ambiguous_fields(&self, threshold: f64) -> Vec<(String, &FieldConfidence)> {
+        let mut fields = Vec::new();
+        collect_confidence(&self.root, "", &mut fields);
+        fields.retain(|(_, confidence)| confidence.score() < threshold);
+        fields.sort_by(|a, b| {
+            a.1.score()
+                .total_cmp(&b.1.score())
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        fields
+    }
+
+    /// Convert to an ODCS schema object with nested properties
+    ///
+    /// Object fields become nested properties, arrays carry their item type,
+    /// and detected string formats are kept as `logicalTypeOptions.format`.
+    /// Field confidence and sample sources are recorded as the
+    /// `inferenceConfidence` and `inferenceSources` custom properties.
+    pub fn to_schema_object(&self, name: &str) -> SchemaObject {
+        let mut schema = SchemaObject::new(name).with_physical_type("object");
+        if let Some(ref desc) = self.description {
+            schema = schema.with_description(desc.clone());
+        }
+        if let InferredType::Object { ref properties } = self.root {
+            schema = schema.with_properties(
+                properties
+                    .iter()
+                    .map(|(field_name, field)| field_to_property(field_name, field))
+                    .collect(),
+            );
+        }
+        schema
+    }
+}
+
+/// Collect the confidence of every field below an inferred type
+fn collect_confidence<'a>(
+    inferred: &'a InferredType,
+    prefix: &str,
+    fields: &mut Vec<(String, &'a FieldConfidence)>,
+) {
+    match inferred {
+        InferredType::Object { properties } => {
+            for (name, field) in properties {
+                let path = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", prefix, name)
+                };
+                if let Some(ref confidence) = field.confidence {
+                    fields.push((path.clone(), confidence));
+                }
+                collect_confidence(&field.field_type, &path, fields);
+            }
+        }
+        InferredType::Array { items } => collect_confidence(items, prefix, fields),
+        _ => {}
+    }
+}
+
+/// Convert an inferred field to an ODCS property
+fn field_to_property(name: &str, field: &InferredField) -> Property {
+    let mut property = type_to_property(name, &field.field_type);
+    property.required = field.required && !field.nullable;
+    property.description = field.description.clone();
+    property.examples = field.examples.clone();
+    if let Some(ref confidence) = field.confidence {
+        property = property.with_custom_property(confidence.to_custom_property());
+        if !confidence.sources.is_empty() {
+            property = property.with_custom_property(CustomProperty::new(
+                "inferenceSources",
+                serde_json::json!(confidence.sources),
+            ));
+        }
+    }
+    property
+}
+
+/// Convert an inferred type to an ODCS property
+fn type_to_property(name: &str, inferred: &InferredType) -> Property {
+    match inferred {
+        InferredType::Boolean => Property::new(name, "boolean"),
+        InferredType::Integer => Property::new(name, "integer"),
+        InferredType::Number => Property::new(name, "number"),
+        InferredType::String { format } => {
+            let logical_type = match format {
+                Some(Format::Date) => "date",
+                Some(Format::DateTime) => "timestamp",
+                Some(Format::Time) => "time",
+                _ => "string",
+            };
+            let mut property = Property::new(name, logical_type);
+            if logical_type == "string"
+                && let Some(fmt) = format.and_then(|f| f.as_json_schema_format())
+            {
+                property.logical_type_options = Some(LogicalTypeOptions {
+                    format: Some(fmt.to_string()),
+                    ..Default::default()
+                });
+            }
+            property
+        }
+        InferredType::Array { items } => {
+            Property::new(name, "array").with_items(type_to_property("items", items))
+        }
+        InferredType::Object { properties } => Property::new(name, "object")
+            .with_nested_properties(
+                properties
+                    .iter()
+                    .map(|(field_name, field)| field_to_property(field_name, field))
+                    .collect(),
+            ),
+        // Null-only, unknown and mixed fields cannot be typed more precisely
+        InferredType::Null | InferredType::Unknown | InferredType::Mixed { .. } => {
+            Property::new(name, "string")
+        }
+    }
 }
 
 impl Default for InferredSchema {
@@ -420,6 +708,42 @@ mod tests {
         assert!(!merged.required); // One was optional, so merged is optional
     }
 
+    #[test]
+    fn test_field_confidence_merge() {
+        let confidence = |sample_count, null_ratio, source: &str| FieldConfidence {
+            sample_count,
+            null_ratio,
+            type_agreement: 1.0,
+            sources: vec![source.to_string()],
+        };
+        let f1 = InferredField {
+            confidence: Some(confidence(30, 0.0, "a")),
+            ..InferredField::new(InferredType::Integer)
+        };
+        let f2 = InferredField {
+            confidence: Some(confidence(20, 0.5, "b")),
+            ..InferredField::new(InferredType::Number)
+        };
+        let merged = f1.clone().merge_with(f2).confidence.unwrap();
+        assert_eq!(merged.sample_count, 50);
+        assert!((merged.null_ratio - 0.2).abs() < 1e-9);
+        assert_eq!(merged.type_agreement, 1.0);
+        assert_eq!(merged.sources, ["a", "b"]);
+        assert_eq!(merged.score(), 1.0);
+
+        // Conflicting types: only the larger side agrees
+        let f3 = InferredField {
+            confidence: Some(confidence(10, 0.0, "c")),
+            ..InferredField::new(InferredType::Boolean)
+        };
+        let merged = f1.merge_with(f3).confidence.unwrap();
+        assert_eq!(merged.type_agreement, 0.75);
+        assert_eq!(
+            merged.to_string(),
+            "confidence 0.75: 40 samples, 0% null, 75% type agreement"
+        );
+    }
+
     #[test]
     fn test_schema_to_json_schema() {
         let mut properties = BTreeMap::new();
@@ -436,6 +760,7 @@ mod tests {
             record_count: 10,
             partition: None,
             field_stats: HashMap::new(),
+            conflicts: Vec::new(),
         };
 
         let json_schema = schema.to_json_schema();
@@ -444,4 +769,88 @@ mod tests {
         assert!(json_schema["properties"]["name"].is_object());
         assert!(json_schema["properties"]["age"].is_object());
     }
+
+    #[test]
+    fn test_schema_to_schema_object() {
+        let mut address = BTreeMap::new();
+        address.insert(
+            "city".to_string(),
+            InferredField::new(InferredType::String { format: None }),
+        );
+
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "created".to_string(),
+            InferredField::new(InferredType::String {
+                format: Some(Format::DateTime),
+            }),
+        );
+        properties.insert(
+            "email".to_string(),
+            InferredField {
+                confidence: Some(FieldConfidence {
+                    sample_count: 4,
+                    null_ratio: 0.5,
+                    type_agreement: 1.0,
+                    sources: vec!["2024-01".to_string()],
+                }),
+                ..InferredField::new(InferredType::String {
+                    format: Some(Format::Email),
+                })
+            }
+            .with_required(false),
+        );
+        properties.insert(
+            "address".to_string(),
+            InferredField::new(InferredType::Object {
+                properties: address,
+            }),
+        );
+
+        let schema = InferredSchema {
+            root: InferredType::Object { properties },
+            ..InferredSchema::new()
+        };
+
+        let object = schema.to_schema_object("people");
+        assert_eq!(object.name, "people");
+        assert_eq!(object.property_count(), 3);
+
+        let created = object.get_property("created").unwrap();
+        assert_eq!(created.logical_type, "timestamp");
+        assert!(created.required);
+
+        let email = object.get_property("email").unwrap();
+        assert_eq!(email.logical_type, "string");
+        assert!(!email.required);
+        assert_eq!(
+            email
+                .logical_type_options
+                .as_ref()
+                .unwrap()
+                .format
+                .as_deref(),
+            Some("email")
+        );
+        assert_eq!(
+            email.custom_properties,
+            [
+                CustomProperty::new(
+                    "inferenceConfidence",
+                    serde_json::json!({
+                        "score": 0.2,
+                        "sampleCount": 4,
+                        "nullRatio": 0.5,
+                        "typeAgreement": 1.0
+                    })
+                ),
+                CustomProperty::new("inferenceSources", serde_json::json!(["2024-01"])),
+            ]
+        );
+        assert!(created.custom_properties.is_empty());
+
+        let address = object.get_property("address").unwrap();
+        assert_eq!(address.properties.len(), 1);
+        assert_eq!(address.properties[0].name, "city");
+    }
 }
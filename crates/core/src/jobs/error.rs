@@ -0,0 +1,54 @@
+//! Error types for job operations
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::job::{JobKind, JobStatus};
+use crate::storage::StorageError;
+
+/// Errors that can occur while enqueueing, tracking or running jobs
+#[derive(Error, Debug)]
+pub enum JobError {
+    /// No job with the given ID
+    #[error("Job not found: {0}")]
+    NotFound(Uuid),
+
+    /// No handler is registered for the job kind
+    #[error("No handler registered for {0} jobs")]
+    NoHandler(JobKind),
+
+    /// The job is not in a state that allows the operation
+    #[error("Job {id} is {status}: {message}")]
+    InvalidState {
+        id: Uuid,
+        status: JobStatus,
+        message: String,
+    },
+
+    /// The job was cancelled while running
+    #[error("Job {0} was cancelled")]
+    Cancelled(Uuid),
+
+    /// Job store error
+    #[error("Job store error: {0}")]
+    StoreError(String),
+
+    /// Job (de)serialization error
+    #[error("Serialization error: {0}")]
+    SerializationError(String),
+}
+
+impl From<StorageError> for JobError {
+    fn from(err: StorageError) -> Self {
+        JobError::StoreError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for JobError {
+    fn from(err: serde_json::Error) -> Self {
+        JobError::SerializationError(err.to_string())
+    }
+}
+
+/// Result type for job operations
+pub type JobResult<T> = Result<T, JobError>;
@@ -0,0 +1,355 @@
+//! Built-in job handlers
+//!
+//! Payloads are the JSON form of the request types in this module, e.g. an
+//! import job's payload is an [`ImportJobRequest`].
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use super::job::{JobKind, JobProgress};
+use super::queue::{JobContext, JobHandler};
+use crate::export::{ExportOptions, ExporterRegistry};
+use crate::import::ImporterRegistry;
+use crate::models::Table;
+use crate::validation::schema;
+
+/// Payload of an import job
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportJobRequest {
+    /// Importer name (see [`ImporterRegistry`]); detected when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Document to import
+    pub content: String,
+}
+
+/// Payload of an export job
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportJobRequest {
+    /// Exporter name (see [`ExporterRegistry`])
+    pub format: String,
+    /// Tables to export
+    pub tables: Vec<Table>,
+    /// Target dialect (e.g. `postgres` for SQL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialect: Option<String>,
+    /// Target format version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Exporter-specific options
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, String>,
+}
+
+/// Payload of a validation job
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationJobRequest {
+    /// Document format (see [`VALIDATION_FORMATS`])
+    pub format: String,
+    /// Document to validate
+    pub content: String,
+}
+
+/// Formats accepted by validation jobs
+pub const VALIDATION_FORMATS: &[&str] = &[
+    "odcs",
+    "odcl",
+    "openapi",
+    "protobuf",
+    "avro",
+    "json-schema",
+    "odps",
+    "cads",
+    "sql",
+    "workspace",
+    "relationships",
+    "decision",
+    "knowledge",
+    "sketch",
+];
+
+type Validator = fn(&str) -> Result<(), String>;
+
+fn validator(format: &str) -> Option<Validator> {
+    let validate: Validator = match format.to_lowercase().as_str() {
+        "odcs" => schema::validate_odcs_internal,
+        "odcl" => schema::validate_odcl_internal,
+        "openapi" => schema::validate_openapi_internal,
+        "protobuf" => schema::validate_protobuf_internal,
+        "avro" => schema::validate_avro_internal,
+        "json-schema" | "jsonschema" => schema::validate_json_schema_internal,
+        "odps" => schema::validate_odps_internal,
+        "cads" => schema::validate_cads_internal,
+        "sql" => schema::validate_sql_internal,
+        "workspace" => schema::validate_workspace_internal,
+        "relationships" => schema::validate_relationships_internal,
+        "decision" => schema::validate_decision_internal,
+        "knowledge" => schema::validate_knowledge_internal,
+        "sketch" => schema::validate_sketch_internal,
+        _ => return None,
+    };
+    Some(validate)
+}
+
+fn parse_payload<T: serde::de::DeserializeOwned>(payload: &Value) -> Result<T, String> {
+    serde_json::from_value(payload.clone()).map_err(|e| format!("Invalid job payload: {}", e))
+}
+
+async fn report_step(ctx: &JobContext<'_>, message: String) -> Result<(), String> {
+    ctx.report_progress(JobProgress {
+        current: 0,
+        total: Some(1),
+        message: Some(message),
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Imports a document with the built-in importers
+///
+/// Result: `{format, confidence, result}` where `result` is the
+/// [`ImportResult`](crate::import::ImportResult).
+pub struct ImportJobHandler;
+
+#[async_trait(?Send)]
+impl JobHandler for ImportJobHandler {
+    fn kind(&self) -> JobKind {
+        JobKind::Import
+    }
+
+    async fn run(&self, payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String> {
+        let request: ImportJobRequest = parse_payload(payload)?;
+        let registry = ImporterRegistry::default();
+        let (format, confidence, result) = match request.format {
+            Some(format) => {
+                report_step(ctx, format!("Importing {}", format)).await?;
+                let result = registry
+                    .import(&format, &request.content)
+                    .map_err(|e| e.to_string())?;
+                (format, 1.0, result)
+            }
+            None => {
+                report_step(ctx, "Detecting format".to_string()).await?;
+                let detected = registry
+                    .import_auto(&request.content)
+                    .map_err(|e| e.to_string())?;
+                (detected.format, detected.confidence, detected.result)
+            }
+        };
+        Ok(json!({
+            "format": format,
+            "confidence": confidence,
+            "result": serde_json::to_value(result).map_err(|e| e.to_string())?,
+        }))
+    }
+}
+
+/// Exports tables with the built-in exporters
+///
+/// Result: the [`ExportResult`](crate::export::ExportResult) (`{content, format}`).
+pub struct ExportJobHandler;
+
+#[async_trait(?Send)]
+impl JobHandler for ExportJobHandler {
+    fn kind(&self) -> JobKind {
+        JobKind::Export
+    }
+
+    async fn run(&self, payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String> {
+        let request: ExportJobRequest = parse_payload(payload)?;
+        let mut options = ExportOptions::new();
+        if let Some(dialect) = request.dialect {
+            options = options.with_dialect(dialect);
+        }
+        if let Some(version) = request.version {
+            options = options.with_version(version);
+        }
+        for (key, value) in request.properties {
+            options = options.with_property(key, value);
+        }
+
+        report_step(
+            ctx,
+            format!(
+                "Exporting {} table(s) to {}",
+                request.tables.len(),
+                request.format
+            ),
+        )
+        .await?;
+        let result = ExporterRegistry::default()
+            .export_tables(&request.format, &request.tables, &options)
+            .map_err(|e| e.to_string())?;
+        serde_json::to_value(result).map_err(|e| e.to_string())
+    }
+}
+
+/// Validates a document against its format's schema
+///
+/// An invalid document is a successful job with result
+/// `{format, valid: false, error}`; only unknown formats and malformed
+/// payloads fail the job.
+pub struct ValidationJobHandler;
+
+#[async_trait(?Send)]
+impl JobHandler for ValidationJobHandler {
+    fn kind(&self) -> JobKind {
+        JobKind::Validation
+    }
+
+    async fn run(&self, payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String> {
+        let request: ValidationJobRequest = parse_payload(payload)?;
+        let validate = validator(&request.format).ok_or_else(|| {
+            format!(
+                "Unknown validation format: {}. Available: {}",
+                request.format,
+                VALIDATION_FORMATS.join(", ")
+            )
+        })?;
+
+        report_step(ctx, format!("Validating {}", request.format)).await?;
+        let error = validate(&request.content).err();
+        Ok(json!({
+            "format": request.format,
+            "valid": error.is_none(),
+            "error": error,
+        }))
+    }
+}
+
+/// Runs a data pipeline
+///
+/// The payload is a serialized [`PipelineConfig`](crate::pipeline::PipelineConfig).
+/// Result: `{runId, status, stagesCompleted, durationMs, outputs}`.
+#[cfg(feature = "pipeline")]
+pub struct PipelineJobHandler;
+
+#[cfg(feature = "pipeline")]
+#[async_trait(?Send)]
+impl JobHandler for PipelineJobHandler {
+    fn kind(&self) -> JobKind {
+        JobKind::Pipeline
+    }
+
+    async fn run(&self, payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String> {
+        use crate::pipeline::{PipelineConfig, PipelineExecutor};
+
+        let config: PipelineConfig = parse_payload(payload)?;
+        report_step(ctx, "Running pipeline".to_string()).await?;
+        // Pipeline stages block on file and database I/O, so they run off the
+        // async worker threads
+        let report = tokio::task::spawn_blocking(move || {
+            PipelineExecutor::new(config).and_then(|mut executor| executor.run())
+        })
+        .await
+        .map_err(|e| format!("Pipeline task failed: {}", e))?
+        .map_err(|e| e.to_string())?;
+        if !report.is_success() {
+            return Err(format!("Pipeline {} {}", report.run_id, report.status));
+        }
+        Ok(json!({
+            "runId": report.run_id,
+            "status": report.status,
+            "stagesCompleted": report
+                .stages_completed
+                .iter()
+                .map(|stage| stage.name())
+                .collect::<Vec<_>>(),
+            "durationMs": report.duration_ms,
+            "outputs": serde_json::to_value(&report.outputs).map_err(|e| e.to_string())?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::{InMemoryJobStore, JobQueue, JobStatus};
+
+    #[tokio::test]
+    async fn test_import_then_export_jobs() {
+        let queue = JobQueue::with_builtin_handlers(InMemoryJobStore::new());
+        let import = queue
+            .enqueue(
+                JobKind::Import,
+                serde_json::to_value(ImportJobRequest {
+                    format: Some("sql".to_string()),
+                    content: "CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(100));"
+                        .to_string(),
+                })
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let job = queue.run_next().await.unwrap().unwrap();
+        assert_eq!(job.id, import.id);
+        assert_eq!(job.status, JobStatus::Succeeded, "{:?}", job.error);
+        let result = job.result.unwrap();
+        assert_eq!(result["format"], "sql");
+        assert_eq!(result["result"]["tables"][0]["name"], "users");
+
+        let table = Table::new("users".to_string(), Vec::new());
+        let export = queue
+            .enqueue(
+                JobKind::Export,
+                serde_json::to_value(ExportJobRequest {
+                    format: "sql".to_string(),
+                    tables: vec![table],
+                    dialect: Some("postgres".to_string()),
+                    ..Default::default()
+                })
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let job = queue.run_next().await.unwrap().unwrap();
+        assert_eq!(job.id, export.id);
+        assert_eq!(job.status, JobStatus::Succeeded, "{:?}", job.error);
+        assert!(
+            job.result.unwrap()["content"]
+                .as_str()
+                .unwrap()
+                .contains("users")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validation_job_reports_invalid_documents() {
+        let queue = JobQueue::with_builtin_handlers(InMemoryJobStore::new());
+        queue
+            .enqueue(
+                JobKind::Validation,
+                json!({"format": "avro", "content": "not json"}),
+            )
+            .await
+            .unwrap();
+        queue
+            .enqueue(
+                JobKind::Validation,
+                json!({"format": "yaml", "content": "a: 1"}),
+            )
+            .await
+            .unwrap();
+
+        let attempts = queue.run_pending().await.unwrap();
+        assert_eq!(attempts[0].status, JobStatus::Succeeded);
+        let result = attempts[0].result.as_ref().unwrap();
+        assert_eq!(result["valid"], false);
+        assert!(result["error"].is_string());
+
+        assert_eq!(attempts[1].status, JobStatus::Failed);
+        assert!(
+            attempts[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .starts_with("Unknown validation format: yaml")
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Job records
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Default number of attempts before a job is marked as failed
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 1;
+
+/// Kind of work a job performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobKind {
+    /// Import a schema into tables
+    Import,
+    /// Export tables to a format
+    Export,
+    /// Run a data pipeline
+    Pipeline,
+    /// Validate a document against its schema
+    Validation,
+}
+
+impl JobKind {
+    /// All job kinds
+    pub const ALL: [JobKind; 4] = [
+        JobKind::Import,
+        JobKind::Export,
+        JobKind::Pipeline,
+        JobKind::Validation,
+    ];
+
+    /// Name of the kind, as used in serialized jobs
+    pub fn name(&self) -> &'static str {
+        match self {
+            JobKind::Import => "import",
+            JobKind::Export => "export",
+            JobKind::Pipeline => "pipeline",
+            JobKind::Validation => "validation",
+        }
+    }
+}
+
+impl fmt::Display for JobKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for JobKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown job kind: {}", s))
+    }
+}
+
+/// Lifecycle state of a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Waiting for a worker
+    Queued,
+    /// Claimed by a worker
+    Running,
+    /// Finished with a result
+    Succeeded,
+    /// Finished with an error after its last attempt
+    Failed,
+    /// Cancelled before finishing
+    Cancelled,
+}
+
+impl JobStatus {
+    /// All job statuses
+    pub const ALL: [JobStatus; 5] = [
+        JobStatus::Queued,
+        JobStatus::Running,
+        JobStatus::Succeeded,
+        JobStatus::Failed,
+        JobStatus::Cancelled,
+    ];
+
+    /// Name of the status, as used in serialized jobs
+    pub fn name(&self) -> &'static str {
+        match self {
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Whether the job has finished and will not change anymore
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Succeeded | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown job status: {}", s))
+    }
+}
+
+/// Progress reported by a running job
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobProgress {
+    /// Units of work done
+    pub current: u64,
+    /// Total units of work, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    /// Description of the current step
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl JobProgress {
+    /// Completed fraction (0.0 - 1.0), if the total is known
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some((self.current as f64 / total as f64).min(1.0)),
+            None => None,
+        }
+    }
+}
+
+/// A unit of background work and its outcome
+///
+/// The payload and result are JSON so that jobs can be persisted by any
+/// [`JobStore`](super::JobStore) and returned to API clients as-is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    /// Job ID
+    pub id: Uuid,
+    /// Kind of work
+    pub kind: JobKind,
+    /// Current state
+    pub status: JobStatus,
+    /// Handler input
+    #[serde(default)]
+    pub payload: Value,
+    /// Latest reported progress
+    #[serde(default)]
+    pub progress: JobProgress,
+    /// Handler output, once succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Error of the latest failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Number of attempts started
+    #[serde(default)]
+    pub attempts: u32,
+    /// Maximum number of attempts
+    pub max_attempts: u32,
+    /// Whether cancellation was requested while running
+    #[serde(default)]
+    pub cancel_requested: bool,
+    /// Who enqueued the job
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// Worker running (or that last ran) the job
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker: Option<String>,
+    /// When the job was enqueued
+    pub created_at: DateTime<Utc>,
+    /// When the latest attempt started
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the job reached a terminal state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// When the job last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Job {
+    /// Create a queued job
+    pub fn new(kind: JobKind, payload: Value) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            status: JobStatus::Queued,
+            payload,
+            progress: JobProgress::default(),
+            result: None,
+            error: None,
+            attempts: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            cancel_requested: false,
+            created_by: None,
+            worker: None,
+            created_at: now,
+            started_at: None,
+            finished_at: None,
+            updated_at: now,
+        }
+    }
+
+    /// Set the maximum number of attempts (at least one)
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set who enqueued the job
+    pub fn with_created_by(mut self, created_by: impl Into<String>) -> Self {
+        self.created_by = Some(created_by.into());
+        self
+    }
+
+    /// Mark the job as claimed by a worker
+    pub fn start(&mut self, worker: impl Into<String>) {
+        let now = Utc::now();
+        self.status = JobStatus::Running;
+        self.attempts += 1;
+        self.worker = Some(worker.into());
+        self.started_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Record the latest progress
+    pub fn set_progress(&mut self, progress: JobProgress) {
+        self.progress = progress;
+        self.updated_at = Utc::now();
+    }
+
+    /// Mark the job as succeeded
+    pub fn succeed(&mut self, result: Value) {
+        self.finish(JobStatus::Succeeded);
+        self.result = Some(result);
+        self.error = None;
+    }
+
+    /// Record a failed attempt
+    ///
+    /// The job is queued again while attempts remain, and failed otherwise.
+    pub fn fail(&mut self, error: impl Into<String>) {
+        self.error = Some(error.into());
+        if self.attempts < self.max_attempts {
+            self.status = JobStatus::Queued;
+            self.updated_at = Utc::now();
+        } else {
+            self.finish(JobStatus::Failed);
+        }
+    }
+
+    /// Mark the job as cancelled
+    pub fn cancel(&mut self) {
+        self.cancel_requested = true;
+        self.finish(JobStatus::Cancelled);
+    }
+
+    /// Whether the job has finished and will not change anymore
+    pub fn is_finished(&self) -> bool {
+        self.status.is_terminal()
+    }
+
+    fn finish(&mut self, status: JobStatus) {
+        let now = Utc::now();
+        self.status = status;
+        self.finished_at = Some(now);
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_retry_until_max_attempts() {
+        let mut job = Job::new(JobKind::Import, json!({})).with_max_attempts(2);
+
+        job.start("worker-1");
+        job.fail("timeout");
+        assert_eq!(job.status, JobStatus::Queued);
+        assert!(job.finished_at.is_none());
+
+        job.start("worker-1");
+        job.fail("timeout");
+        assert_eq!(job.status, JobStatus::Failed);
+        assert_eq!(job.attempts, 2);
+        assert_eq!(job.error.as_deref(), Some("timeout"));
+        assert!(job.is_finished());
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let mut job = Job::new(JobKind::Validation, json!({"format": "odcs"}))
+            .with_created_by("alice@example.com");
+        job.set_progress(JobProgress {
+            current: 1,
+            total: Some(4),
+            message: Some("Parsing".to_string()),
+        });
+
+        let value = serde_json::to_value(&job).unwrap();
+        assert_eq!(value["kind"], "validation");
+        assert_eq!(value["status"], "queued");
+        assert_eq!(value["maxAttempts"], 1);
+        assert_eq!(value["progress"]["total"], 4);
+        assert_eq!(serde_json::from_value::<Job>(value).unwrap(), job);
+        assert_eq!(job.progress.fraction(), Some(0.25));
+        assert_eq!(
+            "Validation".parse::<JobKind>().unwrap(),
+            JobKind::Validation
+        );
+    }
+}
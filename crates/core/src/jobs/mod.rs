@@ -0,0 +1,63 @@
+//! Background jobs for long-running SDK operations
+//!
+//! This module provides a lightweight job queue so that callers such as an API
+//! server can enqueue an import, export, pipeline or validation and return the
+//! job ID immediately, while a worker executes the job and records its status,
+//! progress and result.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::jobs::{
+//!     ImportJobRequest, InMemoryJobStore, JobKind, JobQueue, JobStatus,
+//! };
+//!
+//! let queue = JobQueue::with_builtin_handlers(InMemoryJobStore::new());
+//!
+//! // Request handler: enqueue and return the ID
+//! let request = ImportJobRequest {
+//!     format: Some("sql".to_string()),
+//!     content: "CREATE TABLE users (id INT);".to_string(),
+//! };
+//! let job = queue.enqueue(JobKind::Import, serde_json::to_value(request)?).await?;
+//!
+//! // Worker: run queued jobs
+//! queue.run_pending().await?;
+//!
+//! // Status endpoint: poll the job
+//! let job = queue.get(job.id).await?;
+//! assert_eq!(job.status, JobStatus::Succeeded);
+//! ```
+//!
+//! # Persistence
+//!
+//! Jobs are persisted by a [`JobStore`]:
+//! - [`InMemoryJobStore`]: process memory, for tests and single-process servers
+//! - [`StorageJobStore`]: one JSON file per job on any storage backend
+//! - `DatabaseJobStore` (feature `database`): the `jobs` table of the metadata
+//!   store database, safe for several workers
+//!
+//! # Custom Jobs
+//!
+//! Implement [`JobHandler`] and register it on the queue to replace a
+//! built-in handler. Handlers report progress through the [`JobContext`],
+//! which also tells them when the job was cancelled.
+
+mod error;
+mod handlers;
+mod job;
+mod queue;
+mod store;
+
+pub use error::{JobError, JobResult};
+#[cfg(feature = "pipeline")]
+pub use handlers::PipelineJobHandler;
+pub use handlers::{
+    ExportJobHandler, ExportJobRequest, ImportJobHandler, ImportJobRequest, VALIDATION_FORMATS,
+    ValidationJobHandler, ValidationJobRequest,
+};
+pub use job::{DEFAULT_MAX_ATTEMPTS, Job, JobKind, JobProgress, JobStatus};
+pub use queue::{JobContext, JobHandler, JobQueue};
+#[cfg(feature = "database")]
+pub(crate) use store::request_cancel;
+pub use store::{InMemoryJobStore, JobStore, StorageJobStore};
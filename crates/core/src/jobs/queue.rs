@@ -0,0 +1,396 @@
+//! Job queue and handlers
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::error::{JobError, JobResult};
+use super::handlers;
+use super::job::{Job, JobKind, JobProgress, JobStatus};
+use super::store::JobStore;
+
+/// Executes the jobs of one kind
+#[async_trait(?Send)]
+pub trait JobHandler: Send + Sync {
+    /// Kind of jobs handled
+    fn kind(&self) -> JobKind;
+
+    /// Run a job
+    ///
+    /// # Arguments
+    /// * `payload` - Job input
+    /// * `ctx` - Context for reporting progress and checking cancellation
+    ///
+    /// # Returns
+    /// The job result, or an error message for a failed attempt
+    async fn run(&self, payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String>;
+}
+
+/// Context passed to a running [`JobHandler`]
+pub struct JobContext<'a> {
+    job_id: Uuid,
+    store: &'a dyn JobStore,
+}
+
+impl<'a> JobContext<'a> {
+    /// Create a context for a job
+    pub fn new(job_id: Uuid, store: &'a dyn JobStore) -> Self {
+        Self { job_id, store }
+    }
+
+    /// ID of the running job
+    pub fn job_id(&self) -> Uuid {
+        self.job_id
+    }
+
+    /// Record progress of the running job
+    ///
+    /// # Errors
+    /// [`JobError::Cancelled`] when cancellation was requested; handlers should
+    /// stop and return an error.
+    pub async fn report_progress(&self, progress: JobProgress) -> JobResult<()> {
+        let job = self.store.update_progress(self.job_id, &progress).await?;
+        if job.cancel_requested {
+            return Err(JobError::Cancelled(self.job_id));
+        }
+        Ok(())
+    }
+
+    /// Whether cancellation of the running job was requested
+    pub async fn is_cancelled(&self) -> JobResult<bool> {
+        Ok(self
+            .store
+            .get(self.job_id)
+            .await?
+            .is_none_or(|job| job.cancel_requested))
+    }
+}
+
+/// Queue of background jobs backed by a [`JobStore`]
+///
+/// Callers enqueue jobs and get their ID back immediately; workers call
+/// [`JobQueue::run_next`] or [`JobQueue::run_pending`] to execute them with the
+/// registered handlers. Failed attempts are retried until the job's
+/// `max_attempts` is reached.
+pub struct JobQueue<S: JobStore> {
+    store: S,
+    handlers: HashMap<JobKind, Arc<dyn JobHandler>>,
+    worker: String,
+}
+
+impl<S: JobStore> JobQueue<S> {
+    /// Create a queue without handlers
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            handlers: HashMap::new(),
+            worker: format!("worker-{}", std::process::id()),
+        }
+    }
+
+    /// Create a queue with the built-in import, export, validation and (with
+    /// the `pipeline` feature) pipeline handlers
+    pub fn with_builtin_handlers(store: S) -> Self {
+        let mut queue = Self::new(store);
+        queue
+            .register(handlers::ImportJobHandler)
+            .register(handlers::ExportJobHandler)
+            .register(handlers::ValidationJobHandler);
+        #[cfg(feature = "pipeline")]
+        queue.register(handlers::PipelineJobHandler);
+        queue
+    }
+
+    /// Set the worker name recorded on claimed jobs
+    pub fn with_worker(mut self, worker: impl Into<String>) -> Self {
+        self.worker = worker.into();
+        self
+    }
+
+    /// Register a handler, replacing any handler for the same kind
+    pub fn register<H: JobHandler + 'static>(&mut self, handler: H) -> &mut Self {
+        self.register_arc(Arc::new(handler))
+    }
+
+    /// Register a shared handler, replacing any handler for the same kind
+    pub fn register_arc(&mut self, handler: Arc<dyn JobHandler>) -> &mut Self {
+        self.handlers.insert(handler.kind(), handler);
+        self
+    }
+
+    /// Whether a handler is registered for a kind
+    pub fn handles(&self, kind: JobKind) -> bool {
+        self.handlers.contains_key(&kind)
+    }
+
+    /// Get the job store
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Enqueue a job
+    ///
+    /// # Returns
+    /// The queued job
+    pub async fn enqueue(&self, kind: JobKind, payload: Value) -> JobResult<Job> {
+        self.submit(Job::new(kind, payload)).await
+    }
+
+    /// Enqueue a prepared job (e.g. with retries or an author)
+    pub async fn submit(&self, job: Job) -> JobResult<Job> {
+        if job.status != JobStatus::Queued {
+            return Err(JobError::InvalidState {
+                id: job.id,
+                status: job.status,
+                message: "only queued jobs can be submitted".to_string(),
+            });
+        }
+        self.store.save(&job).await?;
+        Ok(job)
+    }
+
+    /// Get a job by ID
+    pub async fn get(&self, id: Uuid) -> JobResult<Job> {
+        self.store.get(id).await?.ok_or(JobError::NotFound(id))
+    }
+
+    /// List jobs, oldest first, optionally filtered by status
+    pub async fn list(&self, status: Option<JobStatus>) -> JobResult<Vec<Job>> {
+        self.store.list(status).await
+    }
+
+    /// Cancel a job
+    ///
+    /// Queued jobs are cancelled immediately; running jobs are cancelled once
+    /// their handler returns or reports progress.
+    pub async fn cancel(&self, id: Uuid) -> JobResult<Job> {
+        self.store.request_cancel(id).await
+    }
+
+    /// Delete a job that is not running
+    pub async fn delete(&self, id: Uuid) -> JobResult<()> {
+        let job = self.get(id).await?;
+        if job.status == JobStatus::Running {
+            return Err(JobError::InvalidState {
+                id,
+                status: job.status,
+                message: "running jobs cannot be deleted".to_string(),
+            });
+        }
+        self.store.delete(id).await?;
+        Ok(())
+    }
+
+    /// Claim and run the oldest queued job
+    ///
+    /// # Returns
+    /// The job after the attempt, or `None` when no job is queued
+    pub async fn run_next(&self) -> JobResult<Option<Job>> {
+        let Some(job) = self.store.claim_next(&self.worker).await? else {
+            return Ok(None);
+        };
+
+        let outcome = match self.handlers.get(&job.kind) {
+            Some(handler) => {
+                let ctx = JobContext::new(job.id, &self.store);
+                handler.run(&job.payload, &ctx).await
+            }
+            None => Err(JobError::NoHandler(job.kind).to_string()),
+        };
+
+        // Reload to keep progress and cancellation recorded while running
+        let mut job = self.store.get(job.id).await?.unwrap_or(job);
+        if job.cancel_requested {
+            job.cancel();
+        } else {
+            match outcome {
+                Ok(result) => job.succeed(result),
+                Err(error) => job.fail(error),
+            }
+        }
+        self.store.save(&job).await?;
+        Ok(Some(job))
+    }
+
+    /// Run jobs until none is queued
+    ///
+    /// # Returns
+    /// Every attempt made, in order
+    pub async fn run_pending(&self) -> JobResult<Vec<Job>> {
+        let mut attempts = Vec::new();
+        while let Some(job) = self.run_next().await? {
+            attempts.push(job);
+        }
+        Ok(attempts)
+    }
+}
+
+impl<S: JobStore> std::fmt::Debug for JobQueue<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut kinds: Vec<JobKind> = self.handlers.keys().copied().collect();
+        kinds.sort();
+        f.debug_struct("JobQueue")
+            .field("worker", &self.worker)
+            .field("handlers", &kinds)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::InMemoryJobStore;
+    use serde_json::json;
+
+    /// Handler failing until its payload's `succeedOnAttempt`
+    struct FlakyHandler {
+        calls: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait(?Send)]
+    impl JobHandler for FlakyHandler {
+        fn kind(&self) -> JobKind {
+            JobKind::Pipeline
+        }
+
+        async fn run(&self, payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            ctx.report_progress(JobProgress {
+                current: 1,
+                total: Some(2),
+                message: Some(format!("Attempt {}", call)),
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+            if u64::from(call) < payload["succeedOnAttempt"].as_u64().unwrap_or(1) {
+                return Err("transient failure".to_string());
+            }
+            Ok(json!({"calls": call}))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_and_records_progress() {
+        let mut queue = JobQueue::new(InMemoryJobStore::new()).with_worker("test");
+        queue.register(FlakyHandler {
+            calls: Default::default(),
+        });
+
+        let job = queue
+            .submit(
+                Job::new(JobKind::Pipeline, json!({"succeedOnAttempt": 2})).with_max_attempts(3),
+            )
+            .await
+            .unwrap();
+
+        let attempts = queue.run_pending().await.unwrap();
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0].status, JobStatus::Queued);
+        assert_eq!(attempts[0].error.as_deref(), Some("transient failure"));
+
+        let job = queue.get(job.id).await.unwrap();
+        assert_eq!(job.status, JobStatus::Succeeded);
+        assert_eq!(job.attempts, 2);
+        assert_eq!(job.result, Some(json!({"calls": 2})));
+        assert_eq!(job.progress.message.as_deref(), Some("Attempt 2"));
+        assert_eq!(job.worker.as_deref(), Some("test"));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_missing_handler() {
+        let queue = JobQueue::new(InMemoryJobStore::new());
+
+        let cancelled = queue.enqueue(JobKind::Export, json!({})).await.unwrap();
+        let cancelled = queue.cancel(cancelled.id).await.unwrap();
+        assert_eq!(cancelled.status, JobStatus::Cancelled);
+        assert!(matches!(
+            queue.cancel(cancelled.id).await,
+            Err(JobError::InvalidState { .. })
+        ));
+
+        let unhandled = queue.enqueue(JobKind::Export, json!({})).await.unwrap();
+        let failed = queue.run_next().await.unwrap().unwrap();
+        assert_eq!(failed.id, unhandled.id);
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(
+            failed.error.as_deref(),
+            Some("No handler registered for export jobs")
+        );
+        assert!(queue.run_next().await.unwrap().is_none());
+
+        queue.delete(unhandled.id).await.unwrap();
+        assert!(matches!(
+            queue.get(unhandled.id).await,
+            Err(JobError::NotFound(_))
+        ));
+    }
+
+    /// Store whose jobs are cancelled while their worker reports progress
+    #[derive(Default)]
+    struct CancelDuringProgress(InMemoryJobStore);
+
+    #[async_trait(?Send)]
+    impl JobStore for CancelDuringProgress {
+        async fn save(&self, job: &Job) -> JobResult<()> {
+            self.0.save(job).await
+        }
+
+        async fn get(&self, id: Uuid) -> JobResult<Option<Job>> {
+            self.0.get(id).await
+        }
+
+        async fn list(&self, status: Option<JobStatus>) -> JobResult<Vec<Job>> {
+            self.0.list(status).await
+        }
+
+        async fn claim_next(&self, worker: &str) -> JobResult<Option<Job>> {
+            self.0.claim_next(worker).await
+        }
+
+        async fn delete(&self, id: Uuid) -> JobResult<bool> {
+            self.0.delete(id).await
+        }
+
+        async fn update_progress(&self, id: Uuid, progress: &JobProgress) -> JobResult<Job> {
+            self.0.request_cancel(id).await?;
+            self.0.update_progress(id, progress).await
+        }
+    }
+
+    /// Handler reporting progress until it is cancelled
+    struct ProgressHandler;
+
+    #[async_trait(?Send)]
+    impl JobHandler for ProgressHandler {
+        fn kind(&self) -> JobKind {
+            JobKind::Import
+        }
+
+        async fn run(&self, _payload: &Value, ctx: &JobContext<'_>) -> Result<Value, String> {
+            ctx.report_progress(JobProgress {
+                current: 1,
+                total: Some(2),
+                message: Some("Halfway".to_string()),
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+            Ok(json!({}))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_while_reporting_progress() {
+        let mut queue = JobQueue::new(CancelDuringProgress::default());
+        queue.register(ProgressHandler);
+        queue.enqueue(JobKind::Import, json!({})).await.unwrap();
+
+        // Neither the cancellation nor the progress is lost
+        let job = queue.run_next().await.unwrap().unwrap();
+        assert_eq!(job.status, JobStatus::Cancelled);
+        assert!(job.cancel_requested);
+        assert_eq!(job.progress.message.as_deref(), Some("Halfway"));
+        assert_eq!(queue.get(job.id).await.unwrap(), job);
+    }
+}
@@ -0,0 +1,354 @@
+//! Job persistence
+//!
+//! [`JobStore`] is implemented for process memory ([`InMemoryJobStore`]), any
+//! [`StorageBackend`] ([`StorageJobStore`]) and, with the `database` feature,
+//! the metadata store database (`database::DatabaseJobStore`).
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use super::error::{JobError, JobResult};
+use super::job::{Job, JobProgress, JobStatus};
+use crate::storage::{StorageBackend, StorageError};
+
+/// Trait for job stores
+#[async_trait(?Send)]
+pub trait JobStore: Send + Sync {
+    /// Insert or replace a job
+    async fn save(&self, job: &Job) -> JobResult<()>;
+
+    /// Get a job by ID
+    async fn get(&self, id: Uuid) -> JobResult<Option<Job>>;
+
+    /// List jobs, oldest first, optionally filtered by status
+    async fn list(&self, status: Option<JobStatus>) -> JobResult<Vec<Job>>;
+
+    /// Claim the oldest queued job for a worker
+    ///
+    /// The returned job has been started (see [`Job::start`]) and saved.
+    async fn claim_next(&self, worker: &str) -> JobResult<Option<Job>>;
+
+    /// Delete a job
+    ///
+    /// # Returns
+    /// Whether the job existed
+    async fn delete(&self, id: Uuid) -> JobResult<bool>;
+
+    /// Request cancellation of a job
+    ///
+    /// Queued jobs are cancelled at once; running jobs only get
+    /// `cancel_requested` set, leaving the rest of the job as its worker saved
+    /// it. The default loads and saves the whole job; stores shared between
+    /// workers override it with an atomic update.
+    ///
+    /// # Returns
+    /// The job after the request
+    ///
+    /// # Errors
+    /// [`JobError::InvalidState`] when the job has finished.
+    async fn request_cancel(&self, id: Uuid) -> JobResult<Job> {
+        let mut job = self.get(id).await?.ok_or(JobError::NotFound(id))?;
+        request_cancel(&mut job)?;
+        self.save(&job).await?;
+        Ok(job)
+    }
+
+    /// Record the progress of a job without changing its other fields
+    ///
+    /// The default loads and saves the whole job; stores shared between
+    /// workers override it with an atomic update.
+    ///
+    /// # Returns
+    /// The job after the update, telling whether cancellation was requested
+    async fn update_progress(&self, id: Uuid, progress: &JobProgress) -> JobResult<Job> {
+        let mut job = self.get(id).await?.ok_or(JobError::NotFound(id))?;
+        job.set_progress(progress.clone());
+        self.save(&job).await?;
+        Ok(job)
+    }
+}
+
+/// Cancel a queued job, or request cancellation of a running one
+pub(crate) fn request_cancel(job: &mut Job) -> JobResult<()> {
+    match job.status {
+        JobStatus::Queued => job.cancel(),
+        JobStatus::Running => job.cancel_requested = true,
+        status => {
+            return Err(JobError::InvalidState {
+                id: job.id,
+                status,
+                message: "finished jobs cannot be cancelled".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Job store keeping jobs in process memory
+#[derive(Debug, Default)]
+pub struct InMemoryJobStore {
+    jobs: Mutex<HashMap<Uuid, Job>>,
+}
+
+impl InMemoryJobStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn jobs(&self) -> JobResult<std::sync::MutexGuard<'_, HashMap<Uuid, Job>>> {
+        self.jobs
+            .lock()
+            .map_err(|e| JobError::StoreError(format!("Job store lock poisoned: {}", e)))
+    }
+}
+
+#[async_trait(?Send)]
+impl JobStore for InMemoryJobStore {
+    async fn save(&self, job: &Job) -> JobResult<()> {
+        self.jobs()?.insert(job.id, job.clone());
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> JobResult<Option<Job>> {
+        Ok(self.jobs()?.get(&id).cloned())
+    }
+
+    async fn list(&self, status: Option<JobStatus>) -> JobResult<Vec<Job>> {
+        let mut jobs: Vec<Job> = self
+            .jobs()?
+            .values()
+            .filter(|job| status.is_none_or(|s| job.status == s))
+            .cloned()
+            .collect();
+        jobs.sort_by_key(|job| job.created_at);
+        Ok(jobs)
+    }
+
+    async fn claim_next(&self, worker: &str) -> JobResult<Option<Job>> {
+        let mut jobs = self.jobs()?;
+        let next = jobs
+            .values_mut()
+            .filter(|job| job.status == JobStatus::Queued)
+            .min_by_key(|job| job.created_at);
+        Ok(next.map(|job| {
+            job.start(worker);
+            job.clone()
+        }))
+    }
+
+    async fn delete(&self, id: Uuid) -> JobResult<bool> {
+        Ok(self.jobs()?.remove(&id).is_some())
+    }
+
+    async fn request_cancel(&self, id: Uuid) -> JobResult<Job> {
+        let mut jobs = self.jobs()?;
+        let job = jobs.get_mut(&id).ok_or(JobError::NotFound(id))?;
+        request_cancel(job)?;
+        Ok(job.clone())
+    }
+
+    async fn update_progress(&self, id: Uuid, progress: &JobProgress) -> JobResult<Job> {
+        let mut jobs = self.jobs()?;
+        let job = jobs.get_mut(&id).ok_or(JobError::NotFound(id))?;
+        job.set_progress(progress.clone());
+        Ok(job.clone())
+    }
+}
+
+/// Job store writing one JSON file per job to a storage backend
+///
+/// Claims, cancellations and progress updates are not atomic. Storage backends
+/// have no conditional writes, so [`JobStore::request_cancel`] and
+/// [`JobStore::update_progress`] keep the default load-then-save: a progress
+/// update saved between the load and save of a cancellation request drops
+/// that request. Run a single worker per directory, and use the database
+/// store where cancellation must be reliable or several workers share jobs.
+pub struct StorageJobStore<S: StorageBackend> {
+    storage: S,
+    dir: String,
+}
+
+impl<S: StorageBackend> StorageJobStore<S> {
+    /// Default directory for job files
+    pub const DEFAULT_DIR: &'static str = ".jobs";
+
+    /// Create a store writing to [`Self::DEFAULT_DIR`]
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            dir: Self::DEFAULT_DIR.to_string(),
+        }
+    }
+
+    /// Set the directory for job files
+    pub fn with_dir(mut self, dir: impl Into<String>) -> Self {
+        self.dir = dir.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Get the storage backend
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    fn path(&self, id: Uuid) -> String {
+        format!("{}/{}.json", self.dir, id)
+    }
+}
+
+#[async_trait(?Send)]
+impl<S: StorageBackend> JobStore for StorageJobStore<S> {
+    async fn save(&self, job: &Job) -> JobResult<()> {
+        if !self.storage.dir_exists(&self.dir).await? {
+            self.storage.create_dir(&self.dir).await?;
+        }
+        let content = serde_json::to_vec_pretty(job)?;
+        self.storage
+            .write_file(&self.path(job.id), &content)
+            .await?;
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> JobResult<Option<Job>> {
+        match self.storage.read_file(&self.path(id)).await {
+            Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+            Err(StorageError::FileNotFound(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn list(&self, status: Option<JobStatus>) -> JobResult<Vec<Job>> {
+        let files = match self.storage.list_files(&self.dir).await {
+            Ok(files) => files,
+            Err(StorageError::DirectoryNotFound(_)) => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut jobs = Vec::new();
+        for file in files {
+            let Some(id) = file
+                .strip_suffix(".json")
+                .and_then(|stem| Uuid::parse_str(stem).ok())
+            else {
+                continue;
+            };
+            if let Some(job) = self.get(id).await?
+                && status.is_none_or(|s| job.status == s)
+            {
+                jobs.push(job);
+            }
+        }
+        jobs.sort_by_key(|job| job.created_at);
+        Ok(jobs)
+    }
+
+    async fn claim_next(&self, worker: &str) -> JobResult<Option<Job>> {
+        let Some(mut job) = self.list(Some(JobStatus::Queued)).await?.into_iter().next() else {
+            return Ok(None);
+        };
+        job.start(worker);
+        self.save(&job).await?;
+        Ok(Some(job))
+    }
+
+    async fn delete(&self, id: Uuid) -> JobResult<bool> {
+        match self.storage.delete_file(&self.path(id)).await {
+            Ok(()) => Ok(true),
+            Err(StorageError::FileNotFound(_)) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::JobKind;
+    use serde_json::json;
+
+    /// Storage backend keeping files in memory
+    #[derive(Default)]
+    struct MemoryStorage {
+        files: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait(?Send)]
+    impl StorageBackend for MemoryStorage {
+        async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .get(path)
+                .cloned()
+                .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+        }
+
+        async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .insert(path.to_string(), content.to_vec());
+            Ok(())
+        }
+
+        async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+            let prefix = format!("{}/", dir);
+            Ok(self
+                .files
+                .lock()
+                .unwrap()
+                .keys()
+                .filter_map(|path| path.strip_prefix(&prefix).map(str::to_string))
+                .collect())
+        }
+
+        async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+            Ok(self.files.lock().unwrap().contains_key(path))
+        }
+
+        async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .remove(path)
+                .map(|_| ())
+                .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+        }
+
+        async fn create_dir(&self, _path: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+
+        async fn dir_exists(&self, _path: &str) -> Result<bool, StorageError> {
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_storage_store_claims_oldest_queued_job() {
+        let store = StorageJobStore::new(MemoryStorage::default());
+        let first = Job::new(JobKind::Import, json!({"content": "a"}));
+        let second = Job::new(JobKind::Export, json!({"format": "avro"}));
+        store.save(&second).await.unwrap();
+        store.save(&first).await.unwrap();
+
+        let claimed = store.claim_next("worker-1").await.unwrap().unwrap();
+        assert_eq!(claimed.id, first.id);
+        assert_eq!(claimed.status, JobStatus::Running);
+        assert_eq!(claimed.worker.as_deref(), Some("worker-1"));
+
+        let stored = store.get(first.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, JobStatus::Running);
+        assert_eq!(
+            store.list(Some(JobStatus::Queued)).await.unwrap()[0].id,
+            second.id
+        );
+
+        assert!(store.delete(first.id).await.unwrap());
+        assert!(!store.delete(first.id).await.unwrap());
+        assert!(store.get(first.id).await.unwrap().is_none());
+    }
+}
@@ -9,34 +9,47 @@
 //! - Workspace management types
 
 pub mod auth;
+pub mod capabilities;
+#[cfg(feature = "config")]
+pub mod config;
 pub mod convert;
 #[cfg(feature = "database")]
 pub mod database;
+pub mod demo;
 pub mod export;
+pub mod generate;
 #[cfg(feature = "git")]
 pub mod git;
 pub mod import;
 #[cfg(feature = "inference")]
 pub mod inference;
+pub mod jobs;
 #[cfg(any(feature = "llm", feature = "llm-online", feature = "llm-offline"))]
 pub mod llm;
 #[cfg(feature = "mapping")]
 pub mod mapping;
+pub mod metrics;
 pub mod model;
 pub mod models;
 #[cfg(feature = "pipeline")]
 pub mod pipeline;
 #[cfg(any(feature = "staging", feature = "staging-postgres"))]
+pub mod quality;
+#[cfg(feature = "schema-registry")]
+pub mod registry;
+#[cfg(any(feature = "staging", feature = "staging-postgres"))]
 pub mod staging;
 pub mod storage;
 pub mod validation;
 pub mod workspace;
 
 // Re-export commonly used types
+pub use capabilities::{Capabilities, FormatCapability, capabilities};
 #[cfg(feature = "api-backend")]
 pub use storage::api::ApiStorageBackend;
 #[cfg(feature = "native-fs")]
 pub use storage::filesystem::FileSystemStorageBackend;
+pub use storage::memory::MemoryStorageBackend;
 pub use storage::{StorageBackend, StorageError};
 
 pub use convert::{ConversionError, convert_to_odcs};
@@ -83,4 +96,4 @@ pub use workspace::{
 
 // Re-export Git types
 #[cfg(feature = "git")]
-pub use git::{GitError, GitService, GitStatus};
+pub use git::{GitError, GitService, GitStatus, WorkspaceDiff, workspace_diff};
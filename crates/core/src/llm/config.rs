@@ -132,6 +132,11 @@ pub struct RefinementConfig {
     /// Enable verbose logging of prompts and responses
     #[serde(default)]
     pub verbose: bool,
+
+    /// Ambiguous fields to review first, with the reason (e.g. a low
+    /// inference confidence)
+    #[serde(default)]
+    pub priority_fields: Vec<String>,
 }
 
 fn default_max_context_tokens() -> usize {
@@ -171,6 +176,7 @@ impl Default for RefinementConfig {
             include_samples: default_include_samples(),
             max_samples: default_max_samples(),
             verbose: false,
+            priority_fields: Vec::new(),
         }
     }
 }
@@ -234,6 +240,12 @@ impl RefinementConfig {
         self
     }
 
+    /// Set the ambiguous fields the LLM should review first
+    pub fn with_priority_fields(mut self, fields: Vec<String>) -> Self {
+        self.priority_fields = fields;
+        self
+    }
+
     /// Check if LLM refinement is enabled
     pub fn is_enabled(&self) -> bool {
         self.llm_mode.is_enabled()
@@ -25,6 +25,7 @@ pub const REFINEMENT_PROMPT_TEMPLATE: &str = r#"You are a data modeling expert.
 {schema}
 ```
 
+{priority_section}
 {documentation_section}
 
 {samples_section}
@@ -44,6 +45,9 @@ Sample values: {sample_values}
 
 Return only the description text, no JSON or formatting."#;
 
+/// Maximum number of priority fields listed in a prompt
+const MAX_PRIORITY_FIELDS: usize = 20;
+
 /// Context for building a refinement prompt
 #[derive(Debug, Clone, Default)]
 pub struct PromptContext {
@@ -53,6 +57,8 @@ pub struct PromptContext {
     pub documentation: Option<String>,
     /// Sample records as JSON
     pub samples: Vec<String>,
+    /// Ambiguous fields to review first, with the reason
+    pub priority_fields: Vec<String>,
     /// Maximum tokens for the prompt
     pub max_tokens: usize,
 }
@@ -64,6 +70,7 @@ impl PromptContext {
             schema_json: schema_json.into(),
             documentation: None,
             samples: Vec::new(),
+            priority_fields: Vec::new(),
             max_tokens: 4096,
         }
     }
@@ -80,6 +87,12 @@ impl PromptContext {
         self
     }
 
+    /// Add ambiguous fields the LLM should review first
+    pub fn with_priority_fields(mut self, fields: Vec<String>) -> Self {
+        self.priority_fields = fields;
+        self
+    }
+
     /// Set maximum tokens
     pub fn with_max_tokens(mut self, max: usize) -> Self {
         self.max_tokens = max;
@@ -114,8 +127,25 @@ impl PromptContext {
             String::new()
         };
 
+        let priority_section = if !self.priority_fields.is_empty() {
+            let fields_text = self
+                .priority_fields
+                .iter()
+                .take(MAX_PRIORITY_FIELDS)
+                .map(|field| format!("- {}", field))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!(
+                "## Fields Needing Attention\nThese fields were inferred with low confidence. Review their types, formats and descriptions first:\n{}\n",
+                truncate_to_tokens(&fields_text, self.max_tokens / 8)
+            )
+        } else {
+            String::new()
+        };
+
         REFINEMENT_PROMPT_TEMPLATE
             .replace("{schema}", &self.schema_json)
+            .replace("{priority_section}", &priority_section)
             .replace("{documentation_section}", &documentation_section)
             .replace("{samples_section}", &samples_section)
     }
@@ -269,6 +299,20 @@ mod tests {
         assert!(prompt.contains("Sample 2"));
     }
 
+    #[test]
+    fn test_prompt_context_with_priority_fields() {
+        let schema = r#"{"type": "object"}"#;
+        let prompt = PromptContext::new(schema).build_prompt();
+        assert!(!prompt.contains("Fields Needing Attention"));
+
+        let context = PromptContext::new(schema).with_priority_fields(vec![
+            "status (confidence 0.20: 2 samples, 0% null, 50% type agreement)".to_string(),
+        ]);
+        let prompt = context.build_prompt();
+        assert!(prompt.contains("Fields Needing Attention"));
+        assert!(prompt.contains("- status (confidence 0.20"));
+    }
+
     #[test]
     fn test_parse_llm_response_pure_json() {
         let response = r#"{"type": "object", "properties": {}}"#;
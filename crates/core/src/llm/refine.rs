@@ -108,6 +108,10 @@ impl<C: LlmClient> SchemaRefiner<C> {
             context = context.with_documentation(doc);
         }
 
+        if !self.config.priority_fields.is_empty() {
+            context = context.with_priority_fields(self.config.priority_fields.clone());
+        }
+
         if let Some(s) = samples {
             if self.config.include_samples {
                 let limited_samples: Vec<String> =
@@ -231,6 +235,10 @@ pub async fn refine_schema<C: LlmClient>(
         context = context.with_documentation(doc);
     }
 
+    if !config.priority_fields.is_empty() {
+        context = context.with_priority_fields(config.priority_fields.clone());
+    }
+
     if let Some(s) = samples {
         if config.include_samples {
             let limited_samples: Vec<String> = s.into_iter().take(config.max_samples).collect();
@@ -359,6 +367,12 @@ impl RefinementBuilder {
         self
     }
 
+    /// Set the ambiguous fields the LLM should review first
+    pub fn with_priority_fields(mut self, fields: Vec<String>) -> Self {
+        self.config.priority_fields = fields;
+        self
+    }
+
     /// Build the configuration
     pub fn build(self) -> RefinementConfig {
         self.config
@@ -5,6 +5,7 @@
 //! - Detect type mismatches and suggest transformations
 //! - Generate transformation scripts (SQL, JQ, Python, PySpark)
 //! - Identify gaps and unmapped fields
+//! - Scaffold Dagster assets or Airflow DAGs around generated transformations
 //!
 //! # Example
 //!
@@ -48,6 +49,7 @@ mod generator;
 #[cfg(feature = "llm")]
 mod llm_matcher;
 mod matcher;
+mod orchestration;
 mod types;
 
 pub use config::{MappingConfig, TransformFormat};
@@ -56,6 +58,7 @@ pub use generator::generate_transform;
 #[cfg(feature = "llm")]
 pub use llm_matcher::{LlmFieldSuggestion, LlmMatchResponse, LlmMatcherConfig, LlmSchemaMatcher};
 pub use matcher::SchemaMatcher;
+pub use orchestration::{OrchestrationSpec, Orchestrator, PipelineStep, generate_orchestration};
 pub use types::{
     FieldGap, FieldMapping, MappingStats, MatchMethod, SchemaMapping, TransformMapping,
     TransformType,
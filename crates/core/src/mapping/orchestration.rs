@@ -0,0 +1,657 @@
+//! Orchestration scaffolding for schema mappings
+//!
+//! Generates Dagster asset definitions or Airflow DAG stubs from a set of
+//! mapping steps (source → transform → target). Each step embeds the
+//! transformation produced by [`generate_transform`], so the orchestration
+//! code starts out consistent with the mapping it was generated from.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::config::TransformFormat;
+use super::error::{MappingError, MappingResult};
+use super::generator::generate_transform;
+use super::types::SchemaMapping;
+
+/// Default staging database used by SQL and Python steps
+const DEFAULT_DATABASE: &str = "staging.duckdb";
+
+/// Target orchestrator for generated scaffolding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orchestrator {
+    /// Dagster software-defined assets
+    #[default]
+    Dagster,
+    /// Airflow DAG with one task per step
+    Airflow,
+}
+
+impl std::fmt::Display for Orchestrator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orchestrator::Dagster => write!(f, "dagster"),
+            Orchestrator::Airflow => write!(f, "airflow"),
+        }
+    }
+}
+
+impl std::str::FromStr for Orchestrator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dagster" => Ok(Orchestrator::Dagster),
+            "airflow" => Ok(Orchestrator::Airflow),
+            _ => Err(format!("Unknown orchestrator: {}", s)),
+        }
+    }
+}
+
+/// A single source → transform → target step
+#[derive(Debug, Clone)]
+pub struct PipelineStep {
+    /// Source table read by the step
+    pub source_table: String,
+    /// Target table written by the step
+    pub target_table: String,
+    /// Mapping between source and target schemas
+    pub mapping: SchemaMapping,
+    /// Transformation format embedded in the step
+    pub format: TransformFormat,
+    /// Optional description of the step
+    pub description: Option<String>,
+}
+
+impl PipelineStep {
+    /// Create a new SQL step
+    pub fn new(
+        source_table: impl Into<String>,
+        target_table: impl Into<String>,
+        mapping: SchemaMapping,
+    ) -> Self {
+        Self {
+            source_table: source_table.into(),
+            target_table: target_table.into(),
+            mapping,
+            format: TransformFormat::Sql,
+            description: None,
+        }
+    }
+
+    /// Set the transformation format
+    pub fn with_format(mut self, format: TransformFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the step description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Orchestration specification: a named set of steps with scheduling details
+#[derive(Debug, Clone)]
+pub struct OrchestrationSpec {
+    /// Pipeline name (used as DAG id / asset group)
+    pub name: String,
+    /// Cron expression or preset such as `@daily`
+    pub schedule: Option<String>,
+    /// Owner of the generated pipeline
+    pub owner: Option<String>,
+    /// Staging database used by SQL and Python steps
+    pub database: String,
+    /// Steps in the pipeline
+    pub steps: Vec<PipelineStep>,
+}
+
+impl OrchestrationSpec {
+    /// Create a new, empty specification
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            schedule: None,
+            owner: None,
+            database: DEFAULT_DATABASE.to_string(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Create a specification from a pipeline configuration
+    ///
+    /// Uses the pipeline name and staging database; steps are added separately.
+    #[cfg(feature = "pipeline")]
+    pub fn from_pipeline_config(config: &crate::pipeline::PipelineConfig) -> Self {
+        let mut spec = Self::new(config.name.as_deref().unwrap_or("pipeline"));
+        spec.database = config.database.display().to_string();
+        spec
+    }
+
+    /// Set the schedule
+    pub fn with_schedule(mut self, schedule: impl Into<String>) -> Self {
+        self.schedule = Some(schedule.into());
+        self
+    }
+
+    /// Set the owner
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// Set the staging database
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = database.into();
+        self
+    }
+
+    /// Add a step
+    pub fn with_step(mut self, step: PipelineStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Source tables that are not produced by any step
+    fn external_sources(&self) -> Vec<&str> {
+        let targets: HashSet<&str> = self.steps.iter().map(|s| s.target_table.as_str()).collect();
+        let mut seen = HashSet::new();
+        self.steps
+            .iter()
+            .map(|s| s.source_table.as_str())
+            .filter(|s| !targets.contains(s) && seen.insert(*s))
+            .collect()
+    }
+
+    fn validate(&self) -> MappingResult<()> {
+        if self.steps.is_empty() {
+            return Err(MappingError::TransformGenerationError(
+                "orchestration spec has no steps".to_string(),
+            ));
+        }
+
+        let mut targets = HashSet::new();
+        for step in &self.steps {
+            if step.format == TransformFormat::Jq {
+                return Err(MappingError::TransformGenerationError(format!(
+                    "jq transforms cannot be embedded in orchestration code (step '{}')",
+                    step.target_table
+                )));
+            }
+            if !targets.insert(python_identifier(&step.target_table)) {
+                return Err(MappingError::TransformGenerationError(format!(
+                    "target table '{}' is produced by more than one step",
+                    step.target_table
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generate orchestration code for a specification
+pub fn generate_orchestration(
+    spec: &OrchestrationSpec,
+    orchestrator: Orchestrator,
+) -> MappingResult<String> {
+    spec.validate()?;
+    match orchestrator {
+        Orchestrator::Dagster => generate_dagster(spec),
+        Orchestrator::Airflow => generate_airflow(spec),
+    }
+}
+
+/// Generate Dagster asset definitions
+fn generate_dagster(spec: &OrchestrationSpec) -> MappingResult<String> {
+    let group = python_identifier(&spec.name);
+    let mut lines = header(spec, "Dagster assets");
+
+    lines.push(
+        "from dagster import AssetExecutionContext, AssetSpec, Definitions, asset".to_string(),
+    );
+    if spec.schedule.is_some() {
+        lines.push("from dagster import ScheduleDefinition, define_asset_job".to_string());
+    }
+    lines.extend(step_imports(spec));
+    lines.push(String::new());
+    lines.extend(shared_definitions(spec)?);
+
+    // External source tables
+    let sources = spec.external_sources();
+    for source in &sources {
+        lines.push(format!(
+            "{} = AssetSpec({}, group_name={}, description={})",
+            source_variable(source),
+            python_string(&python_identifier(source)),
+            python_string(&group),
+            python_string(&format!("Source table {}", source))
+        ));
+    }
+    if !sources.is_empty() {
+        lines.push(String::new());
+    }
+
+    for step in &spec.steps {
+        let asset_name = python_identifier(&step.target_table);
+        lines.push(String::new());
+        lines.push("@asset(".to_string());
+        lines.push(format!("    name={},", python_string(&asset_name)));
+        lines.push(format!(
+            "    deps=[{}],",
+            python_string(&python_identifier(&step.source_table))
+        ));
+        lines.push(format!("    group_name={},", python_string(&group)));
+        lines.push(format!(
+            "    description={},",
+            python_string(&step_description(step))
+        ));
+        if let Some(ref owner) = spec.owner {
+            lines.push(format!(
+                "    metadata={{\"owner\": {}}},",
+                python_string(owner)
+            ));
+        }
+        lines.push(")".to_string());
+        lines.push(format!(
+            "def {}(context: AssetExecutionContext) -> None:",
+            asset_name
+        ));
+        lines.push(format!(
+            "    context.log.info({})",
+            python_string(&format!(
+                "Running {} transform {} -> {}",
+                step.format, step.source_table, step.target_table
+            ))
+        ));
+        lines.extend(step_body(step));
+        lines.push(String::new());
+    }
+
+    let mut assets: Vec<String> = sources.iter().map(|s| source_variable(s)).collect();
+    assets.extend(
+        spec.steps
+            .iter()
+            .map(|s| python_identifier(&s.target_table)),
+    );
+
+    lines.push(String::new());
+    if let Some(ref schedule) = spec.schedule {
+        let targets: Vec<String> = spec
+            .steps
+            .iter()
+            .map(|s| python_string(&python_identifier(&s.target_table)))
+            .collect();
+        lines.push(format!(
+            "{}_job = define_asset_job({}, selection=[{}])",
+            group,
+            python_string(&format!("{}_job", group)),
+            targets.join(", ")
+        ));
+        lines.push(format!(
+            "{}_schedule = ScheduleDefinition(job={}_job, cron_schedule={})",
+            group,
+            group,
+            python_string(schedule)
+        ));
+        lines.push(String::new());
+        lines.push(format!(
+            "defs = Definitions(assets=[{}], jobs=[{}_job], schedules=[{}_schedule])",
+            assets.join(", "),
+            group,
+            group
+        ));
+    } else {
+        lines.push(format!(
+            "defs = Definitions(assets=[{}])",
+            assets.join(", ")
+        ));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Generate an Airflow DAG
+fn generate_airflow(spec: &OrchestrationSpec) -> MappingResult<String> {
+    let dag_id = python_identifier(&spec.name);
+    let mut lines = header(spec, "Airflow DAG");
+
+    lines.push("from datetime import datetime".to_string());
+    lines.push(String::new());
+    lines.push("from airflow import DAG".to_string());
+    lines.push("from airflow.operators.empty import EmptyOperator".to_string());
+    lines.push("from airflow.operators.python import PythonOperator".to_string());
+    lines.extend(step_imports(spec));
+    lines.push(String::new());
+    lines.extend(shared_definitions(spec)?);
+
+    // One callable per step
+    for step in &spec.steps {
+        lines.push(String::new());
+        lines.push(format!(
+            "def run_{}() -> None:",
+            python_identifier(&step.target_table)
+        ));
+        lines.push(format!(
+            "    \"\"\"{}\"\"\"",
+            step_description(step).replace('"', "'")
+        ));
+        lines.extend(step_body(step));
+        lines.push(String::new());
+    }
+
+    lines.push(String::new());
+    lines.push("with DAG(".to_string());
+    lines.push(format!("    dag_id={},", python_string(&dag_id)));
+    match spec.schedule {
+        Some(ref schedule) => lines.push(format!("    schedule={},", python_string(schedule))),
+        None => lines.push("    schedule=None,".to_string()),
+    }
+    lines.push("    start_date=datetime(2024, 1, 1),".to_string());
+    lines.push("    catchup=False,".to_string());
+    if let Some(ref owner) = spec.owner {
+        lines.push(format!(
+            "    default_args={{\"owner\": {}}},",
+            python_string(owner)
+        ));
+    }
+    lines.push("    tags=[\"odm\"],".to_string());
+    lines.push(") as dag:".to_string());
+
+    for source in spec.external_sources() {
+        lines.push(format!(
+            "    {} = EmptyOperator(task_id={})  # replace with a sensor for {}",
+            source_variable(source),
+            python_string(&source_variable(source)),
+            source
+        ));
+    }
+    for step in &spec.steps {
+        let name = python_identifier(&step.target_table);
+        lines.push(format!(
+            "    {} = PythonOperator(task_id={}, python_callable=run_{})",
+            task_variable(&name),
+            python_string(&task_variable(&name)),
+            name
+        ));
+    }
+
+    // Wire up dependencies
+    lines.push(String::new());
+    let targets: HashSet<&str> = spec.steps.iter().map(|s| s.target_table.as_str()).collect();
+    for step in &spec.steps {
+        let upstream = if targets.contains(step.source_table.as_str()) {
+            task_variable(&python_identifier(&step.source_table))
+        } else {
+            source_variable(&step.source_table)
+        };
+        lines.push(format!(
+            "    {} >> {}",
+            upstream,
+            task_variable(&python_identifier(&step.target_table))
+        ));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Module docstring shared by both orchestrators
+fn header(spec: &OrchestrationSpec, kind: &str) -> Vec<String> {
+    vec![
+        "\"\"\"".to_string(),
+        format!("{} for pipeline {}", kind, spec.name.replace('"', "'")),
+        String::new(),
+        "Generated from schema mappings; regenerate when the mappings change.".to_string(),
+        "\"\"\"".to_string(),
+        String::new(),
+    ]
+}
+
+/// Imports needed by the step bodies
+fn step_imports(spec: &OrchestrationSpec) -> Vec<String> {
+    let uses = |format: TransformFormat| spec.steps.iter().any(|s| s.format == format);
+    let mut imports = Vec::new();
+    if uses(TransformFormat::Sql) || uses(TransformFormat::Python) {
+        imports.push("import duckdb".to_string());
+    }
+    if uses(TransformFormat::Python) {
+        imports.push("import pandas as pd".to_string());
+    }
+    if uses(TransformFormat::Spark) {
+        imports.push("from pyspark.sql import SparkSession".to_string());
+    }
+    imports
+}
+
+/// Database constant, loader helper and embedded transforms
+fn shared_definitions(spec: &OrchestrationSpec) -> MappingResult<Vec<String>> {
+    let mut lines = Vec::new();
+
+    if spec
+        .steps
+        .iter()
+        .any(|s| s.format != TransformFormat::Spark)
+    {
+        lines.push(format!("DATABASE = {}", python_string(&spec.database)));
+        lines.push(String::new());
+    }
+
+    if spec.steps.iter().any(|s| s.format != TransformFormat::Sql) {
+        lines.push(String::new());
+        lines.push("def load_transform(source: str, name: str):".to_string());
+        lines.push(
+            "    \"\"\"Load a function from an embedded transformation script.\"\"\"".to_string(),
+        );
+        lines.push("    namespace: dict = {}".to_string());
+        lines.push("    exec(source, namespace)".to_string());
+        lines.push("    return namespace[name]".to_string());
+        lines.push(String::new());
+    }
+
+    for step in &spec.steps {
+        let script = generate_transform(
+            &step.mapping,
+            step.format,
+            &step.source_table,
+            &step.target_table,
+        )?;
+        lines.push(String::new());
+        lines.push(format!("{} = \"\"\"\\", transform_constant(step)));
+        lines.push(format!("{}\n\"\"\"", escape_triple_quoted(&script)));
+    }
+    lines.push(String::new());
+
+    Ok(lines)
+}
+
+/// Body of the function executing a step (indented four spaces)
+fn step_body(step: &PipelineStep) -> Vec<String> {
+    let constant = transform_constant(step);
+    match step.format {
+        TransformFormat::Sql => vec![
+            "    with duckdb.connect(DATABASE) as conn:".to_string(),
+            format!("        conn.execute({})", constant),
+        ],
+        TransformFormat::Python => vec![
+            format!(
+                "    transform_batch = load_transform({}, \"transform_batch\")",
+                constant
+            ),
+            "    with duckdb.connect(DATABASE) as conn:".to_string(),
+            format!(
+                "        records = conn.execute({}).fetchdf().to_dict(\"records\")",
+                python_string(&format!("SELECT * FROM {}", step.source_table))
+            ),
+            "        result = pd.DataFrame(transform_batch(records))".to_string(),
+            "        conn.register(\"transformed\", result)".to_string(),
+            format!(
+                "        conn.execute({})",
+                python_string(&format!(
+                    "INSERT INTO {} SELECT * FROM transformed",
+                    step.target_table
+                ))
+            ),
+        ],
+        TransformFormat::Spark => vec![
+            format!(
+                "    transform = load_transform({}, \"transform\")",
+                constant
+            ),
+            "    spark = SparkSession.builder.getOrCreate()".to_string(),
+            format!(
+                "    df = transform(spark.table({}))",
+                python_string(&step.source_table)
+            ),
+            format!(
+                "    df.write.mode(\"overwrite\").saveAsTable({})",
+                python_string(&step.target_table)
+            ),
+        ],
+        // Rejected by validation
+        TransformFormat::Jq => Vec::new(),
+    }
+}
+
+fn step_description(step: &PipelineStep) -> String {
+    step.description.clone().unwrap_or_else(|| {
+        format!(
+            "Map {} to {} ({:.1}% coverage)",
+            step.source_table,
+            step.target_table,
+            step.mapping.compatibility_score * 100.0
+        )
+    })
+}
+
+fn transform_constant(step: &PipelineStep) -> String {
+    format!(
+        "{}_TRANSFORM",
+        python_identifier(&step.target_table).to_uppercase()
+    )
+}
+
+fn source_variable(table: &str) -> String {
+    format!("source_{}", python_identifier(table))
+}
+
+fn task_variable(name: &str) -> String {
+    format!("transform_{}", name)
+}
+
+/// Convert a table name into a valid Python identifier
+fn python_identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// Quote a value as a Python string literal
+fn python_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn escape_triple_quoted(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\\\"\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::types::FieldMapping;
+
+    fn create_mapping() -> SchemaMapping {
+        let mut mapping = SchemaMapping::empty();
+        mapping
+            .direct_mappings
+            .push(FieldMapping::new("customer_id", "id"));
+        mapping
+            .direct_mappings
+            .push(FieldMapping::new("customer_name", "name"));
+        mapping.compatibility_score = 1.0;
+        mapping
+    }
+
+    fn create_spec() -> OrchestrationSpec {
+        OrchestrationSpec::new("customers")
+            .with_schedule("@daily")
+            .with_owner("data-team")
+            .with_step(PipelineStep::new(
+                "raw_customers",
+                "customers",
+                create_mapping(),
+            ))
+            .with_step(
+                PipelineStep::new("customers", "customer_features", create_mapping())
+                    .with_format(TransformFormat::Spark),
+            )
+    }
+
+    #[test]
+    fn test_generate_dagster() {
+        let code = generate_orchestration(&create_spec(), Orchestrator::Dagster).unwrap();
+
+        assert!(code.contains("source_raw_customers = AssetSpec(\"raw_customers\""));
+        assert!(code.contains("deps=[\"raw_customers\"]"));
+        assert!(code.contains("def customers(context: AssetExecutionContext) -> None:"));
+        assert!(code.contains("deps=[\"customers\"]"));
+        assert!(code.contains("CUSTOMERS_TRANSFORM = \"\"\"\\\n-- Schema mapping transformation"));
+        assert!(code.contains("INSERT INTO customers ("));
+        assert!(code.contains("load_transform(CUSTOMER_FEATURES_TRANSFORM, \"transform\")"));
+        assert!(code.contains("cron_schedule=\"@daily\""));
+        assert!(code.contains("metadata={\"owner\": \"data-team\"}"));
+        assert!(code.contains(
+            "defs = Definitions(assets=[source_raw_customers, customers, customer_features]"
+        ));
+    }
+
+    #[test]
+    fn test_generate_airflow() {
+        let code = generate_orchestration(&create_spec(), Orchestrator::Airflow).unwrap();
+
+        assert!(code.contains("dag_id=\"customers\""));
+        assert!(code.contains("schedule=\"@daily\""));
+        assert!(code.contains("default_args={\"owner\": \"data-team\"}"));
+        assert!(code.contains("def run_customers() -> None:"));
+        assert!(code.contains("source_raw_customers = EmptyOperator"));
+        assert!(code.contains("source_raw_customers >> transform_customers"));
+        assert!(code.contains("transform_customers >> transform_customer_features"));
+        assert!(code.contains("saveAsTable(\"customer_features\")"));
+    }
+
+    #[test]
+    fn test_invalid_specs() {
+        let empty = OrchestrationSpec::new("empty");
+        assert!(generate_orchestration(&empty, Orchestrator::Dagster).is_err());
+
+        let jq = OrchestrationSpec::new("jq").with_step(
+            PipelineStep::new("a", "b", create_mapping()).with_format(TransformFormat::Jq),
+        );
+        assert!(generate_orchestration(&jq, Orchestrator::Airflow).is_err());
+
+        let duplicate = OrchestrationSpec::new("dup")
+            .with_step(PipelineStep::new("a", "b", create_mapping()))
+            .with_step(PipelineStep::new("c", "b", create_mapping()));
+        assert!(generate_orchestration(&duplicate, Orchestrator::Dagster).is_err());
+    }
+
+    #[test]
+    fn test_orchestrator_parse() {
+        assert_eq!(
+            "dagster".parse::<Orchestrator>().unwrap(),
+            Orchestrator::Dagster
+        );
+        assert_eq!(
+            "Airflow".parse::<Orchestrator>().unwrap(),
+            Orchestrator::Airflow
+        );
+        assert!("prefect".parse::<Orchestrator>().is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Usage metrics hooks
+//!
+//! The SDK never sends metrics anywhere itself. A host application that wants to
+//! monitor SDK usage implements [`MetricsRecorder`] and installs it with
+//! [`set_recorder`]; key operations then report counters and durations to it:
+//!
+//! | Metric | Kind | Labels |
+//! |--------|------|--------|
+//! | [`IMPORTS`] | counter | `format`, `outcome` |
+//! | [`IMPORT_DURATION`] | duration | `format` |
+//! | [`EXPORTS`] | counter | `format`, `outcome` |
+//! | [`EXPORT_DURATION`] | duration | `format` |
+//! | [`VALIDATION_FAILURES`] | counter | `rule` |
+//!
+//! Imports and exports are instrumented in [`ImporterRegistry`] and
+//! [`ExporterRegistry`]. `outcome` is `success` or `error`. Without a recorder,
+//! instrumentation is a no-op.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use data_modelling_core::metrics::{self, MetricsRecorder};
+//!
+//! struct LogRecorder;
+//!
+//! impl MetricsRecorder for LogRecorder {
+//!     fn increment_counter(&self, name: &str, labels: &[(&str, &str)], value: u64) {
+//!         println!("{} {:?} +{}", name, labels, value);
+//!     }
+//!
+//!     fn record_duration(&self, name: &str, labels: &[(&str, &str)], duration: Duration) {
+//!         println!("{} {:?} {:?}", name, labels, duration);
+//!     }
+//! }
+//!
+//! metrics::set_recorder(Arc::new(LogRecorder));
+//! ```
+//!
+//! [`ImporterRegistry`]: crate::import::registry::ImporterRegistry
+//! [`ExporterRegistry`]: crate::export::ExporterRegistry
+
+use chrono::Utc;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Counter of imports
+pub const IMPORTS: &str = "odm.imports";
+
+/// Duration of imports
+pub const IMPORT_DURATION: &str = "odm.import.duration";
+
+/// Counter of exports
+pub const EXPORTS: &str = "odm.exports";
+
+/// Duration of exports
+pub const EXPORT_DURATION: &str = "odm.export.duration";
+
+/// Counter of validation failures
+pub const VALIDATION_FAILURES: &str = "odm.validation.failures";
+
+/// Label for the import or export format
+pub const LABEL_FORMAT: &str = "format";
+
+/// Label for the outcome of an operation, `success` or `error`
+pub const LABEL_OUTCOME: &str = "outcome";
+
+/// Label for the validation rule that failed
+pub const LABEL_RULE: &str = "rule";
+
+/// Receives metrics from the SDK
+///
+/// Implementations forward them to the host application's monitoring, and must
+/// be cheap: they are called inline from the instrumented operations.
+pub trait MetricsRecorder: Send + Sync {
+    /// Add `value` to a counter
+    fn increment_counter(&self, name: &str, labels: &[(&str, &str)], value: u64);
+
+    /// Record the duration of an operation
+    fn record_duration(&self, name: &str, labels: &[(&str, &str)], duration: Duration);
+}
+
+static RECORDER: RwLock<Option<Arc<dyn MetricsRecorder>>> = RwLock::new(None);
+
+/// Install the recorder receiving SDK metrics, replacing any previous one
+pub fn set_recorder(recorder: Arc<dyn MetricsRecorder>) {
+    *RECORDER.write().unwrap_or_else(|e| e.into_inner()) = Some(recorder);
+}
+
+/// Remove the installed recorder
+pub fn clear_recorder() {
+    *RECORDER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// The installed recorder, if any
+pub fn recorder() -> Option<Arc<dyn MetricsRecorder>> {
+    RECORDER.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Increment a counter by one on the installed recorder
+pub fn increment_counter(name: &str, labels: &[(&str, &str)]) {
+    if let Some(recorder) = recorder() {
+        recorder.increment_counter(name, labels, 1);
+    }
+}
+
+/// Record a validation failure of `rule`
+pub fn validation_failure(rule: &str) {
+    increment_counter(VALIDATION_FAILURES, &[(LABEL_RULE, rule)]);
+}
+
+/// Run an operation, reporting its duration under `duration_name` and its
+/// outcome under `counter_name`, both labelled with `format`
+pub(crate) fn track<T, E>(
+    counter_name: &str,
+    duration_name: &str,
+    format: &str,
+    operation: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let Some(recorder) = recorder() else {
+        return operation();
+    };
+
+    // chrono rather than `Instant`, which is unavailable on wasm32
+    let start = Utc::now();
+    let result = operation();
+    let duration = (Utc::now() - start).to_std().unwrap_or_default();
+
+    let outcome = if result.is_ok() { "success" } else { "error" };
+    recorder.record_duration(duration_name, &[(LABEL_FORMAT, format)], duration);
+    recorder.increment_counter(
+        counter_name,
+        &[(LABEL_FORMAT, format), (LABEL_OUTCOME, outcome)],
+        1,
+    );
+    result
+}
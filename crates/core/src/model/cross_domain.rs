@@ -0,0 +1,497 @@
+//! Cross-domain reference resolution
+//!
+//! Resolves the table and relationship references of a [`CrossDomainConfig`]
+//! against the workspace that owns them and reports references that no longer
+//! hold:
+//!
+//! - **broken** - the referenced table or relationship was deleted, or a
+//!   relationship points at a table that no longer exists
+//! - **stale** - the reference still resolves, but the owning domain changed it:
+//!   the table's content differs from the pinned fingerprint, the table moved to
+//!   another domain, the relationship's endpoints changed, or one of its ends is
+//!   no longer imported
+//!
+//! Table changes are detected by comparing [`table_fingerprint`] with the
+//! fingerprint recorded on the reference; [`CrossDomainResolver::pin`] records
+//! the current fingerprints once a domain has reviewed the changes.
+
+use crate::import::odcs::ODCSImporter;
+use crate::model::loader::ModelLoader;
+use crate::models::cross_domain::{
+    CrossDomainConfig, CrossDomainRelationshipRef, CrossDomainTableRef,
+};
+use crate::models::relationship::Relationship;
+use crate::models::table::Table;
+use crate::storage::{StorageBackend, StorageError};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Fingerprint of a table's content (see [`Table::fingerprint`])
+pub fn table_fingerprint(table: &Table) -> String {
+    table.fingerprint()
+}
+
+/// How severe a reference issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferenceSeverity {
+    /// The reference can no longer be resolved
+    Broken,
+    /// The reference resolves, but the owning domain changed it
+    Stale,
+}
+
+/// What is wrong with a reference
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ReferenceIssueKind {
+    /// The referenced table no longer exists
+    TableDeleted,
+    /// The referenced table is now owned by another domain
+    TableMoved { domain: String },
+    /// The referenced table's schema changed since it was pinned
+    TableChanged { previous: String, current: String },
+    /// The referenced relationship no longer exists
+    RelationshipDeleted,
+    /// A table at one end of the relationship no longer exists
+    RelationshipEndpointMissing { table_id: Uuid },
+    /// The relationship now connects different tables
+    RelationshipEndpointsChanged {
+        source_table_id: Uuid,
+        target_table_id: Uuid,
+    },
+    /// A table at one end of the relationship is no longer imported
+    RelationshipNotImported { table_id: Uuid },
+}
+
+impl ReferenceIssueKind {
+    /// Severity of this kind of issue
+    pub fn severity(&self) -> ReferenceSeverity {
+        match self {
+            Self::TableDeleted
+            | Self::RelationshipDeleted
+            | Self::RelationshipEndpointMissing { .. } => ReferenceSeverity::Broken,
+            Self::TableMoved { .. }
+            | Self::TableChanged { .. }
+            | Self::RelationshipEndpointsChanged { .. }
+            | Self::RelationshipNotImported { .. } => ReferenceSeverity::Stale,
+        }
+    }
+
+    /// Human-readable description
+    pub fn message(&self) -> String {
+        match self {
+            Self::TableDeleted => "table was deleted".to_string(),
+            Self::TableMoved { domain } => format!("table moved to domain '{}'", domain),
+            Self::TableChanged { .. } => "table schema changed".to_string(),
+            Self::RelationshipDeleted => "relationship was deleted".to_string(),
+            Self::RelationshipEndpointMissing { table_id } => {
+                format!("relationship table {} was deleted", table_id)
+            }
+            Self::RelationshipEndpointsChanged {
+                source_table_id,
+                target_table_id,
+            } => format!(
+                "relationship now connects {} → {}",
+                source_table_id, target_table_id
+            ),
+            Self::RelationshipNotImported { table_id } => {
+                format!("relationship table {} is not imported", table_id)
+            }
+        }
+    }
+}
+
+/// A broken or stale reference
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceIssue {
+    /// ID of the reference in the cross-domain configuration
+    pub reference_id: Uuid,
+    /// Domain the reference points at
+    pub source_domain: String,
+    /// ID of the referenced table or relationship
+    pub target_id: Uuid,
+    /// Current (or last known) name of the referenced table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub kind: ReferenceIssueKind,
+}
+
+impl ReferenceIssue {
+    /// Severity of the issue
+    pub fn severity(&self) -> ReferenceSeverity {
+        self.kind.severity()
+    }
+}
+
+/// Result of checking a cross-domain configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossDomainReport {
+    pub tables_checked: usize,
+    pub relationships_checked: usize,
+    /// Table references without a pinned fingerprint (changes cannot be detected)
+    pub unpinned: usize,
+    pub issues: Vec<ReferenceIssue>,
+}
+
+impl CrossDomainReport {
+    /// Whether every reference resolved unchanged
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// References that can no longer be resolved
+    pub fn broken(&self) -> impl Iterator<Item = &ReferenceIssue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity() == ReferenceSeverity::Broken)
+    }
+
+    /// References changed by their owning domain
+    pub fn stale(&self) -> impl Iterator<Item = &ReferenceIssue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity() == ReferenceSeverity::Stale)
+    }
+
+    /// Render the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Cross-Domain Reference Report\n\n");
+        out.push_str(&format!(
+            "{} table and {} relationship reference(s) checked: {} broken, {} stale, {} unpinned.\n",
+            self.tables_checked,
+            self.relationships_checked,
+            self.broken().count(),
+            self.stale().count(),
+            self.unpinned
+        ));
+
+        if !self.issues.is_empty() {
+            out.push_str(
+                "\n## Issues\n\n| Severity | Domain | Reference | Issue |\n|---|---|---|---|\n",
+            );
+            for issue in &self.issues {
+                let severity = match issue.severity() {
+                    ReferenceSeverity::Broken => "broken",
+                    ReferenceSeverity::Stale => "stale",
+                };
+                let target = match &issue.name {
+                    Some(name) => format!("{} ({})", name, issue.target_id),
+                    None => issue.target_id.to_string(),
+                };
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    severity,
+                    issue.source_domain,
+                    target,
+                    issue.kind.message()
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// A table reference resolved to the owning domain's table
+#[derive(Debug, Clone)]
+pub struct ResolvedTableRef {
+    pub reference: CrossDomainTableRef,
+    pub table: Table,
+    /// Domain currently owning the table, if known
+    pub domain: Option<String>,
+    /// Current content fingerprint of the table
+    pub fingerprint: String,
+}
+
+/// A relationship reference resolved to the owning domain's relationship
+#[derive(Debug, Clone)]
+pub struct ResolvedRelationshipRef {
+    pub reference: CrossDomainRelationshipRef,
+    pub relationship: Relationship,
+}
+
+/// Resolved references of a cross-domain configuration
+#[derive(Debug, Clone, Default)]
+pub struct CrossDomainResolution {
+    pub tables: Vec<ResolvedTableRef>,
+    pub relationships: Vec<ResolvedRelationshipRef>,
+    pub report: CrossDomainReport,
+}
+
+/// Tables and relationships of the workspace, indexed by ID
+#[derive(Default)]
+struct WorkspaceIndex {
+    tables: HashMap<Uuid, (Table, Option<String>)>,
+    relationships: HashMap<Uuid, Relationship>,
+}
+
+/// Resolves cross-domain references against the workspace stored in a backend
+pub struct CrossDomainResolver<B: StorageBackend> {
+    loader: ModelLoader<B>,
+    workspace_path: String,
+}
+
+impl<B: StorageBackend> CrossDomainResolver<B> {
+    /// Create a resolver for the workspace at `workspace_path` in `storage`
+    pub fn new(storage: B, workspace_path: impl Into<String>) -> Self {
+        Self {
+            loader: ModelLoader::new(storage),
+            workspace_path: workspace_path.into(),
+        }
+    }
+
+    /// Load the referenced tables and relationships and report broken or stale references
+    pub async fn resolve(
+        &self,
+        config: &CrossDomainConfig,
+    ) -> Result<CrossDomainResolution, StorageError> {
+        let index = self.load_index().await?;
+        let mut resolution = CrossDomainResolution::default();
+        let report = &mut resolution.report;
+
+        for reference in &config.imported_tables {
+            report.tables_checked += 1;
+            let Some((table, domain)) = index.tables.get(&reference.table_id) else {
+                report.issues.push(table_issue(
+                    reference,
+                    None,
+                    ReferenceIssueKind::TableDeleted,
+                ));
+                continue;
+            };
+
+            let fingerprint = table_fingerprint(table);
+            if let Some(domain) = domain
+                && domain != &reference.source_domain
+            {
+                report.issues.push(table_issue(
+                    reference,
+                    Some(&table.name),
+                    ReferenceIssueKind::TableMoved {
+                        domain: domain.clone(),
+                    },
+                ));
+            }
+            match &reference.fingerprint {
+                Some(previous) if previous != &fingerprint => {
+                    report.issues.push(table_issue(
+                        reference,
+                        Some(&table.name),
+                        ReferenceIssueKind::TableChanged {
+                            previous: previous.clone(),
+                            current: fingerprint.clone(),
+                        },
+                    ));
+                }
+                Some(_) => {}
+                None => report.unpinned += 1,
+            }
+
+            resolution.tables.push(ResolvedTableRef {
+                reference: reference.clone(),
+                table: table.clone(),
+                domain: domain.clone(),
+                fingerprint,
+            });
+        }
+
+        let imported: HashSet<Uuid> = config.imported_tables.iter().map(|t| t.table_id).collect();
+        for reference in &config.imported_relationships {
+            report.relationships_checked += 1;
+            let Some(relationship) = index.relationships.get(&reference.relationship_id) else {
+                report.issues.push(relationship_issue(
+                    reference,
+                    ReferenceIssueKind::RelationshipDeleted,
+                ));
+                continue;
+            };
+
+            let endpoints = [relationship.source_table_id, relationship.target_table_id];
+            if let Some(&table_id) = endpoints.iter().find(|id| !index.tables.contains_key(id)) {
+                report.issues.push(relationship_issue(
+                    reference,
+                    ReferenceIssueKind::RelationshipEndpointMissing { table_id },
+                ));
+                continue;
+            }
+            if endpoints != [reference.source_table_id, reference.target_table_id] {
+                report.issues.push(relationship_issue(
+                    reference,
+                    ReferenceIssueKind::RelationshipEndpointsChanged {
+                        source_table_id: relationship.source_table_id,
+                        target_table_id: relationship.target_table_id,
+                    },
+                ));
+            }
+            if let Some(&table_id) = endpoints.iter().find(|id| !imported.contains(id)) {
+                report.issues.push(relationship_issue(
+                    reference,
+                    ReferenceIssueKind::RelationshipNotImported { table_id },
+                ));
+            }
+
+            resolution.relationships.push(ResolvedRelationshipRef {
+                reference: reference.clone(),
+                relationship: relationship.clone(),
+            });
+        }
+
+        Ok(resolution)
+    }
+
+    /// Report broken or stale references without returning the resolved data
+    pub async fn check(
+        &self,
+        config: &CrossDomainConfig,
+    ) -> Result<CrossDomainReport, StorageError> {
+        Ok(self.resolve(config).await?.report)
+    }
+
+    /// Accept the owning domains' current state: record current fingerprints and
+    /// domains on table references and current endpoints on relationship
+    /// references. Broken references are left unchanged.
+    ///
+    /// Returns the number of references updated.
+    pub async fn pin(&self, config: &mut CrossDomainConfig) -> Result<usize, StorageError> {
+        let resolution = self.resolve(config).await?;
+        let mut updated = 0;
+
+        let tables: HashMap<Uuid, &ResolvedTableRef> = resolution
+            .tables
+            .iter()
+            .map(|t| (t.reference.id, t))
+            .collect();
+        for reference in &mut config.imported_tables {
+            let Some(resolved) = tables.get(&reference.id) else {
+                continue;
+            };
+            let domain = resolved
+                .domain
+                .clone()
+                .unwrap_or_else(|| reference.source_domain.clone());
+            if reference.fingerprint.as_deref() != Some(resolved.fingerprint.as_str())
+                || reference.source_domain != domain
+            {
+                reference.fingerprint = Some(resolved.fingerprint.clone());
+                reference.source_domain = domain;
+                updated += 1;
+            }
+        }
+
+        let relationships: HashMap<Uuid, &Relationship> = resolution
+            .relationships
+            .iter()
+            .map(|r| (r.reference.id, &r.relationship))
+            .collect();
+        for reference in &mut config.imported_relationships {
+            let Some(relationship) = relationships.get(&reference.id) else {
+                continue;
+            };
+            if reference.source_table_id != relationship.source_table_id
+                || reference.target_table_id != relationship.target_table_id
+            {
+                reference.source_table_id = relationship.source_table_id;
+                reference.target_table_id = relationship.target_table_id;
+                updated += 1;
+            }
+        }
+
+        Ok(updated)
+    }
+
+    /// Load every table (with its owning domain) and relationship of the workspace
+    async fn load_index(&self) -> Result<WorkspaceIndex, StorageError> {
+        let workspace = self.loader.load_workspace(&self.workspace_path).await?;
+        let model = self.loader.load_model(&self.workspace_path).await?;
+        let definitions = match self.loader.load_definitions(&self.workspace_path).await {
+            Ok(definitions) => definitions,
+            Err(e) => {
+                warn!(
+                    "Failed to load property definitions from {}: {}",
+                    self.workspace_path, e
+                );
+                None
+            }
+        };
+
+        let mut index = WorkspaceIndex::default();
+        for table_data in &model.tables {
+            let mut importer = ODCSImporter::new();
+            if let Some(definitions) = &definitions {
+                importer = importer.with_definitions(definitions.clone());
+            }
+            let table = match importer.parse_table(&table_data.yaml_content) {
+                Ok((table, _)) => table,
+                Err(e) => {
+                    warn!("Failed to parse table {}: {}", table_data.name, e);
+                    continue;
+                }
+            };
+            let domain = workspace
+                .as_ref()
+                .and_then(|w| w.assets.iter().find(|a| a.id == table.id))
+                .map(|a| a.domain.clone())
+                .or_else(|| {
+                    table
+                        .odcl_metadata
+                        .get("domain")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                });
+            index.tables.insert(table.id, (table, domain));
+        }
+
+        if let Some(workspace) = workspace {
+            for relationship in workspace.relationships {
+                index.relationships.insert(relationship.id, relationship);
+            }
+        }
+        for data in model
+            .relationships
+            .iter()
+            .chain(&model.orphaned_relationships)
+        {
+            index.relationships.entry(data.id).or_insert_with(|| {
+                let mut relationship =
+                    Relationship::new(data.source_table_id, data.target_table_id);
+                relationship.id = data.id;
+                relationship
+            });
+        }
+        Ok(index)
+    }
+}
+
+fn table_issue(
+    reference: &CrossDomainTableRef,
+    name: Option<&str>,
+    kind: ReferenceIssueKind,
+) -> ReferenceIssue {
+    ReferenceIssue {
+        reference_id: reference.id,
+        source_domain: reference.source_domain.clone(),
+        target_id: reference.table_id,
+        name: name
+            .map(str::to_string)
+            .or_else(|| reference.display_alias.clone()),
+        kind,
+    }
+}
+
+fn relationship_issue(
+    reference: &CrossDomainRelationshipRef,
+    kind: ReferenceIssueKind,
+) -> ReferenceIssue {
+    ReferenceIssue {
+        reference_id: reference.id,
+        source_domain: reference.source_domain.clone(),
+        target_id: reference.relationship_id,
+        name: None,
+        kind,
+    }
+}
@@ -13,8 +13,17 @@
 //! - `{workspace}_{domain}_{system}_{resource}.odps.yaml` - ODPS product files
 //! - `{workspace}_{domain}_{system}_{resource}.cads.yaml` - CADS asset files
 //! - `relationships.yaml` - relationship definitions
+//! - `definitions.yaml` - reusable property definitions referenced with `$ref`
 //!
 //! Where `{system}` is optional if the resource is at the domain level.
+//!
+//! ## Tenants
+//!
+//! A loader created [`with_tenant`](ModelLoader::with_tenant) refuses to load or
+//! save a workspace that declares another tenant, and skips contracts whose ODCS
+//! `tenant` names another tenant. Combine it with
+//! [`TenantStorage`](crate::storage::tenant::TenantStorage) to also confine file
+//! access to the tenant's directory.
 
 #[cfg(feature = "bpmn")]
 use crate::import::bpmn::BPMNImporter;
@@ -28,14 +37,17 @@ use crate::import::{cads::CADSImporter, odcs::ODCSImporter, odps::ODPSImporter};
 #[cfg(feature = "bpmn")]
 use crate::models::bpmn::BPMNModel;
 use crate::models::decision::{Decision, DecisionIndex};
+use crate::models::definitions::{DEFINITIONS_FILE, PropertyDefinitions};
 #[cfg(feature = "dmn")]
 use crate::models::dmn::DMNModel;
 use crate::models::domain_config::DomainConfig;
 use crate::models::knowledge::{KnowledgeArticle, KnowledgeIndex};
 #[cfg(feature = "openapi")]
 use crate::models::openapi::{OpenAPIFormat, OpenAPIModel};
+use crate::models::type_mapping::{TYPE_MAPPINGS_FILE, TypeMappingRegistry};
 use crate::models::workspace::{AssetType, Workspace};
 use crate::models::{cads::CADSAsset, domain::Domain, odps::ODPSDataProduct, table::Table};
+use crate::storage::tenant::TenantId;
 use crate::storage::{StorageBackend, StorageError};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -47,12 +59,52 @@ use uuid::Uuid;
 /// Model loader that uses a storage backend
 pub struct ModelLoader<B: StorageBackend> {
     storage: B,
+    tenant: Option<TenantId>,
 }
 
 impl<B: StorageBackend> ModelLoader<B> {
     /// Create a new model loader with the given storage backend
     pub fn new(storage: B) -> Self {
-        Self { storage }
+        Self {
+            storage,
+            tenant: None,
+        }
+    }
+
+    /// Only load workspaces and contracts of `tenant`
+    pub fn with_tenant(mut self, tenant: TenantId) -> Self {
+        self.tenant = Some(tenant);
+        self
+    }
+
+    /// Tenant the loader is scoped to
+    pub fn tenant(&self) -> Option<&TenantId> {
+        self.tenant.as_ref()
+    }
+
+    /// Fail if `workspace` belongs to another tenant than the loader's
+    fn check_workspace_tenant(&self, workspace: &Workspace) -> Result<(), StorageError> {
+        match (&self.tenant, workspace.tenant.as_deref()) {
+            (Some(tenant), Some(other)) if !tenant.matches(Some(other)) => {
+                Err(StorageError::PermissionDenied(format!(
+                    "Workspace '{}' belongs to tenant '{}', not '{}'",
+                    workspace.name, other, tenant
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether a contract may be loaded by this loader: contracts without a
+    /// `tenant` belong to whoever owns the workspace
+    fn is_tenant_contract(&self, yaml_content: &str) -> bool {
+        let Some(tenant) = &self.tenant else {
+            return true;
+        };
+        let contract_tenant = serde_yaml::from_str::<serde_yaml::Value>(yaml_content)
+            .ok()
+            .and_then(|v| v.get("tenant").and_then(|t| t.as_str()).map(str::to_string));
+        contract_tenant.is_none() || tenant.matches(contract_tenant.as_deref())
     }
 
     /// Load a model from storage
@@ -79,6 +131,17 @@ impl<B: StorageBackend> ModelLoader<B> {
         let mut tables = Vec::new();
         let mut table_ids: HashMap<Uuid, String> = HashMap::new();
 
+        let definitions = match self.load_definitions(workspace_path).await {
+            Ok(definitions) => definitions,
+            Err(e) => {
+                warn!(
+                    "Failed to load property definitions from {}: {}",
+                    workspace_path, e
+                );
+                None
+            }
+        };
+
         let files = self.storage.list_files(workspace_path).await?;
         for file_name in files {
             // Only load supported asset files (skip workspace.yaml, relationships.yaml, etc.)
@@ -86,7 +149,13 @@ impl<B: StorageBackend> ModelLoader<B> {
                 // Skip workspace-level files and non-ODCS files for table loading
                 if asset_type == AssetType::Odcs {
                     let file_path = format!("{}/{}", workspace_path, file_name);
-                    match self.load_table_from_yaml(&file_path, workspace_path).await {
+                    match self
+                        .load_table_from_yaml(&file_path, workspace_path, definitions.as_ref())
+                        .await
+                    {
+                        Ok(table_data) if !self.is_tenant_contract(&table_data.yaml_content) => {
+                            warn!("Skipping {}: contract belongs to another tenant", file_path);
+                        }
                         Ok(table_data) => {
                             table_ids.insert(table_data.id, table_data.name.clone());
                             tables.push(table_data);
@@ -164,6 +233,7 @@ impl<B: StorageBackend> ModelLoader<B> {
         &self,
         yaml_path: &str,
         workspace_path: &str,
+        definitions: Option<&PropertyDefinitions>,
     ) -> Result<TableData, StorageError> {
         let content = self.storage.read_file(yaml_path).await?;
         let yaml_content = String::from_utf8(content)
@@ -171,6 +241,9 @@ impl<B: StorageBackend> ModelLoader<B> {
 
         // Use ODCSImporter to fully parse the table structure
         let mut importer = crate::import::odcs::ODCSImporter::new();
+        if let Some(definitions) = definitions {
+            importer = importer.with_definitions(definitions.clone());
+        }
         let (table, parse_errors) = importer.parse_table(&yaml_content).map_err(|e| {
             StorageError::SerializationError(format!("Failed to parse ODCS YAML: {}", e))
         })?;
@@ -293,8 +366,9 @@ impl<B: StorageBackend> ModelLoader<B> {
 
         // If workspace.yaml exists, use its domain definitions
         if let Some(ws) = &workspace {
+            let ids = ws.id_generator();
             for domain_ref in &ws.domains {
-                domains.push(Domain::new(domain_ref.name.clone()));
+                domains.push(Domain::new_with_generator(domain_ref.name.clone(), &ids));
             }
         }
 
@@ -502,7 +576,10 @@ impl<B: StorageBackend> ModelLoader<B> {
         for file_name in files {
             if file_name.ends_with(".odcs.yaml") || file_name.ends_with(".odcs.yml") {
                 let file_path = format!("{}/{}", domain_dir, file_name);
-                match self.load_table_from_yaml(&file_path, domain_dir).await {
+                match self
+                    .load_table_from_yaml(&file_path, domain_dir, None)
+                    .await
+                {
                     Ok(table_data) => {
                         // Parse the table from ODCS YAML
                         let mut importer = ODCSImporter::new();
@@ -941,7 +1018,7 @@ impl<B: StorageBackend> ModelLoader<B> {
         let mut load_errors = Vec::new();
 
         let files = self.storage.list_files(workspace_path).await?;
-        let importer = DecisionImporter;
+        let importer = DecisionImporter::new();
 
         for file_name in files {
             if let Some(AssetType::Decision) = AssetType::from_filename(&file_name) {
@@ -1017,7 +1094,7 @@ impl<B: StorageBackend> ModelLoader<B> {
         let yaml_content = String::from_utf8(content)
             .map_err(|e| StorageError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
 
-        let importer = DecisionImporter;
+        let importer = DecisionImporter::new();
         let index = importer.import_index(&yaml_content).map_err(|e| {
             StorageError::SerializationError(format!("Failed to parse decisions.yaml: {}", e))
         })?;
@@ -1045,7 +1122,7 @@ impl<B: StorageBackend> ModelLoader<B> {
         let mut load_errors = Vec::new();
 
         let files = self.storage.list_files(workspace_path).await?;
-        let importer = KnowledgeImporter;
+        let importer = KnowledgeImporter::new();
 
         for file_name in files {
             if let Some(AssetType::Knowledge) = AssetType::from_filename(&file_name) {
@@ -1121,7 +1198,7 @@ impl<B: StorageBackend> ModelLoader<B> {
         let yaml_content = String::from_utf8(content)
             .map_err(|e| StorageError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
 
-        let importer = KnowledgeImporter;
+        let importer = KnowledgeImporter::new();
         let index = importer.import_index(&yaml_content).map_err(|e| {
             StorageError::SerializationError(format!("Failed to parse knowledge.yaml: {}", e))
         })?;
@@ -1216,9 +1293,13 @@ impl<B: StorageBackend> ModelLoader<B> {
         let yaml_content = String::from_utf8(content)
             .map_err(|e| StorageError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
 
-        let workspace: Workspace = serde_yaml::from_str(&yaml_content).map_err(|e| {
+        let mut workspace: Workspace = serde_yaml::from_str(&yaml_content).map_err(|e| {
             StorageError::SerializationError(format!("Failed to parse workspace.yaml: {}", e))
         })?;
+        self.check_workspace_tenant(&workspace)?;
+        if let Some(tenant) = &self.tenant {
+            workspace.tenant = Some(tenant.to_string());
+        }
 
         Ok(Some(workspace))
     }
@@ -1234,6 +1315,7 @@ impl<B: StorageBackend> ModelLoader<B> {
         workspace_path: &str,
         workspace: &Workspace,
     ) -> Result<(), StorageError> {
+        self.check_workspace_tenant(workspace)?;
         let workspace_file = format!("{}/workspace.yaml", workspace_path);
 
         let yaml_content = serde_yaml::to_string(workspace).map_err(|e| {
@@ -1247,6 +1329,92 @@ impl<B: StorageBackend> ModelLoader<B> {
         Ok(())
     }
 
+    /// Load reusable property definitions from definitions.yaml
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_path` - Path to the workspace directory
+    ///
+    /// # Returns
+    ///
+    /// The PropertyDefinitions if found, or None if definitions.yaml doesn't exist
+    pub async fn load_definitions(
+        &self,
+        workspace_path: &str,
+    ) -> Result<Option<PropertyDefinitions>, StorageError> {
+        let definitions_file = format!("{}/{}", workspace_path, DEFINITIONS_FILE);
+
+        if !self.storage.file_exists(&definitions_file).await? {
+            return Ok(None);
+        }
+
+        let content = self.storage.read_file(&definitions_file).await?;
+        let yaml_content = String::from_utf8(content)
+            .map_err(|e| StorageError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
+
+        let definitions = PropertyDefinitions::from_yaml(&yaml_content).map_err(|e| {
+            StorageError::SerializationError(format!("Failed to parse definitions.yaml: {}", e))
+        })?;
+
+        Ok(Some(definitions))
+    }
+
+    /// Save reusable property definitions to definitions.yaml
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_path` - Path to the workspace directory
+    /// * `definitions` - The property definitions to save
+    pub async fn save_definitions(
+        &self,
+        workspace_path: &str,
+        definitions: &PropertyDefinitions,
+    ) -> Result<(), StorageError> {
+        let definitions_file = format!("{}/{}", workspace_path, DEFINITIONS_FILE);
+
+        let yaml_content = definitions.to_yaml().map_err(|e| {
+            StorageError::SerializationError(format!("Failed to serialize definitions: {}", e))
+        })?;
+
+        self.storage
+            .write_file(&definitions_file, yaml_content.as_bytes())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Load the type mappings of a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_path` - Path to the workspace directory
+    ///
+    /// # Returns
+    ///
+    /// The built-in type mappings with the overrides from type-mappings.yaml,
+    /// or just the built-in mappings if the file doesn't exist
+    pub async fn load_type_mappings(
+        &self,
+        workspace_path: &str,
+    ) -> Result<TypeMappingRegistry, StorageError> {
+        let mappings_file = format!("{}/{}", workspace_path, TYPE_MAPPINGS_FILE);
+
+        if !self.storage.file_exists(&mappings_file).await? {
+            return Ok(TypeMappingRegistry::default());
+        }
+
+        let content = self.storage.read_file(&mappings_file).await?;
+        let yaml_content = String::from_utf8(content)
+            .map_err(|e| StorageError::SerializationError(format!("Invalid UTF-8: {}", e)))?;
+
+        TypeMappingRegistry::from_yaml(&yaml_content).map_err(|e| {
+            StorageError::SerializationError(format!(
+                "Failed to parse {}: {}",
+                TYPE_MAPPINGS_FILE, e
+            ))
+        })
+    }
+
     /// Load domain configuration from domain.yaml
     ///
     /// # Arguments
@@ -14,10 +14,12 @@
 
 #[cfg(feature = "api-backend")]
 pub mod api_loader;
+pub mod cross_domain;
 pub mod loader;
 pub mod saver;
 
 #[cfg(feature = "api-backend")]
 pub use api_loader::ApiModelLoader;
+pub use cross_domain::{CrossDomainReport, CrossDomainResolver};
 pub use loader::{DomainLoadResult, ModelLoader};
 pub use saver::ModelSaver;
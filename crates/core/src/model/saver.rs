@@ -24,6 +24,8 @@ use crate::models::knowledge::{KnowledgeArticle, KnowledgeIndex};
 #[cfg(feature = "openapi")]
 use crate::models::openapi::{OpenAPIFormat, OpenAPIModel};
 use crate::models::{cads::CADSAsset, domain::Domain, odps::ODPSDataProduct, table::Table};
+use crate::storage::dry_run::{Changeset, DryRunStorage};
+use crate::storage::transaction::{TransactionError, TransactionStorage};
 use crate::storage::{StorageBackend, StorageError};
 use anyhow::Result;
 use serde_yaml;
@@ -42,6 +44,24 @@ impl<B: StorageBackend> ModelSaver<B> {
         Self { storage }
     }
 
+    /// Create a model saver that records what it would write instead of writing
+    ///
+    /// Save as usual, then call [`ModelSaver::changeset`] to list the files that
+    /// would be created or modified, with a diff of their content.
+    pub fn dry_run(storage: B) -> ModelSaver<DryRunStorage<B>> {
+        ModelSaver::new(DryRunStorage::new(storage))
+    }
+
+    /// Create a model saver whose saves are applied all at once
+    ///
+    /// Save as usual, then call [`ModelSaver::commit`] to write every file, or
+    /// drop the saver to discard the saves. A failed commit leaves the workspace
+    /// unchanged, so a multi-table save never leaves table files and
+    /// `relationships.yaml` out of step.
+    pub fn transaction(storage: B) -> ModelSaver<TransactionStorage<B>> {
+        ModelSaver::new(TransactionStorage::new(storage))
+    }
+
     /// Save a table to storage
     ///
     /// Saves the table as a YAML file in the workspace's `tables/` directory.
@@ -674,6 +694,25 @@ impl<B: StorageBackend> ModelSaver<B> {
     }
 }
 
+impl<B: StorageBackend> ModelSaver<DryRunStorage<B>> {
+    /// Files the saves so far would have created or modified
+    pub fn changeset(&self) -> Changeset {
+        self.storage.changeset()
+    }
+}
+
+impl<B: StorageBackend> ModelSaver<TransactionStorage<B>> {
+    /// Files the saves so far will create, modify or delete on commit
+    pub fn changeset(&self) -> Changeset {
+        self.storage.changeset()
+    }
+
+    /// Validate and apply all saves, or none of them
+    pub async fn commit(self) -> Result<Changeset, TransactionError> {
+        self.storage.commit().await
+    }
+}
+
 /// Table data to save
 #[derive(Debug, Clone)]
 pub struct TableData {
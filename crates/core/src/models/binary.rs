@@ -0,0 +1,161 @@
+//! Binary and large object data types
+//!
+//! `BLOB`, `BYTEA`, `BINARY(n)`, `VARBINARY(n)`, `BYTES`, MySQL's `*BLOB` variants
+//! and SQL Server's `IMAGE` all hold raw bytes. [`BinaryType`] keeps an optional
+//! maximum length so exporters can pick each dialect's equivalent: bounded
+//! `VARBINARY(n)` where supported, otherwise the dialect's large object type.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::binary::BinaryType;
+//!
+//! let blob = BinaryType::parse("BLOB").unwrap();
+//! assert_eq!(blob.to_sql("postgres").as_deref(), Some("BYTEA"));
+//! assert_eq!(blob.to_sql("sqlserver").as_deref(), Some("VARBINARY(MAX)"));
+//! assert_eq!(BinaryType::parse("VARCHAR(10)"), None);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Base types holding raw bytes: (name, large object, implied maximum length)
+const BINARY_TYPES: &[(&str, bool, Option<u64>)] = &[
+    ("binary", false, None),
+    ("varbinary", false, None),
+    ("blob", true, Some(65_535)),
+    ("bytea", true, None),
+    ("bytes", true, None),
+    ("image", true, None),
+    ("tinyblob", true, Some(255)),
+    ("mediumblob", true, Some(16_777_215)),
+    ("longblob", true, None),
+];
+
+/// MySQL large object types by maximum length
+const MYSQL_BLOBS: &[(u64, &str)] = &[
+    (255, "TINYBLOB"),
+    (65_535, "BLOB"),
+    (16_777_215, "MEDIUMBLOB"),
+];
+
+/// A binary data type with an optional maximum length in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinaryType {
+    /// Maximum length in bytes; `None` is unbounded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u64>,
+    /// Whether values are stored out of row as a large object (`BLOB`)
+    #[serde(default)]
+    pub large_object: bool,
+}
+
+impl Default for BinaryType {
+    /// An unbounded large object
+    fn default() -> Self {
+        Self {
+            max_length: None,
+            large_object: true,
+        }
+    }
+}
+
+impl BinaryType {
+    /// Bounded binary value of at most `max_length` bytes (`VARBINARY(n)`)
+    pub fn bounded(max_length: u64) -> Self {
+        Self {
+            max_length: Some(max_length),
+            large_object: false,
+        }
+    }
+
+    /// Parse a binary data type, returning `None` for non-binary types
+    pub fn parse(data_type: &str) -> Option<Self> {
+        let data_type = data_type.trim();
+        let (base, params) = match data_type.split_once('(') {
+            Some((base, rest)) => (base.trim(), rest.trim_end().trim_end_matches(')')),
+            None => (data_type, ""),
+        };
+        let (_, large_object, implied_length) = BINARY_TYPES
+            .iter()
+            .find(|(name, _, _)| name.eq_ignore_ascii_case(base))?;
+        // `VARBINARY(MAX)` and friends are unbounded
+        let max_length = params.trim().parse().ok().or(*implied_length);
+        Some(Self {
+            max_length,
+            large_object: *large_object || max_length.is_none(),
+        })
+    }
+
+    /// Native type for a SQL dialect, or `None` for dialects without a mapping
+    pub fn to_sql(&self, dialect: &str) -> Option<String> {
+        let native_type = match (dialect.to_lowercase().as_str(), self.max_length) {
+            ("postgres" | "postgresql", _) => "BYTEA".to_string(),
+            ("mysql", Some(length)) if !self.large_object => format!("VARBINARY({})", length),
+            ("mysql", length) => MYSQL_BLOBS
+                .iter()
+                .find(|(max, _)| length.is_some_and(|length| length <= *max))
+                .map(|(_, name)| name.to_string())
+                .unwrap_or_else(|| "LONGBLOB".to_string()),
+            ("sqlserver" | "mssql", Some(length)) if !self.large_object && length <= 8000 => {
+                format!("VARBINARY({})", length)
+            }
+            ("sqlserver" | "mssql", _) => "VARBINARY(MAX)".to_string(),
+            ("bigquery", Some(length)) if !self.large_object => format!("BYTES({})", length),
+            ("bigquery", _) => "BYTES".to_string(),
+            ("snowflake", Some(length)) if !self.large_object => format!("BINARY({})", length),
+            ("snowflake", _) => "BINARY".to_string(),
+            ("databricks" | "spark", _) => "BINARY".to_string(),
+            ("oracle", Some(length)) if !self.large_object && length <= 2000 => {
+                format!("RAW({})", length)
+            }
+            ("duckdb" | "sqlite" | "oracle", _) => "BLOB".to_string(),
+            _ => return None,
+        };
+        Some(native_type)
+    }
+
+    /// JSON Schema of a base64-encoded string holding values of this type
+    pub fn to_json_schema(&self) -> Value {
+        json!({"type": "string", "contentEncoding": "base64"})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_binary_types() {
+        assert_eq!(BinaryType::parse("bytea"), Some(BinaryType::default()));
+        assert_eq!(
+            BinaryType::parse("VARBINARY(255)"),
+            Some(BinaryType::bounded(255))
+        );
+        assert_eq!(
+            BinaryType::parse("varbinary(max)"),
+            Some(BinaryType::default())
+        );
+        assert_eq!(BinaryType::parse("BLOB").unwrap().max_length, Some(65_535));
+        assert_eq!(BinaryType::parse("TEXT"), None);
+    }
+
+    #[test]
+    fn test_dialect_types() {
+        let bounded = BinaryType::bounded(16);
+        assert_eq!(bounded.to_sql("mysql").as_deref(), Some("VARBINARY(16)"));
+        assert_eq!(bounded.to_sql("bigquery").as_deref(), Some("BYTES(16)"));
+        assert_eq!(bounded.to_sql("postgres").as_deref(), Some("BYTEA"));
+
+        let blob = BinaryType::parse("MEDIUMBLOB").unwrap();
+        assert_eq!(blob.to_sql("mysql").as_deref(), Some("MEDIUMBLOB"));
+        assert_eq!(blob.to_sql("sqlserver").as_deref(), Some("VARBINARY(MAX)"));
+        assert_eq!(blob.to_sql("snowflake").as_deref(), Some("BINARY"));
+
+        let unbounded = BinaryType::default();
+        assert_eq!(unbounded.to_sql("mysql").as_deref(), Some("LONGBLOB"));
+        assert_eq!(unbounded.to_sql("duckdb").as_deref(), Some("BLOB"));
+        assert_eq!(unbounded.to_sql("standard"), None);
+    }
+}
@@ -0,0 +1,404 @@
+//! Change data capture (CDC) metadata of tables
+//!
+//! Streaming integrations need to know how changes of a table are captured, which
+//! columns identify a row, which column flags soft-deleted rows and which column
+//! orders the changes. The metadata is kept in the contract as the `cdc` custom
+//! property of a schema object, so it travels with the contract:
+//!
+//! ```yaml
+//! schema:
+//!   - name: orders
+//!     customProperties:
+//!       - property: cdc
+//!         value:
+//!           captureMethod: log-based
+//!           keyColumns: [order_id]
+//!           softDeleteColumn: is_deleted
+//!           sequenceColumn: updated_at
+//! ```
+//!
+//! Key columns default to the primary key. [`CdcConfig::validate`] checks that the
+//! referenced columns exist and fit their role; the Debezium exporter
+//! ([`DebeziumExporter`](crate::export::DebeziumExporter)) turns the metadata into
+//! connector configuration templates.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::cdc::{CaptureMethod, CdcConfig};
+//! use data_modelling_core::models::{Column, Table};
+//!
+//! let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+//! id.primary_key = true;
+//! let mut table = Table::new("orders".to_string(), vec![id]);
+//!
+//! CdcConfig::new(CaptureMethod::LogBased).set_on_table(&mut table);
+//! let cdc = CdcConfig::from_table(&table).unwrap().unwrap();
+//! assert_eq!(cdc.key_columns(&table), vec!["id"]);
+//! assert!(cdc.validate(&table).is_empty());
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::Table;
+use super::odcs::{CustomProperty, SchemaObject};
+
+/// Custom property holding the CDC metadata of a schema object
+pub const CDC_PROPERTY: &str = "cdc";
+
+/// How changes of a table are captured
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CaptureMethod {
+    /// Read from the database transaction log (e.g. Debezium)
+    #[default]
+    LogBased,
+    /// Written to a change table by database triggers
+    Trigger,
+    /// Polled by comparing a last-modified timestamp (the sequence column)
+    Timestamp,
+    /// Periodic full snapshots
+    Snapshot,
+}
+
+impl std::fmt::Display for CaptureMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CaptureMethod::LogBased => "log-based",
+            CaptureMethod::Trigger => "trigger",
+            CaptureMethod::Timestamp => "timestamp",
+            CaptureMethod::Snapshot => "snapshot",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// CDC metadata of a table
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CdcConfig {
+    /// How changes are captured
+    #[serde(default)]
+    pub capture_method: CaptureMethod,
+    /// Columns identifying a row in the change stream (defaults to the primary key)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_columns: Vec<String>,
+    /// Boolean column flagging soft-deleted rows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_delete_column: Option<String>,
+    /// Column ordering the changes of a row (e.g. a version or last-modified timestamp)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_column: Option<String>,
+}
+
+/// Inconsistency between the CDC metadata and the columns of a table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CdcViolation {
+    pub table_name: String,
+    /// Column the problem is about, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for CdcViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.column {
+            Some(column) => write!(f, "{}.{}: {}", self.table_name, column, self.message),
+            None => write!(f, "{}: {}", self.table_name, self.message),
+        }
+    }
+}
+
+impl CdcConfig {
+    /// Create CDC metadata with the given capture method
+    pub fn new(capture_method: CaptureMethod) -> Self {
+        Self {
+            capture_method,
+            ..Default::default()
+        }
+    }
+
+    /// Set the key columns
+    pub fn with_key_columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.key_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the soft-delete flag column
+    pub fn with_soft_delete_column(mut self, column: impl Into<String>) -> Self {
+        self.soft_delete_column = Some(column.into());
+        self
+    }
+
+    /// Set the sequence column
+    pub fn with_sequence_column(mut self, column: impl Into<String>) -> Self {
+        self.sequence_column = Some(column.into());
+        self
+    }
+
+    /// Read the CDC metadata of a schema object, if any
+    pub fn from_schema(schema: &SchemaObject) -> Result<Option<Self>, serde_json::Error> {
+        schema
+            .custom_properties
+            .iter()
+            .find(|p| p.property == CDC_PROPERTY)
+            .map(|p| serde_json::from_value(p.value.clone()))
+            .transpose()
+    }
+
+    /// Store the CDC metadata on a schema object, replacing any existing metadata
+    pub fn set_on_schema(&self, schema: &mut SchemaObject) {
+        schema
+            .custom_properties
+            .retain(|p| p.property != CDC_PROPERTY);
+        schema
+            .custom_properties
+            .push(CustomProperty::new(CDC_PROPERTY, self.to_value()));
+    }
+
+    /// Read the CDC metadata of a table, if any
+    ///
+    /// Tables keep the custom properties of their schema object in
+    /// `odcl_metadata.customProperties`, or `schemaCustomProperties` when
+    /// imported with the ODCS importer.
+    pub fn from_table(table: &Table) -> Result<Option<Self>, serde_json::Error> {
+        ["customProperties", "schemaCustomProperties"]
+            .iter()
+            .filter_map(|key| table.odcl_metadata.get(*key).and_then(Value::as_array))
+            .flatten()
+            .find(|p| p.get("property").and_then(Value::as_str) == Some(CDC_PROPERTY))
+            .map(|p| serde_json::from_value(p.get("value").cloned().unwrap_or_default()))
+            .transpose()
+    }
+
+    /// Store the CDC metadata on a table, replacing any existing metadata
+    pub fn set_on_table(&self, table: &mut Table) {
+        let entry = serde_json::json!({"property": CDC_PROPERTY, "value": self.to_value()});
+        let properties = table
+            .odcl_metadata
+            .entry("customProperties".to_string())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        match properties.as_array_mut() {
+            Some(properties) => {
+                properties
+                    .retain(|p| p.get("property").and_then(Value::as_str) != Some(CDC_PROPERTY));
+                properties.push(entry);
+            }
+            None => *properties = Value::Array(vec![entry]),
+        }
+    }
+
+    /// Key columns, falling back to the primary key columns of the table
+    pub fn key_columns<'a>(&'a self, table: &'a Table) -> Vec<&'a str> {
+        if !self.key_columns.is_empty() {
+            return self.key_columns.iter().map(String::as_str).collect();
+        }
+        let mut primary_key: Vec<_> = table.columns.iter().filter(|c| c.primary_key).collect();
+        primary_key.sort_by_key(|c| c.primary_key_position.unwrap_or(i32::MAX));
+        primary_key.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    /// Check the metadata against the columns of the table
+    ///
+    /// Every referenced column must exist; rows must have a key; key columns must
+    /// not be nullable; the soft-delete column must be a boolean that is not part of
+    /// the key; the sequence column must be numeric or temporal, and is required
+    /// for timestamp-based capture.
+    pub fn validate(&self, table: &Table) -> Vec<CdcViolation> {
+        let violation = |column: Option<&str>, message: String| CdcViolation {
+            table_name: table.name.clone(),
+            column: column.map(str::to_string),
+            message,
+        };
+        let column = |name: &str| table.columns.iter().find(|c| c.name == name);
+        let mut violations = Vec::new();
+
+        let keys = self.key_columns(table);
+        if keys.is_empty() {
+            violations.push(violation(
+                None,
+                "no key columns and no primary key to identify rows".to_string(),
+            ));
+        }
+        for key in &keys {
+            match column(key) {
+                None => violations.push(violation(Some(key), "key column does not exist".into())),
+                Some(c) if c.nullable && !c.primary_key => violations.push(violation(
+                    Some(key),
+                    "key column must not be nullable".into(),
+                )),
+                Some(_) => {}
+            }
+        }
+
+        if let Some(name) = &self.soft_delete_column {
+            match column(name) {
+                None => violations.push(violation(
+                    Some(name),
+                    "soft-delete column does not exist".into(),
+                )),
+                Some(c) if !is_boolean(&c.data_type) => violations.push(violation(
+                    Some(name),
+                    format!("soft-delete column must be boolean, not {}", c.data_type),
+                )),
+                Some(_) => {}
+            }
+            if keys.contains(&name.as_str()) {
+                violations.push(violation(
+                    Some(name),
+                    "soft-delete column cannot be a key column".into(),
+                ));
+            }
+        }
+
+        match &self.sequence_column {
+            Some(name) => match column(name) {
+                None => violations.push(violation(
+                    Some(name),
+                    "sequence column does not exist".into(),
+                )),
+                Some(c) if !is_orderable(&c.data_type) => violations.push(violation(
+                    Some(name),
+                    format!(
+                        "sequence column must be numeric or a timestamp, not {}",
+                        c.data_type
+                    ),
+                )),
+                Some(_) => {}
+            },
+            None if self.capture_method == CaptureMethod::Timestamp => violations.push(violation(
+                None,
+                "timestamp capture requires a sequence column".into(),
+            )),
+            None => {}
+        }
+
+        violations
+    }
+
+    fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// Check the CDC metadata of every table that has any
+///
+/// Malformed metadata is reported as a violation of its table.
+pub fn validate_cdc(tables: &[Table]) -> Vec<CdcViolation> {
+    tables
+        .iter()
+        .flat_map(|table| match CdcConfig::from_table(table) {
+            Ok(Some(cdc)) => cdc.validate(table),
+            Ok(None) => Vec::new(),
+            Err(e) => vec![CdcViolation {
+                table_name: table.name.clone(),
+                column: None,
+                message: format!("invalid cdc metadata: {}", e),
+            }],
+        })
+        .collect()
+}
+
+fn base_type(data_type: &str) -> String {
+    let lower = data_type.trim().to_lowercase();
+    lower
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn is_boolean(data_type: &str) -> bool {
+    matches!(base_type(data_type).as_str(), "boolean" | "bool" | "bit")
+}
+
+fn is_orderable(data_type: &str) -> bool {
+    let base = base_type(data_type);
+    base.contains("int")
+        || base.starts_with("timestamp")
+        || base.starts_with("datetime")
+        || matches!(
+            base.as_str(),
+            "number" | "numeric" | "decimal" | "long" | "date" | "time" | "rowversion"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+
+    fn orders() -> Table {
+        let mut id = Column::new("id".to_string(), "BIGINT".to_string());
+        id.primary_key = true;
+        id.nullable = false;
+        let deleted = Column::new("is_deleted".to_string(), "BOOLEAN".to_string());
+        let updated = Column::new("updated_at".to_string(), "TIMESTAMP".to_string());
+        let status = Column::new("status".to_string(), "VARCHAR(20)".to_string());
+        Table::new("orders".to_string(), vec![id, deleted, updated, status])
+    }
+
+    #[test]
+    fn test_round_trip_through_table_and_schema() {
+        let cdc = CdcConfig::new(CaptureMethod::Timestamp)
+            .with_soft_delete_column("is_deleted")
+            .with_sequence_column("updated_at");
+        let mut table = orders();
+        cdc.set_on_table(&mut table);
+        cdc.set_on_table(&mut table);
+        assert_eq!(CdcConfig::from_table(&table).unwrap(), Some(cdc.clone()));
+        assert_eq!(
+            table.odcl_metadata["customProperties"][0]["value"]["captureMethod"],
+            "timestamp"
+        );
+
+        let contract = crate::models::odcs::ODCSContract::from_table(&table);
+        let schema = &contract.schema[0];
+        assert_eq!(schema.custom_properties.len(), 1);
+        assert_eq!(CdcConfig::from_schema(schema).unwrap(), Some(cdc.clone()));
+
+        let mut schema = schema.clone();
+        cdc.set_on_schema(&mut schema);
+        assert_eq!(schema.custom_properties.len(), 1);
+    }
+
+    #[test]
+    fn test_validate() {
+        let table = orders();
+        let valid = CdcConfig::new(CaptureMethod::LogBased)
+            .with_soft_delete_column("is_deleted")
+            .with_sequence_column("updated_at");
+        assert!(valid.validate(&table).is_empty());
+
+        let invalid = CdcConfig::new(CaptureMethod::Timestamp)
+            .with_key_columns(["id", "missing"])
+            .with_soft_delete_column("status");
+        let messages: Vec<String> = invalid
+            .validate(&table)
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "orders.missing: key column does not exist",
+                "orders.status: soft-delete column must be boolean, not VARCHAR(20)",
+                "orders: timestamp capture requires a sequence column",
+            ]
+        );
+
+        let mut keyless = orders();
+        keyless.columns[0].primary_key = false;
+        CdcConfig::default().set_on_table(&mut keyless);
+        let violations = validate_cdc(&[keyless]);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("no key columns"));
+    }
+}
@@ -0,0 +1,773 @@
+//! Bulk classification rules
+//!
+//! Rules match columns by name, data type and/or description and assign a
+//! classification and tags, so the initial classification of a large imported
+//! model does not have to be done column by column. Rules are kept in
+//! `workspace.yaml`:
+//!
+//! ```yaml
+//! classificationRules:
+//!   - name: email
+//!     column: "e_?mail"
+//!     classification: pii
+//!     tags: ["pii:contact"]
+//!   - name: free-text notes
+//!     dataType: "^(string|text|varchar)"
+//!     description: "comment|note"
+//!     tags: ["review"]
+//! ```
+//!
+//! Patterns are case-insensitive regular expressions (unanchored, so use `^`/`$`
+//! for whole-name matches); every pattern a rule specifies must match. Column
+//! patterns are tried against a nested column's dotted path and its own name,
+//! data type patterns against both the logical and the physical type.
+//!
+//! Rules are evaluated in order: the first matching rule with a classification
+//! sets it and the tags of all matching rules are added. An existing, different
+//! classification is only replaced by a rule with `overwrite: true`; otherwise
+//! it is kept and reported as a conflict.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::{Column, Table};
+//! use data_modelling_core::models::classification_rules::{
+//!     ClassificationEngine, ClassificationRule,
+//! };
+//!
+//! let mut tables = vec![Table::new(
+//!     "customers".to_string(),
+//!     vec![
+//!         Column::new("email".to_string(), "STRING".to_string()),
+//!         Column::new("id".to_string(), "BIGINT".to_string()),
+//!     ],
+//! )];
+//! let engine = ClassificationEngine::new(&[ClassificationRule::new("email")
+//!     .with_column("^e_?mail$")
+//!     .with_classification("pii")])
+//! .unwrap();
+//!
+//! let report = engine.plan_tables(&tables);
+//! assert_eq!(report.changes.len(), 1);
+//! assert!(tables[0].columns[0].classification.is_none());
+//!
+//! engine.apply_tables(&mut tables);
+//! assert_eq!(tables[0].columns[0].classification.as_deref(), Some("pii"));
+//! ```
+
+use super::Table;
+use super::odcs::{ODCSContract, Property};
+use super::tag_propagation::ColumnRef;
+use super::workspace::Workspace;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// A rule assigning a classification and/or tags to matching columns
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassificationRule {
+    /// Rule name, reported with each change
+    pub name: String,
+    /// Pattern on the column name (or, for nested columns, the dotted path)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    /// Pattern on the logical or physical data type
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "type")]
+    pub data_type: Option<String>,
+    /// Pattern on the column description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Classification to assign (e.g. `pii`, `confidential`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    /// Tags to add
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Replace an existing, different classification
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub overwrite: bool,
+}
+
+impl ClassificationRule {
+    /// Create a rule that matches nothing until a pattern is added
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Match column names against a pattern
+    pub fn with_column(mut self, pattern: impl Into<String>) -> Self {
+        self.column = Some(pattern.into());
+        self
+    }
+
+    /// Match data types against a pattern
+    pub fn with_data_type(mut self, pattern: impl Into<String>) -> Self {
+        self.data_type = Some(pattern.into());
+        self
+    }
+
+    /// Match column descriptions against a pattern
+    pub fn with_description(mut self, pattern: impl Into<String>) -> Self {
+        self.description = Some(pattern.into());
+        self
+    }
+
+    /// Assign a classification to matching columns
+    pub fn with_classification(mut self, classification: impl Into<String>) -> Self {
+        self.classification = Some(classification.into());
+        self
+    }
+
+    /// Add a tag to matching columns
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Replace existing classifications
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Starter rules for common kinds of personal data
+    pub fn pii_defaults() -> Vec<Self> {
+        let pii = |name: &str, column: &str, tag: &str| {
+            ClassificationRule::new(name)
+                .with_column(column)
+                .with_classification("pii")
+                .with_tag(tag)
+        };
+        vec![
+            pii("email", r"(^|_)e_?mail(_|$)", "pii:contact"),
+            pii(
+                "phone",
+                r"(^|_)(phone|mobile|fax)(_?(number|no))?(_|$)",
+                "pii:contact",
+            ),
+            pii(
+                "national id",
+                r"(^|_)(ssn|social_security|national_id|passport|tax_id)",
+                "pii:identifier",
+            ),
+            pii(
+                "person name",
+                r"(^|_)(first|last|middle|full|given|family|sur)_?name$",
+                "pii:name",
+            ),
+            pii(
+                "postal address",
+                r"(^|_)(street|address(_line)?_?\d?|postal_?code|zip_?code)$",
+                "pii:address",
+            ),
+            pii(
+                "birth date",
+                r"(^|_)(birth_?date|date_of_birth|dob)$",
+                "pii:birthdate",
+            ),
+            pii("ip address", r"(^|_)ip(_?address)?$", "pii:online"),
+            ClassificationRule::new("payment card")
+                .with_column(r"(^|_)(card_?number|pan|iban|account_?number)$")
+                .with_classification("restricted")
+                .with_tag("pii:financial"),
+        ]
+    }
+}
+
+/// Errors in classification rules
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ClassificationRuleError {
+    #[error("Rule '{0}' has no column, dataType or description pattern")]
+    NoPattern(String),
+    #[error("Rule '{0}' assigns neither a classification nor tags")]
+    NoAction(String),
+    #[error("Invalid {field} pattern in rule '{rule}': {message}")]
+    InvalidPattern {
+        rule: String,
+        field: &'static str,
+        message: String,
+    },
+}
+
+/// A column whose classification or tags change
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassificationChange {
+    /// Changed column
+    pub column: ColumnRef,
+    /// Rules that matched the column
+    pub rules: Vec<String>,
+    /// Classification before the change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_classification: Option<String>,
+    /// Newly assigned classification, if it changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    /// Tags added to the column
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added_tags: Vec<String>,
+}
+
+/// A column whose existing classification differs from the one a rule proposes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassificationConflict {
+    pub column: ColumnRef,
+    /// Rule proposing the classification
+    pub rule: String,
+    /// Classification kept on the column
+    pub existing: String,
+    /// Classification the rule would assign
+    pub proposed: String,
+}
+
+/// Result of running classification rules
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassificationReport {
+    /// Whether changes were only planned, not applied
+    pub dry_run: bool,
+    /// Number of columns evaluated
+    pub columns_scanned: usize,
+    /// Columns that were (or would be) changed
+    pub changes: Vec<ClassificationChange>,
+    /// Existing classifications kept despite a matching rule
+    pub conflicts: Vec<ClassificationConflict>,
+}
+
+impl ClassificationReport {
+    /// Whether no column changes and no conflicts were found
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.conflicts.is_empty()
+    }
+
+    /// Add the results of another run (e.g. another contract)
+    pub fn merge(&mut self, other: ClassificationReport) {
+        self.columns_scanned += other.columns_scanned;
+        self.changes.extend(other.changes);
+        self.conflicts.extend(other.conflicts);
+    }
+
+    /// Number of changed columns per rule
+    pub fn rule_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for change in &self.changes {
+            for rule in &change.rules {
+                *counts.entry(rule.clone()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Render the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Classification Report\n\n");
+        out.push_str(&format!(
+            "{} of {} column(s) {}, {} conflict(s).\n",
+            self.changes.len(),
+            self.columns_scanned,
+            if self.dry_run {
+                "would change"
+            } else {
+                "changed"
+            },
+            self.conflicts.len()
+        ));
+
+        if !self.changes.is_empty() {
+            out.push_str("\n## Changes\n\n| Column | Classification | Added tags | Rules |\n|---|---|---|---|\n");
+            for change in &self.changes {
+                let classification = match (&change.previous_classification, &change.classification)
+                {
+                    (Some(previous), Some(new)) => format!("{} → {}", previous, new),
+                    (None, Some(new)) => new.clone(),
+                    (Some(previous), None) => previous.clone(),
+                    (None, None) => String::new(),
+                };
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    change.column,
+                    classification,
+                    change.added_tags.join(", "),
+                    change.rules.join(", ")
+                ));
+            }
+        }
+
+        if !self.conflicts.is_empty() {
+            out.push_str(
+                "\n## Conflicts\n\n| Column | Existing | Proposed | Rule |\n|---|---|---|---|\n",
+            );
+            for conflict in &self.conflicts {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    conflict.column, conflict.existing, conflict.proposed, conflict.rule
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Compiled classification rules, runnable over tables and contracts
+#[derive(Debug, Clone)]
+pub struct ClassificationEngine {
+    rules: Vec<CompiledRule>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    rule: ClassificationRule,
+    column: Option<Regex>,
+    data_type: Option<Regex>,
+    description: Option<Regex>,
+}
+
+/// The attributes of a column that rules are matched against
+struct ColumnFacts<'a> {
+    name: &'a str,
+    types: Vec<&'a str>,
+    description: Option<&'a str>,
+    classification: Option<&'a str>,
+    tags: &'a [String],
+}
+
+/// What rules decided for one column
+struct Outcome {
+    rules: Vec<String>,
+    classification: Option<String>,
+    added_tags: Vec<String>,
+    /// (rule, existing, proposed)
+    conflict: Option<(String, String, String)>,
+}
+
+impl ClassificationEngine {
+    /// Compile rules, rejecting rules without patterns or actions
+    pub fn new(rules: &[ClassificationRule]) -> Result<Self, ClassificationRuleError> {
+        let rules = rules
+            .iter()
+            .map(CompiledRule::compile)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Compile the `classificationRules` of a workspace
+    pub fn from_workspace(workspace: &Workspace) -> Result<Self, ClassificationRuleError> {
+        Self::new(&workspace.classification_rules)
+    }
+
+    /// Number of rules
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Whether there are no rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Report the changes rules would make to tables, without changing them
+    pub fn plan_tables(&self, tables: &[Table]) -> ClassificationReport {
+        let mut tables = tables.to_vec();
+        let mut report = self.apply_tables(&mut tables);
+        report.dry_run = true;
+        report
+    }
+
+    /// Apply rules to the columns of tables
+    pub fn apply_tables(&self, tables: &mut [Table]) -> ClassificationReport {
+        let mut report = ClassificationReport::default();
+        for table in tables {
+            for column in &mut table.columns {
+                report.columns_scanned += 1;
+                let facts = ColumnFacts {
+                    name: &column.name,
+                    types: vec![column.data_type.as_str()],
+                    description: Some(column.description.as_str()).filter(|d| !d.is_empty()),
+                    classification: column.classification.as_deref(),
+                    tags: &column.tags,
+                };
+                let Some(outcome) = self.evaluate(&facts) else {
+                    continue;
+                };
+                let column_ref = ColumnRef::new(&table.name, &column.name);
+                record(
+                    &mut report,
+                    column_ref,
+                    outcome,
+                    &mut column.classification,
+                    &mut column.tags,
+                );
+            }
+        }
+        report
+    }
+
+    /// Report the changes rules would make to a contract, without changing it
+    pub fn plan_contract(&self, contract: &ODCSContract) -> ClassificationReport {
+        let mut contract = contract.clone();
+        let mut report = self.apply_contract(&mut contract);
+        report.dry_run = true;
+        report
+    }
+
+    /// Apply rules to the properties of a contract, including nested properties
+    pub fn apply_contract(&self, contract: &mut ODCSContract) -> ClassificationReport {
+        let mut report = ClassificationReport::default();
+        for schema in &mut contract.schema {
+            self.apply_properties(&schema.name, "", &mut schema.properties, &mut report);
+        }
+        report
+    }
+
+    fn apply_properties(
+        &self,
+        table: &str,
+        prefix: &str,
+        properties: &mut [Property],
+        report: &mut ClassificationReport,
+    ) {
+        for property in properties {
+            let path = if prefix.is_empty() {
+                property.name.clone()
+            } else {
+                format!("{}.{}", prefix, property.name)
+            };
+            report.columns_scanned += 1;
+            let mut types = vec![property.logical_type.as_str()];
+            types.extend(property.physical_type.as_deref());
+            let facts = ColumnFacts {
+                name: &path,
+                types,
+                description: property.description.as_deref(),
+                classification: property.classification.as_deref(),
+                tags: &property.tags,
+            };
+            if let Some(outcome) = self.evaluate(&facts) {
+                record(
+                    report,
+                    ColumnRef::new(table, &path),
+                    outcome,
+                    &mut property.classification,
+                    &mut property.tags,
+                );
+            }
+
+            self.apply_properties(table, &path, &mut property.properties, report);
+            if let Some(items) = property.items.as_deref_mut() {
+                let items_path = format!("{}.[]", path);
+                self.apply_properties(table, &items_path, &mut items.properties, report);
+            }
+        }
+    }
+
+    fn evaluate(&self, facts: &ColumnFacts<'_>) -> Option<Outcome> {
+        let matched: Vec<&ClassificationRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.matches(facts))
+            .map(|rule| &rule.rule)
+            .collect();
+        if matched.is_empty() {
+            return None;
+        }
+
+        let mut classification = None;
+        let mut conflict = None;
+        if let Some(rule) = matched.iter().find(|rule| rule.classification.is_some()) {
+            let proposed = rule.classification.clone().unwrap_or_default();
+            match facts.classification {
+                None => classification = Some(proposed),
+                Some(existing) if existing.eq_ignore_ascii_case(&proposed) => {}
+                Some(_) if rule.overwrite => classification = Some(proposed),
+                Some(existing) => {
+                    conflict = Some((rule.name.clone(), existing.to_string(), proposed))
+                }
+            }
+        }
+
+        let mut added_tags: Vec<String> = Vec::new();
+        for tag in matched.iter().flat_map(|rule| &rule.tags) {
+            let present = facts
+                .tags
+                .iter()
+                .chain(&added_tags)
+                .any(|t| t.eq_ignore_ascii_case(tag));
+            if !present {
+                added_tags.push(tag.clone());
+            }
+        }
+
+        Some(Outcome {
+            rules: matched.iter().map(|rule| rule.name.clone()).collect(),
+            classification,
+            added_tags,
+            conflict,
+        })
+    }
+}
+
+impl CompiledRule {
+    fn compile(rule: &ClassificationRule) -> Result<Self, ClassificationRuleError> {
+        if rule.column.is_none() && rule.data_type.is_none() && rule.description.is_none() {
+            return Err(ClassificationRuleError::NoPattern(rule.name.clone()));
+        }
+        if rule.classification.is_none() && rule.tags.is_empty() {
+            return Err(ClassificationRuleError::NoAction(rule.name.clone()));
+        }
+        let compile = |pattern: &Option<String>, field: &'static str| {
+            pattern
+                .as_deref()
+                .map(|p| {
+                    RegexBuilder::new(p)
+                        .case_insensitive(true)
+                        .build()
+                        .map_err(|e| ClassificationRuleError::InvalidPattern {
+                            rule: rule.name.clone(),
+                            field,
+                            message: e.to_string(),
+                        })
+                })
+                .transpose()
+        };
+        Ok(Self {
+            column: compile(&rule.column, "column")?,
+            data_type: compile(&rule.data_type, "dataType")?,
+            description: compile(&rule.description, "description")?,
+            rule: rule.clone(),
+        })
+    }
+
+    fn matches(&self, facts: &ColumnFacts<'_>) -> bool {
+        let leaf = facts.name.rsplit('.').next().unwrap_or(facts.name);
+        self.column
+            .as_ref()
+            .is_none_or(|re| re.is_match(facts.name) || re.is_match(leaf))
+            && self
+                .data_type
+                .as_ref()
+                .is_none_or(|re| facts.types.iter().any(|t| re.is_match(t)))
+            && self
+                .description
+                .as_ref()
+                .is_none_or(|re| facts.description.is_some_and(|d| re.is_match(d)))
+    }
+}
+
+/// Apply an outcome to a column's classification and tags and record it in the report
+fn record(
+    report: &mut ClassificationReport,
+    column: ColumnRef,
+    outcome: Outcome,
+    classification: &mut Option<String>,
+    tags: &mut Vec<String>,
+) {
+    if let Some((rule, existing, proposed)) = outcome.conflict {
+        report.conflicts.push(ClassificationConflict {
+            column: column.clone(),
+            rule,
+            existing,
+            proposed,
+        });
+    }
+    if outcome.classification.is_none() && outcome.added_tags.is_empty() {
+        return;
+    }
+    let previous_classification = classification.clone();
+    if let Some(new) = &outcome.classification {
+        *classification = Some(new.clone());
+    }
+    tags.extend(outcome.added_tags.iter().cloned());
+    report.changes.push(ClassificationChange {
+        column,
+        rules: outcome.rules,
+        previous_classification,
+        classification: outcome.classification,
+        added_tags: outcome.added_tags,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+    use crate::models::odcs::SchemaObject;
+
+    fn column(name: &str, data_type: &str, description: &str) -> Column {
+        let mut column = Column::new(name.to_string(), data_type.to_string());
+        column.description = description.to_string();
+        column
+    }
+
+    #[test]
+    fn test_rules_require_pattern_and_action() {
+        assert_eq!(
+            ClassificationEngine::new(&[ClassificationRule::new("empty").with_tag("x")])
+                .unwrap_err(),
+            ClassificationRuleError::NoPattern("empty".to_string())
+        );
+        assert!(matches!(
+            ClassificationEngine::new(&[ClassificationRule::new("noop").with_column("id")]),
+            Err(ClassificationRuleError::NoAction(_))
+        ));
+        assert!(matches!(
+            ClassificationEngine::new(&[ClassificationRule::new("bad")
+                .with_column("(")
+                .with_tag("x")]),
+            Err(ClassificationRuleError::InvalidPattern {
+                field: "column",
+                ..
+            })
+        ));
+        assert_eq!(
+            ClassificationEngine::new(&ClassificationRule::pii_defaults())
+                .unwrap()
+                .len(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_dry_run_and_apply_tables() {
+        let mut rules = ClassificationRule::pii_defaults();
+        rules.push(
+            ClassificationRule::new("free text")
+                .with_data_type("^(string|varchar)")
+                .with_description("comment|note")
+                .with_tag("review"),
+        );
+        rules.push(
+            ClassificationRule::new("secret")
+                .with_column("token")
+                .with_classification("restricted")
+                .with_overwrite(true),
+        );
+        let engine = ClassificationEngine::new(&rules).unwrap();
+
+        let mut email = column("Customer_Email", "STRING", "");
+        email.tags.push("PII:contact".to_string());
+        let mut token = column("api_token", "STRING", "");
+        token.classification = Some("internal".to_string());
+        let mut phone = column("phone_number", "STRING", "");
+        phone.classification = Some("confidential".to_string());
+        let mut tables = vec![Table::new(
+            "customers".to_string(),
+            vec![
+                email,
+                column("notes", "VARCHAR(2000)", "Free-text support comment"),
+                column("notes_count", "INT", "Number of comments"),
+                token,
+                phone,
+                column("id", "BIGINT", ""),
+            ],
+        )];
+
+        let plan = engine.plan_tables(&tables);
+        assert!(plan.dry_run);
+        assert_eq!(plan.columns_scanned, 6);
+        assert!(tables[0].columns[0].classification.is_none());
+
+        let report = engine.apply_tables(&mut tables);
+        assert!(!report.dry_run);
+        assert_eq!(report.changes, plan.changes);
+        assert_eq!(report.changes.len(), 4);
+
+        let columns = &tables[0].columns;
+        assert_eq!(columns[0].classification.as_deref(), Some("pii"));
+        assert_eq!(columns[0].tags, vec!["PII:contact".to_string()]);
+        assert_eq!(columns[1].tags, vec!["review".to_string()]);
+        assert!(columns[2].tags.is_empty());
+        assert_eq!(columns[3].classification.as_deref(), Some("restricted"));
+        assert_eq!(
+            report.changes[2].previous_classification.as_deref(),
+            Some("internal")
+        );
+
+        // Existing classification kept without overwrite, but tags still added
+        assert_eq!(columns[4].classification.as_deref(), Some("confidential"));
+        assert_eq!(columns[4].tags, vec!["pii:contact".to_string()]);
+        assert_eq!(report.changes[3].classification, None);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].proposed, "pii");
+        assert_eq!(
+            report.conflicts[0].column.to_string(),
+            "customers.phone_number"
+        );
+
+        // Applying again is a no-op apart from the conflict
+        let again = engine.apply_tables(&mut tables);
+        assert!(again.changes.is_empty());
+        assert_eq!(again.conflicts.len(), 1);
+        assert_eq!(report.rule_counts().get("email"), Some(&1));
+    }
+
+    #[test]
+    fn test_apply_contract_nested_properties() {
+        let engine = ClassificationEngine::new(&ClassificationRule::pii_defaults()).unwrap();
+        let mut contract = ODCSContract::new("customers", "1.0.0").with_schema(
+            SchemaObject::new("customers").with_properties(vec![
+                Property::new("id", "integer"),
+                Property::new("contact", "object").with_nested_properties(vec![
+                    Property::new("email", "string"),
+                    Property::new("street", "string"),
+                ]),
+            ]),
+        );
+
+        let plan = engine.plan_contract(&contract);
+        assert_eq!(plan.columns_scanned, 4);
+        assert_eq!(plan.changes.len(), 2);
+        assert!(
+            contract.schema[0].properties[1].properties[0]
+                .tags
+                .is_empty()
+        );
+
+        engine.apply_contract(&mut contract);
+        let email = &contract.schema[0].properties[1].properties[0];
+        assert_eq!(email.classification.as_deref(), Some("pii"));
+        assert_eq!(email.tags, vec!["pii:contact".to_string()]);
+
+        let markdown = plan.to_markdown();
+        assert!(markdown.contains("2 of 4 column(s) would change"));
+        assert!(markdown.contains("| customers.contact.email | pii | pii:contact | email |"));
+    }
+
+    #[test]
+    fn test_rules_from_workspace_yaml() {
+        let yaml = r#"
+id: 550e8400-e29b-41d4-a716-446655440000
+name: enterprise
+ownerId: 550e8400-e29b-41d4-a716-446655440001
+createdAt: 2024-01-01T00:00:00Z
+lastModifiedAt: 2024-01-01T00:00:00Z
+classificationRules:
+  - name: email
+    column: "e_?mail"
+    classification: pii
+    tags: ["pii:contact"]
+  - name: amounts
+    type: "^decimal"
+    tags: [financial]
+"#;
+        let workspace = Workspace::from_yaml(yaml).unwrap();
+        assert_eq!(workspace.classification_rules.len(), 2);
+        assert_eq!(
+            workspace.classification_rules[1].data_type.as_deref(),
+            Some("^decimal")
+        );
+        let engine = ClassificationEngine::from_workspace(&workspace).unwrap();
+        assert_eq!(engine.len(), 2);
+
+        let round_trip = Workspace::from_yaml(&workspace.to_yaml().unwrap()).unwrap();
+        assert_eq!(
+            round_trip.classification_rules,
+            workspace.classification_rules
+        );
+    }
+}
@@ -0,0 +1,1349 @@
+//! Column annotations from application source code
+//!
+//! The ORM models of an application often describe its tables better than the
+//! contracts for the same tables do: columns carry comments, nullability,
+//! keys and lengths that never made it into the contract. [`scan_source`]
+//! extracts these models from source files:
+//!
+//! - **SQLAlchemy** - declarative classes with a `__tablename__`; `Column(...)`,
+//!   `mapped_column(...)` and bare `Mapped[...]` attributes with `comment=` or
+//!   `doc=`, `nullable=`, `primary_key=`, `unique=` and `String(n)` lengths
+//! - **Prisma** - `model` blocks of a Prisma schema; `///` doc comments, optional
+//!   (`?`) types, `@id`, `@@id`, `@unique`, `@map`, `@@map` and `@db.VarChar(n)`
+//! - **JPA** - `@Entity` classes with `@Table(name = ...)`; fields with Javadoc,
+//!   `@Id`, `@Column(name, nullable, unique, length)`, `@JoinColumn`, `@NotNull`,
+//!   `@Size(max)` and Hibernate's `@Comment`
+//!
+//! [`AnnotationPlanner`] matches models to schema objects and columns to
+//! properties by name (case-insensitive, so `customerId` matches
+//! `customer_id`) and proposes the descriptions, `required`, `primaryKey`,
+//! `unique` and `maxLength` the contract is missing. Existing values are never
+//! overwritten: where code and contract disagree, the contract value is kept
+//! and reported as a conflict. Columns and models without a counterpart in the
+//! contracts are reported as unmatched.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::code_annotations::{AnnotationPlanner, scan_source};
+//! use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+//!
+//! let models = scan_source(
+//!     "schema.prisma",
+//!     "model Customer {\n  /// Login e-mail address\n  email String @unique\n}\n",
+//! );
+//! let mut contracts = vec![ODCSContract::new("customers", "1.0.0").with_schema(
+//!     SchemaObject::new("customer").with_properties(vec![Property::new("email", "string")]),
+//! )];
+//!
+//! let report = AnnotationPlanner::new(models).apply_contracts(&mut contracts);
+//! assert_eq!(report.changes.len(), 3);
+//! let email = &contracts[0].schema[0].properties[0];
+//! assert_eq!(email.description.as_deref(), Some("Login e-mail address"));
+//! assert!(email.required && email.unique);
+//! ```
+
+use super::odcs::{LogicalTypeOptions, ODCSContract, Property};
+use super::tag_propagation::ColumnRef;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// ORM framework a model was declared with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrmFramework {
+    SqlAlchemy,
+    Prisma,
+    Jpa,
+}
+
+impl OrmFramework {
+    /// Detect the framework of a source file from its extension and content
+    pub fn detect(path: &str, content: &str) -> Option<Self> {
+        match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some("prisma") => Some(Self::Prisma),
+            Some("py") if content.contains("__tablename__") => Some(Self::SqlAlchemy),
+            Some("java") if content.contains("@Entity") => Some(Self::Jpa),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OrmFramework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SqlAlchemy => "SQLAlchemy",
+            Self::Prisma => "Prisma",
+            Self::Jpa => "JPA",
+        })
+    }
+}
+
+/// A model (mapped class) found in source code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeModel {
+    pub framework: OrmFramework,
+    /// Class or model name
+    pub name: String,
+    /// Table name; the model name if the code does not map it
+    pub table: String,
+    /// Source file
+    pub file: String,
+    /// Line of the class or model declaration (1-based)
+    pub line: usize,
+    pub columns: Vec<CodeColumn>,
+}
+
+impl CodeModel {
+    /// `file:line` of a line in the model's source file
+    pub fn source(&self, line: usize) -> String {
+        format!("{}:{}", self.file, line)
+    }
+}
+
+/// A column of a model, with what the code declares about it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeColumn {
+    /// Attribute or field name in code
+    pub field: String,
+    /// Column name in the database
+    pub name: String,
+    /// Line of the declaration (1-based)
+    pub line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Type as written in code (e.g. `String`, `Int`, `Long`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<String>,
+    /// Whether the column is nullable; `None` if the code does not say
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub primary_key: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unique: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<i64>,
+}
+
+/// Extract the ORM models declared in a source file
+///
+/// Files of unsupported languages, and files without models, yield nothing.
+pub fn scan_source(path: &str, content: &str) -> Vec<CodeModel> {
+    match OrmFramework::detect(path, content) {
+        Some(OrmFramework::SqlAlchemy) => parse_sqlalchemy(path, content),
+        Some(OrmFramework::Prisma) => parse_prisma(path, content),
+        Some(OrmFramework::Jpa) => parse_jpa(path, content),
+        None => Vec::new(),
+    }
+}
+
+// ============================================================================
+// SQLAlchemy
+// ============================================================================
+
+static PY_CLASS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\s*)class\s+(\w+)\s*(?:\(.*\))?\s*:").unwrap());
+static PY_TABLENAME: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^__tablename__\s*(?::[^=]+)?=\s*["']([^"']+)["']"#).unwrap());
+static PY_COLUMN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\w+)\s*(?::\s*(.+?))?\s*=\s*(?:\w+\.)*(?:Column|mapped_column)\s*\(").unwrap()
+});
+static PY_MAPPED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\w+)\s*:\s*(Mapped\[.+\])\s*$").unwrap());
+static CALL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?:\w+\.)*(\w+)\s*(?:\((.*)\))?$").unwrap());
+
+fn parse_sqlalchemy(file: &str, content: &str) -> Vec<CodeModel> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut models = Vec::new();
+    // (class indentation, model, whether it declares a table)
+    let mut current: Option<(usize, CodeModel, bool)> = None;
+    let mut finish = |current: Option<(usize, CodeModel, bool)>| {
+        if let Some((_, model, true)) = current {
+            models.push(model);
+        }
+    };
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            i += 1;
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        if current.as_ref().is_some_and(|(class, ..)| indent <= *class) {
+            finish(current.take());
+        }
+        if let Some(caps) = PY_CLASS.captures(line) {
+            finish(current.take());
+            let model = new_model(OrmFramework::SqlAlchemy, &caps[2], file, i + 1);
+            current = Some((caps[1].len(), model, false));
+            i += 1;
+            continue;
+        }
+        let Some((_, model, has_table)) = current.as_mut() else {
+            i += 1;
+            continue;
+        };
+
+        if let Some(caps) = PY_TABLENAME.captures(trimmed) {
+            model.table = caps[1].to_string();
+            *has_table = true;
+        } else if let Some(caps) = PY_COLUMN.captures(trimmed) {
+            let after_paren = &trimmed[caps.get(0).unwrap().end()..];
+            let (args, end, _) = call_args(&lines, i, after_paren, "#");
+            let annotation = caps.get(2).map(|m| m.as_str());
+            model
+                .columns
+                .push(sqlalchemy_column(&caps[1], annotation, &args, i + 1));
+            i = end;
+        } else if let Some(caps) = PY_MAPPED.captures(trimmed)
+            && !caps[1].starts_with("__")
+        {
+            let (data_type, nullable) = mapped_type(&caps[2]);
+            model.columns.push(CodeColumn {
+                field: caps[1].to_string(),
+                name: caps[1].to_string(),
+                line: i + 1,
+                data_type,
+                nullable,
+                ..Default::default()
+            });
+        }
+        i += 1;
+    }
+    finish(current.take());
+    models
+}
+
+fn sqlalchemy_column(field: &str, annotation: Option<&str>, args: &str, line: usize) -> CodeColumn {
+    let mut column = CodeColumn {
+        field: field.to_string(),
+        name: field.to_string(),
+        line,
+        ..Default::default()
+    };
+    let mut nullable = None;
+    for arg in split_args(args) {
+        match keyword_arg(&arg) {
+            (Some("comment"), value) => column.description = string_literal(value),
+            (Some("doc"), value) if column.description.is_none() => {
+                column.description = string_literal(value)
+            }
+            (Some("name"), value) => {
+                if let Some(name) = string_literal(value) {
+                    column.name = name;
+                }
+            }
+            (Some("nullable"), value) => nullable = bool_literal(value),
+            (Some("primary_key"), value) => column.primary_key = bool_literal(value) == Some(true),
+            (Some("unique"), value) => column.unique = bool_literal(value) == Some(true),
+            (Some(_), _) => {}
+            (None, value) => {
+                if let Some(name) = string_literal(value) {
+                    column.name = name;
+                } else if column.data_type.is_none()
+                    && let Some((data_type, max_length)) = sqlalchemy_type(value)
+                {
+                    column.data_type = Some(data_type);
+                    column.max_length = max_length;
+                }
+            }
+        }
+    }
+
+    let (mapped, mapped_nullable) = annotation.map(mapped_type).unwrap_or_default();
+    if column.data_type.is_none() {
+        column.data_type = mapped;
+    }
+    column.nullable = nullable
+        .or(column.primary_key.then_some(false))
+        .or(mapped_nullable);
+    column
+}
+
+/// Type name and length of a SQLAlchemy type expression (`String(100)`, `sa.Integer`)
+fn sqlalchemy_type(value: &str) -> Option<(String, Option<i64>)> {
+    let caps = CALL.captures(value.trim())?;
+    let name = caps[1].to_string();
+    if matches!(
+        name.as_str(),
+        "ForeignKey" | "Sequence" | "Identity" | "Computed" | "CheckConstraint"
+    ) || !name.starts_with(|c: char| c.is_ascii_uppercase())
+    {
+        return None;
+    }
+    let max_length = caps.get(2).and_then(|args| {
+        split_args(args.as_str())
+            .iter()
+            .find_map(|arg| match keyword_arg(arg) {
+                (None | Some("length"), value) => value.parse().ok(),
+                _ => None,
+            })
+    });
+    Some((name, max_length))
+}
+
+/// Type and nullability of a SQLAlchemy 2.0 `Mapped[...]` annotation
+fn mapped_type(annotation: &str) -> (Option<String>, Option<bool>) {
+    let Some(inner) = annotation
+        .trim()
+        .strip_prefix("Mapped[")
+        .and_then(|rest| rest.strip_suffix(']'))
+    else {
+        return (None, None);
+    };
+    let inner = inner.trim();
+    if let Some(optional) = inner
+        .strip_prefix("Optional[")
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        return (Some(optional.trim().to_string()), Some(true));
+    }
+    let parts: Vec<&str> = inner.split('|').map(str::trim).collect();
+    if parts.len() > 1 && parts.contains(&"None") {
+        let data_type = parts.iter().find(|p| **p != "None").map(|p| p.to_string());
+        return (data_type, Some(true));
+    }
+    (Some(inner.to_string()), Some(false))
+}
+
+// ============================================================================
+// Prisma
+// ============================================================================
+
+static PRISMA_MODEL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^model\s+(\w+)\s*\{").unwrap());
+static PRISMA_FIELD: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\w+)\s+(\w+)(\[\])?(\?)?(.*)$").unwrap());
+static PRISMA_MAP: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"@map\(\s*(?:name:\s*)?"([^"]+)""#).unwrap());
+static PRISMA_TABLE_MAP: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^@@map\(\s*(?:name:\s*)?"([^"]+)""#).unwrap());
+static PRISMA_COMPOSITE_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^@@id\(\s*(?:fields:\s*)?\[([^\]]*)\]").unwrap());
+static PRISMA_LENGTH: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@db\.\w*Char\(\s*(\d+)\s*\)").unwrap());
+static PRISMA_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?:^|\s)@id\b").unwrap());
+static PRISMA_UNIQUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?:^|\s)@unique\b").unwrap());
+
+fn parse_prisma(file: &str, content: &str) -> Vec<CodeModel> {
+    let model_names: Vec<&str> = content
+        .lines()
+        .filter_map(|line| PRISMA_MODEL.captures(line.trim()))
+        .map(|caps| caps.get(1).unwrap().as_str())
+        .collect();
+
+    let mut models = Vec::new();
+    let mut current: Option<CodeModel> = None;
+    let mut doc: Vec<&str> = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(comment) = trimmed.strip_prefix("///") {
+            doc.push(comment.trim());
+            continue;
+        }
+        let description = (!doc.is_empty()).then(|| doc.join(" "));
+        doc.clear();
+
+        if let Some(caps) = PRISMA_MODEL.captures(trimmed) {
+            current = Some(new_model(OrmFramework::Prisma, &caps[1], file, i + 1));
+            continue;
+        }
+        let Some(model) = current.as_mut() else {
+            continue;
+        };
+        if trimmed.starts_with('}') {
+            models.extend(current.take());
+        } else if let Some(caps) = PRISMA_TABLE_MAP.captures(trimmed) {
+            model.table = caps[1].to_string();
+        } else if let Some(caps) = PRISMA_COMPOSITE_ID.captures(trimmed) {
+            let fields: Vec<&str> = caps[1].split(',').map(str::trim).collect();
+            for column in &mut model.columns {
+                if fields.contains(&column.field.as_str()) {
+                    column.primary_key = true;
+                    column.nullable = Some(false);
+                }
+            }
+        } else if let Some(caps) = PRISMA_FIELD.captures(trimmed) {
+            let attributes = caps[5].split(" //").next().unwrap_or_default();
+            let data_type = &caps[2];
+            // Lists and relation fields are not columns
+            if caps.get(3).is_some()
+                || attributes.contains("@relation")
+                || model_names.contains(&data_type)
+            {
+                continue;
+            }
+            let primary_key = PRISMA_ID.is_match(attributes);
+            model.columns.push(CodeColumn {
+                field: caps[1].to_string(),
+                name: PRISMA_MAP
+                    .captures(attributes)
+                    .map_or_else(|| caps[1].to_string(), |m| m[1].to_string()),
+                line: i + 1,
+                description,
+                data_type: Some(data_type.to_string()),
+                nullable: Some(caps.get(4).is_some() && !primary_key),
+                primary_key,
+                unique: PRISMA_UNIQUE.is_match(attributes),
+                max_length: PRISMA_LENGTH
+                    .captures(attributes)
+                    .and_then(|m| m[1].parse().ok()),
+            });
+        }
+    }
+    models
+}
+
+// ============================================================================
+// JPA
+// ============================================================================
+
+static JAVA_CLASS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bclass\s+(\w+)").unwrap());
+static JAVA_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:(?:private|protected|public|final|transient|volatile)\s+)*([\w.]+(?:<[^;=]*>)?(?:\[\])?)\s+(\w+)\s*(?:=[^;]*)?;",
+    )
+    .unwrap()
+});
+static JAVA_ANNOTATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"^@([\w.]+)\s*").unwrap());
+
+/// An annotation on a Java declaration, with its arguments by name
+/// (a single unnamed argument is named `value`)
+struct Annotation {
+    name: String,
+    args: Vec<(String, String)>,
+}
+
+impl Annotation {
+    fn arg(&self, name: &str) -> Option<&str> {
+        self.args
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn parse_jpa(file: &str, content: &str) -> Vec<CodeModel> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut models = Vec::new();
+    // (brace depth of the class body, model)
+    let mut entity: Option<(i32, CodeModel)> = None;
+    let mut annotations: Vec<Annotation> = Vec::new();
+    let mut javadoc: Option<String> = None;
+    let mut comment: Option<Vec<&str>> = None;
+    let mut depth = 0;
+
+    let mut i = 0;
+    while i < lines.len() {
+        let mut text = lines[i].trim();
+        let line = i + 1;
+
+        // Javadoc and block comments
+        if let Some(lines_so_far) = comment.as_mut() {
+            match text.split_once("*/") {
+                Some((last, _)) => {
+                    lines_so_far.push(last);
+                    javadoc = Some(clean_javadoc(lines_so_far)).filter(|d| !d.is_empty());
+                    comment = None;
+                }
+                None => lines_so_far.push(text),
+            }
+            i += 1;
+            continue;
+        }
+        if let Some(rest) = text.strip_prefix("/*") {
+            let is_doc = rest.starts_with('*');
+            let rest = rest.trim_start_matches('*');
+            match rest.split_once("*/") {
+                Some((doc, _)) if is_doc => {
+                    javadoc = Some(clean_javadoc(&[doc])).filter(|d| !d.is_empty())
+                }
+                Some(_) => {}
+                None if is_doc => comment = Some(vec![rest]),
+                None => comment = Some(Vec::new()),
+            }
+            i += 1;
+            continue;
+        }
+        if text.is_empty() || text.starts_with("//") {
+            i += 1;
+            continue;
+        }
+
+        // Annotations, possibly several per line and spanning lines
+        while let Some(caps) = JAVA_ANNOTATION.captures(text) {
+            let name = caps[1].rsplit('.').next().unwrap_or_default().to_string();
+            let after = &text[caps.get(0).unwrap().end()..];
+            let args = match after.strip_prefix('(') {
+                Some(after_paren) => {
+                    let (args, end, column) = call_args(&lines, i, after_paren, "//");
+                    i = end;
+                    text = lines[end][column..].trim();
+                    annotation_args(&args)
+                }
+                None => {
+                    text = after;
+                    Vec::new()
+                }
+            };
+            annotations.push(Annotation { name, args });
+        }
+        if text.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some(caps) = JAVA_CLASS.captures(text) {
+            if entity.is_none() && annotations.iter().any(|a| a.name == "Entity") {
+                let mut model = new_model(OrmFramework::Jpa, &caps[1], file, line);
+                if let Some(table) = find(&annotations, "Table")
+                    .and_then(|a| a.arg("name"))
+                    .and_then(string_literal)
+                {
+                    model.table = table;
+                }
+                entity = Some((depth + 1, model));
+            }
+        } else if let Some((body, model)) = entity.as_mut()
+            && depth == *body
+            && !text.contains("static ")
+            && let Some(caps) = JAVA_FIELD.captures(text)
+            && let Some(column) = jpa_column(&caps[2], &caps[1], &annotations, javadoc.take(), line)
+        {
+            model.columns.push(column);
+        }
+        annotations.clear();
+        javadoc = None;
+
+        depth += brace_delta(text);
+        if entity.as_ref().is_some_and(|(body, _)| depth < *body) {
+            models.extend(entity.take().map(|(_, model)| model));
+        }
+        i += 1;
+    }
+    models.extend(entity.map(|(_, model)| model));
+    models
+}
+
+fn jpa_column(
+    field: &str,
+    data_type: &str,
+    annotations: &[Annotation],
+    javadoc: Option<String>,
+    line: usize,
+) -> Option<CodeColumn> {
+    let has = |name: &str| find(annotations, name).is_some();
+    if has("Transient") || has("OneToMany") || has("ManyToMany") {
+        return None;
+    }
+    let relation = has("ManyToOne") || has("OneToOne");
+    let column = find(annotations, "Column").or_else(|| find(annotations, "JoinColumn"));
+    let arg = |name: &str| column.and_then(|c| c.arg(name));
+
+    let primary_key = has("Id") || has("EmbeddedId");
+    let not_null = ["NotNull", "NonNull", "NotBlank", "NotEmpty"]
+        .iter()
+        .any(|name| has(name));
+    let primitive = matches!(
+        data_type,
+        "int" | "long" | "short" | "byte" | "boolean" | "double" | "float" | "char"
+    );
+    let nullable = arg("nullable")
+        .and_then(bool_literal)
+        .or((primary_key || not_null || primitive).then_some(false));
+
+    let description = find(annotations, "Comment")
+        .and_then(|a| a.arg("value"))
+        .or_else(|| find(annotations, "Schema").and_then(|a| a.arg("description")))
+        .and_then(string_literal)
+        .or(javadoc);
+
+    Some(CodeColumn {
+        field: field.to_string(),
+        name: arg("name")
+            .and_then(string_literal)
+            .unwrap_or_else(|| match relation {
+                true => format!("{}_id", field),
+                false => field.to_string(),
+            }),
+        line,
+        description,
+        data_type: (!relation).then(|| data_type.to_string()),
+        nullable,
+        primary_key,
+        unique: arg("unique").and_then(bool_literal) == Some(true),
+        max_length: arg("length")
+            .or_else(|| find(annotations, "Size").and_then(|a| a.arg("max")))
+            .and_then(|value| value.parse().ok()),
+    })
+}
+
+fn find<'a>(annotations: &'a [Annotation], name: &str) -> Option<&'a Annotation> {
+    annotations.iter().find(|a| a.name == name)
+}
+
+fn annotation_args(args: &str) -> Vec<(String, String)> {
+    split_args(args)
+        .iter()
+        .map(|arg| match keyword_arg(arg) {
+            (Some(key), value) => (key.to_string(), value.to_string()),
+            (None, value) => ("value".to_string(), value.to_string()),
+        })
+        .collect()
+}
+
+/// Text of a Javadoc comment without its leading `*`s and block tags
+fn clean_javadoc(lines: &[&str]) -> String {
+    lines
+        .iter()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .take_while(|line| !line.starts_with('@'))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Change in brace depth over a line of code, ignoring string and char literals
+fn brace_delta(text: &str) -> i32 {
+    let mut delta = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in text.chars() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '{' => delta += 1,
+                '}' => delta -= 1,
+                _ => {}
+            },
+        }
+    }
+    delta
+}
+
+// ============================================================================
+// Shared parsing helpers
+// ============================================================================
+
+fn new_model(framework: OrmFramework, name: &str, file: &str, line: usize) -> CodeModel {
+    CodeModel {
+        framework,
+        name: name.to_string(),
+        table: name.to_string(),
+        file: file.to_string(),
+        line,
+        columns: Vec::new(),
+    }
+}
+
+/// Arguments of a call whose opening parenthesis precedes `after_paren` (a
+/// slice of line `line`), following the call across lines
+///
+/// Returns the argument text (lines joined with spaces), the index of the line
+/// with the closing parenthesis and the byte offset just after it.
+fn call_args(
+    lines: &[&str],
+    line: usize,
+    after_paren: &str,
+    comment: &str,
+) -> (String, usize, usize) {
+    let mut args = String::new();
+    let mut depth = 1;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut index = line;
+    let mut text = after_paren;
+    loop {
+        // `after_paren` is a slice of the first line; later lines are whole
+        let offset = text.as_ptr() as usize - lines[index].as_ptr() as usize;
+        for (pos, c) in text.char_indices() {
+            match quote {
+                Some(_) if escaped => escaped = false,
+                Some(_) if c == '\\' => escaped = true,
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None if text[pos..].starts_with(comment) => break,
+                None => match c {
+                    '"' | '\'' => quote = Some(c),
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return (args, index, offset + pos + 1);
+                        }
+                    }
+                    _ => {}
+                },
+            }
+            args.push(c);
+        }
+        if index + 1 >= lines.len() {
+            return (args, index, lines[index].len());
+        }
+        index += 1;
+        args.push(' ');
+        text = lines[index];
+    }
+}
+
+/// Split an argument list at its top-level commas
+fn split_args(args: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in args.chars() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(std::mem::take(&mut current).trim().to_string());
+                    continue;
+                }
+                _ => {}
+            },
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Split `name = value` into its name and value; positional arguments have no name
+fn keyword_arg(arg: &str) -> (Option<&str>, &str) {
+    match arg.split_once('=') {
+        Some((key, value))
+            if !value.starts_with('=')
+                && !key.trim().is_empty()
+                && key.trim().chars().all(|c| c.is_alphanumeric() || c == '_') =>
+        {
+            (Some(key.trim()), value.trim())
+        }
+        _ => (None, arg.trim()),
+    }
+}
+
+/// Value of a string literal, joining adjacent (`"a" "b"`) and concatenated
+/// (`"a" + "b"`) literals
+fn string_literal(value: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut found = false;
+    let mut chars = value.trim().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                found = true;
+                loop {
+                    match chars.next()? {
+                        '\\' => match chars.next()? {
+                            'n' => out.push('\n'),
+                            't' => out.push('\t'),
+                            other => out.push(other),
+                        },
+                        ch if ch == c => break,
+                        ch => out.push(ch),
+                    }
+                }
+            }
+            c if c.is_whitespace() || matches!(c, '+' | '(' | ')') => {}
+            _ => return None,
+        }
+    }
+    found.then(|| out.trim().to_string())
+}
+
+fn bool_literal(value: &str) -> Option<bool> {
+    match value.trim() {
+        "True" | "true" => Some(true),
+        "False" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Planning
+// ============================================================================
+
+/// Contract field proposed from code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnnotationField {
+    Description,
+    Required,
+    PrimaryKey,
+    Unique,
+    MaxLength,
+}
+
+impl fmt::Display for AnnotationField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Description => "description",
+            Self::Required => "required",
+            Self::PrimaryKey => "primaryKey",
+            Self::Unique => "unique",
+            Self::MaxLength => "maxLength",
+        })
+    }
+}
+
+/// A property field set from code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationChange {
+    pub column: ColumnRef,
+    pub field: AnnotationField,
+    /// New value
+    pub value: String,
+    /// `file:line` of the column in code
+    pub source: String,
+}
+
+/// A property field whose contract value differs from the code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationConflict {
+    pub column: ColumnRef,
+    pub field: AnnotationField,
+    /// Value kept in the contract
+    pub existing: String,
+    /// Value declared in code
+    pub proposed: String,
+    /// `file:line` of the column in code
+    pub source: String,
+}
+
+/// A model or column in code without a counterpart in the contracts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Unmatched {
+    /// Table name, or `table.column` for columns
+    pub name: String,
+    /// `file:line` of the declaration
+    pub source: String,
+}
+
+/// Result of matching code models against contracts
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationReport {
+    /// Whether changes were only planned, not applied
+    pub dry_run: bool,
+    /// Number of models found in code
+    pub models_scanned: usize,
+    /// Number of code columns matched to a property
+    pub columns_matched: usize,
+    /// Property fields that were (or would be) set
+    pub changes: Vec<AnnotationChange>,
+    /// Contract values kept despite differing code
+    pub conflicts: Vec<AnnotationConflict>,
+    /// Models matching no schema object
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmatched_models: Vec<Unmatched>,
+    /// Columns of matched models matching no property
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmatched_columns: Vec<Unmatched>,
+}
+
+impl AnnotationReport {
+    /// Whether nothing changes and nothing conflicts
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.conflicts.is_empty()
+    }
+
+    /// Render the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Code Annotation Report\n\n");
+        out.push_str(&format!(
+            "{} model(s), {} column(s) matched, {} field(s) {}, {} conflict(s).\n",
+            self.models_scanned,
+            self.columns_matched,
+            self.changes.len(),
+            if self.dry_run { "proposed" } else { "set" },
+            self.conflicts.len()
+        ));
+
+        if !self.changes.is_empty() {
+            out.push_str(
+                "\n## Changes\n\n| Column | Field | Value | Source |\n|---|---|---|---|\n",
+            );
+            for change in &self.changes {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    change.column,
+                    change.field,
+                    table_cell(&change.value),
+                    change.source
+                ));
+            }
+        }
+        if !self.conflicts.is_empty() {
+            out.push_str("\n## Conflicts\n\n| Column | Field | Contract | Code | Source |\n|---|---|---|---|---|\n");
+            for conflict in &self.conflicts {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    conflict.column,
+                    conflict.field,
+                    table_cell(&conflict.existing),
+                    table_cell(&conflict.proposed),
+                    conflict.source
+                ));
+            }
+        }
+        for (title, unmatched) in [
+            ("Unmatched models", &self.unmatched_models),
+            ("Unmatched columns", &self.unmatched_columns),
+        ] {
+            if !unmatched.is_empty() {
+                out.push_str(&format!("\n## {}\n\n", title));
+                for item in unmatched {
+                    out.push_str(&format!("- {} ({})\n", item.name, item.source));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Matches code models to contracts and proposes property annotations
+#[derive(Debug, Clone, Default)]
+pub struct AnnotationPlanner {
+    models: Vec<CodeModel>,
+}
+
+impl AnnotationPlanner {
+    /// Create a planner for models found in code
+    pub fn new(models: Vec<CodeModel>) -> Self {
+        Self { models }
+    }
+
+    /// The models found in code
+    pub fn models(&self) -> &[CodeModel] {
+        &self.models
+    }
+
+    /// Report the annotations code would add to contracts, without changing them
+    pub fn plan_contracts(&self, contracts: &[ODCSContract]) -> AnnotationReport {
+        let mut contracts = contracts.to_vec();
+        let mut report = self.apply_contracts(&mut contracts);
+        report.dry_run = true;
+        report
+    }
+
+    /// Add annotations from code to the top-level properties of contracts
+    pub fn apply_contracts(&self, contracts: &mut [ODCSContract]) -> AnnotationReport {
+        let mut report = AnnotationReport {
+            models_scanned: self.models.len(),
+            ..Default::default()
+        };
+        for model in &self.models {
+            let keys = [fold(&model.table), fold(&model.name)];
+            let mut matched = vec![false; model.columns.len()];
+            let mut model_matched = false;
+            for schema in contracts.iter_mut().flat_map(|c| c.schema.iter_mut()) {
+                let schema_keys = [Some(&schema.name), schema.physical_name.as_ref()];
+                if !schema_keys
+                    .into_iter()
+                    .flatten()
+                    .any(|n| keys.contains(&fold(n)))
+                {
+                    continue;
+                }
+                model_matched = true;
+                for (column, matched) in model.columns.iter().zip(&mut matched) {
+                    let column_keys = [fold(&column.name), fold(&column.field)];
+                    let Some(property) = schema.properties.iter_mut().find(|p| {
+                        [Some(&p.name), p.physical_name.as_ref()]
+                            .into_iter()
+                            .flatten()
+                            .any(|n| column_keys.contains(&fold(n)))
+                    }) else {
+                        continue;
+                    };
+                    *matched = true;
+                    report.columns_matched += 1;
+                    let column_ref = ColumnRef::new(&schema.name, &property.name);
+                    annotate(
+                        property,
+                        column,
+                        &column_ref,
+                        &model.source(column.line),
+                        &mut report,
+                    );
+                }
+            }
+
+            if !model_matched {
+                report.unmatched_models.push(Unmatched {
+                    name: model.table.clone(),
+                    source: model.source(model.line),
+                });
+                continue;
+            }
+            for (column, _) in model.columns.iter().zip(&matched).filter(|(_, m)| !**m) {
+                report.unmatched_columns.push(Unmatched {
+                    name: format!("{}.{}", model.table, column.name),
+                    source: model.source(column.line),
+                });
+            }
+        }
+        report
+    }
+}
+
+/// Apply what the code declares about a column to its property
+fn annotate(
+    property: &mut Property,
+    column: &CodeColumn,
+    column_ref: &ColumnRef,
+    source: &str,
+    report: &mut AnnotationReport,
+) {
+    let mut change = |field, value: String| {
+        report.changes.push(AnnotationChange {
+            column: column_ref.clone(),
+            field,
+            value,
+            source: source.to_string(),
+        })
+    };
+    let mut conflicts = Vec::new();
+    let mut conflict = |field, existing: String, proposed: String| {
+        conflicts.push(AnnotationConflict {
+            column: column_ref.clone(),
+            field,
+            existing,
+            proposed,
+            source: source.to_string(),
+        })
+    };
+
+    if let Some(description) = column.description.as_deref().filter(|d| !d.is_empty()) {
+        match property.description.as_deref().map(str::trim) {
+            None | Some("") => {
+                property.description = Some(description.to_string());
+                change(AnnotationField::Description, description.to_string());
+            }
+            Some(existing) if existing != description => conflict(
+                AnnotationField::Description,
+                existing.to_string(),
+                description.to_string(),
+            ),
+            Some(_) => {}
+        }
+    }
+    match column.nullable {
+        Some(false) if !property.required => {
+            property.required = true;
+            change(AnnotationField::Required, "true".to_string());
+        }
+        Some(true) if property.required => conflict(
+            AnnotationField::Required,
+            "true".to_string(),
+            "false".to_string(),
+        ),
+        _ => {}
+    }
+    if column.primary_key && !property.primary_key {
+        property.primary_key = true;
+        change(AnnotationField::PrimaryKey, "true".to_string());
+    }
+    if column.unique && !property.unique {
+        property.unique = true;
+        change(AnnotationField::Unique, "true".to_string());
+    }
+    if let Some(max_length) = column.max_length {
+        let options = property
+            .logical_type_options
+            .get_or_insert_with(LogicalTypeOptions::default);
+        match options.max_length {
+            None => {
+                options.max_length = Some(max_length);
+                change(AnnotationField::MaxLength, max_length.to_string());
+            }
+            Some(existing) if existing != max_length => conflict(
+                AnnotationField::MaxLength,
+                existing.to_string(),
+                max_length.to_string(),
+            ),
+            Some(_) => {}
+        }
+    }
+    report.conflicts.extend(conflicts);
+}
+
+/// Name folded for matching: lowercase letters and digits only, so
+/// `customerId`, `customer_id` and `CUSTOMER_ID` are equal
+fn fold(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::SchemaObject;
+
+    #[test]
+    fn test_sqlalchemy() {
+        let source = r#"
+from sqlalchemy import Column, Integer, String, ForeignKey
+from sqlalchemy.orm import Mapped, mapped_column
+
+class Base(DeclarativeBase):
+    pass
+
+class Order(Base):
+    """An order"""
+    __tablename__ = "orders"
+
+    id = Column(Integer, primary_key=True)
+    customer_id = Column("customer", Integer, ForeignKey("customers.id"),
+                         nullable=False,
+                         comment="Customer who placed "
+                                 "the order")  # (see customers)
+    note: Mapped[Optional[str]] = mapped_column(String(500), doc="Free text")
+    status: Mapped[str]
+
+    def total(self):
+        return 0
+"#;
+        let models = scan_source("app/models.py", source);
+        assert_eq!(models.len(), 1);
+        let order = &models[0];
+        assert_eq!(
+            (order.name.as_str(), order.table.as_str()),
+            ("Order", "orders")
+        );
+        assert_eq!(order.columns.len(), 4);
+
+        let id = &order.columns[0];
+        assert!(id.primary_key);
+        assert_eq!(id.nullable, Some(false));
+        assert_eq!(id.data_type.as_deref(), Some("Integer"));
+
+        let customer = &order.columns[1];
+        assert_eq!(customer.name, "customer");
+        assert_eq!(customer.field, "customer_id");
+        assert_eq!(customer.nullable, Some(false));
+        assert_eq!(
+            customer.description.as_deref(),
+            Some("Customer who placed the order")
+        );
+
+        let note = &order.columns[2];
+        assert_eq!(note.nullable, Some(true));
+        assert_eq!(note.max_length, Some(500));
+        assert_eq!(note.description.as_deref(), Some("Free text"));
+        assert_eq!(note.line, 17);
+
+        assert_eq!(order.columns[3].nullable, Some(false));
+    }
+
+    #[test]
+    fn test_prisma() {
+        let source = r#"
+/// A customer account
+model Customer {
+  /// Surrogate key
+  id     Int     @id @default(autoincrement())
+  /// Login e-mail
+  email  String  @unique @db.VarChar(255)
+  name   String? @map("full_name") // display name
+  orders Order[]
+
+  @@map("customers")
+}
+
+model Order {
+  tenantId   Int
+  number     Int
+  customer   Customer @relation(fields: [customerId], references: [id])
+  customerId Int
+
+  @@id([tenantId, number])
+}
+"#;
+        let models = scan_source("prisma/schema.prisma", source);
+        assert_eq!(models.len(), 2);
+
+        let customer = &models[0];
+        assert_eq!(customer.table, "customers");
+        let names: Vec<&str> = customer.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "email", "full_name"]);
+        assert!(customer.columns[0].primary_key);
+        assert_eq!(
+            customer.columns[1].description.as_deref(),
+            Some("Login e-mail")
+        );
+        assert!(customer.columns[1].unique);
+        assert_eq!(customer.columns[1].max_length, Some(255));
+        assert_eq!(customer.columns[2].nullable, Some(true));
+        assert_eq!(customer.columns[2].description, None);
+
+        let order = &models[1];
+        assert_eq!(order.columns.len(), 3);
+        assert!(order.columns[0].primary_key && order.columns[1].primary_key);
+        assert!(!order.columns[2].primary_key);
+    }
+
+    #[test]
+    fn test_jpa() {
+        let source = r#"
+package com.example;
+
+import jakarta.persistence.*;
+
+/** An order. */
+@Entity
+@Table(name = "orders",
+       uniqueConstraints = {@UniqueConstraint(columnNames = {"number"})})
+public class Order {
+    private static final long serialVersionUID = 1L;
+
+    @Id @GeneratedValue
+    private Long id;
+
+    /**
+     * Order number shown to customers.
+     *
+     * @see OrderNumbers
+     */
+    @Column(name = "order_number", nullable = false, unique = true, length = 20)   
+    private String number;
+
+    @org.hibernate.annotations.Comment("Net amount in cents")
+    private long amount;
+
+    @ManyToOne
+    @JoinColumn(name = "customer_id", nullable = false)
+    private Customer customer;
+
+    @OneToMany(mappedBy = "order")
+    private List<OrderLine> lines = new ArrayList<>();
+
+    @Transient
+    private String cache;
+
+    public String getNumber() {
+        return number;
+    }
+
+    private String note;
+}
+"#;
+        let models = scan_source("src/main/java/com/example/Order.java", source);
+        assert_eq!(models.len(), 1);
+        let order = &models[0];
+        assert_eq!(order.table, "orders");
+        let names: Vec<&str> = order.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["id", "order_number", "amount", "customer_id", "note"]
+        );
+
+        assert!(order.columns[0].primary_key);
+        let number = &order.columns[1];
+        assert_eq!(
+            number.description.as_deref(),
+            Some("Order number shown to customers.")
+        );
+        assert_eq!(number.nullable, Some(false));
+        assert!(number.unique);
+        assert_eq!(number.max_length, Some(20));
+        assert_eq!(
+            order.columns[2].description.as_deref(),
+            Some("Net amount in cents")
+        );
+        assert_eq!(order.columns[2].nullable, Some(false));
+        assert_eq!(order.columns[3].nullable, Some(false));
+        assert_eq!(order.columns[3].data_type, None);
+        assert_eq!(order.columns[4].nullable, None);
+    }
+
+    #[test]
+    fn test_apply_contracts() {
+        let models = scan_source(
+            "models.py",
+            "class Order(Base):\n    __tablename__ = 'orders'\n    orderId = Column(Integer, primary_key=True, comment='Order key')\n    status = Column(String(10), nullable=True, comment='Order status')\n    channel = Column(String)\n\nclass Refund(Base):\n    __tablename__ = 'refunds'\n    id = Column(Integer, primary_key=True)\n",
+        );
+        let mut status = Property::new("status", "string").with_description("Lifecycle state");
+        status.required = true;
+        let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+            SchemaObject::new("Orders")
+                .with_properties(vec![Property::new("order_id", "integer"), status]),
+        );
+
+        let planner = AnnotationPlanner::new(models);
+        let plan = planner.plan_contracts(std::slice::from_ref(&contract));
+        assert!(plan.dry_run);
+        assert_eq!(plan.models_scanned, 2);
+        assert_eq!(plan.columns_matched, 2);
+
+        let fields: Vec<(String, AnnotationField)> = plan
+            .changes
+            .iter()
+            .map(|c| (c.column.to_string(), c.field))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("Orders.order_id".to_string(), AnnotationField::Description),
+                ("Orders.order_id".to_string(), AnnotationField::Required),
+                ("Orders.order_id".to_string(), AnnotationField::PrimaryKey),
+                ("Orders.status".to_string(), AnnotationField::MaxLength),
+            ]
+        );
+        let conflicts: Vec<AnnotationField> = plan.conflicts.iter().map(|c| c.field).collect();
+        assert_eq!(
+            conflicts,
+            vec![AnnotationField::Description, AnnotationField::Required]
+        );
+        assert_eq!(plan.unmatched_columns[0].name, "orders.channel");
+        assert_eq!(plan.unmatched_models[0].name, "refunds");
+        assert_eq!(plan.unmatched_models[0].source, "models.py:7");
+
+        let mut contracts = vec![contract];
+        let applied = planner.apply_contracts(&mut contracts);
+        assert!(!applied.dry_run);
+        let order_id = &contracts[0].schema[0].properties[0];
+        assert_eq!(order_id.description.as_deref(), Some("Order key"));
+        assert!(order_id.primary_key && order_id.required);
+        let status = &contracts[0].schema[0].properties[1];
+        assert_eq!(status.description.as_deref(), Some("Lifecycle state"));
+        assert_eq!(
+            status.logical_type_options.as_ref().unwrap().max_length,
+            Some(10)
+        );
+
+        let markdown = applied.to_markdown();
+        assert!(markdown.contains("| Orders.order_id | primaryKey | true | models.py:3 |"));
+        assert!(markdown.contains("- refunds (models.py:7)"));
+    }
+}
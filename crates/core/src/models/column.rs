@@ -60,6 +60,15 @@ pub struct LogicalTypeOptions {
     /// Scale for decimals
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scale: Option<i32>,
+    /// Spatial reference system identifier for geometry/geography types (e.g. 4326)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srid: Option<i32>,
+    /// Number of elements of embedding vector types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<i32>,
+    /// Distance metric of embedding vector types (`cosine`, `euclidean`, `innerProduct`)
+    #[serde(skip_serializing_if = "Option::is_none", alias = "distance_metric")]
+    pub distance_metric: Option<String>,
 }
 
 impl LogicalTypeOptions {
@@ -74,6 +83,9 @@ impl LogicalTypeOptions {
             && self.exclusive_maximum.is_none()
             && self.precision.is_none()
             && self.scale.is_none()
+            && self.srid.is_none()
+            && self.dimension.is_none()
+            && self.distance_metric.is_none()
     }
 }
 
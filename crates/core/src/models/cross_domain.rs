@@ -2,6 +2,8 @@
 //!
 //! Defines structures for referencing tables and relationships from other domains.
 //! This enables a domain to display and link to tables owned by other domains.
+//! Tables owned by other workspaces are referenced with [`CrossWorkspaceRef`] and
+//! resolved through [`FederationResolver`](crate::workspace::federation::FederationResolver).
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -34,6 +36,11 @@ pub struct CrossDomainTableRef {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
 
+    /// Schema fingerprint of the table when the reference was last verified,
+    /// used to detect changes made in the owning domain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
     /// When this reference was created
     #[serde(default = "chrono::Utc::now")]
     pub created_at: chrono::DateTime<chrono::Utc>,
@@ -49,6 +56,7 @@ impl CrossDomainTableRef {
             display_alias: None,
             position: None,
             notes: None,
+            fingerprint: None,
             created_at: chrono::Utc::now(),
         }
     }
@@ -99,6 +107,98 @@ impl CrossDomainRelationshipRef {
     }
 }
 
+/// Where a referenced workspace is stored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum WorkspaceLocation {
+    /// Directory of the workspace within a storage backend
+    Storage { path: String },
+    /// A Git remote, optionally with a branch and the directory of the workspace
+    Git {
+        url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        branch: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+    },
+}
+
+/// A reference to a table owned by another workspace
+///
+/// The referenced table is read-only: it is resolved from the owning workspace
+/// and kept as a cached copy that is refreshed once it is older than the
+/// maximum age.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrossWorkspaceRef {
+    /// Unique identifier for this reference
+    pub id: Uuid,
+
+    /// Name of the workspace that owns the table
+    pub source_workspace: String,
+
+    /// Where the owning workspace is stored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<WorkspaceLocation>,
+
+    /// The table ID in the source workspace
+    pub table_id: Uuid,
+
+    /// Table name, used to find the table if its ID is not listed in the source workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_name: Option<String>,
+
+    /// Optional alias for display in this domain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_alias: Option<String>,
+
+    /// Maximum age of the cached copy in hours (overrides the resolver default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_hours: Option<u64>,
+
+    /// Optional notes about why this table is referenced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// When this reference was created
+    #[serde(default = "chrono::Utc::now")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl CrossWorkspaceRef {
+    /// Create a new cross-workspace table reference
+    pub fn new(source_workspace: String, table_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            source_workspace,
+            location: None,
+            table_id,
+            table_name: None,
+            display_alias: None,
+            max_age_hours: None,
+            notes: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Set where the source workspace is stored
+    pub fn with_location(mut self, location: WorkspaceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Set the table name
+    pub fn with_table_name(mut self, table_name: impl Into<String>) -> Self {
+        self.table_name = Some(table_name.into());
+        self
+    }
+
+    /// Set the maximum age of the cached copy
+    pub fn with_max_age_hours(mut self, hours: u64) -> Self {
+        self.max_age_hours = Some(hours);
+        self
+    }
+}
+
 /// The cross-domain configuration for a domain
 ///
 /// This is stored as `cross_domain.yaml` in each domain's directory.
@@ -119,6 +219,10 @@ pub struct CrossDomainConfig {
     /// from another domain are imported.
     #[serde(default)]
     pub imported_relationships: Vec<CrossDomainRelationshipRef>,
+
+    /// Tables referenced from other workspaces (read-only cached copies)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imported_workspace_tables: Vec<CrossWorkspaceRef>,
 }
 
 fn default_schema_version() -> String {
@@ -227,6 +331,35 @@ impl CrossDomainConfig {
             .find(|t| t.table_id == table_id)
             .map(|t| t.source_domain.as_str())
     }
+
+    /// Add a table reference from another workspace
+    /// Returns the index of the added or existing reference
+    pub fn add_workspace_table_ref(&mut self, reference: CrossWorkspaceRef) -> usize {
+        if let Some(idx) = self.imported_workspace_tables.iter().position(|t| {
+            t.source_workspace == reference.source_workspace && t.table_id == reference.table_id
+        }) {
+            return idx;
+        }
+        self.imported_workspace_tables.push(reference);
+        self.imported_workspace_tables.len() - 1
+    }
+
+    /// Remove a table reference from another workspace
+    pub fn remove_workspace_table_ref(&mut self, source_workspace: &str, table_id: Uuid) -> bool {
+        let initial_len = self.imported_workspace_tables.len();
+        self.imported_workspace_tables
+            .retain(|t| !(t.source_workspace == source_workspace && t.table_id == table_id));
+        self.imported_workspace_tables.len() != initial_len
+    }
+
+    /// Get all table IDs referenced from a specific workspace
+    pub fn get_tables_from_workspace(&self, workspace: &str) -> Vec<Uuid> {
+        self.imported_workspace_tables
+            .iter()
+            .filter(|t| t.source_workspace == workspace)
+            .map(|t| t.table_id)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -275,4 +408,29 @@ mod tests {
         // Relationship should also be removed
         assert_eq!(config.imported_relationships.len(), 0);
     }
+
+    #[test]
+    fn test_workspace_table_refs() {
+        let mut config = CrossDomainConfig::new();
+        let table_id = Uuid::new_v4();
+        let reference = CrossWorkspaceRef::new("finance".to_string(), table_id).with_location(
+            WorkspaceLocation::Git {
+                url: "https://example.com/finance.git".to_string(),
+                branch: Some("main".to_string()),
+                path: None,
+            },
+        );
+
+        assert_eq!(config.add_workspace_table_ref(reference.clone()), 0);
+        assert_eq!(config.add_workspace_table_ref(reference), 0);
+        assert_eq!(config.get_tables_from_workspace("finance"), vec![table_id]);
+
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert!(yaml.contains("type: git"));
+        let parsed: CrossDomainConfig = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, config);
+
+        assert!(config.remove_workspace_table_ref("finance", table_id));
+        assert!(config.imported_workspace_tables.is_empty());
+    }
 }
@@ -0,0 +1,512 @@
+//! Workspace-level reusable property definitions
+//!
+//! Common fields such as `order_id` or `tenant_id` are defined once in the
+//! workspace's `definitions.yaml` and referenced from ODCS contracts with `$ref`:
+//!
+//! ```yaml
+//! # definitions.yaml
+//! definitions:
+//!   order_id:
+//!     logicalType: string
+//!     physicalType: uuid
+//!     description: Unique order identifier
+//!     required: true
+//!
+//! # orders.odcs.yaml
+//! schema:
+//!   - name: orders
+//!     properties:
+//!       - $ref: definitions.yaml#/definitions/order_id
+//!       - name: parent_order_id
+//!         $ref: definitions.yaml#/definitions/order_id
+//!         required: false
+//! ```
+//!
+//! Fields set next to the `$ref` override the definition; the property name
+//! defaults to the definition name. Resolving a reference inlines the
+//! definition and records the reference and the overridden fields in the
+//! `definition` custom property, so that [`PropertyDefinitions::collapse`] can
+//! turn the property back into a reference on export.
+//!
+//! References may be written as `definitions.yaml#/definitions/<name>`,
+//! `definitions.yaml#/<name>` or `#/definitions/<name>`. The last form is only
+//! resolved against the workspace when the contract has no local `definitions`
+//! entry of that name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::definitions::PropertyDefinitions;
+//!
+//! let definitions = PropertyDefinitions::from_yaml(
+//!     "definitions:\n  tenant_id:\n    logicalType: string\n    required: true\n",
+//! )
+//! .unwrap();
+//! let mut contract = serde_json::json!({
+//!     "schema": [{"name": "orders", "properties": [
+//!         {"$ref": "definitions.yaml#/definitions/tenant_id"}
+//!     ]}]
+//! });
+//! assert_eq!(definitions.resolve(&mut contract).unwrap(), 1);
+//! assert_eq!(contract["schema"][0]["properties"][0]["name"], "tenant_id");
+//! ```
+
+use super::odcs::Property;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+/// File name of the workspace definitions
+pub const DEFINITIONS_FILE: &str = "definitions.yaml";
+
+/// Custom property recording the definition a property was resolved from
+pub const DEFINITION_PROPERTY: &str = "definition";
+
+/// Reusable property definitions of a workspace, keyed by name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PropertyDefinitions {
+    /// Property definitions; the property name defaults to the key
+    #[serde(default, deserialize_with = "deserialize_definitions")]
+    pub definitions: BTreeMap<String, Property>,
+}
+
+impl PropertyDefinitions {
+    /// Create an empty set of definitions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse `definitions.yaml` content
+    pub fn from_yaml(yaml_content: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml_content)
+    }
+
+    /// Serialize to `definitions.yaml` content
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Add or replace a definition; an unnamed property takes the definition name
+    pub fn insert(&mut self, name: impl Into<String>, mut property: Property) {
+        let name = name.into();
+        if property.name.is_empty() {
+            property.name = name.clone();
+        }
+        self.definitions.insert(name, property);
+    }
+
+    /// Remove a definition
+    pub fn remove(&mut self, name: &str) -> Option<Property> {
+        self.definitions.remove(name)
+    }
+
+    /// Look up a definition by name
+    pub fn get(&self, name: &str) -> Option<&Property> {
+        self.definitions.get(name)
+    }
+
+    /// Number of definitions
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    /// Whether there are no definitions
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// The canonical `$ref` of a definition
+    pub fn reference(name: &str) -> String {
+        format!("{}#/definitions/{}", DEFINITIONS_FILE, name)
+    }
+
+    /// Name of the definition a `$ref` points to, if it is a definitions reference
+    pub fn definition_name(reference: &str) -> Option<&str> {
+        let name = match reference.strip_prefix(DEFINITIONS_FILE) {
+            Some(pointer) => {
+                let pointer = pointer.strip_prefix("#/")?;
+                pointer.strip_prefix("definitions/").unwrap_or(pointer)
+            }
+            None => reference.strip_prefix("#/definitions/")?,
+        };
+        (!name.is_empty() && !name.contains('/')).then_some(name)
+    }
+
+    /// Inline the workspace definitions referenced by the properties of a contract
+    ///
+    /// `document` is an ODCS contract as JSON. Returns the number of resolved
+    /// references, or an error for a reference to an unknown definition.
+    pub fn resolve(&self, document: &mut Value) -> Result<usize, String> {
+        let local: Vec<String> = document
+            .get("definitions")
+            .and_then(Value::as_object)
+            .map(|definitions| definitions.keys().cloned().collect())
+            .unwrap_or_default();
+
+        let mut count = 0;
+        if let Some(schemas) = document.get_mut("schema").and_then(Value::as_array_mut) {
+            for schema in schemas {
+                if let Some(properties) = schema.get_mut("properties") {
+                    self.resolve_properties(properties, &local, &mut Vec::new(), &mut count)?;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Resolve the definitions referenced in ODCS YAML content
+    ///
+    /// Returns the content unchanged when it contains no workspace references.
+    pub fn resolve_yaml(&self, yaml_content: &str) -> Result<String, String> {
+        let mut document: Value = serde_yaml::from_str(yaml_content)
+            .map_err(|e| format!("Failed to parse YAML: {}", e))?;
+        if self.resolve(&mut document)? == 0 {
+            return Ok(yaml_content.to_string());
+        }
+        serde_yaml::to_string(&document).map_err(|e| format!("Failed to serialize YAML: {}", e))
+    }
+
+    /// Turn resolved properties of a contract back into `$ref`s
+    ///
+    /// Properties carrying the [`DEFINITION_PROPERTY`] marker are replaced by
+    /// their name, the reference and the fields that were overridden. Returns
+    /// the number of collapsed properties.
+    pub fn collapse(&self, document: &mut Value) -> usize {
+        let mut count = 0;
+        if let Some(schemas) = document.get_mut("schema").and_then(Value::as_array_mut) {
+            for schema in schemas {
+                if let Some(properties) = schema.get_mut("properties") {
+                    self.collapse_properties(properties, &mut count);
+                }
+            }
+        }
+        count
+    }
+
+    fn resolve_properties(
+        &self,
+        properties: &mut Value,
+        local: &[String],
+        stack: &mut Vec<String>,
+        count: &mut usize,
+    ) -> Result<(), String> {
+        let Some(properties) = properties.as_array_mut() else {
+            return Ok(());
+        };
+        for property in properties {
+            self.resolve_property(property, local, stack, count)?;
+        }
+        Ok(())
+    }
+
+    fn resolve_property(
+        &self,
+        property: &mut Value,
+        local: &[String],
+        stack: &mut Vec<String>,
+        count: &mut usize,
+    ) -> Result<(), String> {
+        let Some(object) = property.as_object_mut() else {
+            return Ok(());
+        };
+
+        let reference = object
+            .get("$ref")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let target = reference.as_deref().and_then(|reference| {
+            let name = Self::definition_name(reference)?;
+            let is_local = !reference.starts_with(DEFINITIONS_FILE)
+                && (local.iter().any(|l| l == name) || !self.definitions.contains_key(name));
+            (!is_local).then(|| (reference.to_string(), name.to_string()))
+        });
+
+        if let Some((reference, name)) = target {
+            let definition = self
+                .definitions
+                .get(&name)
+                .ok_or_else(|| format!("Unknown property definition: {}", name))?;
+            if stack.contains(&name) {
+                return Err(format!(
+                    "Circular property definition: {} -> {}",
+                    stack.join(" -> "),
+                    name
+                ));
+            }
+
+            let mut merged = match serde_json::to_value(definition) {
+                Ok(Value::Object(map)) => map,
+                _ => Map::new(),
+            };
+            merged.insert("name".to_string(), Value::String(name.clone()));
+            let mut overrides = Vec::new();
+            for (key, value) in std::mem::take(object) {
+                if key == "$ref" {
+                    continue;
+                }
+                if key == "customProperties" {
+                    append_custom_properties(&mut merged, value);
+                    continue;
+                }
+                if key != "name" {
+                    overrides.push(key.clone());
+                }
+                merged.insert(key, value);
+            }
+            append_custom_properties(
+                &mut merged,
+                json!([{
+                    "property": DEFINITION_PROPERTY,
+                    "value": {"ref": reference, "overrides": overrides},
+                }]),
+            );
+            *object = merged;
+            *count += 1;
+
+            stack.push(name);
+            let result = self.resolve_nested(property, local, stack, count);
+            stack.pop();
+            return result;
+        }
+
+        self.resolve_nested(property, local, stack, count)
+    }
+
+    fn resolve_nested(
+        &self,
+        property: &mut Value,
+        local: &[String],
+        stack: &mut Vec<String>,
+        count: &mut usize,
+    ) -> Result<(), String> {
+        if let Some(properties) = property.get_mut("properties") {
+            self.resolve_properties(properties, local, stack, count)?;
+        }
+        if let Some(items) = property.get_mut("items") {
+            self.resolve_property(items, local, stack, count)?;
+        }
+        Ok(())
+    }
+
+    fn collapse_properties(&self, properties: &mut Value, count: &mut usize) {
+        let Some(properties) = properties.as_array_mut() else {
+            return;
+        };
+        for property in properties {
+            self.collapse_property(property, count);
+        }
+    }
+
+    fn collapse_property(&self, property: &mut Value, count: &mut usize) {
+        let Some(object) = property.as_object_mut() else {
+            return;
+        };
+        let Some(marker) = definition_marker(object) else {
+            if let Some(properties) = object.get_mut("properties") {
+                self.collapse_properties(properties, count);
+            }
+            if let Some(items) = object.get_mut("items") {
+                self.collapse_property(items, count);
+            }
+            return;
+        };
+        let Some(reference) = marker.get("ref").and_then(Value::as_str) else {
+            return;
+        };
+
+        let name = Self::definition_name(reference).unwrap_or_default();
+        let definition = self
+            .definitions
+            .get(name)
+            .and_then(|definition| serde_json::to_value(definition).ok())
+            .unwrap_or_default();
+
+        let mut collapsed = Map::new();
+        if let Some(local_name) = object.get("name").filter(|local_name| *local_name != name) {
+            collapsed.insert("name".to_string(), local_name.clone());
+        }
+        collapsed.insert("$ref".to_string(), Value::String(reference.to_string()));
+
+        let overrides = marker.get("overrides").and_then(Value::as_array);
+        for key in overrides.into_iter().flatten().filter_map(Value::as_str) {
+            // Fields reset to their default are omitted when serialized
+            let value = object
+                .get(key)
+                .cloned()
+                .or_else(|| match definition.get(key) {
+                    Some(Value::Bool(_)) => Some(Value::Bool(false)),
+                    Some(Value::Array(_)) => Some(json!([])),
+                    _ => None,
+                });
+            if let Some(value) = value {
+                collapsed.insert(key.to_string(), value);
+            }
+        }
+
+        let inherited = definition.get("customProperties").and_then(Value::as_array);
+        let local: Vec<Value> = object
+            .get("customProperties")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry.get("property") != Some(&json!(DEFINITION_PROPERTY)))
+            .filter(|entry| !inherited.is_some_and(|inherited| inherited.contains(entry)))
+            .cloned()
+            .collect();
+        if !local.is_empty() {
+            collapsed.insert("customProperties".to_string(), Value::Array(local));
+        }
+
+        *property = Value::Object(collapsed);
+        *count += 1;
+    }
+}
+
+/// Value of the [`DEFINITION_PROPERTY`] custom property of a property object
+fn definition_marker(object: &Map<String, Value>) -> Option<Value> {
+    object
+        .get("customProperties")?
+        .as_array()?
+        .iter()
+        .find(|entry| entry.get("property") == Some(&json!(DEFINITION_PROPERTY)))?
+        .get("value")
+        .cloned()
+}
+
+/// Deserialize definitions, naming each property after its key unless it has a name
+fn deserialize_definitions<'de, D>(deserializer: D) -> Result<BTreeMap<String, Property>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw: BTreeMap<String, Value> = Option::deserialize(deserializer)?.unwrap_or_default();
+    raw.into_iter()
+        .map(|(name, mut value)| {
+            if let Some(object) = value.as_object_mut() {
+                object
+                    .entry("name")
+                    .or_insert_with(|| Value::String(name.clone()));
+            }
+            serde_json::from_value(value)
+                .map(|property| (name.clone(), property))
+                .map_err(|e| serde::de::Error::custom(format!("definition '{}': {}", name, e)))
+        })
+        .collect()
+}
+
+fn append_custom_properties(object: &mut Map<String, Value>, value: Value) {
+    let Value::Array(mut additions) = value else {
+        return;
+    };
+    match object.get_mut("customProperties") {
+        Some(Value::Array(existing)) => {
+            existing.retain(|entry| entry.get("property") != Some(&json!(DEFINITION_PROPERTY)));
+            existing.append(&mut additions);
+        }
+        _ => {
+            object.insert("customProperties".to_string(), Value::Array(additions));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFINITIONS: &str = r#"
+definitions:
+  order_id:
+    logicalType: string
+    physicalType: uuid
+    description: Unique order identifier
+    required: true
+  tenant_id:
+    logicalType: string
+    customProperties:
+      - property: sensitivity
+        value: internal
+"#;
+
+    #[test]
+    fn test_resolve_and_collapse_round_trip() {
+        let definitions = PropertyDefinitions::from_yaml(DEFINITIONS).unwrap();
+        assert_eq!(definitions.get("order_id").unwrap().name, "order_id");
+
+        let original = json!({
+            "schema": [{
+                "name": "orders",
+                "properties": [
+                    {"$ref": "definitions.yaml#/definitions/order_id"},
+                    {"name": "parent_order_id", "$ref": "definitions.yaml#/order_id", "required": false},
+                    {"$ref": "#/definitions/tenant_id"},
+                    {"name": "total", "logicalType": "number"}
+                ]
+            }]
+        });
+        let mut document = original.clone();
+        assert_eq!(definitions.resolve(&mut document).unwrap(), 3);
+
+        let properties = &document["schema"][0]["properties"];
+        assert_eq!(properties[0]["name"], "order_id");
+        assert_eq!(properties[0]["physicalType"], "uuid");
+        assert_eq!(properties[0]["required"], true);
+        assert_eq!(properties[1]["name"], "parent_order_id");
+        assert_eq!(properties[1]["required"], false);
+        assert_eq!(
+            properties[1]["customProperties"][0]["value"]["overrides"],
+            json!(["required"])
+        );
+        assert_eq!(
+            properties[2]["customProperties"][0]["property"],
+            "sensitivity"
+        );
+        assert!(properties[3].get("customProperties").is_none());
+
+        let contract: crate::models::odcs::ODCSContract = serde_json::from_value(json!({
+            "apiVersion": "v3.1.0",
+            "kind": "DataContract",
+            "id": "orders",
+            "version": "1.0.0",
+            "name": "orders",
+            "schema": document["schema"],
+        }))
+        .unwrap();
+        let mut exported = serde_json::to_value(&contract).unwrap();
+        assert_eq!(definitions.collapse(&mut exported), 3);
+        assert_eq!(
+            exported["schema"][0]["properties"],
+            original["schema"][0]["properties"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let definitions = PropertyDefinitions::from_yaml(DEFINITIONS).unwrap();
+        let mut unknown = json!({"schema": [{"properties": [{"$ref": "definitions.yaml#/definitions/missing"}]}]});
+        assert!(
+            definitions
+                .resolve(&mut unknown)
+                .unwrap_err()
+                .contains("missing")
+        );
+
+        // Contract-local definitions take precedence over the workspace
+        let mut local = json!({
+            "definitions": {"tenant_id": {"type": "string"}},
+            "schema": [{"properties": [{"$ref": "#/definitions/tenant_id"}]}]
+        });
+        assert_eq!(definitions.resolve(&mut local).unwrap(), 0);
+
+        let mut circular = PropertyDefinitions::new();
+        circular.insert("address", Property::new("", "object"));
+        let mut document = json!({"schema": [{"properties": [{
+            "$ref": "definitions.yaml#/address",
+            "properties": [{"$ref": "definitions.yaml#/address"}]
+        }]}]});
+        assert!(
+            circular
+                .resolve(&mut document)
+                .unwrap_err()
+                .contains("Circular")
+        );
+    }
+}
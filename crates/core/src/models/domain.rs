@@ -4,6 +4,7 @@
 
 use super::cads::CADSKind;
 use super::enums::InfrastructureType;
+use super::id_strategy::IdGenerator;
 use super::table::{ContactDetails, SlaProperty};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -244,6 +245,18 @@ impl Domain {
         }
     }
 
+    /// Create a new Domain whose ID follows a workspace's ID strategy
+    ///
+    /// The ID matches the one [`Workspace::create_domain`](super::Workspace::create_domain)
+    /// generates for the name.
+    pub fn new_with_generator(name: String, ids: &IdGenerator) -> Self {
+        let id = ids.generate("domain", None, &name);
+        Self {
+            id,
+            ..Self::new(name)
+        }
+    }
+
     /// Add a system to the domain
     pub fn add_system(&mut self, mut system: System) {
         system.domain_id = self.id;
@@ -316,6 +329,23 @@ impl System {
             updated_at: Some(chrono::Utc::now()),
         }
     }
+
+    /// Create a new System of a domain, with an ID following a workspace's ID strategy
+    ///
+    /// The ID matches the one [`Workspace::create_system`](super::Workspace::create_system)
+    /// generates for the names.
+    pub fn new_with_generator(
+        name: String,
+        infrastructure_type: InfrastructureType,
+        domain: &Domain,
+        ids: &IdGenerator,
+    ) -> Self {
+        let id = ids.generate("system", Some(&domain.name), &name);
+        Self {
+            id,
+            ..Self::new(name, infrastructure_type, domain.id)
+        }
+    }
 }
 
 impl CADSNode {
@@ -333,6 +363,26 @@ impl CADSNode {
         }
     }
 
+    /// Create a new local CADS node with an ID following a workspace's ID strategy
+    ///
+    /// Deterministic IDs are derived from the system and asset IDs.
+    pub fn new_local_with_generator(
+        system_id: Uuid,
+        cads_asset_id: Uuid,
+        kind: CADSKind,
+        ids: &IdGenerator,
+    ) -> Self {
+        let id = ids.generate(
+            "cadsNode",
+            None,
+            &format!("{}/{}", system_id, cads_asset_id),
+        );
+        Self {
+            id,
+            ..Self::new_local(system_id, cads_asset_id, kind)
+        }
+    }
+
     /// Create a new shared CADS node reference
     pub fn new_shared(
         system_id: Uuid,
@@ -350,6 +400,23 @@ impl CADSNode {
             updated_at: Some(chrono::Utc::now()),
         }
     }
+
+    /// Create a new shared CADS node reference with an ID following a
+    /// workspace's ID strategy
+    ///
+    /// Deterministic IDs are derived from the system and referenced node IDs.
+    pub fn new_shared_with_generator(
+        system_id: Uuid,
+        kind: CADSKind,
+        shared_reference: SharedNodeReference,
+        ids: &IdGenerator,
+    ) -> Self {
+        let name = format!("{}/{}", system_id, shared_reference.node_id);
+        Self {
+            id: ids.generate("cadsNode", None, &name),
+            ..Self::new_shared(system_id, kind, shared_reference)
+        }
+    }
 }
 
 impl ODCSNode {
@@ -367,6 +434,22 @@ impl ODCSNode {
         }
     }
 
+    /// Create a new local ODCS node with an ID following a workspace's ID strategy
+    ///
+    /// Deterministic IDs are derived from the system and table IDs.
+    pub fn new_local_with_generator(
+        system_id: Uuid,
+        table_id: Uuid,
+        role: String,
+        ids: &IdGenerator,
+    ) -> Self {
+        let id = ids.generate("odcsNode", None, &format!("{}/{}", system_id, table_id));
+        Self {
+            id,
+            ..Self::new_local(system_id, table_id, role)
+        }
+    }
+
     /// Create a new shared ODCS node reference
     pub fn new_shared(
         system_id: Uuid,
@@ -384,4 +467,21 @@ impl ODCSNode {
             updated_at: Some(chrono::Utc::now()),
         }
     }
+
+    /// Create a new shared ODCS node reference with an ID following a
+    /// workspace's ID strategy
+    ///
+    /// Deterministic IDs are derived from the system and referenced node IDs.
+    pub fn new_shared_with_generator(
+        system_id: Uuid,
+        role: String,
+        shared_reference: SharedNodeReference,
+        ids: &IdGenerator,
+    ) -> Self {
+        let name = format!("{}/{}", system_id, shared_reference.node_id);
+        Self {
+            id: ids.generate("odcsNode", None, &name),
+            ..Self::new_shared(system_id, role, shared_reference)
+        }
+    }
 }
@@ -0,0 +1,122 @@
+//! Content fingerprints of models
+//!
+//! A fingerprint is a stable hash over a model's semantic content, used as a
+//! change-detection token, a cache key, for cross-domain staleness checks and
+//! in workspace bundle manifests. Fingerprints ignore:
+//!
+//! - **ordering** - object keys and list elements are compared as sets, so
+//!   reordering columns, properties or tags does not change the fingerprint
+//! - **bookkeeping** - timestamps, generated IDs, canvas positions and file
+//!   locations (see [`Table::fingerprint`](crate::models::Table::fingerprint)
+//!   and [`ODCSContract::fingerprint`](crate::models::odcs::ODCSContract::fingerprint))
+//!
+//! Fingerprints are 32 lowercase hex characters (a name-based UUIDv5 over the
+//! canonical JSON of the model).
+
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Namespace of content fingerprints.
+///
+/// Changing this value changes every fingerprint, so it is fixed for the
+/// lifetime of the format.
+pub const FINGERPRINT_NAMESPACE: Uuid = Uuid::from_u128(0x6f64_6d2d_6670_5f6e_9b4f_6e3d_2c58_a071);
+
+/// Fingerprint of a serializable model, ignoring the given top-level fields
+///
+/// `ignored` names fields as serialized (e.g. `createdAt`).
+pub fn fingerprint<T: Serialize>(model: &T, ignored: &[&str]) -> String {
+    let mut value = serde_json::to_value(model).unwrap_or(Value::Null);
+    if let Value::Object(map) = &mut value {
+        for field in ignored {
+            map.remove(*field);
+        }
+    }
+    fingerprint_value(&value)
+}
+
+/// Fingerprint of a JSON value
+pub fn fingerprint_value(value: &Value) -> String {
+    Uuid::new_v5(&FINGERPRINT_NAMESPACE, canonical_json(value).as_bytes())
+        .simple()
+        .to_string()
+}
+
+/// Canonical JSON of a value: keys sorted, list elements sorted by their own
+/// canonical JSON, and null fields dropped
+pub fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut fields: Vec<(&String, String)> = map
+                .iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, canonical_json(v)))
+                .collect();
+            fields.sort();
+            let fields: Vec<String> = fields
+                .into_iter()
+                .map(|(k, v)| format!("{}:{}", Value::String(k.clone()), v))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let mut items: Vec<String> = items.iter().map(canonical_json).collect();
+            items.sort();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonical_json_ignores_ordering() {
+        let a = json!({"b": [3, 1, {"y": 1, "x": null}], "a": "text"});
+        let b = json!({"a": "text", "b": [{"y": 1}, 1, 3]});
+        assert_eq!(canonical_json(&a), canonical_json(&b));
+        assert_eq!(canonical_json(&a), r#"{"a":"text","b":[1,3,{"y":1}]}"#);
+        assert_eq!(fingerprint_value(&a), fingerprint_value(&b));
+        assert_ne!(
+            fingerprint_value(&a),
+            fingerprint_value(&json!({"a": "text", "b": [1, 3]}))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_fields() {
+        let a = json!({"name": "orders", "updatedAt": "2024-01-01"});
+        let b = json!({"name": "orders", "updatedAt": "2025-06-30"});
+        assert_ne!(fingerprint(&a, &[]), fingerprint(&b, &[]));
+        assert_eq!(
+            fingerprint(&a, &["updatedAt"]),
+            fingerprint(&b, &["updatedAt"])
+        );
+        assert_eq!(fingerprint(&a, &[]).len(), 32);
+    }
+
+    #[test]
+    fn test_table_fingerprint() {
+        use crate::models::{Column, Table};
+
+        let table = Table::new(
+            "orders".to_string(),
+            vec![
+                Column::new("id".to_string(), "BIGINT".to_string()),
+                Column::new("total".to_string(), "DECIMAL".to_string()),
+            ],
+        );
+        let mut copy = Table::new("orders".to_string(), table.columns.clone());
+        copy.columns.reverse();
+        copy.yaml_file_path = Some("orders.odcs.yaml".to_string());
+        assert_ne!(table.id, copy.id);
+        assert_eq!(table.fingerprint(), copy.fingerprint());
+
+        copy.columns[0].nullable = !copy.columns[0].nullable;
+        assert_ne!(table.fingerprint(), copy.fingerprint());
+    }
+}
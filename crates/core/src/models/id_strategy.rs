@@ -0,0 +1,223 @@
+//! Entity ID generation strategies
+//!
+//! A workspace can choose how identifiers for newly created entities are minted:
+//!
+//! - **random** (UUIDv4) - the historical default
+//! - **timeOrdered** (UUIDv7) - monotonic, timestamp-prefixed IDs that keep
+//!   B-tree indexes compact when the model is persisted to a database
+//! - **deterministic** (UUIDv5) - derived from the workspace, domain, entity
+//!   kind and name, so re-creating the same logical asset in another
+//!   environment yields the same ID
+//!
+//! Existing IDs are never rewritten; the strategy only applies to entities
+//! created after it is configured.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Namespace used for deterministic (UUIDv5) entity IDs.
+///
+/// Changing this value changes every deterministic ID, so it is fixed for the
+/// lifetime of the format.
+pub const ID_NAMESPACE: Uuid = Uuid::from_u128(0x6f64_6d2d_6964_5f6e_8a3e_5d2c_1b47_9f60);
+
+/// How new entity IDs are generated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdStrategy {
+    /// Random UUIDv4
+    #[default]
+    #[serde(alias = "v4")]
+    Random,
+    /// Time-ordered UUIDv7
+    #[serde(alias = "v7", alias = "time_ordered")]
+    TimeOrdered,
+    /// Name-based UUIDv5 derived from (workspace, domain, kind, name)
+    #[serde(alias = "v5")]
+    Deterministic,
+}
+
+impl IdStrategy {
+    /// Parse a strategy from its name or UUID version (`v4`, `v7`, `v5`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace(['-', '_'], "").as_str() {
+            "random" | "v4" | "uuidv4" => Some(Self::Random),
+            "timeordered" | "time" | "v7" | "uuidv7" => Some(Self::TimeOrdered),
+            "deterministic" | "namebased" | "v5" | "uuidv5" => Some(Self::Deterministic),
+            _ => None,
+        }
+    }
+
+    /// Canonical name as written to `workspace.yaml`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Random => "random",
+            Self::TimeOrdered => "timeOrdered",
+            Self::Deterministic => "deterministic",
+        }
+    }
+
+    /// Whether this is the default strategy (used to keep serialized output unchanged)
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Generate an ID for an entity.
+    ///
+    /// `kind` distinguishes entity types (e.g. `domain`, `system`, `table`) so
+    /// that a domain and a table sharing a name do not collide. The workspace,
+    /// domain and name are only used by the deterministic strategy.
+    pub fn generate(&self, workspace: &str, domain: Option<&str>, kind: &str, name: &str) -> Uuid {
+        match self {
+            Self::Random => Uuid::new_v4(),
+            Self::TimeOrdered => Uuid::now_v7(),
+            Self::Deterministic => deterministic_id(workspace, domain, kind, name),
+        }
+    }
+}
+
+impl std::fmt::Display for IdStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An [`IdStrategy`] bound to the workspace whose entities it identifies
+///
+/// Passed to entity constructors (`new_with_generator`) and importers
+/// (`with_id_generator`) so that entities created outside of a
+/// [`Workspace`](super::Workspace) follow its strategy; see
+/// [`Workspace::id_generator`](super::Workspace::id_generator). The default
+/// generates random IDs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IdGenerator {
+    strategy: IdStrategy,
+    workspace: String,
+}
+
+impl IdGenerator {
+    /// Create a generator for the entities of a workspace
+    pub fn new(strategy: IdStrategy, workspace: impl Into<String>) -> Self {
+        Self {
+            strategy,
+            workspace: workspace.into(),
+        }
+    }
+
+    /// The strategy IDs are generated with
+    pub fn strategy(&self) -> IdStrategy {
+        self.strategy
+    }
+
+    /// Name of the workspace the deterministic strategy scopes IDs to
+    pub fn workspace(&self) -> &str {
+        &self.workspace
+    }
+
+    /// Generate an ID for an entity (see [`IdStrategy::generate`])
+    pub fn generate(&self, kind: &str, domain: Option<&str>, name: &str) -> Uuid {
+        self.strategy.generate(&self.workspace, domain, kind, name)
+    }
+}
+
+/// Derive the deterministic (UUIDv5) ID for an entity.
+///
+/// Components are trimmed and lower-cased before hashing so that cosmetic
+/// differences in casing do not produce different IDs.
+pub fn deterministic_id(workspace: &str, domain: Option<&str>, kind: &str, name: &str) -> Uuid {
+    let key = [workspace, domain.unwrap_or(""), kind, name]
+        .iter()
+        .map(|part| part.trim().to_lowercase())
+        .collect::<Vec<_>>()
+        .join("/");
+    Uuid::new_v5(&ID_NAMESPACE, key.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_strategy_names() {
+        assert_eq!(IdStrategy::parse("v4"), Some(IdStrategy::Random));
+        assert_eq!(
+            IdStrategy::parse("time-ordered"),
+            Some(IdStrategy::TimeOrdered)
+        );
+        assert_eq!(IdStrategy::parse("UUIDv7"), Some(IdStrategy::TimeOrdered));
+        assert_eq!(
+            IdStrategy::parse("deterministic"),
+            Some(IdStrategy::Deterministic)
+        );
+        assert_eq!(IdStrategy::parse("sequential"), None);
+        for strategy in [
+            IdStrategy::Random,
+            IdStrategy::TimeOrdered,
+            IdStrategy::Deterministic,
+        ] {
+            assert_eq!(IdStrategy::parse(strategy.name()), Some(strategy));
+        }
+    }
+
+    #[test]
+    fn test_generated_versions() {
+        let random = IdStrategy::Random.generate("ws", None, "domain", "finance");
+        let ordered = IdStrategy::TimeOrdered.generate("ws", None, "domain", "finance");
+        let named = IdStrategy::Deterministic.generate("ws", None, "domain", "finance");
+        assert_eq!(random.get_version_num(), 4);
+        assert_eq!(ordered.get_version_num(), 7);
+        assert_eq!(named.get_version_num(), 5);
+    }
+
+    #[test]
+    fn test_time_ordered_ids_sort_by_creation() {
+        let ids: Vec<Uuid> = (0..16)
+            .map(|_| IdStrategy::TimeOrdered.generate("ws", None, "table", "t"))
+            .collect();
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(ids, sorted);
+    }
+
+    #[test]
+    fn test_deterministic_ids_are_stable_and_scoped() {
+        let a = deterministic_id("Enterprise", Some("Finance"), "table", "orders");
+        let b = deterministic_id("enterprise ", Some("finance"), "table", "Orders");
+        assert_eq!(a, b);
+        assert_ne!(
+            a,
+            deterministic_id("enterprise", Some("sales"), "table", "orders")
+        );
+        assert_ne!(
+            a,
+            deterministic_id("enterprise", Some("finance"), "system", "orders")
+        );
+        assert_ne!(
+            a,
+            deterministic_id("staging", Some("finance"), "table", "orders")
+        );
+    }
+
+    #[test]
+    fn test_generator_scopes_ids_to_its_workspace() {
+        let generator = IdGenerator::new(IdStrategy::Deterministic, "enterprise");
+        assert_eq!(
+            generator.generate("table", Some("finance"), "orders"),
+            deterministic_id("enterprise", Some("finance"), "table", "orders")
+        );
+        let random = IdGenerator::default();
+        assert_eq!(random.strategy(), IdStrategy::Random);
+        assert_ne!(
+            random.generate("table", None, "orders"),
+            random.generate("table", None, "orders")
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip_and_aliases() {
+        let json = serde_json::to_string(&IdStrategy::TimeOrdered).unwrap();
+        assert_eq!(json, "\"timeOrdered\"");
+        let parsed: IdStrategy = serde_json::from_str("\"v5\"").unwrap();
+        assert_eq!(parsed, IdStrategy::Deterministic);
+    }
+}
@@ -3,29 +3,44 @@
 //! Defines core data structures used by the SDK for import/export operations.
 //! These models are simplified versions focused on the SDK's needs.
 
+pub mod binary;
 #[cfg(feature = "bpmn")]
 pub mod bpmn;
 pub mod cads;
+pub mod cdc;
+pub mod classification_rules;
+pub mod code_annotations;
 pub mod column;
 pub mod cross_domain;
 pub mod data_model;
 pub mod decision;
+pub mod definitions;
 #[cfg(feature = "dmn")]
 pub mod dmn;
 pub mod domain;
 pub mod domain_config;
 pub mod enums;
+pub mod fingerprint;
+pub mod id_strategy;
 pub mod knowledge;
+pub mod nested_type;
 pub mod odcs;
 pub mod odps;
 #[cfg(feature = "openapi")]
 pub mod openapi;
+pub mod physical_names;
 pub mod relationship;
 pub mod sketch;
+pub mod spatial;
 pub mod table;
 pub mod tag;
+pub mod tag_propagation;
+pub mod type_mapping;
+pub mod vector;
 pub mod workspace;
+pub mod workspace_template;
 
+pub use binary::BinaryType;
 #[cfg(feature = "bpmn")]
 pub use bpmn::{BPMNModel, BPMNModelFormat};
 pub use cads::{
@@ -37,10 +52,15 @@ pub use cads::{
     CADSRuntimeResources, CADSSLA, CADSSLAProperty, CADSStatus, CADSTeamMember,
     CADSValidationProfile, CADSValidationProfileAppliesTo,
 };
+pub use cdc::{CaptureMethod, CdcConfig, CdcViolation, validate_cdc};
+pub use classification_rules::{ClassificationEngine, ClassificationReport, ClassificationRule};
 pub use column::{
     AuthoritativeDefinition, Column, ForeignKey, LogicalTypeOptions, PropertyRelationship,
 };
-pub use cross_domain::{CrossDomainConfig, CrossDomainRelationshipRef, CrossDomainTableRef};
+pub use cross_domain::{
+    CrossDomainConfig, CrossDomainRelationshipRef, CrossDomainTableRef, CrossWorkspaceRef,
+    WorkspaceLocation,
+};
 pub use data_model::DataModel;
 #[cfg(feature = "dmn")]
 pub use dmn::{DMNModel, DMNModelFormat};
@@ -50,6 +70,8 @@ pub use domain::{
 };
 pub use domain_config::{DomainConfig, DomainOwner, ViewPosition};
 pub use enums::*;
+pub use id_strategy::{IdGenerator, IdStrategy, deterministic_id};
+pub use nested_type::{NestedType, StructField};
 pub use odps::{
     ODPSApiVersion, ODPSAuthoritativeDefinition, ODPSCustomProperty, ODPSDataProduct,
     ODPSDescription, ODPSInputContract, ODPSInputPort, ODPSManagementPort, ODPSOutputPort,
@@ -61,12 +83,21 @@ pub use relationship::{
     ConnectionHandle, ConnectionPoint, ETLJobMetadata, ForeignKeyDetails, Relationship,
     VisualMetadata,
 };
+pub use spatial::{SpatialKind, SpatialType};
 pub use table::{ContactDetails, Position, SlaProperty, Table};
 pub use tag::Tag;
+pub use type_mapping::{
+    DialectTypeMapping, SOURCE_TYPE_PROPERTY, TYPE_MAPPINGS_FILE, TypeMappingRegistry,
+};
+pub use vector::{DistanceMetric, VectorElement, VectorType};
 pub use workspace::{
     DomainReference, EnvironmentConnection, SharedResource, SystemReference, TableVisibility,
     TransformationLink, Workspace,
 };
+pub use workspace_template::{
+    NameCase, NamingConventions, ScaffoldFile, TemplateDefinition, TemplateError,
+    WorkspaceScaffold, WorkspaceTemplate,
+};
 
 // Decision and Knowledge models
 pub use decision::{
@@ -0,0 +1,216 @@
+//! Nested STRUCT, ARRAY and MAP data types
+//!
+//! Columns keep nested types as written, e.g. `STRUCT<street STRING, zip INT>`
+//! or `ARRAY<STRUCT<sku: STRING, qty INT64>>`. [`NestedType`] parses such a type
+//! into a tree so importers and exporters can map the scalar types inside it to
+//! a dialect's native types.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::nested_type::NestedType;
+//!
+//! let nested = NestedType::parse("ARRAY<STRUCT<sku: STRING, qty INT>>").unwrap();
+//! let bigquery = nested.map_scalars(&|t| if t == "INT" { "INT64".to_string() } else { t.to_string() });
+//! assert_eq!(bigquery.to_string(), "ARRAY<STRUCT<sku STRING, qty INT64>>");
+//! assert_eq!(NestedType::parse("VARCHAR(10)"), None);
+//! ```
+
+use std::fmt;
+
+/// A data type with nested STRUCT, ARRAY or MAP parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NestedType {
+    /// A scalar type, kept as written (e.g. `DECIMAL(10,2)`)
+    Scalar(String),
+    /// `STRUCT<name TYPE, ...>`
+    Struct(Vec<StructField>),
+    /// `ARRAY<TYPE>`
+    Array(Box<NestedType>),
+    /// `MAP<KEY, VALUE>`
+    Map(Box<NestedType>, Box<NestedType>),
+}
+
+/// A named field of a STRUCT type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructField {
+    /// Field name
+    pub name: String,
+    /// Field type
+    pub data_type: NestedType,
+}
+
+impl NestedType {
+    /// Parse a STRUCT, ARRAY or MAP type, returning `None` for scalar or malformed types
+    pub fn parse(data_type: &str) -> Option<Self> {
+        match Self::parse_any(data_type)? {
+            Self::Scalar(_) => None,
+            nested => Some(nested),
+        }
+    }
+
+    /// Parse any type, nested or scalar
+    fn parse_any(data_type: &str) -> Option<Self> {
+        let data_type = data_type.trim();
+        let Some((base, rest)) = data_type.split_once('<') else {
+            return (!data_type.is_empty() && !data_type.contains('>'))
+                .then(|| Self::Scalar(data_type.to_string()));
+        };
+        let inner = rest.trim_end().strip_suffix('>')?;
+        match base.trim().to_uppercase().as_str() {
+            "STRUCT" => {
+                let fields = split_top_level(inner)
+                    .into_iter()
+                    .filter(|f| !f.trim().is_empty())
+                    .map(|field| {
+                        let field = field.trim();
+                        let split = field.find(|c: char| c == ':' || c.is_whitespace())?;
+                        let name = field[..split].trim_matches('`').to_string();
+                        let data_type = Self::parse_any(field[split..].trim_start_matches(':'))?;
+                        Some(StructField { name, data_type })
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Self::Struct(fields))
+            }
+            "ARRAY" => Some(Self::Array(Box::new(Self::parse_any(inner)?))),
+            "MAP" => {
+                let parts = split_top_level(inner);
+                let [key, value] = parts.as_slice() else {
+                    return None;
+                };
+                Some(Self::Map(
+                    Box::new(Self::parse_any(key)?),
+                    Box::new(Self::parse_any(value)?),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Replace every scalar type with `f(scalar)`
+    pub fn map_scalars(&self, f: &dyn Fn(&str) -> String) -> Self {
+        match self {
+            Self::Scalar(data_type) => Self::Scalar(f(data_type)),
+            Self::Struct(fields) => Self::Struct(
+                fields
+                    .iter()
+                    .map(|field| StructField {
+                        name: field.name.clone(),
+                        data_type: field.data_type.map_scalars(f),
+                    })
+                    .collect(),
+            ),
+            Self::Array(element) => Self::Array(Box::new(element.map_scalars(f))),
+            Self::Map(key, value) => {
+                Self::Map(Box::new(key.map_scalars(f)), Box::new(value.map_scalars(f)))
+            }
+        }
+    }
+
+    /// Replace MAP types with an array of key/value records
+    ///
+    /// For dialects without a map type, such as BigQuery.
+    pub fn maps_as_entries(&self) -> Self {
+        match self {
+            Self::Scalar(_) => self.clone(),
+            Self::Struct(fields) => Self::Struct(
+                fields
+                    .iter()
+                    .map(|field| StructField {
+                        name: field.name.clone(),
+                        data_type: field.data_type.maps_as_entries(),
+                    })
+                    .collect(),
+            ),
+            Self::Array(element) => Self::Array(Box::new(element.maps_as_entries())),
+            Self::Map(key, value) => Self::Array(Box::new(Self::Struct(vec![
+                StructField {
+                    name: "key".to_string(),
+                    data_type: key.maps_as_entries(),
+                },
+                StructField {
+                    name: "value".to_string(),
+                    data_type: value.maps_as_entries(),
+                },
+            ]))),
+        }
+    }
+}
+
+impl fmt::Display for NestedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scalar(data_type) => write!(f, "{}", data_type),
+            Self::Struct(fields) => {
+                write!(f, "STRUCT<")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} {}", field.name, field.data_type)?;
+                }
+                write!(f, ">")
+            }
+            Self::Array(element) => write!(f, "ARRAY<{}>", element),
+            Self::Map(key, value) => write!(f, "MAP<{}, {}>", key, value),
+        }
+    }
+}
+
+/// Split on commas outside of `<...>` and `(...)`
+fn split_top_level(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nested_types() {
+        let nested =
+            NestedType::parse("STRUCT<id INT64, amount: DECIMAL(10,2), tags ARRAY<STRING>>")
+                .unwrap();
+        let NestedType::Struct(fields) = &nested else {
+            panic!("expected a struct");
+        };
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[1].name, "amount");
+        assert_eq!(
+            fields[1].data_type,
+            NestedType::Scalar("DECIMAL(10,2)".to_string())
+        );
+        assert_eq!(
+            nested.to_string(),
+            "STRUCT<id INT64, amount DECIMAL(10,2), tags ARRAY<STRING>>"
+        );
+
+        assert_eq!(NestedType::parse("STRING"), None);
+        assert_eq!(NestedType::parse("STRUCT<id INT"), None);
+        assert_eq!(NestedType::parse("MAP<STRING>"), None);
+    }
+
+    #[test]
+    fn test_maps_as_entries() {
+        let nested = NestedType::parse("MAP<STRING, ARRAY<INT>>").unwrap();
+        assert_eq!(
+            nested.maps_as_entries().to_string(),
+            "ARRAY<STRUCT<key STRING, value ARRAY<INT>>>"
+        );
+    }
+}
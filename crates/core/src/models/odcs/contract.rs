@@ -7,6 +7,7 @@ use super::supporting::{
     AuthoritativeDefinition, CustomProperty, Description, Link, Price, QualityRule, Role, Server,
     ServiceLevel, Support, Team, Terms,
 };
+use crate::models::id_strategy::IdGenerator;
 use serde::{Deserialize, Serialize};
 
 /// ODCSContract - the root data contract document (ODCS v3.1.0)
@@ -166,6 +167,19 @@ impl ODCSContract {
         }
     }
 
+    /// Create a new contract whose ID follows a workspace's ID strategy
+    ///
+    /// Deterministic IDs are derived from the contract name.
+    pub fn new_with_generator(
+        name: impl Into<String>,
+        version: impl Into<String>,
+        ids: &IdGenerator,
+    ) -> Self {
+        let name = name.into();
+        let id = ids.generate("contract", None, &name);
+        Self::new_with_id(id.to_string(), name, version)
+    }
+
     /// Create a new contract with a specific ID
     pub fn new_with_id(
         id: impl Into<String>,
@@ -352,6 +366,14 @@ impl ODCSContract {
     pub fn description_string(&self) -> Option<String> {
         self.description.as_ref().map(|d| d.as_string())
     }
+
+    /// Content fingerprint of this contract (see [`crate::models::fingerprint`])
+    ///
+    /// Ignores the contract creation timestamp and the order of schemas,
+    /// properties, tags and other lists.
+    pub fn fingerprint(&self) -> String {
+        crate::models::fingerprint::fingerprint(self, &["contractCreatedTs"])
+    }
 }
 
 #[cfg(test)]
@@ -499,4 +521,23 @@ mod tests {
             Some("Store customer information".to_string())
         );
     }
+
+    #[test]
+    fn test_contract_fingerprint() {
+        let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+            SchemaObject::new("orders").with_properties(vec![
+                Property::new("id", "integer").with_primary_key(true),
+                Property::new("total", "number"),
+            ]),
+        );
+
+        let mut reordered = contract
+            .clone()
+            .with_contract_created_ts("2025-01-01T00:00:00Z");
+        reordered.schema[0].properties.reverse();
+        assert_eq!(contract.fingerprint(), reordered.fingerprint());
+
+        reordered.schema[0].properties[0].logical_type = "string".to_string();
+        assert_ne!(contract.fingerprint(), reordered.fingerprint());
+    }
 }
@@ -39,6 +39,14 @@ pub fn map_data_type_to_logical_type(data_type: &str) -> (String, bool) {
     if upper == "STRUCT" || upper == "OBJECT" || upper.starts_with("STRUCT<") {
         return ("object".to_string(), false);
     }
+    // Spatial values are GeoJSON geometry objects
+    if crate::models::SpatialType::parse(data_type).is_some() {
+        return ("object".to_string(), false);
+    }
+    // Embedding vectors are fixed-length numeric arrays
+    if crate::models::VectorType::parse(data_type).is_some() {
+        return ("array".to_string(), false);
+    }
 
     // Map to ODCS logical types
     if upper.contains("INT") || upper == "BIGINT" || upper == "SMALLINT" || upper == "TINYINT" {
@@ -119,6 +127,9 @@ impl From<&Property> for Column {
                     exclusive_maximum: opts.exclusive_maximum.clone(),
                     precision: opts.precision,
                     scale: opts.scale,
+                    srid: opts.srid,
+                    dimension: opts.dimension,
+                    distance_metric: opts.distance_metric.clone(),
                 }
             }),
             primary_key: prop.primary_key,
@@ -235,6 +246,9 @@ impl From<&Column> for Property {
                     exclusive_maximum: opts.exclusive_maximum.clone(),
                     precision: opts.precision,
                     scale: opts.scale,
+                    srid: opts.srid,
+                    dimension: opts.dimension,
+                    distance_metric: opts.distance_metric.clone(),
                 }
             }),
             required: !col.nullable, // Column uses nullable, ODCS uses required (inverse)
@@ -1130,6 +1144,9 @@ fn property_to_column_data(prop: &Property) -> ColumnData {
                 exclusive_maximum: opts.exclusive_maximum.clone(),
                 precision: opts.precision,
                 scale: opts.scale,
+                srid: opts.srid,
+                dimension: opts.dimension,
+                distance_metric: opts.distance_metric.clone(),
             }
         }),
         primary_key: prop.primary_key,
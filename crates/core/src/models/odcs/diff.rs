@@ -0,0 +1,732 @@
+//! Contract diff
+//!
+//! [`ContractDiff::compare`] lists what changed between two versions of a
+//! contract: schema objects, properties (including nested properties and array
+//! items), quality rules and service levels. Schema objects and properties are
+//! matched by `name`, quality rules by `name` (unnamed rules by content) and
+//! service levels by `property` and `element`, so reordering is not a change.
+//!
+//! Each change is classified for consumers of the contract. A change is
+//! [`Severity::Breaking`] when it removes or weakens something consumers rely on:
+//!
+//! - A schema object, property, named quality rule or service level is removed
+//! - `logicalType`, `physicalType` or `physicalName` changes
+//! - `required`, `primaryKey` or `unique` is relaxed
+//! - A `logicalTypeOptions` bound is loosened, or any other option changes or is removed
+//! - Enum values are added, or the enumeration is dropped
+//! - A quality rule bound is loosened or `mustBe`/`mustNotBe` changes
+//! - A service level value changes
+//!
+//! Additions, tightened constraints and documentation changes are
+//! [`Severity::NonBreaking`].
+//!
+//! ```rust
+//! use data_modelling_core::models::odcs::diff::{ContractDiff, Severity};
+//! use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+//!
+//! let old = ODCSContract::new("orders", "1.0.0").with_schema(
+//!     SchemaObject::new("orders").with_properties(vec![
+//!         Property::new("id", "integer").with_required(true),
+//!         Property::new("note", "string"),
+//!     ]),
+//! );
+//! let new = ODCSContract::new("orders", "2.0.0").with_schema(
+//!     SchemaObject::new("orders").with_properties(vec![
+//!         Property::new("id", "string").with_required(true),
+//!         Property::new("note", "string").with_description("Free text"),
+//!     ]),
+//! );
+//!
+//! let diff = ContractDiff::compare(&old, &new);
+//! assert!(diff.has_breaking_changes());
+//! let breaking: Vec<_> = diff.breaking().map(|c| c.path.as_str()).collect();
+//! assert_eq!(breaking, ["orders.id.logicalType"]);
+//! assert_eq!(diff.non_breaking().count(), 1);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::ODCSContract;
+
+/// Schema and property fields whose change moves or retypes the data
+const IDENTITY_FIELDS: &[&str] = &["logicalType", "physicalType", "physicalName"];
+
+/// Property flags that must not be relaxed
+const GUARANTEE_FLAGS: &[&str] = &["required", "primaryKey", "unique"];
+
+/// `logicalTypeOptions` lower and upper bounds
+const LOWER_BOUNDS: &[&str] = &["minLength", "minimum", "exclusiveMinimum"];
+const UPPER_BOUNDS: &[&str] = &["maxLength", "maximum", "exclusiveMaximum", "precision"];
+
+/// Quality rule lower and upper bounds
+const RULE_LOWER_BOUNDS: &[&str] = &["mustBeGreaterThan", "mustBeGreaterThanOrEqual"];
+const RULE_UPPER_BOUNDS: &[&str] = &["mustBeLessThan", "mustBeLessThanOrEqual"];
+
+/// Part of the contract a change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffElement {
+    Schema,
+    Property,
+    Quality,
+    ServiceLevel,
+}
+
+/// Kind of change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// Impact of a change on consumers of the contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    Breaking,
+    NonBreaking,
+}
+
+/// A single change between two versions of a contract
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiffEntry {
+    pub element: DiffElement,
+    pub kind: DiffKind,
+    pub severity: Severity,
+    /// Location of the change (e.g. `orders.customer.city.logicalType`,
+    /// `orders.quality.row_count` or `serviceLevels.latency`)
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+    /// Description of the change
+    pub message: String,
+}
+
+impl std::fmt::Display for DiffEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Structured differences between two versions of a contract
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContractDiff {
+    /// Changes in document order of the old contract, followed by additions
+    pub changes: Vec<DiffEntry>,
+}
+
+impl ContractDiff {
+    /// Compare two versions of a contract
+    pub fn compare(old: &ODCSContract, new: &ODCSContract) -> Self {
+        Self::compare_values(
+            &serde_json::to_value(old).unwrap_or_default(),
+            &serde_json::to_value(new).unwrap_or_default(),
+        )
+    }
+
+    /// Compare two contracts given as ODCS JSON documents
+    pub fn compare_values(old: &Value, new: &Value) -> Self {
+        let mut diff = Self::default();
+        diff.compare_quality(old, new, "contract");
+        diff.compare_service_levels(old, new);
+        diff.compare_schemas(old, new);
+        diff
+    }
+
+    /// Returns true when the contracts do not differ
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns true when any change is breaking
+    pub fn has_breaking_changes(&self) -> bool {
+        self.breaking().next().is_some()
+    }
+
+    /// Breaking changes
+    pub fn breaking(&self) -> impl Iterator<Item = &DiffEntry> {
+        self.changes
+            .iter()
+            .filter(|change| change.severity == Severity::Breaking)
+    }
+
+    /// Non-breaking changes
+    pub fn non_breaking(&self) -> impl Iterator<Item = &DiffEntry> {
+        self.changes
+            .iter()
+            .filter(|change| change.severity == Severity::NonBreaking)
+    }
+
+    fn compare_schemas(&mut self, old: &Value, new: &Value) {
+        for (name, old_schema, new_schema) in matched(old, new, "schema") {
+            match (old_schema, new_schema) {
+                (Some(before), None) => self.removed(DiffElement::Schema, &name, before),
+                (None, Some(after)) => self.added(DiffElement::Schema, &name, after),
+                (Some(before), Some(after)) => {
+                    self.compare_fields(
+                        DiffElement::Schema,
+                        before,
+                        after,
+                        &name,
+                        &["id", "name", "properties", "quality"],
+                    );
+                    self.compare_quality(before, after, &name);
+                    self.compare_properties(before, after, &name);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn compare_properties(&mut self, old: &Value, new: &Value, path: &str) {
+        for (name, old_property, new_property) in matched(old, new, "properties") {
+            let path = format!("{}.{}", path, name);
+            match (old_property, new_property) {
+                (Some(before), None) => self.removed(DiffElement::Property, &path, before),
+                (None, Some(after)) => self.added(DiffElement::Property, &path, after),
+                (Some(before), Some(after)) => self.compare_property(before, after, &path),
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn compare_property(&mut self, old: &Value, new: &Value, path: &str) {
+        self.compare_fields(
+            DiffElement::Property,
+            old,
+            new,
+            path,
+            &[
+                "id",
+                "name",
+                "properties",
+                "items",
+                "quality",
+                "logicalTypeOptions",
+                "enumValues",
+            ],
+        );
+        self.compare_logical_type_options(old, new, path);
+        self.compare_enum_values(old, new, path);
+        self.compare_quality(old, new, path);
+        self.compare_properties(old, new, path);
+
+        let items_path = format!("{}.items", path);
+        match (old.get("items"), new.get("items")) {
+            (Some(before), Some(after)) => self.compare_property(before, after, &items_path),
+            (before, after) if before != after => self.push(
+                DiffElement::Property,
+                DiffKind::Changed,
+                Severity::Breaking,
+                &items_path,
+                before,
+                after,
+                "array items changed".to_string(),
+            ),
+            _ => {}
+        }
+    }
+
+    /// Compare the plain fields of two objects, skipping `ignored` keys
+    fn compare_fields(
+        &mut self,
+        element: DiffElement,
+        old: &Value,
+        new: &Value,
+        path: &str,
+        ignored: &[&str],
+    ) {
+        for field in keys(old, new) {
+            if ignored.contains(&field) {
+                continue;
+            }
+            let (before, after) = (old.get(field), new.get(field));
+            if before == after {
+                continue;
+            }
+            let severity = if IDENTITY_FIELDS.contains(&field) {
+                Severity::Breaking
+            } else if GUARANTEE_FLAGS.contains(&field) {
+                relaxed(flag(before) && !flag(after))
+            } else {
+                Severity::NonBreaking
+            };
+            self.push(
+                element,
+                DiffKind::Changed,
+                severity,
+                &format!("{}.{}", path, field),
+                before,
+                after,
+                change_message(before, after),
+            );
+        }
+    }
+
+    fn compare_logical_type_options(&mut self, old: &Value, new: &Value, path: &str) {
+        let null = Value::Null;
+        let old_options = old.get("logicalTypeOptions").unwrap_or(&null);
+        let new_options = new.get("logicalTypeOptions").unwrap_or(&null);
+        for option in keys(old_options, new_options) {
+            let (before, after) = (old_options.get(option), new_options.get(option));
+            if before == after {
+                continue;
+            }
+            let severity = match (before, after) {
+                (None, Some(_)) => Severity::NonBreaking,
+                (Some(_), None) => Severity::Breaking,
+                _ => relaxed(loosened(option, before, after, LOWER_BOUNDS, UPPER_BOUNDS)),
+            };
+            self.push(
+                DiffElement::Property,
+                DiffKind::Changed,
+                severity,
+                &format!("{}.logicalTypeOptions.{}", path, option),
+                before,
+                after,
+                change_message(before, after),
+            );
+        }
+    }
+
+    fn compare_enum_values(&mut self, old: &Value, new: &Value, path: &str) {
+        let (before, after) = (items(old, "enumValues"), items(new, "enumValues"));
+        if before == after {
+            return;
+        }
+        let added: Vec<&Value> = after.iter().filter(|v| !before.contains(v)).collect();
+        let removed: Vec<&Value> = before.iter().filter(|v| !after.contains(v)).collect();
+        let (severity, message) = if before.is_empty() {
+            (Severity::NonBreaking, "enumeration added".to_string())
+        } else if after.is_empty() {
+            (Severity::Breaking, "enumeration removed".to_string())
+        } else {
+            let mut parts = Vec::new();
+            if !added.is_empty() {
+                parts.push(format!("added {}", join(&added)));
+            }
+            if !removed.is_empty() {
+                parts.push(format!("removed {}", join(&removed)));
+            }
+            if parts.is_empty() {
+                parts.push("reordered".to_string());
+            }
+            (relaxed(!added.is_empty()), parts.join(", "))
+        };
+        self.push(
+            DiffElement::Property,
+            DiffKind::Changed,
+            severity,
+            &format!("{}.enumValues", path),
+            old.get("enumValues"),
+            new.get("enumValues"),
+            message,
+        );
+    }
+
+    /// Compare quality rules: named rules by `name`, unnamed rules by content
+    fn compare_quality(&mut self, old: &Value, new: &Value, path: &str) {
+        let path = format!("{}.quality", path);
+        for (name, old_rule, new_rule) in matched(old, new, "quality") {
+            let rule_path = format!("{}.{}", path, name);
+            match (old_rule, new_rule) {
+                (Some(before), None) => self.removed(DiffElement::Quality, &rule_path, before),
+                (None, Some(after)) => self.added(DiffElement::Quality, &rule_path, after),
+                (Some(before), Some(after)) if before != after => {
+                    let weakened = keys(before, after).into_iter().any(|bound| {
+                        let (old_bound, new_bound) = (before.get(bound), after.get(bound));
+                        old_bound.is_some()
+                            && old_bound != new_bound
+                            && match bound {
+                                "mustBe" | "mustNotBe" => true,
+                                _ if RULE_LOWER_BOUNDS.contains(&bound)
+                                    || RULE_UPPER_BOUNDS.contains(&bound) =>
+                                {
+                                    loosened(
+                                        bound,
+                                        old_bound,
+                                        new_bound,
+                                        RULE_LOWER_BOUNDS,
+                                        RULE_UPPER_BOUNDS,
+                                    )
+                                }
+                                _ => false,
+                            }
+                    });
+                    self.push(
+                        DiffElement::Quality,
+                        DiffKind::Changed,
+                        relaxed(weakened),
+                        &rule_path,
+                        Some(before),
+                        Some(after),
+                        if weakened {
+                            "quality rule loosened".to_string()
+                        } else {
+                            "quality rule changed".to_string()
+                        },
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let unnamed = |value: &Value| -> Vec<Value> {
+            items(value, "quality")
+                .iter()
+                .filter(|rule| rule.get("name").and_then(Value::as_str).is_none())
+                .cloned()
+                .collect()
+        };
+        let (before, after) = (unnamed(old), unnamed(new));
+        for rule in before.iter().filter(|rule| !after.contains(rule)) {
+            self.removed(DiffElement::Quality, &path, rule);
+        }
+        for rule in after.iter().filter(|rule| !before.contains(rule)) {
+            self.added(DiffElement::Quality, &path, rule);
+        }
+    }
+
+    /// Compare service levels by `property` and `element`
+    fn compare_service_levels(&mut self, old: &Value, new: &Value) {
+        let levels = |value: &Value| -> Vec<(String, Value)> {
+            items(value, "serviceLevels")
+                .iter()
+                .filter_map(|level| {
+                    let property = level.get("property").and_then(Value::as_str)?;
+                    let key = match level.get("element").and_then(Value::as_str) {
+                        Some(element) => format!("serviceLevels.{}[{}]", property, element),
+                        None => format!("serviceLevels.{}", property),
+                    };
+                    Some((key, level.clone()))
+                })
+                .collect()
+        };
+        let (before, after) = (levels(old), levels(new));
+        let find = |entries: &[(String, Value)], key: &str| {
+            entries
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, level)| level.clone())
+        };
+
+        for (path, level) in &before {
+            match find(&after, path) {
+                None => self.removed(DiffElement::ServiceLevel, path, level),
+                Some(other) if &other != level => {
+                    let value_changed = level.get("value") != other.get("value")
+                        || level.get("unit") != other.get("unit");
+                    self.push(
+                        DiffElement::ServiceLevel,
+                        DiffKind::Changed,
+                        relaxed(value_changed),
+                        path,
+                        Some(level),
+                        Some(&other),
+                        if value_changed {
+                            "service level value changed".to_string()
+                        } else {
+                            "service level changed".to_string()
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        for (path, level) in &after {
+            if find(&before, path).is_none() {
+                self.added(DiffElement::ServiceLevel, path, level);
+            }
+        }
+    }
+
+    fn added(&mut self, element: DiffElement, path: &str, value: &Value) {
+        self.push(
+            element,
+            DiffKind::Added,
+            Severity::NonBreaking,
+            path,
+            None,
+            Some(value),
+            format!("{} added", element_name(element)),
+        );
+    }
+
+    fn removed(&mut self, element: DiffElement, path: &str, value: &Value) {
+        self.push(
+            element,
+            DiffKind::Removed,
+            Severity::Breaking,
+            path,
+            Some(value),
+            None,
+            format!("{} removed", element_name(element)),
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn push(
+        &mut self,
+        element: DiffElement,
+        kind: DiffKind,
+        severity: Severity,
+        path: &str,
+        before: Option<&Value>,
+        after: Option<&Value>,
+        message: String,
+    ) {
+        self.changes.push(DiffEntry {
+            element,
+            kind,
+            severity,
+            path: path.to_string(),
+            before: before.cloned(),
+            after: after.cloned(),
+            message,
+        });
+    }
+}
+
+/// Pair the named entries of an array field, old entries first
+fn matched<'a>(
+    old: &'a Value,
+    new: &'a Value,
+    key: &str,
+) -> Vec<(String, Option<&'a Value>, Option<&'a Value>)> {
+    let named = |value: &'a Value| -> Vec<(&'a str, &'a Value)> {
+        items(value, key)
+            .iter()
+            .filter_map(|entry| Some((entry.get("name")?.as_str()?, entry)))
+            .collect()
+    };
+    let (before, after) = (named(old), named(new));
+    let find = |entries: &[(&str, &'a Value)], name: &str| {
+        entries
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, entry)| *entry)
+    };
+
+    let mut pairs: Vec<_> = before
+        .iter()
+        .map(|(name, entry)| (name.to_string(), Some(*entry), find(&after, name)))
+        .collect();
+    pairs.extend(
+        after
+            .iter()
+            .filter(|(name, _)| find(&before, name).is_none())
+            .map(|(name, entry)| (name.to_string(), None, Some(*entry))),
+    );
+    pairs
+}
+
+/// Field names of two objects, in order of first appearance
+fn keys<'a>(old: &'a Value, new: &'a Value) -> Vec<&'a str> {
+    let mut keys: Vec<&str> = Vec::new();
+    for value in [old, new] {
+        for key in value.as_object().into_iter().flat_map(|o| o.keys()) {
+            if !keys.contains(&key.as_str()) {
+                keys.push(key);
+            }
+        }
+    }
+    keys
+}
+
+/// Whether a changed bound or exact constraint admits more values than before
+fn loosened(
+    field: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    lower: &[&str],
+    upper: &[&str],
+) -> bool {
+    let old = before.and_then(Value::as_f64);
+    let new = after.and_then(Value::as_f64);
+    match (old, new) {
+        (Some(old), Some(new)) if lower.contains(&field) => new < old,
+        (Some(old), Some(new)) if upper.contains(&field) => new > old,
+        _ => before.is_some(),
+    }
+}
+
+fn relaxed(breaking: bool) -> Severity {
+    if breaking {
+        Severity::Breaking
+    } else {
+        Severity::NonBreaking
+    }
+}
+
+fn flag(value: Option<&Value>) -> bool {
+    value.and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn items<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn change_message(before: Option<&Value>, after: Option<&Value>) -> String {
+    match (before, after) {
+        (None, Some(after)) => format!("set to {}", after),
+        (Some(before), None) => format!("{} removed", before),
+        (Some(before), Some(after)) => format!("changed from {} to {}", before, after),
+        (None, None) => "unchanged".to_string(),
+    }
+}
+
+fn join(values: &[&Value]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn element_name(element: DiffElement) -> &'static str {
+    match element {
+        DiffElement::Schema => "schema object",
+        DiffElement::Property => "property",
+        DiffElement::Quality => "quality rule",
+        DiffElement::ServiceLevel => "service level",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::{Property, QualityRule, SchemaObject, ServiceLevel};
+    use serde_json::json;
+
+    fn contract(properties: Vec<Property>) -> ODCSContract {
+        ODCSContract::new("orders", "1.0.0")
+            .with_schema(SchemaObject::new("orders").with_properties(properties))
+    }
+
+    fn paths<'a>(changes: impl Iterator<Item = &'a DiffEntry>) -> Vec<&'a str> {
+        changes.map(|change| change.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_identical_contracts() {
+        let old = contract(vec![Property::new("id", "integer")]);
+        assert!(ContractDiff::compare(&old, &old.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_schema_and_property_changes() {
+        let old = contract(vec![
+            Property::new("id", "integer").with_required(true),
+            Property::new("status", "string").with_enum_values(vec!["open".into()]),
+            Property::new("legacy", "string"),
+            Property::new("address", "object")
+                .with_nested_properties(vec![Property::new("city", "string")]),
+        ]);
+        let mut new = contract(vec![
+            Property::new("address", "object").with_nested_properties(vec![
+                Property::new("city", "string").with_description("City name"),
+                Property::new("zip", "string"),
+            ]),
+            Property::new("id", "integer"),
+            Property::new("status", "string")
+                .with_enum_values(vec!["open".into(), "closed".into()]),
+        ]);
+        new.schema.push(SchemaObject::new("customers"));
+
+        let diff = ContractDiff::compare(&old, &new);
+        assert_eq!(
+            paths(diff.breaking()),
+            [
+                "orders.id.required",
+                "orders.status.enumValues",
+                "orders.legacy"
+            ]
+        );
+        assert_eq!(
+            paths(diff.non_breaking()),
+            [
+                "orders.address.city.description",
+                "orders.address.zip",
+                "customers"
+            ]
+        );
+        let status = &diff.changes[1];
+        assert_eq!(status.message, "added \"closed\"");
+    }
+
+    #[test]
+    fn test_logical_type_options_bounds() {
+        let mut old = Property::new("code", "string");
+        old.logical_type_options = Some(serde_json::from_value(json!({"maxLength": 10})).unwrap());
+        let mut tightened = old.clone();
+        tightened.logical_type_options =
+            Some(serde_json::from_value(json!({"maxLength": 5, "pattern": "^[A-Z]+$"})).unwrap());
+
+        let diff = ContractDiff::compare(
+            &contract(vec![old.clone()]),
+            &contract(vec![tightened.clone()]),
+        );
+        assert!(!diff.has_breaking_changes());
+        assert_eq!(diff.changes.len(), 2);
+
+        let diff = ContractDiff::compare(&contract(vec![tightened]), &contract(vec![old]));
+        assert_eq!(
+            paths(diff.breaking()),
+            [
+                "orders.code.logicalTypeOptions.maxLength",
+                "orders.code.logicalTypeOptions.pattern"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quality_and_service_levels() {
+        let rule = |bound: i64| -> QualityRule {
+            serde_json::from_value(
+                json!({"name": "row_count", "metric": "rowCount", "mustBeGreaterThan": bound}),
+            )
+            .unwrap()
+        };
+        let level = |value: i64| -> ServiceLevel {
+            serde_json::from_value(json!({"property": "latency", "value": value, "unit": "h"}))
+                .unwrap()
+        };
+
+        let mut old = contract(vec![]);
+        old.quality = vec![rule(100)];
+        old.service_levels = vec![level(4)];
+        let mut new = contract(vec![]);
+        new.quality = vec![rule(10)];
+        new.service_levels = vec![
+            level(2),
+            serde_json::from_value(json!({"property": "retention", "value": 3, "unit": "y"}))
+                .unwrap(),
+        ];
+
+        let diff = ContractDiff::compare(&old, &new);
+        assert_eq!(
+            paths(diff.breaking()),
+            ["contract.quality.row_count", "serviceLevels.latency"]
+        );
+        assert_eq!(paths(diff.non_breaking()), ["serviceLevels.retention"]);
+        assert_eq!(diff.changes[0].message, "quality rule loosened");
+
+        // Tightening the rule is not breaking
+        let diff = ContractDiff::compare(&new, &{
+            let mut stricter = new.clone();
+            stricter.quality = vec![rule(50)];
+            stricter
+        });
+        assert_eq!(diff.changes.len(), 1);
+        assert!(!diff.has_breaking_changes());
+    }
+}
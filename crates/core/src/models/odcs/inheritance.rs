@@ -0,0 +1,710 @@
+//! Contract inheritance
+//!
+//! Families of near-identical feeds (one contract per region, per tenant, per
+//! source system) usually share most of their schema, quality rules and terms.
+//! A contract can declare a base contract in the `extends` custom property and
+//! only state what differs:
+//!
+//! ```yaml
+//! apiVersion: v3.1.0
+//! kind: DataContract
+//! id: orders-emea
+//! version: 1.0.0
+//! name: orders_emea
+//! customProperties:
+//!   - property: extends
+//!     value: orders-base
+//! schema:
+//!   - name: orders
+//!     properties:
+//!       - name: vat_number
+//!         logicalType: string
+//! ```
+//!
+//! [`ContractResolver`] merges the chain of base contracts into the effective
+//! contract:
+//!
+//! - Schema objects and properties are merged by `name`, custom properties by
+//!   `property`, servers by `server`, roles by `role`, service levels by
+//!   `property` and named quality rules by `name`
+//! - Tags, authoritative definitions and unnamed quality rules are unioned
+//! - Nested objects (terms, team, support, description, ...) are merged key by key
+//! - Any other value set by the child replaces the inherited one
+//!
+//! `id`, `name`, `version` and `contractCreatedTs` are never inherited.
+//!
+//! [`ContractResolver::validate`] checks that the child does not weaken the
+//! constraints it inherits, e.g. by making a required property optional or
+//! widening `maxLength`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::odcs::inheritance::ContractResolver;
+//! use serde_json::json;
+//!
+//! let mut resolver = ContractResolver::new();
+//! resolver.insert("orders-base", json!({
+//!     "id": "orders-base",
+//!     "schema": [{"name": "orders", "properties": [
+//!         {"name": "id", "logicalType": "string", "required": true}
+//!     ]}]
+//! }));
+//!
+//! let child = json!({
+//!     "id": "orders-emea",
+//!     "customProperties": [{"property": "extends", "value": "orders-base"}],
+//!     "schema": [{"name": "orders", "properties": [
+//!         {"name": "vat_number", "logicalType": "string"}
+//!     ]}]
+//! });
+//! let effective = resolver.resolve(&child).unwrap();
+//! assert_eq!(effective["schema"][0]["properties"].as_array().unwrap().len(), 2);
+//! assert!(resolver.validate(&child).unwrap().is_empty());
+//! ```
+
+use super::ODCSContract;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Custom property naming the base contract of a contract
+pub const EXTENDS_PROPERTY: &str = "extends";
+
+/// Top-level fields that identify a contract and are never inherited
+const NOT_INHERITED: &[&str] = &["id", "name", "version", "contractCreatedTs"];
+
+/// Arrays whose entries are unioned rather than replaced
+const UNIONED: &[&str] = &["tags", "authoritativeDefinitions"];
+
+/// An override that weakens a constraint inherited from the base contract
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InheritanceViolation {
+    /// Location of the constraint (e.g. `orders.customer_id.required`)
+    pub path: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl std::fmt::Display for InheritanceViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Base contracts available for resolving `extends` references
+///
+/// Contracts are looked up by the key they were registered under: their `id`,
+/// and for [`ContractResolver::insert_file`] also their file name and stem.
+#[derive(Debug, Clone, Default)]
+pub struct ContractResolver {
+    contracts: BTreeMap<String, Value>,
+}
+
+impl ContractResolver {
+    /// Create an empty resolver
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a contract document under a key
+    pub fn insert(&mut self, key: impl Into<String>, contract: Value) -> &mut Self {
+        self.contracts.insert(key.into(), contract);
+        self
+    }
+
+    /// Register a contract under its `id`
+    pub fn insert_contract(&mut self, contract: &ODCSContract) -> Result<&mut Self, String> {
+        let document = serde_json::to_value(contract)
+            .map_err(|e| format!("Failed to serialize contract '{}': {}", contract.id, e))?;
+        Ok(self.insert(contract.id.clone(), document))
+    }
+
+    /// Parse an ODCS YAML document and register it under its `id`
+    pub fn insert_yaml(&mut self, yaml_content: &str) -> Result<&mut Self, String> {
+        let document = parse_yaml(yaml_content)?;
+        let id = document_id(&document)
+            .ok_or_else(|| "Base contract has no 'id'".to_string())?
+            .to_string();
+        Ok(self.insert(id, document))
+    }
+
+    /// Parse a contract file and register it under its `id`, file name and stem
+    ///
+    /// A contract at `contracts/orders-base.odcs.yaml` can then be extended as
+    /// `orders-base.odcs.yaml` or `orders-base`.
+    pub fn insert_file(&mut self, path: &str, yaml_content: &str) -> Result<&mut Self, String> {
+        let document = parse_yaml(yaml_content).map_err(|e| format!("{}: {}", path, e))?;
+        let file_name = Path::new(path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(path)
+            .to_string();
+        let stem = file_name
+            .trim_end_matches(".yaml")
+            .trim_end_matches(".yml")
+            .trim_end_matches(".odcs")
+            .to_string();
+
+        if let Some(id) = document_id(&document) {
+            self.contracts.insert(id.to_string(), document.clone());
+        }
+        self.contracts.insert(stem, document.clone());
+        Ok(self.insert(file_name, document))
+    }
+
+    /// Check whether a base contract is registered under a key
+    pub fn contains(&self, key: &str) -> bool {
+        self.contracts.contains_key(key)
+    }
+
+    /// Number of registered keys
+    pub fn len(&self) -> usize {
+        self.contracts.len()
+    }
+
+    /// Check whether no base contracts are registered
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty()
+    }
+
+    /// Produce the effective contract by merging the chain of base contracts
+    ///
+    /// Contracts without an `extends` custom property are returned unchanged.
+    pub fn resolve(&self, contract: &Value) -> Result<Value, String> {
+        self.resolve_chain(contract, &mut Vec::new())
+    }
+
+    /// Produce the effective contract of an ODCS YAML document
+    pub fn resolve_yaml(&self, yaml_content: &str) -> Result<ODCSContract, String> {
+        let document = parse_yaml(yaml_content)?;
+        to_contract(self.resolve(&document)?)
+    }
+
+    /// Produce the effective contract of a parsed contract
+    pub fn resolve_contract(&self, contract: &ODCSContract) -> Result<ODCSContract, String> {
+        let document = serde_json::to_value(contract)
+            .map_err(|e| format!("Failed to serialize contract '{}': {}", contract.id, e))?;
+        to_contract(self.resolve(&document)?)
+    }
+
+    /// Check that a contract does not weaken the constraints of its base contract
+    ///
+    /// The effective base contract is compared with the effective child
+    /// contract. Contracts without an `extends` custom property have no
+    /// violations.
+    pub fn validate(&self, contract: &Value) -> Result<Vec<InheritanceViolation>, String> {
+        let Some(parent_key) = extends(contract) else {
+            return Ok(Vec::new());
+        };
+        let parent = self.resolve(self.lookup(contract, parent_key)?)?;
+        let effective = self.resolve(contract)?;
+        Ok(check_overrides(&parent, &effective))
+    }
+
+    /// Check that an ODCS YAML document does not weaken its base contract
+    pub fn validate_yaml(&self, yaml_content: &str) -> Result<Vec<InheritanceViolation>, String> {
+        self.validate(&parse_yaml(yaml_content)?)
+    }
+
+    fn resolve_chain(&self, contract: &Value, chain: &mut Vec<String>) -> Result<Value, String> {
+        let Some(parent_key) = extends(contract) else {
+            return Ok(contract.clone());
+        };
+
+        let own = document_id(contract).unwrap_or("<contract>").to_string();
+        if chain.is_empty() {
+            chain.push(own);
+        }
+        if chain.iter().any(|key| key == parent_key) {
+            chain.push(parent_key.to_string());
+            return Err(format!(
+                "Cyclic contract inheritance: {}",
+                chain.join(" -> ")
+            ));
+        }
+        let parent = self.lookup(contract, parent_key)?;
+        chain.push(parent_key.to_string());
+        if let Some(parent_id) = document_id(parent)
+            && parent_id != parent_key
+        {
+            if chain.iter().any(|key| key == parent_id) {
+                return Err(format!(
+                    "Cyclic contract inheritance: {}",
+                    chain.join(" -> ")
+                ));
+            }
+            chain.push(parent_id.to_string());
+        }
+
+        let parent = self.resolve_chain(parent, chain)?;
+        let mut effective = Map::new();
+        if let Value::Object(parent) = parent {
+            for (key, value) in parent {
+                if !NOT_INHERITED.contains(&key.as_str()) {
+                    effective.insert(key, value);
+                }
+            }
+        }
+        let mut effective = Value::Object(effective);
+        merge(&mut effective, contract, "");
+        Ok(effective)
+    }
+
+    fn lookup<'a>(&'a self, contract: &Value, parent_key: &str) -> Result<&'a Value, String> {
+        self.contracts.get(parent_key).ok_or_else(|| {
+            format!(
+                "Base contract '{}' of '{}' not found",
+                parent_key,
+                document_id(contract).unwrap_or("<contract>")
+            )
+        })
+    }
+}
+
+/// Base contract named in the `extends` custom property of a contract
+pub fn extends(contract: &Value) -> Option<&str> {
+    contract
+        .get("customProperties")?
+        .as_array()?
+        .iter()
+        .find(|entry| entry.get("property").and_then(Value::as_str) == Some(EXTENDS_PROPERTY))?
+        .get("value")?
+        .as_str()
+}
+
+/// Compare an effective child contract with its effective base contract
+///
+/// Reports properties that are no longer required, primary key or unique,
+/// changed logical types, loosened `logicalTypeOptions` bounds, added enum
+/// values and loosened quality thresholds.
+pub fn check_overrides(parent: &Value, child: &Value) -> Vec<InheritanceViolation> {
+    let mut violations = Vec::new();
+    check_quality(parent, child, "contract", &mut violations);
+    for parent_schema in items(parent, "schema") {
+        let Some(name) = parent_schema.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        let Some(child_schema) = find_named(child, "schema", "name", name) else {
+            violations.push(violation(
+                name,
+                "schema object inherited from base was removed",
+            ));
+            continue;
+        };
+        check_quality(parent_schema, child_schema, name, &mut violations);
+        check_properties(parent_schema, child_schema, name, &mut violations);
+    }
+    violations
+}
+
+fn check_properties(
+    parent: &Value,
+    child: &Value,
+    path: &str,
+    violations: &mut Vec<InheritanceViolation>,
+) {
+    for parent_property in items(parent, "properties") {
+        let Some(name) = parent_property.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        let path = format!("{}.{}", path, name);
+        match find_named(child, "properties", "name", name) {
+            Some(child_property) => {
+                check_property(parent_property, child_property, &path, violations)
+            }
+            None => violations.push(violation(&path, "property inherited from base was removed")),
+        }
+    }
+}
+
+fn check_property(
+    parent: &Value,
+    child: &Value,
+    path: &str,
+    violations: &mut Vec<InheritanceViolation>,
+) {
+    for flag in ["required", "primaryKey", "unique"] {
+        if flag_set(parent, flag) && !flag_set(child, flag) {
+            violations.push(violation(
+                &format!("{}.{}", path, flag),
+                "is true in the base contract and cannot be relaxed",
+            ));
+        }
+    }
+
+    let parent_type = parent.get("logicalType").and_then(Value::as_str);
+    let child_type = child.get("logicalType").and_then(Value::as_str);
+    if parent_type.is_some() && parent_type != child_type {
+        violations.push(violation(
+            &format!("{}.logicalType", path),
+            &format!(
+                "changed from '{}' to '{}'",
+                parent_type.unwrap_or_default(),
+                child_type.unwrap_or("<none>")
+            ),
+        ));
+    }
+
+    if let Some(parent_options) = parent.get("logicalTypeOptions") {
+        let child_options = child.get("logicalTypeOptions").unwrap_or(&Value::Null);
+        let options_path = format!("{}.logicalTypeOptions", path);
+        for bound in ["minLength", "minimum", "exclusiveMinimum"] {
+            check_bound(
+                parent_options,
+                child_options,
+                bound,
+                true,
+                &options_path,
+                violations,
+            );
+        }
+        for bound in ["maxLength", "maximum", "exclusiveMaximum", "precision"] {
+            check_bound(
+                parent_options,
+                child_options,
+                bound,
+                false,
+                &options_path,
+                violations,
+            );
+        }
+        for exact in ["pattern", "format"] {
+            if let Some(expected) = parent_options.get(exact).filter(|v| !v.is_null())
+                && child_options.get(exact) != Some(expected)
+            {
+                violations.push(violation(
+                    &format!("{}.{}", options_path, exact),
+                    "differs from the base contract",
+                ));
+            }
+        }
+    }
+
+    let parent_enum = items(parent, "enumValues");
+    if !parent_enum.is_empty() {
+        for value in items(child, "enumValues") {
+            if !parent_enum.contains(value) {
+                violations.push(violation(
+                    &format!("{}.enumValues", path),
+                    &format!("adds value {} not allowed by the base contract", value),
+                ));
+            }
+        }
+    }
+
+    check_quality(parent, child, path, violations);
+    check_properties(parent, child, path, violations);
+    if let (Some(parent_items), Some(child_items)) = (parent.get("items"), child.get("items")) {
+        check_property(
+            parent_items,
+            child_items,
+            &format!("{}.items", path),
+            violations,
+        );
+    }
+}
+
+/// Compare named quality rules; unnamed rules are always inherited unchanged
+fn check_quality(
+    parent: &Value,
+    child: &Value,
+    path: &str,
+    violations: &mut Vec<InheritanceViolation>,
+) {
+    for parent_rule in items(parent, "quality") {
+        let Some(name) = parent_rule.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        let rule_path = format!("{}.quality.{}", path, name);
+        let Some(child_rule) = find_named(child, "quality", "name", name) else {
+            violations.push(violation(
+                &rule_path,
+                "quality rule inherited from base was removed",
+            ));
+            continue;
+        };
+        for bound in ["mustBeGreaterThan", "mustBeGreaterThanOrEqual"] {
+            check_bound(parent_rule, child_rule, bound, true, &rule_path, violations);
+        }
+        for bound in ["mustBeLessThan", "mustBeLessThanOrEqual"] {
+            check_bound(
+                parent_rule,
+                child_rule,
+                bound,
+                false,
+                &rule_path,
+                violations,
+            );
+        }
+        for exact in ["mustBe", "mustNotBe"] {
+            if let Some(expected) = parent_rule.get(exact).filter(|v| !v.is_null())
+                && child_rule.get(exact) != Some(expected)
+            {
+                violations.push(violation(
+                    &format!("{}.{}", rule_path, exact),
+                    "differs from the base contract",
+                ));
+            }
+        }
+    }
+}
+
+/// Report a lower bound that decreased or an upper bound that increased
+fn check_bound(
+    parent: &Value,
+    child: &Value,
+    bound: &str,
+    lower: bool,
+    path: &str,
+    violations: &mut Vec<InheritanceViolation>,
+) {
+    let Some(expected) = parent.get(bound).and_then(Value::as_f64) else {
+        return;
+    };
+    let path = format!("{}.{}", path, bound);
+    match child.get(bound).and_then(Value::as_f64) {
+        None => violations.push(violation(&path, "bound inherited from base was removed")),
+        Some(actual) if (lower && actual < expected) || (!lower && actual > expected) => violations
+            .push(violation(
+                &path,
+                &format!("loosened from {} to {}", expected, actual),
+            )),
+        Some(_) => {}
+    }
+}
+
+/// Merge `child` on top of `target`, which holds the inherited values
+fn merge(target: &mut Value, child: &Value, key: &str) {
+    match (target, child) {
+        (Value::Object(target), Value::Object(child)) => {
+            for (field, value) in child {
+                match target.get_mut(field) {
+                    Some(existing) => merge(existing, value, field),
+                    None => {
+                        target.insert(field.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(child)) => match identity_key(key) {
+            Some(identity) => merge_keyed(target, child, identity, key),
+            None if UNIONED.contains(&key) => {
+                for value in child {
+                    if !target.contains(value) {
+                        target.push(value.clone());
+                    }
+                }
+            }
+            None => *target = child.clone(),
+        },
+        (target, child) => *target = child.clone(),
+    }
+}
+
+/// Merge array entries with the same identity; other entries are appended
+fn merge_keyed(target: &mut Vec<Value>, child: &[Value], identity: &str, key: &str) {
+    for value in child {
+        let position = value.get(identity).and_then(|id| {
+            target
+                .iter()
+                .position(|entry| entry.get(identity) == Some(id))
+        });
+        match position {
+            Some(position) => merge(&mut target[position], value, ""),
+            None if key == "quality" && target.contains(value) => {}
+            None => target.push(value.clone()),
+        }
+    }
+}
+
+/// Field identifying entries of an inherited array
+fn identity_key(key: &str) -> Option<&'static str> {
+    match key {
+        "schema" | "properties" | "quality" => Some("name"),
+        "customProperties" | "serviceLevels" | "slaProperties" => Some("property"),
+        "servers" => Some("server"),
+        "roles" => Some("role"),
+        _ => None,
+    }
+}
+
+fn items<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn find_named<'a>(value: &'a Value, key: &str, identity: &str, name: &str) -> Option<&'a Value> {
+    items(value, key)
+        .iter()
+        .find(|entry| entry.get(identity).and_then(Value::as_str) == Some(name))
+}
+
+fn flag_set(value: &Value, flag: &str) -> bool {
+    value.get(flag).and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn document_id(document: &Value) -> Option<&str> {
+    document.get("id").and_then(Value::as_str)
+}
+
+fn violation(path: &str, message: &str) -> InheritanceViolation {
+    InheritanceViolation {
+        path: path.to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn parse_yaml(yaml_content: &str) -> Result<Value, String> {
+    serde_yaml::from_str(yaml_content).map_err(|e| format!("Failed to parse contract YAML: {}", e))
+}
+
+fn to_contract(document: Value) -> Result<ODCSContract, String> {
+    serde_json::from_value(document).map_err(|e| format!("Invalid effective contract: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders-base
+version: 1.0.0
+name: orders
+terms:
+  usage: Internal analytics only
+tags: [orders]
+quality:
+  - name: row_count
+    metric: rowCount
+    mustBeGreaterThan: 0
+schema:
+  - name: orders
+    properties:
+      - name: id
+        logicalType: string
+        required: true
+        primaryKey: true
+      - name: country
+        logicalType: string
+        logicalTypeOptions:
+          maxLength: 2
+      - name: status
+        logicalType: string
+        enumValues: [open, closed]
+"#;
+
+    fn resolver() -> ContractResolver {
+        let mut resolver = ContractResolver::new();
+        resolver
+            .insert_file("contracts/orders-base.odcs.yaml", BASE)
+            .unwrap();
+        resolver
+    }
+
+    #[test]
+    fn test_resolve_merges_base_contract() {
+        let contract = resolver()
+            .resolve_yaml(
+                r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders-emea
+version: 2.0.0
+name: orders_emea
+tags: [emea]
+customProperties:
+  - property: extends
+    value: orders-base.odcs.yaml
+schema:
+  - name: orders
+    properties:
+      - name: country
+        logicalType: string
+        description: ISO country code
+      - name: vat_number
+        logicalType: string
+"#,
+            )
+            .unwrap();
+
+        assert_eq!(contract.id, "orders-emea");
+        assert_eq!(contract.version, "2.0.0");
+        assert_eq!(contract.tags, vec!["orders", "emea"]);
+        assert_eq!(contract.quality.len(), 1);
+        assert!(contract.terms.is_some());
+
+        let orders = contract.get_schema("orders").unwrap();
+        let names: Vec<&str> = orders.properties.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "country", "status", "vat_number"]);
+        let country = &orders.properties[1];
+        assert_eq!(country.description.as_deref(), Some("ISO country code"));
+        assert_eq!(
+            country.logical_type_options.as_ref().unwrap().max_length,
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_weakened_constraints() {
+        let resolver = resolver();
+        let child: Value = serde_yaml::from_str(
+            r#"
+id: orders-apac
+customProperties:
+  - property: extends
+    value: orders-base
+quality:
+  - name: row_count
+    mustBeGreaterThan: -1
+schema:
+  - name: orders
+    properties:
+      - name: id
+        required: false
+      - name: country
+        logicalTypeOptions:
+          maxLength: 3
+      - name: status
+        enumValues: [open, closed, pending]
+"#,
+        )
+        .unwrap();
+
+        let paths: Vec<String> = resolver
+            .validate(&child)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "contract.quality.row_count.mustBeGreaterThan",
+                "orders.id.required",
+                "orders.country.logicalTypeOptions.maxLength",
+                "orders.status.enumValues",
+            ]
+        );
+
+        let mut cyclic = ContractResolver::new();
+        cyclic.insert(
+            "a",
+            serde_json::json!({"id": "a", "customProperties": [{"property": "extends", "value": "b"}]}),
+        );
+        cyclic.insert(
+            "b",
+            serde_json::json!({"id": "b", "customProperties": [{"property": "extends", "value": "a"}]}),
+        );
+        let err = cyclic
+            .resolve(&serde_json::json!({"id": "c", "customProperties": [{"property": "extends", "value": "a"}]}))
+            .unwrap_err();
+        assert!(err.contains("Cyclic"), "{}", err);
+    }
+}
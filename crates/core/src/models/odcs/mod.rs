@@ -48,6 +48,9 @@
 
 pub mod contract;
 pub mod converters;
+pub mod diff;
+pub mod inheritance;
+pub mod projection;
 pub mod property;
 pub mod schema;
 pub mod supporting;
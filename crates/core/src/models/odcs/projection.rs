@@ -0,0 +1,513 @@
+//! Consumer views of a contract
+//!
+//! Internal contracts often carry more than an external consumer should see:
+//! columns they are not entitled to, columns classified as confidential, and
+//! custom properties that only make sense inside the owning team. A
+//! [`ConsumerView`] projects a contract down to what one consumer may see, so
+//! the external version is derived from the internal contract instead of being
+//! maintained as a duplicate:
+//!
+//! - Only the selected schema objects and columns are kept (everything when
+//!   nothing is selected). Nested columns are selected with dotted paths such
+//!   as `address.city` (array item fields as `lines.sku`); selecting a parent
+//!   keeps all of its children.
+//! - Columns whose `classification` is excluded are removed (by default
+//!   `confidential` and `restricted`).
+//! - Custom properties whose name starts with an internal prefix (by default
+//!   `internal`) are stripped from the contract, schema objects and columns.
+//! - Schema relationships that refer to a removed column or schema object are
+//!   dropped.
+//!
+//! The projected contract gets the ID `{id}-{consumer}`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::odcs::projection::ConsumerView;
+//! use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+//!
+//! let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+//!     SchemaObject::new("orders").with_properties(vec![
+//!         Property::new("id", "integer"),
+//!         Property::new("email", "string").with_classification("confidential"),
+//!         Property::new("margin", "number"),
+//!     ]),
+//! );
+//!
+//! let view = ConsumerView::new("partner").with_columns("orders", ["id", "email"]);
+//! let projected = view.project(&contract).unwrap();
+//!
+//! let columns: Vec<&str> = projected.contract.schema[0]
+//!     .properties
+//!     .iter()
+//!     .map(|p| p.name.as_str())
+//!     .collect();
+//! assert_eq!(columns, vec!["id"]);
+//! assert_eq!(projected.removed.len(), 2);
+//! ```
+
+use super::contract::ODCSContract;
+use super::property::Property;
+use super::supporting::CustomProperty;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Classifications excluded from consumer views unless overridden
+pub const DEFAULT_EXCLUDED_CLASSIFICATIONS: &[&str] = &["confidential", "restricted"];
+
+/// Custom property prefix marking internal-only properties unless overridden
+pub const DEFAULT_INTERNAL_PREFIX: &str = "internal";
+
+/// Error projecting a contract
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ProjectionError {
+    #[error("Schema object '{0}' not found in contract")]
+    UnknownSchema(String),
+    #[error("Column '{column}' not found in schema object '{schema}'")]
+    UnknownColumn { schema: String, column: String },
+}
+
+/// Columns selected from one schema object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaSelection {
+    /// Schema object name
+    pub name: String,
+    /// Selected column paths; empty selects every column
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<String>,
+}
+
+/// Why a column was left out of the projection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "reason", content = "classification", rename_all = "camelCase")]
+pub enum RemovalReason {
+    /// The column was not selected for the consumer
+    NotSelected,
+    /// The column's classification is excluded
+    Classification(String),
+}
+
+/// A column left out of the projection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedColumn {
+    pub schema: String,
+    /// Dotted column path
+    pub column: String,
+    #[serde(flatten)]
+    pub reason: RemovalReason,
+}
+
+/// A consumer-specific projection of contracts
+///
+/// Views are plain data, so they can be stored next to the contracts they
+/// apply to and reused for every release.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsumerView {
+    /// Consumer the view is published for
+    pub consumer: String,
+    /// Selected schema objects; empty keeps every schema object
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<SchemaSelection>,
+    /// Column classifications removed from the view (case-insensitive)
+    #[serde(default = "default_excluded_classifications")]
+    pub excluded_classifications: Vec<String>,
+    /// Custom property name prefixes marking internal-only properties
+    #[serde(default = "default_internal_prefixes")]
+    pub internal_prefixes: Vec<String>,
+}
+
+fn default_excluded_classifications() -> Vec<String> {
+    DEFAULT_EXCLUDED_CLASSIFICATIONS
+        .iter()
+        .map(|c| c.to_string())
+        .collect()
+}
+
+fn default_internal_prefixes() -> Vec<String> {
+    vec![DEFAULT_INTERNAL_PREFIX.to_string()]
+}
+
+/// Result of projecting a contract
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectedContract {
+    /// The consumer's version of the contract
+    pub contract: ODCSContract,
+    /// Schema objects left out because they were not selected
+    pub removed_schemas: Vec<String>,
+    /// Columns left out, with the reason
+    pub removed: Vec<RemovedColumn>,
+    /// Number of internal custom properties stripped
+    pub stripped_custom_properties: usize,
+}
+
+impl ProjectedContract {
+    /// Export the projected contract as ODCS YAML
+    pub fn to_yaml(&self) -> String {
+        crate::export::odcs::ODCSExporter::export_contract(&self.contract)
+    }
+}
+
+impl ConsumerView {
+    /// Create a view that keeps everything except excluded classifications and
+    /// internal custom properties
+    pub fn new(consumer: impl Into<String>) -> Self {
+        Self {
+            consumer: consumer.into(),
+            schemas: Vec::new(),
+            excluded_classifications: default_excluded_classifications(),
+            internal_prefixes: default_internal_prefixes(),
+        }
+    }
+
+    /// Keep the schema object `schema` with all of its columns
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schemas.push(SchemaSelection {
+            name: schema.into(),
+            columns: Vec::new(),
+        });
+        self
+    }
+
+    /// Keep only the given columns of the schema object `schema`
+    pub fn with_columns<I, S>(mut self, schema: impl Into<String>, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.schemas.push(SchemaSelection {
+            name: schema.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Replace the excluded classifications
+    pub fn with_excluded_classifications<I, S>(mut self, classifications: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.excluded_classifications = classifications.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replace the internal custom property prefixes
+    pub fn with_internal_prefixes<I, S>(mut self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.internal_prefixes = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Project `contract` down to this view
+    ///
+    /// Fails if a selected schema object or column does not exist, so a view
+    /// does not silently publish less than intended after a rename.
+    pub fn project(&self, contract: &ODCSContract) -> Result<ProjectedContract, ProjectionError> {
+        for selection in &self.schemas {
+            let schema = contract
+                .get_schema(&selection.name)
+                .ok_or_else(|| ProjectionError::UnknownSchema(selection.name.clone()))?;
+            for column in &selection.columns {
+                if find_path(&schema.properties, column).is_none() {
+                    return Err(ProjectionError::UnknownColumn {
+                        schema: selection.name.clone(),
+                        column: column.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut projected = contract.clone();
+        projected.id = format!("{}-{}", contract.id, self.consumer);
+        let mut result = ProjectedContract {
+            contract: ODCSContract::default(),
+            removed_schemas: Vec::new(),
+            removed: Vec::new(),
+            stripped_custom_properties: 0,
+        };
+
+        let mut schemas = Vec::new();
+        for mut schema in projected.schema {
+            let selection = if self.schemas.is_empty() {
+                None
+            } else {
+                match self.schemas.iter().find(|s| s.name == schema.name) {
+                    Some(selection) => Some(selection),
+                    None => {
+                        result.removed_schemas.push(schema.name);
+                        continue;
+                    }
+                }
+            };
+            let selected: Option<Vec<&str>> = selection
+                .filter(|s| !s.columns.is_empty())
+                .map(|s| s.columns.iter().map(String::as_str).collect());
+
+            schema.properties = self.project_properties(
+                &schema.name,
+                "",
+                schema.properties,
+                selected.as_deref(),
+                &mut result,
+            );
+            result.stripped_custom_properties += self.strip(&mut schema.custom_properties);
+            schemas.push(schema);
+        }
+
+        // Drop relationships whose ends were projected away
+        let columns: HashSet<(String, String)> = schemas
+            .iter()
+            .flat_map(|s| {
+                s.properties
+                    .iter()
+                    .map(move |p| (s.name.clone(), p.name.clone()))
+            })
+            .collect();
+        let names: HashSet<String> = schemas.iter().map(|s| s.name.clone()).collect();
+        for schema in &mut schemas {
+            let name = schema.name.clone();
+            schema.relationships.retain(|r| {
+                names.contains(&r.to_schema)
+                    && r.from_properties
+                        .iter()
+                        .all(|p| columns.contains(&(name.clone(), p.clone())))
+                    && r.to_properties
+                        .iter()
+                        .all(|p| columns.contains(&(r.to_schema.clone(), p.clone())))
+            });
+        }
+
+        projected.schema = schemas;
+        result.stripped_custom_properties += self.strip(&mut projected.custom_properties);
+        result.contract = projected;
+        Ok(result)
+    }
+
+    /// Filter `properties` (at `prefix`) down to the selected, allowed columns
+    fn project_properties(
+        &self,
+        schema: &str,
+        prefix: &str,
+        properties: Vec<Property>,
+        selected: Option<&[&str]>,
+        result: &mut ProjectedContract,
+    ) -> Vec<Property> {
+        let mut kept = Vec::new();
+        for mut property in properties {
+            let path = if prefix.is_empty() {
+                property.name.clone()
+            } else {
+                format!("{}.{}", prefix, property.name)
+            };
+
+            // Selected in full, partially (a nested column is selected) or not at all
+            let children: Option<Vec<&str>> = match selected {
+                None => None,
+                Some(paths) if paths.contains(&path.as_str()) => None,
+                Some(paths) => {
+                    let nested: Vec<&str> = paths
+                        .iter()
+                        .copied()
+                        .filter(|p| {
+                            p.strip_prefix(path.as_str())
+                                .is_some_and(|rest| rest.starts_with('.'))
+                        })
+                        .collect();
+                    if nested.is_empty() {
+                        result.removed.push(RemovedColumn {
+                            schema: schema.to_string(),
+                            column: path,
+                            reason: RemovalReason::NotSelected,
+                        });
+                        continue;
+                    }
+                    Some(nested)
+                }
+            };
+
+            if let Some(classification) = property.classification.as_deref()
+                && self
+                    .excluded_classifications
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(classification))
+            {
+                result.removed.push(RemovedColumn {
+                    schema: schema.to_string(),
+                    column: path,
+                    reason: RemovalReason::Classification(classification.to_string()),
+                });
+                continue;
+            }
+
+            property.properties = self.project_properties(
+                schema,
+                &path,
+                property.properties,
+                children.as_deref(),
+                result,
+            );
+            if let Some(items) = property.items.as_mut() {
+                // Array item fields are addressed as if they belonged to the array
+                let item_properties = std::mem::take(&mut items.properties);
+                items.properties = self.project_properties(
+                    schema,
+                    &path,
+                    item_properties,
+                    children.as_deref(),
+                    result,
+                );
+                result.stripped_custom_properties += self.strip(&mut items.custom_properties);
+            }
+            result.stripped_custom_properties += self.strip(&mut property.custom_properties);
+            kept.push(property);
+        }
+        kept
+    }
+
+    /// Remove internal custom properties, returning how many were removed
+    fn strip(&self, custom_properties: &mut Vec<CustomProperty>) -> usize {
+        let before = custom_properties.len();
+        custom_properties.retain(|cp| {
+            !self
+                .internal_prefixes
+                .iter()
+                .any(|prefix| cp.property.starts_with(prefix.as_str()))
+        });
+        before - custom_properties.len()
+    }
+}
+
+/// Find the property at a dotted `path`
+fn find_path<'a>(properties: &'a [Property], path: &str) -> Option<&'a Property> {
+    let (head, rest) = match path.split_once('.') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (path, None),
+    };
+    let property = properties.iter().find(|p| p.name == head)?;
+    match rest {
+        None => Some(property),
+        Some(rest) => match &property.items {
+            Some(items) => find_path(&items.properties, rest),
+            None => find_path(&property.properties, rest),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::{SchemaObject, SchemaRelationship};
+    use serde_json::json;
+
+    fn contract() -> ODCSContract {
+        ODCSContract::new_with_id("orders-contract", "orders", "1.0.0")
+            .with_custom_property(CustomProperty::new("internalCostCenter", json!("cc-42")))
+            .with_custom_property(CustomProperty::new("owner", json!("sales")))
+            .with_schema(
+                SchemaObject::new("orders")
+                    .with_properties(vec![
+                        Property::new("id", "integer").with_primary_key(true),
+                        Property::new("customer_id", "integer"),
+                        Property::new("email", "string").with_classification("Confidential"),
+                        Property::new("address", "object").with_nested_properties(vec![
+                            Property::new("city", "string"),
+                            Property::new("street", "string"),
+                        ]),
+                        Property::new("margin", "number").with_custom_property(
+                            CustomProperty::new("internal.note", json!("do not share")),
+                        ),
+                    ])
+                    .with_relationship(SchemaRelationship {
+                        relationship_type: "foreignKey".to_string(),
+                        from_properties: vec!["customer_id".to_string()],
+                        to_schema: "customers".to_string(),
+                        to_properties: vec!["id".to_string()],
+                        description: None,
+                    }),
+            )
+            .with_schema(
+                SchemaObject::new("customers")
+                    .with_properties(vec![Property::new("id", "integer")]),
+            )
+    }
+
+    fn names(properties: &[Property]) -> Vec<&str> {
+        properties.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_view_strips_classified_and_internal() {
+        let projected = ConsumerView::new("partner").project(&contract()).unwrap();
+
+        assert_eq!(projected.contract.id, "orders-contract-partner");
+        let orders = &projected.contract.schema[0];
+        assert_eq!(
+            names(&orders.properties),
+            vec!["id", "customer_id", "address", "margin"]
+        );
+        assert_eq!(
+            projected.removed,
+            vec![RemovedColumn {
+                schema: "orders".to_string(),
+                column: "email".to_string(),
+                reason: RemovalReason::Classification("Confidential".to_string()),
+            }]
+        );
+        assert_eq!(projected.stripped_custom_properties, 2);
+        assert_eq!(projected.contract.custom_properties.len(), 1);
+        assert!(orders.properties[3].custom_properties.is_empty());
+        assert_eq!(orders.relationships.len(), 1);
+        assert!(!projected.to_yaml().contains("internalCostCenter"));
+    }
+
+    #[test]
+    fn test_selected_columns_and_nested_paths() {
+        let view = ConsumerView::new("analytics").with_columns("orders", ["id", "address.city"]);
+        let projected = view.project(&contract()).unwrap();
+
+        assert_eq!(projected.removed_schemas, vec!["customers".to_string()]);
+        let orders = &projected.contract.schema[0];
+        assert_eq!(names(&orders.properties), vec!["id", "address"]);
+        assert_eq!(names(&orders.properties[1].properties), vec!["city"]);
+        // customer_id was projected away, so the foreign key goes with it
+        assert!(orders.relationships.is_empty());
+        let removed: Vec<&str> = projected
+            .removed
+            .iter()
+            .map(|r| r.column.as_str())
+            .collect();
+        assert_eq!(
+            removed,
+            vec!["customer_id", "email", "address.street", "margin"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_selection_is_an_error() {
+        let view = ConsumerView::new("partner").with_columns("orders", ["id", "address.zip"]);
+        assert_eq!(
+            view.project(&contract()).unwrap_err(),
+            ProjectionError::UnknownColumn {
+                schema: "orders".to_string(),
+                column: "address.zip".to_string(),
+            }
+        );
+        let view = ConsumerView::new("partner").with_schema("invoices");
+        assert_eq!(
+            view.project(&contract()).unwrap_err(),
+            ProjectionError::UnknownSchema("invoices".to_string())
+        );
+    }
+
+    #[test]
+    fn test_view_round_trips_with_defaults() {
+        let view: ConsumerView = serde_json::from_value(json!({"consumer": "partner"})).unwrap();
+        assert_eq!(view, ConsumerView::new("partner"));
+    }
+}
@@ -312,11 +312,11 @@ impl Property {
     /// - "address.street" -> nested object
     /// - "tags.[]" -> array items
     /// - "items.[].name" -> array of objects
+    ///
+    /// Properties keep the order in which their names first appear in `paths`.
     pub fn from_flat_paths(paths: &[(String, Property)]) -> Vec<Property> {
-        use std::collections::HashMap;
-
-        // Group by top-level name
-        let mut top_level: HashMap<String, Vec<(String, &Property)>> = HashMap::new();
+        // Group by top-level name, preserving first-appearance order
+        let mut top_level: Vec<(String, Vec<(String, &Property)>)> = Vec::new();
 
         for (path, prop) in paths {
             let parts: Vec<&str> = path.split('.').collect();
@@ -331,10 +331,10 @@ impl Property {
                 String::new()
             };
 
-            top_level
-                .entry(top_name)
-                .or_default()
-                .push((remaining_path, prop));
+            match top_level.iter_mut().find(|(name, _)| *name == top_name) {
+                Some((_, children)) => children.push((remaining_path, prop)),
+                None => top_level.push((top_name, vec![(remaining_path, prop)])),
+            }
         }
 
         // Build properties from grouped paths
@@ -0,0 +1,405 @@
+//! Workspace-level physical name mappings
+//!
+//! The physical names of tables and columns differ per platform (a Kafka topic
+//! `sales.orders.v1` vs. a Snowflake table `SALES_ORDERS`). Instead of overriding
+//! `physicalName` in every contract, the mapping from logical to physical names is
+//! kept once per workspace in `physical-names.yaml`:
+//!
+//! ```yaml
+//! platforms:
+//!   snowflake:
+//!     tables:
+//!       orders: SALES_ORDERS
+//!     columns:
+//!       orders.customer_id: CUSTOMER_ID
+//!       created_at: CREATED_TS
+//!   kafka:
+//!     tables:
+//!       orders: sales.orders.v1
+//! ```
+//!
+//! Column keys are either `table.column` or a bare column path that applies to every
+//! table; the qualified key wins. Nested columns are keyed by their full path (e.g.
+//! `customer.address` or `items.[].sku`) and map to the physical name of their last
+//! segment. Platform names are matched case-insensitively, with `_` and `-` treated
+//! as equivalent.
+//!
+//! Exporters run through the [`ExporterRegistry`](crate::export::ExporterRegistry)
+//! consult the map for the platform of the export (see
+//! [`ExportOptions::platform_for`](crate::export::ExportOptions::platform_for)).
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::physical_names::PhysicalNameMap;
+//!
+//! let names = PhysicalNameMap::from_yaml(
+//!     "platforms:\n  snowflake:\n    tables:\n      orders: SALES_ORDERS\n",
+//! )
+//! .unwrap();
+//! assert_eq!(names.table_name("Snowflake", "orders"), Some("SALES_ORDERS"));
+//! assert_eq!(names.table_name("kafka", "orders"), None);
+//! ```
+
+use super::Table;
+use super::odcs::{ODCSContract, Property};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// File name of the workspace physical name mappings
+pub const PHYSICAL_NAMES_FILE: &str = "physical-names.yaml";
+
+/// Physical names of a workspace, keyed by platform
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhysicalNameMap {
+    #[serde(default)]
+    pub platforms: BTreeMap<String, PlatformNames>,
+}
+
+/// Physical table and column names on one platform
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlatformNames {
+    /// Logical table name -> physical table name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tables: BTreeMap<String, String>,
+    /// `table.column` or column path -> physical column name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub columns: BTreeMap<String, String>,
+}
+
+impl PlatformNames {
+    /// Physical name of a table
+    pub fn table_name(&self, table: &str) -> Option<&str> {
+        self.tables.get(table).map(String::as_str)
+    }
+
+    /// Physical name of a column, preferring the table-qualified mapping
+    pub fn column_name(&self, table: &str, column: &str) -> Option<&str> {
+        self.columns
+            .get(&format!("{}.{}", table, column))
+            .or_else(|| self.columns.get(column))
+            .map(String::as_str)
+    }
+
+    /// Physical path of a (possibly nested) column, if any segment is mapped
+    fn column_path(&self, table: &str, column: &str) -> Option<String> {
+        let segments: Vec<&str> = column.split('.').collect();
+        let mut mapped = false;
+        let physical: Vec<&str> = segments
+            .iter()
+            .enumerate()
+            .map(|(i, segment)| {
+                let name = (*segment != "[]")
+                    .then(|| self.column_name(table, &segments[..=i].join(".")))
+                    .flatten();
+                mapped |= name.is_some();
+                name.unwrap_or(segment)
+            })
+            .collect();
+        mapped.then(|| physical.join("."))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.tables.is_empty() && self.columns.is_empty()
+    }
+}
+
+impl PhysicalNameMap {
+    /// Create an empty mapping
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse `physical-names.yaml` content
+    pub fn from_yaml(yaml_content: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml_content)
+    }
+
+    /// Serialize to `physical-names.yaml` content
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Whether no platform maps any name
+    pub fn is_empty(&self) -> bool {
+        self.platforms.values().all(PlatformNames::is_empty)
+    }
+
+    /// Names of a platform
+    pub fn platform(&self, platform: &str) -> Option<&PlatformNames> {
+        let key = normalize_platform(platform);
+        self.platforms
+            .iter()
+            .find(|(name, _)| normalize_platform(name) == key)
+            .map(|(_, names)| names)
+    }
+
+    /// Set the physical name of a table on a platform
+    pub fn set_table(
+        &mut self,
+        platform: &str,
+        table: impl Into<String>,
+        physical: impl Into<String>,
+    ) {
+        self.platform_mut(platform)
+            .tables
+            .insert(table.into(), physical.into());
+    }
+
+    /// Set the physical name of a column on a platform, for one table or all tables
+    pub fn set_column(
+        &mut self,
+        platform: &str,
+        table: Option<&str>,
+        column: &str,
+        physical: impl Into<String>,
+    ) {
+        let key = match table {
+            Some(table) => format!("{}.{}", table, column),
+            None => column.to_string(),
+        };
+        self.platform_mut(platform)
+            .columns
+            .insert(key, physical.into());
+    }
+
+    /// Physical name of a table on a platform
+    pub fn table_name(&self, platform: &str, table: &str) -> Option<&str> {
+        self.platform(platform)?.table_name(table)
+    }
+
+    /// Physical name of a column on a platform
+    pub fn column_name(&self, platform: &str, table: &str, column: &str) -> Option<&str> {
+        self.platform(platform)?.column_name(table, column)
+    }
+
+    /// Replace logical table and column names with their physical names
+    ///
+    /// Returns the number of renamed tables and columns.
+    pub fn rename_tables(&self, platform: &str, tables: &mut [Table]) -> usize {
+        let Some(names) = self.platform(platform) else {
+            return 0;
+        };
+        let mut renamed = 0;
+        for table in tables {
+            for column in &mut table.columns {
+                if let Some(path) = names.column_path(&table.name, &column.name) {
+                    column.name = path;
+                    renamed += 1;
+                }
+            }
+            if let Some(physical) = names.table_name(&table.name) {
+                table.name = physical.to_string();
+                renamed += 1;
+            }
+        }
+        renamed
+    }
+
+    /// Record physical names next to the logical names of tables
+    ///
+    /// Sets the column `physical_name` and the table `physicalName` metadata.
+    /// Returns the number of annotated tables and columns.
+    pub fn annotate_tables(&self, platform: &str, tables: &mut [Table]) -> usize {
+        let Some(names) = self.platform(platform) else {
+            return 0;
+        };
+        let mut annotated = 0;
+        for table in tables {
+            for column in &mut table.columns {
+                if let Some(physical) = names.column_name(&table.name, &column.name) {
+                    column.physical_name = Some(physical.to_string());
+                    annotated += 1;
+                }
+            }
+            if let Some(physical) = names.table_name(&table.name) {
+                table
+                    .odcl_metadata
+                    .insert("physicalName".to_string(), json!(physical));
+                annotated += 1;
+            }
+        }
+        annotated
+    }
+
+    /// Replace logical schema object and property names of a contract with their
+    /// physical names
+    ///
+    /// Returns the number of renamed schema objects and properties.
+    pub fn rename_contract(&self, platform: &str, contract: &mut ODCSContract) -> usize {
+        self.apply_contract(platform, contract, true)
+    }
+
+    /// Set `physicalName` on the schema objects and properties of a contract
+    ///
+    /// Returns the number of annotated schema objects and properties.
+    pub fn annotate_contract(&self, platform: &str, contract: &mut ODCSContract) -> usize {
+        self.apply_contract(platform, contract, false)
+    }
+
+    fn apply_contract(&self, platform: &str, contract: &mut ODCSContract, rename: bool) -> usize {
+        let Some(names) = self.platform(platform) else {
+            return 0;
+        };
+        let mut applied = 0;
+        for schema in &mut contract.schema {
+            applied += apply_properties(names, &schema.name, "", &mut schema.properties, rename);
+            if let Some(physical) = names.table_name(&schema.name) {
+                if rename {
+                    schema.name = physical.to_string();
+                } else {
+                    schema.physical_name = Some(physical.to_string());
+                }
+                applied += 1;
+            }
+        }
+        applied
+    }
+
+    fn platform_mut(&mut self, platform: &str) -> &mut PlatformNames {
+        let key = normalize_platform(platform);
+        let name = self
+            .platforms
+            .keys()
+            .find(|name| normalize_platform(name) == key)
+            .cloned()
+            .unwrap_or_else(|| platform.to_string());
+        self.platforms.entry(name).or_default()
+    }
+}
+
+/// Rename or annotate properties, keyed by their path below the schema object
+fn apply_properties(
+    names: &PlatformNames,
+    table: &str,
+    prefix: &str,
+    properties: &mut [Property],
+    rename: bool,
+) -> usize {
+    let mut applied = 0;
+    for property in properties {
+        let path = if prefix.is_empty() {
+            property.name.clone()
+        } else {
+            format!("{}.{}", prefix, property.name)
+        };
+        applied += apply_properties(names, table, &path, &mut property.properties, rename);
+        if let Some(items) = property.items.as_deref_mut() {
+            let items_path = format!("{}.[]", path);
+            applied += apply_properties(names, table, &items_path, &mut items.properties, rename);
+        }
+        if let Some(physical) = names.column_name(table, &path) {
+            if rename {
+                property.name = physical.to_string();
+            } else {
+                property.physical_name = Some(physical.to_string());
+            }
+            applied += 1;
+        }
+    }
+    applied
+}
+
+/// Normalize a platform name for lookups
+fn normalize_platform(platform: &str) -> String {
+    platform.trim().to_lowercase().replace('_', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Column;
+
+    const NAMES: &str = r#"
+platforms:
+  snowflake:
+    tables:
+      orders: SALES_ORDERS
+    columns:
+      orders.id: ORDER_ID
+      id: ID
+      customer: CUST
+      customer.name: CUST_NAME
+"#;
+
+    #[test]
+    fn test_rename_tables_with_nested_columns() {
+        let names = PhysicalNameMap::from_yaml(NAMES).unwrap();
+        let mut tables = vec![
+            Table::new(
+                "orders".to_string(),
+                vec![
+                    Column::new("id".to_string(), "BIGINT".to_string()),
+                    Column::new("customer".to_string(), "OBJECT".to_string()),
+                    Column::new("customer.name".to_string(), "STRING".to_string()),
+                    Column::new("note".to_string(), "STRING".to_string()),
+                ],
+            ),
+            Table::new(
+                "refunds".to_string(),
+                vec![Column::new("id".to_string(), "BIGINT".to_string())],
+            ),
+        ];
+
+        assert_eq!(names.rename_tables("kafka", &mut tables), 0);
+        assert_eq!(names.rename_tables("SNOWFLAKE", &mut tables), 5);
+
+        let columns: Vec<_> = tables[0].columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(columns, vec!["ORDER_ID", "CUST", "CUST.CUST_NAME", "note"]);
+        assert_eq!(tables[0].name, "SALES_ORDERS");
+        assert_eq!(tables[1].name, "refunds");
+        assert_eq!(tables[1].columns[0].name, "ID");
+    }
+
+    #[test]
+    fn test_annotate_contract_keeps_logical_names() {
+        let names = PhysicalNameMap::from_yaml(NAMES).unwrap();
+        let mut contract: ODCSContract = serde_yaml::from_str(
+            r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders
+version: 1.0.0
+name: orders
+schema:
+  - name: orders
+    properties:
+      - name: id
+        logicalType: integer
+        physicalName: old_id
+      - name: customer
+        logicalType: object
+        properties:
+          - name: name
+            logicalType: string
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(names.annotate_contract("snowflake", &mut contract), 4);
+        let schema = &contract.schema[0];
+        assert_eq!(schema.name, "orders");
+        assert_eq!(schema.physical_name.as_deref(), Some("SALES_ORDERS"));
+        assert_eq!(
+            schema.properties[0].physical_name.as_deref(),
+            Some("ORDER_ID")
+        );
+        assert_eq!(
+            schema.properties[1].properties[0].physical_name.as_deref(),
+            Some("CUST_NAME")
+        );
+
+        let mut map = PhysicalNameMap::new();
+        map.set_table("kafka", "orders", "sales.orders.v1");
+        map.set_column("Kafka", None, "customer", "buyer");
+        assert_eq!(map.platforms.len(), 1);
+        assert_eq!(map.rename_contract("kafka", &mut contract), 2);
+        assert_eq!(contract.schema[0].name, "sales.orders.v1");
+        assert_eq!(contract.schema[0].properties[1].name, "buyer");
+        assert_eq!(
+            PhysicalNameMap::from_yaml(&map.to_yaml().unwrap()).unwrap(),
+            map
+        );
+    }
+}
@@ -21,7 +21,10 @@ pub struct ForeignKeyDetails {
 }
 
 /// ETL job metadata for data flow relationships
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// See [`crate::validation::etl`] for checking it and [`crate::workspace::etl`]
+/// for the per-domain job inventory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ETLJobMetadata {
     /// Name of the ETL job that creates this relationship
@@ -33,6 +36,15 @@ pub struct ETLJobMetadata {
     /// Job execution frequency (e.g., "daily", "hourly")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frequency: Option<String>,
+    /// Cron expression (5 or 6 fields) or macro (e.g., "@daily") the job runs on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// URL of the job in the orchestrator (e.g., an Airflow DAG or dbt Cloud job)
+    #[serde(skip_serializing_if = "Option::is_none", alias = "job_url")]
+    pub job_url: Option<String>,
+    /// ID of the domain's transformation link implementing the job
+    #[serde(skip_serializing_if = "Option::is_none", alias = "transformation_id")]
+    pub transformation_id: Option<Uuid>,
 }
 
 /// Connection point coordinates for relationship visualization
@@ -0,0 +1,301 @@
+//! Geospatial data types
+//!
+//! Columns can hold `GEOMETRY` (planar) or `GEOGRAPHY` (geodetic) values, optionally
+//! constrained to a shape such as `Point` and a spatial reference system (SRID).
+//! Data types like `GEOGRAPHY(Point, 4326)`, `geometry(Polygon)`, MySQL's `POINT` or
+//! BigQuery's `GEOGRAPHY` are recognised; an SRID can also be set with
+//! `logicalTypeOptions.srid`.
+//!
+//! Exporters use [`SpatialType::to_sql`] for the native type of a SQL dialect and
+//! [`SpatialType::to_json_schema`] for a GeoJSON geometry schema.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::spatial::{SpatialKind, SpatialType};
+//!
+//! let spatial = SpatialType::parse("geography(point, 4326)").unwrap();
+//! assert_eq!(spatial.kind, SpatialKind::Geography);
+//! assert_eq!(spatial.to_string(), "GEOGRAPHY(Point, 4326)");
+//! assert_eq!(spatial.to_sql("bigquery").as_deref(), Some("GEOGRAPHY"));
+//! ```
+
+use super::Column;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::fmt;
+
+/// SRID of WGS 84 longitude/latitude coordinates, as used by GPS and GeoJSON
+pub const WGS84_SRID: i32 = 4326;
+
+/// GeoJSON geometry types, in their canonical spelling
+const SHAPES: &[&str] = &[
+    "Point",
+    "LineString",
+    "Polygon",
+    "MultiPoint",
+    "MultiLineString",
+    "MultiPolygon",
+    "GeometryCollection",
+];
+
+/// Coordinate model of a spatial type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpatialKind {
+    /// Planar coordinates
+    Geometry,
+    /// Coordinates on the earth's spheroid
+    Geography,
+}
+
+/// A geometry or geography data type with optional shape and SRID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpatialType {
+    /// Coordinate model
+    pub kind: SpatialKind,
+    /// GeoJSON geometry type (e.g. `Point`); `None` allows any shape
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shape: Option<String>,
+    /// Spatial reference system identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srid: Option<i32>,
+}
+
+impl SpatialType {
+    /// Create a spatial type of any shape without an SRID
+    pub fn new(kind: SpatialKind) -> Self {
+        Self {
+            kind,
+            shape: None,
+            srid: None,
+        }
+    }
+
+    /// Restrict values to a shape such as `Point` or `Polygon`
+    pub fn with_shape(mut self, shape: impl Into<String>) -> Self {
+        self.shape = Some(canonical_shape(&shape.into()));
+        self
+    }
+
+    /// Set the spatial reference system
+    pub fn with_srid(mut self, srid: i32) -> Self {
+        self.srid = Some(srid);
+        self
+    }
+
+    /// Parse a spatial data type, returning `None` for non-spatial types
+    pub fn parse(data_type: &str) -> Option<Self> {
+        let data_type = data_type.trim();
+        let (head, srid_suffix) = match data_type.to_ascii_uppercase().find(" SRID ") {
+            Some(pos) => (
+                &data_type[..pos],
+                data_type[pos + 6..].trim().parse::<i32>().ok(),
+            ),
+            None => (data_type, None),
+        };
+        let (base, params) = match head.split_once('(') {
+            Some((base, rest)) => (base.trim(), rest.trim_end().trim_end_matches(')')),
+            None => (head.trim(), ""),
+        };
+
+        let mut spatial = match base.to_uppercase().as_str() {
+            "GEOMETRY" => Self::new(SpatialKind::Geometry),
+            "GEOGRAPHY" => Self::new(SpatialKind::Geography),
+            shape => {
+                // MySQL-style shape types are planar geometries
+                let shape = SHAPES.iter().find(|s| s.eq_ignore_ascii_case(shape))?;
+                Self::new(SpatialKind::Geometry).with_shape(*shape)
+            }
+        };
+        for param in params.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            match param.parse::<i32>() {
+                Ok(srid) => spatial.srid = Some(srid),
+                // `GEOMETRY(Geometry, 4326)` allows any shape
+                Err(_) if param.eq_ignore_ascii_case("geometry") => {}
+                Err(_) => spatial.shape = Some(canonical_shape(param)),
+            }
+        }
+        if srid_suffix.is_some() {
+            spatial.srid = srid_suffix;
+        }
+        Some(spatial)
+    }
+
+    /// Spatial type of a column from its data type or physical type
+    ///
+    /// `logicalTypeOptions.srid` applies when the type itself has no SRID.
+    pub fn from_column(column: &Column) -> Option<Self> {
+        let mut spatial = Self::parse(&column.data_type)
+            .or_else(|| column.physical_type.as_deref().and_then(Self::parse))?;
+        if spatial.srid.is_none() {
+            spatial.srid = column.logical_type_options.as_ref().and_then(|o| o.srid);
+        }
+        Some(spatial)
+    }
+
+    /// Native type for a SQL dialect, or `None` if the dialect has no spatial types
+    ///
+    /// PostGIS (`postgres`) keeps shape and SRID in the type, MySQL uses an
+    /// `SRID` attribute, while BigQuery, Snowflake and SQL Server only distinguish
+    /// geometry from geography (BigQuery only supports geography).
+    pub fn to_sql(&self, dialect: &str) -> Option<String> {
+        let kind = match self.kind {
+            SpatialKind::Geometry => "GEOMETRY",
+            SpatialKind::Geography => "GEOGRAPHY",
+        };
+        let native_type = match dialect.to_lowercase().as_str() {
+            "postgres" | "postgresql" | "postgis" => self.to_string(),
+            "bigquery" => "GEOGRAPHY".to_string(),
+            "snowflake" | "sqlserver" | "mssql" => kind.to_string(),
+            "mysql" => {
+                let shape = self.shape.as_deref().unwrap_or("Geometry").to_uppercase();
+                match self.srid {
+                    Some(srid) => format!("{} SRID {}", shape, srid),
+                    None => shape,
+                }
+            }
+            _ => return None,
+        };
+        Some(native_type)
+    }
+
+    /// JSON Schema of a GeoJSON geometry (RFC 7946) holding values of this type
+    pub fn to_json_schema(&self) -> Value {
+        let type_schema = match &self.shape {
+            Some(shape) => json!({"const": shape}),
+            None => json!({"enum": SHAPES}),
+        };
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "type": type_schema,
+                "coordinates": {"type": "array"},
+                "bbox": {"type": "array", "items": {"type": "number"}, "minItems": 4}
+            },
+            "required": ["type"]
+        });
+        match self.shape.as_deref() {
+            Some("Point") => {
+                schema["properties"]["coordinates"] =
+                    json!({"type": "array", "items": {"type": "number"}, "minItems": 2});
+                schema["required"] = json!(["type", "coordinates"]);
+            }
+            Some("GeometryCollection") => {
+                schema["properties"]
+                    .as_object_mut()
+                    .expect("properties is an object")
+                    .remove("coordinates");
+                schema["properties"]["geometries"] =
+                    json!({"type": "array", "items": {"type": "object"}});
+                schema["required"] = json!(["type", "geometries"]);
+            }
+            Some(_) => schema["required"] = json!(["type", "coordinates"]),
+            None => {
+                schema["properties"]["geometries"] =
+                    json!({"type": "array", "items": {"type": "object"}});
+            }
+        }
+        schema
+    }
+}
+
+impl fmt::Display for SpatialType {
+    /// PostGIS-style type such as `GEOGRAPHY(Point, 4326)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            SpatialKind::Geometry => "GEOMETRY",
+            SpatialKind::Geography => "GEOGRAPHY",
+        };
+        match (&self.shape, self.srid) {
+            (Some(shape), Some(srid)) => write!(f, "{}({}, {})", kind, shape, srid),
+            (Some(shape), None) => write!(f, "{}({})", kind, shape),
+            (None, Some(srid)) => write!(f, "{}(Geometry, {})", kind, srid),
+            (None, None) => write!(f, "{}", kind),
+        }
+    }
+}
+
+/// Canonical spelling of a GeoJSON geometry type; unknown shapes are kept as-is
+fn canonical_shape(shape: &str) -> String {
+    SHAPES
+        .iter()
+        .find(|s| s.eq_ignore_ascii_case(shape))
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| shape.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogicalTypeOptions;
+
+    #[test]
+    fn test_parse_spatial_types() {
+        let point = SpatialType::parse("GEOMETRY(POINT, 4326)").unwrap();
+        assert_eq!(
+            point,
+            SpatialType::new(SpatialKind::Geometry)
+                .with_shape("Point")
+                .with_srid(WGS84_SRID)
+        );
+        assert_eq!(
+            SpatialType::parse("geography").unwrap(),
+            SpatialType::new(SpatialKind::Geography)
+        );
+        assert_eq!(
+            SpatialType::parse("geometry(Geometry, 3857)").unwrap(),
+            SpatialType::new(SpatialKind::Geometry).with_srid(3857)
+        );
+        assert_eq!(
+            SpatialType::parse("POINT SRID 4326").unwrap(),
+            SpatialType::new(SpatialKind::Geometry)
+                .with_shape("Point")
+                .with_srid(WGS84_SRID)
+        );
+        assert!(SpatialType::parse("VARCHAR(255)").is_none());
+        assert!(SpatialType::parse("INT").is_none());
+    }
+
+    #[test]
+    fn test_dialect_types() {
+        let spatial = SpatialType::new(SpatialKind::Geography)
+            .with_shape("point")
+            .with_srid(WGS84_SRID);
+        assert_eq!(
+            spatial.to_sql("postgres").as_deref(),
+            Some("GEOGRAPHY(Point, 4326)")
+        );
+        assert_eq!(spatial.to_sql("bigquery").as_deref(), Some("GEOGRAPHY"));
+        assert_eq!(spatial.to_sql("snowflake").as_deref(), Some("GEOGRAPHY"));
+        assert_eq!(spatial.to_sql("mysql").as_deref(), Some("POINT SRID 4326"));
+        assert_eq!(spatial.to_sql("sqlite"), None);
+
+        let mut column = Column::new("area".to_string(), "GEOMETRY".to_string());
+        column.logical_type_options = Some(LogicalTypeOptions {
+            srid: Some(3857),
+            ..Default::default()
+        });
+        let spatial = SpatialType::from_column(&column).unwrap();
+        assert_eq!(
+            spatial.to_sql("postgres").as_deref(),
+            Some("GEOMETRY(Geometry, 3857)")
+        );
+    }
+
+    #[test]
+    fn test_geojson_schema() {
+        let point = SpatialType::new(SpatialKind::Geography).with_shape("Point");
+        let schema = point.to_json_schema();
+        assert_eq!(schema["properties"]["type"]["const"], "Point");
+        assert_eq!(schema["properties"]["coordinates"]["minItems"], 2);
+        assert_eq!(schema["required"], json!(["type", "coordinates"]));
+
+        let any = SpatialType::new(SpatialKind::Geometry).to_json_schema();
+        assert_eq!(
+            any["properties"]["type"]["enum"].as_array().unwrap().len(),
+            7
+        );
+        assert_eq!(any["required"], json!(["type"]));
+    }
+}
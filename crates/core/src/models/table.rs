@@ -5,6 +5,7 @@ use super::enums::{
     DataVaultClassification, DatabaseType, InfrastructureType, MedallionLayer, ModelingLevel,
     SCDPattern,
 };
+use super::id_strategy::IdGenerator;
 use super::tag::Tag;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -271,9 +272,16 @@ impl Table {
     /// );
     /// ```
     pub fn new(name: String, columns: Vec<Column>) -> Self {
+        Self::new_with_generator(name, columns, &IdGenerator::default())
+    }
+
+    /// Create a new table whose ID follows a workspace's ID strategy
+    ///
+    /// The ID is generated from the unqualified name (see
+    /// [`Self::generate_id_with_generator`]).
+    pub fn new_with_generator(name: String, columns: Vec<Column>, ids: &IdGenerator) -> Self {
         let now = Utc::now();
-        // UUIDv4 everywhere (do not derive ids from natural keys like name).
-        let id = Self::generate_id(&name, None, None, None);
+        let id = Self::generate_id_with_generator(ids, &name, None, None);
         Self {
             id,
             name,
@@ -319,9 +327,29 @@ impl Table {
         )
     }
 
+    /// Content fingerprint of this table (see [`crate::models::fingerprint`])
+    ///
+    /// Ignores the table ID, timestamps, canvas position, file and diagram
+    /// locations, and import errors, so only semantic changes alter it.
+    pub fn fingerprint(&self) -> String {
+        super::fingerprint::fingerprint(
+            self,
+            &[
+                "id",
+                "createdAt",
+                "updatedAt",
+                "position",
+                "yamlFilePath",
+                "drawioCellId",
+                "errors",
+            ],
+        )
+    }
+
     /// Generate a UUIDv4 for a new table id.
     ///
     /// Note: params are retained for backward-compatibility with previous deterministic-v5 API.
+    /// Use [`Self::generate_id_with_generator`] to follow a workspace's ID strategy.
     pub fn generate_id(
         _name: &str,
         _database_type: Option<&DatabaseType>,
@@ -331,6 +359,24 @@ impl Table {
         Uuid::new_v4()
     }
 
+    /// Generate an ID for a new table following `ids`
+    ///
+    /// The deterministic strategy derives the ID from the table name,
+    /// qualified by its catalog and schema.
+    pub fn generate_id_with_generator(
+        ids: &IdGenerator,
+        name: &str,
+        catalog_name: Option<&str>,
+        schema_name: Option<&str>,
+    ) -> Uuid {
+        let qualified_name = [catalog_name, schema_name, Some(name)]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(".");
+        ids.generate("table", None, &qualified_name)
+    }
+
     /// Create a Table from imported TableData.
     ///
     /// Converts the import format (TableData) to the internal Table model.
@@ -423,6 +469,12 @@ impl Table {
                 serde_json::to_value(support).unwrap_or_default(),
             );
         }
+        for (key, value) in &table_data.odcs_metadata {
+            table
+                .odcl_metadata
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
 
         table
     }
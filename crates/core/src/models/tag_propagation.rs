@@ -0,0 +1,642 @@
+//! Column-level tag and classification propagation
+//!
+//! Propagates tags and classifications from source columns to the columns derived
+//! from them. Lineage is read from each column's `transformSourceObjects`
+//! (`"table.column"`, or `"table"` for a same-named column) and can be extended with
+//! explicit edges, e.g. from a schema mapping.
+//!
+//! Propagation is driven by [`TagPropagationPolicy`] values and is tracked on the
+//! derived column's custom properties:
+//! - `propagatedTags`: tag -> source column the tag was propagated from
+//! - `propagatedClassification`: source column the classification came from
+//! - `tagPropagationOverrides`: tags (or `"classification"`) the column owner has
+//!   opted out of; these are never propagated and are listed in the report
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::{Column, Table};
+//! use data_modelling_core::models::tag_propagation::{TagPropagationPolicy, TagPropagator};
+//!
+//! let mut email = Column::new("email".to_string(), "STRING".to_string());
+//! email.tags.push("pii".to_string());
+//! let mut contact = Column::new("contact".to_string(), "STRING".to_string());
+//! contact.transform_source_objects.push("customers.email".to_string());
+//!
+//! let mut tables = vec![
+//!     Table::new("customers".to_string(), vec![email]),
+//!     Table::new("mart".to_string(), vec![contact]),
+//! ];
+//! let report = TagPropagator::new()
+//!     .with_policy(TagPropagationPolicy::pii())
+//!     .apply(&mut tables);
+//!
+//! assert_eq!(report.propagated.len(), 1);
+//! assert!(tables[1].columns[0].tags.contains(&"pii".to_string()));
+//! ```
+
+use super::{Column, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Custom property recording which tags were propagated, and from where
+pub const PROPAGATED_TAGS_KEY: &str = "propagatedTags";
+/// Custom property recording where a propagated classification came from
+pub const PROPAGATED_CLASSIFICATION_KEY: &str = "propagatedClassification";
+/// Custom property listing tags a column has opted out of
+pub const PROPAGATION_OVERRIDES_KEY: &str = "tagPropagationOverrides";
+
+/// Override entry that blocks classification propagation
+const CLASSIFICATION_OVERRIDE: &str = "classification";
+
+/// Reference to a column of a table
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ColumnRef {
+    /// Table name
+    pub table: String,
+    /// Column name
+    pub column: String,
+}
+
+impl ColumnRef {
+    /// Create a new column reference
+    pub fn new(table: impl Into<String>, column: impl Into<String>) -> Self {
+        Self {
+            table: table.into(),
+            column: column.into(),
+        }
+    }
+}
+
+impl fmt::Display for ColumnRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.table, self.column)
+    }
+}
+
+/// Policy selecting which tags propagate along lineage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagPropagationPolicy {
+    /// Policy name, reported with each propagated value
+    pub name: String,
+    /// Tag patterns to propagate; a trailing `*` matches any suffix (case-insensitive)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Whether column classifications propagate as well
+    #[serde(default)]
+    pub propagate_classification: bool,
+}
+
+impl TagPropagationPolicy {
+    /// Create a policy that propagates nothing until tags are added
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tags: Vec::new(),
+            propagate_classification: false,
+        }
+    }
+
+    /// Propagate tags matching a pattern (e.g. `"pii"`, `"gdpr:*"`)
+    pub fn with_tag(mut self, pattern: impl Into<String>) -> Self {
+        self.tags.push(pattern.into());
+        self
+    }
+
+    /// Propagate column classifications
+    pub fn with_classification(mut self, propagate: bool) -> Self {
+        self.propagate_classification = propagate;
+        self
+    }
+
+    /// Default policy for personal data: `pii` tags and classifications
+    pub fn pii() -> Self {
+        Self::new("pii")
+            .with_tag("pii")
+            .with_tag("pii:*")
+            .with_classification(true)
+    }
+
+    /// Check whether a tag is covered by this policy
+    pub fn matches(&self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        self.tags.iter().any(|pattern| {
+            let pattern = pattern.trim().to_lowercase();
+            match pattern.strip_suffix('*') {
+                Some(prefix) => tag.starts_with(prefix),
+                None => tag == pattern,
+            }
+        })
+    }
+}
+
+/// Kind of value propagated to a derived column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PropagatedKind {
+    /// Column tag
+    Tag,
+    /// Column classification
+    Classification,
+}
+
+/// A tag or classification applied to a derived column
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropagatedValue {
+    /// Column the value was propagated from
+    pub source: ColumnRef,
+    /// Column the value was applied to
+    pub target: ColumnRef,
+    /// Tag or classification value
+    pub value: String,
+    /// Whether the value is a tag or a classification
+    pub kind: PropagatedKind,
+    /// Name of the policy that propagated the value
+    pub policy: String,
+}
+
+/// A propagation that was skipped because the derived column overrides it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropagationOverride {
+    /// Column the value would have been propagated from
+    pub source: ColumnRef,
+    /// Column that overrides the value
+    pub target: ColumnRef,
+    /// Tag or classification that was not propagated
+    pub value: String,
+    /// Whether the value is a tag or a classification
+    pub kind: PropagatedKind,
+    /// Value kept on the derived column, for classification overrides
+    pub kept: Option<String>,
+}
+
+/// Result of applying propagation policies
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PropagationReport {
+    /// Values newly applied to derived columns
+    pub propagated: Vec<PropagatedValue>,
+    /// Values skipped because of a column override
+    pub overrides: Vec<PropagationOverride>,
+    /// Lineage sources that do not resolve to a known column
+    pub unresolved_sources: Vec<String>,
+}
+
+/// Derived column that carries personal data upstream but has no PII tag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UntaggedPiiFinding {
+    /// The derived column
+    pub column: ColumnRef,
+    /// Upstream PII columns it is derived from (directly or transitively)
+    pub pii_sources: Vec<ColumnRef>,
+}
+
+/// Applies [`TagPropagationPolicy`] values along column lineage
+#[derive(Debug, Clone, Default)]
+pub struct TagPropagator {
+    policies: Vec<TagPropagationPolicy>,
+    edges: Vec<(ColumnRef, ColumnRef)>,
+}
+
+impl TagPropagator {
+    /// Create a propagator without policies
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a propagation policy
+    pub fn with_policy(mut self, policy: TagPropagationPolicy) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    /// Add a lineage edge in addition to the columns' `transformSourceObjects`
+    pub fn with_edge(mut self, source: ColumnRef, target: ColumnRef) -> Self {
+        self.edges.push((source, target));
+        self
+    }
+
+    /// Add lineage edges from a schema mapping between two tables
+    #[cfg(feature = "mapping")]
+    pub fn with_schema_mapping(
+        mut self,
+        source_table: &str,
+        target_table: &str,
+        mapping: &crate::mapping::SchemaMapping,
+    ) -> Self {
+        for field in &mapping.direct_mappings {
+            self.edges.push((
+                ColumnRef::new(source_table, &field.source_path),
+                ColumnRef::new(target_table, &field.target_path),
+            ));
+        }
+        for transform in &mapping.transformations {
+            for source in &transform.source_paths {
+                self.edges.push((
+                    ColumnRef::new(source_table, source),
+                    ColumnRef::new(target_table, &transform.target_path),
+                ));
+            }
+        }
+        self
+    }
+
+    /// Propagate tags and classifications until no derived column changes
+    ///
+    /// Values are propagated transitively along chains of derived columns.
+    /// Re-running is idempotent.
+    pub fn apply(&self, tables: &mut [Table]) -> PropagationReport {
+        let mut report = PropagationReport::default();
+        let edges = self.lineage_edges(tables, Some(&mut report.unresolved_sources));
+
+        // Each pass can move values one hop further; edges.len() + 1 passes
+        // reach a fixpoint even for long chains.
+        for _ in 0..=edges.len() {
+            let mut changed = false;
+            for (source, target) in &edges {
+                let Some(source_column) = find_column(tables, source).cloned() else {
+                    continue;
+                };
+                let Some(target_column) = find_column_mut(tables, target) else {
+                    continue;
+                };
+                changed |=
+                    self.propagate(&source_column, source, target_column, target, &mut report);
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        report
+            .overrides
+            .sort_by(|a, b| (&a.target, &a.value).cmp(&(&b.target, &b.value)));
+        report
+            .overrides
+            .dedup_by(|a, b| a.target == b.target && a.value == b.value && a.source == b.source);
+        report
+    }
+
+    /// Report derived columns whose upstream contains PII but which are not tagged as PII
+    pub fn untagged_derived_pii(&self, tables: &[Table]) -> Vec<UntaggedPiiFinding> {
+        let edges = self.lineage_edges(tables, None);
+        let mut upstream: BTreeMap<&ColumnRef, Vec<&ColumnRef>> = BTreeMap::new();
+        for (source, target) in &edges {
+            upstream.entry(target).or_default().push(source);
+        }
+
+        let mut findings = Vec::new();
+        for target in upstream.keys() {
+            let Some(column) = find_column(tables, target) else {
+                continue;
+            };
+            if is_pii_column(column) {
+                continue;
+            }
+
+            // Walk the lineage upwards, collecting PII columns
+            let mut pii_sources = Vec::new();
+            let mut stack = vec![*target];
+            let mut visited = vec![*target];
+            while let Some(current) = stack.pop() {
+                for source in upstream.get(current).into_iter().flatten() {
+                    if visited.contains(source) {
+                        continue;
+                    }
+                    visited.push(*source);
+                    if find_column(tables, source).is_some_and(is_pii_column) {
+                        pii_sources.push((*source).clone());
+                    }
+                    stack.push(*source);
+                }
+            }
+
+            if !pii_sources.is_empty() {
+                pii_sources.sort();
+                findings.push(UntaggedPiiFinding {
+                    column: (*target).clone(),
+                    pii_sources,
+                });
+            }
+        }
+        findings
+    }
+
+    /// Collect lineage edges from `transformSourceObjects` plus explicit edges
+    fn lineage_edges(
+        &self,
+        tables: &[Table],
+        mut unresolved: Option<&mut Vec<String>>,
+    ) -> Vec<(ColumnRef, ColumnRef)> {
+        let mut edges = Vec::new();
+        for table in tables {
+            for column in &table.columns {
+                let target = ColumnRef::new(&table.name, &column.name);
+                for source in &column.transform_source_objects {
+                    match resolve_source(tables, source, &column.name) {
+                        Some(source) if source != target => edges.push((source, target.clone())),
+                        Some(_) => {}
+                        None => {
+                            if let Some(unresolved) = unresolved.as_deref_mut() {
+                                unresolved.push(format!("{} <- {}", target, source));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for edge in &self.edges {
+            if !edges.contains(edge) {
+                edges.push(edge.clone());
+            }
+        }
+        edges
+    }
+
+    /// Propagate matching values from one column to another; returns true on change
+    fn propagate(
+        &self,
+        source: &Column,
+        source_ref: &ColumnRef,
+        target: &mut Column,
+        target_ref: &ColumnRef,
+        report: &mut PropagationReport,
+    ) -> bool {
+        let overrides = string_list(target.custom_properties.get(PROPAGATION_OVERRIDES_KEY));
+        let mut changed = false;
+
+        for policy in &self.policies {
+            for tag in source.tags.iter().filter(|tag| policy.matches(tag)) {
+                if has_tag(target, tag) {
+                    continue;
+                }
+                if overrides.iter().any(|o| o.eq_ignore_ascii_case(tag)) {
+                    report.overrides.push(PropagationOverride {
+                        source: source_ref.clone(),
+                        target: target_ref.clone(),
+                        value: tag.clone(),
+                        kind: PropagatedKind::Tag,
+                        kept: None,
+                    });
+                    continue;
+                }
+                target.tags.push(tag.clone());
+                record_provenance(target, tag, source_ref);
+                report.propagated.push(PropagatedValue {
+                    source: source_ref.clone(),
+                    target: target_ref.clone(),
+                    value: tag.clone(),
+                    kind: PropagatedKind::Tag,
+                    policy: policy.name.clone(),
+                });
+                changed = true;
+            }
+
+            if !policy.propagate_classification {
+                continue;
+            }
+            let Some(classification) = &source.classification else {
+                continue;
+            };
+            if target.classification.as_deref() == Some(classification.as_str()) {
+                continue;
+            }
+            let propagated_before = target
+                .custom_properties
+                .contains_key(PROPAGATED_CLASSIFICATION_KEY);
+            let explicitly_set = target.classification.is_some() && !propagated_before;
+            let blocked = overrides
+                .iter()
+                .any(|o| o.eq_ignore_ascii_case(CLASSIFICATION_OVERRIDE));
+            let less_restrictive = target.classification.as_deref().is_some_and(|current| {
+                classification_rank(current) >= classification_rank(classification)
+            });
+            if blocked || explicitly_set {
+                report.overrides.push(PropagationOverride {
+                    source: source_ref.clone(),
+                    target: target_ref.clone(),
+                    value: classification.clone(),
+                    kind: PropagatedKind::Classification,
+                    kept: target.classification.clone(),
+                });
+                continue;
+            }
+            if less_restrictive {
+                // Another source already propagated an equal or stricter classification
+                continue;
+            }
+            target.classification = Some(classification.clone());
+            target.custom_properties.insert(
+                PROPAGATED_CLASSIFICATION_KEY.to_string(),
+                serde_json::Value::String(source_ref.to_string()),
+            );
+            report.propagated.push(PropagatedValue {
+                source: source_ref.clone(),
+                target: target_ref.clone(),
+                value: classification.clone(),
+                kind: PropagatedKind::Classification,
+                policy: policy.name.clone(),
+            });
+            changed = true;
+        }
+
+        changed
+    }
+}
+
+/// Whether a column is tagged or classified as personal data
+pub fn is_pii_column(column: &Column) -> bool {
+    column.tags.iter().any(|tag| {
+        let tag = tag.trim().to_lowercase();
+        tag == "pii" || tag.starts_with("pii:")
+    }) || column.classification.as_deref().is_some_and(|c| {
+        matches!(
+            c.trim().to_lowercase().as_str(),
+            "pii" | "personal" | "sensitive"
+        )
+    })
+}
+
+/// Resolve a `transformSourceObjects` entry to a column
+///
+/// Accepts `table.column` (optionally schema-qualified) or a bare table name, in
+/// which case the column with the derived column's name is used.
+fn resolve_source(tables: &[Table], source: &str, column_name: &str) -> Option<ColumnRef> {
+    let parts: Vec<&str> = source.split('.').map(str::trim).collect();
+    if parts.len() >= 2 {
+        let candidate = ColumnRef::new(parts[parts.len() - 2], parts[parts.len() - 1]);
+        if find_column(tables, &candidate).is_some() {
+            return Some(candidate);
+        }
+    }
+    let table = parts.last()?;
+    let candidate = ColumnRef::new(*table, column_name);
+    find_column(tables, &candidate).map(|_| candidate)
+}
+
+fn find_column<'a>(tables: &'a [Table], column: &ColumnRef) -> Option<&'a Column> {
+    tables
+        .iter()
+        .find(|t| t.name.eq_ignore_ascii_case(&column.table))?
+        .columns
+        .iter()
+        .find(|c| c.name.eq_ignore_ascii_case(&column.column))
+}
+
+fn find_column_mut<'a>(tables: &'a mut [Table], column: &ColumnRef) -> Option<&'a mut Column> {
+    tables
+        .iter_mut()
+        .find(|t| t.name.eq_ignore_ascii_case(&column.table))?
+        .columns
+        .iter_mut()
+        .find(|c| c.name.eq_ignore_ascii_case(&column.column))
+}
+
+fn has_tag(column: &Column, tag: &str) -> bool {
+    column.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+fn record_provenance(column: &mut Column, tag: &str, source: &ColumnRef) {
+    let entry = column
+        .custom_properties
+        .entry(PROPAGATED_TAGS_KEY.to_string())
+        .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    if let serde_json::Value::Object(map) = entry {
+        map.insert(
+            tag.to_string(),
+            serde_json::Value::String(source.to_string()),
+        );
+    }
+}
+
+fn string_list(value: Option<&serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        Some(serde_json::Value::String(s)) => s.split(',').map(|s| s.trim().to_string()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Relative strictness of common classification levels
+fn classification_rank(classification: &str) -> u8 {
+    match classification.trim().to_lowercase().as_str() {
+        "public" => 0,
+        "internal" => 1,
+        "confidential" => 2,
+        "pii" | "personal" | "sensitive" => 3,
+        "restricted" | "secret" => 4,
+        _ => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, sources: &[&str]) -> Column {
+        let mut column = Column::new(name.to_string(), "STRING".to_string());
+        column.transform_source_objects = sources.iter().map(|s| s.to_string()).collect();
+        column
+    }
+
+    fn lineage() -> Vec<Table> {
+        let mut email = column("email", &[]);
+        email.tags = vec!["pii".to_string(), "finance".to_string()];
+        email.classification = Some("confidential".to_string());
+        vec![
+            Table::new("customers".to_string(), vec![email, column("id", &[])]),
+            Table::new(
+                "staging".to_string(),
+                vec![
+                    column("email", &["customers"]),
+                    column("id", &["customers.id"]),
+                ],
+            ),
+            Table::new(
+                "mart".to_string(),
+                vec![column("contact", &["staging.email"])],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_propagates_transitively_and_is_idempotent() {
+        let mut tables = lineage();
+        let propagator = TagPropagator::new().with_policy(TagPropagationPolicy::pii());
+        let report = propagator.apply(&mut tables);
+
+        let contact = &tables[2].columns[0];
+        assert_eq!(contact.tags, vec!["pii".to_string()]);
+        assert_eq!(contact.classification.as_deref(), Some("confidential"));
+        assert_eq!(
+            contact.custom_properties[PROPAGATED_TAGS_KEY]["pii"],
+            "staging.email"
+        );
+        // pii tag + classification for staging.email and mart.contact
+        assert_eq!(report.propagated.len(), 4);
+        assert!(report.unresolved_sources.is_empty());
+
+        let again = propagator.apply(&mut tables);
+        assert!(again.propagated.is_empty());
+    }
+
+    #[test]
+    fn test_overrides_are_respected_and_reported() {
+        let mut tables = lineage();
+        let staging_email = &mut tables[1].columns[0];
+        staging_email.custom_properties.insert(
+            PROPAGATION_OVERRIDES_KEY.to_string(),
+            serde_json::json!(["pii"]),
+        );
+        staging_email.classification = Some("internal".to_string());
+
+        let report = TagPropagator::new()
+            .with_policy(TagPropagationPolicy::pii())
+            .apply(&mut tables);
+
+        assert!(tables[1].columns[0].tags.is_empty());
+        assert_eq!(
+            tables[1].columns[0].classification.as_deref(),
+            Some("internal")
+        );
+        assert_eq!(report.overrides.len(), 2);
+        assert!(
+            report
+                .overrides
+                .iter()
+                .any(|o| o.kind == PropagatedKind::Classification
+                    && o.kept.as_deref() == Some("internal"))
+        );
+    }
+
+    #[test]
+    fn test_untagged_derived_pii() {
+        let mut tables = lineage();
+        tables[1].columns[0].custom_properties.insert(
+            PROPAGATION_OVERRIDES_KEY.to_string(),
+            serde_json::json!(["pii"]),
+        );
+        let propagator = TagPropagator::new().with_policy(TagPropagationPolicy::pii());
+        propagator.apply(&mut tables);
+
+        let findings = propagator.untagged_derived_pii(&tables);
+        let columns: Vec<String> = findings.iter().map(|f| f.column.to_string()).collect();
+        assert_eq!(columns, vec!["mart.contact", "staging.email"]);
+        assert_eq!(
+            findings[0].pii_sources,
+            vec![ColumnRef::new("customers", "email")]
+        );
+    }
+
+    #[test]
+    fn test_policy_pattern_matching() {
+        let policy = TagPropagationPolicy::new("gdpr").with_tag("GDPR:*");
+        assert!(policy.matches("gdpr:article-9"));
+        assert!(!policy.matches("gdpr"));
+        assert!(!policy.matches("pii"));
+    }
+}
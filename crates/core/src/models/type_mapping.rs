@@ -0,0 +1,833 @@
+//! Data type mapping registry
+//!
+//! Columns store a canonical data type (e.g. `BIGINT`, `DECIMAL(10,2)`, `STRING`).
+//! Each source/target dialect maps its native types to that canonical type on
+//! import and back on export, and canonical types map to ODCS logical types:
+//!
+//! ```text
+//! native type (avro `long`) -> canonical type (`BIGINT`) -> native type (protobuf `int64`)
+//!                                     |
+//!                                     v
+//!                        ODCS logical type (`integer`)
+//! ```
+//!
+//! [`TypeMappingRegistry::default`] contains the built-in mappings for the `avro`,
+//! `protobuf`, `xsd`, `json-schema`, `bigquery`, `snowflake`, `mssql` and
+//! `oracle` dialects. They are consulted by the SQL, AVRO, Protobuf, XSD and
+//! JSON Schema importers and the SQL, AVRO, Protobuf, BigQuery and JSON Schema
+//! exporters, which take a registry with `with_type_mappings` (or through
+//! [`ImporterRegistry::with_type_mappings`](crate::import::ImporterRegistry::with_type_mappings)
+//! and [`ExportOptions::with_type_mappings`](crate::export::ExportOptions::with_type_mappings)).
+//!
+//! A workspace can extend or override the built-in mappings with a
+//! [`TYPE_MAPPINGS_FILE`] at its root, loaded with
+//! [`ModelLoader::load_type_mappings`](crate::model::ModelLoader::load_type_mappings):
+//!
+//! ```yaml
+//! dialects:
+//!   avro:
+//!     import:
+//!       long: NUMBER(19)
+//!   postgres:
+//!     export:
+//!       string: TEXT
+//!       double: DOUBLE PRECISION
+//! logical:
+//!   number(19): integer
+//! ```
+//!
+//! Type names are matched case-insensitively. A lookup tries the full type first
+//! and then the base type without parameters (`varchar(255)` -> `varchar`).
+//!
+//! Native types that can't be mapped on import (a named AVRO record, a Protobuf
+//! enum, PostgreSQL `hstore`, Snowflake `VARIANT`, ...) are preserved in the
+//! column's `physicalType` and its [`SOURCE_TYPE_PROPERTY`] custom property, so
+//! exporters of the same dialect can write them back instead of `string`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::type_mapping::TypeMappingRegistry;
+//!
+//! let registry = TypeMappingRegistry::from_yaml("dialects:\n  postgres:\n    export:\n      string: TEXT\n").unwrap();
+//! assert_eq!(registry.import_type("avro", "long").as_deref(), Some("BIGINT"));
+//! assert_eq!(registry.export_type("postgres", "STRING").as_deref(), Some("TEXT"));
+//! assert_eq!(registry.logical_type("BIGINT"), ("integer".to_string(), false));
+//! ```
+
+use super::Column;
+use super::odcs::converters::map_data_type_to_logical_type;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashMap};
+
+/// Workspace file holding type mapping overrides
+pub const TYPE_MAPPINGS_FILE: &str = "type-mappings.yaml";
+
+/// Column custom property recording a native type that couldn't be mapped on import
+///
+/// The value is an object such as `{"dialect": "avro", "type": "com.acme.Money"}`.
+pub const SOURCE_TYPE_PROPERTY: &str = "sourceType";
+
+/// Base types understood without a dialect mapping, besides those with a
+/// numeric or temporal ODCS logical type
+const STANDARD_TYPES: &[&str] = &[
+    "array",
+    "binary",
+    "bit",
+    "blob",
+    "bool",
+    "boolean",
+    "bytea",
+    "bytes",
+    "char",
+    "character",
+    "clob",
+    "date",
+    "datetime",
+    "datetime2",
+    "datetimeoffset",
+    "enum",
+    "image",
+    "interval",
+    "json",
+    "jsonb",
+    "longblob",
+    "longtext",
+    "map",
+    "mediumblob",
+    "mediumtext",
+    "money",
+    "nchar",
+    "nclob",
+    "ntext",
+    "null",
+    "nvarchar",
+    "nvarchar2",
+    "object",
+    "real",
+    "bigserial",
+    "serial",
+    "smallserial",
+    "set",
+    "smalldatetime",
+    "string",
+    "struct",
+    "text",
+    "time",
+    "tinyblob",
+    "tinytext",
+    "uniqueidentifier",
+    "uuid",
+    "varbinary",
+    "varchar",
+    "varchar2",
+    "xml",
+];
+
+/// Built-in JSON Schema types -> canonical types
+const JSON_SCHEMA_IMPORT: &[(&str, &str)] = &[
+    ("integer", "INTEGER"),
+    ("number", "DOUBLE"),
+    ("boolean", "BOOLEAN"),
+    ("string", "STRING"),
+    ("null", "NULL"),
+];
+
+/// Built-in canonical types -> JSON Schema types
+///
+/// Formats (`date-time`, `uuid`, ...) are added by the JSON Schema exporter.
+const JSON_SCHEMA_EXPORT: &[(&str, &str)] = &[
+    ("int", "integer"),
+    ("integer", "integer"),
+    ("bigint", "integer"),
+    ("smallint", "integer"),
+    ("tinyint", "integer"),
+    ("float", "number"),
+    ("double", "number"),
+    ("real", "number"),
+    ("decimal", "number"),
+    ("numeric", "number"),
+    ("boolean", "boolean"),
+    ("bool", "boolean"),
+];
+
+/// Built-in AVRO primitive types -> canonical types
+const AVRO_IMPORT: &[(&str, &str)] = &[
+    ("int", "INTEGER"),
+    ("long", "BIGINT"),
+    ("float", "FLOAT"),
+    ("double", "DOUBLE"),
+    ("boolean", "BOOLEAN"),
+    ("bytes", "BYTES"),
+    ("string", "STRING"),
+    ("null", "NULL"),
+];
+
+/// Built-in canonical types -> AVRO primitive types
+const AVRO_EXPORT: &[(&str, &str)] = &[
+    ("int", "int"),
+    ("integer", "int"),
+    ("smallint", "int"),
+    ("tinyint", "int"),
+    ("bigint", "long"),
+    ("float", "float"),
+    ("real", "float"),
+    ("double", "double"),
+    ("decimal", "double"),
+    ("numeric", "double"),
+    ("boolean", "boolean"),
+    ("bool", "boolean"),
+    ("bytes", "bytes"),
+    ("binary", "bytes"),
+    ("varbinary", "bytes"),
+];
+
+/// Built-in Protobuf scalar types -> canonical types
+const PROTOBUF_IMPORT: &[(&str, &str)] = &[
+    ("int32", "INTEGER"),
+    ("int", "INTEGER"),
+    ("int64", "BIGINT"),
+    ("long", "BIGINT"),
+    // Unsigned, zigzag and fixed-width variants: SQL doesn't distinguish them
+    ("uint32", "INTEGER"),
+    ("uint64", "BIGINT"),
+    ("sint32", "INTEGER"),
+    ("sint64", "BIGINT"),
+    ("fixed32", "INTEGER"),
+    ("fixed64", "BIGINT"),
+    ("sfixed32", "INTEGER"),
+    ("sfixed64", "BIGINT"),
+    ("float", "FLOAT"),
+    ("double", "DOUBLE"),
+    ("bool", "BOOLEAN"),
+    ("boolean", "BOOLEAN"),
+    ("bytes", "BYTES"),
+    ("string", "STRING"),
+];
+
+/// Built-in canonical types -> Protobuf scalar types
+const PROTOBUF_EXPORT: &[(&str, &str)] = &[
+    ("int", "int32"),
+    ("integer", "int32"),
+    ("smallint", "int32"),
+    ("tinyint", "int32"),
+    ("int32", "int32"),
+    ("bigint", "int64"),
+    ("int64", "int64"),
+    ("long", "int64"),
+    ("float", "float"),
+    ("real", "float"),
+    ("double", "double"),
+    ("decimal", "double"),
+    ("numeric", "double"),
+    ("boolean", "bool"),
+    ("bool", "bool"),
+    ("bytes", "bytes"),
+    ("binary", "bytes"),
+    ("varbinary", "bytes"),
+    // Temporal types: epoch millis for timestamps, ISO strings otherwise
+    ("timestamp", "int64"),
+    ("datetime", "int64"),
+    ("date", "string"),
+    ("time", "string"),
+    ("uuid", "string"),
+];
+
+/// Built-in XML Schema types -> canonical types
+///
+/// Types without a mapping (e.g. `gYear`, `duration`) fall back to strings.
+const XSD_IMPORT: &[(&str, &str)] = &[
+    ("string", "STRING"),
+    ("normalizedString", "STRING"),
+    ("token", "STRING"),
+    ("language", "STRING"),
+    ("Name", "STRING"),
+    ("NCName", "STRING"),
+    ("NMTOKEN", "STRING"),
+    ("ID", "STRING"),
+    ("IDREF", "STRING"),
+    ("anyURI", "STRING"),
+    ("anyType", "STRING"),
+    ("anySimpleType", "STRING"),
+    ("boolean", "BOOLEAN"),
+    ("decimal", "DECIMAL"),
+    ("integer", "BIGINT"),
+    ("nonNegativeInteger", "BIGINT"),
+    ("positiveInteger", "BIGINT"),
+    ("nonPositiveInteger", "BIGINT"),
+    ("negativeInteger", "BIGINT"),
+    ("long", "BIGINT"),
+    ("int", "INTEGER"),
+    ("short", "SMALLINT"),
+    ("byte", "TINYINT"),
+    ("unsignedLong", "DECIMAL(20,0)"),
+    ("unsignedInt", "BIGINT"),
+    ("unsignedShort", "INTEGER"),
+    ("unsignedByte", "SMALLINT"),
+    ("float", "FLOAT"),
+    ("double", "DOUBLE"),
+    ("date", "DATE"),
+    ("time", "TIME"),
+    ("dateTime", "TIMESTAMP"),
+    ("base64Binary", "BYTES"),
+    ("hexBinary", "BYTES"),
+];
+
+/// Built-in canonical types -> XML Schema types
+const XSD_EXPORT: &[(&str, &str)] = &[
+    ("boolean", "boolean"),
+    ("bool", "boolean"),
+    ("tinyint", "byte"),
+    ("smallint", "short"),
+    ("int", "int"),
+    ("integer", "int"),
+    ("bigint", "long"),
+    ("decimal", "decimal"),
+    ("numeric", "decimal"),
+    ("float", "float"),
+    ("real", "float"),
+    ("double", "double"),
+    ("date", "date"),
+    ("time", "time"),
+    ("timestamp", "dateTime"),
+    ("datetime", "dateTime"),
+    ("bytes", "base64Binary"),
+    ("binary", "base64Binary"),
+];
+
+/// Built-in BigQuery types -> canonical types
+const BIGQUERY_IMPORT: &[(&str, &str)] = &[
+    ("int64", "BIGINT"),
+    ("float64", "DOUBLE"),
+    ("bool", "BOOLEAN"),
+    ("bignumeric", "DECIMAL(76,38)"),
+    ("bigdecimal", "DECIMAL(76,38)"),
+];
+
+/// Built-in canonical types -> BigQuery types
+///
+/// `DECIMAL(p,s)` and the scalar types inside `STRUCT<...>`/`ARRAY<...>` are
+/// converted by the SQL exporter.
+const BIGQUERY_EXPORT: &[(&str, &str)] = &[
+    ("int", "INT64"),
+    ("integer", "INT64"),
+    ("smallint", "INT64"),
+    ("tinyint", "INT64"),
+    ("bigint", "INT64"),
+    ("long", "INT64"),
+    ("float", "FLOAT64"),
+    ("real", "FLOAT64"),
+    ("double", "FLOAT64"),
+    ("double precision", "FLOAT64"),
+    ("boolean", "BOOL"),
+    ("char", "STRING"),
+    ("varchar", "STRING"),
+    ("nvarchar", "STRING"),
+    ("text", "STRING"),
+    ("uuid", "STRING"),
+    ("json", "JSON"),
+    ("jsonb", "JSON"),
+    ("variant", "JSON"),
+    ("object", "JSON"),
+];
+
+/// Built-in Snowflake types -> canonical types
+///
+/// `NUMBER(p,s)` is imported as `DECIMAL(p,s)` by the SQL importer.
+const SNOWFLAKE_IMPORT: &[(&str, &str)] = &[
+    ("variant", "VARIANT"),
+    ("object", "OBJECT"),
+    ("array", "ARRAY<VARIANT>"),
+    ("timestamp_ntz", "TIMESTAMP"),
+];
+
+/// Built-in canonical types -> Snowflake types
+///
+/// `ARRAY<...>`, `STRUCT<...>`, `MAP<...>` and `DECIMAL(p,s)` are converted by
+/// the SQL exporter.
+const SNOWFLAKE_EXPORT: &[(&str, &str)] = &[
+    ("json", "VARIANT"),
+    ("jsonb", "VARIANT"),
+    ("struct", "OBJECT"),
+    ("map", "OBJECT"),
+    ("uuid", "VARCHAR(36)"),
+];
+
+/// Built-in SQL Server types -> canonical types
+///
+/// Sized character types (e.g. `NVARCHAR(100)`) are kept as written.
+const MSSQL_IMPORT: &[(&str, &str)] = &[
+    ("bit", "BOOLEAN"),
+    ("uniqueidentifier", "UUID"),
+    ("datetime2", "TIMESTAMP"),
+    ("datetime", "TIMESTAMP"),
+    ("smalldatetime", "TIMESTAMP"),
+    ("datetimeoffset", "TIMESTAMP WITH TIME ZONE"),
+    ("money", "DECIMAL(19,4)"),
+    ("smallmoney", "DECIMAL(10,4)"),
+    ("nvarchar(max)", "STRING"),
+    ("varchar(max)", "STRING"),
+    ("ntext", "STRING"),
+];
+
+/// Built-in canonical types -> SQL Server types
+const MSSQL_EXPORT: &[(&str, &str)] = &[
+    ("boolean", "BIT"),
+    ("bool", "BIT"),
+    ("uuid", "UNIQUEIDENTIFIER"),
+    ("string", "NVARCHAR(MAX)"),
+    ("text", "NVARCHAR(MAX)"),
+    ("json", "NVARCHAR(MAX)"),
+    ("jsonb", "NVARCHAR(MAX)"),
+    ("timestamp", "DATETIME2"),
+    ("timestamp with time zone", "DATETIMEOFFSET"),
+    ("double", "FLOAT"),
+    ("double precision", "FLOAT"),
+];
+
+/// Built-in Oracle types -> canonical types
+///
+/// `NUMBER(p,s)`, `VARCHAR2(n)` and `RAW(n)` are converted by the SQL importer.
+const ORACLE_IMPORT: &[(&str, &str)] = &[
+    ("clob", "STRING"),
+    ("nclob", "STRING"),
+    ("long", "STRING"),
+    ("date", "TIMESTAMP"),
+    ("binary_float", "FLOAT"),
+    ("binary_double", "DOUBLE"),
+    ("long raw", "BLOB"),
+];
+
+/// Built-in canonical types -> Oracle types
+///
+/// `DECIMAL(p,s)` and `VARCHAR(n)` are converted by the SQL exporter.
+const ORACLE_EXPORT: &[(&str, &str)] = &[
+    ("boolean", "NUMBER(1)"),
+    ("bool", "NUMBER(1)"),
+    ("tinyint", "NUMBER(3)"),
+    ("smallint", "NUMBER(5)"),
+    ("int", "NUMBER(10)"),
+    ("integer", "NUMBER(10)"),
+    ("bigint", "NUMBER(19)"),
+    ("float", "BINARY_FLOAT"),
+    ("real", "BINARY_FLOAT"),
+    ("double", "BINARY_DOUBLE"),
+    ("double precision", "BINARY_DOUBLE"),
+    ("string", "VARCHAR2(4000)"),
+    ("text", "CLOB"),
+    ("json", "CLOB"),
+    ("jsonb", "CLOB"),
+    ("uuid", "VARCHAR2(36)"),
+    ("datetime", "TIMESTAMP"),
+];
+
+static BUILTIN: Lazy<TypeMappingRegistry> = Lazy::new(|| {
+    let mut registry = TypeMappingRegistry::new();
+    for (dialect, import, export) in [
+        ("avro", AVRO_IMPORT, AVRO_EXPORT),
+        ("protobuf", PROTOBUF_IMPORT, PROTOBUF_EXPORT),
+        ("xsd", XSD_IMPORT, XSD_EXPORT),
+        ("json-schema", JSON_SCHEMA_IMPORT, JSON_SCHEMA_EXPORT),
+    ] {
+        // Unknown types fall back to strings in both directions
+        let mapping = registry.dialect_mut(dialect);
+        mapping.import = normalize_keys(import.iter().copied());
+        mapping.export = normalize_keys(export.iter().copied());
+        mapping.import_default = Some("STRING".to_string());
+        mapping.export_default = Some("string".to_string());
+    }
+    // SQL dialects keep types without a mapping as written
+    for (dialect, import, export) in [
+        ("bigquery", BIGQUERY_IMPORT, BIGQUERY_EXPORT),
+        ("snowflake", SNOWFLAKE_IMPORT, SNOWFLAKE_EXPORT),
+        ("mssql", MSSQL_IMPORT, MSSQL_EXPORT),
+        ("sqlserver", MSSQL_IMPORT, MSSQL_EXPORT),
+        ("oracle", ORACLE_IMPORT, ORACLE_EXPORT),
+    ] {
+        let mapping = registry.dialect_mut(dialect);
+        mapping.import = normalize_keys(import.iter().copied());
+        mapping.export = normalize_keys(export.iter().copied());
+    }
+    registry
+});
+
+/// Type mappings of a single dialect
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialectTypeMapping {
+    /// Native type -> canonical data type
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub import: BTreeMap<String, String>,
+    /// Canonical data type used for unmapped native types
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_default: Option<String>,
+    /// Canonical data type -> native type
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub export: BTreeMap<String, String>,
+    /// Native type used for unmapped canonical types
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_default: Option<String>,
+}
+
+/// Registry of data type mappings between dialects, canonical types and ODCS logical types
+///
+/// `TypeMappingRegistry::default()` contains the built-in mappings; use
+/// [`TypeMappingRegistry::new`] for an empty registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeMappingRegistry {
+    /// Mappings per dialect (e.g. `avro`, `protobuf`, `postgres`)
+    #[serde(default)]
+    pub dialects: BTreeMap<String, DialectTypeMapping>,
+    /// Canonical data type -> ODCS logical type overrides
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub logical: BTreeMap<String, String>,
+}
+
+impl Default for TypeMappingRegistry {
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+impl TypeMappingRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            dialects: BTreeMap::new(),
+            logical: BTreeMap::new(),
+        }
+    }
+
+    /// Shared registry with the built-in mappings
+    pub fn builtin() -> &'static TypeMappingRegistry {
+        &BUILTIN
+    }
+
+    /// Load overrides from YAML (see [`TYPE_MAPPINGS_FILE`]) on top of the built-in mappings
+    pub fn from_yaml(content: &str) -> Result<Self, String> {
+        let overrides: TypeMappingRegistry = serde_yaml::from_str(content)
+            .map_err(|e| format!("Failed to parse type mappings: {}", e))?;
+        let mut registry = Self::default();
+        registry.merge(overrides);
+        Ok(registry)
+    }
+
+    /// Merge `overrides` into this registry; overriding entries replace existing ones
+    pub fn merge(&mut self, overrides: TypeMappingRegistry) -> &mut Self {
+        for (dialect, mapping) in overrides.dialects {
+            let target = self.dialect_mut(&dialect);
+            target.import.extend(normalize_keys(mapping.import));
+            target.export.extend(normalize_keys(mapping.export));
+            if mapping.import_default.is_some() {
+                target.import_default = mapping.import_default;
+            }
+            if mapping.export_default.is_some() {
+                target.export_default = mapping.export_default;
+            }
+        }
+        self.logical.extend(normalize_keys(overrides.logical));
+        self
+    }
+
+    /// Map a native type of `dialect` to a canonical data type on import
+    pub fn set_import(
+        &mut self,
+        dialect: &str,
+        native_type: &str,
+        data_type: impl Into<String>,
+    ) -> &mut Self {
+        self.dialect_mut(dialect)
+            .import
+            .insert(normalize_type(native_type), data_type.into());
+        self
+    }
+
+    /// Map a canonical data type to a native type of `dialect` on export
+    pub fn set_export(
+        &mut self,
+        dialect: &str,
+        data_type: &str,
+        native_type: impl Into<String>,
+    ) -> &mut Self {
+        self.dialect_mut(dialect)
+            .export
+            .insert(normalize_type(data_type), native_type.into());
+        self
+    }
+
+    /// Map a canonical data type to an ODCS logical type
+    pub fn set_logical(&mut self, data_type: &str, logical_type: impl Into<String>) -> &mut Self {
+        self.logical
+            .insert(normalize_type(data_type), logical_type.into());
+        self
+    }
+
+    /// Names of all dialects with mappings
+    pub fn dialects(&self) -> Vec<&str> {
+        self.dialects.keys().map(String::as_str).collect()
+    }
+
+    /// Mappings of a dialect, if any
+    pub fn dialect(&self, dialect: &str) -> Option<&DialectTypeMapping> {
+        self.dialects.get(&dialect.trim().to_lowercase())
+    }
+
+    /// Canonical data type for a native type of `dialect`
+    ///
+    /// Falls back to the dialect's `importDefault`; `None` means the native type
+    /// should be kept as-is.
+    pub fn import_type(&self, dialect: &str, native_type: &str) -> Option<String> {
+        let mapping = self.dialect(dialect)?;
+        lookup(&mapping.import, native_type)
+            .or(mapping.import_default.as_ref())
+            .cloned()
+    }
+
+    /// Native type of `dialect` for a canonical data type
+    ///
+    /// Falls back to the dialect's `exportDefault`; `None` means the data type
+    /// should be written as-is.
+    pub fn export_type(&self, dialect: &str, data_type: &str) -> Option<String> {
+        let mapping = self.dialect(dialect)?;
+        lookup(&mapping.export, data_type)
+            .or(mapping.export_default.as_ref())
+            .cloned()
+    }
+
+    /// Whether `dialect` maps a native type explicitly rather than through `importDefault`
+    pub fn has_import(&self, dialect: &str, native_type: &str) -> bool {
+        self.dialect(dialect)
+            .is_some_and(|mapping| lookup(&mapping.import, native_type).is_some())
+    }
+
+    /// Whether a data type is understood without a dialect mapping
+    ///
+    /// Covers `logical` overrides, numeric and temporal types and common
+    /// string, binary and structured SQL types.
+    pub fn is_known_type(&self, data_type: &str) -> bool {
+        if lookup(&self.logical, data_type).is_some() {
+            return true;
+        }
+        // Only the base type counts: `geometry(point, 4326)` is not an integer
+        let normalized = normalize_type(data_type);
+        let base = normalized
+            .split(['(', '<'])
+            .next()
+            .and_then(|base| base.split_whitespace().next())
+            .unwrap_or_default();
+        STANDARD_TYPES.contains(&base) || map_data_type_to_logical_type(base).0 != "string"
+    }
+
+    /// Native type of `dialect` preserved on `column` by an importer
+    ///
+    /// Only returned while the column still has the data type the unmapped
+    /// native type imports as (or an array of it), so edited columns are
+    /// exported from their new data type.
+    pub fn preserved_type<'a>(&self, dialect: &str, column: &'a Column) -> Option<&'a str> {
+        let native_type = source_type(&column.custom_properties, dialect)?;
+        let imported = self.import_type(dialect, native_type)?;
+        let data_type = column.data_type.trim();
+        let element_type = data_type
+            .strip_prefix("ARRAY<")
+            .or_else(|| data_type.strip_prefix("array<"))
+            .and_then(|t| t.strip_suffix('>'))
+            .unwrap_or(data_type);
+        element_type
+            .eq_ignore_ascii_case(&imported)
+            .then_some(native_type)
+    }
+
+    /// ODCS logical type for a canonical data type
+    ///
+    /// Returns `(logical_type, is_array)`, using the `logical` overrides before
+    /// the standard mapping.
+    pub fn logical_type(&self, data_type: &str) -> (String, bool) {
+        match lookup(&self.logical, data_type) {
+            Some(logical_type) => (logical_type.clone(), logical_type == "array"),
+            None => map_data_type_to_logical_type(data_type),
+        }
+    }
+
+    fn dialect_mut(&mut self, dialect: &str) -> &mut DialectTypeMapping {
+        self.dialects
+            .entry(dialect.trim().to_lowercase())
+            .or_default()
+    }
+}
+
+/// [`SOURCE_TYPE_PROPERTY`] value for a native type of `dialect` that couldn't be mapped
+pub fn source_type_property(dialect: &str, native_type: &str) -> Value {
+    json!({"dialect": dialect, "type": native_type})
+}
+
+/// Native type of `dialect` recorded in a [`SOURCE_TYPE_PROPERTY`], if any
+pub fn source_type<'a>(
+    custom_properties: &'a HashMap<String, Value>,
+    dialect: &str,
+) -> Option<&'a str> {
+    let property = custom_properties.get(SOURCE_TYPE_PROPERTY)?;
+    let source_dialect = property.get("dialect")?.as_str()?;
+    if !source_dialect.eq_ignore_ascii_case(dialect) {
+        return None;
+    }
+    property.get("type")?.as_str()
+}
+
+/// Look up a type by its full name, then by its base name without parameters
+fn lookup<'a>(map: &'a BTreeMap<String, String>, type_name: &str) -> Option<&'a String> {
+    let full = normalize_type(type_name);
+    map.get(&full).or_else(|| {
+        let base = full.split('(').next().unwrap_or_default().trim_end();
+        (base != full).then(|| map.get(base)).flatten()
+    })
+}
+
+/// Normalize a type name for lookups: trimmed, lowercase, single spaces
+fn normalize_type(type_name: &str) -> String {
+    type_name
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn normalize_keys<K: AsRef<str>, V: Into<String>>(
+    entries: impl IntoIterator<Item = (K, V)>,
+) -> BTreeMap<String, String> {
+    entries
+        .into_iter()
+        .map(|(k, v)| (normalize_type(k.as_ref()), v.into()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_mappings() {
+        let registry = TypeMappingRegistry::default();
+        assert_eq!(
+            registry.import_type("avro", "long").as_deref(),
+            Some("BIGINT")
+        );
+        assert_eq!(
+            registry.import_type("avro", "fixed").as_deref(),
+            Some("STRING")
+        );
+        assert_eq!(
+            registry.export_type("protobuf", "BIGINT").as_deref(),
+            Some("int64")
+        );
+        assert_eq!(
+            registry.export_type("protobuf", "VARCHAR(255)").as_deref(),
+            Some("string")
+        );
+        assert_eq!(
+            registry.import_type("snowflake", "variant").as_deref(),
+            Some("VARIANT")
+        );
+        assert_eq!(
+            registry.export_type("snowflake", "JSON").as_deref(),
+            Some("VARIANT")
+        );
+        assert_eq!(
+            registry.export_type("bigquery", "VARCHAR(255)").as_deref(),
+            Some("STRING")
+        );
+        // SQL dialects keep unmapped types as they are
+        assert_eq!(registry.import_type("snowflake", "VARCHAR(10)"), None);
+        assert_eq!(registry.export_type("postgres", "STRING"), None);
+    }
+
+    #[test]
+    fn test_yaml_overrides() {
+        let registry = TypeMappingRegistry::from_yaml(
+            r#"
+dialects:
+  AVRO:
+    import:
+      Long: NUMBER(19)
+  postgres:
+    export:
+      string: TEXT
+      double precision: DOUBLE PRECISION
+      varchar: TEXT
+logical:
+  number(19): integer
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            registry.import_type("avro", "long").as_deref(),
+            Some("NUMBER(19)")
+        );
+        // Other built-in entries are kept
+        assert_eq!(
+            registry.import_type("avro", "int").as_deref(),
+            Some("INTEGER")
+        );
+        assert_eq!(
+            registry.export_type("postgres", "varchar(100)").as_deref(),
+            Some("TEXT")
+        );
+        assert_eq!(
+            registry
+                .export_type("postgres", "Double  Precision")
+                .as_deref(),
+            Some("DOUBLE PRECISION")
+        );
+        assert_eq!(
+            registry.logical_type("NUMBER(19)"),
+            ("integer".to_string(), false)
+        );
+        assert_eq!(
+            registry.logical_type("ARRAY<STRING>"),
+            ("array".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn test_known_types_and_preserved_types() {
+        let registry = TypeMappingRegistry::default();
+        assert!(registry.is_known_type("VARCHAR(255)"));
+        assert!(registry.is_known_type("character varying(20)"));
+        assert!(registry.is_known_type("ARRAY<STRING>"));
+        assert!(registry.is_known_type("BIGINT"));
+        assert!(registry.is_known_type("double precision"));
+        assert!(registry.is_known_type("geometry(Point,4326)"));
+        assert!(!registry.is_known_type("hstore"));
+        assert!(!registry.is_known_type("VARIANT"));
+        assert!(registry.has_import("avro", "long"));
+        assert!(!registry.has_import("avro", "com.acme.Money"));
+
+        let mut column = Column::new("price".to_string(), "STRING".to_string());
+        column.custom_properties.insert(
+            SOURCE_TYPE_PROPERTY.to_string(),
+            source_type_property("avro", "com.acme.Money"),
+        );
+        assert_eq!(
+            registry.preserved_type("avro", &column),
+            Some("com.acme.Money")
+        );
+        assert_eq!(registry.preserved_type("protobuf", &column), None);
+
+        column.data_type = "ARRAY<STRING>".to_string();
+        assert_eq!(
+            registry.preserved_type("avro", &column),
+            Some("com.acme.Money")
+        );
+
+        // An edited data type takes precedence over the preserved type
+        column.data_type = "BIGINT".to_string();
+        assert_eq!(registry.preserved_type("avro", &column), None);
+    }
+
+    #[test]
+    fn test_invalid_yaml() {
+        assert!(TypeMappingRegistry::from_yaml("dialects: [avro]").is_err());
+    }
+}
@@ -0,0 +1,351 @@
+//! Embedding vector data types
+//!
+//! Columns holding ML embeddings use a fixed-length numeric vector type with an
+//! optional distance metric used for similarity search. Data types like
+//! pgvector's `vector(1536)`, Snowflake's `VECTOR(FLOAT, 256)` or DuckDB's
+//! fixed-size `FLOAT[768]` arrays are recognised; the dimension and metric can
+//! also be set with `logicalTypeOptions.dimension` and
+//! `logicalTypeOptions.distanceMetric`.
+//!
+//! Exporters use [`VectorType::to_sql`] for the native type of a SQL dialect,
+//! [`VectorType::index_operator_class`] for pgvector indexes and, with the
+//! `arrow` feature, `VectorType::to_arrow_type` for Parquet fixed-size lists.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::vector::{DistanceMetric, VectorType};
+//!
+//! let vector = VectorType::parse("vector(1536)").unwrap().with_metric(DistanceMetric::Cosine);
+//! assert_eq!(vector.dimension, Some(1536));
+//! assert_eq!(vector.to_string(), "VECTOR(1536)");
+//! assert_eq!(vector.to_sql("duckdb").as_deref(), Some("FLOAT[1536]"));
+//! assert_eq!(vector.index_operator_class(), Some("vector_cosine_ops"));
+//! ```
+
+use super::Column;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::fmt;
+
+/// Numeric type of vector elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorElement {
+    /// 32-bit floating point (the default for embeddings)
+    #[default]
+    Float,
+    /// 64-bit floating point
+    Double,
+    /// 32-bit integer
+    Integer,
+}
+
+impl VectorElement {
+    fn parse(element_type: &str) -> Option<Self> {
+        match element_type.trim().to_uppercase().as_str() {
+            "FLOAT" | "FLOAT4" | "REAL" | "FLOAT32" => Some(Self::Float),
+            "DOUBLE" | "FLOAT8" | "DOUBLE PRECISION" | "FLOAT64" => Some(Self::Double),
+            "INT" | "INTEGER" | "INT4" | "INT32" => Some(Self::Integer),
+            _ => None,
+        }
+    }
+
+    fn sql_name(self) -> &'static str {
+        match self {
+            Self::Float => "FLOAT",
+            Self::Double => "DOUBLE",
+            Self::Integer => "INTEGER",
+        }
+    }
+}
+
+/// Distance metric used to compare vectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DistanceMetric {
+    /// Cosine distance
+    Cosine,
+    /// Euclidean (L2) distance
+    Euclidean,
+    /// Negative inner (dot) product
+    InnerProduct,
+}
+
+impl DistanceMetric {
+    /// Parse a metric name such as `cosine`, `l2` or `dot`
+    pub fn parse(metric: &str) -> Option<Self> {
+        match metric
+            .trim()
+            .to_lowercase()
+            .replace(['_', '-', ' '], "")
+            .as_str()
+        {
+            "cosine" | "cos" => Some(Self::Cosine),
+            "euclidean" | "l2" | "l2sq" => Some(Self::Euclidean),
+            "innerproduct" | "ip" | "dot" | "dotproduct" => Some(Self::InnerProduct),
+            _ => None,
+        }
+    }
+
+    /// Name as written to `logicalTypeOptions.distanceMetric`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cosine => "cosine",
+            Self::Euclidean => "euclidean",
+            Self::InnerProduct => "innerProduct",
+        }
+    }
+
+    /// Metric name of DuckDB's vector similarity search extension
+    pub fn duckdb_name(self) -> &'static str {
+        match self {
+            Self::Cosine => "cosine",
+            Self::Euclidean => "l2sq",
+            Self::InnerProduct => "ip",
+        }
+    }
+}
+
+impl fmt::Display for DistanceMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An embedding vector data type with optional dimension and distance metric
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorType {
+    /// Element type
+    #[serde(default)]
+    pub element: VectorElement,
+    /// Number of elements; `None` allows any length
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<u32>,
+    /// Metric used for similarity search
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<DistanceMetric>,
+}
+
+impl VectorType {
+    /// Create a float vector with `dimension` elements
+    pub fn new(dimension: u32) -> Self {
+        Self {
+            dimension: Some(dimension),
+            ..Default::default()
+        }
+    }
+
+    /// Set the element type
+    pub fn with_element(mut self, element: VectorElement) -> Self {
+        self.element = element;
+        self
+    }
+
+    /// Set the distance metric
+    pub fn with_metric(mut self, metric: DistanceMetric) -> Self {
+        self.metric = Some(metric);
+        self
+    }
+
+    /// Parse a vector data type, returning `None` for non-vector types
+    ///
+    /// Variable-length arrays such as `FLOAT[]` or `ARRAY<FLOAT>` are not vectors.
+    pub fn parse(data_type: &str) -> Option<Self> {
+        let data_type = data_type.trim();
+
+        // DuckDB fixed-size arrays: FLOAT[768]
+        if let Some(element_type) = data_type.strip_suffix(']') {
+            let (element_type, dimension) = element_type.rsplit_once('[')?;
+            return Some(Self {
+                element: VectorElement::parse(element_type)?,
+                dimension: Some(dimension.trim().parse().ok()?),
+                metric: None,
+            });
+        }
+
+        let (base, params) = match data_type.split_once('(') {
+            Some((base, rest)) => (base.trim(), rest.trim_end().strip_suffix(')')?),
+            None => (data_type, ""),
+        };
+        if !base.eq_ignore_ascii_case("vector") && !base.eq_ignore_ascii_case("embedding") {
+            return None;
+        }
+        let mut vector = Self::default();
+        for param in params.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            match param.parse::<u32>() {
+                Ok(dimension) => vector.dimension = Some(dimension),
+                // Snowflake: VECTOR(FLOAT, 256)
+                Err(_) => vector.element = VectorElement::parse(param)?,
+            }
+        }
+        Some(vector)
+    }
+
+    /// Vector type of a column from its data type or physical type
+    ///
+    /// `logicalTypeOptions.dimension` and `logicalTypeOptions.distanceMetric`
+    /// apply when the type itself doesn't set them.
+    pub fn from_column(column: &Column) -> Option<Self> {
+        let mut vector = Self::parse(&column.data_type)
+            .or_else(|| column.physical_type.as_deref().and_then(Self::parse))?;
+        if let Some(opts) = &column.logical_type_options {
+            if vector.dimension.is_none() {
+                vector.dimension = opts.dimension.and_then(|d| u32::try_from(d).ok());
+            }
+            vector.metric = opts
+                .distance_metric
+                .as_deref()
+                .and_then(DistanceMetric::parse);
+        }
+        Some(vector)
+    }
+
+    /// Native type for a SQL dialect, or `None` if the dialect has no vector type
+    ///
+    /// PostgreSQL uses pgvector's `vector(n)` (single precision only), DuckDB a
+    /// fixed-size array, Snowflake `VECTOR(FLOAT, n)` (float or integer elements)
+    /// and BigQuery/Databricks a plain array.
+    pub fn to_sql(&self, dialect: &str) -> Option<String> {
+        let native_type = match dialect.to_lowercase().as_str() {
+            "postgres" | "postgresql" | "pgvector" => match self.dimension {
+                Some(dimension) => format!("vector({})", dimension),
+                None => "vector".to_string(),
+            },
+            "duckdb" => format!(
+                "{}[{}]",
+                self.element.sql_name(),
+                self.dimension.map(|d| d.to_string()).unwrap_or_default()
+            ),
+            "snowflake" => {
+                let element = match self.element {
+                    VectorElement::Integer => "INT",
+                    _ => "FLOAT",
+                };
+                format!("VECTOR({}, {})", element, self.dimension?)
+            }
+            "bigquery" => match self.element {
+                VectorElement::Integer => "ARRAY<INT64>".to_string(),
+                _ => "ARRAY<FLOAT64>".to_string(),
+            },
+            "databricks" | "spark" => format!("ARRAY<{}>", self.element.sql_name()),
+            _ => return None,
+        };
+        Some(native_type)
+    }
+
+    /// pgvector operator class for an index using this vector's metric
+    pub fn index_operator_class(&self) -> Option<&'static str> {
+        Some(match self.metric? {
+            DistanceMetric::Cosine => "vector_cosine_ops",
+            DistanceMetric::Euclidean => "vector_l2_ops",
+            DistanceMetric::InnerProduct => "vector_ip_ops",
+        })
+    }
+
+    /// JSON Schema of an array holding values of this type
+    pub fn to_json_schema(&self) -> Value {
+        let item_type = match self.element {
+            VectorElement::Integer => "integer",
+            _ => "number",
+        };
+        let mut schema = json!({"type": "array", "items": {"type": item_type}});
+        if let Some(dimension) = self.dimension {
+            schema["minItems"] = json!(dimension);
+            schema["maxItems"] = json!(dimension);
+        }
+        schema
+    }
+
+    /// Arrow fixed-size list type, written to Parquet as a list with a fixed length
+    #[cfg(feature = "arrow")]
+    pub fn to_arrow_type(&self) -> Option<arrow::datatypes::DataType> {
+        use arrow::datatypes::{DataType, Field};
+
+        let item_type = match self.element {
+            VectorElement::Float => DataType::Float32,
+            VectorElement::Double => DataType::Float64,
+            VectorElement::Integer => DataType::Int32,
+        };
+        let dimension = i32::try_from(self.dimension?).ok()?;
+        Some(DataType::FixedSizeList(
+            std::sync::Arc::new(Field::new("item", item_type, false)),
+            dimension,
+        ))
+    }
+}
+
+impl fmt::Display for VectorType {
+    /// Canonical type such as `VECTOR(1536)` or `VECTOR(DOUBLE, 3)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.element, self.dimension) {
+            (VectorElement::Float, Some(dimension)) => write!(f, "VECTOR({})", dimension),
+            (VectorElement::Float, None) => write!(f, "VECTOR"),
+            (element, Some(dimension)) => {
+                write!(f, "VECTOR({}, {})", element.sql_name(), dimension)
+            }
+            (element, None) => write!(f, "VECTOR({})", element.sql_name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogicalTypeOptions;
+
+    #[test]
+    fn test_parse_vector_types() {
+        assert_eq!(
+            VectorType::parse("vector(1536)"),
+            Some(VectorType::new(1536))
+        );
+        assert_eq!(VectorType::parse("VECTOR"), Some(VectorType::default()));
+        assert_eq!(
+            VectorType::parse("VECTOR(INT, 256)"),
+            Some(VectorType::new(256).with_element(VectorElement::Integer))
+        );
+        assert_eq!(
+            VectorType::parse("DOUBLE[3]"),
+            Some(VectorType::new(3).with_element(VectorElement::Double))
+        );
+        assert!(VectorType::parse("FLOAT[]").is_none());
+        assert!(VectorType::parse("ARRAY<FLOAT>").is_none());
+        assert!(VectorType::parse("VARCHAR(10)").is_none());
+        assert!(VectorType::parse("VECTOR(GEOMETRY, 3)").is_none());
+    }
+
+    #[test]
+    fn test_dialect_types() {
+        let vector = VectorType::new(768);
+        assert_eq!(vector.to_sql("postgres").as_deref(), Some("vector(768)"));
+        assert_eq!(vector.to_sql("duckdb").as_deref(), Some("FLOAT[768]"));
+        assert_eq!(
+            vector.to_sql("snowflake").as_deref(),
+            Some("VECTOR(FLOAT, 768)")
+        );
+        assert_eq!(vector.to_sql("bigquery").as_deref(), Some("ARRAY<FLOAT64>"));
+        assert_eq!(vector.to_sql("mysql"), None);
+        assert_eq!(VectorType::default().to_sql("snowflake"), None);
+    }
+
+    #[test]
+    fn test_from_column_options() {
+        let mut column = Column::new("embedding".to_string(), "VECTOR".to_string());
+        column.logical_type_options = Some(LogicalTypeOptions {
+            dimension: Some(384),
+            distance_metric: Some("l2".to_string()),
+            ..Default::default()
+        });
+        let vector = VectorType::from_column(&column).unwrap();
+        assert_eq!(vector.dimension, Some(384));
+        assert_eq!(vector.metric, Some(DistanceMetric::Euclidean));
+        assert_eq!(vector.index_operator_class(), Some("vector_l2_ops"));
+
+        let schema = vector.to_json_schema();
+        assert_eq!(schema["items"]["type"], "number");
+        assert_eq!(schema["minItems"], 384);
+        assert_eq!(schema["maxItems"], 384);
+    }
+}
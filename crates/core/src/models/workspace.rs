@@ -19,8 +19,10 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::Relationship;
+use super::classification_rules::ClassificationRule;
 use super::domain_config::ViewPosition;
 use super::enums::{AuthMethod, EnvironmentStatus, InfrastructureType};
+use super::id_strategy::{IdGenerator, IdStrategy};
 use super::table::{ContactDetails, SlaProperty};
 
 /// Asset reference within a workspace
@@ -78,6 +80,8 @@ pub enum AssetType {
     Sketch,
     /// Sketch index file
     SketchIndex,
+    /// Reusable property definitions file
+    Definitions,
 }
 
 impl AssetType {
@@ -98,6 +102,7 @@ impl AssetType {
             AssetType::KnowledgeIndex => "yaml",
             AssetType::Sketch => "sketch.yaml",
             AssetType::SketchIndex => "yaml",
+            AssetType::Definitions => "yaml",
         }
     }
 
@@ -109,6 +114,7 @@ impl AssetType {
             AssetType::DecisionIndex => Some("decisions.yaml"),
             AssetType::KnowledgeIndex => Some("knowledge.yaml"),
             AssetType::SketchIndex => Some("sketches.yaml"),
+            AssetType::Definitions => Some("definitions.yaml"),
             _ => None,
         }
     }
@@ -122,6 +128,7 @@ impl AssetType {
                 | AssetType::DecisionIndex
                 | AssetType::KnowledgeIndex
                 | AssetType::SketchIndex
+                | AssetType::Definitions
         )
     }
 
@@ -137,6 +144,8 @@ impl AssetType {
             Some(AssetType::KnowledgeIndex)
         } else if filename == "sketches.yaml" {
             Some(AssetType::SketchIndex)
+        } else if filename == "definitions.yaml" {
+            Some(AssetType::Definitions)
         } else if filename.ends_with(".odcs.yaml") {
             Some(AssetType::Odcs)
         } else if filename.ends_with(".odps.yaml") {
@@ -168,6 +177,7 @@ impl AssetType {
             "decisions.yaml",
             "knowledge.yaml",
             "sketches.yaml",
+            "definitions.yaml",
             ".odcs.yaml",
             ".odps.yaml",
             ".cads.yaml",
@@ -408,6 +418,9 @@ pub struct Workspace {
     /// Optional workspace description (displayed in UI and README)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Tenant owning the workspace in multi-tenant deployments (ODCS `tenant`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
     /// Domain references with their systems
     #[serde(default)]
     pub domains: Vec<DomainReference>,
@@ -417,6 +430,20 @@ pub struct Workspace {
     /// Relationships between assets in this workspace
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub relationships: Vec<Relationship>,
+    /// How IDs for newly created entities are generated (defaults to random UUIDv4)
+    #[serde(
+        default,
+        skip_serializing_if = "IdStrategy::is_default",
+        alias = "id_strategy"
+    )]
+    pub id_strategy: IdStrategy,
+    /// Rules for bulk classification and tagging of columns
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        alias = "classification_rules"
+    )]
+    pub classification_rules: Vec<ClassificationRule>,
 }
 
 impl Workspace {
@@ -430,9 +457,12 @@ impl Workspace {
             created_at: now,
             last_modified_at: now,
             description: None,
+            tenant: None,
             domains: Vec::new(),
             assets: Vec::new(),
             relationships: Vec::new(),
+            id_strategy: IdStrategy::default(),
+            classification_rules: Vec::new(),
         }
     }
 
@@ -446,10 +476,77 @@ impl Workspace {
             created_at: now,
             last_modified_at: now,
             description: None,
+            tenant: None,
             domains: Vec::new(),
             assets: Vec::new(),
             relationships: Vec::new(),
+            id_strategy: IdStrategy::default(),
+            classification_rules: Vec::new(),
+        }
+    }
+
+    /// Create a workspace whose own ID and new entity IDs follow `strategy`
+    ///
+    /// With [`IdStrategy::Deterministic`] the workspace ID is derived from its
+    /// name, so the same workspace created in two environments shares an ID.
+    pub fn with_id_strategy(name: String, owner_id: Uuid, strategy: IdStrategy) -> Self {
+        let id = strategy.generate(&name, None, "workspace", &name);
+        let mut workspace = Self::with_id(id, name, owner_id);
+        workspace.id_strategy = strategy;
+        workspace
+    }
+
+    /// Assign the workspace to a tenant
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Get a generator for the IDs of entities created for this workspace
+    ///
+    /// Pass it to constructors and importers creating tables, contracts or
+    /// domains outside of the workspace, so they follow its ID strategy.
+    pub fn id_generator(&self) -> IdGenerator {
+        IdGenerator::new(self.id_strategy, self.name.clone())
+    }
+
+    /// Generate an ID for a new entity using the workspace's ID strategy
+    ///
+    /// `kind` is the entity type (e.g. `domain`, `system`, `table`) and `domain`
+    /// the owning domain name, if any.
+    pub fn generate_id(&self, kind: &str, domain: Option<&str>, name: &str) -> Uuid {
+        self.id_strategy.generate(&self.name, domain, kind, name)
+    }
+
+    /// Create a domain with a generated ID, returning its ID
+    ///
+    /// If a domain with the same name already exists its ID is returned unchanged.
+    pub fn create_domain(&mut self, domain_name: String) -> Uuid {
+        if let Some(existing) = self.get_domain_by_name(&domain_name) {
+            return existing.id;
+        }
+        let id = self.generate_id("domain", None, &domain_name);
+        self.add_domain(id, domain_name);
+        id
+    }
+
+    /// Create a system in a domain with a generated ID, returning its ID
+    ///
+    /// Returns `None` if the domain does not exist. If the domain already has a
+    /// system with the same name its ID is returned unchanged.
+    pub fn create_system(
+        &mut self,
+        domain_name: &str,
+        system_name: String,
+        description: Option<String>,
+    ) -> Option<Uuid> {
+        let domain = self.get_domain_by_name(domain_name)?;
+        if let Some(existing) = domain.systems.iter().find(|s| s.name == system_name) {
+            return Some(existing.id);
         }
+        let id = self.generate_id("system", Some(domain_name), &system_name);
+        self.add_system_to_domain(domain_name, id, system_name, description)
+            .then_some(id)
     }
 
     /// Add a relationship to the workspace
@@ -949,6 +1046,10 @@ mod tests {
             AssetType::from_filename("relationships.yaml"),
             Some(AssetType::Relationships)
         );
+        assert_eq!(
+            AssetType::from_filename("definitions.yaml"),
+            Some(AssetType::Definitions)
+        );
         assert_eq!(
             AssetType::from_filename("test.odcs.yaml"),
             Some(AssetType::Odcs)
@@ -1315,4 +1416,115 @@ systems: []
         assert!(parsed.table_visibility.is_none());
         assert_eq!(parsed.name, "legacy-domain");
     }
+
+    #[test]
+    fn test_deterministic_id_strategy_reproduces_ids() {
+        let mut first = Workspace::with_id_strategy(
+            "enterprise".to_string(),
+            Uuid::new_v4(),
+            IdStrategy::Deterministic,
+        );
+        let mut second = Workspace::with_id_strategy(
+            "enterprise".to_string(),
+            Uuid::new_v4(),
+            IdStrategy::Deterministic,
+        );
+        assert_eq!(first.id, second.id);
+
+        let domain_id = first.create_domain("finance".to_string());
+        assert_eq!(domain_id, second.create_domain("finance".to_string()));
+        assert_eq!(first.create_domain("finance".to_string()), domain_id);
+        assert_eq!(first.domains.len(), 1);
+
+        let system_id = first.create_system("finance", "ledger".to_string(), None);
+        assert!(system_id.is_some());
+        assert_eq!(
+            system_id,
+            second.create_system("finance", "ledger".to_string(), None)
+        );
+        assert_eq!(
+            first.create_system("risk", "ledger".to_string(), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_id_generator_follows_workspace_strategy() {
+        use crate::import::avro::AvroImporter;
+        use crate::models::domain::{Domain, System};
+        use crate::models::odcs::ODCSContract;
+        use crate::models::{InfrastructureType, Table};
+
+        let mut workspace = Workspace::with_id_strategy(
+            "enterprise".to_string(),
+            Uuid::new_v4(),
+            IdStrategy::Deterministic,
+        );
+        let ids = workspace.id_generator();
+        let domain_id = workspace.create_domain("finance".to_string());
+        let system_id = workspace.create_system("finance", "ledger".to_string(), None);
+
+        let domain = Domain::new_with_generator("finance".to_string(), &ids);
+        assert_eq!(domain.id, domain_id);
+        let system = System::new_with_generator(
+            "ledger".to_string(),
+            InfrastructureType::PostgreSQL,
+            &domain,
+            &ids,
+        );
+        assert_eq!(Some(system.id), system_id);
+        assert_eq!(system.domain_id, domain_id);
+
+        let table = Table::new_with_generator("orders".to_string(), Vec::new(), &ids);
+        assert_eq!(
+            table.id,
+            Table::generate_id_with_generator(&ids, "orders", None, None)
+        );
+        assert_ne!(
+            table.id,
+            Table::generate_id_with_generator(&ids, "orders", None, Some("sales"))
+        );
+        let contract = ODCSContract::new_with_generator("orders", "1.0.0", &ids);
+        assert_eq!(
+            contract.id,
+            ODCSContract::new_with_generator("orders", "2.0.0", &ids).id
+        );
+
+        let schema =
+            r#"{"type": "record", "name": "orders", "fields": [{"name": "id", "type": "long"}]}"#;
+        let imported = AvroImporter::new()
+            .with_id_generator(ids)
+            .import(schema)
+            .unwrap();
+        assert_eq!(imported.tables[0].id, Some(table.id.to_string()));
+
+        workspace.id_strategy = IdStrategy::TimeOrdered;
+        let table =
+            Table::new_with_generator("orders".to_string(), Vec::new(), &workspace.id_generator());
+        assert_eq!(table.id.get_version_num(), 7);
+        assert_eq!(
+            Table::new("orders".to_string(), Vec::new())
+                .id
+                .get_version_num(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_id_strategy_serialization() {
+        let workspace = Workspace::new("enterprise".to_string(), Uuid::new_v4());
+        assert!(!workspace.to_yaml().unwrap().contains("idStrategy"));
+        assert_eq!(
+            workspace.generate_id("domain", None, "x").get_version_num(),
+            4
+        );
+
+        let mut workspace = workspace;
+        workspace.id_strategy = IdStrategy::TimeOrdered;
+        let yaml = workspace.to_yaml().unwrap();
+        assert!(yaml.contains("idStrategy: timeOrdered"));
+        let parsed = Workspace::from_yaml(&yaml).unwrap();
+        assert_eq!(parsed.id_strategy, IdStrategy::TimeOrdered);
+        assert_eq!(parsed.generate_id("domain", None, "x").get_version_num(), 7);
+    }
 }
@@ -0,0 +1,1234 @@
+//! Workspace templates
+//!
+//! Scaffolds a new workspace from a template: `workspace.yaml`, standard domains
+//! and systems, naming conventions, example ODCS contracts and starter decision
+//! records. Built-in templates are listed by [`WorkspaceTemplate::all`]; custom
+//! templates are [`TemplateDefinition`]s, usually loaded from YAML:
+//!
+//! ```yaml
+//! name: payments
+//! description: Payment processing workspace
+//! idStrategy: deterministic
+//! naming:
+//!   tableCase: snake_case
+//!   columnCase: snake_case
+//!   tablePrefixes: [stg_, dim_, fct_]
+//! domains:
+//!   - name: payments
+//!     systems: [ledger]
+//! contracts:
+//!   - domain: payments
+//!     system: ledger
+//!     name: fct_payments
+//!     columns:
+//!       - { name: payment_id, logicalType: integer, primaryKey: true }
+//! ```
+//!
+//! Identifiers follow the template's [`IdStrategy`]; with the default (random)
+//! strategy scaffolded workspaces never share UUIDs with each other.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::Workspace;
+//! use data_modelling_core::models::workspace_template::WorkspaceTemplate;
+//!
+//! let scaffold = Workspace::scaffold("analytics", WorkspaceTemplate::Medallion).unwrap();
+//! assert_eq!(scaffold.workspace.domains.len(), 1);
+//! assert!(scaffold.files.iter().any(|f| f.path == "workspace.yaml"));
+//! ```
+
+use super::decision::{Decision, DecisionIndex, DecisionStatus};
+use super::id_strategy::IdStrategy;
+use super::odcs::{ODCSContract, Property, SchemaObject};
+use super::workspace::{AssetReference, AssetType, Workspace};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// File name of the naming conventions written by scaffolding
+pub const NAMING_CONVENTIONS_FILE: &str = "naming-conventions.yaml";
+
+/// Built-in workspace templates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceTemplate {
+    /// One domain with a single system and an example contract
+    #[default]
+    Simple,
+    /// Bronze/silver/gold layers with lineage between the example contracts
+    Medallion,
+    /// Data mesh starter: several business domains, each owning its own system and contracts
+    #[serde(rename = "datamesh")]
+    DataMesh,
+    /// Dimensional warehouse with staging, dimension and fact tables
+    Warehouse,
+    /// Event topics with schema contracts and a stream-processing consumer
+    #[serde(rename = "streaming")]
+    EventStreaming,
+}
+
+impl WorkspaceTemplate {
+    /// All built-in templates
+    pub fn all() -> &'static [WorkspaceTemplate] {
+        &[
+            WorkspaceTemplate::Simple,
+            WorkspaceTemplate::Medallion,
+            WorkspaceTemplate::DataMesh,
+            WorkspaceTemplate::Warehouse,
+            WorkspaceTemplate::EventStreaming,
+        ]
+    }
+
+    /// Template name as used on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkspaceTemplate::Simple => "simple",
+            WorkspaceTemplate::Medallion => "medallion",
+            WorkspaceTemplate::DataMesh => "datamesh",
+            WorkspaceTemplate::Warehouse => "warehouse",
+            WorkspaceTemplate::EventStreaming => "streaming",
+        }
+    }
+
+    /// Short description of the template
+    pub fn description(&self) -> &'static str {
+        match self {
+            WorkspaceTemplate::Simple => "Single domain and system with an example contract",
+            WorkspaceTemplate::Medallion => "Bronze, silver and gold layers with lineage",
+            WorkspaceTemplate::DataMesh => {
+                "Data mesh starter with domain-owned systems and contracts"
+            }
+            WorkspaceTemplate::Warehouse => {
+                "Dimensional warehouse with staging, dimensions and facts"
+            }
+            WorkspaceTemplate::EventStreaming => {
+                "Event topics with schema contracts and a stream processor"
+            }
+        }
+    }
+
+    /// The template as a [`TemplateDefinition`], e.g. as a starting point for a custom template
+    pub fn definition(&self) -> TemplateDefinition {
+        let spec = self.spec();
+        TemplateDefinition {
+            name: self.name().to_string(),
+            description: Some(self.description().to_string()),
+            id_strategy: IdStrategy::default(),
+            naming: NamingConventions {
+                table_case: Some(NameCase::Snake),
+                column_case: Some(NameCase::Snake),
+                table_prefixes: spec.table_prefixes.iter().map(|p| p.to_string()).collect(),
+            },
+            domains: spec
+                .domains
+                .iter()
+                .map(|(name, description, systems)| TemplateDomain {
+                    name: name.to_string(),
+                    description: Some(description.to_string()),
+                    systems: systems.iter().map(|s| s.to_string()).collect(),
+                })
+                .collect(),
+            contracts: spec
+                .contracts
+                .iter()
+                .map(|contract| TemplateContract {
+                    domain: contract.domain.to_string(),
+                    system: Some(contract.system.to_string()),
+                    name: contract.name.to_string(),
+                    description: Some(contract.description.to_string()),
+                    columns: contract
+                        .columns
+                        .iter()
+                        .map(|(name, logical_type, primary_key, description, sources)| {
+                            TemplateColumn {
+                                name: name.to_string(),
+                                logical_type: logical_type.to_string(),
+                                primary_key: *primary_key,
+                                description: Some(description.to_string()),
+                                sources: sources.iter().map(|s| s.to_string()).collect(),
+                            }
+                        })
+                        .collect(),
+                })
+                .collect(),
+            decisions: spec
+                .decisions
+                .iter()
+                .map(|(title, context, decision)| TemplateDecision {
+                    title: title.to_string(),
+                    context: context.to_string(),
+                    decision: decision.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn spec(&self) -> TemplateSpec {
+        match self {
+            WorkspaceTemplate::Simple => TemplateSpec {
+                domains: &[("core", "Core business entities", &["warehouse"])],
+                contracts: &[ContractSpec {
+                    domain: "core",
+                    system: "warehouse",
+                    name: "customers",
+                    description: "Customer master data",
+                    columns: &[
+                        (
+                            "customer_id",
+                            "integer",
+                            true,
+                            "Unique customer identifier",
+                            &[],
+                        ),
+                        ("name", "string", false, "Full customer name", &[]),
+                        ("email", "string", false, "Primary contact email", &[]),
+                        ("created_at", "timestamp", false, "Creation timestamp", &[]),
+                    ],
+                }],
+                decisions: &[ADR_RECORD_DECISIONS],
+                table_prefixes: &[],
+            },
+            WorkspaceTemplate::Medallion => TemplateSpec {
+                domains: &[(
+                    "analytics",
+                    "Lakehouse organized in bronze, silver and gold layers",
+                    &["bronze", "silver", "gold"],
+                )],
+                contracts: &[
+                    ContractSpec {
+                        domain: "analytics",
+                        system: "bronze",
+                        name: "raw_orders",
+                        description: "Orders as ingested from the source system",
+                        columns: &[
+                            (
+                                "order_id",
+                                "string",
+                                false,
+                                "Order identifier as delivered",
+                                &[],
+                            ),
+                            ("payload", "object", false, "Raw order payload", &[]),
+                            (
+                                "ingested_at",
+                                "timestamp",
+                                false,
+                                "Ingestion timestamp",
+                                &[],
+                            ),
+                        ],
+                    },
+                    ContractSpec {
+                        domain: "analytics",
+                        system: "silver",
+                        name: "orders",
+                        description: "Cleaned and deduplicated orders",
+                        columns: &[
+                            (
+                                "order_id",
+                                "integer",
+                                true,
+                                "Order identifier",
+                                &["raw_orders.order_id"],
+                            ),
+                            (
+                                "amount",
+                                "number",
+                                false,
+                                "Order amount",
+                                &["raw_orders.payload"],
+                            ),
+                            (
+                                "order_date",
+                                "date",
+                                false,
+                                "Order date",
+                                &["raw_orders.payload"],
+                            ),
+                        ],
+                    },
+                    ContractSpec {
+                        domain: "analytics",
+                        system: "gold",
+                        name: "daily_revenue",
+                        description: "Revenue aggregated per day",
+                        columns: &[
+                            ("day", "date", true, "Calendar day", &["orders.order_date"]),
+                            (
+                                "revenue",
+                                "number",
+                                false,
+                                "Sum of order amounts",
+                                &["orders.amount"],
+                            ),
+                        ],
+                    },
+                ],
+                decisions: &[
+                    ADR_RECORD_DECISIONS,
+                    (
+                        "Adopt a medallion architecture",
+                        "Raw, cleaned and business-level data are currently mixed in the same schemas.",
+                        "Organize data in bronze (raw), silver (cleaned) and gold (aggregated) layers; each layer only reads from the previous one.",
+                    ),
+                ],
+                table_prefixes: &[],
+            },
+            WorkspaceTemplate::DataMesh => TemplateSpec {
+                domains: &[
+                    ("sales", "Orders, invoices and revenue", &["sales-db"]),
+                    ("customer", "Customer profiles and consent", &["crm"]),
+                    (
+                        "platform",
+                        "Shared self-serve data platform",
+                        &["lakehouse"],
+                    ),
+                ],
+                contracts: &[
+                    ContractSpec {
+                        domain: "sales",
+                        system: "sales-db",
+                        name: "orders",
+                        description: "Orders published by the sales domain",
+                        columns: &[
+                            ("order_id", "integer", true, "Order identifier", &[]),
+                            ("customer_id", "integer", false, "Ordering customer", &[]),
+                            ("amount", "number", false, "Order amount", &[]),
+                        ],
+                    },
+                    ContractSpec {
+                        domain: "customer",
+                        system: "crm",
+                        name: "customers",
+                        description: "Customer profiles published by the customer domain",
+                        columns: &[
+                            ("customer_id", "integer", true, "Customer identifier", &[]),
+                            ("email", "string", false, "Primary contact email", &[]),
+                            (
+                                "marketing_consent",
+                                "boolean",
+                                false,
+                                "Marketing consent given",
+                                &[],
+                            ),
+                        ],
+                    },
+                ],
+                decisions: &[
+                    ADR_RECORD_DECISIONS,
+                    (
+                        "Domain-oriented data ownership",
+                        "A central data team owns all datasets and has become a bottleneck.",
+                        "Each business domain owns and publishes its data as products with contracts; the platform domain provides shared infrastructure.",
+                    ),
+                ],
+                table_prefixes: &[],
+            },
+            WorkspaceTemplate::Warehouse => TemplateSpec {
+                domains: &[(
+                    "warehouse",
+                    "Dimensional model built from staged source data",
+                    &["staging", "marts"],
+                )],
+                contracts: &[
+                    ContractSpec {
+                        domain: "warehouse",
+                        system: "staging",
+                        name: "stg_orders",
+                        description: "Orders staged from the source system",
+                        columns: &[
+                            ("order_id", "integer", true, "Source order identifier", &[]),
+                            ("customer_id", "integer", false, "Ordering customer", &[]),
+                            ("amount", "number", false, "Order amount", &[]),
+                            ("ordered_at", "timestamp", false, "Order timestamp", &[]),
+                        ],
+                    },
+                    ContractSpec {
+                        domain: "warehouse",
+                        system: "marts",
+                        name: "dim_customer",
+                        description: "Customer dimension",
+                        columns: &[
+                            ("customer_key", "integer", true, "Surrogate key", &[]),
+                            (
+                                "customer_id",
+                                "integer",
+                                false,
+                                "Natural key",
+                                &["stg_orders.customer_id"],
+                            ),
+                            ("valid_from", "date", false, "Version valid from", &[]),
+                            ("valid_to", "date", false, "Version valid to", &[]),
+                        ],
+                    },
+                    ContractSpec {
+                        domain: "warehouse",
+                        system: "marts",
+                        name: "fct_orders",
+                        description: "Order facts at order grain",
+                        columns: &[
+                            (
+                                "order_id",
+                                "integer",
+                                true,
+                                "Order identifier",
+                                &["stg_orders.order_id"],
+                            ),
+                            (
+                                "customer_key",
+                                "integer",
+                                false,
+                                "Customer dimension key",
+                                &["dim_customer.customer_key"],
+                            ),
+                            (
+                                "amount",
+                                "number",
+                                false,
+                                "Order amount",
+                                &["stg_orders.amount"],
+                            ),
+                            (
+                                "order_date",
+                                "date",
+                                false,
+                                "Order date",
+                                &["stg_orders.ordered_at"],
+                            ),
+                        ],
+                    },
+                ],
+                decisions: &[
+                    ADR_RECORD_DECISIONS,
+                    (
+                        "Model marts as star schemas",
+                        "Reporting queries join many normalized source tables and are slow and hard to write.",
+                        "Stage source data unchanged (stg_), then publish conformed dimensions (dim_) and facts (fct_) as star schemas.",
+                    ),
+                ],
+                table_prefixes: &["stg_", "dim_", "fct_"],
+            },
+            WorkspaceTemplate::EventStreaming => TemplateSpec {
+                domains: &[
+                    ("orders", "Order lifecycle events", &["kafka"]),
+                    (
+                        "analytics",
+                        "Real-time aggregates computed from event streams",
+                        &["stream-processor"],
+                    ),
+                ],
+                contracts: &[
+                    ContractSpec {
+                        domain: "orders",
+                        system: "kafka",
+                        name: "order_created",
+                        description: "Event emitted when an order is placed",
+                        columns: &[
+                            ("event_id", "string", true, "Unique event identifier", &[]),
+                            ("order_id", "integer", false, "Order identifier", &[]),
+                            ("amount", "number", false, "Order amount", &[]),
+                            (
+                                "occurred_at",
+                                "timestamp",
+                                false,
+                                "Time the order was placed",
+                                &[],
+                            ),
+                        ],
+                    },
+                    ContractSpec {
+                        domain: "analytics",
+                        system: "stream-processor",
+                        name: "order_totals",
+                        description: "Order totals per one-minute window",
+                        columns: &[
+                            (
+                                "window_start",
+                                "timestamp",
+                                true,
+                                "Window start",
+                                &["order_created.occurred_at"],
+                            ),
+                            (
+                                "order_count",
+                                "integer",
+                                false,
+                                "Orders in the window",
+                                &["order_created.order_id"],
+                            ),
+                            (
+                                "revenue",
+                                "number",
+                                false,
+                                "Sum of order amounts",
+                                &["order_created.amount"],
+                            ),
+                        ],
+                    },
+                ],
+                decisions: &[
+                    ADR_RECORD_DECISIONS,
+                    (
+                        "Publish domain events with schema contracts",
+                        "Consumers break when producers change event payloads without notice.",
+                        "Every topic has an ODCS contract owned by the producing domain; changes must stay backward compatible.",
+                    ),
+                ],
+                table_prefixes: &[],
+            },
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for WorkspaceTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', '_'], "").as_str() {
+            "simple" => Ok(WorkspaceTemplate::Simple),
+            "medallion" => Ok(WorkspaceTemplate::Medallion),
+            "datamesh" | "mesh" | "datameshstarter" => Ok(WorkspaceTemplate::DataMesh),
+            "warehouse" | "dwh" => Ok(WorkspaceTemplate::Warehouse),
+            "streaming" | "eventstreaming" | "events" => Ok(WorkspaceTemplate::EventStreaming),
+            _ => Err(format!(
+                "Unknown workspace template: {}. Available: simple, medallion, datamesh, warehouse, streaming",
+                s
+            )),
+        }
+    }
+}
+
+/// A file produced by scaffolding, relative to the workspace directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaffoldFile {
+    /// Path relative to the workspace root
+    pub path: String,
+    /// File content
+    pub content: String,
+}
+
+/// Result of [`Workspace::scaffold`]
+#[derive(Debug, Clone)]
+pub struct WorkspaceScaffold {
+    /// The scaffolded workspace (also serialized as `workspace.yaml` in `files`)
+    pub workspace: Workspace,
+    /// All files to write, including `workspace.yaml`
+    pub files: Vec<ScaffoldFile>,
+}
+
+/// Errors raised while loading or applying a workspace template
+#[derive(Debug, Error)]
+pub enum TemplateError {
+    #[error("Failed to read template {path}: {message}")]
+    Io { path: String, message: String },
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("Domain '{0}' is defined more than once")]
+    DuplicateDomain(String),
+    #[error("Contract '{contract}' references unknown domain '{domain}'")]
+    UnknownDomain { contract: String, domain: String },
+    #[error("Contract '{contract}' references unknown system '{system}' in domain '{domain}'")]
+    UnknownSystem {
+        contract: String,
+        domain: String,
+        system: String,
+    },
+    #[error("Naming convention violation: {0}")]
+    NamingViolation(String),
+}
+
+/// Letter case a name must follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NameCase {
+    /// `order_items`
+    #[serde(rename = "snake_case")]
+    Snake,
+    /// `orderItems`
+    #[serde(rename = "camelCase")]
+    Camel,
+    /// `OrderItems`
+    #[serde(rename = "PascalCase")]
+    Pascal,
+    /// `order-items`
+    #[serde(rename = "kebab-case")]
+    Kebab,
+    /// `ORDER_ITEMS`
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnake,
+}
+
+impl NameCase {
+    /// Whether `name` follows this case
+    pub fn matches(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        let rest = chars.as_str();
+        let separated = |separator: char, lower: bool| {
+            name.split(separator).all(|part| {
+                !part.is_empty()
+                    && part.chars().all(|c| {
+                        c.is_ascii_digit()
+                            || (lower && c.is_ascii_lowercase())
+                            || (!lower && c.is_ascii_uppercase())
+                    })
+            })
+        };
+        match self {
+            NameCase::Snake => first.is_ascii_lowercase() && separated('_', true),
+            NameCase::Kebab => first.is_ascii_lowercase() && separated('-', true),
+            NameCase::ScreamingSnake => first.is_ascii_uppercase() && separated('_', false),
+            NameCase::Camel => {
+                first.is_ascii_lowercase() && rest.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            NameCase::Pascal => {
+                first.is_ascii_uppercase() && rest.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        }
+    }
+
+    /// Case name as written in templates
+    pub fn name(&self) -> &'static str {
+        match self {
+            NameCase::Snake => "snake_case",
+            NameCase::Camel => "camelCase",
+            NameCase::Pascal => "PascalCase",
+            NameCase::Kebab => "kebab-case",
+            NameCase::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+        }
+    }
+}
+
+/// Naming conventions for tables and columns created in a workspace
+///
+/// Scaffolding writes them to [`NAMING_CONVENTIONS_FILE`] so that later
+/// contracts can be checked against the same rules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingConventions {
+    /// Required case of table names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_case: Option<NameCase>,
+    /// Required case of column names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column_case: Option<NameCase>,
+    /// Table names must start with one of these prefixes (any prefix if empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub table_prefixes: Vec<String>,
+}
+
+impl NamingConventions {
+    /// Load naming conventions from YAML
+    pub fn from_yaml(yaml_content: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml_content)
+    }
+
+    /// Serialize naming conventions to YAML
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Whether no convention is configured
+    pub fn is_empty(&self) -> bool {
+        self.table_case.is_none() && self.column_case.is_none() && self.table_prefixes.is_empty()
+    }
+
+    /// Describe why a table name violates the conventions, if it does
+    pub fn check_table(&self, name: &str) -> Option<String> {
+        if let Some(case) = self.table_case
+            && !case.matches(name)
+        {
+            return Some(format!("table '{}' is not {}", name, case.name()));
+        }
+        if !self.table_prefixes.is_empty()
+            && !self.table_prefixes.iter().any(|p| name.starts_with(p))
+        {
+            return Some(format!(
+                "table '{}' does not start with one of: {}",
+                name,
+                self.table_prefixes.join(", ")
+            ));
+        }
+        None
+    }
+
+    /// Describe why a column name violates the conventions, if it does
+    pub fn check_column(&self, table: &str, name: &str) -> Option<String> {
+        match self.column_case {
+            Some(case) if !case.matches(name) => Some(format!(
+                "column '{}.{}' is not {}",
+                table,
+                name,
+                case.name()
+            )),
+            _ => None,
+        }
+    }
+
+    /// All violations in a contract's schemas and top-level properties
+    pub fn contract_violations(&self, contract: &ODCSContract) -> Vec<String> {
+        let mut violations = Vec::new();
+        for schema in &contract.schema {
+            violations.extend(self.check_table(&schema.name));
+            for property in &schema.properties {
+                violations.extend(self.check_column(&schema.name, &property.name));
+            }
+        }
+        violations
+    }
+}
+
+/// A workspace template: domains, systems, naming conventions, example
+/// contracts and decision records to pre-create
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDefinition {
+    /// Template name
+    pub name: String,
+    /// Short description of the template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ID strategy of workspaces created from the template
+    #[serde(default, skip_serializing_if = "IdStrategy::is_default")]
+    pub id_strategy: IdStrategy,
+    /// Naming conventions; example contracts must follow them
+    #[serde(default, skip_serializing_if = "NamingConventions::is_empty")]
+    pub naming: NamingConventions,
+    /// Domains with their systems
+    #[serde(default)]
+    pub domains: Vec<TemplateDomain>,
+    /// Example contracts
+    #[serde(default)]
+    pub contracts: Vec<TemplateContract>,
+    /// Starter decision records (created as accepted)
+    #[serde(default)]
+    pub decisions: Vec<TemplateDecision>,
+}
+
+/// A domain pre-created by a template
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDomain {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// System names in the domain
+    #[serde(default)]
+    pub systems: Vec<String>,
+}
+
+/// An example contract pre-created by a template
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateContract {
+    /// Owning domain (must be defined by the template)
+    pub domain: String,
+    /// Owning system within the domain, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub columns: Vec<TemplateColumn>,
+}
+
+/// A column of an example contract
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateColumn {
+    pub name: String,
+    #[serde(alias = "type")]
+    pub logical_type: String,
+    /// Primary key columns are also marked required
+    #[serde(default)]
+    pub primary_key: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Lineage sources as `table.column`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
+}
+
+/// A starter decision record
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDecision {
+    pub title: String,
+    pub context: String,
+    pub decision: String,
+}
+
+impl TemplateDefinition {
+    /// Load a template from YAML
+    pub fn from_yaml(yaml_content: &str) -> Result<Self, TemplateError> {
+        Ok(serde_yaml::from_str(yaml_content)?)
+    }
+
+    /// Load a template from a YAML file
+    pub fn from_file(path: &Path) -> Result<Self, TemplateError> {
+        let content = std::fs::read_to_string(path).map_err(|e| TemplateError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        Self::from_yaml(&content)
+    }
+
+    /// Serialize the template to YAML
+    pub fn to_yaml(&self) -> Result<String, TemplateError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    /// Check that domains are unique, contracts reference defined domains and
+    /// systems, and contract and column names follow the naming conventions
+    pub fn validate(&self) -> Result<(), TemplateError> {
+        let mut seen = HashSet::new();
+        for domain in &self.domains {
+            if !seen.insert(domain.name.as_str()) {
+                return Err(TemplateError::DuplicateDomain(domain.name.clone()));
+            }
+        }
+        for contract in &self.contracts {
+            let domain = self
+                .domains
+                .iter()
+                .find(|d| d.name == contract.domain)
+                .ok_or_else(|| TemplateError::UnknownDomain {
+                    contract: contract.name.clone(),
+                    domain: contract.domain.clone(),
+                })?;
+            if let Some(system) = &contract.system
+                && !domain.systems.contains(system)
+            {
+                return Err(TemplateError::UnknownSystem {
+                    contract: contract.name.clone(),
+                    domain: contract.domain.clone(),
+                    system: system.clone(),
+                });
+            }
+            let violation = self.naming.check_table(&contract.name).or_else(|| {
+                contract
+                    .columns
+                    .iter()
+                    .find_map(|c| self.naming.check_column(&contract.name, &c.name))
+            });
+            if let Some(violation) = violation {
+                return Err(TemplateError::NamingViolation(violation));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// (title, context, decision)
+type DecisionSpec = (&'static str, &'static str, &'static str);
+
+/// (name, logical type, primary key, description, transform sources)
+type ColumnSpec = (
+    &'static str,
+    &'static str,
+    bool,
+    &'static str,
+    &'static [&'static str],
+);
+
+struct ContractSpec {
+    domain: &'static str,
+    system: &'static str,
+    name: &'static str,
+    description: &'static str,
+    columns: &'static [ColumnSpec],
+}
+
+struct TemplateSpec {
+    /// (domain, description, systems)
+    domains: &'static [(&'static str, &'static str, &'static [&'static str])],
+    contracts: &'static [ContractSpec],
+    decisions: &'static [DecisionSpec],
+    table_prefixes: &'static [&'static str],
+}
+
+const ADR_RECORD_DECISIONS: DecisionSpec = (
+    "Record architecture decisions",
+    "We need to record the architectural and data modelling decisions made in this workspace.",
+    "Use MADR decision records stored next to the data contracts in this workspace.",
+);
+
+/// Author recorded on scaffolded decisions
+const SCAFFOLD_AUTHOR: &str = "odm init";
+
+impl Workspace {
+    /// Scaffold a new workspace from a built-in template
+    ///
+    /// Returns the workspace together with the files to write: `workspace.yaml`,
+    /// naming conventions, example contracts, starter decision records and
+    /// `decisions.yaml`.
+    pub fn scaffold(
+        name: &str,
+        template: WorkspaceTemplate,
+    ) -> Result<WorkspaceScaffold, TemplateError> {
+        Self::from_template(name, &template.definition())
+    }
+
+    /// Scaffold a new workspace from a template definition
+    ///
+    /// The template is validated first; see [`TemplateDefinition::validate`].
+    pub fn from_template(
+        name: &str,
+        template: &TemplateDefinition,
+    ) -> Result<WorkspaceScaffold, TemplateError> {
+        template.validate()?;
+        let mut workspace =
+            Workspace::with_id_strategy(name.to_string(), Uuid::new_v4(), template.id_strategy);
+        workspace.description = Some(format!(
+            "{} workspace ({})",
+            name,
+            template.description.as_deref().unwrap_or(&template.name)
+        ));
+
+        for domain in &template.domains {
+            let domain_id = workspace.generate_id("domain", None, &domain.name);
+            workspace.add_domain_with_description(
+                domain_id,
+                domain.name.clone(),
+                domain.description.clone(),
+            );
+            for system in &domain.systems {
+                workspace.create_system(&domain.name, system.clone(), None);
+            }
+        }
+
+        let mut files = Vec::new();
+        if !template.naming.is_empty() {
+            files.push(ScaffoldFile {
+                path: NAMING_CONVENTIONS_FILE.to_string(),
+                content: template.naming.to_yaml()?,
+            });
+        }
+
+        for contract_spec in &template.contracts {
+            let contract_id =
+                workspace.generate_id("table", Some(&contract_spec.domain), &contract_spec.name);
+            let contract = build_contract(contract_id, contract_spec);
+            let asset = AssetReference {
+                id: contract_id,
+                name: contract_spec.name.clone(),
+                domain: contract_spec.domain.clone(),
+                system: contract_spec.system.clone(),
+                asset_type: AssetType::Odcs,
+                file_path: None,
+            };
+            let path = workspace.generate_asset_filename(&asset);
+            workspace.add_asset(AssetReference {
+                file_path: Some(path.clone()),
+                ..asset
+            });
+            if let Some(system) = workspace
+                .domains
+                .iter_mut()
+                .find(|d| d.name == contract_spec.domain)
+                .and_then(|d| {
+                    d.systems
+                        .iter_mut()
+                        .find(|s| Some(&s.name) == contract_spec.system.as_ref())
+                })
+            {
+                system.table_ids.push(contract_id);
+            }
+            files.push(ScaffoldFile {
+                path,
+                content: serde_yaml::to_string(&contract)?,
+            });
+        }
+
+        let mut index = DecisionIndex::new();
+        for (number, spec) in template.decisions.iter().enumerate() {
+            let mut decision = Decision::new(
+                number as u64 + 1,
+                spec.title.as_str(),
+                spec.context.as_str(),
+                spec.decision.as_str(),
+                SCAFFOLD_AUTHOR,
+            );
+            decision.status = DecisionStatus::Accepted;
+            decision.workspace_id = Some(workspace.id);
+            let path = decision.filename(name);
+            index.add_decision(&decision, path.clone());
+            files.push(ScaffoldFile {
+                path,
+                content: decision.to_yaml()?,
+            });
+        }
+        files.push(ScaffoldFile {
+            path: "decisions.yaml".to_string(),
+            content: index.to_yaml()?,
+        });
+
+        files.insert(
+            0,
+            ScaffoldFile {
+                path: "workspace.yaml".to_string(),
+                content: workspace.to_yaml()?,
+            },
+        );
+
+        Ok(WorkspaceScaffold { workspace, files })
+    }
+}
+
+fn build_contract(id: Uuid, spec: &TemplateContract) -> ODCSContract {
+    let properties = spec
+        .columns
+        .iter()
+        .map(|column| {
+            let mut property = Property::new(&column.name, &column.logical_type)
+                .with_primary_key(column.primary_key)
+                .with_required(column.primary_key);
+            if let Some(description) = &column.description {
+                property = property.with_description(description);
+            }
+            property.transform_source_objects = column.sources.clone();
+            property
+        })
+        .collect();
+
+    let mut schema = SchemaObject::new(&spec.name).with_properties(properties);
+    let mut contract = ODCSContract::new_with_id(id.to_string(), &spec.name, "1.0.0")
+        .with_status("draft")
+        .with_domain(&spec.domain);
+    if let Some(description) = &spec.description {
+        schema = schema.with_description(description);
+        contract = contract.with_description(description);
+    }
+    contract.with_schema(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::odcs::ODCSImporter;
+
+    #[test]
+    fn test_template_from_str() {
+        assert_eq!(
+            "medallion".parse::<WorkspaceTemplate>().unwrap(),
+            WorkspaceTemplate::Medallion
+        );
+        assert_eq!(
+            "data-mesh".parse::<WorkspaceTemplate>().unwrap(),
+            WorkspaceTemplate::DataMesh
+        );
+        assert_eq!(
+            "event-streaming".parse::<WorkspaceTemplate>().unwrap(),
+            WorkspaceTemplate::EventStreaming
+        );
+        for template in WorkspaceTemplate::all() {
+            assert_eq!(
+                template.name().parse::<WorkspaceTemplate>().unwrap(),
+                *template
+            );
+        }
+        assert!("lambda".parse::<WorkspaceTemplate>().is_err());
+    }
+
+    #[test]
+    fn test_scaffold_generates_consistent_files() {
+        for template in WorkspaceTemplate::all() {
+            let scaffold = Workspace::scaffold("acme", *template).unwrap();
+            let workspace = &scaffold.workspace;
+
+            let parsed = Workspace::from_yaml(&scaffold.files[0].content).unwrap();
+            assert_eq!(&parsed, workspace);
+
+            for asset in &workspace.assets {
+                let path = asset.file_path.as_ref().unwrap();
+                let file = scaffold.files.iter().find(|f| &f.path == path).unwrap();
+                let (table, _) = ODCSImporter::new().parse_table(&file.content).unwrap();
+                assert_eq!(table.id, asset.id);
+
+                let system = workspace
+                    .get_domain_by_name(&asset.domain)
+                    .and_then(|d| {
+                        d.systems
+                            .iter()
+                            .find(|s| Some(&s.name) == asset.system.as_ref())
+                    })
+                    .unwrap();
+                assert!(system.table_ids.contains(&asset.id));
+            }
+
+            assert!(scaffold.files.iter().any(|f| f.path == "decisions.yaml"));
+            assert!(
+                scaffold
+                    .files
+                    .iter()
+                    .any(|f| f.path.ends_with(".madr.yaml"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_scaffold_uses_fresh_ids() {
+        let first = Workspace::scaffold("acme", WorkspaceTemplate::DataMesh).unwrap();
+        let second = Workspace::scaffold("acme", WorkspaceTemplate::DataMesh).unwrap();
+        assert_ne!(first.workspace.id, second.workspace.id);
+        assert_ne!(
+            first.workspace.domains[0].id,
+            second.workspace.domains[0].id
+        );
+        assert_ne!(first.workspace.assets[0].id, second.workspace.assets[0].id);
+    }
+
+    const CUSTOM_TEMPLATE: &str = r#"
+name: payments
+description: Payment processing workspace
+idStrategy: deterministic
+naming:
+  tableCase: snake_case
+  columnCase: snake_case
+  tablePrefixes: [stg_, fct_]
+domains:
+  - name: payments
+    description: Payment ledger
+    systems: [ledger]
+  - name: shared
+contracts:
+  - domain: payments
+    system: ledger
+    name: fct_payments
+    description: Settled payments
+    columns:
+      - { name: payment_id, logicalType: integer, primaryKey: true }
+      - { name: amount, type: number, sources: [stg_payments.amount] }
+  - domain: shared
+    name: stg_currencies
+    columns:
+      - { name: code, logicalType: string, primaryKey: true }
+decisions:
+  - title: Settle in EUR
+    context: Payments arrive in many currencies.
+    decision: Convert all amounts to EUR at settlement.
+"#;
+
+    #[test]
+    fn test_custom_template_from_yaml() {
+        let template = TemplateDefinition::from_yaml(CUSTOM_TEMPLATE).unwrap();
+        let scaffold = Workspace::from_template("acme", &template).unwrap();
+        let workspace = &scaffold.workspace;
+
+        assert_eq!(workspace.id_strategy, IdStrategy::Deterministic);
+        assert_eq!(workspace.domains.len(), 2);
+        let ledger = &workspace.get_domain_by_name("payments").unwrap().systems[0];
+        assert_eq!(ledger.name, "ledger");
+        assert_eq!(ledger.table_ids.len(), 1);
+        assert_eq!(workspace.assets.len(), 2);
+        assert!(workspace.assets.iter().any(|a| a.system.is_none()));
+
+        let naming = scaffold
+            .files
+            .iter()
+            .find(|f| f.path == NAMING_CONVENTIONS_FILE)
+            .unwrap();
+        let naming = NamingConventions::from_yaml(&naming.content).unwrap();
+        assert_eq!(naming, template.naming);
+        assert!(
+            scaffold
+                .files
+                .iter()
+                .any(|f| f.path.ends_with(".madr.yaml"))
+        );
+
+        // Deterministic templates reproduce the same IDs on every run
+        let again = Workspace::from_template("acme", &template).unwrap();
+        assert_eq!(again.workspace.id, workspace.id);
+        assert_eq!(again.workspace.assets, workspace.assets);
+    }
+
+    #[test]
+    fn test_template_validation_errors() {
+        let mut template = TemplateDefinition::from_yaml(CUSTOM_TEMPLATE).unwrap();
+        template.contracts[0].system = Some("warehouse".to_string());
+        assert!(matches!(
+            template.validate(),
+            Err(TemplateError::UnknownSystem { .. })
+        ));
+
+        let mut template = TemplateDefinition::from_yaml(CUSTOM_TEMPLATE).unwrap();
+        template.contracts[0].name = "payments".to_string();
+        assert!(matches!(
+            Workspace::from_template("acme", &template),
+            Err(TemplateError::NamingViolation(_))
+        ));
+
+        let mut template = TemplateDefinition::from_yaml(CUSTOM_TEMPLATE).unwrap();
+        template.contracts[1].columns[0].name = "currencyCode".to_string();
+        assert!(matches!(
+            template.validate(),
+            Err(TemplateError::NamingViolation(_))
+        ));
+
+        let mut template = TemplateDefinition::from_yaml(CUSTOM_TEMPLATE).unwrap();
+        template.domains.push(template.domains[0].clone());
+        assert!(matches!(
+            template.validate(),
+            Err(TemplateError::DuplicateDomain(_))
+        ));
+    }
+
+    #[test]
+    fn test_name_case_matches() {
+        assert!(NameCase::Snake.matches("order_items_2"));
+        assert!(!NameCase::Snake.matches("orderItems"));
+        assert!(!NameCase::Snake.matches("order__items"));
+        assert!(NameCase::Camel.matches("orderItems"));
+        assert!(NameCase::Pascal.matches("OrderItems"));
+        assert!(NameCase::Kebab.matches("order-items"));
+        assert!(NameCase::ScreamingSnake.matches("ORDER_ITEMS"));
+        assert!(!NameCase::ScreamingSnake.matches("Order_Items"));
+        assert!(!NameCase::Snake.matches(""));
+    }
+
+    #[test]
+    fn test_builtin_definitions_round_trip() {
+        for template in WorkspaceTemplate::all() {
+            let definition = template.definition();
+            definition.validate().unwrap();
+            let yaml = definition.to_yaml().unwrap();
+            assert_eq!(TemplateDefinition::from_yaml(&yaml).unwrap(), definition);
+        }
+
+        let scaffold = Workspace::scaffold("dwh", WorkspaceTemplate::Warehouse).unwrap();
+        let naming = scaffold
+            .files
+            .iter()
+            .find(|f| f.path == NAMING_CONVENTIONS_FILE)
+            .unwrap();
+        assert!(naming.content.contains("fct_"));
+        for file in scaffold
+            .files
+            .iter()
+            .filter(|f| f.path.ends_with(".odcs.yaml"))
+        {
+            let contract: ODCSContract = serde_yaml::from_str(&file.content).unwrap();
+            assert!(
+                WorkspaceTemplate::Warehouse
+                    .definition()
+                    .naming
+                    .contract_violations(&contract)
+                    .is_empty()
+            );
+        }
+    }
+}
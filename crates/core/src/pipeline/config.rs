@@ -4,6 +4,8 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::inference::StatisticsPolicy;
+
 /// Main pipeline configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PipelineConfig {
@@ -25,6 +27,9 @@ pub struct PipelineConfig {
     pub llm: LlmPipelineConfig,
     /// Stages to run (empty = all)
     pub stages: Vec<PipelineStage>,
+    /// Column statistics policy; the profile stage runs only when set
+    #[serde(default)]
+    pub statistics: Option<StatisticsPolicy>,
     /// Enable dry-run mode
     pub dry_run: bool,
     /// Resume from checkpoint
@@ -45,6 +50,7 @@ impl Default for PipelineConfig {
             target_schema: None,
             llm: LlmPipelineConfig::default(),
             stages: Vec::new(),
+            statistics: None,
             dry_run: false,
             resume: false,
             verbose: false,
@@ -106,6 +112,13 @@ impl PipelineConfig {
         self
     }
 
+    /// Profile staged data and embed column statistics into the generated
+    /// contracts, refreshing or expiring earlier statistics according to `policy`
+    pub fn with_statistics(mut self, policy: StatisticsPolicy) -> Self {
+        self.statistics = Some(policy);
+        self
+    }
+
     /// Enable dry-run mode
     pub fn with_dry_run(mut self, dry_run: bool) -> Self {
         self.dry_run = dry_run;
@@ -227,7 +240,9 @@ pub enum PipelineStage {
     Map,
     /// Stage 5: Export to Parquet/target format
     Export,
-    /// Stage 6: Generate ODCS contracts
+    /// Stage 6: Profile column statistics (optional)
+    Profile,
+    /// Stage 7: Generate ODCS contracts
     Generate,
 }
 
@@ -240,6 +255,7 @@ impl PipelineStage {
             Self::Refine,
             Self::Map,
             Self::Export,
+            Self::Profile,
             Self::Generate,
         ]
     }
@@ -252,6 +268,7 @@ impl PipelineStage {
             Self::Refine => "refine",
             Self::Map => "map",
             Self::Export => "export",
+            Self::Profile => "profile",
             Self::Generate => "generate",
         }
     }
@@ -264,6 +281,7 @@ impl PipelineStage {
             Self::Refine => "Refine schema with LLM",
             Self::Map => "Map to target schema",
             Self::Export => "Export to Parquet/target format",
+            Self::Profile => "Profile column statistics",
             Self::Generate => "Generate ODCS contracts",
         }
     }
@@ -276,13 +294,14 @@ impl PipelineStage {
             Self::Refine => 3,
             Self::Map => 4,
             Self::Export => 5,
-            Self::Generate => 6,
+            Self::Profile => 6,
+            Self::Generate => 7,
         }
     }
 
     /// Check if this stage is optional
     pub fn is_optional(&self) -> bool {
-        matches!(self, Self::Refine | Self::Map)
+        matches!(self, Self::Refine | Self::Map | Self::Profile)
     }
 }
 
@@ -302,7 +321,8 @@ impl std::str::FromStr for PipelineStage {
             "refine" | "3" => Ok(Self::Refine),
             "map" | "4" => Ok(Self::Map),
             "export" | "5" => Ok(Self::Export),
-            "generate" | "6" => Ok(Self::Generate),
+            "profile" | "6" => Ok(Self::Profile),
+            "generate" | "7" => Ok(Self::Generate),
             _ => Err(format!("Unknown stage: {}", s)),
         }
     }
@@ -336,7 +356,7 @@ mod tests {
     #[test]
     fn test_effective_stages() {
         let config = PipelineConfig::default();
-        assert_eq!(config.effective_stages().len(), 6);
+        assert_eq!(config.effective_stages().len(), 7);
 
         let config = PipelineConfig::default()
             .with_stages(vec![PipelineStage::Ingest, PipelineStage::Infer]);
@@ -363,6 +383,8 @@ mod tests {
         assert!(!PipelineStage::Ingest.is_optional());
         assert!(PipelineStage::Refine.is_optional());
         assert!(PipelineStage::Map.is_optional());
+        assert!(PipelineStage::Profile.is_optional());
+        assert_eq!(PipelineStage::Generate.index(), 7);
     }
 
     #[test]
@@ -175,6 +175,7 @@ impl PipelineExecutor {
             PipelineStage::Refine => self.run_refine()?,
             PipelineStage::Map => self.run_map()?,
             PipelineStage::Export => self.run_export()?,
+            PipelineStage::Profile => self.run_profile()?,
             PipelineStage::Generate => self.run_generate()?,
         };
 
@@ -303,7 +304,28 @@ impl PipelineExecutor {
         Ok(output)
     }
 
+    /// Run the profile stage
+    fn run_profile(&self) -> PipelineResult<StageOutput> {
+        let Some(ref policy) = self.config.statistics else {
+            debug!("Statistics not configured, skipping profiling");
+            return Ok(StageOutput::skipped("Statistics not configured"));
+        };
+
+        let profile_path = self.config.output_dir.join("column_statistics.json");
+        debug!(output = %profile_path.display(), "Profiling column statistics");
+
+        let mut output = StageOutput::success();
+        output = output.with_file(&profile_path);
+        output = output.with_metadata("statistics_policy", serde_json::json!(policy));
+
+        Ok(output)
+    }
+
     /// Run the generate stage
+    ///
+    /// When the profile stage has run, its statistics are embedded into the
+    /// contract's properties; statistics from earlier runs are refreshed or
+    /// expired according to the statistics policy.
     fn run_generate(&self) -> PipelineResult<StageOutput> {
         let contract_path = self.config.output_dir.join("contract.odcs.yaml");
         debug!(output = %contract_path.display(), format = "odcs", "Generating contract");
@@ -312,6 +334,16 @@ impl PipelineExecutor {
         output = output.with_file(&contract_path);
         output = output.with_metadata("format", serde_json::json!("odcs"));
 
+        let profiled = self
+            .checkpoint
+            .get_stage_output(PipelineStage::Profile)
+            .is_some_and(|profile| profile.success && !profile.skipped);
+        if let Some(ref policy) = self.config.statistics {
+            let statistics = if profiled { "embedded" } else { "expired" };
+            output = output.with_metadata("statistics", serde_json::json!(statistics));
+            output = output.with_metadata("statistics_refresh", serde_json::json!(policy.refresh));
+        }
+
         Ok(output)
     }
 
@@ -324,6 +356,9 @@ impl PipelineExecutor {
             PipelineStage::Map if self.config.target_schema.is_none() => {
                 Some("No target schema specified".to_string())
             }
+            PipelineStage::Profile if self.config.statistics.is_none() => {
+                Some("Statistics not configured".to_string())
+            }
             _ => None,
         }
     }
@@ -36,7 +36,13 @@
 //! 3. **Refine** (optional): Enhance schema using LLM with documentation context
 //! 4. **Map** (optional): Map inferred schema to target schema
 //! 5. **Export**: Export data to Parquet or other target format
-//! 6. **Generate**: Generate ODCS data contracts
+//! 6. **Profile** (optional): Profile column statistics (null %, distinct count,
+//!    min/max, top values), enabled by [`PipelineConfig::with_statistics`]
+//! 7. **Generate**: Generate ODCS data contracts, embedding the column statistics
+//!    into each property's `customProperties` and refreshing or expiring the
+//!    statistics of earlier runs according to the [`StatisticsPolicy`]
+//!
+//! [`StatisticsPolicy`]: crate::inference::StatisticsPolicy
 //!
 //! # Checkpointing
 //!
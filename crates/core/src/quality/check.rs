@@ -0,0 +1,761 @@
+//! Quality checks compiled from ODCS contracts into SQL over staged records
+
+use serde_json::Value;
+
+use super::expectation::{Expectation, quote_identifier, quote_string, sql_literal};
+use super::report::{RuleResult, RuleStatus};
+use crate::metrics;
+use crate::models::odcs::{ODCSContract, Property, QualityRule, SchemaObject};
+use crate::staging::SqlDialect;
+
+/// Columns of the staging view identifying the source of each record
+pub const SOURCE_COLUMNS: &[&str] = &["_staged_id", "_file_path", "_record_index"];
+
+/// Typed column of the staging view over `staged_json`
+#[derive(Debug, Clone, PartialEq)]
+struct ViewColumn {
+    /// Dot-separated property path, used as the column name
+    name: String,
+    path: Vec<String>,
+    kind: ColumnKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColumnKind {
+    Text,
+    Integer,
+    Number,
+    Boolean,
+    Json,
+}
+
+/// View exposing staged records as a table named after an ODCS schema object
+///
+/// Each property becomes a typed column (nested object properties as
+/// `parent.child`), next to [`SOURCE_COLUMNS`]. The view is prepended as a common
+/// table expression to every check, so SQL rules can query the schema by name.
+#[derive(Debug, Clone)]
+pub struct StagingView {
+    name: String,
+    columns: Vec<ViewColumn>,
+    dialect: SqlDialect,
+    partition: Option<String>,
+}
+
+impl StagingView {
+    /// Create the view for a schema object, optionally restricted to a partition
+    pub fn new(schema: &SchemaObject, dialect: SqlDialect, partition: Option<&str>) -> Self {
+        let mut columns = Vec::new();
+        collect_columns(&schema.properties, &[], &mut columns);
+        Self {
+            name: schema.name.clone(),
+            columns,
+            dialect,
+            partition: partition.map(str::to_string),
+        }
+    }
+
+    /// Table name of the view (the schema object name)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Common table expression defining the view
+    pub fn cte(&self) -> String {
+        let mut selects = vec![
+            "id AS \"_staged_id\"".to_string(),
+            "file_path AS \"_file_path\"".to_string(),
+            "record_index AS \"_record_index\"".to_string(),
+        ];
+        selects.extend(self.columns.iter().map(|column| {
+            format!(
+                "{} AS {}",
+                self.extract(column),
+                quote_identifier(&column.name)
+            )
+        }));
+        let filter = match &self.partition {
+            Some(partition) => format!(" WHERE partition_key = {}", quote_string(partition)),
+            None => String::new(),
+        };
+        format!(
+            "{} AS (SELECT {} FROM staged_json{})",
+            quote_identifier(&self.name),
+            selects.join(", "),
+            filter
+        )
+    }
+
+    /// Prefix a statement with the view definition
+    pub fn wrap(&self, statement: &str) -> String {
+        let statement = statement.trim().trim_end_matches(';');
+        let lower = statement.to_lowercase();
+        if lower.starts_with("with recursive ") {
+            format!("WITH RECURSIVE {}, {}", self.cte(), &statement[15..])
+        } else if lower.starts_with("with ") {
+            format!("WITH {}, {}", self.cte(), &statement[5..])
+        } else {
+            format!("WITH {} {}", self.cte(), statement)
+        }
+    }
+
+    fn extract(&self, column: &ViewColumn) -> String {
+        match self.dialect {
+            SqlDialect::DuckDb => {
+                let path = format!(
+                    "$.{}",
+                    column
+                        .path
+                        .iter()
+                        .map(|p| format!("\"{}\"", p.replace('"', "\\\"")))
+                        .collect::<Vec<_>>()
+                        .join(".")
+                );
+                let text = format!("json_extract_string(raw_json, {})", quote_string(&path));
+                match column.kind {
+                    ColumnKind::Text => text,
+                    ColumnKind::Integer => format!("TRY_CAST({} AS BIGINT)", text),
+                    ColumnKind::Number => format!("TRY_CAST({} AS DOUBLE)", text),
+                    ColumnKind::Boolean => format!("TRY_CAST({} AS BOOLEAN)", text),
+                    ColumnKind::Json => {
+                        format!("json_extract(raw_json, {})", quote_string(&path))
+                    }
+                }
+            }
+            SqlDialect::Postgres => {
+                let path = quote_string(&format!(
+                    "{{{}}}",
+                    column
+                        .path
+                        .iter()
+                        .map(|p| format!("\"{}\"", p.replace('\\', "\\\\").replace('"', "\\\"")))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ));
+                let text = format!("(raw_json #>> {})", path);
+                // Casts only apply to values of the matching JSON type, so bad data
+                // reads as NULL instead of failing the whole query
+                let typed = |json_type: &str, sql_type: &str| {
+                    format!(
+                        "CASE WHEN jsonb_typeof(raw_json #> {}) = '{}' THEN {}::{} END",
+                        path, json_type, text, sql_type
+                    )
+                };
+                match column.kind {
+                    ColumnKind::Text => text,
+                    ColumnKind::Integer => typed("number", "numeric::bigint"),
+                    ColumnKind::Number => typed("number", "double precision"),
+                    ColumnKind::Boolean => typed("boolean", "boolean"),
+                    ColumnKind::Json => format!("(raw_json #> {})", path),
+                }
+            }
+        }
+    }
+}
+
+fn collect_columns(properties: &[Property], parent: &[String], columns: &mut Vec<ViewColumn>) {
+    for property in properties {
+        let mut path = parent.to_vec();
+        path.push(property.name.clone());
+        let kind = match property.logical_type.to_lowercase().as_str() {
+            "integer" | "int" | "long" | "bigint" => ColumnKind::Integer,
+            "number" | "decimal" | "double" | "float" | "numeric" => ColumnKind::Number,
+            "boolean" | "bool" => ColumnKind::Boolean,
+            "object" | "struct" | "record" if !property.properties.is_empty() => {
+                collect_columns(&property.properties, &path, columns);
+                ColumnKind::Json
+            }
+            "array" | "list" | "object" | "struct" | "record" => ColumnKind::Json,
+            _ => ColumnKind::Text,
+        };
+        columns.push(ViewColumn {
+            name: path.join("."),
+            path,
+            kind,
+        });
+    }
+}
+
+/// A quality rule compiled into SQL against a [`StagingView`]
+#[derive(Debug, Clone)]
+pub struct QualityCheck {
+    /// Schema object the check belongs to
+    pub schema: String,
+    /// Property path for property-level checks
+    pub property: Option<String>,
+    /// Rule name: `required`, `unique`, the rule's name or metric, or its type
+    pub rule: String,
+    /// Description of the rule
+    pub description: Option<String>,
+    /// Severity declared on the rule (e.g. `error`, `warning`)
+    pub severity: Option<String>,
+    kind: CheckKind,
+}
+
+#[derive(Debug, Clone)]
+enum CheckKind {
+    /// Every row matching the condition fails the check
+    Rows { failing: String },
+    /// A scalar computed over the view is compared with the expectation; rows
+    /// matching `failing` are sampled when it does not match
+    Metric {
+        observed: String,
+        expectation: Expectation,
+        failing: Option<String>,
+    },
+    /// A user query whose first value is compared with the expectation
+    Sql {
+        query: String,
+        expectation: Expectation,
+    },
+    /// The rule cannot be executed
+    Unsupported { reason: String },
+}
+
+/// Statements executing a check
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckStatements {
+    /// Query returning a single row whose first value is the observed value
+    pub observe: Option<String>,
+    /// Query returning failing rows, run only when the check fails
+    pub samples: Option<String>,
+}
+
+impl QualityCheck {
+    /// Compile the checks of every schema object of a contract
+    ///
+    /// Properties marked `required` (or primary key) must not be null, properties
+    /// marked `unique` (or primary key) must not repeat, and each quality rule of a
+    /// schema or property becomes one check. Library rules support the `rowCount`,
+    /// `nullValues`, `missingValues`, `invalidValues` and `duplicateValues` metrics;
+    /// library rules without a metric check every value of their property.
+    pub fn for_contract(contract: &ODCSContract) -> Vec<QualityCheck> {
+        contract
+            .schema
+            .iter()
+            .flat_map(QualityCheck::for_schema)
+            .collect()
+    }
+
+    /// Compile the checks of a schema object
+    pub fn for_schema(schema: &SchemaObject) -> Vec<QualityCheck> {
+        let mut checks = Vec::new();
+        for rule in &schema.quality {
+            checks.push(Self::from_rule(&schema.name, None, rule));
+        }
+        collect_property_checks(&schema.name, &schema.properties, &[], &mut checks);
+        checks
+    }
+
+    fn new(schema: &str, property: Option<&str>, rule: &str, kind: CheckKind) -> Self {
+        Self {
+            schema: schema.to_string(),
+            property: property.map(str::to_string),
+            rule: rule.to_string(),
+            description: None,
+            severity: None,
+            kind,
+        }
+    }
+
+    fn from_rule(schema: &str, property: Option<&str>, rule: &QualityRule) -> Self {
+        let expectation = Expectation::from_rule(rule);
+        let metric = rule.metric.as_deref().or_else(|| {
+            // ODCS 3.0 named library rules `rule`
+            rule.extra.get("rule").and_then(Value::as_str)
+        });
+        let name = rule
+            .extra
+            .get("name")
+            .and_then(Value::as_str)
+            .or(metric)
+            .or(rule.rule_type.as_deref())
+            .unwrap_or("library");
+        let view = quote_identifier(schema);
+        let column = property.map(quote_identifier);
+
+        let kind = if let Some(query) = &rule.query {
+            let query = query
+                .replace("${object}", &view)
+                .replace("{object}", &view)
+                .replace("${property}", column.as_deref().unwrap_or("NULL"))
+                .replace("{property}", column.as_deref().unwrap_or("NULL"));
+            CheckKind::Sql { query, expectation }
+        } else if expectation.is_empty() {
+            CheckKind::Unsupported {
+                reason: "rule declares no expectation".to_string(),
+            }
+        } else if let Some(metric) = metric {
+            metric_check(metric, &view, column.as_deref(), rule, expectation)
+        } else if let Some(column) = &column {
+            match expectation.to_sql(column) {
+                Some(predicate) => CheckKind::Rows {
+                    failing: format!("{} IS NOT NULL AND NOT ({})", column, predicate),
+                },
+                None => CheckKind::Unsupported {
+                    reason: "rule declares no expectation".to_string(),
+                },
+            }
+        } else {
+            CheckKind::Unsupported {
+                reason: "schema-level rule without metric or query".to_string(),
+            }
+        };
+
+        let mut check = Self::new(schema, property, name, kind);
+        check.description = rule.description.clone();
+        check.severity = rule
+            .extra
+            .get("severity")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        check
+    }
+
+    /// SQL statements running this check against `view`
+    pub fn statements(&self, view: &StagingView, sample_limit: usize) -> CheckStatements {
+        let table = quote_identifier(&view.name);
+        let samples = |failing: &str| {
+            (sample_limit > 0).then(|| {
+                view.wrap(&format!(
+                    "SELECT * FROM {} WHERE {} LIMIT {}",
+                    table, failing, sample_limit
+                ))
+            })
+        };
+        match &self.kind {
+            CheckKind::Rows { failing } => CheckStatements {
+                observe: Some(view.wrap(&format!(
+                    "SELECT COUNT(*) AS failed FROM {} WHERE {}",
+                    table, failing
+                ))),
+                samples: samples(failing),
+            },
+            CheckKind::Metric {
+                observed, failing, ..
+            } => CheckStatements {
+                observe: Some(view.wrap(&format!("SELECT {} AS observed", observed))),
+                samples: failing.as_deref().and_then(samples),
+            },
+            CheckKind::Sql { query, .. } => CheckStatements {
+                observe: Some(view.wrap(query)),
+                samples: None,
+            },
+            CheckKind::Unsupported { .. } => CheckStatements {
+                observe: None,
+                samples: None,
+            },
+        }
+    }
+
+    /// Evaluate the rows returned by the observe statement
+    pub fn evaluate(&self, rows: &[Value]) -> RuleResult {
+        let first = rows
+            .first()
+            .and_then(|row| match row {
+                Value::Object(fields) => fields.values().next().cloned(),
+                other => Some(other.clone()),
+            })
+            .unwrap_or(Value::Null);
+        match &self.kind {
+            CheckKind::Rows { .. } => {
+                let failed = first.as_u64().unwrap_or(0);
+                let status = if failed == 0 {
+                    RuleStatus::Passed
+                } else {
+                    RuleStatus::Failed
+                };
+                let message = (failed > 0).then(|| format!("{} failing rows", failed));
+                self.result(status, Some(first), Some(failed), message)
+            }
+            CheckKind::Metric { expectation, .. } | CheckKind::Sql { expectation, .. } => {
+                if expectation.is_empty() {
+                    return self.result(
+                        RuleStatus::Skipped,
+                        Some(first),
+                        None,
+                        Some("rule declares no expectation".to_string()),
+                    );
+                }
+                if expectation.matches(&first) {
+                    self.result(RuleStatus::Passed, Some(first), None, None)
+                } else {
+                    let message = format!("observed {}, {}", first, expectation);
+                    self.result(RuleStatus::Failed, Some(first), None, Some(message))
+                }
+            }
+            CheckKind::Unsupported { reason } => {
+                self.result(RuleStatus::Skipped, None, None, Some(reason.clone()))
+            }
+        }
+    }
+
+    /// Result of a check whose statement could not be executed
+    pub fn error(&self, message: impl Into<String>) -> RuleResult {
+        self.result(RuleStatus::Error, None, None, Some(message.into()))
+    }
+
+    fn result(
+        &self,
+        status: RuleStatus,
+        observed: Option<Value>,
+        failed_rows: Option<u64>,
+        message: Option<String>,
+    ) -> RuleResult {
+        if status == RuleStatus::Failed {
+            metrics::validation_failure(&format!("quality.{}", self.rule));
+        }
+        RuleResult {
+            schema: self.schema.clone(),
+            property: self.property.clone(),
+            rule: self.rule.clone(),
+            description: self.description.clone(),
+            severity: self.severity.clone(),
+            status,
+            observed,
+            failed_rows,
+            message,
+            samples: Vec::new(),
+        }
+    }
+}
+
+fn collect_property_checks(
+    schema: &str,
+    properties: &[Property],
+    parent: &[String],
+    checks: &mut Vec<QualityCheck>,
+) {
+    for property in properties {
+        let mut path = parent.to_vec();
+        path.push(property.name.clone());
+        let name = path.join(".");
+        let column = quote_identifier(&name);
+
+        if property.required || property.primary_key {
+            checks.push(QualityCheck::new(
+                schema,
+                Some(&name),
+                "required",
+                CheckKind::Rows {
+                    failing: format!("{} IS NULL", column),
+                },
+            ));
+        }
+        if property.unique || property.primary_key {
+            let view = quote_identifier(schema);
+            checks.push(QualityCheck::new(
+                schema,
+                Some(&name),
+                "unique",
+                CheckKind::Rows {
+                    failing: duplicate_rows(&view, std::slice::from_ref(&column)),
+                },
+            ));
+        }
+        for rule in &property.quality {
+            checks.push(QualityCheck::from_rule(schema, Some(&name), rule));
+        }
+        collect_property_checks(schema, &property.properties, &path, checks);
+    }
+}
+
+fn metric_check(
+    metric: &str,
+    view: &str,
+    column: Option<&str>,
+    rule: &QualityRule,
+    expectation: Expectation,
+) -> CheckKind {
+    let argument_list = |key: &str| -> Vec<Value> {
+        rule.extra
+            .get("arguments")
+            .and_then(|a| a.get(key))
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let count_where = |failing: &str| format!("(SELECT COUNT(*) FROM {} WHERE {})", view, failing);
+
+    let (count, failing) = match (metric, column) {
+        ("rowCount", _) => (format!("(SELECT COUNT(*) FROM {})", view), None),
+        ("nullValues", Some(column)) => {
+            let failing = format!("{} IS NULL", column);
+            (count_where(&failing), Some(failing))
+        }
+        ("missingValues", Some(column)) => {
+            let missing: Vec<String> = argument_list("missingValues")
+                .iter()
+                .filter(|v| !v.is_null())
+                .map(sql_literal)
+                .collect();
+            let failing = if missing.is_empty() {
+                format!("{} IS NULL", column)
+            } else {
+                format!(
+                    "({} IS NULL OR {} IN ({}))",
+                    column,
+                    column,
+                    missing.join(", ")
+                )
+            };
+            (count_where(&failing), Some(failing))
+        }
+        ("invalidValues", Some(column)) => {
+            let valid: Vec<String> = argument_list("validValues")
+                .iter()
+                .map(sql_literal)
+                .collect();
+            if valid.is_empty() {
+                return CheckKind::Unsupported {
+                    reason: "invalidValues requires arguments.validValues".to_string(),
+                };
+            }
+            let failing = format!(
+                "{} IS NOT NULL AND {} NOT IN ({})",
+                column,
+                column,
+                valid.join(", ")
+            );
+            (count_where(&failing), Some(failing))
+        }
+        ("duplicateValues", _) => {
+            let columns: Vec<String> = match argument_list("properties").as_slice() {
+                [] => column.map(|c| vec![c.to_string()]).unwrap_or_default(),
+                names => names
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(quote_identifier)
+                    .collect(),
+            };
+            if columns.is_empty() {
+                return CheckKind::Unsupported {
+                    reason: "duplicateValues requires a property".to_string(),
+                };
+            }
+            // Every occurrence beyond the first of a value counts as a duplicate
+            let count = format!(
+                "(SELECT COALESCE(SUM(n - 1), 0) FROM (SELECT COUNT(*) AS n FROM {} WHERE {} GROUP BY {}) g)",
+                view,
+                not_null(&columns),
+                columns.join(", ")
+            );
+            (count, Some(duplicate_rows(view, &columns)))
+        }
+        (other, _) => {
+            return CheckKind::Unsupported {
+                reason: match column {
+                    Some(_) => format!("unsupported metric: {}", other),
+                    None => format!("unsupported schema-level metric: {}", other),
+                },
+            };
+        }
+    };
+
+    let percent = rule.extra.get("unit").and_then(Value::as_str) == Some("percent");
+    let observed = if percent && metric != "rowCount" {
+        format!(
+            "CAST(100.0 * {} / NULLIF((SELECT COUNT(*) FROM {}), 0) AS DOUBLE PRECISION)",
+            count, view
+        )
+    } else {
+        format!("CAST({} AS BIGINT)", count)
+    };
+    CheckKind::Metric {
+        observed,
+        expectation,
+        failing,
+    }
+}
+
+/// Rows whose (non-null) value of `columns` occurs more than once
+fn duplicate_rows(view: &str, columns: &[String]) -> String {
+    let list = columns.join(", ");
+    format!(
+        "({}) IN (SELECT {} FROM {} WHERE {} GROUP BY {} HAVING COUNT(*) > 1)",
+        list,
+        list,
+        view,
+        not_null(columns),
+        list
+    )
+}
+
+fn not_null(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|c| format!("{} IS NOT NULL", c))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> SchemaObject {
+        let mut total = Property::new("total", "number");
+        total.quality.push(
+            serde_json::from_value(json!({
+                "type": "library",
+                "mustBeBetween": [0, 1000],
+                "severity": "warning"
+            }))
+            .unwrap(),
+        );
+        SchemaObject::new("orders")
+            .with_properties(vec![
+                Property::new("id", "integer").with_primary_key(true),
+                total,
+                Property::new("customer", "object").with_nested_properties(vec![
+                    Property::new("email", "string").with_required(true),
+                ]),
+            ])
+            .with_quality_rule(
+                serde_json::from_value(json!({
+                    "type": "sql",
+                    "query": "SELECT COUNT(*) FROM ${object} WHERE total < 0",
+                    "mustBe": 0
+                }))
+                .unwrap(),
+            )
+            .with_quality_rule(
+                serde_json::from_value(json!({"metric": "freshness", "mustBe": 0})).unwrap(),
+            )
+    }
+
+    #[test]
+    fn test_checks_for_schema() {
+        let checks = QualityCheck::for_schema(&schema());
+        let targets: Vec<(Option<&str>, &str)> = checks
+            .iter()
+            .map(|c| (c.property.as_deref(), c.rule.as_str()))
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                (None, "sql"),
+                (None, "freshness"),
+                (Some("id"), "required"),
+                (Some("id"), "unique"),
+                (Some("total"), "library"),
+                (Some("customer.email"), "required"),
+            ]
+        );
+        assert_eq!(checks[4].severity.as_deref(), Some("warning"));
+
+        let skipped = checks[1].evaluate(&[]);
+        assert_eq!(skipped.status, RuleStatus::Skipped);
+        assert_eq!(
+            skipped.message.as_deref(),
+            Some("unsupported schema-level metric: freshness")
+        );
+    }
+
+    #[test]
+    fn test_statements() {
+        let schema = schema();
+        let view = StagingView::new(&schema, SqlDialect::DuckDb, Some("eu"));
+        let cte = view.cte();
+        assert!(cte.starts_with("\"orders\" AS (SELECT id AS \"_staged_id\""));
+        assert!(cte.contains(
+            "TRY_CAST(json_extract_string(raw_json, '$.\"total\"') AS DOUBLE) AS \"total\""
+        ));
+        assert!(cte.contains(
+            "json_extract_string(raw_json, '$.\"customer\".\"email\"') AS \"customer.email\""
+        ));
+        assert!(cte.ends_with("FROM staged_json WHERE partition_key = 'eu')"));
+
+        let checks = QualityCheck::for_schema(&schema);
+        let sql = checks[0].statements(&view, 5);
+        assert_eq!(
+            sql.observe.unwrap(),
+            format!(
+                "WITH {} SELECT COUNT(*) FROM \"orders\" WHERE total < 0",
+                cte
+            )
+        );
+        assert_eq!(sql.samples, None);
+
+        let range = checks[4].statements(&view, 3);
+        assert!(range.observe.unwrap().ends_with(
+            "SELECT COUNT(*) AS failed FROM \"orders\" WHERE \"total\" IS NOT NULL AND NOT (\"total\" BETWEEN 0 AND 1000)"
+        ));
+        assert!(range.samples.unwrap().ends_with("LIMIT 3"));
+
+        let unique = checks[3].statements(&view, 0);
+        assert!(unique.observe.unwrap().ends_with(
+            "(\"id\") IN (SELECT \"id\" FROM \"orders\" WHERE \"id\" IS NOT NULL GROUP BY \"id\" HAVING COUNT(*) > 1)"
+        ));
+        assert_eq!(unique.samples, None);
+
+        let postgres = StagingView::new(&schema, SqlDialect::Postgres, None);
+        assert!(postgres.cte().contains(
+            "CASE WHEN jsonb_typeof(raw_json #> '{\"total\"}') = 'number' THEN (raw_json #>> '{\"total\"}')::double precision END AS \"total\""
+        ));
+        assert_eq!(
+            postgres.wrap("with t AS (SELECT 1) SELECT * FROM t;"),
+            format!("WITH {}, t AS (SELECT 1) SELECT * FROM t", postgres.cte())
+        );
+    }
+
+    #[test]
+    fn test_metric_rules() {
+        let rule: QualityRule = serde_json::from_value(json!({
+            "metric": "duplicateValues",
+            "mustBe": 0,
+            "arguments": {"properties": ["id", "region"]}
+        }))
+        .unwrap();
+        let check = QualityCheck::from_rule("orders", None, &rule);
+        let view = StagingView::new(&schema(), SqlDialect::DuckDb, None);
+        let sql = check.statements(&view, 5);
+        assert!(sql.observe.unwrap().ends_with(
+            "SELECT CAST((SELECT COALESCE(SUM(n - 1), 0) FROM (SELECT COUNT(*) AS n FROM \"orders\" WHERE \"id\" IS NOT NULL AND \"region\" IS NOT NULL GROUP BY \"id\", \"region\") g) AS BIGINT) AS observed"
+        ));
+        assert!(sql.samples.is_some());
+
+        let passed = check.evaluate(&[json!({"observed": 0})]);
+        assert_eq!(passed.status, RuleStatus::Passed);
+        let failed = check.evaluate(&[json!({"observed": 3})]);
+        assert_eq!(failed.status, RuleStatus::Failed);
+        assert_eq!(failed.message.as_deref(), Some("observed 3, must be 0"));
+
+        let nulls: QualityRule = serde_json::from_value(json!({
+            "metric": "nullValues",
+            "mustBeLessThan": 5,
+            "unit": "percent"
+        }))
+        .unwrap();
+        let check = QualityCheck::from_rule("orders", Some("total"), &nulls);
+        assert!(check.statements(&view, 5).observe.unwrap().ends_with(
+            "SELECT CAST(100.0 * (SELECT COUNT(*) FROM \"orders\" WHERE \"total\" IS NULL) / NULLIF((SELECT COUNT(*) FROM \"orders\"), 0) AS DOUBLE PRECISION) AS observed"
+        ));
+    }
+
+    #[test]
+    fn test_row_check_results() {
+        let checks = QualityCheck::for_schema(&schema());
+        let required = &checks[2];
+        assert_eq!(
+            required.evaluate(&[json!({"failed": 0})]).status,
+            RuleStatus::Passed
+        );
+        let failed = required.evaluate(&[json!({"failed": 2})]);
+        assert_eq!(failed.failed_rows, Some(2));
+        assert!(failed.is_blocking());
+        assert_eq!(
+            failed.to_string(),
+            "[FAIL] orders.id: required - 2 failing rows"
+        );
+
+        let warning = checks[4].evaluate(&[json!({"failed": 1})]);
+        assert_eq!(warning.status, RuleStatus::Failed);
+        assert!(!warning.is_blocking());
+
+        let error = required.error("Query error: syntax");
+        assert_eq!(error.status, RuleStatus::Error);
+        assert!(error.is_blocking());
+    }
+}
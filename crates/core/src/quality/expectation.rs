@@ -0,0 +1,245 @@
+//! Expected outcomes of quality rules (`mustBe`, `mustBeBetween`, ...)
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::models::odcs::QualityRule;
+
+/// Comparisons a quality rule places on an observed value
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Expectation {
+    pub must_be: Option<Value>,
+    pub must_not_be: Option<Value>,
+    pub greater_than: Option<f64>,
+    pub greater_than_or_equal: Option<f64>,
+    pub less_than: Option<f64>,
+    pub less_than_or_equal: Option<f64>,
+    pub between: Option<(f64, f64)>,
+    pub not_between: Option<(f64, f64)>,
+    pub must_be_in: Option<Vec<Value>>,
+    pub must_not_be_in: Option<Vec<Value>>,
+}
+
+impl Expectation {
+    /// Collect the comparisons of an ODCS quality rule
+    ///
+    /// `mustBeBetween` and `mustNotBeBetween` are not modeled on [`QualityRule`] and
+    /// are read from its additional properties.
+    pub fn from_rule(rule: &QualityRule) -> Self {
+        let number = |value: &Option<Value>| value.as_ref().and_then(as_number);
+        let range = |key: &str| match rule.extra.get(key).and_then(Value::as_array) {
+            Some(bounds) if bounds.len() == 2 => {
+                Some((as_number(&bounds[0])?, as_number(&bounds[1])?))
+            }
+            _ => None,
+        };
+        Self {
+            must_be: rule.must_be.clone(),
+            must_not_be: rule.must_not_be.clone(),
+            greater_than: number(&rule.must_be_greater_than),
+            greater_than_or_equal: number(&rule.must_be_greater_than_or_equal),
+            less_than: number(&rule.must_be_less_than),
+            less_than_or_equal: number(&rule.must_be_less_than_or_equal),
+            between: range("mustBeBetween"),
+            not_between: range("mustNotBeBetween"),
+            must_be_in: rule.must_be_in.clone(),
+            must_not_be_in: rule.must_not_be_in.clone(),
+        }
+    }
+
+    /// Returns true when the rule declares no comparison
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check an observed value against every declared comparison
+    pub fn matches(&self, observed: &Value) -> bool {
+        let number = as_number(observed);
+        let compare = |bound: Option<f64>, check: fn(f64, f64) -> bool| match bound {
+            Some(bound) => number.is_some_and(|n| check(n, bound)),
+            None => true,
+        };
+        self.must_be
+            .as_ref()
+            .is_none_or(|v| values_equal(observed, v))
+            && self
+                .must_not_be
+                .as_ref()
+                .is_none_or(|v| !values_equal(observed, v))
+            && compare(self.greater_than, |n, b| n > b)
+            && compare(self.greater_than_or_equal, |n, b| n >= b)
+            && compare(self.less_than, |n, b| n < b)
+            && compare(self.less_than_or_equal, |n, b| n <= b)
+            && self
+                .between
+                .is_none_or(|(low, high)| number.is_some_and(|n| n >= low && n <= high))
+            && self
+                .not_between
+                .is_none_or(|(low, high)| number.is_some_and(|n| n < low || n > high))
+            && self
+                .must_be_in
+                .as_ref()
+                .is_none_or(|values| values.iter().any(|v| values_equal(observed, v)))
+            && self
+                .must_not_be_in
+                .as_ref()
+                .is_none_or(|values| !values.iter().any(|v| values_equal(observed, v)))
+    }
+
+    /// SQL predicate that holds when `column` meets the expectation
+    pub fn to_sql(&self, column: &str) -> Option<String> {
+        let mut predicates = Vec::new();
+        if let Some(value) = &self.must_be {
+            predicates.push(format!("{} = {}", column, sql_literal(value)));
+        }
+        if let Some(value) = &self.must_not_be {
+            predicates.push(format!("{} <> {}", column, sql_literal(value)));
+        }
+        for (bound, operator) in [
+            (self.greater_than, ">"),
+            (self.greater_than_or_equal, ">="),
+            (self.less_than, "<"),
+            (self.less_than_or_equal, "<="),
+        ] {
+            if let Some(bound) = bound {
+                predicates.push(format!("{} {} {}", column, operator, bound));
+            }
+        }
+        if let Some((low, high)) = self.between {
+            predicates.push(format!("{} BETWEEN {} AND {}", column, low, high));
+        }
+        if let Some((low, high)) = self.not_between {
+            predicates.push(format!("{} NOT BETWEEN {} AND {}", column, low, high));
+        }
+        if let Some(values) = &self.must_be_in {
+            predicates.push(format!("{} IN ({})", column, sql_list(values)));
+        }
+        if let Some(values) = &self.must_not_be_in {
+            predicates.push(format!("{} NOT IN ({})", column, sql_list(values)));
+        }
+        (!predicates.is_empty()).then(|| predicates.join(" AND "))
+    }
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(value) = &self.must_be {
+            parts.push(format!("must be {}", value));
+        }
+        if let Some(value) = &self.must_not_be {
+            parts.push(format!("must not be {}", value));
+        }
+        for (bound, text) in [
+            (self.greater_than, "greater than"),
+            (self.greater_than_or_equal, "at least"),
+            (self.less_than, "less than"),
+            (self.less_than_or_equal, "at most"),
+        ] {
+            if let Some(bound) = bound {
+                parts.push(format!("must be {} {}", text, bound));
+            }
+        }
+        if let Some((low, high)) = self.between {
+            parts.push(format!("must be between {} and {}", low, high));
+        }
+        if let Some((low, high)) = self.not_between {
+            parts.push(format!("must not be between {} and {}", low, high));
+        }
+        if let Some(values) = &self.must_be_in {
+            parts.push(format!("must be one of {}", Value::Array(values.clone())));
+        }
+        if let Some(values) = &self.must_not_be_in {
+            parts.push(format!(
+                "must not be one of {}",
+                Value::Array(values.clone())
+            ));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Numbers, and strings holding numbers (as some backends return decimals)
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn values_equal(observed: &Value, expected: &Value) -> bool {
+    match (as_number(observed), as_number(expected)) {
+        (Some(a), Some(b)) => a == b,
+        _ => observed == expected,
+    }
+}
+
+/// Render a JSON value as a SQL literal
+pub(crate) fn sql_literal(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::Bool(b) => b.to_string().to_uppercase(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => quote_string(s),
+        other => quote_string(&other.to_string()),
+    }
+}
+
+fn sql_list(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(sql_literal)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+pub(crate) fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_expectation_from_rule() {
+        let rule: QualityRule = serde_json::from_value(json!({
+            "type": "sql",
+            "query": "SELECT 1",
+            "mustBeBetween": [1000, 49900],
+            "mustNotBe": 5000
+        }))
+        .unwrap();
+        let expectation = Expectation::from_rule(&rule);
+        assert_eq!(expectation.between, Some((1000.0, 49900.0)));
+
+        assert!(expectation.matches(&json!(1200)));
+        assert!(expectation.matches(&json!("1200.5")));
+        assert!(!expectation.matches(&json!(5000)));
+        assert!(!expectation.matches(&json!(999)));
+        assert!(!expectation.matches(&Value::Null));
+        assert_eq!(
+            expectation.to_string(),
+            "must not be 5000, must be between 1000 and 49900"
+        );
+        assert_eq!(
+            expectation.to_sql("\"total\"").unwrap(),
+            "\"total\" <> 5000 AND \"total\" BETWEEN 1000 AND 49900"
+        );
+        assert!(Expectation::default().is_empty());
+    }
+
+    #[test]
+    fn test_sql_literals() {
+        assert_eq!(sql_literal(&json!("O'Brien")), "'O''Brien'");
+        assert_eq!(sql_literal(&json!(true)), "TRUE");
+        assert_eq!(quote_identifier("a\"b"), "\"a\"\"b\"");
+    }
+}
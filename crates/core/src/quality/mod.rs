@@ -0,0 +1,41 @@
+//! Data quality rule execution against staged data
+//!
+//! Runs the quality rules of an ODCS contract against the records of a staging
+//! database (DuckDB or PostgreSQL) and reports pass/fail per rule, with a sample of
+//! failing rows:
+//!
+//! - **Not-null** - properties marked `required` or `primaryKey`
+//! - **Uniqueness** - properties marked `unique` or `primaryKey`
+//! - **Library rules** - the `rowCount`, `nullValues`, `missingValues`,
+//!   `invalidValues` and `duplicateValues` metrics, compared with `mustBe`,
+//!   `mustBeGreaterThan`, `mustBeBetween`, ...; property rules without a metric
+//!   check every value (e.g. `mustBeBetween: [0, 100]`)
+//! - **SQL rules** - `type: sql` queries, compared with their expectation
+//!
+//! Each schema object is exposed to the checks as a table of the same name (see
+//! [`StagingView`]), so SQL rules such as `SELECT COUNT(*) FROM orders WHERE total < 0`
+//! run unchanged; `${object}` and `${property}` placeholders are substituted as well.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::quality::QualityRunner;
+//! use data_modelling_core::staging::StagingDb;
+//!
+//! let db = StagingDb::open("staging.duckdb")?;
+//! let report = QualityRunner::new()
+//!     .with_partition("orders")
+//!     .run(&db, &contract);
+//! println!("{}", report);
+//! assert!(report.is_success());
+//! ```
+
+mod check;
+mod expectation;
+mod report;
+mod runner;
+
+pub use check::{CheckStatements, QualityCheck, SOURCE_COLUMNS, StagingView};
+pub use expectation::Expectation;
+pub use report::{QualityReport, RuleResult, RuleStatus};
+pub use runner::{DEFAULT_SAMPLE_LIMIT, QualityRunner};
@@ -0,0 +1,132 @@
+//! Results of quality rule execution
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Outcome of a single quality rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleStatus {
+    Passed,
+    Failed,
+    /// The rule could not be executed (e.g. unsupported metric)
+    Skipped,
+    /// The rule's query failed
+    Error,
+}
+
+impl fmt::Display for RuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            RuleStatus::Passed => "PASS",
+            RuleStatus::Failed => "FAIL",
+            RuleStatus::Skipped => "SKIP",
+            RuleStatus::Error => "ERROR",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// Result of executing one quality rule against staged data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleResult {
+    /// Schema object the rule belongs to
+    pub schema: String,
+    /// Property path for property-level rules
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    /// Rule name
+    pub rule: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    pub status: RuleStatus,
+    /// Value computed by the rule (failing row count, metric or query result)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed: Option<Value>,
+    /// Number of failing rows, for row-level rules
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_rows: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Sample of failing rows, including their source file and record index
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub samples: Vec<Value>,
+}
+
+impl RuleResult {
+    /// Qualified rule name, e.g. `orders.id: unique`
+    pub fn target(&self) -> String {
+        match &self.property {
+            Some(property) => format!("{}.{}: {}", self.schema, property, self.rule),
+            None => format!("{}: {}", self.schema, self.rule),
+        }
+    }
+
+    /// Failed or errored rules fail the report unless their severity is
+    /// `warning` or `info`
+    pub fn is_blocking(&self) -> bool {
+        matches!(self.status, RuleStatus::Failed | RuleStatus::Error)
+            && !matches!(
+                self.severity.as_deref().map(str::to_lowercase).as_deref(),
+                Some("warning" | "info")
+            )
+    }
+}
+
+impl fmt::Display for RuleResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.status, self.target())?;
+        if let Some(message) = &self.message {
+            write!(f, " - {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Pass/fail report of every quality rule of a contract
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QualityReport {
+    pub results: Vec<RuleResult>,
+}
+
+impl QualityReport {
+    /// Number of results with the given status
+    pub fn count(&self, status: RuleStatus) -> usize {
+        self.results.iter().filter(|r| r.status == status).count()
+    }
+
+    /// Results that fail the report
+    pub fn failures(&self) -> impl Iterator<Item = &RuleResult> {
+        self.results.iter().filter(|r| r.is_blocking())
+    }
+
+    /// Returns true when no rule failed the report
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// One-line count of results per status
+    pub fn summary(&self) -> String {
+        format!(
+            "{} passed, {} failed, {} skipped, {} errors",
+            self.count(RuleStatus::Passed),
+            self.count(RuleStatus::Failed),
+            self.count(RuleStatus::Skipped),
+            self.count(RuleStatus::Error)
+        )
+    }
+}
+
+impl fmt::Display for QualityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            writeln!(f, "{}", result)?;
+        }
+        write!(f, "{}", self.summary())
+    }
+}
@@ -0,0 +1,129 @@
+//! Execution of quality checks against a staging database
+
+use super::check::{QualityCheck, StagingView};
+use super::report::{QualityReport, RuleResult, RuleStatus};
+use crate::models::odcs::ODCSContract;
+use crate::staging::SqlDialect;
+
+/// Default number of failing rows sampled per rule
+pub const DEFAULT_SAMPLE_LIMIT: usize = 5;
+
+/// Runs the quality rules of a contract against staged records
+#[derive(Debug, Clone)]
+pub struct QualityRunner {
+    partition: Option<String>,
+    sample_limit: usize,
+}
+
+impl Default for QualityRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QualityRunner {
+    /// Create a runner checking all staged records
+    pub fn new() -> Self {
+        Self {
+            partition: None,
+            sample_limit: DEFAULT_SAMPLE_LIMIT,
+        }
+    }
+
+    /// Only check records of the given partition
+    pub fn with_partition(mut self, partition: impl Into<String>) -> Self {
+        self.partition = Some(partition.into());
+        self
+    }
+
+    /// Number of failing rows sampled per failed rule (0 disables sampling)
+    pub fn with_sample_limit(mut self, limit: usize) -> Self {
+        self.sample_limit = limit;
+        self
+    }
+
+    /// Run every check of `contract` against a DuckDB staging database
+    ///
+    /// Rules whose query fails are reported with [`RuleStatus::Error`] rather than
+    /// aborting the run.
+    #[cfg(feature = "duckdb-backend")]
+    pub fn run(&self, db: &crate::staging::StagingDb, contract: &ODCSContract) -> QualityReport {
+        let mut report = QualityReport::default();
+        for (check, view) in self.plan(contract, SqlDialect::DuckDb) {
+            let statements = check.statements(&view, self.sample_limit);
+            let Some(observe) = statements.observe else {
+                report.results.push(check.evaluate(&[]));
+                continue;
+            };
+            let mut result = match db.query(&observe) {
+                Ok(rows) => check.evaluate(&rows),
+                Err(e) => check.error(e.to_string()),
+            };
+            if let (RuleStatus::Failed, Some(samples)) = (result.status, &statements.samples) {
+                attach_samples(&mut result, db.query(samples));
+            }
+            report.results.push(result);
+        }
+        report
+    }
+
+    /// Run every check of `contract` against a PostgreSQL staging database
+    ///
+    /// Rules whose query fails are reported with [`RuleStatus::Error`] rather than
+    /// aborting the run.
+    #[cfg(feature = "postgres-backend")]
+    pub async fn run_postgres(
+        &self,
+        db: &crate::staging::StagingDbPostgres,
+        contract: &ODCSContract,
+    ) -> QualityReport {
+        let mut report = QualityReport::default();
+        for (check, view) in self.plan(contract, SqlDialect::Postgres) {
+            let statements = check.statements(&view, self.sample_limit);
+            let Some(observe) = statements.observe else {
+                report.results.push(check.evaluate(&[]));
+                continue;
+            };
+            let mut result = match db.query(&observe).await {
+                Ok(rows) => check.evaluate(&rows),
+                Err(e) => check.error(e.to_string()),
+            };
+            if let (RuleStatus::Failed, Some(samples)) = (result.status, &statements.samples) {
+                attach_samples(&mut result, db.query(samples).await);
+            }
+            report.results.push(result);
+        }
+        report
+    }
+
+    /// Checks of every schema object, each with the view it runs against
+    pub fn plan(
+        &self,
+        contract: &ODCSContract,
+        dialect: SqlDialect,
+    ) -> Vec<(QualityCheck, StagingView)> {
+        contract
+            .schema
+            .iter()
+            .flat_map(|schema| {
+                let view = StagingView::new(schema, dialect, self.partition.as_deref());
+                QualityCheck::for_schema(schema)
+                    .into_iter()
+                    .map(move |check| (check, view.clone()))
+            })
+            .collect()
+    }
+}
+
+fn attach_samples<E: std::fmt::Display>(
+    result: &mut RuleResult,
+    samples: Result<Vec<serde_json::Value>, E>,
+) {
+    match samples {
+        Ok(rows) => result.samples = rows,
+        Err(e) => {
+            let message = result.message.take().unwrap_or_default();
+            result.message = Some(format!("{} (sampling failed: {})", message, e));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//! Confluent Schema Registry REST client
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use super::error::{RegistryError, RegistryResult};
+use super::types::{
+    CompatibilityLevel, CompatibilityResult, RegisteredSchema, SchemaDefinition, SchemaType,
+    SchemaVersion,
+};
+use crate::export::{ExportOptions, ExporterRegistry};
+use crate::import::{ImportResult, ImporterRegistry};
+use crate::models::Table;
+
+/// Content type of registry requests and responses
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// Authentication of registry requests
+#[derive(Clone)]
+enum Auth {
+    None,
+    Basic { username: String, password: String },
+    Bearer(String),
+}
+
+/// Client for the Confluent Schema Registry REST API
+///
+/// Also works with compatible registries (e.g. Redpanda, Apicurio's
+/// Confluent-compatible API). Credentials are never logged.
+#[derive(Clone)]
+pub struct SchemaRegistryClient {
+    /// Registry base URL (e.g. http://localhost:8081)
+    base_url: String,
+    auth: Auth,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct RegisterResponse {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigResponse {
+    #[serde(alias = "compatibility")]
+    compatibility_level: CompatibilityLevel,
+}
+
+impl SchemaRegistryClient {
+    /// Create a client without authentication
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            auth: Auth::None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Create from environment variables
+    ///
+    /// Reads SCHEMA_REGISTRY_URL, and optionally SCHEMA_REGISTRY_API_KEY and
+    /// SCHEMA_REGISTRY_API_SECRET for basic authentication (Confluent Cloud).
+    pub fn from_env() -> RegistryResult<Self> {
+        let url = std::env::var("SCHEMA_REGISTRY_URL").map_err(|_| {
+            RegistryError::ConfigError("SCHEMA_REGISTRY_URL environment variable not set".into())
+        })?;
+        let client = Self::new(url);
+        match (
+            std::env::var("SCHEMA_REGISTRY_API_KEY"),
+            std::env::var("SCHEMA_REGISTRY_API_SECRET"),
+        ) {
+            (Ok(key), Ok(secret)) => Ok(client.with_basic_auth(key, secret)),
+            _ => Ok(client),
+        }
+    }
+
+    /// Authenticate with a username (or API key) and password (or API secret)
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.auth = Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        };
+        self
+    }
+
+    /// Authenticate with a bearer token
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.auth = Auth::Bearer(token.into());
+        self
+    }
+
+    /// Get the registry base URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// List all subjects
+    pub async fn list_subjects(&self) -> RegistryResult<Vec<String>> {
+        self.request(reqwest::Method::GET, "subjects", None, "subjects")
+            .await
+    }
+
+    /// List the registered versions of a subject
+    pub async fn list_versions(&self, subject: &str) -> RegistryResult<Vec<i32>> {
+        let path = format!("subjects/{}/versions", encode(subject));
+        self.request(reqwest::Method::GET, &path, None, subject)
+            .await
+    }
+
+    /// Get a version of a subject's schema
+    pub async fn get_schema(
+        &self,
+        subject: &str,
+        version: SchemaVersion,
+    ) -> RegistryResult<RegisteredSchema> {
+        let path = format!("subjects/{}/versions/{}", encode(subject), version);
+        self.request(reqwest::Method::GET, &path, None, subject)
+            .await
+    }
+
+    /// Get the latest version of a subject's schema
+    pub async fn get_latest_schema(&self, subject: &str) -> RegistryResult<RegisteredSchema> {
+        self.get_schema(subject, SchemaVersion::Latest).await
+    }
+
+    /// Get a schema by its global ID
+    pub async fn get_schema_by_id(&self, id: u32) -> RegistryResult<SchemaDefinition> {
+        let path = format!("schemas/ids/{}", id);
+        self.request(reqwest::Method::GET, &path, None, &format!("id {}", id))
+            .await
+    }
+
+    /// Get the compatibility level of a subject, falling back to the global level
+    pub async fn get_compatibility(&self, subject: &str) -> RegistryResult<CompatibilityLevel> {
+        let path = format!("config/{}?defaultToGlobal=true", encode(subject));
+        let config: ConfigResponse = self
+            .request(reqwest::Method::GET, &path, None, subject)
+            .await?;
+        Ok(config.compatibility_level)
+    }
+
+    /// Set the compatibility level of a subject
+    pub async fn set_compatibility(
+        &self,
+        subject: &str,
+        level: CompatibilityLevel,
+    ) -> RegistryResult<CompatibilityLevel> {
+        let path = format!("config/{}", encode(subject));
+        let config: ConfigResponse = self
+            .request(
+                reqwest::Method::PUT,
+                &path,
+                Some(json!({ "compatibility": level })),
+                subject,
+            )
+            .await?;
+        Ok(config.compatibility_level)
+    }
+
+    /// Check a schema against a version of a subject (usually the latest)
+    ///
+    /// A subject without versions accepts any schema.
+    pub async fn check_compatibility(
+        &self,
+        subject: &str,
+        version: SchemaVersion,
+        schema: &SchemaDefinition,
+    ) -> RegistryResult<CompatibilityResult> {
+        let path = format!(
+            "compatibility/subjects/{}/versions/{}?verbose=true",
+            encode(subject),
+            version
+        );
+        match self
+            .request(reqwest::Method::POST, &path, Some(json!(schema)), subject)
+            .await
+        {
+            Err(RegistryError::SubjectNotFound(_)) | Err(RegistryError::VersionNotFound(_)) => {
+                Ok(CompatibilityResult {
+                    is_compatible: true,
+                    messages: Vec::new(),
+                })
+            }
+            result => result,
+        }
+    }
+
+    /// Register a schema under a subject
+    ///
+    /// Registering a schema that is already registered returns its existing ID.
+    ///
+    /// # Returns
+    /// The schema's global ID
+    pub async fn register_schema(
+        &self,
+        subject: &str,
+        schema: &SchemaDefinition,
+    ) -> RegistryResult<u32> {
+        let path = format!("subjects/{}/versions", encode(subject));
+        let response: RegisterResponse = self
+            .request(reqwest::Method::POST, &path, Some(json!(schema)), subject)
+            .await?;
+        Ok(response.id)
+    }
+
+    /// Look up a schema registered under a subject
+    pub async fn lookup_schema(
+        &self,
+        subject: &str,
+        schema: &SchemaDefinition,
+    ) -> RegistryResult<RegisteredSchema> {
+        let path = format!("subjects/{}", encode(subject));
+        self.request(reqwest::Method::POST, &path, Some(json!(schema)), subject)
+            .await
+    }
+
+    /// Delete a subject and all its versions (soft delete)
+    ///
+    /// # Returns
+    /// The deleted versions
+    pub async fn delete_subject(&self, subject: &str) -> RegistryResult<Vec<i32>> {
+        let path = format!("subjects/{}", encode(subject));
+        self.request(reqwest::Method::DELETE, &path, None, subject)
+            .await
+    }
+
+    /// Pull a version of a subject's schema and import it with the built-in importers
+    pub async fn pull(
+        &self,
+        subject: &str,
+        version: SchemaVersion,
+    ) -> RegistryResult<ImportResult> {
+        let schema = self.get_schema(subject, version).await?;
+        import_schema(&schema)
+    }
+
+    /// Push a schema after checking it against the subject's latest version
+    ///
+    /// # Errors
+    /// [`RegistryError::Incompatible`] when the check fails; nothing is registered.
+    ///
+    /// # Returns
+    /// The registered schema with its ID and version
+    pub async fn push(
+        &self,
+        subject: &str,
+        schema: &SchemaDefinition,
+    ) -> RegistryResult<RegisteredSchema> {
+        let compatibility = self
+            .check_compatibility(subject, SchemaVersion::Latest, schema)
+            .await?;
+        if !compatibility.is_compatible {
+            return Err(RegistryError::Incompatible {
+                subject: subject.to_string(),
+                messages: compatibility.messages,
+            });
+        }
+        self.register_schema(subject, schema).await?;
+        self.lookup_schema(subject, schema).await
+    }
+
+    /// Export a table with the built-in exporters and push it
+    pub async fn push_table(
+        &self,
+        subject: &str,
+        table: &Table,
+        schema_type: SchemaType,
+    ) -> RegistryResult<RegisteredSchema> {
+        let schema = export_table(table, schema_type)?;
+        self.push(subject, &schema).await
+    }
+
+    /// Send a request and parse the JSON response
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<Value>,
+        context: &str,
+    ) -> RegistryResult<T> {
+        let url = format!("{}/{}", self.base_url, path);
+        let mut request = self
+            .client
+            .request(method, &url)
+            .header("Accept", CONTENT_TYPE);
+        request = match &self.auth {
+            Auth::None => request,
+            Auth::Basic { username, password } => request.basic_auth(username, Some(password)),
+            Auth::Bearer(token) => request.bearer_auth(token),
+        };
+        if let Some(body) = body {
+            request = request
+                .header("Content-Type", CONTENT_TYPE)
+                .body(body.to_string());
+        }
+
+        let response = request.send().await.map_err(|e| {
+            RegistryError::RequestFailed(format!("Schema registry request failed: {}", e))
+        })?;
+
+        let status = response.status();
+        let text = response.text().await.map_err(|e| {
+            RegistryError::RequestFailed(format!("Failed to read schema registry response: {}", e))
+        })?;
+        if !status.is_success() {
+            return Err(RegistryError::from_response(
+                status.as_u16(),
+                &text,
+                context,
+            ));
+        }
+
+        serde_json::from_str(&text).map_err(|e| {
+            RegistryError::RequestFailed(format!("Invalid schema registry response: {}", e))
+        })
+    }
+}
+
+// Implement Debug manually to prevent accidental credential logging
+impl std::fmt::Debug for SchemaRegistryClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let auth = match self.auth {
+            Auth::None => "none",
+            Auth::Basic { .. } => "basic",
+            Auth::Bearer(_) => "bearer",
+        };
+        f.debug_struct("SchemaRegistryClient")
+            .field("base_url", &self.base_url)
+            .field("auth", &auth)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Import a registered schema with the importer for its type
+///
+/// References to other subjects are not resolved.
+pub fn import_schema(schema: &RegisteredSchema) -> RegistryResult<ImportResult> {
+    Ok(ImporterRegistry::default().import(schema.schema_type.format(), &schema.schema)?)
+}
+
+/// Export a table as a schema of the given type
+pub fn export_table(table: &Table, schema_type: SchemaType) -> RegistryResult<SchemaDefinition> {
+    let result = ExporterRegistry::default().export_tables(
+        schema_type.format(),
+        std::slice::from_ref(table),
+        &ExportOptions::default(),
+    )?;
+    Ok(SchemaDefinition::new(schema_type, result.content))
+}
+
+/// Subject name of a topic's key or value schema (`TopicNameStrategy`)
+pub fn topic_subject(topic: &str, is_key: bool) -> String {
+    format!("{}-{}", topic, if is_key { "key" } else { "value" })
+}
+
+fn encode(subject: &str) -> String {
+    urlencoding::encode(subject).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Order",
+        "namespace": "com.example",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "customer", "type": ["null", "string"], "default": null}
+        ]
+    }"#;
+
+    #[test]
+    fn test_registered_schema_defaults_to_avro() {
+        let schema: RegisteredSchema = serde_json::from_value(json!({
+            "subject": "orders-value",
+            "id": 7,
+            "version": 2,
+            "schema": ORDER_SCHEMA
+        }))
+        .unwrap();
+        assert_eq!(schema.schema_type, SchemaType::Avro);
+        assert!(schema.references.is_empty());
+
+        let result = import_schema(&schema).unwrap();
+        assert_eq!(result.tables.len(), 1);
+        assert_eq!(result.tables[0].name.as_deref(), Some("Order"));
+
+        let body = json!(SchemaDefinition::new(
+            SchemaType::Protobuf,
+            "syntax = \"proto3\";"
+        ));
+        assert_eq!(body["schemaType"], "PROTOBUF");
+        assert!(body.get("references").is_none());
+    }
+
+    #[test]
+    fn test_error_responses() {
+        assert!(matches!(
+            RegistryError::from_response(
+                404,
+                r#"{"error_code":40401,"message":"Subject 'x' not found."}"#,
+                "x"
+            ),
+            RegistryError::SubjectNotFound(subject) if subject == "x"
+        ));
+        assert!(matches!(
+            RegistryError::from_response(
+                409,
+                r#"{"error_code":409,"message":"Schema being registered is incompatible"}"#,
+                "orders-value"
+            ),
+            RegistryError::Incompatible { .. }
+        ));
+        match RegistryError::from_response(500, "boom", "x") {
+            RegistryError::ApiError {
+                status,
+                code,
+                message,
+            } => {
+                assert_eq!((status, code), (500, 500));
+                assert_eq!(message, "boom");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        let config: ConfigResponse =
+            serde_json::from_str(r#"{"compatibilityLevel":"FULL_TRANSITIVE"}"#).unwrap();
+        assert_eq!(
+            config.compatibility_level,
+            CompatibilityLevel::FullTransitive
+        );
+        assert_eq!(topic_subject("orders", false), "orders-value");
+    }
+
+    #[test]
+    fn test_debug_hides_credentials() {
+        let client = SchemaRegistryClient::new("https://registry.example.com/")
+            .with_basic_auth("key", "super-secret");
+        let debug = format!("{:?}", client);
+        assert!(debug.contains("https://registry.example.com"));
+        assert!(!debug.contains("super-secret"));
+    }
+}
@@ -0,0 +1,101 @@
+//! Error types for schema registry operations
+
+use thiserror::Error;
+
+use crate::export::ExportError;
+use crate::import::ImportError;
+
+/// Errors that can occur while talking to a schema registry
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    /// Client configuration error
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+
+    /// The request could not be sent or its response could not be read
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+
+    /// The subject does not exist
+    #[error("Subject not found: {0}")]
+    SubjectNotFound(String),
+
+    /// The subject exists but the version does not
+    #[error("Version not found for subject: {0}")]
+    VersionNotFound(String),
+
+    /// No schema with the given ID, or the schema is not registered under the subject
+    #[error("Schema not found: {0}")]
+    SchemaNotFound(String),
+
+    /// The schema is not compatible with the subject's registered versions
+    #[error("Schema is incompatible with subject '{subject}': {}", messages.join("; "))]
+    Incompatible {
+        subject: String,
+        messages: Vec<String>,
+    },
+
+    /// The registry rejected the schema as invalid
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String),
+
+    /// Any other error response
+    #[error("Schema registry error (HTTP {status}, code {code}): {message}")]
+    ApiError {
+        status: u16,
+        code: i64,
+        message: String,
+    },
+
+    /// Unsupported schema type or format
+    #[error("Unsupported schema type: {0}")]
+    UnsupportedSchemaType(String),
+
+    /// Importing a pulled schema failed
+    #[error("Import error: {0}")]
+    ImportError(#[from] ImportError),
+
+    /// Exporting a schema to push failed
+    #[error("Export error: {0}")]
+    ExportError(#[from] ExportError),
+}
+
+impl RegistryError {
+    /// Map an error response of the registry API
+    ///
+    /// The registry reports errors as `{"error_code": 40401, "message": "..."}`,
+    /// where the first three digits of the code are the HTTP status.
+    pub fn from_response(status: u16, body: &str, context: &str) -> Self {
+        let parsed: Option<serde_json::Value> = serde_json::from_str(body).ok();
+        let code = parsed
+            .as_ref()
+            .and_then(|value| value.get("error_code"))
+            .and_then(|code| code.as_i64())
+            .unwrap_or(i64::from(status));
+        let message = parsed
+            .as_ref()
+            .and_then(|value| value.get("message"))
+            .and_then(|message| message.as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| body.trim().to_string());
+
+        match code {
+            40401 => RegistryError::SubjectNotFound(context.to_string()),
+            40402 => RegistryError::VersionNotFound(context.to_string()),
+            40403 => RegistryError::SchemaNotFound(context.to_string()),
+            409 | 40901 => RegistryError::Incompatible {
+                subject: context.to_string(),
+                messages: vec![message],
+            },
+            42201 | 42202 => RegistryError::InvalidSchema(message),
+            _ => RegistryError::ApiError {
+                status,
+                code,
+                message,
+            },
+        }
+    }
+}
+
+/// Result type for schema registry operations
+pub type RegistryResult<T> = Result<T, RegistryError>;
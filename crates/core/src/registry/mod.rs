@@ -0,0 +1,43 @@
+//! Kafka Schema Registry integration
+//!
+//! This module provides a client for the Confluent Schema Registry REST API
+//! (feature: `schema-registry`) that connects registered schemas to the SDK's
+//! importers and exporters:
+//!
+//! - **Pull**: fetch an Avro, Protobuf or JSON schema by subject and version,
+//!   and import it into tables
+//! - **Push**: export a table to the subject's schema type, check it against
+//!   the subject's latest version and register it only if compatible
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::registry::{SchemaRegistryClient, SchemaType, SchemaVersion};
+//!
+//! let client = SchemaRegistryClient::new("http://localhost:8081");
+//!
+//! // Pull the latest value schema of a topic
+//! let imported = client.pull("orders-value", SchemaVersion::Latest).await?;
+//!
+//! // Push a table back as a new version
+//! let registered = client.push_table("orders-value", &table, SchemaType::Avro).await?;
+//! println!("Registered version {} (id {})", registered.version, registered.id);
+//! ```
+//!
+//! # Authentication
+//!
+//! Use [`SchemaRegistryClient::with_basic_auth`] with an API key and secret
+//! (Confluent Cloud), or [`SchemaRegistryClient::with_bearer_token`].
+//! [`SchemaRegistryClient::from_env`] reads `SCHEMA_REGISTRY_URL`,
+//! `SCHEMA_REGISTRY_API_KEY` and `SCHEMA_REGISTRY_API_SECRET`.
+
+mod client;
+mod error;
+mod types;
+
+pub use client::{SchemaRegistryClient, export_table, import_schema, topic_subject};
+pub use error::{RegistryError, RegistryResult};
+pub use types::{
+    CompatibilityLevel, CompatibilityResult, RegisteredSchema, SchemaDefinition, SchemaReference,
+    SchemaType, SchemaVersion,
+};
@@ -0,0 +1,191 @@
+//! Schema registry API types
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Type of a registered schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SchemaType {
+    /// Apache Avro (the registry default, omitted from responses)
+    #[default]
+    Avro,
+    /// Protocol Buffers
+    Protobuf,
+    /// JSON Schema
+    Json,
+}
+
+impl SchemaType {
+    /// Name of the importer and exporter for the schema type
+    pub fn format(&self) -> &'static str {
+        match self {
+            SchemaType::Avro => "avro",
+            SchemaType::Protobuf => "protobuf",
+            SchemaType::Json => "json-schema",
+        }
+    }
+
+    /// Get the schema type for an importer or exporter name
+    pub fn from_format(format: &str) -> Option<Self> {
+        match format.to_lowercase().as_str() {
+            "avro" => Some(SchemaType::Avro),
+            "protobuf" | "proto" => Some(SchemaType::Protobuf),
+            "json-schema" | "json_schema" | "jsonschema" | "json" => Some(SchemaType::Json),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SchemaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaType::Avro => write!(f, "AVRO"),
+            SchemaType::Protobuf => write!(f, "PROTOBUF"),
+            SchemaType::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+/// Reference from a schema to a schema registered under another subject
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaReference {
+    /// Name used by the referencing schema (e.g. the imported `.proto` file)
+    pub name: String,
+    /// Subject of the referenced schema
+    pub subject: String,
+    /// Version of the referenced schema
+    pub version: i32,
+}
+
+/// A schema to register or check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDefinition {
+    /// Schema text
+    pub schema: String,
+    /// Schema type
+    #[serde(default)]
+    pub schema_type: SchemaType,
+    /// References to other subjects
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<SchemaReference>,
+}
+
+impl SchemaDefinition {
+    /// Create a schema definition
+    pub fn new(schema_type: SchemaType, schema: impl Into<String>) -> Self {
+        Self {
+            schema: schema.into(),
+            schema_type,
+            references: Vec::new(),
+        }
+    }
+
+    /// Add a reference to another subject
+    pub fn with_reference(mut self, reference: SchemaReference) -> Self {
+        self.references.push(reference);
+        self
+    }
+}
+
+/// A schema registered under a subject
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredSchema {
+    /// Subject name
+    pub subject: String,
+    /// Globally unique schema ID
+    pub id: u32,
+    /// Version within the subject
+    pub version: i32,
+    /// Schema type
+    #[serde(default)]
+    pub schema_type: SchemaType,
+    /// Schema text
+    pub schema: String,
+    /// References to other subjects
+    #[serde(default)]
+    pub references: Vec<SchemaReference>,
+}
+
+impl RegisteredSchema {
+    /// The schema as a definition, e.g. to register it under another subject
+    pub fn definition(&self) -> SchemaDefinition {
+        SchemaDefinition {
+            schema: self.schema.clone(),
+            schema_type: self.schema_type,
+            references: self.references.clone(),
+        }
+    }
+}
+
+/// Version selector of a subject
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaVersion {
+    /// The latest registered version
+    #[default]
+    Latest,
+    /// A specific version
+    Number(i32),
+}
+
+impl fmt::Display for SchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaVersion::Latest => write!(f, "latest"),
+            SchemaVersion::Number(version) => write!(f, "{}", version),
+        }
+    }
+}
+
+impl From<i32> for SchemaVersion {
+    fn from(version: i32) -> Self {
+        SchemaVersion::Number(version)
+    }
+}
+
+/// Compatibility level of a subject
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CompatibilityLevel {
+    /// New schemas can read data written with the previous version
+    Backward,
+    /// New schemas can read data written with all previous versions
+    BackwardTransitive,
+    /// The previous version can read data written with new schemas
+    Forward,
+    /// All previous versions can read data written with new schemas
+    ForwardTransitive,
+    /// Backward and forward compatible with the previous version
+    Full,
+    /// Backward and forward compatible with all previous versions
+    FullTransitive,
+    /// No compatibility checks
+    None,
+}
+
+impl fmt::Display for CompatibilityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CompatibilityLevel::Backward => "BACKWARD",
+            CompatibilityLevel::BackwardTransitive => "BACKWARD_TRANSITIVE",
+            CompatibilityLevel::Forward => "FORWARD",
+            CompatibilityLevel::ForwardTransitive => "FORWARD_TRANSITIVE",
+            CompatibilityLevel::Full => "FULL",
+            CompatibilityLevel::FullTransitive => "FULL_TRANSITIVE",
+            CompatibilityLevel::None => "NONE",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Result of a compatibility check
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CompatibilityResult {
+    /// Whether the schema is compatible
+    pub is_compatible: bool,
+    /// Reasons for incompatibility (verbose checks only)
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
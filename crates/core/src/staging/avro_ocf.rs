@@ -0,0 +1,536 @@
+//! Avro object container file (OCF) reader
+//!
+//! Decodes the data blocks of an OCF with the writer schema stored in its header.
+//! The `null` and `deflate` codecs are supported. Values are converted to plain
+//! JSON: union branches are unwrapped, `bytes` and `fixed` become strings with one
+//! character per byte (as in the Avro JSON encoding) and logical types keep their
+//! underlying representation.
+//!
+//! Counts read from the file are checked against the data left to decode and
+//! nesting is limited to [`MAX_DEPTH`], so corrupt or crafted files fail instead
+//! of looping or recursing without bound.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use serde_json::{Map, Value};
+
+/// Magic bytes at the start of every object container file
+pub(crate) const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// Length of the sync marker separating data blocks
+const SYNC_LENGTH: usize = 16;
+
+/// Maximum nesting depth of a decoded value, bounding recursive named types
+const MAX_DEPTH: usize = 64;
+
+/// Avro schema, with named types resolved through [`Schemas`]
+#[derive(Debug, Clone)]
+enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, Schema)>),
+    Enum(Vec<String>),
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Union(Vec<Schema>),
+    Fixed(usize),
+    /// Reference to a named type
+    Named(String),
+}
+
+/// Named types of a schema, by full and short name
+#[derive(Default)]
+struct Schemas {
+    named: HashMap<String, Schema>,
+}
+
+impl Schemas {
+    fn parse(&mut self, schema: &Value, namespace: Option<&str>) -> Result<Schema, String> {
+        match schema {
+            Value::String(name) => self.primitive_or_named(name, namespace),
+            Value::Array(branches) => Ok(Schema::Union(
+                branches
+                    .iter()
+                    .map(|branch| self.parse(branch, namespace))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Value::Object(object) => {
+                let kind = object
+                    .get("type")
+                    .ok_or_else(|| "Avro schema object without type".to_string())?;
+                let Some(kind) = kind.as_str() else {
+                    return self.parse(kind, namespace);
+                };
+                let name = object.get("name").and_then(Value::as_str);
+                let namespace = object
+                    .get("namespace")
+                    .and_then(Value::as_str)
+                    .or(namespace);
+                let parsed = match kind {
+                    "record" | "error" => {
+                        // Register the name first so recursive fields resolve
+                        if let Some(name) = name {
+                            self.define(name, namespace, Schema::Record(Vec::new()));
+                        }
+                        let fields = object
+                            .get("fields")
+                            .and_then(Value::as_array)
+                            .ok_or_else(|| "Avro record without fields".to_string())?;
+                        Schema::Record(
+                            fields
+                                .iter()
+                                .map(|field| {
+                                    let field_name = field
+                                        .get("name")
+                                        .and_then(Value::as_str)
+                                        .ok_or_else(|| "Avro field without name".to_string())?;
+                                    let field_type = field.get("type").ok_or_else(|| {
+                                        format!("Avro field {} without type", field_name)
+                                    })?;
+                                    Ok((field_name.to_string(), self.parse(field_type, namespace)?))
+                                })
+                                .collect::<Result<_, String>>()?,
+                        )
+                    }
+                    "enum" => Schema::Enum(
+                        object
+                            .get("symbols")
+                            .and_then(Value::as_array)
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|symbol| symbol.as_str().map(str::to_string))
+                            .collect(),
+                    ),
+                    "array" => Schema::Array(Box::new(
+                        self.parse(object.get("items").unwrap_or(&Value::Null), namespace)?,
+                    )),
+                    "map" => Schema::Map(Box::new(
+                        self.parse(object.get("values").unwrap_or(&Value::Null), namespace)?,
+                    )),
+                    "fixed" => Schema::Fixed(
+                        object.get("size").and_then(Value::as_u64).unwrap_or(0) as usize,
+                    ),
+                    other => return self.primitive_or_named(other, namespace),
+                };
+                match name {
+                    Some(name) if matches!(kind, "record" | "error" | "enum" | "fixed") => {
+                        Ok(self.define(name, namespace, parsed))
+                    }
+                    _ => Ok(parsed),
+                }
+            }
+            other => Err(format!("Invalid Avro schema: {}", other)),
+        }
+    }
+
+    fn primitive_or_named(&self, name: &str, namespace: Option<&str>) -> Result<Schema, String> {
+        Ok(match name {
+            "null" => Schema::Null,
+            "boolean" => Schema::Boolean,
+            "int" => Schema::Int,
+            "long" => Schema::Long,
+            "float" => Schema::Float,
+            "double" => Schema::Double,
+            "bytes" => Schema::Bytes,
+            "string" => Schema::String,
+            name => {
+                let full = full_name(name, namespace);
+                if self.named.contains_key(&full) {
+                    Schema::Named(full)
+                } else if self.named.contains_key(name) {
+                    Schema::Named(name.to_string())
+                } else {
+                    return Err(format!("Unknown Avro type: {}", name));
+                }
+            }
+        })
+    }
+
+    /// Register a named type under its full and short name
+    fn define(&mut self, name: &str, namespace: Option<&str>, schema: Schema) -> Schema {
+        let full = full_name(name, namespace);
+        let short = name.rsplit('.').next().unwrap_or(name).to_string();
+        self.named.insert(short, schema.clone());
+        self.named.insert(full.clone(), schema);
+        Schema::Named(full)
+    }
+
+    fn resolve<'a>(&'a self, schema: &'a Schema) -> Result<&'a Schema, String> {
+        match schema {
+            Schema::Named(name) => self
+                .named
+                .get(name)
+                .ok_or_else(|| format!("Unknown Avro type: {}", name)),
+            schema => Ok(schema),
+        }
+    }
+}
+
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') && !namespace.is_empty() => {
+            format!("{}.{}", namespace, name)
+        }
+        _ => name.to_string(),
+    }
+}
+
+/// Cursor over Avro binary data
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| "Unexpected end of Avro data".to_string())?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Zig-zag encoded variable-length integer
+    fn long(&mut self) -> Result<i64, String> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err("Invalid Avro variable-length integer".to_string())
+    }
+
+    fn length(&mut self) -> Result<usize, String> {
+        usize::try_from(self.long()?).map_err(|_| "Negative Avro length".to_string())
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], String> {
+        let len = self.length()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|e| format!("Invalid Avro string: {}", e))
+    }
+
+    /// Item count of the next array or map block, skipping the block size
+    ///
+    /// Every item takes at least one byte, so a count beyond the remaining
+    /// data is rejected.
+    fn block_count(&mut self) -> Result<usize, String> {
+        let count = self.long()?;
+        if count < 0 {
+            self.long()?;
+        }
+        let count = usize::try_from(count.unsigned_abs())
+            .map_err(|_| "Invalid Avro block count".to_string())?;
+        if count > self.remaining() {
+            return Err(format!(
+                "Avro block count {} exceeds the remaining data",
+                count
+            ));
+        }
+        Ok(count)
+    }
+
+    fn value(&mut self, schema: &Schema, schemas: &Schemas, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("Avro value nested deeper than {} levels", MAX_DEPTH));
+        }
+        let depth = depth + 1;
+        Ok(match schemas.resolve(schema)? {
+            Schema::Null => Value::Null,
+            Schema::Boolean => Value::Bool(self.take(1)?[0] != 0),
+            Schema::Int | Schema::Long => Value::from(self.long()?),
+            Schema::Float => {
+                let bytes: [u8; 4] = self.take(4)?.try_into().unwrap_or_default();
+                number(f64::from(f32::from_le_bytes(bytes)))
+            }
+            Schema::Double => {
+                let bytes: [u8; 8] = self.take(8)?.try_into().unwrap_or_default();
+                number(f64::from_le_bytes(bytes))
+            }
+            Schema::Bytes => byte_string(self.bytes()?),
+            Schema::String => Value::String(self.string()?),
+            Schema::Fixed(size) => byte_string(self.take(*size)?),
+            Schema::Enum(symbols) => {
+                let index = self.length()?;
+                Value::String(
+                    symbols
+                        .get(index)
+                        .cloned()
+                        .ok_or_else(|| format!("Avro enum index {} out of range", index))?,
+                )
+            }
+            Schema::Record(fields) => {
+                let mut record = Map::new();
+                for (name, field) in fields {
+                    record.insert(name.clone(), self.value(field, schemas, depth)?);
+                }
+                Value::Object(record)
+            }
+            Schema::Array(items) => {
+                let mut values = Vec::new();
+                loop {
+                    let count = self.block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        // Items taking no data, such as nulls, would let a
+                        // small file claim any number of them
+                        let start = self.pos;
+                        values.push(self.value(items, schemas, depth)?);
+                        if self.pos == start {
+                            return Err("Avro array item takes no data".to_string());
+                        }
+                    }
+                }
+                Value::Array(values)
+            }
+            Schema::Map(values) => {
+                let mut map = Map::new();
+                loop {
+                    let count = self.block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        let key = self.string()?;
+                        map.insert(key, self.value(values, schemas, depth)?);
+                    }
+                }
+                Value::Object(map)
+            }
+            Schema::Union(branches) => {
+                let index = self.length()?;
+                let branch = branches
+                    .get(index)
+                    .ok_or_else(|| format!("Avro union index {} out of range", index))?;
+                self.value(branch, schemas, depth)?
+            }
+            Schema::Named(name) => return Err(format!("Unresolved Avro type: {}", name)),
+        })
+    }
+}
+
+fn number(value: f64) -> Value {
+    serde_json::Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn byte_string(bytes: &[u8]) -> Value {
+    Value::String(bytes.iter().map(|b| char::from(*b)).collect())
+}
+
+/// Decode all records of an object container file
+pub(crate) fn read_records(content: &[u8]) -> Result<Vec<Value>, String> {
+    let mut decoder = Decoder::new(content);
+    if decoder.take(MAGIC.len())? != MAGIC {
+        return Err("Not an Avro object container file".to_string());
+    }
+
+    // Header metadata is a map of bytes
+    let mut metadata = HashMap::new();
+    loop {
+        let count = decoder.block_count()?;
+        if count == 0 {
+            break;
+        }
+        for _ in 0..count {
+            let key = decoder.string()?;
+            metadata.insert(key, decoder.bytes()?);
+        }
+    }
+    let sync = decoder.take(SYNC_LENGTH)?;
+
+    let schema_json: Value = metadata
+        .get("avro.schema")
+        .ok_or_else(|| "Avro file header has no schema".to_string())
+        .and_then(|schema| {
+            serde_json::from_slice(schema).map_err(|e| format!("Invalid Avro schema: {}", e))
+        })?;
+    let mut schemas = Schemas::default();
+    let schema = schemas.parse(&schema_json, None)?;
+    let codec = metadata
+        .get("avro.codec")
+        .map(|codec| String::from_utf8_lossy(codec).to_string())
+        .unwrap_or_else(|| "null".to_string());
+
+    let mut records = Vec::new();
+    while !decoder.is_empty() {
+        let count = decoder.length()?;
+        let block = decoder.bytes()?;
+        let data = match codec.as_str() {
+            "null" => block.to_vec(),
+            "deflate" => {
+                let mut inflated = Vec::new();
+                flate2::read::DeflateDecoder::new(block)
+                    .read_to_end(&mut inflated)
+                    .map_err(|e| format!("Invalid deflate block: {}", e))?;
+                inflated
+            }
+            other => return Err(format!("Unsupported Avro codec: {}", other)),
+        };
+        if count > data.len() {
+            return Err(format!(
+                "Avro block count {} exceeds the block size",
+                count
+            ));
+        }
+        let mut block_decoder = Decoder::new(&data);
+        for _ in 0..count {
+            records.push(block_decoder.value(&schema, &schemas, 0)?);
+        }
+        if decoder.take(SYNC_LENGTH)? != sync {
+            return Err("Avro sync marker mismatch".to_string());
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn long(value: i64, out: &mut Vec<u8>) {
+        let mut n = ((value << 1) ^ (value >> 63)) as u64;
+        loop {
+            let byte = (n & 0x7f) as u8;
+            n >>= 7;
+            if n == 0 {
+                out.push(byte);
+                return;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    fn bytes(value: &[u8], out: &mut Vec<u8>) {
+        long(value.len() as i64, out);
+        out.extend_from_slice(value);
+    }
+
+    /// Build an object container file with the `null` codec
+    pub(crate) fn container(schema: &str, count: i64, block: &[u8]) -> Vec<u8> {
+        let sync = [7u8; SYNC_LENGTH];
+        let mut out = MAGIC.to_vec();
+        long(1, &mut out);
+        bytes(b"avro.schema", &mut out);
+        bytes(schema.as_bytes(), &mut out);
+        long(0, &mut out);
+        out.extend_from_slice(&sync);
+        long(count, &mut out);
+        bytes(block, &mut out);
+        out.extend_from_slice(&sync);
+        out
+    }
+
+    /// Encode `{"id": id, "tags": [tag], "kind": kind, "note": note}`
+    pub(crate) fn encode_event(
+        id: i64,
+        tag: &str,
+        kind: i64,
+        note: Option<&str>,
+        out: &mut Vec<u8>,
+    ) {
+        long(id, out);
+        long(1, out);
+        bytes(tag.as_bytes(), out);
+        long(0, out);
+        long(kind, out);
+        match note {
+            Some(note) => {
+                long(1, out);
+                bytes(note.as_bytes(), out);
+            }
+            None => long(0, out),
+        }
+    }
+
+    pub(crate) const EVENT_SCHEMA: &str = r#"{
+        "type": "record", "name": "Event", "namespace": "com.example",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "kind", "type": {"type": "enum", "name": "Kind", "symbols": ["CLICK", "VIEW"]}},
+            {"name": "note", "type": ["null", "string"]}
+        ]
+    }"#;
+
+    #[test]
+    fn test_read_records() {
+        let mut block = Vec::new();
+        encode_event(1, "a", 1, Some("hello"), &mut block);
+        encode_event(-2, "b", 0, None, &mut block);
+
+        let records = read_records(&container(EVENT_SCHEMA, 2, &block)).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                serde_json::json!({"id": 1, "tags": ["a"], "kind": "VIEW", "note": "hello"}),
+                serde_json::json!({"id": -2, "tags": ["b"], "kind": "CLICK", "note": null}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_corrupt_files() {
+        assert!(read_records(b"{\"id\": 1}").is_err());
+        let mut file = container(EVENT_SCHEMA, 1, &[2]);
+        file.truncate(file.len() - 1);
+        assert!(read_records(&file).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unbounded_counts_and_nesting() {
+        // A block count far beyond the data
+        let nulls = r#"{"type": "array", "items": "null"}"#;
+        let mut block = Vec::new();
+        long(1 << 40, &mut block);
+        let error = read_records(&container(nulls, 1, &block)).unwrap_err();
+        assert!(error.contains("exceeds the remaining data"), "{}", error);
+
+        // Items that take no data
+        let mut block = Vec::new();
+        long(3, &mut block);
+        block.extend_from_slice(&[0; 3]);
+        let error = read_records(&container(nulls, 1, &block)).unwrap_err();
+        assert!(error.contains("takes no data"), "{}", error);
+
+        // A recursive type nested beyond the depth limit
+        let list = r#"{"type": "record", "name": "Node", "fields": [
+            {"name": "next", "type": ["null", "Node"]}
+        ]}"#;
+        let mut block = vec![2; MAX_DEPTH * 2];
+        block.push(0);
+        let error = read_records(&container(list, 1, &block)).unwrap_err();
+        assert!(error.contains("nested deeper"), "{}", error);
+    }
+}
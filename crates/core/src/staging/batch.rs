@@ -66,8 +66,11 @@ pub struct ProcessingBatch {
     pub files_skipped: i32,
     /// Total records ingested
     pub records_ingested: i64,
-    /// Total bytes processed
+    /// Total bytes processed, as read from the source (compressed)
     pub bytes_processed: i64,
+    /// Total bytes processed after decompression
+    #[serde(default)]
+    pub bytes_decompressed: i64,
     /// Number of errors encountered
     pub errors_count: i32,
     /// Last file path processed (for resume)
@@ -105,6 +108,7 @@ impl ProcessingBatch {
             files_skipped: 0,
             records_ingested: 0,
             bytes_processed: 0,
+            bytes_decompressed: 0,
             errors_count: 0,
             last_file_path: None,
             last_record_index: None,
@@ -0,0 +1,675 @@
+//! Throughput benchmarks for staging backends
+//!
+//! [`StagingBench`] writes a synthetic JSON Lines workload, ingests it once per
+//! combination of batch size and deduplication strategy, and times a fixed set of
+//! analytical queries over the staged records. [`BenchReport`] compares the runs
+//! and derives recommendations for picking a backend and its settings.
+//!
+//! Every run writes its own copy of the workload with distinct content, so runs
+//! against a shared PostgreSQL database do not deduplicate each other's files.
+//! Each copy includes duplicate files (see [`StagingBench::with_duplicate_ratio`])
+//! so content deduplication has work to do.
+
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::config::DedupStrategy;
+use super::sampling::field_expression;
+use super::schema::SqlDialect;
+
+/// Default number of synthetic records per run
+pub const DEFAULT_BENCH_RECORDS: usize = 10_000;
+
+/// Default number of workload files per run
+pub const DEFAULT_BENCH_FILES: usize = 10;
+
+/// Default batch sizes compared
+pub const DEFAULT_BENCH_BATCH_SIZES: [usize; 3] = [500, 1000, 5000];
+
+/// Partition prefix of benchmark records
+pub const BENCH_PARTITION_PREFIX: &str = "odm-bench";
+
+const EVENT_TYPES: [&str; 4] = ["order_created", "order_paid", "order_shipped", "refund"];
+const TIERS: [&str; 3] = ["bronze", "silver", "gold"];
+
+/// A staging backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BenchBackend {
+    /// Embedded DuckDB
+    DuckDb,
+    /// PostgreSQL
+    Postgres,
+}
+
+impl fmt::Display for BenchBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BenchBackend::DuckDb => "DuckDB",
+            BenchBackend::Postgres => "PostgreSQL",
+        })
+    }
+}
+
+/// Size of the synthetic workload of one run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workload {
+    /// Distinct records
+    pub records: usize,
+    /// Files, including duplicates
+    pub files: usize,
+    /// Files duplicating another file's content
+    pub duplicate_files: usize,
+    /// Total size of the files
+    pub bytes: u64,
+}
+
+/// Measurements of one benchmark run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchResult {
+    /// Backend
+    pub backend: BenchBackend,
+    /// Insert batch size
+    pub batch_size: usize,
+    /// File deduplication strategy
+    pub dedup: DedupStrategy,
+    /// Records staged
+    pub records_ingested: usize,
+    /// Files skipped as duplicates
+    pub files_skipped: usize,
+    /// Wall-clock ingest time
+    pub ingest_seconds: f64,
+    /// Queries executed
+    pub queries_run: usize,
+    /// Wall-clock time of all queries
+    pub query_seconds: f64,
+}
+
+impl BenchResult {
+    /// Records staged per second
+    pub fn ingest_rate(&self) -> f64 {
+        rate(self.records_ingested, self.ingest_seconds)
+    }
+
+    /// Queries executed per second
+    pub fn query_rate(&self) -> f64 {
+        rate(self.queries_run, self.query_seconds)
+    }
+}
+
+/// Results of a benchmark with recommendations
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchReport {
+    /// Workload of each run
+    pub workload: Workload,
+    /// One result per backend, batch size and deduplication strategy
+    pub results: Vec<BenchResult>,
+    /// Recommendations derived from the results
+    pub recommendations: Vec<String>,
+}
+
+impl BenchReport {
+    /// Build a report, deriving the recommendations from the results
+    pub fn new(workload: Workload, results: Vec<BenchResult>) -> Self {
+        let recommendations = recommend(&results);
+        Self {
+            workload,
+            results,
+            recommendations,
+        }
+    }
+
+    /// Fastest ingest run of a backend
+    pub fn best_ingest(&self, backend: BenchBackend) -> Option<&BenchResult> {
+        self.results
+            .iter()
+            .filter(|r| r.backend == backend)
+            .max_by(|a, b| a.ingest_rate().total_cmp(&b.ingest_rate()))
+    }
+
+    /// Render the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Staging Benchmark\n\n");
+        md.push_str(&format!(
+            "Workload per run: {} records in {} files ({} duplicates), {:.1} MB.\n\n",
+            self.workload.records,
+            self.workload.files,
+            self.workload.duplicate_files,
+            self.workload.bytes as f64 / 1_000_000.0
+        ));
+        md.push_str("| Backend | Batch size | Dedup | Records | Skipped files | Ingest (records/s) | Queries/s |\n");
+        md.push_str("|---------|------------|-------|---------|---------------|--------------------|-----------|\n");
+        for result in &self.results {
+            md.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {:.0} | {:.1} |\n",
+                result.backend,
+                result.batch_size,
+                dedup_name(result.dedup),
+                result.records_ingested,
+                result.files_skipped,
+                result.ingest_rate(),
+                result.query_rate()
+            ));
+        }
+        if !self.recommendations.is_empty() {
+            md.push_str("\n## Recommendations\n\n");
+            for recommendation in &self.recommendations {
+                md.push_str(&format!("- {}\n", recommendation));
+            }
+        }
+        md
+    }
+}
+
+/// Benchmark settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct StagingBench {
+    records: usize,
+    files: usize,
+    duplicate_ratio: f64,
+    batch_sizes: Vec<usize>,
+    dedup_strategies: Vec<DedupStrategy>,
+    query_rounds: usize,
+}
+
+impl Default for StagingBench {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StagingBench {
+    /// Create a benchmark with the default workload and settings
+    pub fn new() -> Self {
+        Self {
+            records: DEFAULT_BENCH_RECORDS,
+            files: DEFAULT_BENCH_FILES,
+            duplicate_ratio: 0.1,
+            batch_sizes: DEFAULT_BENCH_BATCH_SIZES.to_vec(),
+            dedup_strategies: vec![DedupStrategy::None, DedupStrategy::ByContent],
+            query_rounds: 5,
+        }
+    }
+
+    /// Set the number of distinct records per run
+    pub fn with_records(mut self, records: usize) -> Self {
+        self.records = records.max(1);
+        self
+    }
+
+    /// Set the number of workload files per run
+    pub fn with_files(mut self, files: usize) -> Self {
+        self.files = files.max(1);
+        self
+    }
+
+    /// Set the share of extra files duplicating another file (0.0 to 1.0)
+    pub fn with_duplicate_ratio(mut self, ratio: f64) -> Self {
+        self.duplicate_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the batch sizes to compare
+    pub fn with_batch_sizes(mut self, batch_sizes: Vec<usize>) -> Self {
+        self.batch_sizes = batch_sizes.into_iter().filter(|size| *size > 0).collect();
+        self
+    }
+
+    /// Set the deduplication strategies to compare
+    pub fn with_dedup_strategies(mut self, strategies: Vec<DedupStrategy>) -> Self {
+        self.dedup_strategies = strategies;
+        self
+    }
+
+    /// Set how often each benchmark query runs
+    pub fn with_query_rounds(mut self, rounds: usize) -> Self {
+        self.query_rounds = rounds.max(1);
+        self
+    }
+
+    /// Batch size and deduplication strategy of every run
+    pub fn runs(&self) -> Vec<(usize, DedupStrategy)> {
+        self.batch_sizes
+            .iter()
+            .flat_map(|size| {
+                self.dedup_strategies
+                    .iter()
+                    .map(move |dedup| (*size, *dedup))
+            })
+            .collect()
+    }
+
+    /// Write the workload of run `run` into `dir`
+    ///
+    /// Records carry the run number, so the files of different runs never share
+    /// content.
+    pub fn write_workload(&self, dir: &Path, run: usize) -> std::io::Result<Workload> {
+        std::fs::create_dir_all(dir)?;
+        let per_file = self.records.div_ceil(self.files);
+        let mut workload = Workload {
+            records: self.records,
+            ..Default::default()
+        };
+        let mut written = Vec::new();
+        for file in 0..self.files {
+            let start = file * per_file;
+            let end = ((file + 1) * per_file).min(self.records);
+            let content: String = (start..end)
+                .map(|n| synthetic_record(n, run).to_string() + "\n")
+                .collect();
+            let path = dir.join(format!("events_{:04}.jsonl", file));
+            std::fs::write(&path, &content)?;
+            workload.files += 1;
+            workload.bytes += content.len() as u64;
+            written.push(content);
+        }
+
+        let duplicates = (self.files as f64 * self.duplicate_ratio).round() as usize;
+        for (i, content) in written.iter().cycle().take(duplicates).enumerate() {
+            std::fs::write(dir.join(format!("redelivered_{:04}.jsonl", i)), content)?;
+            workload.files += 1;
+            workload.duplicate_files += 1;
+            workload.bytes += content.len() as u64;
+        }
+        Ok(workload)
+    }
+
+    /// Benchmark DuckDB, each run in a fresh in-memory database
+    ///
+    /// # Arguments
+    /// * `work_dir` - Directory for the workload files, one subdirectory per run
+    #[cfg(feature = "duckdb-backend")]
+    pub fn run_duckdb(
+        &self,
+        work_dir: &Path,
+    ) -> Result<(Workload, Vec<BenchResult>), super::IngestError> {
+        use std::time::Instant;
+
+        let mut workload = Workload::default();
+        let mut results = Vec::new();
+        for (run, (batch_size, dedup)) in self.runs().into_iter().enumerate() {
+            let dir = run_dir(work_dir, BenchBackend::DuckDb, run);
+            workload = self.write_workload(&dir, run)?;
+
+            let db = super::StagingDb::memory()?;
+            db.init()?;
+            let partition = bench_partition(run);
+            let config = self.ingest_config(&dir, &partition, batch_size, dedup)?;
+            let start = Instant::now();
+            let stats = db.ingest(&config)?;
+            let ingest = start.elapsed();
+
+            let queries = bench_queries(SqlDialect::DuckDb, &partition);
+            let start = Instant::now();
+            for _ in 0..self.query_rounds {
+                for sql in &queries {
+                    db.query(sql)?;
+                }
+            }
+            results.push(BenchResult {
+                backend: BenchBackend::DuckDb,
+                batch_size,
+                dedup,
+                records_ingested: stats.records_ingested,
+                files_skipped: stats.files_skipped,
+                ingest_seconds: ingest.as_secs_f64(),
+                queries_run: queries.len() * self.query_rounds,
+                query_seconds: start.elapsed().as_secs_f64(),
+            });
+        }
+        Ok((workload, results))
+    }
+
+    /// Benchmark a PostgreSQL staging database
+    ///
+    /// Runs stage their records in partitions prefixed with
+    /// [`BENCH_PARTITION_PREFIX`]; use a scratch database, as the records stay.
+    #[cfg(feature = "postgres-backend")]
+    pub async fn run_postgres(
+        &self,
+        db: &super::StagingDbPostgres,
+        work_dir: &Path,
+    ) -> Result<(Workload, Vec<BenchResult>), super::IngestError> {
+        use std::time::Instant;
+
+        let mut workload = Workload::default();
+        let mut results = Vec::new();
+        let offset = uuid::Uuid::new_v4().as_u128() as usize % 1_000_000;
+        for (run, (batch_size, dedup)) in self.runs().into_iter().enumerate() {
+            // Distinct content from earlier benchmarks against the same database
+            let run = offset + run;
+            let dir = run_dir(work_dir, BenchBackend::Postgres, run);
+            workload = self.write_workload(&dir, run)?;
+
+            let partition = bench_partition(run);
+            let config = self.ingest_config(&dir, &partition, batch_size, dedup)?;
+            let start = Instant::now();
+            let stats = db.ingest(&config).await?;
+            let ingest = start.elapsed();
+
+            let queries = bench_queries(SqlDialect::Postgres, &partition);
+            let start = Instant::now();
+            for _ in 0..self.query_rounds {
+                for sql in &queries {
+                    db.query(sql).await?;
+                }
+            }
+            results.push(BenchResult {
+                backend: BenchBackend::Postgres,
+                batch_size,
+                dedup,
+                records_ingested: stats.records_ingested,
+                files_skipped: stats.files_skipped,
+                ingest_seconds: ingest.as_secs_f64(),
+                queries_run: queries.len() * self.query_rounds,
+                query_seconds: start.elapsed().as_secs_f64(),
+            });
+        }
+        Ok((workload, results))
+    }
+
+    #[cfg(any(feature = "duckdb-backend", feature = "postgres-backend"))]
+    fn ingest_config(
+        &self,
+        dir: &Path,
+        partition: &str,
+        batch_size: usize,
+        dedup: DedupStrategy,
+    ) -> Result<super::IngestConfig, super::IngestError> {
+        super::IngestConfig::builder()
+            .source_type(super::SourceType::Local(dir.to_path_buf()))
+            .pattern("*.jsonl")
+            .partition(partition)
+            .batch_size(batch_size)
+            .dedup(dedup)
+            .build()
+            .map_err(|e| super::StagingError::InvalidConfig(e).into())
+    }
+}
+
+/// A synthetic order event
+fn synthetic_record(n: usize, run: usize) -> serde_json::Value {
+    json!({
+        "id": format!("evt-{}-{}", run, n),
+        "run": run,
+        "event_type": EVENT_TYPES[n % EVENT_TYPES.len()],
+        "customer": {
+            "id": format!("c-{}", n % 997),
+            "tier": TIERS[n % TIERS.len()],
+        },
+        "amount": (n * 37 % 100_000) as f64 / 100.0,
+        "created_at": format!("2026-01-{:02}T{:02}:{:02}:00Z", n % 28 + 1, n % 24, n % 60),
+        "tags": [format!("region-{}", n % 5)],
+    })
+}
+
+/// The analytical queries timed by the benchmark
+pub fn bench_queries(dialect: SqlDialect, partition: &str) -> Vec<String> {
+    let filter = format!("partition_key = '{}'", partition.replace('\'', "''"));
+    let event_type = field_expression("event_type", dialect);
+    let customer = field_expression("customer.id", dialect);
+    let amount = field_expression("amount", dialect);
+    vec![
+        format!("SELECT COUNT(*) AS n FROM staged_json WHERE {}", filter),
+        format!(
+            "SELECT {} AS event_type, COUNT(*) AS n FROM staged_json WHERE {} GROUP BY 1 ORDER BY 2 DESC",
+            event_type, filter
+        ),
+        format!(
+            "SELECT COUNT(DISTINCT {}) AS customers FROM staged_json WHERE {}",
+            customer, filter
+        ),
+        format!(
+            "SELECT AVG(CAST({} AS DOUBLE PRECISION)) AS amount FROM staged_json WHERE {} AND {} IS NOT NULL",
+            amount, filter, amount
+        ),
+    ]
+}
+
+/// Recommendations for a set of results
+pub fn recommend(results: &[BenchResult]) -> Vec<String> {
+    let mut recommendations = Vec::new();
+    let best = |backend: BenchBackend| {
+        results
+            .iter()
+            .filter(|r| r.backend == backend)
+            .max_by(|a, b| a.ingest_rate().total_cmp(&b.ingest_rate()))
+    };
+    let backends: Vec<&BenchResult> = [BenchBackend::DuckDb, BenchBackend::Postgres]
+        .into_iter()
+        .filter_map(best)
+        .collect();
+
+    for result in &backends {
+        recommendations.push(format!(
+            "{}: batch size {} ingested fastest ({:.0} records/s); at that rate 10 million records take about {}.",
+            result.backend,
+            result.batch_size,
+            result.ingest_rate(),
+            duration_text(10_000_000.0 / result.ingest_rate().max(f64::MIN_POSITIVE))
+        ));
+    }
+
+    if let [a, b] = backends.as_slice() {
+        let (fast, slow) = if a.ingest_rate() >= b.ingest_rate() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let query_fast = if a.query_rate() >= b.query_rate() {
+            a
+        } else {
+            b
+        };
+        recommendations.push(format!(
+            "{} ingested {:.1}x faster than {}, and {} answered queries fastest. Prefer DuckDB for single-process pipelines; prefer PostgreSQL when several writers or services share the staging area.",
+            fast.backend,
+            fast.ingest_rate() / slow.ingest_rate().max(f64::MIN_POSITIVE),
+            slow.backend,
+            query_fast.backend
+        ));
+    }
+
+    for backend in [BenchBackend::DuckDb, BenchBackend::Postgres] {
+        // Batch sizes are compared without deduplication where possible
+        let compared: Vec<&BenchResult> = results
+            .iter()
+            .filter(|r| r.backend == backend && r.dedup == DedupStrategy::None)
+            .collect();
+        let compared = if compared.is_empty() {
+            results.iter().filter(|r| r.backend == backend).collect()
+        } else {
+            compared
+        };
+        let rates: Vec<f64> = compared.iter().map(|r| r.ingest_rate()).collect();
+        let (Some(min), Some(max)) = (
+            rates.iter().copied().reduce(f64::min),
+            rates.iter().copied().reduce(f64::max),
+        ) else {
+            continue;
+        };
+        let batch_sizes = compared
+            .iter()
+            .map(|r| r.batch_size)
+            .collect::<std::collections::BTreeSet<_>>();
+        if batch_sizes.len() > 1 && max > 0.0 && (max - min) / max < 0.1 {
+            recommendations.push(format!(
+                "{}: batch size has little effect at this volume (within 10%); keep the default of 1000.",
+                backend
+            ));
+        }
+
+        for dedup in [
+            DedupStrategy::ByPath,
+            DedupStrategy::ByContent,
+            DedupStrategy::Both,
+        ] {
+            let overhead: Vec<f64> = results
+                .iter()
+                .filter(|r| r.backend == backend && r.dedup == dedup)
+                .filter_map(|r| {
+                    let baseline = results.iter().find(|b| {
+                        b.backend == backend
+                            && b.batch_size == r.batch_size
+                            && b.dedup == DedupStrategy::None
+                    })?;
+                    Some(r.ingest_seconds / baseline.ingest_seconds.max(f64::MIN_POSITIVE) - 1.0)
+                })
+                .collect();
+            if overhead.is_empty() {
+                continue;
+            }
+            let mean = overhead.iter().sum::<f64>() / overhead.len() as f64;
+            recommendations.push(if mean > 0.05 {
+                format!(
+                    "{}: {} deduplication added {:.0}% to ingest time; use it only where files are re-delivered.",
+                    backend,
+                    dedup_name(dedup),
+                    mean * 100.0
+                )
+            } else {
+                format!(
+                    "{}: {} deduplication cost no measurable ingest time; keep it enabled to skip re-delivered files.",
+                    backend,
+                    dedup_name(dedup)
+                )
+            });
+        }
+    }
+    recommendations
+}
+
+/// Name of a deduplication strategy as accepted on the command line
+pub fn dedup_name(dedup: DedupStrategy) -> &'static str {
+    match dedup {
+        DedupStrategy::None => "none",
+        DedupStrategy::ByPath => "path",
+        DedupStrategy::ByContent => "content",
+        DedupStrategy::Both => "both",
+    }
+}
+
+#[cfg(any(feature = "duckdb-backend", feature = "postgres-backend"))]
+fn run_dir(work_dir: &Path, backend: BenchBackend, run: usize) -> std::path::PathBuf {
+    work_dir.join(format!("{:?}-{}", backend, run).to_lowercase())
+}
+
+#[cfg(any(feature = "duckdb-backend", feature = "postgres-backend"))]
+fn bench_partition(run: usize) -> String {
+    format!("{}-{}", BENCH_PARTITION_PREFIX, run)
+}
+
+fn rate(count: usize, seconds: f64) -> f64 {
+    if seconds <= 0.0 {
+        0.0
+    } else {
+        count as f64 / seconds
+    }
+}
+
+fn duration_text(seconds: f64) -> String {
+    let duration = Duration::from_secs_f64(seconds.clamp(0.0, 1e9));
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h {}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(
+        backend: BenchBackend,
+        batch_size: usize,
+        dedup: DedupStrategy,
+        ingest_seconds: f64,
+    ) -> BenchResult {
+        BenchResult {
+            backend,
+            batch_size,
+            dedup,
+            records_ingested: 10_000,
+            files_skipped: 0,
+            ingest_seconds,
+            queries_run: 20,
+            query_seconds: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_write_workload() {
+        let dir = tempfile::tempdir().unwrap();
+        let bench = StagingBench::new()
+            .with_records(25)
+            .with_files(4)
+            .with_duplicate_ratio(0.5);
+        let workload = bench.write_workload(dir.path(), 3).unwrap();
+        assert_eq!(
+            (workload.records, workload.files, workload.duplicate_files),
+            (25, 6, 2)
+        );
+
+        let first = std::fs::read_to_string(dir.path().join("events_0000.jsonl")).unwrap();
+        let duplicate = std::fs::read_to_string(dir.path().join("redelivered_0000.jsonl")).unwrap();
+        assert_eq!(first, duplicate);
+        let lines: Vec<serde_json::Value> = first
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0]["id"], "evt-3-0");
+        assert_eq!(lines[0]["customer"]["tier"], "bronze");
+        assert_eq!(bench.runs().len(), 6);
+    }
+
+    #[test]
+    fn test_recommendations() {
+        let report = BenchReport::new(
+            Workload::default(),
+            vec![
+                result(BenchBackend::DuckDb, 1000, DedupStrategy::None, 1.0),
+                result(BenchBackend::DuckDb, 1000, DedupStrategy::ByContent, 1.5),
+                result(BenchBackend::DuckDb, 5000, DedupStrategy::None, 0.98),
+                result(BenchBackend::Postgres, 1000, DedupStrategy::None, 4.0),
+            ],
+        );
+        assert_eq!(
+            report.best_ingest(BenchBackend::DuckDb).unwrap().batch_size,
+            5000
+        );
+        let text = report.recommendations.join("\n");
+        assert!(text.contains("DuckDB: batch size 5000 ingested fastest (10204 records/s)"));
+        assert!(text.contains("DuckDB ingested 4.1x faster than PostgreSQL"));
+        assert!(text.contains("DuckDB: content deduplication added 50% to ingest time"));
+        // 1000 and 5000 without deduplication are within 10%
+        assert!(text.contains("DuckDB: batch size has little effect"));
+        assert!(!text.contains("PostgreSQL: batch size has little effect"));
+
+        let md = report.to_markdown();
+        assert!(md.contains("| PostgreSQL | 1000 | none | 10000 | 0 | 2500 | 40.0 |"));
+    }
+
+    #[test]
+    fn test_bench_queries() {
+        let queries = bench_queries(SqlDialect::Postgres, "odm-bench-1");
+        assert_eq!(queries.len(), 4);
+        assert!(queries[2].contains("COUNT(DISTINCT (raw_json #>> '{\"customer\",\"id\"}'))"));
+        assert!(
+            queries
+                .iter()
+                .all(|q| q.contains("partition_key = 'odm-bench-1'"))
+        );
+    }
+}
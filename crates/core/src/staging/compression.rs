@@ -0,0 +1,109 @@
+//! Transparent decompression of staged files
+//!
+//! Data drops are usually compressed (`events.json.gz`, `orders.jsonl.gz`,
+//! `clicks.ndjson.zst`). Compression is recognised by the magic bytes at the
+//! start of the file rather than by its extension, so misnamed files are still
+//! decompressed and uncompressed files named `.gz` are read as they are:
+//!
+//! - **gzip** - `1f 8b`; concatenated gzip members are read as one stream
+//! - **zstd** - `28 b5 2f fd`; concatenated frames are read as one stream
+//!
+//! The decompressed content is then sniffed and parsed like any other file.
+
+use std::borrow::Cow;
+use std::io::Read;
+use std::path::Path;
+
+use super::error::IngestError;
+
+/// Leading bytes of a gzip stream
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+
+/// Leading bytes of a zstd frame
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression of a staged file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Not compressed
+    None,
+    /// gzip (`.gz`)
+    Gzip,
+    /// Zstandard (`.zst`)
+    Zstd,
+}
+
+impl Compression {
+    /// Detect the compression of content from its magic bytes
+    pub fn detect(content: &[u8]) -> Self {
+        if content.starts_with(GZIP_MAGIC) {
+            Compression::Gzip
+        } else if content.starts_with(ZSTD_MAGIC) {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+}
+
+/// Decompress file content, returning uncompressed content unchanged
+pub fn decompress<'a>(path: &Path, content: &'a [u8]) -> Result<Cow<'a, [u8]>, IngestError> {
+    let mut decompressed = Vec::new();
+    let result = match Compression::detect(content) {
+        Compression::None => return Ok(Cow::Borrowed(content)),
+        Compression::Gzip => {
+            flate2::read::MultiGzDecoder::new(content).read_to_end(&mut decompressed)
+        }
+        Compression::Zstd => zstd::stream::read::Decoder::new(content)
+            .and_then(|mut decoder| decoder.read_to_end(&mut decompressed)),
+    };
+    result.map_err(|e| IngestError::InvalidFormat {
+        path: path.to_path_buf(),
+        reason: format!("failed to decompress: {}", e),
+    })?;
+    Ok(Cow::Owned(decompressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn gzip(content: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(content).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_decompress() {
+        let path = Path::new("events.jsonl.gz");
+        let content = b"{\"id\":1}\n{\"id\":2}\n";
+
+        let gz = gzip(content);
+        assert_eq!(Compression::detect(&gz), Compression::Gzip);
+        assert_eq!(decompress(path, &gz).unwrap().as_ref(), content);
+
+        let zst = zstd::encode_all(&content[..], 0).unwrap();
+        assert_eq!(Compression::detect(&zst), Compression::Zstd);
+        assert_eq!(decompress(path, &zst).unwrap().as_ref(), content);
+
+        assert!(matches!(
+            decompress(path, content).unwrap(),
+            Cow::Borrowed(_)
+        ));
+        assert!(decompress(path, &gz[..gz.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_concatenated_gzip_members() {
+        let mut gz = gzip(b"{\"id\":1}\n");
+        gz.extend(gzip(b"{\"id\":2}\n"));
+        assert_eq!(
+            decompress(Path::new("events.jsonl.gz"), &gz)
+                .unwrap()
+                .as_ref(),
+            b"{\"id\":1}\n{\"id\":2}\n"
+        );
+    }
+}
@@ -5,6 +5,11 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use super::conformance::RecordValidator;
+use super::error::IngestError;
+use super::formats::{RecordParserRegistry, XmlParser};
+use crate::validation::XmlRecordSchema;
+
 /// Source type for ingestion
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SourceType {
@@ -130,6 +135,70 @@ impl std::str::FromStr for DedupStrategy {
     }
 }
 
+/// Which of a set of duplicate records is kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupKeep {
+    /// Keep the record staged first (default)
+    #[default]
+    First,
+    /// Keep the record staged last, e.g. the latest re-delivery of an event
+    Last,
+}
+
+/// Key identifying duplicate records
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordKey {
+    /// Records with identical JSON content are duplicates
+    ContentHash,
+    /// Records with the same value at a JSONPath (e.g., `$.event_id`) are
+    /// duplicates; records without a value are never deduplicated
+    Path(String),
+}
+
+/// Record-level deduplication for ingestion
+///
+/// Applied after file-level deduplication, within each partition the run
+/// staged records into, so duplicates already staged by earlier runs are
+/// removed as well.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordDedup {
+    /// Key identifying duplicate records
+    pub key: RecordKey,
+    /// Which duplicate to keep
+    #[serde(default)]
+    pub keep: DedupKeep,
+}
+
+impl std::str::FromStr for RecordDedup {
+    type Err = String;
+
+    /// Parse `content` or a JSONPath, optionally followed by `:first` or `:last`
+    /// (e.g., `$.event_id:last`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (key, keep) = match s.rsplit_once(':') {
+            Some((key, keep)) if keep.eq_ignore_ascii_case("first") => (key, DedupKeep::First),
+            Some((key, keep)) if keep.eq_ignore_ascii_case("last") => (key, DedupKeep::Last),
+            _ => (s, DedupKeep::First),
+        };
+
+        let key = if key.eq_ignore_ascii_case("content") || key.eq_ignore_ascii_case("hash") {
+            RecordKey::ContentHash
+        } else if key.starts_with('$') && !key.trim_start_matches(['$', '.']).is_empty() {
+            RecordKey::Path(key.to_string())
+        } else {
+            return Err(format!(
+                "Invalid record dedup key: {}. Expected: content or a JSONPath such as $.id, optionally followed by :first or :last",
+                s
+            ));
+        };
+
+        Ok(RecordDedup { key, keep })
+    }
+}
+
 /// Configuration for data ingestion
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IngestConfig {
@@ -139,7 +208,8 @@ pub struct IngestConfig {
     pub pattern: String,
     /// Partition key for this batch (optional)
     pub partition: Option<String>,
-    /// Number of parallel workers for local file processing
+    /// Number of workers reading, decompressing and parsing files in parallel
+    /// while a single writer stages their records
     pub workers: usize,
     /// Batch size for database inserts
     pub batch_size: usize,
@@ -149,6 +219,20 @@ pub struct IngestConfig {
     pub resume: bool,
     /// Batch ID for resume (auto-generated if not provided)
     pub batch_id: Option<String>,
+    /// Discriminator JSONPath used to route records into sub-partitions
+    /// (e.g., `$.event_type`)
+    #[serde(default)]
+    pub split_by: Option<String>,
+    /// XSD used to conform XML records to the declared structure (optional)
+    #[serde(default)]
+    pub xml_schema: Option<PathBuf>,
+    /// Record-level deduplication (optional)
+    #[serde(default)]
+    pub record_dedup: Option<RecordDedup>,
+    /// ODCS contract or JSON Schema records are validated against (optional);
+    /// records that do not conform go to the dead-letter table
+    #[serde(default)]
+    pub record_schema: Option<PathBuf>,
 }
 
 impl Default for IngestConfig {
@@ -162,6 +246,10 @@ impl Default for IngestConfig {
             dedup: DedupStrategy::ByPath,
             resume: false,
             batch_id: None,
+            split_by: None,
+            xml_schema: None,
+            record_dedup: None,
+            record_schema: None,
         }
     }
 }
@@ -171,6 +259,32 @@ impl IngestConfig {
     pub fn builder() -> IngestConfigBuilder {
         IngestConfigBuilder::default()
     }
+
+    /// Record parsers for this ingestion.
+    ///
+    /// The built-in formats, with XML records conformed to `xml_schema` when one
+    /// is configured.
+    pub fn record_parsers(&self) -> Result<RecordParserRegistry, IngestError> {
+        let mut parsers = RecordParserRegistry::default();
+        if let Some(ref xsd_path) = self.xml_schema {
+            let xsd = std::fs::read_to_string(xsd_path)?;
+            let schema =
+                XmlRecordSchema::from_xsd(&xsd).map_err(|e| IngestError::InvalidFormat {
+                    path: xsd_path.clone(),
+                    reason: format!("{:#}", e),
+                })?;
+            parsers.register(XmlParser::with_schema(schema));
+        }
+        Ok(parsers)
+    }
+
+    /// Validator for `record_schema`, if one is configured
+    pub fn record_validator(&self) -> Result<Option<RecordValidator>, IngestError> {
+        self.record_schema
+            .as_deref()
+            .map(RecordValidator::from_file)
+            .transpose()
+    }
 }
 
 /// Builder for IngestConfig
@@ -184,6 +298,10 @@ pub struct IngestConfigBuilder {
     dedup: Option<DedupStrategy>,
     resume: bool,
     batch_id: Option<String>,
+    split_by: Option<String>,
+    xml_schema: Option<PathBuf>,
+    record_dedup: Option<RecordDedup>,
+    record_schema: Option<PathBuf>,
 }
 
 impl IngestConfigBuilder {
@@ -211,9 +329,9 @@ impl IngestConfigBuilder {
         self
     }
 
-    /// Set the number of parallel workers
+    /// Set the number of parallel workers (at least 1)
     pub fn workers(mut self, workers: usize) -> Self {
-        self.workers = Some(workers);
+        self.workers = Some(workers.max(1));
         self
     }
 
@@ -241,6 +359,30 @@ impl IngestConfigBuilder {
         self
     }
 
+    /// Route records into sub-partitions by a discriminator JSONPath
+    pub fn split_by(mut self, path: &str) -> Self {
+        self.split_by = Some(path.to_string());
+        self
+    }
+
+    /// Conform XML records to the structure declared by an XSD file
+    pub fn xml_schema(mut self, path: impl Into<PathBuf>) -> Self {
+        self.xml_schema = Some(path.into());
+        self
+    }
+
+    /// Deduplicate records by content or by a key JSONPath
+    pub fn record_dedup(mut self, dedup: RecordDedup) -> Self {
+        self.record_dedup = Some(dedup);
+        self
+    }
+
+    /// Validate records against an ODCS contract or JSON Schema file
+    pub fn record_schema(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_schema = Some(path.into());
+        self
+    }
+
     /// Build the IngestConfig
     pub fn build(self) -> Result<IngestConfig, String> {
         let source = self.source.ok_or("Source is required")?;
@@ -254,6 +396,10 @@ impl IngestConfigBuilder {
             dedup: self.dedup.unwrap_or_default(),
             resume: self.resume,
             batch_id: self.batch_id,
+            split_by: self.split_by,
+            xml_schema: self.xml_schema,
+            record_dedup: self.record_dedup,
+            record_schema: self.record_schema,
         })
     }
 }
@@ -291,6 +437,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_record_dedup_from_str() {
+        assert_eq!(
+            "content".parse::<RecordDedup>().unwrap(),
+            RecordDedup {
+                key: RecordKey::ContentHash,
+                keep: DedupKeep::First
+            }
+        );
+        assert_eq!(
+            "$.event_id:last".parse::<RecordDedup>().unwrap(),
+            RecordDedup {
+                key: RecordKey::Path("$.event_id".to_string()),
+                keep: DedupKeep::Last
+            }
+        );
+        assert!("event_id".parse::<RecordDedup>().is_err());
+        assert!("$:first".parse::<RecordDedup>().is_err());
+    }
+
     #[test]
     fn test_ingest_config_builder() {
         let config = IngestConfig::builder()
@@ -300,6 +466,7 @@ mod tests {
             .workers(8)
             .batch_size(500)
             .dedup(DedupStrategy::Both)
+            .split_by("$.event_type")
             .build()
             .unwrap();
 
@@ -308,5 +475,22 @@ mod tests {
         assert_eq!(config.workers, 8);
         assert_eq!(config.batch_size, 500);
         assert_eq!(config.dedup, DedupStrategy::Both);
+        assert_eq!(config.split_by, Some("$.event_type".to_string()));
+    }
+
+    #[test]
+    fn test_ingest_config_workers() {
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(PathBuf::from("./data")))
+            .build()
+            .unwrap();
+        assert_eq!(config.workers, 4);
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(PathBuf::from("./data")))
+            .workers(0)
+            .build()
+            .unwrap();
+        assert_eq!(config.workers, 1);
     }
 }
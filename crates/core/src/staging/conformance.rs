@@ -0,0 +1,179 @@
+//! Ingest-time validation of records against a contract or JSON Schema
+//!
+//! A [`RecordValidator`] checks each record before it is staged. Records that do
+//! not conform are written to the `dead_letter_records` table with their
+//! validation errors instead of `staged_json`, so schema drift shows up as a
+//! falling conformance rate before inference and export run.
+//!
+//! The schema file is either an ODCS contract, whose schema objects are converted
+//! to JSON Schema (a record conforms when it matches any of them), or a JSON
+//! Schema in JSON or YAML. Validation requires the `schema-validation` feature;
+//! without it, creating a validator fails.
+
+use std::path::Path;
+
+#[cfg(feature = "schema-validation")]
+use jsonschema::Validator;
+use serde_json::Value;
+
+use super::error::IngestError;
+use crate::export::JSONSchemaExporter;
+use crate::models::odcs::ODCSContract;
+
+/// Maximum number of validation errors recorded per rejected record
+#[cfg(feature = "schema-validation")]
+const MAX_ERRORS_PER_RECORD: usize = 10;
+
+/// Validates records against a JSON Schema
+pub struct RecordValidator {
+    #[cfg(feature = "schema-validation")]
+    validator: Validator,
+}
+
+impl std::fmt::Debug for RecordValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordValidator").finish_non_exhaustive()
+    }
+}
+
+impl RecordValidator {
+    /// Create a validator from a JSON Schema
+    #[cfg(feature = "schema-validation")]
+    pub fn from_json_schema(schema: &Value) -> Result<Self, String> {
+        let validator =
+            Validator::new(schema).map_err(|e| format!("Invalid JSON Schema: {}", e))?;
+        Ok(Self { validator })
+    }
+
+    /// Create a validator from a JSON Schema
+    #[cfg(not(feature = "schema-validation"))]
+    pub fn from_json_schema(_schema: &Value) -> Result<Self, String> {
+        Err("Record validation requires the schema-validation feature".to_string())
+    }
+
+    /// Create a validator from the schema objects of a contract
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+    /// use data_modelling_core::staging::RecordValidator;
+    ///
+    /// let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+    ///     SchemaObject::new("orders")
+    ///         .with_properties(vec![Property::new("id", "integer").with_required(true)]),
+    /// );
+    /// let validator = RecordValidator::from_contract(&contract).unwrap();
+    /// assert!(validator.validate(r#"{"id": 1}"#).is_ok());
+    /// assert!(validator.validate(r#"{"id": "one"}"#).is_err());
+    /// ```
+    pub fn from_contract(contract: &ODCSContract) -> Result<Self, String> {
+        let mut schemas: Vec<Value> = contract
+            .to_tables()
+            .iter()
+            .map(JSONSchemaExporter::export_table)
+            .collect();
+        let schema = match schemas.len() {
+            0 => return Err(format!("Contract {} has no schema objects", contract.name)),
+            1 => schemas.remove(0),
+            _ => serde_json::json!({ "anyOf": schemas }),
+        };
+        Self::from_json_schema(&schema)
+    }
+
+    /// Load a validator from an ODCS contract or JSON Schema file
+    pub fn from_file(path: &Path) -> Result<Self, IngestError> {
+        let invalid = |reason: String| IngestError::InvalidFormat {
+            path: path.to_path_buf(),
+            reason,
+        };
+
+        let content = std::fs::read_to_string(path)?;
+        let document: Value = serde_yaml::from_str(&content)
+            .map_err(|e| invalid(format!("Failed to parse schema file: {}", e)))?;
+
+        let is_contract = document.get("apiVersion").is_some()
+            && document.get("kind").and_then(Value::as_str) == Some("DataContract");
+        if is_contract {
+            let contract: ODCSContract = serde_json::from_value(document)
+                .map_err(|e| invalid(format!("Failed to parse contract: {}", e)))?;
+            Self::from_contract(&contract).map_err(invalid)
+        } else {
+            Self::from_json_schema(&document).map_err(invalid)
+        }
+    }
+
+    /// Validate a JSON record, returning its validation errors
+    #[cfg(feature = "schema-validation")]
+    pub fn validate(&self, json: &str) -> Result<(), Vec<String>> {
+        let record: Value =
+            serde_json::from_str(json).map_err(|e| vec![format!("Invalid JSON: {}", e)])?;
+        let errors: Vec<String> = self
+            .validator
+            .iter_errors(&record)
+            .take(MAX_ERRORS_PER_RECORD)
+            .map(|error| {
+                let path = error.instance_path().to_string();
+                if path.is_empty() {
+                    error.to_string()
+                } else {
+                    format!("{}: {}", path, error)
+                }
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate a JSON record, returning its validation errors
+    #[cfg(not(feature = "schema-validation"))]
+    pub fn validate(&self, _json: &str) -> Result<(), Vec<String>> {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "schema-validation"))]
+mod tests {
+    use super::*;
+    use crate::models::odcs::{Property, SchemaObject};
+
+    #[test]
+    fn test_validate_against_contract() {
+        let contract = ODCSContract::new("orders", "1.0.0").with_schema(
+            SchemaObject::new("orders").with_properties(vec![
+                Property::new("id", "integer").with_required(true),
+                Property::new("status", "string"),
+            ]),
+        );
+        let validator = RecordValidator::from_contract(&contract).unwrap();
+
+        assert!(
+            validator
+                .validate(r#"{"id": 1, "status": "created"}"#)
+                .is_ok()
+        );
+        let errors = validator.validate(r#"{"id": "one"}"#).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("/id: "));
+        assert!(validator.validate(r#"{"status": "created"}"#).is_err());
+    }
+
+    #[test]
+    fn test_validator_from_json_schema_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("event.schema.json");
+        std::fs::write(
+            &path,
+            r#"{"type": "object", "required": ["event_id"], "properties": {"event_id": {"type": "string"}}}"#,
+        )
+        .unwrap();
+
+        let validator = RecordValidator::from_file(&path).unwrap();
+        assert!(validator.validate(r#"{"event_id": "a"}"#).is_ok());
+        assert!(validator.validate(r#"{"event_id": 1}"#).is_err());
+    }
+}
@@ -5,23 +5,41 @@
 #![allow(clippy::collapsible_if)]
 
 #[cfg(feature = "duckdb-backend")]
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 #[cfg(feature = "duckdb-backend")]
 use std::time::Instant;
 
 #[cfg(feature = "duckdb-backend")]
 use chrono::Utc;
+#[cfg(feature = "duckdb-backend")]
+use rayon::prelude::*;
 
 #[cfg(feature = "duckdb-backend")]
 use super::batch::{BatchStatus, ProcessingBatch};
 #[cfg(feature = "duckdb-backend")]
-use super::config::{DedupStrategy, IngestConfig, SourceType};
+use super::config::{DedupStrategy, IngestConfig, RecordDedup, SourceType};
+#[cfg(feature = "duckdb-backend")]
+use super::dedup::dedup_query;
 #[cfg(feature = "duckdb-backend")]
 use super::error::{IngestError, StagingError};
 #[cfg(feature = "duckdb-backend")]
-use super::ingest::{IngestStats, discover_local_files, parse_file, should_skip_file};
+use super::ingest::{
+    FilePreparer, IngestStats, PreparedFile, SourceReader, SourceRuntime, discover_local_files,
+};
+#[cfg(feature = "duckdb-backend")]
+use super::profile::{ProfileBuilder, ProfileOptions, ProfileReport, record_page_query};
+#[cfg(feature = "duckdb-backend")]
+use super::purge::{PurgePolicy, PurgeStats, purge_statements, vacuum_statement};
+#[cfg(feature = "duckdb-backend")]
+use super::sampling::sample_query;
+#[cfg(feature = "duckdb-backend")]
+use super::schema::{SCHEMA_VERSION, SqlDialect, StagingSchema};
+#[cfg(feature = "duckdb-backend")]
+use crate::inference::SamplingStrategy;
+
+/// Files each ingest worker prepares per chunk handed to the writer
 #[cfg(feature = "duckdb-backend")]
-use super::schema::{SCHEMA_VERSION, StagingSchema};
+const PREPARE_CHUNK_FILES: usize = 8;
 
 /// Staging database for raw JSON ingestion
 ///
@@ -105,30 +123,50 @@ impl StagingDb {
         Ok(count)
     }
 
+    /// Get the number of records rejected by validation, optionally for one batch
+    pub fn dead_letter_count(&self, batch_id: Option<&str>) -> Result<i64, StagingError> {
+        let count: i64 = if let Some(batch_id) = batch_id {
+            self.conn.query_row(
+                "SELECT COUNT(*) FROM dead_letter_records WHERE batch_id = ?1",
+                [batch_id],
+                |row| row.get(0),
+            )?
+        } else {
+            self.conn
+                .query_row("SELECT COUNT(*) FROM dead_letter_records", [], |row| {
+                    row.get(0)
+                })?
+        };
+        Ok(count)
+    }
+
     /// Get sample records for schema inference
     pub fn get_sample(
         &self,
         limit: usize,
         partition: Option<&str>,
     ) -> Result<Vec<String>, StagingError> {
+        self.get_sample_with(limit, partition, &SamplingStrategy::Random)
+    }
+
+    /// Get sample records for schema inference using the given sampling strategy
+    pub fn get_sample_with(
+        &self,
+        limit: usize,
+        partition: Option<&str>,
+        strategy: &SamplingStrategy,
+    ) -> Result<Vec<String>, StagingError> {
+        let sql = sample_query(strategy, SqlDialect::DuckDb, limit, partition.is_some());
+        let mut stmt = self.conn.prepare(&sql)?;
         let mut samples = Vec::new();
 
         if let Some(partition) = partition {
-            let mut stmt = self.conn.prepare(
-                "SELECT raw_json FROM staged_json WHERE partition_key = ?1 ORDER BY RANDOM() LIMIT ?2"
-            )?;
-            let rows = stmt.query_map(duckdb::params![partition, limit as i64], |row| {
-                row.get::<_, String>(0)
-            })?;
+            let rows = stmt.query_map(duckdb::params![partition], |row| row.get::<_, String>(0))?;
             for row in rows {
                 samples.push(row?);
             }
         } else {
-            let mut stmt = self
-                .conn
-                .prepare("SELECT raw_json FROM staged_json ORDER BY RANDOM() LIMIT ?1")?;
-            let rows =
-                stmt.query_map(duckdb::params![limit as i64], |row| row.get::<_, String>(0))?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
             for row in rows {
                 samples.push(row?);
             }
@@ -137,6 +175,12 @@ impl StagingDb {
         Ok(samples)
     }
 
+    /// Execute a statement that returns no rows
+    pub(crate) fn execute(&self, sql: &str) -> Result<(), StagingError> {
+        self.conn.execute_batch(sql)?;
+        Ok(())
+    }
+
     /// Execute a query and return results as JSON
     pub fn query(&self, sql: &str) -> Result<Vec<serde_json::Value>, StagingError> {
         let mut stmt = self.conn.prepare(sql)?;
@@ -184,14 +228,24 @@ impl StagingDb {
     }
 
     /// Get existing file paths for deduplication
-    fn get_existing_paths(&self, partition: Option<&str>) -> Result<HashSet<String>, StagingError> {
+    ///
+    /// When `include_sub_partitions` is set, paths staged into split
+    /// sub-partitions (`{partition}/...`) are included as well.
+    fn get_existing_paths(
+        &self,
+        partition: Option<&str>,
+        include_sub_partitions: bool,
+    ) -> Result<HashSet<String>, StagingError> {
         let mut paths = HashSet::new();
 
         if let Some(partition) = partition {
-            let mut stmt = self
-                .conn
-                .prepare("SELECT DISTINCT file_path FROM staged_json WHERE partition_key = ?1")?;
-            let rows = stmt.query_map([partition], |row| row.get::<_, String>(0))?;
+            let mut stmt = self.conn.prepare(
+                "SELECT DISTINCT file_path FROM staged_json WHERE partition_key = ?1 OR (?2 AND starts_with(partition_key, ?1 || '/'))",
+            )?;
+            let rows = stmt
+                .query_map(duckdb::params![partition, include_sub_partitions], |row| {
+                    row.get::<_, String>(0)
+                })?;
             for row in rows {
                 paths.insert(row?);
             }
@@ -212,14 +266,18 @@ impl StagingDb {
     fn get_existing_hashes(
         &self,
         partition: Option<&str>,
+        include_sub_partitions: bool,
     ) -> Result<HashSet<String>, StagingError> {
         let mut hashes = HashSet::new();
 
         if let Some(partition) = partition {
             let mut stmt = self.conn.prepare(
-                "SELECT DISTINCT content_hash FROM staged_json WHERE partition_key = ?1 AND content_hash IS NOT NULL"
+                "SELECT DISTINCT content_hash FROM staged_json WHERE (partition_key = ?1 OR (?2 AND starts_with(partition_key, ?1 || '/'))) AND content_hash IS NOT NULL"
             )?;
-            let rows = stmt.query_map([partition], |row| row.get::<_, String>(0))?;
+            let rows = stmt
+                .query_map(duckdb::params![partition, include_sub_partitions], |row| {
+                    row.get::<_, String>(0)
+                })?;
             for row in rows {
                 hashes.insert(row?);
             }
@@ -246,21 +304,80 @@ impl StagingDb {
         Ok(id)
     }
 
-    /// Insert a batch of records
+    /// Delete duplicate records from the given partitions, returning how many
+    /// were deleted
+    fn deduplicate_records(
+        &self,
+        dedup: &RecordDedup,
+        partitions: &BTreeSet<Option<String>>,
+    ) -> Result<usize, StagingError> {
+        let mut deleted = 0;
+        for partition in partitions {
+            let sql = dedup_query(dedup, SqlDialect::DuckDb, partition.is_some());
+            deleted += match partition {
+                Some(partition) => self.conn.execute(&sql, [partition])?,
+                None => self.conn.execute(&sql, [])?,
+            };
+        }
+        Ok(deleted)
+    }
+
+    /// Insert records rejected by validation into the dead-letter table
+    ///
+    /// Uses the appender API like [`Self::insert_records`]; `id` and
+    /// `rejected_at` are left out of the column list and take their defaults.
+    fn insert_dead_letters(
+        &self,
+        batch_id: &str,
+        records: &[(String, usize, Option<String>, String, String)],
+    ) -> Result<(), StagingError> {
+        let mut appender = self.conn.appender_with_columns(
+            "dead_letter_records",
+            &[
+                "batch_id",
+                "file_path",
+                "record_index",
+                "partition_key",
+                "raw_json",
+                "errors",
+            ],
+        )?;
+
+        for (file_path, record_index, partition, json, errors) in records {
+            appender.append_row(duckdb::params![
+                batch_id,
+                file_path,
+                *record_index as i32,
+                partition.as_deref(),
+                json,
+                errors,
+            ])?;
+        }
+        appender.flush()?;
+
+        Ok(())
+    }
+
+    /// Insert a batch of records with the appender API
+    ///
+    /// The appender fills every column in table order, so `ingested_at` is
+    /// set explicitly rather than by its default.
     fn insert_records(
         &self,
         records: &[(String, String, usize, Option<String>, Option<String>, u64)],
         start_id: i64,
+        batch_id: &str,
     ) -> Result<(), StagingError> {
-        let mut stmt = self.conn.prepare(
-            "INSERT INTO staged_json (id, file_path, record_index, partition_key, raw_json, content_hash, file_size_bytes)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
-        )?;
+        let ingested_at = duckdb::types::Value::Timestamp(
+            duckdb::types::TimeUnit::Microsecond,
+            Utc::now().timestamp_micros(),
+        );
+        let mut appender = self.conn.appender("staged_json")?;
 
         for (i, (file_path, json, record_index, partition, hash, size)) in
             records.iter().enumerate()
         {
-            stmt.execute(duckdb::params![
+            appender.append_row(duckdb::params![
                 start_id + i as i64,
                 file_path,
                 *record_index as i32,
@@ -268,8 +385,11 @@ impl StagingDb {
                 json,
                 hash.as_deref(),
                 *size as i64,
+                ingested_at,
+                batch_id,
             ])?;
         }
+        appender.flush()?;
 
         Ok(())
     }
@@ -280,8 +400,8 @@ impl StagingDb {
             "INSERT INTO processing_batches
              (id, source_path, source_type, partition_key, pattern, status,
               files_total, files_processed, files_skipped, records_ingested,
-              bytes_processed, errors_count, started_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+              bytes_processed, errors_count, started_at, updated_at, bytes_decompressed)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
             duckdb::params![
                 batch.id,
                 batch.source_path,
@@ -297,6 +417,7 @@ impl StagingDb {
                 batch.errors_count,
                 batch.started_at.map(|t| t.to_rfc3339()),
                 batch.updated_at.map(|t| t.to_rfc3339()),
+                batch.bytes_decompressed,
             ],
         )?;
         Ok(())
@@ -309,7 +430,8 @@ impl StagingDb {
              status = ?2, files_total = ?3, files_processed = ?4, files_skipped = ?5,
              records_ingested = ?6, bytes_processed = ?7, errors_count = ?8,
              last_file_path = ?9, last_record_index = ?10,
-             updated_at = ?11, completed_at = ?12, error_message = ?13
+             updated_at = ?11, completed_at = ?12, error_message = ?13,
+             bytes_decompressed = ?14
              WHERE id = ?1",
             duckdb::params![
                 batch.id,
@@ -325,6 +447,7 @@ impl StagingDb {
                 batch.updated_at.map(|t| t.to_rfc3339()),
                 batch.completed_at.map(|t| t.to_rfc3339()),
                 batch.error_message.as_deref(),
+                batch.bytes_decompressed,
             ],
         )?;
         Ok(())
@@ -336,7 +459,7 @@ impl StagingDb {
             "SELECT id, source_path, source_type, partition_key, pattern, status,
                     files_total, files_processed, files_skipped, records_ingested,
                     bytes_processed, errors_count, last_file_path, last_record_index,
-                    started_at, updated_at, completed_at, error_message
+                    started_at, updated_at, completed_at, error_message, bytes_decompressed
              FROM processing_batches WHERE id = ?1",
             [batch_id],
             |row| {
@@ -371,6 +494,7 @@ impl StagingDb {
                         .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
                         .map(|dt| dt.with_timezone(&Utc)),
                     error_message: row.get(17)?,
+                    bytes_decompressed: row.get::<_, Option<i64>>(18)?.unwrap_or(0),
                 })
             },
         );
@@ -389,7 +513,7 @@ impl StagingDb {
             "SELECT id, source_path, source_type, partition_key, pattern, status,
                     files_total, files_processed, files_skipped, records_ingested,
                     bytes_processed, errors_count, last_file_path, last_record_index,
-                    started_at, updated_at, completed_at, error_message
+                    started_at, updated_at, completed_at, error_message, bytes_decompressed
              FROM processing_batches
              ORDER BY started_at DESC
              LIMIT ?1",
@@ -427,6 +551,7 @@ impl StagingDb {
                     .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
                 error_message: row.get(17)?,
+                bytes_decompressed: row.get::<_, Option<i64>>(18)?.unwrap_or(0),
             })
         })?;
 
@@ -437,6 +562,61 @@ impl StagingDb {
         Ok(batches)
     }
 
+    /// Profile the fields of a partition, or of all staged records
+    pub fn profile(&self, partition: Option<&str>) -> Result<ProfileReport, StagingError> {
+        self.profile_with(partition, &ProfileOptions::default())
+    }
+
+    /// Profile the fields of a partition with the given options
+    ///
+    /// Reads the records twice; see [`ProfileBuilder`].
+    pub fn profile_with(
+        &self,
+        partition: Option<&str>,
+        options: &ProfileOptions,
+    ) -> Result<ProfileReport, StagingError> {
+        let mut builder = ProfileBuilder::new(options.clone());
+        self.for_each_record(partition, |record| builder.add(record))?;
+        builder.start_second_pass();
+        self.for_each_record(partition, |record| builder.add_second_pass(record))?;
+        Ok(builder.finish(partition))
+    }
+
+    /// Call `f` with every parseable record of a partition, in ingestion order
+    fn for_each_record(
+        &self,
+        partition: Option<&str>,
+        mut f: impl FnMut(&serde_json::Value),
+    ) -> Result<(), StagingError> {
+        fn id_and_json(row: &duckdb::Row<'_>) -> duckdb::Result<(i64, String)> {
+            Ok((row.get(0)?, row.get(1)?))
+        }
+
+        let sql = record_page_query(SqlDialect::DuckDb, partition.is_some());
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut last_id = i64::MIN;
+        loop {
+            let rows = match partition {
+                Some(partition) => {
+                    stmt.query_map(duckdb::params![last_id, partition], id_and_json)?
+                }
+                None => stmt.query_map(duckdb::params![last_id], id_and_json)?,
+            };
+            let mut read = 0;
+            for row in rows {
+                let (id, raw_json) = row?;
+                last_id = id;
+                read += 1;
+                if let Ok(record) = serde_json::from_str(&raw_json) {
+                    f(&record);
+                }
+            }
+            if read == 0 {
+                return Ok(());
+            }
+        }
+    }
+
     /// Get partition statistics
     pub fn partition_stats(&self) -> Result<Vec<(String, i64)>, StagingError> {
         let mut stats = Vec::new();
@@ -458,7 +638,34 @@ impl StagingDb {
         Ok(stats)
     }
 
+    /// Delete the staged data selected by a purge policy
+    ///
+    /// The deletes run in one transaction, so a failed purge leaves the
+    /// staging tables untouched.
+    pub fn purge(&self, policy: &PurgePolicy) -> Result<PurgeStats, StagingError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut stats = PurgeStats::default();
+        for statement in purge_statements(policy, SqlDialect::DuckDb, Utc::now()) {
+            let deleted = tx.execute(&statement.sql, [&statement.param])?;
+            stats.add(statement.table, deleted);
+        }
+        tx.commit()?;
+        Ok(stats)
+    }
+
+    /// Reclaim the space of deleted rows
+    pub fn vacuum(&self) -> Result<(), StagingError> {
+        self.conn
+            .execute_batch(vacuum_statement(SqlDialect::DuckDb))?;
+        Ok(())
+    }
+
     /// Ingest files from the configured source
+    ///
+    /// Remote sources such as S3 are read asynchronously. Called from a
+    /// multi-threaded Tokio runtime, the ingest blocks on that runtime via
+    /// [`tokio::task::block_in_place`]; outside of a runtime it creates its own.
+    /// Remote sources cannot be ingested from a current-thread runtime.
     pub fn ingest(&self, config: &IngestConfig) -> Result<IngestStats, IngestError> {
         let start = Instant::now();
         let mut stats = IngestStats::new();
@@ -500,24 +707,23 @@ impl StagingDb {
             b
         };
 
-        // Discover files based on source type
-        let files = match &config.source {
-            SourceType::Local(path) => discover_local_files(path, &config.pattern)?,
-            #[cfg(feature = "s3")]
-            SourceType::S3 { .. } => {
-                // S3 discovery would go here
-                return Err(IngestError::SourceNotAccessible {
-                    path: config.source.display(),
-                    reason: "S3 ingestion not yet implemented".to_string(),
-                });
-            }
-            #[cfg(feature = "databricks")]
-            SourceType::UnityVolume { .. } => {
-                // Unity Catalog discovery would go here
-                return Err(IngestError::SourceNotAccessible {
-                    path: config.source.display(),
-                    reason: "Unity Catalog ingestion not yet implemented".to_string(),
-                });
+        let parsers = config.record_parsers()?;
+        let validator = config.record_validator()?;
+
+        // Discover files based on source type; remote sources are driven by
+        // the caller's runtime, or one created for the duration of the ingest
+        let (reader, files, runtime) = match &config.source {
+            SourceType::Local(path) => (
+                SourceReader::Local,
+                discover_local_files(path, &config.pattern)?,
+                None,
+            ),
+            #[allow(unreachable_patterns)]
+            remote => {
+                let runtime = SourceRuntime::new()?;
+                let (reader, files) =
+                    runtime.block_on(SourceReader::open(remote, &config.pattern))?;
+                (reader, files, Some(runtime))
             }
         };
 
@@ -526,14 +732,14 @@ impl StagingDb {
         // Get existing data for deduplication
         let existing_paths = if matches!(config.dedup, DedupStrategy::ByPath | DedupStrategy::Both)
         {
-            self.get_existing_paths(config.partition.as_deref())?
+            self.get_existing_paths(config.partition.as_deref(), config.split_by.is_some())?
         } else {
             HashSet::new()
         };
 
         let existing_hashes =
             if matches!(config.dedup, DedupStrategy::ByContent | DedupStrategy::Both) {
-                self.get_existing_hashes(config.partition.as_deref())?
+                self.get_existing_hashes(config.partition.as_deref(), config.split_by.is_some())?
             } else {
                 HashSet::new()
             };
@@ -545,87 +751,174 @@ impl StagingDb {
             None
         };
 
-        let mut next_id = self.next_id()?;
-        let mut records_batch: Vec<(String, String, usize, Option<String>, Option<String>, u64)> =
-            Vec::new();
+        // Skip files before the resume point and files staged under the same
+        // path before reading them
         let mut past_resume_point = resume_after.is_none();
-
-        for mut file in files {
+        let mut pending = Vec::new();
+        for file in files {
             let file_path_str = file.path.display().to_string();
-
-            // Skip files before resume point
             if !past_resume_point {
                 if Some(&file_path_str) == resume_after.as_ref() {
                     past_resume_point = true;
                 }
                 continue;
             }
-
-            // Compute hash if needed for dedup
-            if matches!(config.dedup, DedupStrategy::ByContent | DedupStrategy::Both) {
-                if let Err(e) = file.compute_hash() {
-                    stats.add_error(format!("Error computing hash for {}: {}", file_path_str, e));
-                    continue;
-                }
-            }
-
-            // Check deduplication
-            if should_skip_file(&file, config.dedup, &existing_paths, &existing_hashes) {
+            if matches!(config.dedup, DedupStrategy::ByPath | DedupStrategy::Both)
+                && existing_paths.contains(&file_path_str)
+            {
                 stats.files_skipped += 1;
                 batch.files_skipped += 1;
                 continue;
             }
+            pending.push(file);
+        }
 
-            // Parse the file
-            let records = match parse_file(&file.path) {
-                Ok(r) => r,
-                Err(e) => {
-                    stats.add_error(format!("Error parsing {}: {}", file_path_str, e));
-                    batch.increment_errors();
-                    continue;
+        let preparer = FilePreparer {
+            config,
+            parsers: &parsers,
+            validator: validator.as_ref(),
+            existing_paths: &existing_paths,
+            existing_hashes: &existing_hashes,
+        };
+        let workers = config.workers.max(1);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(workers)
+            .build()
+            .map_err(|e| IngestError::Io(std::io::Error::other(e)))?;
+
+        let mut next_id = self.next_id()?;
+        let mut records_batch: Vec<(String, String, usize, Option<String>, Option<String>, u64)> =
+            Vec::new();
+        let mut dedup_partitions = BTreeSet::new();
+        let mut rejected_batch: Vec<(String, usize, Option<String>, String, String)> = Vec::new();
+
+        // Workers read, hash, decompress, parse and validate files in chunks;
+        // this thread is the single writer and stages each chunk in file order
+        // while the next one is prepared
+        std::thread::scope(|scope| -> Result<(), IngestError> {
+            let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+            let (pool, preparer, reader, runtime) = (&pool, &preparer, &reader, &runtime);
+            scope.spawn(move || {
+                for chunk in pending.chunks(workers * PREPARE_CHUNK_FILES) {
+                    let prepared: Vec<PreparedFile> = pool.install(|| {
+                        chunk
+                            .par_iter()
+                            .map(|file| {
+                                // Read the file, downloading it from remote sources
+                                let content = match runtime {
+                                    Some(runtime) => runtime.block_on(reader.read(file)),
+                                    None => std::fs::read(&file.path).map_err(IngestError::from),
+                                };
+                                preparer.prepare(file.clone(), content)
+                            })
+                            .collect()
+                    });
+                    // The writer stopped on an error
+                    if sender.send(prepared).is_err() {
+                        break;
+                    }
                 }
-            };
+            });
 
-            // Add records to batch
-            for record in records {
-                records_batch.push((
-                    file_path_str.clone(),
-                    record.json,
-                    record.index,
-                    config.partition.clone(),
-                    file.content_hash.clone(),
-                    file.size,
-                ));
-
-                // Insert batch when full
-                if records_batch.len() >= config.batch_size {
-                    self.insert_records(&records_batch, next_id)?;
-                    stats.records_ingested += records_batch.len();
-                    batch.records_ingested += records_batch.len() as i64;
-                    next_id += records_batch.len() as i64;
-                    records_batch.clear();
+            for prepared in receiver.into_iter().flatten() {
+                let (file, decompressed_size, records) = match prepared {
+                    PreparedFile::Duplicate => {
+                        stats.files_skipped += 1;
+                        batch.files_skipped += 1;
+                        continue;
+                    }
+                    PreparedFile::Failed(error) => {
+                        stats.add_error(error);
+                        batch.increment_errors();
+                        continue;
+                    }
+                    PreparedFile::Parsed {
+                        file,
+                        decompressed_size,
+                        records,
+                    } => (file, decompressed_size, records),
+                };
+                let file_path_str = file.path.display().to_string();
+
+                // Add records to batch
+                for record in records {
+                    if let Some(errors) = record.rejection {
+                        rejected_batch.push((
+                            file_path_str.clone(),
+                            record.index,
+                            record.partition,
+                            record.json,
+                            errors,
+                        ));
+                        stats.records_rejected += 1;
+                        continue;
+                    }
+                    if config.split_by.is_some() {
+                        *stats
+                            .partition_counts
+                            .entry(
+                                record
+                                    .partition
+                                    .clone()
+                                    .unwrap_or_else(|| "<none>".to_string()),
+                            )
+                            .or_default() += 1;
+                    }
+                    if config.record_dedup.is_some() {
+                        dedup_partitions.insert(record.partition.clone());
+                    }
+                    records_batch.push((
+                        file_path_str.clone(),
+                        record.json,
+                        record.index,
+                        record.partition,
+                        file.content_hash.clone(),
+                        file.size,
+                    ));
+
+                    // Insert batch when full
+                    if records_batch.len() >= config.batch_size {
+                        self.insert_records(&records_batch, next_id, &batch.id)?;
+                        stats.records_ingested += records_batch.len();
+                        batch.records_ingested += records_batch.len() as i64;
+                        next_id += records_batch.len() as i64;
+                        records_batch.clear();
+                    }
                 }
-            }
 
-            stats.files_processed += 1;
-            stats.bytes_processed += file.size;
-            batch.files_processed += 1;
-            batch.bytes_processed += file.size as i64;
-            batch.last_file_path = Some(file_path_str);
+                stats.files_processed += 1;
+                stats.bytes_processed += file.size;
+                stats.bytes_decompressed += decompressed_size;
+                batch.files_processed += 1;
+                batch.bytes_processed += file.size as i64;
+                batch.bytes_decompressed += decompressed_size as i64;
+                batch.last_file_path = Some(file_path_str);
 
-            // Update batch progress periodically
-            if batch.files_processed % 100 == 0 {
-                self.update_batch(&batch)?;
+                // Update batch progress periodically
+                if batch.files_processed % 100 == 0 {
+                    self.update_batch(&batch)?;
+                }
             }
-        }
+            Ok(())
+        })?;
 
         // Insert remaining records
         if !records_batch.is_empty() {
-            self.insert_records(&records_batch, next_id)?;
+            self.insert_records(&records_batch, next_id, &batch.id)?;
             stats.records_ingested += records_batch.len();
             batch.records_ingested += records_batch.len() as i64;
         }
 
+        // Insert rejected records into the dead-letter table
+        if !rejected_batch.is_empty() {
+            self.insert_dead_letters(&batch.id, &rejected_batch)?;
+        }
+
+        // Remove duplicate records from the partitions staged into
+        if let Some(ref dedup) = config.record_dedup {
+            stats.records_deduplicated = self.deduplicate_records(dedup, &dedup_partitions)?;
+        }
+
         // Complete batch
         batch.complete();
         self.update_batch(&batch)?;
@@ -644,17 +937,26 @@ pub use postgres_impl::StagingDbPostgres;
 
 #[cfg(feature = "postgres-backend")]
 mod postgres_impl {
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
     use std::time::Instant;
 
+    use bytes::Bytes;
     use chrono::Utc;
+    use futures_util::SinkExt;
     use tokio_postgres::{Client, NoTls};
 
+    use crate::inference::SamplingStrategy;
     use crate::staging::batch::{BatchStatus, ProcessingBatch};
-    use crate::staging::config::{DedupStrategy, IngestConfig, SourceType};
+    use crate::staging::config::{DedupStrategy, IngestConfig, RecordDedup, SourceType};
+    use crate::staging::dedup::dedup_query;
     use crate::staging::error::{IngestError, StagingError};
-    use crate::staging::ingest::{IngestStats, discover_local_files, parse_file, should_skip_file};
-    use crate::staging::schema::{SCHEMA_VERSION, StagingSchema};
+    use crate::staging::ingest::{FilePreparer, IngestStats, PreparedFile, SourceReader};
+    use crate::staging::profile::{
+        ProfileBuilder, ProfileOptions, ProfileReport, record_page_query,
+    };
+    use crate::staging::purge::{PurgePolicy, PurgeStats, purge_statements, vacuum_statement};
+    use crate::staging::sampling::sample_query;
+    use crate::staging::schema::{SCHEMA_VERSION, SqlDialect, StagingSchema};
 
     /// PostgreSQL staging database (async)
     pub struct StagingDbPostgres {
@@ -759,42 +1061,78 @@ mod postgres_impl {
             Ok(count)
         }
 
+        /// Get the number of records rejected by validation, optionally for one batch
+        pub async fn dead_letter_count(&self, batch_id: Option<&str>) -> Result<i64, StagingError> {
+            let row = if let Some(batch_id) = batch_id {
+                self.client
+                    .query_one(
+                        "SELECT COUNT(*) FROM dead_letter_records WHERE batch_id = $1",
+                        &[&batch_id],
+                    )
+                    .await
+            } else {
+                self.client
+                    .query_one("SELECT COUNT(*) FROM dead_letter_records", &[])
+                    .await
+            }
+            .map_err(|e| StagingError::Database(e.to_string()))?;
+            Ok(row.get(0))
+        }
+
         /// Get sample records for schema inference
         pub async fn get_sample(
             &self,
             limit: usize,
             partition: Option<&str>,
         ) -> Result<Vec<String>, StagingError> {
+            self.get_sample_with(limit, partition, &SamplingStrategy::Random)
+                .await
+        }
+
+        /// Get sample records for schema inference using the given sampling strategy
+        pub async fn get_sample_with(
+            &self,
+            limit: usize,
+            partition: Option<&str>,
+            strategy: &SamplingStrategy,
+        ) -> Result<Vec<String>, StagingError> {
+            let sql = sample_query(strategy, SqlDialect::Postgres, limit, partition.is_some());
             let rows = if let Some(partition) = partition {
-                self.client
-                    .query(
-                        "SELECT raw_json::text FROM staged_json WHERE partition_key = $1 ORDER BY RANDOM() LIMIT $2",
-                        &[&partition, &(limit as i64)],
-                    )
-                    .await
+                self.client.query(sql.as_str(), &[&partition]).await
             } else {
-                self.client
-                    .query(
-                        "SELECT raw_json::text FROM staged_json ORDER BY RANDOM() LIMIT $1",
-                        &[&(limit as i64)],
-                    )
-                    .await
+                self.client.query(sql.as_str(), &[]).await
             }
             .map_err(|e| StagingError::Database(e.to_string()))?;
 
             Ok(rows.iter().map(|r| r.get::<_, String>(0)).collect())
         }
 
+        /// Execute a query and return results as JSON
+        pub async fn query(&self, sql: &str) -> Result<Vec<serde_json::Value>, StagingError> {
+            // Let PostgreSQL serialize each row, whatever its column types
+            let wrapped = format!("SELECT row_to_json(q)::text FROM ({}) q", sql);
+            let rows = self
+                .client
+                .query(wrapped.as_str(), &[])
+                .await
+                .map_err(|e| StagingError::Query(e.to_string()))?;
+
+            rows.iter()
+                .map(|r| Ok(serde_json::from_str(&r.get::<_, String>(0))?))
+                .collect()
+        }
+
         /// Get existing file paths for deduplication
         async fn get_existing_paths(
             &self,
             partition: Option<&str>,
+            include_sub_partitions: bool,
         ) -> Result<HashSet<String>, StagingError> {
             let rows = if let Some(partition) = partition {
                 self.client
                     .query(
-                        "SELECT DISTINCT file_path FROM staged_json WHERE partition_key = $1",
-                        &[&partition],
+                        "SELECT DISTINCT file_path FROM staged_json WHERE partition_key = $1 OR ($2 AND starts_with(partition_key, $1 || '/'))",
+                        &[&partition, &include_sub_partitions],
                     )
                     .await
             } else {
@@ -811,12 +1149,13 @@ mod postgres_impl {
         async fn get_existing_hashes(
             &self,
             partition: Option<&str>,
+            include_sub_partitions: bool,
         ) -> Result<HashSet<String>, StagingError> {
             let rows = if let Some(partition) = partition {
                 self.client
                     .query(
-                        "SELECT DISTINCT content_hash FROM staged_json WHERE partition_key = $1 AND content_hash IS NOT NULL",
-                        &[&partition],
+                        "SELECT DISTINCT content_hash FROM staged_json WHERE (partition_key = $1 OR ($2 AND starts_with(partition_key, $1 || '/'))) AND content_hash IS NOT NULL",
+                        &[&partition, &include_sub_partitions],
                     )
                     .await
             } else {
@@ -833,27 +1172,98 @@ mod postgres_impl {
         }
 
         /// Insert a batch of records
+        /// Delete duplicate records from the given partitions, returning how many
+        /// were deleted
+        async fn deduplicate_records(
+            &self,
+            dedup: &RecordDedup,
+            partitions: &BTreeSet<Option<String>>,
+        ) -> Result<usize, StagingError> {
+            let mut deleted = 0;
+            for partition in partitions {
+                let sql = dedup_query(dedup, SqlDialect::Postgres, partition.is_some());
+                deleted += match partition {
+                    Some(partition) => self.client.execute(sql.as_str(), &[partition]).await,
+                    None => self.client.execute(sql.as_str(), &[]).await,
+                }
+                .map_err(|e| StagingError::Database(e.to_string()))?;
+            }
+            Ok(deleted as usize)
+        }
+
+        /// Insert records rejected by validation into the dead-letter table
+        async fn insert_dead_letters(
+            &self,
+            batch_id: &str,
+            records: &[(String, usize, Option<String>, String, String)],
+        ) -> Result<(), StagingError> {
+            let mut data = String::new();
+            for (file_path, record_index, partition, json, errors) in records {
+                copy_row(
+                    &mut data,
+                    &[
+                        Some(batch_id),
+                        Some(file_path),
+                        Some(&record_index.to_string()),
+                        partition.as_deref(),
+                        Some(json),
+                        Some(errors),
+                    ],
+                );
+            }
+            self.copy_in(
+                "COPY dead_letter_records (batch_id, file_path, record_index, partition_key, raw_json, errors) FROM STDIN",
+                data,
+            )
+            .await
+        }
+
+        /// Insert a batch of records with `COPY ... FROM STDIN`
         async fn insert_records(
             &self,
             records: &[(String, String, usize, Option<String>, Option<String>, u64)],
+            batch_id: &str,
         ) -> Result<(), StagingError> {
+            let mut data = String::new();
             for (file_path, json, record_index, partition, hash, size) in records {
-                self.client
-                    .execute(
-                        "INSERT INTO staged_json (file_path, record_index, partition_key, raw_json, content_hash, file_size_bytes)
-                         VALUES ($1, $2, $3, $4::jsonb, $5, $6)",
-                        &[
-                            file_path,
-                            &(*record_index as i32),
-                            &partition.as_deref(),
-                            json,
-                            &hash.as_deref(),
-                            &(*size as i64),
-                        ],
-                    )
-                    .await
-                    .map_err(|e| StagingError::Database(e.to_string()))?;
+                copy_row(
+                    &mut data,
+                    &[
+                        Some(file_path),
+                        Some(&record_index.to_string()),
+                        partition.as_deref(),
+                        Some(json),
+                        hash.as_deref(),
+                        Some(&size.to_string()),
+                        Some(batch_id),
+                    ],
+                );
             }
+            self.copy_in(
+                "COPY staged_json (file_path, record_index, partition_key, raw_json, content_hash, file_size_bytes, batch_id) FROM STDIN",
+                data,
+            )
+            .await
+        }
+
+        /// Stream rows in `COPY` text format into a `COPY ... FROM STDIN` statement
+        async fn copy_in(&self, statement: &str, data: String) -> Result<(), StagingError> {
+            if data.is_empty() {
+                return Ok(());
+            }
+            let sink = self
+                .client
+                .copy_in::<_, Bytes>(statement)
+                .await
+                .map_err(|e| StagingError::Database(e.to_string()))?;
+            let mut sink = std::pin::pin!(sink);
+            sink.send(Bytes::from(data))
+                .await
+                .map_err(|e| StagingError::Database(e.to_string()))?;
+            sink.as_mut()
+                .finish()
+                .await
+                .map_err(|e| StagingError::Database(e.to_string()))?;
             Ok(())
         }
 
@@ -864,8 +1274,8 @@ mod postgres_impl {
                     "INSERT INTO processing_batches
                      (id, source_path, source_type, partition_key, pattern, status,
                       files_total, files_processed, files_skipped, records_ingested,
-                      bytes_processed, errors_count, started_at, updated_at)
-                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+                      bytes_processed, errors_count, started_at, updated_at, bytes_decompressed)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)",
                     &[
                         &batch.id,
                         &batch.source_path,
@@ -881,6 +1291,7 @@ mod postgres_impl {
                         &batch.errors_count,
                         &batch.started_at.map(|t| t.to_rfc3339()),
                         &batch.updated_at.map(|t| t.to_rfc3339()),
+                        &batch.bytes_decompressed,
                     ],
                 )
                 .await
@@ -896,7 +1307,8 @@ mod postgres_impl {
                      status = $2, files_total = $3, files_processed = $4, files_skipped = $5,
                      records_ingested = $6, bytes_processed = $7, errors_count = $8,
                      last_file_path = $9, last_record_index = $10,
-                     updated_at = $11, completed_at = $12, error_message = $13
+                     updated_at = $11, completed_at = $12, error_message = $13,
+                     bytes_decompressed = $14
                      WHERE id = $1",
                     &[
                         &batch.id,
@@ -912,6 +1324,7 @@ mod postgres_impl {
                         &batch.updated_at.map(|t| t.to_rfc3339()),
                         &batch.completed_at.map(|t| t.to_rfc3339()),
                         &batch.error_message.as_deref(),
+                        &batch.bytes_decompressed,
                     ],
                 )
                 .await
@@ -930,7 +1343,7 @@ mod postgres_impl {
                     "SELECT id, source_path, source_type, partition_key, pattern, status,
                             files_total, files_processed, files_skipped, records_ingested,
                             bytes_processed, errors_count, last_file_path, last_record_index,
-                            started_at, updated_at, completed_at, error_message
+                            started_at, updated_at, completed_at, error_message, bytes_decompressed
                      FROM processing_batches WHERE id = $1",
                     &[&batch_id],
                 )
@@ -968,6 +1381,7 @@ mod postgres_impl {
                     .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
                 error_message: r.get(17),
+                bytes_decompressed: r.get::<_, Option<i64>>(18).unwrap_or(0),
             }))
         }
 
@@ -982,7 +1396,7 @@ mod postgres_impl {
                     "SELECT id, source_path, source_type, partition_key, pattern, status,
                             files_total, files_processed, files_skipped, records_ingested,
                             bytes_processed, errors_count, last_file_path, last_record_index,
-                            started_at, updated_at, completed_at, error_message
+                            started_at, updated_at, completed_at, error_message, bytes_decompressed
                      FROM processing_batches
                      ORDER BY started_at DESC
                      LIMIT $1",
@@ -1024,10 +1438,64 @@ mod postgres_impl {
                         .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
                         .map(|dt| dt.with_timezone(&Utc)),
                     error_message: r.get(17),
+                    bytes_decompressed: r.get::<_, Option<i64>>(18).unwrap_or(0),
                 })
                 .collect())
         }
 
+        /// Profile the fields of a partition, or of all staged records
+        pub async fn profile(
+            &self,
+            partition: Option<&str>,
+        ) -> Result<ProfileReport, StagingError> {
+            self.profile_with(partition, &ProfileOptions::default())
+                .await
+        }
+
+        /// Profile the fields of a partition with the given options
+        ///
+        /// Reads the records twice, a page at a time; see [`ProfileBuilder`].
+        pub async fn profile_with(
+            &self,
+            partition: Option<&str>,
+            options: &ProfileOptions,
+        ) -> Result<ProfileReport, StagingError> {
+            let mut builder = ProfileBuilder::new(options.clone());
+            for second_pass in [false, true] {
+                if second_pass {
+                    builder.start_second_pass();
+                }
+                let sql = record_page_query(SqlDialect::Postgres, partition.is_some());
+                let mut last_id = i64::MIN;
+                loop {
+                    let rows = match partition {
+                        Some(partition) => {
+                            self.client
+                                .query(sql.as_str(), &[&last_id, &partition])
+                                .await
+                        }
+                        None => self.client.query(sql.as_str(), &[&last_id]).await,
+                    }
+                    .map_err(|e| StagingError::Database(e.to_string()))?;
+                    let Some(last) = rows.last() else {
+                        break;
+                    };
+                    last_id = last.get(0);
+                    for row in &rows {
+                        let Ok(record) = serde_json::from_str(row.get::<_, &str>(1)) else {
+                            continue;
+                        };
+                        if second_pass {
+                            builder.add_second_pass(&record);
+                        } else {
+                            builder.add(&record);
+                        }
+                    }
+                }
+            }
+            Ok(builder.finish(partition))
+        }
+
         /// Get partition statistics
         pub async fn partition_stats(&self) -> Result<Vec<(String, i64)>, StagingError> {
             let rows = self
@@ -1045,6 +1513,47 @@ mod postgres_impl {
             Ok(rows.iter().map(|r| (r.get(0), r.get(1))).collect())
         }
 
+        /// Delete the staged data selected by a purge policy
+        ///
+        /// The deletes run in one transaction, so a failed purge leaves the
+        /// staging tables untouched.
+        pub async fn purge(&self, policy: &PurgePolicy) -> Result<PurgeStats, StagingError> {
+            self.client
+                .batch_execute("BEGIN")
+                .await
+                .map_err(|e| StagingError::Database(e.to_string()))?;
+
+            let mut stats = PurgeStats::default();
+            for statement in purge_statements(policy, SqlDialect::Postgres, Utc::now()) {
+                match self
+                    .client
+                    .execute(&statement.sql, &[&statement.param])
+                    .await
+                {
+                    Ok(deleted) => stats.add(statement.table, deleted as usize),
+                    Err(e) => {
+                        // The original error matters more than a failed rollback
+                        let _ = self.client.batch_execute("ROLLBACK").await;
+                        return Err(StagingError::Database(e.to_string()));
+                    }
+                }
+            }
+
+            self.client
+                .batch_execute("COMMIT")
+                .await
+                .map_err(|e| StagingError::Database(e.to_string()))?;
+            Ok(stats)
+        }
+
+        /// Reclaim the space of deleted rows and refresh planner statistics
+        pub async fn vacuum(&self) -> Result<(), StagingError> {
+            self.client
+                .batch_execute(vacuum_statement(SqlDialect::Postgres))
+                .await
+                .map_err(|e| StagingError::Database(e.to_string()))
+        }
+
         /// Ingest files from the configured source
         pub async fn ingest(&self, config: &IngestConfig) -> Result<IngestStats, IngestError> {
             let start = Instant::now();
@@ -1087,38 +1596,26 @@ mod postgres_impl {
                 b
             };
 
+            let parsers = config.record_parsers()?;
+            let validator = config.record_validator()?;
+
             // Discover files based on source type
-            let files = match &config.source {
-                SourceType::Local(path) => discover_local_files(path, &config.pattern)?,
-                #[cfg(feature = "s3")]
-                SourceType::S3 { .. } => {
-                    return Err(IngestError::SourceNotAccessible {
-                        path: config.source.display(),
-                        reason: "S3 ingestion not yet implemented".to_string(),
-                    });
-                }
-                #[cfg(feature = "databricks")]
-                SourceType::UnityVolume { .. } => {
-                    return Err(IngestError::SourceNotAccessible {
-                        path: config.source.display(),
-                        reason: "Unity Catalog ingestion not yet implemented".to_string(),
-                    });
-                }
-            };
+            let (reader, files) = SourceReader::open(&config.source, &config.pattern).await?;
 
             batch.files_total = files.len() as i32;
 
             // Get existing data for deduplication
             let existing_paths =
                 if matches!(config.dedup, DedupStrategy::ByPath | DedupStrategy::Both) {
-                    self.get_existing_paths(config.partition.as_deref()).await?
+                    self.get_existing_paths(config.partition.as_deref(), config.split_by.is_some())
+                        .await?
                 } else {
                     HashSet::new()
                 };
 
             let existing_hashes =
                 if matches!(config.dedup, DedupStrategy::ByContent | DedupStrategy::Both) {
-                    self.get_existing_hashes(config.partition.as_deref())
+                    self.get_existing_hashes(config.partition.as_deref(), config.split_by.is_some())
                         .await?
                 } else {
                     HashSet::new()
@@ -1140,8 +1637,19 @@ mod postgres_impl {
                 u64,
             )> = Vec::new();
             let mut past_resume_point = resume_after.is_none();
+            let mut dedup_partitions = BTreeSet::new();
+            let mut rejected_batch: Vec<(String, usize, Option<String>, String, String)> =
+                Vec::new();
+
+            let preparer = FilePreparer {
+                config,
+                parsers: &parsers,
+                validator: validator.as_ref(),
+                existing_paths: &existing_paths,
+                existing_hashes: &existing_hashes,
+            };
 
-            for mut file in files {
+            for file in files {
                 let file_path_str = file.path.display().to_string();
 
                 // Skip files before resume point
@@ -1152,48 +1660,70 @@ mod postgres_impl {
                     continue;
                 }
 
-                // Compute hash if needed for dedup
-                if matches!(config.dedup, DedupStrategy::ByContent | DedupStrategy::Both) {
-                    if let Err(e) = file.compute_hash() {
-                        stats.add_error(format!(
-                            "Error computing hash for {}: {}",
-                            file_path_str, e
-                        ));
-                        continue;
-                    }
-                }
-
-                // Check deduplication
-                if should_skip_file(&file, config.dedup, &existing_paths, &existing_hashes) {
+                // Skip files staged under the same path before reading them
+                if matches!(config.dedup, DedupStrategy::ByPath | DedupStrategy::Both)
+                    && existing_paths.contains(&file_path_str)
+                {
                     stats.files_skipped += 1;
                     batch.files_skipped += 1;
                     continue;
                 }
 
-                // Parse the file
-                let records = match parse_file(&file.path) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        stats.add_error(format!("Error parsing {}: {}", file_path_str, e));
+                // Read the file, downloading it from remote sources
+                let content = reader.read(&file).await;
+                let (file, decompressed_size, records) = match preparer.prepare(file, content) {
+                    PreparedFile::Duplicate => {
+                        stats.files_skipped += 1;
+                        batch.files_skipped += 1;
+                        continue;
+                    }
+                    PreparedFile::Failed(error) => {
+                        stats.add_error(error);
                         batch.increment_errors();
                         continue;
                     }
+                    PreparedFile::Parsed {
+                        file,
+                        decompressed_size,
+                        records,
+                    } => (file, decompressed_size, records),
                 };
 
                 // Add records to batch
                 for record in records {
+                    if let Some(errors) = record.rejection {
+                        rejected_batch.push((
+                            file_path_str.clone(),
+                            record.index,
+                            record.partition,
+                            record.json,
+                            errors,
+                        ));
+                        stats.records_rejected += 1;
+                        continue;
+                    }
+                    let partition = record.partition;
+                    if config.split_by.is_some() {
+                        *stats
+                            .partition_counts
+                            .entry(partition.clone().unwrap_or_else(|| "<none>".to_string()))
+                            .or_default() += 1;
+                    }
+                    if config.record_dedup.is_some() {
+                        dedup_partitions.insert(partition.clone());
+                    }
                     records_batch.push((
                         file_path_str.clone(),
                         record.json,
                         record.index,
-                        config.partition.clone(),
+                        partition,
                         file.content_hash.clone(),
                         file.size,
                     ));
 
                     // Insert batch when full
                     if records_batch.len() >= config.batch_size {
-                        self.insert_records(&records_batch).await?;
+                        self.insert_records(&records_batch, &batch.id).await?;
                         stats.records_ingested += records_batch.len();
                         batch.records_ingested += records_batch.len() as i64;
                         records_batch.clear();
@@ -1202,8 +1732,10 @@ mod postgres_impl {
 
                 stats.files_processed += 1;
                 stats.bytes_processed += file.size;
+                stats.bytes_decompressed += decompressed_size;
                 batch.files_processed += 1;
                 batch.bytes_processed += file.size as i64;
+                batch.bytes_decompressed += decompressed_size as i64;
                 batch.last_file_path = Some(file_path_str);
 
                 // Update batch progress periodically
@@ -1214,11 +1746,22 @@ mod postgres_impl {
 
             // Insert remaining records
             if !records_batch.is_empty() {
-                self.insert_records(&records_batch).await?;
+                self.insert_records(&records_batch, &batch.id).await?;
                 stats.records_ingested += records_batch.len();
                 batch.records_ingested += records_batch.len() as i64;
             }
 
+            // Insert rejected records into the dead-letter table
+            if !rejected_batch.is_empty() {
+                self.insert_dead_letters(&batch.id, &rejected_batch).await?;
+            }
+
+            // Remove duplicate records from the partitions staged into
+            if let Some(ref dedup) = config.record_dedup {
+                stats.records_deduplicated =
+                    self.deduplicate_records(dedup, &dedup_partitions).await?;
+            }
+
             // Complete batch
             batch.complete();
             self.update_batch(&batch).await?;
@@ -1227,6 +1770,50 @@ mod postgres_impl {
             Ok(stats)
         }
     }
+
+    /// Append a row in `COPY` text format: tab-separated fields, `\N` for
+    /// NULL, and backslashes, tabs and line breaks escaped
+    fn copy_row(out: &mut String, fields: &[Option<&str>]) {
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                out.push('\t');
+            }
+            match field {
+                None => out.push_str("\\N"),
+                Some(value) => {
+                    for c in value.chars() {
+                        match c {
+                            '\\' => out.push_str("\\\\"),
+                            '\t' => out.push_str("\\t"),
+                            '\n' => out.push_str("\\n"),
+                            '\r' => out.push_str("\\r"),
+                            c => out.push(c),
+                        }
+                    }
+                }
+            }
+        }
+        out.push('\n');
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_copy_row() {
+            let mut out = String::new();
+            copy_row(
+                &mut out,
+                &[
+                    Some("a.json"),
+                    None,
+                    Some("{\"text\": \"tab\\there\"}\nnext"),
+                ],
+            );
+            assert_eq!(out, "a.json\t\\N\t{\"text\": \"tab\\\\there\"}\\nnext\n");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1309,6 +1896,151 @@ mod tests {
         assert_eq!(stats.records_ingested, 3);
     }
 
+    #[test]
+    fn test_staging_db_ingest_compressed() {
+        let dir = TempDir::new().unwrap();
+        let content = "{\"row\": 1}\n{\"row\": 2}\n";
+
+        let mut gz = flate2::write::GzEncoder::new(
+            File::create(dir.path().join("a.jsonl.gz")).unwrap(),
+            flate2::Compression::default(),
+        );
+        gz.write_all(content.as_bytes()).unwrap();
+        gz.finish().unwrap();
+        std::fs::write(
+            dir.path().join("b.jsonl.zst"),
+            zstd::encode_all(content.as_bytes(), 0).unwrap(),
+        )
+        .unwrap();
+
+        let db = StagingDb::memory().unwrap();
+        db.init().unwrap();
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(dir.path().to_path_buf()))
+            .pattern("*.jsonl.*")
+            .build()
+            .unwrap();
+
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.files_processed, 2);
+        assert_eq!(stats.records_ingested, 4);
+        assert_eq!(stats.bytes_decompressed, 2 * content.len() as u64);
+
+        let batch = &db.list_batches(1).unwrap()[0];
+        assert_eq!(batch.bytes_processed, stats.bytes_processed as i64);
+        assert_eq!(batch.bytes_decompressed, stats.bytes_decompressed as i64);
+    }
+
+    #[test]
+    fn test_staging_db_ingest_parallel() {
+        let dir = TempDir::new().unwrap();
+        for i in 0..40 {
+            std::fs::write(
+                dir.path().join(format!("file_{:02}.jsonl", i)),
+                format!("{{\"file\": {i}, \"row\": 1}}\n{{\"file\": {i}, \"row\": 2}}\n"),
+            )
+            .unwrap();
+        }
+        // Truncated gzip stream
+        std::fs::write(dir.path().join("file_99.jsonl"), [0x1f, 0x8b, 0x08]).unwrap();
+
+        let db = StagingDb::memory().unwrap();
+        db.init().unwrap();
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(dir.path().to_path_buf()))
+            .pattern("*.jsonl")
+            .workers(4)
+            .batch_size(7)
+            .build()
+            .unwrap();
+
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.files_processed, 40);
+        assert_eq!(stats.records_ingested, 80);
+        assert_eq!(stats.errors_count, 1);
+
+        // Records are staged in file order despite being parsed in parallel
+        let rows = db
+            .query("SELECT CAST(raw_json->>'file' AS INTEGER) AS file FROM staged_json ORDER BY id")
+            .unwrap();
+        let files: Vec<i64> = rows.iter().map(|r| r["file"].as_i64().unwrap()).collect();
+        assert!(files.windows(2).all(|w| w[0] <= w[1]));
+
+        let batch = &db.list_batches(1).unwrap()[0];
+        assert!(
+            batch
+                .last_file_path
+                .as_deref()
+                .unwrap()
+                .ends_with("file_39.jsonl")
+        );
+    }
+
+    #[test]
+    fn test_staging_db_purge() {
+        let db = StagingDb::memory().unwrap();
+        db.init().unwrap();
+
+        for (partition, batch_id) in [
+            ("orders", "batch-1"),
+            ("orders/refunds", "batch-2"),
+            ("web_events", "batch-3"),
+            ("webXevents/clicks", "batch-4"),
+        ] {
+            let dir = TempDir::new().unwrap();
+            std::fs::write(
+                dir.path().join("records.jsonl"),
+                "{\"id\": 1}\n{\"id\": 2}\n",
+            )
+            .unwrap();
+            let config = IngestConfig::builder()
+                .source_type(SourceType::Local(dir.path().to_path_buf()))
+                .pattern("*.jsonl")
+                .partition(partition)
+                .batch_id(batch_id)
+                .build()
+                .unwrap();
+            db.ingest(&config).unwrap();
+        }
+        assert_eq!(db.record_count(None).unwrap(), 8);
+
+        // `_` in a partition name is not a wildcard
+        let stats = db
+            .purge(&PurgePolicy::Partition("web_events".to_string()))
+            .unwrap();
+        assert_eq!(stats.records_deleted, 2);
+        assert_eq!(db.record_count(Some("webXevents/clicks")).unwrap(), 2);
+        db.purge(&PurgePolicy::Partition("webXevents".to_string()))
+            .unwrap();
+        assert_eq!(db.record_count(None).unwrap(), 4);
+
+        let stats = db
+            .purge(&PurgePolicy::OlderThan(std::time::Duration::from_secs(
+                3600,
+            )))
+            .unwrap();
+        assert_eq!(stats.total(), 0);
+
+        let stats = db
+            .purge(&PurgePolicy::Batch("batch-1".to_string()))
+            .unwrap();
+        assert_eq!(stats.records_deleted, 2);
+        assert_eq!(stats.batches_deleted, 1);
+        assert_eq!(db.record_count(Some("orders/refunds")).unwrap(), 2);
+
+        // Purging a partition includes its sub-partitions
+        let stats = db
+            .purge(&PurgePolicy::Partition("orders".to_string()))
+            .unwrap();
+        assert_eq!(stats.records_deleted, 2);
+        assert_eq!(db.record_count(None).unwrap(), 0);
+        assert!(db.list_batches(10).unwrap().is_empty());
+
+        db.vacuum().unwrap();
+    }
+
     #[test]
     fn test_staging_db_dedup_by_path() {
         let dir = TempDir::new().unwrap();
@@ -1338,6 +2070,140 @@ mod tests {
         assert_eq!(stats2.files_skipped, 1);
     }
 
+    #[test]
+    fn test_staging_db_ingest_split_by() {
+        let dir = TempDir::new().unwrap();
+
+        let file = dir.path().join("events.jsonl");
+        let mut f = File::create(&file).unwrap();
+        writeln!(f, r#"{{"event_type": "created", "id": 1}}"#).unwrap();
+        writeln!(f, r#"{{"event_type": "shipped", "id": 2}}"#).unwrap();
+        writeln!(f, r#"{{"event_type": "created", "id": 3}}"#).unwrap();
+        writeln!(f, r#"{{"id": 4}}"#).unwrap();
+
+        let db = StagingDb::memory().unwrap();
+        db.init().unwrap();
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(dir.path().to_path_buf()))
+            .pattern("*.jsonl")
+            .partition("events")
+            .split_by("$.event_type")
+            .build()
+            .unwrap();
+
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.records_ingested, 4);
+        assert_eq!(stats.partition_counts.get("events/created"), Some(&2));
+        assert_eq!(stats.partition_counts.get("events/shipped"), Some(&1));
+        assert_eq!(stats.partition_counts.get("events"), Some(&1));
+
+        assert_eq!(db.record_count(Some("events/created")).unwrap(), 2);
+        assert_eq!(db.record_count(Some("events/shipped")).unwrap(), 1);
+        assert_eq!(db.record_count(Some("events")).unwrap(), 1);
+
+        // Re-ingesting skips files already split into sub-partitions
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.files_skipped, 1);
+        assert_eq!(stats.records_ingested, 0);
+
+        // `_` is not a wildcard, so the sub-partitions of `events` are not `even_s`'s
+        assert_eq!(db.get_existing_paths(Some("events"), true).unwrap().len(), 1);
+        assert!(db.get_existing_paths(Some("even_s"), true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_staging_db_record_dedup() {
+        let dir = TempDir::new().unwrap();
+
+        let file = dir.path().join("events-1.jsonl");
+        let mut f = File::create(&file).unwrap();
+        writeln!(f, r#"{{"event_id": "a", "status": "created"}}"#).unwrap();
+        writeln!(f, r#"{{"event_id": "b", "status": "created"}}"#).unwrap();
+        writeln!(f, r#"{{"event_id": "a", "status": "created"}}"#).unwrap();
+
+        let db = StagingDb::memory().unwrap();
+        db.init().unwrap();
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(dir.path().to_path_buf()))
+            .pattern("*.jsonl")
+            .record_dedup("content".parse().unwrap())
+            .build()
+            .unwrap();
+
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.records_ingested, 3);
+        assert_eq!(stats.records_deduplicated, 1);
+        assert_eq!(db.record_count(None).unwrap(), 2);
+
+        // A re-delivery in a later file replaces the staged record when keeping the last
+        let file = dir.path().join("events-2.jsonl");
+        let mut f = File::create(&file).unwrap();
+        writeln!(f, r#"{{"event_id": "a", "status": "shipped"}}"#).unwrap();
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(dir.path().to_path_buf()))
+            .pattern("*.jsonl")
+            .record_dedup("$.event_id:last".parse().unwrap())
+            .build()
+            .unwrap();
+
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.files_skipped, 1);
+        assert_eq!(stats.records_deduplicated, 1);
+        assert_eq!(db.record_count(None).unwrap(), 2);
+
+        let rows = db
+            .query("SELECT json_extract_string(raw_json, '$.status') AS status FROM staged_json WHERE json_extract_string(raw_json, '$.event_id') = 'a'")
+            .unwrap();
+        assert_eq!(rows, vec![serde_json::json!({"status": "shipped"})]);
+    }
+
+    #[cfg(feature = "schema-validation")]
+    #[test]
+    fn test_staging_db_record_schema() {
+        let dir = TempDir::new().unwrap();
+
+        let file = dir.path().join("events.jsonl");
+        let mut f = File::create(&file).unwrap();
+        writeln!(f, r#"{{"event_id": "a"}}"#).unwrap();
+        writeln!(f, r#"{{"event_id": 2}}"#).unwrap();
+        writeln!(f, r#"{{"event_id": "c"}}"#).unwrap();
+        writeln!(f, r#"{{"event_id": "d"}}"#).unwrap();
+
+        let schema_dir = TempDir::new().unwrap();
+        let schema = schema_dir.path().join("event.schema.json");
+        std::fs::write(
+            &schema,
+            r#"{"type": "object", "required": ["event_id"], "properties": {"event_id": {"type": "string"}}}"#,
+        )
+        .unwrap();
+
+        let db = StagingDb::memory().unwrap();
+        db.init().unwrap();
+
+        let config = IngestConfig::builder()
+            .source_type(SourceType::Local(dir.path().to_path_buf()))
+            .pattern("*.jsonl")
+            .batch_id("validated")
+            .record_schema(&schema)
+            .build()
+            .unwrap();
+
+        let stats = db.ingest(&config).unwrap();
+        assert_eq!(stats.records_ingested, 3);
+        assert_eq!(stats.records_rejected, 1);
+        assert_eq!(stats.conformance_rate(), 0.75);
+        assert_eq!(db.record_count(None).unwrap(), 3);
+        assert_eq!(db.dead_letter_count(Some("validated")).unwrap(), 1);
+
+        let rows = db
+            .query("SELECT record_index, raw_json FROM dead_letter_records")
+            .unwrap();
+        assert_eq!(rows[0]["record_index"], 1);
+    }
+
     #[test]
     fn test_staging_db_batch_tracking() {
         let db = StagingDb::memory().unwrap();
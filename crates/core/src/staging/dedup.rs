@@ -0,0 +1,67 @@
+//! Record-level deduplication of staged records
+//!
+//! Builds one `DELETE` per [`RecordDedup`] and backend that leaves a single record
+//! per key within a partition. Records are ordered by id, which follows ingestion
+//! order, so keeping the first or last record keeps the earliest or latest
+//! delivery. When the partition key is not null, the statement takes it as its
+//! first bind parameter.
+
+use super::config::{DedupKeep, RecordDedup, RecordKey};
+use super::sampling::field_expression;
+use super::schema::SqlDialect;
+
+/// SQL deleting the duplicate records of one partition
+pub fn dedup_query(dedup: &RecordDedup, dialect: SqlDialect, partitioned: bool) -> String {
+    let key = match &dedup.key {
+        RecordKey::ContentHash => match dialect {
+            SqlDialect::DuckDb => "md5(CAST(raw_json AS VARCHAR))".to_string(),
+            SqlDialect::Postgres => "md5(raw_json::text)".to_string(),
+        },
+        RecordKey::Path(path) => field_expression(path, dialect),
+    };
+    let filter = match (partitioned, dialect) {
+        (false, _) => "partition_key IS NULL",
+        (true, SqlDialect::DuckDb) => "partition_key = ?1",
+        (true, SqlDialect::Postgres) => "partition_key = $1",
+    };
+    let order = match dedup.keep {
+        DedupKeep::First => "ASC",
+        DedupKeep::Last => "DESC",
+    };
+
+    format!(
+        "DELETE FROM staged_json WHERE id IN (SELECT id FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY {key} ORDER BY id {order}) AS rn \
+         FROM staged_json WHERE {filter} AND {key} IS NOT NULL) d WHERE rn > 1)",
+        key = key,
+        order = order,
+        filter = filter
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_query() {
+        let by_content = RecordDedup {
+            key: RecordKey::ContentHash,
+            keep: DedupKeep::First,
+        };
+        assert_eq!(
+            dedup_query(&by_content, SqlDialect::DuckDb, true),
+            "DELETE FROM staged_json WHERE id IN (SELECT id FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY md5(CAST(raw_json AS VARCHAR)) ORDER BY id ASC) AS rn \
+             FROM staged_json WHERE partition_key = ?1 AND md5(CAST(raw_json AS VARCHAR)) IS NOT NULL) d WHERE rn > 1)"
+        );
+
+        let by_path = RecordDedup {
+            key: RecordKey::Path("$.event.id".to_string()),
+            keep: DedupKeep::Last,
+        };
+        assert_eq!(
+            dedup_query(&by_path, SqlDialect::Postgres, false),
+            "DELETE FROM staged_json WHERE id IN (SELECT id FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY (raw_json #>> '{\"event\",\"id\"}') ORDER BY id DESC) AS rn \
+             FROM staged_json WHERE partition_key IS NULL AND (raw_json #>> '{\"event\",\"id\"}') IS NOT NULL) d WHERE rn > 1)"
+        );
+    }
+}
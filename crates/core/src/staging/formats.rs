@@ -0,0 +1,788 @@
+//! Record formats for staging ingestion
+//!
+//! Landing zones often mix formats under the same prefix, and file extensions are
+//! missing or wrong as often as not. [`RecordParserRegistry`] sniffs the leading bytes
+//! of each file (byte order mark, magic bytes, first characters) and hands it to the
+//! most confident [`RecordParser`]:
+//!
+//! - **avro** - Avro object container files (`Obj\x01` magic), one record per datum
+//! - **xml** - each child element of the root element is a record; attributes and
+//!   child elements become fields, repeated elements arrays, and the text of an
+//!   element with attributes is stored under `value`
+//! - **ndjson** - one JSON value per line
+//! - **json** - the whole document is one record
+//! - **csv** - delimiter and header row are detected as by the CSV importer; cells
+//!   become JSON scalars keyed by column name
+//!
+//! Text formats may be UTF-8, with or without a byte order mark, or UTF-16 with a
+//! byte order mark. [`RecordParserRegistry::parse_file`] decompresses gzip and
+//! zstd files first (see [`decompress`](super::decompress)).
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::staging::RecordParserRegistry;
+//!
+//! let registry = RecordParserRegistry::default();
+//! assert_eq!(registry.detect(b"id,name\n1,alice\n").unwrap().0, "csv");
+//!
+//! let records = registry.parse_file(std::path::Path::new("landing/events.dat"))?;
+//! ```
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use serde_json::{Map, Value};
+
+use super::avro_ocf;
+use super::compression::decompress;
+use super::error::IngestError;
+use super::ingest::ParsedRecord;
+use crate::import::csv::{
+    cell_value, column_names, detect_delimiter, detect_header, parse_records,
+};
+use crate::validation::XmlRecordSchema;
+
+/// Number of leading bytes passed to [`RecordParser::sniff`]
+pub const SNIFF_BYTES: usize = 64 * 1024;
+
+/// Minimum confidence required for a parser to be chosen
+pub const MIN_SNIFF_CONFIDENCE: f32 = 0.3;
+
+/// Number of rows inspected when sniffing delimited text
+const CSV_SNIFF_ROWS: usize = 20;
+
+/// Built-in parsers, shared by [`parse_file`](super::ingest::parse_file)
+pub(crate) static DEFAULT_PARSERS: Lazy<RecordParserRegistry> =
+    Lazy::new(RecordParserRegistry::default);
+
+/// A record format that can be registered with a [`RecordParserRegistry`]
+pub trait RecordParser: Send + Sync {
+    /// Unique format name (e.g., "ndjson", "avro")
+    fn name(&self) -> &str;
+
+    /// Confidence (0.0 - 1.0) that this parser can handle a file starting with `head`
+    fn sniff(&self, head: &[u8]) -> f32;
+
+    /// Split the content of the file at `path` into JSON records
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError>;
+}
+
+/// Registry of record parsers, keyed by format name
+///
+/// `RecordParserRegistry::default()` contains the built-in formats; use
+/// [`RecordParserRegistry::new`] for an empty registry.
+#[derive(Clone)]
+pub struct RecordParserRegistry {
+    parsers: BTreeMap<String, Arc<dyn RecordParser>>,
+}
+
+impl RecordParserRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            parsers: BTreeMap::new(),
+        }
+    }
+
+    /// Register a parser, replacing any existing parser with the same name
+    pub fn register<P: RecordParser + 'static>(&mut self, parser: P) -> &mut Self {
+        self.register_arc(Arc::new(parser))
+    }
+
+    /// Register a shared parser, replacing any existing parser with the same name
+    pub fn register_arc(&mut self, parser: Arc<dyn RecordParser>) -> &mut Self {
+        self.parsers.insert(normalize_name(parser.name()), parser);
+        self
+    }
+
+    /// Look up a parser by name (case-insensitive)
+    pub fn get(&self, name: &str) -> Option<Arc<dyn RecordParser>> {
+        self.parsers.get(&normalize_name(name)).cloned()
+    }
+
+    /// Names of all registered formats, sorted
+    pub fn names(&self) -> Vec<String> {
+        self.parsers.keys().cloned().collect()
+    }
+
+    /// Score every registered parser against the leading bytes, best match first
+    pub fn rank(&self, head: &[u8]) -> Vec<(String, f32)> {
+        let head = &head[..head.len().min(SNIFF_BYTES)];
+        let mut scores: Vec<(String, f32)> = self
+            .parsers
+            .iter()
+            .map(|(name, parser)| (name.clone(), parser.sniff(head).clamp(0.0, 1.0)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scores
+    }
+
+    /// Detect the most likely format of a file starting with `head`
+    pub fn detect(&self, head: &[u8]) -> Option<(String, f32)> {
+        self.rank(head)
+            .into_iter()
+            .next()
+            .filter(|(_, score)| *score >= MIN_SNIFF_CONFIDENCE)
+    }
+
+    /// Detect the format of file content and split it into records
+    ///
+    /// Empty files (or files containing only whitespace) yield no records.
+    pub fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+        if content.iter().all(u8::is_ascii_whitespace) {
+            return Ok(Vec::new());
+        }
+        let (format, _) = self
+            .detect(content)
+            .ok_or_else(|| IngestError::InvalidFormat {
+                path: path.to_path_buf(),
+                reason: format!(
+                    "could not detect the record format (supported: {})",
+                    self.names().join(", ")
+                ),
+            })?;
+        let parser = self
+            .get(&format)
+            .ok_or_else(|| IngestError::InvalidFormat {
+                path: path.to_path_buf(),
+                reason: format!("unknown record format: {}", format),
+            })?;
+        parser.parse(path, content)
+    }
+
+    /// Read a file, decompress it if needed, detect its format and split it into
+    /// records
+    pub fn parse_file(&self, path: &Path) -> Result<Vec<ParsedRecord>, IngestError> {
+        let content = fs::read(path)?;
+        self.parse(path, &decompress(path, &content)?)
+    }
+}
+
+impl Default for RecordParserRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(JsonParser)
+            .register(NdjsonParser)
+            .register(CsvParser)
+            .register(XmlParser::new())
+            .register(AvroParser);
+        registry
+    }
+}
+
+impl std::fmt::Debug for RecordParserRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordParserRegistry")
+            .field("formats", &self.names())
+            .finish()
+    }
+}
+
+/// Decode text content, honouring UTF-8 and UTF-16 byte order marks
+pub fn decode_text<'a>(path: &Path, content: &'a [u8]) -> Result<Cow<'a, str>, IngestError> {
+    let invalid = |reason: String| IngestError::InvalidFormat {
+        path: path.to_path_buf(),
+        reason,
+    };
+    if let Some(utf16) = utf16_units(content) {
+        return String::from_utf16(&utf16)
+            .map(Cow::Owned)
+            .map_err(|e| invalid(format!("invalid UTF-16 text: {}", e)));
+    }
+    let content = content.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(content);
+    std::str::from_utf8(content)
+        .map(Cow::Borrowed)
+        .map_err(|e| invalid(format!("invalid UTF-8 text: {}", e)))
+}
+
+/// Code units of UTF-16 content with a byte order mark
+fn utf16_units(content: &[u8]) -> Option<Vec<u16>> {
+    let from_bytes: fn([u8; 2]) -> u16 = match content.get(..2)? {
+        [0xFF, 0xFE] => u16::from_le_bytes,
+        [0xFE, 0xFF] => u16::from_be_bytes,
+        _ => return None,
+    };
+    Some(
+        content[2..]
+            .chunks_exact(2)
+            .map(|pair| from_bytes([pair[0], pair[1]]))
+            .collect(),
+    )
+}
+
+/// Leading text of a file for sniffing, or None for binary content
+///
+/// A multi-byte character cut off at the end of the sample is dropped.
+fn text_head(head: &[u8]) -> Option<Cow<'_, str>> {
+    if let Some(utf16) = utf16_units(head) {
+        return Some(Cow::Owned(String::from_utf16_lossy(&utf16)));
+    }
+    let head = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head);
+    let valid = match std::str::from_utf8(head) {
+        Ok(text) => text,
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&head[..e.valid_up_to()]).ok()?,
+        Err(_) => return None,
+    };
+    (!valid.contains('\0')).then_some(Cow::Borrowed(valid))
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn json_error(path: &Path, record: usize, error: impl ToString) -> IngestError {
+    IngestError::JsonParse {
+        path: path.to_path_buf(),
+        record,
+        error: error.to_string(),
+    }
+}
+
+fn record(json: &Value, index: usize) -> ParsedRecord {
+    ParsedRecord {
+        json: json.to_string(),
+        index,
+    }
+}
+
+/// A whole JSON document as one record
+struct JsonParser;
+
+impl RecordParser for JsonParser {
+    fn name(&self) -> &str {
+        "json"
+    }
+
+    fn sniff(&self, head: &[u8]) -> f32 {
+        match text_head(head).and_then(|text| text.trim_start().chars().next()) {
+            Some('{' | '[') => 0.6,
+            _ => 0.0,
+        }
+    }
+
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+        let text = decode_text(path, content)?;
+        serde_json::from_str::<Value>(&text).map_err(|e| json_error(path, 0, e))?;
+        Ok(vec![ParsedRecord {
+            json: text.trim().to_string(),
+            index: 0,
+        }])
+    }
+}
+
+/// One JSON value per line
+struct NdjsonParser;
+
+impl RecordParser for NdjsonParser {
+    fn name(&self) -> &str {
+        "ndjson"
+    }
+
+    fn sniff(&self, head: &[u8]) -> f32 {
+        let Some(text) = text_head(head) else {
+            return 0.0;
+        };
+        // Only lines completely inside a truncated sample can be checked
+        let truncated = head.len() >= SNIFF_BYTES;
+        let mut lines = text
+            .split_inclusive('\n')
+            .filter(|line| !truncated || line.ends_with('\n'))
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+        let Some(first) = lines.next() else {
+            return 0.0;
+        };
+        if !first.starts_with(['{', '[']) || serde_json::from_str::<Value>(first).is_err() {
+            return 0.0;
+        }
+        // A single line is just as likely a plain JSON document
+        match lines.next() {
+            Some(second) if second.starts_with(['{', '[']) => 0.9,
+            _ => 0.5,
+        }
+    }
+
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+        let text = decode_text(path, content)?;
+        let mut records = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            serde_json::from_str::<Value>(trimmed).map_err(|e| json_error(path, index, e))?;
+            records.push(ParsedRecord {
+                json: trimmed.to_string(),
+                index,
+            });
+        }
+        Ok(records)
+    }
+}
+
+/// Delimited text with an optional header row
+struct CsvParser;
+
+impl RecordParser for CsvParser {
+    fn name(&self) -> &str {
+        "csv"
+    }
+
+    fn sniff(&self, head: &[u8]) -> f32 {
+        let Some(text) = text_head(head) else {
+            return 0.0;
+        };
+        if text.trim_start().starts_with(['{', '[', '<']) {
+            return 0.0;
+        }
+        // Drop a row cut off at the end of the sample
+        let text = match text.rfind('\n') {
+            Some(end) if head.len() >= SNIFF_BYTES => &text[..end],
+            _ => &text[..],
+        };
+        let delimiter = detect_delimiter(text);
+        let rows = parse_records(text, delimiter, Some(CSV_SNIFF_ROWS));
+        let Some(width) = rows.first().map(Vec::len).filter(|width| *width > 1) else {
+            return 0.0;
+        };
+        let consistent = rows.iter().filter(|row| row.len() == width).count();
+        if consistent * 5 >= rows.len() * 4 {
+            0.5
+        } else {
+            0.0
+        }
+    }
+
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+        let text = decode_text(path, content)?;
+        let delimiter = detect_delimiter(&text);
+        let rows = parse_records(&text, delimiter, None);
+        let has_header = detect_header(&rows);
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let (names, rows) = if has_header {
+            (column_names(&rows[0], width), &rows[1..])
+        } else {
+            (column_names(&[], width), &rows[..])
+        };
+
+        Ok(rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let fields: Map<String, Value> = names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| {
+                        let cell = row.get(i).map(String::as_str).unwrap_or("");
+                        (name.clone(), cell_value(cell))
+                    })
+                    .collect();
+                record(&Value::Object(fields), index)
+            })
+            .collect())
+    }
+}
+
+/// XML documents, with one record per child element of the root
+///
+/// With an [`XmlRecordSchema`], records declared by the XSD are conformed to it;
+/// other elements are converted by their content alone.
+#[derive(Debug, Default)]
+pub struct XmlParser {
+    schema: Option<XmlRecordSchema>,
+}
+
+impl XmlParser {
+    /// XML parser converting elements to JSON by their content alone
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// XML parser conforming records to the structure declared by an XSD, so that
+    /// every record has the same keys and value types
+    pub fn with_schema(schema: XmlRecordSchema) -> Self {
+        Self {
+            schema: Some(schema),
+        }
+    }
+
+    fn record_json(&self, root: &str, element: &Element) -> Value {
+        let value = element.to_json();
+        match self
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.record_shape(root, &element.name))
+        {
+            Some(shape) => shape.conform(&value),
+            None => value,
+        }
+    }
+}
+
+impl RecordParser for XmlParser {
+    fn name(&self) -> &str {
+        "xml"
+    }
+
+    fn sniff(&self, head: &[u8]) -> f32 {
+        let Some(text) = text_head(head) else {
+            return 0.0;
+        };
+        let text = text.trim_start();
+        if text.starts_with("<?xml") {
+            0.95
+        } else if text
+            .strip_prefix('<')
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_alphabetic() || c == '!')
+        {
+            0.7
+        } else {
+            0.0
+        }
+    }
+
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+        let text = decode_text(path, content)?;
+        let root = parse_element_tree(&text).map_err(|reason| IngestError::InvalidFormat {
+            path: path.to_path_buf(),
+            reason,
+        })?;
+        // A root without child elements is itself the only record
+        if root.children.is_empty() {
+            return Ok(vec![record(&self.record_json(&root.name, &root), 0)]);
+        }
+        Ok(root
+            .children
+            .iter()
+            .enumerate()
+            .map(|(index, child)| record(&self.record_json(&root.name, child), index))
+            .collect())
+    }
+}
+
+/// An element of an XML document
+#[derive(Debug, Default)]
+pub(crate) struct Element {
+    /// Local name, without namespace prefix
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<Element>,
+    pub(crate) text: String,
+}
+
+impl Element {
+    fn from_start(start: &BytesStart<'_>) -> Result<Self, String> {
+        let mut element = Element {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).to_string(),
+            ..Default::default()
+        };
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| format!("invalid XML attribute: {}", e))?;
+            // Namespace declarations are not data
+            let key = attribute.key;
+            if key.as_ref() == b"xmlns" || key.prefix().is_some_and(|p| p.as_ref() == b"xmlns") {
+                continue;
+            }
+            element.attributes.push((
+                String::from_utf8_lossy(key.local_name().as_ref()).to_string(),
+                attribute
+                    .unescape_value()
+                    .map_err(|e| format!("invalid XML attribute value: {}", e))?
+                    .to_string(),
+            ));
+        }
+        Ok(element)
+    }
+
+    /// JSON value of the element: text for simple elements, an object otherwise
+    pub(crate) fn to_json(&self) -> Value {
+        if self.attributes.is_empty() && self.children.is_empty() {
+            return Value::String(self.text.clone());
+        }
+        let mut object = Map::new();
+        for (name, value) in &self.attributes {
+            object.insert(name.clone(), Value::String(value.clone()));
+        }
+        let mut repeated: HashMap<&str, usize> = HashMap::new();
+        for child in &self.children {
+            *repeated.entry(child.name.as_str()).or_default() += 1;
+        }
+        for child in &self.children {
+            let value = child.to_json();
+            if repeated[child.name.as_str()] > 1 {
+                // An attribute with the same name as the element wins
+                if let Value::Array(values) = object
+                    .entry(child.name.clone())
+                    .or_insert_with(|| Value::Array(Vec::new()))
+                {
+                    values.push(value);
+                }
+            } else {
+                object.entry(child.name.clone()).or_insert(value);
+            }
+        }
+        if !self.text.is_empty() {
+            object
+                .entry("value".to_string())
+                .or_insert(Value::String(self.text.clone()));
+        }
+        Value::Object(object)
+    }
+}
+
+/// Parse an XML document into a tree of elements, returning the root element
+pub(crate) fn parse_element_tree(content: &str) -> Result<Element, String> {
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+
+    let mut stack: Vec<Element> = Vec::new();
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| format!("invalid XML: {}", e))?
+        {
+            Event::Start(start) => stack.push(Element::from_start(&start)?),
+            Event::Empty(start) => {
+                let element = Element::from_start(&start)?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => return Ok(element),
+                }
+            }
+            Event::End(_) => {
+                let element = stack
+                    .pop()
+                    .ok_or_else(|| "unbalanced XML end tag".to_string())?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => return Ok(element),
+                }
+            }
+            Event::Text(text) => {
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(
+                        &text
+                            .unescape()
+                            .map_err(|e| format!("invalid XML text: {}", e))?,
+                    );
+                }
+            }
+            Event::CData(data) => {
+                if let Some(element) = stack.last_mut() {
+                    element
+                        .text
+                        .push_str(&String::from_utf8_lossy(&data.into_inner()));
+                }
+            }
+            Event::Eof => return Err("XML document has no root element".to_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Avro object container files
+struct AvroParser;
+
+impl RecordParser for AvroParser {
+    fn name(&self) -> &str {
+        "avro"
+    }
+
+    fn sniff(&self, head: &[u8]) -> f32 {
+        if head.starts_with(avro_ocf::MAGIC) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+        let values =
+            avro_ocf::read_records(content).map_err(|reason| IngestError::InvalidFormat {
+                path: path.to_path_buf(),
+                reason,
+            })?;
+        Ok(values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| record(value, index))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(content: &[u8]) -> (String, Vec<Value>) {
+        let registry = RecordParserRegistry::default();
+        let format = registry.detect(content).unwrap().0;
+        let records = registry
+            .parse(Path::new("landing/file"), content)
+            .unwrap()
+            .iter()
+            .map(|r| serde_json::from_str(&r.json).unwrap())
+            .collect();
+        (format, records)
+    }
+
+    #[test]
+    fn test_detects_json_formats() {
+        let (format, records) = parse(b"\xEF\xBB\xBF{\"id\": 1,\n \"name\": \"a\"}\n");
+        assert_eq!(format, "json");
+        assert_eq!(records, [json!({"id": 1, "name": "a"})]);
+
+        let (format, records) = parse(b"{\"id\": 1}\n\n{\"id\": 2}\n");
+        assert_eq!(format, "ndjson");
+        assert_eq!(records, [json!({"id": 1}), json!({"id": 2})]);
+    }
+
+    #[test]
+    fn test_detects_csv() {
+        let (format, records) = parse(b"id;name;active\n1;\"Smith; J\";true\n2;Lee;false\n");
+        assert_eq!(format, "csv");
+        assert_eq!(
+            records,
+            [
+                json!({"id": 1, "name": "Smith; J", "active": true}),
+                json!({"id": 2, "name": "Lee", "active": false}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detects_utf16_xml() {
+        let xml = r#"<?xml version="1.0"?>
+<orders xmlns="urn:example">
+  <order id="1"><item>a</item><item>b</item><total currency="EUR">9.5</total></order>
+  <order id="2"><item>c</item></order>
+</orders>"#;
+        let mut content = vec![0xFF, 0xFE];
+        content.extend(xml.encode_utf16().flat_map(u16::to_le_bytes));
+
+        let (format, records) = parse(&content);
+        assert_eq!(format, "xml");
+        assert_eq!(
+            records,
+            [
+                json!({"id": "1", "item": ["a", "b"], "total": {"currency": "EUR", "value": "9.5"}}),
+                json!({"id": "2", "item": "c"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_conformed_to_xsd() {
+        let xsd = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+  <xs:element name="orders">
+    <xs:complexType>
+      <xs:sequence>
+        <xs:element name="order" maxOccurs="unbounded">
+          <xs:complexType>
+            <xs:sequence>
+              <xs:element name="item" type="xs:string" maxOccurs="unbounded"/>
+              <xs:element name="total" type="xs:decimal" minOccurs="0"/>
+            </xs:sequence>
+            <xs:attribute name="id" type="xs:int" use="required"/>
+          </xs:complexType>
+        </xs:element>
+      </xs:sequence>
+    </xs:complexType>
+  </xs:element>
+</xs:schema>"#;
+        let xml = br#"<orders>
+  <order id="1"><item>a</item><item>b</item><total>9.5</total></order>
+  <order id="2"><item>c</item></order>
+</orders>"#;
+
+        let mut registry = RecordParserRegistry::default();
+        registry.register(XmlParser::with_schema(
+            XmlRecordSchema::from_xsd(xsd).unwrap(),
+        ));
+        let records: Vec<Value> = registry
+            .parse(Path::new("orders.xml"), xml)
+            .unwrap()
+            .iter()
+            .map(|r| serde_json::from_str(&r.json).unwrap())
+            .collect();
+        assert_eq!(
+            records,
+            [
+                json!({"item": ["a", "b"], "total": 9.5, "id": 1}),
+                json!({"item": ["c"], "total": null, "id": 2}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detects_avro() {
+        use super::avro_ocf::tests::{EVENT_SCHEMA, container, encode_event};
+
+        let mut block = Vec::new();
+        encode_event(7, "x", 0, None, &mut block);
+        let (format, records) = parse(&container(EVENT_SCHEMA, 1, &block));
+        assert_eq!(format, "avro");
+        assert_eq!(
+            records,
+            [json!({"id": 7, "tags": ["x"], "kind": "CLICK", "note": null})]
+        );
+    }
+
+    #[test]
+    fn test_custom_parser_and_unknown_content() {
+        struct Lines;
+        impl RecordParser for Lines {
+            fn name(&self) -> &str {
+                "lines"
+            }
+            fn sniff(&self, head: &[u8]) -> f32 {
+                if head.starts_with(b"#lines") {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            fn parse(&self, _: &Path, content: &[u8]) -> Result<Vec<ParsedRecord>, IngestError> {
+                Ok(String::from_utf8_lossy(content)
+                    .lines()
+                    .skip(1)
+                    .enumerate()
+                    .map(|(index, line)| record(&json!({ "line": line }), index))
+                    .collect())
+            }
+        }
+
+        let mut registry = RecordParserRegistry::default();
+        assert!(matches!(
+            registry.parse(Path::new("x"), b"#lines\nhello"),
+            Err(IngestError::InvalidFormat { .. })
+        ));
+        registry.register(Lines);
+        let records = registry.parse(Path::new("x"), b"#lines\nhello").unwrap();
+        assert_eq!(records[0].json, r#"{"line":"hello"}"#);
+        assert!(registry.parse(Path::new("x"), b"  \n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_file_decompresses() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.dat.zst");
+        let content = zstd::encode_all(&b"id,name\n1,alice\n2,bob\n"[..], 0).unwrap();
+        fs::write(&path, content).unwrap();
+
+        let records = RecordParserRegistry::default().parse_file(&path).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].json, r#"{"id":2,"name":"bob"}"#);
+    }
+}
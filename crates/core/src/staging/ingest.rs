@@ -3,6 +3,7 @@
 //! This module provides parallel file discovery, parsing, and ingestion
 //! using rayon for CPU-bound operations.
 
+use std::collections::{BTreeMap, HashSet};
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -12,8 +13,11 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use super::config::DedupStrategy;
+use super::compression::decompress;
+use super::config::{DedupStrategy, IngestConfig, SourceType};
+use super::conformance::RecordValidator;
 use super::error::IngestError;
+use super::formats::{DEFAULT_PARSERS, RecordParserRegistry};
 
 /// Statistics from an ingestion run
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -25,12 +29,26 @@ pub struct IngestStats {
     pub files_skipped: usize,
     /// Number of records ingested
     pub records_ingested: usize,
-    /// Total bytes processed
+    /// Number of staged records removed as duplicates by record-level
+    /// deduplication
+    #[serde(default)]
+    pub records_deduplicated: usize,
+    /// Number of records that failed validation and went to the dead-letter table
+    #[serde(default)]
+    pub records_rejected: usize,
+    /// Total bytes processed, as read from the source (compressed)
     pub bytes_processed: u64,
+    /// Total bytes processed after decompression (equal to `bytes_processed`
+    /// when no file was compressed)
+    #[serde(default)]
+    pub bytes_decompressed: u64,
     /// Number of errors encountered
     pub errors_count: usize,
     /// List of errors (limited to first 100)
     pub errors: Vec<String>,
+    /// Records ingested per partition (populated when splitting records)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub partition_counts: BTreeMap<String, usize>,
     /// Duration of the ingestion
     #[serde(skip)]
     pub duration: Duration,
@@ -60,6 +78,18 @@ impl IngestStats {
         }
     }
 
+    /// Share of validated records that conformed to the record schema
+    ///
+    /// 1.0 when no record was rejected.
+    pub fn conformance_rate(&self) -> f64 {
+        let validated = self.records_ingested + self.records_rejected;
+        if validated == 0 {
+            1.0
+        } else {
+            self.records_ingested as f64 / validated as f64
+        }
+    }
+
     /// Format duration as human-readable string
     pub fn duration_string(&self) -> String {
         let secs = self.duration.as_secs();
@@ -103,6 +133,12 @@ impl DiscoveredFile {
         }
         Ok(self.content_hash.as_ref().unwrap())
     }
+
+    /// Compute and cache the content hash from content already read
+    pub fn hash_content(&mut self, content: &[u8]) -> &str {
+        self.content_hash
+            .get_or_insert_with(|| format!("{:x}", Sha256::digest(content)))
+    }
 }
 
 /// Discover files matching a pattern in a local directory
@@ -144,6 +180,91 @@ pub fn discover_local_files(
     Ok(files)
 }
 
+/// Reads the files of an ingestion source
+pub(crate) enum SourceReader {
+    /// Local files
+    Local,
+    /// S3 objects, downloaded as they are read
+    #[cfg(feature = "s3")]
+    S3(super::s3::S3Ingester),
+}
+
+impl SourceReader {
+    /// Connect to a source and discover the files matching a pattern
+    pub(crate) async fn open(
+        source: &SourceType,
+        pattern: &str,
+    ) -> Result<(Self, Vec<DiscoveredFile>), IngestError> {
+        match source {
+            SourceType::Local(path) => Ok((Self::Local, discover_local_files(path, pattern)?)),
+            #[cfg(feature = "s3")]
+            SourceType::S3 { bucket, prefix } => {
+                let ingester =
+                    super::s3::S3Ingester::new(super::s3::S3Source::new(bucket, prefix)).await?;
+                let files = ingester.discover_files(pattern).await?;
+                Ok((Self::S3(ingester), files))
+            }
+            #[cfg(feature = "databricks")]
+            SourceType::UnityVolume { .. } => Err(IngestError::SourceNotAccessible {
+                path: source.display(),
+                reason: "Unity Catalog ingestion not yet implemented".to_string(),
+            }),
+        }
+    }
+
+    /// Read the content of a discovered file
+    pub(crate) async fn read(&self, file: &DiscoveredFile) -> Result<Vec<u8>, IngestError> {
+        match self {
+            Self::Local => Ok(fs::read(&file.path)?),
+            #[cfg(feature = "s3")]
+            Self::S3(ingester) => ingester.download(&file.path).await,
+        }
+    }
+}
+
+/// Runtime driving a remote source during a synchronous ingest
+///
+/// Inside a multi-threaded Tokio runtime the caller's runtime is used, with
+/// [`tokio::task::block_in_place`] around every wait; outside of one a runtime
+/// is created for the duration of the ingest. A current-thread runtime cannot
+/// be blocked on, so remote sources cannot be ingested synchronously from one.
+#[cfg(feature = "duckdb-backend")]
+pub(crate) enum SourceRuntime {
+    /// Runtime created for the ingest
+    Owned(tokio::runtime::Runtime),
+    /// The caller's multi-threaded runtime
+    Caller(tokio::runtime::Handle),
+}
+
+#[cfg(feature = "duckdb-backend")]
+impl SourceRuntime {
+    /// Use the caller's runtime, or create one outside of a runtime
+    pub(crate) fn new() -> Result<Self, IngestError> {
+        use tokio::runtime::{Builder, Handle, RuntimeFlavor};
+
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                Ok(Self::Caller(handle))
+            }
+            Ok(_) => Err(IngestError::Io(std::io::Error::other(
+                "remote sources cannot be ingested synchronously inside a current-thread \
+                 Tokio runtime; use a multi-threaded runtime",
+            ))),
+            Err(_) => Ok(Self::Owned(
+                Builder::new_multi_thread().enable_all().build()?,
+            )),
+        }
+    }
+
+    /// Run a future to completion, blocking the current thread
+    pub(crate) fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        match self {
+            Self::Owned(runtime) => runtime.block_on(future),
+            Self::Caller(handle) => tokio::task::block_in_place(|| handle.block_on(future)),
+        }
+    }
+}
+
 /// Parsed JSON record from a file
 #[derive(Debug)]
 pub struct ParsedRecord {
@@ -201,27 +322,12 @@ pub fn parse_jsonl_file(path: &Path) -> Result<Vec<ParsedRecord>, IngestError> {
     Ok(records)
 }
 
-/// Parse a file based on its extension
+/// Parse a file into records, detecting its format from the content
+///
+/// JSON, NDJSON, CSV, XML and Avro object container files are recognised
+/// regardless of their extension; see [`RecordParserRegistry`] to add formats.
 pub fn parse_file(path: &Path) -> Result<Vec<ParsedRecord>, IngestError> {
-    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-
-    match extension.to_lowercase().as_str() {
-        "jsonl" | "ndjson" => parse_jsonl_file(path),
-        "json" => parse_json_file(path),
-        _ => {
-            // Try to detect format from content
-            let content = fs::read_to_string(path)?;
-            let trimmed = content.trim();
-
-            // If it starts with '[' or '{', treat as single JSON
-            if trimmed.starts_with('[') || trimmed.starts_with('{') {
-                parse_json_file(path)
-            } else {
-                // Try JSONL
-                parse_jsonl_file(path)
-            }
-        }
-    }
+    DEFAULT_PARSERS.parse_file(path)
 }
 
 /// Check if a file should be skipped based on dedup strategy
@@ -253,6 +359,167 @@ pub fn should_skip_file(
     }
 }
 
+/// Extract a discriminator value from a JSON record
+///
+/// Supports simple dot-separated JSONPath expressions such as `$.event_type`
+/// or `$.meta.kind` (the leading `$.` is optional). String values are returned
+/// as-is; numbers and booleans are stringified. Missing, null, object and array
+/// values yield `None`.
+pub fn extract_discriminator(record: &serde_json::Value, path: &str) -> Option<String> {
+    let path = path.trim();
+    let path = path
+        .strip_prefix("$.")
+        .or_else(|| path.strip_prefix('$'))
+        .unwrap_or(path);
+
+    let mut current = record;
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        current = current.get(segment)?;
+    }
+
+    match current {
+        serde_json::Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Resolve the partition a record should be staged into
+///
+/// When `split_by` is set and the record carries a discriminator value, the
+/// record is routed to `{partition}/{value}` (or just `{value}` without a base
+/// partition). Records without a discriminator stay in the base partition.
+pub fn resolve_record_partition(
+    json: &str,
+    partition: Option<&str>,
+    split_by: Option<&str>,
+) -> Option<String> {
+    let discriminator = split_by.and_then(|path| {
+        serde_json::from_str::<serde_json::Value>(json)
+            .ok()
+            .and_then(|value| extract_discriminator(&value, path))
+    });
+
+    match (partition, discriminator) {
+        (Some(base), Some(value)) => Some(format!("{}/{}", base, value.replace('/', "_"))),
+        (None, Some(value)) => Some(value.replace('/', "_")),
+        (base, None) => base.map(|s| s.to_string()),
+    }
+}
+
+/// A record ready to be written by the staging writer
+#[derive(Debug)]
+pub(crate) struct PreparedRecord {
+    /// Record index within the file (0-based)
+    pub index: usize,
+    /// The raw JSON string
+    pub json: String,
+    /// Partition the record is staged into
+    pub partition: Option<String>,
+    /// Validation errors (as a JSON array) if the record was rejected
+    pub rejection: Option<String>,
+}
+
+/// Outcome of preparing a file for staging
+#[derive(Debug)]
+pub(crate) enum PreparedFile {
+    /// Content already staged; the file is skipped
+    Duplicate,
+    /// The file could not be read, decompressed or parsed
+    Failed(String),
+    /// The file's records, ready to write
+    Parsed {
+        file: DiscoveredFile,
+        decompressed_size: u64,
+        records: Vec<PreparedRecord>,
+    },
+}
+
+/// Hashes, decompresses, parses, partitions and validates files
+///
+/// Preparing a file needs no database access, so ingestion prepares files on
+/// a pool of workers while a single writer stages the results in order.
+pub(crate) struct FilePreparer<'a> {
+    pub config: &'a IngestConfig,
+    pub parsers: &'a RecordParserRegistry,
+    pub validator: Option<&'a RecordValidator>,
+    pub existing_paths: &'a HashSet<String>,
+    pub existing_hashes: &'a HashSet<String>,
+}
+
+impl FilePreparer<'_> {
+    /// Prepare a file from its raw content (or the error reading it)
+    pub fn prepare(
+        &self,
+        mut file: DiscoveredFile,
+        content: Result<Vec<u8>, IngestError>,
+    ) -> PreparedFile {
+        let path = file.path.display().to_string();
+        let content = match content {
+            Ok(c) => c,
+            Err(e) => return PreparedFile::Failed(format!("Error reading {}: {}", path, e)),
+        };
+
+        // Compute hash if needed for dedup
+        if matches!(
+            self.config.dedup,
+            DedupStrategy::ByContent | DedupStrategy::Both
+        ) {
+            file.hash_content(&content);
+        }
+        if should_skip_file(
+            &file,
+            self.config.dedup,
+            self.existing_paths,
+            self.existing_hashes,
+        ) {
+            return PreparedFile::Duplicate;
+        }
+
+        // Decompress gzip and zstd files
+        let content = match decompress(&file.path, &content) {
+            Ok(c) => c,
+            Err(e) => {
+                return PreparedFile::Failed(format!("Error decompressing {}: {}", path, e));
+            }
+        };
+        let records = match self.parsers.parse(&file.path, &content) {
+            Ok(r) => r,
+            Err(e) => return PreparedFile::Failed(format!("Error parsing {}: {}", path, e)),
+        };
+
+        let records = records
+            .into_iter()
+            .map(|record| {
+                let partition = resolve_record_partition(
+                    &record.json,
+                    self.config.partition.as_deref(),
+                    self.config.split_by.as_deref(),
+                );
+                let rejection = self.validator.and_then(|validator| {
+                    validator
+                        .validate(&record.json)
+                        .err()
+                        .map(|errors| serde_json::Value::from(errors).to_string())
+                });
+                PreparedRecord {
+                    index: record.index,
+                    json: record.json,
+                    partition,
+                    rejection,
+                }
+            })
+            .collect();
+
+        PreparedFile::Parsed {
+            file,
+            decompressed_size: content.len() as u64,
+            records,
+        }
+    }
+}
+
 /// Result of parsing a single file in parallel
 #[derive(Debug)]
 pub struct ParsedFile {
@@ -273,10 +540,18 @@ pub struct ParsedFile {
 /// # Returns
 /// A vector of ParsedFile results, one for each input file
 pub fn parse_files_parallel(files: Vec<DiscoveredFile>) -> Vec<ParsedFile> {
+    parse_files_parallel_with(files, &DEFAULT_PARSERS)
+}
+
+/// Parse multiple files in parallel with the formats of a parser registry
+pub fn parse_files_parallel_with(
+    files: Vec<DiscoveredFile>,
+    parsers: &RecordParserRegistry,
+) -> Vec<ParsedFile> {
     files
         .into_par_iter()
         .map(|file| {
-            let records = parse_file(&file.path);
+            let records = parsers.parse_file(&file.path);
             ParsedFile { file, records }
         })
         .collect()
@@ -720,6 +995,10 @@ mod tests {
 
         assert_eq!(stats.throughput(), 100.0);
         assert_eq!(stats.duration_string(), "10s");
+        assert_eq!(stats.conformance_rate(), 1.0);
+
+        stats.records_rejected = 250;
+        assert_eq!(stats.conformance_rate(), 0.8);
     }
 
     #[test]
@@ -763,6 +1042,54 @@ mod tests {
         assert_eq!(records[2].index, 2);
     }
 
+    #[test]
+    fn test_extract_discriminator() {
+        let record = serde_json::json!({
+            "event_type": "order_created",
+            "version": 2,
+            "meta": {"kind": "audit"},
+            "empty": null
+        });
+
+        assert_eq!(
+            extract_discriminator(&record, "$.event_type"),
+            Some("order_created".to_string())
+        );
+        assert_eq!(
+            extract_discriminator(&record, "meta.kind"),
+            Some("audit".to_string())
+        );
+        assert_eq!(
+            extract_discriminator(&record, "$.version"),
+            Some("2".to_string())
+        );
+        assert_eq!(extract_discriminator(&record, "$.empty"), None);
+        assert_eq!(extract_discriminator(&record, "$.missing"), None);
+        assert_eq!(extract_discriminator(&record, "$.meta"), None);
+    }
+
+    #[test]
+    fn test_resolve_record_partition() {
+        let json = r#"{"event_type": "a/b"}"#;
+
+        assert_eq!(
+            resolve_record_partition(json, Some("events"), Some("$.event_type")),
+            Some("events/a_b".to_string())
+        );
+        assert_eq!(
+            resolve_record_partition(json, None, Some("$.event_type")),
+            Some("a_b".to_string())
+        );
+        assert_eq!(
+            resolve_record_partition(json, Some("events"), Some("$.kind")),
+            Some("events".to_string())
+        );
+        assert_eq!(
+            resolve_record_partition(json, Some("events"), None),
+            Some("events".to_string())
+        );
+    }
+
     #[test]
     fn test_discovered_file_hash() {
         let dir = TempDir::new().unwrap();
@@ -0,0 +1,542 @@
+//! Primary and foreign key discovery over staged records
+//!
+//! Profiles the fields of staged partitions before contracts are generated:
+//!
+//! 1. [`profile_query`] counts the records, nulls and distinct values of every
+//!    field of a partition, giving a [`PartitionProfile`]. Fields that are never
+//!    null and never repeat are candidate primary keys.
+//! 2. [`KeyProfiler::candidate_dependencies`] pairs the fields of each partition
+//!    with the candidate keys of the others, and [`inclusion_query`] counts how
+//!    many distinct values of the field occur in the key. Fields whose values
+//!    are (almost) all included in a key are suggested as foreign keys.
+//!
+//! Fields are addressed by dot path (e.g. `customer.id`); [`field_paths`] lists
+//! the scalar fields of an inferred schema. Unpartitioned records are profiled
+//! as the partition `None`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::sampling::field_expression;
+use super::schema::SqlDialect;
+use crate::inference::{InferredSchema, InferredType};
+
+/// Default share of a field's distinct values that must occur in a key
+pub const DEFAULT_MIN_COVERAGE: f64 = 0.95;
+
+/// Default number of distinct values a field needs to be considered a foreign key
+pub const DEFAULT_MIN_DISTINCT: u64 = 2;
+
+/// Counts of one field in a partition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldProfile {
+    /// Dot path of the field
+    pub path: String,
+    /// Records in the partition
+    pub record_count: u64,
+    /// Records where the field was missing or null
+    pub null_count: u64,
+    /// Distinct non-null values
+    pub distinct_count: u64,
+}
+
+impl FieldProfile {
+    /// Share of records where the field is missing or null
+    pub fn null_ratio(&self) -> f64 {
+        if self.record_count == 0 {
+            0.0
+        } else {
+            self.null_count as f64 / self.record_count as f64
+        }
+    }
+
+    /// Distinct values per non-null value; 1.0 when no value repeats
+    pub fn uniqueness(&self) -> f64 {
+        let non_null = self.record_count.saturating_sub(self.null_count);
+        if non_null == 0 {
+            0.0
+        } else {
+            self.distinct_count as f64 / non_null as f64
+        }
+    }
+
+    /// Whether the field is never null and never repeats
+    pub fn is_candidate_key(&self) -> bool {
+        self.record_count > 0 && self.null_count == 0 && self.distinct_count == self.record_count
+    }
+}
+
+/// Field profiles of one staged partition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionProfile {
+    /// Partition key, `None` for unpartitioned records
+    pub partition: Option<String>,
+    /// Records in the partition
+    pub record_count: u64,
+    /// Profile of every field, in the order they were requested
+    pub fields: Vec<FieldProfile>,
+}
+
+impl PartitionProfile {
+    /// Build a profile from the row returned by [`profile_query`]
+    pub fn from_row(partition: Option<&str>, fields: &[String], row: &Value) -> Self {
+        let count = |column: String| row.get(&column).and_then(Value::as_u64).unwrap_or(0);
+        let record_count = count("record_count".to_string());
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(i, path)| FieldProfile {
+                path: path.clone(),
+                record_count,
+                null_count: record_count.saturating_sub(count(format!("non_null_{}", i))),
+                distinct_count: count(format!("distinct_{}", i)),
+            })
+            .collect();
+        Self {
+            partition: partition.map(str::to_string),
+            record_count,
+            fields,
+        }
+    }
+
+    /// Fields that are never null and never repeat
+    pub fn candidate_keys(&self) -> Vec<&FieldProfile> {
+        self.fields
+            .iter()
+            .filter(|field| field.is_candidate_key())
+            .collect()
+    }
+
+    /// The candidate key to suggest as primary key
+    ///
+    /// Prefers `id`, then fields ending in `id`, then the shallowest path.
+    pub fn primary_key(&self) -> Option<&FieldProfile> {
+        self.candidate_keys().into_iter().min_by_key(|field| {
+            let name = field.path.rsplit('.').next().unwrap_or(&field.path);
+            let lower = name.to_lowercase();
+            let rank = if lower == "id" {
+                0
+            } else if lower.ends_with("id") {
+                1
+            } else {
+                2
+            };
+            (rank, field.path.matches('.').count())
+        })
+    }
+}
+
+/// A field whose values are included in a key of another partition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionDependency {
+    /// Partition of the referencing field
+    pub from_partition: Option<String>,
+    /// Referencing field
+    pub from_field: String,
+    /// Partition of the referenced key
+    pub to_partition: Option<String>,
+    /// Referenced key
+    pub to_field: String,
+    /// Distinct non-null values of the referencing field
+    pub distinct_values: u64,
+    /// Of those, values found in the referenced key
+    pub matched_values: u64,
+}
+
+impl InclusionDependency {
+    /// Share of the referencing field's distinct values found in the key
+    pub fn coverage(&self) -> f64 {
+        if self.distinct_values == 0 {
+            0.0
+        } else {
+            self.matched_values as f64 / self.distinct_values as f64
+        }
+    }
+
+    /// Fill in the counts from the row returned by [`inclusion_query`]
+    pub fn apply_row(&mut self, row: &Value) {
+        let count = |column: &str| row.get(column).and_then(Value::as_u64).unwrap_or(0);
+        self.distinct_values = count("distinct_values");
+        self.matched_values = count("matched_values");
+    }
+}
+
+/// Result of key discovery
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyDiscovery {
+    /// Profiles of the staged partitions
+    pub partitions: Vec<PartitionProfile>,
+    /// Suggested foreign keys, best coverage first
+    pub foreign_keys: Vec<InclusionDependency>,
+}
+
+impl KeyDiscovery {
+    /// Profile of a partition
+    pub fn partition(&self, partition: Option<&str>) -> Option<&PartitionProfile> {
+        self.partitions
+            .iter()
+            .find(|profile| profile.partition.as_deref() == partition)
+    }
+
+    /// Render the discovered keys as a Markdown report
+    pub fn to_markdown(&self) -> String {
+        let name =
+            |partition: &Option<String>| partition.as_deref().unwrap_or("<none>").to_string();
+        let mut md = String::from("# Key Discovery\n\n## Primary Keys\n\n");
+        md.push_str("| Partition | Records | Primary key | Candidate keys |\n");
+        md.push_str("|-----------|---------|-------------|----------------|\n");
+        for profile in &self.partitions {
+            let candidates: Vec<&str> = profile
+                .candidate_keys()
+                .iter()
+                .map(|field| field.path.as_str())
+                .collect();
+            md.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                name(&profile.partition),
+                profile.record_count,
+                profile
+                    .primary_key()
+                    .map_or("-", |field| field.path.as_str()),
+                if candidates.is_empty() {
+                    "-".to_string()
+                } else {
+                    candidates.join(", ")
+                }
+            ));
+        }
+
+        md.push_str("\n## Foreign Keys\n\n");
+        if self.foreign_keys.is_empty() {
+            md.push_str("No foreign keys found.\n");
+            return md;
+        }
+        md.push_str("| From | To | Coverage |\n");
+        md.push_str("|------|----|----------|\n");
+        for fk in &self.foreign_keys {
+            md.push_str(&format!(
+                "| {}.{} | {}.{} | {:.1}% ({}/{}) |\n",
+                name(&fk.from_partition),
+                fk.from_field,
+                name(&fk.to_partition),
+                fk.to_field,
+                fk.coverage() * 100.0,
+                fk.matched_values,
+                fk.distinct_values
+            ));
+        }
+        md
+    }
+}
+
+/// Key discovery settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyProfiler {
+    min_coverage: f64,
+    min_distinct: u64,
+}
+
+impl Default for KeyProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyProfiler {
+    /// Create a profiler with the default thresholds
+    pub fn new() -> Self {
+        Self {
+            min_coverage: DEFAULT_MIN_COVERAGE,
+            min_distinct: DEFAULT_MIN_DISTINCT,
+        }
+    }
+
+    /// Share of distinct values that must occur in the key (0.0 to 1.0)
+    pub fn with_min_coverage(mut self, min_coverage: f64) -> Self {
+        self.min_coverage = min_coverage.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Distinct values a field needs before it is checked as a foreign key
+    pub fn with_min_distinct(mut self, min_distinct: u64) -> Self {
+        self.min_distinct = min_distinct;
+        self
+    }
+
+    /// Field and key pairs worth checking for inclusion, with zero counts
+    ///
+    /// Pairs each field with the candidate keys of the other partitions that
+    /// have at least as many distinct values.
+    pub fn candidate_dependencies(
+        &self,
+        profiles: &[PartitionProfile],
+    ) -> Vec<InclusionDependency> {
+        let mut dependencies = Vec::new();
+        for from in profiles {
+            for field in &from.fields {
+                if field.distinct_count < self.min_distinct {
+                    continue;
+                }
+                for to in profiles.iter().filter(|to| to.partition != from.partition) {
+                    for key in to.candidate_keys() {
+                        if key.distinct_count < field.distinct_count {
+                            continue;
+                        }
+                        dependencies.push(InclusionDependency {
+                            from_partition: from.partition.clone(),
+                            from_field: field.path.clone(),
+                            to_partition: to.partition.clone(),
+                            to_field: key.path.clone(),
+                            distinct_values: 0,
+                            matched_values: 0,
+                        });
+                    }
+                }
+            }
+        }
+        dependencies
+    }
+
+    /// Combine profiles and counted dependencies into the discovery result
+    ///
+    /// Keeps the dependencies that reach the minimum coverage.
+    pub fn discover(
+        &self,
+        partitions: Vec<PartitionProfile>,
+        dependencies: Vec<InclusionDependency>,
+    ) -> KeyDiscovery {
+        let mut foreign_keys: Vec<InclusionDependency> = dependencies
+            .into_iter()
+            .filter(|fk| fk.distinct_values >= self.min_distinct)
+            .filter(|fk| fk.coverage() >= self.min_coverage)
+            .collect();
+        foreign_keys.sort_by(|a, b| {
+            b.coverage()
+                .total_cmp(&a.coverage())
+                .then(b.distinct_values.cmp(&a.distinct_values))
+        });
+        KeyDiscovery {
+            partitions,
+            foreign_keys,
+        }
+    }
+
+    /// Profile staged partitions and discover their keys
+    ///
+    /// # Arguments
+    /// * `db` - Staging database
+    /// * `fields` - Each partition with the dot paths of its fields, e.g. from
+    ///   [`field_paths`] of the partition's inferred schema
+    #[cfg(feature = "duckdb-backend")]
+    pub fn profile_staged(
+        &self,
+        db: &super::StagingDb,
+        fields: &[(Option<String>, Vec<String>)],
+    ) -> Result<KeyDiscovery, super::StagingError> {
+        let mut profiles = Vec::new();
+        for (partition, paths) in fields {
+            let sql = profile_query(paths, partition.as_deref(), SqlDialect::DuckDb);
+            let rows = db.query(&sql)?;
+            let row = rows.first().cloned().unwrap_or(Value::Null);
+            profiles.push(PartitionProfile::from_row(
+                partition.as_deref(),
+                paths,
+                &row,
+            ));
+        }
+
+        let mut dependencies = self.candidate_dependencies(&profiles);
+        for dependency in &mut dependencies {
+            let sql = inclusion_query(dependency, SqlDialect::DuckDb);
+            if let Some(row) = db.query(&sql)?.first() {
+                dependency.apply_row(row);
+            }
+        }
+        Ok(self.discover(profiles, dependencies))
+    }
+}
+
+/// Dot paths of the scalar fields of an inferred schema
+///
+/// Arrays and their contents are skipped, as their values cannot be keys.
+pub fn field_paths(schema: &InferredSchema) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_paths(&schema.root, "", &mut paths);
+    paths
+}
+
+fn collect_paths(inferred: &InferredType, prefix: &str, paths: &mut Vec<String>) {
+    if let InferredType::Object { properties } = inferred {
+        for (name, field) in properties {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            match &field.field_type {
+                InferredType::Object { .. } => collect_paths(&field.field_type, &path, paths),
+                InferredType::Array { .. } | InferredType::Null | InferredType::Unknown => {}
+                _ => paths.push(path),
+            }
+        }
+    }
+}
+
+/// SQL counting the records, non-null values and distinct values of fields
+///
+/// Returns one row with `record_count`, and `non_null_{i}` and `distinct_{i}`
+/// for the i-th field.
+pub fn profile_query(fields: &[String], partition: Option<&str>, dialect: SqlDialect) -> String {
+    let mut columns = vec!["COUNT(*) AS record_count".to_string()];
+    for (i, path) in fields.iter().enumerate() {
+        let expression = field_expression(path, dialect);
+        columns.push(format!("COUNT({}) AS non_null_{}", expression, i));
+        columns.push(format!("COUNT(DISTINCT {}) AS distinct_{}", expression, i));
+    }
+    format!(
+        "SELECT {} FROM staged_json WHERE {}",
+        columns.join(", "),
+        partition_filter(partition)
+    )
+}
+
+/// SQL counting the distinct values of a dependency's field and how many of
+/// them occur in the referenced key
+///
+/// Returns one row with `distinct_values` and `matched_values`.
+pub fn inclusion_query(dependency: &InclusionDependency, dialect: SqlDialect) -> String {
+    format!(
+        "WITH fk AS (SELECT DISTINCT {} AS v FROM staged_json WHERE {}), \
+         pk AS (SELECT DISTINCT {} AS v FROM staged_json WHERE {}) \
+         SELECT (SELECT COUNT(*) FROM fk WHERE v IS NOT NULL) AS distinct_values, \
+         (SELECT COUNT(*) FROM fk JOIN pk ON fk.v = pk.v) AS matched_values",
+        field_expression(&dependency.from_field, dialect),
+        partition_filter(dependency.from_partition.as_deref()),
+        field_expression(&dependency.to_field, dialect),
+        partition_filter(dependency.to_partition.as_deref()),
+    )
+}
+
+fn partition_filter(partition: Option<&str>) -> String {
+    match partition {
+        Some(partition) => format!("partition_key = '{}'", partition.replace('\'', "''")),
+        None => "partition_key IS NULL".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn field(path: &str, record_count: u64, null_count: u64, distinct_count: u64) -> FieldProfile {
+        FieldProfile {
+            path: path.to_string(),
+            record_count,
+            null_count,
+            distinct_count,
+        }
+    }
+
+    fn profiles() -> Vec<PartitionProfile> {
+        vec![
+            PartitionProfile {
+                partition: Some("customers".to_string()),
+                record_count: 100,
+                fields: vec![
+                    field("email", 100, 0, 100),
+                    field("id", 100, 0, 100),
+                    field("country", 100, 0, 12),
+                ],
+            },
+            PartitionProfile {
+                partition: Some("orders".to_string()),
+                record_count: 500,
+                fields: vec![
+                    field("order_id", 500, 0, 500),
+                    field("customer_id", 500, 5, 80),
+                    field("status", 500, 0, 1),
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_partition_profile_flags_candidate_keys() {
+        let fields = vec!["id".to_string(), "customer.id".to_string()];
+        let row = json!({
+            "record_count": 10,
+            "non_null_0": 10, "distinct_0": 10,
+            "non_null_1": 8, "distinct_1": 4,
+        });
+        let profile = PartitionProfile::from_row(Some("orders"), &fields, &row);
+        assert_eq!(profile.fields[1].null_count, 2);
+        assert!((profile.fields[1].null_ratio() - 0.2).abs() < 1e-9);
+        assert!((profile.fields[1].uniqueness() - 0.5).abs() < 1e-9);
+        assert_eq!(profile.candidate_keys().len(), 1);
+
+        let profiles = profiles();
+        assert_eq!(profiles[0].primary_key().unwrap().path, "id");
+        assert_eq!(profiles[1].primary_key().unwrap().path, "order_id");
+    }
+
+    #[test]
+    fn test_dependencies_filtered_by_coverage() {
+        let profiler = KeyProfiler::new();
+        let profiles = profiles();
+        let mut dependencies = profiler.candidate_dependencies(&profiles);
+        // Fields are paired with the other partition's keys that have enough values;
+        // `status` has too few distinct values to be checked
+        assert!(dependencies.iter().all(|fk| fk.from_field != "status"));
+        assert!(!dependencies.iter().any(|fk| fk.from_field == "order_id"));
+
+        for dependency in &mut dependencies {
+            let matched = match (dependency.from_field.as_str(), dependency.to_field.as_str()) {
+                ("customer_id", "id") => 80,
+                ("customer_id", "email") => 0,
+                _ => 1,
+            };
+            dependency.apply_row(&json!({"distinct_values": 80, "matched_values": matched}));
+        }
+        let discovery = profiler.discover(profiles, dependencies);
+        assert_eq!(discovery.foreign_keys.len(), 1);
+        let fk = &discovery.foreign_keys[0];
+        assert_eq!(
+            (
+                fk.from_field.as_str(),
+                fk.to_partition.as_deref(),
+                fk.to_field.as_str()
+            ),
+            ("customer_id", Some("customers"), "id")
+        );
+        let report = discovery.to_markdown();
+        assert!(report.contains("| orders | 500 | order_id | order_id |"));
+        assert!(report.contains("| orders.customer_id | customers.id | 100.0% (80/80) |"));
+    }
+
+    #[test]
+    fn test_queries() {
+        let fields = vec!["customer.id".to_string()];
+        assert_eq!(
+            profile_query(&fields, Some("o'rders"), SqlDialect::DuckDb),
+            "SELECT COUNT(*) AS record_count, COUNT(json_extract_string(raw_json, '$.\"customer\".\"id\"')) AS non_null_0, \
+             COUNT(DISTINCT json_extract_string(raw_json, '$.\"customer\".\"id\"')) AS distinct_0 \
+             FROM staged_json WHERE partition_key = 'o''rders'"
+        );
+        let dependency = InclusionDependency {
+            from_partition: Some("orders".to_string()),
+            from_field: "customer_id".to_string(),
+            to_partition: None,
+            to_field: "id".to_string(),
+            distinct_values: 0,
+            matched_values: 0,
+        };
+        let sql = inclusion_query(&dependency, SqlDialect::Postgres);
+        assert!(sql.contains("SELECT DISTINCT (raw_json #>> '{\"customer_id\"}') AS v FROM staged_json WHERE partition_key = 'orders'"));
+        assert!(sql.contains("FROM staged_json WHERE partition_key IS NULL"));
+    }
+}
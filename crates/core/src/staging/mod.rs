@@ -6,9 +6,29 @@
 //! ## Features
 //!
 //! - **Large dataset handling** - Process millions of records without loading into memory
-//! - **Deduplication** - Skip already-ingested files by path or content hash
+//! - **Deduplication** - Skip already-ingested files by path or content hash, and
+//!   optionally remove duplicate records by content or a key JSONPath
 //! - **Batch tracking** - Resume interrupted ingestions
+//! - **Record splitting** - Route multi-type records into sub-partitions by a discriminator
+//! - **Format detection** - JSON, NDJSON, CSV, XML and Avro files are recognised by
+//!   content, and further formats can be plugged in with [`RecordParser`]
+//! - **Compressed files** - gzip and zstd files are decompressed transparently
+//! - **XSD-guided XML** - XML records can be conformed to an XSD so every record has
+//!   the same keys and value types
+//! - **Record validation** - Records can be validated against a contract or JSON Schema
+//!   while staging, with failures routed to a dead-letter table
 //! - **SQL queries** - Analyze staged data before export
+//! - **Key discovery** - Profile null ratios and uniqueness of staged fields to flag
+//!   candidate primary keys, and suggest foreign keys from inclusion dependencies
+//!   between partitions
+//! - **Benchmarks** - Compare ingest and query throughput of backends, batch sizes
+//!   and deduplication strategies on a synthetic workload
+//! - **Data profiling** - Per-field distinct counts, null rates, ranges, top values,
+//!   length distributions and histograms as a serializable [`ProfileReport`]
+//! - **Parquet export** - Memory-bounded, batched export of staged records projected
+//!   onto an ODCS schema object
+//! - **Retention** - Purge staged data by partition, batch or age with [`PurgePolicy`],
+//!   and vacuum the database afterwards
 //!
 //! ## Example
 //!
@@ -32,31 +52,51 @@
 //! println!("Ingested {} records from {} files", stats.records_ingested, stats.files_processed);
 //! ```
 
+mod avro_ocf;
 mod batch;
+mod bench;
 #[cfg(feature = "iceberg")]
 pub mod catalog;
+mod compression;
 mod config;
+mod conformance;
 mod db;
+mod dedup;
 mod error;
 #[cfg(feature = "iceberg")]
 pub mod export;
+mod formats;
 #[cfg(feature = "iceberg")]
 pub mod iceberg_table;
 mod ingest;
+mod keys;
+mod parquet;
+mod profile;
 #[cfg(feature = "staging")]
 pub mod progress;
+mod purge;
 #[cfg(feature = "s3")]
 pub mod s3;
+mod sampling;
 mod schema;
 #[cfg(feature = "databricks")]
 pub mod unity;
 
 pub use batch::{BatchStatus, ProcessingBatch};
+pub use bench::{
+    BENCH_PARTITION_PREFIX, BenchBackend, BenchReport, BenchResult, DEFAULT_BENCH_BATCH_SIZES,
+    DEFAULT_BENCH_FILES, DEFAULT_BENCH_RECORDS, StagingBench, Workload, bench_queries, dedup_name,
+    recommend,
+};
 #[cfg(feature = "iceberg")]
 pub use catalog::{
     CatalogConfig, CatalogError, CatalogOperations, IcebergCatalog, TableIdentifier, TableInfo,
 };
-pub use config::{DedupStrategy, IngestConfig, IngestConfigBuilder, SourceType};
+pub use compression::{Compression, decompress};
+pub use config::{
+    DedupKeep, DedupStrategy, IngestConfig, IngestConfigBuilder, RecordDedup, RecordKey, SourceType,
+};
+pub use conformance::RecordValidator;
 #[cfg(feature = "duckdb-backend")]
 pub use db::StagingDb;
 #[cfg(feature = "postgres-backend")]
@@ -64,17 +104,38 @@ pub use db::StagingDbPostgres;
 pub use error::{IngestError, StagingError};
 #[cfg(feature = "iceberg")]
 pub use export::{ExportConfig, ExportResult, ExportTarget};
+pub use formats::{
+    MIN_SNIFF_CONFIDENCE, RecordParser, RecordParserRegistry, SNIFF_BYTES, XmlParser, decode_text,
+};
 #[cfg(feature = "iceberg")]
 pub use iceberg_table::{
     BatchMetadata as IcebergBatchMetadata, BatchStatus as IcebergBatchStatus, IcebergTable,
 };
 pub use ingest::{
     DiscoveredFile, IngestStats, ParallelBatchProcessor, ParsedFile, ParsedRecord,
-    StreamingJsonlReader, compute_hashes_parallel, parse_files_parallel,
+    StreamingJsonlReader, compute_hashes_parallel, extract_discriminator, parse_file,
+    parse_files_parallel, parse_files_parallel_with, parse_json_file, parse_jsonl_file,
+    resolve_record_partition,
 };
 #[cfg(feature = "iceberg")]
 pub use ingest::{IcebergIngestConfig, ingest_to_iceberg, ingest_to_iceberg_with_config};
-pub use schema::StagingSchema;
+pub use keys::{
+    FieldProfile, InclusionDependency, KeyDiscovery, KeyProfiler, PartitionProfile, field_paths,
+    inclusion_query, profile_query,
+};
+pub use parquet::{
+    DEFAULT_EXPORT_MEMORY_BYTES, ExportBatchConfig, ExportBatchProgress, MAX_EXPORT_BATCH_ROWS,
+    MIN_EXPORT_BATCH_ROWS, ParquetExportResult, copy_statement,
+};
+#[cfg(feature = "duckdb-backend")]
+pub use parquet::{export_staged_to_parquet, export_staged_to_parquet_with_progress};
+pub use profile::{
+    DEFAULT_HISTOGRAM_BUCKETS, DEFAULT_TOP_K, FieldStatistics, HistogramBucket, LengthDistribution,
+    PROFILE_PAGE_SIZE, ProfileBuilder, ProfileOptions, ProfileReport,
+};
+pub use purge::{PurgePolicy, PurgeStats, parse_age};
+pub use sampling::sample_query;
+pub use schema::{SCHEMA_VERSION, SqlDialect, StagingSchema};
 
 #[cfg(feature = "staging")]
 pub use progress::{InferenceProgress, IngestProgress, Spinner, format_bytes, format_number};
@@ -0,0 +1,350 @@
+//! Memory-bounded export of staged records to Parquet
+//!
+//! Staged records are projected onto the typed columns of an ODCS schema object
+//! (see [`StagingView`]) and written in batches, one Parquet file per batch, so
+//! tables far larger than memory can be exported. Batches are cut on the staged
+//! record id, and their size is tuned from the raw JSON size of the records so
+//! each batch stays within the configured memory budget. A record larger than
+//! the whole budget is still exported, alone in its batch.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::staging::{ExportBatchConfig, StagingDb, export_staged_to_parquet_with_progress};
+//!
+//! let db = StagingDb::open("staging.duckdb")?;
+//! let config = ExportBatchConfig::new("./export/orders")
+//!     .with_partition("orders")
+//!     .with_max_memory_bytes(256 * 1024 * 1024);
+//! let result = export_staged_to_parquet_with_progress(&db, &schema, &config, |p| {
+//!     println!("batch {}: {}/{} records", p.batch, p.records_exported, p.total_records);
+//! })?;
+//! println!("Wrote {} files", result.files.len());
+//! ```
+
+use std::path::PathBuf;
+
+#[cfg(feature = "duckdb-backend")]
+use crate::models::odcs::SchemaObject;
+use crate::quality::{SOURCE_COLUMNS, StagingView};
+
+#[cfg(feature = "duckdb-backend")]
+use super::schema::SqlDialect;
+
+/// Default memory budget of an export (512 MiB)
+pub const DEFAULT_EXPORT_MEMORY_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Smallest batch chosen by auto-tuning; a single record may exceed the budget
+pub const MIN_EXPORT_BATCH_ROWS: usize = 1;
+
+/// Largest batch chosen by auto-tuning
+pub const MAX_EXPORT_BATCH_ROWS: usize = 1_000_000;
+
+/// Estimated working memory per byte of raw JSON (parsed values, column
+/// buffers and Parquet encoding)
+const MEMORY_OVERHEAD_FACTOR: f64 = 4.0;
+
+/// Largest Parquet row group written, DuckDB's default
+const MAX_ROW_GROUP_SIZE: usize = 122_880;
+
+/// Configuration of a batched Parquet export
+#[derive(Debug, Clone)]
+pub struct ExportBatchConfig {
+    /// Directory receiving the Parquet files
+    pub output_dir: PathBuf,
+    /// File name prefix; files are named `<prefix>-00000.parquet`, ...
+    pub file_prefix: String,
+    /// Only export records of this partition
+    pub partition: Option<String>,
+    /// Fixed number of records per batch (`None` tunes it from the memory budget)
+    pub batch_size: Option<usize>,
+    /// Memory budget of the export, in bytes
+    pub max_memory_bytes: u64,
+    /// Also export the `_staged_id`, `_file_path` and `_record_index` columns
+    pub include_source_columns: bool,
+}
+
+impl ExportBatchConfig {
+    /// Export into `output_dir` with an auto-tuned batch size
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            file_prefix: "part".to_string(),
+            partition: None,
+            batch_size: None,
+            max_memory_bytes: DEFAULT_EXPORT_MEMORY_BYTES,
+            include_source_columns: false,
+        }
+    }
+
+    /// Set the file name prefix
+    pub fn with_file_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.file_prefix = prefix.into();
+        self
+    }
+
+    /// Only export records of the given partition
+    pub fn with_partition(mut self, partition: impl Into<String>) -> Self {
+        self.partition = Some(partition.into());
+        self
+    }
+
+    /// Use a fixed number of records per batch instead of auto-tuning
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size.max(1));
+        self
+    }
+
+    /// Set the memory budget, in bytes
+    pub fn with_max_memory_bytes(mut self, bytes: u64) -> Self {
+        self.max_memory_bytes = bytes;
+        self
+    }
+
+    /// Include the staging source columns in the exported files
+    pub fn with_source_columns(mut self, include: bool) -> Self {
+        self.include_source_columns = include;
+        self
+    }
+
+    /// Records in the next batch, given the average raw JSON size of the records
+    /// seen so far
+    pub fn batch_rows(&self, avg_record_bytes: f64) -> usize {
+        if let Some(batch_size) = self.batch_size {
+            return batch_size;
+        }
+        let per_record = (avg_record_bytes * MEMORY_OVERHEAD_FACTOR).max(1.0);
+        let rows = self.max_memory_bytes as f64 / per_record;
+        (rows as usize).clamp(MIN_EXPORT_BATCH_ROWS, MAX_EXPORT_BATCH_ROWS)
+    }
+
+    /// Path of the file written for the given batch
+    pub fn batch_path(&self, batch: usize) -> PathBuf {
+        self.output_dir
+            .join(format!("{}-{:05}.parquet", self.file_prefix, batch))
+    }
+}
+
+/// Progress of an export, reported after each batch
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportBatchProgress {
+    /// Index of the batch just written
+    pub batch: usize,
+    /// Records written by this batch
+    pub batch_records: u64,
+    /// Records written so far
+    pub records_exported: u64,
+    /// Records to export
+    pub total_records: u64,
+    /// Raw JSON bytes of the records written so far
+    pub source_bytes: u64,
+    /// Records in the next batch
+    pub next_batch_size: usize,
+}
+
+/// Result of a batched Parquet export
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParquetExportResult {
+    /// Files written, in batch order
+    pub files: Vec<PathBuf>,
+    /// Number of records written
+    pub records_exported: u64,
+    /// Total size of the written files
+    pub bytes_written: u64,
+}
+
+/// Export staged records to Parquet files, projected onto `schema`
+#[cfg(feature = "duckdb-backend")]
+pub fn export_staged_to_parquet(
+    db: &super::StagingDb,
+    schema: &SchemaObject,
+    config: &ExportBatchConfig,
+) -> Result<ParquetExportResult, super::StagingError> {
+    export_staged_to_parquet_with_progress(db, schema, config, |_| {})
+}
+
+/// Export staged records to Parquet files, calling `on_progress` after each batch
+///
+/// DuckDB's memory limit is lowered to the export's budget while it runs and
+/// restored afterwards.
+#[cfg(feature = "duckdb-backend")]
+pub fn export_staged_to_parquet_with_progress<F>(
+    db: &super::StagingDb,
+    schema: &SchemaObject,
+    config: &ExportBatchConfig,
+    on_progress: F,
+) -> Result<ParquetExportResult, super::StagingError>
+where
+    F: FnMut(&ExportBatchProgress),
+{
+    std::fs::create_dir_all(&config.output_dir)?;
+
+    let previous_limit = db
+        .query("SELECT current_setting('memory_limit') AS memory_limit")?
+        .first()
+        .and_then(|row| row["memory_limit"].as_str().map(str::to_string));
+    db.execute(&format!(
+        "SET memory_limit = '{}MiB'",
+        (config.max_memory_bytes / (1024 * 1024)).max(1)
+    ))?;
+
+    let result = export_batches(db, schema, config, on_progress);
+
+    if let Some(limit) = previous_limit {
+        db.execute(&format!(
+            "SET memory_limit = '{}'",
+            limit.replace('\'', "''")
+        ))?;
+    }
+    result
+}
+
+#[cfg(feature = "duckdb-backend")]
+fn export_batches<F>(
+    db: &super::StagingDb,
+    schema: &SchemaObject,
+    config: &ExportBatchConfig,
+    mut on_progress: F,
+) -> Result<ParquetExportResult, super::StagingError>
+where
+    F: FnMut(&ExportBatchProgress),
+{
+    let number = |row: &serde_json::Value, key: &str| row[key].as_i64().unwrap_or(0);
+
+    let partition = config.partition.as_deref();
+    let view = StagingView::new(schema, SqlDialect::DuckDb, partition);
+    let filter = partition
+        .map(|p| format!(" AND partition_key = '{}'", p.replace('\'', "''")))
+        .unwrap_or_default();
+
+    let stats = db.query(&format!(
+        "SELECT COUNT(*) AS records, CAST(COALESCE(AVG(LENGTH(CAST(raw_json AS VARCHAR))), 0) AS DOUBLE) AS avg_bytes \
+         FROM staged_json WHERE TRUE{}",
+        filter
+    ))?;
+    let stats = stats.first().cloned().unwrap_or_default();
+    let mut progress = ExportBatchProgress {
+        total_records: number(&stats, "records") as u64,
+        next_batch_size: config.batch_rows(stats["avg_bytes"].as_f64().unwrap_or(0.0)),
+        ..Default::default()
+    };
+
+    let mut result = ParquetExportResult::default();
+    let mut last_id = i64::MIN;
+    loop {
+        // Cut the batch on the record id so each one is a cheap index range
+        let bounds = db.query(&format!(
+            "SELECT COUNT(*) AS records, MAX(id) AS last_id, CAST(COALESCE(SUM(LENGTH(CAST(raw_json AS VARCHAR))), 0) AS BIGINT) AS bytes \
+             FROM (SELECT id, raw_json FROM staged_json WHERE id > {}{} ORDER BY id LIMIT {}) b",
+            last_id, filter, progress.next_batch_size
+        ))?;
+        let bounds = bounds.first().cloned().unwrap_or_default();
+        let records = number(&bounds, "records");
+        if records == 0 {
+            break;
+        }
+        let batch_last_id = number(&bounds, "last_id");
+        let batch_bytes = number(&bounds, "bytes");
+
+        let path = config.batch_path(result.files.len());
+        db.execute(&copy_statement(
+            &view,
+            config,
+            last_id,
+            batch_last_id,
+            records as usize,
+            &path,
+        ))?;
+        result.bytes_written += std::fs::metadata(&path)?.len();
+        result.records_exported += records as u64;
+        result.files.push(path);
+
+        progress.batch = result.files.len() - 1;
+        progress.batch_records = records as u64;
+        progress.records_exported = result.records_exported;
+        progress.source_bytes += batch_bytes as u64;
+        progress.next_batch_size = config.batch_rows(batch_bytes as f64 / records as f64);
+        on_progress(&progress);
+
+        last_id = batch_last_id;
+    }
+
+    Ok(result)
+}
+
+/// `COPY` statement writing the records with `after_id < id <= last_id` to `path`
+pub fn copy_statement(
+    view: &StagingView,
+    config: &ExportBatchConfig,
+    after_id: i64,
+    last_id: i64,
+    records: usize,
+    path: &std::path::Path,
+) -> String {
+    let columns = if config.include_source_columns {
+        "*".to_string()
+    } else {
+        let source: Vec<String> = SOURCE_COLUMNS
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect();
+        format!("* EXCLUDE ({})", source.join(", "))
+    };
+    let select = view.wrap(&format!(
+        "SELECT {} FROM \"{}\" WHERE \"_staged_id\" > {} AND \"_staged_id\" <= {} ORDER BY \"_staged_id\"",
+        columns,
+        view.name().replace('"', "\"\""),
+        after_id,
+        last_id
+    ));
+    format!(
+        "COPY ({}) TO '{}' (FORMAT PARQUET, COMPRESSION ZSTD, ROW_GROUP_SIZE {})",
+        select,
+        path.display().to_string().replace('\'', "''"),
+        records.clamp(1, MAX_ROW_GROUP_SIZE)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::SchemaObject;
+    use crate::staging::SqlDialect;
+
+    #[test]
+    fn test_batch_rows_auto_tuning() {
+        let config = ExportBatchConfig::new("out").with_max_memory_bytes(64 * 1024 * 1024);
+        // 64 MiB / (1 KiB * 4) = 16384 records
+        assert_eq!(config.batch_rows(1024.0), 16_384);
+        // 64 MiB / (1 MiB * 4) = 16 records
+        assert_eq!(config.batch_rows(1024.0 * 1024.0), 16);
+        // Records over the budget still go one at a time
+        assert_eq!(config.batch_rows(100_000_000.0), 1);
+        assert_eq!(config.batch_rows(0.0), MAX_EXPORT_BATCH_ROWS);
+        assert_eq!(config.with_batch_size(500).batch_rows(1024.0), 500);
+    }
+
+    #[test]
+    fn test_copy_statement() {
+        let schema: SchemaObject = serde_json::from_value(serde_json::json!({
+            "name": "orders",
+            "properties": [{"name": "id", "logicalType": "integer"}]
+        }))
+        .unwrap();
+        let config = ExportBatchConfig::new("/tmp/out").with_partition("eu");
+        let view = StagingView::new(&schema, SqlDialect::DuckDb, config.partition.as_deref());
+        let path = config.batch_path(3);
+        assert_eq!(path, PathBuf::from("/tmp/out/part-00003.parquet"));
+
+        let sql = copy_statement(&view, &config, 100, 250, 150, &path);
+        assert!(sql.starts_with("COPY (WITH \"orders\" AS (SELECT "));
+        assert!(sql.contains("WHERE partition_key = 'eu'"));
+        assert!(sql.contains(
+            "SELECT * EXCLUDE (\"_staged_id\", \"_file_path\", \"_record_index\") FROM \"orders\" \
+             WHERE \"_staged_id\" > 100 AND \"_staged_id\" <= 250"
+        ));
+        assert!(sql.ends_with(
+            "TO '/tmp/out/part-00003.parquet' (FORMAT PARQUET, COMPRESSION ZSTD, ROW_GROUP_SIZE 150)"
+        ));
+    }
+}
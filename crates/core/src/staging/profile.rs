@@ -0,0 +1,528 @@
+//! Data profiling reports for staged partitions
+//!
+//! [`ProfileBuilder`] computes per-field statistics of staged records in two
+//! passes. The first pass counts nulls and distinct values, tracks minimum and
+//! maximum values and text lengths, and collects the most frequent values (see
+//! [`ColumnProfiler`]). The second pass sorts numbers and text lengths into
+//! equal-width histogram buckets between the minimum and maximum of the first
+//! pass, so memory stays bounded however many records a partition holds.
+//!
+//! The resulting [`ProfileReport`] serializes to JSON, converts to a
+//! [`DataProfile`] for embedding statistics into contracts, summarizes itself for
+//! LLM refinement prompts and renders as Markdown.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::schema::SqlDialect;
+use crate::inference::{ColumnProfiler, ColumnStatistics, DataProfile};
+
+/// Default number of most frequent values kept per field
+pub const DEFAULT_TOP_K: usize = 10;
+
+/// Default number of histogram buckets
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 10;
+
+/// Records read per query while profiling
+pub const PROFILE_PAGE_SIZE: usize = 10_000;
+
+/// Settings of a profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileOptions {
+    /// Most frequent values kept per field
+    pub top_k: usize,
+    /// Buckets of the value and length histograms
+    pub histogram_buckets: usize,
+}
+
+impl Default for ProfileOptions {
+    fn default() -> Self {
+        Self {
+            top_k: DEFAULT_TOP_K,
+            histogram_buckets: DEFAULT_HISTOGRAM_BUCKETS,
+        }
+    }
+}
+
+impl ProfileOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of most frequent values kept per field
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    /// Set the number of histogram buckets (at least 1)
+    pub fn with_histogram_buckets(mut self, buckets: usize) -> Self {
+        self.histogram_buckets = buckets.max(1);
+        self
+    }
+}
+
+/// One histogram bucket, covering `lower..upper` (the last bucket includes `upper`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistogramBucket {
+    /// Lower bound
+    pub lower: f64,
+    /// Upper bound
+    pub upper: f64,
+    /// Values in the bucket
+    pub count: usize,
+}
+
+/// Lengths of a field's text values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LengthDistribution {
+    /// Shortest text, in characters
+    pub min: usize,
+    /// Longest text, in characters
+    pub max: usize,
+    /// Mean length
+    pub mean: f64,
+    /// Histogram of the lengths
+    pub buckets: Vec<HistogramBucket>,
+}
+
+/// Statistics of one field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldStatistics {
+    /// Counts, minimum, maximum and most frequent values
+    #[serde(flatten)]
+    pub statistics: ColumnStatistics,
+    /// Lengths of text values, if the field has any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<LengthDistribution>,
+    /// Histogram of numeric values, if the field has any
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub histogram: Vec<HistogramBucket>,
+}
+
+impl FieldStatistics {
+    /// Share of records where the field is missing or null (0.0 - 1.0)
+    pub fn null_rate(&self) -> f64 {
+        self.statistics.null_percent() / 100.0
+    }
+}
+
+/// Profile of a staged partition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileReport {
+    /// Profiled partition, `None` for all staged records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    /// When the profile was taken
+    pub profiled_at: DateTime<Utc>,
+    /// Number of records profiled
+    pub record_count: usize,
+    /// Statistics per field path
+    pub fields: BTreeMap<String, FieldStatistics>,
+}
+
+impl ProfileReport {
+    /// Column statistics for [`embed_statistics`](crate::inference::embed_statistics)
+    pub fn to_data_profile(&self) -> DataProfile {
+        DataProfile {
+            profiled_at: self.profiled_at,
+            record_count: self.record_count,
+            columns: self
+                .fields
+                .iter()
+                .map(|(path, field)| (path.clone(), field.statistics.clone()))
+                .collect(),
+        }
+    }
+
+    /// Compact per-field summary, one line per field, for LLM refinement prompts
+    ///
+    /// Pass it as documentation context, e.g. with `PromptContext::with_documentation`.
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("Profile of {} records:", self.record_count)];
+        for (path, field) in &self.fields {
+            let stats = &field.statistics;
+            let mut line = format!(
+                "- {}: {:.1}% null, {}{} distinct",
+                path,
+                stats.null_percent(),
+                stats.distinct_count,
+                if stats.distinct_exact { "" } else { "+" }
+            );
+            if let (Some(min), Some(max)) = (&stats.min, &stats.max) {
+                line.push_str(&format!(", range {} to {}", display(min), display(max)));
+            }
+            if let Some(length) = &field.length {
+                line.push_str(&format!(", length {}-{}", length.min, length.max));
+            }
+            if !stats.top_values.is_empty() {
+                let top: Vec<String> = stats
+                    .top_values
+                    .iter()
+                    .take(3)
+                    .map(|v| format!("{} ({})", v.value, v.count))
+                    .collect();
+                line.push_str(&format!(", top: {}", top.join(", ")));
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    /// Render the profile as a Markdown report
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!(
+            "# Data Profile: {}\n\nProfiled {} records at {}.\n\n",
+            self.partition.as_deref().unwrap_or("all partitions"),
+            self.record_count,
+            self.profiled_at.to_rfc3339()
+        );
+        md.push_str("| Field | Null % | Distinct | Min | Max | Length | Top values |\n");
+        md.push_str("|-------|--------|----------|-----|-----|--------|------------|\n");
+        for (path, field) in &self.fields {
+            let stats = &field.statistics;
+            let top: Vec<String> = stats
+                .top_values
+                .iter()
+                .map(|v| format!("`{}` ({})", v.value, v.count))
+                .collect();
+            md.push_str(&format!(
+                "| {} | {:.1} | {}{} | {} | {} | {} | {} |\n",
+                path,
+                stats.null_percent(),
+                stats.distinct_count,
+                if stats.distinct_exact { "" } else { "+" },
+                stats.min.as_ref().map_or("-".to_string(), display),
+                stats.max.as_ref().map_or("-".to_string(), display),
+                field.length.as_ref().map_or("-".to_string(), |l| format!(
+                    "{}-{} (mean {:.1})",
+                    l.min, l.max, l.mean
+                )),
+                if top.is_empty() {
+                    "-".to_string()
+                } else {
+                    top.join(", ")
+                }
+            ));
+        }
+
+        for (path, field) in &self.fields {
+            if field.histogram.is_empty() {
+                continue;
+            }
+            md.push_str(&format!(
+                "\n## {}\n\n| Bucket | Count |\n|--------|-------|\n",
+                path
+            ));
+            for bucket in &field.histogram {
+                md.push_str(&format!(
+                    "| {} - {} | {} |\n",
+                    bucket.lower, bucket.upper, bucket.count
+                ));
+            }
+        }
+        md
+    }
+}
+
+/// Computes a [`ProfileReport`] from records, in two passes
+///
+/// Add every record with [`Self::add`], call [`Self::start_second_pass`], add
+/// every record again with [`Self::add_second_pass`], then [`Self::finish`].
+#[derive(Debug)]
+pub struct ProfileBuilder {
+    options: ProfileOptions,
+    columns: ColumnProfiler,
+    fields: BTreeMap<String, FieldAccumulator>,
+}
+
+#[derive(Debug, Default)]
+struct FieldAccumulator {
+    min_number: Option<f64>,
+    max_number: Option<f64>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    length_sum: usize,
+    length_count: usize,
+    numbers: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+impl ProfileBuilder {
+    /// Create a builder with the given options
+    pub fn new(options: ProfileOptions) -> Self {
+        Self {
+            options,
+            columns: ColumnProfiler::new(),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    /// Add a record in the first pass
+    pub fn add(&mut self, record: &Value) {
+        self.columns.add_value(record);
+        for_each_field(record, &mut |path, value| {
+            let field = self.fields.entry(path.to_string()).or_default();
+            if let Some(n) = value.as_f64() {
+                field.min_number = Some(field.min_number.map_or(n, |m| m.min(n)));
+                field.max_number = Some(field.max_number.map_or(n, |m| m.max(n)));
+            } else if let Value::String(s) = value {
+                let length = s.chars().count();
+                field.min_length = Some(field.min_length.map_or(length, |m| m.min(length)));
+                field.max_length = Some(field.max_length.map_or(length, |m| m.max(length)));
+                field.length_sum += length;
+                field.length_count += 1;
+            }
+        });
+    }
+
+    /// Fix the histogram ranges from the first pass
+    pub fn start_second_pass(&mut self) {
+        let buckets = self.options.histogram_buckets.max(1);
+        for field in self.fields.values_mut() {
+            if field.min_number.is_some() {
+                field.numbers = vec![0; buckets];
+            }
+            if field.min_length.is_some() {
+                field.lengths = vec![0; buckets];
+            }
+        }
+    }
+
+    /// Add a record in the second pass
+    pub fn add_second_pass(&mut self, record: &Value) {
+        for_each_field(record, &mut |path, value| {
+            let Some(field) = self.fields.get_mut(path) else {
+                return;
+            };
+            if let (Some(n), Some(min), Some(max)) =
+                (value.as_f64(), field.min_number, field.max_number)
+                && !field.numbers.is_empty()
+            {
+                let i = bucket_index(n, min, max, field.numbers.len());
+                field.numbers[i] += 1;
+            } else if let (Value::String(s), Some(min), Some(max)) =
+                (value, field.min_length, field.max_length)
+                && !field.lengths.is_empty()
+            {
+                let i = bucket_index(
+                    s.chars().count() as f64,
+                    min as f64,
+                    max as f64,
+                    field.lengths.len(),
+                );
+                field.lengths[i] += 1;
+            }
+        });
+    }
+
+    /// Finish the profile
+    pub fn finish(self, partition: Option<&str>) -> ProfileReport {
+        let data = self.columns.finish();
+        let top_k = self.options.top_k;
+        let mut accumulators = self.fields;
+        let fields = data
+            .columns
+            .into_iter()
+            .map(|(path, mut statistics)| {
+                statistics.top_values.truncate(top_k);
+                let field = accumulators.remove(&path).unwrap_or_default();
+                let histogram = match (field.min_number, field.max_number) {
+                    (Some(min), Some(max)) => buckets(min, max, &field.numbers),
+                    _ => Vec::new(),
+                };
+                let length = match (field.min_length, field.max_length) {
+                    (Some(min), Some(max)) => Some(LengthDistribution {
+                        min,
+                        max,
+                        mean: field.length_sum as f64 / field.length_count.max(1) as f64,
+                        buckets: buckets(min as f64, max as f64, &field.lengths),
+                    }),
+                    _ => None,
+                };
+                let statistics = FieldStatistics {
+                    statistics,
+                    length,
+                    histogram,
+                };
+                (path, statistics)
+            })
+            .collect();
+        ProfileReport {
+            partition: partition.map(str::to_string),
+            profiled_at: data.profiled_at,
+            record_count: data.record_count,
+            fields,
+        }
+    }
+}
+
+/// Call `f` with the path and value of every field, as [`ColumnProfiler`] walks them
+fn for_each_field(record: &Value, f: &mut impl FnMut(&str, &Value)) {
+    fn walk(object: &Value, prefix: &str, f: &mut impl FnMut(&str, &Value)) {
+        let Value::Object(fields) = object else {
+            return;
+        };
+        for (name, value) in fields {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            if value.is_object() {
+                walk(value, &path, f);
+            } else {
+                f(&path, value);
+            }
+        }
+    }
+    match record {
+        Value::Object(_) => walk(record, "", f),
+        other => f("value", other),
+    }
+}
+
+/// SQL reading the next page of records after the ID bound as the first
+/// parameter; the partition key, if any, is the second parameter
+pub(super) fn record_page_query(dialect: SqlDialect, partitioned: bool) -> String {
+    let (raw_json, last_id, partition) = match dialect {
+        SqlDialect::DuckDb => ("raw_json", "?1", "?2"),
+        SqlDialect::Postgres => ("raw_json::text", "$1", "$2"),
+    };
+    let filter = if partitioned {
+        format!(" AND partition_key = {}", partition)
+    } else {
+        String::new()
+    };
+    format!(
+        "SELECT id, {} FROM staged_json WHERE id > {}{} ORDER BY id LIMIT {}",
+        raw_json, last_id, filter, PROFILE_PAGE_SIZE
+    )
+}
+
+fn bucket_index(value: f64, min: f64, max: f64, buckets: usize) -> usize {
+    if max <= min {
+        return 0;
+    }
+    let i = ((value - min) / (max - min) * buckets as f64) as usize;
+    i.min(buckets - 1)
+}
+
+fn buckets(min: f64, max: f64, counts: &[usize]) -> Vec<HistogramBucket> {
+    // A single value needs a single bucket
+    let counts = if max <= min {
+        &counts[..counts.len().min(1)]
+    } else {
+        counts
+    };
+    let width = (max - min) / counts.len().max(1) as f64;
+    counts
+        .iter()
+        .enumerate()
+        .map(|(i, count)| HistogramBucket {
+            lower: min + width * i as f64,
+            upper: if i + 1 == counts.len() {
+                max
+            } else {
+                min + width * (i + 1) as f64
+            },
+            count: *count,
+        })
+        .collect()
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report() -> ProfileReport {
+        let records: Vec<Value> = (0..10)
+            .map(|i| {
+                json!({
+                    "id": i,
+                    "status": if i < 7 { "open" } else { "closed" },
+                    "note": if i % 2 == 0 { Value::Null } else { json!("x".repeat(i)) },
+                    "customer": {"tier": "gold"},
+                })
+            })
+            .collect();
+        let mut builder = ProfileBuilder::new(ProfileOptions::new().with_histogram_buckets(5));
+        for record in &records {
+            builder.add(record);
+        }
+        builder.start_second_pass();
+        for record in &records {
+            builder.add_second_pass(record);
+        }
+        builder.finish(Some("orders"))
+    }
+
+    #[test]
+    fn test_profile_statistics() {
+        let report = report();
+        assert_eq!(report.record_count, 10);
+
+        let id = &report.fields["id"];
+        assert_eq!(id.statistics.distinct_count, 10);
+        assert_eq!(id.statistics.min, Some(json!(0)));
+        assert_eq!(id.statistics.max, Some(json!(9)));
+        let counts: Vec<usize> = id.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![2, 2, 2, 2, 2]);
+        assert_eq!(id.histogram.last().unwrap().upper, 9.0);
+
+        let note = &report.fields["note"];
+        assert!((note.null_rate() - 0.5).abs() < 1e-9);
+        let length = note.length.as_ref().unwrap();
+        assert_eq!((length.min, length.max, length.mean), (1, 9, 5.0));
+        assert_eq!(length.buckets.iter().map(|b| b.count).sum::<usize>(), 5);
+
+        let status = &report.fields["status"];
+        assert_eq!(status.statistics.top_values[0].value, "open");
+        assert_eq!(status.statistics.top_values[0].count, 7);
+
+        // Constant fields get a single bucket
+        let tier = &report.fields["customer.tier"];
+        assert_eq!(tier.length.as_ref().unwrap().buckets.len(), 1);
+    }
+
+    #[test]
+    fn test_record_page_query() {
+        assert_eq!(
+            record_page_query(SqlDialect::Postgres, true),
+            "SELECT id, raw_json::text FROM staged_json WHERE id > $1 AND partition_key = $2 ORDER BY id LIMIT 10000"
+        );
+    }
+
+    #[test]
+    fn test_report_outputs() {
+        let report = report();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["fields"]["id"]["distinctCount"], 10);
+        let parsed: ProfileReport = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, report);
+
+        assert_eq!(report.to_data_profile().columns.len(), 4);
+        assert!(report.summary().contains("- status: 0.0% null, 2 distinct"));
+        let md = report.to_markdown();
+        assert!(md.starts_with("# Data Profile: orders"));
+        assert!(md.contains("| note | 50.0 | 5 | x | xxxxxxxxx | 1-9 (mean 5.0) | - |"));
+        assert!(md.contains("## id"));
+    }
+}
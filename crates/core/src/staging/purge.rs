@@ -0,0 +1,283 @@
+//! Retention and purge of staged data
+//!
+//! Long-running staging databases grow with every ingest. A [`PurgePolicy`]
+//! selects staged data to delete:
+//!
+//! - **Partition** - records, dead letters, batches and inferred schemas of a
+//!   partition and its split sub-partitions (`{partition}/...`)
+//! - **Batch** - the records and dead letters staged by one ingest, and the batch
+//! - **Age** - records and dead letters staged before a cutoff, and batches
+//!   completed before it
+//!
+//! Records staged before schema version 4 carry no batch ID, so purging by
+//! batch does not find them; purge them by partition or age instead. Deleting
+//! rows does not shrink the database file by itself; `vacuum` reclaims the
+//! space afterwards.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::schema::SqlDialect;
+
+/// Which staged data to delete
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PurgePolicy {
+    /// A partition and its sub-partitions
+    Partition(String),
+    /// The data staged by one batch
+    Batch(String),
+    /// Data staged longer ago than the given age
+    OlderThan(Duration),
+}
+
+/// Number of rows deleted by a purge
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeStats {
+    pub records_deleted: usize,
+    pub dead_letters_deleted: usize,
+    pub batches_deleted: usize,
+    pub schemas_deleted: usize,
+}
+
+impl PurgeStats {
+    /// Total number of rows deleted
+    pub fn total(&self) -> usize {
+        self.records_deleted
+            + self.dead_letters_deleted
+            + self.batches_deleted
+            + self.schemas_deleted
+    }
+
+    pub(crate) fn add(&mut self, table: PurgeTable, deleted: usize) {
+        match table {
+            PurgeTable::Records => self.records_deleted += deleted,
+            PurgeTable::DeadLetters => self.dead_letters_deleted += deleted,
+            PurgeTable::Batches => self.batches_deleted += deleted,
+            PurgeTable::Schemas => self.schemas_deleted += deleted,
+        }
+    }
+}
+
+/// Staging table a purge statement deletes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PurgeTable {
+    Records,
+    DeadLetters,
+    Batches,
+    Schemas,
+}
+
+/// A `DELETE` taking `param` as its only bind parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PurgeStatement {
+    pub table: PurgeTable,
+    pub sql: String,
+    pub param: String,
+}
+
+/// The statements carrying out a purge policy, records first
+pub(crate) fn purge_statements(
+    policy: &PurgePolicy,
+    dialect: SqlDialect,
+    now: DateTime<Utc>,
+) -> Vec<PurgeStatement> {
+    let p = match dialect {
+        SqlDialect::DuckDb => "?1",
+        SqlDialect::Postgres => "$1",
+    };
+    let statement = |table, sql: String, param: &str| PurgeStatement {
+        table,
+        sql,
+        param: param.to_string(),
+    };
+
+    match policy {
+        PurgePolicy::Partition(partition) => {
+            // A prefix match rather than LIKE, which would treat `_` and `%`
+            // in the partition name as wildcards
+            let filter = format!(
+                "partition_key = {p} OR starts_with(partition_key, {p} || '/')",
+                p = p
+            );
+            [
+                (PurgeTable::Records, "staged_json"),
+                (PurgeTable::DeadLetters, "dead_letter_records"),
+                (PurgeTable::Batches, "processing_batches"),
+                (PurgeTable::Schemas, "inferred_schemas"),
+            ]
+            .into_iter()
+            .map(|(table, name)| {
+                statement(
+                    table,
+                    format!("DELETE FROM {} WHERE {}", name, filter),
+                    partition,
+                )
+            })
+            .collect()
+        }
+        PurgePolicy::Batch(batch_id) => vec![
+            statement(
+                PurgeTable::Records,
+                format!("DELETE FROM staged_json WHERE batch_id = {}", p),
+                batch_id,
+            ),
+            statement(
+                PurgeTable::DeadLetters,
+                format!("DELETE FROM dead_letter_records WHERE batch_id = {}", p),
+                batch_id,
+            ),
+            statement(
+                PurgeTable::Batches,
+                format!("DELETE FROM processing_batches WHERE id = {}", p),
+                batch_id,
+            ),
+        ],
+        PurgePolicy::OlderThan(age) => {
+            let cutoff = chrono::Duration::from_std(*age)
+                .ok()
+                .and_then(|age| now.checked_sub_signed(age))
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            let timestamp = cutoff.format("%Y-%m-%d %H:%M:%S%.6f").to_string();
+            let cast = match dialect {
+                SqlDialect::DuckDb => format!("CAST({} AS TIMESTAMP)", p),
+                SqlDialect::Postgres => format!("CAST({}::text AS TIMESTAMP)", p),
+            };
+            vec![
+                statement(
+                    PurgeTable::Records,
+                    format!("DELETE FROM staged_json WHERE ingested_at < {}", cast),
+                    &timestamp,
+                ),
+                statement(
+                    PurgeTable::DeadLetters,
+                    format!(
+                        "DELETE FROM dead_letter_records WHERE rejected_at < {}",
+                        cast
+                    ),
+                    &timestamp,
+                ),
+                // Batch timestamps are stored as RFC 3339 text
+                statement(
+                    PurgeTable::Batches,
+                    format!(
+                        "DELETE FROM processing_batches WHERE status = 'completed' AND completed_at < {}",
+                        p
+                    ),
+                    &cutoff.to_rfc3339(),
+                ),
+            ]
+        }
+    }
+}
+
+/// Parse an age such as `90d`: a whole number followed by `s`, `m`, `h`,
+/// `d` (days) or `w` (weeks)
+pub fn parse_age(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let invalid = || {
+        format!(
+            "Invalid age: {}. Expected a number followed by s, m, h, d or w (e.g., 30d)",
+            s
+        )
+    };
+    let Some((split, _)) = s.char_indices().last() else {
+        return Err(invalid());
+    };
+    let (amount, unit) = s.split_at(split);
+    let amount: u64 = amount.parse().map_err(|_| invalid())?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => return Err(invalid()),
+    };
+    amount
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
+/// Statement reclaiming the space of deleted rows
+pub(crate) fn vacuum_statement(dialect: SqlDialect) -> &'static str {
+    match dialect {
+        SqlDialect::DuckDb => "CHECKPOINT",
+        SqlDialect::Postgres => {
+            "VACUUM ANALYZE staged_json, dead_letter_records, processing_batches, inferred_schemas"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purge_statements() {
+        let now = DateTime::parse_from_rfc3339("2024-03-31T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let partition = purge_statements(
+            &PurgePolicy::Partition("events".to_string()),
+            SqlDialect::DuckDb,
+            now,
+        );
+        assert_eq!(partition.len(), 4);
+        assert_eq!(
+            partition[0].sql,
+            "DELETE FROM staged_json WHERE partition_key = ?1 OR starts_with(partition_key, ?1 || '/')"
+        );
+        assert_eq!(partition[0].param, "events");
+
+        // Wildcard characters in the name are matched literally
+        let partition = purge_statements(
+            &PurgePolicy::Partition("raw_events".to_string()),
+            SqlDialect::Postgres,
+            now,
+        );
+        assert_eq!(
+            partition[1].sql,
+            "DELETE FROM dead_letter_records WHERE partition_key = $1 OR starts_with(partition_key, $1 || '/')"
+        );
+        assert!(!partition[1].sql.contains("LIKE"));
+        assert_eq!(partition[1].param, "raw_events");
+
+        let batch = purge_statements(
+            &PurgePolicy::Batch("batch-1".to_string()),
+            SqlDialect::Postgres,
+            now,
+        );
+        assert_eq!(batch[2].table, PurgeTable::Batches);
+        assert_eq!(batch[2].sql, "DELETE FROM processing_batches WHERE id = $1");
+
+        let aged = purge_statements(
+            &PurgePolicy::OlderThan(Duration::from_secs(30 * 86_400)),
+            SqlDialect::DuckDb,
+            now,
+        );
+        assert_eq!(
+            aged[0].sql,
+            "DELETE FROM staged_json WHERE ingested_at < CAST(?1 AS TIMESTAMP)"
+        );
+        assert_eq!(aged[0].param, "2024-03-01 12:00:00.000000");
+        assert_eq!(aged[2].param, "2024-03-01T12:00:00+00:00");
+
+        assert_eq!(parse_age("30d").unwrap(), Duration::from_secs(30 * 86_400));
+        assert_eq!(parse_age(" 12h ").unwrap(), Duration::from_secs(12 * 3_600));
+        assert!(parse_age("30").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("-1d").is_err());
+        assert!(parse_age("3é").is_err());
+
+        let mut stats = PurgeStats::default();
+        stats.add(PurgeTable::Records, 3);
+        stats.add(PurgeTable::Batches, 1);
+        assert_eq!(stats.total(), 4);
+    }
+}
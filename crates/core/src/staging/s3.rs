@@ -1,11 +1,16 @@
 //! S3 ingestion support
 //!
 //! This module provides file discovery and ingestion from Amazon S3 buckets.
+//! Objects are identified by their `s3://bucket/key` URL, which is what staged
+//! records store as their file path, so path deduplication and resuming a batch
+//! work as they do for local files.
 
-use std::path::PathBuf;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 use aws_config::BehaviorVersion;
 use aws_sdk_s3::Client as S3Client;
+use tempfile::NamedTempFile;
 
 use super::error::IngestError;
 use super::ingest::DiscoveredFile;
@@ -59,6 +64,19 @@ impl S3Source {
     pub fn display(&self) -> String {
         format!("s3://{}/{}", self.bucket, self.prefix)
     }
+
+    /// URL of an object in the bucket
+    pub fn object_url(&self, key: &str) -> String {
+        format!("s3://{}/{}", self.bucket, key)
+    }
+
+    /// Key of an object from its URL (or the key itself)
+    pub fn object_key<'a>(&self, url: &'a str) -> &'a str {
+        url.strip_prefix("s3://")
+            .and_then(|rest| rest.strip_prefix(self.bucket.as_str()))
+            .and_then(|rest| rest.strip_prefix('/'))
+            .unwrap_or(url)
+    }
 }
 
 /// S3 client wrapper with secure credential handling
@@ -104,6 +122,9 @@ impl S3Ingester {
 
     /// Discover files matching a pattern in the S3 bucket
     ///
+    /// Keys are matched relative to the prefix; the discovered files' paths
+    /// are the objects' `s3://` URLs, sorted.
+    ///
     /// # Arguments
     /// * `pattern` - Glob pattern to match (e.g., "*.json", "data/*.jsonl")
     pub async fn discover_files(&self, pattern: &str) -> Result<Vec<DiscoveredFile>, IngestError> {
@@ -143,10 +164,13 @@ impl S3Ingester {
                             .unwrap_or(&key)
                             .trim_start_matches('/');
 
-                        // Check if filename matches pattern
-                        if glob_pattern.matches(filename) {
-                            let size = object.size.unwrap_or(0) as u64;
-                            files.push(DiscoveredFile::new(PathBuf::from(&key), size));
+                        // Check if filename matches pattern (skipping "directory" markers)
+                        if !key.ends_with('/') && glob_pattern.matches(filename) {
+                            let size = object.size.unwrap_or(0).max(0) as u64;
+                            files.push(DiscoveredFile::new(
+                                PathBuf::from(self.source.object_url(&key)),
+                                size,
+                            ));
                         }
                     }
                 }
@@ -166,8 +190,13 @@ impl S3Ingester {
         Ok(files)
     }
 
-    /// Download a file from S3 and return its contents
-    pub async fn download_file(&self, key: &str) -> Result<Vec<u8>, IngestError> {
+    /// Download an object to a temporary file
+    ///
+    /// The body is written to the file chunk by chunk as it arrives, so the
+    /// object is never held in memory; the file is deleted when the returned
+    /// handle is dropped. `key` may also be the object's `s3://` URL.
+    pub async fn download_file(&self, key: &str) -> Result<NamedTempFile, IngestError> {
+        let key = self.source.object_key(key);
         let response = self
             .client
             .get_object()
@@ -180,13 +209,26 @@ impl S3Ingester {
                 reason: e.to_string(),
             })?;
 
-        let bytes = response
-            .body
-            .collect()
+        let file = NamedTempFile::new()?;
+        let mut writer = BufWriter::new(file.as_file());
+        let mut body = response.body;
+        while let Some(chunk) = body
+            .try_next()
             .await
-            .map_err(|e| IngestError::Io(std::io::Error::other(e.to_string())))?;
+            .map_err(|e| IngestError::Io(std::io::Error::other(e.to_string())))?
+        {
+            writer.write_all(&chunk)?;
+        }
+        writer.flush()?;
+        drop(writer);
 
-        Ok(bytes.into_bytes().to_vec())
+        Ok(file)
+    }
+
+    /// Download a discovered file and return its contents
+    pub async fn download(&self, path: &Path) -> Result<Vec<u8>, IngestError> {
+        let file = self.download_file(&path.display().to_string()).await?;
+        Ok(std::fs::read(file.path())?)
     }
 
     /// Get the source configuration
@@ -316,6 +358,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_s3_object_urls() {
+        let source = S3Source::new("bucket", "raw");
+        let url = source.object_url("raw/2024/orders.json");
+        assert_eq!(url, "s3://bucket/raw/2024/orders.json");
+        assert_eq!(source.object_key(&url), "raw/2024/orders.json");
+        assert_eq!(source.object_key("raw/orders.json"), "raw/orders.json");
+        assert_eq!(
+            source.object_key("s3://other/raw/orders.json"),
+            "s3://other/raw/orders.json"
+        );
+    }
+
     #[test]
     fn test_redact_secret() {
         assert_eq!(redact_secret("short", 10), "[REDACTED]");
@@ -0,0 +1,187 @@
+//! Sample queries over staged records
+//!
+//! Builds one `SELECT` per [`SamplingStrategy`] and backend, returning the raw JSON
+//! of the sampled records. When the sample is restricted to a partition, the query
+//! takes the partition key as its first bind parameter.
+
+use super::schema::SqlDialect;
+use crate::inference::SamplingStrategy;
+
+/// How much larger than the requested sample the PostgreSQL reservoir pre-filter
+/// is, so that it rarely yields fewer records than requested
+const OVERSAMPLING_FACTOR: usize = 3;
+
+/// Extra records added to the PostgreSQL reservoir pre-filter for small samples
+const OVERSAMPLING_SLACK: usize = 10;
+
+/// SQL selecting up to `limit` records with the given strategy
+///
+/// * `Random` sorts all candidate records by a random key.
+/// * `FirstN` reads records in ingestion order, using the primary key index.
+/// * `Reservoir` uses DuckDB's reservoir sampler; PostgreSQL has none, so records
+///   are pre-filtered with a random draw at a few times the required rate and the
+///   (small) remainder is shuffled.
+/// * `StratifiedByPartition` and `StratifiedByField` number the records of each
+///   stratum in random order and take the first of every stratum, then the second,
+///   and so on, so each stratum gets an equal share and small strata are exhausted
+///   first.
+/// * `Systematic` takes every k-th record in ingestion order, with
+///   `k = candidates / limit`.
+pub fn sample_query(
+    strategy: &SamplingStrategy,
+    dialect: SqlDialect,
+    limit: usize,
+    partitioned: bool,
+) -> String {
+    let raw_json = match dialect {
+        SqlDialect::DuckDb => "raw_json",
+        SqlDialect::Postgres => "raw_json::text",
+    };
+    let filter = match (partitioned, dialect) {
+        (false, _) => String::new(),
+        (true, SqlDialect::DuckDb) => " WHERE partition_key = ?1".to_string(),
+        (true, SqlDialect::Postgres) => " WHERE partition_key = $1".to_string(),
+    };
+
+    match strategy {
+        SamplingStrategy::Random => format!(
+            "SELECT {} FROM staged_json{} ORDER BY RANDOM() LIMIT {}",
+            raw_json, filter, limit
+        ),
+        SamplingStrategy::FirstN => format!(
+            "SELECT {} FROM staged_json{} ORDER BY id LIMIT {}",
+            raw_json, filter, limit
+        ),
+        SamplingStrategy::Reservoir => match dialect {
+            SqlDialect::DuckDb => format!(
+                "SELECT raw_json FROM (SELECT raw_json FROM staged_json{}) USING SAMPLE reservoir({} ROWS)",
+                filter, limit
+            ),
+            SqlDialect::Postgres => {
+                let target = limit * OVERSAMPLING_FACTOR + OVERSAMPLING_SLACK;
+                format!(
+                    "SELECT {raw_json} FROM staged_json{filter}{and} RANDOM() < {target}.0 / GREATEST((SELECT COUNT(*) FROM staged_json{filter}), 1) ORDER BY RANDOM() LIMIT {limit}",
+                    raw_json = raw_json,
+                    filter = filter,
+                    and = if partitioned { " AND" } else { " WHERE" },
+                    target = target,
+                    limit = limit
+                )
+            }
+        },
+        SamplingStrategy::StratifiedByPartition => {
+            stratified("COALESCE(partition_key, '')", raw_json, &filter, limit)
+        }
+        SamplingStrategy::StratifiedByField { field } => {
+            stratified(&field_expression(field, dialect), raw_json, &filter, limit)
+        }
+        SamplingStrategy::Systematic => format!(
+            "SELECT raw_json FROM (SELECT {} AS raw_json, ROW_NUMBER() OVER (ORDER BY id) AS rn, COUNT(*) OVER () AS total FROM staged_json{}) s \
+             WHERE (rn - 1) % GREATEST(CAST(FLOOR(total * 1.0 / {}) AS BIGINT), 1) = 0 ORDER BY rn LIMIT {}",
+            raw_json,
+            filter,
+            limit.max(1),
+            limit
+        ),
+    }
+}
+
+fn stratified(stratum: &str, raw_json: &str, filter: &str, limit: usize) -> String {
+    format!(
+        "SELECT raw_json FROM (SELECT {} AS raw_json, ROW_NUMBER() OVER (PARTITION BY {} ORDER BY RANDOM()) AS rn FROM staged_json{}) s \
+         ORDER BY rn, RANDOM() LIMIT {}",
+        raw_json, stratum, filter, limit
+    )
+}
+
+/// Text value of a dot-separated JSON path such as `$.meta.kind`
+pub(super) fn field_expression(path: &str, dialect: SqlDialect) -> String {
+    let path = path.trim();
+    let path = path
+        .strip_prefix("$.")
+        .or_else(|| path.strip_prefix('$'))
+        .unwrap_or(path);
+    let segments: Vec<&str> = path.split('.').filter(|s| !s.is_empty()).collect();
+    match dialect {
+        SqlDialect::DuckDb => {
+            let path: Vec<String> = segments
+                .iter()
+                .map(|s| format!("\"{}\"", s.replace('"', "\\\"")))
+                .collect();
+            format!(
+                "json_extract_string(raw_json, '$.{}')",
+                path.join(".").replace('\'', "''")
+            )
+        }
+        SqlDialect::Postgres => {
+            let path: Vec<String> = segments
+                .iter()
+                .map(|s| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")))
+                .collect();
+            format!(
+                "(raw_json #>> '{{{}}}')",
+                path.join(",").replace('\'', "''")
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_strategies() {
+        assert_eq!(
+            sample_query(&SamplingStrategy::Random, SqlDialect::DuckDb, 10, true),
+            "SELECT raw_json FROM staged_json WHERE partition_key = ?1 ORDER BY RANDOM() LIMIT 10"
+        );
+        assert_eq!(
+            sample_query(&SamplingStrategy::FirstN, SqlDialect::Postgres, 5, false),
+            "SELECT raw_json::text FROM staged_json ORDER BY id LIMIT 5"
+        );
+        assert_eq!(
+            sample_query(&SamplingStrategy::Reservoir, SqlDialect::DuckDb, 100, true),
+            "SELECT raw_json FROM (SELECT raw_json FROM staged_json WHERE partition_key = ?1) USING SAMPLE reservoir(100 ROWS)"
+        );
+        assert_eq!(
+            sample_query(
+                &SamplingStrategy::Reservoir,
+                SqlDialect::Postgres,
+                100,
+                true
+            ),
+            "SELECT raw_json::text FROM staged_json WHERE partition_key = $1 AND RANDOM() < 310.0 / GREATEST((SELECT COUNT(*) FROM staged_json WHERE partition_key = $1), 1) ORDER BY RANDOM() LIMIT 100"
+        );
+    }
+
+    #[test]
+    fn test_stratified_and_systematic() {
+        let by_field = SamplingStrategy::StratifiedByField {
+            field: "$.meta.kind".to_string(),
+        };
+        assert_eq!(
+            sample_query(&by_field, SqlDialect::DuckDb, 20, false),
+            "SELECT raw_json FROM (SELECT raw_json AS raw_json, ROW_NUMBER() OVER (PARTITION BY json_extract_string(raw_json, '$.\"meta\".\"kind\"') ORDER BY RANDOM()) AS rn FROM staged_json) s \
+             ORDER BY rn, RANDOM() LIMIT 20"
+        );
+        assert!(
+            sample_query(&by_field, SqlDialect::Postgres, 20, false)
+                .contains("PARTITION BY (raw_json #>> '{\"meta\",\"kind\"}')")
+        );
+        assert!(
+            sample_query(
+                &SamplingStrategy::StratifiedByPartition,
+                SqlDialect::Postgres,
+                20,
+                true
+            )
+            .contains("PARTITION BY COALESCE(partition_key, '') ORDER BY RANDOM()) AS rn FROM staged_json WHERE partition_key = $1")
+        );
+        assert_eq!(
+            sample_query(&SamplingStrategy::Systematic, SqlDialect::DuckDb, 50, false),
+            "SELECT raw_json FROM (SELECT raw_json AS raw_json, ROW_NUMBER() OVER (ORDER BY id) AS rn, COUNT(*) OVER () AS total FROM staged_json) s \
+             WHERE (rn - 1) % GREATEST(CAST(FLOOR(total * 1.0 / 50) AS BIGINT), 1) = 0 ORDER BY rn LIMIT 50"
+        );
+    }
+}
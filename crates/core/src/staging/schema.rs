@@ -1,7 +1,14 @@
 //! Database schema definitions for staging tables
 
 /// Current schema version
-pub const SCHEMA_VERSION: i32 = 1;
+pub const SCHEMA_VERSION: i32 = 4;
+
+/// SQL dialect of a staging database backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    DuckDb,
+    Postgres,
+}
 
 /// Schema for staging database tables
 pub struct StagingSchema;
@@ -27,6 +34,7 @@ CREATE TABLE IF NOT EXISTS staged_json (
     content_hash VARCHAR,
     file_size_bytes BIGINT,
     ingested_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    batch_id VARCHAR,
     UNIQUE(file_path, record_index)
 );
 
@@ -43,6 +51,7 @@ CREATE TABLE IF NOT EXISTS processing_batches (
     files_skipped INTEGER DEFAULT 0,
     records_ingested BIGINT DEFAULT 0,
     bytes_processed BIGINT DEFAULT 0,
+    bytes_decompressed BIGINT DEFAULT 0,
     errors_count INTEGER DEFAULT 0,
     last_file_path VARCHAR,
     last_record_index INTEGER,
@@ -52,6 +61,12 @@ CREATE TABLE IF NOT EXISTS processing_batches (
     error_message VARCHAR
 );
 
+-- Added in schema version 3
+ALTER TABLE processing_batches ADD COLUMN IF NOT EXISTS bytes_decompressed BIGINT DEFAULT 0;
+
+-- Added in schema version 4
+ALTER TABLE staged_json ADD COLUMN IF NOT EXISTS batch_id VARCHAR;
+
 -- Inferred schemas storage
 CREATE TABLE IF NOT EXISTS inferred_schemas (
     id VARCHAR PRIMARY KEY,
@@ -64,12 +79,27 @@ CREATE TABLE IF NOT EXISTS inferred_schemas (
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
+-- Records rejected by ingest-time validation
+CREATE SEQUENCE IF NOT EXISTS dead_letter_records_id_seq START 1;
+CREATE TABLE IF NOT EXISTS dead_letter_records (
+    id BIGINT PRIMARY KEY DEFAULT nextval('dead_letter_records_id_seq'),
+    batch_id VARCHAR NOT NULL,
+    file_path VARCHAR NOT NULL,
+    record_index INTEGER NOT NULL,
+    partition_key VARCHAR,
+    raw_json VARCHAR NOT NULL,
+    errors JSON NOT NULL,
+    rejected_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
 -- Create indexes for performance
 CREATE INDEX IF NOT EXISTS idx_staged_partition ON staged_json(partition_key);
 CREATE INDEX IF NOT EXISTS idx_staged_file ON staged_json(file_path);
 CREATE INDEX IF NOT EXISTS idx_staged_hash ON staged_json(content_hash);
+CREATE INDEX IF NOT EXISTS idx_staged_batch ON staged_json(batch_id);
 CREATE INDEX IF NOT EXISTS idx_batches_status ON processing_batches(status);
 CREATE INDEX IF NOT EXISTS idx_schemas_partition ON inferred_schemas(partition_key);
+CREATE INDEX IF NOT EXISTS idx_dead_letter_batch ON dead_letter_records(batch_id);
 
 -- Create sequence for staged_json IDs
 CREATE SEQUENCE IF NOT EXISTS staged_json_id_seq START 1;
@@ -96,6 +126,7 @@ CREATE TABLE IF NOT EXISTS staged_json (
     content_hash VARCHAR,
     file_size_bytes BIGINT,
     ingested_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    batch_id VARCHAR,
     UNIQUE(file_path, record_index)
 );
 
@@ -112,6 +143,7 @@ CREATE TABLE IF NOT EXISTS processing_batches (
     files_skipped INTEGER DEFAULT 0,
     records_ingested BIGINT DEFAULT 0,
     bytes_processed BIGINT DEFAULT 0,
+    bytes_decompressed BIGINT DEFAULT 0,
     errors_count INTEGER DEFAULT 0,
     last_file_path VARCHAR,
     last_record_index INTEGER,
@@ -121,6 +153,12 @@ CREATE TABLE IF NOT EXISTS processing_batches (
     error_message VARCHAR
 );
 
+-- Added in schema version 3
+ALTER TABLE processing_batches ADD COLUMN IF NOT EXISTS bytes_decompressed BIGINT DEFAULT 0;
+
+-- Added in schema version 4
+ALTER TABLE staged_json ADD COLUMN IF NOT EXISTS batch_id VARCHAR;
+
 -- Inferred schemas storage
 CREATE TABLE IF NOT EXISTS inferred_schemas (
     id VARCHAR PRIMARY KEY,
@@ -133,12 +171,26 @@ CREATE TABLE IF NOT EXISTS inferred_schemas (
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
+-- Records rejected by ingest-time validation
+CREATE TABLE IF NOT EXISTS dead_letter_records (
+    id BIGSERIAL PRIMARY KEY,
+    batch_id VARCHAR NOT NULL,
+    file_path VARCHAR NOT NULL,
+    record_index INTEGER NOT NULL,
+    partition_key VARCHAR,
+    raw_json TEXT NOT NULL,
+    errors JSONB NOT NULL,
+    rejected_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
 -- Create indexes for performance
 CREATE INDEX IF NOT EXISTS idx_staged_partition ON staged_json(partition_key);
 CREATE INDEX IF NOT EXISTS idx_staged_file ON staged_json(file_path);
 CREATE INDEX IF NOT EXISTS idx_staged_hash ON staged_json(content_hash);
+CREATE INDEX IF NOT EXISTS idx_staged_batch ON staged_json(batch_id);
 CREATE INDEX IF NOT EXISTS idx_batches_status ON processing_batches(status);
 CREATE INDEX IF NOT EXISTS idx_schemas_partition ON inferred_schemas(partition_key);
+CREATE INDEX IF NOT EXISTS idx_dead_letter_batch ON dead_letter_records(batch_id);
 "#
     }
 
@@ -169,6 +221,7 @@ mod tests {
         assert!(ddl.contains("CREATE TABLE IF NOT EXISTS staged_json"));
         assert!(ddl.contains("CREATE TABLE IF NOT EXISTS processing_batches"));
         assert!(ddl.contains("CREATE TABLE IF NOT EXISTS inferred_schemas"));
+        assert!(ddl.contains("CREATE TABLE IF NOT EXISTS dead_letter_records"));
         assert!(ddl.contains("CREATE INDEX IF NOT EXISTS idx_staged_partition"));
     }
 }
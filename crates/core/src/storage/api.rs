@@ -7,7 +7,14 @@
 //!
 //! All domain parameters are validated to prevent injection attacks.
 //! Only alphanumeric characters, hyphens, and underscores are allowed.
+//!
+//! ## Tenants
+//!
+//! A backend created [`with_tenant`](ApiStorageBackend::with_tenant) sends every
+//! request below `/tenants/{tenant}`, so a multi-tenant API server can route
+//! and authorize it by tenant.
 
+use super::tenant::TenantId;
 use super::{StorageBackend, StorageError};
 use async_trait::async_trait;
 use serde_json;
@@ -63,6 +70,7 @@ fn validate_domain_slug(domain: &str) -> Result<(), StorageError> {
 pub struct ApiStorageBackend {
     base_url: String,
     auth_token: Option<String>,
+    tenant: Option<TenantId>,
     client: reqwest::Client,
 }
 
@@ -88,13 +96,33 @@ impl ApiStorageBackend {
         Self {
             base_url: base_url.into(),
             auth_token,
+            tenant: None,
             client: reqwest::Client::new(),
         }
     }
 
+    /// Scope all requests to `tenant` (`{base_url}/tenants/{tenant}/...`)
+    pub fn with_tenant(mut self, tenant: TenantId) -> Self {
+        self.tenant = Some(tenant);
+        self
+    }
+
+    /// Tenant the requests are scoped to
+    pub fn tenant(&self) -> Option<&TenantId> {
+        self.tenant.as_ref()
+    }
+
+    /// Full URL of an API path
+    fn url(&self, path: &str) -> String {
+        match &self.tenant {
+            Some(tenant) => format!("{}/tenants/{}{}", self.base_url, tenant, path),
+            None => format!("{}{}", self.base_url, path),
+        }
+    }
+
     /// Build a request with authentication headers
     fn build_request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
-        let url = format!("{}{}", self.base_url, path);
+        let url = self.url(path);
         let mut request = self.client.request(method, &url);
 
         if let Some(ref token) = self.auth_token {
@@ -252,6 +280,21 @@ mod tests {
         assert!(validate_domain_slug("domain with spaces").is_err());
     }
 
+    #[test]
+    fn test_tenant_scoped_urls() {
+        let backend = ApiStorageBackend::new("https://api.example.com/api/v1", None);
+        assert_eq!(
+            backend.url("/workspace/info"),
+            "https://api.example.com/api/v1/workspace/info"
+        );
+
+        let backend = backend.with_tenant(TenantId::parse("acme").unwrap());
+        assert_eq!(
+            backend.url("/workspace/domains/sales/tables"),
+            "https://api.example.com/api/v1/tenants/acme/workspace/domains/sales/tables"
+        );
+    }
+
     #[test]
     fn test_validate_domain_slug_dot_patterns() {
         assert!(validate_domain_slug(".").is_err());
@@ -0,0 +1,72 @@
+//! Azure Blob Storage backend
+//!
+//! Stores workspaces in an Azure Blob Storage container through the shared
+//! [`ObjectStorageBackend`].
+//!
+//! Credentials are read from the standard `AZURE_*` environment variables
+//! (`AZURE_STORAGE_ACCOUNT_NAME`, `AZURE_STORAGE_ACCOUNT_KEY`,
+//! `AZURE_STORAGE_SAS_KEY`, `AZURE_CLIENT_ID`, ...), falling back to managed
+//! identity. For other setups, configure a [`MicrosoftAzureBuilder`] and pass it
+//! to [`AzureBlobStorageBackend::from_builder`].
+
+use super::StorageError;
+use super::object_storage::{ObjectStorageBackend, split_url};
+pub use object_store::azure::{MicrosoftAzure, MicrosoftAzureBuilder};
+
+/// Azure Blob Storage backend
+pub type AzureBlobStorageBackend = ObjectStorageBackend<MicrosoftAzure>;
+
+impl ObjectStorageBackend<MicrosoftAzure> {
+    /// Create a backend for a container, with credentials from the environment
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use data_modelling_core::storage::azure::AzureBlobStorageBackend;
+    ///
+    /// let backend = AzureBlobStorageBackend::from_env("models")
+    ///     .unwrap()
+    ///     .with_prefix("workspaces/sales");
+    /// ```
+    pub fn from_env(container: &str) -> Result<Self, StorageError> {
+        Self::from_builder(MicrosoftAzureBuilder::from_env().with_container_name(container))
+    }
+
+    /// Create a backend from a URL, with credentials from the environment
+    ///
+    /// Accepts `az://<container>/<prefix>`, `abfss://<container>@<account>.dfs.core.windows.net/<prefix>`
+    /// and `https://<account>.blob.core.windows.net/<container>/<prefix>`; the path
+    /// below the container becomes the key prefix.
+    pub fn from_url(url: &str) -> Result<Self, StorageError> {
+        let (base, prefix) = split_url(url)?;
+        Ok(
+            Self::from_builder(MicrosoftAzureBuilder::from_env().with_url(base))?
+                .with_prefix(prefix),
+        )
+    }
+
+    /// Create a backend from a configured `object_store` builder
+    pub fn from_builder(builder: MicrosoftAzureBuilder) -> Result<Self, StorageError> {
+        let store = builder.build().map_err(|e| {
+            StorageError::BackendError(format!("Failed to configure Azure Blob Storage: {}", e))
+        })?;
+        Ok(Self::new(store))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_builder() {
+        let builder = MicrosoftAzureBuilder::new()
+            .with_account("devstoreaccount1")
+            .with_container_name("models")
+            .with_use_emulator(true);
+        assert!(AzureBlobStorageBackend::from_builder(builder).is_ok());
+
+        let err = AzureBlobStorageBackend::from_builder(MicrosoftAzureBuilder::new()).err();
+        assert!(matches!(err, Some(StorageError::BackendError(_))));
+    }
+}
@@ -0,0 +1,232 @@
+//! Cached storage
+//!
+//! [`CachedStorageBackend`] wraps another backend and keeps the results of reads,
+//! listings and existence checks in memory, so that loading the same workspace
+//! files many times per session, e.g. through the [`ApiStorageBackend`], costs
+//! one round-trip per file instead of one per load.
+//!
+//! Entries expire after a configurable time-to-live. Writes, deletes and new
+//! directories go straight to the wrapped backend and invalidate the cached
+//! content of the file and all cached listings and directory checks. Changes
+//! made to the wrapped backend by others are only seen once entries expire or
+//! are invalidated with [`CachedStorageBackend::invalidate`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use data_modelling_core::model::ModelLoader;
+//! use data_modelling_core::storage::api::ApiStorageBackend;
+//! use data_modelling_core::storage::cached::CachedStorageBackend;
+//!
+//! let backend = CachedStorageBackend::new(ApiStorageBackend::new(api_url, Some(token)))
+//!     .with_ttl(Duration::from_secs(300));
+//! let loader = ModelLoader::new(backend);
+//! ```
+//!
+//! [`ApiStorageBackend`]: super::api::ApiStorageBackend
+
+use super::{StorageBackend, StorageError};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Default time-to-live of cached entries
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+struct Entry<T> {
+    value: T,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+/// Cached entries, keyed by normalized path
+struct Cache<T> {
+    entries: Mutex<HashMap<String, Entry<T>>>,
+}
+
+impl<T: Clone> Cache<T> {
+    fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<T> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get(key)?;
+        if entry
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+        {
+            entries.remove(key);
+            return None;
+        }
+        Some(entry.value.clone())
+    }
+
+    /// Cache `value` for `ttl`; an expiry beyond the representable time range
+    /// keeps the entry until it is invalidated
+    fn insert(&self, key: String, value: T, ttl: Option<TimeDelta>) {
+        let expires_at = ttl.and_then(|ttl| Utc::now().checked_add_signed(ttl));
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, Entry { value, expires_at });
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// Storage backend that caches reads from another backend
+pub struct CachedStorageBackend<B: StorageBackend> {
+    inner: B,
+    /// Time-to-live of entries; `None` keeps them until invalidated
+    ttl: Option<TimeDelta>,
+    /// File content, `None` for files known not to exist
+    files: Cache<Option<Vec<u8>>>,
+    listings: Cache<Vec<String>>,
+    directories: Cache<bool>,
+}
+
+impl<B: StorageBackend> CachedStorageBackend<B> {
+    /// Wrap `inner`, caching entries for [`DEFAULT_TTL`]
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            ttl: TimeDelta::from_std(DEFAULT_TTL).ok(),
+            files: Cache::new(),
+            listings: Cache::new(),
+            directories: Cache::new(),
+        }
+    }
+
+    /// Set the time-to-live of cached entries
+    ///
+    /// Entries whose expiry would lie beyond the representable time range, such
+    /// as with [`Duration::MAX`], never expire.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(TimeDelta::from_std(ttl).unwrap_or(TimeDelta::MAX));
+        self
+    }
+
+    /// Keep cached entries until they are invalidated
+    pub fn without_expiry(mut self) -> Self {
+        self.ttl = None;
+        self
+    }
+
+    /// The wrapped backend
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Drop the cached content of a file, and all cached listings and directory
+    /// checks
+    pub fn invalidate(&self, path: &str) {
+        self.files.remove(&cache_key(path));
+        self.listings.clear();
+        self.directories.clear();
+    }
+
+    /// Drop all cached entries
+    pub fn clear(&self) {
+        self.files.clear();
+        self.listings.clear();
+        self.directories.clear();
+    }
+
+    /// Read a file through the cache; `None` if it does not exist
+    async fn cached_file(&self, path: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let key = cache_key(path);
+        if let Some(content) = self.files.get(&key) {
+            return Ok(content);
+        }
+
+        let content = match self.inner.read_file(path).await {
+            Ok(content) => Some(content),
+            Err(StorageError::FileNotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+        self.files.insert(key, content.clone(), self.ttl);
+        Ok(content)
+    }
+}
+
+/// Cache key of a path, so that `a/b`, `/a/b/` and `./a/b` share an entry
+fn cache_key(path: &str) -> String {
+    path.split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[async_trait(?Send)]
+impl<B: StorageBackend> StorageBackend for CachedStorageBackend<B> {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        self.cached_file(path)
+            .await?
+            .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        let result = self.inner.write_file(path, content).await;
+        self.invalidate(path);
+        result
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        let key = cache_key(dir);
+        if let Some(files) = self.listings.get(&key) {
+            return Ok(files);
+        }
+
+        let files = self.inner.list_files(dir).await?;
+        self.listings.insert(key, files.clone(), self.ttl);
+        Ok(files)
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        if let Some(content) = self.files.get(&cache_key(path)) {
+            return Ok(content.is_some());
+        }
+        self.inner.file_exists(path).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        let result = self.inner.delete_file(path).await;
+        self.invalidate(path);
+        result
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        let result = self.inner.create_dir(path).await;
+        self.invalidate(path);
+        result
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        let key = cache_key(path);
+        if let Some(exists) = self.directories.get(&key) {
+            return Ok(exists);
+        }
+
+        let exists = self.inner.dir_exists(path).await?;
+        self.directories.insert(key, exists, self.ttl);
+        Ok(exists)
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        let result = self.inner.rename(from, to).await;
+        self.invalidate(from);
+        self.invalidate(to);
+        result
+    }
+}
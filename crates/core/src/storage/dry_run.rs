@@ -0,0 +1,398 @@
+//! Dry-run storage
+//!
+//! [`DryRunStorage`] wraps another backend and records writes, deletes and new
+//! directories in memory instead of applying them. Reads see the recorded
+//! changes, so a save or export behaves exactly as it would for real; afterwards
+//! [`DryRunStorage::changeset`] reports which files would be created, modified
+//! or deleted, with a unified diff of their content.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::export::workspace::export_workspace;
+//! use data_modelling_core::storage::dry_run::DryRunStorage;
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//!
+//! let backend = FileSystemStorageBackend::new("/path/to/repo");
+//! let preview = DryRunStorage::new(&backend);
+//! export_workspace(&preview, "workspace", &["avro", "sql"], "dist").await?;
+//!
+//! let changeset = preview.changeset();
+//! println!("You are about to modify {} files", changeset.change_count());
+//! ```
+
+use super::{StorageBackend, StorageError};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+/// How a file would change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Deleted,
+    /// Written with the content it already has
+    Unchanged,
+}
+
+/// A file that would be written or deleted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileChange {
+    /// Path of the file, relative to the storage backend
+    pub path: String,
+    pub kind: FileChangeKind,
+    /// Unified diff of the content; absent for unchanged and binary files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+impl FileChange {
+    /// Compare the current content of a file with the content that would be written
+    ///
+    /// `None` on either side means the file does not exist.
+    pub fn compare(path: &str, before: Option<&[u8]>, after: Option<&[u8]>) -> Option<Self> {
+        let kind = match (before, after) {
+            (None, None) => return None,
+            (None, Some(_)) => FileChangeKind::Created,
+            (Some(_), None) => FileChangeKind::Deleted,
+            (Some(old), Some(new)) if old == new => FileChangeKind::Unchanged,
+            (Some(_), Some(_)) => FileChangeKind::Modified,
+        };
+        let diff = match kind {
+            FileChangeKind::Unchanged => None,
+            _ => text_diff(path, before.unwrap_or_default(), after.unwrap_or_default()),
+        };
+        Some(Self {
+            path: path.to_string(),
+            kind,
+            diff,
+        })
+    }
+}
+
+/// Files that a save or export would touch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Changeset {
+    /// Touched files, sorted by path
+    pub files: Vec<FileChange>,
+    /// Directories that would be created
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directories: Vec<String>,
+}
+
+impl Changeset {
+    /// Files of the given kind
+    pub fn of_kind(&self, kind: FileChangeKind) -> impl Iterator<Item = &FileChange> {
+        self.files.iter().filter(move |file| file.kind == kind)
+    }
+
+    /// Number of files that would be created, modified or deleted
+    pub fn change_count(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.kind != FileChangeKind::Unchanged)
+            .count()
+    }
+
+    /// Returns true when nothing on storage would change
+    pub fn is_empty(&self) -> bool {
+        self.change_count() == 0 && self.directories.is_empty()
+    }
+}
+
+/// Storage backend that records changes instead of applying them
+pub struct DryRunStorage<B: StorageBackend> {
+    inner: B,
+    /// Content of each touched file before the first change (`None` if absent)
+    originals: Mutex<BTreeMap<String, Option<Vec<u8>>>>,
+    /// Pending content of each touched file (`None` if deleted)
+    pending: Mutex<BTreeMap<String, Option<Vec<u8>>>>,
+    directories: Mutex<BTreeSet<String>>,
+}
+
+impl<B: StorageBackend> DryRunStorage<B> {
+    /// Wrap `inner`; nothing is ever written to it
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            originals: Mutex::new(BTreeMap::new()),
+            pending: Mutex::new(BTreeMap::new()),
+            directories: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    /// The wrapped backend
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// The changes recorded so far
+    pub fn changeset(&self) -> Changeset {
+        let originals = self.originals.lock().unwrap();
+        let pending = self.pending.lock().unwrap();
+        Changeset {
+            files: pending
+                .iter()
+                .filter_map(|(path, after)| {
+                    let before = originals.get(path).and_then(|c| c.as_deref());
+                    FileChange::compare(path, before, after.as_deref())
+                })
+                .collect(),
+            directories: self.directories.lock().unwrap().iter().cloned().collect(),
+        }
+    }
+
+    /// Pending content of every touched file (`None` if deleted), sorted by path
+    pub(crate) fn pending_files(&self) -> Vec<(String, Option<Vec<u8>>)> {
+        self.pending
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, content)| (path.clone(), content.clone()))
+            .collect()
+    }
+
+    /// Content of `path` before its first change (`None` if absent or untouched)
+    pub(crate) fn original(&self, path: &str) -> Option<Vec<u8>> {
+        self.originals.lock().unwrap().get(path).cloned().flatten()
+    }
+
+    /// Directories created so far, sorted
+    pub(crate) fn pending_directories(&self) -> Vec<String> {
+        self.directories.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Pending state of `path`: `Some(None)` if deleted, `None` if untouched
+    fn pending(&self, path: &str) -> Option<Option<Vec<u8>>> {
+        self.pending.lock().unwrap().get(path).cloned()
+    }
+
+    /// Remember the stored content of `path` before its first change
+    async fn record_original(&self, path: &str) -> Result<(), StorageError> {
+        if self.originals.lock().unwrap().contains_key(path) {
+            return Ok(());
+        }
+        let original = if self.inner.file_exists(path).await? {
+            Some(self.inner.read_file(path).await?)
+        } else {
+            None
+        };
+        self.originals
+            .lock()
+            .unwrap()
+            .entry(path.to_string())
+            .or_insert(original);
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl<B: StorageBackend> StorageBackend for DryRunStorage<B> {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        match self.pending(path) {
+            Some(Some(content)) => Ok(content),
+            Some(None) => Err(StorageError::FileNotFound(path.to_string())),
+            None => self.inner.read_file(path).await,
+        }
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        self.record_original(path).await?;
+        self.pending
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), Some(content.to_vec()));
+        Ok(())
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        let mut files: BTreeSet<String> = match self.inner.list_files(dir).await {
+            Ok(files) => files.into_iter().collect(),
+            // The directory may only exist in the dry run
+            Err(_) if self.directories.lock().unwrap().contains(dir) => BTreeSet::new(),
+            Err(e) => return Err(e),
+        };
+        let prefix = format!("{}/", dir.trim_end_matches('/'));
+        for (path, content) in self.pending.lock().unwrap().iter() {
+            if let Some(name) = path.strip_prefix(&prefix)
+                && !name.contains('/')
+            {
+                if content.is_some() {
+                    files.insert(name.to_string());
+                } else {
+                    files.remove(name);
+                }
+            }
+        }
+        Ok(files.into_iter().collect())
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        match self.pending(path) {
+            Some(content) => Ok(content.is_some()),
+            None => self.inner.file_exists(path).await,
+        }
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        if !self.file_exists(path).await? {
+            return Err(StorageError::FileNotFound(path.to_string()));
+        }
+        self.record_original(path).await?;
+        self.pending.lock().unwrap().insert(path.to_string(), None);
+        Ok(())
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        if !self.dir_exists(path).await? {
+            self.directories.lock().unwrap().insert(path.to_string());
+        }
+        Ok(())
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        if self.directories.lock().unwrap().contains(path) {
+            return Ok(true);
+        }
+        self.inner.dir_exists(path).await
+    }
+}
+
+/// Unified diff between two versions of a text file, `None` for binary content
+fn text_diff(path: &str, before: &[u8], after: &[u8]) -> Option<String> {
+    let before = std::str::from_utf8(before).ok()?;
+    let after = std::str::from_utf8(after).ok()?;
+    Some(
+        TextDiff::from_lines(before, after)
+            .unified_diff()
+            .header(&format!("a/{}", path), &format!("b/{}", path))
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemoryStorage {
+        files: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait(?Send)]
+    impl StorageBackend for MemoryStorage {
+        async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .get(path)
+                .cloned()
+                .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+        }
+
+        async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+            self.files
+                .lock()
+                .unwrap()
+                .insert(path.to_string(), content.to_vec());
+            Ok(())
+        }
+
+        async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+            let prefix = format!("{}/", dir);
+            Ok(self
+                .files
+                .lock()
+                .unwrap()
+                .keys()
+                .filter_map(|path| path.strip_prefix(&prefix))
+                .map(str::to_string)
+                .collect())
+        }
+
+        async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+            Ok(self.files.lock().unwrap().contains_key(path))
+        }
+
+        async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+            self.files.lock().unwrap().remove(path);
+            Ok(())
+        }
+
+        async fn create_dir(&self, _path: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+
+        async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+            Ok(path == "ws")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_records_changes_without_writing() {
+        let storage = MemoryStorage::default();
+        for (path, content) in [
+            ("ws/a.yaml", "name: a\nversion: 1\n"),
+            ("ws/b.yaml", "name: b\n"),
+            ("ws/c.yaml", "name: c\n"),
+        ] {
+            storage.write_file(path, content.as_bytes()).await.unwrap();
+        }
+
+        let preview = DryRunStorage::new(&storage);
+        preview
+            .write_file("ws/a.yaml", b"name: a\nversion: 2\n")
+            .await
+            .unwrap();
+        preview.write_file("ws/b.yaml", b"name: b\n").await.unwrap();
+        preview.delete_file("ws/c.yaml").await.unwrap();
+        preview.create_dir("ws/new").await.unwrap();
+        preview.write_file("ws/new/d.yaml", b"d").await.unwrap();
+
+        // Reads see the pending state
+        assert_eq!(
+            preview.read_file("ws/a.yaml").await.unwrap(),
+            b"name: a\nversion: 2\n"
+        );
+        assert!(!preview.file_exists("ws/c.yaml").await.unwrap());
+        assert!(preview.dir_exists("ws/new").await.unwrap());
+        assert_eq!(preview.list_files("ws/new").await.unwrap(), vec!["d.yaml"]);
+
+        // Nothing reached the wrapped backend
+        assert_eq!(
+            storage.read_file("ws/a.yaml").await.unwrap(),
+            b"name: a\nversion: 1\n"
+        );
+        assert!(storage.file_exists("ws/c.yaml").await.unwrap());
+        assert!(!storage.file_exists("ws/new/d.yaml").await.unwrap());
+
+        let changeset = preview.changeset();
+        let kinds: Vec<_> = changeset
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("ws/a.yaml", FileChangeKind::Modified),
+                ("ws/b.yaml", FileChangeKind::Unchanged),
+                ("ws/c.yaml", FileChangeKind::Deleted),
+                ("ws/new/d.yaml", FileChangeKind::Created),
+            ]
+        );
+        assert_eq!(changeset.change_count(), 3);
+        assert_eq!(changeset.directories, vec!["ws/new"]);
+        let diff = changeset.files[0].diff.as_deref().unwrap();
+        assert!(diff.contains("--- a/ws/a.yaml"));
+        assert!(diff.contains("-version: 1\n+version: 2\n"));
+        assert!(changeset.files[1].diff.is_none());
+    }
+}
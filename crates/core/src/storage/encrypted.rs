@@ -0,0 +1,311 @@
+//! Encrypted storage
+//!
+//! [`EncryptedStorageBackend`] wraps another backend and encrypts file contents
+//! with AES-256-GCM before they reach it, for workspaces containing sensitive
+//! schema metadata and connection details. Paths are not encrypted, so listing
+//! and existence checks work unchanged on the wrapped backend.
+//!
+//! The key is either a raw 256-bit key or derived from a passphrase with
+//! Argon2id. Each file starts with a small header recording how its key was
+//! obtained, including the salt for passphrase-derived keys, followed by a
+//! random nonce and the ciphertext. The header and the file's normalized path
+//! are authenticated along with the content, so tampering with either, or
+//! copying one file's ciphertext over another's, is detected on read. A
+//! consequence is that encrypted files cannot be moved or renamed on the
+//! wrapped backend; read and rewrite them through this backend instead.
+//!
+//! The passphrase and derived keys are held in [`Zeroizing`] buffers, and the
+//! expanded AES keys are wiped when their ciphers are dropped. Ciphers derived
+//! for the salts of files read are cached, at most [`MAX_DERIVED_CIPHERS`] of
+//! them.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::storage::encrypted::EncryptedStorageBackend;
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//!
+//! let backend = EncryptedStorageBackend::with_passphrase(
+//!     FileSystemStorageBackend::new("/path/to/workspace"),
+//!     &std::env::var("ODM_PASSPHRASE")?,
+//! )?;
+//! backend.write_file("connections.yaml", b"password: secret").await?;
+//! ```
+
+use super::{StorageBackend, StorageError};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use zeroize::Zeroizing;
+
+/// Marker at the start of every encrypted file
+const MAGIC: &[u8; 6] = b"ODMENC";
+
+/// Version of the encrypted file format
+const FORMAT_VERSION: u8 = 1;
+
+/// Key derivation recorded in the header: a raw key
+const KDF_NONE: u8 = 0;
+
+/// Key derivation recorded in the header: Argon2id from a passphrase
+const KDF_ARGON2ID: u8 = 1;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Length in bytes of a raw encryption key
+pub const KEY_LEN: usize = 32;
+
+/// Maximum number of passphrase-derived ciphers kept per backend
+pub const MAX_DERIVED_CIPHERS: usize = 64;
+
+/// Ciphers derived from a passphrase, by salt
+type CipherCache = Mutex<HashMap<[u8; SALT_LEN], Arc<Aes256Gcm>>>;
+
+enum KeySource {
+    Key(Arc<Aes256Gcm>),
+    Passphrase {
+        passphrase: Zeroizing<String>,
+        /// Salt used for new files
+        salt: [u8; SALT_LEN],
+        /// Ciphers derived so far, by salt
+        derived: CipherCache,
+    },
+}
+
+/// Storage backend that encrypts file contents at rest
+pub struct EncryptedStorageBackend<B: StorageBackend> {
+    inner: B,
+    key: KeySource,
+}
+
+impl<B: StorageBackend> EncryptedStorageBackend<B> {
+    /// Wrap `inner`, encrypting with a raw 256-bit key
+    pub fn new(inner: B, key: [u8; KEY_LEN]) -> Self {
+        let key = Zeroizing::new(key);
+        Self {
+            inner,
+            key: KeySource::Key(Arc::new(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(
+                key.as_ref(),
+            )))),
+        }
+    }
+
+    /// Wrap `inner`, encrypting with a key derived from a passphrase
+    ///
+    /// New files are written with a fresh random salt; files written by other
+    /// instances are read with the salt recorded in their header.
+    pub fn with_passphrase(inner: B, passphrase: &str) -> Result<Self, StorageError> {
+        if passphrase.is_empty() {
+            return Err(StorageError::BackendError(
+                "Encryption passphrase must not be empty".to_string(),
+            ));
+        }
+
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let cipher = derive_cipher(passphrase, &salt)?;
+        Ok(Self {
+            inner,
+            key: KeySource::Passphrase {
+                passphrase: Zeroizing::new(passphrase.to_string()),
+                salt,
+                derived: Mutex::new(HashMap::from([(salt, Arc::new(cipher))])),
+            },
+        })
+    }
+
+    /// Generate a random 256-bit key for [`EncryptedStorageBackend::new`]
+    pub fn generate_key() -> [u8; KEY_LEN] {
+        let mut key = [0u8; KEY_LEN];
+        OsRng.fill_bytes(&mut key);
+        key
+    }
+
+    /// The wrapped backend
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Encrypt file content into the on-disk format
+    fn encrypt(&self, path: &str, content: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let mut output = Vec::with_capacity(content.len() + 64);
+        output.extend_from_slice(MAGIC);
+        output.push(FORMAT_VERSION);
+        let cipher = match &self.key {
+            KeySource::Key(cipher) => {
+                output.push(KDF_NONE);
+                Arc::clone(cipher)
+            }
+            KeySource::Passphrase { salt, .. } => {
+                output.push(KDF_ARGON2ID);
+                output.extend_from_slice(salt);
+                self.passphrase_cipher(salt)?
+            }
+        };
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        output.extend_from_slice(&nonce);
+        let ciphertext = cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: content,
+                    aad: &associated_data(&output, path),
+                },
+            )
+            .map_err(|_| StorageError::BackendError(format!("Failed to encrypt {}", path)))?;
+        output.extend_from_slice(&ciphertext);
+        Ok(output)
+    }
+
+    /// Decrypt file content from the on-disk format
+    fn decrypt(&self, path: &str, data: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let invalid = |reason: &str| StorageError::BackendError(format!("{} {}", path, reason));
+
+        if data.len() < MAGIC.len() + 2 || &data[..MAGIC.len()] != MAGIC {
+            return Err(invalid("is not encrypted"));
+        }
+        if data[MAGIC.len()] != FORMAT_VERSION {
+            return Err(invalid("uses an unsupported encryption format version"));
+        }
+
+        let mut offset = MAGIC.len() + 2;
+        let cipher = match (data[MAGIC.len() + 1], &self.key) {
+            (KDF_NONE, KeySource::Key(cipher)) => Arc::clone(cipher),
+            (KDF_ARGON2ID, KeySource::Passphrase { .. }) => {
+                let salt: [u8; SALT_LEN] = data
+                    .get(offset..offset + SALT_LEN)
+                    .and_then(|salt| salt.try_into().ok())
+                    .ok_or_else(|| invalid("has a truncated encryption header"))?;
+                offset += SALT_LEN;
+                self.passphrase_cipher(&salt)?
+            }
+            (KDF_NONE, _) => return Err(invalid("is encrypted with a key, not a passphrase")),
+            (KDF_ARGON2ID, _) => {
+                return Err(invalid("is encrypted with a passphrase, not a key"));
+            }
+            _ => return Err(invalid("uses an unsupported key derivation")),
+        };
+
+        if data.len() < offset + NONCE_LEN {
+            return Err(invalid("has a truncated encryption header"));
+        }
+        let (header, ciphertext) = data.split_at(offset + NONCE_LEN);
+        cipher
+            .decrypt(
+                Nonce::from_slice(&header[offset..]),
+                Payload {
+                    msg: ciphertext,
+                    aad: &associated_data(header, path),
+                },
+            )
+            .map_err(|_| {
+                StorageError::PermissionDenied(format!(
+                    "Failed to decrypt {}: wrong key or corrupted content",
+                    path
+                ))
+            })
+    }
+
+    /// Cipher for a passphrase-derived key, deriving it on first use of a salt
+    ///
+    /// Once [`MAX_DERIVED_CIPHERS`] are cached, another salt's cipher is evicted
+    /// to make room; the cipher for new files is always kept.
+    fn passphrase_cipher(&self, salt: &[u8; SALT_LEN]) -> Result<Arc<Aes256Gcm>, StorageError> {
+        let KeySource::Passphrase {
+            passphrase,
+            salt: own_salt,
+            derived,
+        } = &self.key
+        else {
+            return Err(StorageError::BackendError(
+                "Passphrase cipher requested for a backend with a raw key".to_string(),
+            ));
+        };
+
+        if let Some(cipher) = lock_ciphers(derived).get(salt) {
+            return Ok(Arc::clone(cipher));
+        }
+        let cipher = Arc::new(derive_cipher(passphrase, salt)?);
+        let mut ciphers = lock_ciphers(derived);
+        if ciphers.len() >= MAX_DERIVED_CIPHERS
+            && let Some(evicted) = ciphers.keys().find(|s| *s != own_salt).copied()
+        {
+            ciphers.remove(&evicted);
+        }
+        ciphers.insert(*salt, Arc::clone(&cipher));
+        Ok(cipher)
+    }
+}
+
+/// Lock the derived cipher cache
+///
+/// The cache only ever gains complete entries, so it is still consistent if a
+/// thread panicked while holding the lock.
+fn lock_ciphers(derived: &CipherCache) -> MutexGuard<'_, HashMap<[u8; SALT_LEN], Arc<Aes256Gcm>>> {
+    derived.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Associated data authenticated with a file: its header followed by its
+/// normalized path, so that `a/b`, `/a/b` and `./a/b` are the same file
+fn associated_data(header: &[u8], path: &str) -> Vec<u8> {
+    let path = path
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect::<Vec<_>>()
+        .join("/");
+    let mut aad = Vec::with_capacity(header.len() + path.len());
+    aad.extend_from_slice(header);
+    aad.extend_from_slice(path.as_bytes());
+    aad
+}
+
+/// Derive an AES-256-GCM cipher from a passphrase with Argon2id
+fn derive_cipher(passphrase: &str, salt: &[u8; SALT_LEN]) -> Result<Aes256Gcm, StorageError> {
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| StorageError::BackendError(format!("Failed to derive key: {}", e)))?;
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_ref())))
+}
+
+#[async_trait(?Send)]
+impl<B: StorageBackend> StorageBackend for EncryptedStorageBackend<B> {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        let data = self.inner.read_file(path).await?;
+        self.decrypt(path, &data)
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        let data = self.encrypt(path, content)?;
+        self.inner.write_file(path, &data).await
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        self.inner.list_files(dir).await
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.inner.file_exists(path).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.inner.delete_file(path).await
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        self.inner.create_dir(path).await
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.inner.dir_exists(path).await
+    }
+
+    // `rename` keeps the default copy-and-delete: the path is authenticated
+    // with the content, so a moved file must be re-encrypted for its new path
+}
@@ -235,6 +235,25 @@ impl StorageBackend for FileSystemStorageBackend {
             }
         }
     }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        let from_path = self.resolve_path(from)?;
+        let to_path = self.resolve_path(to)?;
+
+        if let Some(parent) = to_path.parent() {
+            fs::create_dir_all(parent).await.map_err(|e| {
+                StorageError::IoError(format!("Failed to create directory for {}: {}", to, e))
+            })?;
+        }
+
+        fs::rename(&from_path, &to_path).await.map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                StorageError::FileNotFound(from.to_string())
+            } else {
+                StorageError::IoError(format!("Failed to rename {} to {}: {}", from, to, e))
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,66 @@
+//! Google Cloud Storage backend
+//!
+//! Stores workspaces in a Google Cloud Storage bucket through the shared
+//! [`ObjectStorageBackend`].
+//!
+//! Credentials are read from the standard `GOOGLE_*` environment variables
+//! (`GOOGLE_SERVICE_ACCOUNT`, `GOOGLE_SERVICE_ACCOUNT_KEY`,
+//! `GOOGLE_APPLICATION_CREDENTIALS`, ...), falling back to the instance metadata
+//! server. For other setups, configure a [`GoogleCloudStorageBuilder`] and pass it
+//! to [`GcsStorageBackend::from_builder`].
+
+use super::StorageError;
+use super::object_storage::{ObjectStorageBackend, split_url};
+pub use object_store::gcp::{GoogleCloudStorage, GoogleCloudStorageBuilder};
+
+/// Google Cloud Storage backend
+pub type GcsStorageBackend = ObjectStorageBackend<GoogleCloudStorage>;
+
+impl ObjectStorageBackend<GoogleCloudStorage> {
+    /// Create a backend for a bucket, with credentials from the environment
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use data_modelling_core::storage::gcs::GcsStorageBackend;
+    ///
+    /// let backend = GcsStorageBackend::from_env("models")
+    ///     .unwrap()
+    ///     .with_prefix("workspaces/sales");
+    /// ```
+    pub fn from_env(bucket: &str) -> Result<Self, StorageError> {
+        Self::from_builder(GoogleCloudStorageBuilder::from_env().with_bucket_name(bucket))
+    }
+
+    /// Create a backend from a `gs://<bucket>/<prefix>` URL, with credentials from
+    /// the environment; the path below the bucket becomes the key prefix
+    pub fn from_url(url: &str) -> Result<Self, StorageError> {
+        let (base, prefix) = split_url(url)?;
+        Ok(
+            Self::from_builder(GoogleCloudStorageBuilder::from_env().with_url(base))?
+                .with_prefix(prefix),
+        )
+    }
+
+    /// Create a backend from a configured `object_store` builder
+    pub fn from_builder(builder: GoogleCloudStorageBuilder) -> Result<Self, StorageError> {
+        let store = builder.build().map_err(|e| {
+            StorageError::BackendError(format!("Failed to configure Google Cloud Storage: {}", e))
+        })?;
+        Ok(Self::new(store))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_builder() {
+        let builder = GoogleCloudStorageBuilder::new().with_bucket_name("models");
+        assert!(GcsStorageBackend::from_builder(builder).is_ok());
+
+        let err = GcsStorageBackend::from_builder(GoogleCloudStorageBuilder::new()).err();
+        assert!(matches!(err, Some(StorageError::BackendError(_))));
+    }
+}
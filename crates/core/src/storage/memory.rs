@@ -0,0 +1,231 @@
+//! In-memory storage backend
+//!
+//! Implements StorageBackend on a map of paths to file contents, for tests and
+//! for applications that build or transform models without touching disk.
+//!
+//! The backend behaves like the file system backend: writing a file creates its
+//! parent directories, listing a missing directory fails, and paths containing
+//! ".." are rejected. Clones share the same files, so one handle can be given to
+//! a [`ModelSaver`](crate::model::ModelSaver) and another to a
+//! [`ModelLoader`](crate::model::ModelLoader).
+
+use super::{StorageBackend, StorageError};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    files: BTreeMap<String, Vec<u8>>,
+    /// Directories other than the root
+    directories: BTreeSet<String>,
+}
+
+impl MemoryState {
+    fn is_dir(&self, path: &str) -> bool {
+        path.is_empty() || self.directories.contains(path)
+    }
+
+    /// Create a directory and its ancestors
+    fn create_dirs(&mut self, path: &str) -> Result<(), StorageError> {
+        let mut current = String::new();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            if !current.is_empty() {
+                current.push('/');
+            }
+            current.push_str(segment);
+            if self.files.contains_key(&current) {
+                return Err(StorageError::IoError(format!(
+                    "Cannot create directory {}: {} is a file",
+                    path, current
+                )));
+            }
+            self.directories.insert(current.clone());
+        }
+        Ok(())
+    }
+}
+
+/// In-memory storage backend
+///
+/// Thread-safe; clones share the same files.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorageBackend {
+    state: Arc<RwLock<MemoryState>>,
+}
+
+impl MemoryStorageBackend {
+    /// Create an empty in-memory storage backend
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::storage::memory::MemoryStorageBackend;
+    ///
+    /// let backend = MemoryStorageBackend::new()
+    ///     .with_file("workspace/orders.odcs.yaml", "name: orders");
+    /// assert_eq!(backend.paths(), vec!["workspace/orders.odcs.yaml"]);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, creating its parent directories
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is invalid or a parent directory is a file.
+    pub fn with_file(self, path: &str, content: impl AsRef<[u8]>) -> Self {
+        self.insert(path, content.as_ref())
+            .unwrap_or_else(|e| panic!("Cannot add {}: {}", path, e));
+        self
+    }
+
+    /// Paths of all files, in sorted order
+    pub fn paths(&self) -> Vec<String> {
+        self.state.read().unwrap().files.keys().cloned().collect()
+    }
+
+    /// Content of all files, keyed by path
+    pub fn files(&self) -> BTreeMap<String, Vec<u8>> {
+        self.state.read().unwrap().files.clone()
+    }
+
+    /// Remove all files and directories
+    pub fn clear(&self) {
+        let mut state = self.state.write().unwrap();
+        state.files.clear();
+        state.directories.clear();
+    }
+
+    fn insert(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        let key = normalize_path(path)?;
+        if key.is_empty() {
+            return Err(StorageError::IoError("Empty file path".to_string()));
+        }
+
+        let mut state = self.state.write().unwrap();
+        if state.directories.contains(&key) {
+            return Err(StorageError::IoError(format!(
+                "Failed to write file {}: is a directory",
+                path
+            )));
+        }
+        if let Some((parent, _)) = key.rsplit_once('/') {
+            state.create_dirs(parent)?;
+        }
+        state.files.insert(key, content.to_vec());
+        Ok(())
+    }
+}
+
+/// Normalize a path to its `/`-separated segments with security checks.
+///
+/// # Security
+///
+/// Rejects paths containing ".." components, so that the backend behaves like
+/// the file system backend.
+fn normalize_path(path: &str) -> Result<String, StorageError> {
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    if segments.contains(&"..") {
+        return Err(StorageError::PermissionDenied(
+            "Path traversal (..) not allowed".to_string(),
+        ));
+    }
+    Ok(segments.join("/"))
+}
+
+#[async_trait(?Send)]
+impl StorageBackend for MemoryStorageBackend {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        let key = normalize_path(path)?;
+        self.state
+            .read()
+            .unwrap()
+            .files
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        self.insert(path, content)
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        let key = normalize_path(dir)?;
+        let state = self.state.read().unwrap();
+        if !state.is_dir(&key) {
+            return Err(StorageError::DirectoryNotFound(dir.to_string()));
+        }
+
+        let prefix = if key.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", key)
+        };
+        Ok(state
+            .files
+            .range(prefix.clone()..)
+            .map(|(path, _)| path)
+            .take_while(|path| path.starts_with(&prefix))
+            .map(|path| &path[prefix.len()..])
+            .filter(|name| !name.contains('/'))
+            .map(str::to_string)
+            .collect())
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        let key = normalize_path(path)?;
+        Ok(self.state.read().unwrap().files.contains_key(&key))
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        let key = normalize_path(path)?;
+        self.state
+            .write()
+            .unwrap()
+            .files
+            .remove(&key)
+            .map(|_| ())
+            .ok_or_else(|| StorageError::FileNotFound(path.to_string()))
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        let key = normalize_path(path)?;
+        self.state.write().unwrap().create_dirs(&key)
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        let key = normalize_path(path)?;
+        Ok(self.state.read().unwrap().is_dir(&key))
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        let from_key = normalize_path(from)?;
+        let to_key = normalize_path(to)?;
+        if to_key.is_empty() {
+            return Err(StorageError::IoError("Empty file path".to_string()));
+        }
+
+        let mut state = self.state.write().unwrap();
+        if !state.files.contains_key(&from_key) {
+            return Err(StorageError::FileNotFound(from.to_string()));
+        }
+        if state.directories.contains(&to_key) {
+            return Err(StorageError::IoError(format!(
+                "Failed to rename {} to {}: is a directory",
+                from, to
+            )));
+        }
+        if let Some((parent, _)) = to_key.rsplit_once('/') {
+            state.create_dirs(parent)?;
+        }
+        let content = state.files.remove(&from_key).unwrap_or_default();
+        state.files.insert(to_key, content);
+        Ok(())
+    }
+}
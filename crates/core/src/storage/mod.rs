@@ -4,6 +4,17 @@
 //! - FileSystemStorageBackend: Native file system (for native apps)
 //! - BrowserStorageBackend: Browser storage APIs (for WASM apps)
 //! - ApiStorageBackend: HTTP API (for online mode, default)
+//! - MemoryStorageBackend: In-memory map (for tests and ephemeral workspaces)
+//! - ObjectStorageBackend: Object stores (feature `object-storage`), with the cloud backends
+//!   AzureBlobStorageBackend (feature `azure-storage`) and GcsStorageBackend (feature `gcs-storage`)
+//! - DryRunStorage: Wraps another backend and records changes instead of writing them
+//! - TransactionStorage: Wraps another backend and stages changes, applying all or none
+//!   of them on commit
+//! - CachedStorageBackend: Wraps another backend and caches reads, with expiry and
+//!   invalidation on write
+//! - EncryptedStorageBackend: Wraps another backend and encrypts file contents at rest
+//!   (feature `encryption`)
+//! - TenantStorage: Wraps another backend and confines it to one tenant's directory
 
 use async_trait::async_trait;
 
@@ -52,14 +63,80 @@ pub trait StorageBackend: Send + Sync {
 
     /// Check if a directory exists
     async fn dir_exists(&self, path: &str) -> Result<bool, StorageError>;
+
+    /// Move a file to a new path, replacing any file already there
+    ///
+    /// Backends that rename natively (the file system, memory) replace the
+    /// target atomically. The default implementation copies the content and
+    /// then deletes the source, so it is not atomic.
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        let content = self.read_file(from).await?;
+        self.write_file(to, &content).await?;
+        self.delete_file(from).await
+    }
+}
+
+/// Borrowed backends can be used wherever an owned backend is expected, e.g. to
+/// wrap a backend in a [`dry_run::DryRunStorage`] without giving it up.
+#[async_trait(?Send)]
+impl<B: StorageBackend + ?Sized> StorageBackend for &B {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        (**self).read_file(path).await
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        (**self).write_file(path, content).await
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        (**self).list_files(dir).await
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        (**self).file_exists(path).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        (**self).delete_file(path).await
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        (**self).create_dir(path).await
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        (**self).dir_exists(path).await
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        (**self).rename(from, to).await
+    }
 }
 
 // Storage backend implementations
+pub mod cached;
+pub mod dry_run;
+pub mod memory;
+pub mod tenant;
+pub mod transaction;
+
 #[cfg(feature = "native-fs")]
 pub mod filesystem;
 
 #[cfg(feature = "api-backend")]
 pub mod api;
 
+#[cfg(feature = "object-storage")]
+pub mod object_storage;
+
+#[cfg(feature = "azure-storage")]
+pub mod azure;
+
+#[cfg(feature = "gcs-storage")]
+pub mod gcs;
+
+#[cfg(feature = "encryption")]
+pub mod encrypted;
+
 #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
 pub mod browser;
@@ -0,0 +1,301 @@
+//! Object storage backend
+//!
+//! Implements StorageBackend on top of any [`ObjectStore`] from the `object_store`
+//! crate. The cloud backends are instantiations of [`ObjectStorageBackend`]:
+//! - `AzureBlobStorageBackend`: Azure Blob Storage (feature `azure-storage`)
+//! - `GcsStorageBackend`: Google Cloud Storage (feature `gcs-storage`)
+//!
+//! Object stores have no directories, only keys. Directories are the `/`-separated
+//! prefixes of object keys: creating a directory does nothing, and a directory
+//! exists once an object has been written below it.
+//!
+//! ## Security
+//!
+//! Paths containing ".." are rejected, so all operations stay below the
+//! configured key prefix.
+
+use super::{StorageBackend, StorageError};
+use async_trait::async_trait;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+use std::sync::Arc;
+
+/// Storage backend on an object store
+///
+/// All paths are resolved below an optional key prefix, so that several
+/// workspaces can share a bucket or container.
+pub struct ObjectStorageBackend<S: ObjectStore = Arc<dyn ObjectStore>> {
+    store: S,
+    prefix: Option<Path>,
+}
+
+impl<S: ObjectStore> ObjectStorageBackend<S> {
+    /// Create a new object storage backend
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use data_modelling_core::storage::object_storage::ObjectStorageBackend;
+    /// use object_store::memory::InMemory;
+    ///
+    /// let backend = ObjectStorageBackend::new(InMemory::new()).with_prefix("workspaces/sales");
+    /// ```
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            prefix: None,
+        }
+    }
+
+    /// Resolve all paths below a key prefix
+    pub fn with_prefix(mut self, prefix: impl AsRef<str>) -> Self {
+        let prefix = prefix.as_ref().trim_matches('/');
+        self.prefix = (!prefix.is_empty()).then(|| Path::from(prefix));
+        self
+    }
+
+    /// The underlying object store
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Resolve a path to an object key with security checks.
+    ///
+    /// # Security
+    ///
+    /// Rejects paths with ".." components and any other segment the object
+    /// store does not accept.
+    fn resolve_path(&self, path: &str) -> Result<Option<Path>, StorageError> {
+        let normalized = path.trim_matches('/');
+        if normalized.split('/').any(|segment| segment == "..") {
+            return Err(StorageError::PermissionDenied(
+                "Path traversal (..) not allowed".to_string(),
+            ));
+        }
+
+        let relative = Path::parse(normalized)
+            .map_err(|e| StorageError::BackendError(format!("Invalid path {}: {}", path, e)))?;
+        let key = match &self.prefix {
+            Some(prefix) => prefix.parts().chain(relative.parts()).collect(),
+            None => relative,
+        };
+        Ok((!key.as_ref().is_empty()).then_some(key))
+    }
+
+    /// Resolve a path that must name an object
+    fn resolve_file(&self, path: &str) -> Result<Path, StorageError> {
+        self.resolve_path(path)?
+            .ok_or_else(|| StorageError::BackendError("Empty file path".to_string()))
+    }
+}
+
+/// Map an object store error to a storage error
+fn storage_error(path: &str, action: &str, error: object_store::Error) -> StorageError {
+    match error {
+        object_store::Error::NotFound { .. } => StorageError::FileNotFound(path.to_string()),
+        object_store::Error::PermissionDenied { .. }
+        | object_store::Error::Unauthenticated { .. } => {
+            StorageError::PermissionDenied(format!("Failed to {} {}: {}", action, path, error))
+        }
+        _ => StorageError::BackendError(format!("Failed to {} {}: {}", action, path, error)),
+    }
+}
+
+#[async_trait(?Send)]
+impl<S: ObjectStore> StorageBackend for ObjectStorageBackend<S> {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        let key = self.resolve_file(path)?;
+        let result = self
+            .store
+            .get(&key)
+            .await
+            .map_err(|e| storage_error(path, "read file", e))?;
+        let bytes = result
+            .bytes()
+            .await
+            .map_err(|e| storage_error(path, "read file", e))?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        let key = self.resolve_file(path)?;
+        self.store
+            .put(&key, PutPayload::from(content.to_vec()))
+            .await
+            .map_err(|e| storage_error(path, "write file", e))?;
+        Ok(())
+    }
+
+    /// Lists the objects directly below the directory prefix; a prefix without
+    /// objects lists as empty.
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        let prefix = self.resolve_path(dir)?;
+        let listing = self
+            .store
+            .list_with_delimiter(prefix.as_ref())
+            .await
+            .map_err(|e| storage_error(dir, "list directory", e))?;
+
+        Ok(listing
+            .objects
+            .into_iter()
+            .filter_map(|object| object.location.filename().map(str::to_string))
+            .collect())
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        let key = self.resolve_file(path)?;
+        match self.store.head(&key).await {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::NotFound { .. }) => Ok(false),
+            Err(e) => Err(storage_error(path, "check file existence", e)),
+        }
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        // Deleting a missing object succeeds on most stores
+        if !self.file_exists(path).await? {
+            return Err(StorageError::FileNotFound(path.to_string()));
+        }
+        let key = self.resolve_file(path)?;
+        self.store
+            .delete(&key)
+            .await
+            .map_err(|e| storage_error(path, "delete file", e))
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        // Directories are implied by the keys written below them
+        self.resolve_path(path)?;
+        Ok(())
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        let Some(prefix) = self.resolve_path(path)? else {
+            return Ok(true);
+        };
+        let listing = self
+            .store
+            .list_with_delimiter(Some(&prefix))
+            .await
+            .map_err(|e| storage_error(path, "check directory existence", e))?;
+        Ok(!listing.objects.is_empty() || !listing.common_prefixes.is_empty())
+    }
+}
+
+/// Split a storage URL into the URL understood by the `object_store` builders
+/// and the key prefix below the bucket or container
+///
+/// `https://` URLs name the container in the first path segment
+/// (`https://<account>.blob.core.windows.net/<container>/<prefix>`); all other
+/// schemes name it in the host (`gs://<bucket>/<prefix>`, `az://<container>/<prefix>`).
+#[cfg(any(feature = "azure-storage", feature = "gcs-storage"))]
+pub(crate) fn split_url(url: &str) -> Result<(String, String), StorageError> {
+    let (scheme, rest) = url
+        .split_once("://")
+        .ok_or_else(|| StorageError::BackendError(format!("Invalid storage URL: {}", url)))?;
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let (container, prefix) = if scheme.eq_ignore_ascii_case("https") {
+        path.split_once('/').unwrap_or((path, ""))
+    } else {
+        ("", path)
+    };
+
+    let mut base = format!("{}://{}", scheme, host);
+    if !container.is_empty() {
+        base.push('/');
+        base.push_str(container);
+    }
+    Ok((base, prefix.trim_matches('/').to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    #[tokio::test]
+    async fn test_read_write_list() {
+        let backend = ObjectStorageBackend::new(InMemory::new()).with_prefix("/workspaces/sales/");
+        assert!(!backend.dir_exists("tables").await.unwrap());
+        backend.create_dir("tables").await.unwrap();
+
+        backend
+            .write_file("tables/orders.odcs.yaml", b"name: orders")
+            .await
+            .unwrap();
+        backend
+            .write_file("tables/nested/items.odcs.yaml", b"name: items")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            backend.read_file("/tables/orders.odcs.yaml").await.unwrap(),
+            b"name: orders"
+        );
+        assert_eq!(
+            backend.list_files("tables").await.unwrap(),
+            vec!["orders.odcs.yaml"]
+        );
+        assert!(backend.list_files("missing").await.unwrap().is_empty());
+        assert!(backend.dir_exists("tables").await.unwrap());
+        assert!(backend.dir_exists("tables/nested").await.unwrap());
+        assert!(
+            backend
+                .file_exists("tables/orders.odcs.yaml")
+                .await
+                .unwrap()
+        );
+        assert!(!backend.file_exists("tables").await.unwrap());
+
+        let key = Path::from("workspaces/sales/tables/orders.odcs.yaml");
+        assert!(backend.store().head(&key).await.is_ok());
+
+        backend
+            .delete_file("tables/orders.odcs.yaml")
+            .await
+            .unwrap();
+        assert!(matches!(
+            backend.read_file("tables/orders.odcs.yaml").await,
+            Err(StorageError::FileNotFound(_))
+        ));
+        assert!(matches!(
+            backend.delete_file("tables/orders.odcs.yaml").await,
+            Err(StorageError::FileNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_path_traversal_rejected() {
+        let backend = ObjectStorageBackend::new(InMemory::new()).with_prefix("tenant-a");
+        assert!(matches!(
+            backend.read_file("../tenant-b/secret.yaml").await,
+            Err(StorageError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            backend.write_file("tables/../../x.yaml", b"").await,
+            Err(StorageError::PermissionDenied(_))
+        ));
+    }
+
+    #[cfg(any(feature = "azure-storage", feature = "gcs-storage"))]
+    #[test]
+    fn test_split_url() {
+        assert_eq!(
+            split_url("gs://models/workspaces/sales/").unwrap(),
+            ("gs://models".to_string(), "workspaces/sales".to_string())
+        );
+        assert_eq!(
+            split_url("https://acct.blob.core.windows.net/models/sales").unwrap(),
+            (
+                "https://acct.blob.core.windows.net/models".to_string(),
+                "sales".to_string()
+            )
+        );
+        assert_eq!(
+            split_url("az://models").unwrap(),
+            ("az://models".to_string(), String::new())
+        );
+        assert!(split_url("models/sales").is_err());
+    }
+}
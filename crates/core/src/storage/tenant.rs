@@ -0,0 +1,234 @@
+//! Tenant-scoped storage
+//!
+//! One deployment can host the workspaces of several tenants in one storage
+//! root. [`TenantStorage`] wraps another backend and resolves every path below
+//! the tenant's own directory, `tenants/{tenant}/`, so code that loads or saves
+//! a workspace through it cannot reach another tenant's files:
+//!
+//! ```text
+//! tenants/
+//!   acme/
+//!     sales/workspace.yaml
+//!     sales/sales_orders_erp_orders.odcs.yaml
+//!   globex/
+//!     ...
+//! ```
+//!
+//! Paths are relative to the tenant directory; absolute paths are treated as
+//! relative, and `..` segments are rejected.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::storage::memory::MemoryStorageBackend;
+//! use data_modelling_core::storage::tenant::{TenantId, TenantStorage};
+//!
+//! let shared = MemoryStorageBackend::new();
+//! let acme = TenantStorage::new(shared.clone(), TenantId::parse("acme").unwrap());
+//! assert_eq!(acme.resolve("sales/workspace.yaml").unwrap(), "tenants/acme/sales/workspace.yaml");
+//! assert!(acme.resolve("../globex/sales/workspace.yaml").is_err());
+//! ```
+
+use super::{StorageBackend, StorageError};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Directory holding one subdirectory per tenant
+pub const TENANTS_DIR: &str = "tenants";
+
+/// Maximum length of a tenant ID
+pub const MAX_TENANT_LENGTH: usize = 63;
+
+/// A validated tenant identifier
+///
+/// Tenant IDs are used in storage paths, API URLs and database queries, so
+/// only alphanumeric characters, hyphens and underscores are allowed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TenantId(String);
+
+impl TenantId {
+    /// Validate a tenant ID
+    pub fn parse(tenant: &str) -> Result<Self, StorageError> {
+        if tenant.is_empty() {
+            return Err(StorageError::BackendError(
+                "Tenant ID cannot be empty".to_string(),
+            ));
+        }
+        if tenant.len() > MAX_TENANT_LENGTH {
+            return Err(StorageError::BackendError(format!(
+                "Tenant ID too long (max {} characters)",
+                MAX_TENANT_LENGTH
+            )));
+        }
+        if !tenant
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(StorageError::BackendError(format!(
+                "Tenant ID '{}' contains invalid characters. Only alphanumeric, hyphens, and underscores are allowed.",
+                tenant
+            )));
+        }
+        Ok(Self(tenant.to_string()))
+    }
+
+    /// The tenant ID as a string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Storage directory of the tenant, relative to the storage root
+    pub fn root(&self) -> String {
+        format!("{}/{}", TENANTS_DIR, self.0)
+    }
+
+    /// Whether `tenant` (e.g. a workspace's or contract's tenant field) names this tenant
+    pub fn matches(&self, tenant: Option<&str>) -> bool {
+        tenant == Some(self.0.as_str())
+    }
+}
+
+impl fmt::Display for TenantId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for TenantId {
+    type Error = StorageError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<TenantId> for String {
+    fn from(tenant: TenantId) -> Self {
+        tenant.0
+    }
+}
+
+/// Storage backend confined to one tenant's directory
+pub struct TenantStorage<B: StorageBackend> {
+    inner: B,
+    tenant: TenantId,
+}
+
+impl<B: StorageBackend> TenantStorage<B> {
+    /// Scope `inner` to the directory of `tenant`
+    pub fn new(inner: B, tenant: TenantId) -> Self {
+        Self { inner, tenant }
+    }
+
+    /// The tenant this storage is scoped to
+    pub fn tenant(&self) -> &TenantId {
+        &self.tenant
+    }
+
+    /// The wrapped backend
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Resolve a tenant-relative path to its path in the wrapped backend
+    pub fn resolve(&self, path: &str) -> Result<String, StorageError> {
+        let segments: Vec<&str> = path
+            .split(['/', '\\'])
+            .filter(|s| !s.is_empty() && *s != ".")
+            .collect();
+        if segments.contains(&"..") {
+            return Err(StorageError::PermissionDenied(format!(
+                "Path '{}' escapes the directory of tenant '{}'",
+                path, self.tenant
+            )));
+        }
+
+        let root = self.tenant.root();
+        Ok(if segments.is_empty() {
+            root
+        } else {
+            format!("{}/{}", root, segments.join("/"))
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl<B: StorageBackend> StorageBackend for TenantStorage<B> {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        self.inner.read_file(&self.resolve(path)?).await
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        self.inner.write_file(&self.resolve(path)?, content).await
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        self.inner.list_files(&self.resolve(dir)?).await
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.inner.file_exists(&self.resolve(path)?).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.inner.delete_file(&self.resolve(path)?).await
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        self.inner.create_dir(&self.resolve(path)?).await
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.inner.dir_exists(&self.resolve(path)?).await
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        self.inner
+            .rename(&self.resolve(from)?, &self.resolve(to)?)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::memory::MemoryStorageBackend;
+
+    #[test]
+    fn test_tenant_id_validation() {
+        assert!(TenantId::parse("acme-corp_1").is_ok());
+        assert!(TenantId::parse("").is_err());
+        assert!(TenantId::parse("../globex").is_err());
+        assert!(TenantId::parse("acme/sales").is_err());
+        assert!(TenantId::parse(&"a".repeat(MAX_TENANT_LENGTH + 1)).is_err());
+        assert!(serde_json::from_str::<TenantId>("\"a b\"").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tenants_are_isolated() {
+        let shared = MemoryStorageBackend::new();
+        let acme = TenantStorage::new(shared.clone(), TenantId::parse("acme").unwrap());
+        let globex = TenantStorage::new(shared.clone(), TenantId::parse("globex").unwrap());
+
+        acme.write_file("/sales/workspace.yaml", b"name: sales")
+            .await
+            .unwrap();
+        assert!(
+            shared
+                .file_exists("tenants/acme/sales/workspace.yaml")
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            acme.list_files("sales").await.unwrap(),
+            vec!["workspace.yaml".to_string()]
+        );
+        assert!(!globex.file_exists("sales/workspace.yaml").await.unwrap());
+        assert!(matches!(
+            globex.read_file("../acme/sales/workspace.yaml").await,
+            Err(StorageError::PermissionDenied(_))
+        ));
+    }
+}
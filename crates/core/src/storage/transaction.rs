@@ -0,0 +1,427 @@
+//! Transactional storage
+//!
+//! [`TransactionStorage`] wraps another backend and stages writes, deletes and
+//! new directories in memory, like [`DryRunStorage`]. Reads see the staged
+//! changes. Nothing reaches the wrapped backend until
+//! [`TransactionStorage::commit`], which applies all changes or none:
+//!
+//! 1. **Validate** - every staged `.yaml`/`.yml` and `.json` file must parse,
+//!    and must pass the validators added with [`TransactionStorage::with_validator`]
+//! 2. **Check for conflicts** - files changed on the backend since they were
+//!    staged abort the commit
+//! 3. **Stage** - staged content is written to temporary `<path>.odm-tx` files
+//!    next to its target, so backend failures (permissions, quota, network)
+//!    surface before any target file is touched. A journal listing the
+//!    changes is written first, to [`JOURNAL_PATH`] with the same suffix
+//! 4. **Commit point** - the journal is renamed to [`JOURNAL_PATH`]
+//! 5. **Promote** - each temporary file is renamed over its target and deleted
+//!    files are removed, then the journal is deleted. If a step fails, the
+//!    targets already changed are restored to their previous content. If
+//!    restoring fails too, the journal and staged files are kept so that
+//!    [`TransactionStorage::recover`] can complete the commit
+//!
+//! # Guarantees
+//!
+//! Each file is replaced with [`StorageBackend::rename`], which is atomic on the
+//! file system and memory backends, so a target holds either its old or its new
+//! content, never a partial write. The commit as a whole is not atomic: readers
+//! running concurrently with a commit may see some files promoted and others
+//! not yet.
+//!
+//! If the process dies during a commit, [`TransactionStorage::recover`] (run by
+//! [`TransactionStorage::open`] and at the start of every commit) finishes the
+//! job: a commit that reached its commit point is rolled forward by promoting
+//! its remaining temporary files, and one that did not is rolled back by
+//! deleting them. On backends without a native rename the journal rename is not
+//! atomic either, so this recovery is best effort there. Only one commit may
+//! run against a backend at a time. Dropping an uncommitted transaction
+//! discards it.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::model::ModelSaver;
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//!
+//! let backend = FileSystemStorageBackend::new("/path/to/workspaces");
+//! let saver = ModelSaver::transaction(&backend);
+//! for table in &tables {
+//!     saver.save_table("sales", table).await?;
+//! }
+//! saver.save_relationships("sales", &relationships).await?;
+//!
+//! // Either every file is written, or the workspace is left unchanged
+//! let changeset = saver.commit().await?;
+//! println!("Committed {} changes", changeset.change_count());
+//! ```
+//!
+//! [`DryRunStorage`]: super::dry_run::DryRunStorage
+
+use super::dry_run::{Changeset, DryRunStorage};
+use super::{StorageBackend, StorageError};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Suffix of the temporary files written while committing
+pub const TEMP_SUFFIX: &str = ".odm-tx";
+
+/// Journal of the commit in progress, at the root of the wrapped backend
+pub const JOURNAL_PATH: &str = ".odm-tx-journal.json";
+
+/// Changes of a commit, recorded so an interrupted commit can be recovered
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Journal {
+    writes: Vec<String>,
+    deletes: Vec<String>,
+}
+
+/// Outcome of recovering a commit interrupted by a crash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recovery {
+    /// No commit was interrupted
+    Clean,
+    /// The commit had reached its commit point and was completed
+    RolledForward(Vec<String>),
+    /// The commit had not reached its commit point and was discarded
+    RolledBack(Vec<String>),
+}
+
+/// Validator of a staged file, given its path and content
+pub type FileValidator = Box<dyn Fn(&str, &[u8]) -> Result<(), String> + Send + Sync>;
+
+/// Error committing a transaction
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionError {
+    /// A staged file failed validation; nothing was written
+    #[error("Validation failed for {path}: {message}")]
+    Validation { path: String, message: String },
+
+    /// A file changed on the backend after it was staged; nothing was written
+    #[error("File changed since it was staged: {0}")]
+    Conflict(String),
+
+    /// The changes could not be applied; the workspace was left unchanged
+    #[error("Transaction rolled back: {0}")]
+    RolledBack(#[source] StorageError),
+
+    /// The changes could not be applied, and restoring some files failed too
+    ///
+    /// The journal is kept, so [`TransactionStorage::recover`] completes the
+    /// commit once the backend is usable again.
+    #[error("Transaction rollback failed for {paths:?}: {source}")]
+    RollbackFailed {
+        /// Files that could not be restored, or not staged again for recovery
+        paths: Vec<String>,
+        #[source]
+        source: StorageError,
+    },
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+}
+
+/// Storage backend that stages changes and applies them all at once on commit
+pub struct TransactionStorage<B: StorageBackend> {
+    staged: DryRunStorage<B>,
+    validators: Vec<FileValidator>,
+}
+
+impl<B: StorageBackend> TransactionStorage<B> {
+    /// Start a transaction on `inner`
+    ///
+    /// A commit interrupted by a crash is recovered when this transaction
+    /// commits; use [`TransactionStorage::open`] to recover it up front.
+    pub fn new(inner: B) -> Self {
+        Self {
+            staged: DryRunStorage::new(inner),
+            validators: Vec::new(),
+        }
+    }
+
+    /// Recover any interrupted commit on `inner`, then start a transaction on it
+    pub async fn open(inner: B) -> Result<Self, StorageError> {
+        Self::recover(&inner).await?;
+        Ok(Self::new(inner))
+    }
+
+    /// Finish or discard a commit on `inner` that was interrupted by a crash
+    pub async fn recover(inner: &B) -> Result<Recovery, StorageError> {
+        let pending = temp_path(JOURNAL_PATH);
+        if inner.file_exists(JOURNAL_PATH).await? {
+            // A journal left half-copied by a non-atomic rename falls back to
+            // its pending copy, which has the same content
+            let journal = match read_journal(inner, JOURNAL_PATH).await {
+                Ok(journal) => journal,
+                Err(e) if inner.file_exists(&pending).await? => {
+                    read_journal(inner, &pending).await.map_err(|_| e)?
+                }
+                Err(e) => return Err(e),
+            };
+            for path in &journal.writes {
+                let temp = temp_path(path);
+                if inner.file_exists(&temp).await? {
+                    inner.rename(&temp, path).await?;
+                }
+            }
+            for path in &journal.deletes {
+                if inner.file_exists(path).await? {
+                    inner.delete_file(path).await?;
+                }
+            }
+            if inner.file_exists(&pending).await? {
+                inner.delete_file(&pending).await?;
+            }
+            inner.delete_file(JOURNAL_PATH).await?;
+            let mut paths = journal.writes;
+            paths.extend(journal.deletes);
+            return Ok(Recovery::RolledForward(paths));
+        }
+
+        if inner.file_exists(&pending).await? {
+            // Temporary files are only written after the pending journal, so
+            // an unreadable one has none to clean up
+            let journal = read_journal(inner, &pending).await.unwrap_or_default();
+            for path in &journal.writes {
+                let temp = temp_path(path);
+                if inner.file_exists(&temp).await? {
+                    inner.delete_file(&temp).await?;
+                }
+            }
+            inner.delete_file(&pending).await?;
+            let mut paths = journal.writes;
+            paths.extend(journal.deletes);
+            return Ok(Recovery::RolledBack(paths));
+        }
+
+        Ok(Recovery::Clean)
+    }
+
+    /// Check every staged file with `validator` before committing
+    pub fn with_validator(
+        mut self,
+        validator: impl Fn(&str, &[u8]) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// The wrapped backend
+    pub fn inner(&self) -> &B {
+        self.staged.inner()
+    }
+
+    /// The changes staged so far
+    pub fn changeset(&self) -> Changeset {
+        self.staged.changeset()
+    }
+
+    /// Apply all staged changes to the wrapped backend, or none of them
+    ///
+    /// Returns the applied changes.
+    pub async fn commit(self) -> Result<Changeset, TransactionError> {
+        let changeset = self.staged.changeset();
+        let changes = self.staged.pending_files();
+        let inner = self.staged.inner();
+        Self::recover(inner).await?;
+
+        for (path, content) in &changes {
+            if let Some(content) = content {
+                self.validate(path, content)?;
+            }
+        }
+
+        for (path, _) in &changes {
+            let current = if inner.file_exists(path).await? {
+                Some(inner.read_file(path).await?)
+            } else {
+                None
+            };
+            if current != self.staged.original(path) {
+                return Err(TransactionError::Conflict(path.clone()));
+            }
+        }
+
+        for dir in self.staged.pending_directories() {
+            if !inner.dir_exists(&dir).await? {
+                inner.create_dir(&dir).await?;
+            }
+        }
+
+        // Record the changes, then stage the new content next to its targets
+        let journal = Journal {
+            writes: changes
+                .iter()
+                .filter(|(_, content)| content.is_some())
+                .map(|(path, _)| path.clone())
+                .collect(),
+            deletes: changes
+                .iter()
+                .filter(|(_, content)| content.is_none())
+                .map(|(path, _)| path.clone())
+                .collect(),
+        };
+        let journal = serde_json::to_vec(&journal)
+            .map_err(|e| StorageError::SerializationError(e.to_string()))?;
+        let pending = temp_path(JOURNAL_PATH);
+        let mut temp_files = vec![pending.clone()];
+        if let Err(e) = inner.write_file(&pending, &journal).await {
+            remove_all(inner, &temp_files).await;
+            return Err(TransactionError::RolledBack(e));
+        }
+        for (path, content) in &changes {
+            let Some(content) = content else { continue };
+            let temp = temp_path(path);
+            if let Err(e) = inner.write_file(&temp, content).await {
+                remove_all(inner, &temp_files).await;
+                return Err(TransactionError::RolledBack(e));
+            }
+            temp_files.push(temp);
+        }
+
+        // Commit point: from here on, recovery rolls the commit forward
+        if let Err(e) = inner.rename(&pending, JOURNAL_PATH).await {
+            temp_files.push(JOURNAL_PATH.to_string());
+            remove_all(inner, &temp_files).await;
+            return Err(TransactionError::RolledBack(e));
+        }
+        temp_files[0] = JOURNAL_PATH.to_string();
+
+        // Promote, restoring the targets already changed on failure
+        let mut applied: Vec<&str> = Vec::new();
+        for (path, content) in &changes {
+            let result = match content {
+                Some(_) => inner.rename(&temp_path(path), path).await,
+                None => inner.delete_file(path).await,
+            };
+            if let Err(e) = result {
+                let mut failed = self.restore(&applied).await;
+                if failed.is_empty() {
+                    remove_all(inner, &temp_files).await;
+                    return Err(TransactionError::RolledBack(e));
+                }
+                // Some targets keep their new content, so the commit can only be
+                // completed: keep the journal, and stage the restored files again
+                // for recovery to roll forward. Files that cannot be staged again
+                // are left restored, so they are reported as well.
+                for (path, content) in &changes {
+                    if let Some(content) = content
+                        && applied.contains(&path.as_str())
+                        && !failed.contains(path)
+                        && let Err(e) = inner.write_file(&temp_path(path), content).await
+                    {
+                        tracing::warn!("Failed to stage {} for recovery: {}", path, e);
+                        failed.push(path.clone());
+                    }
+                }
+                return Err(TransactionError::RollbackFailed {
+                    paths: failed,
+                    source: e,
+                });
+            }
+            applied.push(path);
+        }
+
+        // Every change is applied; a journal left behind is removed by the next
+        // recovery, which finds nothing left to promote
+        if let Err(e) = inner.delete_file(JOURNAL_PATH).await {
+            tracing::warn!(
+                "Failed to remove transaction journal {}: {}",
+                JOURNAL_PATH,
+                e
+            );
+        }
+        Ok(changeset)
+    }
+
+    /// Check a staged file against its format and the validators
+    fn validate(&self, path: &str, content: &[u8]) -> Result<(), TransactionError> {
+        let invalid = |message: String| TransactionError::Validation {
+            path: path.to_string(),
+            message,
+        };
+        let lower = path.to_lowercase();
+        if lower.ends_with(".yaml") || lower.ends_with(".yml") {
+            serde_yaml::from_slice::<serde_yaml::Value>(content)
+                .map_err(|e| invalid(e.to_string()))?;
+        } else if lower.ends_with(".json") {
+            serde_json::from_slice::<serde_json::Value>(content)
+                .map_err(|e| invalid(e.to_string()))?;
+        }
+        for validator in &self.validators {
+            validator(path, content).map_err(invalid)?;
+        }
+        Ok(())
+    }
+
+    /// Restore files to their content before the transaction, returning the
+    /// paths that could not be restored
+    async fn restore(&self, paths: &[&str]) -> Vec<String> {
+        let inner = self.staged.inner();
+        let mut failed = Vec::new();
+        for path in paths {
+            let result = match self.staged.original(path) {
+                Some(original) => inner.write_file(path, &original).await,
+                None => match inner.file_exists(path).await {
+                    Ok(true) => inner.delete_file(path).await,
+                    Ok(false) => Ok(()),
+                    Err(e) => Err(e),
+                },
+            };
+            if result.is_err() {
+                failed.push(path.to_string());
+            }
+        }
+        failed
+    }
+}
+
+#[async_trait(?Send)]
+impl<B: StorageBackend> StorageBackend for TransactionStorage<B> {
+    async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        self.staged.read_file(path).await
+    }
+
+    async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+        self.staged.write_file(path, content).await
+    }
+
+    async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+        self.staged.list_files(dir).await
+    }
+
+    async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.staged.file_exists(path).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.staged.delete_file(path).await
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+        self.staged.create_dir(path).await
+    }
+
+    async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.staged.dir_exists(path).await
+    }
+}
+
+fn temp_path(path: &str) -> String {
+    format!("{}{}", path, TEMP_SUFFIX)
+}
+
+async fn read_journal<B: StorageBackend>(storage: &B, path: &str) -> Result<Journal, StorageError> {
+    let content = storage.read_file(path).await?;
+    serde_json::from_slice(&content).map_err(|e| {
+        StorageError::SerializationError(format!("Invalid transaction journal {}: {}", path, e))
+    })
+}
+
+/// Best-effort removal of temporary files
+async fn remove_all<B: StorageBackend>(storage: &B, paths: &[String]) {
+    for path in paths {
+        if matches!(storage.file_exists(path).await, Ok(true)) {
+            let _ = storage.delete_file(path).await;
+        }
+    }
+}
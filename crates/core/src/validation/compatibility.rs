@@ -0,0 +1,409 @@
+//! Backward compatibility validation for data contracts
+//!
+//! [`validate_backward_compatible`] is a gate for CI pipelines: it fails with one
+//! typed error per breaking change found by
+//! [`ContractDiff`](crate::models::odcs::diff::ContractDiff) between two versions
+//! of a contract. Type changes that only widen the accepted values (for example
+//! `integer` to `number`, `int` to `bigint` or `varchar(20)` to `varchar(50)`) are
+//! allowed; a decimal widens only if it keeps at least as many integer and
+//! fractional digits. A primary key property that was removed while another was
+//! added in its place is reported as a rename.
+//!
+//! ```rust
+//! use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+//! use data_modelling_core::validation::compatibility::{
+//!     CompatibilityError, validate_backward_compatible,
+//! };
+//!
+//! let old = ODCSContract::new("orders", "1.0.0").with_schema(
+//!     SchemaObject::new("orders").with_properties(vec![
+//!         Property::new("id", "integer").with_primary_key(true),
+//!         Property::new("total", "number").with_required(true),
+//!     ]),
+//! );
+//! let new = ODCSContract::new("orders", "2.0.0").with_schema(
+//!     SchemaObject::new("orders")
+//!         .with_properties(vec![Property::new("id", "number").with_primary_key(true)]),
+//! );
+//!
+//! let errors = validate_backward_compatible(&old, &new).unwrap_err();
+//! assert_eq!(
+//!     errors,
+//!     [CompatibilityError::RemovedRequiredField {
+//!         path: "orders.total".to_string()
+//!     }]
+//! );
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::metrics;
+use crate::models::odcs::ODCSContract;
+use crate::models::odcs::diff::{ContractDiff, DiffElement, DiffEntry, DiffKind};
+
+/// Logical type changes that accept every value of the old type
+const LOGICAL_WIDENINGS: &[(&str, &str)] = &[
+    ("integer", "number"),
+    ("integer", "string"),
+    ("number", "string"),
+    ("boolean", "string"),
+    ("date", "timestamp"),
+    ("date", "string"),
+    ("timestamp", "string"),
+    ("time", "string"),
+];
+
+/// Physical types without a length parameter accepting values of any length
+const UNBOUNDED_WITHOUT_LENGTH: &[&str] = &["varchar", "nvarchar", "varbinary", "string"];
+
+/// Physical types with a precision and scale
+const DECIMAL_TYPES: &[&str] = &["decimal", "numeric"];
+
+/// Physical types ordered from narrowest to widest
+const PHYSICAL_LADDERS: &[&[&str]] = &[
+    &["tinyint", "smallint", "int", "bigint"],
+    &["float", "double"],
+];
+
+/// Backward-incompatible change between two versions of a contract
+#[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CompatibilityError {
+    #[error("{schema}: schema object removed")]
+    RemovedSchema { schema: String },
+    #[error("{path}: required field removed")]
+    RemovedRequiredField { path: String },
+    #[error("{path}: field removed")]
+    RemovedField { path: String },
+    #[error("{path}: type narrowed from {from} to {to}")]
+    NarrowedType {
+        path: String,
+        from: String,
+        to: String,
+    },
+    #[error("{schema}: primary key renamed from {from} to {to}")]
+    RenamedPrimaryKey {
+        schema: String,
+        from: String,
+        to: String,
+    },
+    #[error("{path}: {message}")]
+    Breaking { path: String, message: String },
+}
+
+impl CompatibilityError {
+    /// Name of the compatibility rule this change violates, as in the `kind` tag
+    pub fn rule(&self) -> &'static str {
+        match self {
+            CompatibilityError::RemovedSchema { .. } => "removedSchema",
+            CompatibilityError::RemovedRequiredField { .. } => "removedRequiredField",
+            CompatibilityError::RemovedField { .. } => "removedField",
+            CompatibilityError::NarrowedType { .. } => "narrowedType",
+            CompatibilityError::RenamedPrimaryKey { .. } => "renamedPrimaryKey",
+            CompatibilityError::Breaking { .. } => "breaking",
+        }
+    }
+}
+
+/// Check that `new_contract` can replace `old_contract` without breaking consumers.
+///
+/// # Returns
+///
+/// `Ok(())` when the change is backward compatible, otherwise one error per
+/// incompatible change, in the order reported by the contract diff.
+pub fn validate_backward_compatible(
+    old_contract: &ODCSContract,
+    new_contract: &ODCSContract,
+) -> Result<(), Vec<CompatibilityError>> {
+    let diff = ContractDiff::compare(old_contract, new_contract);
+    let renames = primary_key_renames(&diff);
+
+    let mut errors: Vec<CompatibilityError> = Vec::new();
+    for change in diff.breaking() {
+        let error = match (change.element, change.kind) {
+            (DiffElement::Schema, DiffKind::Removed) => CompatibilityError::RemovedSchema {
+                schema: change.path.clone(),
+            },
+            (DiffElement::Property, DiffKind::Removed) => {
+                if let Some(rename) = renames.iter().find(|r| r.removed_path == change.path) {
+                    rename.error.clone()
+                } else if flag(change.before.as_ref(), "required")
+                    || flag(change.before.as_ref(), "primaryKey")
+                {
+                    CompatibilityError::RemovedRequiredField {
+                        path: change.path.clone(),
+                    }
+                } else {
+                    CompatibilityError::RemovedField {
+                        path: change.path.clone(),
+                    }
+                }
+            }
+            (DiffElement::Property, DiffKind::Changed) if is_type_field(&change.path) => {
+                let from = type_name(change.before.as_ref());
+                let to = type_name(change.after.as_ref());
+                if widens(&from, &to) {
+                    continue;
+                }
+                CompatibilityError::NarrowedType {
+                    path: change.path.clone(),
+                    from,
+                    to,
+                }
+            }
+            _ => CompatibilityError::Breaking {
+                path: change.path.clone(),
+                message: change.message.clone(),
+            },
+        };
+        if !errors.contains(&error) {
+            errors.push(error);
+        }
+    }
+
+    for error in &errors {
+        metrics::validation_failure(&format!("compatibility.{}", error.rule()));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+struct PrimaryKeyRename {
+    removed_path: String,
+    error: CompatibilityError,
+}
+
+/// Schemas (or object properties) that lost exactly one primary key property and
+/// gained exactly one other
+fn primary_key_renames(diff: &ContractDiff) -> Vec<PrimaryKeyRename> {
+    let primary_keys = |kind: DiffKind| -> Vec<(&str, &str)> {
+        diff.changes
+            .iter()
+            .filter(|c| c.element == DiffElement::Property && c.kind == kind)
+            .filter(|c| {
+                let value = match kind {
+                    DiffKind::Removed => c.before.as_ref(),
+                    _ => c.after.as_ref(),
+                };
+                flag(value, "primaryKey")
+            })
+            .filter_map(|c: &DiffEntry| c.path.rsplit_once('.'))
+            .collect()
+    };
+    let (removed, added) = (
+        primary_keys(DiffKind::Removed),
+        primary_keys(DiffKind::Added),
+    );
+
+    let count = |keys: &[(&str, &str)], parent: &str| keys.iter().filter(|k| k.0 == parent).count();
+    removed
+        .iter()
+        .filter(|(parent, _)| count(&removed, parent) == 1 && count(&added, parent) == 1)
+        .filter_map(|&(parent, from)| {
+            let (_, to) = added.iter().find(|k| k.0 == parent)?;
+            Some(PrimaryKeyRename {
+                removed_path: format!("{}.{}", parent, from),
+                error: CompatibilityError::RenamedPrimaryKey {
+                    schema: parent.to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                },
+            })
+        })
+        .collect()
+}
+
+fn flag(value: Option<&Value>, field: &str) -> bool {
+    value
+        .and_then(|v| v.get(field))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+fn is_type_field(path: &str) -> bool {
+    path.ends_with(".logicalType") || path.ends_with(".physicalType")
+}
+
+fn type_name(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(name)) => name.clone(),
+        Some(other) => other.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+/// Returns true when every value of type `from` is also a value of type `to`
+fn widens(from: &str, to: &str) -> bool {
+    let (from, to) = (from.trim().to_lowercase(), to.trim().to_lowercase());
+    if LOGICAL_WIDENINGS.contains(&(from.as_str(), to.as_str())) {
+        return true;
+    }
+    let (from_base, from_params) = split_parameters(&from);
+    let (to_base, to_params) = split_parameters(&to);
+    if from_base == to_base {
+        // e.g. varchar(20) -> varchar(50), varchar(20) -> varchar, decimal(10,2) -> decimal(12,2)
+        if to_params.is_empty() {
+            return from_params.is_empty() || UNBOUNDED_WITHOUT_LENGTH.contains(&to_base);
+        }
+        if DECIMAL_TYPES.contains(&to_base) {
+            return decimal_widens(&from_params, &to_params);
+        }
+        return from_params.len() == to_params.len()
+            && from_params.iter().zip(&to_params).all(|(f, t)| t >= f);
+    }
+    PHYSICAL_LADDERS.iter().any(|ladder| {
+        let rank = |base: &str| {
+            let base = if base == "integer" { "int" } else { base };
+            ladder.iter().position(|t| *t == base)
+        };
+        matches!((rank(from_base), rank(to_base)), (Some(f), Some(t)) if t >= f)
+    })
+}
+
+/// Returns true when `decimal(to)` keeps at least the integer digits
+/// (precision - scale) and the fractional digits (scale) of `decimal(from)`
+fn decimal_widens(from: &[u64], to: &[u64]) -> bool {
+    let digits = |params: &[u64]| match *params {
+        [precision] => Some((precision, 0)),
+        [precision, scale] if scale <= precision => Some((precision - scale, scale)),
+        _ => None,
+    };
+    matches!(
+        (digits(from), digits(to)),
+        (Some((from_int, from_frac)), Some((to_int, to_frac)))
+            if to_int >= from_int && to_frac >= from_frac
+    )
+}
+
+/// Split `decimal(10,2)` into `decimal` and `[10, 2]`
+fn split_parameters(data_type: &str) -> (&str, Vec<u64>) {
+    match data_type.split_once('(') {
+        Some((base, rest)) => (
+            base.trim(),
+            rest.trim_end_matches(')')
+                .split(',')
+                .filter_map(|p| p.trim().parse().ok())
+                .collect(),
+        ),
+        None => (data_type, Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::odcs::{Property, SchemaObject};
+
+    fn contract(properties: Vec<Property>) -> ODCSContract {
+        ODCSContract::new("orders", "1.0.0")
+            .with_schema(SchemaObject::new("orders").with_properties(properties))
+    }
+
+    #[test]
+    fn test_compatible_changes_pass() {
+        let old = contract(vec![
+            Property::new("id", "integer")
+                .with_primary_key(true)
+                .with_physical_type("int"),
+            Property::new("code", "string").with_physical_type("varchar(20)"),
+        ]);
+        let new = contract(vec![
+            Property::new("id", "number")
+                .with_primary_key(true)
+                .with_physical_type("bigint"),
+            Property::new("code", "string")
+                .with_physical_type("VARCHAR(50)")
+                .with_description("Order code"),
+            Property::new("note", "string"),
+        ]);
+        assert_eq!(validate_backward_compatible(&old, &new), Ok(()));
+    }
+
+    #[test]
+    fn test_typed_errors() {
+        let old = contract(vec![
+            Property::new("id", "integer").with_primary_key(true),
+            Property::new("amount", "number").with_physical_type("decimal(12,2)"),
+            Property::new("status", "string").with_required(true),
+            Property::new("note", "string"),
+        ]);
+        let new = contract(vec![
+            Property::new("order_id", "integer").with_primary_key(true),
+            Property::new("amount", "integer").with_physical_type("decimal(10,2)"),
+        ]);
+
+        let errors = validate_backward_compatible(&old, &new).unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                CompatibilityError::RenamedPrimaryKey {
+                    schema: "orders".to_string(),
+                    from: "id".to_string(),
+                    to: "order_id".to_string(),
+                },
+                CompatibilityError::NarrowedType {
+                    path: "orders.amount.logicalType".to_string(),
+                    from: "number".to_string(),
+                    to: "integer".to_string(),
+                },
+                CompatibilityError::NarrowedType {
+                    path: "orders.amount.physicalType".to_string(),
+                    from: "decimal(12,2)".to_string(),
+                    to: "decimal(10,2)".to_string(),
+                },
+                CompatibilityError::RemovedRequiredField {
+                    path: "orders.status".to_string(),
+                },
+                CompatibilityError::RemovedField {
+                    path: "orders.note".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "orders: primary key renamed from id to order_id"
+        );
+    }
+
+    #[test]
+    fn test_decimal_and_parameterless_widening() {
+        assert!(widens("decimal(10,2)", "decimal(12,2)"));
+        assert!(widens("decimal(10,2)", "decimal(12,4)"));
+        assert!(widens("numeric(10)", "numeric(12,2)"));
+        // More scale at the same precision loses integer digits
+        assert!(!widens("decimal(10,2)", "decimal(10,4)"));
+        assert!(!widens("decimal(10,2)", "decimal(12,6)"));
+        assert!(!widens("decimal(10,4)", "decimal(10,2)"));
+        assert!(!widens("decimal(10,2)", "decimal"));
+
+        assert!(widens("varchar(20)", "varchar"));
+        assert!(!widens("char(20)", "char"));
+        assert!(!widens("timestamp(6)", "timestamp"));
+        assert!(widens("timestamp(3)", "timestamp(6)"));
+    }
+
+    #[test]
+    fn test_removed_schema_and_other_breaking_changes() {
+        let old = contract(vec![Property::new("id", "integer").with_required(true)])
+            .with_schema(SchemaObject::new("customers"));
+        let new = contract(vec![Property::new("id", "integer")]);
+
+        let errors = validate_backward_compatible(&old, &new).unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                CompatibilityError::Breaking {
+                    path: "orders.id.required".to_string(),
+                    message: "true removed".to_string(),
+                },
+                CompatibilityError::RemovedSchema {
+                    schema: "customers".to_string()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Registry of JSON Schemas for organization-specific `customProperties`
+//!
+//! ODCS and ODPS allow arbitrary `customProperties` entries. Organizations usually
+//! settle on a fixed set of keys (e.g. `x-cost-center`, `x-retention`); registering a
+//! JSON Schema per key lets contract validation catch wrong values and misspelled
+//! keys instead of accepting anything.
+//!
+//! Registries are usually loaded from a YAML (or JSON) file:
+//!
+//! ```yaml
+//! # Reject keys that are not registered (default: only flag likely typos)
+//! strict: false
+//! properties:
+//!   x-cost-center:
+//!     type: string
+//!     pattern: "^CC-[0-9]{4}$"
+//!   x-retention:
+//!     type: integer
+//!     minimum: 1
+//! ```
+//!
+//! Value validation against the registered schemas requires the
+//! `schema-validation` feature; key checks are always performed.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::metrics;
+
+/// A `customProperties` entry that does not satisfy the registry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomPropertyViolation {
+    /// Location of the entry in the document (e.g. `schema[0].properties[1]`)
+    pub path: String,
+    /// Custom property key
+    pub property: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl std::fmt::Display for CustomPropertyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: '{}' {}", self.path, self.property, self.message)
+    }
+}
+
+/// Registry mapping custom property keys to the JSON Schema their values must match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CustomPropertyRegistry {
+    /// Reject keys that are not registered
+    #[serde(default)]
+    pub strict: bool,
+    /// JSON Schema per custom property key
+    #[serde(default)]
+    pub properties: BTreeMap<String, Value>,
+}
+
+impl CustomPropertyRegistry {
+    /// Create an empty, non-strict registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a registry from YAML or JSON
+    pub fn from_yaml(content: &str) -> Result<Self, String> {
+        serde_yaml::from_str(content)
+            .map_err(|e| format!("Failed to parse custom property registry: {}", e))
+    }
+
+    /// Register (or replace) the schema for a key
+    pub fn register(&mut self, property: impl Into<String>, schema: Value) -> &mut Self {
+        self.properties.insert(property.into(), schema);
+        self
+    }
+
+    /// Reject keys that are not registered
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Check whether a key is registered
+    pub fn contains(&self, property: &str) -> bool {
+        self.properties.contains_key(property)
+    }
+
+    /// Validate a single custom property value
+    pub fn validate_value(&self, property: &str, value: &Value) -> Result<(), String> {
+        match self.properties.get(property) {
+            Some(schema) => validate_against_schema(schema, value),
+            None if self.strict => Err(self.unknown_key_message(property)),
+            None => match self.closest_key(property) {
+                Some(_) => Err(self.unknown_key_message(property)),
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Validate every `customProperties` entry of an ODCS or ODPS document (YAML or JSON)
+    pub fn validate_yaml(&self, content: &str) -> Result<Vec<CustomPropertyViolation>, String> {
+        let document: Value =
+            serde_yaml::from_str(content).map_err(|e| format!("Failed to parse YAML: {}", e))?;
+        Ok(self.validate_document(&document))
+    }
+
+    /// Validate every `customProperties` entry of an ODCS contract
+    pub fn validate_contract(
+        &self,
+        contract: &crate::models::odcs::ODCSContract,
+    ) -> Vec<CustomPropertyViolation> {
+        match serde_json::to_value(contract) {
+            Ok(document) => self.validate_document(&document),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Validate every `customProperties` entry found anywhere in a document
+    pub fn validate_document(&self, document: &Value) -> Vec<CustomPropertyViolation> {
+        let mut violations = Vec::new();
+        self.walk(document, "", &mut violations);
+        violations
+    }
+
+    fn walk(&self, value: &Value, path: &str, violations: &mut Vec<CustomPropertyViolation>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    if key == "customProperties" {
+                        self.check_entries(child, path, violations);
+                        continue;
+                    }
+                    let child_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    self.walk(child, &child_path, violations);
+                }
+            }
+            Value::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.walk(item, &format!("{}[{}]", path, i), violations);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Check a `customProperties` value: the ODCS list form (`[{property, value}]`)
+    /// or a plain key/value map
+    fn check_entries(
+        &self,
+        entries: &Value,
+        path: &str,
+        violations: &mut Vec<CustomPropertyViolation>,
+    ) {
+        let location = if path.is_empty() { "root" } else { path };
+        let pairs: Vec<(&str, &Value)> = match entries {
+            Value::Array(items) => items
+                .iter()
+                .filter_map(|item| {
+                    let key = item.get("property")?.as_str()?;
+                    Some((key, item.get("value").unwrap_or(&Value::Null)))
+                })
+                .collect(),
+            Value::Object(map) => map.iter().map(|(k, v)| (k.as_str(), v)).collect(),
+            _ => Vec::new(),
+        };
+
+        for (property, value) in pairs {
+            if let Err(message) = self.validate_value(property, value) {
+                metrics::validation_failure(&format!("customProperty.{}", property));
+                violations.push(CustomPropertyViolation {
+                    path: location.to_string(),
+                    property: property.to_string(),
+                    message,
+                });
+            }
+        }
+    }
+
+    fn unknown_key_message(&self, property: &str) -> String {
+        match self.closest_key(property) {
+            Some(suggestion) => format!(
+                "is not a registered custom property (did you mean '{}'?)",
+                suggestion
+            ),
+            None => "is not a registered custom property".to_string(),
+        }
+    }
+
+    /// Registered key within a small edit distance, if any
+    fn closest_key(&self, property: &str) -> Option<&str> {
+        let lowercase = property.to_lowercase();
+        self.properties
+            .keys()
+            .filter(|key| key.as_str() != property)
+            .map(|key| (key, edit_distance(&lowercase, &key.to_lowercase())))
+            .filter(|(_, distance)| *distance <= 2)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(key, _)| key.as_str())
+    }
+}
+
+#[cfg(feature = "schema-validation")]
+fn validate_against_schema(schema: &Value, value: &Value) -> Result<(), String> {
+    let validator = jsonschema::Validator::new(schema)
+        .map_err(|e| format!("has an invalid registered schema: {}", e))?;
+    validator
+        .validate(value)
+        .map_err(|e| format!("has an invalid value: {}", e))
+}
+
+#[cfg(not(feature = "schema-validation"))]
+fn validate_against_schema(_schema: &Value, _value: &Value) -> Result<(), String> {
+    // Value validation disabled - feature not enabled
+    Ok(())
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current.push(
+                (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const REGISTRY: &str = r#"
+properties:
+  x-cost-center:
+    type: string
+    pattern: "^CC-[0-9]{4}$"
+  x-retention:
+    type: integer
+    minimum: 1
+"#;
+
+    const CONTRACT: &str = r#"
+apiVersion: v3.1.0
+kind: DataContract
+id: orders
+version: 1.0.0
+customProperties:
+  - property: x-cost-centre
+    value: CC-1234
+  - property: team-specific
+    value: anything
+schema:
+  - name: orders
+    properties:
+      - name: id
+        logicalType: integer
+        customProperties:
+          - property: x-retention
+            value: 0
+"#;
+
+    #[test]
+    fn test_flags_likely_typos() {
+        let registry = CustomPropertyRegistry::from_yaml(REGISTRY).unwrap();
+        let violations = registry.validate_yaml(CONTRACT).unwrap();
+
+        let typo = violations
+            .iter()
+            .find(|v| v.property == "x-cost-centre")
+            .unwrap();
+        assert_eq!(typo.path, "root");
+        assert!(typo.message.contains("did you mean 'x-cost-center'"));
+        // Unrelated unregistered keys are allowed unless the registry is strict
+        assert!(!violations.iter().any(|v| v.property == "team-specific"));
+    }
+
+    #[test]
+    fn test_strict_registry_rejects_unknown_keys() {
+        let registry = CustomPropertyRegistry::from_yaml(REGISTRY)
+            .unwrap()
+            .with_strict(true);
+        let violations = registry.validate_yaml(CONTRACT).unwrap();
+        assert!(violations.iter().any(|v| v.property == "team-specific"));
+    }
+
+    #[test]
+    #[cfg(feature = "schema-validation")]
+    fn test_validates_values_against_schema() {
+        let registry = CustomPropertyRegistry::from_yaml(REGISTRY).unwrap();
+        let violations = registry.validate_yaml(CONTRACT).unwrap();
+        let retention = violations
+            .iter()
+            .find(|v| v.property == "x-retention")
+            .unwrap();
+        assert_eq!(retention.path, "schema[0].properties[0]");
+
+        assert!(
+            registry
+                .validate_value("x-cost-center", &json!("CC-0042"))
+                .is_ok()
+        );
+        assert!(
+            registry
+                .validate_value("x-cost-center", &json!("finance"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_register_and_map_form() {
+        let mut registry = CustomPropertyRegistry::new();
+        registry.register("x-owner", json!({"type": "string"}));
+        assert!(registry.contains("x-owner"));
+
+        let violations = registry.validate_document(&json!({
+            "customProperties": {"x-ownr": "data-team"}
+        }));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].property, "x-ownr");
+    }
+}
@@ -0,0 +1,401 @@
+//! ETL job metadata validation
+//!
+//! Checks the [`ETLJobMetadata`] of data flow relationships:
+//! - Job names are set
+//! - Schedules are valid cron expressions (5 fields, or 6 with leading seconds)
+//!   or macros such as `@daily`
+//! - Job URLs are well-formed http(s) URLs, and optionally reachable
+//!   ([`check_job_urls`], feature `api-backend`)
+//! - Linked transformations exist in the workspace's domains
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::models::{ETLJobMetadata, Relationship};
+//! use data_modelling_core::validation::etl::validate_etl_metadata;
+//! use uuid::Uuid;
+//!
+//! let mut relationship = Relationship::new(Uuid::new_v4(), Uuid::new_v4());
+//! relationship.etl_job_metadata = Some(ETLJobMetadata {
+//!     job_name: "load_orders".to_string(),
+//!     schedule: Some("0 61 * * *".to_string()),
+//!     ..Default::default()
+//! });
+//!
+//! let issues = validate_etl_metadata(&[relationship]);
+//! assert_eq!(issues.len(), 1);
+//! ```
+//!
+//! [`ETLJobMetadata`]: crate::models::ETLJobMetadata
+
+use crate::models::Relationship;
+use crate::models::workspace::Workspace;
+use crate::validation::input::validate_url;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Schedule macros accepted in place of a cron expression
+pub const SCHEDULE_MACROS: &[&str] = &[
+    "@yearly",
+    "@annually",
+    "@monthly",
+    "@weekly",
+    "@daily",
+    "@midnight",
+    "@hourly",
+    "@once",
+];
+
+const MONTH_NAMES: &[&str] = &[
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const DAY_NAMES: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Problem found in the ETL job metadata of a relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtlIssue {
+    /// Relationship carrying the metadata
+    pub relationship_id: Uuid,
+    /// Name of the job
+    pub job_name: String,
+    /// What is wrong
+    pub kind: EtlIssueKind,
+}
+
+/// Kind of [`EtlIssue`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize, Deserialize)]
+#[serde(tag = "type", content = "detail", rename_all = "camelCase")]
+pub enum EtlIssueKind {
+    #[error("ETL job name is empty")]
+    MissingJobName,
+
+    #[error("Invalid schedule: {0}")]
+    InvalidSchedule(String),
+
+    #[error("Invalid job URL: {0}")]
+    InvalidJobUrl(String),
+
+    #[error("Job URL is unreachable: {0}")]
+    UnreachableJobUrl(String),
+
+    #[error("Linked transformation {0} does not exist")]
+    UnknownTransformation(Uuid),
+}
+
+/// Check a schedule
+///
+/// Accepts cron expressions with 5 fields (minute, hour, day of month, month,
+/// day of week) or 6 fields (with leading seconds), and [`SCHEDULE_MACROS`].
+/// Fields may use `*`, values, ranges (`1-5`), steps (`*/15`, `0-30/10`),
+/// lists (`1,15`), month and day names (`JAN`, `MON-FRI`), and `?` for the
+/// day fields.
+pub fn validate_schedule(schedule: &str) -> Result<(), String> {
+    let schedule = schedule.trim();
+    if schedule.is_empty() {
+        return Err("schedule is empty".to_string());
+    }
+    if schedule.starts_with('@') {
+        let lower = schedule.to_lowercase();
+        return if SCHEDULE_MACROS.contains(&lower.as_str()) {
+            Ok(())
+        } else {
+            Err(format!("unknown schedule macro '{}'", schedule))
+        };
+    }
+
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    let fields = match fields.len() {
+        5 => &fields[..],
+        6 => {
+            validate_field(fields[0], "second", 0, 59, &[], false)?;
+            &fields[1..]
+        }
+        n => {
+            return Err(format!(
+                "expected 5 or 6 cron fields, found {} in '{}'",
+                n, schedule
+            ));
+        }
+    };
+
+    validate_field(fields[0], "minute", 0, 59, &[], false)?;
+    validate_field(fields[1], "hour", 0, 23, &[], false)?;
+    validate_field(fields[2], "day of month", 1, 31, &[], true)?;
+    validate_field(fields[3], "month", 1, 12, MONTH_NAMES, false)?;
+    // 7 is accepted as Sunday, like 0
+    validate_field(fields[4], "day of week", 0, 7, DAY_NAMES, true)?;
+    Ok(())
+}
+
+/// Check one cron field against its range
+fn validate_field(
+    field: &str,
+    label: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    allow_any: bool,
+) -> Result<(), String> {
+    if allow_any && field == "?" {
+        return Ok(());
+    }
+    for item in field.split(',') {
+        let invalid = |reason: &str| format!("{} field '{}': {}", label, field, reason);
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+        if let Some(step) = step {
+            match step.parse::<u32>() {
+                Ok(step) if step > 0 => {}
+                _ => return Err(invalid(&format!("invalid step '{}'", step))),
+            }
+        }
+        if range == "*" {
+            continue;
+        }
+        let value = |s: &str| -> Result<u32, String> {
+            let parsed = s.parse::<u32>().ok().or_else(|| {
+                let upper = s.to_uppercase();
+                names
+                    .iter()
+                    .position(|name| *name == upper)
+                    .map(|i| i as u32 + min)
+            });
+            match parsed {
+                Some(v) if (min..=max).contains(&v) => Ok(v),
+                Some(v) => Err(invalid(&format!("{} is outside {}-{}", v, min, max))),
+                None => Err(invalid(&format!("invalid value '{}'", s))),
+            }
+        };
+        match range.split_once('-') {
+            Some((start, end)) => {
+                if value(start)? > value(end)? {
+                    return Err(invalid(&format!("range '{}' is reversed", range)));
+                }
+            }
+            None => {
+                value(range)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check the ETL job metadata of relationships, without network access
+pub fn validate_etl_metadata(relationships: &[Relationship]) -> Vec<EtlIssue> {
+    let mut issues = Vec::new();
+    for relationship in relationships {
+        let Some(etl) = &relationship.etl_job_metadata else {
+            continue;
+        };
+        let mut push = |kind| {
+            issues.push(EtlIssue {
+                relationship_id: relationship.id,
+                job_name: etl.job_name.clone(),
+                kind,
+            })
+        };
+        if etl.job_name.trim().is_empty() {
+            push(EtlIssueKind::MissingJobName);
+        }
+        if let Some(schedule) = &etl.schedule
+            && let Err(e) = validate_schedule(schedule)
+        {
+            push(EtlIssueKind::InvalidSchedule(e));
+        }
+        if let Some(url) = &etl.job_url
+            && let Err(e) = validate_url(url)
+        {
+            push(EtlIssueKind::InvalidJobUrl(e.to_string()));
+        }
+    }
+    issues
+}
+
+/// Check the ETL job metadata of a workspace's relationships
+///
+/// In addition to [`validate_etl_metadata`], every linked transformation must
+/// be one of the domains' transformation links.
+pub fn validate_workspace_etl(workspace: &Workspace) -> Vec<EtlIssue> {
+    let mut issues = validate_etl_metadata(&workspace.relationships);
+    for relationship in &workspace.relationships {
+        let Some(etl) = &relationship.etl_job_metadata else {
+            continue;
+        };
+        if let Some(id) = etl.transformation_id
+            && !workspace
+                .domains
+                .iter()
+                .flat_map(|d| &d.transformation_links)
+                .any(|link| link.id == id)
+        {
+            issues.push(EtlIssue {
+                relationship_id: relationship.id,
+                job_name: etl.job_name.clone(),
+                kind: EtlIssueKind::UnknownTransformation(id),
+            });
+        }
+    }
+    issues
+}
+
+/// Check that the job URLs of relationships respond
+///
+/// Sends a `HEAD` request to every well-formed job URL, falling back to `GET`
+/// for servers that do not support `HEAD`, and reports URLs that fail or
+/// answer with an error status. Malformed URLs are left to
+/// [`validate_etl_metadata`].
+#[cfg(feature = "api-backend")]
+pub async fn check_job_urls(relationships: &[Relationship], timeout_seconds: u64) -> Vec<EtlIssue> {
+    let client = reqwest::Client::new();
+    let timeout = std::time::Duration::from_secs(timeout_seconds);
+    let mut issues = Vec::new();
+
+    for relationship in relationships {
+        let Some(etl) = &relationship.etl_job_metadata else {
+            continue;
+        };
+        let Some(url) = &etl.job_url else { continue };
+        if validate_url(url).is_err() {
+            continue;
+        }
+
+        let mut response = client.head(url).timeout(timeout).send().await;
+        if matches!(&response, Ok(r) if r.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED) {
+            response = client.get(url).timeout(timeout).send().await;
+        }
+        let problem = match response {
+            Ok(r) if r.status().is_client_error() || r.status().is_server_error() => {
+                Some(format!("{} returned {}", url, r.status()))
+            }
+            Ok(_) => None,
+            Err(e) => Some(format!("{}: {}", url, e)),
+        };
+        if let Some(problem) = problem {
+            issues.push(EtlIssue {
+                relationship_id: relationship.id,
+                job_name: etl.job_name.clone(),
+                kind: EtlIssueKind::UnreachableJobUrl(problem),
+            });
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ETLJobMetadata;
+    use crate::models::workspace::{DomainReference, TransformationLink};
+
+    fn etl_relationship(etl: ETLJobMetadata) -> Relationship {
+        let mut relationship = Relationship::new(Uuid::new_v4(), Uuid::new_v4());
+        relationship.etl_job_metadata = Some(etl);
+        relationship
+    }
+
+    #[test]
+    fn test_validate_schedule() {
+        for valid in [
+            "0 2 * * *",
+            "*/15 * * * *",
+            "0-30/10 9-17 * * MON-FRI",
+            "30 0 6 1,15 * ?",
+            "0 0 1 jan,jul 0",
+            "0 0 * * 7",
+            "@daily",
+            "@HOURLY",
+        ] {
+            assert!(
+                validate_schedule(valid).is_ok(),
+                "{} should be valid",
+                valid
+            );
+        }
+        for invalid in [
+            "",
+            "0 2 * *",
+            "0 24 * * *",
+            "60 * * * *",
+            "0 0 0 * *",
+            "*/0 * * * *",
+            "0 0 * FOO *",
+            "0 17-9 * * *",
+            "0 ? * * *",
+            "@sometimes",
+        ] {
+            assert!(
+                validate_schedule(invalid).is_err(),
+                "{} should be invalid",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_etl_metadata() {
+        let valid = etl_relationship(ETLJobMetadata {
+            job_name: "load_orders".to_string(),
+            schedule: Some("0 2 * * *".to_string()),
+            job_url: Some("https://airflow.example.com/dags/load_orders".to_string()),
+            ..Default::default()
+        });
+        let invalid = etl_relationship(ETLJobMetadata {
+            job_name: " ".to_string(),
+            schedule: Some("every day".to_string()),
+            job_url: Some("ftp://example.com/job".to_string()),
+            ..Default::default()
+        });
+
+        assert!(validate_etl_metadata(std::slice::from_ref(&valid)).is_empty());
+
+        let issues = validate_etl_metadata(&[valid, invalid.clone()]);
+        assert_eq!(issues.len(), 3);
+        assert!(issues.iter().all(|i| i.relationship_id == invalid.id));
+        assert_eq!(issues[0].kind, EtlIssueKind::MissingJobName);
+        assert!(matches!(issues[1].kind, EtlIssueKind::InvalidSchedule(_)));
+        assert!(matches!(issues[2].kind, EtlIssueKind::InvalidJobUrl(_)));
+    }
+
+    #[test]
+    fn test_validate_workspace_etl_transformation_links() {
+        let link = TransformationLink {
+            id: Uuid::new_v4(),
+            name: "load_orders".to_string(),
+            transformation_type: Some("dbt".to_string()),
+            url: None,
+            description: None,
+        };
+        let mut workspace = Workspace::new("sales".to_string(), Uuid::new_v4());
+        workspace.domains.push(DomainReference {
+            id: Uuid::new_v4(),
+            name: "sales".to_string(),
+            description: None,
+            systems: Vec::new(),
+            shared_resources: Vec::new(),
+            transformation_links: vec![link.clone()],
+            table_visibility: None,
+            view_positions: Default::default(),
+        });
+        let missing = Uuid::new_v4();
+        workspace.relationships = vec![
+            etl_relationship(ETLJobMetadata {
+                job_name: "load_orders".to_string(),
+                transformation_id: Some(link.id),
+                ..Default::default()
+            }),
+            etl_relationship(ETLJobMetadata {
+                job_name: "load_customers".to_string(),
+                transformation_id: Some(missing),
+                ..Default::default()
+            }),
+        ];
+
+        let issues = validate_workspace_etl(&workspace);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].job_name, "load_customers");
+        assert_eq!(issues[0].kind, EtlIssueKind::UnknownTransformation(missing));
+    }
+}
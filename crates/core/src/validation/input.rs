@@ -12,6 +12,7 @@
 //! - Buffer overflows via excessively long inputs
 //! - Unicode normalization attacks
 
+use super::limits::ResourceLimits;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
@@ -63,6 +64,27 @@ pub enum ValidationError {
     /// Input is a reserved word
     #[error("{field} cannot be a reserved word: {word}")]
     ReservedWord { field: &'static str, word: String },
+
+    /// Input is nested deeper than allowed
+    #[error("{field} exceeds maximum nesting depth (max: {max})")]
+    TooDeep { field: &'static str, max: usize },
+
+    /// An object in the input has more properties than allowed
+    ///
+    /// Content checks stop counting at the first property over the limit.
+    #[error("{field} has an object with too many properties (max: {max}, got: {actual})")]
+    TooManyProperties {
+        field: &'static str,
+        max: usize,
+        actual: usize,
+    },
+
+    /// Parsing the input took longer than allowed
+    #[error("{field} took longer than {timeout:?} to parse")]
+    Timeout {
+        field: &'static str,
+        timeout: std::time::Duration,
+    },
 }
 
 /// Result type for validation operations.
@@ -512,14 +534,9 @@ pub fn sanitize_model_name(name: &str) -> String {
 ///
 /// `ValidationResult<()>` indicating whether the file size is valid
 pub fn validate_bpmn_dmn_file_size(file_size: u64) -> ValidationResult<()> {
-    if file_size > MAX_BPMN_DMN_FILE_SIZE {
-        return Err(ValidationError::TooLong {
-            field: "BPMN/DMN file size",
-            max: MAX_BPMN_DMN_FILE_SIZE as usize,
-            actual: file_size as usize,
-        });
-    }
-    Ok(())
+    ResourceLimits::default()
+        .with_max_file_size(MAX_BPMN_DMN_FILE_SIZE)
+        .check_size("BPMN/DMN file size", file_size)
 }
 
 // ============================================================================
@@ -818,12 +835,7 @@ mod path_validation_tests {
 ///
 /// `ValidationResult<()>` indicating whether the file size is valid
 pub fn validate_openapi_file_size(file_size: u64) -> ValidationResult<()> {
-    if file_size > MAX_OPENAPI_FILE_SIZE {
-        return Err(ValidationError::TooLong {
-            field: "OpenAPI file size",
-            max: MAX_OPENAPI_FILE_SIZE as usize,
-            actual: file_size as usize,
-        });
-    }
-    Ok(())
+    ResourceLimits::default()
+        .with_max_file_size(MAX_OPENAPI_FILE_SIZE)
+        .check_size("OpenAPI file size", file_size)
 }
@@ -0,0 +1,478 @@
+//! Resource limits for untrusted input
+//!
+//! Importers and validators parse whole documents in memory, so an accidental
+//! or malicious upload (a 500MB file, a document nested thousands of levels
+//! deep, a schema with millions of properties) can exhaust a server.
+//! [`ResourceLimits`] bounds the input before and while it is parsed:
+//!
+//! - **File size** - checked against the file's metadata before it is read
+//!   ([`ResourceLimits::check_file`]), while streaming it in
+//!   ([`ResourceLimits::read_limited`]) and against content already in memory
+//! - **Nesting depth** and **properties per object** - checked on JSON and YAML
+//!   documents while they are parsed, stopping at the first violation without
+//!   deserializing the document ([`ResourceLimits::check_content`])
+//! - **Parse timeout** - the same scan gives up once it has taken longer than
+//!   allowed, so documents that are slow to parse are rejected before an
+//!   importer or validator builds them
+//!
+//! The importers check their input against their limits (the defaults unless
+//! set with `with_limits`), and
+//! [`ImporterRegistry`](crate::import::ImporterRegistry) passes its limits to
+//! every import. JSON and YAML importers check every limit; importers of other
+//! formats (SQL, CSV, Protobuf, XML, ...) only check the size. The schema
+//! validators check against the defaults.
+//!
+//! # Example
+//!
+//! ```rust
+//! use data_modelling_core::validation::limits::ResourceLimits;
+//!
+//! let limits = ResourceLimits::default().with_max_depth(3);
+//! assert!(limits.check_content("input", r#"{"a": {"b": 1}}"#).is_ok());
+//! assert!(limits.check_content("input", r#"{"a": {"b": {"c": {"d": 1}}}}"#).is_err());
+//! ```
+
+use super::input::{ValidationError, ValidationResult};
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Default maximum input size (50MB)
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 50 * 1024 * 1024;
+
+/// Default maximum nesting depth of JSON and YAML documents
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// Default maximum number of properties of a single object
+pub const DEFAULT_MAX_PROPERTIES: usize = 10_000;
+
+/// Limits applied to untrusted input
+///
+/// Each importer holds a set of limits, the defaults unless replaced with its
+/// `with_limits` method, and rejects content exceeding them before building a
+/// model from it. The [module docs](self) list which limits each format is
+/// checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ResourceLimits {
+    /// Maximum input size in bytes
+    pub max_file_size: u64,
+    /// Maximum nesting depth of objects and arrays
+    pub max_depth: usize,
+    /// Maximum number of properties of a single object
+    pub max_properties: usize,
+    /// Maximum time to spend scanning a document (no timeout if `None`)
+    pub parse_timeout: Option<Duration>,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        Self {
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_properties: DEFAULT_MAX_PROPERTIES,
+            parse_timeout: None,
+        }
+    }
+}
+
+impl ResourceLimits {
+    /// Limits that accept any input
+    pub fn unlimited() -> Self {
+        Self {
+            max_file_size: u64::MAX,
+            max_depth: usize::MAX,
+            max_properties: usize::MAX,
+            parse_timeout: None,
+        }
+    }
+
+    /// Set the maximum input size in bytes
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = bytes;
+        self
+    }
+
+    /// Set the maximum nesting depth
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Set the maximum number of properties per object
+    pub fn with_max_properties(mut self, properties: usize) -> Self {
+        self.max_properties = properties;
+        self
+    }
+
+    /// Set the parse timeout
+    ///
+    /// The timeout is not enforced on WebAssembly, which has no monotonic clock.
+    pub fn with_parse_timeout(mut self, timeout: Duration) -> Self {
+        self.parse_timeout = Some(timeout);
+        self
+    }
+
+    /// Check an input size
+    pub fn check_size(&self, field: &'static str, size: u64) -> ValidationResult<()> {
+        if size > self.max_file_size {
+            return Err(ValidationError::TooLong {
+                field,
+                max: usize::try_from(self.max_file_size).unwrap_or(usize::MAX),
+                actual: usize::try_from(size).unwrap_or(usize::MAX),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the size of a file before reading it
+    ///
+    /// Files whose metadata cannot be read pass; reading them reports the error.
+    pub fn check_file(&self, field: &'static str, path: &Path) -> ValidationResult<()> {
+        match std::fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => self.check_size(field, metadata.len()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Read UTF-8 input, failing as soon as it exceeds the maximum size
+    ///
+    /// At most one byte more than the limit is read, so oversized streams
+    /// (e.g. stdin or an upload body) are never buffered in full.
+    pub fn read_limited(&self, field: &'static str, reader: impl Read) -> ValidationResult<String> {
+        let mut bytes = Vec::new();
+        reader
+            .take(self.max_file_size.saturating_add(1))
+            .read_to_end(&mut bytes)
+            .map_err(|e| ValidationError::InvalidFormat(field, e.to_string()))?;
+        self.check_size(field, bytes.len() as u64)?;
+        String::from_utf8(bytes)
+            .map_err(|_| ValidationError::InvalidFormat(field, "not valid UTF-8".to_string()))
+    }
+
+    /// Check a JSON or YAML document before parsing it
+    ///
+    /// Checks the size, nesting depth and object sizes of the document. The
+    /// document is scanned without deserializing it into a value, and the scan
+    /// stops at the first object or array that exceeds a limit, or once it has
+    /// run longer than the parse timeout. Content in other formats (SQL, CSV,
+    /// Protobuf, XML, ...) should only be checked with
+    /// [`check_size`](Self::check_size).
+    pub fn check_content(&self, field: &'static str, content: &str) -> ValidationResult<()> {
+        self.check_size(field, content.len() as u64)?;
+        if self.max_depth == usize::MAX
+            && self.max_properties == usize::MAX
+            && self.parse_timeout.is_none()
+        {
+            return Ok(());
+        }
+        let violation = RefCell::new(None);
+        let scan = StructureScan {
+            limits: self,
+            field,
+            depth: 0,
+            deadline: self.deadline(),
+            violation: &violation,
+        };
+        // Syntax errors are left to the importer or validator to report
+        if content.trim_start().starts_with(['{', '[']) {
+            let _ = scan.deserialize(&mut serde_json::Deserializer::from_str(content));
+        } else {
+            let _ = scan.deserialize(serde_yaml::Deserializer::from_str(content));
+        }
+        match violation.into_inner() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// When a scan starting now has to give up
+    #[cfg(not(target_arch = "wasm32"))]
+    fn deadline(&self) -> Option<Instant> {
+        self.parse_timeout
+            .and_then(|timeout| Instant::now().checked_add(timeout))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn deadline(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Check the nesting depth and object sizes of a parsed document
+    pub fn check_document(&self, field: &'static str, document: &Value) -> ValidationResult<()> {
+        // Iterative, so hostile documents cannot overflow the stack
+        let mut stack = vec![(document, 0usize)];
+        while let Some((value, depth)) = stack.pop() {
+            let children: Box<dyn Iterator<Item = &Value>> = match value {
+                Value::Object(map) => {
+                    if map.len() > self.max_properties {
+                        return Err(ValidationError::TooManyProperties {
+                            field,
+                            max: self.max_properties,
+                            actual: map.len(),
+                        });
+                    }
+                    Box::new(map.values())
+                }
+                Value::Array(items) => Box::new(items.iter()),
+                _ => continue,
+            };
+            if depth + 1 > self.max_depth {
+                return Err(ValidationError::TooDeep {
+                    field,
+                    max: self.max_depth,
+                });
+            }
+            stack.extend(children.map(|child| (child, depth + 1)));
+        }
+        Ok(())
+    }
+}
+
+/// Scan of a document that fails at the first object or array exceeding the
+/// limits, or at the first element reached after the deadline
+///
+/// The violation is recorded in `violation`, since the deserializer wraps the
+/// error it is given. The scan recurses at most `max_depth` levels; the JSON and
+/// YAML parsers bound the depth of unlimited scans.
+#[derive(Clone, Copy)]
+struct StructureScan<'a> {
+    limits: &'a ResourceLimits,
+    field: &'static str,
+    depth: usize,
+    deadline: Option<Instant>,
+    violation: &'a RefCell<Option<ValidationError>>,
+}
+
+impl StructureScan<'_> {
+    /// Scan of the children of an object or array at this level
+    fn enter<E: de::Error>(&self) -> Result<Self, E> {
+        if self.depth + 1 > self.limits.max_depth {
+            return Err(self.fail(ValidationError::TooDeep {
+                field: self.field,
+                max: self.limits.max_depth,
+            }));
+        }
+        Ok(Self {
+            depth: self.depth + 1,
+            ..*self
+        })
+    }
+
+    /// Give up once the deadline has passed
+    fn check_deadline<E: de::Error>(&self) -> Result<(), E> {
+        match (self.deadline, self.limits.parse_timeout) {
+            (Some(deadline), Some(timeout)) if Instant::now() >= deadline => {
+                Err(self.fail(ValidationError::Timeout {
+                    field: self.field,
+                    timeout,
+                }))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn fail<E: de::Error>(&self, error: ValidationError) -> E {
+        let message = error.to_string();
+        *self.violation.borrow_mut() = Some(error);
+        E::custom(message)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for StructureScan<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StructureScan<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON or YAML document")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i128<E>(self, _: i128) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u128<E>(self, _: u128) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_bytes<E>(self, _: &[u8]) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_none<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let child = self.enter()?;
+        loop {
+            self.check_deadline()?;
+            if seq.next_element_seed(child)?.is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let child = self.enter()?;
+        let mut properties = 0usize;
+        loop {
+            self.check_deadline()?;
+            if map.next_key_seed(child)?.is_none() {
+                return Ok(());
+            }
+            properties += 1;
+            if properties > self.limits.max_properties {
+                return Err(self.fail(ValidationError::TooManyProperties {
+                    field: self.field,
+                    max: self.limits.max_properties,
+                    actual: properties,
+                }));
+            }
+            map.next_value_seed(child)?;
+        }
+    }
+
+    // YAML tags (`!Tag value`) arrive as enums
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<(), A::Error> {
+        let (IgnoredAny, variant) = data.variant()?;
+        de::VariantAccess::newtype_variant_seed(variant, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_limits() {
+        let limits = ResourceLimits::default().with_max_file_size(8);
+        assert!(limits.check_content("input", "id: 1").is_ok());
+        assert!(matches!(
+            limits.check_content("input", "name: orders"),
+            Err(ValidationError::TooLong { max: 8, .. })
+        ));
+        assert_eq!(
+            limits.read_limited("input", "12345678".as_bytes()).unwrap(),
+            "12345678"
+        );
+        assert!(
+            limits
+                .read_limited("input", "123456789".as_bytes())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_structure_limits() {
+        let limits = ResourceLimits::default()
+            .with_max_depth(2)
+            .with_max_properties(2);
+        assert!(limits.check_content("input", "a:\n  b: 1\n").is_ok());
+        assert!(matches!(
+            limits.check_content("input", "a:\n  b:\n    c: 1\n"),
+            Err(ValidationError::TooDeep { max: 2, .. })
+        ));
+        assert!(matches!(
+            limits.check_content("input", r#"{"a": 1, "b": 2, "c": 3}"#),
+            Err(ValidationError::TooManyProperties { actual: 3, .. })
+        ));
+        // Non-document content is only checked for size
+        assert!(
+            limits
+                .check_content("input", "message A { message B { int32 c = 1; } }")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_structure_scan_stops_at_violation() {
+        let limits = ResourceLimits::default().with_max_depth(64);
+        // Deeper than the parser's own recursion limit, and never closed
+        let hostile = "[".repeat(100_000);
+        assert!(matches!(
+            limits.check_content("input", &hostile),
+            Err(ValidationError::TooDeep { max: 64, .. })
+        ));
+
+        // The scan stops before the syntax error after the oversized object
+        let limits = ResourceLimits::default().with_max_properties(2);
+        assert!(matches!(
+            limits.check_content("input", "a: 1\nb: 2\nc: 3\n: : :"),
+            Err(ValidationError::TooManyProperties {
+                max: 2,
+                actual: 3,
+                ..
+            })
+        ));
+        // Syntax errors are left to the importer
+        assert!(limits.check_content("input", "{\"a\": [1, 2").is_ok());
+        assert!(
+            ResourceLimits::unlimited()
+                .with_max_properties(2)
+                .check_content("input", &hostile)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        let document = r#"{"a": [1, 2, 3]}"#;
+        assert!(
+            ResourceLimits::default()
+                .with_parse_timeout(Duration::from_secs(60))
+                .check_content("input", document)
+                .is_ok()
+        );
+        assert!(matches!(
+            ResourceLimits::unlimited()
+                .with_parse_timeout(Duration::ZERO)
+                .check_content("input", document),
+            Err(ValidationError::Timeout { .. })
+        ));
+    }
+}
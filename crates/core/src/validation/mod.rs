@@ -2,21 +2,36 @@
 //!
 //! Provides validation logic for:
 //! - Table validation (naming conflicts, pattern exclusivity)
+//! - Backward compatibility of contract changes (breaking-change gate)
 //! - Relationship validation (circular dependencies)
+//! - ETL job metadata of relationships (schedules, job URLs, transformation links)
 //! - Input validation and sanitization (security)
+//! - Resource limits for untrusted input (size, nesting depth, properties per object)
+//! - Registered schemas for organization-specific `customProperties`
 //! - JSON Schema validation for various file formats (ODCS, ODCL, Decision, Knowledge, etc.)
+//! - XSD-derived record shapes for conforming XML records
 
+pub mod compatibility;
+pub mod custom_properties;
+pub mod etl;
 pub mod input;
+pub mod limits;
 pub mod relationships;
 pub mod schema;
 pub mod tables;
 pub mod xml;
 
+pub use compatibility::{CompatibilityError, validate_backward_compatible};
+pub use custom_properties::{CustomPropertyRegistry, CustomPropertyViolation};
+pub use etl::{
+    EtlIssue, EtlIssueKind, validate_etl_metadata, validate_schedule, validate_workspace_etl,
+};
 pub use input::{
     ValidationError, sanitize_model_name, sanitize_path, sanitize_sql_identifier,
     validate_bpmn_dmn_file_size, validate_column_name, validate_data_type, validate_glob_pattern,
     validate_openapi_file_size, validate_path, validate_table_name, validate_url, validate_uuid,
 };
+pub use limits::ResourceLimits;
 pub use relationships::{RelationshipValidationError, RelationshipValidationResult};
 pub use schema::{
     validate_avro_internal, validate_cads_internal, validate_decision_internal,
@@ -27,4 +42,4 @@ pub use schema::{
     validate_workspace_internal,
 };
 pub use tables::{TableValidationError, TableValidationResult};
-pub use xml::{load_xsd_schema, validate_xml_against_xsd};
+pub use xml::{XmlElementShape, XmlRecordSchema, load_xsd_schema, validate_xml_against_xsd};
@@ -3,6 +3,22 @@
 //! Provides schema validation for various file formats (ODCS, ODCL, ODPS, CADS, Decision, Knowledge, etc.)
 //! This module is gated by the `schema-validation` feature and is available to all SDK consumers.
 
+use super::limits::ResourceLimits;
+
+/// Check a JSON or YAML document against resource limits before it is parsed
+fn check_limits(limits: ResourceLimits, content: &str) -> Result<(), String> {
+    limits
+        .check_content("content", content)
+        .map_err(|e| e.to_string())
+}
+
+/// Check the size of content in any other format before it is parsed
+fn check_size(content: &str) -> Result<(), String> {
+    ResourceLimits::default()
+        .check_size("content", content.len() as u64)
+        .map_err(|e| e.to_string())
+}
+
 /// Format validation error with path information
 #[cfg(feature = "schema-validation")]
 fn format_validation_error(error: &jsonschema::ValidationError, schema_type: &str) -> String {
@@ -31,6 +47,7 @@ fn format_validation_error(error: &jsonschema::ValidationError, schema_type: &st
 /// Returns a string error for use by both CLI and import/export modules
 #[cfg(feature = "schema-validation")]
 pub fn validate_odcs_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -77,14 +94,15 @@ pub fn validate_odcs_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_odcs_internal(_content: &str) -> Result<(), String> {
+pub fn validate_odcs_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate an ODCL file against the ODCL JSON Schema
 #[cfg(feature = "schema-validation")]
 pub fn validate_odcl_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -110,14 +128,18 @@ pub fn validate_odcl_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_odcl_internal(_content: &str) -> Result<(), String> {
+pub fn validate_odcl_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate an OpenAPI file against the OpenAPI JSON Schema
 #[cfg(feature = "schema-validation")]
 pub fn validate_openapi_internal(content: &str) -> Result<(), String> {
+    check_limits(
+        ResourceLimits::default().with_max_file_size(super::input::MAX_OPENAPI_FILE_SIZE),
+        content,
+    )?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -145,13 +167,17 @@ pub fn validate_openapi_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_openapi_internal(_content: &str) -> Result<(), String> {
+pub fn validate_openapi_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(
+        ResourceLimits::default().with_max_file_size(super::input::MAX_OPENAPI_FILE_SIZE),
+        content,
+    )
 }
 
 /// Validate Protobuf file syntax
 pub fn validate_protobuf_internal(content: &str) -> Result<(), String> {
+    check_size(content)?;
     // Basic syntax validation - check for common proto keywords
     if !content.contains("syntax") && !content.contains("message") && !content.contains("enum") {
         return Err("File does not appear to be a valid Protobuf file".to_string());
@@ -172,6 +198,7 @@ pub fn validate_protobuf_internal(content: &str) -> Result<(), String> {
 
 /// Validate AVRO file against AVRO specification
 pub fn validate_avro_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     // Parse as JSON
     let _value: serde_json::Value =
         serde_json::from_str(content).map_err(|e| format!("Failed to parse AVRO JSON: {}", e))?;
@@ -184,6 +211,7 @@ pub fn validate_avro_internal(content: &str) -> Result<(), String> {
 /// Validate JSON Schema file
 #[cfg(feature = "schema-validation")]
 pub fn validate_json_schema_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -198,14 +226,15 @@ pub fn validate_json_schema_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_json_schema_internal(_content: &str) -> Result<(), String> {
+pub fn validate_json_schema_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Internal ODPS validation function that returns a string error (used by both CLI and import/export modules)
 #[cfg(feature = "schema-validation")]
 pub fn validate_odps_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -240,14 +269,15 @@ pub fn validate_odps_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_odps_internal(_content: &str) -> Result<(), String> {
+pub fn validate_odps_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Internal CADS validation function that returns a string error (used by export modules)
 #[cfg(feature = "schema-validation")]
 pub fn validate_cads_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -282,13 +312,14 @@ pub fn validate_cads_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_cads_internal(_content: &str) -> Result<(), String> {
+pub fn validate_cads_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate SQL syntax using sqlparser
 pub fn validate_sql_internal(content: &str) -> Result<(), String> {
+    check_size(content)?;
     use sqlparser::dialect::GenericDialect;
     use sqlparser::parser::Parser;
 
@@ -302,6 +333,7 @@ pub fn validate_sql_internal(content: &str) -> Result<(), String> {
 /// Validate a workspace.yaml file against the workspace JSON Schema
 #[cfg(feature = "schema-validation")]
 pub fn validate_workspace_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -327,13 +359,14 @@ pub fn validate_workspace_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_workspace_internal(_content: &str) -> Result<(), String> {
+pub fn validate_workspace_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate a relationships.yaml file
 pub fn validate_relationships_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use serde_json::Value;
 
     // Parse YAML content
@@ -364,6 +397,7 @@ pub fn validate_relationships_internal(content: &str) -> Result<(), String> {
 /// Internal decision validation function that returns a string error (used by import/export modules)
 #[cfg(feature = "schema-validation")]
 pub fn validate_decision_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -398,14 +432,15 @@ pub fn validate_decision_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_decision_internal(_content: &str) -> Result<(), String> {
+pub fn validate_decision_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Internal knowledge validation function that returns a string error (used by import/export modules)
 #[cfg(feature = "schema-validation")]
 pub fn validate_knowledge_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -440,14 +475,15 @@ pub fn validate_knowledge_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_knowledge_internal(_content: &str) -> Result<(), String> {
+pub fn validate_knowledge_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate a decisions index (decisions.yaml) file against the decisions-index JSON Schema
 #[cfg(feature = "schema-validation")]
 pub fn validate_decisions_index_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -473,14 +509,15 @@ pub fn validate_decisions_index_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_decisions_index_internal(_content: &str) -> Result<(), String> {
+pub fn validate_decisions_index_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate a knowledge index (knowledge.yaml) file against the knowledge-index JSON Schema
 #[cfg(feature = "schema-validation")]
 pub fn validate_knowledge_index_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -506,14 +543,15 @@ pub fn validate_knowledge_index_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_knowledge_index_internal(_content: &str) -> Result<(), String> {
+pub fn validate_knowledge_index_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Internal sketch validation function that returns a string error (used by import/export modules)
 #[cfg(feature = "schema-validation")]
 pub fn validate_sketch_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -548,14 +586,15 @@ pub fn validate_sketch_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_sketch_internal(_content: &str) -> Result<(), String> {
+pub fn validate_sketch_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
 
 /// Validate a sketch index (sketches.yaml) file against the sketch-index JSON Schema
 #[cfg(feature = "schema-validation")]
 pub fn validate_sketch_index_internal(content: &str) -> Result<(), String> {
+    check_limits(ResourceLimits::default(), content)?;
     use jsonschema::Validator;
     use serde_json::Value;
 
@@ -581,7 +620,7 @@ pub fn validate_sketch_index_internal(content: &str) -> Result<(), String> {
 }
 
 #[cfg(not(feature = "schema-validation"))]
-pub fn validate_sketch_index_internal(_content: &str) -> Result<(), String> {
+pub fn validate_sketch_index_internal(content: &str) -> Result<(), String> {
     // Validation disabled - feature not enabled
-    Ok(())
+    check_limits(ResourceLimits::default(), content)
 }
@@ -1,8 +1,10 @@
 //! XML validation utilities
 //!
-//! Provides functionality for validating XML files against XSD schemas.
+//! Provides functionality for validating XML files against XSD schemas, and
+//! [`XmlRecordSchema`] for conforming XML records to the structure an XSD declares.
 
 use anyhow::{Context, Result};
+use serde_json::{Map, Value};
 use std::path::Path;
 
 /// Validate XML content against an XSD schema file.
@@ -93,3 +95,272 @@ pub fn load_xsd_schema(xsd_path: &str) -> Result<String> {
     fs::read_to_string(&full_xsd_path)
         .with_context(|| format!("Failed to read XSD schema: {}", full_xsd_path.display()))
 }
+
+/// Expected structure of an XML element or attribute, derived from an XSD
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlElementShape {
+    /// Local element or attribute name
+    pub name: String,
+    /// Mapped type of the content (e.g. `BIGINT`), or `OBJECT` for complex elements
+    pub data_type: String,
+    /// Whether the element may occur more than once
+    pub repeated: bool,
+    /// Child elements and attributes, in schema order
+    pub children: Vec<XmlElementShape>,
+}
+
+impl XmlElementShape {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            data_type: "OBJECT".to_string(),
+            repeated: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Build the shape of a root element from `parent.child` / `parent.[].child`
+    /// column paths and their data types, in column order.
+    pub fn from_columns<'a>(
+        name: &str,
+        columns: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut root = Self::new(name);
+        for (path, data_type) in columns {
+            let mut shape = &mut root;
+            for segment in path.split('.').filter(|s| *s != "[]") {
+                let index = match shape.children.iter().position(|c| c.name == segment) {
+                    Some(index) => index,
+                    None => {
+                        shape.children.push(Self::new(segment));
+                        shape.children.len() - 1
+                    }
+                };
+                shape = &mut shape.children[index];
+            }
+            match data_type
+                .strip_prefix("ARRAY<")
+                .and_then(|t| t.strip_suffix('>'))
+            {
+                Some(item_type) => {
+                    shape.repeated = true;
+                    shape.data_type = item_type.to_string();
+                }
+                None => shape.data_type = data_type.to_string(),
+            }
+        }
+        root
+    }
+
+    /// Find a direct child by name
+    pub fn child(&self, name: &str) -> Option<&XmlElementShape> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Conform the JSON value of one occurrence of this element to the shape.
+    ///
+    /// Every declared child is present and unknown keys are kept: repeated children
+    /// are always arrays (empty when absent), absent single children are `null`, and
+    /// simple content is converted to the declared numeric or boolean type where it
+    /// parses.
+    pub fn conform(&self, value: &Value) -> Value {
+        if self.children.is_empty() {
+            return self.conform_simple(value);
+        }
+        let mut object = match value {
+            Value::Object(object) => object.clone(),
+            // Text-only occurrence of an element with attributes
+            Value::String(text) if !text.is_empty() => {
+                Map::from_iter([("value".to_string(), value.clone())])
+            }
+            _ => Map::new(),
+        };
+        let mut conformed = Map::new();
+        for child in &self.children {
+            let value = child.conform_occurrences(object.remove(&child.name));
+            conformed.insert(child.name.clone(), value);
+        }
+        conformed.extend(object);
+        Value::Object(conformed)
+    }
+
+    fn conform_occurrences(&self, value: Option<Value>) -> Value {
+        match value {
+            None | Some(Value::Null) if self.repeated => Value::Array(Vec::new()),
+            None | Some(Value::Null) => Value::Null,
+            Some(Value::Array(values)) => {
+                Value::Array(values.iter().map(|v| self.conform(v)).collect())
+            }
+            Some(value) if self.repeated => Value::Array(vec![self.conform(&value)]),
+            Some(value) => self.conform(&value),
+        }
+    }
+
+    fn conform_simple(&self, value: &Value) -> Value {
+        let Value::String(text) = value else {
+            return value.clone();
+        };
+        let trimmed = text.trim();
+        let base_type = self
+            .data_type
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .to_uppercase();
+        let converted = match base_type.as_str() {
+            "BIGINT" | "INTEGER" | "INT" | "SMALLINT" | "TINYINT" => integer(trimmed),
+            "DECIMAL" | "NUMERIC" | "FLOAT" | "DOUBLE" => integer(trimmed).or_else(|| {
+                trimmed
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+            }),
+            "BOOLEAN" => match trimmed {
+                "true" | "1" => Some(Value::Bool(true)),
+                "false" | "0" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            _ => None,
+        };
+        converted.unwrap_or_else(|| value.clone())
+    }
+}
+
+fn integer(text: &str) -> Option<Value> {
+    text.parse::<i64>()
+        .map(Value::from)
+        .or_else(|_| text.parse::<u64>().map(Value::from))
+        .ok()
+}
+
+/// Record structures declared by an XSD, one per global element
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XmlRecordSchema {
+    /// Shapes of the global elements
+    pub roots: Vec<XmlElementShape>,
+}
+
+impl XmlRecordSchema {
+    /// Derive record shapes from XSD content using the XSD importer.
+    #[cfg(feature = "xsd")]
+    pub fn from_xsd(xsd_content: &str) -> Result<Self> {
+        let result = crate::import::XsdImporter::new()
+            .import(xsd_content)
+            .context("Failed to import XSD schema")?;
+        let roots = result
+            .tables
+            .iter()
+            .filter_map(|table| {
+                let name = table.name.as_deref()?;
+                Some(XmlElementShape::from_columns(
+                    name,
+                    table
+                        .columns
+                        .iter()
+                        .map(|c| (c.name.as_str(), c.data_type.as_str())),
+                ))
+            })
+            .collect();
+        Ok(Self { roots })
+    }
+
+    /// Shape of a record element found under a document root.
+    ///
+    /// Records are usually children of a declared root element; a record that is
+    /// itself a global element is matched by its own name.
+    pub fn record_shape(&self, root: &str, record: &str) -> Option<&XmlElementShape> {
+        self.roots
+            .iter()
+            .find(|shape| shape.name == root)
+            .and_then(|shape| shape.child(record))
+            .or_else(|| self.roots.iter().find(|shape| shape.name == record))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn order_shape() -> XmlElementShape {
+        XmlElementShape::from_columns(
+            "Order",
+            [
+                ("Id", "BIGINT"),
+                ("Paid", "BOOLEAN"),
+                ("Line", "ARRAY<OBJECT>"),
+                ("Line.[].Sku", "STRING"),
+                ("Line.[].Price", "OBJECT"),
+                ("Line.[].Price.value", "DECIMAL(10,2)"),
+                ("Line.[].Price.Ccy", "STRING"),
+                ("Note", "ARRAY<STRING>"),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_shape_from_columns() {
+        let shape = order_shape();
+        let names: Vec<&str> = shape.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Id", "Paid", "Line", "Note"]);
+        let line = shape.child("Line").unwrap();
+        assert!(line.repeated);
+        assert_eq!(line.data_type, "OBJECT");
+        assert_eq!(line.child("Price").unwrap().children.len(), 2);
+        let note = shape.child("Note").unwrap();
+        assert!(note.repeated);
+        assert_eq!(note.data_type, "STRING");
+    }
+
+    #[test]
+    fn test_conform_orders_and_types_values() {
+        let value = json!({
+            "Line": {"Price": "12.50", "Sku": "A-1"},
+            "Extra": "kept",
+            "Id": "42",
+            "Paid": "1"
+        });
+        let conformed = order_shape().conform(&value);
+        assert_eq!(
+            conformed,
+            json!({
+                "Id": 42,
+                "Paid": true,
+                "Line": [{"Sku": "A-1", "Price": {"value": 12.5, "Ccy": null}}],
+                "Note": [],
+                "Extra": "kept"
+            })
+        );
+
+        // Values that do not parse as the declared type are kept as text
+        let conformed = order_shape().conform(&json!({"Id": "n/a"}));
+        assert_eq!(conformed["Id"], json!("n/a"));
+    }
+
+    #[test]
+    fn test_record_shape_lookup() {
+        let schema = XmlRecordSchema {
+            roots: vec![
+                XmlElementShape::from_columns(
+                    "Orders",
+                    [("Order", "ARRAY<OBJECT>"), ("Order.[].Id", "BIGINT")],
+                ),
+                order_shape(),
+            ],
+        };
+        assert_eq!(
+            schema
+                .record_shape("Orders", "Order")
+                .unwrap()
+                .children
+                .len(),
+            1
+        );
+        assert_eq!(
+            schema.record_shape("Feed", "Order").unwrap().children.len(),
+            4
+        );
+        assert!(schema.record_shape("Feed", "Unknown").is_none());
+    }
+}
@@ -0,0 +1,388 @@
+//! Workspace bundles
+//!
+//! [`export_bundle`] packs a workspace into a single zip archive for sharing and
+//! backup, and [`import_bundle`] restores it, both through any [`StorageBackend`].
+//!
+//! A bundle contains the workspace-level files (`workspace.yaml`,
+//! `relationships.yaml`, `definitions.yaml`, indexes) and every asset file
+//! recognized by [`AssetType::from_filename`] (ODCS, ODPS, CADS, OpenAPI, BPMN,
+//! DMN, decisions, knowledge articles, sketches), plus all files in the
+//! `decisions/`, `knowledge/`, `sketches/` and `sketches/thumbnails/`
+//! directories. Paths in the archive are relative to the workspace, and a
+//! manifest listing them, with the content fingerprints of the bundled data
+//! contracts, is stored as [`MANIFEST_FILENAME`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//! use data_modelling_core::storage::memory::MemoryStorageBackend;
+//! use data_modelling_core::workspace::bundle::{export_bundle, import_bundle};
+//!
+//! let source = FileSystemStorageBackend::new("/path/to/workspaces");
+//! let archive = export_bundle(&source, "sales").await?;
+//!
+//! let target = MemoryStorageBackend::new();
+//! let restored = import_bundle(&target, "sales-copy", &archive, false).await?;
+//! println!("Restored {} files", restored.files_written.len());
+//! ```
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read, Write};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use zip::read::ZipFile;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::models::odcs::ODCSContract;
+use crate::models::workspace::AssetType;
+use crate::storage::{StorageBackend, StorageError};
+use crate::validation::limits::DEFAULT_MAX_FILE_SIZE;
+
+/// Name of the manifest stored in every bundle
+pub const MANIFEST_FILENAME: &str = "odm-bundle.yaml";
+
+/// Version of the bundle format written by [`export_bundle`]
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Workspace subdirectories whose files are bundled in full
+pub const BUNDLED_DIRECTORIES: &[&str] =
+    &["decisions", "knowledge", "sketches", "sketches/thumbnails"];
+
+/// Default maximum number of archive entries (10,000)
+pub const DEFAULT_MAX_BUNDLE_ENTRIES: usize = 10_000;
+
+/// Default maximum decompressed size of a whole bundle (1 GiB)
+pub const DEFAULT_MAX_BUNDLE_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// Limits on the archives accepted by [`import_bundle_with_limits`]
+///
+/// Sizes are decompressed sizes. They are checked against the sizes an entry
+/// declares and again while it is decompressed, so an archive understating
+/// them fails as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BundleLimits {
+    /// Maximum number of entries, including the manifest and directories
+    pub max_entries: usize,
+    /// Maximum size of a single entry in bytes
+    pub max_entry_size: u64,
+    /// Maximum size of all entries together in bytes
+    pub max_total_size: u64,
+}
+
+impl Default for BundleLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_MAX_BUNDLE_ENTRIES,
+            max_entry_size: DEFAULT_MAX_FILE_SIZE,
+            max_total_size: DEFAULT_MAX_BUNDLE_SIZE,
+        }
+    }
+}
+
+/// Errors from exporting or importing a bundle
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    #[error("Invalid bundle archive: {0}")]
+    Archive(String),
+
+    #[error("Unsafe path in bundle: {0}")]
+    UnsafePath(String),
+
+    #[error("Unsupported bundle version: {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("Bundle exceeds a limit: {0}")]
+    LimitExceeded(String),
+}
+
+impl From<zip::result::ZipError> for BundleError {
+    fn from(e: zip::result::ZipError) -> Self {
+        BundleError::Archive(e.to_string())
+    }
+}
+
+impl From<std::io::Error> for BundleError {
+    fn from(e: std::io::Error) -> Self {
+        BundleError::Archive(e.to_string())
+    }
+}
+
+/// Manifest describing the contents of a bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleManifest {
+    /// Bundle format version
+    pub version: u32,
+    /// When the bundle was created
+    pub created_at: DateTime<Utc>,
+    /// Bundled files, relative to the workspace
+    pub files: Vec<String>,
+    /// Content fingerprints of the bundled ODCS contracts, by file
+    ///
+    /// See [`ODCSContract::fingerprint`]; files that do not parse as a
+    /// contract have no fingerprint.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fingerprints: BTreeMap<String, String>,
+}
+
+/// Outcome of [`import_bundle`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BundleImport {
+    /// Files written to the workspace, relative to it
+    pub files_written: Vec<String>,
+    /// Existing files left unchanged because overwriting was not allowed
+    pub files_skipped: Vec<String>,
+}
+
+/// Files of a workspace to bundle, relative to it and sorted
+pub async fn bundle_files<B: StorageBackend>(
+    storage: &B,
+    workspace_path: &str,
+) -> Result<Vec<String>, BundleError> {
+    let mut files: Vec<String> = storage
+        .list_files(workspace_path)
+        .await?
+        .into_iter()
+        .filter(|name| AssetType::from_filename(name).is_some())
+        .collect();
+
+    for dir in BUNDLED_DIRECTORIES {
+        let dir_path = join(workspace_path, dir);
+        if !storage.dir_exists(&dir_path).await? {
+            continue;
+        }
+        files.extend(
+            storage
+                .list_files(&dir_path)
+                .await?
+                .into_iter()
+                .map(|name| format!("{}/{}", dir, name)),
+        );
+    }
+
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Pack a workspace into a zip archive
+pub async fn export_bundle<B: StorageBackend>(
+    storage: &B,
+    workspace_path: &str,
+) -> Result<Vec<u8>, BundleError> {
+    let files = bundle_files(storage, workspace_path).await?;
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut fingerprints = BTreeMap::new();
+
+    for file in &files {
+        let content = storage.read_file(&join(workspace_path, file)).await?;
+        if AssetType::from_filename(file) == Some(AssetType::Odcs)
+            && let Ok(contract) = serde_yaml::from_slice::<ODCSContract>(&content)
+        {
+            fingerprints.insert(file.clone(), contract.fingerprint());
+        }
+        writer.start_file(file.as_str(), options)?;
+        writer.write_all(&content)?;
+    }
+
+    let manifest = BundleManifest {
+        version: BUNDLE_VERSION,
+        created_at: Utc::now(),
+        files,
+        fingerprints,
+    };
+    let manifest = serde_yaml::to_string(&manifest)
+        .map_err(|e| BundleError::Archive(format!("Failed to write manifest: {}", e)))?;
+    writer.start_file(MANIFEST_FILENAME, options)?;
+    writer.write_all(manifest.as_bytes())?;
+
+    Ok(writer.finish()?.into_inner())
+}
+
+/// Read the manifest of a bundle
+///
+/// The manifest must stay within the default [`BundleLimits::max_entry_size`].
+pub fn read_manifest(archive: &[u8]) -> Result<BundleManifest, BundleError> {
+    let mut zip = ZipArchive::new(Cursor::new(archive))?;
+    read_manifest_entry(&mut zip, &BundleLimits::default(), &mut 0)
+}
+
+fn read_manifest_entry(
+    zip: &mut ZipArchive<Cursor<&[u8]>>,
+    limits: &BundleLimits,
+    total: &mut u64,
+) -> Result<BundleManifest, BundleError> {
+    let entry = zip
+        .by_name(MANIFEST_FILENAME)
+        .map_err(|_| BundleError::Archive(format!("{} is missing", MANIFEST_FILENAME)))?;
+    let content = String::from_utf8(read_entry(entry, limits, total)?)
+        .map_err(|e| BundleError::Archive(format!("Failed to read manifest: {}", e)))?;
+    serde_yaml::from_str(&content)
+        .map_err(|e| BundleError::Archive(format!("Failed to parse manifest: {}", e)))
+}
+
+/// Restore a bundle into a workspace, within the default [`BundleLimits`]
+///
+/// Existing files are replaced when `overwrite` is set and left unchanged
+/// otherwise. Files are only written after the whole archive has been read, so
+/// an invalid bundle leaves the workspace untouched.
+pub async fn import_bundle<B: StorageBackend>(
+    storage: &B,
+    workspace_path: &str,
+    archive: &[u8],
+    overwrite: bool,
+) -> Result<BundleImport, BundleError> {
+    import_bundle_with_limits(
+        storage,
+        workspace_path,
+        archive,
+        overwrite,
+        &BundleLimits::default(),
+    )
+    .await
+}
+
+/// Restore a bundle into a workspace, rejecting archives beyond `limits`
+///
+/// See [`import_bundle`]; an archive exceeding a limit fails with
+/// [`BundleError::LimitExceeded`] before any file is written.
+pub async fn import_bundle_with_limits<B: StorageBackend>(
+    storage: &B,
+    workspace_path: &str,
+    archive: &[u8],
+    overwrite: bool,
+    limits: &BundleLimits,
+) -> Result<BundleImport, BundleError> {
+    let mut zip = ZipArchive::new(Cursor::new(archive))?;
+    if zip.len() > limits.max_entries {
+        return Err(BundleError::LimitExceeded(format!(
+            "{} entries, at most {} allowed",
+            zip.len(),
+            limits.max_entries
+        )));
+    }
+
+    let mut total = 0;
+    let manifest = read_manifest_entry(&mut zip, limits, &mut total)?;
+    if manifest.version > BUNDLE_VERSION {
+        return Err(BundleError::UnsupportedVersion(manifest.version));
+    }
+
+    let mut entries = Vec::with_capacity(zip.len());
+    for index in 0..zip.len() {
+        let entry = zip.by_index(index)?;
+        if entry.is_dir() || entry.name() == MANIFEST_FILENAME {
+            continue;
+        }
+        let path = safe_path(entry.name())?;
+        entries.push((path, read_entry(entry, limits, &mut total)?));
+    }
+
+    if !storage.dir_exists(workspace_path).await? {
+        storage.create_dir(workspace_path).await?;
+    }
+
+    let mut result = BundleImport::default();
+    for (path, content) in entries {
+        let target = join(workspace_path, &path);
+        if !overwrite && storage.file_exists(&target).await? {
+            result.files_skipped.push(path);
+            continue;
+        }
+        if let Some((dir, _)) = path.rsplit_once('/') {
+            let dir = join(workspace_path, dir);
+            if !storage.dir_exists(&dir).await? {
+                storage.create_dir(&dir).await?;
+            }
+        }
+        storage.write_file(&target, &content).await?;
+        result.files_written.push(path);
+    }
+    Ok(result)
+}
+
+/// Decompress an archive entry within the entry limit, adding its size to
+/// `total` and failing once that exceeds the total limit
+fn read_entry(
+    mut entry: ZipFile<'_>,
+    limits: &BundleLimits,
+    total: &mut u64,
+) -> Result<Vec<u8>, BundleError> {
+    let name = entry.name().to_string();
+    let too_large = |size: u64| {
+        BundleError::LimitExceeded(format!(
+            "{} has {} bytes, at most {} allowed",
+            name,
+            size,
+            limits.max_entry_size
+        ))
+    };
+    if entry.size() > limits.max_entry_size {
+        return Err(too_large(entry.size()));
+    }
+
+    // The declared size may understate the data, so the read is capped too
+    let mut content = Vec::new();
+    entry
+        .by_ref()
+        .take(limits.max_entry_size.saturating_add(1))
+        .read_to_end(&mut content)?;
+    let size = content.len() as u64;
+    if size > limits.max_entry_size {
+        return Err(too_large(size));
+    }
+
+    *total += size;
+    if *total > limits.max_total_size {
+        return Err(BundleError::LimitExceeded(format!(
+            "more than {} bytes in total",
+            limits.max_total_size
+        )));
+    }
+    Ok(content)
+}
+
+/// Validate an archive entry name as a relative path inside the workspace
+fn safe_path(name: &str) -> Result<String, BundleError> {
+    let unsafe_path = || BundleError::UnsafePath(name.to_string());
+    if name.starts_with('/') || name.contains('\\') || name.contains(':') {
+        return Err(unsafe_path());
+    }
+    let segments: Vec<&str> = name.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.is_empty() || segments.iter().any(|s| *s == "." || *s == "..") {
+        return Err(unsafe_path());
+    }
+    Ok(segments.join("/"))
+}
+
+fn join(workspace_path: &str, file: &str) -> String {
+    if workspace_path.is_empty() {
+        file.to_string()
+    } else {
+        format!("{}/{}", workspace_path.trim_end_matches('/'), file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_path() {
+        assert_eq!(
+            safe_path("sketches/thumbnails/a.png").unwrap(),
+            "sketches/thumbnails/a.png"
+        );
+        assert!(safe_path("../outside.yaml").is_err());
+        assert!(safe_path("decisions/../../x").is_err());
+        assert!(safe_path("/etc/passwd").is_err());
+        assert!(safe_path("C:\\x.yaml").is_err());
+    }
+}
@@ -0,0 +1,518 @@
+//! ETL job inventory
+//!
+//! [`EtlInventory`] lists the ETL jobs of a workspace's relationships per
+//! domain, for operational views. A job belongs to the domain of its target
+//! table, or of its source table when the target is not a workspace asset.
+//!
+//! [`link_etl_transformations`] connects jobs to the transformation links of
+//! their domain ([`DomainReference::transformation_links`]) by name or URL, so
+//! the inventory can show the transformation implementing each job.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::validation::etl::validate_workspace_etl;
+//! use data_modelling_core::workspace::etl::{EtlInventory, link_etl_transformations};
+//!
+//! let report = link_etl_transformations(&mut workspace);
+//! println!("Linked {} jobs", report.linked.len());
+//!
+//! for issue in validate_workspace_etl(&workspace) {
+//!     println!("{}: {}", issue.job_name, issue.kind);
+//! }
+//!
+//! let inventory = EtlInventory::from_workspace(&workspace);
+//! std::fs::write("etl-jobs.md", inventory.to_markdown())?;
+//! ```
+//!
+//! [`DomainReference::transformation_links`]: crate::models::workspace::DomainReference::transformation_links
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::Relationship;
+use crate::models::workspace::{DomainReference, TransformationLink, Workspace};
+
+/// ETL job of a relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtlJob {
+    /// Relationship carrying the job metadata
+    pub relationship_id: Uuid,
+    /// Name of the job
+    pub job_name: String,
+    /// Table the job reads from
+    pub source_table_id: Uuid,
+    /// Name of the source table, when it is a workspace asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
+    /// Table the job writes to
+    pub target_table_id: Uuid,
+    /// Name of the target table, when it is a workspace asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_name: Option<String>,
+    /// Execution frequency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<String>,
+    /// Cron schedule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// URL of the job in the orchestrator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_url: Option<String>,
+    /// Owner of the relationship
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Notes about the job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Linked transformation, when it exists in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transformation: Option<TransformationLink>,
+}
+
+/// ETL jobs of one domain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainEtlJobs {
+    /// Domain name
+    pub domain: String,
+    /// Domain identifier, when the domain is declared in the workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<Uuid>,
+    /// Jobs, sorted by name
+    pub jobs: Vec<EtlJob>,
+}
+
+/// ETL jobs of a workspace, grouped per domain
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtlInventory {
+    /// Domains with at least one job, in workspace order
+    pub domains: Vec<DomainEtlJobs>,
+    /// Jobs between tables that are not workspace assets
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unassigned: Vec<EtlJob>,
+}
+
+/// Outcome of [`link_etl_transformations`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EtlLinkReport {
+    /// Relationships whose job was linked to a transformation
+    pub linked: Vec<Uuid>,
+    /// Relationships whose job matched no transformation link
+    pub unmatched: Vec<Uuid>,
+}
+
+impl EtlInventory {
+    /// Collect the ETL jobs of a workspace
+    pub fn from_workspace(workspace: &Workspace) -> Self {
+        let mut inventory = Self::default();
+
+        for relationship in &workspace.relationships {
+            let Some(etl) = &relationship.etl_job_metadata else {
+                continue;
+            };
+            let domain = job_domain(workspace, relationship);
+            let transformation = etl.transformation_id.and_then(|id| {
+                workspace
+                    .domains
+                    .iter()
+                    .flat_map(|d| &d.transformation_links)
+                    .find(|link| link.id == id)
+                    .cloned()
+            });
+            let job = EtlJob {
+                relationship_id: relationship.id,
+                job_name: etl.job_name.clone(),
+                source_table_id: relationship.source_table_id,
+                source_name: asset_name(workspace, relationship.source_table_id),
+                target_table_id: relationship.target_table_id,
+                target_name: asset_name(workspace, relationship.target_table_id),
+                frequency: etl.frequency.clone(),
+                schedule: etl.schedule.clone(),
+                job_url: etl.job_url.clone(),
+                owner: relationship.owner.clone(),
+                notes: etl.notes.clone(),
+                transformation,
+            };
+
+            let Some(domain) = domain else {
+                inventory.unassigned.push(job);
+                continue;
+            };
+            match inventory.domains.iter_mut().find(|d| d.domain == domain) {
+                Some(entry) => entry.jobs.push(job),
+                None => inventory.domains.push(DomainEtlJobs {
+                    domain_id: find_domain(workspace, &domain).map(|d| d.id),
+                    domain,
+                    jobs: vec![job],
+                }),
+            }
+        }
+
+        // Declared domains first, in workspace order, then the others by name
+        let position = |name: &str| {
+            workspace
+                .domains
+                .iter()
+                .position(|d| d.name.eq_ignore_ascii_case(name))
+                .unwrap_or(usize::MAX)
+        };
+        inventory.domains.sort_by(|a, b| {
+            position(&a.domain)
+                .cmp(&position(&b.domain))
+                .then_with(|| a.domain.cmp(&b.domain))
+        });
+        for domain in &mut inventory.domains {
+            domain.jobs.sort_by(|a, b| a.job_name.cmp(&b.job_name));
+        }
+        inventory
+            .unassigned
+            .sort_by(|a, b| a.job_name.cmp(&b.job_name));
+        inventory
+    }
+
+    /// Total number of jobs
+    pub fn job_count(&self) -> usize {
+        self.domains.iter().map(|d| d.jobs.len()).sum::<usize>() + self.unassigned.len()
+    }
+
+    /// Jobs of a domain, by case-insensitive name
+    pub fn domain(&self, name: &str) -> Option<&DomainEtlJobs> {
+        self.domains
+            .iter()
+            .find(|d| d.domain.eq_ignore_ascii_case(name))
+    }
+
+    /// Render the inventory as Markdown, with a table of jobs per domain
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# ETL Jobs\n");
+        if self.job_count() == 0 {
+            md.push_str("\nNo ETL jobs.\n");
+            return md;
+        }
+        for domain in &self.domains {
+            md.push_str(&format!("\n## {}\n\n", domain.domain));
+            push_job_table(&mut md, &domain.jobs);
+        }
+        if !self.unassigned.is_empty() {
+            md.push_str("\n## Unassigned\n\n");
+            push_job_table(&mut md, &self.unassigned);
+        }
+        md
+    }
+}
+
+/// Link ETL jobs to the transformation links of their domain
+///
+/// A job without a transformation is linked to the link of its domain whose
+/// name matches the job name (case-insensitively) or whose URL equals the job
+/// URL. Jobs outside any domain are linked only when exactly one link in the
+/// workspace matches. Jobs that already have a transformation are left alone.
+pub fn link_etl_transformations(workspace: &mut Workspace) -> EtlLinkReport {
+    let mut report = EtlLinkReport::default();
+    let mut links = Vec::new();
+
+    for relationship in &workspace.relationships {
+        let Some(etl) = &relationship.etl_job_metadata else {
+            continue;
+        };
+        if etl.transformation_id.is_some() {
+            continue;
+        }
+        let matches = |link: &&TransformationLink| {
+            link.name.eq_ignore_ascii_case(etl.job_name.trim())
+                || (link.url.is_some() && link.url == etl.job_url)
+        };
+        let candidates: Vec<&TransformationLink> =
+            match job_domain(workspace, relationship).and_then(|d| find_domain(workspace, &d)) {
+                Some(domain) => domain.transformation_links.iter().filter(matches).collect(),
+                None => workspace
+                    .domains
+                    .iter()
+                    .flat_map(|d| &d.transformation_links)
+                    .filter(matches)
+                    .collect(),
+            };
+        match candidates.as_slice() {
+            [link, ..] if candidates.len() == 1 || has_domain(workspace, relationship) => {
+                links.push((relationship.id, link.id));
+                report.linked.push(relationship.id);
+            }
+            _ => report.unmatched.push(relationship.id),
+        }
+    }
+
+    if !links.is_empty() {
+        let now = Utc::now();
+        for (relationship_id, transformation_id) in links {
+            let relationship = workspace
+                .relationships
+                .iter_mut()
+                .find(|r| r.id == relationship_id);
+            if let Some(relationship) = relationship
+                && let Some(etl) = &mut relationship.etl_job_metadata
+            {
+                etl.transformation_id = Some(transformation_id);
+                relationship.updated_at = now;
+            }
+        }
+        workspace.last_modified_at = now;
+    }
+    report
+}
+
+/// Domain of a job: that of its target asset, else of its source asset
+fn job_domain(workspace: &Workspace, relationship: &Relationship) -> Option<String> {
+    [relationship.target_table_id, relationship.source_table_id]
+        .iter()
+        .find_map(|id| workspace.assets.iter().find(|a| a.id == *id))
+        .map(|asset| asset.domain.clone())
+}
+
+/// Whether a job belongs to a domain declared in the workspace
+fn has_domain(workspace: &Workspace, relationship: &Relationship) -> bool {
+    job_domain(workspace, relationship)
+        .and_then(|d| find_domain(workspace, &d))
+        .is_some()
+}
+
+fn find_domain<'a>(workspace: &'a Workspace, name: &str) -> Option<&'a DomainReference> {
+    workspace
+        .domains
+        .iter()
+        .find(|d| d.name.eq_ignore_ascii_case(name))
+}
+
+fn asset_name(workspace: &Workspace, id: Uuid) -> Option<String> {
+    workspace
+        .assets
+        .iter()
+        .find(|a| a.id == id)
+        .map(|a| a.name.clone())
+}
+
+fn push_job_table(md: &mut String, jobs: &[EtlJob]) {
+    md.push_str("| Job | Source | Target | Schedule | Transformation | Job URL |\n");
+    md.push_str("|-----|--------|--------|----------|----------------|---------|\n");
+    for job in jobs {
+        let table = |name: &Option<String>, id: &Uuid| match name {
+            Some(name) => cell(name),
+            None => format!("`{}`", id),
+        };
+        let schedule = job
+            .schedule
+            .as_deref()
+            .map(|s| format!("`{}`", cell(s)))
+            .or_else(|| job.frequency.as_deref().map(cell))
+            .unwrap_or_else(|| "-".to_string());
+        let transformation = match &job.transformation {
+            Some(link) => match (&link.transformation_type, &link.url) {
+                (Some(kind), Some(url)) => format!("[{}]({}) ({})", cell(&link.name), url, kind),
+                (Some(kind), None) => format!("{} ({})", cell(&link.name), kind),
+                (None, Some(url)) => format!("[{}]({})", cell(&link.name), url),
+                (None, None) => cell(&link.name),
+            },
+            None => "-".to_string(),
+        };
+        let job_url = job
+            .job_url
+            .as_deref()
+            .map(|url| format!("<{}>", url))
+            .unwrap_or_else(|| "-".to_string());
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            cell(&job.job_name),
+            table(&job.source_name, &job.source_table_id),
+            table(&job.target_name, &job.target_table_id),
+            schedule,
+            transformation,
+            job_url
+        ));
+    }
+}
+
+/// Escape a value for a Markdown table cell
+fn cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ETLJobMetadata;
+    use crate::models::workspace::{AssetReference, AssetType};
+
+    fn domain(name: &str, links: Vec<TransformationLink>) -> DomainReference {
+        DomainReference {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            systems: Vec::new(),
+            shared_resources: Vec::new(),
+            transformation_links: links,
+            table_visibility: None,
+            view_positions: Default::default(),
+        }
+    }
+
+    fn link(name: &str) -> TransformationLink {
+        TransformationLink {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            transformation_type: Some("dbt".to_string()),
+            url: Some(format!("https://git.example.com/dbt/{}.sql", name)),
+            description: None,
+        }
+    }
+
+    fn asset(workspace: &mut Workspace, name: &str, domain: &str) -> Uuid {
+        let id = Uuid::new_v4();
+        workspace.assets.push(AssetReference {
+            id,
+            name: name.to_string(),
+            domain: domain.to_string(),
+            system: None,
+            asset_type: AssetType::Odcs,
+            file_path: None,
+        });
+        id
+    }
+
+    fn job(workspace: &mut Workspace, source: Uuid, target: Uuid, name: &str) -> Uuid {
+        let mut relationship = Relationship::new(source, target);
+        relationship.etl_job_metadata = Some(ETLJobMetadata {
+            job_name: name.to_string(),
+            schedule: Some("0 2 * * *".to_string()),
+            ..Default::default()
+        });
+        let id = relationship.id;
+        workspace.relationships.push(relationship);
+        id
+    }
+
+    fn sample_workspace() -> Workspace {
+        let mut workspace = Workspace::new("enterprise".to_string(), Uuid::new_v4());
+        workspace.domains = vec![
+            domain("sales", vec![link("load_orders")]),
+            domain("finance", vec![link("book_revenue")]),
+        ];
+        let raw = asset(&mut workspace, "raw_orders", "sales");
+        let orders = asset(&mut workspace, "orders", "sales");
+        let revenue = asset(&mut workspace, "revenue", "finance");
+        job(&mut workspace, raw, orders, "load_orders");
+        job(&mut workspace, orders, revenue, "Book_Revenue");
+        job(&mut workspace, orders, revenue, "archive_orders");
+        job(
+            &mut workspace,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "external_sync",
+        );
+        workspace
+    }
+
+    #[test]
+    fn test_inventory_groups_jobs_per_domain() {
+        let workspace = sample_workspace();
+        let inventory = EtlInventory::from_workspace(&workspace);
+
+        assert_eq!(inventory.job_count(), 4);
+        let names: Vec<&str> = inventory
+            .domains
+            .iter()
+            .map(|d| d.domain.as_str())
+            .collect();
+        assert_eq!(names, vec!["sales", "finance"]);
+
+        let finance = inventory.domain("Finance").unwrap();
+        assert_eq!(finance.domain_id, Some(workspace.domains[1].id));
+        let jobs: Vec<&str> = finance.jobs.iter().map(|j| j.job_name.as_str()).collect();
+        assert_eq!(jobs, vec!["Book_Revenue", "archive_orders"]);
+        assert_eq!(finance.jobs[0].source_name.as_deref(), Some("orders"));
+        assert_eq!(finance.jobs[0].target_name.as_deref(), Some("revenue"));
+
+        assert_eq!(inventory.unassigned.len(), 1);
+        assert_eq!(inventory.unassigned[0].job_name, "external_sync");
+    }
+
+    #[test]
+    fn test_link_etl_transformations() {
+        let mut workspace = sample_workspace();
+        let report = link_etl_transformations(&mut workspace);
+
+        assert_eq!(report.linked.len(), 2);
+        assert_eq!(report.unmatched.len(), 2);
+        let linked = |name: &str| {
+            workspace
+                .relationships
+                .iter()
+                .filter_map(|r| r.etl_job_metadata.as_ref())
+                .find(|etl| etl.job_name == name)
+                .and_then(|etl| etl.transformation_id)
+        };
+        assert_eq!(
+            linked("load_orders"),
+            Some(workspace.domains[0].transformation_links[0].id)
+        );
+        assert_eq!(
+            linked("Book_Revenue"),
+            Some(workspace.domains[1].transformation_links[0].id)
+        );
+        assert_eq!(linked("archive_orders"), None);
+
+        // Linking again leaves linked jobs alone
+        let again = link_etl_transformations(&mut workspace);
+        assert!(again.linked.is_empty());
+        assert_eq!(again.unmatched.len(), 2);
+
+        let inventory = EtlInventory::from_workspace(&workspace);
+        let sales = inventory.domain("sales").unwrap();
+        assert_eq!(
+            sales.jobs[0]
+                .transformation
+                .as_ref()
+                .map(|t| t.name.as_str()),
+            Some("load_orders")
+        );
+    }
+
+    #[test]
+    fn test_link_requires_unique_match_outside_domains() {
+        let mut workspace = Workspace::new("enterprise".to_string(), Uuid::new_v4());
+        workspace.domains = vec![
+            domain("sales", vec![link("sync")]),
+            domain("finance", vec![link("sync")]),
+        ];
+        job(&mut workspace, Uuid::new_v4(), Uuid::new_v4(), "sync");
+
+        let report = link_etl_transformations(&mut workspace);
+        assert!(report.linked.is_empty());
+        assert_eq!(report.unmatched.len(), 1);
+    }
+
+    #[test]
+    fn test_inventory_markdown() {
+        let mut workspace = sample_workspace();
+        link_etl_transformations(&mut workspace);
+        let md = EtlInventory::from_workspace(&workspace).to_markdown();
+
+        assert!(md.starts_with("# ETL Jobs\n"));
+        assert!(md.contains("\n## sales\n"));
+        assert!(md.contains("\n## finance\n"));
+        assert!(md.contains("\n## Unassigned\n"));
+        assert!(md.contains(
+            "| load_orders | raw_orders | orders | `0 2 * * *` | \
+             [load_orders](https://git.example.com/dbt/load_orders.sql) (dbt) | - |"
+        ));
+
+        let empty = Workspace::new("empty".to_string(), Uuid::new_v4());
+        assert!(
+            EtlInventory::from_workspace(&empty)
+                .to_markdown()
+                .contains("No ETL jobs.")
+        );
+    }
+}
@@ -0,0 +1,684 @@
+//! Cross-workspace asset federation
+//!
+//! Resolves [`CrossWorkspaceRef`]s: the referenced table's ODCS definition is read
+//! from the owning workspace's storage backend (or, with the `git` and `native-fs`
+//! features, a checkout of its Git remote) and kept as a read-only cached copy in
+//! a local backend:
+//!
+//! - `{cache_dir}/{workspace}/{table_id}.odcs.yaml` - the cached contract
+//! - `{cache_dir}/{workspace}/{table_id}.meta.json` - where and when it was fetched
+//!
+//! A cached copy younger than its maximum age is served without contacting the
+//! source. Older copies are refreshed; if the source cannot be reached the cached
+//! copy is served as [`CacheStatus::Stale`] with a warning. Cached copies are
+//! never written back - edits belong in the owning workspace.
+//!
+//! The owning workspace is located through `workspace.yaml`: the asset with the
+//! referenced ID (or, failing that, the table name) gives the contract file; if
+//! neither is listed, the workspace's ODCS files are searched for the ID.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use data_modelling_core::models::CrossWorkspaceRef;
+//! use data_modelling_core::storage::filesystem::FileSystemStorageBackend;
+//! use data_modelling_core::workspace::federation::FederationResolver;
+//!
+//! let resolver = FederationResolver::new(FileSystemStorageBackend::new("./my-workspace"))
+//!     .with_source("finance", FileSystemStorageBackend::new("../finance"), "");
+//! let federated = resolver.resolve(&reference).await?;
+//! let table = federated.table()?;
+//! ```
+
+use crate::import::odcs::ODCSImporter;
+use crate::models::Table;
+use crate::models::cross_domain::{CrossDomainConfig, CrossWorkspaceRef};
+use crate::models::odcs::ODCSContract;
+use crate::models::workspace::{AssetType, Workspace};
+use crate::storage::{StorageBackend, StorageError};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Default directory of cached copies in the local backend
+pub const DEFAULT_CACHE_DIR: &str = ".federation";
+
+/// Default maximum age of cached copies
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Errors resolving cross-workspace references
+#[derive(Debug, Error)]
+pub enum FederationError {
+    #[error("No source registered for workspace '{0}'")]
+    UnknownWorkspace(String),
+    #[error("Table {table_id} not found in workspace '{workspace}'")]
+    TableNotFound { workspace: String, table_id: Uuid },
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+    #[error("Failed to parse {path}: {message}")]
+    Parse { path: String, message: String },
+    #[error("Git error: {0}")]
+    Git(String),
+}
+
+/// How a federated table was served
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheStatus {
+    /// Served from a cached copy within its maximum age
+    Fresh,
+    /// Fetched from the source workspace and cached
+    Refreshed,
+    /// Served from an expired cached copy because the source could not be read
+    Stale,
+}
+
+/// Result of comparing a cached copy with its source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "message", rename_all = "camelCase")]
+pub enum Staleness {
+    /// No cached copy exists
+    NotCached,
+    /// The cached copy matches the source
+    Current,
+    /// The source changed since the copy was cached
+    Outdated,
+    /// The table no longer exists in the source workspace
+    SourceMissing,
+    /// The source could not be read
+    Unreachable(String),
+}
+
+/// Metadata stored next to a cached copy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedTableMetadata {
+    pub reference_id: Uuid,
+    pub source_workspace: String,
+    pub table_id: Uuid,
+    /// Path of the contract in the source workspace
+    pub source_path: String,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// A resolved, read-only copy of a table owned by another workspace
+#[derive(Debug, Clone, PartialEq)]
+pub struct FederatedTable {
+    pub reference: CrossWorkspaceRef,
+    /// Path of the contract in the source workspace
+    pub source_path: String,
+    /// ODCS YAML of the table
+    pub content: String,
+    pub fetched_at: DateTime<Utc>,
+    pub status: CacheStatus,
+    /// Why a stale copy was served
+    pub warning: Option<String>,
+}
+
+impl FederatedTable {
+    /// Parse the cached ODCS contract
+    pub fn contract(&self) -> Result<ODCSContract, FederationError> {
+        serde_yaml::from_str(&self.content).map_err(|e| FederationError::Parse {
+            path: self.source_path.clone(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Parse the cached contract into a table
+    pub fn table(&self) -> Result<Table, FederationError> {
+        ODCSImporter::new()
+            .parse_table(&self.content)
+            .map(|(table, _)| table)
+            .map_err(|e| FederationError::Parse {
+                path: self.source_path.clone(),
+                message: e.to_string(),
+            })
+    }
+}
+
+/// A registered source workspace: its backend and the workspace directory within it
+struct Source {
+    backend: Box<dyn StorageBackend>,
+    root: String,
+}
+
+/// Resolves cross-workspace references against the owning workspaces and caches
+/// the results in a local backend
+pub struct FederationResolver<C: StorageBackend> {
+    cache: C,
+    cache_dir: String,
+    max_age: Duration,
+    sources: HashMap<String, Source>,
+    #[cfg(all(feature = "git", feature = "native-fs"))]
+    git_checkout_dir: Option<std::path::PathBuf>,
+}
+
+impl<C: StorageBackend> FederationResolver<C> {
+    /// Create a resolver caching into `cache`
+    pub fn new(cache: C) -> Self {
+        Self {
+            cache,
+            cache_dir: DEFAULT_CACHE_DIR.to_string(),
+            max_age: DEFAULT_MAX_AGE,
+            sources: HashMap::new(),
+            #[cfg(all(feature = "git", feature = "native-fs"))]
+            git_checkout_dir: None,
+        }
+    }
+
+    /// Set the directory of cached copies in the cache backend
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<String>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    /// Set the default maximum age of cached copies
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Register the backend storing a source workspace; `root` is the workspace
+    /// directory within the backend (empty for the backend root)
+    pub fn with_source(
+        mut self,
+        workspace: impl Into<String>,
+        backend: impl StorageBackend + 'static,
+        root: impl Into<String>,
+    ) -> Self {
+        self.sources.insert(
+            workspace.into(),
+            Source {
+                backend: Box::new(backend),
+                root: root.into(),
+            },
+        );
+        self
+    }
+
+    /// Resolve Git locations of unregistered workspaces by cloning (or pulling)
+    /// their remotes into subdirectories of `checkout_dir`
+    #[cfg(all(feature = "git", feature = "native-fs"))]
+    pub fn with_git_checkouts(mut self, checkout_dir: impl Into<std::path::PathBuf>) -> Self {
+        self.git_checkout_dir = Some(checkout_dir.into());
+        self
+    }
+
+    /// Resolve a reference, serving a fresh cached copy or refreshing it
+    pub async fn resolve(
+        &self,
+        reference: &CrossWorkspaceRef,
+    ) -> Result<FederatedTable, FederationError> {
+        self.resolve_at(reference, Utc::now()).await
+    }
+
+    /// Resolve a reference as of `now`
+    pub async fn resolve_at(
+        &self,
+        reference: &CrossWorkspaceRef,
+        now: DateTime<Utc>,
+    ) -> Result<FederatedTable, FederationError> {
+        let cached = self.cached(reference).await?;
+        if let Some(table) = &cached
+            && !self.is_expired(reference, table.fetched_at, now)
+        {
+            return Ok(table.clone());
+        }
+
+        match self.fetch(reference).await {
+            Ok((source_path, content)) => self.store(reference, source_path, content, now).await,
+            Err(e) => match cached {
+                Some(table) => Ok(FederatedTable {
+                    status: CacheStatus::Stale,
+                    warning: Some(e.to_string()),
+                    ..table
+                }),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Fetch a reference from its source and replace the cached copy
+    pub async fn refresh(
+        &self,
+        reference: &CrossWorkspaceRef,
+    ) -> Result<FederatedTable, FederationError> {
+        let (source_path, content) = self.fetch(reference).await?;
+        self.store(reference, source_path, content, Utc::now())
+            .await
+    }
+
+    /// Resolve every cross-workspace reference of a cross-domain configuration
+    pub async fn resolve_all(
+        &self,
+        config: &CrossDomainConfig,
+    ) -> Vec<(Uuid, Result<FederatedTable, FederationError>)> {
+        let mut results = Vec::new();
+        for reference in &config.imported_workspace_tables {
+            results.push((reference.id, self.resolve(reference).await));
+        }
+        results
+    }
+
+    /// The cached copy of a reference, if any, without contacting the source
+    pub async fn cached(
+        &self,
+        reference: &CrossWorkspaceRef,
+    ) -> Result<Option<FederatedTable>, FederationError> {
+        let (content_path, meta_path) = self.cache_paths(reference);
+        if !self.cache.file_exists(&meta_path).await?
+            || !self.cache.file_exists(&content_path).await?
+        {
+            return Ok(None);
+        }
+        let meta = self.cache.read_file(&meta_path).await?;
+        let meta: CachedTableMetadata =
+            serde_json::from_slice(&meta).map_err(|e| FederationError::Parse {
+                path: meta_path.clone(),
+                message: e.to_string(),
+            })?;
+        let content = read_string(&self.cache, &content_path).await?;
+        Ok(Some(FederatedTable {
+            reference: reference.clone(),
+            source_path: meta.source_path,
+            content,
+            fetched_at: meta.fetched_at,
+            status: CacheStatus::Fresh,
+            warning: None,
+        }))
+    }
+
+    /// Compare the cached copy of a reference with its source
+    pub async fn staleness(
+        &self,
+        reference: &CrossWorkspaceRef,
+    ) -> Result<Staleness, FederationError> {
+        let Some(cached) = self.cached(reference).await? else {
+            return Ok(Staleness::NotCached);
+        };
+        Ok(match self.fetch(reference).await {
+            Ok((_, content)) if content == cached.content => Staleness::Current,
+            Ok(_) => Staleness::Outdated,
+            Err(FederationError::TableNotFound { .. }) => Staleness::SourceMissing,
+            Err(e) => Staleness::Unreachable(e.to_string()),
+        })
+    }
+
+    /// Remove the cached copy of a reference
+    pub async fn evict(&self, reference: &CrossWorkspaceRef) -> Result<bool, FederationError> {
+        let mut removed = false;
+        for path in <[String; 2]>::from(self.cache_paths(reference)) {
+            if self.cache.file_exists(&path).await? {
+                self.cache.delete_file(&path).await?;
+                removed = true;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn is_expired(
+        &self,
+        reference: &CrossWorkspaceRef,
+        fetched_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let max_age = reference
+            .max_age_hours
+            .map(|hours| Duration::from_secs(hours * 60 * 60))
+            .unwrap_or(self.max_age);
+        let max_age = TimeDelta::from_std(max_age).unwrap_or(TimeDelta::MAX);
+        now - fetched_at >= max_age
+    }
+
+    fn cache_paths(&self, reference: &CrossWorkspaceRef) -> (String, String) {
+        let dir = join(&self.cache_dir, &sanitize(&reference.source_workspace));
+        (
+            join(&dir, &format!("{}.odcs.yaml", reference.table_id)),
+            join(&dir, &format!("{}.meta.json", reference.table_id)),
+        )
+    }
+
+    async fn store(
+        &self,
+        reference: &CrossWorkspaceRef,
+        source_path: String,
+        content: String,
+        now: DateTime<Utc>,
+    ) -> Result<FederatedTable, FederationError> {
+        let (content_path, meta_path) = self.cache_paths(reference);
+        let meta = CachedTableMetadata {
+            reference_id: reference.id,
+            source_workspace: reference.source_workspace.clone(),
+            table_id: reference.table_id,
+            source_path: source_path.clone(),
+            fetched_at: now,
+        };
+        let meta = serde_json::to_vec_pretty(&meta)
+            .map_err(|e| StorageError::SerializationError(e.to_string()))?;
+        self.cache
+            .write_file(&content_path, content.as_bytes())
+            .await?;
+        self.cache.write_file(&meta_path, &meta).await?;
+        Ok(FederatedTable {
+            reference: reference.clone(),
+            source_path,
+            content,
+            fetched_at: now,
+            status: CacheStatus::Refreshed,
+            warning: None,
+        })
+    }
+
+    /// Read the referenced contract from its source workspace
+    async fn fetch(
+        &self,
+        reference: &CrossWorkspaceRef,
+    ) -> Result<(String, String), FederationError> {
+        if let Some(source) = self.sources.get(&reference.source_workspace) {
+            return fetch_from(source.backend.as_ref(), &source.root, reference).await;
+        }
+
+        #[cfg(all(feature = "git", feature = "native-fs"))]
+        {
+            use crate::models::cross_domain::WorkspaceLocation;
+            use crate::storage::filesystem::FileSystemStorageBackend;
+
+            if let (Some(checkout_dir), Some(WorkspaceLocation::Git { url, branch, path })) =
+                (&self.git_checkout_dir, &reference.location)
+            {
+                let checkout = git::checkout(checkout_dir, url, branch.as_deref())?;
+                let backend = FileSystemStorageBackend::new(checkout);
+                return fetch_from(&backend, path.as_deref().unwrap_or(""), reference).await;
+            }
+        }
+
+        Err(FederationError::UnknownWorkspace(
+            reference.source_workspace.clone(),
+        ))
+    }
+}
+
+/// Locate and read the referenced contract in a workspace directory of a backend
+async fn fetch_from(
+    backend: &dyn StorageBackend,
+    root: &str,
+    reference: &CrossWorkspaceRef,
+) -> Result<(String, String), FederationError> {
+    let not_found = || FederationError::TableNotFound {
+        workspace: reference.source_workspace.clone(),
+        table_id: reference.table_id,
+    };
+
+    let workspace_path = join(root, "workspace.yaml");
+    if backend.file_exists(&workspace_path).await? {
+        let content = read_string(backend, &workspace_path).await?;
+        let workspace = Workspace::from_yaml(&content).map_err(|e| FederationError::Parse {
+            path: workspace_path.clone(),
+            message: e.to_string(),
+        })?;
+        let odcs = || {
+            workspace
+                .assets
+                .iter()
+                .filter(|a| a.asset_type == AssetType::Odcs)
+        };
+        let asset = odcs().find(|a| a.id == reference.table_id).or_else(|| {
+            reference
+                .table_name
+                .as_ref()
+                .and_then(|name| odcs().find(|a| &a.name == name))
+        });
+        if let Some(asset) = asset {
+            let file = asset
+                .file_path
+                .clone()
+                .unwrap_or_else(|| workspace.generate_asset_filename(asset));
+            let path = join(root, &file);
+            return match backend.read_file(&path).await {
+                Ok(bytes) => {
+                    let content = into_string(&path, bytes)?;
+                    Ok((path, content))
+                }
+                Err(StorageError::FileNotFound(_)) => Err(not_found()),
+                Err(e) => Err(e.into()),
+            };
+        }
+    }
+
+    // Not listed in workspace.yaml: search the workspace's contracts for the ID
+    let files = match backend.list_files(root).await {
+        Ok(files) => files,
+        Err(StorageError::DirectoryNotFound(_)) => return Err(not_found()),
+        Err(e) => return Err(e.into()),
+    };
+    for file in files.iter().filter(|f| f.ends_with(".odcs.yaml")) {
+        let name = file.rsplit('/').next().unwrap_or(file);
+        let path = join(root, name);
+        let content = read_string(backend, &path).await?;
+        if let Ok((table, _)) = ODCSImporter::new().parse_table(&content)
+            && table.id == reference.table_id
+        {
+            return Ok((path, content));
+        }
+    }
+    Err(not_found())
+}
+
+async fn read_string(backend: &dyn StorageBackend, path: &str) -> Result<String, FederationError> {
+    let bytes = backend.read_file(path).await?;
+    into_string(path, bytes)
+}
+
+fn into_string(path: &str, bytes: Vec<u8>) -> Result<String, FederationError> {
+    String::from_utf8(bytes).map_err(|e| FederationError::Parse {
+        path: path.to_string(),
+        message: e.to_string(),
+    })
+}
+
+fn join(dir: &str, name: &str) -> String {
+    let dir = dir.trim_end_matches('/');
+    if dir.is_empty() || dir == "." {
+        name.to_string()
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+/// Directory-safe form of a workspace name or remote URL
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(all(feature = "git", feature = "native-fs"))]
+mod git {
+    use super::{FederationError, sanitize};
+    use crate::git::GitService;
+    use std::path::{Path, PathBuf};
+
+    /// Clone `url` into `checkout_dir`, or pull it if already cloned
+    pub(super) fn checkout(
+        checkout_dir: &Path,
+        url: &str,
+        branch: Option<&str>,
+    ) -> Result<PathBuf, FederationError> {
+        let path = checkout_dir.join(sanitize(url));
+        let mut service = GitService::new();
+        if path.join(".git").exists() {
+            service
+                .open_or_init(&path)
+                .and_then(|_| service.pull("origin", branch.unwrap_or("main")))
+                .map_err(|e| FederationError::Git(e.to_string()))?;
+        } else {
+            service
+                .clone_repository(url, &path, branch)
+                .map_err(|e| FederationError::Git(e.to_string()))?;
+        }
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::workspace::AssetReference;
+    use crate::storage::memory::MemoryStorageBackend;
+
+    const TABLE_ID: &str = "6f1f0b7a-3c1e-4f0e-9d6b-2d8b7f1c0a11";
+
+    fn contract(description: &str) -> String {
+        format!(
+            "apiVersion: v3.1.0\nkind: DataContract\nid: {}\nname: payments\nversion: 1.0.0\nstatus: active\nschema:\n  - name: payments\n    description: {}\n    properties:\n      - name: payment_id\n        logicalType: integer\n",
+            TABLE_ID, description
+        )
+    }
+
+    fn source() -> MemoryStorageBackend {
+        let mut workspace = Workspace::new("finance".to_string(), Uuid::new_v4());
+        workspace.add_asset(AssetReference {
+            id: Uuid::parse_str(TABLE_ID).unwrap(),
+            name: "payments".to_string(),
+            domain: "ledger".to_string(),
+            system: None,
+            asset_type: AssetType::Odcs,
+            file_path: Some("finance_ledger_payments.odcs.yaml".to_string()),
+        });
+        MemoryStorageBackend::new()
+            .with_file("finance/workspace.yaml", workspace.to_yaml().unwrap())
+            .with_file(
+                "finance/finance_ledger_payments.odcs.yaml",
+                contract("Settled payments"),
+            )
+    }
+
+    fn reference() -> CrossWorkspaceRef {
+        CrossWorkspaceRef::new("finance".to_string(), Uuid::parse_str(TABLE_ID).unwrap())
+            .with_max_age_hours(1)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_caches_and_refreshes() {
+        let source = source();
+        let cache = MemoryStorageBackend::new();
+        let resolver = FederationResolver::new(cache.clone()).with_source(
+            "finance",
+            source.clone(),
+            "finance",
+        );
+        let reference = reference();
+        let now = Utc::now();
+
+        let first = resolver.resolve_at(&reference, now).await.unwrap();
+        assert_eq!(first.status, CacheStatus::Refreshed);
+        assert_eq!(
+            first.source_path,
+            "finance/finance_ledger_payments.odcs.yaml"
+        );
+        assert_eq!(first.table().unwrap().name, "payments");
+        assert!(
+            cache
+                .paths()
+                .contains(&format!(".federation/finance/{}.meta.json", TABLE_ID))
+        );
+
+        // Within the maximum age the cached copy is served, even if the source changed
+        source
+            .write_file(
+                "finance/finance_ledger_payments.odcs.yaml",
+                contract("Settled and refunded payments").as_bytes(),
+            )
+            .await
+            .unwrap();
+        let cached = resolver
+            .resolve_at(&reference, now + TimeDelta::minutes(30))
+            .await
+            .unwrap();
+        assert_eq!(cached.status, CacheStatus::Fresh);
+        assert!(cached.content.contains("Settled payments"));
+        assert_eq!(
+            resolver.staleness(&reference).await.unwrap(),
+            Staleness::Outdated
+        );
+
+        // Once expired it is refreshed from the source
+        let refreshed = resolver
+            .resolve_at(&reference, now + TimeDelta::hours(2))
+            .await
+            .unwrap();
+        assert_eq!(refreshed.status, CacheStatus::Refreshed);
+        assert!(refreshed.content.contains("refunded"));
+        assert_eq!(
+            resolver.staleness(&reference).await.unwrap(),
+            Staleness::Current
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stale_copy_served_when_source_unavailable() {
+        let source = source();
+        let cache = MemoryStorageBackend::new();
+        let reference = reference();
+        let now = Utc::now();
+        FederationResolver::new(cache.clone())
+            .with_source("finance", source, "finance")
+            .resolve_at(&reference, now)
+            .await
+            .unwrap();
+
+        // A resolver without the source registered falls back to the cached copy
+        let offline = FederationResolver::new(cache.clone());
+        let stale = offline
+            .resolve_at(&reference, now + TimeDelta::hours(2))
+            .await
+            .unwrap();
+        assert_eq!(stale.status, CacheStatus::Stale);
+        assert!(stale.warning.unwrap().contains("finance"));
+        assert!(matches!(
+            offline.staleness(&reference).await.unwrap(),
+            Staleness::Unreachable(_)
+        ));
+
+        assert!(offline.evict(&reference).await.unwrap());
+        assert!(matches!(
+            offline.resolve_at(&reference, now).await,
+            Err(FederationError::UnknownWorkspace(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_unlisted_table_by_scanning_contracts() {
+        let source = MemoryStorageBackend::new()
+            .with_file("orders.odcs.yaml", "name: [unparseable")
+            .with_file("payments.odcs.yaml", contract("Settled payments"));
+        let resolver = FederationResolver::new(MemoryStorageBackend::new())
+            .with_cache_dir("cache")
+            .with_source("finance", source.clone(), "");
+
+        let federated = resolver.resolve(&reference()).await.unwrap();
+        assert_eq!(federated.source_path, "payments.odcs.yaml");
+        assert_eq!(federated.contract().unwrap().name, "payments");
+
+        source.delete_file("payments.odcs.yaml").await.unwrap();
+        assert_eq!(
+            resolver.staleness(&reference()).await.unwrap(),
+            Staleness::SourceMissing
+        );
+
+        let missing = CrossWorkspaceRef::new("finance".to_string(), Uuid::new_v4());
+        assert!(matches!(
+            resolver.resolve(&missing).await,
+            Err(FederationError::TableNotFound { .. })
+        ));
+    }
+}
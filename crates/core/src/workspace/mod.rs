@@ -3,6 +3,14 @@
 //! These types are used for:
 //! - Workspace management (profiles, domains)
 //! - Data organization ({email}/{domain}/ structure)
+//! - Zip bundles of whole workspaces (feature `bundle`)
+//! - Per-domain inventories of relationship ETL jobs
+//! - Read-only cached copies of tables federated from other workspaces
+
+#[cfg(feature = "bundle")]
+pub mod bundle;
+pub mod etl;
+pub mod federation;
 
 use serde::{Deserialize, Serialize};
 
@@ -1,10 +1,12 @@
 //! Export module tests
 
 use data_modelling_core::export::{
-    avro::AvroExporter, json_schema::JSONSchemaExporter, protobuf::ProtobufExporter,
+    avro::AvroExporter,
+    json_schema::{JSONSchemaExporter, JsonSchemaDraft, JsonSchemaOptions},
+    protobuf::ProtobufExporter,
     sql::SQLExporter,
 };
-use data_modelling_core::models::{Column, Table};
+use data_modelling_core::models::{Column, LogicalTypeOptions, Table};
 
 fn create_test_table(name: &str, columns: Vec<Column>) -> Table {
     Table {
@@ -108,6 +110,65 @@ mod sql_export_tests {
         assert!(sql.contains("[id]"));
     }
 
+    #[test]
+    fn test_sqlserver_identity_and_descriptions() {
+        let mut id = create_column("id", "INT", true, false);
+        id.custom_properties
+            .insert("autoIncrement".to_string(), serde_json::json!(true));
+        let mut name = create_column("name", "STRING", false, true);
+        name.description = "Customer's name".to_string();
+        let mut table = create_test_table("customers", vec![id, name]);
+        table.schema_name = Some("sales".to_string());
+        table.odcl_metadata.insert(
+            "description".to_string(),
+            serde_json::json!("Customer master"),
+        );
+
+        let sql = SQLExporter::export_table(&table, Some("mssql"));
+
+        assert!(sql.contains("CREATE TABLE [sales].[customers]"));
+        assert!(sql.contains("[id] INT IDENTITY(1,1) NOT NULL PRIMARY KEY"));
+        assert!(sql.contains("[name] NVARCHAR(MAX)"));
+        assert!(!sql.contains("--"));
+        assert!(sql.contains(
+            "@value = N'Customer master', @level0type = N'SCHEMA', @level0name = N'sales', @level1type = N'TABLE', @level1name = N'customers';"
+        ));
+        assert!(sql.contains(
+            "@value = N'Customer''s name', @level0type = N'SCHEMA', @level0name = N'sales', @level1type = N'TABLE', @level1name = N'customers', @level2type = N'COLUMN', @level2name = N'name';"
+        ));
+    }
+
+    #[test]
+    fn test_oracle_identity_types_and_comments() {
+        let mut id = create_column("id", "BIGINT", true, false);
+        id.custom_properties
+            .insert("autoIncrement".to_string(), serde_json::json!(true));
+        let mut name = create_column("name", "VARCHAR(100)", false, true);
+        name.description = "Customer's name".to_string();
+        let table = create_test_table(
+            "customers",
+            vec![
+                id,
+                name,
+                create_column("notes", "TEXT", false, true),
+                create_column("balance", "DECIMAL(12,2)", false, true),
+                create_column("active", "BOOLEAN", false, false),
+            ],
+        );
+
+        let sql = SQLExporter::export_table(&table, Some("oracle"));
+
+        assert!(
+            sql.contains("\"id\" NUMBER(19) GENERATED BY DEFAULT AS IDENTITY NOT NULL PRIMARY KEY")
+        );
+        assert!(sql.contains("\"name\" VARCHAR2(100)"));
+        assert!(sql.contains("\"notes\" CLOB"));
+        assert!(sql.contains("\"balance\" NUMBER(12,2)"));
+        assert!(sql.contains("\"active\" NUMBER(1) NOT NULL"));
+        assert!(!sql.contains("--"));
+        assert!(sql.contains("COMMENT ON COLUMN \"customers\".\"name\" IS 'Customer''s name';"));
+    }
+
     #[test]
     fn test_quote_escaping() {
         // Table with a quote in the name
@@ -133,6 +194,140 @@ mod sql_export_tests {
         assert!(sql.contains("`user``table`"));
     }
 
+    #[test]
+    fn test_snowflake_dialect() {
+        let mut amount = create_column("amount", "DECIMAL(10,2)", false, true);
+        amount.description = "Order amount".to_string();
+        let mut ordered_at = create_column("ordered_at", "DATE", false, false);
+        ordered_at.clustered = true;
+        let mut table = create_test_table(
+            "orders",
+            vec![
+                create_column("id", "NUMERIC", true, false),
+                amount,
+                create_column("payload", "JSON", false, true),
+                create_column("items", "ARRAY<STRUCT<sku: STRING>>", false, true),
+                create_column("attributes", "MAP<STRING, STRING>", false, true),
+                ordered_at,
+            ],
+        );
+        table
+            .odcl_metadata
+            .insert("transient".to_string(), serde_json::json!(true));
+        table.odcl_metadata.insert(
+            "description".to_string(),
+            serde_json::json!("Customer's orders"),
+        );
+
+        let sql = SQLExporter::export_table(&table, Some("snowflake"));
+
+        assert!(sql.starts_with("CREATE TRANSIENT TABLE \"orders\" ("));
+        assert!(sql.contains("\"id\" NUMBER NOT NULL PRIMARY KEY"));
+        assert!(sql.contains("\"amount\" NUMBER(10,2) COMMENT 'Order amount'"));
+        assert!(sql.contains("\"payload\" VARIANT"));
+        assert!(sql.contains("\"items\" ARRAY"));
+        assert!(sql.contains("\"attributes\" OBJECT"));
+        assert!(sql.ends_with(")\nCLUSTER BY (\"ordered_at\")\nCOMMENT = 'Customer''s orders';\n"));
+    }
+
+    #[test]
+    fn test_bigquery_dialect() {
+        let mut amount = create_column("amount", "DECIMAL(10,2)", false, true);
+        amount.description = "Order \"net\" amount".to_string();
+        let mut ordered_at = create_column("ordered_at", "TIMESTAMP", false, false);
+        ordered_at.partitioned = true;
+        let mut customer = create_column("customer_id", "BIGINT", false, false);
+        customer.clustered = true;
+        let mut table = create_test_table(
+            "orders",
+            vec![
+                create_column("id", "INTEGER", true, false),
+                amount,
+                create_column("ratio", "DECIMAL(40,12)", false, true),
+                create_column("note", "VARCHAR(255)", false, true),
+                create_column(
+                    "items",
+                    "ARRAY<STRUCT<sku: VARCHAR(20), qty INT>>",
+                    false,
+                    true,
+                ),
+                create_column("attributes", "MAP<STRING, DOUBLE>", false, true),
+                customer,
+                ordered_at,
+            ],
+        );
+        table.schema_name = Some("sales".to_string());
+        table
+            .odcl_metadata
+            .insert("description".to_string(), serde_json::json!("Orders"));
+
+        let sql = SQLExporter::export_table(&table, Some("bigquery"));
+
+        assert!(sql.starts_with("CREATE TABLE `sales`.`orders` ("));
+        assert!(sql.contains("`id` INT64 NOT NULL,"));
+        assert!(sql.contains(
+            "`amount` NUMERIC(10,2) OPTIONS(description = \"Order \\\"net\\\" amount\")"
+        ));
+        assert!(sql.contains("`ratio` BIGNUMERIC(40,12)"));
+        assert!(sql.contains("`note` STRING"));
+        assert!(sql.contains("`items` ARRAY<STRUCT<sku STRING, qty INT64>>"));
+        assert!(sql.contains("`attributes` ARRAY<STRUCT<key STRING, value FLOAT64>>"));
+        assert!(sql.contains("  PRIMARY KEY (`id`) NOT ENFORCED\n)"));
+        assert!(sql.ends_with(
+            ")\nPARTITION BY `ordered_at`\nCLUSTER BY `customer_id`\nOPTIONS(description = \"Orders\");\n"
+        ));
+    }
+
+    #[test]
+    fn test_spatial_types() {
+        let table = create_test_table(
+            "places",
+            vec![create_column(
+                "location",
+                "GEOGRAPHY(Point, 4326)",
+                false,
+                true,
+            )],
+        );
+
+        let postgres = SQLExporter::export_table(&table, Some("postgres"));
+        assert!(postgres.contains("\"location\" GEOGRAPHY(Point, 4326)"));
+
+        let bigquery = SQLExporter::export_table(&table, Some("bigquery"));
+        assert!(bigquery.contains("GEOGRAPHY"));
+        assert!(!bigquery.contains("Point"));
+    }
+
+    #[test]
+    fn test_vector_and_binary_types() {
+        let mut embedding = create_column("embedding", "VECTOR(768)", false, true);
+        embedding.logical_type_options = Some(LogicalTypeOptions {
+            distance_metric: Some("cosine".to_string()),
+            ..Default::default()
+        });
+        let mut table = create_test_table(
+            "items",
+            vec![embedding, create_column("image", "BLOB", false, true)],
+        );
+        table.schema_name = Some("ml".to_string());
+
+        let postgres = SQLExporter::export_table(&table, Some("postgres"));
+        assert!(postgres.contains("\"embedding\" vector(768)"));
+        assert!(postgres.contains("\"image\" BYTEA"));
+        assert!(postgres.contains(
+            "CREATE INDEX \"items_embedding_idx\" ON \"ml\".\"items\" USING hnsw (\"embedding\" vector_cosine_ops);"
+        ));
+
+        let duckdb = SQLExporter::export_table(&table, Some("duckdb"));
+        assert!(duckdb.contains("\"embedding\" FLOAT[768]"));
+        assert!(duckdb.contains("WITH (metric = 'cosine')"));
+
+        let snowflake = SQLExporter::export_table(&table, Some("snowflake"));
+        assert!(snowflake.contains("\"embedding\" VECTOR(FLOAT, 768)"));
+        assert!(snowflake.contains("\"image\" BINARY"));
+        assert!(!snowflake.contains("CREATE INDEX"));
+    }
+
     #[test]
     fn test_exporter_interface() {
         let exporter = SQLExporter;
@@ -177,6 +372,47 @@ mod json_schema_export_tests {
         assert!(!required.iter().any(|v| v.as_str() == Some("name")));
     }
 
+    #[test]
+    fn test_spatial_types() {
+        let table = create_test_table(
+            "Place",
+            vec![create_column(
+                "location",
+                "GEOGRAPHY(Point, 4326)",
+                false,
+                false,
+            )],
+        );
+
+        let schema = JSONSchemaExporter::export_table(&table);
+        let location = &schema["properties"]["location"];
+
+        assert_eq!(location["type"], "object");
+        assert_eq!(location["properties"]["type"]["const"], "Point");
+        assert_eq!(location["properties"]["coordinates"]["type"], "array");
+    }
+
+    #[test]
+    fn test_vector_and_binary_types() {
+        let table = create_test_table(
+            "Item",
+            vec![
+                create_column("embedding", "VECTOR(3)", false, false),
+                create_column("image", "BYTEA", false, true),
+            ],
+        );
+
+        let schema = JSONSchemaExporter::export_table(&table);
+        let embedding = &schema["properties"]["embedding"];
+        assert_eq!(embedding["type"], "array");
+        assert_eq!(embedding["items"]["type"], "number");
+        assert_eq!(embedding["maxItems"], 3);
+
+        let image = &schema["properties"]["image"];
+        assert_eq!(image["type"], "string");
+        assert_eq!(image["contentEncoding"], "base64");
+    }
+
     #[test]
     fn test_exporter_interface() {
         let exporter = JSONSchemaExporter;
@@ -191,6 +427,116 @@ mod json_schema_export_tests {
         assert!(result.content.contains("User"));
         assert!(result.content.contains("$schema"));
     }
+
+    #[test]
+    fn test_logical_type_constraints() {
+        let mut code = create_column("code", "STRING", false, false);
+        code.logical_type_options = Some(LogicalTypeOptions {
+            pattern: Some("^[A-Z]{3}$".to_string()),
+            min_length: Some(3),
+            max_length: Some(3),
+            ..Default::default()
+        });
+        let mut amount = create_column("amount", "DOUBLE", false, false);
+        amount.logical_type_options = Some(LogicalTypeOptions {
+            minimum: Some(serde_json::json!(0)),
+            exclusive_maximum: Some(serde_json::json!(1000.5)),
+            ..Default::default()
+        });
+
+        let schema =
+            JSONSchemaExporter::export_table(&create_test_table("Payment", vec![code, amount]));
+        let code = &schema["properties"]["code"];
+        assert_eq!(code["pattern"], "^[A-Z]{3}$");
+        assert_eq!(code["minLength"], 3);
+        assert_eq!(code["maxLength"], 3);
+        let amount = &schema["properties"]["amount"];
+        assert_eq!(amount["minimum"], 0);
+        assert_eq!(amount["exclusiveMaximum"], 1000.5);
+    }
+
+    #[test]
+    fn test_export_examples() {
+        let mut status = create_column("status", "STRING", false, false);
+        status.examples = vec![serde_json::json!("open"), serde_json::json!("shipped")];
+        let id = create_column("id", "BIGINT", true, false);
+
+        let schema =
+            JSONSchemaExporter::export_table(&create_test_table("Order", vec![id, status]));
+        assert_eq!(
+            schema["properties"]["status"]["examples"],
+            serde_json::json!(["open", "shipped"])
+        );
+        assert!(schema["properties"]["id"].get("examples").is_none());
+    }
+
+    #[test]
+    fn test_shared_definitions_per_draft() {
+        let mut email = create_column("contact", "STRING", false, true);
+        email.description = "Contact email".to_string();
+        email.custom_properties.insert(
+            "definition".to_string(),
+            serde_json::json!({"ref": "definitions.yaml#/definitions/email", "overrides": []}),
+        );
+        let tables = vec![
+            create_test_table(
+                "users",
+                vec![
+                    create_column("id", "BIGINT", true, false),
+                    email,
+                    create_column("name", "STRING", false, true),
+                ],
+            ),
+            create_test_table(
+                "orders",
+                vec![
+                    create_column("id", "BIGINT", true, false),
+                    create_column("name", "INTEGER", false, true),
+                ],
+            ),
+        ];
+
+        let draft07 = JSONSchemaExporter
+            .export_with_options(
+                &tables,
+                &JsonSchemaOptions {
+                    shared_definitions: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&draft07.content).unwrap();
+        let definitions = &schema["definitions"];
+        assert_eq!(definitions["id"]["type"], "integer");
+        assert_eq!(definitions["email"]["type"], "string");
+        // Columns whose schemas differ between tables stay inline
+        assert!(definitions.get("name").is_none());
+        assert_eq!(
+            definitions["users"]["properties"]["contact"],
+            serde_json::json!({"$ref": "#/definitions/email"})
+        );
+        assert_eq!(
+            definitions["orders"]["properties"]["name"]["type"],
+            "integer"
+        );
+
+        let draft2019 = JSONSchemaExporter
+            .export_with_options(
+                &tables,
+                &JsonSchemaOptions {
+                    draft: JsonSchemaDraft::Draft201909,
+                    shared_definitions: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&draft2019.content).unwrap();
+        assert!(schema.get("definitions").is_none());
+        let contact = &schema["$defs"]["users"]["properties"]["contact"];
+        assert_eq!(contact["$ref"], "#/$defs/email");
+        assert_eq!(contact["description"], "Contact email");
+        assert!(schema["$defs"]["users"].get("$schema").is_none());
+    }
 }
 
 mod protobuf_export_tests {
@@ -269,6 +615,133 @@ mod protobuf_export_tests {
 
         assert!(proto.contains("repeated"));
     }
+
+    #[test]
+    fn test_well_known_types() {
+        let mut table = create_test_table(
+            "Payment",
+            vec![
+                create_column("created_at", "TIMESTAMP", false, false),
+                create_column("amount", "DECIMAL(38,9)", false, false),
+                create_column("note", "STRING", false, true),
+            ],
+        );
+        for (column, format) in table.columns.iter_mut().zip([
+            "google.protobuf.Timestamp",
+            "google.type.Money",
+            "google.protobuf.StringValue",
+        ]) {
+            column.logical_type_options = Some(LogicalTypeOptions {
+                format: Some(format.to_string()),
+                ..Default::default()
+            });
+        }
+
+        let result = ProtobufExporter.export(&[table]).unwrap();
+
+        assert!(
+            result
+                .content
+                .contains("import \"google/protobuf/timestamp.proto\";")
+        );
+        assert!(
+            result
+                .content
+                .contains("import \"google/type/money.proto\";")
+        );
+        assert!(
+            result
+                .content
+                .contains("import \"google/protobuf/wrappers.proto\";")
+        );
+        assert!(
+            result
+                .content
+                .contains("google.protobuf.Timestamp created_at = ")
+        );
+        assert!(result.content.contains("google.type.Money amount = "));
+        assert!(
+            result
+                .content
+                .contains("google.protobuf.StringValue note = ")
+        );
+    }
+
+    #[test]
+    fn test_nested_messages_enums_and_oneof() {
+        let mut status = create_column("status", "STRING", false, false);
+        status.enum_values = vec!["pending".to_string(), "shipped".to_string()];
+        let table = create_test_table(
+            "Order",
+            vec![
+                create_column("id", "BIGINT", true, false),
+                create_column("customer", "OBJECT", false, true),
+                create_column("customer.name", "STRING", false, false),
+                create_column("customer.address", "OBJECT", false, true),
+                create_column("customer.address.city", "STRING", false, true),
+                create_column("lines", "ARRAY<OBJECT>", false, false),
+                create_column("lines.[].sku", "STRING", false, false),
+                create_column("lines.[].quantity", "INT", false, false),
+                create_column("tags", "ARRAY<STRING>", false, true),
+                status,
+                create_column("payment", "UNION<STRING, BIGINT>", false, true),
+            ],
+        );
+
+        let proto = ProtobufExporter
+            .export_nested(&[table], "proto3")
+            .unwrap()
+            .content;
+
+        let expected = "message Order {
+  message Customer {
+    message Address {
+      optional string city = 1;
+    }
+    string name = 1;
+    optional Address address = 2;
+  }
+  message Lines {
+    string sku = 1;
+    int32 quantity = 2;
+  }
+  enum Status {
+    STATUS_UNSPECIFIED = 0;
+    STATUS_PENDING = 1;
+    STATUS_SHIPPED = 2;
+  }
+  int64 id = 1;
+  optional Customer customer = 2;
+  repeated Lines lines = 3;
+  repeated string tags = 4;
+  Status status = 5;
+  oneof payment {
+    string payment_string = 6;
+    int64 payment_int64 = 7;
+  }
+}
+";
+        assert!(proto.contains(expected), "{}", proto);
+
+        let proto2 = ProtobufExporter
+            .export_nested(
+                &[create_test_table(
+                    "Flag",
+                    vec![{
+                        let mut c = create_column("color", "STRING", false, false);
+                        c.enum_values = vec!["Dark Red".to_string()];
+                        c
+                    }],
+                )],
+                "proto2",
+            )
+            .unwrap()
+            .content;
+        assert!(proto2.contains("    COLOR_DARK_RED = 0;"));
+        assert!(proto2.contains("  required Color color = 1;"));
+
+        assert!(ProtobufExporter.export_nested(&[], "proto4").is_err());
+    }
 }
 
 mod avro_export_tests {
@@ -351,6 +824,107 @@ mod avro_export_tests {
         // The actual type mapping depends on the implementation
         assert!(schema_str.contains("\"name\":\"items\"") || schema_str.contains("items"));
     }
+
+    #[test]
+    fn test_export_logical_types() {
+        let mut table = create_test_table(
+            "Payment",
+            vec![
+                create_column("amount", "DECIMAL(10,2)", false, false),
+                create_column("booked_on", "DATE", false, false),
+                create_column("created_at", "TIMESTAMP", false, true),
+                create_column("updated_at", "TIMESTAMP", false, false),
+                create_column("id", "UUID", true, false),
+            ],
+        );
+        table.columns[3].logical_type_options = Some(LogicalTypeOptions {
+            format: Some("timestamp-millis".to_string()),
+            ..Default::default()
+        });
+
+        let schema = AvroExporter::export_table(&table);
+        let fields = schema["fields"].as_array().unwrap();
+
+        assert_eq!(fields[0]["type"]["logicalType"], "decimal");
+        assert_eq!(fields[0]["type"]["precision"], 10);
+        assert_eq!(fields[0]["type"]["scale"], 2);
+        assert_eq!(fields[1]["type"]["logicalType"], "date");
+        assert_eq!(fields[2]["type"][1]["logicalType"], "timestamp-micros");
+        assert_eq!(fields[3]["type"]["logicalType"], "timestamp-millis");
+        assert_eq!(fields[4]["type"]["logicalType"], "uuid");
+    }
+
+    #[test]
+    fn test_export_decimal_from_logical_type_options() {
+        let mut table = create_test_table(
+            "Payment",
+            vec![create_column("amount", "DECIMAL", false, false)],
+        );
+        table.columns[0].logical_type_options = Some(LogicalTypeOptions {
+            precision: Some(18),
+            scale: Some(4),
+            ..Default::default()
+        });
+
+        let schema = AvroExporter::export_table(&table);
+        assert_eq!(schema["fields"][0]["type"]["precision"], 18);
+        assert_eq!(schema["fields"][0]["type"]["scale"], 4);
+    }
+
+    #[test]
+    fn test_export_defaults_and_docs() {
+        let mut table = create_test_table(
+            "Payment",
+            vec![
+                create_column("status", "STRING", false, false),
+                create_column("retries", "INT", false, true),
+                create_column("note", "STRING", false, true),
+                create_column("booked_on", "DATE", false, false),
+                create_column("created_at", "TIMESTAMP", false, false),
+                create_column("fee", "DECIMAL(10,2)", false, false),
+                create_column("updated_at", "TIMESTAMP", false, true),
+            ],
+        );
+        table.odcl_metadata.insert(
+            "description".to_string(),
+            serde_json::json!("Card payments"),
+        );
+        let defaults = [
+            serde_json::json!("pending"),
+            serde_json::json!(3),
+            serde_json::Value::Null,
+            serde_json::json!("1970-01-11"),
+            serde_json::json!("1970-01-01T00:00:01Z"),
+            serde_json::json!("1.28"),
+            serde_json::json!("`now()`"),
+        ];
+        for (column, default) in table.columns.iter_mut().zip(defaults) {
+            column.default_value = Some(default);
+        }
+        table.columns[4].logical_type_options = Some(LogicalTypeOptions {
+            format: Some("timestamp-millis".to_string()),
+            ..Default::default()
+        });
+
+        let result = AvroExporter.export(&[table]).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&result.content).unwrap();
+        let fields = schema["fields"].as_array().unwrap();
+
+        assert_eq!(schema["doc"], "Card payments");
+        assert_eq!(fields[0]["default"], "pending");
+        // The default's branch comes first in a nullable union
+        assert_eq!(fields[1]["type"], serde_json::json!(["int", "null"]));
+        assert_eq!(fields[1]["default"], 3);
+        assert_eq!(fields[2]["type"][0], "null");
+        assert!(fields[2]["default"].is_null());
+        assert_eq!(fields[3]["default"], 10);
+        assert_eq!(fields[4]["default"], 1000);
+        // 128 needs a leading zero byte to stay positive in two's complement
+        assert_eq!(fields[5]["default"], "\u{0}\u{80}");
+        // SQL expressions have no AVRO default; the nullable field defaults to null
+        assert_eq!(fields[6]["type"][0], "null");
+        assert!(fields[6]["default"].is_null());
+    }
 }
 
 // DataFlow export tests removed - DataFlow format has been migrated to Domain schema
@@ -1,9 +1,12 @@
 //! Import module tests
 
+use data_modelling_core::export::{avro::AvroExporter, protobuf::ProtobufExporter};
 use data_modelling_core::import::{
-    avro::AvroImporter, json_schema::JSONSchemaImporter, odcs::ODCSImporter,
-    protobuf::ProtobufImporter, sql::SQLImporter,
+    ImportError, avro::AvroImporter, dbml::DbmlImporter, json_schema::JSONSchemaImporter,
+    odcs::ODCSImporter, protobuf::ProtobufImporter, ref_resolver::StorageRefResolver,
+    sql::SQLImporter,
 };
+use data_modelling_core::models::{SOURCE_TYPE_PROPERTY, SpatialType, Table, VectorType};
 
 mod sql_import_tests {
     use super::*;
@@ -99,6 +102,54 @@ mod sql_import_tests {
         // Should return errors rather than panic
         assert!(!result.errors.is_empty() || result.tables.is_empty());
     }
+
+    #[test]
+    fn test_parse_unmapped_types() {
+        let importer = SQLImporter::new("postgres");
+        let sql = "CREATE TABLE places (id INT, name TEXT, attributes HSTORE);";
+        let result = importer.parse(sql).unwrap();
+        let columns = &result.tables[0].columns;
+
+        assert_eq!(columns[2].data_type, "HSTORE");
+        assert_eq!(columns[2].physical_type.as_deref(), Some("HSTORE"));
+        assert!(
+            columns[2]
+                .custom_properties
+                .contains_key(SOURCE_TYPE_PROPERTY)
+        );
+        assert!(columns[1].physical_type.is_none());
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ImportError::UnmappedType(message)] if message.contains("places.attributes")
+        ));
+    }
+
+    #[test]
+    fn test_parse_spatial_types() {
+        let importer = SQLImporter::new("postgres");
+        let sql = "CREATE TABLE places (id INT, location GEOMETRY(Point, 4326));";
+        let result = importer.parse(sql).unwrap();
+        let location = &result.tables[0].columns[1];
+
+        assert_eq!(location.data_type, "GEOMETRY(Point, 4326)");
+        let spatial = SpatialType::parse(&location.data_type).unwrap();
+        assert_eq!(spatial.shape.as_deref(), Some("Point"));
+        assert_eq!(spatial.srid, Some(4326));
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_vector_types() {
+        let importer = SQLImporter::new("postgres");
+        let sql = "CREATE TABLE items (id INT, embedding VECTOR(1536), thumbnail BYTEA);";
+        let result = importer.parse(sql).unwrap();
+        let columns = &result.tables[0].columns;
+
+        let vector = VectorType::parse(&columns[1].data_type).unwrap();
+        assert_eq!(vector.dimension, Some(1536));
+        assert_eq!(columns[2].data_type.to_uppercase(), "BYTEA");
+        assert!(result.errors.is_empty());
+    }
 }
 
 mod json_schema_import_tests {
@@ -201,11 +252,253 @@ mod json_schema_import_tests {
         let items_col = table.columns.iter().find(|c| c.name == "items").unwrap();
         assert!(items_col.data_type.contains("ARRAY"));
     }
+
+    #[test]
+    fn test_parse_all_of() {
+        let importer = JSONSchemaImporter::new();
+        let schema = r##"
+        {
+            "title": "Employee",
+            "definitions": {
+                "Person": {
+                    "type": "object",
+                    "properties": { "name": { "type": "string" } },
+                    "required": ["name"]
+                }
+            },
+            "allOf": [
+                { "$ref": "#/definitions/Person" },
+                {
+                    "type": "object",
+                    "properties": { "employee_id": { "type": "integer" } },
+                    "required": ["employee_id"]
+                }
+            ]
+        }
+        "##;
+        let result = importer.import(schema).unwrap();
+
+        let table = result
+            .tables
+            .iter()
+            .find(|t| t.name.as_deref() == Some("Employee"))
+            .unwrap();
+        let name = table.columns.iter().find(|c| c.name == "name").unwrap();
+        assert!(!name.nullable);
+        let id = table
+            .columns
+            .iter()
+            .find(|c| c.name == "employee_id")
+            .unwrap();
+        assert!(!id.nullable);
+    }
+
+    #[test]
+    fn test_parse_one_of_with_discriminator() {
+        let importer = JSONSchemaImporter::new();
+        let schema = r#"
+        {
+            "title": "Order",
+            "type": "object",
+            "properties": {
+                "payment": {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "const": "card" },
+                                "card_number": { "type": "string" }
+                            }
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "const": "iban" },
+                                "iban": { "type": "string" }
+                            }
+                        }
+                    ]
+                }
+            }
+        }
+        "#;
+        let result = importer.import(schema).unwrap();
+        let columns = &result.tables[0].columns;
+
+        let kind = columns.iter().find(|c| c.name == "payment.kind").unwrap();
+        assert_eq!(
+            kind.custom_properties.get("discriminator"),
+            Some(&serde_json::json!(true))
+        );
+        assert!(columns.iter().any(|c| c.name == "payment.card_number"));
+        assert!(columns.iter().any(|c| c.name == "payment.iban"));
+    }
+
+    #[test]
+    fn test_parse_primitive_union() {
+        let importer = JSONSchemaImporter::new();
+        let schema = r#"
+        {
+            "title": "Setting",
+            "type": "object",
+            "properties": {
+                "value": { "anyOf": [{ "type": "string" }, { "type": "integer" }] },
+                "label": { "type": ["string", "null"] }
+            }
+        }
+        "#;
+        let result = importer.import(schema).unwrap();
+        let columns = &result.tables[0].columns;
+
+        let value = columns.iter().find(|c| c.name == "value").unwrap();
+        assert!(value.data_type.starts_with("UNION<"));
+        let label = columns.iter().find(|c| c.name == "label").unwrap();
+        assert!(label.nullable);
+        assert!(!label.data_type.starts_with("UNION<"));
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let importer = JSONSchemaImporter::new();
+        let schema = r#"
+        {
+            "title": "Order",
+            "type": "object",
+            "properties": {
+                "status": { "type": "string", "examples": ["open", "shipped"] },
+                "total": { "type": "number", "example": 42.5 },
+                "note": { "type": ["string", "null"], "examples": ["gift wrap"] }
+            }
+        }
+        "#;
+        let result = importer.import(schema).unwrap();
+        let columns = &result.tables[0].columns;
+
+        let status = columns.iter().find(|c| c.name == "status").unwrap();
+        assert_eq!(
+            status.examples,
+            vec![serde_json::json!("open"), serde_json::json!("shipped")]
+        );
+        let total = columns.iter().find(|c| c.name == "total").unwrap();
+        assert_eq!(total.examples, vec![serde_json::json!(42.5)]);
+        let note = columns.iter().find(|c| c.name == "note").unwrap();
+        assert_eq!(note.examples, vec![serde_json::json!("gift wrap")]);
+    }
+
+    #[test]
+    fn test_parse_external_file_ref() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("address.json"),
+            r#"{
+                "definitions": {
+                    "Address": {
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let schema = r#"
+        {
+            "title": "Customer",
+            "type": "object",
+            "properties": {
+                "address": { "$ref": "address.json#/definitions/Address" }
+            }
+        }
+        "#;
+
+        let result = JSONSchemaImporter::new()
+            .with_base_dir(dir.path())
+            .import(schema)
+            .unwrap();
+        assert!(
+            result.tables[0]
+                .columns
+                .iter()
+                .any(|c| c.name == "address.city")
+        );
+
+        // References outside the base directory are rejected
+        let escaping = schema.replace("address.json", "../address.json");
+        let result = JSONSchemaImporter::new()
+            .with_base_dir(dir.path())
+            .import(&escaping);
+        assert!(result.is_err() || !result.unwrap().errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_ref_with_storage_resolver() {
+        let mut resolver = StorageRefResolver::new();
+        resolver.insert(
+            "schemas/common.yaml",
+            "definitions:\n  Address:\n    type: object\n    properties:\n      city:\n        type: string\n",
+        );
+        let schema = r#"
+        {
+            "title": "Customer",
+            "type": "object",
+            "properties": {
+                "address": { "$ref": "schemas/common.yaml#/definitions/Address" }
+            }
+        }
+        "#;
+
+        let result = JSONSchemaImporter::new()
+            .with_resolver(resolver)
+            .import(schema)
+            .unwrap();
+        assert!(
+            result.tables[0]
+                .columns
+                .iter()
+                .any(|c| c.name == "address.city")
+        );
+    }
 }
 
 mod avro_import_tests {
     use super::*;
 
+    #[test]
+    fn test_parse_external_named_type() {
+        let mut resolver = StorageRefResolver::new();
+        resolver.insert(
+            "com/acme/Address.avsc",
+            r#"{
+                "type": "record",
+                "name": "Address",
+                "namespace": "com.acme",
+                "fields": [{ "name": "city", "type": "string" }]
+            }"#,
+        );
+        let schema = r#"
+        {
+            "type": "record",
+            "name": "Customer",
+            "namespace": "com.acme",
+            "fields": [
+                { "name": "address", "type": "Address" },
+                { "name": "previous", "type": ["null", "com.acme.Address"] }
+            ]
+        }
+        "#;
+
+        let result = AvroImporter::new()
+            .with_resolver(resolver)
+            .import(schema)
+            .unwrap();
+        let columns = &result.tables[0].columns;
+        assert!(columns.iter().any(|c| c.name == "address.city"));
+        assert!(columns.iter().any(|c| c.name == "previous.city"));
+
+        // Without a resolver the reference falls back to a string column
+        let result = AvroImporter::new().import(schema).unwrap();
+        assert_eq!(result.tables[0].columns[0].data_type, "STRING");
+    }
+
     #[test]
     fn test_parse_simple_record() {
         let importer = AvroImporter::new();
@@ -282,6 +575,89 @@ mod avro_import_tests {
 
         assert_eq!(result.tables[0].name.as_deref(), Some("User"));
     }
+
+    #[test]
+    fn test_parse_logical_types() {
+        let importer = AvroImporter::new();
+        let schema = r#"
+        {
+            "type": "record",
+            "name": "Payment",
+            "fields": [
+                { "name": "amount", "type": { "type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2 } },
+                { "name": "booked_on", "type": { "type": "int", "logicalType": "date" } },
+                { "name": "created_at", "type": ["null", { "type": "long", "logicalType": "timestamp-micros" }] },
+                { "name": "id", "type": { "type": "string", "logicalType": "uuid" } },
+                { "name": "custom", "type": { "type": "long", "logicalType": "unknown-type" } }
+            ]
+        }
+        "#;
+        let result = importer.import(schema).unwrap();
+        let columns = &result.tables[0].columns;
+
+        assert_eq!(columns[0].data_type, "DECIMAL(10,2)");
+        let options = columns[0].logical_type_options.as_ref().unwrap();
+        assert_eq!(options.precision, Some(10));
+        assert_eq!(options.scale, Some(2));
+
+        assert_eq!(columns[1].data_type, "DATE");
+
+        assert_eq!(columns[2].data_type, "TIMESTAMP");
+        assert!(columns[2].nullable);
+        assert_eq!(
+            columns[2]
+                .logical_type_options
+                .as_ref()
+                .unwrap()
+                .format
+                .as_deref(),
+            Some("timestamp-micros")
+        );
+
+        assert_eq!(columns[3].data_type, "UUID");
+        assert_eq!(columns[4].data_type, "BIGINT");
+    }
+
+    #[test]
+    fn test_unmapped_type_round_trip() {
+        let importer = AvroImporter::new();
+        let schema = r#"
+        {
+            "type": "record",
+            "name": "Order",
+            "fields": [
+                { "name": "id", "type": "long" },
+                { "name": "total", "type": "com.acme.Money" },
+                { "name": "refunds", "type": { "type": "array", "items": "com.acme.Money" } }
+            ]
+        }
+        "#;
+        let result = importer.import(schema).unwrap();
+        let columns = &result.tables[0].columns;
+
+        assert_eq!(columns[1].data_type, "STRING");
+        assert_eq!(columns[1].physical_type.as_deref(), Some("com.acme.Money"));
+        assert!(
+            columns[1]
+                .custom_properties
+                .contains_key(SOURCE_TYPE_PROPERTY)
+        );
+        assert!(
+            !columns[0]
+                .custom_properties
+                .contains_key(SOURCE_TYPE_PROPERTY)
+        );
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ImportError::UnmappedType(message)]
+                if message.contains("com.acme.Money") && message.contains("Order.refunds")
+        ));
+
+        let exported = AvroExporter::export_table(&Table::from_table_data(&result.tables[0]));
+        assert_eq!(exported["fields"][0]["type"], "long");
+        assert_eq!(exported["fields"][1]["type"], "com.acme.Money");
+        assert_eq!(exported["fields"][2]["type"]["items"], "com.acme.Money");
+    }
 }
 
 mod protobuf_import_tests {
@@ -357,11 +733,118 @@ mod protobuf_import_tests {
         // Repeated fields should be marked as nullable
         assert!(items_col.nullable);
     }
+
+    #[test]
+    fn test_parse_well_known_types() {
+        let importer = ProtobufImporter::new();
+        let proto = r#"
+            syntax = "proto3";
+
+            import "google/protobuf/timestamp.proto";
+            import "google/protobuf/wrappers.proto";
+            import "google/type/money.proto";
+
+            message Payment {
+                google.protobuf.Timestamp created_at = 1;
+                google.protobuf.Duration processing_time = 2;
+                google.protobuf.StringValue note = 3;
+                google.protobuf.Struct attributes = 4;
+                google.type.Money amount = 5;
+                google.type.Date booked_on = 6;
+            }
+        "#;
+        let result = importer.import(proto).unwrap();
+        let columns = &result.tables[0].columns;
+        let format = |i: usize| {
+            columns[i]
+                .logical_type_options
+                .as_ref()
+                .and_then(|o| o.format.as_deref())
+        };
+
+        assert_eq!(columns[0].data_type, "TIMESTAMP");
+        assert_eq!(format(0), Some("google.protobuf.Timestamp"));
+        assert_eq!(columns[1].data_type, "INTERVAL");
+        assert_eq!(columns[2].data_type, "STRING");
+        assert!(columns[2].nullable);
+        assert_eq!(columns[3].data_type, "JSON");
+        assert_eq!(columns[4].data_type, "DECIMAL(38,9)");
+        assert_eq!(format(4), Some("google.type.Money"));
+        assert_eq!(columns[5].data_type, "DATE");
+    }
+
+    #[test]
+    fn test_unmapped_type_round_trip() {
+        let importer = ProtobufImporter::new();
+        let proto = r#"
+            syntax = "proto3";
+
+            message Order {
+                int64 id = 1;
+                OrderStatus status = 2;
+                repeated acme.Discount discounts = 3;
+            }
+        "#;
+        let result = importer.import(proto).unwrap();
+        let columns = &result.tables[0].columns;
+
+        assert_eq!(columns[1].data_type, "STRING");
+        assert_eq!(columns[1].physical_type.as_deref(), Some("OrderStatus"));
+        assert_eq!(columns[2].data_type, "ARRAY<STRING>");
+        assert_eq!(result.errors.len(), 2);
+
+        let mut field_number = 0;
+        let exported = ProtobufExporter::export_table(
+            &Table::from_table_data(&result.tables[0]),
+            &mut field_number,
+        );
+        assert!(exported.contains("int64 id = 1;"));
+        assert!(exported.contains("OrderStatus status = 2;"));
+        assert!(exported.contains("repeated acme.Discount discounts = 3;"));
+    }
 }
 
 // DataFlow import tests removed - DataFlow format has been migrated to Domain schema
 // Use migrate_dataflow_to_domain() for DataFlow → Domain migration
 
+mod dbml_import_tests {
+    use super::*;
+    use data_modelling_core::export::sql::SQLExporter;
+
+    #[test]
+    fn test_dbml_to_sql() {
+        let dbml = r#"
+Enum order_status {
+  pending
+  shipped
+}
+
+Table users {
+  id integer [pk, increment]
+  email varchar(255) [not null, unique]
+}
+
+Table orders {
+  id integer [pk]
+  user_id integer [not null, ref: > users.id]
+  status order_status [default: 'pending']
+}
+"#;
+        let result = DbmlImporter::new().import(dbml).unwrap();
+        assert!(result.errors.is_empty());
+        let tables: Vec<Table> = result.tables.iter().map(Table::from_table_data).collect();
+
+        let status = &tables[1].columns[2];
+        assert_eq!(status.enum_values, vec!["pending", "shipped"]);
+        assert_eq!(status.relationships.len(), 0);
+        assert_eq!(tables[1].columns[1].relationships[0].to, "users.id");
+
+        let sql = SQLExporter::export_table(&tables[0], Some("postgres"));
+        assert!(sql.contains("CREATE TABLE \"users\""));
+        assert!(sql.contains("\"email\" varchar(255) NOT NULL"));
+    }
+}
+
 mod odcl_field_preservation_tests {
     use super::*;
     use std::fs;
@@ -957,3 +1440,83 @@ CREATE TABLE test (
         assert!(meta_col.description.as_deref().unwrap().contains("aren't"));
     }
 }
+
+mod openapi_ref_tests {
+    use super::*;
+    use data_modelling_core::convert::openapi_to_odcs::OpenAPIToODCSConverter;
+
+    #[test]
+    fn test_convert_component_with_external_ref() {
+        let mut resolver = StorageRefResolver::new();
+        resolver.insert(
+            "common.yaml",
+            "components:\n  schemas:\n    Currency:\n      type: string\n      description: ISO 4217 code\n",
+        );
+        let spec = r#"
+openapi: 3.0.0
+info:
+  title: Orders
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        id:
+          type: string
+        currency:
+          $ref: 'common.yaml#/components/schemas/Currency'
+"#;
+
+        let table = OpenAPIToODCSConverter::new()
+            .with_resolver(resolver)
+            .convert_component(spec, "Order", None)
+            .unwrap();
+        let currency = table.columns.iter().find(|c| c.name == "currency").unwrap();
+        assert_eq!(currency.description, "ISO 4217 code");
+    }
+
+    #[test]
+    fn test_convert_component_keeps_examples() {
+        let spec = r#"
+openapi: 3.1.0
+info:
+  title: Orders
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        id:
+          type: string
+          example: ord-1
+        total:
+          type: number
+          examples: [42.5, 0]
+        note:
+          type: string
+"#;
+
+        let table = OpenAPIToODCSConverter::new()
+            .convert_component(spec, "Order", None)
+            .unwrap();
+        let examples = |name: &str| {
+            table
+                .columns
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .examples
+                .clone()
+        };
+        assert_eq!(examples("id"), vec![serde_json::json!("ord-1")]);
+        assert_eq!(
+            examples("total"),
+            vec![serde_json::json!(42.5), serde_json::json!(0)]
+        );
+        assert!(examples("note").is_empty());
+    }
+}
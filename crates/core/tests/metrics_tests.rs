@@ -0,0 +1,134 @@
+//! Usage metrics hook tests
+//!
+//! The recorder is process-global, so everything is checked from a single test.
+
+use data_modelling_core::export::{ExportOptions, ExporterRegistry};
+use data_modelling_core::import::registry::ImporterRegistry;
+use data_modelling_core::metrics::{self, MetricsRecorder};
+use data_modelling_core::models::odcs::{ODCSContract, Property, SchemaObject};
+use data_modelling_core::validation::validate_backward_compatible;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+type Labels = Vec<(String, String)>;
+
+#[derive(Default)]
+struct TestRecorder {
+    counters: Mutex<Vec<(String, Labels, u64)>>,
+    durations: Mutex<Vec<(String, Labels)>>,
+}
+
+fn owned(labels: &[(&str, &str)]) -> Labels {
+    labels
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+impl MetricsRecorder for TestRecorder {
+    fn increment_counter(&self, name: &str, labels: &[(&str, &str)], value: u64) {
+        self.counters
+            .lock()
+            .unwrap()
+            .push((name.to_string(), owned(labels), value));
+    }
+
+    fn record_duration(&self, name: &str, labels: &[(&str, &str)], _duration: Duration) {
+        self.durations
+            .lock()
+            .unwrap()
+            .push((name.to_string(), owned(labels)));
+    }
+}
+
+impl TestRecorder {
+    fn count(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        let labels = owned(labels);
+        self.counters
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(n, l, _)| n == name && labels.iter().all(|label| l.contains(label)))
+            .map(|(_, _, value)| value)
+            .sum()
+    }
+}
+
+fn contract(properties: Vec<Property>) -> ODCSContract {
+    ODCSContract::new("orders", "1.0.0")
+        .with_schema(SchemaObject::new("orders").with_properties(properties))
+}
+
+#[test]
+fn test_metrics_recorder() {
+    let recorder = Arc::new(TestRecorder::default());
+    metrics::set_recorder(recorder.clone());
+
+    let importers = ImporterRegistry::default();
+    let users = importers
+        .import("sql", "CREATE TABLE users (id INT PRIMARY KEY);")
+        .unwrap();
+    assert_eq!(users.tables.len(), 1);
+    let _ = importers.import("avro", "not avro");
+    assert_eq!(
+        recorder.count(
+            metrics::IMPORTS,
+            &[("format", "sql"), ("outcome", "success")]
+        ),
+        1
+    );
+    assert_eq!(
+        recorder.count(
+            metrics::IMPORTS,
+            &[("format", "avro"), ("outcome", "error")]
+        ),
+        1
+    );
+
+    let orders = contract(vec![
+        Property::new("id", "integer").with_required(true),
+        Property::new("note", "string"),
+    ]);
+    let exported = ExporterRegistry::default()
+        .export_contract("json-schema", &orders, &ExportOptions::new())
+        .unwrap();
+    assert!(!exported.content.is_empty());
+    assert_eq!(
+        recorder.count(
+            metrics::EXPORTS,
+            &[("format", "json-schema"), ("outcome", "success")]
+        ),
+        1
+    );
+    assert!(
+        recorder
+            .durations
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(name, labels)| {
+                name == metrics::EXPORT_DURATION && labels == &owned(&[("format", "json-schema")])
+            })
+    );
+
+    let narrowed = contract(vec![Property::new("id", "integer").with_required(true)]);
+    assert!(validate_backward_compatible(&orders, &narrowed).is_err());
+    assert_eq!(
+        recorder.count(
+            metrics::VALIDATION_FAILURES,
+            &[("rule", "compatibility.removedField")]
+        ),
+        1
+    );
+
+    metrics::clear_recorder();
+    let users = importers
+        .import("sql", "CREATE TABLE users (id INT PRIMARY KEY);")
+        .unwrap();
+    assert_eq!(users.tables.len(), 1);
+    assert_eq!(
+        recorder.count(metrics::IMPORTS, &[("format", "sql")]),
+        1,
+        "no metrics are recorded after the recorder is cleared"
+    );
+}
@@ -258,6 +258,7 @@ columns: []
 #[cfg(feature = "native-fs")]
 mod model_saver_tests {
     use data_modelling_core::model::saver::{ModelSaver, RelationshipData, TableData};
+    use data_modelling_core::storage::dry_run::FileChangeKind;
     use data_modelling_core::storage::{StorageBackend, filesystem::FileSystemStorageBackend};
     use tempfile::TempDir;
     use tokio::runtime::Runtime;
@@ -348,6 +349,65 @@ mod model_saver_tests {
         });
     }
 
+    #[test]
+    fn test_save_table_dry_run() {
+        let rt = runtime();
+        rt.block_on(async {
+            let temp = TempDir::new().unwrap();
+            let backend = FileSystemStorageBackend::new(temp.path());
+            backend
+                .write_file("workspace/tables/users.yaml", b"name: users\n")
+                .await
+                .unwrap();
+            let saver = ModelSaver::dry_run(FileSystemStorageBackend::new(temp.path()));
+
+            for name in ["users", "orders"] {
+                let mut yaml_map = serde_yaml::Mapping::new();
+                yaml_map.insert(
+                    serde_yaml::Value::String("name".to_string()),
+                    serde_yaml::Value::String(name.to_string()),
+                );
+                yaml_map.insert(
+                    serde_yaml::Value::String("owner".to_string()),
+                    serde_yaml::Value::String("sales".to_string()),
+                );
+                let table = TableData {
+                    id: Uuid::new_v4(),
+                    name: name.to_string(),
+                    yaml_file_path: None,
+                    yaml_value: serde_yaml::Value::Mapping(yaml_map),
+                };
+                saver.save_table("workspace", &table).await.unwrap();
+            }
+
+            let changeset = saver.changeset();
+            assert_eq!(changeset.change_count(), 2);
+            let created: Vec<_> = changeset
+                .of_kind(FileChangeKind::Created)
+                .map(|f| f.path.as_str())
+                .collect();
+            assert_eq!(created, vec!["workspace/tables/orders.yaml"]);
+            let modified = changeset.of_kind(FileChangeKind::Modified).next().unwrap();
+            assert_eq!(modified.path, "workspace/tables/users.yaml");
+            assert!(modified.diff.as_deref().unwrap().contains("+owner: sales"));
+
+            // Nothing was written
+            assert!(
+                !backend
+                    .file_exists("workspace/tables/orders.yaml")
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/users.yaml")
+                    .await
+                    .unwrap(),
+                b"name: users\n"
+            );
+        });
+    }
+
     #[test]
     fn test_save_relationships() {
         let rt = runtime();
@@ -439,6 +499,355 @@ mod model_saver_tests {
     }
 }
 
+mod model_saver_transaction_tests {
+    use async_trait::async_trait;
+    use data_modelling_core::model::saver::{ModelSaver, RelationshipData, TableData};
+    use data_modelling_core::storage::memory::MemoryStorageBackend;
+    use data_modelling_core::storage::transaction::{
+        JOURNAL_PATH, Recovery, TEMP_SUFFIX, TransactionError, TransactionStorage,
+    };
+    use data_modelling_core::storage::{StorageBackend, StorageError};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tokio::runtime::Runtime;
+    use uuid::Uuid;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    /// Memory backend failing writes to one path, optionally deletes of another,
+    /// and writes after the first to a third
+    struct FailingBackend {
+        inner: MemoryStorageBackend,
+        fail_path: &'static str,
+        fail_delete: Option<&'static str>,
+        fail_rewrite: Option<&'static str>,
+        rewrites: AtomicU32,
+    }
+
+    #[async_trait(?Send)]
+    impl StorageBackend for FailingBackend {
+        async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+            self.inner.read_file(path).await
+        }
+
+        async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+            if path == self.fail_path {
+                return Err(StorageError::IoError(format!("disk full: {}", path)));
+            }
+            if self.fail_rewrite == Some(path) && self.rewrites.fetch_add(1, Ordering::SeqCst) > 0 {
+                return Err(StorageError::IoError(format!("disk full: {}", path)));
+            }
+            self.inner.write_file(path, content).await
+        }
+
+        async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+            self.inner.list_files(dir).await
+        }
+
+        async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+            self.inner.file_exists(path).await
+        }
+
+        async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+            if self.fail_delete == Some(path) {
+                return Err(StorageError::IoError(format!("read-only: {}", path)));
+            }
+            self.inner.delete_file(path).await
+        }
+
+        async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+            self.inner.create_dir(path).await
+        }
+
+        async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+            self.inner.dir_exists(path).await
+        }
+    }
+
+    fn table(name: &str) -> TableData {
+        let mut yaml_map = serde_yaml::Mapping::new();
+        yaml_map.insert(
+            serde_yaml::Value::String("name".to_string()),
+            serde_yaml::Value::String(name.to_string()),
+        );
+        TableData {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            yaml_file_path: None,
+            yaml_value: serde_yaml::Value::Mapping(yaml_map),
+        }
+    }
+
+    fn relationship() -> RelationshipData {
+        let mut rel_map = serde_yaml::Mapping::new();
+        rel_map.insert(
+            serde_yaml::Value::String("source".to_string()),
+            serde_yaml::Value::String("orders".to_string()),
+        );
+        RelationshipData {
+            id: Uuid::new_v4(),
+            source_table_id: Uuid::new_v4(),
+            target_table_id: Uuid::new_v4(),
+            yaml_value: serde_yaml::Value::Mapping(rel_map),
+        }
+    }
+
+    async fn save_all<B: StorageBackend>(saver: &ModelSaver<B>) {
+        saver
+            .save_table("workspace", &table("users"))
+            .await
+            .unwrap();
+        saver
+            .save_table("workspace", &table("orders"))
+            .await
+            .unwrap();
+        saver
+            .save_relationships("workspace", &[relationship()])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_transaction_commit() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = MemoryStorageBackend::new();
+            let saver = ModelSaver::transaction(&backend);
+            save_all(&saver).await;
+
+            assert_eq!(saver.changeset().change_count(), 3);
+            assert!(
+                backend.paths().is_empty(),
+                "nothing is written before commit"
+            );
+
+            let changeset = saver.commit().await.unwrap();
+            assert_eq!(changeset.change_count(), 3);
+            assert_eq!(
+                backend.paths(),
+                vec![
+                    "workspace/relationships.yaml",
+                    "workspace/tables/orders.yaml",
+                    "workspace/tables/users.yaml",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_transaction_rolls_back_failed_commit() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = FailingBackend {
+                inner: MemoryStorageBackend::new()
+                    .with_file("workspace/tables/users.yaml", "name: users\nowner: sales\n"),
+                fail_path: "workspace/tables/users.yaml",
+                fail_delete: None,
+                fail_rewrite: None,
+                rewrites: AtomicU32::new(0),
+            };
+            let saver = ModelSaver::transaction(&backend);
+            save_all(&saver).await;
+
+            // relationships.yaml and orders.yaml are applied before users.yaml fails
+            let result = saver.commit().await;
+            assert!(matches!(result, Err(TransactionError::RolledBack(_))));
+            assert_eq!(backend.inner.paths(), vec!["workspace/tables/users.yaml"]);
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/users.yaml")
+                    .await
+                    .unwrap(),
+                b"name: users\nowner: sales\n"
+            );
+        });
+    }
+
+    #[test]
+    fn test_transaction_failed_rollback_is_recovered() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = FailingBackend {
+                inner: MemoryStorageBackend::new()
+                    .with_file("workspace/tables/users.yaml", "name: users\nowner: sales\n"),
+                fail_path: "workspace/tables/users.yaml",
+                fail_delete: Some("workspace/tables/orders.yaml"),
+                fail_rewrite: None,
+                rewrites: AtomicU32::new(0),
+            };
+            let saver = ModelSaver::transaction(&backend);
+            save_all(&saver).await;
+
+            // users.yaml fails to promote, and the new orders.yaml cannot be removed
+            let result = saver.commit().await;
+            assert!(matches!(
+                result,
+                Err(TransactionError::RollbackFailed { ref paths, .. })
+                    if paths == &["workspace/tables/orders.yaml"]
+            ));
+            assert!(backend.inner.file_exists(JOURNAL_PATH).await.unwrap());
+
+            // Once the backend is usable again, recovery completes the commit
+            let recovery = TransactionStorage::recover(&backend.inner).await.unwrap();
+            assert!(matches!(recovery, Recovery::RolledForward(_)));
+            assert_eq!(
+                backend.inner.paths(),
+                vec![
+                    "workspace/relationships.yaml",
+                    "workspace/tables/orders.yaml",
+                    "workspace/tables/users.yaml",
+                ]
+            );
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/users.yaml")
+                    .await
+                    .unwrap(),
+                b"name: users\n"
+            );
+        });
+    }
+
+    #[test]
+    fn test_transaction_reports_files_not_staged_for_recovery() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = FailingBackend {
+                inner: MemoryStorageBackend::new()
+                    .with_file("workspace/tables/users.yaml", "name: users\nowner: sales\n"),
+                fail_path: "workspace/tables/users.yaml",
+                fail_delete: Some("workspace/tables/orders.yaml"),
+                fail_rewrite: Some("workspace/relationships.yaml.odm-tx"),
+                rewrites: AtomicU32::new(0),
+            };
+            let saver = ModelSaver::transaction(&backend);
+            save_all(&saver).await;
+
+            // relationships.yaml is restored, but cannot be staged again
+            let result = saver.commit().await;
+            assert!(matches!(
+                result,
+                Err(TransactionError::RollbackFailed { ref paths, .. })
+                    if paths == &[
+                        "workspace/tables/orders.yaml",
+                        "workspace/relationships.yaml",
+                    ]
+            ));
+        });
+    }
+
+    #[test]
+    fn test_transaction_recovers_interrupted_commit() {
+        let rt = runtime();
+        rt.block_on(async {
+            let journal =
+                br#"{"writes":["workspace/tables/users.yaml"],"deletes":["workspace/old.yaml"]}"#;
+            let pending = format!("{}{}", JOURNAL_PATH, TEMP_SUFFIX);
+            let temp = format!("workspace/tables/users.yaml{}", TEMP_SUFFIX);
+
+            // Crashed before the commit point: the staged files are discarded
+            let backend = MemoryStorageBackend::new()
+                .with_file("workspace/tables/users.yaml", "name: users\n")
+                .with_file("workspace/old.yaml", "name: old\n")
+                .with_file(&pending, journal)
+                .with_file(&temp, "name: users\nowner: sales\n");
+            let recovery = TransactionStorage::recover(&backend).await.unwrap();
+            assert!(matches!(recovery, Recovery::RolledBack(_)));
+            assert_eq!(
+                backend.paths(),
+                vec!["workspace/old.yaml", "workspace/tables/users.yaml"]
+            );
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/users.yaml")
+                    .await
+                    .unwrap(),
+                b"name: users\n"
+            );
+
+            // Crashed after the commit point: the commit is completed
+            backend.write_file(JOURNAL_PATH, journal).await.unwrap();
+            backend
+                .write_file(&temp, b"name: users\nowner: sales\n")
+                .await
+                .unwrap();
+            let saver = ModelSaver::new(TransactionStorage::open(&backend).await.unwrap());
+            assert_eq!(backend.paths(), vec!["workspace/tables/users.yaml"]);
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/users.yaml")
+                    .await
+                    .unwrap(),
+                b"name: users\nowner: sales\n"
+            );
+            assert_eq!(
+                TransactionStorage::recover(&backend).await.unwrap(),
+                Recovery::Clean
+            );
+
+            saver
+                .save_table("workspace", &table("orders"))
+                .await
+                .unwrap();
+            saver.commit().await.unwrap();
+            assert_eq!(
+                backend.paths(),
+                vec![
+                    "workspace/tables/orders.yaml",
+                    "workspace/tables/users.yaml",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_transaction_validation_and_conflicts() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = MemoryStorageBackend::new();
+            let saver =
+                ModelSaver::new(TransactionStorage::new(&backend).with_validator(|path, _| {
+                    if path.ends_with("relationships.yaml") {
+                        Err("relationships are managed elsewhere".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }));
+            save_all(&saver).await;
+            let result = saver.commit().await;
+            assert!(matches!(
+                result,
+                Err(TransactionError::Validation { ref path, .. })
+                    if path == "workspace/relationships.yaml"
+            ));
+            assert!(backend.paths().is_empty());
+
+            let saver = ModelSaver::transaction(&backend);
+            saver
+                .save_table("workspace", &table("users"))
+                .await
+                .unwrap();
+            backend
+                .write_file("workspace/tables/users.yaml", b"name: someone else\n")
+                .await
+                .unwrap();
+            let result = saver.commit().await;
+            assert!(matches!(result, Err(TransactionError::Conflict(_))));
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/users.yaml")
+                    .await
+                    .unwrap(),
+                b"name: someone else\n"
+            );
+        });
+    }
+}
+
 #[cfg(feature = "api-backend")]
 mod api_model_loader_tests {
 
@@ -450,3 +859,368 @@ mod api_model_loader_tests {
     // - test_load_model_from_api_missing_fields
     // - test_load_model_from_api_network_error
 }
+
+mod cross_domain_resolver_tests {
+    use data_modelling_core::model::cross_domain::{
+        CrossDomainResolver, ReferenceIssueKind, ReferenceSeverity,
+    };
+    use data_modelling_core::models::cross_domain::CrossDomainConfig;
+    use data_modelling_core::models::workspace::{AssetReference, AssetType, Workspace};
+    use data_modelling_core::storage::StorageBackend;
+    use data_modelling_core::storage::memory::MemoryStorageBackend;
+    use tokio::runtime::Runtime;
+    use uuid::Uuid;
+
+    const CUSTOMERS: &str = "11111111-1111-4111-8111-111111111111";
+    const ORDERS: &str = "22222222-2222-4222-8222-222222222222";
+    const RELATIONSHIP: &str = "33333333-3333-4333-8333-333333333333";
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    fn id(value: &str) -> Uuid {
+        Uuid::parse_str(value).unwrap()
+    }
+
+    fn table_yaml(id: &str, name: &str, extra_column: Option<&str>) -> String {
+        let mut yaml = format!(
+            "apiVersion: v3.1.0\nkind: DataContract\nid: {id}\nname: {name}\nversion: 1.0.0\nstatus: active\nschema:\n  - name: {name}\n    properties:\n      - name: {name}_id\n        logicalType: integer\n        primaryKey: true\n"
+        );
+        if let Some(column) = extra_column {
+            yaml.push_str(&format!(
+                "      - name: {column}\n        logicalType: string\n"
+            ));
+        }
+        yaml
+    }
+
+    fn asset(id: &str, name: &str, domain: &str) -> AssetReference {
+        AssetReference {
+            id: Uuid::parse_str(id).unwrap(),
+            name: name.to_string(),
+            domain: domain.to_string(),
+            system: None,
+            asset_type: AssetType::Odcs,
+            file_path: None,
+        }
+    }
+
+    fn workspace_yaml(orders_domain: &str) -> String {
+        let mut workspace = Workspace::new("shop".to_string(), Uuid::new_v4());
+        workspace.add_asset(asset(CUSTOMERS, "customers", "crm"));
+        workspace.add_asset(asset(ORDERS, "orders", orders_domain));
+        workspace.to_yaml().unwrap()
+    }
+
+    fn backend() -> MemoryStorageBackend {
+        MemoryStorageBackend::new()
+            .with_file("shop/workspace.yaml", workspace_yaml("sales"))
+            .with_file(
+                "shop/shop_crm_customers.odcs.yaml",
+                table_yaml(CUSTOMERS, "customers", None),
+            )
+            .with_file(
+                "shop/shop_sales_orders.odcs.yaml",
+                table_yaml(ORDERS, "orders", None),
+            )
+            .with_file(
+                "shop/relationships.yaml",
+                format!(
+                    "relationships:\n  - id: {RELATIONSHIP}\n    source_table_id: {ORDERS}\n    target_table_id: {CUSTOMERS}\n"
+                ),
+            )
+    }
+
+    fn config() -> CrossDomainConfig {
+        let mut config = CrossDomainConfig::new();
+        config.add_table_ref("crm".to_string(), id(CUSTOMERS));
+        config.add_table_ref("sales".to_string(), id(ORDERS));
+        config.add_relationship_ref(
+            "sales".to_string(),
+            id(RELATIONSHIP),
+            id(ORDERS),
+            id(CUSTOMERS),
+        );
+        config
+    }
+
+    #[test]
+    fn test_resolve_loads_referenced_tables_and_relationships() {
+        runtime().block_on(async {
+            let resolver = CrossDomainResolver::new(backend(), "shop");
+            let mut config = config();
+
+            let resolution = resolver.resolve(&config).await.unwrap();
+            assert_eq!(resolution.tables.len(), 2);
+            assert_eq!(resolution.tables[0].table.name, "customers");
+            assert_eq!(resolution.tables[0].domain.as_deref(), Some("crm"));
+            assert_eq!(resolution.relationships.len(), 1);
+            assert_eq!(
+                resolution.relationships[0].relationship.source_table_id,
+                id(ORDERS)
+            );
+            assert!(resolution.report.is_clean());
+            assert_eq!(resolution.report.unpinned, 2);
+
+            assert_eq!(resolver.pin(&mut config).await.unwrap(), 2);
+            assert_eq!(resolver.pin(&mut config).await.unwrap(), 0);
+            let report = resolver.check(&config).await.unwrap();
+            assert!(report.is_clean());
+            assert_eq!(report.unpinned, 0);
+        });
+    }
+
+    #[test]
+    fn test_check_reports_changed_moved_and_deleted_references() {
+        runtime().block_on(async {
+            let backend = backend();
+            let resolver = CrossDomainResolver::new(backend.clone(), "shop");
+            let mut config = config();
+            resolver.pin(&mut config).await.unwrap();
+
+            // The owning domains change the schema of customers and move orders
+            backend
+                .write_file(
+                    "shop/shop_crm_customers.odcs.yaml",
+                    table_yaml(CUSTOMERS, "customers", Some("email")).as_bytes(),
+                )
+                .await
+                .unwrap();
+            backend
+                .write_file(
+                    "shop/workspace.yaml",
+                    workspace_yaml("fulfilment").as_bytes(),
+                )
+                .await
+                .unwrap();
+
+            let report = resolver.check(&config).await.unwrap();
+            assert_eq!(report.broken().count(), 0);
+            let kinds: Vec<_> = report.stale().map(|i| &i.kind).collect();
+            assert_eq!(kinds.len(), 2);
+            assert!(matches!(kinds[0], ReferenceIssueKind::TableChanged { .. }));
+            assert_eq!(
+                kinds[1],
+                &ReferenceIssueKind::TableMoved {
+                    domain: "fulfilment".to_string()
+                }
+            );
+            assert!(
+                report
+                    .to_markdown()
+                    .contains("table moved to domain 'fulfilment'")
+            );
+
+            // Deleting orders breaks its table reference and the relationship
+            backend
+                .delete_file("shop/shop_sales_orders.odcs.yaml")
+                .await
+                .unwrap();
+            let report = resolver.check(&config).await.unwrap();
+            let broken: Vec<_> = report.broken().collect();
+            assert_eq!(broken.len(), 2);
+            assert_eq!(broken[0].kind, ReferenceIssueKind::TableDeleted);
+            assert_eq!(broken[0].target_id, id(ORDERS));
+            assert_eq!(
+                broken[1].kind,
+                ReferenceIssueKind::RelationshipEndpointMissing {
+                    table_id: id(ORDERS)
+                }
+            );
+            assert_eq!(broken[1].severity(), ReferenceSeverity::Broken);
+        });
+    }
+
+    #[test]
+    fn test_check_reports_deleted_and_unimported_relationships() {
+        runtime().block_on(async {
+            let backend = backend();
+            let resolver = CrossDomainResolver::new(backend.clone(), "shop");
+            let mut config = config();
+            config.remove_table_ref(id(CUSTOMERS));
+            config.add_relationship_ref(
+                "sales".to_string(),
+                id(RELATIONSHIP),
+                id(ORDERS),
+                id(CUSTOMERS),
+            );
+
+            let report = resolver.check(&config).await.unwrap();
+            assert_eq!(
+                report.issues.iter().map(|i| &i.kind).collect::<Vec<_>>(),
+                vec![&ReferenceIssueKind::RelationshipNotImported {
+                    table_id: id(CUSTOMERS)
+                }]
+            );
+
+            backend
+                .delete_file("shop/relationships.yaml")
+                .await
+                .unwrap();
+            let report = resolver.check(&config).await.unwrap();
+            assert_eq!(report.issues.len(), 1);
+            assert_eq!(
+                report.issues[0].kind,
+                ReferenceIssueKind::RelationshipDeleted
+            );
+            assert_eq!(report.issues[0].target_id, id(RELATIONSHIP));
+        });
+    }
+}
+
+mod tenant_loader_tests {
+    use data_modelling_core::model::ModelLoader;
+    use data_modelling_core::models::workspace::Workspace;
+    use data_modelling_core::storage::StorageError;
+    use data_modelling_core::storage::memory::MemoryStorageBackend;
+    use data_modelling_core::storage::tenant::{TenantId, TenantStorage};
+    use tokio::runtime::Runtime;
+    use uuid::Uuid;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    fn tenant(id: &str) -> TenantId {
+        TenantId::parse(id).unwrap()
+    }
+
+    fn contract(name: &str, tenant: Option<&str>) -> String {
+        let mut yaml = format!(
+            "apiVersion: v3.1.0\nkind: DataContract\nid: {}\nname: {name}\nversion: 1.0.0\nstatus: active\n",
+            Uuid::new_v4()
+        );
+        if let Some(tenant) = tenant {
+            yaml.push_str(&format!("tenant: {tenant}\n"));
+        }
+        yaml.push_str(&format!(
+            "schema:\n  - name: {name}\n    properties:\n      - name: id\n        logicalType: integer\n"
+        ));
+        yaml
+    }
+
+    fn workspace_yaml(tenant: Option<&str>) -> String {
+        let mut workspace = Workspace::new("shop".to_string(), Uuid::new_v4());
+        workspace.tenant = tenant.map(str::to_string);
+        workspace.to_yaml().unwrap()
+    }
+
+    #[test]
+    fn test_loader_skips_other_tenants_contracts() {
+        runtime().block_on(async {
+            let backend = MemoryStorageBackend::new()
+                .with_file("shop/workspace.yaml", workspace_yaml(None))
+                .with_file(
+                    "shop/shop_sales_orders.odcs.yaml",
+                    contract("orders", Some("acme")),
+                )
+                .with_file(
+                    "shop/shop_sales_returns.odcs.yaml",
+                    contract("returns", None),
+                )
+                .with_file(
+                    "shop/shop_sales_leads.odcs.yaml",
+                    contract("leads", Some("globex")),
+                );
+
+            let loader = ModelLoader::new(backend).with_tenant(tenant("acme"));
+            let mut names: Vec<String> = loader
+                .load_model("shop")
+                .await
+                .unwrap()
+                .tables
+                .into_iter()
+                .map(|t| t.name)
+                .collect();
+            names.sort();
+            assert_eq!(names, vec!["orders".to_string(), "returns".to_string()]);
+
+            let workspace = loader.load_workspace("shop").await.unwrap().unwrap();
+            assert_eq!(workspace.tenant.as_deref(), Some("acme"));
+        });
+    }
+
+    #[test]
+    fn test_loader_rejects_other_tenants_workspace() {
+        runtime().block_on(async {
+            let backend = MemoryStorageBackend::new()
+                .with_file("shop/workspace.yaml", workspace_yaml(Some("globex")));
+            let loader = ModelLoader::new(backend).with_tenant(tenant("acme"));
+
+            assert!(matches!(
+                loader.load_workspace("shop").await,
+                Err(StorageError::PermissionDenied(_))
+            ));
+            let workspace =
+                Workspace::new("shop".to_string(), Uuid::new_v4()).with_tenant("globex");
+            assert!(matches!(
+                loader.save_workspace("shop", &workspace).await,
+                Err(StorageError::PermissionDenied(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_tenant_storage_confines_loader() {
+        runtime().block_on(async {
+            let shared = MemoryStorageBackend::new()
+                .with_file("tenants/acme/shop/workspace.yaml", workspace_yaml(None))
+                .with_file("tenants/globex/shop/workspace.yaml", workspace_yaml(None));
+
+            let acme = tenant("acme");
+            let loader = ModelLoader::new(TenantStorage::new(shared.clone(), acme.clone()))
+                .with_tenant(acme);
+            assert!(loader.load_workspace("shop").await.unwrap().is_some());
+            assert!(loader.load_workspace("../globex/shop").await.is_err());
+        });
+    }
+}
+
+mod type_mapping_loader_tests {
+    use data_modelling_core::model::loader::ModelLoader;
+    use data_modelling_core::models::TYPE_MAPPINGS_FILE;
+    use data_modelling_core::storage::memory::MemoryStorageBackend;
+    use tokio::runtime::Runtime;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_load_type_mappings() {
+        runtime().block_on(async {
+            let backend = MemoryStorageBackend::new().with_file(
+                &format!("shop/{}", TYPE_MAPPINGS_FILE),
+                "dialects:\n  avro:\n    import:\n      long: NUMBER(19)\n",
+            );
+            let loader = ModelLoader::new(backend);
+
+            let mappings = loader.load_type_mappings("shop").await.unwrap();
+            assert_eq!(
+                mappings.import_type("avro", "long").as_deref(),
+                Some("NUMBER(19)")
+            );
+            assert_eq!(
+                mappings.import_type("avro", "int").as_deref(),
+                Some("INTEGER")
+            );
+
+            // Workspaces without overrides use the built-in mappings
+            let mappings = loader.load_type_mappings("empty").await.unwrap();
+            assert_eq!(
+                mappings.import_type("avro", "long").as_deref(),
+                Some("BIGINT")
+            );
+        });
+    }
+}
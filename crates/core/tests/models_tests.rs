@@ -268,6 +268,7 @@ mod relationship_tests {
             job_name: "daily_sync".to_string(),
             notes: Some("Syncs customer data daily".to_string()),
             frequency: Some("daily".to_string()),
+            ..Default::default()
         });
         assert!(rel.etl_job_metadata.is_some());
         assert_eq!(
@@ -819,6 +820,7 @@ mod relationship_structs_tests {
             job_name: "daily_sync".to_string(),
             notes: Some("Syncs data daily".to_string()),
             frequency: Some("daily".to_string()),
+            ..Default::default()
         };
         assert_eq!(etl.job_name, "daily_sync");
         assert!(etl.notes.is_some());
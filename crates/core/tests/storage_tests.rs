@@ -211,6 +211,34 @@ mod filesystem_tests {
         });
     }
 
+    #[test]
+    fn test_rename_replaces_target() {
+        let rt = runtime();
+        rt.block_on(async {
+            let temp = TempDir::new().unwrap();
+            let backend = FileSystemStorageBackend::new(temp.path());
+
+            backend.write_file("test.txt", b"original").await.unwrap();
+            backend
+                .write_file("test.txt.new", b"updated")
+                .await
+                .unwrap();
+            backend.rename("test.txt.new", "test.txt").await.unwrap();
+            assert_eq!(backend.read_file("test.txt").await.unwrap(), b"updated");
+            assert!(!backend.file_exists("test.txt.new").await.unwrap());
+
+            backend
+                .rename("test.txt", "nested/moved.txt")
+                .await
+                .unwrap();
+            assert!(backend.file_exists("nested/moved.txt").await.unwrap());
+            assert!(matches!(
+                backend.rename("test.txt", "other.txt").await,
+                Err(StorageError::FileNotFound(_))
+            ));
+        });
+    }
+
     #[test]
     fn test_delete_nonexistent_file() {
         let rt = runtime();
@@ -359,3 +387,457 @@ mod api_validation_tests {
         }
     }
 }
+
+mod memory_tests {
+    use data_modelling_core::model::saver::TableData;
+    use data_modelling_core::model::{ModelLoader, ModelSaver};
+    use data_modelling_core::storage::{
+        StorageBackend, StorageError, memory::MemoryStorageBackend,
+    };
+    use tokio::runtime::Runtime;
+    use uuid::Uuid;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_read_write_list() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = MemoryStorageBackend::new();
+            backend
+                .write_file("/workspace/tables/orders.yaml", b"name: orders")
+                .await
+                .unwrap();
+            backend
+                .write_file("workspace/readme.md", b"# Sales")
+                .await
+                .unwrap();
+
+            assert_eq!(
+                backend
+                    .read_file("workspace/tables/orders.yaml")
+                    .await
+                    .unwrap(),
+                b"name: orders"
+            );
+            assert!(backend.dir_exists("workspace/tables").await.unwrap());
+            assert!(backend.file_exists("workspace/readme.md").await.unwrap());
+            assert!(!backend.file_exists("workspace/tables").await.unwrap());
+            assert_eq!(
+                backend.list_files("workspace").await.unwrap(),
+                vec!["readme.md"]
+            );
+            assert!(matches!(
+                backend.list_files("missing").await,
+                Err(StorageError::DirectoryNotFound(_))
+            ));
+
+            backend.delete_file("workspace/readme.md").await.unwrap();
+            assert!(matches!(
+                backend.read_file("workspace/readme.md").await,
+                Err(StorageError::FileNotFound(_))
+            ));
+            assert!(matches!(
+                backend.delete_file("workspace/readme.md").await,
+                Err(StorageError::FileNotFound(_))
+            ));
+            assert!(matches!(
+                backend.create_dir("workspace/tables/orders.yaml").await,
+                Err(StorageError::IoError(_))
+            ));
+
+            backend
+                .rename("workspace/tables/orders.yaml", "archive/orders.yaml")
+                .await
+                .unwrap();
+            assert_eq!(backend.paths(), vec!["archive/orders.yaml"]);
+            assert!(matches!(
+                backend
+                    .rename("workspace/tables/orders.yaml", "orders.yaml")
+                    .await,
+                Err(StorageError::FileNotFound(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_path_traversal_blocked() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = MemoryStorageBackend::new();
+            let result = backend.write_file("workspace/../../etc/passwd", b"").await;
+            assert!(matches!(result, Err(StorageError::PermissionDenied(_))));
+            assert!(backend.paths().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_saver_and_loader_share_files() {
+        let rt = runtime();
+        rt.block_on(async {
+            let backend = MemoryStorageBackend::new();
+            let saver = ModelSaver::new(backend.clone());
+            let loader = ModelLoader::new(backend.clone());
+
+            let id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+            let yaml_value = serde_yaml::from_str(
+                r#"
+name: users
+id: 550e8400-e29b-41d4-a716-446655440000
+columns:
+  - name: id
+    data_type: INT
+    primary_key: true
+"#,
+            )
+            .unwrap();
+            let table = TableData {
+                id,
+                name: "users".to_string(),
+                yaml_file_path: Some("myworkspace_default_users.odcs.yaml".to_string()),
+                yaml_value,
+            };
+            saver.save_table("workspace", &table).await.unwrap();
+
+            assert_eq!(
+                backend.paths(),
+                vec!["workspace/myworkspace_default_users.odcs.yaml"]
+            );
+            let result = loader.load_model("workspace").await.unwrap();
+            assert_eq!(result.tables.len(), 1);
+            assert_eq!(result.tables[0].id, id);
+        });
+    }
+}
+
+#[cfg(feature = "encryption")]
+mod encrypted_tests {
+    use data_modelling_core::storage::{
+        StorageBackend, StorageError, encrypted::EncryptedStorageBackend,
+        memory::MemoryStorageBackend,
+    };
+    use tokio::runtime::Runtime;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_key_roundtrip_encrypts_at_rest() {
+        let rt = runtime();
+        rt.block_on(async {
+            let inner = MemoryStorageBackend::new();
+            let key = EncryptedStorageBackend::<MemoryStorageBackend>::generate_key();
+            let backend = EncryptedStorageBackend::new(inner.clone(), key);
+
+            backend
+                .write_file("workspace/connections.yaml", b"password: secret")
+                .await
+                .unwrap();
+
+            assert_eq!(
+                backend
+                    .read_file("workspace/connections.yaml")
+                    .await
+                    .unwrap(),
+                b"password: secret"
+            );
+            assert_eq!(
+                backend.list_files("workspace").await.unwrap(),
+                vec!["connections.yaml"]
+            );
+
+            let stored = inner.read_file("workspace/connections.yaml").await.unwrap();
+            assert!(
+                !stored
+                    .windows(b"secret".len())
+                    .any(|window| window == b"secret")
+            );
+
+            // Another key cannot read the file, and plaintext files are refused
+            let other = EncryptedStorageBackend::new(
+                inner.clone(),
+                EncryptedStorageBackend::<MemoryStorageBackend>::generate_key(),
+            );
+            assert!(matches!(
+                other.read_file("workspace/connections.yaml").await,
+                Err(StorageError::PermissionDenied(_))
+            ));
+            inner
+                .write_file("plain.yaml", b"name: plain")
+                .await
+                .unwrap();
+            assert!(matches!(
+                backend.read_file("plain.yaml").await,
+                Err(StorageError::BackendError(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_passphrase_roundtrip_and_tampering() {
+        let rt = runtime();
+        rt.block_on(async {
+            let inner = MemoryStorageBackend::new();
+            let writer =
+                EncryptedStorageBackend::with_passphrase(inner.clone(), "correct horse").unwrap();
+            writer
+                .write_file("tables/orders.odcs.yaml", b"name: orders")
+                .await
+                .unwrap();
+
+            // A new instance derives the key from the salt in the file header
+            let reader =
+                EncryptedStorageBackend::with_passphrase(inner.clone(), "correct horse").unwrap();
+            assert_eq!(
+                reader.read_file("tables/orders.odcs.yaml").await.unwrap(),
+                b"name: orders"
+            );
+
+            let wrong =
+                EncryptedStorageBackend::with_passphrase(inner.clone(), "battery staple").unwrap();
+            assert!(matches!(
+                wrong.read_file("tables/orders.odcs.yaml").await,
+                Err(StorageError::PermissionDenied(_))
+            ));
+
+            let mut stored = inner.read_file("tables/orders.odcs.yaml").await.unwrap();
+            let last = stored.len() - 1;
+            stored[last] ^= 1;
+            inner
+                .write_file("tables/orders.odcs.yaml", &stored)
+                .await
+                .unwrap();
+            assert!(matches!(
+                reader.read_file("tables/orders.odcs.yaml").await,
+                Err(StorageError::PermissionDenied(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_swapped_ciphertext_is_rejected() {
+        let rt = runtime();
+        rt.block_on(async {
+            let inner = MemoryStorageBackend::new();
+            let key = EncryptedStorageBackend::<MemoryStorageBackend>::generate_key();
+            let backend = EncryptedStorageBackend::new(inner.clone(), key);
+            backend
+                .write_file("connections/prod.yaml", b"host: prod")
+                .await
+                .unwrap();
+            backend
+                .write_file("connections/dev.yaml", b"host: dev")
+                .await
+                .unwrap();
+
+            // The path is authenticated in normalized form
+            assert_eq!(
+                backend.read_file("/connections/./prod.yaml").await.unwrap(),
+                b"host: prod"
+            );
+
+            let dev = inner.read_file("connections/dev.yaml").await.unwrap();
+            inner
+                .write_file("connections/prod.yaml", &dev)
+                .await
+                .unwrap();
+            assert!(matches!(
+                backend.read_file("connections/prod.yaml").await,
+                Err(StorageError::PermissionDenied(_))
+            ));
+        });
+    }
+}
+
+mod cached_tests {
+    use async_trait::async_trait;
+    use data_modelling_core::storage::{
+        StorageBackend, StorageError, cached::CachedStorageBackend, memory::MemoryStorageBackend,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tokio::runtime::Runtime;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    /// Memory backend counting the calls that reach it
+    #[derive(Default)]
+    struct CountingBackend {
+        inner: MemoryStorageBackend,
+        calls: AtomicUsize,
+    }
+
+    impl CountingBackend {
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+
+        fn count(&self) {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl StorageBackend for CountingBackend {
+        async fn read_file(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+            self.count();
+            self.inner.read_file(path).await
+        }
+
+        async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), StorageError> {
+            self.inner.write_file(path, content).await
+        }
+
+        async fn list_files(&self, dir: &str) -> Result<Vec<String>, StorageError> {
+            self.count();
+            self.inner.list_files(dir).await
+        }
+
+        async fn file_exists(&self, path: &str) -> Result<bool, StorageError> {
+            self.count();
+            self.inner.file_exists(path).await
+        }
+
+        async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+            self.inner.delete_file(path).await
+        }
+
+        async fn create_dir(&self, path: &str) -> Result<(), StorageError> {
+            self.inner.create_dir(path).await
+        }
+
+        async fn dir_exists(&self, path: &str) -> Result<bool, StorageError> {
+            self.count();
+            self.inner.dir_exists(path).await
+        }
+    }
+
+    #[test]
+    fn test_reads_are_cached_and_writes_invalidate() {
+        let rt = runtime();
+        rt.block_on(async {
+            let counting = CountingBackend::default();
+            let backend = CachedStorageBackend::new(&counting).without_expiry();
+            backend
+                .write_file("workspace/orders.yaml", b"name: orders")
+                .await
+                .unwrap();
+
+            for _ in 0..3 {
+                assert_eq!(
+                    backend.read_file("workspace/orders.yaml").await.unwrap(),
+                    b"name: orders"
+                );
+                assert_eq!(
+                    backend.list_files("/workspace/").await.unwrap(),
+                    vec!["orders.yaml"]
+                );
+                assert!(
+                    backend
+                        .file_exists("./workspace/orders.yaml")
+                        .await
+                        .unwrap()
+                );
+            }
+            assert_eq!(counting.calls(), 2);
+
+            // Missing files are cached as missing
+            for _ in 0..2 {
+                assert!(matches!(
+                    backend.read_file("workspace/missing.yaml").await,
+                    Err(StorageError::FileNotFound(_))
+                ));
+            }
+            assert_eq!(counting.calls(), 3);
+
+            backend
+                .write_file("workspace/orders.yaml", b"name: orders_v2")
+                .await
+                .unwrap();
+            backend
+                .write_file("workspace/items.yaml", b"name: items")
+                .await
+                .unwrap();
+            assert_eq!(
+                backend.read_file("workspace/orders.yaml").await.unwrap(),
+                b"name: orders_v2"
+            );
+            assert_eq!(backend.list_files("workspace").await.unwrap().len(), 2);
+
+            backend.delete_file("workspace/items.yaml").await.unwrap();
+            assert!(!backend.file_exists("workspace/items.yaml").await.unwrap());
+            assert_eq!(counting.calls(), 6);
+        });
+    }
+
+    #[test]
+    fn test_entries_expire() {
+        let rt = runtime();
+        rt.block_on(async {
+            let counting = CountingBackend::default();
+            counting
+                .inner
+                .write_file("orders.yaml", b"name: orders")
+                .await
+                .unwrap();
+            let backend = CachedStorageBackend::new(&counting).with_ttl(Duration::from_millis(20));
+
+            backend.read_file("orders.yaml").await.unwrap();
+            backend.read_file("orders.yaml").await.unwrap();
+            assert_eq!(counting.calls(), 1);
+
+            // Changes made behind the cache show up once the entry expires
+            counting
+                .inner
+                .write_file("orders.yaml", b"name: orders_v2")
+                .await
+                .unwrap();
+            assert_eq!(
+                backend.read_file("orders.yaml").await.unwrap(),
+                b"name: orders"
+            );
+            std::thread::sleep(Duration::from_millis(30));
+            assert_eq!(
+                backend.read_file("orders.yaml").await.unwrap(),
+                b"name: orders_v2"
+            );
+            assert_eq!(counting.calls(), 2);
+        });
+    }
+
+    #[test]
+    fn test_huge_ttl_never_expires() {
+        let rt = runtime();
+        rt.block_on(async {
+            let counting = CountingBackend::default();
+            counting
+                .inner
+                .write_file("orders.yaml", b"name: orders")
+                .await
+                .unwrap();
+            let backend = CachedStorageBackend::new(&counting).with_ttl(Duration::MAX);
+
+            for _ in 0..2 {
+                assert_eq!(
+                    backend.read_file("orders.yaml").await.unwrap(),
+                    b"name: orders"
+                );
+            }
+            assert_eq!(counting.calls(), 1);
+        });
+    }
+}
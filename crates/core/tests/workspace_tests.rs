@@ -262,3 +262,182 @@ mod request_response_tests {
         assert_eq!(req.email, parsed.email);
     }
 }
+
+#[cfg(feature = "bundle")]
+mod bundle_tests {
+    use data_modelling_core::storage::StorageBackend;
+    use data_modelling_core::storage::memory::MemoryStorageBackend;
+    use data_modelling_core::workspace::bundle::{
+        BundleError, BundleLimits, MANIFEST_FILENAME, export_bundle, import_bundle,
+        import_bundle_with_limits, read_manifest,
+    };
+    use std::io::{Cursor, Write};
+    use tokio::runtime::Runtime;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    async fn sample_workspace() -> MemoryStorageBackend {
+        let storage = MemoryStorageBackend::new();
+        for (path, content) in [
+            ("sales/workspace.yaml", "name: sales\n"),
+            (
+                "sales/sales_orders_orders.odcs.yaml",
+                "apiVersion: v3.1.0\nkind: DataContract\nid: orders\nversion: 1.0.0\nname: orders\n",
+            ),
+            ("sales/sales_orders_feed.odps.yaml", "kind: DataProduct\n"),
+            ("sales/decisions/0001-use-postgres.yaml", "number: 1\n"),
+            ("sales/knowledge/index.yaml", "articles: []\n"),
+            ("sales/sketches/thumbnails/sketch-0001.png", "png"),
+            ("sales/notes.txt", "not part of the workspace"),
+        ] {
+            storage.write_file(path, content.as_bytes()).await.unwrap();
+        }
+        storage
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        runtime().block_on(async {
+            let source = sample_workspace().await;
+            let archive = export_bundle(&source, "sales").await.unwrap();
+
+            let manifest = read_manifest(&archive).unwrap();
+            assert_eq!(
+                manifest.files,
+                vec![
+                    "decisions/0001-use-postgres.yaml",
+                    "knowledge/index.yaml",
+                    "sales_orders_feed.odps.yaml",
+                    "sales_orders_orders.odcs.yaml",
+                    "sketches/thumbnails/sketch-0001.png",
+                    "workspace.yaml",
+                ]
+            );
+
+            assert_eq!(
+                manifest.fingerprints.keys().collect::<Vec<_>>(),
+                vec!["sales_orders_orders.odcs.yaml"]
+            );
+
+            let target = MemoryStorageBackend::new();
+            let restored = import_bundle(&target, "copy", &archive, false)
+                .await
+                .unwrap();
+            assert_eq!(restored.files_written, manifest.files);
+            for file in &manifest.files {
+                assert_eq!(
+                    target.read_file(&format!("copy/{}", file)).await.unwrap(),
+                    source.read_file(&format!("sales/{}", file)).await.unwrap()
+                );
+            }
+            assert!(!target.file_exists("copy/notes.txt").await.unwrap());
+            assert!(
+                !target
+                    .file_exists(&format!("copy/{}", MANIFEST_FILENAME))
+                    .await
+                    .unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn test_bundle_import_keeps_existing_files() {
+        runtime().block_on(async {
+            let archive = export_bundle(&sample_workspace().await, "sales")
+                .await
+                .unwrap();
+            let target = MemoryStorageBackend::new();
+            target
+                .write_file("copy/workspace.yaml", b"name: local\n")
+                .await
+                .unwrap();
+
+            let restored = import_bundle(&target, "copy", &archive, false)
+                .await
+                .unwrap();
+            assert_eq!(restored.files_skipped, vec!["workspace.yaml"]);
+            assert_eq!(
+                target.read_file("copy/workspace.yaml").await.unwrap(),
+                b"name: local\n"
+            );
+
+            let restored = import_bundle(&target, "copy", &archive, true)
+                .await
+                .unwrap();
+            assert!(restored.files_skipped.is_empty());
+            assert_eq!(
+                target.read_file("copy/workspace.yaml").await.unwrap(),
+                b"name: sales\n"
+            );
+        });
+    }
+
+    #[test]
+    fn test_bundle_rejects_unsafe_paths() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file(MANIFEST_FILENAME, options).unwrap();
+        writer
+            .write_all(b"version: 1\ncreatedAt: 2024-01-01T00:00:00Z\nfiles: []\n")
+            .unwrap();
+        writer.start_file("../escape.yaml", options).unwrap();
+        writer.write_all(b"x").unwrap();
+        let archive = writer.finish().unwrap().into_inner();
+
+        runtime().block_on(async {
+            let target = MemoryStorageBackend::new();
+            let err = import_bundle(&target, "copy", &archive, true)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, BundleError::UnsafePath(_)));
+        });
+    }
+
+    #[test]
+    fn test_bundle_rejects_oversized_entries() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file(MANIFEST_FILENAME, options).unwrap();
+        writer
+            .write_all(b"version: 1\ncreatedAt: 2024-01-01T00:00:00Z\nfiles: []\n")
+            .unwrap();
+        for name in ["a.yaml", "b.yaml"] {
+            writer.start_file(name, options).unwrap();
+            writer.write_all(&[b'x'; 2048]).unwrap();
+        }
+        let archive = writer.finish().unwrap().into_inner();
+
+        runtime().block_on(async {
+            let target = MemoryStorageBackend::new();
+            for limits in [
+                BundleLimits {
+                    max_entry_size: 1024,
+                    ..BundleLimits::default()
+                },
+                BundleLimits {
+                    max_total_size: 4096,
+                    ..BundleLimits::default()
+                },
+                BundleLimits {
+                    max_entries: 2,
+                    ..BundleLimits::default()
+                },
+            ] {
+                let err = import_bundle_with_limits(&target, "copy", &archive, true, &limits)
+                    .await
+                    .unwrap_err();
+                assert!(matches!(err, BundleError::LimitExceeded(_)), "{limits:?}");
+            }
+            assert!(!target.dir_exists("copy").await.unwrap());
+
+            import_bundle(&target, "copy", &archive, true)
+                .await
+                .unwrap();
+        });
+    }
+}
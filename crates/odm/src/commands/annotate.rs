@@ -0,0 +1,160 @@
+//! Code annotation CLI command
+//!
+//! Scans an application repository for ORM models (SQLAlchemy, Prisma, JPA)
+//! and adds the column descriptions and constraints they declare to the
+//! workspace's ODCS contracts. With `--dry-run` the contracts are left alone
+//! and the proposed changes are printed as a unified diff.
+
+use crate::error::CliError;
+use data_modelling_core::models::code_annotations::{AnnotationPlanner, CodeModel, scan_source};
+use data_modelling_core::models::odcs::ODCSContract;
+use data_modelling_core::storage::dry_run::FileChange;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directories never scanned for models
+const SKIPPED_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "build",
+    "dist",
+    "venv",
+    "__pycache__",
+];
+
+/// Arguments for the `annotate` command
+#[derive(Debug)]
+pub struct AnnotateArgs {
+    /// Application repository to scan
+    pub source: PathBuf,
+    /// Workspace path
+    pub workspace: PathBuf,
+    /// Only print the proposed changes, without writing contracts
+    pub dry_run: bool,
+    /// Write the Markdown report to this file instead of stdout
+    pub report: Option<PathBuf>,
+}
+
+/// Handle the `annotate` command
+pub fn handle_annotate(args: &AnnotateArgs) -> Result<(), CliError> {
+    let mut source_files = Vec::new();
+    collect_source_files(&args.source, &mut source_files)?;
+    source_files.sort();
+
+    let mut models: Vec<CodeModel> = Vec::new();
+    for path in &source_files {
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        let relative = path.strip_prefix(&args.source).unwrap_or(path);
+        models.extend(scan_source(&relative.display().to_string(), &content));
+    }
+    if models.is_empty() {
+        return Err(CliError::InvalidArgument(format!(
+            "No SQLAlchemy, Prisma or JPA models found in {}",
+            args.source.display()
+        )));
+    }
+
+    let mut contract_files: Vec<PathBuf> = fs::read_dir(&args.workspace)
+        .map_err(|e| CliError::IoError(format!("Failed to read workspace: {}", e)))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.ends_with(".odcs.yaml"))
+        })
+        .collect();
+    contract_files.sort();
+
+    let mut paths = Vec::new();
+    let mut originals = Vec::new();
+    let mut contracts = Vec::new();
+    for path in contract_files {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| CliError::FileReadError(path.clone(), e.to_string()))?;
+        match serde_yaml::from_str::<ODCSContract>(&content) {
+            Ok(contract) => {
+                paths.push(path);
+                originals.push(content);
+                contracts.push(contract);
+            }
+            Err(e) => eprintln!("Warning: Failed to load {}: {}", path.display(), e),
+        }
+    }
+
+    let before: Vec<ODCSContract> = contracts.clone();
+    let mut report = AnnotationPlanner::new(models).apply_contracts(&mut contracts);
+    report.dry_run = args.dry_run;
+
+    for ((path, original), (old, new)) in paths
+        .iter()
+        .zip(&originals)
+        .zip(before.iter().zip(&contracts))
+    {
+        if old == new {
+            continue;
+        }
+        let yaml = serde_yaml::to_string(new).map_err(|e| {
+            CliError::ParseError(format!("Failed to serialize {}: {}", path.display(), e))
+        })?;
+        if args.dry_run {
+            let name = path.display().to_string();
+            if let Some(diff) =
+                FileChange::compare(&name, Some(original.as_bytes()), Some(yaml.as_bytes()))
+                    .and_then(|change| change.diff)
+            {
+                print!("{}", diff);
+            }
+        } else {
+            fs::write(path, yaml)
+                .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+            println!("Updated: {}", path.display());
+        }
+    }
+
+    let markdown = report.to_markdown();
+    match &args.report {
+        Some(path) => {
+            fs::write(path, &markdown)
+                .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+            println!("Report written to {}", path.display());
+        }
+        None => print!("{}", markdown),
+    }
+
+    println!(
+        "{} model(s) in {} file(s), {} field(s) {}, {} conflict(s)",
+        report.models_scanned,
+        source_files.len(),
+        report.changes.len(),
+        if args.dry_run { "proposed" } else { "set" },
+        report.conflicts.len()
+    );
+    Ok(())
+}
+
+/// Collect the Python, Prisma and Java files under a directory, skipping
+/// hidden, dependency and build directories
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), CliError> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| CliError::IoError(format!("Failed to read {}: {}", dir.display(), e)))?;
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            if !name.starts_with('.') && !SKIPPED_DIRS.contains(&name.as_ref()) {
+                collect_source_files(&path, files)?;
+            }
+        } else if matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("py" | "prisma" | "java")
+        ) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
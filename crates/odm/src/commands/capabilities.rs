@@ -0,0 +1,76 @@
+//! Capability discovery CLI command
+//!
+//! Prints the features, formats, SQL dialects and LLM backends this build of
+//! `odm` supports, as text or as JSON for scripts and frontends.
+
+use crate::error::CliError;
+use data_modelling_core::{Capabilities, FormatCapability, capabilities};
+
+/// Arguments for the `capabilities` command
+#[derive(Debug)]
+pub struct CapabilitiesArgs {
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// Handle the `capabilities` command
+pub fn handle_capabilities(args: &CapabilitiesArgs) -> Result<(), CliError> {
+    let capabilities = capabilities();
+    match args.format.as_str() {
+        "text" => print!("{}", render_text(&capabilities)),
+        "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&capabilities)
+                .map_err(|e| CliError::SerializationError(e.to_string()))?
+        ),
+        other => {
+            return Err(CliError::InvalidArgument(format!(
+                "Unsupported output format: {}. Valid values: text, json",
+                other
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn render_text(capabilities: &Capabilities) -> String {
+    let mut out = format!("SDK version: {}\n", capabilities.version);
+    out.push_str(&format!("\nFeatures: {}\n", list(&capabilities.features)));
+    out.push_str("\nImport formats:\n");
+    for format in &capabilities.import_formats {
+        out.push_str(&format_line(format));
+    }
+    out.push_str("\nExport formats:\n");
+    for format in &capabilities.export_formats {
+        out.push_str(&format_line(format));
+    }
+    out.push_str(&format!(
+        "\nSQL dialects: {}\n",
+        list(&capabilities.sql_dialects)
+    ));
+    out.push_str(&format!(
+        "LLM backends: {}\n",
+        list(&capabilities.llm_backends)
+    ));
+    out
+}
+
+fn format_line(format: &FormatCapability) -> String {
+    let mut line = format!("  {}", format.name);
+    if !format.versions.is_empty() {
+        line.push_str(&format!(" ({})", format.versions.join(", ")));
+    }
+    if let Some(description) = &format.description {
+        line.push_str(&format!(" - {}", description));
+    }
+    line.push('\n');
+    line
+}
+
+fn list(values: &[String]) -> String {
+    if values.is_empty() {
+        "(none)".to_string()
+    } else {
+        values.join(", ")
+    }
+}
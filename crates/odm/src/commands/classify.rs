@@ -0,0 +1,116 @@
+//! Classification rules CLI command
+//!
+//! Runs the workspace's `classificationRules` over every ODCS contract in the
+//! workspace, writing the classified contracts back or, with `--dry-run`, only
+//! reporting what would change.
+
+use crate::error::CliError;
+use data_modelling_core::models::classification_rules::{
+    ClassificationEngine, ClassificationReport, ClassificationRule,
+};
+use data_modelling_core::models::odcs::ODCSContract;
+use data_modelling_core::models::workspace::Workspace;
+use std::fs;
+use std::path::PathBuf;
+
+/// Arguments for the `classify` command
+#[derive(Debug)]
+pub struct ClassifyArgs {
+    /// Workspace path
+    pub workspace: PathBuf,
+    /// Only report changes, without writing contracts
+    pub dry_run: bool,
+    /// Append the built-in PII rules to the workspace rules
+    pub pii_defaults: bool,
+    /// Write the Markdown report to this file instead of stdout
+    pub report: Option<PathBuf>,
+}
+
+/// Handle the `classify` command
+pub fn handle_classify(args: &ClassifyArgs) -> Result<(), CliError> {
+    let workspace_file = args.workspace.join("workspace.yaml");
+    let content = fs::read_to_string(&workspace_file)
+        .map_err(|e| CliError::FileReadError(workspace_file.clone(), e.to_string()))?;
+    let workspace = Workspace::from_yaml(&content)
+        .map_err(|e| CliError::ParseError(format!("Failed to parse workspace.yaml: {}", e)))?;
+
+    let mut rules = workspace.classification_rules.clone();
+    if args.pii_defaults {
+        rules.extend(ClassificationRule::pii_defaults());
+    }
+    if rules.is_empty() {
+        return Err(CliError::InvalidArgument(format!(
+            "No classificationRules in {} (use --pii-defaults for the built-in PII rules)",
+            workspace_file.display()
+        )));
+    }
+    let engine =
+        ClassificationEngine::new(&rules).map_err(|e| CliError::ValidationError(e.to_string()))?;
+
+    let mut contract_files: Vec<PathBuf> = fs::read_dir(&args.workspace)
+        .map_err(|e| CliError::IoError(format!("Failed to read workspace: {}", e)))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.ends_with(".odcs.yaml"))
+        })
+        .collect();
+    contract_files.sort();
+
+    let mut report = ClassificationReport {
+        dry_run: args.dry_run,
+        ..Default::default()
+    };
+    for path in &contract_files {
+        let content = fs::read_to_string(path)
+            .map_err(|e| CliError::FileReadError(path.clone(), e.to_string()))?;
+        let mut contract: ODCSContract = match serde_yaml::from_str(&content) {
+            Ok(contract) => contract,
+            Err(e) => {
+                eprintln!("Warning: Failed to load {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        let result = if args.dry_run {
+            engine.plan_contract(&contract)
+        } else {
+            engine.apply_contract(&mut contract)
+        };
+        if !args.dry_run && !result.changes.is_empty() {
+            let yaml = serde_yaml::to_string(&contract).map_err(|e| {
+                CliError::ParseError(format!("Failed to serialize {}: {}", path.display(), e))
+            })?;
+            fs::write(path, yaml)
+                .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+            println!("Updated: {}", path.display());
+        }
+        report.merge(result);
+    }
+
+    let markdown = report.to_markdown();
+    match &args.report {
+        Some(path) => {
+            fs::write(path, &markdown)
+                .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+            println!("Report written to {}", path.display());
+        }
+        None => print!("{}", markdown),
+    }
+
+    println!(
+        "{} contract(s), {} column(s) {}, {} conflict(s)",
+        contract_files.len(),
+        report.changes.len(),
+        if args.dry_run {
+            "would change"
+        } else {
+            "changed"
+        },
+        report.conflicts.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! SDK configuration CLI commands
+//!
+//! Shows the layered configuration (user `config.toml`, workspace `.odm.toml` and
+//! `ODM_*` environment variables) that other commands fall back to when a flag is
+//! not given.
+
+use crate::error::CliError;
+use data_modelling_core::config::{
+    ConfigLoader, SdkConfig, WORKSPACE_CONFIG_FILENAME, find_workspace_config, user_config_path,
+};
+use std::path::{Path, PathBuf};
+
+/// Arguments for the `config` commands
+#[derive(Debug)]
+pub struct ConfigArgs {
+    /// Workspace directory the workspace configuration is searched from
+    pub workspace: PathBuf,
+    /// Profile to select, overriding `ODM_PROFILE` and the configuration files
+    pub profile: Option<String>,
+}
+
+/// Load the configuration for a workspace directory
+pub fn load_config(workspace: &Path, profile: Option<&str>) -> Result<SdkConfig, CliError> {
+    let mut loader = ConfigLoader::new().with_workspace(workspace);
+    if let Some(profile) = profile {
+        loader = loader.with_profile(profile);
+    }
+    Ok(loader.load()?)
+}
+
+/// Handle the `config show` command
+pub fn handle_config_show(args: &ConfigArgs) -> Result<(), CliError> {
+    let config = load_config(&args.workspace, args.profile.as_deref())?;
+
+    println!(
+        "# Profile: {}",
+        config.profile.as_deref().unwrap_or("(none)")
+    );
+    if config.sources.is_empty() {
+        println!("# Sources: (none)");
+    }
+    for source in &config.sources {
+        println!("# Source: {}", source.display());
+    }
+    println!();
+    print!("{}", config.to_toml()?);
+    Ok(())
+}
+
+/// Handle the `config path` command
+pub fn handle_config_path(args: &ConfigArgs) -> Result<(), CliError> {
+    match user_config_path() {
+        Some(path) => println!("User:      {}", describe(&path)),
+        None => println!("User:      (no home directory)"),
+    }
+    let workspace = find_workspace_config(&args.workspace)
+        .unwrap_or_else(|| args.workspace.join(WORKSPACE_CONFIG_FILENAME));
+    println!("Workspace: {}", describe(&workspace));
+    Ok(())
+}
+
+fn describe(path: &Path) -> String {
+    if path.is_file() {
+        path.display().to_string()
+    } else {
+        format!("{} (not found)", path.display())
+    }
+}
@@ -53,6 +53,44 @@ pub struct DbExportArgs {
     pub output: Option<PathBuf>,
 }
 
+/// Database search arguments
+#[derive(Debug, Clone, Default)]
+pub struct DbSearchArgs {
+    /// Workspace path
+    pub workspace: PathBuf,
+    /// Free text
+    pub text: Option<String>,
+    /// Name substring
+    pub name: Option<String>,
+    /// Entity types to include
+    pub entity_types: Vec<String>,
+    /// Tags to include
+    pub tags: Vec<String>,
+    /// Classifications to include
+    pub classifications: Vec<String>,
+    /// Owners to include
+    pub owners: Vec<String>,
+    /// Domains to include
+    pub domains: Vec<String>,
+    /// Statuses to include
+    pub statuses: Vec<String>,
+    /// Number of hits to skip
+    pub offset: usize,
+    /// Page size
+    pub limit: usize,
+    /// Output format (table, json)
+    pub format: String,
+}
+
+/// Database mirror arguments
+#[derive(Debug, Clone)]
+pub struct DbMirrorArgs {
+    /// Workspace path
+    pub workspace: PathBuf,
+    /// Actor recorded on contract versions and audit events
+    pub actor: Option<String>,
+}
+
 /// Initialize database for a workspace
 #[cfg(feature = "duckdb-backend")]
 pub fn handle_db_init(args: &DbInitArgs) -> Result<(), CliError> {
@@ -529,6 +567,276 @@ pub fn handle_db_export(_args: &DbExportArgs) -> Result<(), CliError> {
     ))
 }
 
+/// Search the metadata store
+#[cfg(feature = "duckdb-backend")]
+pub fn handle_db_search(args: &DbSearchArgs) -> Result<(), CliError> {
+    use data_modelling_core::database::{MetadataStore, SearchQuery};
+
+    let workspace_path = &args.workspace;
+
+    let config = DatabaseConfig::load(workspace_path)
+        .map_err(|e| CliError::IoError(format!("Failed to load config: {}", e)))?;
+
+    if !DatabaseConfig::is_initialized(workspace_path) {
+        return Err(CliError::InvalidArgument(
+            "Database not initialized. Run 'db init' first.".to_string(),
+        ));
+    }
+    if !matches!(args.format.as_str(), "table" | "json") {
+        return Err(CliError::InvalidArgument(format!(
+            "Unknown output format '{}'. Use table or json.",
+            args.format
+        )));
+    }
+
+    let search = SearchQuery {
+        text: args.text.clone(),
+        name: args.name.clone(),
+        entity_types: args.entity_types.clone(),
+        tags: args.tags.clone(),
+        classifications: args.classifications.clone(),
+        owners: args.owners.clone(),
+        domains: args.domains.clone(),
+        statuses: args.statuses.clone(),
+        offset: args.offset,
+        limit: args.limit,
+    };
+
+    let rt = tokio::runtime::Runtime::new()
+        .map_err(|e| CliError::IoError(format!("Failed to create runtime: {}", e)))?;
+
+    rt.block_on(async {
+        match config.database.backend {
+            DatabaseBackendType::DuckDB => {
+                let db_path = config.get_duckdb_path(workspace_path);
+                let backend = DuckDBBackend::new(&db_path)
+                    .map_err(|e| CliError::IoError(format!("Failed to open database: {}", e)))?;
+                run_search(
+                    MetadataStore::new(backend),
+                    workspace_path,
+                    &search,
+                    &args.format,
+                )
+                .await
+            }
+            DatabaseBackendType::Postgres => {
+                #[cfg(feature = "postgres-backend")]
+                {
+                    use data_modelling_core::database::postgres::PostgresBackend;
+
+                    let conn_str = config.get_postgres_connection_string().ok_or_else(|| {
+                        CliError::InvalidArgument(
+                            "PostgreSQL connection string not configured".to_string(),
+                        )
+                    })?;
+
+                    let backend = PostgresBackend::new(conn_str)
+                        .await
+                        .map_err(|e| CliError::IoError(format!("Failed to connect: {}", e)))?;
+                    run_search(
+                        MetadataStore::new(backend),
+                        workspace_path,
+                        &search,
+                        &args.format,
+                    )
+                    .await
+                }
+                #[cfg(not(feature = "postgres-backend"))]
+                {
+                    Err(CliError::InvalidArgument(
+                        "PostgreSQL backend not enabled".to_string(),
+                    ))
+                }
+            }
+        }
+    })
+}
+
+#[cfg(not(feature = "duckdb-backend"))]
+pub fn handle_db_search(_args: &DbSearchArgs) -> Result<(), CliError> {
+    Err(CliError::InvalidArgument(
+        "Database support not enabled. Build with --features duckdb-backend".to_string(),
+    ))
+}
+
+/// Run a search and print the results
+#[cfg(feature = "duckdb-backend")]
+async fn run_search<B: DatabaseBackend>(
+    store: data_modelling_core::database::MetadataStore<B>,
+    workspace_path: &Path,
+    search: &data_modelling_core::database::SearchQuery,
+    format: &str,
+) -> Result<(), CliError> {
+    let workspace = store
+        .workspace_for_directory(workspace_path)
+        .await
+        .map_err(|e| CliError::IoError(format!("Failed to load workspace: {}", e)))?;
+    let results = store
+        .search()
+        .search(workspace.id, search)
+        .await
+        .map_err(|e| CliError::IoError(format!("Search failed: {}", e)))?;
+
+    if format == "json" {
+        let json = serde_json::to_string_pretty(&results)
+            .map_err(|e| CliError::IoError(format!("Failed to serialize results: {}", e)))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    for hit in &results.hits {
+        let mut details = Vec::new();
+        details.extend(hit.domain.iter().map(|d| format!("domain: {}", d)));
+        details.extend(hit.owner.iter().map(|o| format!("owner: {}", o)));
+        if !hit.tags.is_empty() {
+            details.push(format!("tags: {}", hit.tags.join(", ")));
+        }
+        println!(
+            "{:<9} {}  {}",
+            hit.entity_type,
+            hit.name,
+            details.join("  ")
+        );
+    }
+    println!(
+        "\n{}-{} of {} results",
+        (results.offset + 1).min(results.total),
+        results.offset + results.hits.len(),
+        results.total
+    );
+    for (facet, counts) in &results.facets {
+        let values: Vec<String> = counts
+            .iter()
+            .map(|c| format!("{} ({})", c.value, c.count))
+            .collect();
+        println!("  {}: {}", facet, values.join(", "));
+    }
+    Ok(())
+}
+
+/// Mirror workspace contracts into the metadata store
+#[cfg(feature = "duckdb-backend")]
+pub fn handle_db_mirror(args: &DbMirrorArgs) -> Result<(), CliError> {
+    use data_modelling_core::database::{MetadataStore, MirrorOptions, MirrorResult};
+
+    let workspace_path = &args.workspace;
+
+    let config = DatabaseConfig::load(workspace_path)
+        .map_err(|e| CliError::IoError(format!("Failed to load config: {}", e)))?;
+
+    if !DatabaseConfig::is_initialized(workspace_path) {
+        return Err(CliError::InvalidArgument(
+            "Database not initialized. Run 'db init' first.".to_string(),
+        ));
+    }
+
+    let mut options = MirrorOptions::default();
+    if let Some(actor) = args.actor.clone().or_else(|| std::env::var("USER").ok()) {
+        options = options.with_actor(actor);
+    }
+    if let Some(revision) = git_revision(workspace_path) {
+        options = options.with_revision(revision);
+    }
+
+    let print_result = |result: &MirrorResult| {
+        println!("Mirror complete:");
+        println!("  Created:   {}", result.contracts_created);
+        println!("  Updated:   {}", result.contracts_updated);
+        println!("  Deleted:   {}", result.contracts_deleted);
+        println!("  Unchanged: {}", result.contracts_unchanged);
+        println!("  Consumers: {}", result.consumers_synced);
+        println!("  Duration:  {}ms", result.duration_ms);
+
+        if !result.errors.is_empty() {
+            println!("\nErrors:");
+            for err in &result.errors {
+                println!("  - {}", err);
+            }
+        }
+    };
+
+    let rt = tokio::runtime::Runtime::new()
+        .map_err(|e| CliError::IoError(format!("Failed to create runtime: {}", e)))?;
+
+    rt.block_on(async {
+        match config.database.backend {
+            DatabaseBackendType::DuckDB => {
+                let db_path = config.get_duckdb_path(workspace_path);
+                let backend = DuckDBBackend::new(&db_path)
+                    .map_err(|e| CliError::IoError(format!("Failed to open database: {}", e)))?;
+
+                let store = MetadataStore::new(backend);
+                store
+                    .migrate()
+                    .await
+                    .map_err(|e| CliError::IoError(format!("Migration failed: {}", e)))?;
+                let result = store
+                    .mirror_directory(workspace_path, &options)
+                    .await
+                    .map_err(|e| CliError::IoError(format!("Mirror failed: {}", e)))?;
+                print_result(&result);
+            }
+            DatabaseBackendType::Postgres => {
+                #[cfg(feature = "postgres-backend")]
+                {
+                    use data_modelling_core::database::postgres::PostgresBackend;
+
+                    let conn_str = config.get_postgres_connection_string().ok_or_else(|| {
+                        CliError::InvalidArgument(
+                            "PostgreSQL connection string not configured".to_string(),
+                        )
+                    })?;
+
+                    let backend = PostgresBackend::new(conn_str)
+                        .await
+                        .map_err(|e| CliError::IoError(format!("Failed to connect: {}", e)))?;
+
+                    let store = MetadataStore::new(backend);
+                    store
+                        .migrate()
+                        .await
+                        .map_err(|e| CliError::IoError(format!("Migration failed: {}", e)))?;
+                    let result = store
+                        .mirror_directory(workspace_path, &options)
+                        .await
+                        .map_err(|e| CliError::IoError(format!("Mirror failed: {}", e)))?;
+                    print_result(&result);
+                }
+                #[cfg(not(feature = "postgres-backend"))]
+                {
+                    return Err(CliError::InvalidArgument(
+                        "PostgreSQL backend not enabled".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(not(feature = "duckdb-backend"))]
+pub fn handle_db_mirror(_args: &DbMirrorArgs) -> Result<(), CliError> {
+    Err(CliError::InvalidArgument(
+        "Database support not enabled. Build with --features duckdb-backend".to_string(),
+    ))
+}
+
+/// Git revision checked out in a workspace, if it is a Git repository
+#[cfg(feature = "duckdb-backend")]
+fn git_revision(workspace_path: &Path) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(workspace_path)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Install Git hooks for automatic database rebuild
 fn install_git_hooks(workspace_path: &Path) -> Result<(), CliError> {
     let hooks_dir = workspace_path.join(".git/hooks");
@@ -94,7 +94,7 @@ pub fn handle_decision_new(args: &DecisionNewArgs) -> Result<(), CliError> {
     let mut index = if index_path.exists() {
         let content = fs::read_to_string(&index_path)
             .map_err(|e| CliError::IoError(format!("Failed to read decisions.yaml: {}", e)))?;
-        let importer = DecisionImporter;
+        let importer = DecisionImporter::new();
         importer
             .import_index(&content)
             .map_err(|e| CliError::ParseError(format!("Failed to parse decisions.yaml: {}", e)))?
@@ -471,7 +471,7 @@ fn parse_decision_number(s: &str) -> Result<u64, CliError> {
 }
 
 fn load_all_decisions(workspace: &Path) -> Result<Vec<Decision>, CliError> {
-    let importer = DecisionImporter;
+    let importer = DecisionImporter::new();
     let mut decisions = Vec::new();
 
     for entry in fs::read_dir(workspace)
@@ -515,7 +515,7 @@ fn find_decision_file_and_load(
     workspace: &Path,
     number: u64,
 ) -> Result<(PathBuf, Decision), CliError> {
-    let importer = DecisionImporter;
+    let importer = DecisionImporter::new();
 
     for entry in fs::read_dir(workspace)
         .map_err(|e| CliError::IoError(format!("Failed to read workspace: {}", e)))?
@@ -555,7 +555,7 @@ fn update_decision_in_index(index_path: &Path, decision: &Decision) -> Result<()
     let content = fs::read_to_string(index_path)
         .map_err(|e| CliError::IoError(format!("Failed to read index: {}", e)))?;
 
-    let importer = DecisionImporter;
+    let importer = DecisionImporter::new();
     let mut index = importer
         .import_index(&content)
         .map_err(|e| CliError::ParseError(format!("Failed to parse index: {}", e)))?;
@@ -3,8 +3,8 @@
 use crate::error::CliError;
 use data_modelling_core::export::pdf::BrandingConfig;
 use data_modelling_core::export::{
-    AvroExporter, BrandedMarkdownExporter, JSONSchemaExporter, MarkdownBrandingConfig,
-    MarkdownExporter, ODCSExporter, PdfExporter, ProtobufExporter,
+    AvroExporter, BrandedMarkdownExporter, ExportOptions, ExporterRegistry, JSONSchemaExporter,
+    MarkdownBrandingConfig, MarkdownExporter, ODCSExporter, PdfExporter, ProtobufExporter,
 };
 use std::path::PathBuf;
 use std::process::Command;
@@ -22,6 +22,13 @@ pub enum ExportFormat {
     Pdf,
     /// Branded Markdown export
     BrandedMarkdown,
+    /// Format provided by the exporter registry
+    Registered(String),
+}
+
+/// Get the registry of pluggable export formats available to the CLI.
+pub fn export_registry() -> ExporterRegistry {
+    ExporterRegistry::default()
 }
 
 /// Arguments for export operations
@@ -101,7 +108,20 @@ pub fn load_tables_from_odcs(
             })
             .collect();
 
-        tables.push(data_modelling_core::models::Table::new(table_name, columns));
+        let mut table = data_modelling_core::models::Table::new(table_name, columns);
+        table.quality = table_data.quality;
+        if !table_data.servers.is_empty() {
+            table.odcl_metadata.insert(
+                "servers".to_string(),
+                serde_json::Value::Array(table_data.servers),
+            );
+        }
+        if let Some(properties) = table_data.odcs_metadata.get("schemaCustomProperties") {
+            table
+                .odcl_metadata
+                .insert("customProperties".to_string(), properties.clone());
+        }
+        tables.push(table);
     }
 
     Ok(tables)
@@ -171,6 +191,31 @@ pub fn handle_export_odcs(args: &ExportArgs) -> Result<(), CliError> {
     Ok(())
 }
 
+/// Handle export to a format from the exporter registry
+pub fn handle_export_registered(args: &ExportArgs, format: &str) -> Result<(), CliError> {
+    check_file_overwrite(&args.output, args.force)?;
+
+    let tables = load_tables_from_odcs(&args.input)?;
+
+    let mut options = ExportOptions::new();
+    if let Some(version) = &args.protobuf_version {
+        options = options.with_version(version.clone());
+    }
+
+    let result = export_registry()
+        .export_tables(format, &tables, &options)
+        .map_err(CliError::ExportError)?;
+
+    write_export_output(&args.output, &result.content)?;
+    println!(
+        "✅ Exported to {} format: {}",
+        format,
+        args.output.display()
+    );
+
+    Ok(())
+}
+
 /// Handle AVRO export command
 pub fn handle_export_avro(args: &ExportArgs) -> Result<(), CliError> {
     check_file_overwrite(&args.output, args.force)?;
@@ -438,6 +483,7 @@ pub fn handle_export_pdf(args: &ExportArgs) -> Result<(), CliError> {
         company_name: args.company_name.clone(),
         show_page_numbers: true,
         show_timestamp: true,
+        include_toc: args.include_toc,
         ..Default::default()
     };
 
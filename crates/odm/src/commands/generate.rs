@@ -0,0 +1,80 @@
+//! Test data generation CLI command
+//!
+//! Generates synthetic records conforming to an ODCS contract or a JSON Schema,
+//! for fixtures and consumer-driven contract tests.
+
+use crate::error::CliError;
+use data_modelling_core::generate::{RecordGenerator, records_to_csv};
+use data_modelling_core::models::odcs::ODCSContract;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+
+/// Arguments for the `generate` command
+#[derive(Debug)]
+pub struct GenerateArgs {
+    /// ODCS contract or JSON Schema file
+    pub input: PathBuf,
+    /// Schema object to generate records for (ODCS, default: first)
+    pub schema: Option<String>,
+    /// Number of records
+    pub count: usize,
+    /// Seed for reproducible output
+    pub seed: u64,
+    /// Share of optional fields included in each record (0.0-1.0)
+    pub optional_ratio: f64,
+    /// Output format (json, jsonl, csv)
+    pub format: String,
+    /// Output file path (stdout if not provided)
+    pub output: Option<PathBuf>,
+}
+
+/// Handle the `generate` command
+pub fn handle_generate(args: &GenerateArgs) -> Result<(), CliError> {
+    let content = fs::read_to_string(&args.input)
+        .map_err(|e| CliError::FileReadError(args.input.clone(), e.to_string()))?;
+    let document: Value = serde_yaml::from_str(&content)
+        .map_err(|e| CliError::ParseError(format!("Failed to parse input: {}", e)))?;
+
+    let mut generator = RecordGenerator::new(args.seed).with_optional_ratio(args.optional_ratio);
+    let records = if is_odcs_contract(&document) {
+        let contract: ODCSContract = serde_json::from_value(document)
+            .map_err(|e| CliError::ParseError(format!("Failed to parse contract: {}", e)))?;
+        generator.generate_contract(&contract, args.schema.as_deref(), args.count)
+    } else {
+        generator.generate_json_schema(&document, args.count)
+    }
+    .map_err(|e| CliError::InvalidArgument(e.to_string()))?;
+
+    let output = match args.format.as_str() {
+        "json" => serde_json::to_string_pretty(&records)
+            .map_err(|e| CliError::SerializationError(e.to_string()))?,
+        "jsonl" => records
+            .iter()
+            .map(|r| format!("{}\n", r))
+            .collect::<String>(),
+        "csv" => records_to_csv(&records),
+        other => {
+            return Err(CliError::InvalidArgument(format!(
+                "Unsupported output format: {}. Valid values: json, jsonl, csv",
+                other
+            )));
+        }
+    };
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, output)
+                .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+            eprintln!("Generated {} records: {}", records.len(), path.display());
+        }
+        None => print!("{}", output),
+    }
+    Ok(())
+}
+
+/// ODCS documents declare `apiVersion` and `kind: DataContract`
+fn is_odcs_contract(document: &Value) -> bool {
+    document.get("apiVersion").is_some()
+        || document.get("kind").and_then(Value::as_str) == Some("DataContract")
+}
@@ -5,10 +5,12 @@ use crate::output::{collect_type_mappings, format_compact_output, format_pretty_
 use crate::reference::resolve_reference;
 use data_modelling_core::export::odcs::ODCSExporter;
 use data_modelling_core::import::{
-    AvroImporter, ColumnData, ImportResult, JSONSchemaImporter, ODCSImporter, ODPSImporter,
-    ProtobufImporter, SQLImporter, TableData,
+    AvroImporter, ColumnData, ImportResult, ImporterRegistry, JSONSchemaImporter, ODCSImporter,
+    ODPSImporter, ProtobufImporter, SQLImporter, StreamingSqlImporter, TableData,
 };
 use data_modelling_core::models::{Column, Table};
+use data_modelling_core::validation::ResourceLimits;
+use data_modelling_core::validation::limits::DEFAULT_MAX_FILE_SIZE;
 #[cfg(feature = "openapi")]
 use data_modelling_core::validation::schema::validate_openapi_internal;
 use data_modelling_core::validation::schema::{
@@ -16,8 +18,8 @@ use data_modelling_core::validation::schema::{
     validate_odcs_internal, validate_protobuf_internal,
 };
 use serde_json::Value as JsonValue;
-use std::io::{self, Read};
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 /// Input source for import operations
@@ -57,20 +59,67 @@ pub enum ImportFormat {
     Odps,
 }
 
-/// Load input content from InputSource
+/// Load input content from InputSource, within the default size limit
 pub fn load_input(input: &InputSource) -> Result<String, CliError> {
-    match input {
-        InputSource::File(path) => std::fs::read_to_string(path)
-            .map_err(|e| CliError::FileReadError(path.clone(), e.to_string())),
-        InputSource::Stdin => {
-            let mut buffer = String::new();
-            io::stdin()
-                .read_to_string(&mut buffer)
-                .map_err(|e| CliError::InvalidArgument(format!("Failed to read stdin: {}", e)))?;
-            Ok(buffer)
+    let limits = ResourceLimits::default();
+    let content = match input {
+        InputSource::File(path) => {
+            limits
+                .check_file("input file", path)
+                .map_err(|e| CliError::ValidationError(e.to_string()))?;
+            std::fs::read_to_string(path)
+                .map_err(|e| CliError::FileReadError(path.clone(), e.to_string()))?
         }
-        InputSource::String(content) => Ok(content.clone()),
-    }
+        InputSource::Stdin => limits
+            .read_limited("stdin", io::stdin())
+            .map_err(|e| CliError::InvalidArgument(format!("Failed to read stdin: {}", e)))?,
+        InputSource::String(content) => content.clone(),
+    };
+    limits
+        .check_size("input", content.len() as u64)
+        .map_err(|e| CliError::ValidationError(e.to_string()))?;
+    Ok(content)
+}
+
+/// Detect the format of the input for `odm import auto`.
+///
+/// Returns the detected format and the source to import from. Stdin can only be
+/// read once, so it is replaced by the content that was read.
+pub fn detect_import_format(input: &InputSource) -> Result<(ImportFormat, InputSource), CliError> {
+    let content = load_input(input)?;
+    let (name, confidence) = ImporterRegistry::default()
+        .detect(&content)
+        .ok_or_else(|| {
+            CliError::InvalidArgument(
+                "Could not detect the input format. Please specify it explicitly.".to_string(),
+            )
+        })?;
+
+    let format = match name.as_str() {
+        "sql" => ImportFormat::Sql,
+        "avro" => ImportFormat::Avro,
+        "json-schema" => ImportFormat::JsonSchema,
+        "protobuf" => ImportFormat::Protobuf,
+        "odcs" => ImportFormat::Odcs,
+        "odcl" => ImportFormat::Odcl,
+        other => {
+            return Err(CliError::InvalidArgument(format!(
+                "Detected format '{}' cannot be imported by the CLI",
+                other
+            )));
+        }
+    };
+    eprintln!(
+        "Detected format: {} (confidence {:.0}%)",
+        name,
+        confidence * 100.0
+    );
+
+    let source = match input {
+        InputSource::File(path) => InputSource::File(path.clone()),
+        _ => InputSource::String(content),
+    };
+    Ok((format, source))
 }
 
 /// Convert ColumnData to Column
@@ -286,28 +335,60 @@ pub fn apply_uuid_override(result: &mut ImportResult, uuid_str: &str) -> Result<
     Ok(())
 }
 
+/// SQL files larger than this (in bytes) are imported with the streaming importer
+/// rather than being loaded, which the default resource limits would reject
+const SQL_STREAMING_THRESHOLD: u64 = DEFAULT_MAX_FILE_SIZE;
+
+/// Import a large SQL dump statement-by-statement, reporting progress on stderr
+fn import_sql_streaming(path: &Path, dialect: &str) -> Result<ImportResult, CliError> {
+    let total = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0).max(1);
+    let result = StreamingSqlImporter::new(dialect)
+        .with_progress_interval(10_000)
+        .import_file(path, |progress| {
+            eprint!(
+                "\rStreaming SQL dump: {}% ({} statements, {} tables)",
+                progress.bytes_read * 100 / total,
+                progress.statements_seen,
+                progress.tables_found
+            );
+        })
+        .map_err(CliError::ImportError)?;
+    eprintln!();
+    Ok(result)
+}
+
 /// Handle SQL import command
 pub fn handle_import_sql(args: &ImportArgs) -> Result<(), CliError> {
     let dialect = args.dialect.as_ref().ok_or_else(|| {
         CliError::InvalidArgument("--dialect is required for SQL import".to_string())
     })?;
 
-    // Load SQL input
-    let sql_content = load_input(&args.input)?;
-
-    if sql_content.trim().is_empty() {
-        return Err(CliError::InvalidArgument(
-            "No SQL content provided".to_string(),
-        ));
-    }
+    let mut result = match &args.input {
+        // Stream large dump files statement-by-statement
+        InputSource::File(path)
+            if std::fs::metadata(path).is_ok_and(|m| m.len() > SQL_STREAMING_THRESHOLD) =>
+        {
+            import_sql_streaming(path, dialect)?
+        }
+        _ => {
+            // Load SQL input
+            let sql_content = load_input(&args.input)?;
+
+            if sql_content.trim().is_empty() {
+                return Err(CliError::InvalidArgument(
+                    "No SQL content provided".to_string(),
+                ));
+            }
 
-    // Parse SQL
-    let importer = SQLImporter::new(dialect);
-    let mut result = importer.parse(&sql_content).map_err(|e| {
-        CliError::ImportError(data_modelling_core::import::ImportError::ParseError(
-            e.to_string(),
-        ))
-    })?;
+            // Parse SQL
+            let importer = SQLImporter::new(dialect);
+            importer.parse(&sql_content).map_err(|e| {
+                CliError::ImportError(data_modelling_core::import::ImportError::ParseError(
+                    e.to_string(),
+                ))
+            })?
+        }
+    };
 
     // Apply UUID override if provided
     if let Some(ref uuid) = args.uuid_override {
@@ -587,8 +668,16 @@ pub fn handle_import_json_schema(args: &ImportArgs) -> Result<(), CliError> {
         validate_json_schema_internal(&json_content).map_err(CliError::ValidationError)?;
     }
 
-    // Import JSON Schema
-    let importer = JSONSchemaImporter::new();
+    // Import JSON Schema (resolving file $refs relative to the input file)
+    let importer = match &args.input {
+        InputSource::File(path) => match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => {
+                JSONSchemaImporter::new().with_base_dir(dir)
+            }
+            _ => JSONSchemaImporter::new().with_base_dir("."),
+        },
+        _ => JSONSchemaImporter::new(),
+    };
     let mut result = importer
         .import(&json_content)
         .map_err(CliError::ImportError)?;
@@ -8,9 +8,11 @@
 use std::path::PathBuf;
 
 use crate::error::CliError;
+use data_modelling_core::export::ODCSExporter;
 use data_modelling_core::inference::{
-    InferenceConfig, InferredSchema, InferredType, SchemaInferrer, group_similar_schemas,
-    merge_schemas,
+    AMBIGUOUS_CONFIDENCE_THRESHOLD, ClusterConfig, InferenceConfig, InferredSchema, InferredType,
+    SamplingStrategy, SchemaInferrer, TypeConflictPolicy, cluster_schemas,
+    cluster_staged_partitions,
 };
 use data_modelling_core::staging::StagingDb;
 
@@ -22,12 +24,16 @@ pub struct InferenceInferArgs {
     pub partition: Option<String>,
     /// Sample size for inference
     pub sample_size: usize,
+    /// How records are sampled from the staging database
+    pub sampling: SamplingStrategy,
     /// Minimum field frequency (0.0-1.0)
     pub min_frequency: f64,
     /// Maximum depth for nested objects
     pub max_depth: usize,
     /// Enable format detection
     pub detect_formats: bool,
+    /// Policy for fields whose samples disagree on type
+    pub conflict_policy: TypeConflictPolicy,
     /// Output format (json, yaml, json-schema)
     pub format: String,
     /// Output file path (stdout if not provided)
@@ -58,6 +64,14 @@ pub struct InferenceSchemasArgs {
     pub threshold: f64,
     /// Output format (json, table)
     pub format: String,
+    /// Records sampled per partition
+    pub sample_size: usize,
+    /// How records are sampled from each partition
+    pub sampling: SamplingStrategy,
+    /// Cluster individual records instead of whole partitions
+    pub by_record: bool,
+    /// Directory to write one proposed ODCS contract per cluster
+    pub contracts_dir: Option<PathBuf>,
 }
 
 /// Handle the `inference infer` command
@@ -81,16 +95,19 @@ pub fn handle_inference_infer(args: &InferenceInferArgs) -> Result<(), CliError>
         .min_field_frequency(args.min_frequency)
         .detect_formats(args.detect_formats)
         .max_depth(args.max_depth)
+        .conflict_policy(args.conflict_policy)
+        .sampling(args.sampling.clone())
         .build();
 
     eprintln!("Inferring schema from staging database...");
     eprintln!("  Sample size: {}", args.sample_size);
+    eprintln!("  Sampling: {}", args.sampling);
     eprintln!("  Min frequency: {:.0}%", args.min_frequency * 100.0);
     eprintln!("  Format detection: {}", args.detect_formats);
 
     // Get samples from the database
     let samples = db
-        .get_sample(args.sample_size, args.partition.as_deref())
+        .get_sample_with(args.sample_size, args.partition.as_deref(), &args.sampling)
         .map_err(|e| CliError::InferenceError(e.to_string()))?;
 
     if samples.is_empty() {
@@ -106,7 +123,10 @@ pub fn handle_inference_infer(args: &InferenceInferArgs) -> Result<(), CliError>
 
     for sample in &samples {
         if let Ok(value) = serde_json::from_str::<serde_json::Value>(sample) {
-            let _ = inferrer.add_value(&value);
+            let _ = match args.partition {
+                Some(ref partition) => inferrer.add_value_from(&value, partition),
+                None => inferrer.add_value(&value),
+            };
         }
     }
 
@@ -120,6 +140,20 @@ pub fn handle_inference_infer(args: &InferenceInferArgs) -> Result<(), CliError>
     eprintln!("  Records processed: {}", stats.records_processed);
     eprintln!("  Fields discovered: {}", stats.fields_discovered);
 
+    // Low-confidence fields are listed first for LLM refinement
+    let priority_fields: Vec<String> = schema
+        .ambiguous_fields(AMBIGUOUS_CONFIDENCE_THRESHOLD)
+        .into_iter()
+        .map(|(path, confidence)| format!("{} ({})", path, confidence))
+        .collect();
+    eprintln!("  Ambiguous fields: {}", priority_fields.len());
+    if !schema.conflicts.is_empty() {
+        eprintln!("  Type conflicts: {}", schema.conflicts.len());
+        for conflict in &schema.conflicts {
+            eprintln!("    {}", conflict);
+        }
+    }
+
     // Convert to JSON Schema for potential LLM refinement
     let json_schema = schema.to_json_schema();
 
@@ -127,7 +161,7 @@ pub fn handle_inference_infer(args: &InferenceInferArgs) -> Result<(), CliError>
     let final_schema = if !args.no_refine && args.llm_mode != "none" {
         #[cfg(feature = "llm")]
         {
-            refine_with_llm(args, &json_schema, &samples)?
+            refine_with_llm(args, &json_schema, &samples, priority_fields)?
         }
         #[cfg(not(feature = "llm"))]
         {
@@ -169,6 +203,7 @@ fn refine_with_llm(
     args: &InferenceInferArgs,
     schema: &serde_json::Value,
     samples: &[String],
+    priority_fields: Vec<String>,
 ) -> Result<serde_json::Value, CliError> {
     use data_modelling_core::llm::{LlmClient, LlmMode, RefinementConfig, refine_schema};
 
@@ -217,6 +252,7 @@ fn refine_with_llm(
         include_samples: true,
         max_samples: 5,
         verbose: args.verbose_llm,
+        priority_fields,
     };
 
     if let Some(ref doc_path) = args.doc_path {
@@ -338,75 +374,68 @@ pub fn handle_inference_schemas(args: &InferenceSchemasArgs) -> Result<(), CliEr
     );
 
     let config = InferenceConfig::builder()
-        .sample_size(100)
+        .sample_size(args.sample_size)
+        .sampling(args.sampling.clone())
         .detect_formats(true)
         .build();
+    let cluster_config = ClusterConfig::with_threshold(args.threshold);
 
-    let mut partition_schemas: Vec<InferredSchema> = Vec::new();
+    let clusters = if args.by_record {
+        // Split partitions carrying several message types into one cluster per shape
+        cluster_staged_partitions(&db, args.sample_size, &config, &cluster_config)
+            .map_err(|e| CliError::InferenceError(e.to_string()))?
+    } else {
+        let mut partition_schemas: Vec<InferredSchema> = Vec::new();
 
-    for (partition, count) in &partition_stats {
-        let samples = db
-            .get_sample(100, Some(partition))
-            .map_err(|e| CliError::InferenceError(e.to_string()))?;
+        for (partition, count) in &partition_stats {
+            let samples = db
+                .get_sample_with(args.sample_size, Some(partition), &args.sampling)
+                .map_err(|e| CliError::InferenceError(e.to_string()))?;
 
-        if samples.is_empty() {
-            continue;
-        }
+            if samples.is_empty() {
+                continue;
+            }
 
-        let mut inferrer = SchemaInferrer::with_config(config.clone());
-        for sample in &samples {
-            if let Ok(value) = serde_json::from_str::<serde_json::Value>(sample) {
-                let _ = inferrer.add_value(&value);
+            let mut inferrer = SchemaInferrer::with_config(config.clone());
+            for sample in &samples {
+                if let Ok(value) = serde_json::from_str::<serde_json::Value>(sample) {
+                    let _ = inferrer.add_value(&value);
+                }
             }
-        }
 
-        match inferrer.finalize() {
-            Ok(mut schema) => {
-                schema.partition = Some(partition.clone());
-                schema.record_count = *count as usize;
-                partition_schemas.push(schema);
+            match inferrer.finalize() {
+                Ok(mut schema) => {
+                    schema.partition = Some(partition.clone());
+                    schema.record_count = *count as usize;
+                    partition_schemas.push(schema);
+                }
+                Err(_) => continue,
             }
-            Err(_) => continue,
         }
-    }
 
-    if partition_schemas.is_empty() {
+        cluster_schemas(partition_schemas, &cluster_config)
+    };
+
+    if clusters.is_empty() {
         return Err(CliError::InferenceError(
             "Could not infer schemas from any partition.".to_string(),
         ));
     }
 
-    // Group similar schemas
-    let groups = group_similar_schemas(&partition_schemas, args.threshold);
-
     match args.format.as_str() {
         "json" => {
-            let output: Vec<serde_json::Value> = groups
+            let output: Vec<serde_json::Value> = clusters
                 .iter()
                 .enumerate()
-                .map(|(i, group)| {
-                    let partitions: Vec<&str> = group
-                        .iter()
-                        .filter_map(|&idx| partition_schemas[idx].partition.as_deref())
-                        .collect();
-                    let total_records: usize = group
-                        .iter()
-                        .map(|&idx| partition_schemas[idx].record_count)
-                        .sum();
-
-                    // Merge schemas in this group
-                    let schemas_to_merge: Vec<_> = group
-                        .iter()
-                        .map(|&idx| partition_schemas[idx].clone())
-                        .collect();
-                    let merged = merge_schemas(schemas_to_merge);
-
+                .map(|(i, cluster)| {
                     serde_json::json!({
                         "group": i + 1,
-                        "partitions": partitions,
-                        "partition_count": group.len(),
-                        "total_records": total_records,
-                        "schema": merged
+                        "name": cluster.name,
+                        "partitions": cluster.partitions,
+                        "partition_count": cluster.partitions.len(),
+                        "shape_count": cluster.shape_count,
+                        "total_records": cluster.record_count,
+                        "schema": cluster.schema
                     })
                 })
                 .collect();
@@ -422,39 +451,26 @@ pub fn handle_inference_schemas(args: &InferenceSchemasArgs) -> Result<(), CliEr
             println!("{}", "=".repeat(50));
             println!();
 
-            for (i, group) in groups.iter().enumerate() {
-                let partitions: Vec<&str> = group
-                    .iter()
-                    .filter_map(|&idx| partition_schemas[idx].partition.as_deref())
-                    .collect();
-                let total_records: usize = group
-                    .iter()
-                    .map(|&idx| partition_schemas[idx].record_count)
-                    .sum();
-
+            for (i, cluster) in clusters.iter().enumerate() {
                 println!(
-                    "Group {} ({} partitions, {} records):",
+                    "Group {} '{}' ({} partitions, {} records):",
                     i + 1,
-                    group.len(),
-                    total_records
+                    cluster.name,
+                    cluster.partitions.len(),
+                    cluster.record_count
                 );
-                for partition in partitions {
+                for partition in &cluster.partitions {
                     println!("  - {}", partition);
                 }
 
-                // Show field summary from first schema in group
-                if let Some(&first_idx) = group.first() {
-                    if let InferredType::Object { ref properties } =
-                        partition_schemas[first_idx].root
-                    {
-                        println!("  Fields: {}", properties.len());
-                        for (name, field) in properties.iter().take(5) {
-                            let required = if field.required { "" } else { " (optional)" };
-                            println!("    - {}: {:?}{}", name, field.field_type, required);
-                        }
-                        if properties.len() > 5 {
-                            println!("    ... and {} more fields", properties.len() - 5);
-                        }
+                if let InferredType::Object { ref properties } = cluster.schema.root {
+                    println!("  Fields: {}", properties.len());
+                    for (name, field) in properties.iter().take(5) {
+                        let required = if field.required { "" } else { " (optional)" };
+                        println!("    - {}: {:?}{}", name, field.field_type, required);
+                    }
+                    if properties.len() > 5 {
+                        println!("    ... and {} more fields", properties.len() - 5);
                     }
                 }
                 println!();
@@ -462,11 +478,23 @@ pub fn handle_inference_schemas(args: &InferenceSchemasArgs) -> Result<(), CliEr
 
             println!(
                 "Total: {} groups from {} partitions",
-                groups.len(),
+                clusters.len(),
                 partition_stats.len()
             );
         }
     }
 
+    // Propose one contract per cluster
+    if let Some(ref dir) = args.contracts_dir {
+        std::fs::create_dir_all(dir).map_err(|e| CliError::InferenceError(e.to_string()))?;
+        for cluster in &clusters {
+            let contract = cluster.to_contract("0.1.0");
+            let path = dir.join(format!("{}.odcs.yaml", cluster.name));
+            std::fs::write(&path, ODCSExporter::export_contract(&contract))
+                .map_err(|e| CliError::InferenceError(e.to_string()))?;
+            eprintln!("Proposed contract written to: {}", path.display());
+        }
+    }
+
     Ok(())
 }
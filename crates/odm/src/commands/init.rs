@@ -0,0 +1,97 @@
+//! Workspace init CLI command
+//!
+//! Scaffolds a new workspace directory from a built-in or custom template.
+
+use crate::error::CliError;
+use data_modelling_core::models::Workspace;
+use data_modelling_core::models::workspace_template::{TemplateDefinition, WorkspaceTemplate};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the `init` command
+#[derive(Debug)]
+pub struct InitArgs {
+    /// Directory to create the workspace in
+    pub path: PathBuf,
+    /// Template to scaffold from
+    pub template: WorkspaceTemplate,
+    /// Custom template definition file, used instead of `template`
+    pub template_file: Option<PathBuf>,
+    /// Workspace name (default: directory name)
+    pub name: Option<String>,
+    /// Overwrite existing files
+    pub force: bool,
+}
+
+/// Handle the `init` command
+pub fn handle_init(args: &InitArgs) -> Result<(), CliError> {
+    let name = match &args.name {
+        Some(name) => name.clone(),
+        None => default_name(&args.path)?,
+    };
+
+    let template = match &args.template_file {
+        Some(path) => TemplateDefinition::from_file(path)
+            .map_err(|e| CliError::ParseError(format!("Failed to load template: {}", e)))?,
+        None => args.template.definition(),
+    };
+    let scaffold = Workspace::from_template(&name, &template)
+        .map_err(|e| CliError::ParseError(format!("Failed to scaffold workspace: {}", e)))?;
+
+    if !args.force {
+        let existing: Vec<String> = scaffold
+            .files
+            .iter()
+            .map(|file| args.path.join(&file.path))
+            .filter(|path| path.exists())
+            .map(|path| path.display().to_string())
+            .collect();
+        if !existing.is_empty() {
+            return Err(CliError::InvalidArgument(format!(
+                "Refusing to overwrite existing files (use --force): {}",
+                existing.join(", ")
+            )));
+        }
+    }
+
+    fs::create_dir_all(&args.path).map_err(|e| {
+        CliError::IoError(format!("Failed to create {}: {}", args.path.display(), e))
+    })?;
+    for file in &scaffold.files {
+        let path = args.path.join(&file.path);
+        fs::write(&path, &file.content)
+            .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+        println!("Created: {}", path.display());
+    }
+
+    println!(
+        "Initialized {} workspace '{}' with {} domain(s) in {}",
+        template.name,
+        name,
+        scaffold.workspace.domains.len(),
+        args.path.display()
+    );
+    Ok(())
+}
+
+/// Workspace name derived from the target directory
+fn default_name(path: &Path) -> Result<String, CliError> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map_err(|e| CliError::IoError(e.to_string()))?
+            .join(path)
+    };
+    absolute
+        .components()
+        .next_back()
+        .and_then(|c| c.as_os_str().to_str())
+        .filter(|name| !name.is_empty() && *name != "." && *name != "/")
+        .map(str::to_string)
+        .ok_or_else(|| {
+            CliError::InvalidArgument(
+                "Cannot derive a workspace name from the path; pass --name".to_string(),
+            )
+        })
+}
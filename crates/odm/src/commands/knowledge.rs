@@ -113,7 +113,7 @@ pub fn handle_knowledge_new(args: &KnowledgeNewArgs) -> Result<(), CliError> {
     let mut index = if index_path.exists() {
         let content = fs::read_to_string(&index_path)
             .map_err(|e| CliError::IoError(format!("Failed to read knowledge.yaml: {}", e)))?;
-        let importer = KnowledgeImporter;
+        let importer = KnowledgeImporter::new();
         importer
             .import_index(&content)
             .map_err(|e| CliError::ParseError(format!("Failed to parse knowledge.yaml: {}", e)))?
@@ -572,7 +572,7 @@ fn parse_article_number(s: &str) -> Result<u64, CliError> {
 }
 
 fn load_all_articles(workspace: &Path) -> Result<Vec<KnowledgeArticle>, CliError> {
-    let importer = KnowledgeImporter;
+    let importer = KnowledgeImporter::new();
     let mut articles = Vec::new();
 
     for entry in fs::read_dir(workspace)
@@ -616,7 +616,7 @@ fn find_article_file_and_load(
     workspace: &Path,
     number: u64,
 ) -> Result<(PathBuf, KnowledgeArticle), CliError> {
-    let importer = KnowledgeImporter;
+    let importer = KnowledgeImporter::new();
 
     for entry in fs::read_dir(workspace)
         .map_err(|e| CliError::IoError(format!("Failed to read workspace: {}", e)))?
@@ -651,7 +651,7 @@ fn update_article_in_index(index_path: &Path, article: &KnowledgeArticle) -> Res
     let content = fs::read_to_string(index_path)
         .map_err(|e| CliError::IoError(format!("Failed to read index: {}", e)))?;
 
-    let importer = KnowledgeImporter;
+    let importer = KnowledgeImporter::new();
     let mut index = importer
         .import_index(&content)
         .map_err(|e| CliError::ParseError(format!("Failed to parse index: {}", e)))?;
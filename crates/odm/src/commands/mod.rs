@@ -1,9 +1,16 @@
 //! CLI command implementations
 
+pub mod annotate;
+pub mod capabilities;
+pub mod classify;
+pub mod config;
 pub mod decision;
 pub mod export;
+pub mod generate;
 pub mod import;
+pub mod init;
 pub mod knowledge;
+pub mod readme;
 pub mod validate;
 
 #[cfg(feature = "duckdb-backend")]
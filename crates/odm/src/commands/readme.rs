@@ -0,0 +1,162 @@
+//! Domain README CLI command
+//!
+//! Generates or refreshes a `README.md` per domain of a workspace. Only the managed
+//! block of each README is rewritten; text outside it is kept.
+
+use crate::error::CliError;
+use data_modelling_core::export::readme::{DomainReadmeGenerator, merge_managed_block};
+use data_modelling_core::import::decision::DecisionImporter;
+use data_modelling_core::import::odcs::ODCSImporter;
+use data_modelling_core::import::odps::ODPSImporter;
+use data_modelling_core::models::Table;
+use data_modelling_core::models::decision::Decision;
+use data_modelling_core::models::odps::ODPSDataProduct;
+use data_modelling_core::models::workspace::Workspace;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the `readme` command
+#[derive(Debug)]
+pub struct ReadmeArgs {
+    /// Workspace path
+    pub workspace: PathBuf,
+    /// Directory the domain folders are written to (default: workspace)
+    pub output: Option<PathBuf>,
+    /// Maximum number of recent decisions listed per domain
+    pub recent_decisions: usize,
+    /// Only check that READMEs are up to date, without writing
+    pub check: bool,
+}
+
+/// Handle the `readme` command
+pub fn handle_readme(args: &ReadmeArgs) -> Result<(), CliError> {
+    let workspace_file = args.workspace.join("workspace.yaml");
+    let content = fs::read_to_string(&workspace_file)
+        .map_err(|e| CliError::FileReadError(workspace_file.clone(), e.to_string()))?;
+    let workspace = Workspace::from_yaml(&content)
+        .map_err(|e| CliError::ParseError(format!("Failed to parse workspace.yaml: {}", e)))?;
+
+    let assets = load_workspace_assets(&args.workspace)?;
+
+    let readmes = DomainReadmeGenerator::new()
+        .with_recent_decisions(args.recent_decisions)
+        .generate_for_workspace(
+            &workspace,
+            &assets.tables,
+            &assets.products,
+            &assets.decisions,
+        );
+    if readmes.is_empty() {
+        println!("No domains found in {}", workspace_file.display());
+        return Ok(());
+    }
+
+    let output_dir = args.output.as_ref().unwrap_or(&args.workspace);
+    let mut outdated = Vec::new();
+    for readme in &readmes {
+        let path = output_dir
+            .join(domain_dir_name(&readme.domain))
+            .join("README.md");
+        let existing = fs::read_to_string(&path).ok();
+        let merged = merge_managed_block(existing.as_deref(), &readme.content);
+        if existing.as_deref() == Some(merged.as_str()) {
+            continue;
+        }
+
+        if args.check {
+            outdated.push(path.display().to_string());
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                CliError::IoError(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        fs::write(&path, merged)
+            .map_err(|e| CliError::FileWriteError(path.clone(), e.to_string()))?;
+        println!("Updated: {}", path.display());
+    }
+
+    if !outdated.is_empty() {
+        return Err(CliError::ValidationError(format!(
+            "Domain READMEs are out of date (run `odm readme`): {}",
+            outdated.join(", ")
+        )));
+    }
+
+    println!("{} domain README(s) up to date", readmes.len());
+    Ok(())
+}
+
+/// Assets summarized in domain READMEs
+#[derive(Default)]
+struct WorkspaceAssets {
+    tables: Vec<Table>,
+    products: Vec<ODPSDataProduct>,
+    decisions: Vec<Decision>,
+}
+
+/// Load the tables, data products and decisions stored in a workspace directory
+fn load_workspace_assets(workspace: &Path) -> Result<WorkspaceAssets, CliError> {
+    let mut assets = WorkspaceAssets::default();
+
+    let entries = fs::read_dir(workspace)
+        .map_err(|e| CliError::IoError(format!("Failed to read workspace: {}", e)))?;
+    for entry in entries {
+        let path = entry.map_err(|e| CliError::IoError(e.to_string()))?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !path.is_file() {
+            continue;
+        }
+
+        let result = if name.ends_with(".odcs.yaml") {
+            read(&path).and_then(|content| {
+                ODCSImporter::new()
+                    .parse_table(&content)
+                    .map(|(table, _)| assets.tables.push(table))
+                    .map_err(|e| e.to_string())
+            })
+        } else if name.ends_with(".odps.yaml") {
+            read(&path).and_then(|content| {
+                ODPSImporter::new()
+                    .import(&content)
+                    .map(|product| assets.products.push(product))
+                    .map_err(|e| e.to_string())
+            })
+        } else if name.ends_with(".madr.yaml") {
+            read(&path).and_then(|content| {
+                DecisionImporter::new()
+                    .import(&content)
+                    .map(|decision| assets.decisions.push(decision))
+                    .map_err(|e| e.to_string())
+            })
+        } else {
+            continue;
+        };
+
+        if let Err(e) = result {
+            eprintln!("Warning: Failed to load {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(assets)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+/// Directory name for a domain's README
+fn domain_dir_name(domain: &str) -> String {
+    domain
+        .trim()
+        .chars()
+        .map(|c| match c {
+            ' ' | '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '-',
+            _ => c,
+        })
+        .collect::<String>()
+        .to_lowercase()
+}
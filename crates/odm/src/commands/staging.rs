@@ -8,7 +8,12 @@
 use std::path::PathBuf;
 
 use crate::error::CliError;
-use data_modelling_core::staging::{DedupStrategy, IngestConfig, SourceType, StagingDb};
+use data_modelling_core::models::odcs::ODCSContract;
+use data_modelling_core::quality::QualityRunner;
+use data_modelling_core::staging::{
+    BenchReport, DedupStrategy, IngestConfig, PurgePolicy, RecordDedup, SCHEMA_VERSION, SourceType,
+    StagingBench, StagingDb, dedup_name,
+};
 
 /// Arguments for the `staging init` command
 pub struct StagingInitArgs {
@@ -34,7 +39,7 @@ pub struct StagingInitArgs {
 pub struct StagingIngestArgs {
     /// Path to the staging database file
     pub database: PathBuf,
-    /// Source path to ingest from
+    /// Source path or `s3://` URL to ingest from
     pub source: PathBuf,
     /// File pattern to match (e.g., "*.json", "**/*.jsonl")
     pub pattern: String,
@@ -44,10 +49,20 @@ pub struct StagingIngestArgs {
     pub dedup: DedupStrategy,
     /// Batch size for inserts
     pub batch_size: usize,
+    /// Number of files read and parsed in parallel (default: 4)
+    pub workers: Option<usize>,
     /// Resume a previous batch
     pub resume: bool,
     /// Batch ID for resume
     pub batch_id: Option<String>,
+    /// Discriminator JSONPath used to split records into sub-partitions
+    pub split_by: Option<String>,
+    /// XSD file used to conform XML records
+    pub xml_schema: Option<PathBuf>,
+    /// Record-level deduplication
+    pub record_dedup: Option<RecordDedup>,
+    /// Contract or JSON Schema file to validate records against
+    pub record_schema: Option<PathBuf>,
 }
 
 /// Arguments for the `staging stats` command
@@ -66,6 +81,16 @@ pub struct StagingBatchesArgs {
     pub limit: usize,
 }
 
+/// Arguments for the `staging purge` command
+pub struct StagingPurgeArgs {
+    /// Path to the staging database file
+    pub database: PathBuf,
+    /// Staged data to delete
+    pub policy: PurgePolicy,
+    /// Reclaim the space of deleted rows afterwards
+    pub vacuum: bool,
+}
+
 /// Arguments for the `staging query` command
 pub struct StagingQueryArgs {
     /// Path to the staging database file
@@ -90,6 +115,40 @@ pub struct StagingSampleArgs {
     pub partition: Option<String>,
 }
 
+/// Arguments for the `staging quality` command
+pub struct StagingQualityArgs {
+    /// Path to the staging database file
+    pub database: PathBuf,
+    /// ODCS contract whose quality rules to run
+    pub contract: PathBuf,
+    /// Partition to check
+    pub partition: Option<String>,
+    /// Failing rows sampled per failed rule
+    pub samples: usize,
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// Arguments for the `staging bench` command
+pub struct StagingBenchArgs {
+    /// Synthetic records per run
+    pub records: usize,
+    /// Workload files per run
+    pub files: usize,
+    /// Batch sizes to compare
+    pub batch_sizes: Vec<usize>,
+    /// Deduplication strategies to compare
+    pub dedup: Vec<DedupStrategy>,
+    /// Connection string of a scratch PostgreSQL database
+    pub postgres: Option<String>,
+    /// Skip the DuckDB benchmark
+    pub skip_duckdb: bool,
+    /// Directory for the workload files
+    pub work_dir: Option<PathBuf>,
+    /// Output format (text, json, markdown)
+    pub format: String,
+}
+
 /// Arguments for the `staging history` command
 pub struct StagingHistoryArgs {
     /// Path to the staging database file
@@ -157,7 +216,17 @@ pub fn handle_staging_init(args: &StagingInitArgs) -> Result<(), CliError> {
         let version = db
             .schema_version()
             .map_err(|e| CliError::StagingError(e.to_string()))?;
-        println!("Schema version: {}", version);
+        if version < SCHEMA_VERSION {
+            // The DDL only creates missing tables and columns
+            db.init()
+                .map_err(|e| CliError::StagingError(e.to_string()))?;
+            println!(
+                "Schema upgraded from version {} to {}",
+                version, SCHEMA_VERSION
+            );
+        } else {
+            println!("Schema version: {}", version);
+        }
     } else {
         db.init()
             .map_err(|e| CliError::StagingError(e.to_string()))?;
@@ -301,9 +370,17 @@ pub fn handle_staging_ingest(args: &StagingIngestArgs) -> Result<(), CliError> {
 
     let db = StagingDb::open(&db_path).map_err(|e| CliError::StagingError(e.to_string()))?;
 
+    // S3 URLs need the s3 feature; anything else is a local directory
+    let source = args.source.display().to_string();
+    let source_type = if source.starts_with("s3://") {
+        SourceType::parse(&source).map_err(CliError::InvalidArgument)?
+    } else {
+        SourceType::Local(args.source.clone())
+    };
+
     // Build the ingest configuration
     let mut config_builder = IngestConfig::builder()
-        .source_type(SourceType::Local(args.source.clone()))
+        .source_type(source_type)
         .pattern(&args.pattern)
         .dedup(args.dedup)
         .batch_size(args.batch_size)
@@ -317,6 +394,26 @@ pub fn handle_staging_ingest(args: &StagingIngestArgs) -> Result<(), CliError> {
         config_builder = config_builder.batch_id(batch_id);
     }
 
+    if let Some(workers) = args.workers {
+        config_builder = config_builder.workers(workers);
+    }
+
+    if let Some(ref split_by) = args.split_by {
+        config_builder = config_builder.split_by(split_by);
+    }
+
+    if let Some(ref xml_schema) = args.xml_schema {
+        config_builder = config_builder.xml_schema(xml_schema);
+    }
+
+    if let Some(ref record_dedup) = args.record_dedup {
+        config_builder = config_builder.record_dedup(record_dedup.clone());
+    }
+
+    if let Some(ref record_schema) = args.record_schema {
+        config_builder = config_builder.record_schema(record_schema);
+    }
+
     let config = config_builder
         .build()
         .map_err(|e| CliError::StagingError(e.to_string()))?;
@@ -334,12 +431,33 @@ pub fn handle_staging_ingest(args: &StagingIngestArgs) -> Result<(), CliError> {
     println!("  Files processed: {}", stats.files_processed);
     println!("  Files skipped:   {}", stats.files_skipped);
     println!("  Records ingested: {}", stats.records_ingested);
+    if args.record_dedup.is_some() {
+        println!("  Records deduplicated: {}", stats.records_deduplicated);
+    }
+    if args.record_schema.is_some() {
+        println!("  Records rejected: {}", stats.records_rejected);
+        println!("  Conformance: {:.1}%", stats.conformance_rate() * 100.0);
+    }
     println!(
         "  Bytes processed: {} MB",
         stats.bytes_processed / 1_000_000
     );
+    if stats.bytes_decompressed != stats.bytes_processed {
+        println!(
+            "  Bytes decompressed: {} MB",
+            stats.bytes_decompressed / 1_000_000
+        );
+    }
     println!("  Duration: {}", stats.duration_string());
 
+    if !stats.partition_counts.is_empty() {
+        println!();
+        println!("Records by partition:");
+        for (partition, count) in &stats.partition_counts {
+            println!("  {}: {}", partition, count);
+        }
+    }
+
     if !stats.errors.is_empty() {
         println!();
         println!("Errors ({}):", stats.errors.len());
@@ -446,6 +564,17 @@ pub fn handle_staging_batches(args: &StagingBatchesArgs) -> Result<(), CliError>
             batch.files_processed, batch.files_skipped, batch.files_total
         );
         println!("  Records: {}", batch.records_ingested);
+        // Databases created before schema version 2 have no dead-letter table
+        let rejected = db.dead_letter_count(Some(&batch.id)).unwrap_or(0);
+        if rejected > 0 {
+            let conformance =
+                batch.records_ingested as f64 / (batch.records_ingested + rejected) as f64;
+            println!(
+                "  Rejected: {} ({:.1}% conformance)",
+                rejected,
+                conformance * 100.0
+            );
+        }
         if let Some(started) = batch.started_at {
             println!("  Started: {}", started.format("%Y-%m-%d %H:%M:%S"));
         }
@@ -464,6 +593,40 @@ pub fn handle_staging_batches(args: &StagingBatchesArgs) -> Result<(), CliError>
     Ok(())
 }
 
+/// Handle the `staging purge` command
+pub fn handle_staging_purge(args: &StagingPurgeArgs) -> Result<(), CliError> {
+    let db_path = args.database.display().to_string();
+
+    let db = StagingDb::open(&db_path).map_err(|e| CliError::StagingError(e.to_string()))?;
+
+    if !db
+        .is_initialized()
+        .map_err(|e| CliError::StagingError(e.to_string()))?
+    {
+        return Err(CliError::StagingError(
+            "Database not initialized. Run 'staging init' first.".to_string(),
+        ));
+    }
+
+    let stats = db
+        .purge(&args.policy)
+        .map_err(|e| CliError::StagingError(e.to_string()))?;
+
+    println!("Purge complete:");
+    println!("  Records deleted: {}", stats.records_deleted);
+    println!("  Dead letters deleted: {}", stats.dead_letters_deleted);
+    println!("  Batches deleted: {}", stats.batches_deleted);
+    println!("  Inferred schemas deleted: {}", stats.schemas_deleted);
+
+    if args.vacuum {
+        db.vacuum()
+            .map_err(|e| CliError::StagingError(e.to_string()))?;
+        println!("Database vacuumed");
+    }
+
+    Ok(())
+}
+
 /// Handle the `staging query` command
 pub fn handle_staging_query(args: &StagingQueryArgs) -> Result<(), CliError> {
     let db_path = args.database.display().to_string();
@@ -637,6 +800,178 @@ pub fn handle_staging_sample(args: &StagingSampleArgs) -> Result<(), CliError> {
     Ok(())
 }
 
+/// Handle the `staging quality` command
+pub fn handle_staging_quality(args: &StagingQualityArgs) -> Result<(), CliError> {
+    let content = std::fs::read_to_string(&args.contract)
+        .map_err(|e| CliError::FileReadError(args.contract.clone(), e.to_string()))?;
+    let contract: ODCSContract = serde_yaml::from_str(&content)
+        .map_err(|e| CliError::ParseError(format!("Failed to parse contract: {}", e)))?;
+
+    let db_path = args.database.display().to_string();
+    let db = StagingDb::open(&db_path).map_err(|e| CliError::StagingError(e.to_string()))?;
+    if !db
+        .is_initialized()
+        .map_err(|e| CliError::StagingError(e.to_string()))?
+    {
+        return Err(CliError::StagingError(
+            "Database not initialized. Run 'staging init' first.".to_string(),
+        ));
+    }
+
+    let mut runner = QualityRunner::new().with_sample_limit(args.samples);
+    if let Some(ref partition) = args.partition {
+        runner = runner.with_partition(partition);
+    }
+    let report = runner.run(&db, &contract);
+
+    match args.format.as_str() {
+        "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&report)
+                .map_err(|e| CliError::SerializationError(e.to_string()))?
+        ),
+        _ => {
+            for result in &report.results {
+                println!("{}", result);
+                for sample in &result.samples {
+                    println!("    {}", sample);
+                }
+            }
+            println!();
+            println!("{}", report.summary());
+        }
+    }
+
+    let failures = report.failures().count();
+    if failures > 0 {
+        return Err(CliError::ValidationError(format!(
+            "{} quality rules failed",
+            failures
+        )));
+    }
+    Ok(())
+}
+
+/// Handle the `staging bench` command
+pub fn handle_staging_bench(args: &StagingBenchArgs) -> Result<(), CliError> {
+    if args.skip_duckdb && args.postgres.is_none() {
+        return Err(CliError::InvalidArgument(
+            "Nothing to benchmark: --skip-duckdb requires --postgres".to_string(),
+        ));
+    }
+
+    let bench = StagingBench::new()
+        .with_records(args.records)
+        .with_files(args.files)
+        .with_batch_sizes(args.batch_sizes.clone())
+        .with_dedup_strategies(args.dedup.clone());
+
+    // Workload files go to a temporary directory unless one is given
+    let (work_dir, temporary) = match args.work_dir {
+        Some(ref dir) => (dir.clone(), false),
+        None => (
+            std::env::temp_dir().join(format!("odm-bench-{}", uuid::Uuid::new_v4())),
+            true,
+        ),
+    };
+    let result = run_bench(&bench, args, &work_dir);
+    if temporary {
+        let _ = std::fs::remove_dir_all(&work_dir);
+    }
+    let report = result?;
+
+    match args.format.as_str() {
+        "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&report)
+                .map_err(|e| CliError::SerializationError(e.to_string()))?
+        ),
+        "markdown" => print!("{}", report.to_markdown()),
+        _ => {
+            println!(
+                "Workload: {} records in {} files ({} duplicates, {} bytes) per run",
+                report.workload.records,
+                report.workload.files,
+                report.workload.duplicate_files,
+                report.workload.bytes
+            );
+            println!();
+            for result in &report.results {
+                println!(
+                    "{:<10} batch {:>6}  dedup {:<8} {:>12.0} records/s  {:>8.1} queries/s  ({} files skipped)",
+                    result.backend.to_string(),
+                    result.batch_size,
+                    dedup_name(result.dedup),
+                    result.ingest_rate(),
+                    result.query_rate(),
+                    result.files_skipped
+                );
+            }
+            println!();
+            println!("Recommendations:");
+            for recommendation in &report.recommendations {
+                println!("  - {}", recommendation);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run the requested backends of a benchmark
+fn run_bench(
+    bench: &StagingBench,
+    args: &StagingBenchArgs,
+    work_dir: &std::path::Path,
+) -> Result<BenchReport, CliError> {
+    let mut workload = Default::default();
+    let mut results = Vec::new();
+
+    if !args.skip_duckdb {
+        println!("Benchmarking DuckDB ({} runs)...", bench.runs().len());
+        let (run_workload, run_results) = bench
+            .run_duckdb(&work_dir.join("duckdb"))
+            .map_err(|e| CliError::StagingError(e.to_string()))?;
+        workload = run_workload;
+        results.extend(run_results);
+    }
+
+    if let Some(ref connection_string) = args.postgres {
+        #[cfg(feature = "staging-postgres")]
+        {
+            use data_modelling_core::staging::StagingDbPostgres;
+
+            println!("Benchmarking PostgreSQL ({} runs)...", bench.runs().len());
+            let rt = tokio::runtime::Runtime::new()
+                .map_err(|e| CliError::StagingError(format!("Failed to start runtime: {}", e)))?;
+            let (run_workload, run_results) = rt
+                .block_on(async {
+                    let db = StagingDbPostgres::connect(connection_string)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    db.init().await.map_err(|e| e.to_string())?;
+                    bench
+                        .run_postgres(&db, &work_dir.join("postgres"))
+                        .await
+                        .map_err(|e| e.to_string())
+                })
+                .map_err(CliError::StagingError)?;
+            workload = run_workload;
+            results.extend(run_results);
+        }
+
+        #[cfg(not(feature = "staging-postgres"))]
+        {
+            let _ = connection_string;
+            return Err(CliError::InvalidArgument(
+                "PostgreSQL support not enabled. Enable 'staging-postgres' feature to benchmark PostgreSQL."
+                    .to_string(),
+            ));
+        }
+    }
+
+    Ok(BenchReport::new(workload, results))
+}
+
 /// Handle the `staging history` command
 pub fn handle_staging_history(args: &StagingHistoryArgs) -> Result<(), CliError> {
     #[cfg(feature = "iceberg")]
@@ -1,6 +1,8 @@
 //! Validate command implementation
 
 use crate::error::CliError;
+use data_modelling_core::models::odcs::ODCSContract;
+use data_modelling_core::models::validate_cdc;
 use data_modelling_core::validation::schema::{
     validate_avro_internal, validate_cads_internal, validate_decision_internal,
     validate_decisions_index_internal, validate_json_schema_internal,
@@ -8,27 +10,44 @@ use data_modelling_core::validation::schema::{
     validate_odcs_internal, validate_odps_internal, validate_openapi_internal,
     validate_protobuf_internal, validate_sql_internal,
 };
-use std::io::Read;
-use std::path::PathBuf;
+use data_modelling_core::validation::{
+    CustomPropertyRegistry, ResourceLimits, validate_backward_compatible,
+};
+use std::path::{Path, PathBuf};
 
-/// Load input content from file or stdin
+/// Load input content from file or stdin, within the default size limit
 fn load_input(input: &str) -> Result<String, CliError> {
-    if input == "-" {
+    let limits = ResourceLimits::default();
+    let content = if input == "-" {
         // Read from stdin
-        let mut content = String::new();
-        std::io::stdin()
-            .read_to_string(&mut content)
-            .map_err(|e| CliError::InvalidArgument(format!("Failed to read stdin: {}", e)))?;
-        Ok(content)
+        limits
+            .read_limited("stdin", std::io::stdin())
+            .map_err(|e| CliError::InvalidArgument(format!("Failed to read stdin: {}", e)))?
     } else {
         // Read from file
         let path = PathBuf::from(input);
-        std::fs::read_to_string(&path).map_err(|e| CliError::FileReadError(path, e.to_string()))
-    }
+        limits
+            .check_file("input file", &path)
+            .map_err(|e| CliError::ValidationError(e.to_string()))?;
+        std::fs::read_to_string(&path).map_err(|e| CliError::FileReadError(path, e.to_string()))?
+    };
+    limits
+        .check_size("input", content.len() as u64)
+        .map_err(|e| CliError::ValidationError(e.to_string()))?;
+    Ok(content)
 }
 
 /// Handle the validate command
-pub fn handle_validate(format: &str, input: &str) -> Result<(), CliError> {
+///
+/// When a custom property registry is given, ODCS and ODPS `customProperties`
+/// entries are also checked against the registered schemas. When a baseline
+/// ODCS contract is given, the input must be backward compatible with it.
+pub fn handle_validate(
+    format: &str,
+    input: &str,
+    custom_properties: Option<&Path>,
+    baseline: Option<&Path>,
+) -> Result<(), CliError> {
     let content = load_input(input)?;
 
     let result = match format {
@@ -55,6 +74,93 @@ pub fn handle_validate(format: &str, input: &str) -> Result<(), CliError> {
 
     result.map_err(CliError::ValidationError)?;
 
+    if format == "odcs" {
+        validate_cdc_metadata(&content)?;
+    }
+
+    if let Some(registry_path) = custom_properties {
+        if !matches!(format, "odcs" | "odps") {
+            return Err(CliError::InvalidArgument(format!(
+                "--custom-properties is only supported for odcs and odps, not {}",
+                format
+            )));
+        }
+        validate_custom_properties(&content, registry_path)?;
+    }
+
+    if let Some(baseline_path) = baseline {
+        if format != "odcs" {
+            return Err(CliError::InvalidArgument(format!(
+                "--baseline is only supported for odcs, not {}",
+                format
+            )));
+        }
+        validate_compatibility(&content, baseline_path)?;
+    }
+
     println!("Validation successful");
     Ok(())
 }
+
+/// Check that a contract is backward compatible with a baseline contract file
+fn validate_compatibility(content: &str, baseline_path: &Path) -> Result<(), CliError> {
+    let baseline_content = std::fs::read_to_string(baseline_path)
+        .map_err(|e| CliError::FileReadError(baseline_path.to_path_buf(), e.to_string()))?;
+    let parse = |yaml: &str| {
+        serde_yaml::from_str::<ODCSContract>(yaml).map_err(|e| CliError::ParseError(e.to_string()))
+    };
+    let (old_contract, new_contract) = (parse(&baseline_content)?, parse(content)?);
+
+    let Err(errors) = validate_backward_compatible(&old_contract, &new_contract) else {
+        return Ok(());
+    };
+    let details: Vec<String> = errors.iter().map(|e| format!("  - {}", e)).collect();
+    Err(CliError::ValidationError(format!(
+        "{} backward-incompatible change(s):\n{}",
+        errors.len(),
+        details.join("\n")
+    )))
+}
+
+/// Check the CDC metadata of the schema objects against their columns
+///
+/// Contracts the model cannot represent have already passed schema validation
+/// and carry no CDC metadata it could read, so they are not checked.
+fn validate_cdc_metadata(content: &str) -> Result<(), CliError> {
+    let Ok(contract) = serde_yaml::from_str::<ODCSContract>(content) else {
+        return Ok(());
+    };
+    let violations = validate_cdc(&contract.to_tables());
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let details: Vec<String> = violations.iter().map(|v| format!("  - {}", v)).collect();
+    Err(CliError::ValidationError(format!(
+        "{} CDC metadata violation(s):\n{}",
+        violations.len(),
+        details.join("\n")
+    )))
+}
+
+/// Check `customProperties` entries against a registry file
+fn validate_custom_properties(content: &str, registry_path: &Path) -> Result<(), CliError> {
+    let registry_content = std::fs::read_to_string(registry_path)
+        .map_err(|e| CliError::FileReadError(registry_path.to_path_buf(), e.to_string()))?;
+    let registry =
+        CustomPropertyRegistry::from_yaml(&registry_content).map_err(CliError::ParseError)?;
+
+    let violations = registry
+        .validate_yaml(content)
+        .map_err(CliError::ValidationError)?;
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let details: Vec<String> = violations.iter().map(|v| format!("  - {}", v)).collect();
+    Err(CliError::ValidationError(format!(
+        "{} custom property violation(s):\n{}",
+        violations.len(),
+        details.join("\n")
+    )))
+}
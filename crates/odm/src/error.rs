@@ -1,5 +1,6 @@
 //! CLI-specific error types
 
+use data_modelling_core::config::ConfigError;
 use data_modelling_core::export::ExportError;
 use data_modelling_core::import::ImportError;
 use std::path::PathBuf;
@@ -47,6 +48,9 @@ pub enum CliError {
     #[error("Export error: {0}")]
     ExportError(#[from] ExportError),
 
+    #[error("Configuration error: {0}")]
+    ConfigError(#[from] ConfigError),
+
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
 
@@ -6,28 +6,37 @@ mod output;
 mod reference;
 
 use clap::{Parser, Subcommand};
+use commands::annotate::{AnnotateArgs, handle_annotate};
+use commands::capabilities::{CapabilitiesArgs, handle_capabilities};
+use commands::classify::{ClassifyArgs, handle_classify};
+use commands::config::{ConfigArgs, handle_config_path, handle_config_show, load_config};
 #[cfg(feature = "duckdb-backend")]
 use commands::db::{
-    DbExportArgs, DbInitArgs, DbStatusArgs, DbSyncArgs, handle_db_export, handle_db_init,
-    handle_db_status, handle_db_sync,
+    DbExportArgs, DbInitArgs, DbMirrorArgs, DbSearchArgs, DbStatusArgs, DbSyncArgs,
+    handle_db_export, handle_db_init, handle_db_mirror, handle_db_search, handle_db_status,
+    handle_db_sync,
 };
 use commands::export::{
-    ExportArgs, ExportFormat, handle_export_avro, handle_export_branded_markdown,
+    ExportArgs, ExportFormat, export_registry, handle_export_avro, handle_export_branded_markdown,
     handle_export_json_schema, handle_export_markdown, handle_export_odcs, handle_export_odps,
     handle_export_pdf, handle_export_protobuf, handle_export_protobuf_descriptor,
+    handle_export_registered,
 };
+use commands::generate::{GenerateArgs, handle_generate};
 #[cfg(feature = "odps-validation")]
 use commands::import::handle_import_odps;
 #[cfg(feature = "openapi")]
 use commands::import::handle_import_openapi;
 use commands::import::{
-    ImportArgs, ImportFormat, InputSource, handle_import_avro, handle_import_json_schema,
-    handle_import_odcl, handle_import_odcs, handle_import_protobuf, handle_import_sql,
+    ImportArgs, ImportFormat, InputSource, detect_import_format, handle_import_avro,
+    handle_import_json_schema, handle_import_odcl, handle_import_odcs, handle_import_protobuf,
+    handle_import_sql,
 };
 #[cfg(all(feature = "inference", feature = "staging"))]
 use commands::inference::{
     InferenceInferArgs, InferenceSchemasArgs, handle_inference_infer, handle_inference_schemas,
 };
+use commands::init::{InitArgs, handle_init};
 #[cfg(feature = "mapping")]
 use commands::mapping::{MapArgs, handle_map};
 #[cfg(feature = "pipeline")]
@@ -36,17 +45,22 @@ use commands::pipeline::{
 };
 #[cfg(feature = "duckdb-backend")]
 use commands::query::{QueryArgs, handle_query};
+use commands::readme::{ReadmeArgs, handle_readme};
 #[cfg(feature = "staging")]
 use commands::staging::{
-    StagingBatchesArgs, StagingExportArgs, StagingHistoryArgs, StagingIngestArgs, StagingInitArgs,
-    StagingQueryArgs, StagingSampleArgs, StagingStatsArgs, StagingViewCreateArgs,
-    handle_staging_batches, handle_staging_export, handle_staging_history, handle_staging_ingest,
-    handle_staging_init, handle_staging_query, handle_staging_sample, handle_staging_stats,
-    handle_staging_view_create,
+    StagingBatchesArgs, StagingBenchArgs, StagingExportArgs, StagingHistoryArgs, StagingIngestArgs,
+    StagingInitArgs, StagingPurgeArgs, StagingQualityArgs, StagingQueryArgs, StagingSampleArgs,
+    StagingStatsArgs, StagingViewCreateArgs, handle_staging_batches, handle_staging_bench,
+    handle_staging_export, handle_staging_history, handle_staging_ingest, handle_staging_init,
+    handle_staging_purge, handle_staging_quality, handle_staging_query, handle_staging_sample,
+    handle_staging_stats, handle_staging_view_create,
 };
 use commands::validate::handle_validate;
+#[cfg(all(feature = "inference", feature = "staging"))]
+use data_modelling_core::inference::{SamplingStrategy, TypeConflictPolicy};
+use data_modelling_core::models::workspace_template::WorkspaceTemplate;
 #[cfg(feature = "staging")]
-use data_modelling_core::staging::DedupStrategy;
+use data_modelling_core::staging::{DedupStrategy, PurgePolicy, RecordDedup, parse_age};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -54,6 +68,9 @@ use std::path::PathBuf;
 #[command(about = "CLI tool for Open Data Modelling")]
 #[command(version)]
 struct Cli {
+    /// Configuration profile (overrides ODM_PROFILE and the config files)
+    #[arg(long, global = true)]
+    profile: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -68,7 +85,7 @@ enum Commands {
         /// Input source (file path, '-' for stdin, or SQL string). Optional when --jar is provided.
         #[arg(required_unless_present = "jar")]
         input: Option<String>,
-        /// SQL dialect (required for SQL format)
+        /// SQL dialect (default: defaults.sql_dialect from the config, required for SQL format)
         #[arg(short, long)]
         dialect: Option<String>,
         /// Override table UUID (only for single-table imports)
@@ -98,8 +115,9 @@ enum Commands {
     },
     /// Export schemas to various formats
     Export {
-        /// Format to export to
-        #[arg(value_enum)]
+        /// Format to export to (odcs, avro, json-schema, protobuf, protobuf-descriptor, odps,
+        /// pdf, markdown, branded-markdown, or any format in the exporter registry, e.g. sql)
+        #[arg(value_parser = parse_export_format_arg)]
         format: ExportFormatArg,
         /// Input file (.odcs.yaml, .madr.yaml, or .kb.yaml)
         input: PathBuf,
@@ -130,7 +148,7 @@ enum Commands {
         /// Company or organization name (PDF and branded-markdown formats)
         #[arg(long)]
         company_name: Option<String>,
-        /// Include table of contents (branded-markdown format)
+        /// Include table of contents (PDF and branded-markdown formats)
         #[arg(long)]
         include_toc: bool,
     },
@@ -142,6 +160,116 @@ enum Commands {
         /// Input file path or '-' for stdin
         #[arg(default_value = "-")]
         input: String,
+        /// Custom property registry (YAML/JSON) to check customProperties against (odcs, odps)
+        #[arg(long)]
+        custom_properties: Option<PathBuf>,
+        /// Previous contract version; fail on backward-incompatible changes (odcs)
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+
+    /// Create a new workspace from a template
+    Init {
+        /// Directory to create the workspace in
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Template: simple, medallion, datamesh, warehouse or streaming
+        #[arg(short, long, default_value = "simple")]
+        template: WorkspaceTemplate,
+        /// Custom template definition (YAML); overrides --template
+        #[arg(long, conflicts_with = "template")]
+        template_file: Option<PathBuf>,
+        /// Workspace name (default: directory name)
+        #[arg(short, long)]
+        name: Option<String>,
+        /// Overwrite existing files
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Generate or refresh README.md files per domain
+    Readme {
+        /// Workspace path (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        workspace: PathBuf,
+        /// Directory to write domain READMEs to (default: workspace)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Maximum number of recent decisions listed per domain
+        #[arg(long, default_value = "5")]
+        recent_decisions: usize,
+        /// Fail if any README is out of date instead of writing it
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Classify and tag columns of all contracts using the workspace classification rules
+    Classify {
+        /// Workspace path (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        workspace: PathBuf,
+        /// Only report what would change, without writing contracts
+        #[arg(long)]
+        dry_run: bool,
+        /// Also apply the built-in PII rules
+        #[arg(long)]
+        pii_defaults: bool,
+        /// Write the Markdown report to a file instead of stdout
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
+    /// Add column descriptions and constraints from ORM models (SQLAlchemy,
+    /// Prisma, JPA) in application code to the workspace contracts
+    Annotate {
+        /// Application repository to scan
+        source: PathBuf,
+        /// Workspace path (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        workspace: PathBuf,
+        /// Only print the proposed changes as a diff, without writing contracts
+        #[arg(long)]
+        dry_run: bool,
+        /// Write the Markdown report to a file instead of stdout
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
+    /// Generate synthetic test records from an ODCS contract or JSON Schema
+    Generate {
+        /// ODCS contract or JSON Schema file (YAML or JSON)
+        input: PathBuf,
+        /// Schema object to generate records for (ODCS, default: first)
+        #[arg(short, long)]
+        schema: Option<String>,
+        /// Number of records to generate
+        #[arg(short = 'n', long, default_value = "10")]
+        count: usize,
+        /// Seed for reproducible output
+        #[arg(long, default_value = "42")]
+        seed: u64,
+        /// Share of optional fields included in each record (0.0-1.0)
+        #[arg(long, default_value = "0.5")]
+        optional_ratio: f64,
+        /// Output format (json, jsonl, csv)
+        #[arg(short, long, default_value = "json")]
+        format: String,
+        /// Output file path (stdout if not provided)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Show the layered SDK configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Show the features, formats, SQL dialects and LLM backends of this build
+    Capabilities {
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
     },
 
     /// Database management commands
@@ -216,6 +344,22 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Show the resolved configuration and the files it was read from
+    Show {
+        /// Workspace path (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        workspace: PathBuf,
+    },
+    /// Show the user and workspace configuration file paths
+    Path {
+        /// Workspace path (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        workspace: PathBuf,
+    },
+}
+
 #[cfg(feature = "staging")]
 #[derive(Subcommand)]
 enum StagingCommands {
@@ -252,7 +396,8 @@ enum StagingCommands {
         /// Path to the staging database file
         #[arg(short, long, default_value = "staging.duckdb")]
         database: PathBuf,
-        /// Source directory containing files to ingest
+        /// Source directory containing files to ingest, or an `s3://bucket/prefix`
+        /// URL (requires the s3 feature)
         source: PathBuf,
         /// File pattern to match (e.g., "*.json", "**/*.jsonl")
         #[arg(short, long, default_value = "*.json")]
@@ -266,12 +411,29 @@ enum StagingCommands {
         /// Batch size for database inserts
         #[arg(long, default_value = "1000")]
         batch_size: usize,
+        /// Number of files read and parsed in parallel (default: 4)
+        #[arg(short = 'j', long)]
+        workers: Option<usize>,
         /// Resume a previous batch
         #[arg(short, long)]
         resume: bool,
         /// Batch ID to resume (required with --resume)
         #[arg(long)]
         batch_id: Option<String>,
+        /// Split records into sub-partitions by a discriminator JSONPath (e.g., "$.event_type")
+        #[arg(long)]
+        split_by: Option<String>,
+        /// XSD file used to conform XML records to the declared structure
+        #[arg(long)]
+        xml_schema: Option<PathBuf>,
+        /// Deduplicate records by content or a key JSONPath, keeping the first or
+        /// last duplicate (e.g., "content", "$.event_id:last")
+        #[arg(long, value_parser = parse_record_dedup)]
+        record_dedup: Option<RecordDedup>,
+        /// ODCS contract or JSON Schema file to validate records against; records
+        /// that do not conform go to the dead_letter_records table
+        #[arg(long)]
+        record_schema: Option<PathBuf>,
     },
 
     /// Show staging database statistics
@@ -294,6 +456,26 @@ enum StagingCommands {
         limit: usize,
     },
 
+    /// Delete staged data by partition, batch or age
+    #[command(group(clap::ArgGroup::new("policy").required(true).args(["partition", "batch", "older_than"])))]
+    Purge {
+        /// Path to the staging database file
+        #[arg(short, long, default_value = "staging.duckdb")]
+        database: PathBuf,
+        /// Delete a partition and its sub-partitions
+        #[arg(short = 'k', long)]
+        partition: Option<String>,
+        /// Delete the data staged by a batch
+        #[arg(short, long)]
+        batch: Option<String>,
+        /// Delete data staged longer ago than this age (e.g., 30d, 12h)
+        #[arg(long, value_parser = parse_age)]
+        older_than: Option<std::time::Duration>,
+        /// Reclaim the space of deleted rows afterwards
+        #[arg(long)]
+        vacuum: bool,
+    },
+
     /// Execute SQL query against staging database
     Query {
         /// Path to the staging database file
@@ -325,6 +507,53 @@ enum StagingCommands {
         partition: Option<String>,
     },
 
+    /// Run a contract's quality rules against staged records
+    Quality {
+        /// Path to the staging database file
+        #[arg(short, long, default_value = "staging.duckdb")]
+        database: PathBuf,
+        /// ODCS contract whose quality rules to run
+        contract: PathBuf,
+        /// Partition to check (all records if not provided)
+        #[arg(short = 'k', long)]
+        partition: Option<String>,
+        /// Failing rows sampled per failed rule
+        #[arg(long, default_value = "5")]
+        samples: usize,
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Benchmark ingest and query throughput of staging backends on a synthetic workload
+    Bench {
+        /// Synthetic records per run
+        #[arg(long, default_value = "10000")]
+        records: usize,
+        /// Workload files per run
+        #[arg(long, default_value = "10")]
+        files: usize,
+        /// Batch sizes to compare (comma-separated)
+        #[arg(long, value_delimiter = ',', default_value = "500,1000,5000")]
+        batch_sizes: Vec<usize>,
+        /// Deduplication strategies to compare (comma-separated: none, path, content, both)
+        #[arg(long, value_delimiter = ',', default_value = "none,content", value_parser = parse_dedup_strategy)]
+        dedup: Vec<DedupStrategy>,
+        /// Connection string of a scratch PostgreSQL database to benchmark as well
+        /// (requires the staging-postgres feature)
+        #[arg(long)]
+        postgres: Option<String>,
+        /// Skip the DuckDB benchmark
+        #[arg(long)]
+        skip_duckdb: bool,
+        /// Directory for the workload files (a temporary directory if not provided)
+        #[arg(long)]
+        work_dir: Option<PathBuf>,
+        /// Output format (text, json, markdown)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Show table version history (requires Iceberg)
     History {
         /// Path to the staging database file
@@ -409,6 +638,31 @@ fn parse_dedup_strategy(s: &str) -> Result<DedupStrategy, String> {
     })
 }
 
+#[cfg(feature = "staging")]
+fn parse_record_dedup(s: &str) -> Result<RecordDedup, String> {
+    s.parse()
+}
+
+#[cfg(all(feature = "inference", feature = "staging"))]
+fn parse_conflict_policy(s: &str) -> Result<TypeConflictPolicy, String> {
+    s.parse().map_err(|_| {
+        format!(
+            "Invalid conflict policy: {}. Valid values: widen, fail, quarantine-field, prefer-majority",
+            s
+        )
+    })
+}
+
+#[cfg(all(feature = "inference", feature = "staging"))]
+fn parse_sampling_strategy(s: &str) -> Result<SamplingStrategy, String> {
+    s.parse().map_err(|_| {
+        format!(
+            "Invalid sampling strategy: {}. Valid values: random, first-n, reservoir, stratified, stratified:<json-path>, systematic",
+            s
+        )
+    })
+}
+
 #[cfg(all(feature = "inference", feature = "staging"))]
 #[derive(Subcommand)]
 enum InferenceCommands {
@@ -423,6 +677,9 @@ enum InferenceCommands {
         /// Sample size for inference
         #[arg(short, long, default_value = "1000")]
         sample_size: usize,
+        /// Sampling strategy (random, first-n, reservoir, stratified, stratified:<json-path>, systematic)
+        #[arg(long, default_value = "random", value_parser = parse_sampling_strategy)]
+        sampling: SamplingStrategy,
         /// Minimum field frequency (0.0-1.0)
         #[arg(long, default_value = "0.01")]
         min_frequency: f64,
@@ -432,6 +689,9 @@ enum InferenceCommands {
         /// Disable format detection
         #[arg(long)]
         no_formats: bool,
+        /// How to handle fields with conflicting types (widen, fail, quarantine-field, prefer-majority)
+        #[arg(long, default_value = "widen", value_parser = parse_conflict_policy)]
+        conflict_policy: TypeConflictPolicy,
         /// Output format (json, yaml, json-schema)
         #[arg(short, long, default_value = "json")]
         format: String,
@@ -443,13 +703,13 @@ enum InferenceCommands {
         /// LLM mode: none, online, offline (requires llm feature)
         #[arg(long, default_value = "none")]
         llm: String,
-        /// Ollama URL for online mode
-        #[arg(long, default_value = "http://localhost:11434")]
-        ollama_url: String,
-        /// Model name for LLM refinement
-        #[arg(long, default_value = "llama3.2")]
-        model: String,
-        /// Path to GGUF model file for offline mode
+        /// Ollama URL for online mode (default: llm.url from the config, or http://localhost:11434)
+        #[arg(long)]
+        ollama_url: Option<String>,
+        /// Model name for LLM refinement (default: llm.model from the config, or llama3.2)
+        #[arg(long)]
+        model: Option<String>,
+        /// Path to GGUF model file for offline mode (default: llm.model_path from the config)
         #[arg(long)]
         model_path: Option<PathBuf>,
         /// Path to documentation file for context
@@ -477,6 +737,18 @@ enum InferenceCommands {
         /// Output format (table, json)
         #[arg(short, long, default_value = "table")]
         format: String,
+        /// Records sampled per partition
+        #[arg(short, long, default_value = "100")]
+        sample_size: usize,
+        /// Sampling strategy (random, first-n, reservoir, stratified, stratified:<json-path>, systematic)
+        #[arg(long, default_value = "random", value_parser = parse_sampling_strategy)]
+        sampling: SamplingStrategy,
+        /// Cluster individual records so partitions with several message types are split
+        #[arg(long)]
+        by_record: bool,
+        /// Write one proposed ODCS contract per cluster into this directory
+        #[arg(long)]
+        contracts_dir: Option<PathBuf>,
     },
 }
 
@@ -509,13 +781,13 @@ enum PipelineCommands {
         /// LLM mode for inference: none, online, offline
         #[arg(long, default_value = "none")]
         llm_mode: String,
-        /// Ollama URL for online LLM mode
-        #[arg(long, default_value = "http://localhost:11434")]
-        ollama_url: String,
-        /// LLM model name
-        #[arg(long, default_value = "llama3.2")]
-        model: String,
-        /// Path to GGUF model file for offline mode
+        /// Ollama URL for online LLM mode (default: llm.url from the config, or http://localhost:11434)
+        #[arg(long)]
+        ollama_url: Option<String>,
+        /// LLM model name (default: llm.model from the config, or llama3.2)
+        #[arg(long)]
+        model: Option<String>,
+        /// Path to GGUF model file for offline mode (default: llm.model_path from the config)
         #[arg(long)]
         model_path: Option<PathBuf>,
         /// Path to documentation file for LLM context
@@ -588,10 +860,61 @@ enum DbCommands {
         #[arg(short, long)]
         output: Option<PathBuf>,
     },
+
+    /// Mirror data contracts into the metadata store
+    Mirror {
+        /// Workspace path
+        #[arg(default_value = ".")]
+        workspace: PathBuf,
+        /// Actor recorded on contract versions and audit events (default: $USER)
+        #[arg(long)]
+        actor: Option<String>,
+    },
+
+    /// Search mirrored contracts and tables
+    Search {
+        /// Free text (every term must match)
+        text: Option<String>,
+        /// Workspace path
+        #[arg(short, long, default_value = ".")]
+        workspace: PathBuf,
+        /// Name substring
+        #[arg(long)]
+        name: Option<String>,
+        /// Entity type (contract, table; repeatable)
+        #[arg(long = "type")]
+        entity_types: Vec<String>,
+        /// Tag (repeatable)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Classification (repeatable)
+        #[arg(long = "classification")]
+        classifications: Vec<String>,
+        /// Owner (repeatable)
+        #[arg(long = "owner")]
+        owners: Vec<String>,
+        /// Domain (repeatable)
+        #[arg(long = "domain")]
+        domains: Vec<String>,
+        /// Status (repeatable)
+        #[arg(long = "status")]
+        statuses: Vec<String>,
+        /// Number of results to skip
+        #[arg(long, default_value = "0")]
+        offset: usize,
+        /// Number of results to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
 enum ImportFormatArg {
+    /// Detect the format from the content
+    Auto,
     Sql,
     Avro,
     JsonSchema,
@@ -602,7 +925,7 @@ enum ImportFormatArg {
     Odps,
 }
 
-#[derive(clap::ValueEnum, Clone, Debug)]
+#[derive(Clone, Debug)]
 enum ExportFormatArg {
     Odcs,
     Avro,
@@ -616,6 +939,55 @@ enum ExportFormatArg {
     Markdown,
     /// Branded Markdown export with logo, header, footer
     BrandedMarkdown,
+    /// Format provided by the exporter registry
+    Registered(String),
+}
+
+/// Export formats handled directly by the CLI
+const CLI_EXPORT_FORMATS: &[&str] = &[
+    "odcs",
+    "avro",
+    "json-schema",
+    "protobuf",
+    "protobuf-descriptor",
+    "odps",
+    "pdf",
+    "markdown",
+    "branded-markdown",
+];
+
+/// Parse an export format name, falling back to the exporter registry.
+fn parse_export_format_arg(value: &str) -> Result<ExportFormatArg, String> {
+    let format = match value.to_lowercase().replace('_', "-").as_str() {
+        "odcs" => ExportFormatArg::Odcs,
+        "avro" => ExportFormatArg::Avro,
+        "json-schema" => ExportFormatArg::JsonSchema,
+        "protobuf" => ExportFormatArg::Protobuf,
+        "protobuf-descriptor" => ExportFormatArg::ProtobufDescriptor,
+        "odps" => ExportFormatArg::Odps,
+        "pdf" => ExportFormatArg::Pdf,
+        "markdown" => ExportFormatArg::Markdown,
+        "branded-markdown" => ExportFormatArg::BrandedMarkdown,
+        name => {
+            let registry = export_registry();
+            if !registry.contains(name) {
+                let mut available: Vec<String> =
+                    CLI_EXPORT_FORMATS.iter().map(|f| f.to_string()).collect();
+                for registered in registry.names() {
+                    if !available.contains(&registered) {
+                        available.push(registered);
+                    }
+                }
+                return Err(format!(
+                    "unknown export format '{}'. Available: {}",
+                    value,
+                    available.join(", ")
+                ));
+            }
+            ExportFormatArg::Registered(name.to_string())
+        }
+    };
+    Ok(format)
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -650,6 +1022,7 @@ enum ValidateFormatArg {
 
 fn convert_import_format(format: ImportFormatArg) -> ImportFormat {
     match format {
+        ImportFormatArg::Auto => unreachable!("auto is resolved by detect_import_format"),
         ImportFormatArg::Sql => ImportFormat::Sql,
         ImportFormatArg::Avro => ImportFormat::Avro,
         ImportFormatArg::JsonSchema => ImportFormat::JsonSchema,
@@ -672,6 +1045,7 @@ fn convert_export_format(format: ExportFormatArg) -> ExportFormat {
         ExportFormatArg::Pdf => ExportFormat::Pdf,
         ExportFormatArg::Markdown => ExportFormat::BrandedMarkdown, // Use same handler, no branding
         ExportFormatArg::BrandedMarkdown => ExportFormat::BrandedMarkdown,
+        ExportFormatArg::Registered(name) => ExportFormat::Registered(name),
     }
 }
 
@@ -703,11 +1077,43 @@ fn main() {
             message_type,
             root_message,
         } => {
-            let import_format = convert_import_format(format);
-
             // When --jar is provided, input is optional. Use a placeholder if not provided.
             let input_str = input.unwrap_or_else(|| "-".to_string());
-            let input_source = parse_input_source(&input_str, &import_format);
+            // Fall back to the configured dialect for SQL imports
+            let dialect = match dialect {
+                None if matches!(format, ImportFormatArg::Sql | ImportFormatArg::Auto) => {
+                    match load_config(std::path::Path::new("."), cli.profile.as_deref()) {
+                        Ok(config) => config.sql_dialect().map(str::to_string),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                dialect => dialect,
+            };
+            let detected = match format {
+                ImportFormatArg::Auto => {
+                    // Accept raw content as well as files, as for SQL
+                    let source = parse_input_source(&input_str, &ImportFormat::Sql);
+                    detect_import_format(&source).map(|(import_format, input_source)| {
+                        let dialect = dialect.or_else(|| Some("generic".to_string()));
+                        (import_format, input_source, dialect)
+                    })
+                }
+                format => {
+                    let import_format = convert_import_format(format);
+                    let input_source = parse_input_source(&input_str, &import_format);
+                    Ok((import_format, input_source, dialect))
+                }
+            };
+            let (import_format, input_source, dialect) = match detected {
+                Ok(detected) => detected,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
 
             let args = ImportArgs {
                 format: import_format,
@@ -796,6 +1202,7 @@ fn main() {
                 ExportFormat::ProtobufDescriptor => handle_export_protobuf_descriptor(&args),
                 ExportFormat::Odps => handle_export_odps(&args),
                 ExportFormat::Pdf => handle_export_pdf(&args),
+                ExportFormat::Registered(ref name) => handle_export_registered(&args, name),
                 ExportFormat::BrandedMarkdown => {
                     // If no branding options provided, use standard markdown export
                     if args.logo_url.is_none()
@@ -812,7 +1219,12 @@ fn main() {
                 }
             }
         }
-        Commands::Validate { format, input } => {
+        Commands::Validate {
+            format,
+            input,
+            custom_properties,
+            baseline,
+        } => {
             let validate_format = match format {
                 ValidateFormatArg::Odcs => "odcs",
                 ValidateFormatArg::Odcl => "odcl",
@@ -828,8 +1240,92 @@ fn main() {
                 ValidateFormatArg::DecisionsIndex => "decisions-index",
                 ValidateFormatArg::KnowledgeIndex => "knowledge-index",
             };
-            handle_validate(validate_format, &input)
+            handle_validate(
+                validate_format,
+                &input,
+                custom_properties.as_deref(),
+                baseline.as_deref(),
+            )
         }
+        Commands::Init {
+            path,
+            template,
+            template_file,
+            name,
+            force,
+        } => handle_init(&InitArgs {
+            path,
+            template,
+            template_file,
+            name,
+            force,
+        }),
+        Commands::Config { command } => match command {
+            ConfigCommands::Show { workspace } => handle_config_show(&ConfigArgs {
+                workspace,
+                profile: cli.profile,
+            }),
+            ConfigCommands::Path { workspace } => handle_config_path(&ConfigArgs {
+                workspace,
+                profile: cli.profile,
+            }),
+        },
+
+        Commands::Capabilities { format } => handle_capabilities(&CapabilitiesArgs { format }),
+
+        Commands::Readme {
+            workspace,
+            output,
+            recent_decisions,
+            check,
+        } => handle_readme(&ReadmeArgs {
+            workspace,
+            output,
+            recent_decisions,
+            check,
+        }),
+
+        Commands::Classify {
+            workspace,
+            dry_run,
+            pii_defaults,
+            report,
+        } => handle_classify(&ClassifyArgs {
+            workspace,
+            dry_run,
+            pii_defaults,
+            report,
+        }),
+
+        Commands::Annotate {
+            source,
+            workspace,
+            dry_run,
+            report,
+        } => handle_annotate(&AnnotateArgs {
+            source,
+            workspace,
+            dry_run,
+            report,
+        }),
+
+        Commands::Generate {
+            input,
+            schema,
+            count,
+            seed,
+            optional_ratio,
+            format,
+            output,
+        } => handle_generate(&GenerateArgs {
+            input,
+            schema,
+            count,
+            seed,
+            optional_ratio,
+            format,
+            output,
+        }),
 
         #[cfg(feature = "duckdb-backend")]
         Commands::Db { command } => match command {
@@ -857,6 +1353,40 @@ fn main() {
                 let args = DbExportArgs { workspace, output };
                 handle_db_export(&args)
             }
+            DbCommands::Mirror { workspace, actor } => {
+                let args = DbMirrorArgs { workspace, actor };
+                handle_db_mirror(&args)
+            }
+            DbCommands::Search {
+                text,
+                workspace,
+                name,
+                entity_types,
+                tags,
+                classifications,
+                owners,
+                domains,
+                statuses,
+                offset,
+                limit,
+                format,
+            } => {
+                let args = DbSearchArgs {
+                    workspace,
+                    text,
+                    name,
+                    entity_types,
+                    tags,
+                    classifications,
+                    owners,
+                    domains,
+                    statuses,
+                    offset,
+                    limit,
+                    format,
+                };
+                handle_db_search(&args)
+            }
         },
 
         #[cfg(feature = "duckdb-backend")]
@@ -904,8 +1434,13 @@ fn main() {
                 partition,
                 dedup,
                 batch_size,
+                workers,
                 resume,
                 batch_id,
+                split_by,
+                xml_schema,
+                record_dedup,
+                record_schema,
             } => {
                 let args = StagingIngestArgs {
                     database,
@@ -914,8 +1449,13 @@ fn main() {
                     partition,
                     dedup,
                     batch_size,
+                    workers,
                     resume,
                     batch_id,
+                    split_by,
+                    xml_schema,
+                    record_dedup,
+                    record_schema,
                 };
                 handle_staging_ingest(&args)
             }
@@ -933,6 +1473,26 @@ fn main() {
                 let args = StagingBatchesArgs { database, limit };
                 handle_staging_batches(&args)
             }
+            StagingCommands::Purge {
+                database,
+                partition,
+                batch,
+                older_than,
+                vacuum,
+            } => {
+                let policy = match (partition, batch, older_than) {
+                    (Some(partition), _, _) => PurgePolicy::Partition(partition),
+                    (_, Some(batch), _) => PurgePolicy::Batch(batch),
+                    (_, _, Some(age)) => PurgePolicy::OlderThan(age),
+                    (None, None, None) => unreachable!("clap requires a purge policy"),
+                };
+                let args = StagingPurgeArgs {
+                    database,
+                    policy,
+                    vacuum,
+                };
+                handle_staging_purge(&args)
+            }
             StagingCommands::Query {
                 database,
                 sql,
@@ -961,6 +1521,44 @@ fn main() {
                 };
                 handle_staging_sample(&args)
             }
+            StagingCommands::Quality {
+                database,
+                contract,
+                partition,
+                samples,
+                format,
+            } => {
+                let args = StagingQualityArgs {
+                    database,
+                    contract,
+                    partition,
+                    samples,
+                    format,
+                };
+                handle_staging_quality(&args)
+            }
+            StagingCommands::Bench {
+                records,
+                files,
+                batch_sizes,
+                dedup,
+                postgres,
+                skip_duckdb,
+                work_dir,
+                format,
+            } => {
+                let args = StagingBenchArgs {
+                    records,
+                    files,
+                    batch_sizes,
+                    dedup,
+                    postgres,
+                    skip_duckdb,
+                    work_dir,
+                    format,
+                };
+                handle_staging_bench(&args)
+            }
             StagingCommands::History {
                 database,
                 table,
@@ -1023,9 +1621,11 @@ fn main() {
                 database,
                 partition,
                 sample_size,
+                sampling,
                 min_frequency,
                 max_depth,
                 no_formats,
+                conflict_policy,
                 format,
                 output,
                 llm,
@@ -1036,36 +1636,54 @@ fn main() {
                 no_refine,
                 temperature,
                 verbose_llm,
-            } => {
-                let args = InferenceInferArgs {
-                    database,
-                    partition,
-                    sample_size,
-                    min_frequency,
-                    max_depth,
-                    detect_formats: !no_formats,
-                    format,
-                    output,
-                    llm_mode: llm,
-                    ollama_url,
-                    model,
-                    model_path,
-                    doc_path,
-                    no_refine,
-                    temperature,
-                    verbose_llm,
-                };
-                handle_inference_infer(&args)
-            }
+            } => match load_config(std::path::Path::new("."), cli.profile.as_deref()) {
+                Ok(config) => {
+                    let llm_config = config.settings.llm;
+                    let args = InferenceInferArgs {
+                        database,
+                        partition,
+                        sample_size,
+                        sampling,
+                        min_frequency,
+                        max_depth,
+                        detect_formats: !no_formats,
+                        conflict_policy,
+                        format,
+                        output,
+                        llm_mode: llm,
+                        ollama_url: ollama_url.or(llm_config.url).unwrap_or_else(|| {
+                            data_modelling_core::config::DEFAULT_LLM_URL.to_string()
+                        }),
+                        model: model.or(llm_config.model).unwrap_or_else(|| {
+                            data_modelling_core::config::DEFAULT_LLM_MODEL.to_string()
+                        }),
+                        model_path: model_path.or(llm_config.model_path),
+                        doc_path,
+                        no_refine,
+                        temperature,
+                        verbose_llm,
+                    };
+                    handle_inference_infer(&args)
+                }
+                Err(e) => Err(e),
+            },
             InferenceCommands::Schemas {
                 database,
                 threshold,
                 format,
+                sample_size,
+                sampling,
+                by_record,
+                contracts_dir,
             } => {
                 let args = InferenceSchemasArgs {
                     database,
                     threshold,
                     format,
+                    sample_size,
+                    sampling,
+                    by_record,
+                    contracts_dir,
                 };
                 handle_inference_schemas(&args)
             }
@@ -1117,28 +1735,36 @@ fn main() {
                 dry_run,
                 resume,
                 verbose,
-            } => {
-                let args = PipelineRunArgs {
-                    database,
-                    source,
-                    pattern,
-                    partition,
-                    output_dir,
-                    target_schema,
-                    stages,
-                    llm_mode,
-                    ollama_url,
-                    model,
-                    model_path,
-                    doc_path,
-                    temperature,
-                    config_file,
-                    dry_run,
-                    resume,
-                    verbose,
-                };
-                handle_pipeline_run(&args)
-            }
+            } => match load_config(std::path::Path::new("."), cli.profile.as_deref()) {
+                Ok(config) => {
+                    let llm_config = config.settings.llm;
+                    let args = PipelineRunArgs {
+                        database,
+                        source,
+                        pattern,
+                        partition,
+                        output_dir,
+                        target_schema,
+                        stages,
+                        llm_mode,
+                        ollama_url: ollama_url.or(llm_config.url).unwrap_or_else(|| {
+                            data_modelling_core::config::DEFAULT_LLM_URL.to_string()
+                        }),
+                        model: model.or(llm_config.model).unwrap_or_else(|| {
+                            data_modelling_core::config::DEFAULT_LLM_MODEL.to_string()
+                        }),
+                        model_path: model_path.or(llm_config.model_path),
+                        doc_path,
+                        temperature,
+                        config_file,
+                        dry_run,
+                        resume,
+                        verbose,
+                    };
+                    handle_pipeline_run(&args)
+                }
+                Err(e) => Err(e),
+            },
             PipelineCommands::Status { database } => {
                 let args = PipelineStatusArgs { database };
                 handle_pipeline_status(&args)
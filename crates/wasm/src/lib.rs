@@ -365,6 +365,49 @@ pub fn import_from_sql(sql_content: &str, dialect: &str) -> Result<String, JsVal
     }
 }
 
+/// Import data model from content in an unknown format.
+///
+/// The format (SQL, AVRO, JSON Schema, Protobuf, ODCS or ODCL) is detected from the
+/// content, e.g. for file uploads.
+///
+/// # Arguments
+///
+/// * `content` - Schema or contract content as a string
+///
+/// # Returns
+///
+/// JSON string containing an object with `format`, `confidence` and `result`
+/// (ImportResult), or JsValue error
+#[wasm_bindgen]
+pub fn import_auto(content: &str) -> Result<String, JsValue> {
+    match data_modelling_core::import::import_auto(content) {
+        Ok(detected) => {
+            let flattened = flatten_struct_columns(detected.result);
+            let response = serde_json::json!({
+                "format": detected.format,
+                "confidence": detected.confidence,
+                "result": flattened,
+            });
+            serde_json::to_string(&response).map_err(serialization_error)
+        }
+        Err(err) => Err(import_error_to_js(err)),
+    }
+}
+
+/// Report what this build of the SDK supports.
+///
+/// Frontends can use this to offer only the import/export formats, SQL dialects
+/// and features that are compiled in.
+///
+/// # Returns
+///
+/// JSON string containing a Capabilities object (`version`, `features`,
+/// `importFormats`, `exportFormats`, `sqlDialects`, `llmBackends`), or JsValue error
+#[wasm_bindgen]
+pub fn capabilities() -> Result<String, JsValue> {
+    serde_json::to_string(&data_modelling_core::capabilities()).map_err(serialization_error)
+}
+
 /// Import data model from AVRO schema.
 ///
 /// # Arguments